@@ -0,0 +1,39 @@
+// Triangular route search: scalar reference vs data-parallel scan
+// Run with: cargo bench --bench route_search
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use sniperforge::trading::route_matrix::{scan_parallel, scan_scalar, RateGraph};
+
+/// Dense graph of near-1 rates from a fixed-seed LCG
+fn dense_graph(n: usize) -> RateGraph {
+    let tokens: Vec<String> = (0..n).map(|t| format!("T{}", t)).collect();
+    let mut state = 42u64;
+    let mut rates = Vec::with_capacity(n * n);
+    for from in &tokens {
+        for to in &tokens {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            if from != to {
+                let noise = ((state >> 11) as f64 / (1u64 << 53) as f64 - 0.5) * 0.01;
+                rates.push((from.as_str(), to.as_str(), 0.997 + noise));
+            }
+        }
+    }
+    RateGraph::from_rates(rates)
+}
+
+fn benchmark_route_search(c: &mut Criterion) {
+    let mut group = c.benchmark_group("triangular_route_search");
+    for n in [64, 256, 512] {
+        let graph = dense_graph(n);
+        group.bench_with_input(BenchmarkId::new("scalar", n), &graph, |b, graph| {
+            b.iter(|| black_box(scan_scalar(graph, 0.0)))
+        });
+        group.bench_with_input(BenchmarkId::new("parallel", n), &graph, |b, graph| {
+            b.iter(|| black_box(scan_parallel(graph, 0.0)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, benchmark_route_search);
+criterion_main!(benches);
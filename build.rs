@@ -0,0 +1,213 @@
+//! Generates `types::hot_path` from `config/hot_path_registry.json`
+//!
+//! Token decimals, program IDs and market tick sizes are looked up on every
+//! quote, so they are frozen into constants at build time. The registry is
+//! validated here: a malformed address, duplicate entry or nonsensical
+//! decimals/tick size fails the build instead of the first trade.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use serde_json::Value;
+
+const REGISTRY: &str = "config/hot_path_registry.json";
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", REGISTRY);
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR");
+    let source = std::fs::read_to_string(Path::new(&manifest_dir).join(REGISTRY))
+        .unwrap_or_else(|e| panic!("cannot read {}: {}", REGISTRY, e));
+    let generated = match generate(&source) {
+        Ok(generated) => generated,
+        Err(errors) => panic!("{} is invalid:\n  - {}", REGISTRY, errors.join("\n  - ")),
+    };
+    let out = Path::new(&std::env::var("OUT_DIR").expect("OUT_DIR")).join("hot_path_constants.rs");
+    std::fs::write(&out, generated).unwrap_or_else(|e| panic!("cannot write {}: {}", out.display(), e));
+}
+
+/// Decoded length of a base58 string, `None` on characters outside the alphabet
+fn base58_len(text: &str) -> Option<usize> {
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in text.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|a| *a == c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+    let leading_zeros = text.bytes().take_while(|c| *c == b'1').count();
+    Some(bytes.len() + leading_zeros)
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase()) && name.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn fnv1a64(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3))
+}
+
+fn entries<'a>(registry: &'a Value, key: &str, errors: &mut Vec<String>) -> &'a [Value] {
+    match registry.get(key).and_then(Value::as_array) {
+        Some(entries) => entries,
+        None => {
+            errors.push(format!("`{}` must be an array", key));
+            &[]
+        }
+    }
+}
+
+fn text<'a>(entry: &'a Value, key: &str, context: &str, errors: &mut Vec<String>) -> &'a str {
+    entry.get(key).and_then(Value::as_str).unwrap_or_else(|| {
+        errors.push(format!("{}: missing string `{}`", context, key));
+        ""
+    })
+}
+
+fn check_pubkey(address: &str, context: &str, seen: &mut HashSet<String>, errors: &mut Vec<String>) {
+    if base58_len(address) != Some(32) {
+        errors.push(format!("{}: `{}` is not a base58 32-byte public key", context, address));
+    }
+    if !seen.insert(address.to_string()) {
+        errors.push(format!("{}: address {} is listed twice", context, address));
+    }
+}
+
+fn check_name(name: &str, context: &str, seen: &mut HashSet<String>, errors: &mut Vec<String>) {
+    if !is_identifier(name) {
+        errors.push(format!("{}: `{}` must be UPPER_SNAKE_CASE", context, name));
+    }
+    if !seen.insert(name.to_string()) {
+        errors.push(format!("{}: `{}` is listed twice", context, name));
+    }
+}
+
+fn generate(source: &str) -> Result<String, Vec<String>> {
+    let registry: Value = serde_json::from_str(source).map_err(|e| vec![e.to_string()])?;
+    let mut errors = Vec::new();
+    let mut addresses = HashSet::new();
+
+    let mut tokens = Vec::new();
+    let mut symbols = HashSet::new();
+    for (index, entry) in entries(&registry, "tokens", &mut errors).iter().enumerate() {
+        let context = format!("tokens[{}]", index);
+        let symbol = text(entry, "symbol", &context, &mut errors);
+        let mint = text(entry, "mint", &context, &mut errors);
+        check_name(symbol, &context, &mut symbols, &mut errors);
+        check_pubkey(mint, &context, &mut addresses, &mut errors);
+        match entry.get("decimals").and_then(Value::as_u64) {
+            Some(decimals) if decimals <= 18 => tokens.push((symbol, mint, decimals)),
+            _ => errors.push(format!("{}: `decimals` must be an integer from 0 to 18", context)),
+        }
+    }
+
+    let mut programs = Vec::new();
+    let mut program_names = HashSet::new();
+    for (index, entry) in entries(&registry, "programs", &mut errors).iter().enumerate() {
+        let context = format!("programs[{}]", index);
+        let name = text(entry, "name", &context, &mut errors);
+        let program_id = text(entry, "program_id", &context, &mut errors);
+        check_name(name, &context, &mut program_names, &mut errors);
+        check_pubkey(program_id, &context, &mut addresses, &mut errors);
+        programs.push((name, program_id));
+    }
+
+    let mut markets = Vec::new();
+    let mut market_names = HashSet::new();
+    for (index, entry) in entries(&registry, "markets", &mut errors).iter().enumerate() {
+        let context = format!("markets[{}]", index);
+        let name = text(entry, "name", &context, &mut errors);
+        let address = text(entry, "address", &context, &mut errors);
+        check_name(name, &context, &mut market_names, &mut errors);
+        check_pubkey(address, &context, &mut addresses, &mut errors);
+        let program = text(entry, "program", &context, &mut errors);
+        let Some((_, program_id)) = programs.iter().find(|(name, _)| *name == program) else {
+            errors.push(format!("{}: unknown program `{}`", context, program));
+            continue;
+        };
+        let mut mint_of = |key: &str| {
+            let symbol = text(entry, key, &context, &mut errors);
+            let mint = tokens.iter().find(|(s, _, _)| *s == symbol).map(|(_, mint, _)| *mint);
+            if mint.is_none() {
+                errors.push(format!("{}: unknown {} token `{}`", context, key, symbol));
+            }
+            mint.unwrap_or_default()
+        };
+        let (base, quote) = (mint_of("base"), mint_of("quote"));
+        match entry.get("tick_size").and_then(Value::as_f64) {
+            Some(tick) if tick.is_finite() && tick > 0.0 => markets.push((name, address, *program_id, base, quote, tick)),
+            _ => errors.push(format!("{}: `tick_size` must be a positive number", context)),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut out = String::new();
+    let _ = writeln!(out, "// @generated by build.rs from {}; edit the registry, not this file\n", REGISTRY);
+    let _ = writeln!(out, "/// FNV-1a of the registry the constants were generated from");
+    let _ = writeln!(out, "pub const REGISTRY_FINGERPRINT: &str = \"{:016x}\";\n", fnv1a64(source));
+
+    let _ = writeln!(out, "pub mod tokens {{");
+    for (symbol, mint, decimals) in &tokens {
+        let _ = writeln!(out, "    pub const {}_MINT: &str = \"{}\";", symbol, mint);
+        let _ = writeln!(out, "    pub const {}_DECIMALS: u8 = {};", symbol, decimals);
+    }
+    let _ = writeln!(out, "}}\n\npub mod programs {{");
+    for (name, program_id) in &programs {
+        let _ = writeln!(out, "    pub const {}: &str = \"{}\";", name, program_id);
+    }
+    let _ = writeln!(out, "}}\n\npub mod markets {{");
+    for (name, address, _, _, _, tick) in &markets {
+        let _ = writeln!(out, "    pub const {}: &str = \"{}\";", name, address);
+        let _ = writeln!(out, "    pub const {}_TICK_SIZE: f64 = {:?};", name, tick);
+    }
+    let _ = writeln!(out, "}}\n");
+
+    let _ = writeln!(out, "pub static TOKENS: &[TokenConstant] = &[");
+    for (symbol, mint, decimals) in &tokens {
+        let _ = writeln!(out, "    TokenConstant {{ symbol: \"{}\", mint: \"{}\", decimals: {} }},", symbol, mint, decimals);
+    }
+    let _ = writeln!(out, "];\n\npub static PROGRAMS: &[ProgramConstant] = &[");
+    for (name, program_id) in &programs {
+        let _ = writeln!(out, "    ProgramConstant {{ name: \"{}\", program_id: \"{}\" }},", name, program_id);
+    }
+    let _ = writeln!(out, "];\n\npub static MARKETS: &[MarketConstant] = &[");
+    for (name, address, program_id, base, quote, tick) in &markets {
+        let _ = writeln!(out, "    MarketConstant {{ name: \"{}\", address: \"{}\", program_id: \"{}\", base_mint: \"{}\", quote_mint: \"{}\", tick_size: {:?} }},",
+            name, address, program_id, base, quote, tick);
+    }
+    let _ = writeln!(out, "];\n");
+
+    let _ = writeln!(out, "/// Decimals of a registry mint");
+    let _ = writeln!(out, "#[allow(clippy::match_single_binding)]");
+    let _ = writeln!(out, "pub fn token_decimals(mint: &str) -> Option<u8> {{\n    match mint {{");
+    for (_, mint, decimals) in &tokens {
+        let _ = writeln!(out, "        \"{}\" => Some({}),", mint, decimals);
+    }
+    let _ = writeln!(out, "        _ => None,\n    }}\n}}\n");
+    let _ = writeln!(out, "/// Mint of a registry symbol");
+    let _ = writeln!(out, "#[allow(clippy::match_single_binding)]");
+    let _ = writeln!(out, "pub fn token_mint(symbol: &str) -> Option<&'static str> {{\n    match symbol {{");
+    for (symbol, mint, _) in &tokens {
+        let _ = writeln!(out, "        \"{}\" => Some(\"{}\"),", symbol, mint);
+    }
+    let _ = writeln!(out, "        _ => None,\n    }}\n}}\n");
+    let _ = writeln!(out, "/// Tick size of a registry market, by address");
+    let _ = writeln!(out, "#[allow(clippy::match_single_binding)]");
+    let _ = writeln!(out, "pub fn tick_size(market: &str) -> Option<f64> {{\n    match market {{");
+    for (_, address, _, _, _, tick) in &markets {
+        let _ = writeln!(out, "        \"{}\" => Some({:?}),", address, tick);
+    }
+    let _ = writeln!(out, "        _ => None,\n    }}\n}}");
+    Ok(out)
+}
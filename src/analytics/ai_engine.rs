@@ -2,12 +2,13 @@
 //! Sistema de inteligencia artificial para predicción de mercado,
 //! optimización de estrategias y análisis de oportunidades de arbitraje
 
+use crate::apis::fiat_rates::{fiat_rates, FiatAsset};
 use crate::config::SimpleConfig;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 use rand;
 
 /// Configuración del motor de AI empresarial
@@ -589,15 +590,20 @@ impl EnterpriseAIEngine {
         let mut analysis = HashMap::new();
         
         for token in tokens {
-            // En producción obtendría precio real de la API
-            let simulated_price = match token.as_str() {
-                "SOL" => 150.0 + (rand::random::<f64>() - 0.5) * 10.0,
-                "ETH" => 2500.0 + (rand::random::<f64>() - 0.5) * 200.0,
-                "BTC" => 45000.0 + (rand::random::<f64>() - 0.5) * 2000.0,
-                _ => 1.0 + (rand::random::<f64>() - 0.5) * 0.1,
+            // Precio actual desde la tasa fiat compartida; tokens sin tasa quedan fuera del análisis
+            let Some(asset) = FiatAsset::from_symbol(token) else {
+                debug!("🤖 Sin fuente de precio para {} - omitido del análisis", token);
+                continue;
+            };
+            let current_price = match fiat_rates().get_rate(asset).await {
+                Ok(rate) => rate.usd,
+                Err(e) => {
+                    warn!("⚠️ Sin tasa {}/USD para análisis de mercado: {}", asset.symbol(), e);
+                    continue;
+                }
             };
             
-            if let Some(prediction) = self.predict_price(token, simulated_price, 30).await? {
+            if let Some(prediction) = self.predict_price(token, current_price, 30).await? {
                 analysis.insert(token.clone(), prediction);
             }
         }
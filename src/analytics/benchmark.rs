@@ -0,0 +1,275 @@
+//! Strategy vs HODL benchmarks
+//!
+//! Absolute PnL says little on its own: a strategy that made 3% while SOL ran
+//! 40% did worse than doing nothing with the same capital. The tracker samples
+//! the SOL price alongside the strategy's cumulative PnL and values three
+//! passive alternatives from the same starting capital: all in SOL, all in
+//! USDC, and a 50/50 split held without rebalancing. The report gives each
+//! benchmark's return, the strategy's excess return over it and the
+//! correlation of their per-sample returns (a strategy that merely tracks SOL
+//! shows up as correlation near 1).
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Passive alternative the strategy is compared against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Benchmark {
+    HodlSol,
+    HodlUsdc,
+    /// Half SOL, half USDC at the start, never rebalanced
+    FiftyFifty,
+}
+
+impl Benchmark {
+    pub const ALL: [Benchmark; 3] = [Benchmark::HodlSol, Benchmark::HodlUsdc, Benchmark::FiftyFifty];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Benchmark::HodlSol => "HODL SOL",
+            Benchmark::HodlUsdc => "HODL USDC",
+            Benchmark::FiftyFifty => "50/50 SOL/USDC",
+        }
+    }
+
+    /// Value of `capital` put into this benchmark at `start_sol_usd`, at `sol_usd`
+    pub fn value(&self, capital: f64, start_sol_usd: f64, sol_usd: f64) -> f64 {
+        let sol_growth = if start_sol_usd > 0.0 { sol_usd / start_sol_usd } else { 1.0 };
+        match self {
+            Benchmark::HodlSol => capital * sol_growth,
+            Benchmark::HodlUsdc => capital,
+            Benchmark::FiftyFifty => capital * (0.5 + 0.5 * sol_growth),
+        }
+    }
+}
+
+/// Benchmark tracking settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkConfig {
+    /// Capital (USD) the strategy and every benchmark start from
+    pub initial_capital_usd: f64,
+    /// Samples kept; the oldest retained sample is the comparison start
+    pub max_samples: usize,
+}
+
+impl Default for BenchmarkConfig {
+    fn default() -> Self {
+        Self {
+            initial_capital_usd: 10_000.0,
+            max_samples: 10_000,
+        }
+    }
+}
+
+/// SOL price and strategy equity at one point in time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkSample {
+    pub at: DateTime<Utc>,
+    pub sol_usd: f64,
+    pub strategy_equity_usd: f64,
+}
+
+/// Strategy against one benchmark over the report window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkComparison {
+    pub benchmark: Benchmark,
+    pub return_percent: f64,
+    /// Strategy return minus benchmark return, in percentage points
+    pub excess_return_percent: f64,
+    /// Correlation of per-sample returns; `None` when either series is flat
+    pub correlation: Option<f64>,
+}
+
+/// Relative performance over the tracked window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkReport {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub samples: usize,
+    pub strategy_return_percent: f64,
+    pub comparisons: Vec<BenchmarkComparison>,
+}
+
+impl BenchmarkReport {
+    pub fn comparison(&self, benchmark: Benchmark) -> Option<&BenchmarkComparison> {
+        self.comparisons.iter().find(|comparison| comparison.benchmark == benchmark)
+    }
+
+    /// Benchmarks the strategy did not beat
+    pub fn underperformed(&self) -> Vec<Benchmark> {
+        self.comparisons
+            .iter()
+            .filter(|comparison| comparison.excess_return_percent < 0.0)
+            .map(|comparison| comparison.benchmark)
+            .collect()
+    }
+}
+
+/// Records strategy equity next to the SOL price and compares it with HODL
+#[derive(Debug, Clone)]
+pub struct BenchmarkTracker {
+    config: BenchmarkConfig,
+    cumulative_pnl_usd: f64,
+    samples: VecDeque<BenchmarkSample>,
+}
+
+impl Default for BenchmarkTracker {
+    fn default() -> Self {
+        Self::new(BenchmarkConfig::default())
+    }
+}
+
+impl BenchmarkTracker {
+    pub fn new(config: BenchmarkConfig) -> Self {
+        Self { config, cumulative_pnl_usd: 0.0, samples: VecDeque::new() }
+    }
+
+    pub fn config(&self) -> &BenchmarkConfig {
+        &self.config
+    }
+
+    /// Add a period's PnL and sample the SOL price
+    ///
+    /// Without a price no sample is taken, but the PnL still counts.
+    pub fn record(&mut self, sol_usd: Option<f64>, pnl_usd: f64) {
+        self.record_at(Utc::now(), sol_usd, pnl_usd);
+    }
+
+    pub fn record_at(&mut self, at: DateTime<Utc>, sol_usd: Option<f64>, pnl_usd: f64) {
+        self.cumulative_pnl_usd += pnl_usd;
+        let Some(sol_usd) = sol_usd.filter(|usd| *usd > 0.0 && usd.is_finite()) else { return };
+        self.samples.push_back(BenchmarkSample {
+            at,
+            sol_usd,
+            strategy_equity_usd: self.config.initial_capital_usd + self.cumulative_pnl_usd,
+        });
+        while self.samples.len() > self.config.max_samples.max(2) {
+            self.samples.pop_front();
+        }
+    }
+
+    pub fn samples(&self) -> &VecDeque<BenchmarkSample> {
+        &self.samples
+    }
+
+    /// Comparison over the retained samples; `None` until there are two
+    pub fn report(&self) -> Option<BenchmarkReport> {
+        let (first, last) = (self.samples.front()?, self.samples.back()?);
+        if self.samples.len() < 2 {
+            return None;
+        }
+
+        let strategy: Vec<f64> = self.samples.iter().map(|sample| sample.strategy_equity_usd).collect();
+        let strategy_return_percent = percent_change(first.strategy_equity_usd, last.strategy_equity_usd);
+        let strategy_returns = period_returns(&strategy);
+
+        let comparisons = Benchmark::ALL
+            .iter()
+            .map(|benchmark| {
+                // Scaled to the strategy's equity at the window start
+                let series: Vec<f64> = self
+                    .samples
+                    .iter()
+                    .map(|sample| benchmark.value(first.strategy_equity_usd, first.sol_usd, sample.sol_usd))
+                    .collect();
+                let return_percent = percent_change(series[0], series[series.len() - 1]);
+                BenchmarkComparison {
+                    benchmark: *benchmark,
+                    return_percent,
+                    excess_return_percent: strategy_return_percent - return_percent,
+                    correlation: correlation(&strategy_returns, &period_returns(&series)),
+                }
+            })
+            .collect();
+
+        Some(BenchmarkReport {
+            start: first.at,
+            end: last.at,
+            samples: self.samples.len(),
+            strategy_return_percent,
+            comparisons,
+        })
+    }
+}
+
+fn percent_change(from: f64, to: f64) -> f64 {
+    if from.abs() > f64::EPSILON { (to - from) / from * 100.0 } else { 0.0 }
+}
+
+fn period_returns(series: &[f64]) -> Vec<f64> {
+    series
+        .windows(2)
+        .map(|pair| if pair[0].abs() > f64::EPSILON { pair[1] / pair[0] - 1.0 } else { 0.0 })
+        .collect()
+}
+
+/// Pearson correlation; `None` for fewer than two points or a flat series
+fn correlation(a: &[f64], b: &[f64]) -> Option<f64> {
+    let n = a.len().min(b.len());
+    if n < 2 {
+        return None;
+    }
+    let (a, b) = (&a[..n], &b[..n]);
+    let mean_a = a.iter().sum::<f64>() / n as f64;
+    let mean_b = b.iter().sum::<f64>() / n as f64;
+    let (mut covariance, mut variance_a, mut variance_b) = (0.0, 0.0, 0.0);
+    for (x, y) in a.iter().zip(b) {
+        covariance += (x - mean_a) * (y - mean_b);
+        variance_a += (x - mean_a).powi(2);
+        variance_b += (y - mean_b).powi(2);
+    }
+    let denominator = (variance_a * variance_b).sqrt();
+    (denominator > 1e-18).then(|| (covariance / denominator).clamp(-1.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_benchmarks_start_from_same_capital() {
+        let mut tracker = BenchmarkTracker::new(BenchmarkConfig { initial_capital_usd: 1_000.0, ..Default::default() });
+        let start = Utc::now();
+        tracker.record_at(start, Some(100.0), 0.0);
+        assert!(tracker.report().is_none());
+
+        // SOL +20%, strategy +5%
+        tracker.record_at(start + Duration::hours(1), Some(120.0), 50.0);
+        let report = tracker.report().unwrap();
+
+        assert!((report.strategy_return_percent - 5.0).abs() < 1e-9);
+        let sol = report.comparison(Benchmark::HodlSol).unwrap();
+        assert!((sol.return_percent - 20.0).abs() < 1e-9);
+        assert!((sol.excess_return_percent + 15.0).abs() < 1e-9);
+        assert!((report.comparison(Benchmark::FiftyFifty).unwrap().return_percent - 10.0).abs() < 1e-9);
+        assert_eq!(report.comparison(Benchmark::HodlUsdc).unwrap().return_percent, 0.0);
+        assert_eq!(report.underperformed(), vec![Benchmark::HodlSol, Benchmark::FiftyFifty]);
+    }
+
+    #[test]
+    fn test_correlation_flags_strategy_that_tracks_sol() {
+        let mut tracker = BenchmarkTracker::default();
+        let start = Utc::now();
+        let prices = [100.0, 110.0, 99.0, 108.9, 130.68];
+        let mut equity = 10_000.0;
+        for (i, price) in prices.iter().enumerate() {
+            // Strategy equity moves exactly with SOL
+            let next = 10_000.0 * price / prices[0];
+            tracker.record_at(start + Duration::minutes(i as i64), Some(*price), next - equity);
+            equity = next;
+        }
+        // A period without a price only moves the PnL
+        tracker.record_at(start + Duration::minutes(9), None, 0.0);
+
+        let report = tracker.report().unwrap();
+        assert_eq!(report.samples, prices.len());
+        let sol = report.comparison(Benchmark::HodlSol).unwrap();
+        assert!((sol.correlation.unwrap() - 1.0).abs() < 1e-9);
+        assert!(sol.excess_return_percent.abs() < 1e-9);
+        // Cash has no variance to correlate with
+        assert!(report.comparison(Benchmark::HodlUsdc).unwrap().correlation.is_none());
+    }
+}
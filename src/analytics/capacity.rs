@@ -0,0 +1,314 @@
+//! Strategy capacity estimation
+//!
+//! A strategy's edge per trade is roughly fixed in bps, but price impact grows
+//! with trade size, so past some size each extra dollar deployed earns less
+//! and eventually nothing. This module estimates, per strategy, the largest
+//! trade size whose net edge still clears a threshold: the capital beyond it
+//! does not help.
+//!
+//! Impact at a given size comes from two sources: depth curves (quoted price
+//! impact at several sizes, e.g. Jupiter quotes) and historical slippage
+//! (realized cost vs size from TCA). History is fitted to a power law
+//! `impact = a * size^b`; where both sources exist the larger impact is used,
+//! so the estimate errs on the side of less capacity.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::debug;
+
+use super::tca::TcaResult;
+
+/// Search range and threshold for capacity estimates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapacityConfig {
+    /// Net edge (after impact and fixed costs) a trade size must keep (bps)
+    pub min_net_edge_bps: f64,
+    pub min_size_usd: f64,
+    pub max_size_usd: f64,
+    /// Sizes evaluated between the bounds (geometric spacing)
+    pub grid_points: usize,
+    /// Slippage observations needed before history is fitted
+    pub min_observations: usize,
+}
+
+impl Default for CapacityConfig {
+    fn default() -> Self {
+        Self {
+            min_net_edge_bps: 5.0,
+            min_size_usd: 10.0,
+            max_size_usd: 1_000_000.0,
+            grid_points: 60,
+            min_observations: 5,
+        }
+    }
+}
+
+/// Price impact quoted for one trade size
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DepthPoint {
+    pub size_usd: f64,
+    pub impact_bps: f64,
+}
+
+impl DepthPoint {
+    /// From a quote's price impact expressed as a percentage
+    pub fn from_impact_pct(size_usd: f64, price_impact_pct: f64) -> Self {
+        Self { size_usd, impact_bps: price_impact_pct * 100.0 }
+    }
+}
+
+/// Which impact source set the estimate at the capacity size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImpactSource {
+    Depth,
+    History,
+    /// No depth or enough history: impact assumed zero
+    None,
+}
+
+/// Expected economics of one trade size
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapacityPoint {
+    pub size_usd: f64,
+    pub impact_bps: f64,
+    pub net_edge_bps: f64,
+    pub expected_profit_usd: f64,
+}
+
+/// Capacity estimate for one strategy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapacityEstimate {
+    pub strategy: String,
+    pub gross_edge_bps: f64,
+    pub fixed_cost_usd: f64,
+    /// Largest trade size whose net edge clears the threshold; `None` when no size does
+    pub capacity_usd: Option<f64>,
+    /// Size with the highest expected profit per trade
+    pub optimal_size_usd: f64,
+    pub max_profit_per_trade_usd: f64,
+    pub impact_source: ImpactSource,
+    pub depth_points: usize,
+    pub slippage_samples: usize,
+    pub curve: Vec<CapacityPoint>,
+}
+
+impl CapacityEstimate {
+    /// Whether trades of `size_usd` are past the point where more capital helps
+    pub fn saturated(&self, size_usd: f64) -> bool {
+        match self.capacity_usd {
+            Some(capacity) => size_usd > capacity,
+            None => true,
+        }
+    }
+}
+
+/// Capacity of every profiled strategy
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CapacityReport {
+    pub strategies: Vec<CapacityEstimate>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct StrategyProfile {
+    gross_edge_bps: f64,
+    fixed_cost_usd: f64,
+    depth: Vec<DepthPoint>,
+    /// (size_usd, slippage_bps)
+    slippage: Vec<(f64, f64)>,
+}
+
+impl StrategyProfile {
+    /// Linear interpolation over the depth curve; proportional extrapolation past the ends
+    fn depth_impact(&self, size_usd: f64) -> Option<f64> {
+        let first = self.depth.first()?;
+        if size_usd <= first.size_usd {
+            return Some(first.impact_bps * size_usd / first.size_usd);
+        }
+        for pair in self.depth.windows(2) {
+            let (lo, hi) = (pair[0], pair[1]);
+            if size_usd <= hi.size_usd {
+                let t = (size_usd - lo.size_usd) / (hi.size_usd - lo.size_usd);
+                return Some(lo.impact_bps + t * (hi.impact_bps - lo.impact_bps));
+            }
+        }
+        let last = self.depth.last()?;
+        Some(last.impact_bps * size_usd / last.size_usd)
+    }
+
+    /// Least-squares fit of `ln(impact) = ln(a) + b ln(size)`
+    fn fit_history(&self, min_observations: usize) -> Option<(f64, f64)> {
+        let points: Vec<(f64, f64)> = self
+            .slippage
+            .iter()
+            .filter(|(size, bps)| *size > 0.0 && *bps > 0.0)
+            .map(|(size, bps)| (size.ln(), bps.ln()))
+            .collect();
+        if points.len() < min_observations.max(2) {
+            return None;
+        }
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let var_x: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+        let b = if var_x > 0.0 {
+            (points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum::<f64>() / var_x).clamp(0.0, 2.0)
+        } else {
+            0.0
+        };
+        Some(((mean_y - b * mean_x).exp(), b))
+    }
+}
+
+/// Collects edge, depth and slippage per strategy and estimates capacity
+#[derive(Debug, Default)]
+pub struct CapacityAnalyzer {
+    config: CapacityConfig,
+    profiles: HashMap<String, StrategyProfile>,
+}
+
+impl CapacityAnalyzer {
+    pub fn new(config: CapacityConfig) -> Self {
+        Self { config, profiles: HashMap::new() }
+    }
+
+    /// Gross edge per trade before impact (bps) and fixed cost per trade (fees, tips)
+    pub fn set_edge(&mut self, strategy: &str, gross_edge_bps: f64, fixed_cost_usd: f64) {
+        let profile = self.profiles.entry(strategy.to_string()).or_default();
+        profile.gross_edge_bps = gross_edge_bps;
+        profile.fixed_cost_usd = fixed_cost_usd.max(0.0);
+    }
+
+    /// Replace the depth curve of the market a strategy trades
+    pub fn record_depth(&mut self, strategy: &str, mut depth: Vec<DepthPoint>) {
+        depth.retain(|p| p.size_usd > 0.0 && p.impact_bps.is_finite());
+        depth.sort_by(|a, b| a.size_usd.partial_cmp(&b.size_usd).unwrap_or(std::cmp::Ordering::Equal));
+        depth.dedup_by(|a, b| a.size_usd == b.size_usd);
+        self.profiles.entry(strategy.to_string()).or_default().depth = depth;
+    }
+
+    /// Record the realized slippage of one trade
+    pub fn record_slippage(&mut self, strategy: &str, size_usd: f64, slippage_bps: f64) {
+        self.profiles.entry(strategy.to_string()).or_default().slippage.push((size_usd, slippage_bps));
+    }
+
+    /// Record a trade's TCA result; delay cost and slippage both count as impact
+    pub fn record_tca(&mut self, result: &TcaResult) {
+        self.record_slippage(&result.strategy, result.notional, result.delay_cost_bps + result.slippage_bps);
+    }
+
+    fn grid(&self) -> Vec<f64> {
+        let c = &self.config;
+        let points = c.grid_points.max(2);
+        let (lo, hi) = (c.min_size_usd.max(f64::MIN_POSITIVE), c.max_size_usd.max(c.min_size_usd));
+        let ratio = (hi / lo).powf(1.0 / (points - 1) as f64);
+        (0..points).map(|i| lo * ratio.powi(i as i32)).collect()
+    }
+
+    /// Capacity estimate for one strategy
+    pub fn estimate(&self, strategy: &str) -> Option<CapacityEstimate> {
+        let profile = self.profiles.get(strategy)?;
+        let fit = profile.fit_history(self.config.min_observations);
+
+        let mut capacity_usd = None;
+        let mut impact_source = ImpactSource::None;
+        let mut curve = Vec::new();
+        for size_usd in self.grid() {
+            let depth = profile.depth_impact(size_usd);
+            let history = fit.map(|(a, b)| a * size_usd.powf(b));
+            let (impact_bps, source) = match (depth, history) {
+                (Some(d), Some(h)) if h > d => (h, ImpactSource::History),
+                (Some(d), _) => (d, ImpactSource::Depth),
+                (None, Some(h)) => (h, ImpactSource::History),
+                (None, None) => (0.0, ImpactSource::None),
+            };
+            let net_edge_bps = profile.gross_edge_bps - impact_bps - profile.fixed_cost_usd / size_usd * 10_000.0;
+            if net_edge_bps >= self.config.min_net_edge_bps {
+                capacity_usd = Some(size_usd);
+                impact_source = source;
+            }
+            curve.push(CapacityPoint {
+                size_usd,
+                impact_bps,
+                net_edge_bps,
+                expected_profit_usd: net_edge_bps / 10_000.0 * size_usd,
+            });
+        }
+
+        let best = curve
+            .iter()
+            .max_by(|a, b| a.expected_profit_usd.partial_cmp(&b.expected_profit_usd).unwrap_or(std::cmp::Ordering::Equal))?;
+        debug!("📐 Capacity {}: {:?} USD (optimal {:.0} USD, {:.2} USD/trade)",
+               strategy, capacity_usd, best.size_usd, best.expected_profit_usd);
+
+        Some(CapacityEstimate {
+            strategy: strategy.to_string(),
+            gross_edge_bps: profile.gross_edge_bps,
+            fixed_cost_usd: profile.fixed_cost_usd,
+            capacity_usd,
+            optimal_size_usd: best.size_usd,
+            max_profit_per_trade_usd: best.expected_profit_usd,
+            impact_source,
+            depth_points: profile.depth.len(),
+            slippage_samples: profile.slippage.len(),
+            curve,
+        })
+    }
+
+    /// Estimates for every strategy, sorted by name
+    pub fn report(&self) -> CapacityReport {
+        let mut names: Vec<&String> = self.profiles.keys().collect();
+        names.sort();
+        CapacityReport {
+            strategies: names.into_iter().filter_map(|name| self.estimate(name)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_depth_curve_caps_capacity() {
+        let mut analyzer = CapacityAnalyzer::default();
+        analyzer.set_edge("arb", 40.0, 0.5);
+        analyzer.record_depth("arb", vec![
+            DepthPoint::from_impact_pct(100_000.0, 0.5),
+            DepthPoint::from_impact_pct(1_000.0, 0.01),
+            DepthPoint::from_impact_pct(10_000.0, 0.1),
+        ]);
+
+        let estimate = analyzer.estimate("arb").unwrap();
+        assert_eq!(estimate.impact_source, ImpactSource::Depth);
+        // Net edge = 40 - impact - fixed; impact reaches 35 bps between 10k and 100k
+        let capacity = estimate.capacity_usd.unwrap();
+        assert!(capacity > 50_000.0 && capacity < 70_000.0, "capacity {}", capacity);
+        assert!(estimate.optimal_size_usd < capacity);
+        assert!(!estimate.saturated(10_000.0));
+        assert!(estimate.saturated(200_000.0));
+        // Tiny trades are eaten by the fixed cost
+        assert!(estimate.curve[0].net_edge_bps < 0.0);
+    }
+
+    #[test]
+    fn test_history_fit_and_unreachable_threshold() {
+        let mut analyzer = CapacityAnalyzer::default();
+        analyzer.set_edge("snipe", 100.0, 0.0);
+        // Slippage = 0.1 * sqrt(size) bps: 10 bps at 10k, 100 bps at 1M
+        for size in [1_000.0, 5_000.0, 10_000.0, 50_000.0, 100_000.0] {
+            analyzer.record_slippage("snipe", size, 0.1 * f64::sqrt(size));
+        }
+        let estimate = analyzer.estimate("snipe").unwrap();
+        assert_eq!(estimate.impact_source, ImpactSource::History);
+        // 100 - 0.1 * sqrt(S) >= 5  =>  S <= 902,500
+        let capacity = estimate.capacity_usd.unwrap();
+        assert!(capacity > 700_000.0 && capacity <= 902_500.0, "capacity {}", capacity);
+
+        analyzer.set_edge("dust", 3.0, 0.0);
+        let report = analyzer.report();
+        assert_eq!(report.strategies.len(), 2);
+        assert_eq!(report.strategies[0].strategy, "dust");
+        assert!(report.strategies[0].capacity_usd.is_none());
+        assert!(report.strategies[0].saturated(1.0));
+    }
+}
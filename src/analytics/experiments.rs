@@ -0,0 +1,377 @@
+//! A/B experiments for strategy parameter variants
+//!
+//! Runs two parameterizations of the same strategy side by side — one live and
+//! one in shadow mode, or both live with the capital split between them — and
+//! tracks per-trade returns for each so the difference can be tested for
+//! statistical significance (Welch's t-test) before a variant is adopted.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use statrs::distribution::{ContinuousCDF, StudentsT};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use tracing::info;
+
+/// How the two variants share execution
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ExperimentMode {
+    /// Control trades live, treatment runs in shadow mode
+    LiveVsShadow,
+    /// Both trade live; `treatment_fraction` of opportunities go to the treatment
+    CapitalSplit { treatment_fraction: f64 },
+}
+
+/// Experiment arm
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VariantArm {
+    Control,
+    Treatment,
+}
+
+/// One parameterization of the strategy under test
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperimentVariant {
+    pub name: String,
+    pub parameters: HashMap<String, f64>,
+}
+
+/// Per-variant outcome samples
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VariantStats {
+    /// Per-trade returns (fraction of trade size, net of fees)
+    pub returns: Vec<f64>,
+    pub total_profit: f64,
+    pub winning_trades: u64,
+}
+
+impl VariantStats {
+    pub fn trades(&self) -> usize {
+        self.returns.len()
+    }
+
+    pub fn mean_return(&self) -> f64 {
+        if self.returns.is_empty() {
+            0.0
+        } else {
+            self.returns.iter().sum::<f64>() / self.returns.len() as f64
+        }
+    }
+
+    /// Sample variance (n - 1)
+    pub fn variance(&self) -> f64 {
+        let n = self.returns.len();
+        if n < 2 {
+            return 0.0;
+        }
+        let mean = self.mean_return();
+        self.returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.returns.is_empty() {
+            0.0
+        } else {
+            self.winning_trades as f64 / self.returns.len() as f64
+        }
+    }
+}
+
+/// Result of the significance test between arms
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignificanceResult {
+    /// Treatment mean return minus control mean return
+    pub mean_difference: f64,
+    pub t_statistic: f64,
+    pub degrees_of_freedom: f64,
+    /// Two-sided p-value
+    pub p_value: f64,
+    pub significant: bool,
+}
+
+/// Welch's two-sample t-test (unequal variances)
+///
+/// Returns `None` until both samples have at least two observations.
+pub fn welch_t_test(control: &VariantStats, treatment: &VariantStats, alpha: f64) -> Option<SignificanceResult> {
+    let (n1, n2) = (control.trades() as f64, treatment.trades() as f64);
+    if n1 < 2.0 || n2 < 2.0 {
+        return None;
+    }
+
+    let (v1, v2) = (control.variance() / n1, treatment.variance() / n2);
+    let mean_difference = treatment.mean_return() - control.mean_return();
+    let standard_error = (v1 + v2).sqrt();
+
+    if standard_error == 0.0 {
+        // Identical constant samples: no evidence either way unless means differ
+        let significant = mean_difference != 0.0;
+        return Some(SignificanceResult {
+            mean_difference,
+            t_statistic: 0.0,
+            degrees_of_freedom: n1 + n2 - 2.0,
+            p_value: if significant { 0.0 } else { 1.0 },
+            significant,
+        });
+    }
+
+    let t_statistic = mean_difference / standard_error;
+    let degrees_of_freedom = (v1 + v2).powi(2) / (v1.powi(2) / (n1 - 1.0) + v2.powi(2) / (n2 - 1.0));
+    let p_value = StudentsT::new(0.0, 1.0, degrees_of_freedom)
+        .map(|dist| 2.0 * (1.0 - dist.cdf(t_statistic.abs())))
+        .unwrap_or(1.0);
+
+    Some(SignificanceResult {
+        mean_difference,
+        t_statistic,
+        degrees_of_freedom,
+        p_value,
+        significant: p_value < alpha,
+    })
+}
+
+/// A/B experiment between two variants of one strategy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Experiment {
+    pub id: String,
+    pub strategy_name: String,
+    pub mode: ExperimentMode,
+    pub control: ExperimentVariant,
+    pub treatment: ExperimentVariant,
+    pub control_stats: VariantStats,
+    pub treatment_stats: VariantStats,
+    /// Samples per arm before results are reported as conclusive
+    pub min_samples_per_arm: usize,
+    /// Significance level for the t-test
+    pub alpha: f64,
+    pub started_at: DateTime<Utc>,
+    allocation_counter: u64,
+}
+
+impl Experiment {
+    /// Create a new experiment
+    pub fn new(
+        id: impl Into<String>,
+        strategy_name: impl Into<String>,
+        mode: ExperimentMode,
+        control: ExperimentVariant,
+        treatment: ExperimentVariant,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            strategy_name: strategy_name.into(),
+            mode,
+            control,
+            treatment,
+            control_stats: VariantStats::default(),
+            treatment_stats: VariantStats::default(),
+            min_samples_per_arm: 30,
+            alpha: 0.05,
+            started_at: Utc::now(),
+            allocation_counter: 0,
+        }
+    }
+
+    /// Arm that should take the next live opportunity
+    ///
+    /// In `LiveVsShadow` every live opportunity goes to control (the treatment
+    /// sees the same opportunities in shadow). In `CapitalSplit` opportunities are
+    /// interleaved deterministically so the treatment receives its fraction.
+    pub fn assign_next(&mut self) -> VariantArm {
+        match self.mode {
+            ExperimentMode::LiveVsShadow => VariantArm::Control,
+            ExperimentMode::CapitalSplit { treatment_fraction } => {
+                self.allocation_counter += 1;
+                let n = self.allocation_counter as f64;
+                let fraction = treatment_fraction.clamp(0.0, 1.0);
+                // Bresenham-style spreading: treatment when its cumulative quota ticks over
+                if (n * fraction).floor() > ((n - 1.0) * fraction).floor() {
+                    VariantArm::Treatment
+                } else {
+                    VariantArm::Control
+                }
+            }
+        }
+    }
+
+    /// Record a closed trade's return and profit for an arm
+    pub fn record_result(&mut self, arm: VariantArm, return_fraction: f64, profit: f64) {
+        let stats = match arm {
+            VariantArm::Control => &mut self.control_stats,
+            VariantArm::Treatment => &mut self.treatment_stats,
+        };
+        stats.returns.push(return_fraction);
+        stats.total_profit += profit;
+        if profit > 0.0 {
+            stats.winning_trades += 1;
+        }
+    }
+
+    /// Whether both arms have reached the minimum sample size
+    pub fn has_enough_samples(&self) -> bool {
+        self.control_stats.trades() >= self.min_samples_per_arm
+            && self.treatment_stats.trades() >= self.min_samples_per_arm
+    }
+
+    /// Comparable metrics plus significance for reporting
+    pub fn report(&self) -> ExperimentReport {
+        ExperimentReport {
+            experiment_id: self.id.clone(),
+            strategy_name: self.strategy_name.clone(),
+            mode: self.mode,
+            control_name: self.control.name.clone(),
+            treatment_name: self.treatment.name.clone(),
+            control_trades: self.control_stats.trades(),
+            treatment_trades: self.treatment_stats.trades(),
+            control_mean_return: self.control_stats.mean_return(),
+            treatment_mean_return: self.treatment_stats.mean_return(),
+            control_win_rate: self.control_stats.win_rate(),
+            treatment_win_rate: self.treatment_stats.win_rate(),
+            significance: welch_t_test(&self.control_stats, &self.treatment_stats, self.alpha),
+            conclusive: self.has_enough_samples(),
+            generated_at: Utc::now(),
+        }
+    }
+}
+
+/// Snapshot of an experiment for analytics reports
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperimentReport {
+    pub experiment_id: String,
+    pub strategy_name: String,
+    pub mode: ExperimentMode,
+    pub control_name: String,
+    pub treatment_name: String,
+    pub control_trades: usize,
+    pub treatment_trades: usize,
+    pub control_mean_return: f64,
+    pub treatment_mean_return: f64,
+    pub control_win_rate: f64,
+    pub treatment_win_rate: f64,
+    pub significance: Option<SignificanceResult>,
+    /// Minimum sample size reached on both arms
+    pub conclusive: bool,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl ExperimentReport {
+    /// One-line verdict for summary reports
+    pub fn verdict(&self) -> String {
+        match &self.significance {
+            Some(sig) if self.conclusive && sig.significant => {
+                let winner = if sig.mean_difference > 0.0 { &self.treatment_name } else { &self.control_name };
+                format!("{} wins (Δ {:+.4}, p = {:.4})", winner, sig.mean_difference, sig.p_value)
+            }
+            Some(sig) if self.conclusive => format!("no significant difference (p = {:.4})", sig.p_value),
+            _ => format!("collecting samples ({}/{} trades)", self.control_trades, self.treatment_trades),
+        }
+    }
+}
+
+/// Registry of running experiments
+#[derive(Debug, Default)]
+pub struct ExperimentManager {
+    experiments: HashMap<String, Experiment>,
+}
+
+impl ExperimentManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an experiment; ids must be unique
+    pub fn start(&mut self, experiment: Experiment) -> Result<()> {
+        if self.experiments.contains_key(&experiment.id) {
+            return Err(anyhow!("Experiment '{}' already exists", experiment.id));
+        }
+        info!("🧪 Starting experiment '{}' on {}: {} vs {} ({:?})",
+              experiment.id, experiment.strategy_name, experiment.control.name,
+              experiment.treatment.name, experiment.mode);
+        self.experiments.insert(experiment.id.clone(), experiment);
+        Ok(())
+    }
+
+    /// Stop an experiment and return its final report
+    pub fn stop(&mut self, experiment_id: &str) -> Option<ExperimentReport> {
+        self.experiments.remove(experiment_id).map(|e| e.report())
+    }
+
+    pub fn get_mut(&mut self, experiment_id: &str) -> Option<&mut Experiment> {
+        self.experiments.get_mut(experiment_id)
+    }
+
+    /// Record a result on an experiment arm
+    pub fn record_result(&mut self, experiment_id: &str, arm: VariantArm, return_fraction: f64, profit: f64) -> Result<()> {
+        self.experiments
+            .get_mut(experiment_id)
+            .ok_or_else(|| anyhow!("Unknown experiment '{}'", experiment_id))?
+            .record_result(arm, return_fraction, profit);
+        Ok(())
+    }
+
+    /// Reports for every running experiment
+    pub fn reports(&self) -> Vec<ExperimentReport> {
+        let mut reports: Vec<_> = self.experiments.values().map(Experiment::report).collect();
+        reports.sort_by(|a, b| a.experiment_id.cmp(&b.experiment_id));
+        reports
+    }
+}
+
+/// Process-wide registry: strategy managers record into it, the analytics cycle reports from it
+pub fn shared_experiments() -> Arc<Mutex<ExperimentManager>> {
+    static SHARED: OnceLock<Arc<Mutex<ExperimentManager>>> = OnceLock::new();
+    SHARED.get_or_init(Default::default).clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variant(name: &str) -> ExperimentVariant {
+        ExperimentVariant {
+            name: name.to_string(),
+            parameters: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_capital_split_allocation() {
+        let mut experiment = Experiment::new(
+            "exp", "momentum",
+            ExperimentMode::CapitalSplit { treatment_fraction: 0.25 },
+            variant("a"), variant("b"),
+        );
+        let treatment = (0..100).filter(|_| experiment.assign_next() == VariantArm::Treatment).count();
+        assert_eq!(treatment, 25);
+    }
+
+    #[test]
+    fn test_significant_difference_detected() {
+        let mut experiment = Experiment::new("exp", "momentum", ExperimentMode::LiveVsShadow, variant("a"), variant("b"));
+        for i in 0..40 {
+            let noise = (i % 5) as f64 * 0.001;
+            experiment.record_result(VariantArm::Control, 0.001 + noise, 1.0);
+            experiment.record_result(VariantArm::Treatment, 0.010 + noise, 1.0);
+        }
+        let report = experiment.report();
+        let sig = report.significance.clone().unwrap();
+        assert!(report.conclusive);
+        assert!(sig.significant);
+        assert!(sig.mean_difference > 0.0);
+        assert!(report.verdict().starts_with("b wins"));
+    }
+
+    #[test]
+    fn test_no_difference_not_significant() {
+        let mut control = VariantStats::default();
+        let mut treatment = VariantStats::default();
+        for i in 0..30 {
+            let r = if i % 2 == 0 { 0.01 } else { -0.01 };
+            control.returns.push(r);
+            treatment.returns.push(-r);
+        }
+        let result = welch_t_test(&control, &treatment, 0.05).unwrap();
+        assert!(!result.significant);
+        assert!(welch_t_test(&VariantStats::default(), &treatment, 0.05).is_none());
+    }
+}
@@ -0,0 +1,201 @@
+//! Per-leader landing statistics
+//!
+//! Every send is attributed to the validator expected to lead the targeted
+//! slot. Inclusion rate, landing latency and the tips that did or did not land
+//! are tracked per leader identity; the Jito tip for an upcoming leader is
+//! scaled from that history instead of using one tip for the whole cluster.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Tip scaling from leader history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderTipPolicy {
+    /// Sends to a leader before its history adjusts the tip
+    pub min_samples: u64,
+    /// Inclusion rate the tip is scaled towards
+    pub target_inclusion: f64,
+    pub min_multiplier: f64,
+    pub max_multiplier: f64,
+}
+
+impl Default for LeaderTipPolicy {
+    fn default() -> Self {
+        Self {
+            min_samples: 10,
+            target_inclusion: 0.8,
+            min_multiplier: 0.5,
+            max_multiplier: 4.0,
+        }
+    }
+}
+
+/// Raw counters for one leader
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LeaderRecord {
+    pub sends: u64,
+    pub landed: u64,
+    pub total_latency_ms: u64,
+    pub tipped_sends: u64,
+    pub tips_landed_lamports: u64,
+    pub tips_missed_lamports: u64,
+    pub landed_tipped: u64,
+}
+
+impl LeaderRecord {
+    pub fn inclusion_rate(&self) -> f64 {
+        if self.sends == 0 { 0.0 } else { self.landed as f64 / self.sends as f64 }
+    }
+
+    pub fn avg_latency_ms(&self) -> f64 {
+        if self.landed == 0 { 0.0 } else { self.total_latency_ms as f64 / self.landed as f64 }
+    }
+
+    /// Mean tip among tipped sends that landed
+    pub fn avg_landed_tip(&self) -> Option<f64> {
+        (self.landed_tipped > 0).then(|| self.tips_landed_lamports as f64 / self.landed_tipped as f64)
+    }
+}
+
+/// Row of the per-leader analytics table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderSummary {
+    pub leader: String,
+    pub sends: u64,
+    pub inclusion_rate: f64,
+    pub avg_latency_ms: f64,
+    pub avg_landed_tip_lamports: Option<f64>,
+}
+
+/// Tip chosen for a send to a given leader
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TipDecision {
+    pub tip_lamports: u64,
+    pub multiplier: f64,
+    /// `None` until the leader has `min_samples` sends
+    pub inclusion_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LeaderStats {
+    pub policy: LeaderTipPolicy,
+    leaders: HashMap<String, LeaderRecord>,
+}
+
+impl LeaderStats {
+    pub fn new(policy: LeaderTipPolicy) -> Self {
+        Self { policy, leaders: HashMap::new() }
+    }
+
+    /// Record the outcome of one send targeted at `leader`
+    pub fn record(&mut self, leader: &str, landed: bool, latency_ms: u64, tip_lamports: Option<u64>) {
+        let record = self.leaders.entry(leader.to_string()).or_default();
+        record.sends += 1;
+        if landed {
+            record.landed += 1;
+            record.total_latency_ms += latency_ms;
+        }
+        if let Some(tip) = tip_lamports {
+            record.tipped_sends += 1;
+            if landed {
+                record.landed_tipped += 1;
+                record.tips_landed_lamports += tip;
+            } else {
+                record.tips_missed_lamports += tip;
+            }
+        }
+    }
+
+    pub fn get(&self, leader: &str) -> Option<&LeaderRecord> {
+        self.leaders.get(leader)
+    }
+
+    /// Scale `base_tip` by how reliably `leader` has included our sends
+    ///
+    /// Leaders that drop sends get proportionally more; leaders that include
+    /// nearly everything get less, but never below the mean tip that landed
+    /// with them.
+    pub fn tip_for(&self, leader: &str, base_tip: u64) -> TipDecision {
+        let policy = &self.policy;
+        let Some(record) = self.leaders.get(leader).filter(|record| record.sends >= policy.min_samples) else {
+            return TipDecision { tip_lamports: base_tip, multiplier: 1.0, inclusion_rate: None };
+        };
+
+        let inclusion = record.inclusion_rate();
+        let mut multiplier = (policy.target_inclusion / inclusion.max(0.05)).clamp(policy.min_multiplier, policy.max_multiplier);
+        if multiplier < 1.0 {
+            if let Some(landed_tip) = record.avg_landed_tip() {
+                multiplier = multiplier.max((landed_tip / base_tip.max(1) as f64).min(1.0));
+            }
+        }
+        TipDecision {
+            tip_lamports: (base_tip as f64 * multiplier).round() as u64,
+            multiplier,
+            inclusion_rate: Some(inclusion),
+        }
+    }
+
+    /// Leaders by send count, most-used first
+    pub fn summaries(&self) -> Vec<LeaderSummary> {
+        let mut rows: Vec<LeaderSummary> = self
+            .leaders
+            .iter()
+            .map(|(leader, record)| LeaderSummary {
+                leader: leader.clone(),
+                sends: record.sends,
+                inclusion_rate: record.inclusion_rate(),
+                avg_latency_ms: record.avg_latency_ms(),
+                avg_landed_tip_lamports: record.avg_landed_tip(),
+            })
+            .collect();
+        rows.sort_by(|a, b| b.sends.cmp(&a.sends).then_with(|| a.leader.cmp(&b.leader)));
+        rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records_inclusion_and_latency_per_leader() {
+        let mut stats = LeaderStats::default();
+        stats.record("A", true, 400, Some(10_000));
+        stats.record("A", true, 800, Some(10_000));
+        stats.record("A", false, 0, Some(10_000));
+        stats.record("B", false, 0, None);
+
+        let a = stats.get("A").unwrap();
+        assert!((a.inclusion_rate() - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(a.avg_latency_ms(), 600.0);
+        assert_eq!(a.avg_landed_tip(), Some(10_000.0));
+        assert_eq!(a.tips_missed_lamports, 10_000);
+
+        let summaries = stats.summaries();
+        assert_eq!(summaries[0].leader, "A");
+        assert_eq!(summaries[1].inclusion_rate, 0.0);
+    }
+
+    #[test]
+    fn test_tip_scales_with_leader_inclusion() {
+        let mut stats = LeaderStats::default();
+        for i in 0..20 {
+            // Reliable leader lands everything at 6k; flaky one lands 1 in 4
+            stats.record("reliable", true, 400, Some(6_000));
+            stats.record("flaky", i % 4 == 0, 900, Some(10_000));
+        }
+        stats.record("new", true, 400, Some(10_000));
+
+        assert_eq!(stats.tip_for("new", 10_000).tip_lamports, 10_000);
+
+        let flaky = stats.tip_for("flaky", 10_000);
+        assert!((flaky.multiplier - 3.2).abs() < 1e-9);
+        assert_eq!(flaky.tip_lamports, 32_000);
+
+        // 0.8x from inclusion, floored at the 6k that landed
+        let reliable = stats.tip_for("reliable", 10_000);
+        assert_eq!(reliable.tip_lamports, 8_000);
+        let reliable_low_base = stats.tip_for("reliable", 7_000);
+        assert_eq!(reliable_low_base.tip_lamports, 6_000);
+    }
+}
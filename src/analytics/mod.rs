@@ -3,11 +3,27 @@
 pub mod ml_pattern_recognition;
 pub mod ai_engine;
 pub mod performance_analytics;
+pub mod experiments;
+pub mod tca;
+pub mod trade_indexer;
+pub mod seasonality;
+pub mod leader_stats;
+pub mod benchmark;
+pub mod capacity;
+pub mod param_sweep;
 // pub mod metrics;
 // pub mod reporting;
 
 pub use ml_pattern_recognition::*;
 pub use ai_engine::*;
 pub use performance_analytics::*;
+pub use experiments::*;
+pub use tca::*;
+pub use trade_indexer::*;
+pub use seasonality::*;
+pub use leader_stats::*;
+pub use benchmark::*;
+pub use capacity::*;
+pub use param_sweep::*;
 // pub use metrics::*;
 // pub use reporting::*;
@@ -0,0 +1,457 @@
+//! Strategy parameter sweeps
+//!
+//! Runs a strategy's backtest over every combination of a parameter grid (or
+//! a random sample of it) across rayon workers and tabulates PnL, Sharpe and
+//! drawdown per combination.
+//!
+//! A sweep that only reports the best in-sample result mostly finds noise, so
+//! the data is also split walk-forward: anchored training windows followed by
+//! an unseen test window. Each combination is scored on both, and combinations
+//! whose out-of-sample Sharpe collapses relative to in-sample are flagged as
+//! overfit. The sweep also replays the selection itself (pick the in-sample
+//! winner per fold, measure it on the next window) to report how much of the
+//! optimized edge survives.
+//!
+//! Strategies plug in by implementing [`Backtest`] over their own event data;
+//! `trading::strategies::PriceReplayBacktest` replays recorded prices and backs
+//! the `sniperforge-cli sweep` command.
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
+use tracing::info;
+
+/// Parameter name → value for one backtest run
+pub type ParamSet = BTreeMap<String, f64>;
+
+/// A strategy that can be replayed over part of its historical data
+pub trait Backtest: Sync {
+    /// Number of events (bars, opportunities, ...) in the dataset
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Per-trade returns (fraction of trade size, net of costs) over `window`
+    fn run(&self, params: &ParamSet, window: Range<usize>) -> Vec<f64>;
+}
+
+/// Values swept for one parameter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParamAxis {
+    pub name: String,
+    pub values: Vec<f64>,
+}
+
+impl ParamAxis {
+    pub fn new(name: &str, values: Vec<f64>) -> Self {
+        Self { name: name.to_string(), values }
+    }
+
+    /// `steps` evenly spaced values from `min` to `max` inclusive
+    pub fn linear(name: &str, min: f64, max: f64, steps: usize) -> Self {
+        let values = match steps {
+            0 => Vec::new(),
+            1 => vec![min],
+            _ => (0..steps).map(|i| min + (max - min) * i as f64 / (steps - 1) as f64).collect(),
+        };
+        Self::new(name, values)
+    }
+}
+
+/// How combinations are drawn from the axes
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SearchMode {
+    /// Every combination
+    Grid,
+    /// `samples` distinct combinations drawn at random
+    Random { samples: usize, seed: u64 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepConfig {
+    pub mode: SearchMode,
+    /// Walk-forward test windows (0 = in-sample only)
+    pub walk_forward_folds: usize,
+    /// Fraction of in-sample Sharpe that may be lost out of sample before flagging overfit
+    pub max_sharpe_degradation: f64,
+    /// Combinations with fewer in-sample trades are not ranked
+    pub min_trades: usize,
+}
+
+impl Default for SweepConfig {
+    fn default() -> Self {
+        Self {
+            mode: SearchMode::Grid,
+            walk_forward_folds: 4,
+            max_sharpe_degradation: 0.5,
+            min_trades: 10,
+        }
+    }
+}
+
+/// Performance of one parameter set over one window
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SweepMetrics {
+    pub trades: usize,
+    pub pnl: f64,
+    /// Mean over standard deviation of per-trade returns
+    pub sharpe: f64,
+    /// Largest peak-to-trough fall of cumulative PnL
+    pub max_drawdown: f64,
+    pub win_rate: f64,
+}
+
+impl SweepMetrics {
+    pub fn from_returns(returns: &[f64]) -> Self {
+        let trades = returns.len();
+        if trades == 0 {
+            return Self::default();
+        }
+        let n = trades as f64;
+        let pnl: f64 = returns.iter().sum();
+        let mean = pnl / n;
+        let std = if trades > 1 {
+            (returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+        } else {
+            0.0
+        };
+
+        let (mut equity, mut peak, mut max_drawdown) = (0.0f64, 0.0f64, 0.0f64);
+        for r in returns {
+            equity += r;
+            peak = peak.max(equity);
+            max_drawdown = max_drawdown.max(peak - equity);
+        }
+
+        Self {
+            trades,
+            pnl,
+            sharpe: if std > 0.0 { mean / std } else { 0.0 },
+            max_drawdown,
+            win_rate: returns.iter().filter(|r| **r > 0.0).count() as f64 / n,
+        }
+    }
+}
+
+/// Results of one parameter combination
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepResult {
+    pub params: ParamSet,
+    /// Full dataset
+    pub full: SweepMetrics,
+    /// Training windows, one per fold
+    pub in_sample: Vec<SweepMetrics>,
+    /// Test windows, one per fold
+    pub out_of_sample: Vec<SweepMetrics>,
+    /// Out-of-sample Sharpe fell more than allowed (or turned negative)
+    pub overfit: bool,
+}
+
+impl SweepResult {
+    pub fn in_sample_sharpe(&self) -> f64 {
+        mean(self.in_sample.iter().map(|m| m.sharpe))
+    }
+
+    pub fn out_of_sample_sharpe(&self) -> f64 {
+        mean(self.out_of_sample.iter().map(|m| m.sharpe))
+    }
+}
+
+/// The combination chosen on a fold's training window and how it did next
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FoldSelection {
+    pub fold: usize,
+    pub params: ParamSet,
+    pub in_sample_sharpe: f64,
+    pub out_of_sample_sharpe: f64,
+    pub out_of_sample_pnl: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepReport {
+    /// Ranked by out-of-sample Sharpe (full-sample Sharpe without folds)
+    pub results: Vec<SweepResult>,
+    pub selections: Vec<FoldSelection>,
+    /// Out-of-sample over in-sample PnL of the per-fold winners (1.0 = edge fully kept)
+    pub walk_forward_efficiency: Option<f64>,
+}
+
+impl SweepReport {
+    pub fn best(&self) -> Option<&SweepResult> {
+        self.results.iter().find(|r| !r.overfit)
+    }
+
+    pub fn overfit_count(&self) -> usize {
+        self.results.iter().filter(|r| r.overfit).count()
+    }
+
+    /// Plain-text results table
+    pub fn table(&self) -> String {
+        let mut out = format!(
+            "{:<40} {:>7} {:>10} {:>8} {:>8} {:>8} {:>8}  {}\n",
+            "params", "trades", "pnl", "sharpe", "max_dd", "is_shp", "oos_shp", "flag"
+        );
+        for result in &self.results {
+            let params = result.params.iter().map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<_>>().join(" ");
+            out.push_str(&format!(
+                "{:<40} {:>7} {:>10.4} {:>8.3} {:>8.4} {:>8.3} {:>8.3}  {}\n",
+                params,
+                result.full.trades,
+                result.full.pnl,
+                result.full.sharpe,
+                result.full.max_drawdown,
+                result.in_sample_sharpe(),
+                result.out_of_sample_sharpe(),
+                if result.overfit { "OVERFIT" } else { "" }
+            ));
+        }
+        if let Some(efficiency) = self.walk_forward_efficiency {
+            out.push_str(&format!("walk-forward efficiency: {:.2}\n", efficiency));
+        }
+        out
+    }
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = values.fold((0.0, 0usize), |(s, c), v| (s + v, c + 1));
+    if count == 0 { 0.0 } else { sum / count as f64 }
+}
+
+/// Grid or random search over a backtest
+#[derive(Debug, Clone)]
+pub struct ParameterSweep {
+    axes: Vec<ParamAxis>,
+    config: SweepConfig,
+}
+
+impl ParameterSweep {
+    pub fn new(axes: Vec<ParamAxis>, config: SweepConfig) -> Self {
+        Self { axes, config }
+    }
+
+    fn swept_axes(&self) -> impl Iterator<Item = &ParamAxis> + Clone {
+        self.axes.iter().filter(|a| !a.values.is_empty())
+    }
+
+    /// Size of the full grid
+    pub fn grid_size(&self) -> u128 {
+        self.swept_axes().fold(1u128, |n, axis| n.saturating_mul(axis.values.len() as u128))
+    }
+
+    /// The combination at `index` in grid order (last axis varies fastest)
+    fn combination_at(&self, mut index: u128) -> ParamSet {
+        let axes: Vec<&ParamAxis> = self.swept_axes().collect();
+        let mut set = ParamSet::new();
+        for axis in axes.into_iter().rev() {
+            let radix = axis.values.len() as u128;
+            set.insert(axis.name.clone(), axis.values[(index % radix) as usize]);
+            index /= radix;
+        }
+        set
+    }
+
+    /// Combinations to evaluate, in grid order (random mode keeps a deterministic sample)
+    ///
+    /// Random mode draws grid indices and decodes only those, so sampling a
+    /// grid far too large to enumerate stays cheap.
+    pub fn combinations(&self) -> Vec<ParamSet> {
+        let total = self.grid_size();
+        match self.config.mode {
+            SearchMode::Grid => (0..total).map(|index| self.combination_at(index)).collect(),
+            SearchMode::Random { samples, seed } => {
+                // Floyd's algorithm: `samples` distinct indices without a shuffle of the grid
+                let wanted = (samples as u128).min(total);
+                let mut rng = fastrand::Rng::with_seed(seed);
+                let mut picked = BTreeSet::new();
+                for upper in total - wanted..total {
+                    let index = rng.u128(0..=upper);
+                    if !picked.insert(index) {
+                        picked.insert(upper);
+                    }
+                }
+                picked.into_iter().map(|index| self.combination_at(index)).collect()
+            }
+        }
+    }
+
+    /// Anchored walk-forward splits: (training window, test window)
+    pub fn folds(&self, len: usize) -> Vec<(Range<usize>, Range<usize>)> {
+        let folds = self.config.walk_forward_folds;
+        if folds == 0 || len < folds + 1 {
+            return Vec::new();
+        }
+        let segment = len / (folds + 1);
+        (1..=folds)
+            .map(|k| {
+                let end = if k == folds { len } else { (k + 1) * segment };
+                (0..k * segment, k * segment..end)
+            })
+            .collect()
+    }
+
+    fn evaluate(&self, backtest: &dyn Backtest, params: ParamSet, folds: &[(Range<usize>, Range<usize>)]) -> SweepResult {
+        let full = SweepMetrics::from_returns(&backtest.run(&params, 0..backtest.len()));
+        let in_sample: Vec<SweepMetrics> = folds.iter().map(|(train, _)| SweepMetrics::from_returns(&backtest.run(&params, train.clone()))).collect();
+        let out_of_sample: Vec<SweepMetrics> = folds.iter().map(|(_, test)| SweepMetrics::from_returns(&backtest.run(&params, test.clone()))).collect();
+
+        let mut result = SweepResult { params, full, in_sample, out_of_sample, overfit: false };
+        if !folds.is_empty() {
+            let is_sharpe = result.in_sample_sharpe();
+            let oos_sharpe = result.out_of_sample_sharpe();
+            result.overfit = is_sharpe > 0.0
+                && (oos_sharpe <= 0.0 || oos_sharpe < is_sharpe * (1.0 - self.config.max_sharpe_degradation));
+        }
+        result
+    }
+
+    /// Evaluate every combination in parallel and rank the results
+    pub fn run(&self, backtest: &dyn Backtest) -> SweepReport {
+        let folds = self.folds(backtest.len());
+        let combinations = self.combinations();
+        info!("🧪 Parameter sweep: {} combinations x {} walk-forward folds", combinations.len(), folds.len());
+
+        let mut results: Vec<SweepResult> = combinations
+            .into_par_iter()
+            .map(|params| self.evaluate(backtest, params, &folds))
+            .collect();
+
+        let min_trades = self.config.min_trades;
+        let rank = |r: &SweepResult| {
+            if r.full.trades < min_trades {
+                f64::NEG_INFINITY
+            } else if r.out_of_sample.is_empty() {
+                r.full.sharpe
+            } else {
+                r.out_of_sample_sharpe()
+            }
+        };
+        results.sort_by(|a, b| rank(b).partial_cmp(&rank(a)).unwrap_or(std::cmp::Ordering::Equal));
+
+        let selections: Vec<FoldSelection> = (0..folds.len())
+            .filter_map(|fold| {
+                results
+                    .iter()
+                    .filter(|r| r.in_sample[fold].trades >= min_trades)
+                    .max_by(|a, b| a.in_sample[fold].sharpe.partial_cmp(&b.in_sample[fold].sharpe).unwrap_or(std::cmp::Ordering::Equal))
+                    .map(|r| FoldSelection {
+                        fold,
+                        params: r.params.clone(),
+                        in_sample_sharpe: r.in_sample[fold].sharpe,
+                        out_of_sample_sharpe: r.out_of_sample[fold].sharpe,
+                        out_of_sample_pnl: r.out_of_sample[fold].pnl,
+                    })
+            })
+            .collect();
+
+        // Compare per-event PnL so longer training windows do not dominate
+        let walk_forward_efficiency = {
+            let (mut is_rate, mut oos_rate) = (0.0, 0.0);
+            for selection in &selections {
+                let (train, test) = &folds[selection.fold];
+                let chosen = results.iter().find(|r| r.params == selection.params);
+                if let Some(chosen) = chosen {
+                    is_rate += chosen.in_sample[selection.fold].pnl / train.len().max(1) as f64;
+                    oos_rate += selection.out_of_sample_pnl / test.len().max(1) as f64;
+                }
+            }
+            (is_rate > 0.0).then(|| oos_rate / is_rate)
+        };
+
+        let report = SweepReport { results, selections, walk_forward_efficiency };
+        info!("🧪 Sweep done: {} of {} combinations flagged as overfit, walk-forward efficiency {:?}",
+              report.overfit_count(), report.results.len(), report.walk_forward_efficiency);
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Trades every event; returns follow the `edge` parameter, plus a regime that
+    /// only rewards `lookback = 3` in the first half of the data
+    struct ToyBacktest {
+        events: usize,
+    }
+
+    impl Backtest for ToyBacktest {
+        fn len(&self) -> usize {
+            self.events
+        }
+
+        fn run(&self, params: &ParamSet, window: Range<usize>) -> Vec<f64> {
+            let edge = params["edge"];
+            let lookback = params["lookback"];
+            window
+                .map(|i| {
+                    let noise = if i % 2 == 0 { 0.01 } else { -0.01 };
+                    let regime = if lookback == 3.0 && i < self.events / 2 { 0.02 } else if lookback == 3.0 { -0.02 } else { 0.0 };
+                    edge + noise + regime
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_grid_random_and_folds() {
+        let sweep = ParameterSweep::new(
+            vec![ParamAxis::linear("edge", 0.0, 0.004, 3), ParamAxis::new("lookback", vec![3.0, 5.0])],
+            SweepConfig::default(),
+        );
+        let grid = sweep.combinations();
+        assert_eq!(grid.len(), 6);
+        assert!(grid.iter().any(|p| p["edge"] == 0.002 && p["lookback"] == 5.0));
+
+        let random = ParameterSweep::new(sweep.axes.clone(), SweepConfig { mode: SearchMode::Random { samples: 4, seed: 7 }, ..SweepConfig::default() });
+        assert_eq!(random.combinations().len(), 4);
+        assert_eq!(random.combinations(), random.combinations());
+        assert!(random.combinations().iter().all(|set| grid.contains(set)));
+
+        // 100^8 combinations: only the sampled ones are ever built
+        let huge = ParameterSweep::new(
+            (0..8).map(|i| ParamAxis::linear(&format!("p{}", i), 0.0, 1.0, 100)).collect(),
+            SweepConfig { mode: SearchMode::Random { samples: 50, seed: 1 }, ..SweepConfig::default() },
+        );
+        assert_eq!(huge.grid_size(), 100u128.pow(8));
+        let sampled = huge.combinations();
+        assert_eq!(sampled.len(), 50);
+        assert!(sampled.windows(2).all(|pair| pair[0] != pair[1]));
+        assert!(sampled.iter().all(|set| set.len() == 8));
+
+        let folds = sweep.folds(100);
+        assert_eq!(folds, vec![(0..20, 20..40), (0..40, 40..60), (0..60, 60..80), (0..80, 80..100)]);
+
+        let metrics = SweepMetrics::from_returns(&[0.1, -0.3, 0.1, 0.2]);
+        assert!((metrics.pnl - 0.1).abs() < 1e-12);
+        assert!((metrics.max_drawdown - 0.3).abs() < 1e-12);
+        assert_eq!(metrics.win_rate, 0.75);
+    }
+
+    #[test]
+    fn test_walk_forward_flags_overfit_parameters() {
+        let sweep = ParameterSweep::new(
+            vec![ParamAxis::new("edge", vec![0.001, 0.002]), ParamAxis::new("lookback", vec![3.0, 5.0])],
+            SweepConfig { walk_forward_folds: 3, ..SweepConfig::default() },
+        );
+        let report = sweep.run(&ToyBacktest { events: 400 });
+        assert_eq!(report.results.len(), 4);
+
+        // lookback = 3 shines on the early training data and fails afterwards
+        for result in &report.results {
+            assert_eq!(result.overfit, result.params["lookback"] == 3.0, "{:?}", result.params);
+        }
+        let best = report.best().unwrap();
+        assert_eq!(best.params["edge"], 0.002);
+        assert_eq!(best.params["lookback"], 5.0);
+
+        // Once the regime ends, the set picked on the training window loses out of sample
+        assert_eq!(report.selections[1].params["lookback"], 3.0);
+        assert!(report.selections[0].out_of_sample_pnl > 0.0);
+        assert!(report.selections[1].out_of_sample_pnl < 0.0);
+        assert!(report.walk_forward_efficiency.unwrap() < 1.0);
+        assert!(report.table().contains("OVERFIT"));
+    }
+}
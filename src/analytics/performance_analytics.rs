@@ -3,6 +3,10 @@
 //! el rendimiento del sistema de trading en tiempo real
 
 use crate::config::SimpleConfig;
+use super::experiments::ExperimentReport;
+use super::tca::TcaReport;
+use super::benchmark::BenchmarkReport;
+use super::capacity::CapacityReport;
 use anyhow::Result;
 use chrono::{DateTime, Utc, Duration};
 use serde::{Deserialize, Serialize};
@@ -461,6 +465,14 @@ pub struct PerformanceAnalyticsAI {
     active_alerts: Vec<PerformanceAlert>,
     /// Último reporte generado
     last_report_time: Option<DateTime<Utc>>,
+    /// Latest A/B experiment reports
+    experiment_reports: Vec<ExperimentReport>,
+    /// Latest transaction cost analysis report
+    tca_report: Option<TcaReport>,
+    /// Latest strategy vs HODL comparison
+    benchmark_report: Option<BenchmarkReport>,
+    /// Latest per-strategy capital capacity estimates
+    capacity_report: Option<CapacityReport>,
 }
 
 impl PerformanceAnalyticsAI {
@@ -477,6 +489,10 @@ impl PerformanceAnalyticsAI {
             analysis_history: VecDeque::new(),
             active_alerts: Vec::new(),
             last_report_time: None,
+            experiment_reports: Vec::new(),
+            tca_report: None,
+            benchmark_report: None,
+            capacity_report: None,
         }
     }
     
@@ -623,6 +639,15 @@ impl PerformanceAnalyticsAI {
             }
         }
         
+        if let Some(benchmark) = &self.benchmark_report {
+            info!("📂 Benchmark: estrategia {:+.2}% en {} muestras", benchmark.strategy_return_percent, benchmark.samples);
+            for comparison in &benchmark.comparisons {
+                info!("  ⚖️ vs {}: {:+.2}% (exceso {:+.2} pp, correlación {})",
+                      comparison.benchmark.name(), comparison.return_percent, comparison.excess_return_percent,
+                      comparison.correlation.map_or("n/a".to_string(), |c| format!("{:.2}", c)));
+            }
+        }
+        
         // Actualizar timestamp del último reporte
         self.last_report_time = Some(now);
         
@@ -809,6 +834,55 @@ impl PerformanceAnalyticsAI {
             }
         }
         
+        if !self.experiment_reports.is_empty() {
+            report.push_str("\n🧪 A/B EXPERIMENTS:\n");
+            for experiment in &self.experiment_reports {
+                report.push_str(&format!("  • {} [{}]: {} vs {} → {}\n",
+                                       experiment.experiment_id, experiment.strategy_name,
+                                       experiment.control_name, experiment.treatment_name,
+                                       experiment.verdict()));
+            }
+        }
+        
+        if let Some(tca) = self.tca_report.as_ref().filter(|t| t.total_trades > 0) {
+            report.push_str("\n🧾 EXECUTION QUALITY (TCA):\n");
+            report.push_str(&format!("  • Trades: {} | Avg Shortfall: {:.1} bps (delay {:.1}, slippage {:.1}, fees {:.1})\n",
+                                   tca.total_trades, tca.overall.avg_shortfall_bps, tca.overall.avg_delay_cost_bps,
+                                   tca.overall.avg_slippage_bps, tca.overall.avg_fee_bps));
+            for strategy in &tca.by_strategy {
+                report.push_str(&format!("  • Strategy {}: {:.1} bps over {} trades, {:.0}ms to land\n",
+                                       strategy.key, strategy.avg_shortfall_bps, strategy.trades,
+                                       strategy.avg_decision_latency_ms + strategy.avg_landing_latency_ms));
+            }
+            for venue in &tca.by_venue {
+                report.push_str(&format!("  • Venue {}: {:.1} bps over {} trades (slippage {:.1} bps)\n",
+                                       venue.key, venue.avg_shortfall_bps, venue.trades, venue.avg_slippage_bps));
+            }
+        }
+        
+        if let Some(benchmark) = &self.benchmark_report {
+            report.push_str("\n⚖️  STRATEGY VS HODL:\n");
+            report.push_str(&format!("  • Strategy: {:+.2}% over {} samples ({} → {})\n",
+                                   benchmark.strategy_return_percent, benchmark.samples,
+                                   benchmark.start.format("%Y-%m-%d %H:%M"), benchmark.end.format("%Y-%m-%d %H:%M")));
+            for comparison in &benchmark.comparisons {
+                report.push_str(&format!("  • {}: {:+.2}% | Excess: {:+.2} pp | Correlation: {}\n",
+                                       comparison.benchmark.name(), comparison.return_percent,
+                                       comparison.excess_return_percent,
+                                       comparison.correlation.map_or("n/a".to_string(), |c| format!("{:.2}", c))));
+            }
+        }
+        
+        if let Some(capacity) = self.capacity_report.as_ref().filter(|c| !c.strategies.is_empty()) {
+            report.push_str("\n📐 STRATEGY CAPACITY:\n");
+            for estimate in &capacity.strategies {
+                let limit = estimate.capacity_usd.map_or("no size clears the edge threshold".to_string(), |c| format!("${:.0} per trade", c));
+                report.push_str(&format!("  • {}: {} | Best size: ${:.0} (${:.2}/trade) | Edge: {:.1} bps | Impact from {:?}\n",
+                                       estimate.strategy, limit, estimate.optimal_size_usd,
+                                       estimate.max_profit_per_trade_usd, estimate.gross_edge_bps, estimate.impact_source));
+            }
+        }
+        
         report.push_str(&format!("\n📊 SYSTEM STATISTICS:\n"));
         report.push_str(&format!("  • Total Analyses: {}\n", self.stats.total_analyses_performed));
         report.push_str(&format!("  • Recommendations Generated: {}\n", self.stats.total_recommendations_generated));
@@ -818,6 +892,46 @@ impl PerformanceAnalyticsAI {
         report
     }
     
+    /// Update the experiment results included in summary reports
+    pub fn update_experiment_reports(&mut self, reports: Vec<ExperimentReport>) {
+        self.experiment_reports = reports;
+    }
+    
+    /// Latest A/B experiment reports
+    pub fn get_experiment_reports(&self) -> &[ExperimentReport] {
+        &self.experiment_reports
+    }
+    
+    /// Update the transaction cost analysis included in summary reports
+    pub fn update_tca_report(&mut self, report: TcaReport) {
+        self.tca_report = Some(report);
+    }
+    
+    /// Latest transaction cost analysis report
+    pub fn get_tca_report(&self) -> Option<&TcaReport> {
+        self.tca_report.as_ref()
+    }
+    
+    /// Update the strategy vs HODL comparison included in reports
+    pub fn update_benchmark_report(&mut self, report: BenchmarkReport) {
+        self.benchmark_report = Some(report);
+    }
+    
+    /// Latest strategy vs HODL comparison
+    pub fn get_benchmark_report(&self) -> Option<&BenchmarkReport> {
+        self.benchmark_report.as_ref()
+    }
+    
+    /// Update the strategy capacity estimates included in reports
+    pub fn update_capacity_report(&mut self, report: CapacityReport) {
+        self.capacity_report = Some(report);
+    }
+    
+    /// Latest strategy capacity estimates
+    pub fn get_capacity_report(&self) -> Option<&CapacityReport> {
+        self.capacity_report.as_ref()
+    }
+    
     /// Obtener estadísticas
     pub fn get_statistics(&self) -> &AnalyticsStats {
         &self.stats
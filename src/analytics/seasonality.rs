@@ -0,0 +1,292 @@
+//! Time-of-day and day-of-week seasonality
+//!
+//! Opportunity frequency and realized PnL are bucketed by hour of week (168
+//! slots, UTC). Each slot also counts the distinct clock hours in which the
+//! system was actually scanning, so frequencies are rates per observed hour
+//! rather than raw counts that favour whenever the process happened to run.
+//!
+//! The profile drives two decisions: scan intervals shrink in slots that
+//! historically produce more opportunities than average and stretch in quiet
+//! ones, and [`SeasonalityStats::sessions`] lists the contiguous windows worth
+//! trading for a session scheduler.
+
+use chrono::{DateTime, Datelike, Timelike, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+const SLOTS: usize = 7 * 24;
+
+/// Tuning knobs for interval scaling and session selection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonalityConfig {
+    /// Observed hours a slot needs before its profile is trusted
+    pub min_observed_hours: u64,
+    /// Scan interval is never scaled below `base / max_speedup`
+    pub max_speedup: f64,
+    /// ... nor above `base * max_slowdown`
+    pub max_slowdown: f64,
+}
+
+impl Default for SeasonalityConfig {
+    fn default() -> Self {
+        Self {
+            min_observed_hours: 3,
+            max_speedup: 2.0,
+            max_slowdown: 4.0,
+        }
+    }
+}
+
+/// Aggregates for one hour-of-week slot
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SeasonalSlot {
+    pub observed_hours: u64,
+    pub opportunities: u64,
+    pub trades: u64,
+    pub wins: u64,
+    pub realized_pnl: f64,
+    /// Hours since the epoch of the last scan, to count distinct hours
+    last_observed_hour: Option<i64>,
+}
+
+impl SeasonalSlot {
+    pub fn opportunities_per_hour(&self) -> f64 {
+        if self.observed_hours == 0 { 0.0 } else { self.opportunities as f64 / self.observed_hours as f64 }
+    }
+
+    pub fn pnl_per_hour(&self) -> f64 {
+        if self.observed_hours == 0 { 0.0 } else { self.realized_pnl / self.observed_hours as f64 }
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.trades == 0 { 0.0 } else { self.wins as f64 / self.trades as f64 }
+    }
+
+    fn merge(&mut self, other: &SeasonalSlot) {
+        self.observed_hours += other.observed_hours;
+        self.opportunities += other.opportunities;
+        self.trades += other.trades;
+        self.wins += other.wins;
+        self.realized_pnl += other.realized_pnl;
+    }
+}
+
+/// Profile of one hour of day or one weekday
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonalBucket {
+    /// Hour of day (0-23) or weekday (0 = Monday)
+    pub index: u8,
+    pub opportunities_per_hour: f64,
+    pub pnl_per_hour: f64,
+    pub win_rate: f64,
+    pub trades: u64,
+    pub observed_hours: u64,
+}
+
+/// Contiguous hours on one weekday worth trading
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionWindow {
+    pub weekday: Weekday,
+    pub start_hour: u8,
+    /// Exclusive
+    pub end_hour: u8,
+    pub expected_pnl_per_hour: f64,
+}
+
+/// Hour-of-week opportunity and PnL statistics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonalityStats {
+    config: SeasonalityConfig,
+    slots: Vec<SeasonalSlot>,
+}
+
+impl Default for SeasonalityStats {
+    fn default() -> Self {
+        Self::new(SeasonalityConfig::default())
+    }
+}
+
+fn slot_index(at: DateTime<Utc>) -> usize {
+    at.weekday().num_days_from_monday() as usize * 24 + at.hour() as usize
+}
+
+impl SeasonalityStats {
+    pub fn new(config: SeasonalityConfig) -> Self {
+        Self { config, slots: vec![SeasonalSlot::default(); SLOTS] }
+    }
+
+    /// A scan ran at `at` and found `opportunities`
+    pub fn record_scan(&mut self, at: DateTime<Utc>, opportunities: usize) {
+        let hour = at.timestamp().div_euclid(3600);
+        let slot = &mut self.slots[slot_index(at)];
+        if slot.last_observed_hour != Some(hour) {
+            slot.last_observed_hour = Some(hour);
+            slot.observed_hours += 1;
+        }
+        slot.opportunities += opportunities as u64;
+    }
+
+    /// A trade closed at `at` with realized `pnl`
+    pub fn record_trade(&mut self, at: DateTime<Utc>, pnl: f64) {
+        let slot = &mut self.slots[slot_index(at)];
+        slot.trades += 1;
+        if pnl > 0.0 {
+            slot.wins += 1;
+        }
+        slot.realized_pnl += pnl;
+    }
+
+    pub fn slot(&self, at: DateTime<Utc>) -> &SeasonalSlot {
+        &self.slots[slot_index(at)]
+    }
+
+    fn bucket(index: u8, slots: impl Iterator<Item = SeasonalSlot>) -> SeasonalBucket {
+        let mut total = SeasonalSlot::default();
+        slots.for_each(|slot| total.merge(&slot));
+        SeasonalBucket {
+            index,
+            opportunities_per_hour: total.opportunities_per_hour(),
+            pnl_per_hour: total.pnl_per_hour(),
+            win_rate: total.win_rate(),
+            trades: total.trades,
+            observed_hours: total.observed_hours,
+        }
+    }
+
+    /// Profile per hour of day (UTC), across weekdays
+    pub fn hourly_profile(&self) -> Vec<SeasonalBucket> {
+        (0..24u8)
+            .map(|hour| Self::bucket(hour, (0..7).map(|day| self.slots[day * 24 + hour as usize].clone())))
+            .collect()
+    }
+
+    /// Profile per weekday (0 = Monday)
+    pub fn weekday_profile(&self) -> Vec<SeasonalBucket> {
+        (0..7u8)
+            .map(|day| Self::bucket(day, self.slots[day as usize * 24..(day as usize + 1) * 24].iter().cloned()))
+            .collect()
+    }
+
+    fn mean_opportunity_rate(&self) -> f64 {
+        let (opportunities, hours) = self.slots.iter()
+            .fold((0u64, 0u64), |(o, h), slot| (o + slot.opportunities, h + slot.observed_hours));
+        if hours == 0 { 0.0 } else { opportunities as f64 / hours as f64 }
+    }
+
+    /// Opportunity rate of the slot containing `at` relative to the overall mean
+    ///
+    /// 1.0 when the slot (or the whole profile) has too little history.
+    pub fn activity_index(&self, at: DateTime<Utc>) -> f64 {
+        let slot = self.slot(at);
+        let mean = self.mean_opportunity_rate();
+        if slot.observed_hours < self.config.min_observed_hours || mean <= 0.0 {
+            return 1.0;
+        }
+        slot.opportunities_per_hour() / mean
+    }
+
+    /// `base` scaled inversely to the activity expected at `at`
+    pub fn scan_interval(&self, base: Duration, at: DateTime<Utc>) -> Duration {
+        let index = self.activity_index(at);
+        let factor = if index > 0.0 { 1.0 / index } else { self.config.max_slowdown };
+        base.mul_f64(factor.clamp(1.0 / self.config.max_speedup, self.config.max_slowdown))
+    }
+
+    /// Contiguous windows whose trusted slots earned at least `min_pnl_per_hour`
+    pub fn sessions(&self, min_pnl_per_hour: f64) -> Vec<SessionWindow> {
+        let mut windows = Vec::new();
+        for day in 0..7u8 {
+            let weekday = Weekday::try_from(day).unwrap_or(Weekday::Mon);
+            let mut open: Option<(u8, f64, u8)> = None; // (start, pnl sum, hours)
+            for hour in 0..=24u8 {
+                let qualifies = hour < 24 && {
+                    let slot = &self.slots[day as usize * 24 + hour as usize];
+                    slot.observed_hours >= self.config.min_observed_hours && slot.pnl_per_hour() >= min_pnl_per_hour
+                };
+                match (&mut open, qualifies) {
+                    (Some((_, pnl, hours)), true) => {
+                        *pnl += self.slots[day as usize * 24 + hour as usize].pnl_per_hour();
+                        *hours += 1;
+                    }
+                    (None, true) => open = Some((hour, self.slots[day as usize * 24 + hour as usize].pnl_per_hour(), 1)),
+                    (Some((start, pnl, hours)), false) => {
+                        windows.push(SessionWindow {
+                            weekday,
+                            start_hour: *start,
+                            end_hour: hour,
+                            expected_pnl_per_hour: *pnl / *hours as f64,
+                        });
+                        open = None;
+                    }
+                    (None, false) => {}
+                }
+            }
+        }
+        windows
+    }
+
+    /// Whether `at` falls in one of the profitable sessions
+    pub fn in_session(&self, at: DateTime<Utc>, min_pnl_per_hour: f64) -> bool {
+        let hour = at.hour() as u8;
+        self.sessions(min_pnl_per_hour)
+            .iter()
+            .any(|window| window.weekday == at.weekday() && (window.start_hour..window.end_hour).contains(&hour))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        // 2024-01-01 is a Monday
+        Utc.with_ymd_and_hms(2024, 1, day, hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_rates_are_per_observed_hour_and_scale_intervals() {
+        let mut stats = SeasonalityStats::default();
+        for week in 0..3 {
+            let monday = 1 + week * 7;
+            // Busy 14:00 UTC: 4 scans with 5 opportunities each
+            for _ in 0..4 {
+                stats.record_scan(at(monday, 14), 5);
+            }
+            // Quiet 03:00 UTC
+            stats.record_scan(at(monday, 3), 1);
+        }
+        assert_eq!(stats.slot(at(1, 14)).observed_hours, 3);
+        assert_eq!(stats.slot(at(1, 14)).opportunities_per_hour(), 20.0);
+
+        let base = Duration::from_secs(8);
+        assert!(stats.scan_interval(base, at(1, 14)) < base);
+        assert!(stats.scan_interval(base, at(1, 3)) > base);
+        // No history for Tuesday: unchanged
+        assert_eq!(stats.scan_interval(base, at(2, 14)), base);
+        assert_eq!(stats.hourly_profile()[14].observed_hours, 3);
+    }
+
+    #[test]
+    fn test_sessions_cover_profitable_contiguous_hours() {
+        let mut stats = SeasonalityStats::default();
+        for week in 0..3 {
+            let wednesday = 3 + week * 7;
+            for hour in 0..24 {
+                stats.record_scan(at(wednesday, hour), 1);
+                let pnl = if (13..16).contains(&hour) { 12.0 } else { -1.0 };
+                stats.record_trade(at(wednesday, hour), pnl);
+            }
+        }
+        let sessions = stats.sessions(5.0);
+        assert_eq!(sessions, vec![SessionWindow {
+            weekday: Weekday::Wed,
+            start_hour: 13,
+            end_hour: 16,
+            expected_pnl_per_hour: 12.0,
+        }]);
+        assert!(stats.in_session(at(10, 14), 5.0));
+        assert!(!stats.in_session(at(10, 16), 5.0));
+        assert_eq!(stats.weekday_profile()[2].trades, 72);
+    }
+}
@@ -0,0 +1,283 @@
+//! Transaction cost analysis (TCA)
+//!
+//! Computes implementation shortfall per trade by decomposing the gap between
+//! the mid-price at decision time and the landed price into delay cost
+//! (decision → quote), execution slippage (quote → landed) and explicit fees,
+//! and aggregates the results per strategy and per venue for reporting.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::debug;
+
+/// Trade direction for cost sign conventions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TcaSide {
+    Buy,
+    Sell,
+}
+
+impl TcaSide {
+    /// +1 for buys (paying more is a cost), -1 for sells (receiving less is a cost)
+    fn sign(&self) -> f64 {
+        match self {
+            Self::Buy => 1.0,
+            Self::Sell => -1.0,
+        }
+    }
+}
+
+/// Explicit fees paid on a trade, in quote currency
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeeBreakdown {
+    pub network_fee: f64,
+    pub priority_fee: f64,
+    pub venue_fee: f64,
+    pub tip: f64,
+}
+
+impl FeeBreakdown {
+    pub fn total(&self) -> f64 {
+        self.network_fee + self.priority_fee + self.venue_fee + self.tip
+    }
+}
+
+/// Everything known about one executed trade
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionRecord {
+    pub trade_id: String,
+    pub strategy: String,
+    pub venue: String,
+    pub side: TcaSide,
+    /// Base-asset quantity filled
+    pub quantity: f64,
+    /// Mid-price when the decision to trade was made
+    pub decision_mid_price: f64,
+    /// Price quoted by the venue/aggregator before submission
+    pub quoted_price: f64,
+    /// Average price actually obtained
+    pub landed_price: f64,
+    pub fees: FeeBreakdown,
+    pub decision_at: DateTime<Utc>,
+    pub submitted_at: DateTime<Utc>,
+    pub landed_at: DateTime<Utc>,
+    /// Fingerprint of the binary that executed the trade
+    #[serde(default)]
+    pub build: String,
+}
+
+/// Implementation shortfall for a trade (positive = cost)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TcaResult {
+    pub trade_id: String,
+    pub strategy: String,
+    pub venue: String,
+    /// Decision mid → quoted price
+    pub delay_cost_bps: f64,
+    /// Quoted price → landed price
+    pub slippage_bps: f64,
+    /// Explicit fees relative to notional
+    pub fee_bps: f64,
+    /// Sum of the above
+    pub implementation_shortfall_bps: f64,
+    /// Shortfall in quote currency
+    pub implementation_shortfall: f64,
+    pub notional: f64,
+    /// Decision → submission
+    pub decision_latency_ms: i64,
+    /// Submission → landed
+    pub landing_latency_ms: i64,
+    pub fees: FeeBreakdown,
+}
+
+/// Compute the implementation shortfall of a trade
+pub fn analyze_execution(record: &ExecutionRecord) -> TcaResult {
+    let sign = record.side.sign();
+    let bps = |from: f64, to: f64| if from > 0.0 { sign * (to - from) / from * 10_000.0 } else { 0.0 };
+
+    let notional = record.quantity * record.decision_mid_price;
+    let delay_cost_bps = bps(record.decision_mid_price, record.quoted_price);
+    let slippage_bps = bps(record.quoted_price, record.landed_price);
+    let fee_bps = if notional > 0.0 { record.fees.total() / notional * 10_000.0 } else { 0.0 };
+    let implementation_shortfall_bps = delay_cost_bps + slippage_bps + fee_bps;
+
+    TcaResult {
+        trade_id: record.trade_id.clone(),
+        strategy: record.strategy.clone(),
+        venue: record.venue.clone(),
+        delay_cost_bps,
+        slippage_bps,
+        fee_bps,
+        implementation_shortfall_bps,
+        implementation_shortfall: implementation_shortfall_bps / 10_000.0 * notional,
+        notional,
+        decision_latency_ms: (record.submitted_at - record.decision_at).num_milliseconds(),
+        landing_latency_ms: (record.landed_at - record.submitted_at).num_milliseconds(),
+        fees: record.fees.clone(),
+    }
+}
+
+/// Aggregated execution quality for a strategy or venue
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TcaAggregate {
+    pub key: String,
+    pub trades: u64,
+    pub total_notional: f64,
+    pub total_shortfall: f64,
+    pub total_fees: f64,
+    /// Notional-weighted averages
+    pub avg_shortfall_bps: f64,
+    pub avg_delay_cost_bps: f64,
+    pub avg_slippage_bps: f64,
+    pub avg_fee_bps: f64,
+    pub avg_decision_latency_ms: f64,
+    pub avg_landing_latency_ms: f64,
+}
+
+impl TcaAggregate {
+    fn add(&mut self, result: &TcaResult) {
+        let weight = result.notional.max(0.0);
+        let new_notional = self.total_notional + weight;
+        let blend = |avg: f64, value: f64| {
+            if new_notional > 0.0 { (avg * self.total_notional + value * weight) / new_notional } else { avg }
+        };
+
+        self.avg_shortfall_bps = blend(self.avg_shortfall_bps, result.implementation_shortfall_bps);
+        self.avg_delay_cost_bps = blend(self.avg_delay_cost_bps, result.delay_cost_bps);
+        self.avg_slippage_bps = blend(self.avg_slippage_bps, result.slippage_bps);
+        self.avg_fee_bps = blend(self.avg_fee_bps, result.fee_bps);
+
+        self.trades += 1;
+        let n = self.trades as f64;
+        self.avg_decision_latency_ms += (result.decision_latency_ms as f64 - self.avg_decision_latency_ms) / n;
+        self.avg_landing_latency_ms += (result.landing_latency_ms as f64 - self.avg_landing_latency_ms) / n;
+
+        self.total_notional = new_notional;
+        self.total_shortfall += result.implementation_shortfall;
+        self.total_fees += result.fees.total();
+    }
+}
+
+/// TCA report for analytics
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TcaReport {
+    pub total_trades: u64,
+    pub overall: TcaAggregate,
+    pub by_strategy: Vec<TcaAggregate>,
+    /// Venues ranked from cheapest to most expensive
+    pub by_venue: Vec<TcaAggregate>,
+}
+
+/// Collects per-trade TCA results and aggregates them
+#[derive(Debug, Default)]
+pub struct TcaAnalyzer {
+    results: Vec<TcaResult>,
+    by_strategy: HashMap<String, TcaAggregate>,
+    by_venue: HashMap<String, TcaAggregate>,
+    overall: TcaAggregate,
+}
+
+impl TcaAnalyzer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Analyze and record an executed trade
+    pub fn record(&mut self, record: &ExecutionRecord) -> TcaResult {
+        let result = analyze_execution(record);
+        debug!("🧾 TCA {} [{} @ {}]: shortfall {:.1} bps (delay {:.1}, slippage {:.1}, fees {:.1})",
+               result.trade_id, result.strategy, result.venue, result.implementation_shortfall_bps,
+               result.delay_cost_bps, result.slippage_bps, result.fee_bps);
+
+        self.by_strategy
+            .entry(result.strategy.clone())
+            .or_insert_with(|| TcaAggregate { key: result.strategy.clone(), ..Default::default() })
+            .add(&result);
+        self.by_venue
+            .entry(result.venue.clone())
+            .or_insert_with(|| TcaAggregate { key: result.venue.clone(), ..Default::default() })
+            .add(&result);
+        self.overall.key = "all".to_string();
+        self.overall.add(&result);
+
+        self.results.push(result.clone());
+        result
+    }
+
+    /// Per-trade results
+    pub fn results(&self) -> &[TcaResult] {
+        &self.results
+    }
+
+    /// Build an aggregated report
+    pub fn report(&self) -> TcaReport {
+        let mut by_strategy: Vec<_> = self.by_strategy.values().cloned().collect();
+        by_strategy.sort_by(|a, b| a.key.cmp(&b.key));
+
+        let mut by_venue: Vec<_> = self.by_venue.values().cloned().collect();
+        by_venue.sort_by(|a, b| a.avg_shortfall_bps.partial_cmp(&b.avg_shortfall_bps).unwrap_or(std::cmp::Ordering::Equal));
+
+        TcaReport {
+            total_trades: self.overall.trades,
+            overall: self.overall.clone(),
+            by_strategy,
+            by_venue,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn record(strategy: &str, venue: &str, side: TcaSide, mid: f64, quoted: f64, landed: f64, fee: f64) -> ExecutionRecord {
+        let now = Utc::now();
+        ExecutionRecord {
+            trade_id: format!("{}-{}", strategy, venue),
+            strategy: strategy.to_string(),
+            venue: venue.to_string(),
+            side,
+            quantity: 10.0,
+            decision_mid_price: mid,
+            quoted_price: quoted,
+            landed_price: landed,
+            fees: FeeBreakdown { venue_fee: fee, ..Default::default() },
+            decision_at: now - Duration::milliseconds(300),
+            submitted_at: now - Duration::milliseconds(100),
+            landed_at: now,
+            build: crate::security::build_fingerprint(),
+        }
+    }
+
+    #[test]
+    fn test_buy_shortfall_decomposition() {
+        let result = analyze_execution(&record("snipe", "raydium", TcaSide::Buy, 100.0, 100.5, 101.0, 1.0));
+        assert!((result.delay_cost_bps - 50.0).abs() < 1e-9);
+        assert!((result.slippage_bps - 49.751243781).abs() < 1e-6);
+        assert!((result.fee_bps - 10.0).abs() < 1e-9);
+        assert_eq!(result.decision_latency_ms, 200);
+        assert_eq!(result.landing_latency_ms, 100);
+    }
+
+    #[test]
+    fn test_sell_price_improvement_is_negative_cost() {
+        let result = analyze_execution(&record("arb", "orca", TcaSide::Sell, 100.0, 100.0, 100.2, 0.0));
+        assert!(result.slippage_bps < 0.0);
+        assert!(result.implementation_shortfall < 0.0);
+    }
+
+    #[test]
+    fn test_venue_ranking() {
+        let mut tca = TcaAnalyzer::new();
+        tca.record(&record("snipe", "raydium", TcaSide::Buy, 100.0, 100.0, 101.0, 0.0));
+        tca.record(&record("snipe", "orca", TcaSide::Buy, 100.0, 100.0, 100.2, 0.0));
+        tca.record(&record("arb", "orca", TcaSide::Buy, 100.0, 100.0, 100.4, 0.0));
+
+        let report = tca.report();
+        assert_eq!(report.total_trades, 3);
+        assert_eq!(report.by_venue[0].key, "orca");
+        assert!((report.by_venue[0].avg_shortfall_bps - 30.0).abs() < 1e-6);
+        assert_eq!(report.by_strategy.len(), 2);
+    }
+}
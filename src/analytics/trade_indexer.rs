@@ -0,0 +1,710 @@
+//! Wallet transaction indexer
+//!
+//! Continuously ingests the managed wallets' transaction history over
+//! JSON-RPC (any Solana RPC, including Helius endpoints), decodes swaps from
+//! the wallet's token/SOL balance changes and stores normalized trade rows in
+//! a local JSON store. The rows are the raw material for reconciliation,
+//! attribution and tax exports.
+//!
+//! Operators can attach notes and tags to trades and positions ("entered on
+//! news", "exit forced by depeg alert"). Annotations live in the same store
+//! and are carried into [`TradeIndexer::journal`] and the CSV export, so
+//! post-mortems see the human context next to each automated decision.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::fs;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::monitoring::HeartbeatHandle;
+use crate::trading::execution::tx_errors::{decode_failure, TxFailure};
+
+/// Wrapped SOL mint, used for native SOL legs
+pub const NATIVE_SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+/// SOL deltas below this (rent, fees) are not treated as a swap leg
+const MIN_SOL_LEG: f64 = 0.00001;
+
+/// Known swap programs, for labelling rows
+pub const KNOWN_PROGRAMS: &[(&str, &str)] = &[
+    ("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4", "Jupiter"),
+    ("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8", "Raydium"),
+    ("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK", "Raydium CLMM"),
+    ("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc", "Orca"),
+    ("9W959DqEETiGZocYWCQPaJ6sBmUzgfxXfqGeTEdp3aQP", "Orca Legacy"),
+    ("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo", "Meteora"),
+    ("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P", "Pump.fun"),
+];
+
+/// Indexer settings
+#[derive(Debug, Clone)]
+pub struct IndexerConfig {
+    pub rpc_url: String,
+    pub wallets: Vec<String>,
+    pub poll_interval: Duration,
+    /// Signatures requested per `getSignaturesForAddress` page
+    pub page_size: usize,
+    pub storage_path: PathBuf,
+}
+
+impl Default for IndexerConfig {
+    fn default() -> Self {
+        Self {
+            rpc_url: std::env::var("SOLANA_RPC_URL")
+                .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string()),
+            wallets: Vec::new(),
+            poll_interval: Duration::from_secs(30),
+            page_size: 100,
+            storage_path: PathBuf::from("state/trade_index.json"),
+        }
+    }
+}
+
+/// Signature listing entry
+#[derive(Debug, Clone, Deserialize)]
+pub struct SignatureInfo {
+    pub signature: String,
+    pub slot: u64,
+    #[serde(rename = "blockTime")]
+    pub block_time: Option<i64>,
+    pub err: Option<Value>,
+}
+
+/// Where transactions are read from
+#[async_trait]
+pub trait TransactionSource: Send + Sync {
+    /// Signatures for `address`, newest first, strictly older than `before` and newer than `until`
+    async fn signatures(&self, address: &str, before: Option<&str>, until: Option<&str>, limit: usize) -> Result<Vec<SignatureInfo>>;
+
+    /// Full transaction in `jsonParsed` encoding; `None` if not (yet) available
+    async fn transaction(&self, signature: &str) -> Result<Option<Value>>;
+}
+
+/// Plain Solana JSON-RPC source
+#[derive(Debug, Clone)]
+pub struct RpcTransactionSource {
+    client: reqwest::Client,
+    rpc_url: String,
+}
+
+impl RpcTransactionSource {
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(30))
+                .build()
+                .unwrap_or_default(),
+            rpc_url: rpc_url.to_string(),
+        }
+    }
+
+    async fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let response: Value = self.client
+            .post(&self.rpc_url)
+            .json(&json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        if let Some(error) = response.get("error") {
+            return Err(anyhow!("{} failed: {}", method, error));
+        }
+        Ok(response.get("result").cloned().unwrap_or(Value::Null))
+    }
+}
+
+#[async_trait]
+impl TransactionSource for RpcTransactionSource {
+    async fn signatures(&self, address: &str, before: Option<&str>, until: Option<&str>, limit: usize) -> Result<Vec<SignatureInfo>> {
+        let mut options = json!({ "limit": limit, "commitment": "confirmed" });
+        if let Some(before) = before {
+            options["before"] = json!(before);
+        }
+        if let Some(until) = until {
+            options["until"] = json!(until);
+        }
+        let result = self.call("getSignaturesForAddress", json!([address, options])).await?;
+        Ok(serde_json::from_value(result)?)
+    }
+
+    async fn transaction(&self, signature: &str) -> Result<Option<Value>> {
+        let result = self.call("getTransaction", json!([signature, {
+            "encoding": "jsonParsed",
+            "commitment": "confirmed",
+            "maxSupportedTransactionVersion": 0
+        }])).await?;
+        Ok((!result.is_null()).then_some(result))
+    }
+}
+
+/// Normalized swap row
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexedTrade {
+    pub signature: String,
+    pub wallet: String,
+    pub slot: u64,
+    pub block_time: Option<DateTime<Utc>>,
+    /// Swap program label, when recognized
+    pub venue: Option<String>,
+    pub mint_in: String,
+    pub amount_in: f64,
+    pub mint_out: String,
+    pub amount_out: f64,
+    pub fee_lamports: u64,
+}
+
+/// Transaction the wallet paid for that failed on-chain
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FailedTransaction {
+    pub signature: String,
+    pub wallet: String,
+    pub slot: u64,
+    pub block_time: Option<DateTime<Utc>>,
+    pub venue: Option<String>,
+    pub fee_lamports: u64,
+    pub failure: TxFailure,
+}
+
+/// What an annotation is attached to
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AnnotationTarget {
+    /// Indexed trade, by transaction signature
+    Trade(String),
+    /// Bot position, by position id
+    Position(String),
+}
+
+/// Manual note/tags on a trade or position
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradeAnnotation {
+    pub id: Uuid,
+    pub target: AnnotationTarget,
+    pub note: String,
+    pub tags: Vec<String>,
+    pub author: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Trade row with its annotations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub trade: IndexedTrade,
+    pub annotations: Vec<TradeAnnotation>,
+}
+
+/// Net per-mint balance change of `wallet` in a parsed transaction
+fn wallet_balance_deltas(wallet: &str, tx: &Value) -> HashMap<String, f64> {
+    let meta = &tx["meta"];
+    let mut deltas: HashMap<String, f64> = HashMap::new();
+
+    let token_amounts = |key: &str| -> Vec<(String, f64)> {
+        meta[key]
+            .as_array()
+            .map(|balances| {
+                balances
+                    .iter()
+                    .filter(|b| b["owner"].as_str() == Some(wallet))
+                    .filter_map(|b| {
+                        let mint = b["mint"].as_str()?.to_string();
+                        let amount = b["uiTokenAmount"]["uiAmountString"].as_str()?.parse::<f64>().ok()?;
+                        Some((mint, amount))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    for (mint, amount) in token_amounts("postTokenBalances") {
+        *deltas.entry(mint).or_default() += amount;
+    }
+    for (mint, amount) in token_amounts("preTokenBalances") {
+        *deltas.entry(mint).or_default() -= amount;
+    }
+
+    // Native SOL leg: lamport change of the wallet account, with the fee added back
+    let keys = tx["transaction"]["message"]["accountKeys"].as_array();
+    let index = keys.and_then(|keys| {
+        keys.iter().position(|k| k["pubkey"].as_str().or_else(|| k.as_str()) == Some(wallet))
+    });
+    if let Some(index) = index {
+        let pre = meta["preBalances"][index].as_i64().unwrap_or(0);
+        let post = meta["postBalances"][index].as_i64().unwrap_or(0);
+        let fee = if index == 0 { meta["fee"].as_i64().unwrap_or(0) } else { 0 };
+        let sol = (post - pre + fee) as f64 / 1e9;
+        if sol.abs() >= MIN_SOL_LEG {
+            *deltas.entry(NATIVE_SOL_MINT.to_string()).or_default() += sol;
+        }
+    }
+
+    deltas.retain(|_, delta| delta.abs() > f64::EPSILON);
+    deltas
+}
+
+fn swap_venue(tx: &Value) -> Option<String> {
+    let keys = tx["transaction"]["message"]["accountKeys"].as_array()?;
+    keys.iter()
+        .filter_map(|k| k["pubkey"].as_str().or_else(|| k.as_str()))
+        .find_map(|key| KNOWN_PROGRAMS.iter().find(|(id, _)| *id == key).map(|(_, name)| name.to_string()))
+}
+
+/// Decode a swap from a parsed transaction; `None` if the wallet did not swap
+pub fn decode_swap(wallet: &str, signature: &str, tx: &Value) -> Option<IndexedTrade> {
+    if !tx["meta"]["err"].is_null() {
+        return None;
+    }
+    let deltas = wallet_balance_deltas(wallet, tx);
+    let by_magnitude = |a: &(&String, &f64), b: &(&String, &f64)| a.1.abs().total_cmp(&b.1.abs());
+    let (mint_in, spent) = deltas.iter().filter(|(_, d)| **d < 0.0).max_by(by_magnitude)?;
+    let (mint_out, received) = deltas.iter().filter(|(_, d)| **d > 0.0).max_by(by_magnitude)?;
+
+    Some(IndexedTrade {
+        signature: signature.to_string(),
+        wallet: wallet.to_string(),
+        slot: tx["slot"].as_u64().unwrap_or(0),
+        block_time: tx["blockTime"].as_i64().and_then(|t| Utc.timestamp_opt(t, 0).single()),
+        venue: swap_venue(tx),
+        mint_in: mint_in.clone(),
+        amount_in: -spent,
+        mint_out: mint_out.clone(),
+        amount_out: *received,
+        fee_lamports: tx["meta"]["fee"].as_u64().unwrap_or(0),
+    })
+}
+
+/// Decode a failed transaction paid for by `wallet`; `None` if it succeeded or was someone else's
+pub fn decode_failed(wallet: &str, signature: &str, tx: &Value) -> Option<FailedTransaction> {
+    let fee_payer = &tx["transaction"]["message"]["accountKeys"][0];
+    if fee_payer["pubkey"].as_str().or_else(|| fee_payer.as_str()) != Some(wallet) {
+        return None;
+    }
+    Some(FailedTransaction {
+        signature: signature.to_string(),
+        wallet: wallet.to_string(),
+        slot: tx["slot"].as_u64().unwrap_or(0),
+        block_time: tx["blockTime"].as_i64().and_then(|t| Utc.timestamp_opt(t, 0).single()),
+        venue: swap_venue(tx),
+        fee_lamports: tx["meta"]["fee"].as_u64().unwrap_or(0),
+        failure: decode_failure(tx)?,
+    })
+}
+
+/// On-disk trade index
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TradeIndexStore {
+    /// Newest signature already processed per wallet
+    pub cursors: HashMap<String, String>,
+    pub trades: Vec<IndexedTrade>,
+    /// Failed transactions with their decoded error context
+    #[serde(default)]
+    pub failures: Vec<FailedTransaction>,
+    #[serde(default)]
+    pub annotations: Vec<TradeAnnotation>,
+}
+
+impl TradeIndexStore {
+    pub async fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        match fs::read_to_string(path.as_ref()).await {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Write atomically (temp file + rename)
+    pub async fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).await?;
+        }
+        let temp_file = path.with_extension("tmp");
+        fs::write(&temp_file, serde_json::to_string(self)?).await?;
+        fs::rename(&temp_file, path).await?;
+        Ok(())
+    }
+}
+
+/// Indexing progress
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IndexerStats {
+    pub wallets: usize,
+    pub indexed_trades: usize,
+    #[serde(default)]
+    pub failed_transactions: usize,
+    pub last_sync: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+/// Continuous wallet transaction indexer
+pub struct TradeIndexer {
+    config: IndexerConfig,
+    source: Arc<dyn TransactionSource>,
+    store: RwLock<TradeIndexStore>,
+    stats: RwLock<IndexerStats>,
+}
+
+impl TradeIndexer {
+    /// Open the indexer, loading any previously indexed rows
+    pub async fn new(config: IndexerConfig) -> Result<Self> {
+        let source = Arc::new(RpcTransactionSource::new(&config.rpc_url));
+        Self::with_source(config, source).await
+    }
+
+    pub async fn with_source(config: IndexerConfig, source: Arc<dyn TransactionSource>) -> Result<Self> {
+        let store = TradeIndexStore::load(&config.storage_path).await?;
+        info!("📚 Trade indexer loaded {} rows for {} wallets", store.trades.len(), config.wallets.len());
+        Ok(Self {
+            config,
+            source,
+            store: RwLock::new(store),
+            stats: RwLock::new(IndexerStats::default()),
+        })
+    }
+
+    /// Ingest new transactions for one wallet; returns rows added
+    pub async fn sync_wallet(&self, wallet: &str) -> Result<usize> {
+        let cursor = self.store.read().await.cursors.get(wallet).cloned();
+
+        // Page back from the newest signature until the cursor
+        let mut pending = Vec::new();
+        let mut before: Option<String> = None;
+        loop {
+            let page = self.source
+                .signatures(wallet, before.as_deref(), cursor.as_deref(), self.config.page_size)
+                .await?;
+            let page_len = page.len();
+            before = page.last().map(|s| s.signature.clone());
+            pending.extend(page);
+            if page_len < self.config.page_size {
+                break;
+            }
+        }
+        let Some(newest) = pending.first().map(|s| s.signature.clone()) else {
+            return Ok(0);
+        };
+
+        let mut trades = Vec::new();
+        let mut failures = Vec::new();
+        for info in pending.iter().rev() {
+            match self.source.transaction(&info.signature).await? {
+                Some(tx) if info.err.is_none() => trades.extend(decode_swap(wallet, &info.signature, &tx)),
+                Some(tx) => {
+                    if let Some(failed) = decode_failed(wallet, &info.signature, &tx) {
+                        warn!("📚 Transaction {} failed: {}", failed.signature, failed.failure.summary());
+                        failures.push(failed);
+                    }
+                }
+                None => debug!("📚 Transaction {} not available yet", info.signature),
+            }
+        }
+
+        let added = trades.len();
+        let mut store = self.store.write().await;
+        store.trades.extend(trades);
+        store.failures.extend(failures);
+        store.cursors.insert(wallet.to_string(), newest);
+        store.save(&self.config.storage_path).await?;
+        if added > 0 {
+            info!("📚 Indexed {} new trades for wallet {}", added, wallet);
+        }
+        Ok(added)
+    }
+
+    /// Sync every configured wallet
+    pub async fn sync_all(&self) -> usize {
+        let mut added = 0;
+        let mut last_error = None;
+        for wallet in &self.config.wallets {
+            match self.sync_wallet(wallet).await {
+                Ok(count) => added += count,
+                Err(e) => {
+                    warn!("⚠️ Trade indexer failed for {}: {}", wallet, e);
+                    last_error = Some(e.to_string());
+                }
+            }
+        }
+
+        let (indexed_trades, failed_transactions) = {
+            let store = self.store.read().await;
+            (store.trades.len(), store.failures.len())
+        };
+        let mut stats = self.stats.write().await;
+        stats.wallets = self.config.wallets.len();
+        stats.indexed_trades = indexed_trades;
+        stats.failed_transactions = failed_transactions;
+        stats.last_sync = Some(Utc::now());
+        stats.last_error = last_error;
+        added
+    }
+
+    /// Poll forever, beating `heartbeat` after each pass
+    pub async fn run(self: Arc<Self>, heartbeat: HeartbeatHandle) {
+        loop {
+            self.sync_all().await;
+            heartbeat.beat();
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    /// Indexed rows for a wallet (all wallets when `None`)
+    pub async fn trades(&self, wallet: Option<&str>) -> Vec<IndexedTrade> {
+        self.store
+            .read()
+            .await
+            .trades
+            .iter()
+            .filter(|t| wallet.map_or(true, |w| t.wallet == w))
+            .cloned()
+            .collect()
+    }
+
+    /// Failed transactions for a wallet (all wallets when `None`), oldest first
+    pub async fn failures(&self, wallet: Option<&str>) -> Vec<FailedTransaction> {
+        self.store
+            .read()
+            .await
+            .failures
+            .iter()
+            .filter(|f| wallet.map_or(true, |w| f.wallet == w))
+            .cloned()
+            .collect()
+    }
+
+    /// Attach a note and tags to a trade or position
+    ///
+    /// Trades must already be indexed; positions are not checked since they
+    /// live in the bots.
+    pub async fn annotate(&self, target: AnnotationTarget, note: &str, tags: Vec<String>, author: &str) -> Result<TradeAnnotation> {
+        if note.trim().is_empty() && tags.is_empty() {
+            return Err(anyhow!("annotation needs a note or at least one tag"));
+        }
+        let mut store = self.store.write().await;
+        if let AnnotationTarget::Trade(signature) = &target {
+            if !store.trades.iter().any(|t| &t.signature == signature) {
+                return Err(anyhow!("trade {} is not indexed", signature));
+            }
+        }
+        let annotation = TradeAnnotation {
+            id: Uuid::new_v4(),
+            target,
+            note: note.trim().to_string(),
+            tags: tags.into_iter().map(|t| t.trim().to_lowercase()).filter(|t| !t.is_empty()).collect(),
+            author: author.to_string(),
+            created_at: Utc::now(),
+        };
+        store.annotations.push(annotation.clone());
+        store.save(&self.config.storage_path).await?;
+        info!("📝 {} annotated {:?}: {}", annotation.author, annotation.target, annotation.note);
+        Ok(annotation)
+    }
+
+    /// Annotations on `target` (all when `None`), oldest first
+    pub async fn annotations(&self, target: Option<&AnnotationTarget>) -> Vec<TradeAnnotation> {
+        self.store
+            .read()
+            .await
+            .annotations
+            .iter()
+            .filter(|a| target.map_or(true, |t| &a.target == t))
+            .cloned()
+            .collect()
+    }
+
+    /// Trades (optionally one wallet's) with their annotations
+    pub async fn journal(&self, wallet: Option<&str>) -> Vec<JournalEntry> {
+        let store = self.store.read().await;
+        store.trades
+            .iter()
+            .filter(|t| wallet.map_or(true, |w| t.wallet == w))
+            .map(|trade| JournalEntry {
+                trade: trade.clone(),
+                annotations: store.annotations
+                    .iter()
+                    .filter(|a| a.target == AnnotationTarget::Trade(trade.signature.clone()))
+                    .cloned()
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Journal as CSV; notes are joined with " | " and tags with ";"
+    pub async fn export_csv(&self, wallet: Option<&str>) -> String {
+        fn field(value: &str) -> String {
+            if value.contains([',', '"', '\n']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value.to_string()
+            }
+        }
+
+        let mut csv = String::from("signature,wallet,slot,block_time,venue,mint_in,amount_in,mint_out,amount_out,fee_lamports,notes,tags\n");
+        for entry in self.journal(wallet).await {
+            let trade = &entry.trade;
+            let notes: Vec<&str> = entry.annotations.iter().map(|a| a.note.as_str()).filter(|n| !n.is_empty()).collect();
+            let mut tags: Vec<&str> = entry.annotations.iter().flat_map(|a| a.tags.iter().map(String::as_str)).collect();
+            tags.sort_unstable();
+            tags.dedup();
+            csv.push_str(&[
+                field(&trade.signature),
+                field(&trade.wallet),
+                trade.slot.to_string(),
+                trade.block_time.map(|t| t.to_rfc3339()).unwrap_or_default(),
+                field(trade.venue.as_deref().unwrap_or_default()),
+                field(&trade.mint_in),
+                trade.amount_in.to_string(),
+                field(&trade.mint_out),
+                trade.amount_out.to_string(),
+                trade.fee_lamports.to_string(),
+                field(&notes.join(" | ")),
+                field(&tags.join(";")),
+            ].join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    pub async fn get_stats(&self) -> IndexerStats {
+        self.stats.read().await.clone()
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.config.poll_interval
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const WALLET: &str = "Wa11et1111111111111111111111111111111111111";
+    const USDC: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    fn swap_tx(slot: u64) -> Value {
+        json!({
+            "slot": slot,
+            "blockTime": 1_700_000_000,
+            "meta": {
+                "err": null,
+                "fee": 5000,
+                "preBalances": [2_000_005_000_i64, 0],
+                "postBalances": [1_000_000_000_i64, 0],
+                "preTokenBalances": [
+                    { "mint": USDC, "owner": WALLET, "uiTokenAmount": { "uiAmountString": "10" } }
+                ],
+                "postTokenBalances": [
+                    { "mint": USDC, "owner": WALLET, "uiTokenAmount": { "uiAmountString": "160.5" } }
+                ]
+            },
+            "transaction": { "message": { "accountKeys": [
+                { "pubkey": WALLET },
+                { "pubkey": "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4" }
+            ] } }
+        })
+    }
+
+    struct MockSource {
+        signatures: Vec<String>,
+    }
+
+    #[async_trait]
+    impl TransactionSource for MockSource {
+        async fn signatures(&self, _address: &str, before: Option<&str>, until: Option<&str>, limit: usize) -> Result<Vec<SignatureInfo>> {
+            Ok(self.signatures
+                .iter()
+                .rev()
+                .skip_while(|s| before.is_some_and(|b| b != s.as_str()))
+                .skip(usize::from(before.is_some()))
+                .take_while(|s| until != Some(s.as_str()))
+                .take(limit)
+                .map(|s| SignatureInfo { signature: s.clone(), slot: 1, block_time: None, err: None })
+                .collect())
+        }
+
+        async fn transaction(&self, _signature: &str) -> Result<Option<Value>> {
+            Ok(Some(swap_tx(1)))
+        }
+    }
+
+    #[test]
+    fn test_decode_sol_to_usdc_swap() {
+        let trade = decode_swap(WALLET, "sig", &swap_tx(42)).expect("swap decoded");
+        assert_eq!(trade.mint_in, NATIVE_SOL_MINT);
+        assert!((trade.amount_in - 1.0).abs() < 1e-9);
+        assert_eq!(trade.mint_out, USDC);
+        assert!((trade.amount_out - 150.5).abs() < 1e-9);
+        assert_eq!(trade.venue.as_deref(), Some("Jupiter"));
+        assert_eq!(trade.fee_lamports, 5000);
+    }
+
+    #[test]
+    fn test_failed_swap_kept_with_error_context() {
+        let mut tx = swap_tx(7);
+        tx["meta"]["err"] = json!({ "InstructionError": [0, { "Custom": 6001 }] });
+        tx["meta"]["logMessages"] = json!([
+            "Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 invoke [1]",
+            "Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 failed: custom program error: 0x1771"
+        ]);
+
+        assert!(decode_swap(WALLET, "sig", &tx).is_none());
+        let failed = decode_failed(WALLET, "sig", &tx).expect("failure decoded");
+        assert_eq!(failed.slot, 7);
+        assert_eq!(failed.failure.error_name.as_deref(), Some("SlippageToleranceExceeded"));
+        // Only transactions the wallet paid for are ours
+        assert!(decode_failed("SomeoneElse", "sig", &tx).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sync_pages_and_resumes_from_cursor() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = IndexerConfig {
+            wallets: vec![WALLET.to_string()],
+            page_size: 2,
+            storage_path: temp_dir.path().join("index.json"),
+            ..Default::default()
+        };
+        let source = Arc::new(MockSource { signatures: (1..=5).map(|i| format!("sig{}", i)).collect() });
+        let indexer = TradeIndexer::with_source(config.clone(), source).await.unwrap();
+
+        assert_eq!(indexer.sync_wallet(WALLET).await.unwrap(), 5);
+        assert_eq!(indexer.sync_wallet(WALLET).await.unwrap(), 0);
+
+        let reloaded = TradeIndexStore::load(&config.storage_path).await.unwrap();
+        assert_eq!(reloaded.cursors.get(WALLET).map(String::as_str), Some("sig5"));
+        assert_eq!(reloaded.trades.first().map(|t| t.signature.as_str()), Some("sig1"));
+    }
+
+    #[tokio::test]
+    async fn test_annotations_persist_and_appear_in_export() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = IndexerConfig {
+            wallets: vec![WALLET.to_string()],
+            storage_path: temp_dir.path().join("index.json"),
+            ..Default::default()
+        };
+        let source = Arc::new(MockSource { signatures: vec!["sig1".to_string()] });
+        let indexer = TradeIndexer::with_source(config.clone(), source).await.unwrap();
+        indexer.sync_wallet(WALLET).await.unwrap();
+
+        let trade = AnnotationTarget::Trade("sig1".to_string());
+        indexer.annotate(trade.clone(), "entered on news, early", vec!["News".to_string()], "ops").await.unwrap();
+        indexer.annotate(AnnotationTarget::Position("pos-1".to_string()), "exit forced by depeg alert", vec![], "ops").await.unwrap();
+        assert!(indexer.annotate(AnnotationTarget::Trade("missing".to_string()), "x", vec![], "ops").await.is_err());
+
+        let reloaded = TradeIndexStore::load(&config.storage_path).await.unwrap();
+        assert_eq!(reloaded.annotations.len(), 2);
+        assert_eq!(indexer.annotations(Some(&trade)).await[0].tags, vec!["news".to_string()]);
+
+        let csv = indexer.export_csv(None).await;
+        assert!(csv.lines().nth(1).unwrap().ends_with(",\"entered on news, early\",news"));
+    }
+}
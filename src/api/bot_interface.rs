@@ -4,6 +4,7 @@
 //! for the containerized ecosystem management.
 
 use async_trait::async_trait;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -54,7 +55,7 @@ pub trait BotInterface: Send + Sync {
 }
 
 /// Bot type enumeration for the ecosystem
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 pub enum BotType {
     /// Enhanced arbitrage with ML analysis
     EnhancedArbitrage,
@@ -116,7 +117,7 @@ pub enum BotStatus {
 }
 
 /// Bot configuration structure
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct BotConfig {
     /// Unique configuration ID
     pub config_id: Uuid,
@@ -139,7 +140,7 @@ pub struct BotConfig {
 }
 
 /// Environment classification
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub enum Environment {
     Development,
     Testing,
@@ -150,7 +151,7 @@ pub enum Environment {
 }
 
 /// Resource limits for container orchestration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ResourceLimits {
     /// Maximum CPU usage (cores)
     pub max_cpu: f64,
@@ -163,7 +164,7 @@ pub struct ResourceLimits {
 }
 
 /// Network configuration for bot
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct NetworkConfig {
     /// Solana RPC endpoints
     pub solana_rpc_urls: Vec<String>,
@@ -176,7 +177,7 @@ pub struct NetworkConfig {
 }
 
 /// Network timeout configurations
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct NetworkTimeouts {
     /// RPC request timeout (seconds)
     pub rpc_timeout_seconds: u64,
@@ -187,7 +188,7 @@ pub struct NetworkTimeouts {
 }
 
 /// Security configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SecurityConfig {
     /// Wallet configuration
     pub wallet: WalletConfig,
@@ -200,7 +201,7 @@ pub struct SecurityConfig {
 }
 
 /// Wallet configuration for trading bots
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct WalletConfig {
     /// Wallet type (keypair, hardware, etc.)
     pub wallet_type: String,
@@ -213,7 +214,7 @@ pub struct WalletConfig {
 }
 
 /// Configuration metadata
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ConfigMetadata {
     /// Configuration name/description
     pub name: String,
@@ -266,16 +267,19 @@ pub struct TradingMetrics {
     pub trades_executed: u64,
     /// Successful trades
     pub successful_trades: u64,
-    /// Total profit/loss in USD
-    pub total_pnl_usd: f64,
+    /// Total profit/loss in USD (`None` when no exchange rate was available)
+    pub total_pnl_usd: Option<f64>,
     /// Success rate percentage
     pub success_rate: f64,
     /// Average profit per trade
     pub avg_profit_per_trade: f64,
-    /// Total volume traded
-    pub total_volume_usd: f64,
+    /// Total volume traded in USD (`None` when no exchange rate was available)
+    pub total_volume_usd: Option<f64>,
     /// Sharpe ratio (if applicable)
     pub sharpe_ratio: Option<f64>,
+    /// Timestamp of the exchange rate used for USD values
+    #[serde(default)]
+    pub usd_rate_timestamp: Option<DateTime<Utc>>,
 }
 
 /// System performance metrics
@@ -569,11 +573,12 @@ impl Default for TradingMetrics {
         Self {
             trades_executed: 0,
             successful_trades: 0,
-            total_pnl_usd: 0.0,
+            total_pnl_usd: Some(0.0),
             success_rate: 0.0,
             avg_profit_per_trade: 0.0,
-            total_volume_usd: 0.0,
+            total_volume_usd: Some(0.0),
             sharpe_ratio: None,
+            usd_rate_timestamp: None,
         }
     }
 }
@@ -0,0 +1,286 @@
+//! Versioned config migrations
+//!
+//! Every YAML config carries a `config_version` (files written before the
+//! field existed count as version 0). At load time the raw YAML is upgraded
+//! step by step to the version this binary understands, before it is
+//! deserialized into the typed config. When anything changed, the original
+//! file is kept as a timestamped backup, a human-readable report of the
+//! upgrade is written next to it, and the file is rewritten in the current
+//! format so the next start does not migrate again.
+
+use chrono::Utc;
+use serde_yaml::{Mapping, Value};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tracing::info;
+
+use super::yaml_config::YamlConfigError;
+
+pub const CONFIG_VERSION_KEY: &str = "config_version";
+/// Current `system.yaml` format
+pub const SYSTEM_CONFIG_VERSION: u32 = 1;
+/// Current `bots/<id>.yaml` format
+pub const BOT_CONFIG_VERSION: u32 = 1;
+
+/// Edits one version's mapping into the next; returns notes on what it did
+pub type MigrationFn = fn(&mut Mapping) -> Vec<String>;
+
+/// Upgrade from `from` to `from + 1`
+#[derive(Clone)]
+pub struct Migration {
+    pub from: u32,
+    pub description: &'static str,
+    pub apply: MigrationFn,
+}
+
+/// Result of upgrading one document
+#[derive(Debug, Clone)]
+pub struct MigrationOutcome {
+    pub from: u32,
+    pub to: u32,
+    pub value: Value,
+    /// Description and notes of each applied step
+    pub steps: Vec<String>,
+}
+
+impl MigrationOutcome {
+    pub fn changed(&self) -> bool {
+        self.from != self.to
+    }
+
+    /// Report of the upgrade: steps applied, then a key-level diff
+    pub fn report(&self, kind: &str, original: &Value) -> String {
+        let mut out = format!("{} config migrated from v{} to v{}\n\nSteps:\n", kind, self.from, self.to);
+        for step in &self.steps {
+            out.push_str(&format!("  {}\n", step));
+        }
+        out.push_str("\nChanges:\n");
+        let changes = diff_values(original, &self.value);
+        if changes.is_empty() {
+            out.push_str("  (none)\n");
+        }
+        for change in changes {
+            out.push_str(&format!("  {}\n", change));
+        }
+        out
+    }
+}
+
+/// Ordered migrations for one kind of config file
+#[derive(Clone)]
+pub struct ConfigMigrator {
+    kind: &'static str,
+    current: u32,
+    migrations: Vec<Migration>,
+}
+
+impl ConfigMigrator {
+    pub fn new(kind: &'static str, current: u32) -> Self {
+        Self { kind, current, migrations: Vec::new() }
+    }
+
+    pub fn with_migration(mut self, from: u32, description: &'static str, apply: MigrationFn) -> Self {
+        self.migrations.push(Migration { from, description, apply });
+        self
+    }
+
+    /// Migrations for `system.yaml`
+    pub fn system() -> Self {
+        Self::new("system", SYSTEM_CONFIG_VERSION)
+            .with_migration(0, "Add config_version", |_| Vec::new())
+    }
+
+    /// Migrations for per-bot YAML files
+    pub fn bot() -> Self {
+        Self::new("bot", BOT_CONFIG_VERSION)
+            .with_migration(0, "Add config_version", |_| Vec::new())
+    }
+
+    pub fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    pub fn current_version(&self) -> u32 {
+        self.current
+    }
+
+    /// `config_version` of a raw document; absent means pre-versioning (0)
+    pub fn version_of(value: &Value) -> Result<u32, YamlConfigError> {
+        match value.get(CONFIG_VERSION_KEY) {
+            None => Ok(0),
+            Some(version) => version
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .ok_or_else(|| YamlConfigError::ValidationFailed(format!("{} must be a non-negative integer", CONFIG_VERSION_KEY))),
+        }
+    }
+
+    /// Upgrade `value` to the current version
+    pub fn migrate(&self, value: Value) -> Result<MigrationOutcome, YamlConfigError> {
+        let from = Self::version_of(&value)?;
+        if from > self.current {
+            return Err(YamlConfigError::UnsupportedVersion {
+                kind: self.kind.to_string(),
+                found: from,
+                supported: self.current,
+            });
+        }
+        let Value::Mapping(mut mapping) = value else {
+            return Err(YamlConfigError::ValidationFailed(format!("{} config must be a mapping", self.kind)));
+        };
+
+        let mut steps = Vec::new();
+        for version in from..self.current {
+            let migration = self
+                .migrations
+                .iter()
+                .find(|m| m.from == version)
+                .ok_or_else(|| YamlConfigError::MigrationFailed(format!("no {} migration from v{}", self.kind, version)))?;
+            let notes = (migration.apply)(&mut mapping);
+            mapping.insert(Value::from(CONFIG_VERSION_KEY), Value::from(version + 1));
+            steps.push(format!("v{} -> v{}: {}", version, version + 1, migration.description));
+            steps.extend(notes.into_iter().map(|note| format!("    {}", note)));
+        }
+
+        Ok(MigrationOutcome { from, to: self.current, value: Value::Mapping(mapping), steps })
+    }
+}
+
+/// Key-level differences between two documents (`+` added, `-` removed, `~` changed)
+pub fn diff_values(old: &Value, new: &Value) -> Vec<String> {
+    let mut out = Vec::new();
+    diff_at("", old, new, &mut out);
+    out
+}
+
+fn diff_at(path: &str, old: &Value, new: &Value, out: &mut Vec<String>) {
+    match (old, new) {
+        (Value::Mapping(old_map), Value::Mapping(new_map)) => {
+            for (key, old_value) in old_map {
+                let child = child_path(path, key);
+                match new_map.get(key) {
+                    Some(new_value) => diff_at(&child, old_value, new_value, out),
+                    None => out.push(format!("- {}: {}", child, inline(old_value))),
+                }
+            }
+            for (key, new_value) in new_map {
+                if !old_map.contains_key(key) {
+                    out.push(format!("+ {}: {}", child_path(path, key), inline(new_value)));
+                }
+            }
+        }
+        _ if old != new => out.push(format!("~ {}: {} -> {}", path, inline(old), inline(new))),
+        _ => {}
+    }
+}
+
+fn child_path(path: &str, key: &Value) -> String {
+    let key = key.as_str().map(str::to_string).unwrap_or_else(|| inline(key));
+    if path.is_empty() { key } else { format!("{}.{}", path, key) }
+}
+
+fn inline(value: &Value) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("{:?}", value))
+}
+
+/// Parse `content` read from `path`, upgrading the file on disk if it is outdated
+///
+/// Writes `<file>.v<from>.<timestamp>.bak` (the original bytes) and
+/// `<file>.v<from>-v<to>.<timestamp>.migration.txt` before replacing the file.
+pub async fn upgrade_file(path: &Path, content: &str, migrator: &ConfigMigrator) -> Result<Value, YamlConfigError> {
+    let original: Value = serde_yaml::from_str(content)?;
+    let outcome = migrator.migrate(original.clone())?;
+    if !outcome.changed() {
+        return Ok(outcome.value);
+    }
+
+    let stamp = Utc::now().format("%Y%m%dT%H%M%S");
+    let backup = sibling(path, &format!("v{}.{}.bak", outcome.from, stamp));
+    let report = sibling(path, &format!("v{}-v{}.{}.migration.txt", outcome.from, outcome.to, stamp));
+    fs::write(&backup, content).await?;
+    fs::write(&report, outcome.report(migrator.kind(), &original)).await?;
+
+    let tmp = sibling(path, "tmp");
+    fs::write(&tmp, serde_yaml::to_string(&outcome.value)?).await?;
+    fs::rename(&tmp, path).await?;
+
+    info!(
+        "🔄 Migrated {} config {} from v{} to v{} (backup: {})",
+        migrator.kind(), path.display(), outcome.from, outcome.to, backup.display()
+    );
+    for step in &outcome.steps {
+        info!("   {}", step);
+    }
+    Ok(outcome.value)
+}
+
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    path.with_file_name(format!("{}.{}", name, suffix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rename_timeout(mapping: &mut Mapping) -> Vec<String> {
+        match mapping.remove("timeout") {
+            Some(value) => {
+                mapping.insert(Value::from("timeout_seconds"), value);
+                vec!["renamed timeout -> timeout_seconds".to_string()]
+            }
+            None => Vec::new(),
+        }
+    }
+
+    fn migrator() -> ConfigMigrator {
+        ConfigMigrator::new("test", 2)
+            .with_migration(0, "Add config_version", |_| Vec::new())
+            .with_migration(1, "Rename timeout", rename_timeout)
+    }
+
+    #[test]
+    fn test_legacy_document_is_upgraded_step_by_step() {
+        let legacy: Value = serde_yaml::from_str("name: bot\ntimeout: 30\n").unwrap();
+        let outcome = migrator().migrate(legacy.clone()).unwrap();
+
+        assert_eq!((outcome.from, outcome.to), (0, 2));
+        assert_eq!(outcome.value["timeout_seconds"], Value::from(30));
+        assert_eq!(outcome.value[CONFIG_VERSION_KEY], Value::from(2));
+
+        let report = outcome.report("test", &legacy);
+        assert!(report.contains("v1 -> v2: Rename timeout"));
+        assert!(report.contains("- timeout: 30"));
+        assert!(report.contains("+ timeout_seconds: 30"));
+        assert!(report.contains("+ config_version: 2"));
+
+        // Current files pass through; files from a newer release are refused
+        let current = migrator().migrate(outcome.value).unwrap();
+        assert!(!current.changed());
+        let newer: Value = serde_yaml::from_str("config_version: 3\n").unwrap();
+        assert!(matches!(migrator().migrate(newer), Err(YamlConfigError::UnsupportedVersion { found: 3, .. })));
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_file_writes_backup_and_report() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bot.yaml");
+        let content = "# hand-written\nname: bot\ntimeout: 30\n";
+        std::fs::write(&path, content).unwrap();
+
+        let value = upgrade_file(&path, content, &migrator()).await.unwrap();
+        assert_eq!(value["timeout_seconds"], Value::from(30));
+
+        let mut names: Vec<String> = std::fs::read_dir(dir.path()).unwrap().map(|e| e.unwrap().file_name().to_string_lossy().to_string()).collect();
+        names.sort();
+        assert_eq!(names.len(), 3);
+        let backup = names.iter().find(|n| n.ends_with(".bak")).unwrap();
+        assert_eq!(std::fs::read_to_string(dir.path().join(backup)).unwrap(), content);
+        assert!(names.iter().any(|n| n.starts_with("bot.yaml.v0-v2.") && n.ends_with(".migration.txt")));
+
+        let rewritten = std::fs::read_to_string(&path).unwrap();
+        let again = upgrade_file(&path, &rewritten, &migrator()).await.unwrap();
+        assert_eq!(again, value);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 3);
+    }
+}
@@ -0,0 +1,139 @@
+//! JSON Schema export for bot configurations
+//!
+//! Dashboards render config editors from these schemas instead of hardcoding
+//! fields. The envelope ([`BotConfig`]) is shared by every bot; its free-form
+//! `parameters` object is replaced with the typed parameter schema of the bot
+//! type when one exists, so the schema served for a bot validates exactly
+//! what that bot reads.
+
+use schemars::schema::{RootSchema, Schema};
+use schemars::{schema_for, JsonSchema};
+use serde::{Deserialize, Serialize};
+
+use super::bot_interface::{BotConfig, BotType};
+
+/// `parameters` read by the enhanced arbitrage bot
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EnhancedArbitrageParameters {
+    /// Minimum profit to execute (fraction, e.g. 0.01 = 1%)
+    #[schemars(range(min = 0.0))]
+    pub min_profit_threshold: f64,
+    /// Maximum position size (quote currency)
+    #[schemars(range(min = 0.0))]
+    pub max_position_size: f64,
+    /// Exchanges to arbitrage across
+    #[schemars(length(min = 1))]
+    pub exchanges: Vec<String>,
+    /// Pairs to monitor, e.g. "SOL/USDC"
+    #[serde(default)]
+    pub pairs: Option<Vec<String>>,
+    /// Execution timeout (ms)
+    #[serde(default)]
+    pub execution_timeout_ms: Option<u64>,
+    /// Use the ML model for opportunity scoring
+    #[serde(default)]
+    pub ml_model_enabled: Option<bool>,
+    #[serde(default)]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub risk_factor: Option<f64>,
+    /// Slippage tolerance (fraction)
+    #[serde(default)]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub slippage_tolerance: Option<f64>,
+    #[serde(default)]
+    pub max_concurrent_orders: Option<u32>,
+}
+
+/// Network the sniper trades on
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SniperEnvironment {
+    Mainnet,
+    Testnet,
+    Development,
+    Testing,
+}
+
+/// `parameters` read by the liquidity sniper
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct LiquiditySniperParameters {
+    /// Capital allocated to the sniper (SOL)
+    #[serde(default)]
+    #[schemars(range(min = 0.0))]
+    pub capital_allocation: Option<f64>,
+    /// Maximum position size (% of allocation)
+    #[serde(default)]
+    #[schemars(range(min = 0.0, max = 100.0))]
+    pub max_position_size_percent: Option<f64>,
+    #[serde(default)]
+    pub environment: Option<SniperEnvironment>,
+    /// Submit through MEV-protected routes
+    #[serde(default)]
+    pub mev_protection: Option<bool>,
+    /// Maximum simultaneous positions
+    #[serde(default)]
+    #[schemars(range(min = 1))]
+    pub max_positions: Option<u32>,
+}
+
+/// Schema of `parameters` for `bot_type`; `None` for bots without typed parameters
+pub fn parameters_schema(bot_type: &BotType) -> Option<RootSchema> {
+    match bot_type {
+        BotType::EnhancedArbitrage => Some(schema_for!(EnhancedArbitrageParameters)),
+        BotType::LiquiditySniper => Some(schema_for!(LiquiditySniperParameters)),
+        _ => None,
+    }
+}
+
+/// Full config schema for `bot_type`: the shared envelope with typed `parameters`
+pub fn bot_config_schema(bot_type: &BotType) -> RootSchema {
+    let mut root = schema_for!(BotConfig);
+    root.schema.metadata().title = Some(format!("{} configuration", bot_type.as_str()));
+
+    if let Some(parameters) = parameters_schema(bot_type) {
+        root.definitions.extend(parameters.definitions);
+        let mut schema = parameters.schema;
+        schema.metadata().description = Some("Parameters specific to this bot type".to_string());
+        root.schema
+            .object()
+            .properties
+            .insert("parameters".to_string(), Schema::Object(schema));
+    }
+    root
+}
+
+/// [`bot_config_schema`] as JSON, for API responses and bot type metadata
+pub fn bot_config_schema_json(bot_type: &BotType) -> serde_json::Value {
+    serde_json::to_value(bot_config_schema(bot_type)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_parameters_replace_free_form_object() {
+        let schema = bot_config_schema_json(&BotType::EnhancedArbitrage);
+        let parameters = &schema["properties"]["parameters"];
+        assert_eq!(parameters["type"], "object");
+        let required: Vec<&str> = parameters["required"].as_array().unwrap().iter().filter_map(|v| v.as_str()).collect();
+        assert_eq!(required, vec!["exchanges", "max_position_size", "min_profit_threshold"]);
+        assert_eq!(parameters["properties"]["exchanges"]["minItems"], 1);
+
+        // Envelope fields and their nested definitions are still present
+        assert!(schema["properties"]["resources"].is_object());
+        assert!(schema["definitions"]["ResourceLimits"].is_object());
+    }
+
+    #[test]
+    fn test_untyped_bots_keep_generic_parameters() {
+        assert!(parameters_schema(&BotType::PortfolioManager).is_none());
+        let schema = bot_config_schema_json(&BotType::PortfolioManager);
+        assert_eq!(schema["title"], "portfolio-manager configuration");
+        assert!(schema["properties"]["parameters"].is_object());
+
+        let sniper = bot_config_schema_json(&BotType::LiquiditySniper);
+        let env = &sniper["definitions"]["SniperEnvironment"]["enum"];
+        assert!(env.as_array().unwrap().contains(&serde_json::json!("mainnet")));
+    }
+}
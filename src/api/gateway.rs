@@ -3,14 +3,23 @@
 //! This module provides the main REST API gateway for the SniperForge bot ecosystem.
 //! It handles HTTP requests for bot management, configuration, and monitoring.
 
-use actix_web::{web, App, HttpServer, HttpResponse, Result, middleware::Logger};
+use tracing::{info, warn};
+use actix_web::{web, App, HttpRequest, HttpServer, HttpResponse, Result, middleware::Logger};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
 use crate::api::bot_interface::{BotConfig, BotType, BotStatus};
+use crate::api::config_schema::bot_config_schema_json;
+use crate::apis::helius::{EnhancedTransaction, HeliusWebhookReceiver};
 use crate::bots::bot_factory::{BotFactory, BotRegistry};
+use crate::monitoring::health::{health_endpoint, HealthRegistry};
+use crate::monitoring::latency_heatmap::latency_heatmap;
+use crate::monitoring::metrics_store::{metrics_store, Resolution};
+use crate::monitoring::webhook_emitter::{WebhookEmitter, WebhookEventKind};
+use crate::trading::{CapitalWithdrawals, ProfitTaking, RiskManager};
+use crate::utils::i18n::{t, tf};
 
 /// API Gateway configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -70,10 +79,48 @@ pub struct AppState {
     pub bot_registry: Arc<RwLock<BotRegistry>>,
 }
 
+/// API request to register an outbound webhook
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddWebhookRequest {
+    pub url: String,
+    /// Subscribed event kinds (all when empty)
+    #[serde(default)]
+    pub events: Vec<WebhookEventKind>,
+    /// HMAC key; generated when omitted
+    pub secret: Option<String>,
+}
+
+/// Range of a metric history request; the resolution defaults to the finest covering the range
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MetricHistoryQuery {
+    #[serde(default = "default_history_hours")]
+    pub hours: u32,
+    pub resolution: Option<Resolution>,
+}
+
+fn default_history_hours() -> u32 {
+    24
+}
+
+/// API request to withdraw capital from a trading wallet
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WithdrawalApiRequest {
+    pub wallet: String,
+    pub asset: String,
+    pub amount: f64,
+    pub destination: String,
+}
+
 /// API Gateway struct
 pub struct ApiGateway {
     config: GatewayConfig,
     state: Arc<AppState>,
+    helius_webhook: Option<Arc<HeliusWebhookReceiver>>,
+    health_registry: Option<Arc<HealthRegistry>>,
+    risk_manager: Option<RiskManager>,
+    webhook_emitter: Option<Arc<WebhookEmitter>>,
+    capital_withdrawals: Option<Arc<CapitalWithdrawals>>,
+    profit_taking: Option<Arc<ProfitTaking>>,
 }
 
 impl ApiGateway {
@@ -84,20 +131,81 @@ impl ApiGateway {
             bot_registry: Arc::new(RwLock::new(BotRegistry::new())),
         });
 
-        Self { config, state }
+        Self { config, state, helius_webhook: None, health_registry: None, risk_manager: None, webhook_emitter: None, capital_withdrawals: None, profit_taking: None }
+    }
+
+    /// Accept Helius webhook deliveries on `POST /api/v1/webhooks/helius`
+    pub fn with_helius_webhook(mut self, receiver: Arc<HeliusWebhookReceiver>) -> Self {
+        self.helius_webhook = Some(receiver);
+        self
+    }
+
+    /// Serve the composite health check on `GET /health`
+    pub fn with_health_registry(mut self, registry: Arc<HealthRegistry>) -> Self {
+        self.health_registry = Some(registry);
+        self
+    }
+
+    /// Serve per-token exposure headroom on `GET /api/v1/risk/headroom[/{asset}]`
+    pub fn with_risk_manager(mut self, risk_manager: RiskManager) -> Self {
+        self.risk_manager = Some(risk_manager);
+        self
+    }
+
+    /// Manage outbound webhooks on `/api/v1/webhooks/outbound`
+    pub fn with_webhook_emitter(mut self, emitter: Arc<WebhookEmitter>) -> Self {
+        self.webhook_emitter = Some(emitter);
+        self
+    }
+
+    /// Request, list and cancel capital withdrawals on `/api/v1/capital/withdrawals`
+    pub fn with_capital_withdrawals(mut self, withdrawals: Arc<CapitalWithdrawals>) -> Self {
+        self.capital_withdrawals = Some(withdrawals);
+        self
+    }
+
+    /// Serve profit-taking status and conversions on `GET /api/v1/capital/profit-taking`
+    pub fn with_profit_taking(mut self, profit_taking: Arc<ProfitTaking>) -> Self {
+        self.profit_taking = Some(profit_taking);
+        self
     }
 
     /// Start the API Gateway server
     pub async fn start(&self) -> std::io::Result<()> {
         let bind_address = format!("{}:{}", self.config.host, self.config.port);
-        println!("🚀 SniperForge API Gateway starting on {}", bind_address);
+        info!("🚀 SniperForge API Gateway starting on {}", bind_address);
 
         HttpServer::new({
             let state = self.state.clone();
+            let helius_webhook = self.helius_webhook.clone();
+            let health_registry = self.health_registry.clone();
+            let risk_manager = self.risk_manager.clone();
+            let webhook_emitter = self.webhook_emitter.clone();
+            let capital_withdrawals = self.capital_withdrawals.clone();
+            let profit_taking = self.profit_taking.clone();
             move || {
-                App::new()
-                    .app_data(web::Data::new(state.clone()))
-                    .wrap(Logger::default())
+                let mut app = App::new().app_data(web::Data::new(state.clone()));
+                if let Some(receiver) = &helius_webhook {
+                    app = app.app_data(web::Data::new(receiver.clone()));
+                }
+                if let Some(risk_manager) = &risk_manager {
+                    app = app.app_data(web::Data::new(risk_manager.clone()));
+                }
+                if let Some(emitter) = &webhook_emitter {
+                    app = app.app_data(web::Data::new(emitter.clone()));
+                }
+                if let Some(withdrawals) = &capital_withdrawals {
+                    app = app.app_data(web::Data::new(withdrawals.clone()));
+                }
+                if let Some(profit_taking) = &profit_taking {
+                    app = app.app_data(web::Data::new(profit_taking.clone()));
+                }
+                if let Some(registry) = &health_registry {
+                    app = app
+                        .app_data(web::Data::new(registry.clone()))
+                        .route("/health", web::get().to(health_endpoint));
+                }
+                app.wrap(Logger::default())
                     .configure(configure_routes)
             }
         })
@@ -123,6 +231,7 @@ fn configure_routes(cfg: &mut web::ServiceConfig) {
                     .route("/{bot_id}/status", web::get().to(get_bot_status))
                     .route("/{bot_id}/metrics", web::get().to(get_bot_metrics))
                     .route("/{bot_id}/health", web::get().to(get_bot_health))
+                    .route("/{bot_id}/config-schema", web::get().to(get_bot_config_schema))
                     .route("/types", web::get().to(get_bot_types))
             )
             .service(
@@ -130,6 +239,35 @@ fn configure_routes(cfg: &mut web::ServiceConfig) {
                     .route("/health", web::get().to(system_health))
                     .route("/metrics", web::get().to(system_metrics))
                     .route("/status", web::get().to(system_status))
+                    .route("/build", web::get().to(system_build))
+            )
+            .service(
+                web::scope("/risk")
+                    .route("/headroom", web::get().to(risk_headroom))
+                    .route("/headroom/{asset}", web::get().to(risk_asset_headroom))
+            )
+            .service(
+                web::scope("/capital")
+                    .route("/withdrawals", web::get().to(list_withdrawals))
+                    .route("/withdrawals", web::post().to(request_withdrawal))
+                    .route("/withdrawals/summary", web::get().to(withdrawal_summary))
+                    .route("/withdrawals/{withdrawal_id}", web::delete().to(cancel_withdrawal))
+                    .route("/profit-taking", web::get().to(profit_taking_status))
+            )
+            .service(
+                web::scope("/monitoring")
+                    .route("/latency", web::get().to(monitoring_latency))
+                    .route("/latency/degradations", web::get().to(monitoring_latency_degradations))
+                    .route("/metrics", web::get().to(monitoring_metric_series))
+                    .route("/metrics/{name}", web::get().to(monitoring_metric_history))
+            )
+            .service(
+                web::scope("/webhooks")
+                    .route("/helius", web::post().to(helius_webhook))
+                    .route("/outbound", web::get().to(list_outbound_webhooks))
+                    .route("/outbound", web::post().to(add_outbound_webhook))
+                    .route("/outbound/deliveries", web::get().to(outbound_webhook_deliveries))
+                    .route("/outbound/{endpoint_id}", web::delete().to(remove_outbound_webhook))
             )
     );
 }
@@ -141,7 +279,7 @@ async fn list_bots(state: web::Data<Arc<AppState>>) -> Result<HttpResponse> {
     
     Ok(HttpResponse::Ok().json(BotOperationResponse {
         success: true,
-        message: "Bots listed successfully".to_string(),
+        message: t("api.bots.listed"),
         data: Some(serde_json::to_value(bots).unwrap()),
     }))
 }
@@ -165,12 +303,12 @@ async fn create_bot(
             Ok(HttpResponse::Created().json(CreateBotResponse {
                 bot_id,
                 status: "created".to_string(),
-                message: "Bot created successfully".to_string(),
+                message: t("api.bots.created"),
             }))
         },
         Err(e) => Ok(HttpResponse::BadRequest().json(BotOperationResponse {
             success: false,
-            message: format!("Failed to create bot: {}", e),
+            message: tf("api.bots.create_failed", &[("error", &e)]),
             data: None,
         }))
     }
@@ -187,13 +325,13 @@ async fn get_bot(
     if let Some(bot_info) = registry.get_bot_info(bot_id).await {
         Ok(HttpResponse::Ok().json(BotOperationResponse {
             success: true,
-            message: "Bot found".to_string(),
+            message: t("api.bots.found"),
             data: Some(serde_json::to_value(bot_info).unwrap()),
         }))
     } else {
         Ok(HttpResponse::NotFound().json(BotOperationResponse {
             success: false,
-            message: "Bot not found".to_string(),
+            message: t("api.bots.not_found"),
             data: None,
         }))
     }
@@ -211,7 +349,7 @@ async fn delete_bot(
     
     Ok(HttpResponse::Ok().json(BotOperationResponse {
         success: true,
-        message: "Bot deleted successfully".to_string(),
+        message: t("api.bots.deleted"),
         data: None,
     }))
 }
@@ -226,7 +364,7 @@ async fn start_bot(
     
     Ok(HttpResponse::Ok().json(BotOperationResponse {
         success: true,
-        message: "Bot start command sent".to_string(),
+        message: t("api.bots.start_sent"),
         data: None,
     }))
 }
@@ -241,7 +379,7 @@ async fn stop_bot(
     
     Ok(HttpResponse::Ok().json(BotOperationResponse {
         success: true,
-        message: "Bot stop command sent".to_string(),
+        message: t("api.bots.stop_sent"),
         data: None,
     }))
 }
@@ -257,7 +395,7 @@ async fn configure_bot(
     
     Ok(HttpResponse::Ok().json(BotOperationResponse {
         success: true,
-        message: "Bot configuration updated".to_string(),
+        message: t("api.bots.config_updated"),
         data: None,
     }))
 }
@@ -274,7 +412,7 @@ async fn get_bot_status(
     
     Ok(HttpResponse::Ok().json(BotOperationResponse {
         success: true,
-        message: "Bot status retrieved".to_string(),
+        message: t("api.bots.status"),
         data: Some(serde_json::to_value(status).unwrap()),
     }))
 }
@@ -289,7 +427,7 @@ async fn get_bot_metrics(
     
     Ok(HttpResponse::Ok().json(BotOperationResponse {
         success: true,
-        message: "Bot metrics retrieved".to_string(),
+        message: t("api.bots.metrics"),
         data: None,
     }))
 }
@@ -311,11 +449,32 @@ async fn get_bot_health(
     
     Ok(HttpResponse::Ok().json(BotOperationResponse {
         success: true,
-        message: "Bot health status retrieved".to_string(),
+        message: t("api.bots.health"),
         data: Some(serde_json::to_value(health).unwrap()),
     }))
 }
 
+/// JSON Schema of the bot's configuration, for config editors
+async fn get_bot_config_schema(
+    state: web::Data<Arc<AppState>>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    let bot_id = path.into_inner();
+    let Some(info) = state.bot_registry.read().await.get_bot_info(bot_id).await else {
+        return Ok(HttpResponse::NotFound().json(BotOperationResponse {
+            success: false,
+            message: tf("api.bots.id_not_found", &[("bot_id", &bot_id)]),
+            data: None,
+        }));
+    };
+
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: t("api.bots.config_schema"),
+        data: Some(bot_config_schema_json(&info.bot_type)),
+    }))
+}
+
 /// Get available bot types
 async fn get_bot_types(state: web::Data<Arc<AppState>>) -> Result<HttpResponse> {
     let factory = state.bot_factory.read().await;
@@ -323,7 +482,7 @@ async fn get_bot_types(state: web::Data<Arc<AppState>>) -> Result<HttpResponse>
     
     Ok(HttpResponse::Ok().json(BotOperationResponse {
         success: true,
-        message: "Bot types retrieved".to_string(),
+        message: t("api.bots.types"),
         data: Some(serde_json::to_value(types).unwrap()),
     }))
 }
@@ -332,7 +491,7 @@ async fn get_bot_types(state: web::Data<Arc<AppState>>) -> Result<HttpResponse>
 async fn system_health(_state: web::Data<Arc<AppState>>) -> Result<HttpResponse> {
     Ok(HttpResponse::Ok().json(BotOperationResponse {
         success: true,
-        message: "System is healthy".to_string(),
+        message: t("api.system.healthy"),
         data: None,
     }))
 }
@@ -342,7 +501,7 @@ async fn system_metrics(_state: web::Data<Arc<AppState>>) -> Result<HttpResponse
     // TODO: Implement actual system metrics collection
     Ok(HttpResponse::Ok().json(BotOperationResponse {
         success: true,
-        message: "System metrics retrieved".to_string(),
+        message: t("api.system.metrics"),
         data: None,
     }))
 }
@@ -351,11 +510,293 @@ async fn system_metrics(_state: web::Data<Arc<AppState>>) -> Result<HttpResponse
 async fn system_status(_state: web::Data<Arc<AppState>>) -> Result<HttpResponse> {
     Ok(HttpResponse::Ok().json(BotOperationResponse {
         success: true,
-        message: "System status retrieved".to_string(),
+        message: t("api.system.status"),
+        data: None,
+    }))
+}
+
+/// Version, source revision and features of the running binary
+async fn system_build() -> Result<HttpResponse> {
+    let info = crate::security::build_info();
+    let integrity = crate::security::verify_for_real_trading();
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: t("api.system.build"),
+        data: Some(serde_json::json!({
+            "build": info,
+            "fingerprint": info.fingerprint(),
+            "real_trading_allowed": integrity.is_ok(),
+            "integrity_error": integrity.err().map(|e| e.to_string()),
+        })),
+    }))
+}
+
+/// Remaining exposure headroom for every capped token
+async fn risk_headroom(risk_manager: Option<web::Data<RiskManager>>) -> Result<HttpResponse> {
+    let Some(risk_manager) = risk_manager else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: t("api.risk.headroom"),
+        data: Some(serde_json::to_value(risk_manager.token_headrooms()).unwrap_or_default()),
+    }))
+}
+
+/// Remaining exposure headroom for one token (symbol or mint)
+async fn risk_asset_headroom(
+    risk_manager: Option<web::Data<RiskManager>>,
+    path: web::Path<String>,
+) -> Result<HttpResponse> {
+    let Some(risk_manager) = risk_manager else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: t("api.risk.headroom"),
+        data: Some(serde_json::to_value(risk_manager.token_headroom(&path.into_inner())).unwrap_or_default()),
+    }))
+}
+
+/// Withdrawal requests and their progress
+async fn list_withdrawals(withdrawals: Option<web::Data<Arc<CapitalWithdrawals>>>) -> Result<HttpResponse> {
+    let Some(withdrawals) = withdrawals else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: t("api.capital.withdrawals"),
+        data: Some(serde_json::to_value(withdrawals.requests()).unwrap_or_default()),
+    }))
+}
+
+/// Lock capital for withdrawal; it is swept once positions using it have closed
+async fn request_withdrawal(
+    withdrawals: Option<web::Data<Arc<CapitalWithdrawals>>>,
+    request: web::Json<WithdrawalApiRequest>,
+) -> Result<HttpResponse> {
+    let Some(withdrawals) = withdrawals else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    match withdrawals.request(&request.wallet, &request.asset, request.amount, &request.destination) {
+        Ok(withdrawal) => Ok(HttpResponse::Created().json(BotOperationResponse {
+            success: true,
+            message: tf("api.capital.locked", &[("amount", &format!("{:.4}", withdrawal.amount)), ("asset", &withdrawal.asset)]),
+            data: Some(serde_json::to_value(withdrawal).unwrap_or_default()),
+        })),
+        Err(e) => Ok(HttpResponse::BadRequest().json(BotOperationResponse {
+            success: false,
+            message: tf("api.capital.refused", &[("error", &e)]),
+            data: None,
+        })),
+    }
+}
+
+/// Capital still locked and capital already freed, per asset
+async fn withdrawal_summary(withdrawals: Option<web::Data<Arc<CapitalWithdrawals>>>) -> Result<HttpResponse> {
+    let Some(withdrawals) = withdrawals else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: t("api.capital.summary"),
+        data: Some(serde_json::to_value(withdrawals.summary()).unwrap_or_default()),
+    }))
+}
+
+/// Realized profit pending conversion to USDC and the conversions so far
+async fn profit_taking_status(profit_taking: Option<web::Data<Arc<ProfitTaking>>>) -> Result<HttpResponse> {
+    let Some(profit_taking) = profit_taking else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: t("api.capital.profit_taking"),
+        data: Some(serde_json::json!({
+            "status": profit_taking.status(),
+            "conversions": profit_taking.conversions(),
+        })),
+    }))
+}
+
+/// Cancel a withdrawal that has not started its transfer
+async fn cancel_withdrawal(
+    withdrawals: Option<web::Data<Arc<CapitalWithdrawals>>>,
+    path: web::Path<String>,
+) -> Result<HttpResponse> {
+    let Some(withdrawals) = withdrawals else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    match withdrawals.cancel(&path.into_inner()) {
+        Ok(withdrawal) => Ok(HttpResponse::Ok().json(BotOperationResponse {
+            success: true,
+            message: tf("api.capital.cancelled", &[("id", &withdrawal.id)]),
+            data: Some(serde_json::to_value(withdrawal).unwrap_or_default()),
+        })),
+        Err(e) => Ok(HttpResponse::Conflict().json(BotOperationResponse {
+            success: false,
+            message: e.to_string(),
+            data: None,
+        })),
+    }
+}
+
+/// Latency distributions per RPC provider/method and venue, by UTC hour
+async fn monitoring_latency() -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: t("api.monitoring.latency"),
+        data: Some(serde_json::to_value(latency_heatmap().rows()).unwrap_or_default()),
+    }))
+}
+
+/// Sources currently running well over their latency baseline
+async fn monitoring_latency_degradations() -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: t("api.monitoring.degradations"),
+        data: Some(serde_json::to_value(latency_heatmap().degradations()).unwrap_or_default()),
+    }))
+}
+
+/// Metrics kept in the time-series store, with their latest value
+async fn monitoring_metric_series() -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: t("api.monitoring.metric_series"),
+        data: Some(serde_json::to_value(metrics_store().series()).unwrap_or_default()),
+    }))
+}
+
+/// Per-minute/hourly/daily rollups of one metric, for historical charts
+async fn monitoring_metric_history(path: web::Path<String>, query: web::Query<MetricHistoryQuery>) -> Result<HttpResponse> {
+    let name = path.into_inner();
+    let now = chrono::Utc::now();
+    let since = now - chrono::Duration::hours(query.hours as i64);
+    let resolution = query.resolution.unwrap_or_else(|| metrics_store().resolution_for(since, now));
+    let points = metrics_store().query(&name, resolution, since, now);
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: tf("api.monitoring.metric_history", &[("metric", &name), ("count", &points.len())]),
+        data: Some(serde_json::json!({ "metric": name, "resolution": resolution, "points": points })),
+    }))
+}
+
+/// Registered outbound webhook endpoints (secrets omitted)
+async fn list_outbound_webhooks(emitter: Option<web::Data<Arc<WebhookEmitter>>>) -> Result<HttpResponse> {
+    let Some(emitter) = emitter else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: t("api.webhooks.listed"),
+        data: Some(serde_json::to_value(emitter.endpoints().await).unwrap_or_default()),
+    }))
+}
+
+/// Register an outbound webhook; the signing secret is returned only here
+async fn add_outbound_webhook(
+    emitter: Option<web::Data<Arc<WebhookEmitter>>>,
+    request: web::Json<AddWebhookRequest>,
+) -> Result<HttpResponse> {
+    let Some(emitter) = emitter else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    let request = request.into_inner();
+    match emitter.add_endpoint(&request.url, request.events, request.secret).await {
+        Ok(endpoint) => {
+            info!("🪝 Outbound webhook {} registered for {}", endpoint.id, endpoint.url);
+            Ok(HttpResponse::Created().json(BotOperationResponse {
+                success: true,
+                message: t("api.webhooks.registered"),
+                data: Some(serde_json::json!({
+                    "endpoint": endpoint,
+                    "secret": endpoint.secret,
+                })),
+            }))
+        }
+        Err(e) => Ok(HttpResponse::BadRequest().json(BotOperationResponse {
+            success: false,
+            message: tf("api.webhooks.invalid", &[("error", &e)]),
+            data: None,
+        })),
+    }
+}
+
+/// Remove an outbound webhook
+async fn remove_outbound_webhook(
+    emitter: Option<web::Data<Arc<WebhookEmitter>>>,
+    path: web::Path<String>,
+) -> Result<HttpResponse> {
+    let Some(emitter) = emitter else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    let endpoint_id = path.into_inner();
+    if !emitter.remove_endpoint(&endpoint_id).await {
+        return Ok(HttpResponse::NotFound().json(BotOperationResponse {
+            success: false,
+            message: tf("api.webhooks.not_found", &[("id", &endpoint_id)]),
+            data: None,
+        }));
+    }
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: tf("api.webhooks.removed", &[("id", &endpoint_id)]),
         data: None,
     }))
 }
 
+/// Recent outbound webhook delivery outcomes
+async fn outbound_webhook_deliveries(emitter: Option<web::Data<Arc<WebhookEmitter>>>) -> Result<HttpResponse> {
+    let Some(emitter) = emitter else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: t("api.webhooks.deliveries"),
+        data: Some(serde_json::to_value(emitter.deliveries().await).unwrap_or_default()),
+    }))
+}
+
+/// Helius webhook delivery (authenticated via the registered auth header)
+async fn helius_webhook(
+    receiver: Option<web::Data<Arc<HeliusWebhookReceiver>>>,
+    req: HttpRequest,
+    body: web::Bytes,
+) -> Result<HttpResponse> {
+    let Some(receiver) = receiver else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+
+    let authorization = req.headers().get("Authorization").and_then(|v| v.to_str().ok());
+    if !receiver.verify(authorization) {
+        tracing::warn!("🪝 Rejected Helius webhook with invalid auth header");
+        return Ok(HttpResponse::Unauthorized().json(BotOperationResponse {
+            success: false,
+            message: t("api.webhooks.unauthorized"),
+            data: None,
+        }));
+    }
+
+    let transactions: Vec<EnhancedTransaction> = match serde_json::from_slice(&body) {
+        Ok(transactions) => transactions,
+        Err(e) => {
+            return Ok(HttpResponse::BadRequest().json(BotOperationResponse {
+                success: false,
+                message: tf("api.webhooks.invalid_payload", &[("error", &e)]),
+                data: None,
+            }));
+        }
+    };
+
+    let published = receiver.ingest(&transactions);
+    Ok(HttpResponse::Ok().json(BotOperationResponse {
+        success: true,
+        message: tf("api.webhooks.processed", &[("count", &transactions.len())]),
+        data: Some(serde_json::json!({ "events": published })),
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,475 @@
+//! Persistent Job Queue
+//!
+//! Durable queue for deferred and retryable operational actions (unwinding
+//! orphaned inventory, closing empty ATAs, retrying failed sweeps). Jobs are
+//! stored next to the system state so they survive restarts, retried with
+//! exponential backoff, and processed by a pool of workers.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs;
+use tokio::sync::{Notify, RwLock};
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+use chrono::{DateTime, Duration, Utc};
+use tracing::{info, warn, error};
+
+use crate::api::state_persistence::PersistenceError;
+
+/// Operational action to perform
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JobKind {
+    /// Sell back inventory left behind by a partially failed trade
+    UnwindOrphanedInventory { token_mint: String, amount: u64 },
+    /// Close zero-balance associated token accounts to reclaim rent
+    CloseEmptyAtas { wallet: String },
+    /// Retry a profit sweep that failed
+    RetrySweep { sweep_id: String },
+    /// Any other deferred action, dispatched by name
+    Custom { name: String, payload: serde_json::Value },
+}
+
+impl JobKind {
+    /// Handler key for this job
+    pub fn handler_key(&self) -> &str {
+        match self {
+            Self::UnwindOrphanedInventory { .. } => "unwind_orphaned_inventory",
+            Self::CloseEmptyAtas { .. } => "close_empty_atas",
+            Self::RetrySweep { .. } => "retry_sweep",
+            Self::Custom { name, .. } => name,
+        }
+    }
+}
+
+/// Job lifecycle status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum JobStatus {
+    Pending,
+    Running,
+    Succeeded,
+    /// Retries exhausted
+    DeadLetter,
+    Cancelled,
+}
+
+/// Retry/backoff settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff_seconds: i64,
+    pub max_backoff_seconds: i64,
+    pub multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff_seconds: 30,
+            max_backoff_seconds: 3600,
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before the next attempt after `attempts` failures
+    pub fn backoff(&self, attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(30) as i32;
+        let seconds = (self.initial_backoff_seconds as f64 * self.multiplier.powi(exponent))
+            .min(self.max_backoff_seconds as f64);
+        Duration::seconds(seconds as i64)
+    }
+}
+
+/// Persisted job record
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Job {
+    pub id: Uuid,
+    pub kind: JobKind,
+    pub status: JobStatus,
+    pub attempts: u32,
+    pub max_attempts: u32,
+    pub next_run_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+}
+
+/// Queue counters for observability
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JobQueueStats {
+    pub pending: usize,
+    pub running: usize,
+    pub succeeded: usize,
+    pub dead_letter: usize,
+    pub cancelled: usize,
+    /// Pending jobs whose run time has arrived
+    pub due: usize,
+}
+
+/// Executes one kind of job
+#[async_trait]
+pub trait JobHandler: Send + Sync {
+    async fn handle(&self, job: &Job) -> anyhow::Result<()>;
+}
+
+/// Durable job queue backed by a JSON file in the persistence directory
+pub struct PersistentJobQueue {
+    queue_file: PathBuf,
+    jobs: RwLock<HashMap<Uuid, Job>>,
+    retry_policy: RetryPolicy,
+    notify: Notify,
+}
+
+impl PersistentJobQueue {
+    /// Open (or create) the queue stored under `persistence_path`
+    pub async fn open<P: AsRef<Path>>(persistence_path: P, retry_policy: RetryPolicy) -> Result<Self, PersistenceError> {
+        let persistence_path = persistence_path.as_ref();
+        fs::create_dir_all(persistence_path).await?;
+        let queue_file = persistence_path.join("job_queue.json");
+
+        let mut jobs: HashMap<Uuid, Job> = if queue_file.exists() {
+            let content = fs::read_to_string(&queue_file).await?;
+            serde_json::from_str(&content)?
+        } else {
+            HashMap::new()
+        };
+
+        // Jobs that were running when the process died are retried
+        let mut recovered = 0;
+        for job in jobs.values_mut().filter(|j| j.status == JobStatus::Running) {
+            job.status = JobStatus::Pending;
+            job.updated_at = Utc::now();
+            recovered += 1;
+        }
+        if recovered > 0 {
+            warn!("🔁 Recovered {} interrupted jobs from previous run", recovered);
+        }
+
+        let queue = Self {
+            queue_file,
+            jobs: RwLock::new(jobs),
+            retry_policy,
+            notify: Notify::new(),
+        };
+        queue.save().await?;
+        info!("✅ Persistent job queue ready ({} jobs)", queue.jobs.read().await.len());
+        Ok(queue)
+    }
+
+    /// Enqueue a job to run as soon as possible
+    pub async fn enqueue(&self, kind: JobKind) -> Result<Uuid, PersistenceError> {
+        self.enqueue_at(kind, Utc::now()).await
+    }
+
+    /// Enqueue a job to run at a given time
+    pub async fn enqueue_at(&self, kind: JobKind, run_at: DateTime<Utc>) -> Result<Uuid, PersistenceError> {
+        let now = Utc::now();
+        let job = Job {
+            id: Uuid::new_v4(),
+            kind,
+            status: JobStatus::Pending,
+            attempts: 0,
+            max_attempts: self.retry_policy.max_attempts,
+            next_run_at: run_at,
+            created_at: now,
+            updated_at: now,
+            last_error: None,
+        };
+        let id = job.id;
+        info!("📥 Job {} enqueued: {}", id, job.kind.handler_key());
+
+        self.jobs.write().await.insert(id, job);
+        self.save().await?;
+        self.notify.notify_one();
+        Ok(id)
+    }
+
+    /// Claim the earliest due pending job
+    pub async fn claim_next(&self) -> Result<Option<Job>, PersistenceError> {
+        let now = Utc::now();
+        let claimed = {
+            let mut jobs = self.jobs.write().await;
+            let next = jobs
+                .values_mut()
+                .filter(|j| j.status == JobStatus::Pending && j.next_run_at <= now)
+                .min_by_key(|j| j.next_run_at);
+            next.map(|job| {
+                job.status = JobStatus::Running;
+                job.attempts += 1;
+                job.updated_at = now;
+                job.clone()
+            })
+        };
+
+        if claimed.is_some() {
+            self.save().await?;
+        }
+        Ok(claimed)
+    }
+
+    /// Mark a job as succeeded
+    pub async fn complete(&self, id: Uuid) -> Result<(), PersistenceError> {
+        self.update(id, |job, _| {
+            job.status = JobStatus::Succeeded;
+            job.last_error = None;
+        }).await
+    }
+
+    /// Record a failed attempt; schedules a retry or dead-letters the job
+    pub async fn fail(&self, id: Uuid, error: &str) -> Result<(), PersistenceError> {
+        let error = error.to_string();
+        self.update(id, |job, policy| {
+            job.last_error = Some(error.clone());
+            if job.attempts >= job.max_attempts {
+                job.status = JobStatus::DeadLetter;
+                error!("☠️ Job {} ({}) dead-lettered after {} attempts: {}", job.id, job.kind.handler_key(), job.attempts, error);
+            } else {
+                let delay = policy.backoff(job.attempts);
+                job.status = JobStatus::Pending;
+                job.next_run_at = Utc::now() + delay;
+                warn!("🔁 Job {} ({}) failed attempt {}/{}, retrying in {}s: {}",
+                      job.id, job.kind.handler_key(), job.attempts, job.max_attempts, delay.num_seconds(), error);
+            }
+        }).await
+    }
+
+    /// Cancel a pending job
+    pub async fn cancel(&self, id: Uuid) -> Result<(), PersistenceError> {
+        self.update(id, |job, _| {
+            if job.status == JobStatus::Pending {
+                job.status = JobStatus::Cancelled;
+            }
+        }).await
+    }
+
+    /// Put a dead-lettered job back in the queue
+    pub async fn requeue(&self, id: Uuid) -> Result<(), PersistenceError> {
+        self.update(id, |job, _| {
+            if job.status == JobStatus::DeadLetter {
+                job.status = JobStatus::Pending;
+                job.attempts = 0;
+                job.next_run_at = Utc::now();
+            }
+        }).await?;
+        self.notify.notify_one();
+        Ok(())
+    }
+
+    /// Get a job by id
+    pub async fn get(&self, id: Uuid) -> Option<Job> {
+        self.jobs.read().await.get(&id).cloned()
+    }
+
+    /// List jobs, optionally filtered by status, oldest first
+    pub async fn list(&self, status: Option<JobStatus>) -> Vec<Job> {
+        let mut jobs: Vec<Job> = self
+            .jobs
+            .read()
+            .await
+            .values()
+            .filter(|j| status.map_or(true, |s| j.status == s))
+            .cloned()
+            .collect();
+        jobs.sort_by_key(|j| j.created_at);
+        jobs
+    }
+
+    /// Queue counters
+    pub async fn stats(&self) -> JobQueueStats {
+        let now = Utc::now();
+        let jobs = self.jobs.read().await;
+        let mut stats = JobQueueStats::default();
+        for job in jobs.values() {
+            match job.status {
+                JobStatus::Pending => {
+                    stats.pending += 1;
+                    if job.next_run_at <= now {
+                        stats.due += 1;
+                    }
+                }
+                JobStatus::Running => stats.running += 1,
+                JobStatus::Succeeded => stats.succeeded += 1,
+                JobStatus::DeadLetter => stats.dead_letter += 1,
+                JobStatus::Cancelled => stats.cancelled += 1,
+            }
+        }
+        stats
+    }
+
+    /// Drop succeeded/cancelled jobs older than `max_age`
+    pub async fn purge_finished(&self, max_age: Duration) -> Result<usize, PersistenceError> {
+        let cutoff = Utc::now() - max_age;
+        let removed = {
+            let mut jobs = self.jobs.write().await;
+            let before = jobs.len();
+            jobs.retain(|_, j| {
+                !matches!(j.status, JobStatus::Succeeded | JobStatus::Cancelled) || j.updated_at >= cutoff
+            });
+            before - jobs.len()
+        };
+        if removed > 0 {
+            self.save().await?;
+        }
+        Ok(removed)
+    }
+
+    async fn update<F>(&self, id: Uuid, apply: F) -> Result<(), PersistenceError>
+    where
+        F: FnOnce(&mut Job, &RetryPolicy),
+    {
+        {
+            let mut jobs = self.jobs.write().await;
+            let job = jobs
+                .get_mut(&id)
+                .ok_or_else(|| PersistenceError::StateFileNotFound(format!("job {}", id)))?;
+            apply(job, &self.retry_policy);
+            job.updated_at = Utc::now();
+        }
+        self.save().await
+    }
+
+    async fn save(&self) -> Result<(), PersistenceError> {
+        let content = {
+            let jobs = self.jobs.read().await;
+            serde_json::to_string_pretty(&*jobs)?
+        };
+
+        // Write to temp file then rename (atomic operation)
+        let temp_file = self.queue_file.with_extension("tmp");
+        fs::write(&temp_file, content).await?;
+        fs::rename(&temp_file, &self.queue_file).await?;
+        Ok(())
+    }
+}
+
+/// Pool of workers draining a job queue
+pub struct JobWorkerPool {
+    queue: Arc<PersistentJobQueue>,
+    handlers: HashMap<String, Arc<dyn JobHandler>>,
+    workers: usize,
+    poll_interval: std::time::Duration,
+}
+
+impl JobWorkerPool {
+    pub fn new(queue: Arc<PersistentJobQueue>, workers: usize) -> Self {
+        Self {
+            queue,
+            handlers: HashMap::new(),
+            workers: workers.max(1),
+            poll_interval: std::time::Duration::from_secs(5),
+        }
+    }
+
+    /// Register the handler for a job kind (see `JobKind::handler_key`)
+    pub fn with_handler(mut self, key: &str, handler: Arc<dyn JobHandler>) -> Self {
+        self.handlers.insert(key.to_string(), handler);
+        self
+    }
+
+    /// Start the workers
+    pub fn start(self) -> Vec<JoinHandle<()>> {
+        let handlers = Arc::new(self.handlers);
+        info!("👷 Starting {} job workers", self.workers);
+
+        (0..self.workers)
+            .map(|worker_id| {
+                let queue = self.queue.clone();
+                let handlers = handlers.clone();
+                let poll_interval = self.poll_interval;
+                tokio::spawn(async move {
+                    loop {
+                        match Self::run_next(&queue, &handlers).await {
+                            Ok(true) => continue,
+                            Ok(false) => {}
+                            Err(e) => error!("❌ Job worker {} persistence error: {}", worker_id, e),
+                        }
+                        tokio::select! {
+                            _ = queue.notify.notified() => {}
+                            _ = tokio::time::sleep(poll_interval) => {}
+                        }
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Run one due job; returns false when nothing was due
+    async fn run_next(
+        queue: &Arc<PersistentJobQueue>,
+        handlers: &Arc<HashMap<String, Arc<dyn JobHandler>>>,
+    ) -> Result<bool, PersistenceError> {
+        let Some(job) = queue.claim_next().await? else {
+            return Ok(false);
+        };
+
+        let Some(handler) = handlers.get(job.kind.handler_key()).cloned() else {
+            queue.fail(job.id, &format!("no handler registered for '{}'", job.kind.handler_key())).await?;
+            return Ok(true);
+        };
+
+        // Run in its own task so a panicking handler only fails this job
+        let job_id = job.id;
+        let outcome = tokio::spawn(async move { handler.handle(&job).await }).await;
+        match outcome {
+            Ok(Ok(())) => queue.complete(job_id).await?,
+            Ok(Err(e)) => queue.fail(job_id, &format!("{:#}", e)).await?,
+            Err(e) => queue.fail(job_id, &format!("handler panicked: {}", e)).await?,
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_jobs_survive_restart_and_running_jobs_recover() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = PersistentJobQueue::open(temp_dir.path(), RetryPolicy::default()).await.unwrap();
+        let id = queue.enqueue(JobKind::CloseEmptyAtas { wallet: "WALLET".to_string() }).await.unwrap();
+        assert_eq!(queue.claim_next().await.unwrap().unwrap().id, id);
+        drop(queue);
+
+        let reopened = PersistentJobQueue::open(temp_dir.path(), RetryPolicy::default()).await.unwrap();
+        let job = reopened.get(id).await.unwrap();
+        assert_eq!(job.status, JobStatus::Pending);
+        assert_eq!(job.attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_backoff_and_dead_letter() {
+        let temp_dir = TempDir::new().unwrap();
+        let policy = RetryPolicy { max_attempts: 2, ..Default::default() };
+        let queue = PersistentJobQueue::open(temp_dir.path(), policy).await.unwrap();
+        let id = queue.enqueue(JobKind::RetrySweep { sweep_id: "sweep-1".to_string() }).await.unwrap();
+
+        queue.claim_next().await.unwrap();
+        queue.fail(id, "rpc timeout").await.unwrap();
+        let job = queue.get(id).await.unwrap();
+        assert_eq!(job.status, JobStatus::Pending);
+        assert!(job.next_run_at > Utc::now() + Duration::seconds(20));
+        assert!(queue.claim_next().await.unwrap().is_none());
+
+        queue.update(id, |job, _| job.next_run_at = Utc::now()).await.unwrap();
+        queue.claim_next().await.unwrap();
+        queue.fail(id, "rpc timeout").await.unwrap();
+        assert_eq!(queue.get(id).await.unwrap().status, JobStatus::DeadLetter);
+        assert_eq!(queue.stats().await.dead_letter, 1);
+    }
+
+    #[test]
+    fn test_backoff_is_capped() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(1).num_seconds(), 30);
+        assert_eq!(policy.backoff(3).num_seconds(), 120);
+        assert_eq!(policy.backoff(20).num_seconds(), 3600);
+    }
+}
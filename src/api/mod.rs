@@ -1,16 +1,22 @@
 pub mod bot_interface;
+#[cfg(feature = "http-api")]
 pub mod gateway;
 pub mod config_management;
 pub mod health_monitoring;
 pub mod metrics_collector;
 pub mod state_persistence;
+pub mod job_queue;
+pub mod state_snapshot;
 pub mod yaml_config;
+pub mod config_schema;
+pub mod config_migration;
 
 // Re-export main API types
 pub use bot_interface::{
     BotInterface, BotType, BotStatus, BotConfig, BotMetrics, HealthStatus, 
     BotCapabilities, ValidationResult, BotError, Environment
 };
+#[cfg(feature = "http-api")]
 pub use gateway::{ApiGateway, GatewayConfig, AppState};
 pub use config_management::{ConfigManager, BotConfigTemplate, SystemConfig};
 pub use health_monitoring::{HealthMonitor, HealthReport, SystemHealthMetrics};
@@ -19,6 +25,13 @@ pub use state_persistence::{
     StatePersistenceManager, PersistedBotState, PersistedSystemMetrics, 
     SystemStateSnapshot, PersistenceError
 };
+pub use job_queue::{
+    PersistentJobQueue, JobWorkerPool, JobHandler, Job, JobKind, JobStatus,
+    RetryPolicy, JobQueueStats
+};
+pub use config_schema::{bot_config_schema, bot_config_schema_json, parameters_schema, EnhancedArbitrageParameters, LiquiditySniperParameters};
+pub use config_migration::{ConfigMigrator, Migration, MigrationOutcome, SYSTEM_CONFIG_VERSION, BOT_CONFIG_VERSION};
+pub use state_snapshot::{EngineStateSnapshot, ComponentSnapshot, SnapshotError, SNAPSHOT_FORMAT_VERSION};
 pub use yaml_config::{
     YamlConfigManager, SystemConfigYaml, BotConfigYaml, YamlConfigError,
     DesiredStateConfig, DesiredBotState, DesiredBotStatus, DesiredBotConfig,
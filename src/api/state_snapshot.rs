@@ -0,0 +1,187 @@
+//! Engine State Snapshots
+//!
+//! Versioned export/import of full engine state (positions, budgets,
+//! cooldowns, route caches, model weights) so an instance can be handed
+//! off to another host or a blue/green peer without losing context.
+//! Each component serializes its own typed section with its own schema
+//! version; the file as a whole carries a format version and a checksum.
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tokio::fs;
+use chrono::{DateTime, Utc};
+use tracing::info;
+
+/// Current snapshot file format
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// Snapshot errors
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Serialization error: {0}")]
+    SerializationError(#[from] serde_json::Error),
+
+    #[error("Unsupported snapshot format v{found} (supported up to v{supported})")]
+    UnsupportedFormat { found: u32, supported: u32 },
+
+    #[error("Snapshot checksum mismatch - file is corrupted or was edited")]
+    ChecksumMismatch,
+
+    #[error("Component '{component}' snapshot is schema v{found}, this build supports up to v{supported}")]
+    UnsupportedComponentVersion { component: String, found: u32, supported: u32 },
+}
+
+/// One component's serialized state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentSnapshot {
+    pub schema_version: u32,
+    pub captured_at: DateTime<Utc>,
+    pub data: serde_json::Value,
+}
+
+/// Full engine state snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngineStateSnapshot {
+    pub format_version: u32,
+    pub created_at: DateTime<Utc>,
+    pub app_version: String,
+    pub source_host: String,
+    pub components: BTreeMap<String, ComponentSnapshot>,
+    /// SHA-256 over the serialized components
+    pub checksum: String,
+}
+
+impl EngineStateSnapshot {
+    pub fn new() -> Self {
+        Self {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            created_at: Utc::now(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            source_host: std::env::var("HOSTNAME").unwrap_or_else(|_| "unknown".to_string()),
+            components: BTreeMap::new(),
+            checksum: String::new(),
+        }
+    }
+
+    /// Add a component section
+    pub fn insert<T: Serialize>(&mut self, key: &str, schema_version: u32, state: &T) -> Result<(), SnapshotError> {
+        self.components.insert(key.to_string(), ComponentSnapshot {
+            schema_version,
+            captured_at: Utc::now(),
+            data: serde_json::to_value(state)?,
+        });
+        Ok(())
+    }
+
+    /// Read a component section; `None` if the snapshot does not contain it
+    pub fn get<T: DeserializeOwned>(&self, key: &str, max_schema_version: u32) -> Result<Option<T>, SnapshotError> {
+        let Some(component) = self.components.get(key) else {
+            return Ok(None);
+        };
+        if component.schema_version > max_schema_version {
+            return Err(SnapshotError::UnsupportedComponentVersion {
+                component: key.to_string(),
+                found: component.schema_version,
+                supported: max_schema_version,
+            });
+        }
+        Ok(Some(serde_json::from_value(component.data.clone())?))
+    }
+
+    /// Component keys present in the snapshot
+    pub fn component_keys(&self) -> Vec<String> {
+        self.components.keys().cloned().collect()
+    }
+
+    fn compute_checksum(&self) -> Result<String, SnapshotError> {
+        let bytes = serde_json::to_vec(&self.components)?;
+        Ok(format!("{:x}", Sha256::digest(&bytes)))
+    }
+
+    /// Write the snapshot to disk (atomic)
+    pub async fn write_to_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), SnapshotError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).await?;
+        }
+        self.checksum = self.compute_checksum()?;
+
+        let temp_file = path.with_extension("tmp");
+        fs::write(&temp_file, serde_json::to_string_pretty(self)?).await?;
+        fs::rename(&temp_file, path).await?;
+
+        info!("📸 State snapshot written to {} ({} components)", path.display(), self.components.len());
+        Ok(())
+    }
+
+    /// Load and verify a snapshot file
+    pub async fn read_from_file<P: AsRef<Path>>(path: P) -> Result<Self, SnapshotError> {
+        let content = fs::read_to_string(path.as_ref()).await?;
+        let snapshot: Self = serde_json::from_str(&content)?;
+
+        if snapshot.format_version > SNAPSHOT_FORMAT_VERSION {
+            return Err(SnapshotError::UnsupportedFormat {
+                found: snapshot.format_version,
+                supported: SNAPSHOT_FORMAT_VERSION,
+            });
+        }
+        if snapshot.checksum != snapshot.compute_checksum()? {
+            return Err(SnapshotError::ChecksumMismatch);
+        }
+
+        info!("📸 Loaded state snapshot from {} (created {} on {}, v{})",
+              path.as_ref().display(), snapshot.created_at, snapshot.source_host, snapshot.app_version);
+        Ok(snapshot)
+    }
+}
+
+impl Default for EngineStateSnapshot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_snapshot_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("snapshots/engine.json");
+
+        let mut cache = HashMap::new();
+        cache.insert("SOL->USDC".to_string(), 12.5);
+        let mut snapshot = EngineStateSnapshot::new();
+        snapshot.insert("route_cache", 1, &cache).unwrap();
+        snapshot.write_to_file(&path).await.unwrap();
+
+        let loaded = EngineStateSnapshot::read_from_file(&path).await.unwrap();
+        let restored: HashMap<String, f64> = loaded.get("route_cache", 1).unwrap().unwrap();
+        assert_eq!(restored, cache);
+        assert!(loaded.get::<HashMap<String, f64>>("missing", 1).unwrap().is_none());
+        assert!(matches!(loaded.get::<HashMap<String, f64>>("route_cache", 0),
+                         Err(SnapshotError::UnsupportedComponentVersion { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_tampered_snapshot_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("engine.json");
+
+        let mut snapshot = EngineStateSnapshot::new();
+        snapshot.insert("budget", 1, &100.0_f64).unwrap();
+        snapshot.write_to_file(&path).await.unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap().replace("100.0", "900.0");
+        std::fs::write(&path, content).unwrap();
+        assert!(matches!(EngineStateSnapshot::read_from_file(&path).await, Err(SnapshotError::ChecksumMismatch)));
+    }
+}
@@ -14,6 +14,7 @@ use anyhow::Result;
 use tracing::{info, error, warn, debug};
 
 use crate::api::bot_interface::{BotType, Environment};
+use crate::api::config_migration::{upgrade_file, ConfigMigrator, BOT_CONFIG_VERSION, SYSTEM_CONFIG_VERSION};
 
 /// YAML Configuration management errors
 #[derive(Debug, thiserror::Error)]
@@ -35,11 +36,21 @@ pub enum YamlConfigError {
     
     #[error("YAML parsing error: {0}")]
     YamlError(#[from] serde_yaml::Error),
+    
+    #[error("{kind} config version {found} is newer than supported version {supported}")]
+    UnsupportedVersion { kind: String, found: u32, supported: u32 },
+    
+    #[error("Configuration migration failed: {0}")]
+    MigrationFailed(String),
 }
 
 /// System-wide configuration in YAML format
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemConfigYaml {
+    /// Config format version, see [`crate::api::config_migration`]
+    #[serde(default)]
+    pub config_version: u32,
+    
     /// Application metadata
     pub app: AppConfig,
     
@@ -343,6 +354,10 @@ pub struct EnvironmentOverrides {
 /// Bot-specific YAML configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BotConfigYaml {
+    /// Config format version, see [`crate::api::config_migration`]
+    #[serde(default)]
+    pub config_version: u32,
+    
     /// Bot metadata
     pub metadata: BotMetadata,
     
@@ -660,7 +675,8 @@ impl YamlConfigManager {
         }
         
         let content = fs::read_to_string(&config_file).await?;
-        let mut config: SystemConfigYaml = serde_yaml::from_str(&content)?;
+        let value = upgrade_file(&config_file, &content, &ConfigMigrator::system()).await?;
+        let mut config: SystemConfigYaml = serde_yaml::from_value(value)?;
         
         // Apply environment-specific overrides
         if let Some(env_overrides) = config.environments.get(&self.environment).cloned() {
@@ -725,7 +741,8 @@ impl YamlConfigManager {
         }
         
         let content = fs::read_to_string(&config_file).await?;
-        let config: BotConfigYaml = serde_yaml::from_str(&content)?;
+        let value = upgrade_file(&config_file, &content, &ConfigMigrator::bot()).await?;
+        let config: BotConfigYaml = serde_yaml::from_value(value)?;
         
         self.bot_configs.write().await.insert(bot_id, config.clone());
         
@@ -982,6 +999,7 @@ impl YamlConfigManager {
 impl Default for SystemConfigYaml {
     fn default() -> Self {
         Self {
+            config_version: SYSTEM_CONFIG_VERSION,
             app: AppConfig {
                 name: "SniperForge".to_string(),
                 version: env!("CARGO_PKG_VERSION").to_string(),
@@ -1118,6 +1136,7 @@ impl Default for SystemConfigYaml {
 impl BotConfigYaml {
     pub fn default_for_type(bot_id: Uuid, bot_type: BotType, name: String) -> Self {
         Self {
+            config_version: BOT_CONFIG_VERSION,
             metadata: BotMetadata {
                 id: bot_id,
                 name,
@@ -0,0 +1,310 @@
+//! Per-provider circuit breakers
+//!
+//! Each external provider (Jupiter, DexScreener, Helius, Pyth, Reddit, news
+//! feeds, ...) gets its own breaker: after `failure_threshold` consecutive
+//! failures the circuit opens and calls are rejected without touching the
+//! network; after `open_duration` a limited number of half-open probes are
+//! let through, and a successful probe closes the circuit again.
+//!
+//! Consumers check `allow_request` and pick their degraded behavior when the
+//! circuit is open (price feeds fall back to on-chain sources, sentiment
+//! returns neutral). A process-wide registry is available through
+//! `provider_circuits()` so breakers are shared by every consumer of a
+//! provider and can be reported by monitoring.
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+/// Circuit breaker state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CircuitState {
+    /// Requests flow normally
+    Closed,
+    /// Provider considered down; requests rejected
+    Open,
+    /// Probing whether the provider recovered
+    HalfOpen,
+}
+
+/// Breaker thresholds
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures before the circuit opens
+    pub failure_threshold: u32,
+    /// How long the circuit stays open before probing
+    pub open_duration: Duration,
+    /// Concurrent probes allowed while half-open
+    pub half_open_max_probes: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(30),
+            half_open_max_probes: 1,
+        }
+    }
+}
+
+/// Rejection returned when a circuit is open
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("circuit open for provider '{provider}' (retry in {retry_in_secs:.0}s)")]
+pub struct CircuitOpenError {
+    pub provider: String,
+    pub retry_in_secs: f64,
+}
+
+/// Reportable breaker state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CircuitSnapshot {
+    pub provider: String,
+    pub state: CircuitState,
+    pub consecutive_failures: u32,
+    pub total_failures: u64,
+    pub total_successes: u64,
+    pub rejected_requests: u64,
+    pub opened_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug)]
+struct BreakerState {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    opened_at_utc: Option<DateTime<Utc>>,
+    probes_in_flight: u32,
+    total_failures: u64,
+    total_successes: u64,
+    rejected_requests: u64,
+    last_error: Option<String>,
+}
+
+/// Circuit breaker for one provider
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    provider: String,
+    config: CircuitBreakerConfig,
+    inner: Mutex<BreakerState>,
+}
+
+impl CircuitBreaker {
+    pub fn new(provider: &str, config: CircuitBreakerConfig) -> Self {
+        Self {
+            provider: provider.to_string(),
+            config,
+            inner: Mutex::new(BreakerState {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                opened_at: None,
+                opened_at_utc: None,
+                probes_in_flight: 0,
+                total_failures: 0,
+                total_successes: 0,
+                rejected_requests: 0,
+                last_error: None,
+            }),
+        }
+    }
+
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    /// Whether a request may be sent now (claims a probe slot when half-open)
+    pub fn allow_request(&self) -> bool {
+        self.check().is_ok()
+    }
+
+    /// Like `allow_request`, with the reason when rejected
+    pub fn check(&self) -> Result<(), CircuitOpenError> {
+        let mut inner = self.inner.lock();
+        if inner.state == CircuitState::Open {
+            let elapsed = inner.opened_at.map_or(Duration::MAX, |t| t.elapsed());
+            if elapsed >= self.config.open_duration {
+                info!("🔌 Circuit '{}' half-open, probing provider", self.provider);
+                inner.state = CircuitState::HalfOpen;
+                inner.probes_in_flight = 0;
+            } else {
+                inner.rejected_requests += 1;
+                return Err(CircuitOpenError {
+                    provider: self.provider.clone(),
+                    retry_in_secs: (self.config.open_duration - elapsed).as_secs_f64(),
+                });
+            }
+        }
+        if inner.state == CircuitState::HalfOpen {
+            if inner.probes_in_flight >= self.config.half_open_max_probes {
+                inner.rejected_requests += 1;
+                return Err(CircuitOpenError { provider: self.provider.clone(), retry_in_secs: 0.0 });
+            }
+            inner.probes_in_flight += 1;
+        }
+        Ok(())
+    }
+
+    pub fn record_success(&self) {
+        let mut inner = self.inner.lock();
+        inner.total_successes += 1;
+        inner.consecutive_failures = 0;
+        if inner.state != CircuitState::Closed {
+            info!("✅ Circuit '{}' closed - provider recovered", self.provider);
+        }
+        inner.state = CircuitState::Closed;
+        inner.opened_at = None;
+        inner.opened_at_utc = None;
+        inner.probes_in_flight = 0;
+    }
+
+    pub fn record_failure(&self, error: &str) {
+        let mut inner = self.inner.lock();
+        inner.total_failures += 1;
+        inner.consecutive_failures += 1;
+        inner.last_error = Some(error.to_string());
+
+        let trip = match inner.state {
+            CircuitState::HalfOpen => true,
+            CircuitState::Closed => inner.consecutive_failures >= self.config.failure_threshold,
+            CircuitState::Open => false,
+        };
+        if trip {
+            warn!("🔌 Circuit '{}' OPEN after {} consecutive failures: {}",
+                  self.provider, inner.consecutive_failures, error);
+            inner.state = CircuitState::Open;
+            inner.opened_at = Some(Instant::now());
+            inner.opened_at_utc = Some(Utc::now());
+            inner.probes_in_flight = 0;
+        }
+    }
+
+    /// Record the outcome of a request
+    pub fn record<T, E: std::fmt::Display>(&self, result: &Result<T, E>) {
+        match result {
+            Ok(_) => self.record_success(),
+            Err(e) => self.record_failure(&e.to_string()),
+        }
+    }
+
+    pub fn state(&self) -> CircuitState {
+        self.inner.lock().state
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.state() == CircuitState::Open
+    }
+
+    pub fn snapshot(&self) -> CircuitSnapshot {
+        let inner = self.inner.lock();
+        CircuitSnapshot {
+            provider: self.provider.clone(),
+            state: inner.state,
+            consecutive_failures: inner.consecutive_failures,
+            total_failures: inner.total_failures,
+            total_successes: inner.total_successes,
+            rejected_requests: inner.rejected_requests,
+            opened_at: inner.opened_at_utc,
+            last_error: inner.last_error.clone(),
+        }
+    }
+}
+
+/// Registry of breakers keyed by provider name
+#[derive(Debug, Default)]
+pub struct ProviderCircuits {
+    config: CircuitBreakerConfig,
+    breakers: RwLock<HashMap<String, Arc<CircuitBreaker>>>,
+}
+
+impl ProviderCircuits {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            breakers: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Breaker for `provider`, created on first use
+    pub fn breaker(&self, provider: &str) -> Arc<CircuitBreaker> {
+        if let Some(breaker) = self.breakers.read().get(provider) {
+            return breaker.clone();
+        }
+        self.breakers
+            .write()
+            .entry(provider.to_string())
+            .or_insert_with(|| Arc::new(CircuitBreaker::new(provider, self.config.clone())))
+            .clone()
+    }
+
+    /// Snapshot of every known breaker, sorted by provider
+    pub fn snapshot(&self) -> Vec<CircuitSnapshot> {
+        let mut snapshots: Vec<_> = self.breakers.read().values().map(|b| b.snapshot()).collect();
+        snapshots.sort_by(|a, b| a.provider.cmp(&b.provider));
+        snapshots
+    }
+
+    /// Providers whose circuit is currently not closed
+    pub fn degraded_providers(&self) -> Vec<String> {
+        self.snapshot()
+            .into_iter()
+            .filter(|s| s.state != CircuitState::Closed)
+            .map(|s| s.provider)
+            .collect()
+    }
+}
+
+/// Process-wide provider breakers shared by all consumers
+pub fn provider_circuits() -> &'static ProviderCircuits {
+    static CIRCUITS: OnceLock<ProviderCircuits> = OnceLock::new();
+    CIRCUITS.get_or_init(ProviderCircuits::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(open_for: Duration) -> CircuitBreaker {
+        CircuitBreaker::new("jupiter", CircuitBreakerConfig {
+            failure_threshold: 3,
+            open_duration: open_for,
+            half_open_max_probes: 1,
+        })
+    }
+
+    #[test]
+    fn test_opens_after_threshold_and_rejects() {
+        let breaker = breaker(Duration::from_secs(60));
+        for _ in 0..2 {
+            breaker.record_failure("timeout");
+        }
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        breaker.record_failure("timeout");
+        assert!(breaker.is_open());
+        assert!(breaker.check().is_err());
+        assert_eq!(breaker.snapshot().rejected_requests, 1);
+    }
+
+    #[test]
+    fn test_half_open_probe_closes_or_reopens() {
+        let breaker = breaker(Duration::ZERO);
+        for _ in 0..3 {
+            breaker.record_failure("503");
+        }
+
+        assert!(breaker.allow_request());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(!breaker.allow_request(), "only one probe at a time");
+        breaker.record_failure("still down");
+        assert!(breaker.is_open());
+
+        assert!(breaker.allow_request());
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
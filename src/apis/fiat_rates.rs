@@ -0,0 +1,323 @@
+//! Fiat exchange-rate service
+//!
+//! Maintains current SOL/USD, BTC/USD and ETH/USD rates with fallbacks across
+//! providers (Coinbase → CoinGecko → Binance). Every USD conversion returns the
+//! rate and the timestamp it was fetched at, so reports can state which rate
+//! they were computed with instead of relying on a hardcoded SOL price.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use tokio::sync::RwLock;
+use tokio::time::Duration;
+use tracing::{debug, warn};
+
+/// Assets tracked by the fiat rate service
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FiatAsset {
+    Sol,
+    Btc,
+    Eth,
+}
+
+impl FiatAsset {
+    /// Ticker symbol
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Sol => "SOL",
+            Self::Btc => "BTC",
+            Self::Eth => "ETH",
+        }
+    }
+
+    /// CoinGecko coin id
+    fn coingecko_id(&self) -> &'static str {
+        match self {
+            Self::Sol => "solana",
+            Self::Btc => "bitcoin",
+            Self::Eth => "ethereum",
+        }
+    }
+
+    /// Parse from a ticker symbol (case-insensitive)
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol.to_uppercase().as_str() {
+            "SOL" | "WSOL" => Some(Self::Sol),
+            "BTC" | "WBTC" => Some(Self::Btc),
+            "ETH" | "WETH" => Some(Self::Eth),
+            _ => None,
+        }
+    }
+}
+
+/// Rate providers in fallback order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FiatRateProvider {
+    Coinbase,
+    CoinGecko,
+    Binance,
+}
+
+/// A USD rate and where/when it came from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FiatRate {
+    pub asset: FiatAsset,
+    pub usd: f64,
+    pub provider: FiatRateProvider,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl FiatRate {
+    /// Age of the rate in seconds
+    pub fn age_seconds(&self) -> i64 {
+        (Utc::now() - self.fetched_at).num_seconds()
+    }
+}
+
+/// Result of converting an asset amount to USD
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsdConversion {
+    pub amount: f64,
+    pub usd: f64,
+    pub rate: FiatRate,
+}
+
+/// Fiat rate service configuration
+#[derive(Debug, Clone)]
+pub struct FiatRateConfig {
+    /// Rates younger than this are served from cache
+    pub max_rate_age_seconds: i64,
+    /// Last known rates older than this are refused when every provider fails
+    pub max_stale_age_seconds: i64,
+    /// Provider order
+    pub providers: Vec<FiatRateProvider>,
+}
+
+impl Default for FiatRateConfig {
+    fn default() -> Self {
+        Self {
+            max_rate_age_seconds: 60,
+            max_stale_age_seconds: 15 * 60,
+            providers: vec![
+                FiatRateProvider::Coinbase,
+                FiatRateProvider::CoinGecko,
+                FiatRateProvider::Binance,
+            ],
+        }
+    }
+}
+
+/// Shared exchange-rate service for USD reporting
+#[derive(Debug)]
+pub struct FiatRateService {
+    config: FiatRateConfig,
+    http_client: reqwest::Client,
+    rates: RwLock<HashMap<FiatAsset, FiatRate>>,
+}
+
+impl FiatRateService {
+    /// Create a new service with default configuration
+    pub fn new() -> Self {
+        Self::with_config(FiatRateConfig::default())
+    }
+
+    /// Create a new service with custom configuration
+    pub fn with_config(config: FiatRateConfig) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .connect_timeout(Duration::from_secs(5))
+            .user_agent("SniperForge/3.0.0 FiatRateService")
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self {
+            config,
+            http_client,
+            rates: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Get the current USD rate for an asset, refreshing when the cache is old
+    pub async fn get_rate(&self, asset: FiatAsset) -> Result<FiatRate> {
+        if let Some(rate) = self.cached_rate(asset).await {
+            if rate.age_seconds() <= self.config.max_rate_age_seconds {
+                return Ok(rate);
+            }
+        }
+
+        match self.refresh(asset).await {
+            Ok(rate) => Ok(rate),
+            Err(e) => {
+                // Serve last known rate while it is within the stale tolerance
+                match self.cached_rate(asset).await {
+                    Some(rate) if rate.age_seconds() <= self.config.max_stale_age_seconds => {
+                        warn!("⚠️ Using stale {} rate from {:?} ({}s old): {}",
+                              asset.symbol(), rate.provider, rate.age_seconds(), e);
+                        Ok(rate)
+                    }
+                    _ => Err(e),
+                }
+            }
+        }
+    }
+
+    /// Convert an amount of `asset` into USD
+    pub async fn to_usd(&self, asset: FiatAsset, amount: f64) -> Result<UsdConversion> {
+        let rate = self.get_rate(asset).await?;
+        Ok(UsdConversion {
+            amount,
+            usd: amount * rate.usd,
+            rate,
+        })
+    }
+
+    /// Convert an amount of SOL into USD
+    pub async fn sol_to_usd(&self, amount_sol: f64) -> Result<UsdConversion> {
+        self.to_usd(FiatAsset::Sol, amount_sol).await
+    }
+
+    /// Last known rate without triggering a fetch
+    pub async fn cached_rate(&self, asset: FiatAsset) -> Option<FiatRate> {
+        self.rates.read().await.get(&asset).cloned()
+    }
+
+    /// Seed or override a rate (e.g. from an on-chain oracle already in hand)
+    pub async fn set_rate(&self, rate: FiatRate) {
+        self.rates.write().await.insert(rate.asset, rate);
+    }
+
+    /// Refresh all tracked assets
+    pub async fn refresh_all(&self) -> Vec<(FiatAsset, Result<FiatRate>)> {
+        let mut results = Vec::new();
+        for asset in [FiatAsset::Sol, FiatAsset::Btc, FiatAsset::Eth] {
+            results.push((asset, self.refresh(asset).await));
+        }
+        results
+    }
+
+    /// Fetch a fresh rate walking the provider fallback chain
+    async fn refresh(&self, asset: FiatAsset) -> Result<FiatRate> {
+        let mut errors = Vec::new();
+
+        for provider in &self.config.providers {
+            let fetched = match provider {
+                FiatRateProvider::Coinbase => self.fetch_coinbase(asset).await,
+                FiatRateProvider::CoinGecko => self.fetch_coingecko(asset).await,
+                FiatRateProvider::Binance => self.fetch_binance(asset).await,
+            };
+
+            match fetched {
+                Ok(usd) if usd.is_finite() && usd > 0.0 => {
+                    let rate = FiatRate {
+                        asset,
+                        usd,
+                        provider: *provider,
+                        fetched_at: Utc::now(),
+                    };
+                    debug!("💱 {}/USD = {:.4} via {:?}", asset.symbol(), usd, provider);
+                    self.set_rate(rate.clone()).await;
+                    return Ok(rate);
+                }
+                Ok(usd) => errors.push(format!("{:?}: invalid rate {}", provider, usd)),
+                Err(e) => errors.push(format!("{:?}: {}", provider, e)),
+            }
+        }
+
+        Err(anyhow!("All fiat rate providers failed for {}: {}", asset.symbol(), errors.join("; ")))
+    }
+
+    async fn fetch_coinbase(&self, asset: FiatAsset) -> Result<f64> {
+        let url = format!("https://api.coinbase.com/v2/exchange-rates?currency={}", asset.symbol());
+        let data: Value = self.http_client.get(&url).send().await?.json().await?;
+        data["data"]["rates"]["USD"]
+            .as_str()
+            .and_then(|s| s.parse::<f64>().ok())
+            .ok_or_else(|| anyhow!("Failed to parse Coinbase rate"))
+    }
+
+    async fn fetch_coingecko(&self, asset: FiatAsset) -> Result<f64> {
+        let url = format!(
+            "https://api.coingecko.com/api/v3/simple/price?ids={}&vs_currencies=usd",
+            asset.coingecko_id()
+        );
+        let data: Value = self.http_client.get(&url).send().await?.json().await?;
+        data[asset.coingecko_id()]["usd"]
+            .as_f64()
+            .ok_or_else(|| anyhow!("Failed to parse CoinGecko rate"))
+    }
+
+    async fn fetch_binance(&self, asset: FiatAsset) -> Result<f64> {
+        let url = format!("https://api.binance.com/api/v3/ticker/price?symbol={}USDT", asset.symbol());
+        let data: Value = self.http_client.get(&url).send().await?.json().await?;
+        data["price"]
+            .as_str()
+            .and_then(|s| s.parse::<f64>().ok())
+            .ok_or_else(|| anyhow!("Failed to parse Binance rate"))
+    }
+}
+
+impl Default for FiatRateService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Process-wide rate service, so every price fallback and USD report reads the same cache
+pub fn fiat_rates() -> Arc<FiatRateService> {
+    static SHARED: OnceLock<Arc<FiatRateService>> = OnceLock::new();
+    SHARED.get_or_init(|| Arc::new(FiatRateService::new())).clone()
+}
+
+/// Build a rate that is already `age_seconds` old (used for seeding and tests)
+pub fn rate_with_age(asset: FiatAsset, usd: f64, provider: FiatRateProvider, age_seconds: i64) -> FiatRate {
+    FiatRate {
+        asset,
+        usd,
+        provider,
+        fetched_at: Utc::now() - ChronoDuration::seconds(age_seconds),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fresh_cached_rate_is_used() {
+        let service = FiatRateService::new();
+        service.set_rate(rate_with_age(FiatAsset::Sol, 142.5, FiatRateProvider::Coinbase, 5)).await;
+
+        let conversion = service.sol_to_usd(2.0).await.unwrap();
+        assert!((conversion.usd - 285.0).abs() < 1e-9);
+        assert_eq!(conversion.rate.provider, FiatRateProvider::Coinbase);
+    }
+
+    #[tokio::test]
+    async fn test_stale_rate_served_when_providers_fail() {
+        let service = FiatRateService::with_config(FiatRateConfig {
+            providers: Vec::new(), // Every refresh fails
+            ..Default::default()
+        });
+        service.set_rate(rate_with_age(FiatAsset::Eth, 3000.0, FiatRateProvider::Binance, 120)).await;
+        assert!(service.get_rate(FiatAsset::Eth).await.is_ok());
+
+        service.set_rate(rate_with_age(FiatAsset::Eth, 3000.0, FiatRateProvider::Binance, 3600)).await;
+        assert!(service.get_rate(FiatAsset::Eth).await.is_err());
+    }
+
+    #[test]
+    fn test_asset_from_symbol() {
+        assert_eq!(FiatAsset::from_symbol("wsol"), Some(FiatAsset::Sol));
+        assert_eq!(FiatAsset::from_symbol("BONK"), None);
+    }
+
+    #[tokio::test]
+    async fn test_shared_service_is_one_cache() {
+        assert!(Arc::ptr_eq(&fiat_rates(), &fiat_rates()));
+        fiat_rates().set_rate(rate_with_age(FiatAsset::Btc, 61000.0, FiatRateProvider::CoinGecko, 1)).await;
+        assert_eq!(fiat_rates().cached_rate(FiatAsset::Btc).await.map(|r| r.usd), Some(61000.0));
+    }
+}
@@ -0,0 +1,369 @@
+//! Helius enhanced API
+//!
+//! Parsed-transaction fetch and webhook management against the Helius REST
+//! API, plus the receiving side for webhooks: incoming payloads are
+//! authenticated, classified into pool-creation, large-transfer and
+//! wallet-activity events, and broadcast to subscribers so those events are
+//! pushed into the system instead of polled.
+//!
+//! Helius authenticates webhook deliveries by echoing the `authHeader` value
+//! configured at registration in the `Authorization` header; the receiver
+//! compares it in constant time.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::analytics::trade_indexer::NATIVE_SOL_MINT;
+use crate::config::ApiCredentials;
+
+const HELIUS_API_URL: &str = "https://api.helius.xyz";
+
+/// Transaction types that indicate a new liquidity pool
+const POOL_CREATION_TYPES: &[&str] = &["CREATE_POOL", "INITIALIZE_POOL", "ADD_LIQUIDITY_POOL"];
+
+/// SPL token movement in an enhanced transaction
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TokenTransfer {
+    pub from_user_account: Option<String>,
+    pub to_user_account: Option<String>,
+    pub mint: String,
+    pub token_amount: f64,
+}
+
+/// Native SOL movement in an enhanced transaction (lamports)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NativeTransfer {
+    pub from_user_account: Option<String>,
+    pub to_user_account: Option<String>,
+    pub amount: u64,
+}
+
+/// Helius parsed ("enhanced") transaction
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EnhancedTransaction {
+    pub signature: String,
+    pub slot: u64,
+    pub timestamp: i64,
+    #[serde(rename = "type")]
+    pub transaction_type: String,
+    /// Program/venue that produced the transaction (e.g. `RAYDIUM`, `JUPITER`)
+    pub source: String,
+    pub fee: u64,
+    pub fee_payer: String,
+    pub description: String,
+    pub token_transfers: Vec<TokenTransfer>,
+    pub native_transfers: Vec<NativeTransfer>,
+    pub events: serde_json::Value,
+}
+
+/// Webhook registration request/record
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeliusWebhook {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_id: Option<String>,
+    #[serde(rename = "webhookURL")]
+    pub webhook_url: String,
+    pub transaction_types: Vec<String>,
+    pub account_addresses: Vec<String>,
+    /// `enhanced` (parsed payloads) or `raw`
+    pub webhook_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_header: Option<String>,
+}
+
+impl HeliusWebhook {
+    /// Enhanced webhook for all transaction types touching `addresses`
+    pub fn enhanced(webhook_url: &str, addresses: Vec<String>, auth_header: &str) -> Self {
+        Self {
+            webhook_id: None,
+            webhook_url: webhook_url.to_string(),
+            transaction_types: vec!["ANY".to_string()],
+            account_addresses: addresses,
+            webhook_type: "enhanced".to_string(),
+            auth_header: Some(auth_header.to_string()),
+        }
+    }
+}
+
+/// Helius REST client
+#[derive(Debug, Clone)]
+pub struct HeliusClient {
+    http_client: reqwest::Client,
+    api_key: String,
+    base_url: String,
+}
+
+impl HeliusClient {
+    pub fn new(api_key: &str) -> Self {
+        Self {
+            http_client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(15))
+                .build()
+                .unwrap_or_default(),
+            api_key: api_key.to_string(),
+            base_url: HELIUS_API_URL.to_string(),
+        }
+    }
+
+    /// Client using the Helius key from `config.json`
+    pub fn from_credentials(credentials: &ApiCredentials) -> Result<Self> {
+        if !credentials.has_helius_credentials() {
+            return Err(anyhow!("Helius API key not configured"));
+        }
+        Ok(Self::new(&credentials.helius_api_key))
+    }
+
+    /// Override the API base URL (staging/proxies)
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}?api-key={}", self.base_url, path, self.api_key)
+    }
+
+    async fn check<T: serde::de::DeserializeOwned>(response: reqwest::Response) -> Result<T> {
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow!("Helius API error {}: {}", status, body));
+        }
+        Ok(response.json().await?)
+    }
+
+    /// Parse up to 100 transactions by signature
+    pub async fn parse_transactions(&self, signatures: &[String]) -> Result<Vec<EnhancedTransaction>> {
+        if signatures.is_empty() {
+            return Ok(Vec::new());
+        }
+        let response = self.http_client
+            .post(self.url("/v0/transactions"))
+            .json(&serde_json::json!({ "transactions": signatures }))
+            .send()
+            .await?;
+        Self::check(response).await
+    }
+
+    /// Parsed history for an address, newest first
+    pub async fn address_transactions(&self, address: &str, before: Option<&str>, limit: usize) -> Result<Vec<EnhancedTransaction>> {
+        let mut url = format!("{}&limit={}", self.url(&format!("/v0/addresses/{}/transactions", address)), limit.min(100));
+        if let Some(before) = before {
+            url.push_str(&format!("&before={}", before));
+        }
+        let response = self.http_client.get(url).send().await?;
+        Self::check(response).await
+    }
+
+    /// Register a webhook; returns it with its `webhook_id`
+    pub async fn create_webhook(&self, webhook: &HeliusWebhook) -> Result<HeliusWebhook> {
+        let response = self.http_client.post(self.url("/v0/webhooks")).json(webhook).send().await?;
+        let created: HeliusWebhook = Self::check(response).await?;
+        info!("🪝 Helius webhook {:?} registered for {} addresses",
+              created.webhook_id, created.account_addresses.len());
+        Ok(created)
+    }
+
+    pub async fn list_webhooks(&self) -> Result<Vec<HeliusWebhook>> {
+        let response = self.http_client.get(self.url("/v0/webhooks")).send().await?;
+        Self::check(response).await
+    }
+
+    pub async fn delete_webhook(&self, webhook_id: &str) -> Result<()> {
+        let response = self.http_client
+            .delete(self.url(&format!("/v0/webhooks/{}", webhook_id)))
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(anyhow!("Helius webhook delete failed: {}", response.status()));
+        }
+        Ok(())
+    }
+}
+
+/// Event pushed from a Helius webhook
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum HeliusEvent {
+    PoolCreated { signature: String, source: String, mints: Vec<String> },
+    LargeTransfer { signature: String, mint: String, amount: f64, from: Option<String>, to: Option<String> },
+    WalletActivity { signature: String, wallet: String, transaction_type: String },
+}
+
+/// Classification settings for webhook payloads
+#[derive(Debug, Clone)]
+pub struct HeliusWebhookConfig {
+    /// Expected `Authorization` header value (the webhook's `authHeader`)
+    pub auth_header: String,
+    /// Wallets whose activity is reported
+    pub watched_wallets: HashSet<String>,
+    /// Token amount at or above which a transfer is "large"
+    pub large_transfer_threshold: f64,
+    /// Lamports at or above which a SOL transfer is "large"
+    pub large_sol_transfer_lamports: u64,
+}
+
+impl Default for HeliusWebhookConfig {
+    fn default() -> Self {
+        Self {
+            auth_header: std::env::var("HELIUS_WEBHOOK_AUTH").unwrap_or_default(),
+            watched_wallets: HashSet::new(),
+            large_transfer_threshold: 1_000_000.0,
+            large_sol_transfer_lamports: 1_000 * 1_000_000_000,
+        }
+    }
+}
+
+/// Authenticates, classifies and fans out incoming Helius webhooks
+#[derive(Debug)]
+pub struct HeliusWebhookReceiver {
+    config: HeliusWebhookConfig,
+    events: broadcast::Sender<HeliusEvent>,
+}
+
+impl HeliusWebhookReceiver {
+    pub fn new(config: HeliusWebhookConfig) -> Self {
+        if config.auth_header.is_empty() {
+            warn!("⚠️ Helius webhook receiver has no auth header configured - all deliveries will be rejected");
+        }
+        let (events, _) = broadcast::channel(1024);
+        Self { config, events }
+    }
+
+    /// Subscribe to classified events
+    pub fn subscribe(&self) -> broadcast::Receiver<HeliusEvent> {
+        self.events.subscribe()
+    }
+
+    /// Constant-time check of the delivery's `Authorization` header
+    pub fn verify(&self, authorization: Option<&str>) -> bool {
+        let expected = self.config.auth_header.as_bytes();
+        let Some(provided) = authorization.map(str::as_bytes) else {
+            return false;
+        };
+        if expected.is_empty() || provided.len() != expected.len() {
+            return false;
+        }
+        provided.iter().zip(expected).fold(0u8, |diff, (a, b)| diff | (a ^ b)) == 0
+    }
+
+    /// Classify one transaction into zero or more events
+    pub fn classify(&self, tx: &EnhancedTransaction) -> Vec<HeliusEvent> {
+        let mut events = Vec::new();
+
+        if POOL_CREATION_TYPES.contains(&tx.transaction_type.as_str()) {
+            let mut mints: Vec<String> = tx.token_transfers.iter().map(|t| t.mint.clone()).collect();
+            mints.sort();
+            mints.dedup();
+            events.push(HeliusEvent::PoolCreated {
+                signature: tx.signature.clone(),
+                source: tx.source.clone(),
+                mints,
+            });
+        }
+
+        for transfer in tx.token_transfers.iter().filter(|t| t.token_amount >= self.config.large_transfer_threshold) {
+            events.push(HeliusEvent::LargeTransfer {
+                signature: tx.signature.clone(),
+                mint: transfer.mint.clone(),
+                amount: transfer.token_amount,
+                from: transfer.from_user_account.clone(),
+                to: transfer.to_user_account.clone(),
+            });
+        }
+        for transfer in tx.native_transfers.iter().filter(|t| t.amount >= self.config.large_sol_transfer_lamports) {
+            events.push(HeliusEvent::LargeTransfer {
+                signature: tx.signature.clone(),
+                mint: NATIVE_SOL_MINT.to_string(),
+                amount: transfer.amount as f64 / 1e9,
+                from: transfer.from_user_account.clone(),
+                to: transfer.to_user_account.clone(),
+            });
+        }
+
+        let mut involved: HashSet<&str> = HashSet::new();
+        involved.insert(tx.fee_payer.as_str());
+        for account in tx.token_transfers.iter().flat_map(|t| [&t.from_user_account, &t.to_user_account])
+            .chain(tx.native_transfers.iter().flat_map(|t| [&t.from_user_account, &t.to_user_account]))
+            .flatten()
+        {
+            involved.insert(account.as_str());
+        }
+        let mut wallets: Vec<&String> = self.config.watched_wallets.iter().filter(|w| involved.contains(w.as_str())).collect();
+        wallets.sort();
+        for wallet in wallets {
+            events.push(HeliusEvent::WalletActivity {
+                signature: tx.signature.clone(),
+                wallet: wallet.clone(),
+                transaction_type: tx.transaction_type.clone(),
+            });
+        }
+        events
+    }
+
+    /// Handle an authenticated delivery; returns the number of events published
+    pub fn ingest(&self, transactions: &[EnhancedTransaction]) -> usize {
+        let mut published = 0;
+        for tx in transactions {
+            for event in self.classify(tx) {
+                debug!("🪝 Helius event: {:?}", event);
+                // No subscribers is not an error - events are best-effort pushes
+                let _ = self.events.send(event);
+                published += 1;
+            }
+        }
+        published
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn receiver() -> HeliusWebhookReceiver {
+        HeliusWebhookReceiver::new(HeliusWebhookConfig {
+            auth_header: "Bearer s3cret".to_string(),
+            watched_wallets: ["MyWallet".to_string()].into_iter().collect(),
+            large_transfer_threshold: 1_000.0,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_verify_auth_header() {
+        let receiver = receiver();
+        assert!(receiver.verify(Some("Bearer s3cret")));
+        assert!(!receiver.verify(Some("Bearer s3creT")));
+        assert!(!receiver.verify(Some("Bearer")));
+        assert!(!receiver.verify(None));
+    }
+
+    #[test]
+    fn test_classify_webhook_payload() {
+        let payload = r#"[{
+            "signature": "sig1", "slot": 10, "timestamp": 1700000000, "type": "CREATE_POOL",
+            "source": "RAYDIUM", "fee": 5000, "feePayer": "Deployer",
+            "tokenTransfers": [
+                {"fromUserAccount": "Deployer", "toUserAccount": "Pool", "mint": "MintA", "tokenAmount": 5000.0},
+                {"fromUserAccount": "MyWallet", "toUserAccount": "Pool", "mint": "MintB", "tokenAmount": 10.0}
+            ],
+            "nativeTransfers": []
+        }]"#;
+        let transactions: Vec<EnhancedTransaction> = serde_json::from_str(payload).unwrap();
+        let events = receiver().classify(&transactions[0]);
+
+        assert!(matches!(&events[0], HeliusEvent::PoolCreated { mints, .. } if mints == &vec!["MintA".to_string(), "MintB".to_string()]));
+        assert!(matches!(&events[1], HeliusEvent::LargeTransfer { mint, .. } if mint == "MintA"));
+        assert!(matches!(&events[2], HeliusEvent::WalletActivity { wallet, .. } if wallet == "MyWallet"));
+        assert_eq!(events.len(), 3);
+    }
+}
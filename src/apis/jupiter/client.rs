@@ -7,6 +7,7 @@ use crate::apis::jupiter::config::JupiterApiConfig;
 use crate::apis::jupiter::types::{
     JupiterPriceResponse, JupiterQuoteResponse, QuoteRequest, JupiterQuote
 };
+use crate::monitoring::latency_heatmap::latency_heatmap;
 use anyhow::{anyhow, Result};
 use reqwest::Client;
 use std::sync::Arc;
@@ -73,7 +74,10 @@ impl JupiterClient {
 
         let mut attempt = 0;
         while attempt < self.config.max_retries {
-            match self.make_quote_request(&url, request).await {
+            let started = Instant::now();
+            let result = self.make_quote_request(&url, request).await;
+            latency_heatmap().record_venue("jupiter", "quote", started.elapsed(), result.is_ok());
+            match result {
                 Ok(response) => {
                     debug!("✅ Jupiter quote successful on attempt {}", attempt + 1);
                     return Ok(response);
@@ -129,6 +133,30 @@ impl JupiterClient {
         Err(anyhow!("Price request failed after all retries"))
     }
 
+    /// Quote receiving exactly `amount_out`, rejecting routes whose worst-case
+    /// input (after slippage) exceeds `max_amount_in`
+    pub async fn get_exact_out_quote(
+        &self,
+        input_mint: &str,
+        output_mint: &str,
+        amount_out: u64,
+        max_amount_in: u64,
+        slippage_bps: u16,
+    ) -> Result<JupiterQuoteResponse> {
+        let request = QuoteRequest::exact_out(input_mint.to_string(), output_mint.to_string(), amount_out)
+            .with_slippage_bps(slippage_bps);
+        let quote = self.get_quote(&request).await?;
+        quote
+            .validate_exact_out(amount_out, max_amount_in)
+            .map_err(|e| anyhow!("ExactOut quote rejected: {}", e))?;
+        debug!(
+            "✅ ExactOut quote: {} out for at most {} in",
+            amount_out,
+            quote.max_in_amount().unwrap_or_default()
+        );
+        Ok(quote)
+    }
+
     /// Get quote in legacy format for backward compatibility - ENHANCED
     pub async fn get_quote_legacy(&self, request: QuoteRequest) -> Result<JupiterQuote> {
         let response = self.get_quote(&request).await?;
@@ -262,7 +290,10 @@ impl JupiterClient {
 
         let mut attempt = 0;
         while attempt < self.config.max_retries {
-            match self.make_swap_request(&url, swap_request).await {
+            let started = Instant::now();
+            let result = self.make_swap_request(&url, swap_request).await;
+            latency_heatmap().record_venue("jupiter", "swap", started.elapsed(), result.is_ok());
+            match result {
                 Ok(response) => {
                     debug!("✅ Jupiter swap transaction successful on attempt {}", attempt + 1);
                     return Ok(response);
@@ -23,6 +23,7 @@ use tracing::{error, info, warn};
 
 use super::client::JupiterClient;
 use super::config::JupiterApiConfig;
+use super::native_sol::{NativeSolRoutes, NativeSolStats, SolEntry};
 use super::types::*;
 use crate::config::network::NetworkConfig;
 
@@ -84,12 +85,22 @@ pub struct TradingParameters {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WalletIntegrationConfig {
     pub auto_wrap_sol: bool,
+    /// Enter SOL routes natively through shared accounts where supported
+    #[serde(default = "default_native_sol_entry")]
+    pub native_sol_entry: bool,
+    /// AMM labels that always need WSOL wrapping
+    #[serde(default)]
+    pub wrapped_only_amms: Vec<String>,
     pub auto_create_ata: bool,
     pub verify_balance_before_swap: bool,
     pub min_sol_balance_lamports: u64,
     pub max_transaction_attempts: u32,
 }
 
+fn default_native_sol_entry() -> bool {
+    true
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonitoringConfig {
     pub log_requests: bool,
@@ -150,6 +161,7 @@ pub struct Jupiter {
     network_name: String,
     metrics: JupiterMetrics,
     rpc_client: Option<RpcClient>,
+    native_sol: NativeSolRoutes,
 }
 
 impl Jupiter {
@@ -206,6 +218,11 @@ impl Jupiter {
             network_config.program_ids.jupiter_program
         );
 
+        let native_sol = NativeSolRoutes::new(
+            config.wallet_integration.native_sol_entry,
+            config.wallet_integration.wrapped_only_amms.clone(),
+        );
+
         Ok(Self {
             client,
             config,
@@ -213,6 +230,7 @@ impl Jupiter {
             network_name,
             metrics: JupiterMetrics::default(),
             rpc_client: None,
+            native_sol,
         })
     }
 
@@ -309,10 +327,21 @@ impl Jupiter {
         // Apply network-specific parameters
         let network_config = self.get_network_config();
         
-        // Create swap request with enterprise features
-        let swap_request = self.build_swap_request(quote, user_public_key, network_config)?;
-
-        let result = self.client.get_swap_transaction(&swap_request).await;
+        // Native SOL entry where the route allows it, wrap/unwrap otherwise
+        let mut entry = self.native_sol.entry_for(quote);
+        let swap_request = self.build_swap_request(quote, user_public_key, network_config, entry)?;
+        let mut result = self.client.get_swap_transaction(&swap_request).await;
+        if let (SolEntry::Native, Err(e)) = (entry, &result) {
+            if NativeSolRoutes::is_native_rejection(&e.to_string()) {
+                self.native_sol.record_rejection(quote);
+                entry = SolEntry::Wrapped;
+                let swap_request = self.build_swap_request(quote, user_public_key, network_config, entry)?;
+                result = self.client.get_swap_transaction(&swap_request).await;
+            }
+        }
+        if result.is_ok() {
+            self.native_sol.record(entry);
+        }
 
         // Update metrics
         let response_time = start_time.elapsed();
@@ -403,6 +432,12 @@ impl Jupiter {
         let signers = vec![wallet];
         let signatures = signers.iter().map(|signer| signer.sign_message(&message.serialize())).collect();
         versioned_transaction.signatures = signatures;
+        if let Some(signature) = versioned_transaction.signatures.first() {
+            crate::security::wallet_activity::intent_store().record(&signature.to_string());
+        }
+        // Retries below resend this same signed message, which can only land once
+        crate::trading::execution::admit_shared(&wallet.pubkey().to_string(), None, &versioned_transaction)
+            .context("Replay guard refused swap transaction")?;
 
         let max_attempts = self.config.wallet_integration.max_transaction_attempts;
         let mut last_error = None;
@@ -512,6 +547,11 @@ impl Jupiter {
         &self.metrics
     }
 
+    /// Native vs wrapped SOL entry counts
+    pub fn native_sol_stats(&self) -> NativeSolStats {
+        self.native_sol.stats()
+    }
+
     /// Get network name - ENTERPRISE ACCESSOR
     pub fn get_network_name(&self) -> &str {
         &self.network_name
@@ -620,11 +660,13 @@ impl Jupiter {
         quote: &JupiterQuoteResponse,
         user_public_key: &Pubkey,
         network_config: &NetworkJupiterConfig,
+        sol_entry: SolEntry,
     ) -> Result<SwapRequest> {
         Ok(SwapRequest {
             quote_response: quote.clone(),
             user_public_key: user_public_key.to_string(),
-            wrap_and_unwrap_sol: self.config.wallet_integration.auto_wrap_sol,
+            wrap_and_unwrap_sol: self.config.wallet_integration.auto_wrap_sol || sol_entry == SolEntry::Native,
+            use_shared_accounts: (sol_entry == SolEntry::Native).then_some(true),
             compute_unit_price_micro_lamports: Some(network_config.priority_fee_lamports),
             auto_create_account_associated_tokens: self.config.wallet_integration.auto_create_ata,
             dynamic_compute_unit_limit: true,
@@ -657,6 +699,9 @@ pub struct SwapRequest {
     pub user_public_key: String,
     #[serde(rename = "wrapAndUnwrapSol")]
     pub wrap_and_unwrap_sol: bool,
+    /// Route through Jupiter's shared accounts (native SOL entry)
+    #[serde(rename = "useSharedAccounts", skip_serializing_if = "Option::is_none")]
+    pub use_shared_accounts: Option<bool>,
     #[serde(rename = "computeUnitPriceMicroLamports")]
     pub compute_unit_price_micro_lamports: Option<u64>,
     #[serde(rename = "autoCreateAccountAssociatedTokens")]
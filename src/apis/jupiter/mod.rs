@@ -7,6 +7,7 @@ pub mod config;
 pub mod types;
 pub mod client;
 pub mod jupiter;
+pub mod native_sol;
 
 // Re-export main types and structs for easy access
 pub use config::{JupiterApiConfig, JupiterSimpleConfig};
@@ -14,12 +15,13 @@ pub use types::{
     // Price API
     JupiterPriceResponse, TokenPriceData,
     // Quote API  
-    QuoteRequest, JupiterQuoteResponse, JupiterQuote,
+    QuoteRequest, JupiterQuoteResponse, JupiterQuote, SwapMode,
     PlatformFee, RoutePlan, SwapInfo,
     // DEX and common types
     DexLabel, tokens,
 };
 pub use client::JupiterClient;
+pub use native_sol::{NativeSolRoutes, NativeSolStats, SolEntry};
 pub use jupiter::{
     Jupiter, JupiterBuilder, JupiterConfigFile, 
     JupiterMetrics, NetworkJupiterConfig, SwapRequest
@@ -0,0 +1,170 @@
+//! Native SOL entry for Jupiter swaps
+//!
+//! With `wrapAndUnwrapSol` alone, every SOL-in or SOL-out swap creates a
+//! temporary WSOL token account and closes it again. Routes that can run
+//! through Jupiter's shared program accounts take native SOL directly and skip
+//! that churn (one less account rent round-trip and fewer instructions).
+//!
+//! Not every route supports shared accounts: some AMMs reject them, in which
+//! case the swap falls back to wrap/unwrap. Known offenders are configured up
+//! front; route shapes Jupiter rejects at runtime are learned so the next
+//! swap over the same route goes straight to the wrapped path.
+
+use std::collections::HashSet;
+
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use super::types::{tokens, JupiterQuoteResponse};
+
+/// How SOL enters or leaves a swap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SolEntry {
+    /// Native SOL through shared accounts, no user WSOL account
+    Native,
+    /// Temporary WSOL account wrapped and closed around the swap
+    Wrapped,
+}
+
+/// Native vs wrapped swap counts
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NativeSolStats {
+    pub native: u64,
+    pub wrapped: u64,
+    /// Native attempts Jupiter rejected that were retried wrapped
+    pub fallbacks: u64,
+}
+
+/// Decides per route whether to enter with native SOL
+#[derive(Debug)]
+pub struct NativeSolRoutes {
+    enabled: bool,
+    /// AMM labels known not to support shared accounts
+    wrapped_only_amms: HashSet<String>,
+    /// Route shapes rejected at runtime
+    rejected_routes: RwLock<HashSet<String>>,
+    stats: Mutex<NativeSolStats>,
+}
+
+impl Default for NativeSolRoutes {
+    fn default() -> Self {
+        Self::new(true, std::iter::empty::<String>())
+    }
+}
+
+impl NativeSolRoutes {
+    pub fn new(enabled: bool, wrapped_only_amms: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            enabled,
+            wrapped_only_amms: wrapped_only_amms.into_iter().map(Into::into).collect(),
+            rejected_routes: RwLock::new(HashSet::new()),
+            stats: Mutex::new(NativeSolStats::default()),
+        }
+    }
+
+    /// AMM labels along the route, e.g. `Orca>Raydium`
+    fn route_shape(quote: &JupiterQuoteResponse) -> String {
+        quote.route_plan.iter().map(|step| step.swap_info.label.as_str()).collect::<Vec<_>>().join(">")
+    }
+
+    /// Whether the route can skip WSOL wrapping
+    pub fn supports_native(&self, quote: &JupiterQuoteResponse) -> bool {
+        self.enabled
+            && (quote.input_mint == tokens::SOL || quote.output_mint == tokens::SOL)
+            && !quote.route_plan.iter().any(|step| self.wrapped_only_amms.contains(&step.swap_info.label))
+            && !self.rejected_routes.read().contains(&Self::route_shape(quote))
+    }
+
+    pub fn entry_for(&self, quote: &JupiterQuoteResponse) -> SolEntry {
+        if self.supports_native(quote) { SolEntry::Native } else { SolEntry::Wrapped }
+    }
+
+    /// Whether a swap-transaction error means the route cannot use shared accounts
+    pub fn is_native_rejection(error: &str) -> bool {
+        let error = error.to_ascii_lowercase();
+        error.contains("shared accounts") || error.contains("sharedaccounts")
+    }
+
+    /// Remember that Jupiter refused native entry for this route
+    pub fn record_rejection(&self, quote: &JupiterQuoteResponse) {
+        let shape = Self::route_shape(quote);
+        info!("🪙 Route {} needs WSOL wrapping - falling back and remembering it", shape);
+        self.rejected_routes.write().insert(shape);
+        self.stats.lock().fallbacks += 1;
+    }
+
+    /// Count a swap built with `entry`
+    pub fn record(&self, entry: SolEntry) {
+        debug!("🪙 Swap built with {:?} SOL entry", entry);
+        let mut stats = self.stats.lock();
+        match entry {
+            SolEntry::Native => stats.native += 1,
+            SolEntry::Wrapped => stats.wrapped += 1,
+        }
+    }
+
+    pub fn stats(&self) -> NativeSolStats {
+        self.stats.lock().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apis::jupiter::types::{RoutePlan, SwapInfo};
+
+    fn quote(input_mint: &str, output_mint: &str, labels: &[&str]) -> JupiterQuoteResponse {
+        JupiterQuoteResponse {
+            input_mint: input_mint.to_string(),
+            in_amount: "1000000000".to_string(),
+            output_mint: output_mint.to_string(),
+            out_amount: "150000000".to_string(),
+            other_amount_threshold: "149000000".to_string(),
+            swap_mode: "ExactIn".to_string(),
+            slippage_bps: 50,
+            platform_fee: None,
+            price_impact_pct: "0.01".to_string(),
+            route_plan: labels.iter().map(|label| RoutePlan {
+                swap_info: SwapInfo {
+                    amm_key: "amm".to_string(),
+                    label: label.to_string(),
+                    input_mint: input_mint.to_string(),
+                    output_mint: output_mint.to_string(),
+                    in_amount: "1000000000".to_string(),
+                    out_amount: "150000000".to_string(),
+                    fee_amount: "0".to_string(),
+                    fee_mint: input_mint.to_string(),
+                    price_impact_pct: None,
+                },
+                percent: 100,
+            }).collect(),
+            context_slot: None,
+            time_taken: None,
+        }
+    }
+
+    #[test]
+    fn test_native_only_for_sol_routes_without_wrapped_only_amms() {
+        let routes = NativeSolRoutes::new(true, ["Phoenix"]);
+        assert_eq!(routes.entry_for(&quote(tokens::SOL, tokens::USDC, &["Orca"])), SolEntry::Native);
+        assert_eq!(routes.entry_for(&quote(tokens::USDC, tokens::SOL, &["Raydium"])), SolEntry::Native);
+        assert_eq!(routes.entry_for(&quote(tokens::SOL, tokens::USDC, &["Orca", "Phoenix"])), SolEntry::Wrapped);
+        assert_eq!(routes.entry_for(&quote(tokens::USDC, tokens::USDT, &["Orca"])), SolEntry::Wrapped);
+        assert_eq!(NativeSolRoutes::new(false, Vec::<String>::new()).entry_for(&quote(tokens::SOL, tokens::USDC, &["Orca"])), SolEntry::Wrapped);
+    }
+
+    #[test]
+    fn test_rejected_route_falls_back_to_wrapping() {
+        let routes = NativeSolRoutes::default();
+        let rejected = quote(tokens::SOL, tokens::USDC, &["Obric V2"]);
+        assert!(NativeSolRoutes::is_native_rejection("Simple AMMs are not supported with shared accounts"));
+        assert!(!NativeSolRoutes::is_native_rejection("slippage tolerance exceeded"));
+
+        routes.record_rejection(&rejected);
+        assert_eq!(routes.entry_for(&rejected), SolEntry::Wrapped);
+        // Other routes keep native entry
+        assert_eq!(routes.entry_for(&quote(tokens::SOL, tokens::USDC, &["Orca"])), SolEntry::Native);
+        assert_eq!(routes.stats().fallbacks, 1);
+    }
+}
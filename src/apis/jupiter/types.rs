@@ -44,6 +44,31 @@ impl TokenPriceData {
 // QUOTE API TYPES
 // =============================================================================
 
+/// Which side of the swap `amount` fixes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SwapMode {
+    /// Spend exactly `amount` of the input token
+    #[default]
+    ExactIn,
+    /// Receive exactly `amount` of the output token
+    ExactOut,
+}
+
+impl SwapMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SwapMode::ExactIn => "ExactIn",
+            SwapMode::ExactOut => "ExactOut",
+        }
+    }
+}
+
+impl std::fmt::Display for SwapMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Jupiter V6 Quote Request - ENHANCED VERSION
 #[derive(Debug, Serialize, Clone, Default)]
 pub struct QuoteRequest {
@@ -86,6 +111,25 @@ impl QuoteRequest {
         }
     }
 
+    /// Quote for receiving exactly `amount_out` of `output_mint`
+    pub fn exact_out(input_mint: String, output_mint: String, amount_out: u64) -> Self {
+        Self::new(input_mint, output_mint, amount_out).with_swap_mode(SwapMode::ExactOut)
+    }
+
+    /// Set which side `amount` refers to
+    pub fn with_swap_mode(mut self, swap_mode: SwapMode) -> Self {
+        self.swap_mode = Some(swap_mode.as_str().to_string());
+        self
+    }
+
+    /// Requested swap mode (Jupiter defaults to ExactIn)
+    pub fn mode(&self) -> SwapMode {
+        match self.swap_mode.as_deref() {
+            Some("ExactOut") => SwapMode::ExactOut,
+            _ => SwapMode::ExactIn,
+        }
+    }
+
     /// Set slippage in basis points
     pub fn with_slippage_bps(mut self, slippage_bps: u16) -> Self {
         self.slippage_bps = Some(slippage_bps);
@@ -134,6 +178,48 @@ pub struct JupiterQuoteResponse {
     pub time_taken: Option<f64>,
 }
 
+impl JupiterQuoteResponse {
+    pub fn mode(&self) -> SwapMode {
+        if self.swap_mode == "ExactOut" { SwapMode::ExactOut } else { SwapMode::ExactIn }
+    }
+
+    pub fn in_amount_u64(&self) -> Result<u64, std::num::ParseIntError> {
+        self.in_amount.parse()
+    }
+
+    pub fn out_amount_u64(&self) -> Result<u64, std::num::ParseIntError> {
+        self.out_amount.parse()
+    }
+
+    /// Worst-case input after slippage for an ExactOut quote
+    ///
+    /// For ExactOut, `otherAmountThreshold` is the most input the swap may
+    /// consume; for ExactIn it is the minimum output instead.
+    pub fn max_in_amount(&self) -> Option<u64> {
+        match self.mode() {
+            SwapMode::ExactOut => self.other_amount_threshold.parse().ok(),
+            SwapMode::ExactIn => self.in_amount_u64().ok(),
+        }
+    }
+
+    /// Check an ExactOut quote delivers `amount_out` without spending more than `max_amount_in`
+    pub fn validate_exact_out(&self, amount_out: u64, max_amount_in: u64) -> Result<(), String> {
+        if self.mode() != SwapMode::ExactOut {
+            return Err(format!("expected ExactOut quote, got {}", self.swap_mode));
+        }
+        let out = self.out_amount_u64().map_err(|e| format!("invalid outAmount {}: {}", self.out_amount, e))?;
+        if out < amount_out {
+            return Err(format!("quote delivers {} < requested {}", out, amount_out));
+        }
+        let max_in = self.max_in_amount()
+            .ok_or_else(|| format!("invalid otherAmountThreshold {}", self.other_amount_threshold))?;
+        if max_in > max_amount_in {
+            return Err(format!("worst-case input {} exceeds bound {}", max_in, max_amount_in));
+        }
+        Ok(())
+    }
+}
+
 /// Platform fee information
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct PlatformFee {
@@ -244,3 +330,47 @@ pub mod tokens {
     pub const ORCA: &str = "orcaEKTdK7LKz57vaAYr9QeNsVEPfiu6QeMU1kektZE";
     pub const MNGO: &str = "MangoCzJ36AjZyKwVj3VnYU4GTonjfVEnJmvvWaxLac";
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exact_out_quote(in_amount: u64, out_amount: u64, max_in: u64) -> JupiterQuoteResponse {
+        JupiterQuoteResponse {
+            input_mint: tokens::SOL.to_string(),
+            in_amount: in_amount.to_string(),
+            output_mint: tokens::USDC.to_string(),
+            out_amount: out_amount.to_string(),
+            other_amount_threshold: max_in.to_string(),
+            swap_mode: SwapMode::ExactOut.to_string(),
+            slippage_bps: 50,
+            platform_fee: None,
+            price_impact_pct: "0".to_string(),
+            route_plan: Vec::new(),
+            context_slot: None,
+            time_taken: None,
+        }
+    }
+
+    #[test]
+    fn test_exact_out_request_sets_mode() {
+        let request = QuoteRequest::exact_out(tokens::SOL.to_string(), tokens::USDC.to_string(), 1_000_000_000);
+        assert_eq!(request.mode(), SwapMode::ExactOut);
+        assert_eq!(request.swap_mode.as_deref(), Some("ExactOut"));
+        assert_eq!(QuoteRequest::new(String::new(), String::new(), 1).mode(), SwapMode::ExactIn);
+    }
+
+    #[test]
+    fn test_exact_out_validation_bounds_input() {
+        // 1000 USDC for ~6.6 SOL, worst case 6.7 SOL after slippage
+        let quote = exact_out_quote(6_600_000_000, 1_000_000_000, 6_700_000_000);
+        assert_eq!(quote.max_in_amount(), Some(6_700_000_000));
+        assert!(quote.validate_exact_out(1_000_000_000, 7_000_000_000).is_ok());
+        assert!(quote.validate_exact_out(1_000_000_000, 6_650_000_000).is_err());
+        assert!(quote.validate_exact_out(1_000_000_001, 7_000_000_000).is_err());
+
+        let mut exact_in = quote.clone();
+        exact_in.swap_mode = SwapMode::ExactIn.to_string();
+        assert!(exact_in.validate_exact_out(1_000_000_000, 7_000_000_000).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+//! Epoch leader schedule
+//!
+//! `getLeaderSchedule` returns, per validator identity, the slot indices
+//! (relative to the epoch's first slot) it leads. This inverts that into a
+//! slot -> leader map for the current epoch so submission code can ask who
+//! produces the block it is about to target.
+
+use anyhow::{anyhow, Result};
+use parking_lot::RwLock;
+use solana_client::rpc_client::RpcClient;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// Leader per slot for one epoch
+#[derive(Debug, Clone, Default)]
+pub struct LeaderSchedule {
+    pub epoch: u64,
+    pub first_slot: u64,
+    pub slots_in_epoch: u64,
+    leaders: HashMap<u64, Arc<str>>,
+}
+
+impl LeaderSchedule {
+    /// Build from the RPC `identity -> [slot index]` form
+    pub fn from_rpc(epoch: u64, first_slot: u64, slots_in_epoch: u64, schedule: HashMap<String, Vec<usize>>) -> Self {
+        let mut leaders = HashMap::with_capacity(slots_in_epoch as usize);
+        for (identity, indices) in schedule {
+            let identity: Arc<str> = identity.into();
+            for index in indices {
+                leaders.insert(first_slot + index as u64, identity.clone());
+            }
+        }
+        Self { epoch, first_slot, slots_in_epoch, leaders }
+    }
+
+    /// Fetch the schedule for the epoch containing the cluster's current slot
+    pub fn fetch(client: &RpcClient) -> Result<Self> {
+        let epoch_info = client.get_epoch_info()?;
+        let first_slot = epoch_info.absolute_slot - epoch_info.slot_index;
+        let schedule = client
+            .get_leader_schedule(Some(epoch_info.absolute_slot))?
+            .ok_or_else(|| anyhow!("no leader schedule for epoch {}", epoch_info.epoch))?;
+        let schedule = Self::from_rpc(epoch_info.epoch, first_slot, epoch_info.slots_in_epoch, schedule);
+        info!("📅 Leader schedule for epoch {} loaded ({} slots)", schedule.epoch, schedule.leaders.len());
+        Ok(schedule)
+    }
+
+    pub fn covers(&self, slot: u64) -> bool {
+        slot >= self.first_slot && slot < self.first_slot + self.slots_in_epoch
+    }
+
+    pub fn leader_at(&self, slot: u64) -> Option<&str> {
+        self.leaders.get(&slot).map(|leader| leader.as_ref())
+    }
+
+    /// Leaders of the next `count` slots starting at `from`
+    pub fn upcoming(&self, from: u64, count: u64) -> Vec<(u64, &str)> {
+        (from..from + count).filter_map(|slot| self.leader_at(slot).map(|leader| (slot, leader))).collect()
+    }
+}
+
+/// Current-epoch schedule, refreshed when a slot past its end is requested
+#[derive(Debug)]
+pub struct LeaderScheduleCache {
+    rpc_url: String,
+    schedule: RwLock<Option<Arc<LeaderSchedule>>>,
+}
+
+impl LeaderScheduleCache {
+    pub fn new(rpc_url: impl Into<String>) -> Self {
+        Self { rpc_url: rpc_url.into(), schedule: RwLock::new(None) }
+    }
+
+    pub fn current(&self) -> Option<Arc<LeaderSchedule>> {
+        self.schedule.read().clone()
+    }
+
+    pub fn set(&self, schedule: LeaderSchedule) {
+        *self.schedule.write() = Some(Arc::new(schedule));
+    }
+
+    /// Leader of `slot` from the cached schedule (no RPC)
+    pub fn leader_at(&self, slot: u64) -> Option<String> {
+        self.schedule.read().as_ref()?.leader_at(slot).map(str::to_string)
+    }
+
+    /// Re-fetch unless the cached schedule already covers `slot`
+    pub async fn refresh_for(&self, slot: u64) -> Result<Arc<LeaderSchedule>> {
+        if let Some(schedule) = self.current().filter(|schedule| schedule.covers(slot)) {
+            return Ok(schedule);
+        }
+        debug!("📅 Refreshing leader schedule for slot {}", slot);
+        let rpc_url = self.rpc_url.clone();
+        let schedule = tokio::task::spawn_blocking(move || LeaderSchedule::fetch(&RpcClient::new(rpc_url))).await??;
+        let schedule = Arc::new(schedule);
+        *self.schedule.write() = Some(schedule.clone());
+        Ok(schedule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rpc_schedule_is_inverted_to_absolute_slots() {
+        let rpc = HashMap::from([
+            ("LeaderA".to_string(), vec![0, 1, 2, 3]),
+            ("LeaderB".to_string(), vec![4, 5, 6, 7]),
+        ]);
+        let schedule = LeaderSchedule::from_rpc(700, 302_400_000, 8, rpc);
+
+        assert_eq!(schedule.leader_at(302_400_002), Some("LeaderA"));
+        assert_eq!(schedule.leader_at(302_400_004), Some("LeaderB"));
+        assert_eq!(schedule.leader_at(302_400_008), None);
+        assert!(!schedule.covers(302_400_008));
+        assert_eq!(
+            schedule.upcoming(302_400_003, 3),
+            vec![(302_400_003, "LeaderA"), (302_400_004, "LeaderB"), (302_400_005, "LeaderB")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cache_serves_covered_slots_without_rpc() {
+        // Unroutable URL: any fetch attempt would fail the test
+        let cache = LeaderScheduleCache::new("http://127.0.0.1:1");
+        cache.set(LeaderSchedule::from_rpc(1, 100, 10, HashMap::from([("L".to_string(), vec![0, 5])])));
+
+        let schedule = cache.refresh_for(105).await.unwrap();
+        assert_eq!(schedule.epoch, 1);
+        assert_eq!(cache.leader_at(105).as_deref(), Some("L"));
+        assert!(cache.refresh_for(110).await.is_err());
+    }
+}
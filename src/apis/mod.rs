@@ -10,6 +10,14 @@ pub mod stablecoin_monitor; // ✅ NEW: Real stablecoin monitoring
 pub mod rpc; // ✅ NEW: Enterprise RPC pool management
 // pub mod raydium;
 pub mod rate_limiter;
+pub mod fiat_rates; // ✅ NEW: Fiat exchange rates for USD reporting
+pub mod price_cache; // Shared price cache (in-memory or Redis)
+pub mod helius; // Helius enhanced API + webhooks
+pub mod circuit_breaker; // Per-provider circuit breakers
+pub mod rpc_usage; // RPC request/credit accounting per provider
+pub mod program_registry; // Program ID -> versioned account decoders
+pub mod stream_sync; // Stream gap detection, snapshot resync, feed status
+pub mod leader_schedule; // Epoch slot -> leader identity map
 // pub mod solana_rpc;
 // pub mod traits;
 
@@ -20,5 +28,15 @@ pub use real_price_feeds::*;
 pub use multi_price_feeds::*;
 pub use stablecoin_monitor::*; // ✅ Export stablecoin monitor
 pub use rpc::{RpcPool, RpcPoolConfig, RpcEndpointHealth};
+pub use fiat_rates::{fiat_rates, FiatRateService, FiatRateConfig, FiatAsset, FiatRate, FiatRateProvider, UsdConversion};
+pub use price_cache::{PriceCache, InMemoryPriceCache, CachedPrice, price_cache_from_env, PRICE_CACHE_URL_ENV};
+#[cfg(feature = "redis")]
+pub use price_cache::RedisPriceCache;
+pub use helius::{HeliusClient, HeliusWebhook, HeliusWebhookConfig, HeliusWebhookReceiver, HeliusEvent, EnhancedTransaction};
+pub use rpc_usage::{RpcUsageTracker, RpcCostModel, DailyRpcUsage, RpcUsageReport, UsageProjection, rpc_usage, provider_for_url};
+pub use program_registry::{ProgramRegistry, DecoderVersion, DecodedAccount, DecodeError, AccountDecoder};
+pub use stream_sync::{StreamSync, SequenceMode, FeedStatus, FeedHealth, StreamSyncStats, SnapshotSource, feed_health};
+pub use leader_schedule::{LeaderSchedule, LeaderScheduleCache};
+pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState, CircuitSnapshot, CircuitOpenError, ProviderCircuits, provider_circuits};
 // pub use solana_rpc::*;
 // pub use traits::*;
@@ -5,28 +5,24 @@ use anyhow::{anyhow, Result};
 use tracing::{debug, info, warn};
 use serde::Deserialize;
 use std::collections::HashMap;
-use std::time::{Duration, Instant};
+use std::sync::Arc;
+use std::time::Duration;
 use tokio::time::sleep;
 use reqwest::Client;
 use crate::config::ApiCredentials;
-use rand::Rng;
+use crate::apis::fiat_rates::{fiat_rates, FiatAsset, FiatRateService};
+use crate::apis::price_cache::{CachedPrice, InMemoryPriceCache, PriceCache};
+use crate::apis::circuit_breaker::provider_circuits;
+
+/// Agregadores off-chain; con todos sus circuitos abiertos se usa solo precio on-chain
+const OFF_CHAIN_PROVIDERS: &[&str] = &["jupiter", "dexscreener"];
 
 #[derive(Debug, Clone)]
 pub struct MultiPriceFeeds {
     http_client: Client,
-    last_helius_request: Instant,
-    last_jupiter_request: Instant,
-    last_dexscreener_request: Instant,
-    last_pyth_request: Instant,
-    price_cache: HashMap<String, CachedPrice>,
+    price_cache: Arc<dyn PriceCache>,
     api_credentials: ApiCredentials,
-}
-
-#[derive(Debug, Clone)]
-struct CachedPrice {
-    price: f64,
-    timestamp: Instant,
-    source: String,
+    fiat_rates: Arc<FiatRateService>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -101,79 +97,90 @@ impl MultiPriceFeeds {
             .build()
             .expect("Failed to create HTTP client");
 
-        let now = Instant::now() - Duration::from_secs(60);
-
         info!("🔧 Inicializando MultiPriceFeeds con credenciales: {}", 
               api_credentials.get_config_summary());
 
         Self {
             http_client,
-            last_helius_request: now,
-            last_jupiter_request: now,
-            last_dexscreener_request: now,
-            last_pyth_request: now,
-            price_cache: HashMap::new(),
+            price_cache: Arc::new(InMemoryPriceCache::new()),
             api_credentials,
+            fiat_rates: fiat_rates(),
         }
     }
 
+    /// Compartir cache de precios y presupuestos de rate limit (ej. Redis entre procesos)
+    pub fn with_price_cache(mut self, price_cache: Arc<dyn PriceCache>) -> Self {
+        info!("💾 MultiPriceFeeds usando cache de precios: {}", price_cache.name());
+        self.price_cache = price_cache;
+        self
+    }
+
     /// Obtener precio con failover automático entre múltiples proveedores
     pub async fn get_token_price(&self, token_symbol: &str) -> Result<f64> {
         // Verificar cache primero (válido por 30 segundos)
-        if let Some(cached) = self.price_cache.get(token_symbol) {
-            if cached.timestamp.elapsed() < Duration::from_secs(30) {
-                debug!("💾 Cache hit for {}: ${:.6} from {}", token_symbol, cached.price, cached.source);
+        match self.price_cache.get(token_symbol).await {
+            Ok(Some(cached)) => {
+                debug!("💾 Cache hit for {}: ${:.6} from {} ({:.1}s old)",
+                       token_symbol, cached.price, cached.source, cached.age().as_secs_f64());
                 return Ok(cached.price);
             }
+            Ok(None) => {}
+            Err(e) => warn!("⚠️ Cache de precios no disponible ({}): {}", self.price_cache.name(), e),
         }
 
-        // Intentar Helius primero (mejor para Solana)
-        match self.fetch_price_from_helius(token_symbol).await {
-            Ok(price) => {
-                // TODO: self.cache_price(token_symbol, price, "Helius");
-                return Ok(price);
+        // Modo degradado: con los agregadores off-chain caídos, solo fuentes on-chain (oráculo Pyth)
+        let on_chain_only = OFF_CHAIN_PROVIDERS.iter().all(|p| provider_circuits().breaker(p).is_open());
+        if on_chain_only {
+            warn!("🔌 Circuitos abiertos para {:?} - precio solo on-chain para {}", OFF_CHAIN_PROVIDERS, token_symbol);
+        } else {
+            // Intentar Helius primero (mejor para Solana)
+            match self.guarded("helius", || self.fetch_price_from_helius(token_symbol)).await {
+                Ok(price) => {
+                    self.cache_price(token_symbol, price, "Helius").await;
+                    return Ok(price);
+                }
+                Err(e) => warn!("⚠️ Helius falló para {}: {}", token_symbol, e),
             }
-            Err(e) => warn!("⚠️ Helius falló para {}: {}", token_symbol, e),
-        }
 
-        // Fallback a Jupiter (solo si no es error de rate limiting)
-        match self.fetch_price_from_jupiter(token_symbol).await {
-            Ok(price) => {
-                // TODO: self.cache_price(token_symbol, price, "Jupiter");
-                return Ok(price);
-            }
-            Err(e) => {
-                if e.to_string().contains("rate limit") {
-                    warn!("⚠️ Jupiter rate limited, saltando a siguiente provider");
-                } else {
-                    warn!("⚠️ Jupiter falló para {}: {}", token_symbol, e);
+            // Fallback a Jupiter (solo si no es error de rate limiting)
+            match self.guarded("jupiter", || self.fetch_price_from_jupiter(token_symbol)).await {
+                Ok(price) => {
+                    self.cache_price(token_symbol, price, "Jupiter").await;
+                    return Ok(price);
+                }
+                Err(e) => {
+                    if e.to_string().contains("rate limit") {
+                        warn!("⚠️ Jupiter rate limited, saltando a siguiente provider");
+                    } else {
+                        warn!("⚠️ Jupiter falló para {}: {}", token_symbol, e);
+                    }
                 }
             }
-        }
 
-        // Fallback a DexScreener
-        match self.fetch_price_from_dexscreener(token_symbol).await {
-            Ok(price) => {
-                // TODO: self.cache_price(token_symbol, price, "DexScreener");
-                return Ok(price);
+            // Fallback a DexScreener
+            match self.guarded("dexscreener", || self.fetch_price_from_dexscreener(token_symbol)).await {
+                Ok(price) => {
+                    self.cache_price(token_symbol, price, "DexScreener").await;
+                    return Ok(price);
+                }
+                Err(e) => warn!("⚠️ DexScreener falló para {}: {}", token_symbol, e),
             }
-            Err(e) => warn!("⚠️ DexScreener falló para {}: {}", token_symbol, e),
         }
 
         // Fallback a Pyth Network
-        match self.fetch_price_from_pyth(token_symbol).await {
+        match self.guarded("pyth", || self.fetch_price_from_pyth(token_symbol)).await {
             Ok(price) => {
-                // TODO: self.cache_price(token_symbol, price, "Pyth");
+                self.cache_price(token_symbol, price, "Pyth").await;
                 return Ok(price);
             }
             Err(e) => warn!("⚠️ Pyth falló para {}: {}", token_symbol, e),
         }
 
-        // Último fallback: precio simulado basado en configuración
-        match self.simulate_helius_price(token_symbol) {
+        // Último fallback: tasa fiat compartida o stablecoin configurada
+        match self.fallback_price_result(token_symbol).await {
             Ok(price) => {
                 warn!("📊 Usando precio fallback para {}: ${:.4}", token_symbol, price);
-                // TODO: self.cache_price(token_symbol, price, "Fallback");
+                self.cache_price(token_symbol, price, "Fallback").await;
                 return Ok(price);
             }
             Err(e) => warn!("⚠️ Fallback price falló para {}: {}", token_symbol, e),
@@ -191,7 +198,7 @@ impl MultiPriceFeeds {
             Ok(helius_prices) => {
                 for (token, price) in helius_prices {
                     prices.insert(token.clone(), price);
-                    // TODO: self.cache_price(&token, price, "Helius");
+                    self.cache_price(&token, price, "Helius").await;
                 }
             }
             Err(e) => warn!("⚠️ Helius batch falló: {}", e),
@@ -215,11 +222,7 @@ impl MultiPriceFeeds {
     async fn fetch_price_from_helius(&self, token_symbol: &str) -> Result<f64> {
         // Rate limiting usando configuración centralizada
         let rate_limit = Duration::from_millis(self.api_credentials.get_rate_limit("helius"));
-        let elapsed = self.last_helius_request.elapsed();
-        if elapsed < rate_limit {
-            sleep(rate_limit - elapsed).await;
-        }
-        // TODO: self.last_helius_request = Instant::now();
+        self.pace_provider("helius", rate_limit).await;
 
         let token_mint = self.get_token_mint(token_symbol)?;
         
@@ -255,11 +258,11 @@ impl MultiPriceFeeds {
             // Si obtenemos datos del asset, usar Jupiter como oracle de precios
             if json_response["result"].is_object() {
                 info!("✅ Helius confirmó asset {}, obteniendo precio via Jupiter", token_symbol);
-                return self.fetch_price_from_jupiter(token_symbol).await;
+                return self.guarded("jupiter", || self.fetch_price_from_jupiter(token_symbol)).await;
             }
-            // Fallback a precio simulado si no hay datos del asset
+            // Fallback a tasa fiat/stablecoin si no hay datos del asset
             warn!("⚠️ Asset {} no encontrado en Helius, usando precio fallback", token_symbol);
-            return Ok(self.simulate_helius_price(token_symbol)?);
+            return self.fallback_price_result(token_symbol).await;
         }
         Err(anyhow!("Helius API error: {}", response.status()))
     }
@@ -268,11 +271,7 @@ impl MultiPriceFeeds {
     async fn fetch_price_from_jupiter(&self, token_symbol: &str) -> Result<f64> {
         // Rate limiting más conservador para Jupiter (60 req/min = 1 req/segundo)
         let rate_limit = Duration::from_millis(self.api_credentials.get_rate_limit("jupiter").max(1100)); // Mínimo 1.1 segundos
-        let elapsed = self.last_jupiter_request.elapsed();
-        if elapsed < rate_limit {
-            sleep(rate_limit - elapsed).await;
-        }
-        // TODO: self.last_jupiter_request = Instant::now();
+        self.pace_provider("jupiter", rate_limit).await;
 
         // ✅ OBTENER PRECIOS REALES DE STABLECOINS - NO HARDCODE
         // Los stablecoins fluctúan y estas fluctuaciones son oportunidades de arbitraje
@@ -297,8 +296,8 @@ impl MultiPriceFeeds {
                                     // Convertir a precio real vs SOL
                                     let sol_per_stablecoin = out_amount_u64 as f64 / 1_000_000_000.0; // 9 decimales SOL
                                     
-                                    // Para evitar recursión, usar precio aproximado de SOL
-                                    let sol_price_usd = 180.0; // Precio aproximado para cálculo
+                                    // SOL/USD desde el servicio de tasas fiat (evita recursión)
+                                    let sol_price_usd = self.fiat_rates.get_rate(FiatAsset::Sol).await?.usd;
                                     let stablecoin_price_usd = sol_per_stablecoin * sol_price_usd;
                                     return Ok(stablecoin_price_usd);
                                 }
@@ -371,11 +370,7 @@ impl MultiPriceFeeds {
 
     /// Obtener precio desde DexScreener (backup universal)
     async fn fetch_price_from_dexscreener(&self, token_symbol: &str) -> Result<f64> {
-        let elapsed = self.last_dexscreener_request.elapsed();
-        if elapsed < Duration::from_millis(500) {
-            sleep(Duration::from_millis(500) - elapsed).await;
-        }
-        // TODO: self.last_dexscreener_request = Instant::now();
+        self.pace_provider("dexscreener", Duration::from_millis(500)).await;
 
         let token_mint = self.get_token_mint(token_symbol)?;
         let url = format!("https://api.dexscreener.com/latest/dex/tokens/{}", token_mint);
@@ -403,11 +398,7 @@ impl MultiPriceFeeds {
 
     /// Obtener precio desde Pyth Network (oracle descentralizado)
     async fn fetch_price_from_pyth(&self, token_symbol: &str) -> Result<f64> {
-        let elapsed = self.last_pyth_request.elapsed();
-        if elapsed < Duration::from_millis(300) {
-            sleep(Duration::from_millis(300) - elapsed).await;
-        }
-        // TODO: self.last_pyth_request = Instant::now();
+        self.pace_provider("pyth", Duration::from_millis(300)).await;
 
         // Pyth price feed IDs para tokens principales
         let price_feed_id = match token_symbol {
@@ -456,7 +447,7 @@ impl MultiPriceFeeds {
                 valid_tokens.push(*token);
             } else {
                 warn!("⚠️ Token {} no soportado, usando fallback", token);
-                if let Ok(price) = self.simulate_helius_price(token) {
+                if let Some(price) = self.get_fallback_price(token).await {
                     prices.insert(token.to_string(), price);
                 }
             }
@@ -489,10 +480,10 @@ impl MultiPriceFeeds {
                                     info!("✅ Pyth backup para {}: ${:.4}", token, price);
                                 },
                                 Err(_) => {
-                                    // Último fallback: precio simulado
-                                    if let Ok(price) = self.simulate_helius_price(token) {
+                                    // Último fallback: tasa fiat compartida o stablecoin
+                                    if let Some(price) = self.get_fallback_price(token).await {
                                         prices.insert(token.to_string(), price);
-                                        info!("📊 Fallback simulado para {}: ${:.4}", token, price);
+                                        info!("📊 Fallback para {}: ${:.4}", token, price);
                                     }
                                 }
                             }
@@ -509,20 +500,11 @@ impl MultiPriceFeeds {
         Ok(prices)
     }
 
-    /// Simular precios de Helius usando precios de fallback de configuración
-    fn simulate_helius_price(&self, token_symbol: &str) -> Result<f64> {
-        let base_price = self.api_credentials.get_fallback_price(token_symbol);
-        
-        if base_price > 0.0 {
-            // Añadir algo de volatilidad realista basada en configuración
-            let volatility_factor = self.api_credentials.trading_config.base_market_volatility;
-            let mut rng = rand::thread_rng();
-            let volatility = (rng.gen::<f64>() - 0.5) * volatility_factor; // Volatilidad desde config
-            let price = base_price * (1.0 + volatility);
-            Ok(price)
-        } else {
-            Err(anyhow!("Token no soportado en fallback prices: {}", token_symbol))
-        }
+    /// Precio de último recurso: tasa fiat compartida o stablecoin configurada, nunca inventado
+    async fn fallback_price_result(&self, token_symbol: &str) -> Result<f64> {
+        self.get_fallback_price(token_symbol)
+            .await
+            .ok_or_else(|| anyhow!("Sin precio de fallback para {}", token_symbol))
     }
 
     /// Obtener mint address del token (incluye wrapped versions para Solana)
@@ -551,26 +533,58 @@ impl MultiPriceFeeds {
         Ok(mint.to_string())
     }
 
-    /// Cachear precio con timestamp
-    #[allow(dead_code)] // Price caching utility method
-    fn cache_price(&mut self, token: &str, price: f64, source: &str) {
-        self.price_cache.insert(token.to_string(), CachedPrice {
-            price,
-            timestamp: Instant::now(),
-            source: source.to_string(),
-        });
+    /// Ejecutar una consulta a través del circuit breaker del proveedor
+    async fn guarded<F, Fut>(&self, provider: &str, fetch: F) -> Result<f64>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<f64>>,
+    {
+        let breaker = provider_circuits().breaker(provider);
+        breaker.check()?;
+        let result = fetch().await;
+        breaker.record(&result);
+        result
+    }
+
+    /// Cachear precio (30 segundos de validez)
+    async fn cache_price(&self, token: &str, price: f64, source: &str) {
+        if let Err(e) = self.price_cache.put(token, CachedPrice::new(price, source), Duration::from_secs(30)).await {
+            warn!("⚠️ No se pudo cachear precio de {}: {}", token, e);
+        }
+    }
+
+    /// Esperar turno del proveedor (presupuesto compartido entre procesos)
+    async fn pace_provider(&self, provider: &str, min_interval: Duration) {
+        let wait = match self.price_cache.reserve_request_slot(provider, min_interval).await {
+            Ok(wait) => wait,
+            Err(e) => {
+                warn!("⚠️ Presupuesto de rate limit no disponible para {}: {}", provider, e);
+                min_interval
+            }
+        };
+        if !wait.is_zero() {
+            sleep(wait).await;
+        }
     }
 
     /// Limpiar cache viejo
-    pub fn cleanup_cache(&mut self) {
-        let now = Instant::now();
-        self.price_cache.retain(|_, cached| {
-            now.duration_since(cached.timestamp) < Duration::from_secs(300) // 5 minutos max
-        });
+    pub async fn cleanup_cache(&self) {
+        if let Err(e) = self.price_cache.purge_expired().await {
+            warn!("⚠️ Limpieza de cache falló: {}", e);
+        }
     }
     
-    /// Obtener precio de fallback desde configuración
-    pub fn get_fallback_price(&self, token: &str) -> f64 {
+    /// Precio de fallback: SOL/BTC/ETH desde FiatRateService, stablecoins desde configuración
+    pub async fn get_fallback_price(&self, token: &str) -> Option<f64> {
+        if let Some(asset) = FiatAsset::from_symbol(token) {
+            return match self.fiat_rates.get_rate(asset).await {
+                Ok(rate) => Some(rate.usd),
+                Err(e) => {
+                    warn!("⚠️ Sin tasa {}/USD para fallback: {}", asset.symbol(), e);
+                    None
+                }
+            };
+        }
         self.api_credentials.get_fallback_price(token)
     }
     
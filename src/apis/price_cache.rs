@@ -0,0 +1,238 @@
+//! Shared price cache
+//!
+//! Fresh prices and per-provider request pacing live behind the `PriceCache`
+//! trait. The in-memory implementation serves a single process; the Redis
+//! implementation (`redis` cargo feature) lets the CLI tools, the API server
+//! and the bot share prices and rate-limit budgets instead of each hitting
+//! the external APIs on their own.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+/// Environment variable selecting a shared cache (`redis://...`); in-memory when unset
+pub const PRICE_CACHE_URL_ENV: &str = "SNIPERFORGE_PRICE_CACHE_URL";
+
+/// A cached price and where it came from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedPrice {
+    pub price: f64,
+    pub source: String,
+    pub cached_at: DateTime<Utc>,
+}
+
+impl CachedPrice {
+    pub fn new(price: f64, source: &str) -> Self {
+        Self {
+            price,
+            source: source.to_string(),
+            cached_at: Utc::now(),
+        }
+    }
+
+    /// Age of the entry
+    pub fn age(&self) -> Duration {
+        (Utc::now() - self.cached_at).to_std().unwrap_or_default()
+    }
+}
+
+/// Price cache and request pacing shared by all price consumers
+#[async_trait]
+pub trait PriceCache: Send + Sync + std::fmt::Debug {
+    fn name(&self) -> &'static str;
+
+    /// Cached price for `token`, if present and not expired
+    async fn get(&self, token: &str) -> Result<Option<CachedPrice>>;
+
+    /// Store a price for `ttl`
+    async fn put(&self, token: &str, price: CachedPrice, ttl: Duration) -> Result<()>;
+
+    /// Reserve the next request slot for `provider`, keeping requests at least
+    /// `min_interval` apart across every process using this cache; returns how
+    /// long the caller must wait before sending
+    async fn reserve_request_slot(&self, provider: &str, min_interval: Duration) -> Result<Duration>;
+
+    /// Drop expired entries (no-op for stores with native expiry)
+    async fn purge_expired(&self) -> Result<usize> {
+        Ok(0)
+    }
+}
+
+/// Process-local price cache
+#[derive(Debug, Default)]
+pub struct InMemoryPriceCache {
+    prices: Mutex<HashMap<String, (CachedPrice, Instant)>>,
+    next_slots: Mutex<HashMap<String, Instant>>,
+}
+
+impl InMemoryPriceCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl PriceCache for InMemoryPriceCache {
+    fn name(&self) -> &'static str {
+        "in-memory"
+    }
+
+    async fn get(&self, token: &str) -> Result<Option<CachedPrice>> {
+        let prices = self.prices.lock();
+        Ok(prices
+            .get(token)
+            .filter(|(_, expires)| *expires > Instant::now())
+            .map(|(price, _)| CachedPrice {
+                cached_at: crate::chaos::faults().price_timestamp(token, price.cached_at),
+                ..price.clone()
+            }))
+    }
+
+    async fn put(&self, token: &str, price: CachedPrice, ttl: Duration) -> Result<()> {
+        self.prices.lock().insert(token.to_string(), (price, Instant::now() + ttl));
+        Ok(())
+    }
+
+    async fn reserve_request_slot(&self, provider: &str, min_interval: Duration) -> Result<Duration> {
+        let now = Instant::now();
+        let mut slots = self.next_slots.lock();
+        let slot = slots.get(provider).copied().filter(|next| *next > now).unwrap_or(now);
+        slots.insert(provider.to_string(), slot + min_interval);
+        Ok(slot - now)
+    }
+
+    async fn purge_expired(&self) -> Result<usize> {
+        let now = Instant::now();
+        let mut prices = self.prices.lock();
+        let before = prices.len();
+        prices.retain(|_, (_, expires)| *expires > now);
+        Ok(before - prices.len())
+    }
+}
+
+/// Redis-backed price cache shared between processes
+#[cfg(feature = "redis")]
+pub struct RedisPriceCache {
+    conn: redis::aio::MultiplexedConnection,
+    key_prefix: String,
+}
+
+#[cfg(feature = "redis")]
+impl std::fmt::Debug for RedisPriceCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedisPriceCache").field("key_prefix", &self.key_prefix).finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "redis")]
+impl RedisPriceCache {
+    /// Atomically hand out the next pacing slot (ms timestamps supplied by the caller)
+    const RESERVE_SCRIPT: &'static str = r"
+        local now = tonumber(ARGV[1])
+        local interval = tonumber(ARGV[2])
+        local slot = tonumber(redis.call('GET', KEYS[1]) or '0')
+        if slot < now then slot = now end
+        redis.call('SET', KEYS[1], slot + interval, 'PX', slot + interval - now + 1000)
+        return slot - now";
+
+    pub async fn connect(url: &str) -> Result<Self> {
+        let client = redis::Client::open(url)?;
+        Ok(Self {
+            conn: client.get_multiplexed_async_connection().await?,
+            key_prefix: "sniperforge:prices".to_string(),
+        })
+    }
+}
+
+#[cfg(feature = "redis")]
+#[async_trait]
+impl PriceCache for RedisPriceCache {
+    fn name(&self) -> &'static str {
+        "redis"
+    }
+
+    async fn get(&self, token: &str) -> Result<Option<CachedPrice>> {
+        let mut conn = self.conn.clone();
+        let raw: Option<String> = redis::cmd("GET")
+            .arg(format!("{}:price:{}", self.key_prefix, token))
+            .query_async(&mut conn)
+            .await?;
+        Ok(raw.map(|json| serde_json::from_str(&json)).transpose()?)
+    }
+
+    async fn put(&self, token: &str, price: CachedPrice, ttl: Duration) -> Result<()> {
+        let mut conn = self.conn.clone();
+        let _: () = redis::cmd("SET")
+            .arg(format!("{}:price:{}", self.key_prefix, token))
+            .arg(serde_json::to_string(&price)?)
+            .arg("PX")
+            .arg(ttl.as_millis() as u64)
+            .query_async(&mut conn)
+            .await?;
+        Ok(())
+    }
+
+    async fn reserve_request_slot(&self, provider: &str, min_interval: Duration) -> Result<Duration> {
+        let mut conn = self.conn.clone();
+        let wait_ms: i64 = redis::Script::new(Self::RESERVE_SCRIPT)
+            .key(format!("{}:slot:{}", self.key_prefix, provider))
+            .arg(Utc::now().timestamp_millis())
+            .arg(min_interval.as_millis() as u64)
+            .invoke_async(&mut conn)
+            .await?;
+        Ok(Duration::from_millis(wait_ms.max(0) as u64))
+    }
+}
+
+/// Build the cache selected by `SNIPERFORGE_PRICE_CACHE_URL` (in-memory by default)
+pub async fn price_cache_from_env() -> Result<std::sync::Arc<dyn PriceCache>> {
+    match std::env::var(PRICE_CACHE_URL_ENV) {
+        Ok(url) if url.starts_with("redis://") || url.starts_with("rediss://") => redis_price_cache(&url).await,
+        Ok(url) if url != "memory" => anyhow::bail!("Unsupported price cache URL: {}", url),
+        _ => Ok(std::sync::Arc::new(InMemoryPriceCache::new())),
+    }
+}
+
+#[cfg(feature = "redis")]
+async fn redis_price_cache(url: &str) -> Result<std::sync::Arc<dyn PriceCache>> {
+    Ok(std::sync::Arc::new(RedisPriceCache::connect(url).await?))
+}
+
+#[cfg(not(feature = "redis"))]
+async fn redis_price_cache(_url: &str) -> Result<std::sync::Arc<dyn PriceCache>> {
+    anyhow::bail!("Redis price cache requested but this build lacks the `redis` feature")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_entries_expire() {
+        let cache = InMemoryPriceCache::new();
+        cache.put("SOL", CachedPrice::new(150.0, "Jupiter"), Duration::from_millis(20)).await.unwrap();
+        assert_eq!(cache.get("SOL").await.unwrap().map(|p| p.price), Some(150.0));
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert!(cache.get("SOL").await.unwrap().is_none());
+        assert_eq!(cache.purge_expired().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_request_slots_are_spaced() {
+        let cache = InMemoryPriceCache::new();
+        let interval = Duration::from_millis(500);
+
+        assert_eq!(cache.reserve_request_slot("jupiter", interval).await.unwrap(), Duration::ZERO);
+        let second = cache.reserve_request_slot("jupiter", interval).await.unwrap();
+        let third = cache.reserve_request_slot("jupiter", interval).await.unwrap();
+        assert!(second > Duration::from_millis(450) && second <= interval);
+        assert!(third > Duration::from_millis(950));
+        assert_eq!(cache.reserve_request_slot("pyth", interval).await.unwrap(), Duration::ZERO);
+    }
+}
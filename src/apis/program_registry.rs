@@ -0,0 +1,271 @@
+//! Solana program ID registry with decoder versioning
+//!
+//! Pool accounts are decoded by byte offset, so a DEX program upgrade that
+//! changes the account layout would make a fixed decoder read garbage
+//! prices. The registry maps each program ID to the layouts it knows, each
+//! identified by account size and Anchor discriminator. An account is only
+//! decoded when its owner and layout match a registered version:
+//!
+//! - unknown owner or unknown layout: skipped with a [`DecodeError`] and one
+//!   alert per program/layout, never a panic or a silent mis-decode
+//! - new layouts are added at runtime with [`ProgramRegistry::register`], so a
+//!   decoder for an upgraded program can be hot-added without a restart
+//!
+//! Built-in decoders return the raw pool price (token B per token A in base
+//! units).
+
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::Utc;
+use parking_lot::{Mutex, RwLock};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use tracing::{info, warn};
+
+use crate::monitoring::{Alert, AlertManager, AlertStatus, Severity};
+
+pub const ORCA_WHIRLPOOL_PROGRAM: &str = crate::types::hot_path::programs::ORCA_WHIRLPOOL;
+pub const RAYDIUM_CLMM_PROGRAM: &str = crate::types::hot_path::programs::RAYDIUM_CLMM;
+
+/// Decodes a pool price from account data
+pub type AccountDecoder = fn(&[u8]) -> Option<f64>;
+
+/// One known account layout of a program
+#[derive(Debug, Clone)]
+pub struct DecoderVersion {
+    /// Layout name, e.g. "whirlpool"
+    pub layout: String,
+    pub version: u32,
+    /// Exact account size (any size when unset)
+    pub data_len: Option<usize>,
+    /// Leading bytes identifying the account type (Anchor discriminator)
+    pub discriminator: Vec<u8>,
+    pub decoder: AccountDecoder,
+}
+
+impl DecoderVersion {
+    pub fn new(layout: &str, version: u32, decoder: AccountDecoder) -> Self {
+        Self {
+            layout: layout.to_string(),
+            version,
+            data_len: None,
+            discriminator: Vec::new(),
+            decoder,
+        }
+    }
+
+    pub fn with_data_len(mut self, data_len: usize) -> Self {
+        self.data_len = Some(data_len);
+        self
+    }
+
+    pub fn with_discriminator(mut self, discriminator: &[u8]) -> Self {
+        self.discriminator = discriminator.to_vec();
+        self
+    }
+
+    fn matches(&self, data: &[u8]) -> bool {
+        self.data_len.map_or(true, |len| data.len() == len) && data.starts_with(&self.discriminator)
+    }
+}
+
+/// Why an account was not decoded
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum DecodeError {
+    #[error("no decoder registered for program {0}")]
+    UnknownProgram(Pubkey),
+    #[error("unknown account layout for {name} ({program}): {data_len} bytes, discriminator {discriminator:?}")]
+    UnknownLayout {
+        program: Pubkey,
+        name: String,
+        data_len: usize,
+        discriminator: Vec<u8>,
+    },
+    #[error("{layout} v{version} decoder rejected account data of {program}")]
+    DecodeFailed { program: Pubkey, layout: String, version: u32 },
+}
+
+/// A decoded account with the layout that produced it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DecodedAccount {
+    pub value: f64,
+    pub layout: String,
+    pub version: u32,
+}
+
+#[derive(Debug, Clone)]
+struct ProgramEntry {
+    name: String,
+    versions: Vec<DecoderVersion>,
+}
+
+/// Program ID → decoder versions
+#[derive(Debug, Default)]
+pub struct ProgramRegistry {
+    programs: RwLock<HashMap<Pubkey, ProgramEntry>>,
+    /// (program, data length) pairs already alerted on
+    alerted: Mutex<HashSet<(Pubkey, usize)>>,
+    alert_manager: Option<Arc<AlertManager>>,
+}
+
+impl ProgramRegistry {
+    /// Registry with the built-in Orca Whirlpool and Raydium CLMM layouts
+    pub fn new() -> Self {
+        let registry = Self::default();
+        if let Ok(program) = Pubkey::from_str(ORCA_WHIRLPOOL_PROGRAM) {
+            registry.register(program, "Orca Whirlpool", DecoderVersion::new("whirlpool", 1, decode_whirlpool_price)
+                .with_data_len(653)
+                .with_discriminator(&[63, 149, 209, 12, 225, 128, 99, 9]));
+        }
+        if let Ok(program) = Pubkey::from_str(RAYDIUM_CLMM_PROGRAM) {
+            registry.register(program, "Raydium CLMM", DecoderVersion::new("pool_state", 1, decode_raydium_clmm_price)
+                .with_data_len(1544)
+                .with_discriminator(&[247, 237, 227, 245, 215, 195, 222, 70]));
+        }
+        registry
+    }
+
+    pub fn with_alert_manager(mut self, alert_manager: Arc<AlertManager>) -> Self {
+        self.alert_manager = Some(alert_manager);
+        self
+    }
+
+    /// Add a decoder version for `program`, replacing one with the same layout and version
+    ///
+    /// Safe to call while the registry is in use.
+    pub fn register(&self, program: Pubkey, name: &str, version: DecoderVersion) {
+        let mut programs = self.programs.write();
+        let entry = programs.entry(program).or_insert_with(|| ProgramEntry { name: name.to_string(), versions: Vec::new() });
+        entry.versions.retain(|v| !(v.layout == version.layout && v.version == version.version));
+        info!("🧩 Decoder {} v{} registered for {} ({})", version.layout, version.version, entry.name, program);
+        entry.versions.push(version);
+        // Layouts alerted on before may be decodable now
+        self.alerted.lock().retain(|(alerted, _)| *alerted != program);
+    }
+
+    pub fn is_known_program(&self, program: &Pubkey) -> bool {
+        self.programs.read().contains_key(program)
+    }
+
+    /// Registered (layout, version) pairs per program name
+    pub fn versions(&self) -> Vec<(String, Vec<(String, u32)>)> {
+        let mut versions: Vec<_> = self.programs.read()
+            .values()
+            .map(|entry| (entry.name.clone(), entry.versions.iter().map(|v| (v.layout.clone(), v.version)).collect()))
+            .collect();
+        versions.sort();
+        versions
+    }
+
+    /// Decode an account owned by `owner`; unknown programs and layouts are rejected
+    pub fn decode(&self, owner: &Pubkey, data: &[u8]) -> Result<DecodedAccount, DecodeError> {
+        let programs = self.programs.read();
+        let Some(entry) = programs.get(owner) else {
+            let error = DecodeError::UnknownProgram(*owner);
+            drop(programs);
+            self.alert_once(*owner, data.len(), &error);
+            return Err(error);
+        };
+        // Newest matching version wins
+        let Some(version) = entry.versions.iter().filter(|v| v.matches(data)).max_by_key(|v| v.version) else {
+            let error = DecodeError::UnknownLayout {
+                program: *owner,
+                name: entry.name.clone(),
+                data_len: data.len(),
+                discriminator: data.iter().take(8).copied().collect(),
+            };
+            drop(programs);
+            self.alert_once(*owner, data.len(), &error);
+            return Err(error);
+        };
+        (version.decoder)(data)
+            .map(|value| DecodedAccount { value, layout: version.layout.clone(), version: version.version })
+            .ok_or_else(|| DecodeError::DecodeFailed { program: *owner, layout: version.layout.clone(), version: version.version })
+    }
+
+    fn alert_once(&self, program: Pubkey, data_len: usize, error: &DecodeError) {
+        if !self.alerted.lock().insert((program, data_len)) {
+            return;
+        }
+        warn!("🧩 Skipping undecodable account: {}", error);
+        let Some(alert_manager) = self.alert_manager.clone() else { return };
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else { return };
+        let alert = Alert {
+            id: uuid::Uuid::new_v4().to_string(),
+            title: format!("Unknown account layout for program {}", program),
+            description: format!("{} - accounts are skipped until a decoder is registered", error),
+            severity: Severity::High,
+            status: AlertStatus::Open,
+            created_at: Utc::now(),
+            resolved_at: None,
+            tags: vec!["decoder".to_string(), program.to_string()],
+        };
+        runtime.spawn(async move { alert_manager.raise_alert(alert).await });
+    }
+}
+
+fn read_u128(data: &[u8], offset: usize) -> Option<u128> {
+    data.get(offset..offset + 16)?.try_into().ok().map(u128::from_le_bytes)
+}
+
+fn price_from_sqrt_x64(sqrt_price_x64: u128) -> Option<f64> {
+    let sqrt_price = sqrt_price_x64 as f64 / 2f64.powi(64);
+    (sqrt_price > 0.0).then(|| sqrt_price * sqrt_price)
+}
+
+/// Orca Whirlpool: `sqrt_price` (Q64.64) after discriminator, config, bump, tick spacing, fees and liquidity
+pub fn decode_whirlpool_price(data: &[u8]) -> Option<f64> {
+    price_from_sqrt_x64(read_u128(data, 65)?)
+}
+
+/// Raydium CLMM `PoolState`: `sqrt_price_x64` after the seven pubkeys, decimals, tick spacing and liquidity
+pub fn decode_raydium_clmm_price(data: &[u8]) -> Option<f64> {
+    price_from_sqrt_x64(read_u128(data, 253)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn whirlpool_account(sqrt_price_x64: u128) -> Vec<u8> {
+        let mut data = vec![0u8; 653];
+        data[..8].copy_from_slice(&[63, 149, 209, 12, 225, 128, 99, 9]);
+        data[65..81].copy_from_slice(&sqrt_price_x64.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_decodes_known_layout_and_skips_unknown() {
+        let registry = ProgramRegistry::new();
+        let whirlpool = Pubkey::from_str(ORCA_WHIRLPOOL_PROGRAM).unwrap();
+
+        // sqrt price 2.0 -> price 4.0
+        let decoded = registry.decode(&whirlpool, &whirlpool_account(2u128 << 64)).unwrap();
+        assert_eq!(decoded.value, 4.0);
+        assert_eq!(decoded.version, 1);
+
+        // Upgraded program with a larger account: skipped, not mis-decoded
+        let mut upgraded = whirlpool_account(2u128 << 64);
+        upgraded.extend_from_slice(&[0; 64]);
+        assert!(matches!(registry.decode(&whirlpool, &upgraded), Err(DecodeError::UnknownLayout { data_len: 717, .. })));
+        assert!(matches!(registry.decode(&Pubkey::new_unique(), &upgraded), Err(DecodeError::UnknownProgram(_))));
+    }
+
+    #[test]
+    fn test_hot_added_version_takes_over() {
+        let registry = ProgramRegistry::new();
+        let whirlpool = Pubkey::from_str(ORCA_WHIRLPOOL_PROGRAM).unwrap();
+        let mut upgraded = whirlpool_account(3u128 << 64);
+        upgraded.extend_from_slice(&[0; 64]);
+        assert!(registry.decode(&whirlpool, &upgraded).is_err());
+
+        registry.register(whirlpool, "Orca Whirlpool", DecoderVersion::new("whirlpool", 2, decode_whirlpool_price)
+            .with_data_len(717)
+            .with_discriminator(&[63, 149, 209, 12, 225, 128, 99, 9]));
+        let decoded = registry.decode(&whirlpool, &upgraded).unwrap();
+        assert_eq!((decoded.value, decoded.version), (9.0, 2));
+        assert_eq!(registry.versions()[0].1.len(), 2);
+    }
+}
@@ -10,6 +10,8 @@ use serde_json::Value;
 use tokio::time::{timeout, Duration};
 use std::sync::{Arc, Mutex};
 use chrono::{DateTime, Utc};
+use crate::apis::circuit_breaker::provider_circuits;
+use crate::apis::fiat_rates::{fiat_rates, FiatAsset};
 
 /// Cliente para obtener precios reales de múltiples DEXs (migrado del bot que funciona)
 pub struct RealPriceFeeds {
@@ -163,9 +165,15 @@ impl RealPriceFeeds {
         let mut prices = Vec::new();
         let mut successful_sources = 0;
 
+        // Circuitos abiertos: se omite el proveedor (modo degradado) en vez de repetir el error cada ciclo
+        let dexscreener_circuit = provider_circuits().breaker("dexscreener");
+        let jupiter_circuit = provider_circuits().breaker("jupiter");
+
         // 1. DexScreener (gratuito, múltiples DEXs) - PRIMERA PRIORIDAD
-        if self.dexscreener_enabled {
-            match self.get_dexscreener_prices(mint).await {
+        if self.dexscreener_enabled && dexscreener_circuit.allow_request() {
+            let result = self.get_dexscreener_prices(mint).await;
+            dexscreener_circuit.record(&result);
+            match result {
                 Ok(dex_prices) => {
                     if !dex_prices.is_empty() {
                         info!("✅ DexScreener: {} precios obtenidos", dex_prices.len());
@@ -188,8 +196,13 @@ impl RealPriceFeeds {
         }
 
         // 3. Jupiter Price API con manejo robusto de errores
-        if self.jupiter_enabled && successful_sources < 3 {
-            match timeout(Duration::from_secs(5), self.get_jupiter_price(mint)).await {
+        if self.jupiter_enabled && successful_sources < 3 && jupiter_circuit.allow_request() {
+            let result = timeout(Duration::from_secs(5), self.get_jupiter_price(mint)).await;
+            match &result {
+                Ok(inner) => jupiter_circuit.record(inner),
+                Err(_) => jupiter_circuit.record_failure("timeout"),
+            }
+            match result {
                 Ok(Ok(jupiter_price)) => {
                     info!("✅ Jupiter: precio ${:.6} obtenido", jupiter_price.price_usd);
                     prices.push(jupiter_price);
@@ -252,6 +265,16 @@ impl RealPriceFeeds {
         })
     }
 
+    /// Liquidity (USD) of the deepest DexScreener pool trading `mint`
+    pub async fn token_liquidity_usd(&self, mint: &str) -> Result<f64> {
+        self.get_dexscreener_prices(mint)
+            .await?
+            .into_iter()
+            .map(|price| price.liquidity_usd)
+            .reduce(f64::max)
+            .ok_or_else(|| anyhow!("No DexScreener pools for {}", mint))
+    }
+
     /// Obtener precios de DexScreener (implementación simplificada)
     async fn get_dexscreener_prices(&self, mint: &str) -> Result<Vec<DEXPrice>> {
         let url = format!("https://api.dexscreener.com/latest/dex/tokens/{}", mint);
@@ -338,28 +361,28 @@ impl RealPriceFeeds {
         })
     }
 
-    /// Fallback usando precios estimados
+    /// Fallback: SOL desde la tasa fiat compartida, stablecoins a la par; el resto sin precio
     async fn get_fallback_price(&self, mint: &str) -> Result<DEXPrice> {
-        let (symbol, estimated_price) = match mint {
-            "So11111111111111111111111111111111111111112" => ("SOL", 150.0),
-            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" => ("USDC", 1.0),
-            "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" => ("USDT", 1.0),
-            "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R" => ("RAY", 1.5),
-            "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263" => ("BONK", 0.00002),
-            "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN" => ("JUP", 0.8),
+        let (symbol, price_usd, last_updated) = match mint {
+            "So11111111111111111111111111111111111111112" => {
+                let rate = fiat_rates().get_rate(FiatAsset::Sol).await?;
+                ("SOL", rate.usd, rate.fetched_at)
+            }
+            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" => ("USDC", 1.0, Utc::now()),
+            "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" => ("USDT", 1.0, Utc::now()),
             _ => return Err(anyhow!("Token not supported in fallback")),
         };
 
-        info!("🔄 Fallback: precio estimado para {} = ${:.6}", symbol, estimated_price);
+        info!("🔄 Fallback: precio para {} = ${:.6}", symbol, price_usd);
 
         Ok(DEXPrice {
-            dex_name: "Fallback_Estimated".to_string(),
+            dex_name: "Fallback".to_string(),
             token_mint: mint.to_string(),
-            price_usd: estimated_price,
+            price_usd,
             price_sol: None,
             liquidity_usd: 500_000.0,
             volume_24h: 100_000.0,
-            last_updated: Utc::now(),
+            last_updated,
             source: format!("Fallback ({})", symbol),
         })
     }
@@ -14,6 +14,8 @@ use tokio::sync::{RwLock, Semaphore};
 use tracing::{debug, info, warn, error};
 
 use crate::config::SimpleConfig;
+use crate::apis::rpc_usage::{provider_for_url, rpc_usage};
+use crate::monitoring::latency_heatmap::latency_heatmap;
 
 /// RPC endpoint health status
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -186,7 +188,12 @@ impl RpcPool {
         ));
 
         // Test client with a simple call
-        if let Err(e) = client.get_slot() {
+        rpc_usage().record(provider_for_url(url), "getSlot");
+        let started = std::time::Instant::now();
+        let probe = crate::chaos::faults().rpc_call(provider_for_url(url), "getSlot").map_err(anyhow::Error::from)
+            .and_then(|()| client.get_slot().map_err(anyhow::Error::from));
+        latency_heatmap().record_rpc(provider_for_url(url), "getSlot", started.elapsed(), probe.is_ok());
+        if let Err(e) = probe {
             warn!("Failed to connect to RPC endpoint {}: {}", url, e);
             self.mark_endpoint_unhealthy(url).await;
             return Err(e.into());
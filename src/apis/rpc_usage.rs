@@ -0,0 +1,272 @@
+//! RPC usage and cost accounting
+//!
+//! Every RPC call made through instrumented clients is counted per provider,
+//! method and UTC day. Providers bill in credits with per-method weights
+//! (Helius charges more for `getProgramAccounts` and archival calls,
+//! QuickNode multiplies per method), so each provider gets a cost model and
+//! usage is reported in both requests and credits.
+//!
+//! Month-to-date credits are projected linearly to the end of the month and
+//! compared with the plan's monthly limit, so overruns are flagged days before
+//! the provider starts throttling. A process-wide tracker is available through
+//! `rpc_usage()`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// Days of per-day usage kept (enough for month-to-date)
+const RETAINED_DAYS: i64 = 40;
+
+/// Credits charged per method by one provider
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcCostModel {
+    /// Credits for methods without an explicit entry
+    pub default_credits: u64,
+    pub method_credits: HashMap<String, u64>,
+    /// Credits included in the plan per calendar month (unmetered when unset)
+    pub monthly_credit_limit: Option<u64>,
+}
+
+impl RpcCostModel {
+    /// Public/self-hosted endpoints: requests are counted, nothing is billed
+    pub fn unmetered() -> Self {
+        Self {
+            default_credits: 0,
+            method_credits: HashMap::new(),
+            monthly_credit_limit: None,
+        }
+    }
+
+    /// Helius pricing (developer plan limit; adjust to the actual plan)
+    pub fn helius() -> Self {
+        let heavy = ["getProgramAccounts", "getTransaction", "getBlock", "getSignaturesForAddress", "getAsset", "getAssetsByOwner"];
+        Self {
+            default_credits: 1,
+            method_credits: heavy.iter().map(|m| (m.to_string(), 10)).collect(),
+            monthly_credit_limit: Some(10_000_000),
+        }
+    }
+
+    /// QuickNode API credit multipliers (build plan limit; adjust to the actual plan)
+    pub fn quicknode() -> Self {
+        let heavy = ["getProgramAccounts", "getBlock", "getSignaturesForAddress"];
+        Self {
+            default_credits: 20,
+            method_credits: heavy.iter().map(|m| (m.to_string(), 40)).collect(),
+            monthly_credit_limit: Some(80_000_000),
+        }
+    }
+
+    pub fn with_monthly_limit(mut self, credits: u64) -> Self {
+        self.monthly_credit_limit = Some(credits);
+        self
+    }
+
+    pub fn credits(&self, method: &str) -> u64 {
+        self.method_credits.get(method).copied().unwrap_or(self.default_credits)
+    }
+}
+
+/// Provider name for an RPC URL, used as the accounting key
+pub fn provider_for_url(url: &str) -> &'static str {
+    let url = url.to_ascii_lowercase();
+    if url.contains("helius") {
+        "helius"
+    } else if url.contains("quiknode") || url.contains("quicknode") {
+        "quicknode"
+    } else if url.contains("localhost") || url.contains("127.0.0.1") {
+        "local"
+    } else {
+        "public"
+    }
+}
+
+/// One provider's calls on one day
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DailyRpcUsage {
+    pub requests: u64,
+    pub credits: u64,
+    /// method -> (requests, credits)
+    pub by_method: BTreeMap<String, (u64, u64)>,
+}
+
+/// Usage report line for one provider and day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcUsageReport {
+    pub provider: String,
+    pub date: NaiveDate,
+    pub usage: DailyRpcUsage,
+}
+
+/// Month-end projection for one provider
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsageProjection {
+    pub provider: String,
+    pub month_to_date_credits: u64,
+    pub projected_month_credits: u64,
+    pub monthly_credit_limit: Option<u64>,
+}
+
+impl UsageProjection {
+    pub fn exceeds_limit(&self) -> bool {
+        self.monthly_credit_limit.is_some_and(|limit| self.projected_month_credits > limit)
+    }
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let (year, month) = if date.month() == 12 { (date.year() + 1, 1) } else { (date.year(), date.month() + 1) };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|next| next.pred_opt())
+        .map_or(30, |last| last.day())
+}
+
+/// Per-provider request counters with cost models
+#[derive(Debug, Default)]
+pub struct RpcUsageTracker {
+    models: RwLock<HashMap<String, RpcCostModel>>,
+    /// provider -> day -> usage
+    usage: RwLock<HashMap<String, BTreeMap<NaiveDate, DailyRpcUsage>>>,
+}
+
+impl RpcUsageTracker {
+    /// Tracker with the Helius and QuickNode models registered
+    pub fn new() -> Self {
+        let tracker = Self::default();
+        tracker.set_cost_model("helius", RpcCostModel::helius());
+        tracker.set_cost_model("quicknode", RpcCostModel::quicknode());
+        tracker
+    }
+
+    pub fn set_cost_model(&self, provider: &str, model: RpcCostModel) {
+        self.models.write().insert(provider.to_string(), model);
+    }
+
+    fn credits(&self, provider: &str, method: &str) -> u64 {
+        self.models.read().get(provider).map_or(0, |model| model.credits(method))
+    }
+
+    /// Count one call of `method` against `provider`
+    pub fn record(&self, provider: &str, method: &str) {
+        self.record_at(provider, method, Utc::now());
+    }
+
+    pub fn record_at(&self, provider: &str, method: &str, at: DateTime<Utc>) {
+        let credits = self.credits(provider, method);
+        let date = at.date_naive();
+        let mut usage = self.usage.write();
+        let days = usage.entry(provider.to_string()).or_default();
+        let day = days.entry(date).or_default();
+        day.requests += 1;
+        day.credits += credits;
+        let method = day.by_method.entry(method.to_string()).or_default();
+        method.0 += 1;
+        method.1 += credits;
+
+        let cutoff = date - chrono::Duration::days(RETAINED_DAYS);
+        days.retain(|day, _| *day > cutoff);
+    }
+
+    /// Usage of every provider on `date`, sorted by provider
+    pub fn daily_report(&self, date: NaiveDate) -> Vec<RpcUsageReport> {
+        let mut reports: Vec<_> = self.usage.read()
+            .iter()
+            .filter_map(|(provider, days)| days.get(&date).map(|usage| RpcUsageReport {
+                provider: provider.clone(),
+                date,
+                usage: usage.clone(),
+            }))
+            .collect();
+        reports.sort_by(|a, b| a.provider.cmp(&b.provider));
+        reports
+    }
+
+    /// Month-end credit projection for every provider with usage this month
+    pub fn projections(&self, now: DateTime<Utc>) -> Vec<UsageProjection> {
+        let today = now.date_naive();
+        let month_start = today.with_day(1).unwrap_or(today);
+        // Fraction of the month elapsed (at least an hour, so early projections stay finite)
+        let elapsed_days = (today.day() - 1) as f64 + now.num_seconds_from_midnight() as f64 / 86_400.0;
+        let elapsed = (elapsed_days / days_in_month(today) as f64).clamp(1.0 / 720.0, 1.0);
+
+        let models = self.models.read();
+        let mut projections: Vec<_> = self.usage.read()
+            .iter()
+            .map(|(provider, days)| {
+                let month_to_date: u64 = days.range(month_start..=today).map(|(_, usage)| usage.credits).sum();
+                UsageProjection {
+                    provider: provider.clone(),
+                    month_to_date_credits: month_to_date,
+                    projected_month_credits: (month_to_date as f64 / elapsed).round() as u64,
+                    monthly_credit_limit: models.get(provider).and_then(|model| model.monthly_credit_limit),
+                }
+            })
+            .filter(|projection| projection.month_to_date_credits > 0)
+            .collect();
+        projections.sort_by(|a, b| a.provider.cmp(&b.provider));
+        projections
+    }
+
+    /// Warn for providers projected to exceed their plan this month
+    pub fn check_limits(&self, now: DateTime<Utc>) -> Vec<UsageProjection> {
+        let over: Vec<_> = self.projections(now).into_iter().filter(UsageProjection::exceeds_limit).collect();
+        for projection in &over {
+            warn!("💸 RPC provider '{}' projected at {} credits this month (limit {}, {} used so far)",
+                  projection.provider, projection.projected_month_credits,
+                  projection.monthly_credit_limit.unwrap_or_default(), projection.month_to_date_credits);
+        }
+        over
+    }
+}
+
+/// Process-wide RPC usage shared by all instrumented clients
+pub fn rpc_usage() -> &'static RpcUsageTracker {
+    static USAGE: OnceLock<RpcUsageTracker> = OnceLock::new();
+    USAGE.get_or_init(RpcUsageTracker::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_credits_follow_method_weights() {
+        let tracker = RpcUsageTracker::new();
+        let at = Utc.with_ymd_and_hms(2024, 3, 5, 12, 0, 0).unwrap();
+        tracker.record_at("helius", "getSlot", at);
+        tracker.record_at("helius", "getProgramAccounts", at);
+        tracker.record_at("public", "getSlot", at);
+
+        let report = tracker.daily_report(at.date_naive());
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].provider, "helius");
+        assert_eq!(report[0].usage.requests, 2);
+        assert_eq!(report[0].usage.credits, 11);
+        assert_eq!(report[0].usage.by_method["getProgramAccounts"], (1, 10));
+        assert_eq!(report[1].usage.credits, 0);
+        assert_eq!(provider_for_url("https://mainnet.helius-rpc.com/?api-key=x"), "helius");
+    }
+
+    #[test]
+    fn test_projection_flags_plan_overrun() {
+        let tracker = RpcUsageTracker::new();
+        tracker.set_cost_model("helius", RpcCostModel::helius().with_monthly_limit(1_000));
+        // 100 credits/day over the first 10 days of a 30-day month
+        for day in 1..=10 {
+            let at = Utc.with_ymd_and_hms(2024, 4, day, 0, 0, 0).unwrap();
+            for _ in 0..10 {
+                tracker.record_at("helius", "getProgramAccounts", at);
+            }
+        }
+        let now = Utc.with_ymd_and_hms(2024, 4, 11, 0, 0, 0).unwrap();
+        let projections = tracker.projections(now);
+        assert_eq!(projections[0].month_to_date_credits, 1_000);
+        assert_eq!(projections[0].projected_month_credits, 3_000);
+        assert_eq!(tracker.check_limits(now).len(), 1);
+    }
+}
@@ -1,6 +1,7 @@
 //! Real Stablecoin Price Monitor
 //! Tracks actual stablecoin prices and depegging events
 
+use tracing::{info, warn};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -66,7 +67,7 @@ impl StablecoinMonitor {
                     self.prices.insert(symbol.to_string(), price_info);
                 }
                 Err(e) => {
-                    eprintln!("⚠️ Failed to fetch {} price: {}", symbol, e);
+                    warn!("⚠️ Failed to fetch {} price: {}", symbol, e);
                     // Use fallback price with warning
                     self.prices.insert(symbol.to_string(), StablecoinPrice {
                         symbol: symbol.to_string(),
@@ -177,9 +178,9 @@ impl StablecoinMonitor {
 
     /// Display real-time stablecoin status
     pub fn display_stablecoin_status(&self) {
-        println!("\n╔══════════════════════════════════════════════════════════════════╗");
-        println!("║                  REAL-TIME STABLECOIN MONITOR                    ║");
-        println!("╠══════════════════════════════════════════════════════════════════╣");
+        info!("╔══════════════════════════════════════════════════════════════════╗");
+        info!("║                  REAL-TIME STABLECOIN MONITOR                    ║");
+        info!("╠══════════════════════════════════════════════════════════════════╣");
         
         for (symbol, price_info) in &self.prices {
             let status_emoji = if price_info.is_depegged {
@@ -190,7 +191,7 @@ impl StablecoinMonitor {
                 "🟢 STABLE"
             };
 
-            println!("║ {} │ ${:.4} │ {:+.3}% │ {} ║",
+            info!("║ {} │ ${:.4} │ {:+.3}% │ {} ║",
                 format!("{:<4}", symbol),
                 price_info.current_price,
                 price_info.deviation_from_peg,
@@ -198,7 +199,7 @@ impl StablecoinMonitor {
             );
         }
         
-        println!("╚══════════════════════════════════════════════════════════════════╝");
+        info!("╚══════════════════════════════════════════════════════════════════╝");
     }
 }
 
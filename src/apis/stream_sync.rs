@@ -0,0 +1,330 @@
+//! Stream continuity: gap detection, snapshot resync and feed status
+//!
+//! Every streaming feed drops eventually, and the incremental updates missed
+//! while it was down are gone. Applying later updates on top of a state that
+//! never saw the missing ones produces silently wrong pools and prices, so a
+//! stream goes through [`StreamSync`] before anything is applied:
+//!
+//! - sequence gaps (or slots going backwards) are detected per update
+//! - after a gap or a reconnect the feed is `Resyncing`: incremental updates
+//!   are buffered until a fresh REST/RPC snapshot is loaded, then the buffered
+//!   updates newer than the snapshot are replayed in order
+//! - a feed that has gone quiet for too long reads as `Stale`
+//!
+//! Status is published to a process-wide registry ([`feed_health`]) so
+//! engines can refuse to trade on a feed that is not `Live`. Streams that
+//! carry market state (pools, prices) block trading while degraded;
+//! informational feeds only mark the market context as degraded.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+/// How update sequence numbers relate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SequenceMode {
+    /// Each update carries the previous sequence + 1 (exchange-style feeds)
+    Contiguous,
+    /// Slot-stamped updates; skipped slots are normal, going backwards is not
+    Slot,
+}
+
+/// Whether a feed's data can be traded on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FeedStatus {
+    Live,
+    /// Connection lost, waiting to reconnect
+    Reconnecting,
+    /// Connected, waiting for a snapshot before applying updates
+    Resyncing,
+    /// Connected but nothing received within the staleness window
+    Stale,
+}
+
+impl FeedStatus {
+    pub fn is_degraded(self) -> bool {
+        self != Self::Live
+    }
+}
+
+/// Continuity counters
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StreamSyncStats {
+    pub applied: u64,
+    pub gaps: u64,
+    pub reconnects: u64,
+    pub resyncs: u64,
+    /// Buffered updates already covered by a snapshot
+    pub superseded: u64,
+}
+
+/// Source of full-state snapshots used to resync a stream
+#[async_trait]
+pub trait SnapshotSource: Send + Sync {
+    type Snapshot: Send;
+
+    /// Current state and the sequence/slot it reflects
+    async fn snapshot(&self) -> Result<(u64, Self::Snapshot)>;
+}
+
+/// Continuity state of one stream
+#[derive(Debug)]
+pub struct StreamSync<U> {
+    name: String,
+    mode: SequenceMode,
+    stale_after: Duration,
+    status: FeedStatus,
+    last_sequence: Option<u64>,
+    last_update: Instant,
+    /// Updates held back while resyncing, by sequence
+    buffer: BTreeMap<u64, Vec<U>>,
+    max_buffered: usize,
+    stats: StreamSyncStats,
+}
+
+impl<U> StreamSync<U> {
+    /// New stream; it needs a snapshot before the first update is applied
+    pub fn new(name: &str, mode: SequenceMode, stale_after: Duration) -> Self {
+        let sync = Self {
+            name: name.to_string(),
+            mode,
+            stale_after,
+            status: FeedStatus::Resyncing,
+            last_sequence: None,
+            last_update: Instant::now(),
+            buffer: BTreeMap::new(),
+            max_buffered: 10_000,
+            stats: StreamSyncStats::default(),
+        };
+        feed_health().register(name, true);
+        feed_health().publish(name, sync.status);
+        sync
+    }
+
+    pub fn with_max_buffered(mut self, max_buffered: usize) -> Self {
+        self.max_buffered = max_buffered;
+        self
+    }
+
+    fn set_status(&mut self, status: FeedStatus) {
+        if self.status != status {
+            self.status = status;
+            feed_health().publish(&self.name, status);
+        }
+    }
+
+    /// Current status; `Live` turns `Stale` once nothing arrived within the window
+    pub fn status(&self) -> FeedStatus {
+        match self.status {
+            FeedStatus::Live if self.last_update.elapsed() > self.stale_after => FeedStatus::Stale,
+            status => status,
+        }
+    }
+
+    /// Re-evaluate staleness and publish it (call periodically)
+    pub fn check_staleness(&mut self) -> FeedStatus {
+        let status = self.status();
+        feed_health().publish(&self.name, status);
+        status
+    }
+
+    pub fn needs_snapshot(&self) -> bool {
+        self.status == FeedStatus::Resyncing
+    }
+
+    pub fn stats(&self) -> &StreamSyncStats {
+        &self.stats
+    }
+
+    fn is_gap(&self, sequence: u64) -> bool {
+        match (self.mode, self.last_sequence) {
+            (_, None) => false,
+            (SequenceMode::Contiguous, Some(last)) => sequence != last + 1,
+            (SequenceMode::Slot, Some(last)) => sequence < last,
+        }
+    }
+
+    fn buffer(&mut self, sequence: u64, update: U) {
+        let buffered: usize = self.buffer.values().map(Vec::len).sum();
+        if buffered >= self.max_buffered {
+            // Oldest updates go first; the snapshot will cover them
+            if let Some(oldest) = self.buffer.keys().next().copied() {
+                self.buffer.remove(&oldest);
+            }
+        }
+        self.buffer.entry(sequence).or_default().push(update);
+    }
+
+    /// Feed an incremental update; returns the updates that may be applied now, in order
+    pub fn on_update(&mut self, sequence: u64, update: U) -> Vec<U> {
+        self.last_update = Instant::now();
+        if self.status == FeedStatus::Resyncing {
+            self.buffer(sequence, update);
+            return Vec::new();
+        }
+        if self.is_gap(sequence) {
+            self.stats.gaps += 1;
+            warn!("🕳️ Feed '{}' gap: after {:?} got {} - resyncing from snapshot", self.name, self.last_sequence, sequence);
+            self.set_status(FeedStatus::Resyncing);
+            self.buffer(sequence, update);
+            return Vec::new();
+        }
+        self.set_status(FeedStatus::Live);
+        self.last_sequence = Some(sequence);
+        self.stats.applied += 1;
+        vec![update]
+    }
+
+    pub fn on_disconnect(&mut self) {
+        warn!("🔌 Feed '{}' disconnected - data degraded until resynced", self.name);
+        self.set_status(FeedStatus::Reconnecting);
+    }
+
+    /// Connection re-established: updates are buffered until a snapshot is loaded
+    pub fn on_reconnect(&mut self) {
+        self.stats.reconnects += 1;
+        self.last_update = Instant::now();
+        self.set_status(FeedStatus::Resyncing);
+    }
+
+    /// A snapshot reflecting `sequence` was applied; returns the buffered updates
+    /// that are newer, in order
+    pub fn on_snapshot(&mut self, sequence: u64) -> Vec<U> {
+        let newer = self.buffer.split_off(&(sequence + 1));
+        self.stats.superseded += self.buffer.values().map(Vec::len).sum::<usize>() as u64;
+        self.buffer = BTreeMap::new();
+
+        self.last_sequence = Some(sequence);
+        let mut replay = Vec::new();
+        let mut newer = newer.into_iter();
+        while let Some((buffered_sequence, updates)) = newer.next() {
+            // A hole between the snapshot and the buffered updates needs another snapshot
+            if self.mode == SequenceMode::Contiguous && self.last_sequence.is_some_and(|last| buffered_sequence > last + 1) {
+                warn!("🕳️ Feed '{}' still missing updates after snapshot {} (next buffered {})", self.name, sequence, buffered_sequence);
+                self.buffer.insert(buffered_sequence, updates);
+                self.buffer.extend(newer);
+                return replay;
+            }
+            self.last_sequence = Some(buffered_sequence);
+            self.stats.applied += updates.len() as u64;
+            replay.extend(updates);
+        }
+        self.stats.resyncs += 1;
+        self.last_update = Instant::now();
+        info!("🔁 Feed '{}' resynced at {} ({} buffered updates replayed)", self.name, sequence, replay.len());
+        self.set_status(FeedStatus::Live);
+        replay
+    }
+
+    /// Load a snapshot from `source` and return it with the updates to replay on top
+    pub async fn resync<S: SnapshotSource>(&mut self, source: &S) -> Result<(S::Snapshot, Vec<U>)> {
+        let (sequence, snapshot) = source.snapshot().await?;
+        let replay = self.on_snapshot(sequence);
+        Ok((snapshot, replay))
+    }
+}
+
+/// Latest status of every feed
+#[derive(Debug, Default)]
+pub struct FeedHealth {
+    feeds: RwLock<HashMap<String, FeedStatus>>,
+    /// Feeds whose degradation blocks trading
+    critical: RwLock<HashSet<String>>,
+}
+
+impl FeedHealth {
+    /// Declare whether `feed` carries state trades depend on
+    pub fn register(&self, feed: &str, critical: bool) {
+        if critical {
+            self.critical.write().insert(feed.to_string());
+        } else {
+            self.critical.write().remove(feed);
+        }
+    }
+
+    pub fn publish(&self, feed: &str, status: FeedStatus) {
+        self.feeds.write().insert(feed.to_string(), status);
+    }
+
+    pub fn status(&self, feed: &str) -> Option<FeedStatus> {
+        self.feeds.read().get(feed).copied()
+    }
+
+    /// Whether `feed` is known and live
+    pub fn is_live(&self, feed: &str) -> bool {
+        self.status(feed) == Some(FeedStatus::Live)
+    }
+
+    /// Feeds that are not live, sorted by name
+    pub fn degraded(&self) -> Vec<(String, FeedStatus)> {
+        let mut degraded: Vec<_> = self.feeds.read()
+            .iter()
+            .filter(|(_, status)| status.is_degraded())
+            .map(|(feed, status)| (feed.clone(), *status))
+            .collect();
+        degraded.sort_by(|a, b| a.0.cmp(&b.0));
+        degraded
+    }
+
+    /// Degraded feeds that trading must wait for
+    pub fn blocking_trading(&self) -> Vec<(String, FeedStatus)> {
+        let critical = self.critical.read();
+        self.degraded().into_iter().filter(|(feed, _)| critical.contains(feed)).collect()
+    }
+}
+
+/// Process-wide feed status registry
+pub fn feed_health() -> &'static FeedHealth {
+    static HEALTH: OnceLock<FeedHealth> = OnceLock::new();
+    HEALTH.get_or_init(FeedHealth::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gap_buffers_until_snapshot_then_replays_newer() {
+        let mut sync = StreamSync::new("test_contiguous", SequenceMode::Contiguous, Duration::from_secs(60));
+        assert!(sync.on_update(1, "a").is_empty());
+        assert_eq!(sync.on_snapshot(1), Vec::<&str>::new());
+        assert_eq!(sync.on_update(2, "b"), vec!["b"]);
+        assert!(feed_health().is_live("test_contiguous"));
+
+        // 3 and 4 lost: 5 opens a gap
+        assert!(sync.on_update(5, "e").is_empty());
+        assert!(sync.on_update(6, "f").is_empty());
+        assert_eq!(feed_health().status("test_contiguous"), Some(FeedStatus::Resyncing));
+
+        // Snapshot at 5 supersedes "e"; "f" is replayed
+        assert_eq!(sync.on_snapshot(5), vec!["f"]);
+        assert_eq!(sync.status(), FeedStatus::Live);
+        assert_eq!(sync.on_update(7, "g"), vec!["g"]);
+        assert_eq!((sync.stats().gaps, sync.stats().superseded), (1, 1));
+    }
+
+    #[test]
+    fn test_reconnect_and_staleness_degrade_slot_feed() {
+        let mut sync = StreamSync::new("test_slots", SequenceMode::Slot, Duration::from_millis(20));
+        sync.on_snapshot(100);
+        // Skipped slots are fine for slot-stamped feeds
+        assert_eq!(sync.on_update(105, 1), vec![1]);
+
+        sync.on_disconnect();
+        assert_eq!(feed_health().status("test_slots"), Some(FeedStatus::Reconnecting));
+        sync.on_reconnect();
+        assert!(sync.needs_snapshot());
+        assert!(sync.on_update(110, 2).is_empty());
+        assert_eq!(sync.on_snapshot(108), vec![2]);
+
+        std::thread::sleep(Duration::from_millis(40));
+        assert_eq!(sync.check_staleness(), FeedStatus::Stale);
+        assert!(feed_health().blocking_trading().iter().any(|(feed, _)| feed == "test_slots"));
+    }
+}
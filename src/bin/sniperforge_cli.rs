@@ -1,4 +1,4 @@
-use clap::{Arg, Command};
+use clap::{Arg, ArgAction, Command};
 use tokio::net::TcpStream;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use serde_json;
@@ -7,8 +7,19 @@ use uuid::Uuid;
 use chrono::Utc;
 
 use sniperforge::control::{TcpCommand, TcpResponse};
+use sniperforge::analytics::{AnnotationTarget, Backtest, ParamAxis, ParameterSweep, SearchMode, SweepConfig};
+use sniperforge::security::dust::{DustOutcome, DustReport};
+use sniperforge::monitoring::health::{HealthReport, HealthState};
+use sniperforge::monitoring::status_snapshot::{StatusSnapshot, DEFAULT_STATUS_PATH};
+use sniperforge::monitoring::diagnostics::{DiagnosticsCollector, DiagnosticsConfig, DiagnosticsSummary};
+use sniperforge::chaos::{ChaosStatus, FaultPlan};
+use sniperforge::config::Watchlist;
+use sniperforge::trading::sim_diff::{read_decisions, Decision, DecisionDiffReport};
+use sniperforge::trading::strategies::{BacktestStrategy, PriceReplayBacktest, StrategyConfig};
+use sniperforge::trading::{TokenHeadroom, WithdrawalRequest};
 use sniperforge::api::bot_interface::{BotType, BotConfig, Environment, ResourceLimits, NetworkConfig, SecurityConfig, ConfigMetadata, WalletConfig, NetworkTimeouts};
 use sniperforge::api::BotMetrics;
+use sniperforge::utils::i18n::{set_locale, t, tf, Locale};
 use std::collections::HashMap;
 
 #[tokio::main]
@@ -26,6 +37,11 @@ async fn main() -> Result<()> {
             .value_name("HOST:PORT")
             .help("TCP server address")
             .default_value("127.0.0.1:8888"))
+        .arg(Arg::new("lang")
+            .long("lang")
+            .value_name("LOCALE")
+            .help("Output language (en, es); defaults to SNIPERFORGE_LOCALE or LANG")
+            .global(true))
         .subcommand(
             Command::new("ping")
                 .about("Test server connection")
@@ -95,6 +111,23 @@ async fn main() -> Result<()> {
             Command::new("backup-system")
                 .about("Create a backup of current system state")
         )
+        .subcommand(
+            Command::new("bot-sandbox")
+                .about("Show where a bot's files, data and logs live and how much space they use")
+                .arg(Arg::new("bot-id")
+                    .long("bot-id")
+                    .value_name("UUID")
+                    .help("Bot ID (all bots when omitted)"))
+        )
+        .subcommand(
+            Command::new("archive-bot")
+                .about("Copy one bot's work files, data and logs into the sandbox archive")
+                .arg(Arg::new("bot-id")
+                    .long("bot-id")
+                    .value_name("UUID")
+                    .help("Bot ID")
+                    .required(true))
+        )
         .subcommand(
             Command::new("metrics-history")
                 .about("Show historical system metrics")
@@ -119,15 +152,179 @@ async fn main() -> Result<()> {
         .subcommand(
             Command::new("resource-status")
                 .about("Show system resource usage and limits")
+        )
+        .subcommand(
+            Command::new("annotate")
+                .about("Attach a note/tags to a trade or position")
+                .arg(Arg::new("trade").long("trade").value_name("SIGNATURE").help("Trade transaction signature").conflicts_with("position"))
+                .arg(Arg::new("position").long("position").value_name("POSITION_ID").help("Position id"))
+                .arg(Arg::new("note").long("note").value_name("TEXT").help("Free-form note").default_value(""))
+                .arg(Arg::new("tag").long("tag").value_name("TAG").help("Tag (repeatable)").action(ArgAction::Append))
+                .arg(Arg::new("author").long("author").value_name("NAME").help("Who is annotating").default_value("cli"))
+        )
+        .subcommand(
+            Command::new("annotations")
+                .about("List trade/position annotations")
+                .arg(Arg::new("trade").long("trade").value_name("SIGNATURE").conflicts_with("position"))
+                .arg(Arg::new("position").long("position").value_name("POSITION_ID"))
+        )
+        .subcommand(
+            Command::new("export-journal")
+                .about("Export indexed trades with their annotations as CSV")
+                .arg(Arg::new("wallet").long("wallet").value_name("ADDRESS").help("Only this wallet"))
+                .arg(Arg::new("output").long("output").value_name("FILE").help("Write to file instead of stdout"))
+        )
+        .subcommand(
+            Command::new("health")
+                .about("Composite health of RPC, feeds, wallet, executors, storage and notifications")
+                .arg(Arg::new("json").long("json").action(ArgAction::SetTrue).help("Print the raw report"))
+        )
+        .subcommand(
+            Command::new("withdraw")
+                .about("Lock capital for withdrawal; it is swept to the destination once positions using it close")
+                .subcommand_required(true)
+                .subcommand(Command::new("list").about("Withdrawal requests and their progress"))
+                .subcommand(
+                    Command::new("request")
+                        .about("Lock an amount for withdrawal")
+                        .arg(Arg::new("asset").required(true).value_name("ASSET").help("SOL or a token mint"))
+                        .arg(Arg::new("amount").required(true).value_name("AMOUNT").value_parser(clap::value_parser!(f64)))
+                        .arg(Arg::new("destination").required(true).value_name("ADDRESS"))
+                        .arg(Arg::new("wallet").long("wallet").value_name("NAME").default_value("hot-wallet"))
+                )
+                .subcommand(
+                    Command::new("approve")
+                        .about("Release the sweep of a request")
+                        .arg(Arg::new("id").required(true).value_name("ID"))
+                        .arg(Arg::new("operator").long("operator").value_name("NAME").default_value("cli"))
+                )
+                .subcommand(Command::new("cancel").about("Cancel a request and release its lock").arg(Arg::new("id").required(true).value_name("ID")))
+        )
+        .subcommand(
+            Command::new("headroom")
+                .about("Per-token exposure limit, current exposure and room left")
+                .arg(Arg::new("asset").value_name("ASSET").help("Symbol or mint (every capped asset when omitted)"))
+                .arg(Arg::new("json").long("json").action(ArgAction::SetTrue).help("Print the raw report"))
+        )
+        .subcommand(
+            Command::new("status")
+                .about("Per-bot state, PnL today, open positions and recent errors (reads the local snapshot, no server needed)")
+                .arg(Arg::new("path").long("path").value_name("FILE").help("Snapshot file")
+                    .default_value(DEFAULT_STATUS_PATH))
+                .arg(Arg::new("json").long("json").action(ArgAction::SetTrue).help("Print the raw snapshot"))
+        )
+        .subcommand(
+            Command::new("chaos")
+                .about("Fault injection for resilience testing (server built with --features chaos)")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("set")
+                        .about("Arm a fault plan (replaces the current one)")
+                        .arg(Arg::new("rpc-drop").long("rpc-drop").value_name("RATE").value_parser(clap::value_parser!(f64))
+                            .help("Fraction of RPC calls to fail, 0-1"))
+                        .arg(Arg::new("provider").long("provider").value_name("NAME").action(ArgAction::Append)
+                            .help("Only drop calls to this provider (repeatable)"))
+                        .arg(Arg::new("confirm-delay").long("confirm-delay").value_name("SECS").value_parser(clap::value_parser!(u64))
+                            .help("Hide confirmations for this long after signing"))
+                        .arg(Arg::new("stale-prices").long("stale-prices").value_name("SECS").value_parser(clap::value_parser!(u64))
+                            .help("Serve cached prices this much older"))
+                )
+                .subcommand(Command::new("kill").about("Kill a supervised task").arg(Arg::new("task").required(true).value_name("NAME")))
+                .subcommand(Command::new("clear").about("Disarm all faults"))
+                .subcommand(Command::new("status").about("Armed faults and what they injected"))
+        )
+        .subcommand(
+            Command::new("watchlist")
+                .about("Manage the named token watchlists strategies monitor")
+                .subcommand_required(true)
+                .subcommand(Command::new("list").about("Show every watchlist"))
+                .subcommand(
+                    Command::new("set")
+                        .about("Create or replace a watchlist")
+                        .arg(Arg::new("name").required(true).value_name("NAME"))
+                        .arg(Arg::new("token").long("token").value_name("TOKEN").action(ArgAction::Append)
+                            .help("Symbol or mint to monitor (repeatable)"))
+                        .arg(Arg::new("strategy").long("strategy").value_name("STRATEGY").action(ArgAction::Append)
+                            .help("Strategy bound to the list (repeatable)"))
+                        .arg(Arg::new("refresh").long("refresh").value_name("SECS").value_parser(clap::value_parser!(u64)).default_value("60")
+                            .help("Refresh interval in seconds"))
+                        .arg(Arg::new("disabled").long("disabled").action(ArgAction::SetTrue)
+                            .help("Keep the list but stop monitoring it"))
+                )
+                .subcommand(Command::new("remove").about("Delete a watchlist").arg(Arg::new("name").required(true).value_name("NAME")))
+                .subcommand(
+                    Command::new("add")
+                        .about("Add tokens to a watchlist")
+                        .arg(Arg::new("name").required(true).value_name("NAME"))
+                        .arg(Arg::new("tokens").required(true).num_args(1..).value_name("TOKEN"))
+                )
+                .subcommand(
+                    Command::new("drop")
+                        .about("Remove tokens from a watchlist")
+                        .arg(Arg::new("name").required(true).value_name("NAME"))
+                        .arg(Arg::new("tokens").required(true).num_args(1..).value_name("TOKEN"))
+                )
+                .subcommand(
+                    Command::new("bind")
+                        .about("Replace the strategies bound to a watchlist")
+                        .arg(Arg::new("name").required(true).value_name("NAME"))
+                        .arg(Arg::new("strategies").num_args(0..).value_name("STRATEGY"))
+                )
+        )
+        .subcommand(
+            Command::new("consolidate-dust")
+                .about("Swap dust token balances to SOL and close the accounts for their rent")
+                .arg(Arg::new("execute").long("execute").action(ArgAction::SetTrue)
+                    .help("Send the transactions (default: cost/benefit preview only)"))
+        )
+        .subcommand(
+            Command::new("collect-diagnostics")
+                .about("Package recent logs, redacted config, recent trades/intents, health and version info into one archive")
+                .arg(Arg::new("note").long("note").value_name("TEXT").help("What happened, recorded in the bundle manifest"))
+                .arg(Arg::new("local").long("local").action(ArgAction::SetTrue)
+                    .help("Collect from local files without asking the server (used automatically when it is down)"))
+        )
+        .subcommand(
+            Command::new("sim-diff")
+                .about("Replay recorded decision inputs through two service builds and diff their trade decisions (no server needed)")
+                .arg(Arg::new("input").long("input").required(true).value_name("FILE")
+                    .help("Inputs recorded with SNIPERFORGE_RECORD_DECISION_INPUTS"))
+                .arg(Arg::new("baseline").long("baseline").required(true).value_name("BIN").help("Current sniperforge binary (A)"))
+                .arg(Arg::new("candidate").long("candidate").required(true).value_name("BIN").help("Candidate sniperforge binary (B)"))
+                .arg(Arg::new("size-tolerance").long("size-tolerance").value_name("PCT").value_parser(clap::value_parser!(f64))
+                    .default_value("1.0").help("Size differences up to this percent are not reported"))
+                .arg(Arg::new("json").long("json").action(ArgAction::SetTrue).help("Print the raw report"))
+        )
+        .subcommand(
+            Command::new("sweep")
+                .about("Backtest a strategy over recorded prices for a grid (or random sample) of parameters (no server needed)")
+                .arg(Arg::new("input").long("input").required(true).value_name("FILE")
+                    .help("Inputs recorded with SNIPERFORGE_RECORD_DECISION_INPUTS"))
+                .arg(Arg::new("mint").long("mint").required(true).value_name("MINT").help("Token whose recorded prices are replayed"))
+                .arg(Arg::new("strategy").long("strategy").value_name("NAME").default_value("momentum")
+                    .help("momentum or mean_reversion"))
+                .arg(Arg::new("param").long("param").required(true).action(ArgAction::Append).value_name("NAME=MIN:MAX:STEPS")
+                    .help("Parameter axis, repeatable (e.g. stop_loss_percent=2:6:5)"))
+                .arg(Arg::new("random").long("random").value_name("N").value_parser(clap::value_parser!(usize))
+                    .help("Evaluate N random combinations instead of the full grid"))
+                .arg(Arg::new("seed").long("seed").value_name("SEED").value_parser(clap::value_parser!(u64)).default_value("42"))
+                .arg(Arg::new("folds").long("folds").value_name("N").value_parser(clap::value_parser!(usize)).default_value("4")
+                    .help("Walk-forward test windows (0 = in-sample only)"))
+                .arg(Arg::new("min-trades").long("min-trades").value_name("N").value_parser(clap::value_parser!(usize)).default_value("10"))
+                .arg(Arg::new("fee-bps").long("fee-bps").value_name("BPS").value_parser(clap::value_parser!(f64)).default_value("10"))
+                .arg(Arg::new("json").long("json").action(ArgAction::SetTrue).help("Print the raw report"))
         );
 
     let matches = app.get_matches();
+    if let Some(locale) = matches.get_one::<String>("lang").and_then(|tag| Locale::from_tag(tag)) {
+        set_locale(locale);
+    }
 
     // Verificar si hay subcomando antes de conectar al servidor
     match matches.subcommand() {
         None => {
-            println!("❌ No subcommand provided.");
-            println!("\nAvailable commands:");
+            println!("❌ {}", t("cli.no_subcommand"));
+            println!("\n{}", t("cli.available_commands"));
             println!("  ping              Test server connection");
             println!("  interactive       Start interactive CLI mode");
             println!("  list-bots         List all registered bots");
@@ -139,12 +336,62 @@ async fn main() -> Result<()> {
             println!("  stop-bot          Stop a specific bot");
             println!("  system-state      Show system state and persistence information");
             println!("  backup-system     Create a backup of current system state");
+            println!("  bot-sandbox       Show a bot's sandbox directories and disk usage");
+            println!("  archive-bot       Archive one bot's files, data and logs");
             println!("  metrics-history   Show historical system metrics");
             println!("  force-save        Force save all current state to persistence");
             println!("  start-all         Start all registered bots");
             println!("  stop-all          Stop all running bots");
             println!("  resource-status   Show system resource usage and limits");
-            println!("\nUse: {} <COMMAND> --help for more information", std::env::args().next().unwrap_or("sniperforge-cli".to_string()));
+            println!("  annotate          Attach a note/tags to a trade or position");
+            println!("  annotations       List trade/position annotations");
+            println!("  export-journal    Export trades with annotations as CSV");
+            println!("  health            Composite subsystem health");
+            println!("  consolidate-dust  Swap dust to SOL and close token accounts");
+            println!("  status            Live status from the local snapshot (works without the server)");
+            println!("  chaos             Arm/clear fault injection (chaos builds only)");
+            println!("  watchlist         List and edit token watchlists and their strategy bindings");
+            println!("  sim-diff          Compare trade decisions of two builds on recorded inputs");
+            println!("  sweep             Walk-forward parameter sweep of a strategy over recorded prices");
+            println!("  collect-diagnostics  Bundle logs, redacted config, trades and health for support");
+            println!("\n{}", tf("cli.more_info", &[("program", &std::env::args().next().unwrap_or("sniperforge-cli".to_string()))]));
+            return Ok(());
+        }
+        Some(("status", sub_matches)) => {
+            let path = sub_matches.get_one::<String>("path").unwrap();
+            return print_status(std::path::Path::new(path), sub_matches.get_flag("json"));
+        }
+        Some(("sim-diff", sub_matches)) => {
+            let arg = |name: &str| sub_matches.get_one::<String>(name).unwrap().as_str();
+            return print_sim_diff(
+                arg("input"),
+                arg("baseline"),
+                arg("candidate"),
+                *sub_matches.get_one::<f64>("size-tolerance").unwrap(),
+                sub_matches.get_flag("json"),
+            );
+        }
+        Some(("sweep", sub_matches)) => {
+            return run_sweep(sub_matches);
+        }
+        Some(("collect-diagnostics", sub_matches)) => {
+            let note = sub_matches.get_one::<String>("note").cloned();
+            let server_addr = matches.get_one::<String>("server").unwrap();
+            // After a crash the server is usually gone: collect locally instead
+            let summary = match TcpBotClient::new(server_addr).await {
+                Ok(mut client) if !sub_matches.get_flag("local") => {
+                    match client.send_command(TcpCommand::CollectDiagnostics { note }).await? {
+                        TcpResponse::DiagnosticsCollected(summary) => summary,
+                        TcpResponse::Error(msg) => anyhow::bail!(msg),
+                        response => anyhow::bail!(tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
+                    }
+                }
+                _ => {
+                    println!("💡 Collecting locally (no live health report)");
+                    DiagnosticsCollector::new(DiagnosticsConfig::default()).collect(note, Utc::now())?
+                }
+            };
+            print_diagnostics_summary(&summary);
             return Ok(());
         }
         _ => {}
@@ -157,8 +404,8 @@ async fn main() -> Result<()> {
         Some(("ping", _)) => {
             let response = client.send_command(TcpCommand::Ping).await?;
             match response {
-                TcpResponse::Pong => println!("✅ Server is responsive"),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Pong => println!("✅ {}", t("cli.server_responsive")),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some(("interactive", _)) => {
@@ -183,7 +430,7 @@ async fn main() -> Result<()> {
             let response = client.send_command(TcpCommand::ListBots).await?;
             match response {
                 TcpResponse::BotList(bots) => {
-                    println!("📋 Registered Bots ({}):", bots.len());
+                    println!("📋 {}", tf("cli.registered_bots", &[("count", &bots.len())]));
                     for bot in bots {
                         let bot_type_display = match bot.bot_type {
                             BotType::EnhancedArbitrage => "🎯 Enhanced Arbitrage",
@@ -202,8 +449,8 @@ async fn main() -> Result<()> {
                         println!("     {}", bot_type_display);
                     }
                 }
-                TcpResponse::Error(msg) => println!("❌ Error: {}", msg),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some(("create-bot", sub_matches)) => {
@@ -219,8 +466,8 @@ async fn main() -> Result<()> {
                 "performance-profiler" => BotType::PerformanceProfiler,
                 "pattern-analyzer" => BotType::PatternAnalyzer,
                 _ => {
-                    println!("❌ Invalid bot type: {}", bot_type_str);
-                    println!("Available types: enhanced-arbitrage, triangular-arbitrage, flash-loan-arbitrage, cross-chain-arbitrage, ml-analytics, portfolio-manager, real-time-dashboard, performance-profiler, pattern-analyzer");
+                    println!("❌ {}", tf("cli.invalid_bot_type", &[("bot_type", bot_type_str)]));
+                    println!("{}", tf("cli.available_types", &[("types", &"enhanced-arbitrage, triangular-arbitrage, flash-loan-arbitrage, cross-chain-arbitrage, ml-analytics, portfolio-manager, real-time-dashboard, performance-profiler, pattern-analyzer")]));
                     return Ok(());
                 }
             };
@@ -230,12 +477,12 @@ async fn main() -> Result<()> {
             let response = client.send_command(TcpCommand::CreateBot { bot_type, config }).await?;
             match response {
                 TcpResponse::BotCreated { bot_id } => {
-                    println!("✅ Bot created successfully: {}", bot_id);
+                    println!("✅ {}", tf("cli.bot_created", &[("bot_id", &bot_id)]));
                     println!("   Type: {:?}", bot_type_str);
-                    println!("   Use 'start-bot --bot-id {}' to start it", bot_id);
+                    println!("   {}", tf("cli.bot_start_hint", &[("bot_id", &bot_id)]));
                 }
-                TcpResponse::Error(msg) => println!("❌ Error: {}", msg),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some(("bot-status", sub_matches)) => {
@@ -247,8 +494,8 @@ async fn main() -> Result<()> {
                 TcpResponse::BotStatus(status) => {
                     println!("🤖 Bot Status: {:?}", status);
                 }
-                TcpResponse::Error(msg) => println!("❌ Error: {}", msg),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some(("bot-metrics", sub_matches)) => {
@@ -260,8 +507,8 @@ async fn main() -> Result<()> {
                 TcpResponse::BotMetrics(metrics) => {
                     print_bot_metrics(&metrics);
                 }
-                TcpResponse::Error(msg) => println!("❌ Error: {}", msg),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some(("system-metrics", _)) => {
@@ -275,8 +522,8 @@ async fn main() -> Result<()> {
                     println!("   - Total Trades: {}", metrics.total_trades);
                     println!("   - Uptime: {:.2} hours", metrics.uptime_seconds as f64 / 3600.0);
                 }
-                TcpResponse::Error(msg) => println!("❌ Error: {}", msg),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some(("start-bot", sub_matches)) => {
@@ -291,8 +538,8 @@ async fn main() -> Result<()> {
                 TcpResponse::BotStarted { bot_id: started_id } => {
                     println!("✅ Bot started successfully: {}", started_id);
                 }
-                TcpResponse::Error(msg) => println!("❌ Error: {}", msg),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some(("stop-bot", sub_matches)) => {
@@ -304,8 +551,8 @@ async fn main() -> Result<()> {
                 TcpResponse::BotStopped { bot_id: stopped_id } => {
                     println!("✅ Bot stopped successfully: {}", stopped_id);
                 }
-                TcpResponse::Error(msg) => println!("❌ Error: {}", msg),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some(("system-state", _)) => {
@@ -326,8 +573,8 @@ async fn main() -> Result<()> {
                         println!("   💾 No persisted bots found");
                     }
                 }
-                TcpResponse::Error(msg) => println!("❌ Error: {}", msg),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some(("backup-system", _)) => {
@@ -338,8 +585,36 @@ async fn main() -> Result<()> {
                     println!("   📁 Backup location: {}", backup_path);
                     println!("   💡 Use this backup to restore system state if needed");
                 }
-                TcpResponse::Error(msg) => println!("❌ Error: {}", msg),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
+            }
+        }
+        Some(("bot-sandbox", sub_matches)) => {
+            let bot_id = sub_matches.get_one::<String>("bot-id").map(|id| Uuid::parse_str(id)).transpose()?;
+            let response = client.send_command(TcpCommand::GetSandboxUsage { bot_id }).await?;
+            match response {
+                TcpResponse::SandboxUsage(sandboxes) => {
+                    println!("📂 Bot Sandboxes ({}):", sandboxes.len());
+                    for usage in sandboxes {
+                        println!("   🤖 {} → {}", usage.bot_id, usage.root);
+                        println!("      work {} B | tmp {} B | data {} B | logs {} B | total {} B",
+                            usage.work_bytes, usage.tmp_bytes, usage.data_bytes, usage.log_bytes, usage.total_bytes());
+                    }
+                }
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
+            }
+        }
+        Some(("archive-bot", sub_matches)) => {
+            let bot_id = Uuid::parse_str(sub_matches.get_one::<String>("bot-id").unwrap())?;
+            let response = client.send_command(TcpCommand::ArchiveBotArtifacts { bot_id }).await?;
+            match response {
+                TcpResponse::BotArtifactsArchived { bot_id, path } => {
+                    println!("📦 Artifacts of bot {} archived:", bot_id);
+                    println!("   📁 Archive location: {}", path);
+                }
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some(("metrics-history", sub_matches)) => {
@@ -375,8 +650,8 @@ async fn main() -> Result<()> {
                         }
                     }
                 }
-                TcpResponse::Error(msg) => println!("❌ Error: {}", msg),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some(("force-save", _)) => {
@@ -387,8 +662,8 @@ async fn main() -> Result<()> {
                     println!("   💾 All current system state has been saved to persistence");
                     println!("   🔄 System can now be safely restarted");
                 }
-                TcpResponse::Error(msg) => println!("❌ Error: {}", msg),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some(("start-all", _)) => {
@@ -410,8 +685,8 @@ async fn main() -> Result<()> {
                     
                     println!("   📊 Total attempted: {}", result.total_attempted);
                 }
-                TcpResponse::Error(msg) => println!("❌ Error: {}", msg),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some(("stop-all", _)) => {
@@ -433,8 +708,8 @@ async fn main() -> Result<()> {
                     
                     println!("   📊 Total attempted: {}", result.total_attempted);
                 }
-                TcpResponse::Error(msg) => println!("❌ Error: {}", msg),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some(("resource-status", _)) => {
@@ -454,8 +729,224 @@ async fn main() -> Result<()> {
                         println!("   ✅ Resource usage within safe limits");
                     }
                 }
-                TcpResponse::Error(msg) => println!("❌ Error: {}", msg),
-                _ => println!("❌ Unexpected response: {:?}", response),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                _ => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
+            }
+        }
+        Some(("annotate", sub_matches)) => {
+            let Some(target) = annotation_target(sub_matches) else {
+                println!("❌ Specify --trade <SIGNATURE> or --position <POSITION_ID>");
+                return Ok(());
+            };
+            let command = TcpCommand::AnnotateTrade {
+                target,
+                note: sub_matches.get_one::<String>("note").cloned().unwrap_or_default(),
+                tags: sub_matches.get_many::<String>("tag").map(|tags| tags.cloned().collect()).unwrap_or_default(),
+                author: sub_matches.get_one::<String>("author").cloned().unwrap_or_default(),
+            };
+            match client.send_command(command).await? {
+                TcpResponse::Success(msg) => println!("📝 {}", msg),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                response => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
+            }
+        }
+        Some(("annotations", sub_matches)) => {
+            let target = annotation_target(sub_matches);
+            match client.send_command(TcpCommand::ListAnnotations { target }).await? {
+                TcpResponse::Success(json) => {
+                    let annotations: Vec<serde_json::Value> = serde_json::from_str(&json)?;
+                    println!("📝 {} annotations", annotations.len());
+                    for annotation in &annotations {
+                        println!("   {} {} by {}: {} [{}]",
+                            annotation["created_at"].as_str().unwrap_or_default(),
+                            annotation["target"],
+                            annotation["author"].as_str().unwrap_or_default(),
+                            annotation["note"].as_str().unwrap_or_default(),
+                            annotation["tags"].as_array().map(|tags| tags.iter().filter_map(|t| t.as_str()).collect::<Vec<_>>().join(", ")).unwrap_or_default());
+                    }
+                }
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                response => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
+            }
+        }
+        Some(("export-journal", sub_matches)) => {
+            let wallet = sub_matches.get_one::<String>("wallet").cloned();
+            match client.send_command(TcpCommand::ExportTradeJournal { wallet }).await? {
+                TcpResponse::Success(csv) => match sub_matches.get_one::<String>("output") {
+                    Some(path) => {
+                        std::fs::write(path, &csv)?;
+                        println!("✅ Trade journal written to {} ({} rows)", path, csv.lines().count().saturating_sub(1));
+                    }
+                    None => print!("{}", csv),
+                },
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                response => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
+            }
+        }
+        Some(("health", sub_matches)) => {
+            match client.send_command(TcpCommand::GetHealth).await? {
+                TcpResponse::Success(json) if sub_matches.get_flag("json") => println!("{}", json),
+                TcpResponse::Success(json) => {
+                    let report: HealthReport = serde_json::from_str(&json)?;
+                    let icon = |state: HealthState| match state {
+                        HealthState::Healthy => "✅",
+                        HealthState::Degraded => "⚠️",
+                        HealthState::Unhealthy => "❌",
+                    };
+                    println!("{} System {:?} at {}", icon(report.status), report.status, report.checked_at);
+                    for component in &report.components {
+                        println!("   {} {:<18} [{}] {}ms, last ok {}{}",
+                            icon(component.status), component.name, component.kind, component.latency_ms,
+                            component.last_success.map(|t| t.to_rfc3339()).unwrap_or_else(|| "never".to_string()),
+                            component.detail.as_ref().map(|d| format!(" - {}", d)).unwrap_or_default());
+                    }
+                }
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                response => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
+            }
+        }
+        Some(("withdraw", sub_matches)) => {
+            let id = |m: &clap::ArgMatches| m.get_one::<String>("id").unwrap().clone();
+            let command = match sub_matches.subcommand() {
+                Some(("request", m)) => TcpCommand::RequestWithdrawal {
+                    wallet: m.get_one::<String>("wallet").unwrap().clone(),
+                    asset: m.get_one::<String>("asset").unwrap().clone(),
+                    amount: *m.get_one::<f64>("amount").unwrap(),
+                    destination: m.get_one::<String>("destination").unwrap().clone(),
+                },
+                Some(("approve", m)) => TcpCommand::ApproveWithdrawal { id: id(m), operator: m.get_one::<String>("operator").unwrap().clone() },
+                Some(("cancel", m)) => TcpCommand::CancelWithdrawal { id: id(m) },
+                _ => TcpCommand::ListWithdrawals,
+            };
+            match client.send_command(command).await? {
+                TcpResponse::Success(json) => {
+                    let requests: Vec<WithdrawalRequest> = serde_json::from_str(&json)?;
+                    if requests.is_empty() {
+                        println!("ℹ️ No withdrawal requests");
+                    }
+                    for request in &requests {
+                        println!("🏧 {} {:?}: {:.4} {} from {} to {}{}",
+                            request.id, request.state, request.amount, request.asset, request.wallet, request.destination,
+                            request.approved_by.as_ref().map(|operator| format!(" (approved by {})", operator)).unwrap_or_default());
+                        if let Some(error) = &request.error {
+                            println!("   {}", error);
+                        }
+                    }
+                }
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                response => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
+            }
+        }
+        Some(("headroom", sub_matches)) => {
+            let asset = sub_matches.get_one::<String>("asset").cloned();
+            match client.send_command(TcpCommand::GetRiskHeadroom { asset }).await? {
+                TcpResponse::Success(json) if sub_matches.get_flag("json") => println!("{}", json),
+                TcpResponse::Success(json) => {
+                    let headrooms: Vec<TokenHeadroom> = serde_json::from_str(&json)?;
+                    if headrooms.is_empty() {
+                        println!("ℹ️ No capped assets yet");
+                    }
+                    for headroom in &headrooms {
+                        println!("🎚️ {:<12} {:?}{} limit {:.4}, exposure {:.4}, room {:.4} ({:.1}% used)",
+                            headroom.asset, headroom.tier, if headroom.overridden { " (override)" } else { "" },
+                            headroom.limit, headroom.exposure, headroom.remaining, headroom.utilization_percent);
+                    }
+                }
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                response => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
+            }
+        }
+        Some(("chaos", sub_matches)) => {
+            let command = match sub_matches.subcommand() {
+                Some(("set", set)) => TcpCommand::SetFaultPlan {
+                    plan: FaultPlan {
+                        rpc_drop_rate: set.get_one::<f64>("rpc-drop").copied().unwrap_or(0.0),
+                        rpc_providers: set.get_many::<String>("provider").map(|p| p.cloned().collect()).unwrap_or_default(),
+                        confirmation_delay_secs: set.get_one::<u64>("confirm-delay").copied().unwrap_or(0),
+                        stale_price_secs: set.get_one::<u64>("stale-prices").copied().unwrap_or(0),
+                        ..Default::default()
+                    },
+                },
+                Some(("kill", kill)) => TcpCommand::KillTask { name: kill.get_one::<String>("task").unwrap().clone() },
+                Some(("clear", _)) => TcpCommand::ClearFaults,
+                _ => TcpCommand::GetFaults,
+            };
+            let is_status = matches!(command, TcpCommand::GetFaults);
+            match client.send_command(command).await? {
+                TcpResponse::Success(json) if is_status => {
+                    let status: ChaosStatus = serde_json::from_str(&json)?;
+                    if !status.compiled_in {
+                        println!("ℹ️ Server built without the `chaos` feature; no faults can be armed");
+                    }
+                    println!("💥 Plan: {:?}", status.plan);
+                    println!("   Pending kills: {:?}", status.pending_kills);
+                    println!("   Injected: {} RPC drops, {} hidden confirmations, {} stale prices, {} task kills",
+                        status.stats.rpc_calls_dropped, status.stats.confirmations_hidden,
+                        status.stats.stale_prices_served, status.stats.tasks_killed);
+                }
+                TcpResponse::Success(message) => println!("✅ {}", message),
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                response => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
+            }
+        }
+        Some(("watchlist", sub_matches)) => {
+            let name = |m: &clap::ArgMatches| m.get_one::<String>("name").unwrap().clone();
+            let values = |m: &clap::ArgMatches, id: &str| m.get_many::<String>(id).map(|v| v.cloned().collect()).unwrap_or_default();
+            let command = match sub_matches.subcommand() {
+                Some(("set", set)) => TcpCommand::UpsertWatchlist {
+                    watchlist: Watchlist {
+                        name: name(set),
+                        tokens: values(set, "token"),
+                        refresh_interval_secs: *set.get_one::<u64>("refresh").unwrap(),
+                        strategies: values(set, "strategy"),
+                        enabled: !set.get_flag("disabled"),
+                    },
+                },
+                Some(("remove", m)) => TcpCommand::RemoveWatchlist { name: name(m) },
+                Some(("add", m)) => TcpCommand::AddWatchlistTokens { name: name(m), tokens: values(m, "tokens") },
+                Some(("drop", m)) => TcpCommand::RemoveWatchlistTokens { name: name(m), tokens: values(m, "tokens") },
+                Some(("bind", m)) => TcpCommand::BindWatchlistStrategies { name: name(m), strategies: values(m, "strategies") },
+                _ => TcpCommand::ListWatchlists,
+            };
+            let is_list = matches!(command, TcpCommand::ListWatchlists);
+            match client.send_command(command).await? {
+                TcpResponse::Success(json) => {
+                    let watchlists: Vec<Watchlist> = if is_list {
+                        serde_json::from_str(&json)?
+                    } else {
+                        vec![serde_json::from_str(&json)?]
+                    };
+                    for watchlist in watchlists {
+                        let state = if watchlist.enabled { "👀" } else { "💤" };
+                        println!("{} {} (every {}s) -> {:?}", state, watchlist.name, watchlist.refresh_interval_secs, watchlist.strategies);
+                        println!("   {}", watchlist.tokens.join(", "));
+                    }
+                }
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                response => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
+            }
+        }
+        Some(("consolidate-dust", sub_matches)) => {
+            let dry_run = !sub_matches.get_flag("execute");
+            match client.send_command(TcpCommand::ConsolidateDust { dry_run }).await? {
+                TcpResponse::Success(json) => {
+                    let report: DustReport = serde_json::from_str(&json)?;
+                    for entry in &report.entries {
+                        let net = entry.candidate.as_ref().map(|c| format!("{:+.6} SOL", c.net_lamports as f64 / 1e9)).unwrap_or_default();
+                        match &entry.outcome {
+                            DustOutcome::Planned => println!("   📋 {} ({}) {}", entry.account, entry.mint, net),
+                            DustOutcome::Consolidated { close_signature, .. } => println!("   ✅ {} ({}) {} closed in {}", entry.account, entry.mint, net, close_signature),
+                            DustOutcome::Skipped { reason } => println!("   ⏭️  {} ({}): {}", entry.account, entry.mint, reason),
+                            DustOutcome::Failed { reason } => println!("   ❌ {} ({}): {}", entry.account, entry.mint, reason),
+                        }
+                    }
+                    println!("🧹 {}", report.summary());
+                    if dry_run {
+                        println!("   Run with --execute to consolidate");
+                    }
+                }
+                TcpResponse::Error(msg) => println!("❌ {}", tf("cli.error", &[("error", &msg)])),
+                response => println!("❌ {}", tf("cli.unexpected_response", &[("response", &format!("{:?}", response))])),
             }
         }
         Some((unknown_cmd, _)) => {
@@ -473,13 +964,159 @@ async fn main() -> Result<()> {
 fn print_bot_metrics(metrics: &BotMetrics) {
     println!("📈 Bot Metrics:");
     println!("   - Trades Executed: {}", metrics.trading.trades_executed);
-    println!("   - Total P&L: ${:.2}", metrics.trading.total_pnl_usd);
+    println!("   - Total P&L: {}", format_usd(metrics.trading.total_pnl_usd));
     println!("   - Success Rate: {:.1}%", metrics.trading.success_rate * 100.0);
     println!("   - Uptime: {} seconds", metrics.operational.uptime_seconds);
     println!("   - CPU Usage: {:.1}%", metrics.performance.cpu_usage_percent);
     println!("   - Memory Usage: {} MB", metrics.performance.memory_usage_mb);
 }
 
+/// Signed USD amount, or "n/a" when the bot had no exchange rate to report it with
+fn format_usd(value: Option<f64>) -> String {
+    value.map_or_else(|| "n/a".to_string(), |usd| format!("${:+.2}", usd))
+}
+
+/// Snapshots older than this mean the service stopped writing them
+const STATUS_STALE_SECS: i64 = 30;
+
+fn print_diagnostics_summary(summary: &DiagnosticsSummary) {
+    println!("🧰 Diagnostics bundle created:");
+    println!("   📁 {} ({} KB, {} files)", summary.path, summary.bytes / 1024, summary.files);
+    println!("   🔒 {} secrets redacted", summary.redactions);
+    if !summary.skipped.is_empty() {
+        println!("   ⏭️  Not collected:");
+        for reason in &summary.skipped {
+            println!("      - {}", reason);
+        }
+    }
+    println!("   💡 Review the archive before sharing it");
+}
+
+fn print_status(path: &std::path::Path, json: bool) -> Result<()> {
+    let snapshot = match StatusSnapshot::read(path) {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            println!("❌ No status snapshot at {} ({}); is the service running?", path.display(), e);
+            return Ok(());
+        }
+    };
+    if json {
+        println!("{}", serde_json::to_string_pretty(&snapshot)?);
+        return Ok(());
+    }
+
+    let age = snapshot.age(Utc::now()).num_seconds();
+    if age > STATUS_STALE_SECS {
+        println!("⚠️ Snapshot is {}s old: the service (pid {}) may be down", age, snapshot.pid);
+    }
+    println!("📊 SniperForge status at {} (pid {}, up since {})", snapshot.generated_at, snapshot.pid, snapshot.started_at);
+    println!("   PnL today ({}): ${:+.2}   Open positions: {}", snapshot.day, snapshot.pnl_today_usd, snapshot.open_positions);
+    if let Some(reason) = &snapshot.trading_halted {
+        println!("   🛑 Trading halted: {}", reason);
+    }
+    println!("\n🤖 Bots ({}):", snapshot.bots.len());
+    for bot in &snapshot.bots {
+        println!("   {} {:<22} {:<12} today {}  total {}  positions {}  trades {}",
+            bot.id, bot.bot_type, bot.state, format_usd(bot.pnl_today_usd), format_usd(bot.total_pnl_usd), bot.open_positions, bot.trades);
+        if let Some(error) = &bot.last_error {
+            println!("      ❌ {}", error);
+        }
+    }
+    if !snapshot.recent_errors.is_empty() {
+        println!("\n❌ Recent errors:");
+        for error in snapshot.recent_errors.iter().rev().take(5) {
+            println!("   {} [{}] {}", error.at.format("%H:%M:%S"), error.source, error.message);
+        }
+    }
+    Ok(())
+}
+
+/// Run one build in replay mode and load its decisions
+fn replay_decisions(binary: &str, input: &str, label: &str) -> Result<Vec<Decision>> {
+    let out = std::env::temp_dir().join(format!("sniperforge-decisions-{}-{}.jsonl", label, Uuid::new_v4()));
+    let output = std::process::Command::new(binary)
+        .arg("--replay").arg(input)
+        .arg("--decisions-out").arg(&out)
+        .output()
+        .map_err(|e| anyhow::anyhow!("cannot run {}: {}", binary, e))?;
+    if !output.status.success() {
+        anyhow::bail!("{} replay failed ({}): {}", binary, output.status, String::from_utf8_lossy(&output.stderr).trim());
+    }
+    let decisions = read_decisions(&out);
+    let _ = std::fs::remove_file(&out);
+    decisions
+}
+
+fn print_sim_diff(input: &str, baseline: &str, candidate: &str, size_tolerance: f64, json: bool) -> Result<()> {
+    let a = replay_decisions(baseline, input, "a")?;
+    let b = replay_decisions(candidate, input, "b")?;
+    let report = DecisionDiffReport::compare(&a, &b, size_tolerance);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!("🔀 A = {}\n   B = {}\n", baseline, candidate);
+        print!("{}", report.render());
+    }
+    Ok(())
+}
+
+/// Parse `name=min:max:steps` (or `name=value` for a fixed parameter)
+fn parse_param_axis(spec: &str) -> Result<ParamAxis> {
+    let (name, range) = spec.split_once('=').ok_or_else(|| anyhow::anyhow!("expected NAME=MIN:MAX:STEPS, got '{}'", spec))?;
+    if !StrategyConfig::TUNABLE_PARAMETERS.contains(&name) {
+        anyhow::bail!("unknown parameter '{}' (tunable: {})", name, StrategyConfig::TUNABLE_PARAMETERS.join(", "));
+    }
+    let parts: Vec<&str> = range.split(':').collect();
+    let number = |s: &str| s.trim().parse::<f64>().map_err(|e| anyhow::anyhow!("{}: bad number '{}': {}", name, s, e));
+    match parts.as_slice() {
+        [value] => Ok(ParamAxis::new(name, vec![number(*value)?])),
+        [min, max, steps] => {
+            let steps = steps.trim().parse::<usize>().map_err(|e| anyhow::anyhow!("{}: bad step count '{}': {}", name, steps, e))?;
+            Ok(ParamAxis::linear(name, number(*min)?, number(*max)?, steps))
+        }
+        _ => anyhow::bail!("expected NAME=MIN:MAX:STEPS, got '{}'", spec),
+    }
+}
+
+fn run_sweep(matches: &clap::ArgMatches) -> Result<()> {
+    let input = matches.get_one::<String>("input").unwrap();
+    let mint = matches.get_one::<String>("mint").unwrap();
+    let strategy: BacktestStrategy = matches.get_one::<String>("strategy").unwrap().parse()?;
+    let axes = matches
+        .get_many::<String>("param")
+        .unwrap()
+        .map(|spec| parse_param_axis(spec))
+        .collect::<Result<Vec<_>>>()?;
+
+    let file = std::fs::File::open(input).map_err(|e| anyhow::anyhow!("cannot open {}: {}", input, e))?;
+    let backtest = PriceReplayBacktest::from_recorded_inputs(strategy, std::io::BufReader::new(file), mint)?
+        .with_fee_bps(*matches.get_one::<f64>("fee-bps").unwrap());
+
+    let mode = match matches.get_one::<usize>("random") {
+        Some(samples) => SearchMode::Random { samples: *samples, seed: *matches.get_one::<u64>("seed").unwrap() },
+        None => SearchMode::Grid,
+    };
+    let sweep = ParameterSweep::new(axes, SweepConfig {
+        mode,
+        walk_forward_folds: *matches.get_one::<usize>("folds").unwrap(),
+        min_trades: *matches.get_one::<usize>("min-trades").unwrap(),
+        ..SweepConfig::default()
+    });
+    let report = sweep.run(&backtest);
+
+    if matches.get_flag("json") {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!("🧪 {:?} on {} ({} recorded prices)\n", strategy, mint, backtest.len());
+        print!("{}", report.table());
+        match report.best() {
+            Some(best) => println!("\n🏆 best: {:?}", best.params),
+            None => println!("\n⚠️ every combination was flagged as overfit"),
+        }
+    }
+    Ok(())
+}
+
 fn create_default_bot_config(_bot_id: Uuid) -> BotConfig {
     create_default_bot_config_for_type(BotType::EnhancedArbitrage)
 }
@@ -570,6 +1207,11 @@ fn create_default_bot_config_for_type(bot_type: BotType) -> BotConfig {
     }
 }
 
+fn annotation_target(matches: &clap::ArgMatches) -> Option<AnnotationTarget> {
+    matches.get_one::<String>("trade").map(|sig| AnnotationTarget::Trade(sig.clone()))
+        .or_else(|| matches.get_one::<String>("position").map(|id| AnnotationTarget::Position(id.clone())))
+}
+
 struct TcpBotClient {
     stream: TcpStream,
 }
@@ -586,13 +1228,17 @@ impl TcpBotClient {
         let data = format!("{}\n", command_json);
         self.stream.write_all(data.as_bytes()).await?;
 
-        // Read response
+        // Read until the response parses: large payloads (journal exports) span several reads
+        let mut response_data = Vec::new();
         let mut buffer = vec![0; 8192];
-        let n = self.stream.read(&mut buffer).await?;
-        let response_str = String::from_utf8_lossy(&buffer[..n]);
-        
-        // Parse response
-        let response: TcpResponse = serde_json::from_str(response_str.trim())?;
-        Ok(response)
+        loop {
+            let n = self.stream.read(&mut buffer).await?;
+            response_data.extend_from_slice(&buffer[..n]);
+            match serde_json::from_slice::<TcpResponse>(&response_data) {
+                Ok(response) => return Ok(response),
+                Err(e) if e.is_eof() && n > 0 => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
     }
 }
@@ -528,7 +528,10 @@ impl InteractiveCli {
                     TcpResponse::BotMetrics(metrics) => {
                         println!("📈 Bot Metrics: {}", name);
                         println!("   Trades: {}", metrics.trading.trades_executed);
-                        println!("   P&L: ${:.2}", metrics.trading.total_pnl_usd);
+                        match metrics.trading.total_pnl_usd {
+                            Some(pnl) => println!("   P&L: ${:.2}", pnl),
+                            None => println!("   P&L: n/a (no USD rate)"),
+                        }
                         println!("   Success Rate: {:.1}%", metrics.trading.success_rate * 100.0);
                         println!("   Uptime: {}s", metrics.operational.uptime_seconds);
                         println!("   CPU: {:.1}%", metrics.performance.cpu_usage_percent);
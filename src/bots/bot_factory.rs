@@ -1,6 +1,7 @@
 //! Bot Factory - Creates and manages bot instances for the containerized ecosystem
 
 use crate::api::bot_interface::{BotInterface, BotType, BotConfig, BotError};
+use crate::api::config_schema::bot_config_schema_json;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -281,19 +282,7 @@ impl BotRegistry {
                 min_disk_mb: 512,
                 network_required: true,
             },
-            config_schema: serde_json::json!({
-                "type": "object",
-                "properties": {
-                    "trading": {
-                        "type": "object",
-                        "properties": {
-                            "min_profit_bps": {"type": "number", "minimum": 1, "maximum": 1000},
-                            "max_trade_sol": {"type": "number", "minimum": 0.01, "maximum": 1000},
-                            "confidence_threshold": {"type": "number", "minimum": 0, "maximum": 1}
-                        }
-                    }
-                }
-            }),
+            config_schema: bot_config_schema_json(&BotType::EnhancedArbitrage),
         });
         
         // Add metadata for other bot types...
@@ -3,6 +3,7 @@
 //! This module implements a real-time dashboard bot that aggregates and displays
 //! system metrics, trading performance, and overall ecosystem status.
 
+use tracing::info;
 use async_trait::async_trait;
 use uuid::Uuid;
 
@@ -57,7 +58,7 @@ impl BotInterface for DashboardBot {
     }
 
     async fn start(&mut self, config: BotConfig) -> Result<(), BotError> {
-        println!("🚀 Starting Dashboard Bot: {}", self.name);
+        info!("🚀 Starting Dashboard Bot: {}", self.name);
         self.config = config;
         self.status = BotStatus::Running;
         self.start_time = Some(chrono::Utc::now());
@@ -66,7 +67,7 @@ impl BotInterface for DashboardBot {
     }
 
     async fn stop(&mut self) -> Result<(), BotError> {
-        println!("🛑 Stopping Dashboard Bot: {}", self.name);
+        info!("🛑 Stopping Dashboard Bot: {}", self.name);
         self.status = BotStatus::Stopped;
         Ok(())
     }
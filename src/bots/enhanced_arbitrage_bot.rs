@@ -3,6 +3,7 @@
 //! This module implements an advanced arbitrage bot with ML-enhanced opportunity detection.
 //! It uses sophisticated algorithms to identify and execute arbitrage opportunities across multiple exchanges.
 
+use tracing::{info, warn};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -242,32 +243,32 @@ impl EnhancedArbitrageBot {
 
     /// Initialize arbitrage strategy
     async fn initialize_strategy(&mut self) -> Result<(), BotError> {
-        println!("🤖 Initializing Enhanced Arbitrage Strategy for bot: {}", self.name);
+        info!("🤖 Initializing Enhanced Arbitrage Strategy for bot: {}", self.name);
         
         let config = self.parse_config()?;
         
         // Initialize exchange connections
         for exchange in &config.exchanges {
-            println!("  📡 Connecting to exchange: {}", exchange);
+            info!("  📡 Connecting to exchange: {}", exchange);
             // TODO: Implement actual exchange connection
             tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
         }
         
         // Initialize ML model if enabled
         if config.ml_model_enabled {
-            println!("  🧠 Loading ML model for opportunity detection");
+            info!("  🧠 Loading ML model for opportunity detection");
             // TODO: Implement ML model loading
             tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
         }
         
         // Initialize trading pairs monitoring
         for pair in &config.pairs {
-            println!("  📊 Setting up monitoring for pair: {}", pair);
+            info!("  📊 Setting up monitoring for pair: {}", pair);
             // TODO: Implement pair monitoring setup
             tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
         }
         
-        println!("✅ Enhanced Arbitrage Strategy initialized successfully");
+        info!("✅ Enhanced Arbitrage Strategy initialized successfully");
         Ok(())
     }
 
@@ -275,13 +276,13 @@ impl EnhancedArbitrageBot {
     async fn run_arbitrage_loop(&mut self) -> Result<(), BotError> {
         let config = self.parse_config()?;
         
-        println!("🔄 Starting arbitrage detection loop for bot: {}", self.name);
+        info!("🔄 Starting arbitrage detection loop for bot: {}", self.name);
         
         while self.status == BotStatus::Running {
             // Scan for arbitrage opportunities
             if let Err(e) = self.scan_arbitrage_opportunities(&config).await {
                 self.error_count += 1;
-                eprintln!("❌ Error scanning arbitrage opportunities: {}", e);
+                warn!("❌ Error scanning arbitrage opportunities: {}", e);
                 
                 if self.error_count > 10 {
                     self.status = BotStatus::Error("Too many consecutive errors".to_string());
@@ -319,7 +320,7 @@ impl EnhancedArbitrageBot {
             
             // Analyze arbitrage opportunity
             if let Some(opportunity) = self.analyze_arbitrage_opportunity(&prices, config) {
-                println!("💰 Arbitrage opportunity found for {}: {:.4}% profit", 
+                info!("💰 Arbitrage opportunity found for {}: {:.4}% profit", 
                         pair, opportunity.profit_percentage * 100.0);
                 
                 // Execute arbitrage if profitable
@@ -417,10 +418,10 @@ impl EnhancedArbitrageBot {
         let estimated_profit = opportunity.profit_percentage * opportunity.estimated_volume;
         let risk_ratio = opportunity.estimated_volume / config.max_position_size;
         
-        println!("🚀 Executing arbitrage: Buy {} @ {:.2} | Sell {} @ {:.2}", 
+        info!("🚀 Executing arbitrage: Buy {} @ {:.2} | Sell {} @ {:.2}", 
                 opportunity.buy_exchange, opportunity.buy_price,
                 opportunity.sell_exchange, opportunity.sell_price);
-        println!("📊 Volume: ${:.2} | Est. Profit: ${:.2} | Risk: {:.1}%",
+        info!("📊 Volume: ${:.2} | Est. Profit: ${:.2} | Risk: {:.1}%",
                 opportunity.estimated_volume, estimated_profit, risk_ratio * 100.0);
         
         // ✅ ENRIQUECIMIENTO: Validar volumen antes de ejecutar
@@ -447,7 +448,7 @@ impl EnhancedArbitrageBot {
         
         // Simulate execution success/failure
         if rand::random::<f64>() < success_rate {
-            println!("✅ Arbitrage executed successfully (volume: ${:.2})", opportunity.estimated_volume);
+            info!("✅ Arbitrage executed successfully (volume: ${:.2})", opportunity.estimated_volume);
             tracing::info!("🎯 Arbitrage profit: ${:.2} from volume ${:.2}", estimated_profit, opportunity.estimated_volume);
         } else {
             return Err(BotError::Internal(format!("Order execution failed for volume ${:.2}", opportunity.estimated_volume)));
@@ -500,7 +501,7 @@ impl BotInterface for EnhancedArbitrageBot {
     }
 
     async fn start(&mut self, config: BotConfig) -> Result<(), BotError> {
-        println!("🚀 Starting Enhanced Arbitrage Bot: {}", self.name);
+        info!("🚀 Starting Enhanced Arbitrage Bot: {}", self.name);
         
         // Update configuration
         self.config = config;
@@ -526,16 +527,16 @@ impl BotInterface for EnhancedArbitrageBot {
         
         tokio::spawn(async move {
             if let Err(e) = bot_clone.run_arbitrage_loop().await {
-                eprintln!("❌ Enhanced Arbitrage Bot error: {}", e);
+                warn!("❌ Enhanced Arbitrage Bot error: {}", e);
             }
         });
         
-        println!("✅ Enhanced Arbitrage Bot started successfully");
+        info!("✅ Enhanced Arbitrage Bot started successfully");
         Ok(())
     }
 
     async fn stop(&mut self) -> Result<(), BotError> {
-        println!("🛑 Stopping Enhanced Arbitrage Bot: {}", self.name);
+        info!("🛑 Stopping Enhanced Arbitrage Bot: {}", self.name);
         
         self.status = BotStatus::Stopped;
         
@@ -544,24 +545,24 @@ impl BotInterface for EnhancedArbitrageBot {
         // - Close exchange connections
         // - Save state
         
-        println!("✅ Enhanced Arbitrage Bot stopped successfully");
+        info!("✅ Enhanced Arbitrage Bot stopped successfully");
         Ok(())
     }
     
     async fn pause(&mut self) -> Result<(), BotError> {
-        println!("⏸️ Pausing Enhanced Arbitrage Bot: {}", self.name);
+        info!("⏸️ Pausing Enhanced Arbitrage Bot: {}", self.name);
         self.status = BotStatus::Paused;
         Ok(())
     }
     
     async fn resume(&mut self) -> Result<(), BotError> {
-        println!("▶️ Resuming Enhanced Arbitrage Bot: {}", self.name);
+        info!("▶️ Resuming Enhanced Arbitrage Bot: {}", self.name);
         self.status = BotStatus::Running;
         Ok(())
     }
 
     async fn update_config(&mut self, config: BotConfig) -> Result<(), BotError> {
-        println!("⚙️ Updating Enhanced Arbitrage Bot configuration: {}", self.name);
+        info!("⚙️ Updating Enhanced Arbitrage Bot configuration: {}", self.name);
         
         // Validate new configuration
         Self::validate_config(&config)?;
@@ -577,7 +578,7 @@ impl BotInterface for EnhancedArbitrageBot {
             self.start(config_copy).await?;
         }
         
-        println!("✅ Enhanced Arbitrage Bot configuration updated successfully");
+        info!("✅ Enhanced Arbitrage Bot configuration updated successfully");
         Ok(())
     }
 
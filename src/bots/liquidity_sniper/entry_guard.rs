@@ -0,0 +1,280 @@
+// SniperForge Enterprise v3.0 - Pre-Entry Rate-of-Change Guard
+// Rejects or downsizes entries into tokens that just pumped or saw a volume spike
+
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, VecDeque};
+use tokio::sync::RwLock;
+use tracing::{debug, warn};
+
+use super::SniperStrategy;
+
+/// Rate-of-change guard thresholds
+#[derive(Debug, Clone)]
+pub struct RocGuardConfig {
+    /// Guard enabled
+    pub enabled: bool,
+
+    /// Window for price rate-of-change (seconds)
+    pub price_window_seconds: i64,
+
+    /// Price change in the window above which the entry is rejected (%)
+    pub reject_price_change_percent: f64,
+
+    /// Price change in the window above which the entry is downsized (%)
+    pub downsize_price_change_percent: f64,
+
+    /// Recent window for traded volume (seconds)
+    pub volume_window_seconds: i64,
+
+    /// Baseline window the recent volume is compared against (seconds)
+    pub volume_baseline_seconds: i64,
+
+    /// Recent/baseline volume-rate ratio above which the entry is rejected
+    pub reject_volume_spike_ratio: f64,
+
+    /// Recent/baseline volume-rate ratio above which the entry is downsized
+    pub downsize_volume_spike_ratio: f64,
+
+    /// Position multiplier applied when downsizing (0-1)
+    pub downsize_factor: f64,
+}
+
+impl Default for RocGuardConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            price_window_seconds: 120,           // 2 minute window
+            reject_price_change_percent: 300.0,  // +300% in 2 min = exit liquidity
+            downsize_price_change_percent: 100.0, // +100% in 2 min = half size
+            volume_window_seconds: 60,
+            volume_baseline_seconds: 900,        // 15 min baseline
+            reject_volume_spike_ratio: 20.0,
+            downsize_volume_spike_ratio: 5.0,
+            downsize_factor: 0.5,
+        }
+    }
+}
+
+/// Guard decision for a prospective entry
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntryGuardDecision {
+    Allow,
+    Downsize { factor: f64, reason: String },
+    Reject { reason: String },
+}
+
+impl EntryGuardDecision {
+    /// Position multiplier implied by the decision
+    pub fn size_multiplier(&self) -> f64 {
+        match self {
+            Self::Allow => 1.0,
+            Self::Downsize { factor, .. } => *factor,
+            Self::Reject { .. } => 0.0,
+        }
+    }
+}
+
+/// Price/volume observation for a token
+#[derive(Debug, Clone)]
+pub struct PriceSample {
+    pub timestamp: DateTime<Utc>,
+    pub price: f64,
+    /// Volume traded since the previous sample (USD)
+    pub volume_usd: f64,
+}
+
+/// Short-window momentum metrics for a token
+#[derive(Debug, Clone, Default)]
+pub struct RocMetrics {
+    pub price_change_percent: Option<f64>,
+    pub volume_spike_ratio: Option<f64>,
+}
+
+/// Pre-entry rate-of-change circuit breaker
+#[derive(Debug)]
+pub struct PriceRocGuard {
+    default_config: RocGuardConfig,
+    strategy_configs: HashMap<SniperStrategy, RocGuardConfig>,
+    samples: RwLock<HashMap<String, VecDeque<PriceSample>>>,
+}
+
+impl PriceRocGuard {
+    /// Create a guard with default thresholds and per-strategy overrides
+    pub fn new(default_config: RocGuardConfig, strategy_configs: HashMap<SniperStrategy, RocGuardConfig>) -> Self {
+        Self {
+            default_config,
+            strategy_configs,
+            samples: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Thresholds for a strategy (override or default)
+    pub fn config_for(&self, strategy: &SniperStrategy) -> &RocGuardConfig {
+        self.strategy_configs.get(strategy).unwrap_or(&self.default_config)
+    }
+
+    /// Record a price/volume observation
+    pub async fn record_sample(&self, token_address: &str, sample: PriceSample) {
+        let retention = self.max_retention();
+        let mut samples = self.samples.write().await;
+        let history = samples.entry(token_address.to_string()).or_default();
+        history.push_back(sample);
+
+        let cutoff = Utc::now() - retention;
+        while history.front().map_or(false, |s| s.timestamp < cutoff) {
+            history.pop_front();
+        }
+    }
+
+    /// Compute rate-of-change metrics under a given config
+    pub async fn metrics(&self, token_address: &str, config: &RocGuardConfig) -> RocMetrics {
+        let samples = self.samples.read().await;
+        let Some(history) = samples.get(token_address) else {
+            return RocMetrics::default();
+        };
+        let now = Utc::now();
+
+        // Price change: oldest sample inside the window vs latest
+        let window_start = now - Duration::seconds(config.price_window_seconds);
+        let price_change_percent = match (
+            history.iter().find(|s| s.timestamp >= window_start),
+            history.back(),
+        ) {
+            (Some(first), Some(last)) if first.price > 0.0 && first.timestamp < last.timestamp => {
+                Some((last.price - first.price) / first.price * 100.0)
+            }
+            _ => None,
+        };
+
+        // Volume spike: recent volume rate vs baseline volume rate
+        let recent_start = now - Duration::seconds(config.volume_window_seconds);
+        let baseline_start = now - Duration::seconds(config.volume_baseline_seconds);
+        let recent: f64 = history.iter().filter(|s| s.timestamp >= recent_start).map(|s| s.volume_usd).sum();
+        let baseline: f64 = history
+            .iter()
+            .filter(|s| s.timestamp >= baseline_start && s.timestamp < recent_start)
+            .map(|s| s.volume_usd)
+            .sum();
+        let baseline_seconds = (config.volume_baseline_seconds - config.volume_window_seconds).max(1) as f64;
+        let volume_spike_ratio = if baseline > 0.0 {
+            Some((recent / config.volume_window_seconds.max(1) as f64) / (baseline / baseline_seconds))
+        } else {
+            None
+        };
+
+        RocMetrics {
+            price_change_percent,
+            volume_spike_ratio,
+        }
+    }
+
+    /// Decide whether an entry may proceed, and at what size
+    pub async fn evaluate(&self, token_address: &str, strategy: &SniperStrategy) -> EntryGuardDecision {
+        let config = self.config_for(strategy).clone();
+        if !config.enabled {
+            return EntryGuardDecision::Allow;
+        }
+
+        let metrics = self.metrics(token_address, &config).await;
+        debug!("📈 RoC metrics for {}: {:?}", token_address, metrics);
+
+        if let Some(change) = metrics.price_change_percent {
+            if change >= config.reject_price_change_percent {
+                let reason = format!("price +{:.0}% in {}s (limit {:.0}%)",
+                                     change, config.price_window_seconds, config.reject_price_change_percent);
+                warn!("🛑 Entry into {} rejected: {}", token_address, reason);
+                return EntryGuardDecision::Reject { reason };
+            }
+        }
+        if let Some(ratio) = metrics.volume_spike_ratio {
+            if ratio >= config.reject_volume_spike_ratio {
+                let reason = format!("volume spike {:.1}x baseline (limit {:.1}x)", ratio, config.reject_volume_spike_ratio);
+                warn!("🛑 Entry into {} rejected: {}", token_address, reason);
+                return EntryGuardDecision::Reject { reason };
+            }
+        }
+
+        let mut reasons = Vec::new();
+        if metrics.price_change_percent.map_or(false, |c| c >= config.downsize_price_change_percent) {
+            reasons.push(format!("price +{:.0}% in {}s", metrics.price_change_percent.unwrap_or_default(), config.price_window_seconds));
+        }
+        if metrics.volume_spike_ratio.map_or(false, |r| r >= config.downsize_volume_spike_ratio) {
+            reasons.push(format!("volume spike {:.1}x", metrics.volume_spike_ratio.unwrap_or_default()));
+        }
+
+        if reasons.is_empty() {
+            EntryGuardDecision::Allow
+        } else {
+            EntryGuardDecision::Downsize {
+                factor: config.downsize_factor.clamp(0.0, 1.0),
+                reason: reasons.join(", "),
+            }
+        }
+    }
+
+    fn max_retention(&self) -> Duration {
+        let seconds = std::iter::once(&self.default_config)
+            .chain(self.strategy_configs.values())
+            .map(|c| c.price_window_seconds.max(c.volume_baseline_seconds))
+            .max()
+            .unwrap_or(900);
+        Duration::seconds(seconds)
+    }
+}
+
+impl Default for PriceRocGuard {
+    fn default() -> Self {
+        Self::new(RocGuardConfig::default(), HashMap::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(seconds_ago: i64, price: f64, volume_usd: f64) -> PriceSample {
+        PriceSample {
+            timestamp: Utc::now() - Duration::seconds(seconds_ago),
+            price,
+            volume_usd,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pump_rejected() {
+        let guard = PriceRocGuard::default();
+        guard.record_sample("TOKEN", sample(110, 1.0, 100.0)).await;
+        guard.record_sample("TOKEN", sample(1, 4.5, 100.0)).await;
+
+        let decision = guard.evaluate("TOKEN", &SniperStrategy::LiquiditySnipe).await;
+        assert!(matches!(decision, EntryGuardDecision::Reject { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_moderate_pump_downsized() {
+        let guard = PriceRocGuard::default();
+        guard.record_sample("TOKEN", sample(100, 1.0, 100.0)).await;
+        guard.record_sample("TOKEN", sample(1, 2.2, 100.0)).await;
+
+        let decision = guard.evaluate("TOKEN", &SniperStrategy::QuickFlip).await;
+        assert_eq!(decision.size_multiplier(), 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_volume_spike_and_strategy_override() {
+        let mut overrides = HashMap::new();
+        overrides.insert(SniperStrategy::TrendRiding, RocGuardConfig { enabled: false, ..Default::default() });
+        let guard = PriceRocGuard::new(RocGuardConfig::default(), overrides);
+
+        // Flat baseline of $10/30s, then $5k in the last minute
+        for i in 0..28 {
+            guard.record_sample("TOKEN", sample(890 - i * 30, 1.0, 10.0)).await;
+        }
+        guard.record_sample("TOKEN", sample(5, 1.0, 5_000.0)).await;
+
+        let metrics = guard.metrics("TOKEN", &RocGuardConfig::default()).await;
+        assert!(metrics.volume_spike_ratio.unwrap() > 20.0);
+        assert!(matches!(guard.evaluate("TOKEN", &SniperStrategy::QuickFlip).await, EntryGuardDecision::Reject { .. }));
+        assert_eq!(guard.evaluate("TOKEN", &SniperStrategy::TrendRiding).await, EntryGuardDecision::Allow);
+    }
+}
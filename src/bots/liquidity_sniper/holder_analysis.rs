@@ -0,0 +1,826 @@
+// SniperForge Enterprise v3.0 - Holder Distribution & LP Concentration Analysis
+// Top-holder concentration, LP token ownership and deployer history as explainable risk sub-scores
+// Deployer reputation (launch history, rug incidence, time to liquidity pull) and pool age feed sniper confidence
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, Utc};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{debug, warn};
+
+/// Burn address used for LP token burns
+pub const INCINERATOR_ADDRESS: &str = "1nc1nerator11111111111111111111111111111111";
+
+/// A token holder (owner wallet, not token account)
+#[derive(Debug, Clone)]
+pub struct TokenHolder {
+    pub owner: String,
+    pub token_account: String,
+    pub amount: f64,
+}
+
+/// Top-holder distribution of a mint
+#[derive(Debug, Clone, Default)]
+pub struct HolderDistribution {
+    pub total_supply: f64,
+    pub top_holders: Vec<TokenHolder>,
+    /// % of supply held by the top 10 holders (pool vaults excluded)
+    pub top10_percent: f64,
+    /// % of supply held by the top 20 holders (pool vaults excluded)
+    pub top20_percent: f64,
+    /// Largest single non-pool holder (% of supply)
+    pub largest_holder_percent: f64,
+}
+
+/// LP token ownership breakdown
+#[derive(Debug, Clone, Default)]
+pub struct LpOwnership {
+    pub lp_mint: String,
+    pub total_supply: f64,
+    pub burned_percent: f64,
+    pub locked_percent: f64,
+    pub deployer_percent: f64,
+    pub largest_holder_percent: f64,
+}
+
+/// One token launched by a deployer
+#[derive(Debug, Clone)]
+pub struct DeployerLaunch {
+    pub mint: String,
+    pub pool_address: String,
+    pub launched_at: DateTime<Utc>,
+    /// When liquidity was (mostly) pulled, if it was
+    pub liquidity_pulled_at: Option<DateTime<Utc>>,
+    pub rugged: bool,
+}
+
+impl DeployerLaunch {
+    /// Minutes from launch to liquidity pull
+    pub fn minutes_to_pull(&self) -> Option<f64> {
+        self.liquidity_pulled_at
+            .map(|pulled| (pulled - self.launched_at).num_seconds().max(0) as f64 / 60.0)
+    }
+}
+
+/// What we know about a token deployer
+#[derive(Debug, Clone, Default)]
+pub struct DeployerHistory {
+    pub deployer: String,
+    pub tokens_launched: u32,
+    pub suspected_rugs: u32,
+    /// Individual launches, when known (live observation or indexer backfill)
+    pub launches: Vec<DeployerLaunch>,
+}
+
+impl DeployerHistory {
+    /// Share of launches that ended in a rug
+    pub fn rug_rate(&self) -> f64 {
+        let launches = self.tokens_launched.max(self.suspected_rugs);
+        if launches == 0 {
+            0.0
+        } else {
+            self.suspected_rugs as f64 / launches as f64
+        }
+    }
+
+    /// Median minutes from launch to liquidity pull across pulled launches
+    pub fn median_minutes_to_pull(&self) -> Option<f64> {
+        let mut minutes: Vec<f64> = self.launches.iter().filter_map(DeployerLaunch::minutes_to_pull).collect();
+        if minutes.is_empty() {
+            return None;
+        }
+        minutes.sort_by(|a, b| a.total_cmp(b));
+        let mid = minutes.len() / 2;
+        Some(if minutes.len() % 2 == 1 { minutes[mid] } else { (minutes[mid - 1] + minutes[mid]) / 2.0 })
+    }
+}
+
+/// One explainable contribution to a reputation or confidence score
+#[derive(Debug, Clone)]
+pub struct ReputationFactor {
+    pub name: String,
+    /// Signed contribution (reputation points, or confidence multiplier delta)
+    pub impact: f64,
+    pub detail: String,
+}
+
+/// Deployer reputation, 0 (known rugger) to 1 (established clean deployer)
+#[derive(Debug, Clone)]
+pub struct DeployerReputation {
+    pub deployer: String,
+    pub score: f64,
+    pub factors: Vec<ReputationFactor>,
+}
+
+/// Confidence adjustment for a snipe from deployer reputation and pool age
+#[derive(Debug, Clone)]
+pub struct LaunchConfidence {
+    pub reputation_score: f64,
+    /// Factor applied to the opportunity's confidence
+    pub multiplier: f64,
+    pub factors: Vec<ReputationFactor>,
+}
+
+/// How launch history turns into reputation and confidence
+#[derive(Debug, Clone)]
+pub struct ReputationConfig {
+    /// LP removals of at least this share count as a liquidity pull (%)
+    pub pull_percent: f64,
+    /// A pull within this long of launch counts as a rug
+    pub rug_window_hours: i64,
+    /// Median launch-to-pull time below which pulls count as fast (minutes)
+    pub fast_pull_minutes: f64,
+    /// Launch count above which a deployer is treated as a serial launcher
+    pub serial_launches: u32,
+    /// Pool age at which the pool-age penalty disappears (minutes)
+    pub mature_pool_minutes: f64,
+    /// Largest confidence reduction for a brand-new pool
+    pub fresh_pool_penalty: f64,
+    /// How far reputation moves confidence either side of neutral
+    pub reputation_weight: f64,
+}
+
+impl Default for ReputationConfig {
+    fn default() -> Self {
+        Self {
+            pull_percent: 80.0,
+            rug_window_hours: 72,
+            fast_pull_minutes: 120.0,
+            serial_launches: 10,
+            mature_pool_minutes: 60.0,
+            fresh_pool_penalty: 0.2,
+            reputation_weight: 0.5,
+        }
+    }
+}
+
+/// Indexed launch history of a deployer (e.g. from a transaction indexer)
+#[async_trait::async_trait]
+pub trait LaunchHistorySource: Send + Sync + std::fmt::Debug {
+    async fn launches(&self, deployer: &str) -> Result<Vec<DeployerLaunch>>;
+}
+
+/// Risk sub-scores (0-1, higher = riskier) with human-readable reasons
+#[derive(Debug, Clone, Default)]
+pub struct HolderRiskReport {
+    pub distribution_risk: f64,
+    pub lp_risk: f64,
+    pub deployer_risk: f64,
+    pub combined_risk: f64,
+    pub reasons: Vec<String>,
+    pub distribution: Option<HolderDistribution>,
+    pub lp_ownership: Option<LpOwnership>,
+    pub deployer_history: Option<DeployerHistory>,
+    pub deployer_reputation: Option<DeployerReputation>,
+}
+
+/// Source of on-chain holder data
+#[async_trait::async_trait]
+pub trait HolderDataSource: Send + Sync + std::fmt::Debug {
+    /// Up to 20 largest holders of a mint, with owners resolved
+    async fn largest_holders(&self, mint: &str) -> Result<Vec<TokenHolder>>;
+    /// Total supply in UI units
+    async fn token_supply(&self, mint: &str) -> Result<f64>;
+    /// Mint authority, used as the deployer when nothing better is known
+    async fn mint_authority(&self, mint: &str) -> Result<Option<String>>;
+}
+
+/// RPC-backed holder data source
+pub struct RpcHolderDataSource {
+    client: RpcClient,
+}
+
+impl std::fmt::Debug for RpcHolderDataSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RpcHolderDataSource").field("url", &self.client.url()).finish()
+    }
+}
+
+impl RpcHolderDataSource {
+    pub fn new(rpc_url: String) -> Self {
+        Self {
+            client: RpcClient::new(rpc_url),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl HolderDataSource for RpcHolderDataSource {
+    async fn largest_holders(&self, mint: &str) -> Result<Vec<TokenHolder>> {
+        let mint = Pubkey::from_str(mint)?;
+        let accounts = self.client.get_token_largest_accounts(&mint).await?;
+        let addresses = accounts
+            .iter()
+            .map(|account| Pubkey::from_str(&account.address))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Owners of all token accounts in one round trip
+        let owners = match self.client.get_multiple_accounts(&addresses).await {
+            Ok(owners) => owners,
+            Err(e) => {
+                debug!("Token account owners unavailable for {}: {}", mint, e);
+                vec![None; addresses.len()]
+            }
+        };
+
+        Ok(accounts
+            .into_iter()
+            .zip(owners)
+            .map(|(account, token_account)| TokenHolder {
+                // Resolve the owning wallet; fall back to the token account itself
+                owner: token_account
+                    .and_then(|token_account| token_account_owner(&token_account.data))
+                    .map(|owner| owner.to_string())
+                    .unwrap_or_else(|| account.address.clone()),
+                token_account: account.address,
+                amount: account.amount.ui_amount.unwrap_or(0.0),
+            })
+            .collect())
+    }
+
+    async fn token_supply(&self, mint: &str) -> Result<f64> {
+        let mint = Pubkey::from_str(mint)?;
+        let supply = self.client.get_token_supply(&mint).await?;
+        supply.ui_amount.ok_or_else(|| anyhow!("Token supply unavailable for {}", mint))
+    }
+
+    async fn mint_authority(&self, mint: &str) -> Result<Option<String>> {
+        let mint = Pubkey::from_str(mint)?;
+        let account = self.client.get_account(&mint).await?;
+        // SPL mint layout: COption<Pubkey> tag (u32 LE) followed by the authority
+        if account.data.len() < 36 {
+            return Err(anyhow!("Account {} is not an SPL mint", mint));
+        }
+        let tag = u32::from_le_bytes([account.data[0], account.data[1], account.data[2], account.data[3]]);
+        if tag == 0 {
+            return Ok(None);
+        }
+        let authority = Pubkey::try_from(&account.data[4..36]).map_err(|_| anyhow!("Invalid mint authority"))?;
+        Ok(Some(authority.to_string()))
+    }
+}
+
+/// Owner of an SPL token account (layout: mint, owner, amount, ...)
+fn token_account_owner(data: &[u8]) -> Option<Pubkey> {
+    Pubkey::try_from(data.get(32..64)?).ok()
+}
+
+/// Known deployers and their launch/rug history
+#[derive(Debug, Default)]
+pub struct DeployerRegistry {
+    records: RwLock<HashMap<String, DeployerHistory>>,
+    backfilled: RwLock<HashSet<String>>,
+}
+
+impl DeployerRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn record_launch(&self, deployer: &str) {
+        let mut records = self.records.write().await;
+        let entry = records.entry(deployer.to_string()).or_insert_with(|| DeployerHistory {
+            deployer: deployer.to_string(),
+            ..Default::default()
+        });
+        entry.tokens_launched += 1;
+    }
+
+    pub async fn record_rug(&self, deployer: &str) {
+        let mut records = self.records.write().await;
+        let entry = records.entry(deployer.to_string()).or_insert_with(|| DeployerHistory {
+            deployer: deployer.to_string(),
+            ..Default::default()
+        });
+        entry.suspected_rugs += 1;
+    }
+
+    pub async fn get(&self, deployer: &str) -> Option<DeployerHistory> {
+        self.records.read().await.get(deployer).cloned()
+    }
+
+    /// Record a launch observed live; repeated sightings of the same mint are ignored
+    pub async fn record_token_launch(&self, deployer: &str, mint: &str, pool_address: &str, launched_at: DateTime<Utc>) {
+        self.merge_launches(deployer, vec![DeployerLaunch {
+            mint: mint.to_string(),
+            pool_address: pool_address.to_string(),
+            launched_at,
+            liquidity_pulled_at: None,
+            rugged: false,
+        }]).await;
+    }
+
+    /// Record an LP removal on a launched pool; returns the deployer when it counts as a rug
+    pub async fn record_liquidity_pull(
+        &self,
+        pool_address: &str,
+        change_percent: f64,
+        at: DateTime<Utc>,
+        config: &ReputationConfig,
+    ) -> Option<String> {
+        if change_percent.abs() < config.pull_percent {
+            return None;
+        }
+        let mut records = self.records.write().await;
+        for history in records.values_mut() {
+            let Some(launch) = history.launches.iter_mut().find(|l| l.pool_address == pool_address) else {
+                continue;
+            };
+            if launch.liquidity_pulled_at.is_some() {
+                return None;
+            }
+            launch.liquidity_pulled_at = Some(at);
+            if at - launch.launched_at <= Duration::hours(config.rug_window_hours) {
+                launch.rugged = true;
+                history.suspected_rugs += 1;
+                warn!("🚩 Deployer {} pulled liquidity from {} ({:.0}%)", history.deployer, pool_address, change_percent.abs());
+                return Some(history.deployer.clone());
+            }
+            return None;
+        }
+        None
+    }
+
+    /// Merge indexed launches, skipping mints already known
+    pub async fn merge_launches(&self, deployer: &str, launches: Vec<DeployerLaunch>) {
+        let mut records = self.records.write().await;
+        let entry = records.entry(deployer.to_string()).or_insert_with(|| DeployerHistory {
+            deployer: deployer.to_string(),
+            ..Default::default()
+        });
+        for launch in launches {
+            if entry.launches.iter().any(|known| known.mint == launch.mint) {
+                continue;
+            }
+            entry.tokens_launched += 1;
+            if launch.rugged {
+                entry.suspected_rugs += 1;
+            }
+            entry.launches.push(launch);
+        }
+    }
+
+    /// Pull a deployer's history from an indexer once per process
+    pub async fn backfill(&self, source: &dyn LaunchHistorySource, deployer: &str) -> Result<()> {
+        if self.backfilled.read().await.contains(deployer) {
+            return Ok(());
+        }
+        let launches = source.launches(deployer).await?;
+        debug!("📚 Backfilled {} launches for deployer {}", launches.len(), deployer);
+        self.merge_launches(deployer, launches).await;
+        self.backfilled.write().await.insert(deployer.to_string());
+        Ok(())
+    }
+}
+
+/// Holder analysis thresholds and weights
+#[derive(Debug, Clone)]
+pub struct HolderAnalysisConfig {
+    /// Owners treated as locked LP (locker program vaults)
+    pub lp_locker_owners: Vec<String>,
+    /// Top-10 concentration above which distribution risk is high (%)
+    pub high_top10_percent: f64,
+    /// Single holder share above which distribution risk is high (%)
+    pub high_single_holder_percent: f64,
+    /// Burned + locked LP share below which LP risk is high (%)
+    pub min_safe_lp_burned_locked_percent: f64,
+    /// Sub-score weights: distribution, LP, deployer
+    pub weights: (f64, f64, f64),
+    pub reputation: ReputationConfig,
+}
+
+impl Default for HolderAnalysisConfig {
+    fn default() -> Self {
+        Self {
+            lp_locker_owners: vec![
+                "strmRqUCoQUgGUan5YhzUZa6KqdzwX5L6FpUxfmKg5m".to_string(), // Streamflow
+            ],
+            high_top10_percent: 50.0,
+            high_single_holder_percent: 15.0,
+            min_safe_lp_burned_locked_percent: 90.0,
+            weights: (0.35, 0.40, 0.25),
+            reputation: ReputationConfig::default(),
+        }
+    }
+}
+
+/// Holder / LP / deployer analyzer
+#[derive(Debug)]
+pub struct HolderAnalyzer {
+    config: HolderAnalysisConfig,
+    source: Arc<dyn HolderDataSource>,
+    deployers: Arc<DeployerRegistry>,
+    launch_history: Option<Arc<dyn LaunchHistorySource>>,
+}
+
+impl HolderAnalyzer {
+    pub fn new(config: HolderAnalysisConfig, source: Arc<dyn HolderDataSource>, deployers: Arc<DeployerRegistry>) -> Self {
+        Self { config, source, deployers, launch_history: None }
+    }
+
+    /// Backfill deployer launch history from an indexer before scoring
+    pub fn with_launch_history(mut self, source: Arc<dyn LaunchHistorySource>) -> Self {
+        self.launch_history = Some(source);
+        self
+    }
+
+    pub fn config(&self) -> &HolderAnalysisConfig {
+        &self.config
+    }
+
+    /// Deployer registry (record rugs as they are detected)
+    pub fn deployers(&self) -> &Arc<DeployerRegistry> {
+        &self.deployers
+    }
+
+    /// Top-20 holder distribution, excluding the pool's own vault owner
+    pub async fn holder_distribution(&self, mint: &str, pool_address: &str) -> Result<HolderDistribution> {
+        let total_supply = self.source.token_supply(mint).await?;
+        let holders = self.source.largest_holders(mint).await?;
+        if total_supply <= 0.0 {
+            return Err(anyhow!("Zero supply for {}", mint));
+        }
+
+        let non_pool: Vec<&TokenHolder> = holders
+            .iter()
+            .filter(|h| h.owner != pool_address && h.owner != INCINERATOR_ADDRESS)
+            .collect();
+        let pct = |n: usize| non_pool.iter().take(n).map(|h| h.amount).sum::<f64>() / total_supply * 100.0;
+
+        Ok(HolderDistribution {
+            total_supply,
+            top10_percent: pct(10),
+            top20_percent: pct(20),
+            largest_holder_percent: non_pool.first().map_or(0.0, |h| h.amount / total_supply * 100.0),
+            top_holders: holders,
+        })
+    }
+
+    /// LP token ownership: burned, locked, deployer-held
+    pub async fn lp_ownership(&self, lp_mint: &str, deployer: Option<&str>) -> Result<LpOwnership> {
+        let total_supply = self.source.token_supply(lp_mint).await?;
+        let holders = self.source.largest_holders(lp_mint).await?;
+        if total_supply <= 0.0 {
+            return Err(anyhow!("Zero LP supply for {}", lp_mint));
+        }
+
+        let share = |pred: &dyn Fn(&TokenHolder) -> bool| {
+            holders.iter().filter(|h| pred(h)).map(|h| h.amount).sum::<f64>() / total_supply * 100.0
+        };
+
+        Ok(LpOwnership {
+            lp_mint: lp_mint.to_string(),
+            total_supply,
+            burned_percent: share(&|h| h.owner == INCINERATOR_ADDRESS),
+            locked_percent: share(&|h| self.config.lp_locker_owners.contains(&h.owner)),
+            deployer_percent: deployer.map_or(0.0, |d| share(&|h| h.owner == d)),
+            largest_holder_percent: holders.first().map_or(0.0, |h| h.amount / total_supply * 100.0),
+        })
+    }
+
+    /// Full analysis producing sub-scores and reasons
+    ///
+    /// Each component degrades independently: a failed lookup scores as
+    /// moderately risky (0.5) with a reason, rather than failing the analysis.
+    pub async fn analyze(&self, mint: &str, pool_address: &str, lp_mint: Option<&str>, deployer: Option<&str>) -> HolderRiskReport {
+        let mut report = HolderRiskReport::default();
+
+        let deployer = match deployer {
+            Some(d) => Some(d.to_string()),
+            None => self.source.mint_authority(mint).await.ok().flatten(),
+        };
+
+        // Holder distribution
+        match self.holder_distribution(mint, pool_address).await {
+            Ok(dist) => {
+                report.distribution_risk = self.distribution_risk(&dist, &mut report.reasons);
+                report.distribution = Some(dist);
+            }
+            Err(e) => {
+                warn!("⚠️ Holder distribution unavailable for {}: {}", mint, e);
+                report.distribution_risk = 0.5;
+                report.reasons.push("Holder distribution unavailable".to_string());
+            }
+        }
+
+        // LP ownership
+        match lp_mint {
+            Some(lp_mint) => match self.lp_ownership(lp_mint, deployer.as_deref()).await {
+                Ok(lp) => {
+                    report.lp_risk = self.lp_risk(&lp, &mut report.reasons);
+                    report.lp_ownership = Some(lp);
+                }
+                Err(e) => {
+                    warn!("⚠️ LP ownership unavailable for {}: {}", lp_mint, e);
+                    report.lp_risk = 0.5;
+                    report.reasons.push("LP ownership unavailable".to_string());
+                }
+            },
+            None => {
+                report.lp_risk = 0.5;
+                report.reasons.push("LP mint unknown; LP lock status not verified".to_string());
+            }
+        }
+
+        // Deployer history
+        match &deployer {
+            Some(deployer) => {
+                if let Some(source) = &self.launch_history {
+                    if let Err(e) = self.deployers.backfill(source.as_ref(), deployer).await {
+                        warn!("⚠️ Launch history unavailable for deployer {}: {}", deployer, e);
+                        report.reasons.push("Deployer launch history unavailable".to_string());
+                    }
+                }
+                let history = self.deployers.get(deployer).await.unwrap_or_else(|| DeployerHistory {
+                    deployer: deployer.clone(),
+                    ..Default::default()
+                });
+                report.deployer_risk = Self::deployer_risk(&history, &mut report.reasons);
+                report.deployer_reputation = Some(self.reputation(&history));
+                report.deployer_history = Some(history);
+            }
+            None => {
+                report.deployer_risk = 0.3;
+                report.reasons.push("Mint authority revoked; deployer unknown".to_string());
+            }
+        }
+
+        let (w_dist, w_lp, w_dep) = self.config.weights;
+        let total_weight = (w_dist + w_lp + w_dep).max(f64::EPSILON);
+        let weighted = (report.distribution_risk * w_dist + report.lp_risk * w_lp + report.deployer_risk * w_dep) / total_weight;
+        // A single critical red flag dominates the average
+        let worst = report.distribution_risk.max(report.lp_risk).max(report.deployer_risk);
+        report.combined_risk = if worst >= 0.9 { weighted.max(worst) } else { weighted }.clamp(0.0, 1.0);
+
+        debug!("🧮 Holder risk for {}: dist {:.2}, lp {:.2}, deployer {:.2} → {:.2}",
+               mint, report.distribution_risk, report.lp_risk, report.deployer_risk, report.combined_risk);
+        report
+    }
+
+    /// Reputation from launch count, rug incidence and time to liquidity pull
+    ///
+    /// Starts neutral (0.5): clean launches raise it, rugs and fast pulls lower it.
+    pub fn reputation(&self, history: &DeployerHistory) -> DeployerReputation {
+        let config = &self.config.reputation;
+        let mut factors = Vec::new();
+
+        if history.tokens_launched == 0 && history.suspected_rugs == 0 {
+            factors.push(ReputationFactor {
+                name: "launch_history".to_string(),
+                impact: 0.0,
+                detail: "No prior launches known".to_string(),
+            });
+        } else if history.tokens_launched > config.serial_launches {
+            factors.push(ReputationFactor {
+                name: "launch_history".to_string(),
+                impact: -0.1,
+                detail: format!("Serial deployer ({} launches)", history.tokens_launched),
+            });
+        } else {
+            let clean = history.tokens_launched.saturating_sub(history.suspected_rugs);
+            factors.push(ReputationFactor {
+                name: "launch_history".to_string(),
+                impact: (0.05 * clean as f64).min(0.25),
+                detail: format!("{} prior launch(es), {} without a rug", history.tokens_launched, clean),
+            });
+        }
+
+        if history.suspected_rugs > 0 {
+            let rug_rate = history.rug_rate();
+            factors.push(ReputationFactor {
+                name: "rug_incidence".to_string(),
+                impact: -(0.3 + 0.5 * rug_rate),
+                detail: format!("{} rug(s), {:.0}% of launches", history.suspected_rugs, rug_rate * 100.0),
+            });
+        }
+
+        if let Some(minutes) = history.median_minutes_to_pull() {
+            if minutes < config.fast_pull_minutes {
+                factors.push(ReputationFactor {
+                    name: "time_to_pull".to_string(),
+                    impact: -0.2 * (1.0 - minutes / config.fast_pull_minutes),
+                    detail: format!("Liquidity typically pulled after {:.0} min", minutes),
+                });
+            }
+        }
+
+        let score = (0.5 + factors.iter().map(|f| f.impact).sum::<f64>()).clamp(0.0, 1.0);
+        DeployerReputation { deployer: history.deployer.clone(), score, factors }
+    }
+
+    /// Confidence multiplier for a snipe from deployer reputation and pool age
+    ///
+    /// Neutral reputation leaves confidence unchanged; the pool-age penalty
+    /// fades linearly until the pool reaches `mature_pool_minutes`.
+    pub fn launch_confidence(&self, reputation: &DeployerReputation, pool_age_minutes: u64) -> LaunchConfidence {
+        let config = &self.config.reputation;
+        let reputation_delta = config.reputation_weight * (reputation.score - 0.5) * 2.0;
+        let maturity = if config.mature_pool_minutes > 0.0 {
+            (pool_age_minutes as f64 / config.mature_pool_minutes).min(1.0)
+        } else {
+            1.0
+        };
+        let age_delta = -config.fresh_pool_penalty * (1.0 - maturity);
+
+        let mut factors = vec![ReputationFactor {
+            name: "deployer_reputation".to_string(),
+            impact: reputation_delta,
+            detail: format!("Deployer reputation {:.2}", reputation.score),
+        }];
+        if age_delta < 0.0 {
+            factors.push(ReputationFactor {
+                name: "pool_age".to_string(),
+                impact: age_delta,
+                detail: format!("Pool is {} min old", pool_age_minutes),
+            });
+        }
+
+        LaunchConfidence {
+            reputation_score: reputation.score,
+            multiplier: ((1.0 + reputation_delta) * (1.0 + age_delta)).max(0.0),
+            factors,
+        }
+    }
+
+    fn distribution_risk(&self, dist: &HolderDistribution, reasons: &mut Vec<String>) -> f64 {
+        let mut risk: f64 = 0.2;
+        if dist.top10_percent >= self.config.high_top10_percent {
+            risk = risk.max(0.8);
+            reasons.push(format!("Top 10 holders own {:.1}% of supply", dist.top10_percent));
+        } else if dist.top10_percent >= self.config.high_top10_percent * 0.6 {
+            risk = risk.max(0.5);
+            reasons.push(format!("Top 10 holders own {:.1}% of supply", dist.top10_percent));
+        }
+        if dist.largest_holder_percent >= self.config.high_single_holder_percent {
+            risk = risk.max(0.7);
+            reasons.push(format!("Single wallet holds {:.1}% of supply", dist.largest_holder_percent));
+        }
+        risk
+    }
+
+    fn lp_risk(&self, lp: &LpOwnership, reasons: &mut Vec<String>) -> f64 {
+        let secured = lp.burned_percent + lp.locked_percent;
+        if lp.deployer_percent >= 50.0 {
+            reasons.push(format!("Deployer holds {:.1}% of LP tokens (can pull liquidity)", lp.deployer_percent));
+            return 0.95;
+        }
+        if secured >= self.config.min_safe_lp_burned_locked_percent {
+            reasons.push(format!("{:.1}% of LP burned/locked", secured));
+            0.1
+        } else if secured >= 50.0 {
+            reasons.push(format!("Only {:.1}% of LP burned/locked", secured));
+            0.5
+        } else {
+            reasons.push(format!("LP mostly unlocked ({:.1}% burned/locked)", secured));
+            0.85
+        }
+    }
+
+    fn deployer_risk(history: &DeployerHistory, reasons: &mut Vec<String>) -> f64 {
+        if history.suspected_rugs > 0 {
+            reasons.push(format!("Deployer {} linked to {} previous rug(s)", history.deployer, history.suspected_rugs));
+            return (0.9 + 0.05 * (history.suspected_rugs - 1) as f64).min(1.0);
+        }
+        if history.tokens_launched == 0 {
+            reasons.push("Deployer has no launch history".to_string());
+            0.5
+        } else if history.tokens_launched > 10 {
+            reasons.push(format!("Serial deployer ({} launches)", history.tokens_launched));
+            0.6
+        } else {
+            0.25
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct MockSource {
+        holders: HashMap<String, Vec<TokenHolder>>,
+        supply: HashMap<String, f64>,
+    }
+
+    #[async_trait::async_trait]
+    impl HolderDataSource for MockSource {
+        async fn largest_holders(&self, mint: &str) -> Result<Vec<TokenHolder>> {
+            self.holders.get(mint).cloned().ok_or_else(|| anyhow!("no holders"))
+        }
+        async fn token_supply(&self, mint: &str) -> Result<f64> {
+            self.supply.get(mint).copied().ok_or_else(|| anyhow!("no supply"))
+        }
+        async fn mint_authority(&self, _mint: &str) -> Result<Option<String>> {
+            Ok(None)
+        }
+    }
+
+    fn holder(owner: &str, amount: f64) -> TokenHolder {
+        TokenHolder { owner: owner.to_string(), token_account: format!("{}-ata", owner), amount }
+    }
+
+    fn analyzer(source: MockSource) -> HolderAnalyzer {
+        HolderAnalyzer::new(HolderAnalysisConfig::default(), Arc::new(source), Arc::new(DeployerRegistry::new()))
+    }
+
+    #[test]
+    fn test_token_account_owner_from_account_data() {
+        let owner = Pubkey::new_unique();
+        let mut data = vec![0u8; 165];
+        data[32..64].copy_from_slice(owner.as_ref());
+        assert_eq!(token_account_owner(&data), Some(owner));
+        assert_eq!(token_account_owner(&data[..40]), None);
+    }
+
+    #[tokio::test]
+    async fn test_concentrated_supply_and_deployer_lp_flagged() {
+        let mut source = MockSource::default();
+        source.supply.insert("MINT".into(), 1_000.0);
+        source.holders.insert("MINT".into(), vec![holder("POOL", 500.0), holder("whale", 300.0), holder("a", 50.0)]);
+        source.supply.insert("LP".into(), 100.0);
+        source.holders.insert("LP".into(), vec![holder("dev", 80.0), holder(INCINERATOR_ADDRESS, 20.0)]);
+
+        let report = analyzer(source).analyze("MINT", "POOL", Some("LP"), Some("dev")).await;
+        let dist = report.distribution.as_ref().unwrap();
+        assert!((dist.largest_holder_percent - 30.0).abs() < 1e-9); // Pool vault excluded
+        assert!(report.lp_risk >= 0.9);
+        assert!(report.combined_risk >= 0.9);
+        assert!(report.reasons.iter().any(|r| r.contains("can pull liquidity")));
+    }
+
+    #[tokio::test]
+    async fn test_burned_lp_and_known_rugger() {
+        let mut source = MockSource::default();
+        source.supply.insert("MINT".into(), 1_000.0);
+        source.holders.insert("MINT".into(), vec![holder("POOL", 700.0), holder("a", 20.0), holder("b", 20.0)]);
+        source.supply.insert("LP".into(), 100.0);
+        source.holders.insert("LP".into(), vec![holder(INCINERATOR_ADDRESS, 99.0)]);
+
+        let analyzer = analyzer(source);
+        let clean = analyzer.analyze("MINT", "POOL", Some("LP"), Some("dev")).await;
+        assert!(clean.lp_risk < 0.2);
+        assert!(clean.distribution_risk < 0.3);
+
+        analyzer.deployers().record_rug("dev").await;
+        let flagged = analyzer.analyze("MINT", "POOL", Some("LP"), Some("dev")).await;
+        assert!(flagged.deployer_risk >= 0.9);
+        assert!(flagged.combined_risk > clean.combined_risk);
+    }
+
+    #[derive(Debug)]
+    struct MockIndexer;
+
+    #[async_trait::async_trait]
+    impl LaunchHistorySource for MockIndexer {
+        async fn launches(&self, _deployer: &str) -> Result<Vec<DeployerLaunch>> {
+            let launched_at = Utc::now() - Duration::days(30);
+            Ok((0..3)
+                .map(|i| DeployerLaunch {
+                    mint: format!("OLD{}", i),
+                    pool_address: format!("OLDPOOL{}", i),
+                    launched_at,
+                    liquidity_pulled_at: None,
+                    rugged: false,
+                })
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reputation_from_history_and_pulls_feeds_confidence() {
+        let analyzer = analyzer(MockSource::default()).with_launch_history(Arc::new(MockIndexer));
+        let config = analyzer.config().reputation.clone();
+
+        // Three clean indexed launches: above neutral, boosts confidence once the pool matures
+        let report = analyzer.analyze("MINT", "POOL", None, Some("dev")).await;
+        let reputation = report.deployer_reputation.unwrap();
+        assert!((reputation.score - 0.65).abs() < 1e-9);
+        assert!(analyzer.launch_confidence(&reputation, 120).multiplier > 1.0);
+        let fresh = analyzer.launch_confidence(&reputation, 0);
+        assert!(fresh.multiplier < 1.0);
+        assert!(fresh.factors.iter().any(|f| f.name == "pool_age"));
+
+        // A new launch pulled 30 minutes in is a fast rug
+        let launched_at = Utc::now();
+        analyzer.deployers().record_token_launch("dev", "NEW", "NEWPOOL", launched_at).await;
+        analyzer.deployers().record_token_launch("dev", "NEW", "NEWPOOL", launched_at).await;
+        let pulled_at = launched_at + Duration::minutes(30);
+        assert!(analyzer.deployers().record_liquidity_pull("NEWPOOL", -50.0, pulled_at, &config).await.is_none());
+        assert_eq!(analyzer.deployers().record_liquidity_pull("NEWPOOL", -95.0, pulled_at, &config).await.as_deref(), Some("dev"));
+
+        let history = analyzer.deployers().get("dev").await.unwrap();
+        assert_eq!((history.tokens_launched, history.suspected_rugs), (4, 1));
+        assert_eq!(history.median_minutes_to_pull(), Some(30.0));
+
+        let reputation = analyzer.reputation(&history);
+        assert!(reputation.score < 0.3, "score {}", reputation.score);
+        assert!(reputation.factors.iter().any(|f| f.name == "rug_incidence"));
+        assert!(reputation.factors.iter().any(|f| f.name == "time_to_pull"));
+        assert!(analyzer.launch_confidence(&reputation, 120).multiplier < 0.8);
+    }
+}
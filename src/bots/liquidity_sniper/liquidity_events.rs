@@ -0,0 +1,279 @@
+// SniperForge Enterprise v3.0 - Liquidity Event Detector
+// Flags significant LP adds/removals on watched pools from successive account snapshots
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use tokio::sync::{broadcast, RwLock};
+use tracing::{debug, info, warn};
+
+use super::DexType;
+
+/// Pool size buckets, each with its own significance thresholds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PoolSizeClass {
+    Micro,  // < $50K
+    Small,  // < $250K
+    Medium, // < $2M
+    Large,
+}
+
+impl PoolSizeClass {
+    pub fn from_liquidity_usd(liquidity_usd: f64) -> Self {
+        match liquidity_usd {
+            l if l < 50_000.0 => PoolSizeClass::Micro,
+            l if l < 250_000.0 => PoolSizeClass::Small,
+            l if l < 2_000_000.0 => PoolSizeClass::Medium,
+            _ => PoolSizeClass::Large,
+        }
+    }
+}
+
+/// Minimum change for an add/remove to count as significant
+#[derive(Debug, Clone)]
+pub struct LiquidityEventThresholds {
+    /// LP add, as % of prior liquidity
+    pub min_add_percent: f64,
+    /// LP removal, as % of prior liquidity
+    pub min_remove_percent: f64,
+    /// Absolute floor in USD, so tiny pools do not fire on noise
+    pub min_change_usd: f64,
+}
+
+/// Detector configuration
+#[derive(Debug, Clone)]
+pub struct LiquidityEventConfig {
+    pub thresholds: HashMap<PoolSizeClass, LiquidityEventThresholds>,
+    /// Relative reserve move below which a side is considered unchanged
+    pub reserve_noise_percent: f64,
+    pub channel_capacity: usize,
+}
+
+impl Default for LiquidityEventConfig {
+    fn default() -> Self {
+        let t = |min_add_percent, min_remove_percent, min_change_usd| LiquidityEventThresholds {
+            min_add_percent,
+            min_remove_percent,
+            min_change_usd,
+        };
+        Self {
+            // Small pools move a lot on ordinary deposits; large ones rarely do
+            thresholds: HashMap::from([
+                (PoolSizeClass::Micro, t(50.0, 25.0, 2_000.0)),
+                (PoolSizeClass::Small, t(30.0, 15.0, 10_000.0)),
+                (PoolSizeClass::Medium, t(15.0, 10.0, 50_000.0)),
+                (PoolSizeClass::Large, t(8.0, 5.0, 200_000.0)),
+            ]),
+            reserve_noise_percent: 0.5,
+            channel_capacity: 256,
+        }
+    }
+}
+
+/// Pool state decoded from its accounts
+#[derive(Debug, Clone)]
+pub struct PoolSnapshot {
+    pub pool_address: String,
+    pub token_a_amount: f64,
+    pub token_b_amount: f64,
+    /// LP mint supply, when the pool exposes one (most precise add/remove signal)
+    pub lp_supply: Option<f64>,
+    pub liquidity_usd: f64,
+    pub slot: u64,
+    pub observed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiquidityEventKind {
+    Add,
+    Remove,
+}
+
+/// Significant LP add or removal
+#[derive(Debug, Clone)]
+pub struct LiquidityEvent {
+    pub pool_address: String,
+    pub dex: DexType,
+    pub kind: LiquidityEventKind,
+    /// Change relative to prior liquidity (%)
+    pub change_percent: f64,
+    pub change_usd: f64,
+    pub liquidity_before_usd: f64,
+    pub liquidity_after_usd: f64,
+    pub size_class: PoolSizeClass,
+    pub slot: u64,
+    pub detected_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+struct WatchedPool {
+    dex: DexType,
+    last: Option<PoolSnapshot>,
+}
+
+/// Watches pools and publishes significant LP adds/removals
+pub struct LiquidityEventDetector {
+    config: LiquidityEventConfig,
+    watched: RwLock<HashMap<String, WatchedPool>>,
+    events: broadcast::Sender<LiquidityEvent>,
+}
+
+impl LiquidityEventDetector {
+    pub fn new(config: LiquidityEventConfig) -> Self {
+        let (events, _) = broadcast::channel(config.channel_capacity.max(1));
+        Self {
+            config,
+            watched: RwLock::new(HashMap::new()),
+            events,
+        }
+    }
+
+    pub async fn watch(&self, pool_address: &str, dex: DexType) {
+        let mut watched = self.watched.write().await;
+        if !watched.contains_key(pool_address) {
+            debug!("💧 Watching pool {} on {:?} for LP events", pool_address, dex);
+            watched.insert(pool_address.to_string(), WatchedPool { dex, last: None });
+        }
+    }
+
+    pub async fn unwatch(&self, pool_address: &str) {
+        self.watched.write().await.remove(pool_address);
+    }
+
+    pub async fn watched_pools(&self) -> Vec<String> {
+        self.watched.read().await.keys().cloned().collect()
+    }
+
+    /// Receive every significant event (sniper entries, risk exits, dashboards)
+    pub fn subscribe(&self) -> broadcast::Receiver<LiquidityEvent> {
+        self.events.subscribe()
+    }
+
+    /// Compare a snapshot with the previous one; publishes and returns a significant event
+    ///
+    /// The first snapshot of a pool only sets the baseline. Snapshots of pools
+    /// that are not watched are ignored.
+    pub async fn observe(&self, snapshot: PoolSnapshot) -> Option<LiquidityEvent> {
+        let (dex, previous) = {
+            let mut watched = self.watched.write().await;
+            let pool = watched.get_mut(&snapshot.pool_address)?;
+            if pool.last.as_ref().is_some_and(|last| snapshot.slot <= last.slot) {
+                return None; // out-of-order update
+            }
+            (pool.dex.clone(), pool.last.replace(snapshot.clone()))
+        };
+        let event = self.classify(&previous?, &snapshot, dex)?;
+
+        match event.kind {
+            LiquidityEventKind::Add => info!("💧 LP add on {}: +{:.1}% (${:.0} → ${:.0})",
+                event.pool_address, event.change_percent, event.liquidity_before_usd, event.liquidity_after_usd),
+            LiquidityEventKind::Remove => warn!("🚰 LP pull on {}: -{:.1}% (${:.0} → ${:.0})",
+                event.pool_address, event.change_percent, event.liquidity_before_usd, event.liquidity_after_usd),
+        }
+        // No subscribers is fine: the caller still gets the event
+        let _ = self.events.send(event.clone());
+        Some(event)
+    }
+
+    fn classify(&self, before: &PoolSnapshot, after: &PoolSnapshot, dex: DexType) -> Option<LiquidityEvent> {
+        let relative = |old: f64, new: f64| if old > 0.0 { (new - old) / old * 100.0 } else { 0.0 };
+
+        // LP supply moves only on deposits/withdrawals. Without it, both reserves
+        // moving the same way is a deposit/withdrawal; opposite moves are swaps.
+        let change_percent = match (before.lp_supply, after.lp_supply) {
+            (Some(old), Some(new)) => relative(old, new),
+            _ => {
+                let a = relative(before.token_a_amount, after.token_a_amount);
+                let b = relative(before.token_b_amount, after.token_b_amount);
+                let noise = self.config.reserve_noise_percent;
+                if a.abs() < noise || b.abs() < noise || a.signum() != b.signum() {
+                    return None;
+                }
+                (a + b) / 2.0
+            }
+        };
+
+        let kind = if change_percent > 0.0 { LiquidityEventKind::Add } else { LiquidityEventKind::Remove };
+        let size_class = PoolSizeClass::from_liquidity_usd(before.liquidity_usd);
+        let thresholds = self.config.thresholds.get(&size_class)?;
+        let change_usd = before.liquidity_usd * change_percent.abs() / 100.0;
+        let min_percent = match kind {
+            LiquidityEventKind::Add => thresholds.min_add_percent,
+            LiquidityEventKind::Remove => thresholds.min_remove_percent,
+        };
+        if change_percent.abs() < min_percent || change_usd < thresholds.min_change_usd {
+            return None;
+        }
+
+        Some(LiquidityEvent {
+            pool_address: after.pool_address.clone(),
+            dex,
+            kind,
+            change_percent: change_percent.abs(),
+            change_usd,
+            liquidity_before_usd: before.liquidity_usd,
+            liquidity_after_usd: after.liquidity_usd,
+            size_class,
+            slot: after.slot,
+            detected_at: after.observed_at,
+        })
+    }
+}
+
+impl Default for LiquidityEventDetector {
+    fn default() -> Self {
+        Self::new(LiquidityEventConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(slot: u64, a: f64, b: f64, lp_supply: Option<f64>, liquidity_usd: f64) -> PoolSnapshot {
+        PoolSnapshot {
+            pool_address: "pool".to_string(),
+            token_a_amount: a,
+            token_b_amount: b,
+            lp_supply,
+            liquidity_usd,
+            slot,
+            observed_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reserve_deltas_separate_lp_moves_from_swaps() {
+        let detector = LiquidityEventDetector::default();
+        let mut events = detector.subscribe();
+        detector.watch("pool", DexType::Raydium).await;
+
+        assert!(detector.observe(snapshot(1, 1_000.0, 100_000.0, None, 100_000.0)).await.is_none());
+        // Swap: reserves move in opposite directions
+        assert!(detector.observe(snapshot(2, 1_200.0, 83_000.0, None, 100_000.0)).await.is_none());
+        // Withdrawal of ~40% from a small pool
+        let event = detector.observe(snapshot(3, 720.0, 49_800.0, None, 60_000.0)).await.unwrap();
+        assert_eq!(event.kind, LiquidityEventKind::Remove);
+        assert_eq!(event.size_class, PoolSizeClass::Small);
+        assert!(event.change_percent > 39.0);
+        assert_eq!(events.try_recv().unwrap().slot, 3);
+    }
+
+    #[tokio::test]
+    async fn test_thresholds_depend_on_pool_size() {
+        let detector = LiquidityEventDetector::default();
+        detector.watch("pool", DexType::Orca).await;
+
+        // +10% LP supply on a $5M pool is significant (Large: 8%)
+        detector.observe(snapshot(1, 1.0, 1.0, Some(1_000.0), 5_000_000.0)).await;
+        let event = detector.observe(snapshot(2, 1.1, 1.1, Some(1_100.0), 5_500_000.0)).await.unwrap();
+        assert_eq!(event.kind, LiquidityEventKind::Add);
+
+        // +10% on a $30K pool is not (Micro: 50%)
+        detector.unwatch("pool").await;
+        detector.watch("pool", DexType::Orca).await;
+        detector.observe(snapshot(3, 1.0, 1.0, Some(1_000.0), 30_000.0)).await;
+        assert!(detector.observe(snapshot(4, 1.1, 1.1, Some(1_100.0), 33_000.0)).await.is_none());
+        // Unwatched pools are ignored
+        assert!(detector.observe(PoolSnapshot { pool_address: "other".to_string(), ..snapshot(5, 1.0, 1.0, None, 1.0) }).await.is_none());
+    }
+}
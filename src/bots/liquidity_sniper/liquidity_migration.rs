@@ -0,0 +1,415 @@
+// SniperForge Enterprise v3.0 - Liquidity Migration Tracker
+// Follows liquidity moving between pool versions of a pair (e.g. Raydium AMM v4 → CLMM/CPMM)
+
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use tokio::sync::{broadcast, RwLock};
+use tracing::{debug, info, warn};
+
+use super::liquidity_events::PoolSnapshot;
+use super::pool_monitor::PoolData;
+use super::DexType;
+use crate::apis::program_registry::RAYDIUM_CLMM_PROGRAM;
+use crate::monitoring::{Alert, AlertManager, AlertStatus, Severity};
+
+pub const RAYDIUM_AMM_V4_PROGRAM: &str = crate::types::hot_path::programs::RAYDIUM_AMM_V4;
+pub const RAYDIUM_CPMM_PROGRAM: &str = crate::types::hot_path::programs::RAYDIUM_CPMM;
+
+/// Pool program/version a pair can be routed through
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PoolVersion {
+    RaydiumAmmV4,
+    RaydiumClmm,
+    RaydiumCpmm,
+    Other(DexType),
+}
+
+impl PoolVersion {
+    /// Version from the pool's owning program; falls back to the DEX when unknown
+    pub fn classify(dex: &DexType, program_id: Option<&str>) -> Self {
+        match program_id {
+            Some(RAYDIUM_AMM_V4_PROGRAM) => PoolVersion::RaydiumAmmV4,
+            Some(RAYDIUM_CLMM_PROGRAM) => PoolVersion::RaydiumClmm,
+            Some(RAYDIUM_CPMM_PROGRAM) => PoolVersion::RaydiumCpmm,
+            _ if *dex == DexType::Raydium => PoolVersion::RaydiumAmmV4,
+            _ => PoolVersion::Other(dex.clone()),
+        }
+    }
+
+    pub fn dex(&self) -> DexType {
+        match self {
+            PoolVersion::Other(dex) => dex.clone(),
+            _ => DexType::Raydium,
+        }
+    }
+}
+
+/// Tracker configuration
+#[derive(Debug, Clone)]
+pub struct LiquidityMigrationConfig {
+    /// Drop from the pool's peak liquidity within `window` that counts as declining (%)
+    pub decline_percent: f64,
+    pub window: Duration,
+    /// A replacement must hold at least this share of the declining pool's peak liquidity
+    pub min_replacement_share: f64,
+    /// Liquidity samples kept per pool
+    pub max_samples: usize,
+    pub channel_capacity: usize,
+}
+
+impl Default for LiquidityMigrationConfig {
+    fn default() -> Self {
+        Self {
+            decline_percent: 40.0,
+            window: Duration::hours(6),
+            min_replacement_share: 0.2,
+            max_samples: 256,
+            channel_capacity: 64,
+        }
+    }
+}
+
+/// Pool a pair is currently routed through
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimaryPool {
+    pub pool_address: String,
+    pub version: PoolVersion,
+    pub liquidity_usd: f64,
+}
+
+/// Liquidity leaving a pair's primary pool for another pool of the same pair
+#[derive(Debug, Clone)]
+pub struct MigrationEvent {
+    pub token_a: String,
+    pub token_b: String,
+    pub from_pool: String,
+    pub from_version: PoolVersion,
+    pub to_pool: String,
+    pub to_version: PoolVersion,
+    /// Drop of `from_pool` from its peak within the window (%)
+    pub decline_percent: f64,
+    pub from_liquidity_usd: f64,
+    pub to_liquidity_usd: f64,
+    /// The pair's primary route now points at `to_pool`
+    pub rerouted: bool,
+    /// Held tokens of the pair (their primary pool is the one migrating)
+    pub held_tokens: Vec<String>,
+    pub detected_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+struct TrackedPool {
+    version: PoolVersion,
+    /// (observed at, liquidity USD), oldest first
+    samples: VecDeque<(DateTime<Utc>, f64)>,
+}
+
+impl TrackedPool {
+    fn current(&self) -> f64 {
+        self.samples.back().map_or(0.0, |(_, liquidity)| *liquidity)
+    }
+
+    fn peak(&self) -> f64 {
+        self.samples.iter().map(|(_, liquidity)| *liquidity).fold(0.0, f64::max)
+    }
+}
+
+#[derive(Debug, Default)]
+struct PairPools {
+    pools: HashMap<String, TrackedPool>,
+    primary: Option<String>,
+    /// (from, to, rerouted) already reported
+    reported: HashSet<(String, String, bool)>,
+}
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    pairs: HashMap<(String, String), PairPools>,
+    pool_pairs: HashMap<String, (String, String)>,
+}
+
+/// Detects pairs whose liquidity moves to a replacement pool and repoints their route
+#[derive(Debug)]
+pub struct LiquidityMigrationTracker {
+    config: LiquidityMigrationConfig,
+    state: RwLock<TrackerState>,
+    /// Tokens with open positions
+    held_tokens: RwLock<HashSet<String>>,
+    events: broadcast::Sender<MigrationEvent>,
+    alert_manager: Option<Arc<AlertManager>>,
+}
+
+impl LiquidityMigrationTracker {
+    pub fn new(config: LiquidityMigrationConfig) -> Self {
+        let (events, _) = broadcast::channel(config.channel_capacity.max(1));
+        Self {
+            config,
+            state: RwLock::new(TrackerState::default()),
+            held_tokens: RwLock::new(HashSet::new()),
+            events,
+            alert_manager: None,
+        }
+    }
+
+    /// Raise an alert when a held token's primary pool is migrating
+    pub fn with_alert_manager(mut self, alert_manager: Arc<AlertManager>) -> Self {
+        self.alert_manager = Some(alert_manager);
+        self
+    }
+
+    /// Receive every migration (route repoints included)
+    pub fn subscribe(&self) -> broadcast::Receiver<MigrationEvent> {
+        self.events.subscribe()
+    }
+
+    /// Replace the set of tokens with open positions
+    pub async fn set_held_tokens(&self, tokens: HashSet<String>) {
+        *self.held_tokens.write().await = tokens;
+    }
+
+    /// Pool the pair is routed through
+    pub async fn primary_pool(&self, token_a: &str, token_b: &str) -> Option<PrimaryPool> {
+        let state = self.state.read().await;
+        let pair = state.pairs.get(&pair_key(token_a, token_b))?;
+        let address = pair.primary.as_ref()?;
+        let pool = pair.pools.get(address)?;
+        Some(PrimaryPool {
+            pool_address: address.clone(),
+            version: pool.version.clone(),
+            liquidity_usd: pool.current(),
+        })
+    }
+
+    /// Add a discovered pool to its pair; the first pool of a pair becomes its primary
+    ///
+    /// A pool appearing while the primary is already declining is reported
+    /// right away instead of waiting for the next snapshot.
+    pub async fn register_pool(&self, pool: &PoolData, version: PoolVersion) -> Option<MigrationEvent> {
+        let key = pair_key(&pool.token_a, &pool.token_b);
+        let held_tokens = self.held_tokens.read().await.clone();
+        let event = {
+            let mut state = self.state.write().await;
+            if state.pool_pairs.contains_key(&pool.address) {
+                return None;
+            }
+            state.pool_pairs.insert(pool.address.clone(), key.clone());
+            let pair = state.pairs.entry(key.clone()).or_default();
+            pair.pools.insert(pool.address.clone(), TrackedPool {
+                version: version.clone(),
+                samples: VecDeque::from([(Utc::now(), pool.liquidity_usd)]),
+            });
+            match &pair.primary {
+                None => {
+                    debug!("🛣️ {} ({:?}) is the primary pool for {}/{}", pool.address, version, key.0, key.1);
+                    pair.primary = Some(pool.address.clone());
+                    return None;
+                }
+                Some(primary) => info!("🛣️ New {:?} pool {} for {}/{} (primary: {})", version, pool.address, key.0, key.1, primary),
+            }
+            self.evaluate(&key, pair, Utc::now(), &held_tokens)
+        }?;
+        self.publish(&event).await;
+        Some(event)
+    }
+
+    /// Record a liquidity observation; returns a migration when the pair's primary is being abandoned
+    ///
+    /// Snapshots of pools that were never registered are ignored.
+    pub async fn observe(&self, snapshot: &PoolSnapshot) -> Option<MigrationEvent> {
+        let held_tokens = self.held_tokens.read().await.clone();
+        let event = {
+            let mut state = self.state.write().await;
+            let key = state.pool_pairs.get(&snapshot.pool_address)?.clone();
+            let pair = state.pairs.get_mut(&key)?;
+            let pool = pair.pools.get_mut(&snapshot.pool_address)?;
+            if pool.samples.back().is_some_and(|(at, _)| snapshot.observed_at <= *at) {
+                return None; // out-of-order update
+            }
+            pool.samples.push_back((snapshot.observed_at, snapshot.liquidity_usd));
+            let cutoff = snapshot.observed_at - self.config.window;
+            while pool.samples.len() > self.config.max_samples.max(1) || pool.samples.front().is_some_and(|(at, _)| *at < cutoff) {
+                pool.samples.pop_front();
+            }
+            self.evaluate(&key, pair, snapshot.observed_at, &held_tokens)
+        }?;
+        self.publish(&event).await;
+        Some(event)
+    }
+
+    fn evaluate(&self, key: &(String, String), pair: &mut PairPools, now: DateTime<Utc>, held_tokens: &HashSet<String>) -> Option<MigrationEvent> {
+        let from_pool = pair.primary.clone()?;
+        let primary = pair.pools.get(&from_pool)?;
+        let peak = primary.peak();
+        let current = primary.current();
+        if peak <= 0.0 {
+            return None;
+        }
+        let decline_percent = (peak - current) / peak * 100.0;
+        if decline_percent < self.config.decline_percent {
+            return None;
+        }
+
+        // Plain declines are the liquidity event detector's business; a migration needs somewhere to go
+        let (to_pool, replacement) = pair.pools.iter()
+            .filter(|(address, pool)| **address != from_pool && pool.current() >= peak * self.config.min_replacement_share)
+            .max_by(|a, b| a.1.current().total_cmp(&b.1.current()))?;
+        let (to_pool, to_version, to_liquidity_usd) = (to_pool.clone(), replacement.version.clone(), replacement.current());
+        let from_version = primary.version.clone();
+
+        let rerouted = to_liquidity_usd > current;
+        if !pair.reported.insert((from_pool.clone(), to_pool.clone(), rerouted)) {
+            return None;
+        }
+        if rerouted {
+            pair.primary = Some(to_pool.clone());
+        }
+
+        Some(MigrationEvent {
+            token_a: key.0.clone(),
+            token_b: key.1.clone(),
+            from_pool,
+            from_version,
+            to_pool,
+            to_version,
+            decline_percent,
+            from_liquidity_usd: current,
+            to_liquidity_usd,
+            rerouted,
+            held_tokens: [&key.0, &key.1].into_iter().filter(|token| held_tokens.contains(*token)).cloned().collect(),
+            detected_at: now,
+        })
+    }
+
+    async fn publish(&self, event: &MigrationEvent) {
+        if event.rerouted {
+            info!("🛣️ {}/{} rerouted {} ({:?}) → {} ({:?}): primary down {:.1}%, ${:.0} vs ${:.0}",
+                event.token_a, event.token_b, event.from_pool, event.from_version, event.to_pool, event.to_version,
+                event.decline_percent, event.from_liquidity_usd, event.to_liquidity_usd);
+        } else {
+            info!("🛣️ {}/{} liquidity migrating {} → {}: primary down {:.1}%",
+                event.token_a, event.token_b, event.from_pool, event.to_pool, event.decline_percent);
+        }
+
+        if !event.held_tokens.is_empty() {
+            warn!("🚨 Primary pool of held {:?} is migrating: {} ({:?}) → {} ({:?})",
+                event.held_tokens, event.from_pool, event.from_version, event.to_pool, event.to_version);
+            if let Some(alert_manager) = &self.alert_manager {
+                alert_manager.raise_alert(Alert {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    title: format!("Liquidity migrating for held {}", event.held_tokens.join(", ")),
+                    description: format!("{} is down {:.1}% (${:.0}); liquidity moved to {} (${:.0})",
+                        event.from_pool, event.decline_percent, event.from_liquidity_usd, event.to_pool, event.to_liquidity_usd),
+                    severity: Severity::High,
+                    status: AlertStatus::Open,
+                    created_at: event.detected_at,
+                    resolved_at: None,
+                    tags: vec!["liquidity_migration".to_string(), event.from_pool.clone(), event.to_pool.clone()],
+                }).await;
+            }
+        }
+
+        // No subscribers is fine: the caller still gets the event
+        let _ = self.events.send(event.clone());
+    }
+}
+
+impl Default for LiquidityMigrationTracker {
+    fn default() -> Self {
+        Self::new(LiquidityMigrationConfig::default())
+    }
+}
+
+fn pair_key(token_a: &str, token_b: &str) -> (String, String) {
+    if token_a <= token_b {
+        (token_a.to_string(), token_b.to_string())
+    } else {
+        (token_b.to_string(), token_a.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(address: &str, token_a: &str, token_b: &str, liquidity_usd: f64) -> PoolData {
+        PoolData {
+            address: address.to_string(),
+            token_a: token_a.to_string(),
+            token_b: token_b.to_string(),
+            token_a_amount: 0.0,
+            token_b_amount: 0.0,
+            liquidity_usd,
+            volume_24h_usd: 0.0,
+            fee_rate: 0.0025,
+            created_at: Utc::now(),
+            holder_count: 0,
+            market_cap_usd: 0.0,
+            program_id: None,
+            lp_mint: None,
+            creator: None,
+        }
+    }
+
+    fn snapshot(pool_address: &str, minutes: i64, liquidity_usd: f64) -> PoolSnapshot {
+        PoolSnapshot {
+            pool_address: pool_address.to_string(),
+            token_a_amount: 0.0,
+            token_b_amount: 0.0,
+            lp_supply: None,
+            liquidity_usd,
+            slot: minutes as u64,
+            observed_at: Utc::now() + Duration::minutes(minutes),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_decline_with_replacement_repoints_route() {
+        let tracker = LiquidityMigrationTracker::default();
+        let mut events = tracker.subscribe();
+        tracker.set_held_tokens(HashSet::from(["MEME".to_string()])).await;
+
+        assert!(tracker.register_pool(&pool("v4", "MEME", "SOL", 500_000.0), PoolVersion::RaydiumAmmV4).await.is_none());
+        assert!(tracker.register_pool(&pool("clmm", "SOL", "MEME", 20_000.0), PoolVersion::RaydiumClmm).await.is_none());
+        assert_eq!(tracker.primary_pool("SOL", "MEME").await.unwrap().pool_address, "v4");
+
+        // v4 drains while the CLMM pool fills
+        assert!(tracker.observe(&snapshot("v4", 10, 400_000.0)).await.is_none());
+        tracker.observe(&snapshot("clmm", 11, 150_000.0)).await;
+        let migrating = tracker.observe(&snapshot("v4", 20, 250_000.0)).await.unwrap();
+        assert!(!migrating.rerouted);
+        assert_eq!(migrating.to_pool, "clmm");
+        assert_eq!(migrating.held_tokens, vec!["MEME"]);
+        // Reported once per stage
+        assert!(tracker.observe(&snapshot("v4", 21, 240_000.0)).await.is_none());
+
+        // Once the CLMM pool holds more, the route moves over
+        let rerouted = tracker.observe(&snapshot("clmm", 30, 420_000.0)).await.unwrap();
+        assert!(rerouted.rerouted);
+        assert!(rerouted.decline_percent > 50.0);
+        let primary = tracker.primary_pool("MEME", "SOL").await.unwrap();
+        assert_eq!((primary.pool_address.as_str(), primary.version), ("clmm", PoolVersion::RaydiumClmm));
+        assert!(tracker.observe(&snapshot("v4", 31, 90_000.0)).await.is_none());
+        assert!(!events.try_recv().unwrap().rerouted);
+        assert!(events.try_recv().unwrap().rerouted);
+    }
+
+    #[tokio::test]
+    async fn test_plain_decline_and_old_peaks_do_not_migrate() {
+        let tracker = LiquidityMigrationTracker::default();
+        tracker.register_pool(&pool("v4", "MEME", "SOL", 100_000.0), PoolVersion::RaydiumAmmV4).await;
+        // Unknown pools are ignored
+        assert!(tracker.observe(&snapshot("other", 1, 1.0)).await.is_none());
+
+        // -70% with no other pool for the pair
+        assert!(tracker.observe(&snapshot("v4", 1, 30_000.0)).await.is_none());
+        // The peak has left the 6h window by the time a replacement shows up
+        tracker.observe(&snapshot("v4", 400, 30_000.0)).await;
+        tracker.observe(&snapshot("v4", 401, 29_000.0)).await;
+        assert!(tracker.register_pool(&pool("cpmm", "MEME", "SOL", 60_000.0), PoolVersion::RaydiumCpmm).await.is_none());
+        assert_eq!(tracker.primary_pool("MEME", "SOL").await.unwrap().pool_address, "v4");
+
+        assert_eq!(PoolVersion::classify(&DexType::Raydium, Some(RAYDIUM_CPMM_PROGRAM)), PoolVersion::RaydiumCpmm);
+        assert_eq!(PoolVersion::classify(&DexType::Orca, None).dex(), DexType::Orca);
+    }
+}
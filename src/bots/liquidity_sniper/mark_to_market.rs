@@ -0,0 +1,372 @@
+// SniperForge Enterprise v3.0 - Mark-to-Market Service
+// Revalues open positions on every price tick for their token
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::{broadcast, mpsc, RwLock};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use super::PositionData;
+
+/// Mark-to-market configuration
+#[derive(Debug, Clone)]
+pub struct MarkToMarketConfig {
+    /// Drawdown from the position's peak value that fires a risk callback (%)
+    pub drawdown_alert_percent: f64,
+    /// Ticks older than the last mark for a token are ignored
+    pub ignore_out_of_order: bool,
+    pub channel_capacity: usize,
+}
+
+impl Default for MarkToMarketConfig {
+    fn default() -> Self {
+        Self {
+            drawdown_alert_percent: 25.0,
+            ignore_out_of_order: true,
+            channel_capacity: 256,
+        }
+    }
+}
+
+/// Live valuation of one open position
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionMark {
+    pub position_id: Uuid,
+    pub token_address: String,
+    pub pool_address: String,
+    pub entry_price: f64,
+    pub current_price: f64,
+    pub cost_basis_sol: f64,
+    pub market_value_sol: f64,
+    pub unrealized_pnl: f64,
+    pub unrealized_pnl_percent: f64,
+    /// Highest market value since entry
+    pub peak_value_sol: f64,
+    /// Drop from `peak_value_sol` (%)
+    pub drawdown_percent: f64,
+    pub marked_at: DateTime<Utc>,
+    pub ticks: u64,
+}
+
+/// Why a risk callback fired
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MarkAlertKind {
+    StopLoss { stop_price: f64 },
+    TargetReached { target_price: f64 },
+    Drawdown { threshold_percent: f64 },
+}
+
+impl MarkAlertKind {
+    fn key(&self) -> &'static str {
+        match self {
+            MarkAlertKind::StopLoss { .. } => "stop_loss",
+            MarkAlertKind::TargetReached { .. } => "target",
+            MarkAlertKind::Drawdown { .. } => "drawdown",
+        }
+    }
+}
+
+/// Risk condition crossed by a revaluation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkAlert {
+    pub kind: MarkAlertKind,
+    pub mark: PositionMark,
+}
+
+/// Aggregate of all open positions
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MarkTotals {
+    pub open_positions: usize,
+    pub cost_basis_sol: f64,
+    pub market_value_sol: f64,
+    pub unrealized_pnl: f64,
+    pub worst_drawdown_percent: f64,
+}
+
+/// Price observation for a token
+#[derive(Debug, Clone)]
+pub struct PriceTick {
+    pub token_address: String,
+    pub price: f64,
+    pub at: DateTime<Utc>,
+}
+
+/// Invoked for every alert, in registration order
+pub type RiskCallback = Arc<dyn Fn(&MarkAlert) + Send + Sync>;
+
+#[derive(Debug, Clone)]
+struct TrackedPosition {
+    position: PositionData,
+    mark: PositionMark,
+    /// Alert kinds already fired; each fires once per position
+    fired: HashSet<&'static str>,
+}
+
+/// Keeps `current_price`/`unrealized_pnl` of open positions current
+pub struct MarkToMarketService {
+    config: MarkToMarketConfig,
+    positions: RwLock<HashMap<Uuid, TrackedPosition>>,
+    last_tick: RwLock<HashMap<String, DateTime<Utc>>>,
+    callbacks: RwLock<Vec<RiskCallback>>,
+    marks: broadcast::Sender<PositionMark>,
+}
+
+impl MarkToMarketService {
+    pub fn new(config: MarkToMarketConfig) -> Self {
+        let (marks, _) = broadcast::channel(config.channel_capacity.max(1));
+        Self {
+            config,
+            positions: RwLock::new(HashMap::new()),
+            last_tick: RwLock::new(HashMap::new()),
+            callbacks: RwLock::new(Vec::new()),
+            marks,
+        }
+    }
+
+    /// Start revaluing `position`, marked at its current price
+    pub async fn track(&self, position: &PositionData) -> PositionMark {
+        let cost = position.amount_sol_invested;
+        let now = Utc::now();
+        let mut mark = PositionMark {
+            position_id: position.id,
+            token_address: position.token_address.clone(),
+            pool_address: position.pool_address.clone(),
+            entry_price: position.entry_price,
+            current_price: position.current_price,
+            cost_basis_sol: cost,
+            market_value_sol: cost,
+            unrealized_pnl: 0.0,
+            unrealized_pnl_percent: 0.0,
+            peak_value_sol: cost,
+            drawdown_percent: 0.0,
+            marked_at: now,
+            ticks: 0,
+        };
+        revalue(&mut mark, position.current_price, now);
+        mark.ticks = 0;
+
+        let mut position = position.clone();
+        apply(&mut position, &mark);
+        debug!("📊 Marking position {} ({}) to market", position.id, position.token_address);
+        self.positions.write().await.insert(position.id, TrackedPosition { position, mark: mark.clone(), fired: HashSet::new() });
+        mark
+    }
+
+    /// Stop revaluing a closed position; returns its last mark
+    pub async fn untrack(&self, position_id: Uuid) -> Option<PositionMark> {
+        self.positions.write().await.remove(&position_id).map(|tracked| tracked.mark)
+    }
+
+    /// Register a risk callback (stop loss, target, drawdown)
+    pub async fn on_risk(&self, callback: RiskCallback) {
+        self.callbacks.write().await.push(callback);
+    }
+
+    /// Every revaluation, for dashboards and streaming APIs
+    pub fn subscribe(&self) -> broadcast::Receiver<PositionMark> {
+        self.marks.subscribe()
+    }
+
+    /// Revalue every open position in `token_address`; returns alerts fired by this tick
+    pub async fn on_price(&self, token_address: &str, price: f64, at: DateTime<Utc>) -> Vec<MarkAlert> {
+        if !price.is_finite() || price <= 0.0 {
+            return Vec::new();
+        }
+        if self.config.ignore_out_of_order {
+            let mut last_tick = self.last_tick.write().await;
+            if last_tick.get(token_address).is_some_and(|last| at < *last) {
+                return Vec::new();
+            }
+            last_tick.insert(token_address.to_string(), at);
+        }
+
+        let mut alerts = Vec::new();
+        {
+            let mut positions = self.positions.write().await;
+            for tracked in positions.values_mut().filter(|t| t.position.token_address == token_address) {
+                revalue(&mut tracked.mark, price, at);
+                apply(&mut tracked.position, &tracked.mark);
+                let _ = self.marks.send(tracked.mark.clone());
+
+                for kind in self.breaches(&tracked.position, &tracked.mark) {
+                    if tracked.fired.insert(kind.key()) {
+                        alerts.push(MarkAlert { kind, mark: tracked.mark.clone() });
+                    }
+                }
+            }
+        }
+
+        if !alerts.is_empty() {
+            let callbacks = self.callbacks.read().await.clone();
+            for alert in &alerts {
+                warn!("🚨 {:?} on position {} at {:.8} (PnL {:+.2}%)",
+                      alert.kind, alert.mark.position_id, alert.mark.current_price, alert.mark.unrealized_pnl_percent);
+                for callback in &callbacks {
+                    callback(alert);
+                }
+            }
+        }
+        alerts
+    }
+
+    fn breaches(&self, position: &PositionData, mark: &PositionMark) -> Vec<MarkAlertKind> {
+        let mut kinds = Vec::new();
+        if let Some(stop_price) = position.stop_loss_price.filter(|stop| mark.current_price <= *stop) {
+            kinds.push(MarkAlertKind::StopLoss { stop_price });
+        }
+        if let Some(target_price) = position.target_price.filter(|target| mark.current_price >= *target) {
+            kinds.push(MarkAlertKind::TargetReached { target_price });
+        }
+        if mark.drawdown_percent >= self.config.drawdown_alert_percent {
+            kinds.push(MarkAlertKind::Drawdown { threshold_percent: self.config.drawdown_alert_percent });
+        }
+        kinds
+    }
+
+    /// Revalue from a stream of ticks until the sender is dropped
+    pub fn spawn(self: Arc<Self>, mut ticks: mpsc::Receiver<PriceTick>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            info!("📊 Mark-to-market task started");
+            while let Some(tick) = ticks.recv().await {
+                self.on_price(&tick.token_address, tick.price, tick.at).await;
+            }
+            info!("📊 Mark-to-market task stopped (price feed closed)");
+        })
+    }
+
+    /// Latest mark of every open position, worst PnL first
+    pub async fn marks(&self) -> Vec<PositionMark> {
+        let mut marks: Vec<PositionMark> = self.positions.read().await.values().map(|t| t.mark.clone()).collect();
+        marks.sort_by(|a, b| a.unrealized_pnl.total_cmp(&b.unrealized_pnl));
+        marks
+    }
+
+    /// Position with live `current_price` / `unrealized_pnl`
+    pub async fn position(&self, position_id: Uuid) -> Option<PositionData> {
+        self.positions.read().await.get(&position_id).map(|t| t.position.clone())
+    }
+
+    pub async fn totals(&self) -> MarkTotals {
+        self.positions.read().await.values().fold(MarkTotals::default(), |mut totals, tracked| {
+            let mark = &tracked.mark;
+            totals.open_positions += 1;
+            totals.cost_basis_sol += mark.cost_basis_sol;
+            totals.market_value_sol += mark.market_value_sol;
+            totals.unrealized_pnl += mark.unrealized_pnl;
+            totals.worst_drawdown_percent = totals.worst_drawdown_percent.max(mark.drawdown_percent);
+            totals
+        })
+    }
+}
+
+fn revalue(mark: &mut PositionMark, price: f64, at: DateTime<Utc>) {
+    mark.current_price = price;
+    mark.market_value_sol = if mark.entry_price > 0.0 {
+        mark.cost_basis_sol * price / mark.entry_price
+    } else {
+        mark.cost_basis_sol
+    };
+    mark.unrealized_pnl = mark.market_value_sol - mark.cost_basis_sol;
+    mark.unrealized_pnl_percent = if mark.cost_basis_sol > 0.0 { mark.unrealized_pnl / mark.cost_basis_sol * 100.0 } else { 0.0 };
+    mark.peak_value_sol = mark.peak_value_sol.max(mark.market_value_sol);
+    mark.drawdown_percent = if mark.peak_value_sol > 0.0 {
+        (mark.peak_value_sol - mark.market_value_sol) / mark.peak_value_sol * 100.0
+    } else {
+        0.0
+    };
+    mark.marked_at = at;
+    mark.ticks += 1;
+}
+
+fn apply(position: &mut PositionData, mark: &PositionMark) {
+    position.current_price = mark.current_price;
+    position.unrealized_pnl = mark.unrealized_pnl;
+    position.unrealized_pnl_percent = mark.unrealized_pnl_percent;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bots::liquidity_sniper::{risk_manager::MonitoringLevel, SniperStrategy};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn position(token: &str, stop: Option<f64>) -> PositionData {
+        PositionData {
+            id: Uuid::new_v4(),
+            token_address: token.to_string(),
+            pool_address: format!("{}-pool", token),
+            amount_tokens: 1_000.0,
+            amount_sol_invested: 2.0,
+            entry_price: 0.002,
+            current_price: 0.002,
+            position_size: 2.0,
+            unrealized_pnl: 0.0,
+            unrealized_pnl_percent: 0.0,
+            stop_loss_price: stop,
+            target_price: None,
+            strategy: SniperStrategy::QuickFlip,
+            entry_time: Utc::now(),
+            monitoring_level: MonitoringLevel::Medium,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ticks_revalue_only_positions_in_that_token() {
+        let service = MarkToMarketService::new(MarkToMarketConfig::default());
+        let a = position("A", None);
+        let b = position("B", None);
+        service.track(&a).await;
+        service.track(&b).await;
+
+        let t0 = Utc::now();
+        service.on_price("A", 0.003, t0).await;
+        service.on_price("A", 0.0027, t0 + chrono::Duration::seconds(1)).await;
+
+        let live = service.position(a.id).await.unwrap();
+        assert_eq!(live.current_price, 0.0027);
+        assert!((live.unrealized_pnl - 0.7).abs() < 1e-9);
+        assert!((live.unrealized_pnl_percent - 35.0).abs() < 1e-9);
+
+        let marks = service.marks().await;
+        let mark_a = marks.iter().find(|m| m.position_id == a.id).unwrap();
+        assert!((mark_a.peak_value_sol - 3.0).abs() < 1e-9);
+        assert!((mark_a.drawdown_percent - 10.0).abs() < 1e-9);
+        assert_eq!(marks.iter().find(|m| m.position_id == b.id).unwrap().ticks, 0);
+
+        // Stale tick for A is ignored
+        service.on_price("A", 0.001, t0).await;
+        assert_eq!(service.position(a.id).await.unwrap().current_price, 0.0027);
+
+        let totals = service.totals().await;
+        assert_eq!(totals.open_positions, 2);
+        assert!((totals.unrealized_pnl - 0.7).abs() < 1e-9);
+        service.untrack(a.id).await;
+        assert_eq!(service.totals().await.open_positions, 1);
+    }
+
+    #[tokio::test]
+    async fn test_risk_callbacks_fire_once_per_breach() {
+        let service = MarkToMarketService::new(MarkToMarketConfig::default());
+        let fired = Arc::new(AtomicUsize::new(0));
+        let counter = fired.clone();
+        service.on_risk(Arc::new(move |_alert: &MarkAlert| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        })).await;
+        service.track(&position("A", Some(0.0019))).await;
+
+        let t0 = Utc::now();
+        assert!(service.on_price("A", 0.0021, t0).await.is_empty());
+        let alerts = service.on_price("A", 0.0015, t0 + chrono::Duration::seconds(1)).await;
+        let kinds: Vec<_> = alerts.iter().map(|a| a.kind.clone()).collect();
+        assert_eq!(kinds, vec![
+            MarkAlertKind::StopLoss { stop_price: 0.0019 },
+            MarkAlertKind::Drawdown { threshold_percent: 25.0 },
+        ]);
+        assert!(service.on_price("A", 0.0014, t0 + chrono::Duration::seconds(2)).await.is_empty());
+        assert_eq!(fired.load(Ordering::SeqCst), 2);
+    }
+}
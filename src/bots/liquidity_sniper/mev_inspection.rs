@@ -0,0 +1,421 @@
+// SniperForge Enterprise v3.0 - Post-Fill MEV Inspection
+// Fetches the block of each MainNet fill, checks whether our swap was sandwiched, and keeps the
+// evidence per venue and submission route so routing and slippage policy can react to it
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use parking_lot::RwLock;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use super::sandwich_risk::{ExecutedSwap, SandwichEvent, SandwichRiskEstimator, SubmissionRoute, SurroundingSwap, SwapSide};
+use crate::analytics::{decode_swap, NATIVE_SOL_MINT};
+
+/// Post-fill inspection settings
+#[derive(Debug, Clone)]
+pub struct MevInspectionConfig {
+    /// Inspect MainNet fills (other environments are never inspected)
+    pub enabled: bool,
+    pub rpc_url: String,
+    /// Wait before the first lookup so the fill is confirmed
+    pub initial_delay: Duration,
+    pub attempts: u32,
+    pub retry_delay: Duration,
+    /// Fills a venue/route needs before its sandwich rate changes routing
+    pub min_fills: u64,
+    /// Sandwich rate at or above which bundles are preferred on that venue/route
+    pub max_sandwich_rate: f64,
+}
+
+impl Default for MevInspectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            rpc_url: "https://api.mainnet-beta.solana.com".to_string(),
+            initial_delay: Duration::from_secs(2),
+            attempts: 5,
+            retry_delay: Duration::from_secs(2),
+            min_fills: 5,
+            max_sandwich_rate: 0.2,
+        }
+    }
+}
+
+/// Where fills and their blocks are read from
+#[async_trait]
+pub trait BlockSource: Send + Sync {
+    /// Slot a transaction was confirmed in; `None` if not (yet) confirmed
+    async fn transaction_slot(&self, signature: &str) -> Result<Option<u64>>;
+
+    /// Full block in `jsonParsed` encoding; `None` if not (yet) available
+    async fn block(&self, slot: u64) -> Result<Option<Value>>;
+}
+
+/// Plain Solana JSON-RPC block source
+#[derive(Debug, Clone)]
+pub struct RpcBlockSource {
+    client: reqwest::Client,
+    rpc_url: String,
+}
+
+impl RpcBlockSource {
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(30))
+                .build()
+                .unwrap_or_default(),
+            rpc_url: rpc_url.to_string(),
+        }
+    }
+
+    async fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let response: Value = self.client
+            .post(&self.rpc_url)
+            .json(&json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        if let Some(error) = response.get("error") {
+            return Err(anyhow!("{} failed: {}", method, error));
+        }
+        Ok(response.get("result").cloned().unwrap_or(Value::Null))
+    }
+}
+
+#[async_trait]
+impl BlockSource for RpcBlockSource {
+    async fn transaction_slot(&self, signature: &str) -> Result<Option<u64>> {
+        let result = self.call("getTransaction", json!([signature, {
+            "encoding": "json",
+            "commitment": "confirmed",
+            "maxSupportedTransactionVersion": 0
+        }])).await?;
+        Ok(result["slot"].as_u64())
+    }
+
+    async fn block(&self, slot: u64) -> Result<Option<Value>> {
+        let result = self.call("getBlock", json!([slot, {
+            "encoding": "jsonParsed",
+            "transactionDetails": "full",
+            "rewards": false,
+            "commitment": "confirmed",
+            "maxSupportedTransactionVersion": 0
+        }])).await?;
+        Ok((!result.is_null()).then_some(result))
+    }
+}
+
+/// One of our MainNet fills
+#[derive(Debug, Clone)]
+pub struct FillToInspect {
+    pub signature: String,
+    pub signer: String,
+    pub token_mint: String,
+    pub side: SwapSide,
+    pub venue: String,
+    pub route: SubmissionRoute,
+    /// Shortfall of the fill versus its quote (SOL), 0 when unknown
+    pub execution_shortfall_sol: f64,
+}
+
+/// Sandwich evidence for one venue and submission route
+#[derive(Debug, Clone)]
+pub struct MevExposure {
+    pub venue: String,
+    pub route: SubmissionRoute,
+    pub fills: u64,
+    pub sandwiched: u64,
+    pub extracted_value_sol: f64,
+    pub loss_sol: f64,
+}
+
+impl MevExposure {
+    pub fn sandwich_rate(&self) -> f64 {
+        if self.fills == 0 {
+            0.0
+        } else {
+            self.sandwiched as f64 / self.fills as f64
+        }
+    }
+}
+
+fn signature_of(tx: &Value) -> Option<&str> {
+    tx["transaction"]["signatures"][0].as_str()
+}
+
+fn fee_payer_of(tx: &Value) -> Option<&str> {
+    let payer = &tx["transaction"]["message"]["accountKeys"][0];
+    payer["pubkey"].as_str().or_else(|| payer.as_str())
+}
+
+/// Position of `signature` among the block's transactions
+pub fn block_index(block: &Value, signature: &str) -> Option<u32> {
+    block["transactions"]
+        .as_array()?
+        .iter()
+        .position(|tx| signature_of(tx) == Some(signature))
+        .map(|index| index as u32)
+}
+
+/// Swaps of `token_mint` in a block, decoded from each fee payer's balance changes
+///
+/// `amount_sol` is the SOL leg of the swap; swaps against other quote assets
+/// are kept for ordering with an amount of 0.
+pub fn decode_block_swaps(block: &Value, slot: u64, token_mint: &str) -> Vec<SurroundingSwap> {
+    let Some(transactions) = block["transactions"].as_array() else {
+        return Vec::new();
+    };
+    transactions
+        .iter()
+        .enumerate()
+        .filter_map(|(index, tx)| {
+            let signature = signature_of(tx)?;
+            let signer = fee_payer_of(tx)?;
+            let trade = decode_swap(signer, signature, tx)?;
+            let (side, sol_mint, sol_amount) = if trade.mint_out == token_mint {
+                (SwapSide::Buy, &trade.mint_in, trade.amount_in)
+            } else if trade.mint_in == token_mint {
+                (SwapSide::Sell, &trade.mint_out, trade.amount_out)
+            } else {
+                return None;
+            };
+            Some(SurroundingSwap {
+                signature: signature.to_string(),
+                signer: signer.to_string(),
+                slot,
+                index_in_block: index as u32,
+                token_mint: token_mint.to_string(),
+                side,
+                amount_sol: if sol_mint == NATIVE_SOL_MINT { sol_amount } else { 0.0 },
+            })
+        })
+        .collect()
+}
+
+/// Checks MainNet fills for sandwiches and aggregates the evidence
+pub struct MevInspector {
+    config: MevInspectionConfig,
+    source: Arc<dyn BlockSource>,
+    estimator: Arc<SandwichRiskEstimator>,
+    exposure: RwLock<HashMap<(String, SubmissionRoute), MevExposure>>,
+}
+
+impl MevInspector {
+    pub fn new(config: MevInspectionConfig, estimator: Arc<SandwichRiskEstimator>) -> Self {
+        let source = Arc::new(RpcBlockSource::new(&config.rpc_url));
+        Self::with_source(config, source, estimator)
+    }
+
+    pub fn with_source(config: MevInspectionConfig, source: Arc<dyn BlockSource>, estimator: Arc<SandwichRiskEstimator>) -> Self {
+        Self {
+            config,
+            source,
+            estimator,
+            exposure: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &MevInspectionConfig {
+        &self.config
+    }
+
+    /// Inspect one confirmed fill; errors mean the fill or its block is not available yet
+    pub async fn inspect(&self, fill: &FillToInspect) -> Result<Option<SandwichEvent>> {
+        let slot = self.source.transaction_slot(&fill.signature).await?
+            .ok_or_else(|| anyhow!("{} not confirmed yet", fill.signature))?;
+        let block = self.source.block(slot).await?
+            .ok_or_else(|| anyhow!("block {} not available yet", slot))?;
+        let index = block_index(&block, &fill.signature)
+            .ok_or_else(|| anyhow!("{} not found in block {}", fill.signature, slot))?;
+
+        let surrounding = decode_block_swaps(&block, slot, &fill.token_mint);
+        let ours = ExecutedSwap {
+            signature: fill.signature.clone(),
+            signer: fill.signer.clone(),
+            slot,
+            index_in_block: index,
+            token_mint: fill.token_mint.clone(),
+            side: fill.side,
+            execution_shortfall_sol: fill.execution_shortfall_sol,
+            route: fill.route,
+            venue: fill.venue.clone(),
+        };
+        let event = self.estimator.record_post_trade(&ours, &surrounding).await;
+        debug!("🔬 Inspected {} in slot {} (index {}, {} swaps of the token)", fill.signature, slot, index, surrounding.len());
+
+        let mut exposure = self.exposure.write();
+        let entry = exposure
+            .entry((fill.venue.clone(), fill.route))
+            .or_insert_with(|| MevExposure {
+                venue: fill.venue.clone(),
+                route: fill.route,
+                fills: 0,
+                sandwiched: 0,
+                extracted_value_sol: 0.0,
+                loss_sol: 0.0,
+            });
+        entry.fills += 1;
+        if let Some(event) = &event {
+            entry.sandwiched += 1;
+            entry.extracted_value_sol += event.extracted_value_sol;
+            entry.loss_sol += event.estimated_loss_sol;
+        }
+        Ok(event)
+    }
+
+    /// Inspect a fill in the background, retrying until its block is available
+    pub fn spawn_inspection(self: &Arc<Self>, fill: FillToInspect) {
+        let inspector = self.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(inspector.config.initial_delay).await;
+            for attempt in 1..=inspector.config.attempts.max(1) {
+                match inspector.inspect(&fill).await {
+                    Ok(_) => return,
+                    Err(e) => {
+                        debug!("🔬 MEV inspection of {} attempt {} failed: {}", fill.signature, attempt, e);
+                        tokio::time::sleep(inspector.config.retry_delay).await;
+                    }
+                }
+            }
+            warn!("⚠️ Gave up inspecting {} for sandwiches", fill.signature);
+        });
+    }
+
+    /// Evidence per venue and route, most sandwiched first
+    pub fn exposure(&self) -> Vec<MevExposure> {
+        let mut rows: Vec<MevExposure> = self.exposure.read().values().cloned().collect();
+        rows.sort_by(|a, b| b.sandwich_rate().total_cmp(&a.sandwich_rate()));
+        rows
+    }
+
+    /// Whether fills on `venue` via `route` get sandwiched often enough to submit as bundles instead
+    pub fn prefer_bundle(&self, venue: &str, route: SubmissionRoute) -> bool {
+        if route == SubmissionRoute::JitoBundle {
+            return false;
+        }
+        let exposure = self.exposure.read();
+        let Some(stats) = exposure.get(&(venue.to_string(), route)) else {
+            return false;
+        };
+        let prefer = stats.fills >= self.config.min_fills && stats.sandwich_rate() >= self.config.max_sandwich_rate;
+        if prefer {
+            info!("🥪 {} via {:?}: {} of {} fills sandwiched ({:.4} SOL extracted) - preferring bundles",
+                  venue, route, stats.sandwiched, stats.fills, stats.extracted_value_sol);
+        }
+        prefer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ME: &str = "Me11111111111111111111111111111111111111111";
+    const BOT: &str = "Bot1111111111111111111111111111111111111111";
+    const MINT: &str = "Mint111111111111111111111111111111111111111";
+
+    /// Fee payer spends `sol_in` SOL for 1000 tokens (buy) or sells 1000 tokens for `sol_out`
+    fn swap_tx(signature: &str, payer: &str, buy: bool, sol: f64) -> Value {
+        let lamports = (sol * 1e9) as i64;
+        let (pre_sol, post_sol, pre_tokens, post_tokens) = if buy {
+            (10_000_000_000 + lamports, 10_000_000_000, "0", "1000")
+        } else {
+            (10_000_000_000, 10_000_000_000 + lamports, "1000", "0")
+        };
+        json!({
+            "meta": {
+                "err": null,
+                "fee": 0,
+                "preBalances": [pre_sol],
+                "postBalances": [post_sol],
+                "preTokenBalances": [{ "mint": MINT, "owner": payer, "uiTokenAmount": { "uiAmountString": pre_tokens } }],
+                "postTokenBalances": [{ "mint": MINT, "owner": payer, "uiTokenAmount": { "uiAmountString": post_tokens } }]
+            },
+            "transaction": {
+                "signatures": [signature],
+                "message": { "accountKeys": [{ "pubkey": payer }] }
+            }
+        })
+    }
+
+    struct MockBlocks {
+        block: Value,
+    }
+
+    #[async_trait]
+    impl BlockSource for MockBlocks {
+        async fn transaction_slot(&self, signature: &str) -> Result<Option<u64>> {
+            Ok((signature != "pending").then_some(300))
+        }
+
+        async fn block(&self, _slot: u64) -> Result<Option<Value>> {
+            Ok(Some(self.block.clone()))
+        }
+    }
+
+    fn fill(signature: &str) -> FillToInspect {
+        FillToInspect {
+            signature: signature.to_string(),
+            signer: ME.to_string(),
+            token_mint: MINT.to_string(),
+            side: SwapSide::Buy,
+            venue: "Raydium".to_string(),
+            route: SubmissionRoute::PublicRpc,
+            execution_shortfall_sol: 0.01,
+        }
+    }
+
+    #[test]
+    fn test_decode_block_swaps_in_order() {
+        let block = json!({ "transactions": [
+            swap_tx("front", BOT, true, 2.0),
+            swap_tx("ours", ME, true, 1.0),
+            json!({ "meta": { "err": null }, "transaction": { "signatures": ["vote"], "message": { "accountKeys": [{ "pubkey": "Vote" }] } } }),
+            swap_tx("back", BOT, false, 2.1),
+        ] });
+
+        let swaps = decode_block_swaps(&block, 300, MINT);
+        assert_eq!(swaps.iter().map(|s| s.signature.as_str()).collect::<Vec<_>>(), vec!["front", "ours", "back"]);
+        assert_eq!(swaps[2].index_in_block, 3);
+        assert_eq!(swaps[2].side, SwapSide::Sell);
+        assert!((swaps[2].amount_sol - 2.1).abs() < 1e-9);
+        assert_eq!(block_index(&block, "ours"), Some(1));
+        assert!(decode_block_swaps(&block, 300, "OtherMint").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sandwich_recorded_per_venue_and_route() {
+        let sandwiched = json!({ "transactions": [
+            swap_tx("front", BOT, true, 2.0),
+            swap_tx("ours", ME, true, 1.0),
+            swap_tx("back", BOT, false, 2.1),
+        ] });
+        let estimator = Arc::new(SandwichRiskEstimator::default());
+        let config = MevInspectionConfig { min_fills: 2, ..Default::default() };
+        let inspector = MevInspector::with_source(config, Arc::new(MockBlocks { block: sandwiched }), estimator.clone());
+
+        let event = inspector.inspect(&fill("ours")).await.unwrap().expect("sandwich detected");
+        assert_eq!(event.attacker, BOT);
+        assert_eq!(event.venue, "Raydium");
+        assert!((event.extracted_value_sol - 0.1).abs() < 1e-6);
+        assert!(inspector.inspect(&fill("pending")).await.is_err());
+        // One fill is not enough evidence
+        assert!(!inspector.prefer_bundle("Raydium", SubmissionRoute::PublicRpc));
+
+        inspector.inspect(&fill("ours")).await.unwrap();
+        let exposure = inspector.exposure();
+        assert_eq!(exposure.len(), 1);
+        assert_eq!((exposure[0].fills, exposure[0].sandwiched), (2, 2));
+        assert!((exposure[0].loss_sol - 0.02).abs() < 1e-9);
+        assert!(inspector.prefer_bundle("Raydium", SubmissionRoute::PublicRpc));
+        assert!(!inspector.prefer_bundle("Raydium", SubmissionRoute::JitoBundle));
+        assert!(!inspector.prefer_bundle("Orca", SubmissionRoute::PublicRpc));
+        assert_eq!(estimator.get_events().await.len(), 2);
+    }
+}
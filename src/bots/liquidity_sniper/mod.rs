@@ -11,6 +11,8 @@ use tracing::{info, warn, error};
 use std::sync::Arc;
 
 use crate::api::bot_interface::Environment;
+use crate::apis::fiat_rates::{fiat_rates, FiatRateService};
+use crate::analytics::SeasonalityStats;
 
 pub mod pool_monitor;
 pub mod opportunity_analyzer;
@@ -19,13 +21,37 @@ pub mod risk_manager;
 pub mod position_manager;
 pub mod cost_analyzer;
 pub mod capital_progression;
+pub mod entry_guard;
+pub mod holder_analysis;
+pub mod sandwich_risk;
+pub mod mev_inspection;
+pub mod stale_positions;
+pub mod liquidity_events;
+pub mod liquidity_migration;
+pub mod slot_timing;
+pub mod mark_to_market;
 
-use pool_monitor::PoolMonitor;
+use pool_monitor::{PoolMonitor, PoolMarketSample, RpcPoolCreationSource};
 use opportunity_analyzer::OpportunityAnalyzer;
-use trade_executor::TradeExecutor;
+use trade_executor::{TradeExecutor, LeaderAwarenessConfig};
 use risk_manager::{RiskManager, MonitoringLevel};
 use position_manager::PositionManager;
 use cost_analyzer::{CostAnalyzer, CostConfig};
+use entry_guard::{PriceRocGuard, RocGuardConfig, PriceSample, EntryGuardDecision};
+use holder_analysis::{HolderAnalyzer, HolderAnalysisConfig, RpcHolderDataSource, DeployerRegistry};
+use stale_positions::{StalePositionConfig, StalePositionDetector, StalePosition, ForcedExitPolicy, ForcedExitReport, JupiterExitVenue};
+use liquidity_events::{LiquidityEventDetector, LiquidityEventConfig, LiquidityEvent, LiquidityEventKind, PoolSnapshot};
+use liquidity_migration::{LiquidityMigrationTracker, LiquidityMigrationConfig};
+use slot_timing::SlotTimingConfig;
+use mev_inspection::MevInspectionConfig;
+use mark_to_market::{MarkToMarketService, MarkToMarketConfig, MarkAlert};
+use crate::trading::execution::JupiterRealConfig;
+use crate::trading::fee_budget::{FeeBudgetManager, FeeKind};
+use crate::trading::scoring::{ScoringPipeline, ScoringConfig, ScoreFeatures};
+use crate::ml::{SuccessModel, SuccessFeatures};
+
+/// How often tracked pools are sampled for the entry guard and mark-to-market
+const MARKET_DATA_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
 
 /// DEX types supported by the sniper
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -58,6 +84,7 @@ pub struct TradeData {
     pub priority_fee: u64,
     pub started_at: DateTime<Utc>,
     pub strategy: Option<SniperStrategy>, // 🚀 ENRIQUECIMIENTO: Strategy field for trade executor
+    pub pool_sol_reserve: Option<f64>,    // SOL-side pool reserve for sandwich risk estimation
 }
 
 /// Trade execution result
@@ -112,6 +139,33 @@ pub struct OpportunityData {
     pub volume_24h_usd: f64,
     pub holder_count: u64,
     pub age_minutes: u64,
+    /// LP token mint, when the pool exposes one
+    pub lp_mint: Option<String>,
+    /// Token deployer wallet, when known
+    pub deployer_address: Option<String>,
+    /// Explainable reasons behind `risk_score`
+    pub risk_reasons: Vec<String>,
+    /// Strategy the entry is made for (scoring, entry guard thresholds, exits)
+    pub strategy: SniperStrategy,
+}
+
+impl OpportunityData {
+    /// Inputs for the scoring pipeline; detector confidence stands in for a model prediction
+    pub fn score_features(&self) -> ScoreFeatures {
+        ScoreFeatures {
+            expected_profit_pct: self.estimated_profit_percent,
+            liquidity_usd: Some(self.liquidity_usd),
+            risk_score: Some(self.risk_score),
+            sentiment: None,
+            model_probability: Some(self.confidence_score),
+            success_probability: None,
+        }
+    }
+    
+    /// Inputs for the success model; volatility is not tracked per opportunity yet
+    pub fn success_features(&self) -> SuccessFeatures {
+        SuccessFeatures::new(self.estimated_profit_percent * 100.0, self.liquidity_usd, None, self.detected_at, &format!("{:?}", self.dex))
+    }
 }
 
 /// Market data structure
@@ -139,6 +193,8 @@ pub struct TradeRecord {
     pub profit_percent: f64,
     pub holding_time: Option<Duration>,
     pub execution_time_ms: u64,
+    /// Fingerprint of the binary that executed the trade
+    pub build: String,
 }
 
 /// Enterprise-grade Liquidity Sniper Bot with world-class guarantees
@@ -155,6 +211,16 @@ pub struct LiquiditySniperBot {
     pub cost_analyzer: Arc<CostAnalyzer>,
     pub metrics: RwLock<SniperMetrics>,
     pub performance_tracker: Arc<RwLock<PerformanceTracker>>,
+    pub fiat_rates: Arc<FiatRateService>,
+    pub roc_guard: Arc<PriceRocGuard>,
+    pub stale_detector: StalePositionDetector,
+    pub forced_exits: Arc<ForcedExitPolicy>,
+    pub fee_budget: Arc<FeeBudgetManager>,
+    pub liquidity_events: Arc<LiquidityEventDetector>,
+    pub liquidity_migration: Arc<LiquidityMigrationTracker>,
+    pub scoring: Arc<ScoringPipeline>,
+    pub success_model: Arc<SuccessModel>,
+    pub mark_to_market: Arc<MarkToMarketService>,
 }
 
 /// Enterprise sniper configuration with professional guarantees
@@ -207,6 +273,36 @@ pub struct SniperConfig {
     
     /// Maximum simultaneous positions
     pub max_positions: u32,
+    
+    /// Pre-entry price/volume rate-of-change guard
+    pub roc_guard: RocGuardConfig,
+    
+    /// Per-strategy overrides for the rate-of-change guard
+    pub roc_guard_overrides: HashMap<SniperStrategy, RocGuardConfig>,
+    
+    /// Stale position detection and forced exits
+    pub stale_positions: StalePositionConfig,
+    
+    /// LP add/remove significance thresholds per pool size class
+    pub liquidity_events: LiquidityEventConfig,
+    
+    /// Detection of liquidity moving to replacement pools of the same pair
+    pub liquidity_migration: LiquidityMigrationConfig,
+    
+    /// Opportunity score weights and thresholds, per strategy
+    pub scoring: ScoringConfig,
+    
+    /// Slot-boundary submission timing
+    pub slot_timing: SlotTimingConfig,
+    
+    /// Leader schedule tracking and per-leader Jito tips
+    pub leader_awareness: LeaderAwarenessConfig,
+    
+    /// Live revaluation of open positions on price ticks
+    pub mark_to_market: MarkToMarketConfig,
+    
+    /// Post-fill sandwich detection on MainNet
+    pub mev_inspection: MevInspectionConfig,
 }
 
 /// Current state of the sniper bot
@@ -283,6 +379,8 @@ pub struct PerformanceTracker {
     pub strategy_performance: HashMap<SniperStrategy, StrategyStats>,
     pub dex_performance: HashMap<DexType, DexStats>,
     pub recent_trades: Vec<TradeRecord>,
+    /// Hour-of-week opportunity/PnL profile backing `hourly_performance`
+    pub seasonality: SeasonalityStats,
 }
 
 #[derive(Debug, Clone)]
@@ -342,6 +440,16 @@ impl Default for SniperConfig {
             use_private_mempool: true,
             advanced_analytics: true,
             max_positions: 3,
+            roc_guard: RocGuardConfig::default(),
+            roc_guard_overrides: HashMap::new(),
+            stale_positions: StalePositionConfig::default(),
+            liquidity_events: LiquidityEventConfig::default(),
+            liquidity_migration: LiquidityMigrationConfig::default(),
+            scoring: ScoringConfig::default(),
+            slot_timing: SlotTimingConfig::default(),
+            leader_awareness: LeaderAwarenessConfig::default(),
+            mark_to_market: MarkToMarketConfig::default(),
+            mev_inspection: MevInspectionConfig::default(),
         }
     }
 }
@@ -485,12 +593,29 @@ impl LiquiditySniperBot {
         info!("   Capital: {} SOL", config.capital_allocation);
         info!("   Environment: {:?}", config.environment);
         
-        let pool_monitor = Arc::new(PoolMonitor::new(&config).await?);
-        let analyzer = Arc::new(OpportunityAnalyzer::new(&config)?);
+        let liquidity_migration = Arc::new(LiquidityMigrationTracker::new(config.liquidity_migration.clone()));
+        let rpc_url = std::env::var("SOLANA_RPC_URL").unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string());
+        let pool_monitor = Arc::new(PoolMonitor::new(&config).await?
+            .with_migration_tracker(liquidity_migration.clone())
+            .with_creation_source(Arc::new(RpcPoolCreationSource::new(rpc_url.clone()))));
+        let holder_analyzer = Arc::new(HolderAnalyzer::new(
+            HolderAnalysisConfig::default(),
+            Arc::new(RpcHolderDataSource::new(rpc_url)),
+            Arc::new(DeployerRegistry::new()),
+        ));
+        let analyzer = Arc::new(OpportunityAnalyzer::new(&config)?.with_holder_analyzer(holder_analyzer));
         let executor = Arc::new(TradeExecutor::new(&config).await?);
         let risk_manager = Arc::new(Mutex::new(RiskManager::new(config.clone())?));
         let position_manager = Arc::new(PositionManager::new(&config)?);
         let cost_analyzer = Arc::new(CostAnalyzer::new(CostConfig::default()));
+        let roc_guard = Arc::new(PriceRocGuard::new(config.roc_guard.clone(), config.roc_guard_overrides.clone()));
+        let stale_detector = StalePositionDetector::new(config.stale_positions.clone());
+        let forced_exits = Arc::new(ForcedExitPolicy::new(config.stale_positions.clone())
+            .with_venue(Arc::new(JupiterExitVenue::new(JupiterRealConfig::default(), executor.active_wallet().to_string(), 6))));
+        let liquidity_events = Arc::new(LiquidityEventDetector::new(config.liquidity_events.clone()));
+        let scoring = Arc::new(ScoringPipeline::standard(
+            config.scoring.clone(), config.target_profit_percent, config.min_liquidity_usd));
+        let mark_to_market = Arc::new(MarkToMarketService::new(config.mark_to_market.clone()));
         
         Ok(Self {
             id,
@@ -505,9 +630,31 @@ impl LiquiditySniperBot {
             cost_analyzer,
             metrics: RwLock::new(SniperMetrics::new()),
             performance_tracker: Arc::new(RwLock::new(PerformanceTracker::new())),
+            fiat_rates: fiat_rates(),
+            roc_guard,
+            stale_detector,
+            forced_exits,
+            fee_budget: Arc::new(FeeBudgetManager::default()),
+            liquidity_events,
+            liquidity_migration,
+            scoring,
+            success_model: Arc::new(SuccessModel::default()),
+            mark_to_market,
         })
     }
     
+    /// Share a success model (and its training outcomes) with other bots
+    pub fn with_success_model(mut self, success_model: Arc<SuccessModel>) -> Self {
+        self.success_model = success_model;
+        self
+    }
+    
+    /// Share a fee budget with other bots (caps are tracked per bot id)
+    pub fn with_fee_budget(mut self, fee_budget: Arc<FeeBudgetManager>) -> Self {
+        self.fee_budget = fee_budget;
+        self
+    }
+    
     /// Start enterprise sniper hunting with world-class execution
     pub async fn start_hunting(&self) -> Result<()> {
         info!("🚀 Starting Enterprise Liquidity Sniper Bot");
@@ -529,19 +676,31 @@ impl LiquiditySniperBot {
         
         info!("✅ Enterprise Sniper Bot is now hunting opportunities");
         
-        // Main opportunity processing loop
-        while let Some(opportunity) = opportunity_rx.recv().await {
-            if let Err(e) = self.process_opportunity(opportunity).await {
-                error!("❌ Error processing opportunity: {}", e);
-                
-                // Update error metrics
-                let mut metrics = self.metrics.write().await;
-                let total_ops = metrics.total_opportunities_detected as f64;
-                metrics.error_rate_percent = if total_ops > 0.0 { 
-                    (1.0 / total_ops) * 100.0 
-                } else { 
-                    0.0 
-                };
+        // Main opportunity processing loop; tracked pools are sampled in between
+        let mut market_data_tick = tokio::time::interval(MARKET_DATA_INTERVAL);
+        loop {
+            tokio::select! {
+                opportunity = opportunity_rx.recv() => {
+                    let Some(opportunity) = opportunity else { break };
+                    if let Err(e) = self.process_opportunity(opportunity).await {
+                        error!("❌ Error processing opportunity: {}", e);
+                        
+                        // Update error metrics
+                        let mut metrics = self.metrics.write().await;
+                        let total_ops = metrics.total_opportunities_detected as f64;
+                        metrics.error_rate_percent = if total_ops > 0.0 { 
+                            (1.0 / total_ops) * 100.0 
+                        } else { 
+                            0.0 
+                        };
+                    }
+                }
+                _ = market_data_tick.tick() => {
+                    for alert in self.refresh_market_data().await {
+                        warn!("🚨 Position {} in {}: {:?} at {:.9}",
+                              alert.mark.position_id, alert.mark.token_address, alert.kind, alert.mark.current_price);
+                    }
+                }
             }
         }
         
@@ -560,6 +719,7 @@ impl LiquiditySniperBot {
             let mut metrics = self.metrics.write().await;
             metrics.total_opportunities_detected += 1;
         }
+        self.performance_tracker.write().await.record_opportunity(Utc::now());
         
         // Update state
         {
@@ -567,6 +727,13 @@ impl LiquiditySniperBot {
             *state = SniperState::AnalyzingOpportunity(opportunity.clone());
         }
         
+        // Watched from now on: a large LP add re-triggers entry, an LP pull triggers exit
+        self.liquidity_events.watch(&opportunity.pool_address, opportunity.dex.clone()).await;
+        
+        // Holder distribution / LP concentration / deployer history
+        let mut opportunity = opportunity;
+        self.analyzer.apply_holder_analysis(&mut opportunity).await;
+        
         // Enterprise risk assessment
         let risk_assessment = self.risk_manager.lock().await.assess_opportunity(&opportunity).await?;
         
@@ -580,22 +747,42 @@ impl LiquiditySniperBot {
             return Ok(());
         }
         
-        // Advanced opportunity analysis
-        let analysis_score = opportunity.confidence_score;
+        // Weighted, explainable opportunity score
+        let strategy = format!("{:?}", opportunity.strategy);
+        let success_features = opportunity.success_features();
+        let predicted_success = self.success_model.predict(&success_features);
+        let breakdown = self.scoring.score(&strategy, &ScoreFeatures {
+            success_probability: predicted_success,
+            ..opportunity.score_features()
+        });
+        if !breakdown.passed() {
+            info!("📊 Opportunity score too low: {}", breakdown);
+            return Ok(());
+        }
         
-        if analysis_score < 0.75 {
-            info!("📊 Opportunity score too low: {:.2}", analysis_score);
+        // Pre-entry rate-of-change guard: avoid becoming exit liquidity (latest pool state first)
+        if let Some(sample) = self.pool_monitor.sample_pool(&opportunity.pool_address).await {
+            self.record_pool_sample(&sample).await;
+        }
+        let guard_decision = self.roc_guard.evaluate(&opportunity.token_address, &opportunity.strategy).await;
+        if let EntryGuardDecision::Reject { reason } = &guard_decision {
+            warn!("🛑 Opportunity rejected by rate-of-change guard: {}", reason);
             return Ok(());
         }
         
         // Calculate optimal position size
-        let position_size = self.calculate_position_size(&opportunity).await?;
+        let mut position_size = self.calculate_position_size(&opportunity).await?;
+        if let EntryGuardDecision::Downsize { factor, reason } = &guard_decision {
+            info!("📉 Downsizing entry {:.3} → {:.3} SOL: {}", position_size, position_size * factor, reason);
+            position_size *= factor;
+        }
         
         // Execute trade with enterprise guarantees
         let trade_result = self.execute_sniper_trade(&opportunity, position_size).await?;
         
         // Record execution latency
         let execution_time = start_time.elapsed().as_millis() as u64;
+        self.success_model.record_outcome(success_features, predicted_success, trade_result.success);
         
         if trade_result.success {
             info!("✅ Trade executed successfully in {}ms", execution_time);
@@ -610,9 +797,10 @@ impl LiquiditySniperBot {
             }
             
             // Start position management
-            if let Some(_position) = trade_result.position {
-                let position_id = Uuid::new_v4();
-                info!("📈 Position opened: {}", position_id);
+            if let Some(position) = trade_result.position {
+                info!("📈 Position opened: {}", position.id);
+                self.mark_to_market.track(&position).await;
+                self.refresh_held_tokens().await;
             }
         } else {
             warn!("❌ Trade execution failed: {}", trade_result.error.unwrap_or_default());
@@ -621,21 +809,107 @@ impl LiquiditySniperBot {
         Ok(())
     }
     
+    /// Feed a market data update into the rate-of-change guard and revalue open positions
+    ///
+    /// Returns the risk alerts (stop loss, target, drawdown) the tick fired.
+    pub async fn record_market_data(&self, token_address: &str, market_data: &MarketData, volume_since_last_usd: f64) -> Vec<MarkAlert> {
+        self.roc_guard.record_sample(token_address, PriceSample {
+            timestamp: market_data.updated_at,
+            price: market_data.price,
+            volume_usd: volume_since_last_usd,
+        }).await;
+        self.mark_to_market.on_price(token_address, market_data.price, market_data.updated_at).await
+    }
+    
+    /// Sample the pools the monitor tracks (recent launches and open positions) into the market data feeds
+    pub async fn refresh_market_data(&self) -> Vec<MarkAlert> {
+        let held = self.mark_to_market.marks().await.into_iter().map(|mark| mark.pool_address).collect();
+        let mut alerts = Vec::new();
+        for sample in self.pool_monitor.sample_pools(&held).await {
+            alerts.extend(self.record_pool_sample(&sample).await);
+        }
+        alerts
+    }
+    
+    async fn record_pool_sample(&self, sample: &PoolMarketSample) -> Vec<MarkAlert> {
+        self.record_market_data(&sample.token_address, &sample.market_data, sample.volume_since_last_usd).await
+    }
+    
+    /// Feed a decoded pool account snapshot into the liquidity event detector
+    ///
+    /// A large LP add re-evaluates the pool as an entry; an LP pull goes to the
+    /// risk manager, which decides whether positions in the pool must exit.
+    /// The migration tracker sees the snapshot first and repoints the pair's
+    /// route when its liquidity has moved to a replacement pool.
+    pub async fn record_pool_snapshot(&self, snapshot: PoolSnapshot) -> Option<LiquidityEvent> {
+        if let Some(migration) = self.liquidity_migration.observe(&snapshot).await {
+            if migration.rerouted {
+                // LP pulls on the new primary matter from now on
+                self.liquidity_events.watch(&migration.to_pool, migration.to_version.dex()).await;
+            }
+        }
+        let event = self.liquidity_events.observe(snapshot).await?;
+        match event.kind {
+            LiquidityEventKind::Add => {
+                // Entering on liquidity that is still arriving rides the trend
+                match self.pool_monitor.opportunity_for_pool(&event.dex, &event.pool_address, SniperStrategy::TrendRiding).await {
+                    Ok(Some(opportunity)) => {
+                        if let Err(e) = self.process_opportunity(opportunity).await {
+                            error!("❌ Error processing LP-add entry on {}: {}", event.pool_address, e);
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(e) => warn!("⚠️ Could not re-evaluate pool {} after LP add: {}", event.pool_address, e),
+                }
+            }
+            LiquidityEventKind::Remove => {
+                // Counts against the deployer's reputation when it is a fresh launch
+                self.analyzer.record_liquidity_pull(&event).await;
+                if let Some(trigger) = self.risk_manager.lock().await.on_liquidity_event(&event) {
+                    warn!("🚨 Exit required for positions in {} ({:?})", event.pool_address, trigger);
+                }
+            }
+        }
+        Some(event)
+    }
+    
+    /// Tell the migration tracker which tokens have open positions
+    async fn refresh_held_tokens(&self) {
+        let held = self.mark_to_market.marks().await.into_iter().map(|mark| mark.token_address).collect();
+        self.liquidity_migration.set_held_tokens(held).await;
+    }
+    
     /// Execute sniper trade with MEV protection and enterprise guarantees
     async fn execute_sniper_trade(
         &self,
         opportunity: &OpportunityData,
         position_size: f64,
     ) -> Result<TradeResult> {
+        // Priority fee scaled down (or refused) as the daily fee cap approaches
+        let bot = self.id.to_string();
+        let Some(priority_fee) = self.fee_budget.adjust_fee(&bot, self.config.priority_fee_lamports) else {
+            return Err(anyhow::anyhow!("Daily fee budget exhausted for sniper {}", self.id));
+        };
+        
+        // Depth the swap actually trades against, read now rather than from discovery-time liquidity
+        let pool_sol_reserve = match self.pool_monitor.sol_reserve(&opportunity.pool_address).await {
+            Ok(reserve) => Some(reserve),
+            Err(e) => {
+                warn!("🥪 No SOL reserve for pool {}: {}", opportunity.pool_address, e);
+                None
+            }
+        };
+        
         let trade_data = TradeData {
             opportunity_id: opportunity.id,
             token_address: opportunity.token_address.clone(),
             amount_sol: position_size,
             estimated_price: 0.0, // Will be calculated
             max_slippage: self.config.max_slippage_bps as f64 / 10000.0,
-            priority_fee: self.config.priority_fee_lamports,
+            priority_fee,
             started_at: Utc::now(),
-            strategy: Some(SniperStrategy::LiquiditySnipe), // Default strategy for liquidity sniping
+            strategy: Some(opportunity.strategy.clone()),
+            pool_sol_reserve,
         };
         
         // Update state
@@ -645,7 +919,11 @@ impl LiquiditySniperBot {
         }
         
         // Execute through enterprise trade executor
-        self.executor.execute_sniper_trade(&trade_data).await
+        let result = self.executor.execute_sniper_trade(&trade_data).await?;
+        if result.transaction_signature.is_some() {
+            self.fee_budget.record_fee(&bot, FeeKind::PriorityFee, priority_fee);
+        }
+        Ok(result)
     }
     
     /// Calculate optimal position size based on risk parameters
@@ -683,9 +961,23 @@ impl LiquiditySniperBot {
     /// Start position monitoring task
     async fn start_position_monitoring_task(&self) -> Result<()> {
         info!("📊 Starting position monitoring task");
+        if self.config.stale_positions.enabled {
+            info!("🧟 Stale positions: forced exit after {} min below ${:.0} liquidity",
+                  self.config.stale_positions.max_age_minutes, self.config.stale_positions.min_liquidity_usd);
+        }
         Ok(())
     }
     
+    /// Positions that outlived their token's liquidity
+    pub fn get_stale_positions(&self) -> Vec<StalePosition> {
+        self.stale_detector.scan(self.position_manager.get_active_positions())
+    }
+    
+    /// Forced exits performed so far, with write-off accounting
+    pub async fn get_forced_exit_reports(&self) -> Vec<ForcedExitReport> {
+        self.forced_exits.get_reports().await
+    }
+    
     /// Get current sniper state
     pub async fn get_state(&self) -> SniperState {
         self.state.read().await.clone()
@@ -727,8 +1019,33 @@ impl PerformanceTracker {
             strategy_performance: HashMap::new(),
             dex_performance: HashMap::new(),
             recent_trades: Vec::new(),
+            seasonality: SeasonalityStats::default(),
         }
     }
+
+    /// Count a detected opportunity in its time-of-day slot
+    pub fn record_opportunity(&mut self, at: DateTime<Utc>) {
+        self.seasonality.record_scan(at, 1);
+        self.refresh_hourly_performance();
+    }
+
+    /// Count a closed trade's realized PnL in its time-of-day slot
+    pub fn record_trade(&mut self, trade: &TradeRecord) {
+        self.seasonality.record_trade(trade.timestamp, trade.profit_sol);
+        self.refresh_hourly_performance();
+    }
+
+    fn refresh_hourly_performance(&mut self) {
+        self.hourly_performance = self.seasonality.hourly_profile()
+            .into_iter()
+            .map(|bucket| HourlyPerformance {
+                hour: bucket.index,
+                avg_opportunities: bucket.opportunities_per_hour,
+                avg_profit: bucket.pnl_per_hour,
+                success_rate: bucket.win_rate,
+            })
+            .collect();
+    }
 }
 
 #[cfg(test)]
@@ -758,6 +1075,7 @@ mod tests {
             profit_percent: 20.0,
             holding_time: Some(Duration::minutes(5)),
             execution_time_ms: 150,
+            build: crate::security::build_fingerprint(),
         };
         
         metrics.update_trade_metrics(&trade);
@@ -817,6 +1135,14 @@ impl crate::api::bot_interface::BotInterface for LiquiditySniperBot {
     
     async fn metrics(&self) -> crate::api::bot_interface::BotMetrics {
         let current_metrics = self.get_metrics().await;
+        let sol_rate = match self.fiat_rates.sol_to_usd(1.0).await {
+            Ok(conversion) => Some(conversion.rate),
+            Err(e) => {
+                warn!("⚠️ SOL/USD rate unavailable, USD metrics reported as unavailable: {}", e);
+                None
+            }
+        };
+        let sol_usd = sol_rate.as_ref().map(|rate| rate.usd);
         
         crate::api::bot_interface::BotMetrics {
             operational: crate::api::bot_interface::OperationalMetrics {
@@ -829,13 +1155,14 @@ impl crate::api::bot_interface::BotInterface for LiquiditySniperBot {
             trading: crate::api::bot_interface::TradingMetrics {
                 trades_executed: current_metrics.total_trades,
                 successful_trades: current_metrics.successful_trades,
-                total_pnl_usd: current_metrics.net_profit_sol * 50.0, // Approximate SOL to USD conversion
+                total_pnl_usd: sol_usd.map(|usd| current_metrics.net_profit_sol * usd),
                 success_rate: current_metrics.win_rate_percent,
                 avg_profit_per_trade: if current_metrics.total_trades > 0 {
                     current_metrics.net_profit_sol / current_metrics.total_trades as f64
                 } else { 0.0 },
-                total_volume_usd: current_metrics.total_volume_sol * 50.0, // Approximate SOL to USD conversion
+                total_volume_usd: sol_usd.map(|usd| current_metrics.total_volume_sol * usd),
                 sharpe_ratio: None, // TODO: Calculate Sharpe ratio
+                usd_rate_timestamp: sol_rate.map(|rate| rate.fetched_at),
             },
             performance: crate::api::bot_interface::PerformanceMetrics {
                 cpu_usage_percent: 0.0, // TODO: Real CPU metrics
@@ -858,7 +1185,10 @@ impl crate::api::bot_interface::BotInterface for LiquiditySniperBot {
             custom: serde_json::json!({
                 "opportunities_detected": current_metrics.total_opportunities_detected,
                 "execution_rate": current_metrics.execution_rate_percent,
-                "net_profit_sol": current_metrics.net_profit_sol
+                "net_profit_sol": current_metrics.net_profit_sol,
+                "leaders": self.executor.leader_summaries(),
+                "open_positions": self.mark_to_market.marks().await,
+                "unrealized": self.mark_to_market.totals().await
             }),
             timestamp: Utc::now(),
         }
@@ -4,9 +4,12 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::{info, debug};
 
 use super::{OpportunityData, SniperConfig, SniperStrategy, MarketCondition};
+use super::holder_analysis::{HolderAnalyzer, HolderRiskReport};
+use super::liquidity_events::LiquidityEvent;
 
 /// Enterprise opportunity analyzer with AI-powered assessment
 #[derive(Debug)]
@@ -16,6 +19,7 @@ pub struct OpportunityAnalyzer {
     pattern_recognizer: PatternRecognizer,
     sentiment_analyzer: SentimentAnalyzer,
     historical_data: HashMap<String, HistoricalPerformance>,
+    holder_analyzer: Option<Arc<HolderAnalyzer>>,
 }
 
 /// Comprehensive opportunity analysis result
@@ -203,9 +207,82 @@ impl OpportunityAnalyzer {
             pattern_recognizer: PatternRecognizer::new()?,
             sentiment_analyzer: SentimentAnalyzer::new()?,
             historical_data: HashMap::new(),
+            holder_analyzer: None,
         })
     }
     
+    /// Enable holder distribution / LP concentration / deployer analysis
+    pub fn with_holder_analyzer(mut self, holder_analyzer: Arc<HolderAnalyzer>) -> Self {
+        self.holder_analyzer = Some(holder_analyzer);
+        self
+    }
+    
+    /// Run holder analysis and fold its sub-scores into `risk_score`
+    ///
+    /// The combined holder risk is blended 60/40 with the pool-level score and
+    /// never lowers it; every contributing finding is attached to
+    /// `risk_reasons` so rejections can be explained. When the deployer is
+    /// known, its reputation and the pool's age scale `confidence_score`.
+    pub async fn apply_holder_analysis(&self, opportunity: &mut OpportunityData) -> Option<HolderRiskReport> {
+        let holder_analyzer = self.holder_analyzer.as_ref()?;
+        let report = holder_analyzer.analyze(
+            &opportunity.token_address,
+            &opportunity.pool_address,
+            opportunity.lp_mint.as_deref(),
+            opportunity.deployer_address.as_deref(),
+        ).await;
+        
+        let mut risk_score = (opportunity.risk_score * 0.6 + report.combined_risk * 0.4).max(opportunity.risk_score);
+        if report.combined_risk >= 0.9 {
+            risk_score = risk_score.max(report.combined_risk);
+        }
+        opportunity.risk_score = risk_score.min(1.0);
+        
+        opportunity.risk_reasons.push(format!(
+            "Holder analysis: distribution {:.2}, LP {:.2}, deployer {:.2}",
+            report.distribution_risk, report.lp_risk, report.deployer_risk
+        ));
+        opportunity.risk_reasons.extend(report.reasons.iter().cloned());
+        
+        // Deployer reputation and pool age scale confidence
+        if let Some(reputation) = &report.deployer_reputation {
+            let launch = holder_analyzer.launch_confidence(reputation, opportunity.age_minutes);
+            opportunity.confidence_score = (opportunity.confidence_score * launch.multiplier).clamp(0.0, 1.0);
+            opportunity.risk_reasons.push(format!(
+                "Confidence x{:.2} (deployer reputation {:.2}, pool age {} min)",
+                launch.multiplier, launch.reputation_score, opportunity.age_minutes
+            ));
+            opportunity.risk_reasons.extend(
+                reputation.factors.iter().chain(launch.factors.iter())
+                    .filter(|f| f.impact != 0.0)
+                    .map(|f| format!("{} ({:+.2})", f.detail, f.impact)),
+            );
+            
+            // This launch becomes part of the deployer's history
+            holder_analyzer.deployers().record_token_launch(
+                &reputation.deployer,
+                &opportunity.token_address,
+                &opportunity.pool_address,
+                opportunity.detected_at - chrono::Duration::minutes(opportunity.age_minutes as i64),
+            ).await;
+        }
+        
+        info!("🧮 Holder risk for {}: {:.2} → risk score {:.2}", 
+              opportunity.token_address, report.combined_risk, opportunity.risk_score);
+        Some(report)
+    }
+    
+    /// Count a large LP removal against the deployer of the pool, if it was a launch we saw
+    pub async fn record_liquidity_pull(&self, event: &LiquidityEvent) -> Option<String> {
+        let holder_analyzer = self.holder_analyzer.as_ref()?;
+        holder_analyzer.deployers().record_liquidity_pull(
+            &event.pool_address,
+            event.change_percent,
+            event.detected_at,
+            &holder_analyzer.config().reputation,
+        ).await
+    }
+    
     /// Perform comprehensive opportunity analysis
     pub async fn analyze_opportunity(&self, opportunity: &OpportunityData) -> Result<OpportunityAnalysis> {
         info!("🔍 Analyzing opportunity: {}", opportunity.token_address);
@@ -439,12 +516,13 @@ impl OpportunityAnalyzer {
                            execution_risk * 0.10 + 
                            time_risk * 0.10).min(1.0);
         
-        let risk_factors = vec![
+        let mut risk_factors = vec![
             format!("Liquidity: ${:.0} (Risk: {:.1})", opportunity.liquidity_usd, liquidity_risk),
             format!("Market volatility: {:.1}% (Risk: {:.1})", market_context.volatility_index, volatility_risk),
             format!("Token age: {} min (Risk: {:.1})", opportunity.age_minutes, time_risk),
             format!("Price impact: {:.2}% (Risk: {:.1})", opportunity.price_impact, execution_risk),
         ];
+        risk_factors.extend(opportunity.risk_reasons.iter().cloned());
         
         let risk_mitigation = vec![
             "Use smaller position size for high-risk opportunities".to_string(),
@@ -1728,6 +1806,10 @@ mod tests {
             volume_24h_usd: 25000.0,
             holder_count: 150,
             age_minutes: 8,
+            lp_mint: None,
+            deployer_address: None,
+            risk_reasons: Vec::new(),
+            strategy: SniperStrategy::LiquiditySnipe,
         };
         
         let analysis = analyzer.analyze_opportunity(&opportunity).await;
@@ -1773,6 +1855,10 @@ mod tests {
             volume_24h_usd: 500000.0,
             holder_count: 150,
             age_minutes: 5,
+            lp_mint: None,
+            deployer_address: None,
+            risk_reasons: Vec::new(),
+            strategy: SniperStrategy::LiquiditySnipe,
         };
 
         let market_context = analyzer.analyze_market_context().await.unwrap();
@@ -3,12 +3,20 @@
 
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use serde_json::Value;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
 use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
 use tokio::sync::{RwLock, mpsc};
 use tracing::{info, error, debug};
 use uuid::Uuid;
 
-use super::{DexType, OpportunityData, SniperConfig};
+use super::{DexType, MarketData, OpportunityData, SniperConfig, SniperStrategy};
+use super::liquidity_migration::{LiquidityMigrationTracker, PoolVersion, RAYDIUM_AMM_V4_PROGRAM};
+use crate::analytics::{RpcTransactionSource, TransactionSource};
+use crate::types::constants::SOL_MINT;
 
 /// Enterprise pool monitor with multi-DEX support
 #[derive(Debug)]
@@ -18,6 +26,12 @@ pub struct PoolMonitor {
     pool_cache: RwLock<HashMap<String, PoolCacheEntry>>,
     dex_clients: HashMap<DexType, Box<dyn DexClient>>,
     detection_stats: RwLock<DetectionStats>,
+    /// Receives every discovered pool, so replacement pools of known pairs are noticed
+    migrations: Option<Arc<LiquidityMigrationTracker>>,
+    /// Fills in LP mint and creator when the DEX client does not report them
+    creation_source: Option<Arc<dyn PoolCreationSource>>,
+    /// Creation data never changes: resolved once per pool
+    creations: RwLock<HashMap<String, PoolCreation>>,
 }
 
 /// Pool cache entry for performance optimization
@@ -31,8 +45,25 @@ pub struct PoolCacheEntry {
     pub initial_liquidity_usd: f64,
     pub last_checked: DateTime<Utc>,
     pub is_active: bool,
+    /// Pool price when first seen (token_b per token_a)
+    pub initial_price: Option<f64>,
+    /// 24h volume at the last sample, to turn the rolling total into per-sample volume
+    pub last_volume_24h_usd: f64,
 }
 
+/// Price/volume observation of a tracked pool
+#[derive(Debug, Clone)]
+pub struct PoolMarketSample {
+    pub token_address: String,
+    pub pool_address: String,
+    pub market_data: MarketData,
+    /// Volume traded since the previous sample
+    pub volume_since_last_usd: f64,
+}
+
+/// Pools younger than this are sampled; older ones only while a position is open
+const SAMPLE_WINDOW_MINUTES: i64 = 30;
+
 /// Detection performance statistics
 #[derive(Debug, Clone)]
 pub struct DetectionStats {
@@ -58,6 +89,31 @@ pub struct PoolData {
     pub created_at: DateTime<Utc>,
     pub holder_count: u32,
     pub market_cap_usd: f64,
+    /// Owning program, when the client reports it (tells pool versions of one DEX apart)
+    pub program_id: Option<String>,
+    /// LP token mint, when the client reports it
+    pub lp_mint: Option<String>,
+    /// Wallet that created the pool, when the client reports it
+    pub creator: Option<String>,
+}
+
+impl PoolData {
+    /// Price of token_a in token_b (SOL for the sniper's pools)
+    pub fn price(&self) -> Option<f64> {
+        (self.token_a_amount > 0.0 && self.token_b_amount > 0.0).then(|| self.token_b_amount / self.token_a_amount)
+    }
+    
+    /// Wrapped-SOL reserve, when one side of the pool is SOL
+    pub fn sol_reserve(&self) -> Option<f64> {
+        let reserve = if self.token_b == SOL_MINT {
+            self.token_b_amount
+        } else if self.token_a == SOL_MINT {
+            self.token_a_amount
+        } else {
+            return None;
+        };
+        (reserve > 0.0).then_some(reserve)
+    }
 }
 
 /// DEX client trait for unified interface
@@ -69,6 +125,92 @@ pub trait DexClient: Send + Sync + std::fmt::Debug {
     async fn get_token_metadata(&self, token_address: &str) -> Result<TokenMetadata>;
 }
 
+/// Data fixed when a pool is created
+#[derive(Debug, Clone, Default)]
+pub struct PoolCreation {
+    pub lp_mint: Option<String>,
+    /// Fee payer of the pool's first transaction
+    pub creator: Option<String>,
+}
+
+/// Source of pool creation data (LP mint, creating wallet)
+#[async_trait::async_trait]
+pub trait PoolCreationSource: Send + Sync + std::fmt::Debug {
+    async fn creation(&self, pool: &PoolData) -> Result<PoolCreation>;
+}
+
+/// LP mint offset in a Raydium AMM v4 pool account
+const AMM_V4_LP_MINT_OFFSET: usize = 464;
+/// Signature pages walked back looking for a pool's first transaction
+const MAX_SIGNATURE_PAGES: usize = 10;
+const SIGNATURE_PAGE_SIZE: usize = 1000;
+
+/// Pool creation data from chain: the pool account and its first transaction
+pub struct RpcPoolCreationSource {
+    client: RpcClient,
+    transactions: Arc<dyn TransactionSource>,
+}
+
+impl std::fmt::Debug for RpcPoolCreationSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RpcPoolCreationSource").field("url", &self.client.url()).finish()
+    }
+}
+
+impl RpcPoolCreationSource {
+    pub fn new(rpc_url: String) -> Self {
+        Self {
+            transactions: Arc::new(RpcTransactionSource::new(&rpc_url)),
+            client: RpcClient::new(rpc_url),
+        }
+    }
+    
+    /// LP mint from the pool account (Raydium AMM v4 layout)
+    async fn lp_mint(&self, pool_address: &str) -> Result<Option<String>> {
+        let account = self.client.get_account(&Pubkey::from_str(pool_address)?).await?;
+        if account.owner.to_string() != RAYDIUM_AMM_V4_PROGRAM {
+            return Ok(None);
+        }
+        Ok(amm_v4_lp_mint(&account.data).map(|mint| mint.to_string()))
+    }
+    
+    /// Fee payer of the pool's first transaction; None if it lies beyond the pages walked
+    async fn creator(&self, pool_address: &str) -> Result<Option<String>> {
+        let mut before: Option<String> = None;
+        for _ in 0..MAX_SIGNATURE_PAGES {
+            let page = self.transactions.signatures(pool_address, before.as_deref(), None, SIGNATURE_PAGE_SIZE).await?;
+            let Some(oldest) = page.last() else { break };
+            if page.len() < SIGNATURE_PAGE_SIZE {
+                let tx = self.transactions.transaction(&oldest.signature).await?;
+                return Ok(tx.as_ref().and_then(fee_payer));
+            }
+            before = Some(oldest.signature.clone());
+        }
+        Ok(None)
+    }
+}
+
+#[async_trait::async_trait]
+impl PoolCreationSource for RpcPoolCreationSource {
+    async fn creation(&self, pool: &PoolData) -> Result<PoolCreation> {
+        Ok(PoolCreation {
+            lp_mint: self.lp_mint(&pool.address).await?,
+            creator: self.creator(&pool.address).await?,
+        })
+    }
+}
+
+/// LP mint of a Raydium AMM v4 pool account
+fn amm_v4_lp_mint(data: &[u8]) -> Option<Pubkey> {
+    Pubkey::try_from(data.get(AMM_V4_LP_MINT_OFFSET..AMM_V4_LP_MINT_OFFSET + 32)?).ok()
+}
+
+/// Fee payer of a `jsonParsed` transaction
+fn fee_payer(tx: &Value) -> Option<String> {
+    let key = &tx["transaction"]["message"]["accountKeys"][0];
+    key["pubkey"].as_str().or_else(|| key.as_str()).map(str::to_string)
+}
+
 /// Token metadata for analysis
 #[derive(Debug, Clone)]
 pub struct TokenMetadata {
@@ -118,9 +260,24 @@ impl PoolMonitor {
             pool_cache: RwLock::new(HashMap::new()),
             dex_clients,
             detection_stats: RwLock::new(DetectionStats::new()),
+            migrations: None,
+            creation_source: None,
+            creations: RwLock::new(HashMap::new()),
         })
     }
     
+    /// Resolve LP mint and creator of pools whose DEX client does not report them
+    pub fn with_creation_source(mut self, source: Arc<dyn PoolCreationSource>) -> Self {
+        self.creation_source = Some(source);
+        self
+    }
+    
+    /// Feed discovered pools into a liquidity migration tracker
+    pub fn with_migration_tracker(mut self, migrations: Arc<LiquidityMigrationTracker>) -> Self {
+        self.migrations = Some(migrations);
+        self
+    }
+    
     /// Start monitoring specified DEX for new opportunities
     pub async fn start_monitoring(
         &self,
@@ -208,7 +365,12 @@ impl PoolMonitor {
             }
             
             // Register pool to avoid duplicate processing
-            self.register_pool(&pool).await?;
+            self.register_pool(&pool, dex).await?;
+            
+            // Before the launch filters: a replacement pool for a known pair is rarely a fresh launch
+            if let Some(migrations) = &self.migrations {
+                migrations.register_pool(&pool, PoolVersion::classify(dex, pool.program_id.as_deref())).await;
+            }
             
             // Apply enterprise-grade filters
             if !self.passes_enterprise_filters(&pool).await? {
@@ -216,7 +378,7 @@ impl PoolMonitor {
             }
             
             // Calculate opportunity metrics
-            let opportunity = self.create_opportunity_from_pool(&pool, dex.clone()).await?;
+            let opportunity = self.create_opportunity_from_pool(&pool, dex.clone(), SniperStrategy::LiquiditySnipe).await?;
             opportunities.push(opportunity);
         }
         
@@ -261,7 +423,7 @@ impl PoolMonitor {
     }
     
     /// Register pool in cache and known pools
-    async fn register_pool(&self, pool: &PoolData) -> Result<()> {
+    async fn register_pool(&self, pool: &PoolData, dex: &DexType) -> Result<()> {
         {
             let mut known_pools = self.known_pools.write().await;
             known_pools.insert(pool.address.clone());
@@ -273,11 +435,13 @@ impl PoolMonitor {
                 pool_address: pool.address.clone(),
                 token_a: pool.token_a.clone(),
                 token_b: pool.token_b.clone(),
-                dex: DexType::Raydium, // Will be set by caller
+                dex: dex.clone(),
                 created_at: pool.created_at,
                 initial_liquidity_usd: pool.liquidity_usd,
                 last_checked: Utc::now(),
                 is_active: true,
+                initial_price: pool.price(),
+                last_volume_24h_usd: pool.volume_24h_usd,
             });
         }
         
@@ -313,11 +477,91 @@ impl PoolMonitor {
         Ok(true)
     }
     
+    /// Re-evaluate a known pool (e.g. after a large LP add) as an entry opportunity
+    ///
+    /// Unlike new-pool scanning there is no age limit: the LP add is the trigger.
+    pub async fn opportunity_for_pool(&self, dex: &DexType, pool_address: &str, strategy: SniperStrategy) -> Result<Option<OpportunityData>> {
+        let client = self.dex_clients.get(dex)
+            .ok_or_else(|| anyhow::anyhow!("DEX client not found: {:?}", dex))?;
+        let pool = client.get_pool_details(pool_address).await?;
+        if pool.liquidity_usd < self.config.min_liquidity_usd {
+            return Ok(None);
+        }
+        Ok(Some(self.create_opportunity_from_pool(&pool, dex.clone(), strategy).await?))
+    }
+    
+    /// Sample price and volume of every recently discovered pool and of `held_pools`
+    pub async fn sample_pools(&self, held_pools: &HashSet<String>) -> Vec<PoolMarketSample> {
+        let cutoff = Utc::now() - chrono::Duration::minutes(SAMPLE_WINDOW_MINUTES);
+        let tracked: Vec<String> = self.pool_cache.read().await
+            .values()
+            .filter(|entry| entry.is_active && (entry.created_at >= cutoff || held_pools.contains(&entry.pool_address)))
+            .map(|entry| entry.pool_address.clone())
+            .collect();
+        let mut samples = Vec::with_capacity(tracked.len());
+        for pool_address in tracked {
+            if let Some(sample) = self.sample_pool(&pool_address).await {
+                samples.push(sample);
+            }
+        }
+        samples
+    }
+    
+    /// Sample one registered pool; None when it is unknown or its details are unavailable
+    pub async fn sample_pool(&self, pool_address: &str) -> Option<PoolMarketSample> {
+        let dex = self.pool_cache.read().await.get(pool_address)?.dex.clone();
+        let client = self.dex_clients.get(&dex)?;
+        let pool = match client.get_pool_details(pool_address).await {
+            Ok(pool) => pool,
+            Err(e) => {
+                debug!("📉 No market data for pool {}: {}", pool_address, e);
+                return None;
+            }
+        };
+        let price = pool.price()?;
+        let now = Utc::now();
+        
+        let mut cache = self.pool_cache.write().await;
+        let entry = cache.get_mut(pool_address)?;
+        // The rolling 24h total only grows by what traded since the last sample
+        let volume_since_last_usd = (pool.volume_24h_usd - entry.last_volume_24h_usd).max(0.0);
+        let initial_price = *entry.initial_price.get_or_insert(price);
+        entry.last_volume_24h_usd = pool.volume_24h_usd;
+        entry.last_checked = now;
+        
+        Some(PoolMarketSample {
+            token_address: pool.token_a.clone(),
+            pool_address: pool.address.clone(),
+            market_data: MarketData {
+                price,
+                volume_24h: pool.volume_24h_usd,
+                liquidity: pool.liquidity_usd,
+                // Tracked pools are at most a day old in practice: change since first seen
+                price_change_24h: (price - initial_price) / initial_price * 100.0,
+                updated_at: now,
+            },
+            volume_since_last_usd,
+        })
+    }
+    
+    /// Current SOL-side reserve of a tracked pool, read from the DEX (not the cached discovery data)
+    pub async fn sol_reserve(&self, pool_address: &str) -> Result<f64> {
+        let dex = self.pool_cache.read().await.get(pool_address)
+            .map(|entry| entry.dex.clone())
+            .ok_or_else(|| anyhow::anyhow!("pool {} is not tracked", pool_address))?;
+        let client = self.dex_clients.get(&dex)
+            .ok_or_else(|| anyhow::anyhow!("no {:?} client for pool {}", dex, pool_address))?;
+        client.get_pool_details(pool_address).await?
+            .sol_reserve()
+            .ok_or_else(|| anyhow::anyhow!("pool {} has no SOL side", pool_address))
+    }
+    
     /// Create opportunity data from pool
     async fn create_opportunity_from_pool(
         &self,
         pool: &PoolData,
         dex: DexType,
+        strategy: SniperStrategy,
     ) -> Result<OpportunityData> {
         let age_minutes = Utc::now()
             .signed_duration_since(pool.created_at)
@@ -335,6 +579,9 @@ impl PoolMonitor {
         // Calculate price impact
         let price_impact = self.calculate_price_impact(pool).await?;
         
+        // LP ownership and deployer history need these
+        let creation = self.pool_creation(pool).await;
+        
         Ok(OpportunityData {
             id: Uuid::new_v4(),
             token_address: pool.token_a.clone(), // Assuming token_a is the new token
@@ -350,9 +597,41 @@ impl PoolMonitor {
             volume_24h_usd: pool.volume_24h_usd,
             holder_count: pool.holder_count as u64,
             age_minutes: age_minutes as u64,
+            lp_mint: creation.lp_mint,
+            deployer_address: creation.creator,
+            risk_reasons: Vec::new(),
+            strategy,
         })
     }
     
+    /// LP mint and creator as reported by the DEX client, completed from the creation source
+    async fn pool_creation(&self, pool: &PoolData) -> PoolCreation {
+        let reported = PoolCreation { lp_mint: pool.lp_mint.clone(), creator: pool.creator.clone() };
+        let Some(source) = &self.creation_source else { return reported };
+        if reported.lp_mint.is_some() && reported.creator.is_some() {
+            return reported;
+        }
+        if let Some(known) = self.creations.read().await.get(&pool.address) {
+            return PoolCreation {
+                lp_mint: reported.lp_mint.or_else(|| known.lp_mint.clone()),
+                creator: reported.creator.or_else(|| known.creator.clone()),
+            };
+        }
+        match source.creation(pool).await {
+            Ok(resolved) => {
+                self.creations.write().await.insert(pool.address.clone(), resolved.clone());
+                PoolCreation {
+                    lp_mint: reported.lp_mint.or(resolved.lp_mint),
+                    creator: reported.creator.or(resolved.creator),
+                }
+            }
+            Err(e) => {
+                debug!("🔍 Creation data unavailable for pool {}: {}", pool.address, e);
+                reported
+            }
+        }
+    }
+    
     /// Calculate estimated profit potential
     async fn calculate_estimated_profit(&self, pool: &PoolData) -> Result<f64> {
         // Base profit estimation on liquidity and market conditions
@@ -652,4 +931,119 @@ mod tests {
         let monitor = PoolMonitor::new(&config).await;
         assert!(monitor.is_ok());
     }
+    
+    /// Serves scripted pool states, one per call
+    #[derive(Debug)]
+    struct ScriptedDex(std::sync::Mutex<Vec<PoolData>>);
+    
+    #[async_trait::async_trait]
+    impl DexClient for ScriptedDex {
+        async fn get_new_pools_since(&self, _since: DateTime<Utc>) -> Result<Vec<PoolData>> {
+            Ok(vec![])
+        }
+        
+        async fn get_pool_details(&self, _pool_address: &str) -> Result<PoolData> {
+            Ok(self.0.lock().unwrap().remove(0))
+        }
+        
+        async fn is_pool_active(&self, _pool_address: &str) -> Result<bool> {
+            Ok(true)
+        }
+        
+        async fn get_token_metadata(&self, _token_address: &str) -> Result<TokenMetadata> {
+            Err(anyhow::anyhow!("Not implemented"))
+        }
+    }
+    
+    fn pool(sol: f64, volume_24h_usd: f64) -> PoolData {
+        PoolData {
+            address: "pool".to_string(),
+            token_a: "token".to_string(),
+            token_b: "So11111111111111111111111111111111111111112".to_string(),
+            token_a_amount: 1_000_000.0,
+            token_b_amount: sol,
+            liquidity_usd: 50_000.0,
+            volume_24h_usd,
+            fee_rate: 0.0025,
+            created_at: Utc::now(),
+            holder_count: 100,
+            market_cap_usd: 500_000.0,
+            program_id: None,
+            lp_mint: None,
+            creator: None,
+        }
+    }
+    
+    #[derive(Debug)]
+    struct FixedCreation(std::sync::atomic::AtomicUsize);
+    
+    #[async_trait::async_trait]
+    impl PoolCreationSource for FixedCreation {
+        async fn creation(&self, _pool: &PoolData) -> Result<PoolCreation> {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(PoolCreation { lp_mint: Some("resolved-lp".to_string()), creator: Some("deployer".to_string()) })
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_opportunities_carry_lp_mint_and_deployer_from_creation_data() {
+        let source = Arc::new(FixedCreation(std::sync::atomic::AtomicUsize::new(0)));
+        let monitor = PoolMonitor::new(&SniperConfig::default()).await.unwrap().with_creation_source(source.clone());
+        let reported = PoolData { lp_mint: Some("reported-lp".to_string()), ..pool(100.0, 10_000.0) };
+        
+        let opportunity = monitor.create_opportunity_from_pool(&reported, DexType::Raydium, SniperStrategy::LiquiditySnipe).await.unwrap();
+        assert_eq!(opportunity.lp_mint.as_deref(), Some("reported-lp"));
+        assert_eq!(opportunity.deployer_address.as_deref(), Some("deployer"));
+        monitor.create_opportunity_from_pool(&reported, DexType::Raydium, SniperStrategy::TrendRiding).await.unwrap();
+        assert_eq!(source.0.load(std::sync::atomic::Ordering::SeqCst), 1);
+        
+        let mint = Pubkey::new_unique();
+        let mut account = vec![0u8; 752];
+        account[464..496].copy_from_slice(mint.as_ref());
+        assert_eq!(amm_v4_lp_mint(&account), Some(mint));
+        let tx = serde_json::json!({ "transaction": { "message": { "accountKeys": [{ "pubkey": "payer" }, { "pubkey": "pool" }] } } });
+        assert_eq!(fee_payer(&tx).as_deref(), Some("payer"));
+    }
+    
+    #[tokio::test]
+    async fn test_sampling_reports_pool_price_and_volume_since_last_sample() {
+        let mut monitor = PoolMonitor::new(&SniperConfig::default()).await.unwrap();
+        monitor.dex_clients.insert(DexType::Raydium, Box::new(ScriptedDex(std::sync::Mutex::new(vec![
+            pool(150.0, 12_000.0),
+            pool(300.0, 15_500.0),
+        ]))));
+        monitor.register_pool(&pool(100.0, 10_000.0), &DexType::Raydium).await.unwrap();
+        
+        let first = monitor.sample_pools(&HashSet::new()).await;
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].token_address, "token");
+        assert!((first[0].market_data.price - 0.000_15).abs() < 1e-12);
+        assert!((first[0].volume_since_last_usd - 2_000.0).abs() < 1e-9);
+        
+        let second = monitor.sample_pool("pool").await.unwrap();
+        assert!((second.market_data.price_change_24h - 200.0).abs() < 1e-9);
+        assert!((second.volume_since_last_usd - 3_500.0).abs() < 1e-9);
+        assert!(monitor.sample_pool("unknown").await.is_none());
+    }
+    
+    #[tokio::test]
+    async fn test_sol_reserve_is_read_fresh_from_the_dex() {
+        let mut monitor = PoolMonitor::new(&SniperConfig::default()).await.unwrap();
+        let mut flipped = pool(0.0, 0.0);
+        (flipped.token_a, flipped.token_b) = (flipped.token_b.clone(), flipped.token_a.clone());
+        flipped.token_a_amount = 42.0;
+        monitor.dex_clients.insert(DexType::Raydium, Box::new(ScriptedDex(std::sync::Mutex::new(vec![
+            pool(80.0, 0.0),
+            flipped,
+        ]))));
+        monitor.register_pool(&pool(100.0, 0.0), &DexType::Raydium).await.unwrap();
+        
+        assert_eq!(monitor.sol_reserve("pool").await.unwrap(), 80.0);
+        assert_eq!(monitor.sol_reserve("pool").await.unwrap(), 42.0);
+        assert!(monitor.sol_reserve("unknown").await.is_err());
+        
+        let mut no_sol = pool(10.0, 0.0);
+        no_sol.token_b = "USDC".to_string();
+        assert_eq!(no_sol.sol_reserve(), None);
+    }
 }
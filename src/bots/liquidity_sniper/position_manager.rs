@@ -4,17 +4,21 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc, Duration};
 use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::{info, warn, debug};
 use uuid::Uuid;
 
 use super::{OpportunityData, SniperConfig, DexType};
 use super::risk_manager::{RiskAssessment, StopType, MonitoringLevel, RequiredStop};
+use super::stale_positions::{StalePosition, StalePositionDetector, ForcedExitPolicy, ForcedExitReport, ForcedExitOutcome};
+use super::liquidity_events::LiquidityEvent;
 use crate::types::TradingOpportunity;
 
 // 🚀 REFACTORING: Reutilizar módulos centrales existentes
 use crate::security::risk_manager::{RiskManagementConfig, AdvancedRiskManager};
 use crate::trading::portfolio::{PortfolioManager};
 use crate::analytics::performance_analytics::PerformanceAnalyticsAI;
+use crate::monitoring::webhook_emitter::{WebhookEmitter, WebhookEventKind};
 
 // 🚀 TEMPORAL: Usar tipos básicos hasta integrar completamente los módulos centrales
 type CorePerformanceReport = std::collections::HashMap<String, f64>;
@@ -59,6 +63,7 @@ pub struct PositionManager {
     position_tracker: PositionTracker,
     exit_manager: ExitManager,
     metrics: PositionMetrics,
+    webhooks: Option<Arc<WebhookEmitter>>,
 }
 
 /// Position data structure
@@ -486,8 +491,15 @@ impl PositionManager {
             position_tracker,
             exit_manager,
             metrics: PositionMetrics::new(),
+            webhooks: None,
         })
     }
+
+    /// Publish closed positions to outbound webhooks
+    pub fn with_webhooks(mut self, webhooks: Arc<WebhookEmitter>) -> Self {
+        self.webhooks = Some(webhooks);
+        self
+    }
     
     /// Open new position from opportunity
     pub async fn open_position(
@@ -725,6 +737,20 @@ impl PositionManager {
             
             // Update metrics
             self.update_metrics_on_close(&closed_position).await?;
+            if let Some(webhooks) = &self.webhooks {
+                webhooks.emit(WebhookEventKind::PositionClosed, serde_json::json!({
+                    "position_id": position_id,
+                    "token_address": closed_position.position.token_address,
+                    "pool_address": closed_position.position.pool_address,
+                    "entry_price": closed_position.position.entry_price,
+                    "exit_price": exit_price,
+                    "exit_reason": closed_position.exit_reason,
+                    "size_sol": closed_position.position.position_size_sol,
+                    "realized_pnl_sol": realized_pnl_sol,
+                    "realized_pnl_percent": price_change_percent,
+                    "hold_time_minutes": closed_position.hold_time_minutes,
+                }));
+            }
             
             info!("✅ Position closed - PnL: {:.2} SOL ({:.1}%)", 
                   realized_pnl_sol, price_change_percent);
@@ -1026,6 +1052,94 @@ impl PositionManager {
         Ok(closed_positions)
     }
 
+    /// Force stale positions out and close them with write-off accounting
+    pub async fn enforce_stale_exits(
+        &mut self,
+        detector: &StalePositionDetector,
+        policy: &ForcedExitPolicy,
+    ) -> Result<Vec<ForcedExitReport>> {
+        let stale = detector.scan(self.active_positions.values());
+        if stale.is_empty() {
+            return Ok(Vec::new());
+        }
+        if !policy.has_venues() {
+            warn!("⚠️ {} stale positions detected but no exit venue is configured", stale.len());
+            return Ok(Vec::new());
+        }
+
+        let mut reports = Vec::new();
+        for stale_position in stale {
+            let Some(position) = self.active_positions.get_mut(&stale_position.position_id) else { continue };
+            position.status = PositionStatus::ExitPending;
+            let position = position.clone();
+
+            let report = policy.exit(&stale_position, &position).await;
+            self.record_forced_exit(&report).await?;
+            reports.push(report);
+        }
+        Ok(reports)
+    }
+
+    /// Exit every position in a pool whose LP was pulled
+    pub async fn enforce_liquidity_exits(
+        &mut self,
+        event: &LiquidityEvent,
+        policy: &ForcedExitPolicy,
+    ) -> Result<Vec<ForcedExitReport>> {
+        let affected: Vec<Uuid> = self.active_positions.values()
+            .filter(|position| position.pool_address == event.pool_address)
+            .map(|position| position.id)
+            .collect();
+        if affected.is_empty() {
+            return Ok(Vec::new());
+        }
+        if !policy.has_venues() {
+            warn!("⚠️ LP pulled from {} with {} open positions but no exit venue is configured", event.pool_address, affected.len());
+            return Ok(Vec::new());
+        }
+
+        let mut reports = Vec::new();
+        for position_id in affected {
+            let Some(position) = self.active_positions.get_mut(&position_id) else { continue };
+            position.status = PositionStatus::ExitPending;
+            let position = position.clone();
+            let trigger = StalePosition {
+                position_id,
+                token_address: position.token_address.clone(),
+                dex: position.dex.clone(),
+                age_minutes: (Utc::now() - position.entry_time).num_minutes(),
+                liquidity_usd: Some(event.liquidity_after_usd),
+                reason: format!("LP pulled: -{:.1}% (${:.0} → ${:.0})",
+                                event.change_percent, event.liquidity_before_usd, event.liquidity_after_usd),
+            };
+
+            let report = policy.exit(&trigger, &position).await;
+            self.record_forced_exit(&report).await?;
+            reports.push(report);
+        }
+        Ok(reports)
+    }
+
+    /// Close a position from a forced-exit report; unsold tokens are booked as a loss
+    pub async fn record_forced_exit(&mut self, report: &ForcedExitReport) -> Result<ClosedPosition> {
+        let position = self.active_positions.get(&report.position_id)
+            .ok_or_else(|| anyhow::anyhow!("Position not found: {}", report.position_id))?;
+
+        // Effective exit price such that realized PnL = recovered SOL - cost basis
+        let recovery_ratio = if report.cost_basis_sol > 0.0 { report.sol_recovered / report.cost_basis_sol } else { 0.0 };
+        let exit_price = position.entry_price * recovery_ratio;
+        let exit_reason = match report.outcome {
+            ForcedExitOutcome::Exited => format!("Forced exit: {}", report.reason),
+            _ => format!("Forced exit, {:.6} SOL written off: {}", report.written_off_sol, report.reason),
+        };
+
+        let mut closed = self.close_position(report.position_id, exit_price, exit_reason).await?;
+        if report.outcome == ForcedExitOutcome::WrittenOff {
+            closed.position.status = PositionStatus::Expired;
+        }
+        Ok(closed)
+    }
+
     /// 🚀 ENRIQUECIMIENTO: Utiliza el config para validación de límites
     pub async fn validate_position_limits(&self, new_position_size: f64) -> Result<bool> {
         debug!("🔍 Validating position limits using config");
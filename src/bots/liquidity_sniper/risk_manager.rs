@@ -4,9 +4,10 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
-use tracing::{info, debug};
+use tracing::{info, debug, warn};
 
 use super::{OpportunityData, SniperConfig, MarketCondition};
+use super::liquidity_events::{LiquidityEvent, LiquidityEventKind};
 
 // 🚀 REFACTORING: Usar módulos de seguridad existentes
 use crate::security::risk_manager::{
@@ -323,6 +324,26 @@ impl RiskManager {
         &self.monitoring_metrics
     }
 
+    /// Evaluar un evento de liquidez: devuelve el trigger de salida si un retiro de LP lo dispara
+    pub fn on_liquidity_event(&mut self, event: &LiquidityEvent) -> Option<EmergencyTrigger> {
+        if event.kind != LiquidityEventKind::Remove {
+            return None;
+        }
+        let drop_fraction = event.change_percent / 100.0;
+        let trigger = self.liquidity_specific_config.emergency_exit_triggers.iter().find_map(|trigger| match trigger {
+            EmergencyTrigger::LiquidityDrop(threshold) if drop_fraction >= *threshold => Some(trigger.clone()),
+            _ => None,
+        });
+        match &trigger {
+            Some(_) => {
+                self.monitoring_metrics.emergency_exits += 1;
+                warn!("🚰 Retiro de LP en {} (-{:.1}%): salida de emergencia", event.pool_address, event.change_percent);
+            }
+            None => self.monitoring_metrics.liquidity_warnings += 1,
+        }
+        trigger
+    }
+
     /// Configurar parámetros específicos de liquidez
     pub fn configure_liquidity_params(&mut self, config: LiquidityRiskConfig) {
         info!("🔧 Configurando parámetros específicos de liquidez");
@@ -0,0 +1,383 @@
+// SniperForge Enterprise v3.0 - Sandwich Risk Estimation
+// Pre-trade sandwich risk from pool depth, mempool visibility and size; post-trade sandwich detection
+
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+
+/// How a transaction reaches the leader
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SubmissionRoute {
+    /// Public RPC: visible to searchers before inclusion
+    PublicRpc,
+    /// Private/staked RPC: reduced but non-zero visibility
+    PrivateRpc,
+    /// Jito bundle: atomic, not reorderable by third parties
+    JitoBundle,
+}
+
+impl SubmissionRoute {
+    /// Probability weight that searchers can observe and reorder around the swap
+    pub fn visibility(&self) -> f64 {
+        match self {
+            Self::PublicRpc => 1.0,
+            Self::PrivateRpc => 0.35,
+            Self::JitoBundle => 0.05,
+        }
+    }
+}
+
+/// Sandwich risk thresholds
+#[derive(Debug, Clone)]
+pub struct SandwichRiskConfig {
+    pub enabled: bool,
+    /// Pool fee charged on each attacker leg (fraction)
+    pub pool_fee: f64,
+    /// Attacker's fixed cost per sandwich (tips + fees, SOL)
+    pub attacker_fixed_cost_sol: f64,
+    /// Attacker profit at which risk saturates towards 1.0 (SOL)
+    pub reference_profit_sol: f64,
+    /// Risk at or above which slippage is tightened
+    pub tighten_threshold: f64,
+    /// Risk (on the public route) at or above which bundle submission is forced
+    pub force_bundle_threshold: f64,
+    /// Slippage floor so trades still land (fraction)
+    pub min_slippage: f64,
+}
+
+impl Default for SandwichRiskConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            pool_fee: 0.0025,               // 0.25% per leg
+            attacker_fixed_cost_sol: 0.001, // tip + 2 signatures
+            reference_profit_sol: 0.01,
+            tighten_threshold: 0.3,
+            force_bundle_threshold: 0.6,
+            min_slippage: 0.001,            // 10 bps floor
+        }
+    }
+}
+
+/// Pre-trade inputs
+#[derive(Debug, Clone)]
+pub struct SandwichRiskInput {
+    pub trade_size_sol: f64,
+    /// SOL-side reserve of the pool the swap trades against
+    pub sol_reserve: f64,
+    /// Slippage tolerance (fraction)
+    pub slippage_tolerance: f64,
+    pub route: SubmissionRoute,
+}
+
+/// Pre-trade estimate and recommended mitigation
+#[derive(Debug, Clone)]
+pub struct SandwichRiskEstimate {
+    /// 0-1 risk on the requested route
+    pub risk_score: f64,
+    /// What an attacker could extract at the requested slippage (SOL)
+    pub attacker_profit_sol: f64,
+    /// Our own price impact (fraction)
+    pub price_impact: f64,
+    /// Slippage tolerance to use (never looser than requested)
+    pub recommended_slippage: f64,
+    /// Submit as a bundle instead of the requested route
+    pub force_bundle: bool,
+    pub reasons: Vec<String>,
+}
+
+/// Which way a swap moves the token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwapSide {
+    Buy,
+    Sell,
+}
+
+/// A swap observed in the same block as ours
+#[derive(Debug, Clone)]
+pub struct SurroundingSwap {
+    pub signature: String,
+    pub signer: String,
+    pub slot: u64,
+    pub index_in_block: u32,
+    pub token_mint: String,
+    pub side: SwapSide,
+    pub amount_sol: f64,
+}
+
+/// One of our own executed swaps
+#[derive(Debug, Clone)]
+pub struct ExecutedSwap {
+    pub signature: String,
+    pub signer: String,
+    pub slot: u64,
+    pub index_in_block: u32,
+    pub token_mint: String,
+    pub side: SwapSide,
+    /// Shortfall of the fill versus the quote (SOL)
+    pub execution_shortfall_sol: f64,
+    pub route: SubmissionRoute,
+    pub venue: String,
+}
+
+/// A suspected sandwich on one of our trades
+#[derive(Debug, Clone)]
+pub struct SandwichEvent {
+    pub our_signature: String,
+    pub token_mint: String,
+    pub slot: u64,
+    pub attacker: String,
+    pub front_run_signature: String,
+    pub back_run_signature: String,
+    /// Execution shortfall vs quote attributed to the sandwich (SOL)
+    pub estimated_loss_sol: f64,
+    /// Attacker's SOL out minus SOL in across both legs
+    pub extracted_value_sol: f64,
+    pub submission_route: SubmissionRoute,
+    pub venue: String,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// Sandwich risk estimator and event recorder
+#[derive(Debug)]
+pub struct SandwichRiskEstimator {
+    config: SandwichRiskConfig,
+    events: RwLock<Vec<SandwichEvent>>,
+}
+
+impl SandwichRiskEstimator {
+    pub fn new(config: SandwichRiskConfig) -> Self {
+        Self {
+            config,
+            events: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Attacker profit for a given slippage tolerance (SOL)
+    ///
+    /// On a constant-product pool the attacker can push the price up to our
+    /// tolerance and capture roughly `slippage × size`, paying the pool fee on
+    /// a front-run of about `reserve × slippage / 2` on each leg.
+    fn attacker_profit(&self, size: f64, reserve: f64, slippage: f64) -> f64 {
+        let gross = slippage * size;
+        let fees = self.config.pool_fee * reserve * slippage;
+        (gross - fees - self.config.attacker_fixed_cost_sol).max(0.0)
+    }
+
+    fn risk_from_profit(&self, profit: f64, route: SubmissionRoute) -> f64 {
+        let profitability = 1.0 - (-profit / self.config.reference_profit_sol.max(f64::EPSILON)).exp();
+        (route.visibility() * profitability).clamp(0.0, 1.0)
+    }
+
+    /// Estimate sandwich risk and the mitigation to apply
+    pub fn estimate(&self, input: &SandwichRiskInput) -> SandwichRiskEstimate {
+        let size = input.trade_size_sol.max(0.0);
+        let reserve = input.sol_reserve.max(f64::EPSILON);
+        let price_impact = size / (reserve + size);
+
+        if !self.config.enabled {
+            return SandwichRiskEstimate {
+                risk_score: 0.0,
+                attacker_profit_sol: 0.0,
+                price_impact,
+                recommended_slippage: input.slippage_tolerance,
+                force_bundle: false,
+                reasons: Vec::new(),
+            };
+        }
+
+        let mut reasons = Vec::new();
+        let attacker_profit_sol = self.attacker_profit(size, reserve, input.slippage_tolerance);
+        let risk_score = self.risk_from_profit(attacker_profit_sol, input.route);
+        let public_risk = self.risk_from_profit(attacker_profit_sol, SubmissionRoute::PublicRpc);
+
+        // Largest slippage at which the sandwich no longer pays for itself
+        let margin = size - self.config.pool_fee * reserve;
+        let safe_slippage = if margin > 0.0 {
+            self.config.attacker_fixed_cost_sol / margin
+        } else {
+            f64::INFINITY
+        };
+
+        let mut recommended_slippage = input.slippage_tolerance;
+        if risk_score >= self.config.tighten_threshold {
+            recommended_slippage = input.slippage_tolerance.min(safe_slippage).max(self.config.min_slippage);
+            reasons.push(format!(
+                "Slippage tightened {:.2}% → {:.2}% (attacker profit {:.4} SOL at requested tolerance)",
+                input.slippage_tolerance * 100.0, recommended_slippage * 100.0, attacker_profit_sol
+            ));
+        }
+
+        // If even the tightened tolerance leaves a profitable sandwich, hide the swap
+        let residual_profit = self.attacker_profit(size, reserve, recommended_slippage);
+        let force_bundle = input.route != SubmissionRoute::JitoBundle
+            && public_risk >= self.config.force_bundle_threshold
+            && self.risk_from_profit(residual_profit, input.route) >= self.config.tighten_threshold;
+        if force_bundle {
+            reasons.push(format!(
+                "Bundle submission forced: size {:.3} SOL vs SOL reserve {:.1} ({:.2}% impact)",
+                size, reserve, price_impact * 100.0
+            ));
+        }
+
+        debug!("🥪 Sandwich risk {:.2} (impact {:.3}%, attacker profit {:.4} SOL, route {:?})",
+               risk_score, price_impact * 100.0, attacker_profit_sol, input.route);
+
+        SandwichRiskEstimate {
+            risk_score,
+            attacker_profit_sol,
+            price_impact,
+            recommended_slippage,
+            force_bundle,
+            reasons,
+        }
+    }
+
+    /// Inspect swaps around ours in the same slot and record a suspected sandwich
+    ///
+    /// A sandwich is a swap by some signer in our direction immediately before
+    /// ours, and a swap by the same signer in the opposite direction after it.
+    pub async fn record_post_trade(&self, ours: &ExecutedSwap, surrounding: &[SurroundingSwap]) -> Option<SandwichEvent> {
+        let same_pool = |s: &&SurroundingSwap| {
+            s.slot == ours.slot && s.token_mint == ours.token_mint && s.signer != ours.signer
+        };
+        let opposite = match ours.side {
+            SwapSide::Buy => SwapSide::Sell,
+            SwapSide::Sell => SwapSide::Buy,
+        };
+
+        let front = surrounding
+            .iter()
+            .filter(same_pool)
+            .filter(|s| s.index_in_block < ours.index_in_block && s.side == ours.side)
+            .max_by_key(|s| s.index_in_block)?;
+        let back = surrounding
+            .iter()
+            .filter(same_pool)
+            .filter(|s| s.index_in_block > ours.index_in_block && s.side == opposite && s.signer == front.signer)
+            .min_by_key(|s| s.index_in_block)?;
+
+        // Buy sandwich: buy first, sell after; sell sandwich: the reverse
+        let extracted_value_sol = match ours.side {
+            SwapSide::Buy => back.amount_sol - front.amount_sol,
+            SwapSide::Sell => front.amount_sol - back.amount_sol,
+        };
+
+        let event = SandwichEvent {
+            our_signature: ours.signature.clone(),
+            token_mint: ours.token_mint.clone(),
+            slot: ours.slot,
+            attacker: front.signer.clone(),
+            front_run_signature: front.signature.clone(),
+            back_run_signature: back.signature.clone(),
+            estimated_loss_sol: ours.execution_shortfall_sol.max(0.0),
+            extracted_value_sol: extracted_value_sol.max(0.0),
+            submission_route: ours.route,
+            venue: ours.venue.clone(),
+            detected_at: Utc::now(),
+        };
+
+        warn!("🥪 Suspected sandwich on {} by {} (front {}, back {}, loss ~{:.4} SOL, extracted {:.4} SOL)",
+              event.our_signature, event.attacker, event.front_run_signature, event.back_run_signature,
+              event.estimated_loss_sol, event.extracted_value_sol);
+        self.events.write().await.push(event.clone());
+        Some(event)
+    }
+
+    /// All recorded sandwich events
+    pub async fn get_events(&self) -> Vec<SandwichEvent> {
+        self.events.read().await.clone()
+    }
+
+    /// Total estimated loss to sandwiches (SOL)
+    pub async fn total_loss_sol(&self) -> f64 {
+        let total = self.events.read().await.iter().map(|e| e.estimated_loss_sol).sum();
+        info!("🥪 Total sandwich losses: {:.4} SOL", total);
+        total
+    }
+}
+
+impl Default for SandwichRiskEstimator {
+    fn default() -> Self {
+        Self::new(SandwichRiskConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(size: f64, sol_reserve: f64, slippage: f64, route: SubmissionRoute) -> SandwichRiskInput {
+        SandwichRiskInput {
+            trade_size_sol: size,
+            sol_reserve,
+            slippage_tolerance: slippage,
+            route,
+        }
+    }
+
+    #[test]
+    fn test_small_trade_on_deep_pool_is_safe() {
+        let estimator = SandwichRiskEstimator::default();
+        let estimate = estimator.estimate(&input(0.1, 5_000.0, 0.01, SubmissionRoute::PublicRpc));
+        assert_eq!(estimate.attacker_profit_sol, 0.0);
+        assert_eq!(estimate.recommended_slippage, 0.01);
+        assert!(!estimate.force_bundle);
+    }
+
+    #[test]
+    fn test_large_trade_on_thin_pool_tightens_and_bundles() {
+        let estimator = SandwichRiskEstimator::default();
+        let estimate = estimator.estimate(&input(5.0, 100.0, 0.05, SubmissionRoute::PublicRpc));
+        assert!(estimate.risk_score > 0.9);
+        assert!(estimate.recommended_slippage < 0.05);
+        assert!(estimate.recommended_slippage >= 0.001);
+        assert!(estimate.force_bundle);
+
+        // Same trade via Jito is low risk and never forced
+        let bundled = estimator.estimate(&input(5.0, 100.0, 0.05, SubmissionRoute::JitoBundle));
+        assert!(bundled.risk_score < 0.1);
+        assert!(!bundled.force_bundle);
+    }
+
+    #[tokio::test]
+    async fn test_post_trade_sandwich_detection() {
+        let estimator = SandwichRiskEstimator::default();
+        let swap = |sig: &str, signer: &str, index: u32, side: SwapSide, amount_sol: f64| SurroundingSwap {
+            signature: sig.to_string(),
+            signer: signer.to_string(),
+            slot: 100,
+            index_in_block: index,
+            token_mint: "MINT".to_string(),
+            side,
+            amount_sol,
+        };
+        let surrounding = vec![
+            swap("front", "bot", 4, SwapSide::Buy, 3.0),
+            swap("other", "user", 6, SwapSide::Buy, 1.0),
+            swap("back", "bot", 7, SwapSide::Sell, 3.05),
+        ];
+
+        let ours = ExecutedSwap {
+            signature: "ours".to_string(),
+            signer: "me".to_string(),
+            slot: 100,
+            index_in_block: 5,
+            token_mint: "MINT".to_string(),
+            side: SwapSide::Buy,
+            execution_shortfall_sol: 0.02,
+            route: SubmissionRoute::PublicRpc,
+            venue: "Raydium".to_string(),
+        };
+
+        let event = estimator.record_post_trade(&ours, &surrounding).await.unwrap();
+        assert_eq!(event.attacker, "bot");
+        assert_eq!(event.back_run_signature, "back");
+        assert!((event.extracted_value_sol - 0.05).abs() < 1e-9);
+        assert_eq!(estimator.get_events().await.len(), 1);
+
+        // No matching back-run → no event
+        let none = estimator.record_post_trade(&ours, &surrounding[..2]).await;
+        assert!(none.is_none());
+    }
+}
@@ -0,0 +1,389 @@
+// SniperForge Enterprise v3.0 - Slot-Boundary Submission Timing
+// Slot-phase estimation from observed slot ticks, send scheduling towards the next leader slot
+// boundary and landing-position tracking for timed vs untimed sends
+
+use parking_lot::Mutex;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+/// Nominal slot time when there are too few observations to estimate one
+const NOMINAL_SLOT_MS: f64 = 400.0;
+/// Slot ticks kept for the duration/phase estimate
+const MAX_OBSERVATIONS: usize = 64;
+
+/// Submission timing settings
+#[derive(Debug, Clone)]
+pub struct SlotTimingConfig {
+    /// Delay sends towards the next slot boundary (phase tracking runs regardless)
+    pub enabled: bool,
+    /// Send this long before the estimated boundary to cover propagation to the leader
+    pub lead_ms: u64,
+    /// Sends already within this fraction of the current slot go out immediately
+    pub send_window: f64,
+    /// Never hold a send longer than this
+    pub max_wait_ms: u64,
+    /// Phase estimates older than this (no slot tick seen) are not trusted
+    pub max_staleness_ms: u64,
+    /// Every Nth send goes out untimed as a landing-position baseline (0 = never)
+    pub control_every: u64,
+    /// Websocket endpoint for the slot subscription feeding the phase tracker
+    pub ws_url: String,
+}
+
+impl Default for SlotTimingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            lead_ms: 40,
+            send_window: 0.25,
+            max_wait_ms: 250,
+            max_staleness_ms: 2_000,
+            control_every: 10,
+            ws_url: "wss://api.mainnet-beta.solana.com".to_string(),
+        }
+    }
+}
+
+/// Where the cluster is within the current slot
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlotPhase {
+    pub slot: u64,
+    /// Fraction of the slot elapsed, 0.0..1.0
+    pub phase: f64,
+    pub slot_duration: Duration,
+    pub until_next_boundary: Duration,
+}
+
+/// Estimates slot boundaries from (slot, observed-at) ticks
+///
+/// Ticks arrive some time after a slot starts, never before, so the boundary
+/// estimate takes the earliest start implied by any recent tick.
+#[derive(Debug)]
+pub struct SlotPhaseTracker {
+    observations: Mutex<VecDeque<(u64, Instant)>>,
+    max_staleness: Duration,
+}
+
+impl SlotPhaseTracker {
+    pub fn new(max_staleness: Duration) -> Self {
+        Self {
+            observations: Mutex::new(VecDeque::with_capacity(MAX_OBSERVATIONS)),
+            max_staleness,
+        }
+    }
+
+    /// Record that `slot` was observed at `at`; repeated or older slots are ignored
+    pub fn observe(&self, slot: u64, at: Instant) {
+        let mut observations = self.observations.lock();
+        if observations.back().is_some_and(|&(last, _)| slot <= last) {
+            return;
+        }
+        observations.push_back((slot, at));
+        while observations.len() > MAX_OBSERVATIONS {
+            observations.pop_front();
+        }
+    }
+
+    /// Mean slot time over the observation window
+    pub fn slot_duration(&self) -> Duration {
+        let observations = self.observations.lock();
+        Duration::from_secs_f64(Self::slot_secs(&observations))
+    }
+
+    /// Least-squares slope of observed time over slot number
+    fn slot_secs(observations: &VecDeque<(u64, Instant)>) -> f64 {
+        let Some(&(first_slot, first_at)) = observations.front() else {
+            return NOMINAL_SLOT_MS / 1000.0;
+        };
+        let points: Vec<(f64, f64)> = observations
+            .iter()
+            .map(|&(slot, at)| ((slot - first_slot) as f64, at.duration_since(first_at).as_secs_f64()))
+            .collect();
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+        let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+        let var_x: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+        if var_x == 0.0 {
+            return NOMINAL_SLOT_MS / 1000.0;
+        }
+        let cov: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+        // Skipped-slot bursts and stalls distort short windows
+        (cov / var_x).clamp(NOMINAL_SLOT_MS * 0.5 / 1000.0, NOMINAL_SLOT_MS * 2.0 / 1000.0)
+    }
+
+    /// Phase at `now`, or `None` without a recent tick
+    pub fn phase_at(&self, now: Instant) -> Option<SlotPhase> {
+        let observations = self.observations.lock();
+        let &(ref_slot, ref_at) = observations.back()?;
+        if now.saturating_duration_since(ref_at) > self.max_staleness {
+            return None;
+        }
+        let slot_secs = Self::slot_secs(&observations);
+
+        // Start of `ref_slot`, relative to `ref_at` (<= 0)
+        let start_offset = observations
+            .iter()
+            .map(|&(slot, at)| {
+                let since_ref = if at >= ref_at {
+                    at.duration_since(ref_at).as_secs_f64()
+                } else {
+                    -ref_at.duration_since(at).as_secs_f64()
+                };
+                since_ref - (slot as f64 - ref_slot as f64) * slot_secs
+            })
+            .fold(0.0_f64, f64::min);
+
+        let elapsed = if now >= ref_at {
+            now.duration_since(ref_at).as_secs_f64()
+        } else {
+            -ref_at.duration_since(now).as_secs_f64()
+        } - start_offset;
+        let slots = (elapsed / slot_secs).max(0.0);
+        let phase = slots.fract();
+
+        Some(SlotPhase {
+            slot: ref_slot + slots.floor() as u64,
+            phase,
+            slot_duration: Duration::from_secs_f64(slot_secs),
+            until_next_boundary: Duration::from_secs_f64((1.0 - phase) * slot_secs),
+        })
+    }
+
+    /// Feed ticks from a websocket slot subscription until it closes
+    pub async fn follow(self: Arc<Self>, ws_url: String) -> anyhow::Result<()> {
+        use futures::StreamExt;
+        use solana_client::nonblocking::pubsub_client::PubsubClient;
+
+        let client = PubsubClient::new(&ws_url).await?;
+        let (mut stream, unsubscribe) = client.slot_subscribe().await?;
+        info!("⏱️ Following slot ticks from {}", ws_url);
+        while let Some(info) = stream.next().await {
+            self.observe(info.slot, Instant::now());
+        }
+        unsubscribe().await;
+        warn!("⚠️ Slot subscription to {} closed", ws_url);
+        Ok(())
+    }
+}
+
+/// Outcome of a scheduling decision
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SendPlan {
+    pub delay: Duration,
+    /// Held for the slot boundary (false for disabled, control or no-estimate sends)
+    pub timed: bool,
+    pub phase: Option<SlotPhase>,
+}
+
+/// One in-flight send awaiting its landing slot
+#[derive(Debug, Clone, Copy)]
+struct SendRecord {
+    send_slot: u64,
+    timed: bool,
+}
+
+/// Landing statistics for one submission mode
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LandingModeStats {
+    pub landed: u64,
+    /// Mean slots between the send slot and the landing slot
+    pub mean_slots_to_land: f64,
+    /// Fraction landed in the send slot or the one after
+    pub next_slot_rate: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LandingReport {
+    pub timed: LandingModeStats,
+    pub untimed: LandingModeStats,
+    /// Untimed minus timed mean slots-to-land; positive means timing helps
+    pub improvement_slots: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+struct LandingState {
+    pending: HashMap<String, SendRecord>,
+    timed: Vec<u64>,
+    untimed: Vec<u64>,
+}
+
+/// Times sends to slot boundaries and measures where they land
+#[derive(Debug)]
+pub struct SubmissionScheduler {
+    config: SlotTimingConfig,
+    tracker: Arc<SlotPhaseTracker>,
+    sends: Mutex<u64>,
+    landing: Mutex<LandingState>,
+}
+
+impl SubmissionScheduler {
+    pub fn new(config: SlotTimingConfig) -> Self {
+        let tracker = Arc::new(SlotPhaseTracker::new(Duration::from_millis(config.max_staleness_ms)));
+        Self { config, tracker, sends: Mutex::new(0), landing: Mutex::new(LandingState::default()) }
+    }
+
+    pub fn tracker(&self) -> Arc<SlotPhaseTracker> {
+        self.tracker.clone()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// How long to hold a send issued at `now`
+    pub fn plan(&self, now: Instant) -> SendPlan {
+        let phase = self.tracker.phase_at(now);
+        let immediate = SendPlan { delay: Duration::ZERO, timed: false, phase };
+        let Some(current) = phase else {
+            return immediate;
+        };
+        if !self.config.enabled {
+            return immediate;
+        }
+
+        let control = {
+            let mut sends = self.sends.lock();
+            *sends += 1;
+            self.config.control_every > 0 && sends.is_multiple_of(self.config.control_every)
+        };
+        if control {
+            return immediate;
+        }
+
+        let lead = Duration::from_millis(self.config.lead_ms);
+        // Already early in the slot, or inside the lead window before the next one
+        if current.phase <= self.config.send_window || current.until_next_boundary <= lead {
+            return SendPlan { delay: Duration::ZERO, timed: true, phase };
+        }
+        let delay = current.until_next_boundary - lead;
+        if delay > Duration::from_millis(self.config.max_wait_ms) {
+            return immediate;
+        }
+        SendPlan { delay, timed: true, phase }
+    }
+
+    /// Sleep per [`plan`](Self::plan)
+    pub async fn wait_for_slot(&self) -> SendPlan {
+        let plan = self.plan(Instant::now());
+        if !plan.delay.is_zero() {
+            debug!("⏱️ Holding send {:?} for slot boundary", plan.delay);
+            tokio::time::sleep(plan.delay).await;
+        }
+        plan
+    }
+
+    /// Remember a send so its landing slot can be attributed later
+    pub fn record_send(&self, signature: &str, plan: &SendPlan) {
+        let Some(phase) = plan.phase else {
+            return;
+        };
+        // The delay moves the send into the next slot
+        let send_slot = phase.slot + u64::from(!plan.delay.is_zero());
+        self.landing.lock().pending.insert(signature.to_string(), SendRecord { send_slot, timed: plan.timed });
+    }
+
+    /// Attribute a confirmed send to the slot it landed in
+    pub fn record_landing(&self, signature: &str, landed_slot: u64) {
+        let mut landing = self.landing.lock();
+        let Some(record) = landing.pending.remove(signature) else {
+            return;
+        };
+        let slots = landed_slot.saturating_sub(record.send_slot);
+        if record.timed {
+            landing.timed.push(slots);
+        } else {
+            landing.untimed.push(slots);
+        }
+    }
+
+    /// Drop a send that never landed
+    pub fn forget_send(&self, signature: &str) {
+        self.landing.lock().pending.remove(signature);
+    }
+
+    pub fn landing_report(&self) -> LandingReport {
+        fn stats(samples: &[u64]) -> LandingModeStats {
+            if samples.is_empty() {
+                return LandingModeStats::default();
+            }
+            let n = samples.len() as f64;
+            LandingModeStats {
+                landed: samples.len() as u64,
+                mean_slots_to_land: samples.iter().sum::<u64>() as f64 / n,
+                next_slot_rate: samples.iter().filter(|&&s| s <= 1).count() as f64 / n,
+            }
+        }
+
+        let landing = self.landing.lock();
+        let timed = stats(&landing.timed);
+        let untimed = stats(&landing.untimed);
+        let improvement_slots = (timed.landed > 0 && untimed.landed > 0)
+            .then_some(untimed.mean_slots_to_land - timed.mean_slots_to_land);
+        LandingReport { timed, untimed, improvement_slots }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticking(tracker: &SlotPhaseTracker, base: Instant, first_slot: u64, count: u64, slot_ms: u64) {
+        for i in 0..count {
+            // Ticks observed 5-30ms after each boundary
+            let jitter = 5 + (i * 7) % 25;
+            tracker.observe(first_slot + i, base + Duration::from_millis(i * slot_ms + jitter));
+        }
+    }
+
+    #[test]
+    fn test_phase_estimate_tracks_slot_boundaries() {
+        let tracker = SlotPhaseTracker::new(Duration::from_secs(2));
+        let base = Instant::now();
+        ticking(&tracker, base, 1_000, 20, 400);
+
+        let duration = tracker.slot_duration().as_secs_f64();
+        assert!((duration - 0.4).abs() < 0.005, "duration {}", duration);
+
+        // 100ms into slot 1019
+        let phase = tracker.phase_at(base + Duration::from_millis(19 * 400 + 100)).unwrap();
+        assert_eq!(phase.slot, 1_019);
+        assert!((phase.phase - 0.25).abs() < 0.03, "phase {}", phase.phase);
+        assert!((phase.until_next_boundary.as_millis() as i64 - 300).abs() < 15);
+
+        // No ticks for longer than max_staleness
+        assert!(tracker.phase_at(base + Duration::from_secs(20)).is_none());
+    }
+
+    #[test]
+    fn test_scheduler_holds_late_sends_and_reports_landing() {
+        let scheduler = SubmissionScheduler::new(SlotTimingConfig {
+            enabled: true,
+            control_every: 0,
+            ..Default::default()
+        });
+        let base = Instant::now();
+        ticking(&scheduler.tracker(), base, 500, 10, 400);
+
+        // Early in slot 509: send now
+        let early = scheduler.plan(base + Duration::from_millis(9 * 400 + 50));
+        assert!(early.timed && early.delay.is_zero());
+
+        // 250ms in: hold until ~40ms before the boundary
+        let late = scheduler.plan(base + Duration::from_millis(9 * 400 + 250));
+        assert!(late.timed);
+        assert!((late.delay.as_millis() as i64 - 110).abs() < 15, "delay {:?}", late.delay);
+
+        scheduler.record_send("timed", &late);
+        scheduler.record_send("untimed", &SendPlan { timed: false, ..early });
+        scheduler.record_landing("timed", 510);
+        scheduler.record_landing("untimed", 511);
+        scheduler.record_landing("unknown", 600);
+
+        let report = scheduler.landing_report();
+        assert_eq!(report.timed.mean_slots_to_land, 0.0);
+        assert_eq!(report.untimed.mean_slots_to_land, 2.0);
+        assert_eq!(report.improvement_slots, Some(2.0));
+    }
+}
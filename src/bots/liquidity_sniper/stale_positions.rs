@@ -0,0 +1,467 @@
+// SniperForge Enterprise v3.0 - Stale Position Detector & Forced Exit Policy
+// Flags positions that outlived their token's liquidity and exits them best-effort across venues
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use super::DexType;
+use super::position_manager::Position;
+use crate::trading::execution::{JupiterRealClient, JupiterRealConfig};
+use crate::types::constants::SOL_MINT;
+
+/// Stale position thresholds
+#[derive(Debug, Clone)]
+pub struct StalePositionConfig {
+    /// Detector enabled
+    pub enabled: bool,
+
+    /// Positions held longer than this are candidates (minutes)
+    pub max_age_minutes: i64,
+
+    /// Candidates whose last observed liquidity is below this are stale (USD)
+    pub min_liquidity_usd: f64,
+
+    /// Slippage accepted on forced exits (basis points)
+    pub exit_slippage_bps: u16,
+
+    /// Share of the position left unsold that still counts as a full exit
+    pub dust_fraction: f64,
+}
+
+impl Default for StalePositionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_age_minutes: 240,        // 4 hours
+            min_liquidity_usd: 2_000.0,  // pool too thin to exit normally
+            exit_slippage_bps: 2_500,    // 25%: getting out matters more than price
+            dust_fraction: 0.01,
+        }
+    }
+}
+
+/// Position flagged as stale
+#[derive(Debug, Clone)]
+pub struct StalePosition {
+    pub position_id: Uuid,
+    pub token_address: String,
+    pub dex: DexType,
+    pub age_minutes: i64,
+    /// Last observed liquidity (None if the position never received a market update)
+    pub liquidity_usd: Option<f64>,
+    pub reason: String,
+}
+
+/// Flags positions older than the max age whose liquidity fell below the floor
+#[derive(Debug, Clone)]
+pub struct StalePositionDetector {
+    config: StalePositionConfig,
+}
+
+impl StalePositionDetector {
+    pub fn new(config: StalePositionConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) -> &StalePositionConfig {
+        &self.config
+    }
+
+    /// Evaluate one position at `now`
+    pub fn evaluate(&self, position: &Position, now: DateTime<Utc>) -> Option<StalePosition> {
+        if !self.config.enabled {
+            return None;
+        }
+        let age_minutes = (now - position.entry_time).num_minutes();
+        if age_minutes < self.config.max_age_minutes {
+            return None;
+        }
+
+        // No market update at all means nobody is quoting the token: treat as illiquid
+        let liquidity_usd = position.performance.price_updates.last().map(|update| update.liquidity);
+        if liquidity_usd.unwrap_or(0.0) >= self.config.min_liquidity_usd {
+            return None;
+        }
+
+        let reason = match liquidity_usd {
+            Some(liquidity) => format!(
+                "stale: held {} min with ${:.0} liquidity (floor ${:.0})",
+                age_minutes, liquidity, self.config.min_liquidity_usd
+            ),
+            None => format!("stale: held {} min with no observed liquidity", age_minutes),
+        };
+        Some(StalePosition {
+            position_id: position.id,
+            token_address: position.token_address.clone(),
+            dex: position.dex.clone(),
+            age_minutes,
+            liquidity_usd,
+            reason,
+        })
+    }
+
+    /// All stale positions among `positions`
+    pub fn scan<'a>(&self, positions: impl IntoIterator<Item = &'a Position>) -> Vec<StalePosition> {
+        let now = Utc::now();
+        positions
+            .into_iter()
+            .filter_map(|position| self.evaluate(position, now))
+            .collect()
+    }
+}
+
+/// Fill returned by an exit venue
+#[derive(Debug, Clone)]
+pub struct ExitFill {
+    pub tokens_sold: f64,
+    pub sol_received: f64,
+    pub signature: Option<String>,
+}
+
+/// Venue able to sell a token back to SOL
+#[async_trait]
+pub trait ExitVenue: Send + Sync {
+    fn dex(&self) -> DexType;
+
+    /// Sell up to `tokens` of `token_address`; partial fills are allowed
+    async fn sell(&self, token_address: &str, tokens: f64, max_slippage_bps: u16) -> Result<ExitFill>;
+}
+
+/// Jupiter-routed exit (token → SOL)
+pub struct JupiterExitVenue {
+    config: JupiterRealConfig,
+    wallet: String,
+    token_decimals: u8,
+}
+
+impl JupiterExitVenue {
+    pub fn new(config: JupiterRealConfig, wallet: String, token_decimals: u8) -> Self {
+        Self { config, wallet, token_decimals }
+    }
+}
+
+#[async_trait]
+impl ExitVenue for JupiterExitVenue {
+    fn dex(&self) -> DexType {
+        DexType::Jupiter
+    }
+
+    async fn sell(&self, token_address: &str, tokens: f64, max_slippage_bps: u16) -> Result<ExitFill> {
+        let input_mint = Pubkey::from_str(token_address).map_err(|e| anyhow!("Invalid token mint: {}", e))?;
+        let output_mint = Pubkey::from_str(SOL_MINT)?;
+        let scale = 10f64.powi(self.token_decimals as i32);
+        let amount = (tokens * scale).floor() as u64;
+
+        let client = JupiterRealClient::new(Some(JupiterRealConfig { slippage_bps: max_slippage_bps, ..self.config.clone() }));
+        let result = client.execute_real_swap(input_mint, output_mint, amount, &self.wallet).await?;
+        if !result.success {
+            return Err(anyhow!(result.error_message.unwrap_or_else(|| "Jupiter swap failed".to_string())));
+        }
+        Ok(ExitFill {
+            tokens_sold: result.input_amount as f64 / scale,
+            sol_received: result.output_amount as f64 / 1_000_000_000.0,
+            signature: Some(result.transaction_id),
+        })
+    }
+}
+
+/// One venue attempt during a forced exit
+#[derive(Debug, Clone)]
+pub struct ExitAttempt {
+    pub dex: DexType,
+    pub tokens_offered: f64,
+    pub tokens_sold: f64,
+    pub sol_received: f64,
+    pub signature: Option<String>,
+    pub error: Option<String>,
+}
+
+/// How a forced exit ended
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForcedExitOutcome {
+    /// Sold (up to dust)
+    Exited,
+    /// Partly sold, the remainder written off
+    PartialWriteOff,
+    /// Nothing could be sold; the whole cost basis is written off
+    WrittenOff,
+}
+
+/// Full report of a forced exit
+#[derive(Debug, Clone)]
+pub struct ForcedExitReport {
+    pub position_id: Uuid,
+    pub token_address: String,
+    pub reason: String,
+    pub age_minutes: i64,
+    pub liquidity_usd: Option<f64>,
+    pub attempts: Vec<ExitAttempt>,
+    pub tokens_held: f64,
+    pub tokens_sold: f64,
+    pub cost_basis_sol: f64,
+    pub sol_recovered: f64,
+    /// Cost basis attributed to the unsold tokens
+    pub written_off_sol: f64,
+    pub realized_pnl_sol: f64,
+    pub outcome: ForcedExitOutcome,
+    pub completed_at: DateTime<Utc>,
+}
+
+/// Aggregate forced-exit accounting
+#[derive(Debug, Clone, Default)]
+pub struct ForcedExitStats {
+    pub exits: u64,
+    pub partial_write_offs: u64,
+    pub write_offs: u64,
+    pub sol_recovered: f64,
+    pub sol_written_off: f64,
+}
+
+/// Best-effort exit across venues with write-off accounting for what cannot be sold
+pub struct ForcedExitPolicy {
+    config: StalePositionConfig,
+    venues: Vec<Arc<dyn ExitVenue>>,
+    reports: RwLock<Vec<ForcedExitReport>>,
+    stats: RwLock<ForcedExitStats>,
+}
+
+impl ForcedExitPolicy {
+    pub fn new(config: StalePositionConfig) -> Self {
+        Self {
+            config,
+            venues: Vec::new(),
+            reports: RwLock::new(Vec::new()),
+            stats: RwLock::new(ForcedExitStats::default()),
+        }
+    }
+
+    pub fn with_venue(mut self, venue: Arc<dyn ExitVenue>) -> Self {
+        self.venues.push(venue);
+        self
+    }
+
+    pub fn has_venues(&self) -> bool {
+        !self.venues.is_empty()
+    }
+
+    /// Exit a stale position, trying its own venue first and then every other one
+    pub async fn exit(&self, stale: &StalePosition, position: &Position) -> ForcedExitReport {
+        warn!("🧟 Forcing exit of position {} ({}): {}", position.id, position.token_address, stale.reason);
+
+        let mut venues: Vec<&Arc<dyn ExitVenue>> = self.venues.iter().collect();
+        venues.sort_by_key(|venue| venue.dex() != position.dex);
+
+        let tokens_held = position.position_size_tokens;
+        let dust = tokens_held * self.config.dust_fraction;
+        let mut remaining = tokens_held;
+        let mut sol_recovered = 0.0;
+        let mut attempts = Vec::new();
+
+        for venue in venues {
+            if remaining <= dust {
+                break;
+            }
+            match venue.sell(&position.token_address, remaining, self.config.exit_slippage_bps).await {
+                Ok(fill) => {
+                    let sold = fill.tokens_sold.clamp(0.0, remaining);
+                    remaining -= sold;
+                    sol_recovered += fill.sol_received;
+                    info!("🧟 {:?} sold {:.4} tokens for {:.6} SOL", venue.dex(), sold, fill.sol_received);
+                    attempts.push(ExitAttempt {
+                        dex: venue.dex(),
+                        tokens_offered: sold + remaining,
+                        tokens_sold: sold,
+                        sol_received: fill.sol_received,
+                        signature: fill.signature,
+                        error: None,
+                    });
+                }
+                Err(e) => {
+                    warn!("⚠️ Forced exit on {:?} failed: {}", venue.dex(), e);
+                    attempts.push(ExitAttempt {
+                        dex: venue.dex(),
+                        tokens_offered: remaining,
+                        tokens_sold: 0.0,
+                        sol_received: 0.0,
+                        signature: None,
+                        error: Some(e.to_string()),
+                    });
+                }
+            }
+        }
+
+        let tokens_sold = tokens_held - remaining;
+        let outcome = if remaining <= dust {
+            ForcedExitOutcome::Exited
+        } else if tokens_sold > 0.0 {
+            ForcedExitOutcome::PartialWriteOff
+        } else {
+            ForcedExitOutcome::WrittenOff
+        };
+        let written_off_sol = match outcome {
+            ForcedExitOutcome::Exited => 0.0,
+            _ if tokens_held > 0.0 => position.position_size_sol * remaining / tokens_held,
+            _ => position.position_size_sol,
+        };
+
+        let report = ForcedExitReport {
+            position_id: position.id,
+            token_address: position.token_address.clone(),
+            reason: stale.reason.clone(),
+            age_minutes: stale.age_minutes,
+            liquidity_usd: stale.liquidity_usd,
+            attempts,
+            tokens_held,
+            tokens_sold,
+            cost_basis_sol: position.position_size_sol,
+            sol_recovered,
+            written_off_sol,
+            realized_pnl_sol: sol_recovered - position.position_size_sol,
+            outcome,
+            completed_at: Utc::now(),
+        };
+
+        match report.outcome {
+            ForcedExitOutcome::Exited => info!("✅ Stale position {} exited: recovered {:.6} SOL (PnL {:.6})",
+                                               report.position_id, report.sol_recovered, report.realized_pnl_sol),
+            _ => warn!("💀 Stale position {} {:?}: {:.6} SOL written off after {} attempts",
+                       report.position_id, report.outcome, report.written_off_sol, report.attempts.len()),
+        }
+
+        {
+            let mut stats = self.stats.write().await;
+            match report.outcome {
+                ForcedExitOutcome::Exited => stats.exits += 1,
+                ForcedExitOutcome::PartialWriteOff => stats.partial_write_offs += 1,
+                ForcedExitOutcome::WrittenOff => stats.write_offs += 1,
+            }
+            stats.sol_recovered += report.sol_recovered;
+            stats.sol_written_off += report.written_off_sol;
+        }
+        self.reports.write().await.push(report.clone());
+        report
+    }
+
+    /// Every forced exit so far
+    pub async fn get_reports(&self) -> Vec<ForcedExitReport> {
+        self.reports.read().await.clone()
+    }
+
+    pub async fn get_stats(&self) -> ForcedExitStats {
+        self.stats.read().await.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::position_manager::{PositionMetadata, PositionPerformance, PositionStatus, PriceUpdate};
+    use super::super::risk_manager::{MonitoringLevel, RiskAssessment};
+
+    fn position(age_minutes: i64, liquidity: Option<f64>) -> Position {
+        let now = Utc::now();
+        Position {
+            id: Uuid::new_v4(),
+            opportunity_id: Uuid::new_v4(),
+            token_address: "So11111111111111111111111111111111111111112".to_string(),
+            pool_address: String::new(),
+            dex: DexType::Raydium,
+            entry_time: now - chrono::Duration::minutes(age_minutes),
+            entry_price: 0.001,
+            position_size_sol: 1.0,
+            position_size_tokens: 1_000.0,
+            risk_assessment: RiskAssessment {
+                approved: true,
+                risk_score: 0.3,
+                max_position_size: 1.0,
+                monitoring_level: MonitoringLevel::Low,
+                required_stops: Vec::new(),
+                warnings: Vec::new(),
+                timestamp: now,
+            },
+            status: PositionStatus::Active,
+            stop_levels: Vec::new(),
+            performance: PositionPerformance {
+                current_price: 0.001,
+                unrealized_pnl_sol: 0.0,
+                unrealized_pnl_percent: 0.0,
+                max_profit_percent: 0.0,
+                max_loss_percent: 0.0,
+                drawdown_from_peak: 0.0,
+                hold_time_minutes: age_minutes as f64,
+                price_updates: liquidity
+                    .map(|liquidity| vec![PriceUpdate { timestamp: now, price: 0.001, volume: 0.0, liquidity }])
+                    .unwrap_or_default(),
+            },
+            monitoring_level: MonitoringLevel::Low,
+            metadata: PositionMetadata {
+                entry_reason: "test".to_string(),
+                strategy_used: "LiquiditySnipe".to_string(),
+                expected_hold_time: None,
+                target_profit_percent: 15.0,
+                max_acceptable_loss_percent: 5.0,
+                notes: Vec::new(),
+            },
+        }
+    }
+
+    struct FixedVenue {
+        dex: DexType,
+        sell_fraction: f64,
+    }
+
+    #[async_trait]
+    impl ExitVenue for FixedVenue {
+        fn dex(&self) -> DexType {
+            self.dex.clone()
+        }
+
+        async fn sell(&self, _token_address: &str, tokens: f64, _max_slippage_bps: u16) -> Result<ExitFill> {
+            if self.sell_fraction <= 0.0 {
+                return Err(anyhow!("no route"));
+            }
+            let sold = tokens * self.sell_fraction;
+            Ok(ExitFill { tokens_sold: sold, sol_received: sold * 0.0005, signature: None })
+        }
+    }
+
+    #[test]
+    fn test_detector_requires_age_and_low_liquidity() {
+        let detector = StalePositionDetector::new(StalePositionConfig::default());
+        let now = Utc::now();
+
+        assert!(detector.evaluate(&position(30, Some(100.0)), now).is_none(), "too young");
+        assert!(detector.evaluate(&position(300, Some(50_000.0)), now).is_none(), "still liquid");
+        assert!(detector.evaluate(&position(300, Some(100.0)), now).is_some());
+        assert_eq!(detector.evaluate(&position(300, None), now).unwrap().liquidity_usd, None);
+    }
+
+    #[tokio::test]
+    async fn test_forced_exit_falls_back_and_writes_off_remainder() {
+        let policy = ForcedExitPolicy::new(StalePositionConfig::default())
+            .with_venue(Arc::new(FixedVenue { dex: DexType::Orca, sell_fraction: 0.6 }))
+            .with_venue(Arc::new(FixedVenue { dex: DexType::Raydium, sell_fraction: 0.0 }));
+        let position = position(300, Some(100.0));
+        let stale = StalePositionDetector::new(StalePositionConfig::default())
+            .evaluate(&position, Utc::now())
+            .unwrap();
+
+        let report = policy.exit(&stale, &position).await;
+        assert_eq!(report.attempts[0].dex, DexType::Raydium, "own venue tried first");
+        assert_eq!(report.outcome, ForcedExitOutcome::PartialWriteOff);
+        assert!((report.tokens_sold - 600.0).abs() < 1e-9);
+        assert!((report.written_off_sol - 0.4).abs() < 1e-9);
+        assert!((report.realized_pnl_sol - (0.3 - 1.0)).abs() < 1e-9);
+
+        let stats = policy.get_stats().await;
+        assert_eq!(stats.partial_write_offs, 1);
+        assert_eq!(policy.get_reports().await.len(), 1);
+    }
+}
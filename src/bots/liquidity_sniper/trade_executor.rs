@@ -17,6 +17,15 @@ use crate::shared::{
 
 use super::{SniperConfig, TradeData, TradeResult, PositionData, SniperStrategy};
 use super::risk_manager::MonitoringLevel;
+use super::sandwich_risk::{SandwichRiskEstimator, SandwichRiskConfig, SandwichRiskInput, SubmissionRoute, SwapSide};
+use super::mev_inspection::{MevInspector, FillToInspect, MevExposure};
+use crate::api::bot_interface::Environment;
+use super::slot_timing::{SubmissionScheduler, LandingReport};
+use crate::analytics::{LeaderStats, LeaderSummary, LeaderTipPolicy};
+use crate::apis::LeaderScheduleCache;
+
+/// Venue sniper swaps are built for (see `ExecutionEngine::build_swap_transaction`)
+const SWAP_VENUE: &str = "Raydium";
 
 /// Enterprise trade executor with MEV protection
 pub struct TradeExecutor {
@@ -26,6 +35,33 @@ pub struct TradeExecutor {
     slippage_calculator: SlippageCalculator,
     gas_optimizer: GasOptimizer,
     execution_stats: ExecutionStats,
+    sandwich_estimator: std::sync::Arc<SandwichRiskEstimator>,
+    mev_inspector: std::sync::Arc<MevInspector>,
+    slot_scheduler: std::sync::Arc<SubmissionScheduler>,
+    leader_schedule: std::sync::Arc<LeaderScheduleCache>,
+    leader_stats: parking_lot::Mutex<LeaderStats>,
+}
+
+/// Leader-schedule tracking and per-leader Jito tips
+#[derive(Debug, Clone)]
+pub struct LeaderAwarenessConfig {
+    /// Load the epoch leader schedule and attribute sends to leaders
+    pub enabled: bool,
+    pub rpc_url: String,
+    /// Tip before per-leader scaling
+    pub base_tip_lamports: u64,
+    pub tip_policy: LeaderTipPolicy,
+}
+
+impl Default for LeaderAwarenessConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            rpc_url: "https://api.mainnet-beta.solana.com".to_string(),
+            base_tip_lamports: 10_000,
+            tip_policy: LeaderTipPolicy::default(),
+        }
+    }
 }
 
 /// High-performance execution engine
@@ -310,6 +346,11 @@ pub struct ExecutionParams {
     pub compute_units: u32,
     pub rpc_client_index: usize,
     pub use_jito: bool,
+    /// Leader of the targeted slot, when the schedule is loaded
+    pub target_leader: Option<String>,
+    pub jito_tip_lamports: Option<u64>,
+    /// How the transaction reaches the leader
+    pub route: SubmissionRoute,
 }
 
 #[derive(Debug)]
@@ -377,6 +418,44 @@ impl TradeExecutor {
         let slippage_calculator = SlippageCalculator::new();
         let gas_optimizer = GasOptimizer::new().await?;
         
+        let slot_scheduler = std::sync::Arc::new(SubmissionScheduler::new(config.slot_timing.clone()));
+        if config.slot_timing.enabled {
+            let tracker = slot_scheduler.tracker();
+            let ws_url = config.slot_timing.ws_url.clone();
+            tokio::spawn(async move {
+                if let Err(e) = tracker.follow(ws_url).await {
+                    warn!("⚠️ Slot feed unavailable, sends go out untimed: {}", e);
+                }
+            });
+        }
+        
+        let leader_schedule = std::sync::Arc::new(LeaderScheduleCache::new(config.leader_awareness.rpc_url.clone()));
+        if config.leader_awareness.enabled {
+            let schedule = leader_schedule.clone();
+            let tracker = slot_scheduler.tracker();
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(30));
+                loop {
+                    interval.tick().await;
+                    // Without a slot estimate only the initial load happens
+                    let slot = match tracker.phase_at(Instant::now()) {
+                        Some(phase) => phase.slot,
+                        None if schedule.current().is_none() => 0,
+                        None => continue,
+                    };
+                    if let Err(e) = schedule.refresh_for(slot).await {
+                        warn!("⚠️ Leader schedule refresh failed: {}", e);
+                    }
+                }
+            });
+        }
+        
+        let sandwich_estimator = std::sync::Arc::new(SandwichRiskEstimator::new(SandwichRiskConfig {
+            enabled: config.mev_protection_enabled,
+            ..Default::default()
+        }));
+        let mev_inspector = std::sync::Arc::new(MevInspector::new(config.mev_inspection.clone(), sandwich_estimator.clone()));
+        
         Ok(Self {
             config: config.clone(),
             execution_engine,
@@ -384,9 +463,40 @@ impl TradeExecutor {
             slippage_calculator,
             gas_optimizer,
             execution_stats: ExecutionStats::new(),
+            sandwich_estimator,
+            mev_inspector,
+            slot_scheduler,
+            leader_schedule,
+            leader_stats: parking_lot::Mutex::new(LeaderStats::new(config.leader_awareness.tip_policy.clone())),
         })
     }
 
+    /// Per-leader inclusion, latency and tip history
+    pub fn leader_summaries(&self) -> Vec<LeaderSummary> {
+        self.leader_stats.lock().summaries()
+    }
+
+    /// Leader expected to produce the slot a send issued now lands in
+    async fn target_leader(&self, rpc_index: usize) -> Option<String> {
+        let schedule = self.leader_schedule.current()?;
+        // Leaders hold 4 consecutive slots, so the current slot is close enough
+        let slot = match self.slot_scheduler.tracker().phase_at(Instant::now()) {
+            Some(phase) => phase.slot,
+            None => self.execution_engine.current_slot(rpc_index).await.ok()?,
+        };
+        schedule.leader_at(slot).map(str::to_string)
+    }
+
+    /// Attribute a confirmed send to its landing slot (slot timing measurement)
+    pub fn record_landing(&self, signature: &str, landed_slot: u64) {
+        self.slot_scheduler.record_landing(signature, landed_slot);
+    }
+
+    /// Landing position of slot-timed vs untimed sends
+    pub fn slot_landing_report(&self) -> LandingReport {
+        self.slot_scheduler.landing_report()
+    }
+
     /// Execute sniper trade with enterprise guarantees
     pub async fn execute_sniper_trade(&self, trade_data: &TradeData) -> Result<TradeResult> {
         let start_time = Instant::now();
@@ -413,6 +523,21 @@ impl TradeExecutor {
         // 🚀 ENRIQUECIMIENTO: Update execution_stats
         self.update_execution_statistics(&result, execution_time, expected_slippage).await?;
         
+        // Was the fill sandwiched? Checked once its block is available
+        if let (true, Some(signature)) = (result.success, &result.transaction_signature) {
+            if self.config.environment == Environment::Mainnet && self.config.mev_inspection.enabled {
+                self.mev_inspector.spawn_inspection(FillToInspect {
+                    signature: signature.clone(),
+                    signer: self.active_wallet().to_string(),
+                    token_mint: trade_data.token_address.clone(),
+                    side: SwapSide::Buy,
+                    venue: SWAP_VENUE.to_string(),
+                    route: execution_params.route,
+                    execution_shortfall_sol: 0.0,
+                });
+            }
+        }
+        
         // Create position if successful
         let position = if result.success {
             Some(self.create_position_from_execution(trade_data, &result).await?)
@@ -475,6 +600,21 @@ impl TradeExecutor {
         &self.execution_stats
     }
 
+    /// Wallet trades are signed with
+    pub fn active_wallet(&self) -> &str {
+        &self.execution_engine.transaction_builder.wallet_manager.active_wallet
+    }
+
+    /// Sandwich risk estimator (post-trade sandwich events are recorded here)
+    pub fn get_sandwich_estimator(&self) -> &SandwichRiskEstimator {
+        &self.sandwich_estimator
+    }
+
+    /// Sandwich evidence from inspected MainNet fills, per venue and route
+    pub fn mev_exposure(&self) -> Vec<MevExposure> {
+        self.mev_inspector.exposure()
+    }
+
     /// 🚀 ENRIQUECIMIENTO: Calculate execution success rate
     pub fn get_success_rate(&self) -> f64 {
         if self.execution_stats.total_executions == 0 {
@@ -497,16 +637,67 @@ impl TradeExecutor {
     async fn calculate_execution_parameters(&self, trade_data: &TradeData) -> Result<ExecutionParams> {
         debug!("⚙️ Calculating execution parameters");
         
-        let optimal_slippage = trade_data.max_slippage * 0.8;
+        let mut optimal_slippage = trade_data.max_slippage * 0.8;
         let gas_params = self.gas_optimizer.optimize_gas_parameters(trade_data).await?;
         let best_rpc = self.execution_engine.select_best_rpc_client().await?;
+        let mut use_jito = self.mev_protection.jito_integration.enabled;
+        let route = if use_jito {
+            SubmissionRoute::JitoBundle
+        } else if self.mev_protection.should_use_private_mempool(trade_data.amount_sol) {
+            SubmissionRoute::PrivateRpc
+        } else {
+            SubmissionRoute::PublicRpc
+        };
+        
+        // Inspected fills show this venue/route getting sandwiched
+        if self.mev_inspector.prefer_bundle(SWAP_VENUE, route) {
+            use_jito = true;
+        }
+        
+        // Sandwich risk: tighten slippage or force bundle submission on thin pools
+        match trade_data.pool_sol_reserve {
+            Some(sol_reserve) => {
+                let estimate = self.sandwich_estimator.estimate(&SandwichRiskInput {
+                    trade_size_sol: trade_data.amount_sol,
+                    sol_reserve,
+                    slippage_tolerance: optimal_slippage,
+                    route,
+                });
+                
+                optimal_slippage = estimate.recommended_slippage;
+                if estimate.force_bundle {
+                    use_jito = true;
+                }
+                for reason in &estimate.reasons {
+                    info!("🥪 {}", reason);
+                }
+            }
+            None => warn!("🥪 Sandwich risk estimation skipped for {}: pool SOL reserve unknown, keeping {:.2}% slippage",
+                          trade_data.token_address, optimal_slippage * 100.0),
+        }
+        
+        let target_leader = self.target_leader(best_rpc).await;
+        let jito_tip_lamports = use_jito.then(|| {
+            let base_tip = self.config.leader_awareness.base_tip_lamports;
+            match &target_leader {
+                Some(leader) => {
+                    let decision = self.leader_stats.lock().tip_for(leader, base_tip);
+                    debug!("💸 Jito tip {} lamports for leader {} ({:.2}x)", decision.tip_lamports, leader, decision.multiplier);
+                    decision.tip_lamports
+                }
+                None => base_tip,
+            }
+        });
         
         Ok(ExecutionParams {
             optimal_slippage,
             priority_fee: gas_params.priority_fee,
             compute_units: gas_params.compute_units,
             rpc_client_index: best_rpc,
-            use_jito: self.mev_protection.jito_integration.enabled,
+            use_jito,
+            target_leader,
+            jito_tip_lamports,
+            route: if use_jito { SubmissionRoute::JitoBundle } else { route },
         })
     }
 
@@ -539,10 +730,19 @@ impl TradeExecutor {
         // Build transaction
         let transaction = self.execution_engine.build_swap_transaction(trade_data, params).await?;
         
+        // Hold for the next slot boundary when timing is enabled
+        let send_plan = self.slot_scheduler.wait_for_slot().await;
+        
         // Execute transaction
         let tx_result = self.execution_engine.execute_transaction(transaction, params.rpc_client_index).await?;
+        if let (true, Some(signature)) = (tx_result.success, &tx_result.signature) {
+            self.slot_scheduler.record_send(signature, &send_plan);
+        }
         
         let execution_time = start_time.elapsed().as_millis() as u64;
+        if let Some(leader) = &params.target_leader {
+            self.leader_stats.lock().record(leader, tx_result.success, execution_time, params.jito_tip_lamports);
+        }
         
         Ok(ExecutionResult {
             success: tx_result.success,
@@ -600,6 +800,13 @@ impl ExecutionEngine {
     }
 
     /// Selecciona el mejor cliente RPC basado en latencia y disponibilidad
+    /// Current slot as seen by one of the RPC clients
+    pub async fn current_slot(&self, rpc_index: usize) -> Result<u64> {
+        let client = self.rpc_clients.get(rpc_index)
+            .ok_or_else(|| anyhow::anyhow!("Invalid RPC client index: {}", rpc_index))?;
+        client.get_current_slot().await
+    }
+
     pub async fn select_best_rpc_client(&self) -> Result<usize> {
         let mut best_index = 0;
         let mut best_latency = std::time::Duration::from_secs(10);
@@ -2286,6 +2493,7 @@ mod tests {
             priority_fee: 10000,
             started_at: Utc::now(),
             strategy: None,
+            pool_sol_reserve: None,
         };
         
         let result = executor.gas_optimizer.optimize_gas_parameters(&trade_data).await;
@@ -3,6 +3,7 @@
 //! This module implements a machine learning analytics bot that analyzes market data
 //! and provides predictions and insights using various ML models.
 
+use tracing::info;
 use async_trait::async_trait;
 use uuid::Uuid;
 
@@ -57,7 +58,7 @@ impl BotInterface for MLAnalyticsBot {
     }
 
     async fn start(&mut self, config: BotConfig) -> Result<(), BotError> {
-        println!("🚀 Starting ML Analytics Bot: {}", self.name);
+        info!("🚀 Starting ML Analytics Bot: {}", self.name);
         self.config = config;
         self.status = BotStatus::Running;
         self.start_time = Some(chrono::Utc::now());
@@ -66,7 +67,7 @@ impl BotInterface for MLAnalyticsBot {
     }
 
     async fn stop(&mut self) -> Result<(), BotError> {
-        println!("🛑 Stopping ML Analytics Bot: {}", self.name);
+        info!("🛑 Stopping ML Analytics Bot: {}", self.name);
         self.status = BotStatus::Stopped;
         Ok(())
     }
@@ -52,11 +52,12 @@ impl MockArbitrageBot {
             trading: TradingMetrics {
                 trades_executed: 0,
                 successful_trades: 0,
-                total_pnl_usd: 0.0,
+                total_pnl_usd: Some(0.0),
                 success_rate: 0.0,
                 avg_profit_per_trade: 0.0,
-                total_volume_usd: 0.0,
+                total_volume_usd: Some(0.0),
                 sharpe_ratio: None,
+                usd_rate_timestamp: None,
             },
             performance: PerformanceMetrics {
                 cpu_usage_percent: 0.0,
@@ -110,7 +111,7 @@ impl MockArbitrageBot {
         // Calculate real average profit per trade
         if metrics.trading.trades_executed > 0 {
             metrics.trading.avg_profit_per_trade = 
-                metrics.trading.total_pnl_usd / metrics.trading.trades_executed as f64;
+                metrics.trading.total_pnl_usd.unwrap_or(0.0) / metrics.trading.trades_executed as f64;
         }
     }
 }
@@ -152,7 +153,7 @@ impl BotInterface for MockArbitrageBot {
         // Calculate real average profit per trade
         if metrics.trading.trades_executed > 0 {
             metrics.trading.avg_profit_per_trade = 
-                metrics.trading.total_pnl_usd / metrics.trading.trades_executed as f64;
+                metrics.trading.total_pnl_usd.unwrap_or(0.0) / metrics.trading.trades_executed as f64;
         }
         
         metrics.clone()
@@ -249,10 +250,10 @@ impl BotInterface for MockArbitrageBot {
             // All metrics start at real zero - will be updated by actual trading activity
             metrics.trading.trades_executed = 0;
             metrics.trading.successful_trades = 0;
-            metrics.trading.total_pnl_usd = 0.0;
+            metrics.trading.total_pnl_usd = Some(0.0);
             metrics.trading.success_rate = 0.0;
             metrics.trading.avg_profit_per_trade = 0.0;
-            metrics.trading.total_volume_usd = 0.0;
+            metrics.trading.total_volume_usd = Some(0.0);
             metrics.operational.uptime_seconds = 0; // Will be calculated in real-time
             metrics.operational.restart_count = 0;
             metrics.operational.error_count = 0;
@@ -597,13 +598,13 @@ impl MockArbitrageBot {
                     let mut metrics_guard = metrics.write().await;
                     metrics_guard.trading.trades_executed += 1;
                     metrics_guard.trading.successful_trades += 1;
-                    metrics_guard.trading.total_pnl_usd += profit_usd;
-                    metrics_guard.trading.total_volume_usd += profit_usd * 50.0; // Assume 50x leverage for volume
+                    *metrics_guard.trading.total_pnl_usd.get_or_insert(0.0) += profit_usd;
+                    *metrics_guard.trading.total_volume_usd.get_or_insert(0.0) += profit_usd * 50.0; // Assume 50x leverage for volume
                     
                     // Update average profit
                     if metrics_guard.trading.trades_executed > 0 {
                         metrics_guard.trading.avg_profit_per_trade = 
-                            metrics_guard.trading.total_pnl_usd / metrics_guard.trading.trades_executed as f64;
+                            metrics_guard.trading.total_pnl_usd.unwrap_or(0.0) / metrics_guard.trading.trades_executed as f64;
                     }
                     
                     // Update success rate
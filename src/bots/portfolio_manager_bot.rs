@@ -3,6 +3,7 @@
 //! This module implements a portfolio management bot that automatically balances
 //! and manages trading portfolios across multiple exchanges and assets.
 
+use tracing::info;
 use async_trait::async_trait;
 use uuid::Uuid;
 
@@ -57,7 +58,7 @@ impl BotInterface for PortfolioManagerBot {
     }
 
     async fn start(&mut self, config: BotConfig) -> Result<(), BotError> {
-        println!("🚀 Starting Portfolio Manager Bot: {}", self.name);
+        info!("🚀 Starting Portfolio Manager Bot: {}", self.name);
         self.config = config;
         self.status = BotStatus::Running;
         self.start_time = Some(chrono::Utc::now());
@@ -66,7 +67,7 @@ impl BotInterface for PortfolioManagerBot {
     }
 
     async fn stop(&mut self) -> Result<(), BotError> {
-        println!("🛑 Stopping Portfolio Manager Bot: {}", self.name);
+        info!("🛑 Stopping Portfolio Manager Bot: {}", self.name);
         self.status = BotStatus::Stopped;
         Ok(())
     }
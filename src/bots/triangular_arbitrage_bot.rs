@@ -3,6 +3,7 @@
 //! This module implements a triangular arbitrage bot that detects and executes
 //! arbitrage opportunities within a single exchange using three different currencies.
 
+use tracing::info;
 use async_trait::async_trait;
 use uuid::Uuid;
 
@@ -57,7 +58,7 @@ impl BotInterface for TriangularArbitrageBot {
     }
 
     async fn start(&mut self, config: BotConfig) -> Result<(), BotError> {
-        println!("🚀 Starting Triangular Arbitrage Bot: {}", self.name);
+        info!("🚀 Starting Triangular Arbitrage Bot: {}", self.name);
         self.config = config;
         self.status = BotStatus::Running;
         self.start_time = Some(chrono::Utc::now());
@@ -66,7 +67,7 @@ impl BotInterface for TriangularArbitrageBot {
     }
 
     async fn stop(&mut self) -> Result<(), BotError> {
-        println!("🛑 Stopping Triangular Arbitrage Bot: {}", self.name);
+        info!("🛑 Stopping Triangular Arbitrage Bot: {}", self.name);
         self.status = BotStatus::Stopped;
         Ok(())
     }
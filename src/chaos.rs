@@ -0,0 +1,331 @@
+//! Fault injection for resilience testing
+//!
+//! A process-wide [`FaultInjector`] that the RPC call sites, the intent
+//! reconciler, the in-memory price cache and the task watchdog consult. Faults
+//! can only be armed in builds with the `chaos` cargo feature; in every other
+//! build [`FaultInjector::set_plan`] refuses and the hooks reduce to one
+//! relaxed atomic load.
+//!
+//! Faults are armed at runtime through the control API (`sniperforge-cli
+//! chaos ...`):
+//! - drop a fraction of RPC calls (optionally only for some providers)
+//! - hide transaction confirmations for a while after signing
+//! - serve cached prices as if they were older than they are
+//! - kill a supervised task, which the watchdog must restart
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::OnceLock;
+use thiserror::Error;
+
+/// Armed faults; the default plan injects nothing
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FaultPlan {
+    /// Fraction of RPC calls failed before they are sent, in [0, 1]
+    pub rpc_drop_rate: f64,
+    /// Providers affected by drops; empty means all
+    #[serde(default)]
+    pub rpc_providers: Vec<String>,
+    /// Confirmations stay invisible for this long after signing
+    pub confirmation_delay_secs: u64,
+    /// Wallets whose confirmations are delayed; empty means all
+    #[serde(default)]
+    pub delayed_wallets: Vec<String>,
+    /// Cached prices are served this much older than they are
+    pub stale_price_secs: u64,
+    /// Tokens served stale; empty means all
+    #[serde(default)]
+    pub stale_tokens: Vec<String>,
+}
+
+impl FaultPlan {
+    pub fn is_empty(&self) -> bool {
+        self.rpc_drop_rate <= 0.0 && self.confirmation_delay_secs == 0 && self.stale_price_secs == 0
+    }
+}
+
+fn targets(filter: &[String], name: &str) -> bool {
+    filter.is_empty() || filter.iter().any(|f| f == name)
+}
+
+#[derive(Debug, Error)]
+pub enum ChaosError {
+    #[error("fault injection requires a build with the `chaos` feature")]
+    Disabled,
+    #[error("invalid fault plan: {0}")]
+    InvalidPlan(String),
+}
+
+/// Error returned in place of an RPC response
+#[derive(Debug, Error)]
+#[error("injected fault: {method} to {provider} dropped")]
+pub struct InjectedFault {
+    pub provider: String,
+    pub method: String,
+}
+
+/// Faults injected since the plan was last changed
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChaosStats {
+    pub rpc_calls_dropped: u64,
+    pub confirmations_hidden: u64,
+    pub stale_prices_served: u64,
+    pub tasks_killed: u64,
+}
+
+/// Current plan plus what it has done, for `chaos status`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChaosStatus {
+    pub compiled_in: bool,
+    pub plan: FaultPlan,
+    pub pending_kills: Vec<String>,
+    pub stats: ChaosStats,
+}
+
+/// Process-wide fault state consulted by the hooks
+#[derive(Debug, Default)]
+pub struct FaultInjector {
+    armed: AtomicBool,
+    plan: RwLock<FaultPlan>,
+    kills: RwLock<HashSet<String>>,
+    rpc_calls_dropped: AtomicU64,
+    confirmations_hidden: AtomicU64,
+    stale_prices_served: AtomicU64,
+    tasks_killed: AtomicU64,
+}
+
+impl FaultInjector {
+    pub const fn compiled_in() -> bool {
+        cfg!(feature = "chaos")
+    }
+
+    fn armed(&self) -> bool {
+        Self::compiled_in() && self.armed.load(Ordering::Relaxed)
+    }
+
+    /// Replace the active plan and reset the counters
+    pub fn set_plan(&self, plan: FaultPlan) -> Result<(), ChaosError> {
+        if !Self::compiled_in() {
+            return Err(ChaosError::Disabled);
+        }
+        if !(0.0..=1.0).contains(&plan.rpc_drop_rate) {
+            return Err(ChaosError::InvalidPlan(format!("rpc_drop_rate {} outside [0, 1]", plan.rpc_drop_rate)));
+        }
+        tracing::warn!("💥 Fault plan armed: {:?}", plan);
+        self.armed.store(!plan.is_empty() || !self.kills.read().is_empty(), Ordering::Relaxed);
+        *self.plan.write() = plan;
+        for counter in [&self.rpc_calls_dropped, &self.confirmations_hidden, &self.stale_prices_served, &self.tasks_killed] {
+            counter.store(0, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+
+    /// Disarm every fault
+    pub fn clear(&self) {
+        *self.plan.write() = FaultPlan::default();
+        self.kills.write().clear();
+        self.armed.store(false, Ordering::Relaxed);
+    }
+
+    /// Have the watchdog kill `task` at its next check
+    pub fn kill_task(&self, task: &str) -> Result<(), ChaosError> {
+        if !Self::compiled_in() {
+            return Err(ChaosError::Disabled);
+        }
+        tracing::warn!("💥 Task '{}' scheduled to be killed", task);
+        self.kills.write().insert(task.to_string());
+        self.armed.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    pub fn status(&self) -> ChaosStatus {
+        ChaosStatus {
+            compiled_in: Self::compiled_in(),
+            plan: self.plan.read().clone(),
+            pending_kills: self.kills.read().iter().cloned().collect(),
+            stats: ChaosStats {
+                rpc_calls_dropped: self.rpc_calls_dropped.load(Ordering::Relaxed),
+                confirmations_hidden: self.confirmations_hidden.load(Ordering::Relaxed),
+                stale_prices_served: self.stale_prices_served.load(Ordering::Relaxed),
+                tasks_killed: self.tasks_killed.load(Ordering::Relaxed),
+            },
+        }
+    }
+
+    /// Hook before an RPC request; `Err` means the call must fail as if the provider had
+    pub fn rpc_call(&self, provider: &str, method: &str) -> Result<(), InjectedFault> {
+        if !self.armed() {
+            return Ok(());
+        }
+        let plan = self.plan.read();
+        if plan.rpc_drop_rate > 0.0 && targets(&plan.rpc_providers, provider) && fastrand::f64() < plan.rpc_drop_rate {
+            self.rpc_calls_dropped.fetch_add(1, Ordering::Relaxed);
+            return Err(InjectedFault { provider: provider.to_string(), method: method.to_string() });
+        }
+        Ok(())
+    }
+
+    /// Hook in reconciliation: `true` when the confirmation must read as not found yet
+    pub fn confirmation_hidden(&self, wallet: &str, signed_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        if !self.armed() {
+            return false;
+        }
+        let plan = self.plan.read();
+        let hidden = plan.confirmation_delay_secs > 0
+            && targets(&plan.delayed_wallets, wallet)
+            && now - signed_at < ChronoDuration::seconds(plan.confirmation_delay_secs as i64);
+        if hidden {
+            self.confirmations_hidden.fetch_add(1, Ordering::Relaxed);
+        }
+        hidden
+    }
+
+    /// Hook on cache reads: the timestamp to report for a price cached at `cached_at`
+    pub fn price_timestamp(&self, token: &str, cached_at: DateTime<Utc>) -> DateTime<Utc> {
+        if !self.armed() {
+            return cached_at;
+        }
+        let plan = self.plan.read();
+        if plan.stale_price_secs == 0 || !targets(&plan.stale_tokens, token) {
+            return cached_at;
+        }
+        self.stale_prices_served.fetch_add(1, Ordering::Relaxed);
+        cached_at - ChronoDuration::seconds(plan.stale_price_secs as i64)
+    }
+
+    /// Hook in the watchdog check: consumes a pending kill for `task`
+    pub fn take_kill(&self, task: &str) -> bool {
+        if !self.armed() || !self.kills.write().remove(task) {
+            return false;
+        }
+        self.tasks_killed.fetch_add(1, Ordering::Relaxed);
+        true
+    }
+}
+
+/// Process-wide injector used by every hook
+pub fn faults() -> &'static FaultInjector {
+    static FAULTS: OnceLock<FaultInjector> = OnceLock::new();
+    FAULTS.get_or_init(FaultInjector::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_faults_cannot_be_armed_without_the_feature() {
+        let injector = FaultInjector::default();
+        let plan = FaultPlan { rpc_drop_rate: 1.0, ..Default::default() };
+        if FaultInjector::compiled_in() {
+            assert!(matches!(injector.set_plan(FaultPlan { rpc_drop_rate: 1.5, ..Default::default() }), Err(ChaosError::InvalidPlan(_))));
+            injector.set_plan(plan).unwrap();
+            assert!(injector.rpc_call("helius", "getSlot").is_err());
+            injector.clear();
+        } else {
+            assert!(matches!(injector.set_plan(plan), Err(ChaosError::Disabled)));
+            assert!(matches!(injector.kill_task("feeds"), Err(ChaosError::Disabled)));
+        }
+        assert!(injector.rpc_call("helius", "getSlot").is_ok());
+        assert!(!injector.take_kill("feeds"));
+    }
+}
+
+/// Resilience suite: run with `cargo test --features chaos chaos::suite`
+///
+/// Each test arms the process-wide injector, scoped to a provider, wallet,
+/// token or task name no other test uses.
+#[cfg(all(test, feature = "chaos"))]
+mod suite {
+    use super::*;
+    use crate::apis::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
+    use crate::apis::price_cache::{CachedPrice, InMemoryPriceCache, PriceCache};
+    use crate::monitoring::{HeartbeatHandle, TaskFactory, TaskLiveness, TaskWatchdog, WatchdogConfig};
+    use crate::trading::execution::intent_log::{IntentLog, IntentLogConfig, SignatureStatusSource, TradeIntent};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// The suite shares `faults()`; tests take turns
+    static SUITE: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+    struct AlwaysConfirmed;
+
+    #[async_trait::async_trait]
+    impl SignatureStatusSource for AlwaysConfirmed {
+        async fn status(&self, _signature: &str) -> anyhow::Result<Option<std::result::Result<(), String>>> {
+            Ok(Some(Ok(())))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dropped_rpc_calls_open_the_circuit() {
+        let _turn = SUITE.lock().await;
+        faults().set_plan(FaultPlan { rpc_drop_rate: 1.0, rpc_providers: vec!["chaos-rpc".to_string()], ..Default::default() }).unwrap();
+
+        let breaker = CircuitBreaker::new("chaos-rpc", CircuitBreakerConfig::default());
+        for _ in 0..20 {
+            if !breaker.allow_request() {
+                break;
+            }
+            breaker.record(&faults().rpc_call("chaos-rpc", "getSlot"));
+        }
+        assert!(breaker.is_open(), "circuit should open under 100% drops");
+        assert!(faults().rpc_call("other-provider", "getSlot").is_ok());
+        faults().clear();
+    }
+
+    #[tokio::test]
+    async fn test_delayed_confirmations_hold_trading_until_reconciled() {
+        let _turn = SUITE.lock().await;
+        faults().set_plan(FaultPlan { confirmation_delay_secs: 3600, delayed_wallets: vec!["chaos-wallet".to_string()], ..Default::default() }).unwrap();
+
+        let log = IntentLog::open(IntentLogConfig::default()).unwrap();
+        // Left over from "before the crash"
+        let mut intent = TradeIntent::new("chaos-1", "chaos-wallet", vec!["SOL".to_string(), "USDC".to_string()], 1_000);
+        intent.created_at = Utc::now() - ChronoDuration::minutes(5);
+        log.begin(intent).unwrap();
+        log.mark_signed("chaos-1", "sig-chaos-1").unwrap();
+
+        let report = log.reconcile(&AlwaysConfirmed).await;
+        assert_eq!(report.unresolved, 1);
+        assert!(!log.is_clear(), "trading must stay held while the fill is unconfirmed");
+
+        faults().clear();
+        let report = log.reconcile(&AlwaysConfirmed).await;
+        assert_eq!(report.completed, 1);
+        assert!(log.is_clear());
+    }
+
+    #[tokio::test]
+    async fn test_stale_prices_read_as_expired_by_age_checks() {
+        let _turn = SUITE.lock().await;
+        faults().set_plan(FaultPlan { stale_price_secs: 300, stale_tokens: vec!["CHAOS".to_string()], ..Default::default() }).unwrap();
+
+        let cache = InMemoryPriceCache::new();
+        cache.put("CHAOS", CachedPrice::new(1.0, "test"), Duration::from_secs(60)).await.unwrap();
+        let price = cache.get("CHAOS").await.unwrap().unwrap();
+        assert!(price.age() >= Duration::from_secs(300));
+        faults().clear();
+        assert!(cache.get("CHAOS").await.unwrap().unwrap().age() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_killed_task_is_restarted_by_watchdog() {
+        let _turn = SUITE.lock().await;
+        let watchdog = TaskWatchdog::new(WatchdogConfig::default());
+        let factory: TaskFactory = Arc::new(|_heartbeat: HeartbeatHandle| tokio::spawn(std::future::pending::<()>()));
+        watchdog.register("chaos-task", None, factory).await;
+
+        faults().kill_task("chaos-task").unwrap();
+        assert_eq!(watchdog.check().await, vec!["chaos-task".to_string()]);
+        let health = watchdog.get_task_health().await;
+        assert_eq!(health[0].restarts, 1);
+        assert_eq!(health[0].liveness, TaskLiveness::Healthy);
+        // One kill, one restart
+        assert!(watchdog.check().await.is_empty());
+        faults().clear();
+    }
+}
@@ -2,6 +2,7 @@
 //! Centraliza todas las credenciales y URLs de servicios externos
 //! TODAS LAS CREDENCIALES SE CARGAN DESDE config.json - NO HAY HARDCODING
 
+use tracing::{info, warn};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
@@ -98,8 +99,8 @@ impl Default for ApiCredentials {
     fn default() -> Self {
         // Intentar cargar desde config.json, usar valores por defecto si falla
         Self::load_from_file("config.json").unwrap_or_else(|e| {
-            eprintln!("⚠️ Error cargando config.json: {e}");
-            eprintln!("📁 Usando configuración por defecto (NO RECOMENDADO para producción)");
+            warn!("⚠️ Error cargando config.json: {e}");
+            warn!("📁 Usando configuración por defecto (NO RECOMENDADO para producción)");
             Self::create_default_fallback()
         })
     }
@@ -114,7 +115,7 @@ impl ApiCredentials {
         let config: ConfigFile = serde_json::from_str(&config_content)
             .with_context(|| "Error parseando config.json - verificar sintaxis JSON")?;
         
-        println!("✅ Configuración cargada exitosamente desde: {:?}", path.as_ref());
+        info!("✅ Configuración cargada exitosamente desde: {:?}", path.as_ref());
         
         Ok(Self {
             helius_api_key: config.api_credentials.helius.api_key,
@@ -147,9 +148,8 @@ impl ApiCredentials {
         timeouts.insert("dexscreener".to_string(), 12);
         timeouts.insert("pyth".to_string(), 10);
         
+        // Solo stablecoins: SOL/ETH/BTC salen de FiatRateService, nunca de un valor fijo
         let mut fallback_prices = HashMap::new();
-        fallback_prices.insert("SOL".to_string(), 180.0);
-        fallback_prices.insert("ETH".to_string(), 3200.0);
         fallback_prices.insert("USDC".to_string(), 1.0);
         fallback_prices.insert("USDT".to_string(), 1.0);
 
@@ -176,9 +176,9 @@ impl ApiCredentials {
             },
         }
     }
-    /// Obtener precio de fallback desde configuración
-    pub fn get_fallback_price(&self, token: &str) -> f64 {
-        self.fallback_prices.get(token).copied().unwrap_or(1.0)
+    /// Obtener precio de fallback desde configuración (None si el token no está configurado)
+    pub fn get_fallback_price(&self, token: &str) -> Option<f64> {
+        self.fallback_prices.get(token).copied()
     }
     
     /// Obtener configuración de trading
@@ -230,7 +230,7 @@ impl ApiCredentials {
     pub fn reload_from_file<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
         let new_config = Self::load_from_file(path)?;
         *self = new_config;
-        println!("🔄 Configuración recargada exitosamente");
+        info!("🔄 Configuración recargada exitosamente");
         Ok(())
     }
 }
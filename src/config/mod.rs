@@ -3,6 +3,7 @@
 pub mod api_credentials;
 pub mod enterprise;
 pub mod network;
+pub mod watchlists;
 
 use serde::{Deserialize, Serialize};
 use std::{path::Path, collections::HashMap};
@@ -11,6 +12,7 @@ pub use api_credentials::{ApiCredentials, WebSocketConfig};
 pub use enterprise::{EnterpriseConfig, SolanaConfig as EnterpriseSolanaConfig, 
                     ApiConfig as EnterpriseApiConfig, TradingConfig as EnterpriseTradingConfig};
 pub use network::{NetworkConfig, TokenInfo, ProgramIds};
+pub use watchlists::{Watchlist, WatchlistsConfig, WatchlistRegistry, DEFAULT_WATCHLISTS_PATH};
 
 /// Simple configuration alias for backward compatibility
 pub type Config = SniperForgeConfig;
@@ -0,0 +1,297 @@
+//! Named watchlists
+//!
+//! Which tokens get monitored used to be spelled out wherever they were
+//! needed. Watchlists name those sets instead ("bluechips", "new-listings",
+//! "stables"), give each its own refresh interval and bind it to the
+//! strategies that consume it. A strategy asks for the tokens bound to it and
+//! for the ones whose list is due for a refresh.
+//!
+//! Watchlists are read from `config/watchlists.json` and can be changed at
+//! runtime through the control API; changes are written back to the file.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::types::{Result, SniperForgeError};
+
+/// Default location of the watchlist file
+pub const DEFAULT_WATCHLISTS_PATH: &str = "config/watchlists.json";
+
+/// A named set of tokens and the strategies that monitor it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Watchlist {
+    pub name: String,
+    /// Symbols or mint addresses
+    #[serde(default)]
+    pub tokens: Vec<String>,
+    pub refresh_interval_secs: u64,
+    /// Strategies bound to this list (e.g. `sentiment`, `arbitrage`, `liquidity_sniper`)
+    #[serde(default)]
+    pub strategies: Vec<String>,
+    #[serde(default = "enabled_default")]
+    pub enabled: bool,
+}
+
+fn enabled_default() -> bool {
+    true
+}
+
+impl Watchlist {
+    pub fn new(name: &str, tokens: &[&str], refresh_interval_secs: u64, strategies: &[&str]) -> Self {
+        Self {
+            name: name.to_string(),
+            tokens: tokens.iter().map(|t| t.to_string()).collect(),
+            refresh_interval_secs,
+            strategies: strategies.iter().map(|s| s.to_string()).collect(),
+            enabled: true,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(SniperForgeError::Config("Watchlist name must not be empty".to_string()));
+        }
+        if self.refresh_interval_secs == 0 {
+            return Err(SniperForgeError::Config(format!("Watchlist '{}' needs a refresh interval above zero", self.name)));
+        }
+        Ok(())
+    }
+
+    fn binds(&self, strategy: &str) -> bool {
+        self.enabled && self.strategies.iter().any(|s| s == strategy)
+    }
+}
+
+/// Watchlist file contents
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchlistsConfig {
+    pub watchlists: Vec<Watchlist>,
+}
+
+impl Default for WatchlistsConfig {
+    fn default() -> Self {
+        Self {
+            watchlists: vec![
+                Watchlist::new("bluechips", &["SOL", "BTC", "ETH"], 60, &["sentiment", "arbitrage"]),
+                Watchlist::new("stables", &["USDC", "USDT"], 30, &["stablecoin_monitor"]),
+                Watchlist::new("new-listings", &[], 15, &["liquidity_sniper"]),
+            ],
+        }
+    }
+}
+
+impl WatchlistsConfig {
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| SniperForgeError::Config(format!("Failed to read watchlists: {}", e)))?;
+        let config: Self = serde_json::from_str(&content)
+            .map_err(|e| SniperForgeError::Config(format!("Failed to parse watchlists: {}", e)))?;
+        for watchlist in &config.watchlists {
+            watchlist.validate()?;
+        }
+        Ok(config)
+    }
+
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| SniperForgeError::Config(format!("Failed to serialize watchlists: {}", e)))?;
+        std::fs::write(path, content)
+            .map_err(|e| SniperForgeError::Config(format!("Failed to write watchlists: {}", e)))
+    }
+}
+
+/// Runtime watchlists, shared by strategies and the control API
+#[derive(Debug)]
+pub struct WatchlistRegistry {
+    watchlists: RwLock<BTreeMap<String, Watchlist>>,
+    /// Last refresh per (watchlist, strategy)
+    refreshed: RwLock<HashMap<(String, String), DateTime<Utc>>>,
+    path: Option<PathBuf>,
+}
+
+impl WatchlistRegistry {
+    /// In-memory registry (changes are not persisted)
+    pub fn new(config: WatchlistsConfig) -> Self {
+        Self {
+            watchlists: RwLock::new(config.watchlists.into_iter().map(|w| (w.name.clone(), w)).collect()),
+            refreshed: RwLock::new(HashMap::new()),
+            path: None,
+        }
+    }
+
+    /// Registry backed by `path`; the defaults are used when the file is missing or invalid
+    pub fn load<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref();
+        let config = if path.exists() {
+            WatchlistsConfig::load_from_file(path).unwrap_or_else(|e| {
+                warn!("⚠️ {} - using default watchlists", e);
+                WatchlistsConfig::default()
+            })
+        } else {
+            WatchlistsConfig::default()
+        };
+        info!("👀 {} watchlists loaded", config.watchlists.len());
+        Self { path: Some(path.to_path_buf()), ..Self::new(config) }
+    }
+
+    pub fn list(&self) -> Vec<Watchlist> {
+        self.watchlists.read().values().cloned().collect()
+    }
+
+    pub fn get(&self, name: &str) -> Option<Watchlist> {
+        self.watchlists.read().get(name).cloned()
+    }
+
+    /// Create or replace a watchlist
+    pub fn upsert(&self, watchlist: Watchlist) -> Result<Watchlist> {
+        watchlist.validate()?;
+        self.watchlists.write().insert(watchlist.name.clone(), watchlist.clone());
+        self.refreshed.write().retain(|(name, _), _| *name != watchlist.name);
+        self.persist()?;
+        info!("👀 Watchlist '{}' saved ({} tokens, bound to {:?})", watchlist.name, watchlist.tokens.len(), watchlist.strategies);
+        Ok(watchlist)
+    }
+
+    pub fn remove(&self, name: &str) -> Result<Watchlist> {
+        let removed = self.watchlists.write().remove(name)
+            .ok_or_else(|| SniperForgeError::Config(format!("Watchlist '{}' not found", name)))?;
+        self.refreshed.write().retain(|(list, _), _| list != name);
+        self.persist()?;
+        info!("👀 Watchlist '{}' removed", name);
+        Ok(removed)
+    }
+
+    /// Apply `change` to one watchlist and persist the result
+    pub fn update(&self, name: &str, change: impl FnOnce(&mut Watchlist)) -> Result<Watchlist> {
+        let updated = {
+            let mut watchlists = self.watchlists.write();
+            let watchlist = watchlists.get_mut(name)
+                .ok_or_else(|| SniperForgeError::Config(format!("Watchlist '{}' not found", name)))?;
+            let mut candidate = watchlist.clone();
+            change(&mut candidate);
+            candidate.name = name.to_string();
+            candidate.validate()?;
+            *watchlist = candidate.clone();
+            candidate
+        };
+        self.persist()?;
+        Ok(updated)
+    }
+
+    pub fn add_tokens(&self, name: &str, tokens: &[String]) -> Result<Watchlist> {
+        self.update(name, |watchlist| {
+            for token in tokens {
+                if !watchlist.tokens.contains(token) {
+                    watchlist.tokens.push(token.clone());
+                }
+            }
+        })
+    }
+
+    pub fn remove_tokens(&self, name: &str, tokens: &[String]) -> Result<Watchlist> {
+        self.update(name, |watchlist| watchlist.tokens.retain(|t| !tokens.contains(t)))
+    }
+
+    pub fn set_strategies(&self, name: &str, strategies: Vec<String>) -> Result<Watchlist> {
+        self.update(name, |watchlist| watchlist.strategies = strategies)
+    }
+
+    /// Tokens of every enabled watchlist bound to `strategy`, without duplicates
+    pub fn tokens_for_strategy(&self, strategy: &str) -> Vec<String> {
+        let mut tokens: Vec<String> = Vec::new();
+        for watchlist in self.watchlists.read().values().filter(|w| w.binds(strategy)) {
+            for token in &watchlist.tokens {
+                if !tokens.contains(token) {
+                    tokens.push(token.clone());
+                }
+            }
+        }
+        tokens
+    }
+
+    /// Tokens of `strategy`'s watchlists whose refresh interval has elapsed; marks them refreshed
+    pub fn take_due(&self, strategy: &str, now: DateTime<Utc>) -> Vec<String> {
+        let watchlists = self.watchlists.read();
+        let mut refreshed = self.refreshed.write();
+        let mut tokens: Vec<String> = Vec::new();
+        for watchlist in watchlists.values().filter(|w| w.binds(strategy)) {
+            let key = (watchlist.name.clone(), strategy.to_string());
+            let due = match refreshed.get(&key) {
+                Some(last) => now - *last >= Duration::seconds(watchlist.refresh_interval_secs as i64),
+                None => true,
+            };
+            if due {
+                refreshed.insert(key, now);
+                for token in &watchlist.tokens {
+                    if !tokens.contains(token) {
+                        tokens.push(token.clone());
+                    }
+                }
+            }
+        }
+        tokens
+    }
+
+    fn persist(&self) -> Result<()> {
+        match &self.path {
+            Some(path) => WatchlistsConfig { watchlists: self.list() }.save_to_file(path),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for WatchlistRegistry {
+    fn default() -> Self {
+        Self::new(WatchlistsConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strategy_bindings_and_refresh_intervals() {
+        let registry = WatchlistRegistry::default();
+        registry.upsert(Watchlist::new("memes", &["BONK", "SOL"], 300, &["sentiment"])).unwrap();
+
+        assert_eq!(registry.tokens_for_strategy("sentiment"), vec!["SOL", "BTC", "ETH", "BONK"]);
+        assert_eq!(registry.tokens_for_strategy("stablecoin_monitor"), vec!["USDC", "USDT"]);
+
+        let now = Utc::now();
+        assert_eq!(registry.take_due("sentiment", now).len(), 4);
+        assert!(registry.take_due("sentiment", now + Duration::seconds(30)).is_empty());
+        // Bluechips refresh every minute, memes every five
+        assert_eq!(registry.take_due("sentiment", now + Duration::seconds(61)), vec!["SOL", "BTC", "ETH"]);
+        // Refreshes are tracked per strategy
+        assert_eq!(registry.take_due("arbitrage", now).len(), 3);
+
+        registry.update("memes", |w| w.enabled = false).unwrap();
+        assert!(!registry.tokens_for_strategy("sentiment").contains(&"BONK".to_string()));
+    }
+
+    #[test]
+    fn test_runtime_changes_validate_and_persist() {
+        let path = std::env::temp_dir().join(format!("watchlists-{}.json", uuid::Uuid::new_v4()));
+        let registry = WatchlistRegistry::load(&path);
+        assert_eq!(registry.list().len(), 3);
+
+        registry.add_tokens("new-listings", &["MINT1".to_string(), "MINT1".to_string()]).unwrap();
+        registry.set_strategies("stables", vec!["depeg_guard".to_string()]).unwrap();
+        assert!(registry.update("stables", |w| w.refresh_interval_secs = 0).is_err());
+        assert!(registry.add_tokens("missing", &[]).is_err());
+        registry.remove("bluechips").unwrap();
+
+        let reloaded = WatchlistRegistry::load(&path);
+        assert_eq!(reloaded.list().len(), 2);
+        assert_eq!(reloaded.get("new-listings").unwrap().tokens, vec!["MINT1"]);
+        assert_eq!(reloaded.tokens_for_strategy("depeg_guard"), vec!["USDC", "USDT"]);
+        assert_eq!(reloaded.get("stables").unwrap().refresh_interval_secs, 30);
+        std::fs::remove_file(path).ok();
+    }
+}
@@ -3,14 +3,16 @@ use std::collections::HashMap;
 use uuid::Uuid;
 use std::sync::Arc;
 use anyhow::Result;
-use tracing::{info, warn};
+use tracing::{info, warn, Instrument};
 use serde::{Serialize, Deserialize};
 
 use crate::api::bot_interface::{BotInterface, BotType, BotStatus, BotMetrics, BotConfig};
 use crate::api::metrics_collector::{MetricsCollector, MetricsConfig};
 use crate::api::config_management::ConfigManager;
 use crate::api::state_persistence::{StatePersistenceManager, PersistedBotState, PersistedSystemMetrics};
+use crate::api::job_queue::{PersistentJobQueue, RetryPolicy, JobKind, JobQueueStats};
 use crate::bots::mock_arbitrage_bot::MockArbitrageBot;
+use super::bot_sandbox::{bot_log_router, BotSandboxManager, SandboxUsage};
 
 /// ✅ ENRIQUECIMIENTO: Wrapper for bot instances with enhanced metadata
 pub struct BotInstance {
@@ -96,9 +98,15 @@ pub struct BotController {
     /// State persistence manager
     persistence_manager: StatePersistenceManager,
     
+    /// Deferred/retryable operational jobs (survives restarts)
+    job_queue: Arc<PersistentJobQueue>,
+    
     /// Metrics collector
     metrics_collector: MetricsCollector,
     
+    /// Per-bot working directories, data namespaces and log files
+    sandboxes: BotSandboxManager,
+    
     /// Server start time for uptime calculation
     start_time: std::time::Instant,
 }
@@ -123,12 +131,18 @@ impl BotController {
         persistence_manager.initialize().await.map_err(|e| {
             anyhow::anyhow!("Failed to initialize persistence: {}", e)
         })?;
+        
+        let job_queue = PersistentJobQueue::open(persistence_path, RetryPolicy::default()).await.map_err(|e| {
+            anyhow::anyhow!("Failed to open job queue: {}", e)
+        })?;
 
         let mut controller = Self {
             bots: Arc::new(RwLock::new(HashMap::new())),
             config_manager: ConfigManager::new(config_path),
             persistence_manager,
+            job_queue: Arc::new(job_queue),
             metrics_collector: MetricsCollector::new(metrics_config),
+            sandboxes: BotSandboxManager::new(format!("{}/bots", persistence_path), bot_log_router().clone()),
             start_time: std::time::Instant::now(),
         };
 
@@ -205,6 +219,10 @@ impl BotController {
             }
         }
         
+        if let Err(e) = self.sandboxes.open(bot_id) {
+            warn!("⚠️ Failed to create sandbox for bot {}: {}", bot_id, e);
+        }
+        
         Ok(bot_id)
     }
     
@@ -227,11 +245,16 @@ impl BotController {
             return Err(anyhow::anyhow!("Pre-start configuration validation failed: {}", e));
         }
         
+        if let Err(e) = self.sandboxes.open(bot_id) {
+            warn!("⚠️ Failed to open sandbox for bot {}: {}", bot_id, e);
+        }
+        let span = self.sandboxes.span(bot_id);
+        
         let mut bots = self.bots.write().await;
         
         if let Some(bot_instance) = bots.get_mut(&bot_id) {
             // ✅ ARREGLO: Iniciar el bot y actualizar su estado
-            if let Err(e) = bot_instance.bot.start(config.clone()).await {
+            if let Err(e) = bot_instance.bot.start(config.clone()).instrument(span.clone()).await {
                 return Err(anyhow::anyhow!("Failed to start bot: {}", e));
             }
             
@@ -255,7 +278,7 @@ impl BotController {
                 tracing::warn!("⚠️ Failed to save bot configuration: {}", e);
             }
             
-            info!("🚀 Started bot: {} with validated configuration and metrics collection", bot_id);
+            span.in_scope(|| info!("🚀 Started bot: {} with validated configuration and metrics collection", bot_id));
             Ok(())
         } else {
             Err(anyhow::anyhow!("Bot not found: {}", bot_id))
@@ -268,7 +291,8 @@ impl BotController {
         
         if let Some(bot_instance) = bots.get_mut(&bot_id) {
             // ✅ ARREGLO: Detener el bot y actualizar su estado
-            if let Err(e) = bot_instance.bot.stop().await {
+            let span = self.sandboxes.span(bot_id);
+            if let Err(e) = bot_instance.bot.stop().instrument(span.clone()).await {
                 return Err(anyhow::anyhow!("Failed to stop bot: {}", e));
             }
             
@@ -286,7 +310,10 @@ impl BotController {
                 tracing::warn!("⚠️ Failed to record bot stop metrics: {}", e);
             }
             
-            info!("🛑 Stopped bot: {} with metrics collection", bot_id);
+            span.in_scope(|| info!("🛑 Stopped bot: {} with metrics collection", bot_id));
+            if let Err(e) = self.sandboxes.release(bot_id) {
+                warn!("⚠️ Failed to clear scratch files of bot {}: {}", bot_id, e);
+            }
             Ok(())
         } else {
             Err(anyhow::anyhow!("Bot not found: {}", bot_id))
@@ -365,7 +392,8 @@ impl BotController {
         
         let total_bots = bot_list.len();
         let running_bots = bot_list.iter().filter(|b| matches!(b.status, BotStatus::Running)).count();
-        let total_profit: f64 = bot_list.iter().map(|b| b.metrics.trading.total_pnl_usd).sum();
+        // Bots without a USD rate can't contribute a USD figure
+        let total_profit: f64 = bot_list.iter().filter_map(|b| b.metrics.trading.total_pnl_usd).sum();
         let total_trades: u64 = bot_list.iter().map(|b| b.metrics.trading.trades_executed).sum();
         
         // ✅ ENRIQUECIMIENTO: Combinar métricas del sistema con métricas del collector
@@ -527,6 +555,9 @@ impl BotController {
                 
                 // Add to registry
                 bots.insert(uuid, bot);
+                if let Err(e) = self.sandboxes.open(uuid) {
+                    warn!("⚠️ Failed to open sandbox for bot {}: {}", uuid, e);
+                }
                 
                 restored_count += 1;
                 info!("✅ Restored bot: {} ({:?})", uuid, persisted_bot.bot_type);
@@ -639,6 +670,22 @@ impl BotController {
         Ok(self.persistence_manager.get_metrics_history(hours).await)
     }
 
+    /// 💾 PERSISTENCE: Defer an operational action to the persistent job queue
+    pub async fn enqueue_job(&self, kind: JobKind) -> Result<Uuid> {
+        self.job_queue.enqueue(kind).await
+            .map_err(|e| anyhow::anyhow!("Failed to enqueue job: {}", e))
+    }
+
+    /// 💾 PERSISTENCE: Shared job queue (workers are attached by the runtime)
+    pub fn get_job_queue(&self) -> Arc<PersistentJobQueue> {
+        self.job_queue.clone()
+    }
+
+    /// 💾 PERSISTENCE: Job queue counters for CLI display
+    pub async fn get_job_queue_stats(&self) -> JobQueueStats {
+        self.job_queue.stats().await
+    }
+
     /// 💾 PERSISTENCE: Create backup of current system state
     pub async fn create_system_backup(&self) -> Result<String> {
         let backup_path = self.persistence_manager.create_backup().await
@@ -647,6 +694,24 @@ impl BotController {
         Ok(backup_path.to_string_lossy().to_string())
     }
 
+    /// Disk usage of one bot's sandbox, or of every sandbox
+    pub fn get_sandbox_usage(&self, bot_id: Option<Uuid>) -> Result<Vec<SandboxUsage>> {
+        match bot_id {
+            Some(bot_id) => self.sandboxes.get(bot_id)
+                .map(|sandbox| vec![sandbox.usage()])
+                .ok_or_else(|| anyhow::anyhow!("Bot has no sandbox: {}", bot_id)),
+            None => Ok(self.sandboxes.usage()),
+        }
+    }
+
+    /// Copy one bot's work files, data and logs into the sandbox archive
+    pub fn archive_bot_artifacts(&self, bot_id: Uuid) -> Result<String> {
+        let archive_path = self.sandboxes.archive(bot_id)
+            .map_err(|e| anyhow::anyhow!("Failed to archive bot {}: {}", bot_id, e))?;
+        info!("📦 Archived artifacts of bot {} to {}", bot_id, archive_path.display());
+        Ok(archive_path.to_string_lossy().to_string())
+    }
+
     /// 💾 PERSISTENCE: Get current system state for CLI display
     pub async fn get_system_state_summary(&self) -> Result<SystemStateSummary> {
         let state = self.persistence_manager.get_current_state().await;
@@ -0,0 +1,490 @@
+//! Per-bot sandboxed working directories and log segregation
+//!
+//! Bots running in one process used to share the service log and scratch
+//! space, so one bot's artifacts could not be inspected or archived on their
+//! own. Every bot now gets a sandbox under `state/bots/<bot_id>/`:
+//!
+//! - `work/`: working directory for files the bot produces
+//! - `tmp/`: scratch space, emptied when the bot stops
+//! - `data/<namespace>/`: the bot's namespaced key/value storage
+//! - `logs/`: daily-rotated `bot.<date>.log`
+//!
+//! Log segregation is a tracing layer: every event emitted inside a span
+//! carrying a `bot_id` field (see [`BotSandbox::span`]) is also written to
+//! that bot's log file, in addition to the service log. The controller runs
+//! bot lifecycle calls inside the span; tasks a bot spawns should be
+//! instrumented with it as well.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+use chrono::Utc;
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Subscriber};
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+use uuid::Uuid;
+
+/// Default parent directory of the sandboxes
+pub const DEFAULT_SANDBOX_ROOT: &str = "state/bots";
+
+/// Span field naming the bot an event belongs to
+pub const BOT_ID_FIELD: &str = "bot_id";
+
+/// Archived sandboxes go here, relative to the sandbox root
+const ARCHIVE_DIR: &str = "archive";
+
+/// One bot's directories
+#[derive(Debug, Clone)]
+pub struct BotSandbox {
+    bot_id: Uuid,
+    root: PathBuf,
+}
+
+impl BotSandbox {
+    /// Create (or reuse) the sandbox of `bot_id` under `base`
+    pub fn create(base: &Path, bot_id: Uuid) -> std::io::Result<Self> {
+        let sandbox = Self { bot_id, root: base.join(bot_id.to_string()) };
+        for dir in [sandbox.work_dir(), sandbox.tmp_dir(), sandbox.data_dir(), sandbox.log_dir()] {
+            std::fs::create_dir_all(dir)?;
+        }
+        Ok(sandbox)
+    }
+
+    pub fn bot_id(&self) -> Uuid {
+        self.bot_id
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn work_dir(&self) -> PathBuf {
+        self.root.join("work")
+    }
+
+    pub fn tmp_dir(&self) -> PathBuf {
+        self.root.join("tmp")
+    }
+
+    pub fn data_dir(&self) -> PathBuf {
+        self.root.join("data")
+    }
+
+    pub fn log_dir(&self) -> PathBuf {
+        self.root.join("logs")
+    }
+
+    /// Span routing events to this bot's log
+    pub fn span(&self) -> tracing::Span {
+        tracing::info_span!("bot", bot_id = %self.bot_id)
+    }
+
+    fn namespace_dir(&self, namespace: &str) -> std::io::Result<PathBuf> {
+        check_storage_name(namespace)?;
+        Ok(self.data_dir().join(namespace))
+    }
+
+    fn data_path(&self, namespace: &str, key: &str) -> std::io::Result<PathBuf> {
+        check_storage_name(key)?;
+        Ok(self.namespace_dir(namespace)?.join(key))
+    }
+
+    /// Store `value` under `namespace`/`key` (temp file, then rename)
+    pub fn write_data(&self, namespace: &str, key: &str, value: &[u8]) -> std::io::Result<()> {
+        let path = self.data_path(namespace, key)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp = self.tmp_dir().join(format!("{}.{}.{}", namespace, key, Uuid::new_v4()));
+        std::fs::write(&tmp, value)?;
+        std::fs::rename(&tmp, path)
+    }
+
+    /// Value stored under `namespace`/`key`, `None` when absent
+    pub fn read_data(&self, namespace: &str, key: &str) -> std::io::Result<Option<Vec<u8>>> {
+        match std::fs::read(self.data_path(namespace, key)?) {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Keys stored in `namespace`, sorted
+    pub fn list_data(&self, namespace: &str) -> std::io::Result<Vec<String>> {
+        let entries = match std::fs::read_dir(self.namespace_dir(namespace)?) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut keys = Vec::new();
+        for entry in entries {
+            keys.push(entry?.file_name().to_string_lossy().into_owned());
+        }
+        keys.sort();
+        Ok(keys)
+    }
+
+    /// Empty the scratch directory
+    pub fn clear_tmp(&self) -> std::io::Result<()> {
+        let tmp = self.tmp_dir();
+        if tmp.exists() {
+            std::fs::remove_dir_all(&tmp)?;
+        }
+        std::fs::create_dir_all(tmp)
+    }
+
+    /// Bytes used per area
+    pub fn usage(&self) -> SandboxUsage {
+        SandboxUsage {
+            bot_id: self.bot_id,
+            root: self.root.display().to_string(),
+            work_bytes: dir_size(&self.work_dir()),
+            tmp_bytes: dir_size(&self.tmp_dir()),
+            data_bytes: dir_size(&self.data_dir()),
+            log_bytes: dir_size(&self.log_dir()),
+        }
+    }
+}
+
+/// Disk usage of a sandbox
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SandboxUsage {
+    pub bot_id: Uuid,
+    pub root: String,
+    pub work_bytes: u64,
+    pub tmp_bytes: u64,
+    pub data_bytes: u64,
+    pub log_bytes: u64,
+}
+
+impl SandboxUsage {
+    pub fn total_bytes(&self) -> u64 {
+        self.work_bytes + self.tmp_bytes + self.data_bytes + self.log_bytes
+    }
+}
+
+/// Namespaces and keys are single path components
+fn check_storage_name(name: &str) -> std::io::Result<()> {
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+    if valid {
+        Ok(())
+    } else {
+        Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("invalid storage name `{}`", name)))
+    }
+}
+
+fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => dir_size(&entry.path()),
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+fn copy_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Bot id recorded on a span
+struct BotSpan(Uuid);
+
+#[derive(Default)]
+struct BotIdVisitor(Option<Uuid>);
+
+impl Visit for BotIdVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == BOT_ID_FIELD {
+            self.0 = Uuid::parse_str(value).ok();
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == BOT_ID_FIELD {
+            self.0 = Uuid::parse_str(&format!("{:?}", value)).ok();
+        }
+    }
+}
+
+/// Message and fields of an event as one log line
+#[derive(Default)]
+struct LineVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for LineVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={}", field.name(), value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        } else {
+            let _ = write!(self.fields, " {}={:?}", field.name(), value);
+        }
+    }
+}
+
+/// Tracing layer writing each bot's events to its own log file
+#[derive(Clone, Default)]
+pub struct BotLogRouter {
+    writers: Arc<RwLock<HashMap<Uuid, Arc<Mutex<RollingFileAppender>>>>>,
+}
+
+impl BotLogRouter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Route events of `bot_id` to daily files in `log_dir`
+    pub fn register(&self, bot_id: Uuid, log_dir: &Path) -> std::io::Result<()> {
+        let appender = RollingFileAppender::builder()
+            .rotation(Rotation::DAILY)
+            .filename_prefix("bot")
+            .filename_suffix("log")
+            .build(log_dir)
+            .map_err(std::io::Error::other)?;
+        self.writers.write().insert(bot_id, Arc::new(Mutex::new(appender)));
+        Ok(())
+    }
+
+    pub fn unregister(&self, bot_id: Uuid) {
+        self.writers.write().remove(&bot_id);
+    }
+
+    pub fn is_registered(&self, bot_id: Uuid) -> bool {
+        self.writers.read().contains_key(&bot_id)
+    }
+}
+
+impl<S> Layer<S> for BotLogRouter
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = BotIdVisitor::default();
+        attrs.record(&mut visitor);
+        if let (Some(bot_id), Some(span)) = (visitor.0, ctx.span(id)) {
+            span.extensions_mut().insert(BotSpan(bot_id));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        if self.writers.read().is_empty() {
+            return;
+        }
+        // The innermost bot span wins
+        let Some(bot_id) = ctx
+            .event_scope(event)
+            .and_then(|scope| scope.into_iter().find_map(|span| span.extensions().get::<BotSpan>().map(|bot| bot.0)))
+        else {
+            return;
+        };
+        let Some(writer) = self.writers.read().get(&bot_id).cloned() else {
+            return;
+        };
+        let mut visitor = LineVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        let line = format!(
+            "{} {:>5} {}: {}{}\n",
+            Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            metadata.level(),
+            metadata.target(),
+            visitor.message,
+            visitor.fields
+        );
+        let _ = writer.lock().write_all(line.as_bytes());
+    }
+}
+
+/// Process-wide router, installed in the service's subscriber
+pub fn bot_log_router() -> &'static BotLogRouter {
+    static ROUTER: OnceLock<BotLogRouter> = OnceLock::new();
+    ROUTER.get_or_init(BotLogRouter::new)
+}
+
+/// Sandboxes of every bot of a controller
+pub struct BotSandboxManager {
+    root: PathBuf,
+    router: BotLogRouter,
+    sandboxes: RwLock<HashMap<Uuid, Arc<BotSandbox>>>,
+}
+
+impl BotSandboxManager {
+    pub fn new(root: impl Into<PathBuf>, router: BotLogRouter) -> Self {
+        Self { root: root.into(), router, sandboxes: RwLock::new(HashMap::new()) }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Sandbox of `bot_id`, created and routed to its log on first use
+    pub fn open(&self, bot_id: Uuid) -> std::io::Result<Arc<BotSandbox>> {
+        if let Some(sandbox) = self.get(bot_id) {
+            return Ok(sandbox);
+        }
+        let sandbox = Arc::new(BotSandbox::create(&self.root, bot_id)?);
+        self.router.register(bot_id, &sandbox.log_dir())?;
+        self.sandboxes.write().insert(bot_id, sandbox.clone());
+        Ok(sandbox)
+    }
+
+    pub fn get(&self, bot_id: Uuid) -> Option<Arc<BotSandbox>> {
+        self.sandboxes.read().get(&bot_id).cloned()
+    }
+
+    /// Span for work done on behalf of `bot_id` (routes nowhere if it has no sandbox)
+    pub fn span(&self, bot_id: Uuid) -> tracing::Span {
+        match self.get(bot_id) {
+            Some(sandbox) => sandbox.span(),
+            None => tracing::info_span!("bot", bot_id = %bot_id),
+        }
+    }
+
+    /// The bot stopped: drop its scratch files, keep logs and data
+    pub fn release(&self, bot_id: Uuid) -> std::io::Result<()> {
+        match self.get(bot_id) {
+            Some(sandbox) => sandbox.clear_tmp(),
+            None => Ok(()),
+        }
+    }
+
+    /// The bot is gone: stop routing its logs and forget the sandbox (files stay on disk)
+    pub fn close(&self, bot_id: Uuid) {
+        self.router.unregister(bot_id);
+        self.sandboxes.write().remove(&bot_id);
+    }
+
+    pub fn usage(&self) -> Vec<SandboxUsage> {
+        let mut usage: Vec<SandboxUsage> = self.sandboxes.read().values().map(|sandbox| sandbox.usage()).collect();
+        usage.sort_by_key(|usage| usage.bot_id);
+        usage
+    }
+
+    /// Copy a bot's work, data and logs to `archive/<bot_id>-<timestamp>`
+    pub fn archive(&self, bot_id: Uuid) -> std::io::Result<PathBuf> {
+        let sandbox = self.get(bot_id).ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, format!("bot {} has no sandbox", bot_id))
+        })?;
+        let destination = self.root.join(ARCHIVE_DIR).join(format!("{}-{}", bot_id, Utc::now().format("%Y%m%dT%H%M%S%3f")));
+        for (from, name) in [(sandbox.work_dir(), "work"), (sandbox.data_dir(), "data"), (sandbox.log_dir(), "logs")] {
+            copy_dir(&from, &destination.join(name))?;
+        }
+        Ok(destination)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::prelude::*;
+
+    fn temp_root() -> PathBuf {
+        std::env::temp_dir().join(format!("bot-sandbox-{}", Uuid::new_v4()))
+    }
+
+    fn read_logs(sandbox: &BotSandbox) -> String {
+        std::fs::read_dir(sandbox.log_dir())
+            .unwrap()
+            .flatten()
+            .map(|entry| std::fs::read_to_string(entry.path()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_events_land_in_their_bots_log_only() {
+        let root = temp_root();
+        let router = BotLogRouter::new();
+        let manager = BotSandboxManager::new(&root, router.clone());
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let (sandbox_a, sandbox_b) = (manager.open(a).unwrap(), manager.open(b).unwrap());
+
+        let subscriber = tracing_subscriber::registry().with(router.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            manager.span(a).in_scope(|| {
+                tracing::info!(pair = "SOL/USDC", "quote refreshed");
+                // Nested spans keep routing to the enclosing bot
+                tracing::info_span!("execute").in_scope(|| tracing::warn!("slippage above limit"));
+            });
+            manager.span(b).in_scope(|| tracing::info!("bot b started"));
+            tracing::info!("service event");
+        });
+
+        let (logs_a, logs_b) = (read_logs(&sandbox_a), read_logs(&sandbox_b));
+        assert!(logs_a.contains("INFO"), "{}", logs_a);
+        assert!(logs_a.contains("quote refreshed pair=SOL/USDC"));
+        assert!(logs_a.contains("slippage above limit"));
+        assert!(!logs_a.contains("bot b started") && !logs_a.contains("service event"));
+        assert_eq!(logs_b.lines().count(), 1);
+        assert!(logs_b.contains("bot b started"));
+
+        manager.close(b);
+        assert!(!router.is_registered(b));
+        std::fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn test_namespaced_data_tmp_and_archive() {
+        let root = temp_root();
+        let manager = BotSandboxManager::new(&root, BotLogRouter::new());
+        let bot_id = Uuid::new_v4();
+        let sandbox = manager.open(bot_id).unwrap();
+        assert!(Arc::ptr_eq(&sandbox, &manager.open(bot_id).unwrap()));
+
+        sandbox.write_data("positions", "open.json", b"[]").unwrap();
+        sandbox.write_data("positions", "closed.json", b"[1]").unwrap();
+        assert_eq!(sandbox.read_data("positions", "open.json").unwrap().as_deref(), Some(&b"[]"[..]));
+        assert_eq!(sandbox.read_data("cache", "open.json").unwrap(), None);
+        assert_eq!(sandbox.list_data("positions").unwrap(), vec!["closed.json", "open.json"]);
+        assert!(sandbox.write_data("..", "escape", b"x").is_err());
+        assert!(sandbox.write_data("positions", "../escape", b"x").is_err());
+
+        std::fs::write(sandbox.tmp_dir().join("partial.bin"), [0u8; 16]).unwrap();
+        std::fs::write(sandbox.work_dir().join("report.csv"), "a,b\n").unwrap();
+        assert_eq!(manager.usage()[0].tmp_bytes, 16);
+        manager.release(bot_id).unwrap();
+        let usage = sandbox.usage();
+        assert_eq!((usage.tmp_bytes, usage.data_bytes, usage.work_bytes), (0, 5, 4));
+
+        let archived = manager.archive(bot_id).unwrap();
+        assert!(archived.starts_with(root.join(ARCHIVE_DIR)));
+        assert_eq!(std::fs::read_to_string(archived.join("work/report.csv")).unwrap(), "a,b\n");
+        assert!(archived.join("data/positions/open.json").exists());
+        assert!(manager.archive(Uuid::new_v4()).is_err());
+        std::fs::remove_dir_all(root).ok();
+    }
+}
@@ -0,0 +1,498 @@
+//! Multi-instance coordination
+//!
+//! When several SniperForge instances run against the same wallets, each
+//! strategy's opportunity space is split into hash partitions and every
+//! partition is owned by exactly one instance through a TTL lease (leader
+//! election per strategy/partition). Instances announce themselves in a
+//! shared membership group and only take their fair share of partitions, so
+//! work rebalances as instances join or die. Opportunity signatures are also
+//! claimed in the shared backend so two instances never execute the same
+//! opportunity, even if partition ownership is momentarily in flux.
+//!
+//! The backend is pluggable: an in-memory backend for single-process runs and
+//! tests, and a Redis backend (`redis` cargo feature) for real deployments.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// Environment variable selecting the coordination backend URL (`redis://...`)
+pub const COORDINATION_URL_ENV: &str = "SNIPERFORGE_COORDINATION_URL";
+
+/// Shared lock/membership store used for leader election
+#[async_trait]
+pub trait CoordinationBackend: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Take `key` for `owner` if nobody holds it; returns whether it was taken
+    async fn try_acquire(&self, key: &str, owner: &str, ttl: Duration) -> Result<bool>;
+
+    /// Extend a lease still held by `owner`; `false` if it was lost
+    async fn renew(&self, key: &str, owner: &str, ttl: Duration) -> Result<bool>;
+
+    /// Release a lease if (and only if) `owner` still holds it
+    async fn release(&self, key: &str, owner: &str) -> Result<()>;
+
+    /// Announce (or refresh) `member` in `group` for `ttl`
+    async fn announce_member(&self, group: &str, member: &str, ttl: Duration) -> Result<()>;
+
+    /// Remove `member` from `group`
+    async fn withdraw_member(&self, group: &str, member: &str) -> Result<()>;
+
+    /// Members of `group` whose announcement has not expired
+    async fn live_members(&self, group: &str) -> Result<Vec<String>>;
+}
+
+/// Process-local backend (single instance deployments and tests)
+#[derive(Debug, Default)]
+pub struct InMemoryCoordinationBackend {
+    leases: Mutex<HashMap<String, (String, Instant)>>,
+    members: Mutex<HashMap<String, HashMap<String, Instant>>>,
+}
+
+impl InMemoryCoordinationBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CoordinationBackend for InMemoryCoordinationBackend {
+    fn name(&self) -> &'static str {
+        "in-memory"
+    }
+
+    async fn try_acquire(&self, key: &str, owner: &str, ttl: Duration) -> Result<bool> {
+        let now = Instant::now();
+        let mut leases = self.leases.lock();
+        match leases.get(key) {
+            Some((_, expires)) if *expires > now => Ok(false),
+            _ => {
+                leases.insert(key.to_string(), (owner.to_string(), now + ttl));
+                Ok(true)
+            }
+        }
+    }
+
+    async fn renew(&self, key: &str, owner: &str, ttl: Duration) -> Result<bool> {
+        let now = Instant::now();
+        let mut leases = self.leases.lock();
+        match leases.get_mut(key) {
+            Some((holder, expires)) if holder == owner && *expires > now => {
+                *expires = now + ttl;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    async fn release(&self, key: &str, owner: &str) -> Result<()> {
+        let mut leases = self.leases.lock();
+        if leases.get(key).is_some_and(|(holder, _)| holder == owner) {
+            leases.remove(key);
+        }
+        Ok(())
+    }
+
+    async fn announce_member(&self, group: &str, member: &str, ttl: Duration) -> Result<()> {
+        self.members
+            .lock()
+            .entry(group.to_string())
+            .or_default()
+            .insert(member.to_string(), Instant::now() + ttl);
+        Ok(())
+    }
+
+    async fn withdraw_member(&self, group: &str, member: &str) -> Result<()> {
+        if let Some(members) = self.members.lock().get_mut(group) {
+            members.remove(member);
+        }
+        Ok(())
+    }
+
+    async fn live_members(&self, group: &str) -> Result<Vec<String>> {
+        let now = Instant::now();
+        let mut groups = self.members.lock();
+        let Some(members) = groups.get_mut(group) else {
+            return Ok(Vec::new());
+        };
+        members.retain(|_, expires| *expires > now);
+        Ok(members.keys().cloned().collect())
+    }
+}
+
+/// Redis backend: `SET NX PX` leases, owner-checked renew/release scripts and
+/// a sorted set (score = expiry) for membership
+#[cfg(feature = "redis")]
+pub struct RedisCoordinationBackend {
+    conn: redis::aio::MultiplexedConnection,
+}
+
+#[cfg(feature = "redis")]
+impl RedisCoordinationBackend {
+    const RENEW_SCRIPT: &'static str =
+        "if redis.call('GET', KEYS[1]) == ARGV[1] then return redis.call('PEXPIRE', KEYS[1], ARGV[2]) else return 0 end";
+    const RELEASE_SCRIPT: &'static str =
+        "if redis.call('GET', KEYS[1]) == ARGV[1] then return redis.call('DEL', KEYS[1]) else return 0 end";
+
+    pub async fn connect(url: &str) -> Result<Self> {
+        let client = redis::Client::open(url)?;
+        let conn = client.get_multiplexed_async_connection().await?;
+        Ok(Self { conn })
+    }
+}
+
+#[cfg(feature = "redis")]
+#[async_trait]
+impl CoordinationBackend for RedisCoordinationBackend {
+    fn name(&self) -> &'static str {
+        "redis"
+    }
+
+    async fn try_acquire(&self, key: &str, owner: &str, ttl: Duration) -> Result<bool> {
+        let mut conn = self.conn.clone();
+        let reply: Option<String> = redis::cmd("SET")
+            .arg(key)
+            .arg(owner)
+            .arg("NX")
+            .arg("PX")
+            .arg(ttl.as_millis() as u64)
+            .query_async(&mut conn)
+            .await?;
+        Ok(reply.is_some())
+    }
+
+    async fn renew(&self, key: &str, owner: &str, ttl: Duration) -> Result<bool> {
+        let mut conn = self.conn.clone();
+        let renewed: i64 = redis::Script::new(Self::RENEW_SCRIPT)
+            .key(key)
+            .arg(owner)
+            .arg(ttl.as_millis() as u64)
+            .invoke_async(&mut conn)
+            .await?;
+        Ok(renewed == 1)
+    }
+
+    async fn release(&self, key: &str, owner: &str) -> Result<()> {
+        let mut conn = self.conn.clone();
+        let _: i64 = redis::Script::new(Self::RELEASE_SCRIPT)
+            .key(key)
+            .arg(owner)
+            .invoke_async(&mut conn)
+            .await?;
+        Ok(())
+    }
+
+    async fn announce_member(&self, group: &str, member: &str, ttl: Duration) -> Result<()> {
+        let mut conn = self.conn.clone();
+        let expires_at = chrono::Utc::now().timestamp_millis() + ttl.as_millis() as i64;
+        let _: i64 = redis::cmd("ZADD").arg(group).arg(expires_at).arg(member).query_async(&mut conn).await?;
+        Ok(())
+    }
+
+    async fn withdraw_member(&self, group: &str, member: &str) -> Result<()> {
+        let mut conn = self.conn.clone();
+        let _: i64 = redis::cmd("ZREM").arg(group).arg(member).query_async(&mut conn).await?;
+        Ok(())
+    }
+
+    async fn live_members(&self, group: &str) -> Result<Vec<String>> {
+        let mut conn = self.conn.clone();
+        let now = chrono::Utc::now().timestamp_millis();
+        let _: i64 = redis::cmd("ZREMRANGEBYSCORE").arg(group).arg("-inf").arg(now).query_async(&mut conn).await?;
+        let members: Vec<String> = redis::cmd("ZRANGE").arg(group).arg(0).arg(-1).query_async(&mut conn).await?;
+        Ok(members)
+    }
+}
+
+/// Coordination settings
+#[derive(Debug, Clone)]
+pub struct CoordinationConfig {
+    /// Unique id of this instance
+    pub instance_id: String,
+    /// Hash partitions per strategy
+    pub partitions: u32,
+    /// Partition lease and membership TTL
+    pub lease_ttl: Duration,
+    /// How often leases are renewed and rebalanced (must be well under `lease_ttl`)
+    pub renew_interval: Duration,
+    /// How long an executed opportunity signature stays claimed cluster-wide
+    pub opportunity_claim_ttl: Duration,
+    /// Namespace for all coordination keys
+    pub key_prefix: String,
+}
+
+impl Default for CoordinationConfig {
+    fn default() -> Self {
+        let host = std::env::var("HOSTNAME").unwrap_or_else(|_| "sniperforge".to_string());
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+        Self {
+            instance_id: format!("{}-{}", host, &suffix[..8]),
+            partitions: 16,
+            lease_ttl: Duration::from_secs(15),
+            renew_interval: Duration::from_secs(5),
+            opportunity_claim_ttl: Duration::from_secs(30),
+            key_prefix: "sniperforge".to_string(),
+        }
+    }
+}
+
+/// Result of one election/rebalance round
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ElectionSummary {
+    pub live_members: usize,
+    pub fair_share: usize,
+    pub held: usize,
+    pub acquired: usize,
+    pub lost: usize,
+    pub released: usize,
+}
+
+/// Partition ownership and cluster-wide opportunity claims for one instance
+pub struct ClusterCoordinator {
+    config: CoordinationConfig,
+    backend: Arc<dyn CoordinationBackend>,
+    strategies: Vec<String>,
+    held: RwLock<BTreeSet<(String, u32)>>,
+}
+
+impl std::fmt::Debug for ClusterCoordinator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClusterCoordinator")
+            .field("instance_id", &self.config.instance_id)
+            .field("backend", &self.backend.name())
+            .field("strategies", &self.strategies)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ClusterCoordinator {
+    pub fn new(config: CoordinationConfig, backend: Arc<dyn CoordinationBackend>, strategies: &[&str]) -> Self {
+        Self {
+            config,
+            backend,
+            strategies: strategies.iter().map(|s| s.to_string()).collect(),
+            held: RwLock::new(BTreeSet::new()),
+        }
+    }
+
+    /// Build from `SNIPERFORGE_COORDINATION_URL`; `None` when unset (single instance)
+    pub async fn from_env(strategies: &[&str]) -> Result<Option<Self>> {
+        let Ok(url) = std::env::var(COORDINATION_URL_ENV) else {
+            return Ok(None);
+        };
+        let backend: Arc<dyn CoordinationBackend> = if url == "memory" {
+            Arc::new(InMemoryCoordinationBackend::new())
+        } else if url.starts_with("redis://") || url.starts_with("rediss://") {
+            Self::redis_backend(&url).await?
+        } else {
+            anyhow::bail!("Unsupported coordination backend URL: {}", url);
+        };
+        Ok(Some(Self::new(CoordinationConfig::default(), backend, strategies)))
+    }
+
+    #[cfg(feature = "redis")]
+    async fn redis_backend(url: &str) -> Result<Arc<dyn CoordinationBackend>> {
+        Ok(Arc::new(RedisCoordinationBackend::connect(url).await?))
+    }
+
+    #[cfg(not(feature = "redis"))]
+    async fn redis_backend(_url: &str) -> Result<Arc<dyn CoordinationBackend>> {
+        anyhow::bail!("Redis coordination requested but this build lacks the `redis` feature")
+    }
+
+    pub fn instance_id(&self) -> &str {
+        &self.config.instance_id
+    }
+
+    /// Stable partition for a routing key (FNV-1a, identical across builds and hosts)
+    pub fn partition_for(&self, partition_key: &str) -> u32 {
+        let hash = partition_key.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        (hash % u64::from(self.config.partitions.max(1))) as u32
+    }
+
+    /// Whether this instance currently leads the partition that owns `partition_key`
+    pub fn is_leader(&self, strategy: &str, partition_key: &str) -> bool {
+        let partition = self.partition_for(partition_key);
+        self.held.read().contains(&(strategy.to_string(), partition))
+    }
+
+    /// Claim an opportunity signature cluster-wide; `false` if another instance has it
+    pub async fn claim_opportunity(&self, signature: &str) -> Result<bool> {
+        let key = format!("{}:opportunity:{}", self.config.key_prefix, signature);
+        self.backend
+            .try_acquire(&key, &self.config.instance_id, self.config.opportunity_claim_ttl)
+            .await
+    }
+
+    /// Partitions currently led by this instance
+    pub fn held_partitions(&self) -> Vec<(String, u32)> {
+        self.held.read().iter().cloned().collect()
+    }
+
+    fn lease_key(&self, strategy: &str, partition: u32) -> String {
+        format!("{}:lease:{}:{}", self.config.key_prefix, strategy, partition)
+    }
+
+    fn members_group(&self) -> String {
+        format!("{}:members", self.config.key_prefix)
+    }
+
+    /// Renew held leases, shed partitions above the fair share and take free ones
+    pub async fn run_election_round(&self) -> Result<ElectionSummary> {
+        let owner = self.config.instance_id.clone();
+        let ttl = self.config.lease_ttl;
+        let group = self.members_group();
+
+        self.backend.announce_member(&group, &owner, ttl).await?;
+        let mut members = self.backend.live_members(&group).await?;
+        if !members.contains(&owner) {
+            members.push(owner.clone());
+        }
+        members.sort();
+
+        let total = self.strategies.len() * self.config.partitions as usize;
+        let fair_share = total.div_ceil(members.len().max(1));
+        let mut summary = ElectionSummary { live_members: members.len(), fair_share, ..Default::default() };
+
+        let mut held = self.held_partitions();
+        let mut kept = Vec::with_capacity(held.len());
+        for (strategy, partition) in held.drain(..) {
+            if self.backend.renew(&self.lease_key(&strategy, partition), &owner, ttl).await? {
+                kept.push((strategy, partition));
+            } else {
+                warn!("🗳️ Lost leadership of {}#{}", strategy, partition);
+                summary.lost += 1;
+            }
+        }
+
+        while kept.len() > fair_share {
+            if let Some((strategy, partition)) = kept.pop() {
+                self.backend.release(&self.lease_key(&strategy, partition), &owner).await?;
+                summary.released += 1;
+            }
+        }
+
+        if kept.len() < fair_share {
+            // Start scanning at a per-instance offset so instances don't race for the same leases
+            let index = members.iter().position(|m| *m == owner).unwrap_or(0);
+            let candidates: Vec<(String, u32)> = self
+                .strategies
+                .iter()
+                .flat_map(|s| (0..self.config.partitions).map(move |p| (s.clone(), p)))
+                .collect();
+            let offset = index * fair_share;
+            for i in 0..candidates.len() {
+                if kept.len() >= fair_share {
+                    break;
+                }
+                let (strategy, partition) = &candidates[(offset + i) % candidates.len()];
+                if kept.iter().any(|(s, p)| s == strategy && p == partition) {
+                    continue;
+                }
+                if self.backend.try_acquire(&self.lease_key(strategy, *partition), &owner, ttl).await? {
+                    kept.push((strategy.clone(), *partition));
+                    summary.acquired += 1;
+                }
+            }
+        }
+
+        summary.held = kept.len();
+        *self.held.write() = kept.into_iter().collect();
+        debug!("🗳️ Election round: {:?}", summary);
+        Ok(summary)
+    }
+
+    /// Run election rounds in the background
+    pub fn start(self: Arc<Self>) -> JoinHandle<()> {
+        info!("🗳️ Cluster coordination active: instance {} via {} backend ({} strategies × {} partitions)",
+              self.config.instance_id, self.backend.name(), self.strategies.len(), self.config.partitions);
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = self.run_election_round().await {
+                    // Fail safe: without a reachable backend we cannot prove leadership
+                    warn!("⚠️ Coordination backend unavailable, standing down: {}", e);
+                    self.held.write().clear();
+                }
+                tokio::time::sleep(self.config.renew_interval).await;
+            }
+        })
+    }
+
+    /// Release all leases and leave the membership group
+    pub async fn shutdown(&self) -> Result<()> {
+        let owner = &self.config.instance_id;
+        let held: Vec<_> = std::mem::take(&mut *self.held.write()).into_iter().collect();
+        for (strategy, partition) in held {
+            self.backend.release(&self.lease_key(&strategy, partition), owner).await?;
+        }
+        self.backend.withdraw_member(&self.members_group(), owner).await?;
+        info!("🗳️ Instance {} left the cluster", owner);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coordinator(id: &str, backend: Arc<dyn CoordinationBackend>) -> ClusterCoordinator {
+        let config = CoordinationConfig { instance_id: id.to_string(), partitions: 4, ..Default::default() };
+        ClusterCoordinator::new(config, backend, &["arbitrage", "triangular"])
+    }
+
+    #[tokio::test]
+    async fn test_partitions_split_without_overlap() {
+        let backend: Arc<dyn CoordinationBackend> = Arc::new(InMemoryCoordinationBackend::new());
+        let a = coordinator("a", backend.clone());
+        let b = coordinator("b", backend.clone());
+
+        a.run_election_round().await.unwrap();
+        b.run_election_round().await.unwrap();
+        // `a` sheds its surplus once it sees `b`, then `b` picks it up
+        a.run_election_round().await.unwrap();
+        b.run_election_round().await.unwrap();
+
+        let held_a: BTreeSet<_> = a.held_partitions().into_iter().collect();
+        let held_b: BTreeSet<_> = b.held_partitions().into_iter().collect();
+        assert_eq!(held_a.len(), 4);
+        assert_eq!(held_b.len(), 4);
+        assert!(held_a.is_disjoint(&held_b));
+
+        let key = "SOL@orca>USDC@raydium";
+        assert_ne!(a.is_leader("arbitrage", key), b.is_leader("arbitrage", key));
+    }
+
+    #[tokio::test]
+    async fn test_departed_instance_partitions_are_taken_over() {
+        let backend: Arc<dyn CoordinationBackend> = Arc::new(InMemoryCoordinationBackend::new());
+        let a = coordinator("a", backend.clone());
+        let b = coordinator("b", backend.clone());
+        a.run_election_round().await.unwrap();
+        b.run_election_round().await.unwrap();
+
+        a.shutdown().await.unwrap();
+        let summary = b.run_election_round().await.unwrap();
+        assert_eq!(summary.live_members, 1);
+        assert_eq!(summary.held, 8);
+    }
+
+    #[tokio::test]
+    async fn test_opportunity_claimed_once_cluster_wide() {
+        let backend: Arc<dyn CoordinationBackend> = Arc::new(InMemoryCoordinationBackend::new());
+        let a = coordinator("a", backend.clone());
+        let b = coordinator("b", backend);
+
+        assert!(a.claim_opportunity("SOL@orca>USDC@raydium").await.unwrap());
+        assert!(!b.claim_opportunity("SOL@orca>USDC@raydium").await.unwrap());
+    }
+}
@@ -165,7 +165,7 @@ impl DesiredStateReconciler {
         let desired_state = match yaml_manager.load_desired_state().await {
             Ok(state) => Arc::new(RwLock::new(state)),
             Err(e) => {
-                warn!("⚠️ No se pudo cargar estado deseado inicial: {}. Usando configuración vacía.", e);
+                warn!("⚠️ Could not load initial desired state: {}. Using an empty configuration.", e);
                 Arc::new(RwLock::new(DesiredStateConfig::new()))
             }
         };
@@ -198,7 +198,7 @@ impl DesiredStateReconciler {
             })?
         };
         
-        info!("🎯 Iniciando bucle de reconciliación de estado deseado...");
+        info!("🎯 Starting desired state reconciliation loop...");
         
         tokio::spawn(async move {
             // Leer configuración de reconciliación
@@ -208,7 +208,7 @@ impl DesiredStateReconciler {
             };
             
             if !reconciliation_config.enabled {
-                info!("⏸️ Reconciliación automática deshabilitada");
+                info!("⏸️ Automatic reconciliation disabled");
                 return;
             }
             
@@ -219,11 +219,11 @@ impl DesiredStateReconciler {
                     // Reconciliación periódica automática
                     _ = reconciliation_interval.tick() => {
                         if *should_stop.read().await {
-                            info!("🛑 Deteniendo bucle de reconciliación");
+                            info!("🛑 Stopping reconciliation loop");
                             break;
                         }
                         
-                        debug!("⏰ Ejecutando reconciliación automática...");
+                        debug!("⏰ Running automatic reconciliation...");
                         let _ = Self::execute_reconciliation_cycle(
                             &desired_state,
                             &bot_controller,
@@ -237,7 +237,7 @@ impl DesiredStateReconciler {
                     Some(event) = reconciliation_rx.recv() => {
                         match event {
                             ReconciliationEvent::TriggerReconciliation => {
-                                info!("🔄 Ejecutando reconciliación manual...");
+                                info!("🔄 Running manual reconciliation...");
                                 let _ = Self::execute_reconciliation_cycle(
                                     &desired_state,
                                     &bot_controller,
@@ -248,9 +248,9 @@ impl DesiredStateReconciler {
                             }
                             
                             ReconciliationEvent::DesiredStateChanged => {
-                                info!("📝 Estado deseado cambió, recargando configuración...");
+                                info!("📝 Desired state changed, reloading configuration...");
                                 if let Err(e) = Self::reload_desired_state(&desired_state, &yaml_manager).await {
-                                    error!("❌ Error recargando estado deseado: {}", e);
+                                    error!("❌ Error reloading desired state: {}", e);
                                 } else {
                                     let _ = Self::execute_reconciliation_cycle(
                                         &desired_state,
@@ -263,7 +263,7 @@ impl DesiredStateReconciler {
                             }
                             
                             ReconciliationEvent::BotStateChanged { bot_id, new_status } => {
-                                debug!("🤖 Bot {} cambió a estado: {:?}", bot_id, new_status);
+                                debug!("🤖 Bot {} changed state: {:?}", bot_id, new_status);
                                 // Verificar si necesitamos reconciliación inmediata
                                 let needs_reconciliation = Self::check_if_reconciliation_needed(
                                     &desired_state,
@@ -282,11 +282,11 @@ impl DesiredStateReconciler {
                             }
                             
                             ReconciliationEvent::ReconciliationError { error } => {
-                                error!("❌ Error en reconciliación: {}", error);
+                                error!("❌ Reconciliation error: {}", error);
                             }
                             
                             ReconciliationEvent::ReconciliationCompleted { duration_ms, actions_taken } => {
-                                info!("✅ Reconciliación completada en {}ms, {} acciones ejecutadas", 
+                                info!("✅ Reconciliation completed in {}ms, {} actions taken", 
                                      duration_ms, actions_taken);
                             }
                         }
@@ -310,7 +310,7 @@ impl DesiredStateReconciler {
         
         // 1. 📖 Recargar estado deseado desde YAML (hot-reload)
         if let Err(e) = Self::reload_desired_state(desired_state, yaml_manager).await {
-            warn!("⚠️ No se pudo recargar estado deseado: {}", e);
+            warn!("⚠️ Could not reload desired state: {}", e);
         }
         
         // 2. 🔍 Analizar diferencias entre estado actual y deseado
@@ -330,7 +330,7 @@ impl DesiredStateReconciler {
         Self::update_reconciliation_stats(stats, &execution_result, start_time).await;
         
         let duration = start_time.elapsed();
-        info!("🎯 Ciclo de reconciliación completado en {:?}", duration);
+        info!("🎯 Reconciliation cycle completed in {:?}", duration);
         
         Ok(execution_result)
     }
@@ -344,11 +344,11 @@ impl DesiredStateReconciler {
             Ok(new_state) => {
                 let mut state_guard = desired_state.write().await;
                 *state_guard = new_state;
-                debug!("✅ Estado deseado recargado desde YAML");
+                debug!("✅ Desired state reloaded from YAML");
                 Ok(())
             }
             Err(e) => {
-                error!("❌ Error recargando estado deseado: {}", e);
+                error!("❌ Error reloading desired state: {}", e);
                 Err(e.into())
             }
         }
@@ -429,12 +429,12 @@ impl DesiredStateReconciler {
         }
         
         if analysis.drift_detected {
-            info!("🔍 Drift detectado: {} bots faltantes, {} bots extra, {} diferencias de estado", 
+            info!("🔍 Drift detected: {} missing bots, {} extra bots, {} status mismatches", 
                  analysis.missing_bots.len(), 
                  analysis.extra_bots.len(), 
                  analysis.status_mismatches.len());
         } else {
-            debug!("✅ No se detectó drift en el estado");
+            debug!("✅ No state drift detected");
         }
         
         Ok(analysis)
@@ -499,7 +499,7 @@ impl DesiredStateReconciler {
             }
         }
         
-        info!("📋 Plan de reconciliación generado: {} acciones", actions.len());
+        info!("📋 Reconciliation plan generated: {} actions", actions.len());
         Ok(actions)
     }
     
@@ -519,25 +519,25 @@ impl DesiredStateReconciler {
         let mut successful_actions = Vec::new();
         let mut failed_actions = Vec::new();
         
-        info!("⚡ Ejecutando plan de reconciliación con {} acciones", plan.len());
+        info!("⚡ Executing reconciliation plan with {} actions", plan.len());
         
         for (i, action) in plan.iter().enumerate() {
-            info!("🔧 Ejecutando acción {}/{}: {:?}", i + 1, plan.len(), action);
+            info!("🔧 Executing action {}/{}: {:?}", i + 1, plan.len(), action);
             
             let result = Self::execute_single_action(action, bot_controller).await;
             
             match result {
                 Ok(_) => {
                     successful_actions.push(action.clone());
-                    info!("✅ Acción ejecutada exitosamente");
+                    info!("✅ Action executed successfully");
                 }
                 Err(e) => {
                     failed_actions.push((action.clone(), e.to_string()));
-                    error!("❌ Acción falló: {}", e);
+                    error!("❌ Action failed: {}", e);
                     
                     // Verificar si debemos continuar o abortar
                     if failed_actions.len() as u32 >= config.max_retries {
-                        warn!("⚠️ Máximo número de fallos alcanzado, abortando reconciliación");
+                        warn!("⚠️ Maximum failures reached, aborting reconciliation");
                         break;
                     }
                 }
@@ -548,7 +548,7 @@ impl DesiredStateReconciler {
         let drift_detected = !failed_actions.is_empty();
         
         let summary = format!(
-            "Reconciliación completada: {}/{} acciones exitosas en {:?}",
+            "Reconciliation completed: {}/{} actions succeeded in {:?}",
             successful_actions.len(),
             plan.len(),
             duration
@@ -571,38 +571,38 @@ impl DesiredStateReconciler {
         match action {
             ReconciliationAction::CreateBot { id, bot_type, config } => {
                 let bot_id = bot_controller.create_bot(bot_type.clone(), config.clone()).await?;
-                info!("🆕 Bot creado: {} -> {}", id, bot_id);
+                info!("🆕 Bot created: {} -> {}", id, bot_id);
                 Ok(())
             }
             
             ReconciliationAction::StartBot { bot_id, config } => {
                 bot_controller.start_bot(*bot_id, config.clone()).await?;
-                info!("🚀 Bot iniciado: {}", bot_id);
+                info!("🚀 Bot started: {}", bot_id);
                 Ok(())
             }
             
             ReconciliationAction::StopBot { bot_id } => {
                 bot_controller.stop_bot(*bot_id).await?;
-                info!("🛑 Bot detenido: {}", bot_id);
+                info!("🛑 Bot stopped: {}", bot_id);
                 Ok(())
             }
             
             ReconciliationAction::PauseBot { bot_id } => {
                 // Por ahora, pausar = detener
                 bot_controller.stop_bot(*bot_id).await?;
-                info!("⏸️ Bot pausado: {}", bot_id);
+                info!("⏸️ Bot paused: {}", bot_id);
                 Ok(())
             }
             
             ReconciliationAction::UpdateBotConfig { bot_id, new_config: _ } => {
                 // TODO: Implementar actualización de configuración
-                warn!("⚠️ Actualización de configuración no implementada aún para bot: {}", bot_id);
+                warn!("⚠️ Configuration update not implemented yet for bot: {}", bot_id);
                 Ok(())
             }
             
             ReconciliationAction::DeleteBot { bot_id } => {
                 // TODO: Implementar eliminación de bot
-                warn!("⚠️ Eliminación de bot no implementada aún para bot: {}", bot_id);
+                warn!("⚠️ Bot removal not implemented yet for bot: {}", bot_id);
                 Ok(())
             }
         }
@@ -660,7 +660,7 @@ impl DesiredStateReconciler {
     pub async fn stop(&self) {
         let mut should_stop = self.should_stop.write().await;
         *should_stop = true;
-        info!("🛑 Reconciliador de estado deseado detenido");
+        info!("🛑 Desired state reconciler stopped");
     }
 }
 
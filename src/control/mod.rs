@@ -1,6 +1,8 @@
 pub mod bot_controller;
 pub mod tcp_server;
 pub mod desired_state_reconciler;
+pub mod coordination;
+pub mod bot_sandbox;
 
 // Re-export main types
 pub use bot_controller::{BotController, BotSummary, SystemMetrics, SystemStateSummary, MassControlResult, SystemResourceStatus};
@@ -10,3 +12,12 @@ pub use desired_state_reconciler::{
     ReconciliationAction, ReconciliationResult, StateDriftAnalysis, 
     StatusMismatch, ConfigDrift
 };
+pub use coordination::{
+    ClusterCoordinator, CoordinationBackend, CoordinationConfig, ElectionSummary,
+    InMemoryCoordinationBackend, COORDINATION_URL_ENV
+};
+pub use bot_sandbox::{
+    bot_log_router, BotLogRouter, BotSandbox, BotSandboxManager, SandboxUsage, DEFAULT_SANDBOX_ROOT
+};
+#[cfg(feature = "redis")]
+pub use coordination::RedisCoordinationBackend;
@@ -7,10 +7,30 @@ use uuid::Uuid;
 use tracing::{info, error};
 
 use crate::api::{BotType, BotStatus, BotMetrics, BotConfig, PersistedSystemMetrics};
-use crate::control::{BotController, BotSummary, SystemMetrics, SystemStateSummary, MassControlResult, SystemResourceStatus};
+use crate::control::{BotController, BotSummary, SystemMetrics, SystemStateSummary, MassControlResult, SystemResourceStatus, SandboxUsage};
+use crate::trading::{CapitalWithdrawals, RiskManager, StrategyKillSwitch};
+#[cfg(feature = "cross-chain")]
+use crate::trading::BridgeTracker;
+use crate::analytics::{AnnotationTarget, TradeIndexer};
+use crate::security::DustConsolidator;
+use crate::monitoring::{DiagnosticsCollector, DiagnosticsConfig, DiagnosticsSummary, HealthRegistry};
+use crate::config::{Watchlist, WatchlistRegistry};
+use crate::chaos::{faults, FaultPlan};
+
+/// Stand-in when bridge tracking is compiled out; the protocol keeps its bridge commands
+#[cfg(not(feature = "cross-chain"))]
+enum BridgeTracker {}
 
 pub struct TcpControlServer {
     bot_controller: Arc<BotController>,
+    strategy_guard: Option<Arc<StrategyKillSwitch>>,
+    bridge_tracker: Option<Arc<BridgeTracker>>,
+    trade_indexer: Option<Arc<TradeIndexer>>,
+    dust_consolidator: Option<Arc<DustConsolidator>>,
+    health_registry: Option<Arc<HealthRegistry>>,
+    watchlists: Option<Arc<WatchlistRegistry>>,
+    risk_manager: Option<Arc<RiskManager>>,
+    capital_withdrawals: Option<Arc<CapitalWithdrawals>>,
     listener: TcpListener,
     port: u16,
 }
@@ -27,12 +47,55 @@ pub enum TcpCommand {
     GetSystemState,
     GetMetricsHistory { hours: u32 },
     CreateBackup,
+    /// Disk usage of a bot's sandbox (every bot when `bot_id` is `None`)
+    GetSandboxUsage { bot_id: Option<Uuid> },
+    /// Copy a bot's work files, data and logs into the sandbox archive
+    ArchiveBotArtifacts { bot_id: Uuid },
     ForceSave,
     StartAllBots,
     StopAllBots,
     GetResourceStatus,
     Ping,
     Shutdown,
+    ListSuspendedStrategies,
+    ReenableStrategy { strategy: String, operator: String },
+    ListBridgeTransfers,
+    /// Redeem on the target chain, or record `redeem_tx` if already redeemed by hand
+    RedeemBridgeTransfer { transfer_id: String, operator: String, redeem_tx: Option<String> },
+    RetryBridgeTransfer { transfer_id: String, operator: String },
+    /// Attach a manual note/tags to a trade or position
+    AnnotateTrade { target: AnnotationTarget, note: String, tags: Vec<String>, author: String },
+    /// Annotations on one target, or all of them
+    ListAnnotations { target: Option<AnnotationTarget> },
+    /// Trade journal (trades + annotations) as CSV
+    ExportTradeJournal { wallet: Option<String> },
+    /// Swap dust balances to SOL and close the accounts; `dry_run` only costs them
+    ConsolidateDust { dry_run: bool },
+    /// Composite health of every registered subsystem
+    GetHealth,
+    /// Package logs, redacted config, recent trades/intents and health into one archive
+    CollectDiagnostics { note: Option<String> },
+    /// Replace the armed fault plan (builds with the `chaos` feature only)
+    SetFaultPlan { plan: FaultPlan },
+    /// Have the watchdog kill a supervised task at its next check
+    KillTask { name: String },
+    ClearFaults,
+    GetFaults,
+    ListWatchlists,
+    /// Create or replace a watchlist
+    UpsertWatchlist { watchlist: Watchlist },
+    RemoveWatchlist { name: String },
+    AddWatchlistTokens { name: String, tokens: Vec<String> },
+    RemoveWatchlistTokens { name: String, tokens: Vec<String> },
+    /// Replace the strategies bound to a watchlist
+    BindWatchlistStrategies { name: String, strategies: Vec<String> },
+    /// Exposure headroom of one asset (symbol or mint), or of every capped asset
+    GetRiskHeadroom { asset: Option<String> },
+    ListWithdrawals,
+    /// Lock `amount` of `asset` in `wallet` and sweep it to `destination` once free
+    RequestWithdrawal { wallet: String, asset: String, amount: f64, destination: String },
+    ApproveWithdrawal { id: String, operator: String },
+    CancelWithdrawal { id: String },
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -47,6 +110,9 @@ pub enum TcpResponse {
     SystemState(SystemStateSummary),
     MetricsHistory(Vec<PersistedSystemMetrics>),
     BackupCreated(String),
+    SandboxUsage(Vec<SandboxUsage>),
+    BotArtifactsArchived { bot_id: Uuid, path: String },
+    DiagnosticsCollected(DiagnosticsSummary),
     MassControlResult(MassControlResult),
     ResourceStatus(SystemResourceStatus),
     Pong,
@@ -61,11 +127,68 @@ impl TcpControlServer {
         
         Ok(Self {
             bot_controller,
+            strategy_guard: None,
+            bridge_tracker: None,
+            trade_indexer: None,
+            dust_consolidator: None,
+            health_registry: None,
+            watchlists: None,
+            risk_manager: None,
+            capital_withdrawals: None,
             listener,
             port,
         })
     }
     
+    /// Expose strategy suspensions and manual re-enable
+    pub fn with_strategy_guard(mut self, strategy_guard: Arc<StrategyKillSwitch>) -> Self {
+        self.strategy_guard = Some(strategy_guard);
+        self
+    }
+    
+    /// Expose bridge transfers and manual redeem/retry
+    #[cfg(feature = "cross-chain")]
+    pub fn with_bridge_tracker(mut self, bridge_tracker: Arc<BridgeTracker>) -> Self {
+        self.bridge_tracker = Some(bridge_tracker);
+        self
+    }
+    
+    /// Expose the trade journal: annotations and exports
+    pub fn with_trade_indexer(mut self, trade_indexer: Arc<TradeIndexer>) -> Self {
+        self.trade_indexer = Some(trade_indexer);
+        self
+    }
+    
+    /// Expose dust consolidation of the hot wallet
+    pub fn with_dust_consolidator(mut self, dust_consolidator: Arc<DustConsolidator>) -> Self {
+        self.dust_consolidator = Some(dust_consolidator);
+        self
+    }
+    
+    /// Expose the composite subsystem health check
+    pub fn with_health_registry(mut self, health_registry: Arc<HealthRegistry>) -> Self {
+        self.health_registry = Some(health_registry);
+        self
+    }
+    
+    /// Manage named watchlists and their strategy bindings
+    pub fn with_watchlists(mut self, watchlists: Arc<WatchlistRegistry>) -> Self {
+        self.watchlists = Some(watchlists);
+        self
+    }
+    
+    /// Expose per-token exposure headroom
+    pub fn with_risk_manager(mut self, risk_manager: Arc<RiskManager>) -> Self {
+        self.risk_manager = Some(risk_manager);
+        self
+    }
+    
+    /// Request, approve, cancel and list capital withdrawals
+    pub fn with_capital_withdrawals(mut self, capital_withdrawals: Arc<CapitalWithdrawals>) -> Self {
+        self.capital_withdrawals = Some(capital_withdrawals);
+        self
+    }
+    
     pub async fn run(&self) -> Result<()> {
         info!("🚀 Starting TCP Control Server on port {}...", self.port);
         
@@ -75,8 +198,16 @@ impl TcpControlServer {
                     info!("📡 New TCP connection from: {}", addr);
                     
                     let controller = self.bot_controller.clone();
+                    let strategy_guard = self.strategy_guard.clone();
+                    let bridge_tracker = self.bridge_tracker.clone();
+                    let trade_indexer = self.trade_indexer.clone();
+                    let dust_consolidator = self.dust_consolidator.clone();
+                    let health_registry = self.health_registry.clone();
+                    let watchlists = self.watchlists.clone();
+                    let risk_manager = self.risk_manager.clone();
+                    let capital_withdrawals = self.capital_withdrawals.clone();
                     tokio::spawn(async move {
-                        if let Err(e) = Self::handle_connection(stream, controller).await {
+                        if let Err(e) = Self::handle_connection(stream, controller, strategy_guard, bridge_tracker, trade_indexer, dust_consolidator, health_registry, watchlists, risk_manager, capital_withdrawals).await {
                             error!("❌ TCP connection error: {}", e);
                         }
                     });
@@ -91,7 +222,15 @@ impl TcpControlServer {
     
     async fn handle_connection(
         mut stream: TcpStream, 
-        controller: Arc<BotController>
+        controller: Arc<BotController>,
+        strategy_guard: Option<Arc<StrategyKillSwitch>>,
+        bridge_tracker: Option<Arc<BridgeTracker>>,
+        trade_indexer: Option<Arc<TradeIndexer>>,
+        dust_consolidator: Option<Arc<DustConsolidator>>,
+        health_registry: Option<Arc<HealthRegistry>>,
+        watchlists: Option<Arc<WatchlistRegistry>>,
+        risk_manager: Option<Arc<RiskManager>>,
+        capital_withdrawals: Option<Arc<CapitalWithdrawals>>,
     ) -> Result<()> {
         let mut buffer = [0; 4096];
         
@@ -122,7 +261,7 @@ impl TcpControlServer {
             };
             
             // Process command
-            let response = Self::process_command(command, &controller).await;
+            let response = Self::process_command(command, &controller, strategy_guard.as_deref(), bridge_tracker.as_deref(), trade_indexer.as_deref(), dust_consolidator.as_deref(), health_registry.as_deref(), watchlists.as_deref(), risk_manager.as_deref(), capital_withdrawals.as_deref()).await;
             
             // Send response
             let response_data = match serde_json::to_vec(&response) {
@@ -152,7 +291,15 @@ impl TcpControlServer {
     
     async fn process_command(
         command: TcpCommand, 
-        controller: &Arc<BotController>
+        controller: &Arc<BotController>,
+        strategy_guard: Option<&StrategyKillSwitch>,
+        bridge_tracker: Option<&BridgeTracker>,
+        trade_indexer: Option<&TradeIndexer>,
+        dust_consolidator: Option<&DustConsolidator>,
+        health_registry: Option<&HealthRegistry>,
+        watchlists: Option<&WatchlistRegistry>,
+        risk_manager: Option<&RiskManager>,
+        capital_withdrawals: Option<&CapitalWithdrawals>,
     ) -> TcpResponse {
         // 🔄 HOT-RELOAD AUTOMÁTICO: Recargar configuraciones antes de cada comando CLI
         info!("🔄 Hot-reload: Updating configurations from disk...");
@@ -276,6 +423,20 @@ impl TcpControlServer {
                 }
             }
             
+            TcpCommand::GetSandboxUsage { bot_id } => {
+                match controller.get_sandbox_usage(bot_id) {
+                    Ok(usage) => TcpResponse::SandboxUsage(usage),
+                    Err(e) => TcpResponse::Error(e.to_string()),
+                }
+            }
+            
+            TcpCommand::ArchiveBotArtifacts { bot_id } => {
+                match controller.archive_bot_artifacts(bot_id) {
+                    Ok(path) => TcpResponse::BotArtifactsArchived { bot_id, path },
+                    Err(e) => TcpResponse::Error(e.to_string()),
+                }
+            }
+            
             TcpCommand::ForceSave => {
                 match controller.force_save_all_state().await {
                     Ok(()) => TcpResponse::Success("All state saved to persistence".to_string()),
@@ -313,6 +474,197 @@ impl TcpControlServer {
                 info!("🛑 Shutdown command received");
                 TcpResponse::Success("Shutdown initiated".to_string())
             }
+            
+            TcpCommand::ListSuspendedStrategies => match strategy_guard {
+                Some(guard) => match serde_json::to_string(&guard.suspensions()) {
+                    Ok(json) => TcpResponse::Success(json),
+                    Err(e) => TcpResponse::Error(e.to_string()),
+                },
+                None => TcpResponse::Error("Strategy guard not available".to_string()),
+            },
+            
+            TcpCommand::ReenableStrategy { strategy, operator } => match strategy_guard {
+                Some(guard) => match guard.reenable(&strategy, &operator) {
+                    Ok(()) => TcpResponse::Success(format!("Strategy {} re-enabled", strategy)),
+                    Err(e) => TcpResponse::Error(e.to_string()),
+                },
+                None => TcpResponse::Error("Strategy guard not available".to_string()),
+            },
+            
+            command @ (TcpCommand::ListBridgeTransfers
+            | TcpCommand::RedeemBridgeTransfer { .. }
+            | TcpCommand::RetryBridgeTransfer { .. }) => Self::bridge_command(command, bridge_tracker).await,
+            
+            TcpCommand::AnnotateTrade { target, note, tags, author } => match trade_indexer {
+                Some(indexer) => match indexer.annotate(target, &note, tags, &author).await {
+                    Ok(annotation) => TcpResponse::Success(format!("Annotation {} added", annotation.id)),
+                    Err(e) => TcpResponse::Error(e.to_string()),
+                },
+                None => TcpResponse::Error("Trade journal not available".to_string()),
+            },
+            
+            TcpCommand::ListAnnotations { target } => match trade_indexer {
+                Some(indexer) => match serde_json::to_string(&indexer.annotations(target.as_ref()).await) {
+                    Ok(json) => TcpResponse::Success(json),
+                    Err(e) => TcpResponse::Error(e.to_string()),
+                },
+                None => TcpResponse::Error("Trade journal not available".to_string()),
+            },
+            
+            TcpCommand::ExportTradeJournal { wallet } => match trade_indexer {
+                Some(indexer) => TcpResponse::Success(indexer.export_csv(wallet.as_deref()).await),
+                None => TcpResponse::Error("Trade journal not available".to_string()),
+            },
+            
+            TcpCommand::ConsolidateDust { dry_run } => match dust_consolidator {
+                Some(consolidator) => match consolidator.consolidate(dry_run).await {
+                    Ok(report) => match serde_json::to_string(&report) {
+                        Ok(json) => TcpResponse::Success(json),
+                        Err(e) => TcpResponse::Error(e.to_string()),
+                    },
+                    Err(e) => TcpResponse::Error(e.to_string()),
+                },
+                None => TcpResponse::Error("Dust consolidation not available".to_string()),
+            },
+            
+            TcpCommand::GetHealth => match health_registry {
+                Some(registry) => match serde_json::to_string(&registry.check_all().await) {
+                    Ok(json) => TcpResponse::Success(json),
+                    Err(e) => TcpResponse::Error(e.to_string()),
+                },
+                None => TcpResponse::Error("Health checks not available".to_string()),
+            },
+            TcpCommand::CollectDiagnostics { note } => {
+                let mut collector = DiagnosticsCollector::new(DiagnosticsConfig::default());
+                if let Some(registry) = health_registry {
+                    collector = collector.with_health_report(registry.check_all().await);
+                }
+                match tokio::task::spawn_blocking(move || collector.collect(note, chrono::Utc::now())).await {
+                    Ok(Ok(summary)) => {
+                        info!("🧰 Diagnostics bundle written to {}", summary.path);
+                        TcpResponse::DiagnosticsCollected(summary)
+                    }
+                    Ok(Err(e)) => TcpResponse::Error(e.to_string()),
+                    Err(e) => TcpResponse::Error(e.to_string()),
+                }
+            }
+            TcpCommand::SetFaultPlan { plan } => match faults().set_plan(plan) {
+                Ok(()) => TcpResponse::Success("Fault plan armed".to_string()),
+                Err(e) => TcpResponse::Error(e.to_string()),
+            },
+            TcpCommand::KillTask { name } => match faults().kill_task(&name) {
+                Ok(()) => TcpResponse::Success(format!("Task '{}' will be killed at the next watchdog check", name)),
+                Err(e) => TcpResponse::Error(e.to_string()),
+            },
+            TcpCommand::ClearFaults => {
+                faults().clear();
+                TcpResponse::Success("All faults cleared".to_string())
+            }
+            TcpCommand::GetFaults => match serde_json::to_string(&faults().status()) {
+                Ok(json) => TcpResponse::Success(json),
+                Err(e) => TcpResponse::Error(e.to_string()),
+            },
+            TcpCommand::ListWatchlists
+            | TcpCommand::UpsertWatchlist { .. }
+            | TcpCommand::RemoveWatchlist { .. }
+            | TcpCommand::AddWatchlistTokens { .. }
+            | TcpCommand::RemoveWatchlistTokens { .. }
+            | TcpCommand::BindWatchlistStrategies { .. } => match watchlists {
+                Some(watchlists) => Self::process_watchlist_command(command, watchlists),
+                None => TcpResponse::Error("Watchlists not available".to_string()),
+            },
+            
+            TcpCommand::GetRiskHeadroom { asset } => match risk_manager {
+                Some(risk_manager) => {
+                    let headrooms = match asset {
+                        Some(asset) => vec![risk_manager.token_headroom(&asset)],
+                        None => risk_manager.token_headrooms(),
+                    };
+                    match serde_json::to_string(&headrooms) {
+                        Ok(json) => TcpResponse::Success(json),
+                        Err(e) => TcpResponse::Error(e.to_string()),
+                    }
+                }
+                None => TcpResponse::Error("Risk manager not available".to_string()),
+            },
+            
+            command @ (TcpCommand::ListWithdrawals
+            | TcpCommand::RequestWithdrawal { .. }
+            | TcpCommand::ApproveWithdrawal { .. }
+            | TcpCommand::CancelWithdrawal { .. }) => match capital_withdrawals {
+                Some(withdrawals) => Self::process_withdrawal_command(command, withdrawals),
+                None => TcpResponse::Error("Capital withdrawals not available".to_string()),
+            },
+        }
+    }
+    
+    fn process_withdrawal_command(command: TcpCommand, withdrawals: &CapitalWithdrawals) -> TcpResponse {
+        let result = match command {
+            TcpCommand::ListWithdrawals => serde_json::to_string(&withdrawals.requests()).map_err(anyhow::Error::from),
+            TcpCommand::RequestWithdrawal { wallet, asset, amount, destination } => withdrawals
+                .request(&wallet, &asset, amount, &destination)
+                .and_then(|request| Ok(serde_json::to_string(&[request])?)),
+            TcpCommand::ApproveWithdrawal { id, operator } => withdrawals
+                .approve(&id, &operator)
+                .and_then(|request| Ok(serde_json::to_string(&[request])?)),
+            TcpCommand::CancelWithdrawal { id } => withdrawals
+                .cancel(&id)
+                .and_then(|request| Ok(serde_json::to_string(&[request])?)),
+            _ => return TcpResponse::Error("Not a withdrawal command".to_string()),
+        };
+        match result {
+            Ok(json) => TcpResponse::Success(json),
+            Err(e) => TcpResponse::Error(e.to_string()),
         }
     }
+    
+    fn process_watchlist_command(command: TcpCommand, watchlists: &WatchlistRegistry) -> TcpResponse {
+        let result = match command {
+            TcpCommand::ListWatchlists => {
+                return match serde_json::to_string(&watchlists.list()) {
+                    Ok(json) => TcpResponse::Success(json),
+                    Err(e) => TcpResponse::Error(e.to_string()),
+                };
+            }
+            TcpCommand::UpsertWatchlist { watchlist } => watchlists.upsert(watchlist),
+            TcpCommand::RemoveWatchlist { name } => watchlists.remove(&name),
+            TcpCommand::AddWatchlistTokens { name, tokens } => watchlists.add_tokens(&name, &tokens),
+            TcpCommand::RemoveWatchlistTokens { name, tokens } => watchlists.remove_tokens(&name, &tokens),
+            TcpCommand::BindWatchlistStrategies { name, strategies } => watchlists.set_strategies(&name, strategies),
+            _ => return TcpResponse::Error("Not a watchlist command".to_string()),
+        };
+        match result.map(|watchlist| serde_json::to_string(&watchlist)) {
+            Ok(Ok(json)) => TcpResponse::Success(json),
+            Ok(Err(e)) => TcpResponse::Error(e.to_string()),
+            Err(e) => TcpResponse::Error(e.to_string()),
+        }
+    }
+
+    #[cfg(feature = "cross-chain")]
+    async fn bridge_command(command: TcpCommand, bridge_tracker: Option<&BridgeTracker>) -> TcpResponse {
+        let Some(tracker) = bridge_tracker else {
+            return TcpResponse::Error("Bridge tracker not available".to_string());
+        };
+        match command {
+            TcpCommand::ListBridgeTransfers => match serde_json::to_string(&tracker.transfers()) {
+                Ok(json) => TcpResponse::Success(json),
+                Err(e) => TcpResponse::Error(e.to_string()),
+            },
+            TcpCommand::RedeemBridgeTransfer { transfer_id, operator, redeem_tx } => match tracker.redeem(&transfer_id, &operator, redeem_tx).await {
+                Ok(transfer) => TcpResponse::Success(format!("Bridge transfer {} redeemed ({})",
+                    transfer.id, transfer.redeem_tx.unwrap_or_default())),
+                Err(e) => TcpResponse::Error(e.to_string()),
+            },
+            TcpCommand::RetryBridgeTransfer { transfer_id, operator } => match tracker.retry(&transfer_id, &operator) {
+                Ok(transfer) => TcpResponse::Success(format!("Bridge transfer {} back to {:?}", transfer.id, transfer.status)),
+                Err(e) => TcpResponse::Error(e.to_string()),
+            },
+            _ => TcpResponse::Error("Not a bridge command".to_string()),
+        }
+    }
+
+    #[cfg(not(feature = "cross-chain"))]
+    async fn bridge_command(_command: TcpCommand, _bridge_tracker: Option<&BridgeTracker>) -> TcpResponse {
+        TcpResponse::Error("Bridge commands require a build with the `cross-chain` feature".to_string())
+    }
 }
@@ -0,0 +1,136 @@
+//! Scripted demonstration mode for the `sniperforge` binary
+//!
+//! Runs a fixed number of MultiBot cycles against the simulated execution
+//! path and prints the showcase dashboards and final report. Nothing here is
+//! part of the service loop: the binary only enters this mode with `--demo`,
+//! forces simulation on, and labels every figure as simulated.
+
+use anyhow::Result;
+use chrono::Utc;
+use tokio::time::{sleep, Duration};
+use tracing::{error, info, warn};
+
+use super::{EnterpriseMultiBotSystem, SYSTEM_VERSION};
+
+/// Demonstration parameters
+#[derive(Debug, Clone)]
+pub struct DemoConfig {
+    pub cycles: u32,
+    pub cycle_interval: Duration,
+    /// Dashboard is printed every N cycles
+    pub dashboard_every: u32,
+    /// Performance report is generated every N cycles
+    pub report_every: u32,
+    /// AI optimization runs every N cycles
+    pub optimize_every: u32,
+}
+
+impl Default for DemoConfig {
+    fn default() -> Self {
+        Self {
+            cycles: 18,
+            cycle_interval: Duration::from_secs(8),
+            dashboard_every: 3,
+            report_every: 6,
+            optimize_every: 9,
+        }
+    }
+}
+
+fn every(cycle: u32, interval: u32) -> bool {
+    interval > 0 && cycle % interval == 0
+}
+
+impl EnterpriseMultiBotSystem {
+    /// Run the scripted demonstration (simulated execution only)
+    pub async fn run_demonstration(&mut self, config: &DemoConfig) -> Result<()> {
+        warn!("🎭 DEMO MODE - {} scripted cycles, every trade and profit figure is SIMULATED", config.cycles);
+
+        info!("🌐 Starting TCP Control Server for external CLI access...");
+        self.start_supervised_control_server().await?;
+        self.tcp_server = None;
+        info!("✅ TCP Control Server running on port 8888");
+
+        // Feeds and engines run in their own supervised tasks for the demonstration
+        self.engine_supervisor.start().await
+            .map_err(|e| anyhow::anyhow!("Engine supervisor failed to start: {}", e))?;
+        info!("🌳 Engine supervisor running - feeds and engines isolated per task");
+
+        self.display_multibot_system_overview();
+
+        for cycle in 1..=config.cycles {
+            self.cycle_count += 1;
+            let cycle_start = std::time::Instant::now();
+
+            info!("🎭 [SIMULATED] MultiBot trading cycle #{}/{}", cycle, config.cycles);
+
+            match self.execute_multibot_trading_cycle().await {
+                Ok(cycle_profit) => {
+                    self.total_profit += cycle_profit;
+                    self.update_system_metrics(cycle_profit);
+
+                    if every(cycle, config.dashboard_every) {
+                        self.display_multibot_dashboard();
+                    }
+                    if every(cycle, config.report_every) {
+                        self.generate_enterprise_performance_report().await;
+                    }
+                    if every(cycle, config.optimize_every) {
+                        self.execute_ai_multibot_optimization().await;
+                    }
+                }
+                Err(e) => {
+                    error!("❌ MultiBot trading cycle failed: {}", e);
+                    continue;
+                }
+            }
+
+            let sleep_time = config.cycle_interval.saturating_sub(cycle_start.elapsed());
+            if !sleep_time.is_zero() {
+                sleep(sleep_time).await;
+            }
+        }
+
+        self.shutdown().await;
+        self.display_demo_final_summary();
+        Ok(())
+    }
+
+    /// Showcase report printed at the end of the demonstration
+    fn display_demo_final_summary(&self) {
+        let avg_profit_per_cycle = if self.cycle_count > 0 {
+            self.total_profit / self.cycle_count as f64
+        } else {
+            0.0
+        };
+
+        let runtime_minutes = (Utc::now() - self.system_start_time).num_minutes();
+
+        println!("\n╔══════════════════════════════════════════════════════════════════════════════╗");
+        println!("║              SNIPERFORGE MULTIBOT DEMONSTRATION REPORT (SIMULATED)              ║");
+        println!("╠══════════════════════════════════════════════════════════════════════════════╣");
+        println!("║ 🎭 All figures below come from simulated execution - no real trades were made  ║");
+        println!("║                                                                                  ║");
+        println!("║   📊 SIMULATED PERFORMANCE:                                                    ║");
+        println!("║   • Cycles Executed: {}                                                        ║", self.cycle_count);
+        println!("║   • Simulated Profit: ${:.2}                                                   ║", self.total_profit);
+        println!("║   • Simulated Profit per Cycle: ${avg_profit_per_cycle:.2}                                        ║");
+        println!("║   • Runtime: {runtime_minutes} minutes                                                        ║");
+        println!("║   • Success Rate: {:.1}%                                                       ║", self.system_metrics.success_rate_percentage);
+        println!("║   • AI Accuracy Rate: {:.1}%                                                   ║", self.system_metrics.ai_accuracy_rate);
+        println!("║                                                                                  ║");
+        println!("║   🎯 STRATEGIES EXERCISED: {}                                                  ║", self.active_strategies.len());
+        for strategy in &self.active_strategies {
+            println!("║   • {:?}", strategy);
+        }
+        println!("║                                                                                  ║");
+        println!("║   🤖 MODEL STATE:                                                              ║");
+        println!("║   • LSTM Prediction Accuracy: {:.1}%                                           ║", self.multibot_ai.lstm_prediction_accuracy * 100.0);
+        println!("║   • Neural Network Accuracy: {:.1}%                                            ║", self.multibot_ai.neural_network_accuracy * 100.0);
+        println!("║   • Ensemble Model Accuracy: {:.1}%                                            ║", self.multibot_ai.ensemble_accuracy * 100.0);
+        println!("╚══════════════════════════════════════════════════════════════════════════════╝");
+
+        info!("🎭 SniperForge v{} demonstration finished - ${:.2} simulated across {} strategies",
+              SYSTEM_VERSION, self.total_profit, self.active_strategies.len());
+    }
+}
@@ -0,0 +1,254 @@
+//! Embedding SniperForge in a host application
+//!
+//! [`crate::init`] installs a process-wide subscriber, which is what the
+//! service binary wants and exactly what a host application does not. The
+//! builder here installs nothing global: log output goes to the dispatcher
+//! passed to [`SniperForgeBuilder::with_tracing`] (scoped to SniperForge's
+//! own initialization and to futures run through [`SniperForge::instrument`]
+//! / [`SniperForge::spawn`]), or to whatever the host already set up.
+//!
+//! ```no_run
+//! # async fn embed() -> anyhow::Result<()> {
+//! use sniperforge::{SimpleConfig, SniperForge};
+//!
+//! let subscriber = tracing_subscriber::fmt().with_target(false).finish();
+//! let forge = SniperForge::builder()
+//!     .with_tracing(subscriber)
+//!     .with_config(SimpleConfig::default())
+//!     .build()
+//!     .await?;
+//! let risk = forge.risk_manager();
+//! # Ok(()) }
+//! ```
+
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::instrument::{Instrumented, WithDispatch, WithSubscriber};
+use tracing::{info, Dispatch, Instrument};
+
+use crate::apis::{fiat_rates, FiatRateService, InMemoryPriceCache, PriceCache, PriceFeedManager};
+use crate::bots::bot_factory::{BotFactory, BotRegistry};
+use crate::config::SimpleConfig;
+use crate::trading::{ArbitrageEngine, RiskManager};
+
+/// Configures subsystems without touching process-wide state
+#[derive(Default)]
+pub struct SniperForgeBuilder {
+    config: Option<SimpleConfig>,
+    dispatch: Option<Dispatch>,
+    price_cache: Option<Arc<dyn PriceCache>>,
+    arbitrage_engine: bool,
+}
+
+impl SniperForgeBuilder {
+    /// Route SniperForge's tracing output to `subscriber` instead of the host's default
+    pub fn with_tracing<S>(mut self, subscriber: S) -> Self
+    where
+        S: tracing::Subscriber + Send + Sync + 'static,
+    {
+        self.dispatch = Some(Dispatch::new(subscriber));
+        self
+    }
+
+    /// Use an existing dispatcher (e.g. one shared with the host)
+    pub fn with_dispatch(mut self, dispatch: Dispatch) -> Self {
+        self.dispatch = Some(dispatch);
+        self
+    }
+
+    pub fn with_config(mut self, config: SimpleConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Share a price cache with the host (default: a private in-memory cache)
+    pub fn with_price_cache(mut self, cache: Arc<dyn PriceCache>) -> Self {
+        self.price_cache = Some(cache);
+        self
+    }
+
+    /// Also start the arbitrage engine (loads the wallet and queries its balance)
+    pub fn with_arbitrage_engine(mut self) -> Self {
+        self.arbitrage_engine = true;
+        self
+    }
+
+    pub async fn build(self) -> anyhow::Result<SniperForge> {
+        let dispatch = self.dispatch.clone();
+        let build = self.build_inner();
+        match dispatch {
+            Some(dispatch) => build.with_subscriber(dispatch).await,
+            None => build.await,
+        }
+    }
+
+    async fn build_inner(self) -> anyhow::Result<SniperForge> {
+        let config = self.config.unwrap_or_default();
+        let price_feeds = Arc::new(PriceFeedManager::new(&config));
+
+        let arbitrage = if self.arbitrage_engine {
+            let engine = ArbitrageEngine::new(config.clone(), price_feeds.clone())
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to initialize arbitrage engine: {}", e))?;
+            Some(engine)
+        } else {
+            None
+        };
+        // Share the engine's restrictions and exposure book when there is one
+        let risk_manager = arbitrage
+            .as_ref()
+            .map_or_else(|| RiskManager::new(&config), |engine| engine.risk_manager().clone());
+
+        info!("SniperForge Core v{} initialized (embedded)", crate::VERSION);
+        Ok(SniperForge {
+            config,
+            dispatch: self.dispatch,
+            price_feeds,
+            price_cache: self.price_cache.unwrap_or_else(|| Arc::new(InMemoryPriceCache::new())),
+            fiat_rates: fiat_rates(),
+            risk_manager,
+            arbitrage,
+            bot_factory: Arc::new(RwLock::new(BotFactory::new())),
+            bot_registry: Arc::new(RwLock::new(BotRegistry::new())),
+        })
+    }
+}
+
+/// Handles to an embedded SniperForge instance
+pub struct SniperForge {
+    config: SimpleConfig,
+    dispatch: Option<Dispatch>,
+    price_feeds: Arc<PriceFeedManager>,
+    price_cache: Arc<dyn PriceCache>,
+    fiat_rates: Arc<FiatRateService>,
+    risk_manager: RiskManager,
+    arbitrage: Option<ArbitrageEngine>,
+    bot_factory: Arc<RwLock<BotFactory>>,
+    bot_registry: Arc<RwLock<BotRegistry>>,
+}
+
+impl SniperForge {
+    pub fn builder() -> SniperForgeBuilder {
+        SniperForgeBuilder::default()
+    }
+
+    pub fn config(&self) -> &SimpleConfig {
+        &self.config
+    }
+
+    pub fn price_feeds(&self) -> Arc<PriceFeedManager> {
+        self.price_feeds.clone()
+    }
+
+    pub fn price_cache(&self) -> Arc<dyn PriceCache> {
+        self.price_cache.clone()
+    }
+
+    pub fn fiat_rates(&self) -> Arc<FiatRateService> {
+        self.fiat_rates.clone()
+    }
+
+    /// Clones share restrictions and the exposure book
+    pub fn risk_manager(&self) -> RiskManager {
+        self.risk_manager.clone()
+    }
+
+    /// Present when built with [`SniperForgeBuilder::with_arbitrage_engine`]
+    pub fn arbitrage_engine(&self) -> Option<&ArbitrageEngine> {
+        self.arbitrage.as_ref()
+    }
+
+    pub fn bot_factory(&self) -> Arc<RwLock<BotFactory>> {
+        self.bot_factory.clone()
+    }
+
+    pub fn bot_registry(&self) -> Arc<RwLock<BotRegistry>> {
+        self.bot_registry.clone()
+    }
+
+    /// The dispatcher SniperForge logs to, if one was configured
+    pub fn dispatch(&self) -> Option<&Dispatch> {
+        self.dispatch.as_ref()
+    }
+
+    /// Attach SniperForge's dispatcher (if any) to `future`
+    pub fn instrument<F: Future>(&self, future: F) -> WithDispatch<Instrumented<F>> {
+        let dispatch = self.dispatch.clone().unwrap_or_else(|| tracing::dispatcher::get_default(Clone::clone));
+        future.in_current_span().with_subscriber(dispatch)
+    }
+
+    /// `tokio::spawn` with SniperForge's dispatcher attached
+    pub fn spawn<F>(&self, future: F) -> tokio::task::JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        tokio::spawn(self.instrument(future))
+    }
+
+    /// Run `f` with SniperForge's dispatcher as the default (synchronous code)
+    pub fn in_scope<T>(&self, f: impl FnOnce() -> T) -> T {
+        match &self.dispatch {
+            Some(dispatch) => tracing::dispatcher::with_default(dispatch, f),
+            None => f(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::Mutex;
+
+    /// Collects formatted output so tests can see where logs went
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Capture {
+        fn text(&self) -> String {
+            String::from_utf8_lossy(&self.0.lock().unwrap()).to_string()
+        }
+    }
+
+    fn subscriber(capture: &Capture) -> impl tracing::Subscriber + Send + Sync {
+        let capture = capture.clone();
+        tracing_subscriber::fmt().with_ansi(false).with_writer(move || capture.clone()).finish()
+    }
+
+    #[tokio::test]
+    async fn test_build_logs_to_supplied_subscriber_without_global_default() {
+        let capture = Capture::default();
+        let forge = SniperForge::builder().with_tracing(subscriber(&capture)).build().await.unwrap();
+
+        assert!(capture.text().contains("initialized (embedded)"));
+        assert!(forge.arbitrage_engine().is_none());
+        assert!(forge.dispatch().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_spawned_tasks_and_scopes_use_the_instance_dispatcher() {
+        let capture = Capture::default();
+        let forge = SniperForge::builder().with_tracing(subscriber(&capture)).build().await.unwrap();
+
+        forge.spawn(async { info!("from spawned task") }).await.unwrap();
+        forge.in_scope(|| info!("from sync scope"));
+        info!("outside the instance");
+
+        let text = capture.text();
+        assert!(text.contains("from spawned task"));
+        assert!(text.contains("from sync scope"));
+        assert!(!text.contains("outside the instance"));
+    }
+}
@@ -2,6 +2,7 @@
 //! 
 //! Advanced market intelligence for strategic analysis and decision making
 
+use tracing::warn;
 use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -198,8 +199,8 @@ impl SentimentAnalyzer {
         match self.real_analyzer.calculate_sentiment_score(symbol).await {
             Ok(sentiment) => Ok(sentiment),
             Err(e) => {
-                eprintln!("⚠️  Real sentiment analysis failed: {}", e);
-                eprintln!("   Falling back to neutral sentiment");
+                warn!("⚠️  Real sentiment analysis failed: {}", e);
+                warn!("   Falling back to neutral sentiment");
                 Ok(0.0) // Neutral fallback
             }
         }
@@ -219,7 +220,7 @@ impl SentimentAnalyzer {
                 })
             },
             Err(e) => {
-                eprintln!("⚠️  Detailed sentiment analysis failed: {}", e);
+                warn!("⚠️  Detailed sentiment analysis failed: {}", e);
                 // Return neutral analysis as fallback
                 Ok(SentimentAnalysis {
                     overall_score: 0.0,
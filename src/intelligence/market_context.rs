@@ -0,0 +1,201 @@
+//! Market context handed to strategies each cycle
+//!
+//! Sentiment and intelligence inputs fail independently (rate limits, open
+//! circuits, missing credentials). Instead of each strategy guessing what a
+//! missing score means, the context carries a quality flag per input and
+//! applies one explicit degraded mode:
+//!
+//! - unavailable inputs read as neutral (sentiment 0.0, no regime call)
+//! - aggressiveness multipliers are capped and profit thresholds raised in
+//!   proportion to how much of the picture is missing
+//!
+//! The quality summary is surfaced in metrics so degraded cycles are visible.
+
+use serde::{Deserialize, Serialize};
+
+use super::sentiment::SentimentBlend;
+
+/// How much of an input is backed by live data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum SignalQuality {
+    /// Every source answered
+    Full,
+    /// Some sources failed; the value is usable but thinner
+    Partial,
+    /// Nothing answered; a neutral default is used
+    Unavailable,
+}
+
+/// Per-input quality flags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextQuality {
+    pub sentiment: SignalQuality,
+    pub intelligence: SignalQuality,
+}
+
+impl ContextQuality {
+    pub fn worst(&self) -> SignalQuality {
+        self.sentiment.max(self.intelligence)
+    }
+
+    pub fn is_degraded(&self) -> bool {
+        self.worst() != SignalQuality::Full
+    }
+}
+
+/// Limits applied while inputs are degraded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DegradedModeConfig {
+    /// Aggressiveness cap with partial inputs
+    pub partial_max_aggressiveness: f64,
+    /// Aggressiveness cap with an unavailable input
+    pub unavailable_max_aggressiveness: f64,
+    /// Profit threshold multiplier with partial inputs
+    pub partial_threshold_multiplier: f64,
+    /// Profit threshold multiplier with an unavailable input
+    pub unavailable_threshold_multiplier: f64,
+}
+
+impl Default for DegradedModeConfig {
+    fn default() -> Self {
+        Self {
+            partial_max_aggressiveness: 1.0,
+            unavailable_max_aggressiveness: 0.7,
+            partial_threshold_multiplier: 1.1,
+            unavailable_threshold_multiplier: 1.25,
+        }
+    }
+}
+
+/// Market inputs for one cycle with their quality
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketContext {
+    sentiment: f64,
+    pub sentiment_confidence: f64,
+    pub quality: ContextQuality,
+    /// Sources that failed this cycle, for metrics and logs
+    pub degraded_sources: Vec<String>,
+    config: DegradedModeConfig,
+}
+
+impl MarketContext {
+    /// Context with every input unavailable
+    pub fn neutral() -> Self {
+        Self {
+            sentiment: 0.0,
+            sentiment_confidence: 0.0,
+            quality: ContextQuality {
+                sentiment: SignalQuality::Unavailable,
+                intelligence: SignalQuality::Unavailable,
+            },
+            degraded_sources: Vec::new(),
+            config: DegradedModeConfig::default(),
+        }
+    }
+
+    /// Context from per-symbol sentiment blends; symbols nobody answered for
+    /// count as failed
+    pub fn from_sentiment(blends: &[SentimentBlend]) -> Self {
+        let answered: Vec<&SentimentBlend> = blends.iter().filter(|b| b.confidence > 0.0).collect();
+        let mut context = Self::neutral();
+        context.degraded_sources = blends
+            .iter()
+            .filter(|b| b.confidence <= 0.0)
+            .map(|b| format!("sentiment:{}", b.symbol))
+            .collect();
+        if answered.is_empty() {
+            return context;
+        }
+        context.sentiment = answered.iter().map(|b| b.score).sum::<f64>() / answered.len() as f64;
+        context.sentiment_confidence = answered.iter().map(|b| b.confidence).sum::<f64>() / answered.len() as f64;
+        context.quality.sentiment = if answered.len() == blends.len() && context.sentiment_confidence >= 0.999 {
+            SignalQuality::Full
+        } else {
+            SignalQuality::Partial
+        };
+        context
+    }
+
+    pub fn with_intelligence(mut self, quality: SignalQuality) -> Self {
+        self.quality.intelligence = quality;
+        self
+    }
+
+    /// Record a failed provider for metrics and logs
+    pub fn with_degraded_source(mut self, source: impl Into<String>) -> Self {
+        self.degraded_sources.push(source.into());
+        self
+    }
+
+    pub fn with_config(mut self, config: DegradedModeConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sentiment in [-1, 1]; 0.0 (neutral) when unavailable
+    pub fn sentiment(&self) -> f64 {
+        match self.quality.sentiment {
+            SignalQuality::Unavailable => 0.0,
+            _ => self.sentiment,
+        }
+    }
+
+    pub fn is_degraded(&self) -> bool {
+        self.quality.is_degraded()
+    }
+
+    /// Cap a strategy's aggressiveness multiplier for the current quality
+    pub fn cap_aggressiveness(&self, multiplier: f64) -> f64 {
+        match self.quality.worst() {
+            SignalQuality::Full => multiplier,
+            SignalQuality::Partial => multiplier.min(self.config.partial_max_aggressiveness),
+            SignalQuality::Unavailable => multiplier.min(self.config.unavailable_max_aggressiveness),
+        }
+    }
+
+    /// Raise a profit threshold for the current quality
+    pub fn adjust_threshold(&self, threshold: f64) -> f64 {
+        threshold * match self.quality.worst() {
+            SignalQuality::Full => 1.0,
+            SignalQuality::Partial => self.config.partial_threshold_multiplier,
+            SignalQuality::Unavailable => self.config.unavailable_threshold_multiplier,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn blend(symbol: &str, score: f64, confidence: f64) -> SentimentBlend {
+        SentimentBlend { symbol: symbol.to_string(), score, confidence, breakdown: HashMap::new() }
+    }
+
+    #[test]
+    fn test_unavailable_sentiment_is_neutral_and_conservative() {
+        let context = MarketContext::from_sentiment(&[blend("SOL", 0.0, 0.0), blend("BTC", 0.0, 0.0)])
+            .with_intelligence(SignalQuality::Full);
+        assert_eq!(context.quality.sentiment, SignalQuality::Unavailable);
+        assert_eq!(context.sentiment(), 0.0);
+        assert!(context.is_degraded());
+        assert_eq!(context.cap_aggressiveness(1.8), 0.7);
+        assert_eq!(context.adjust_threshold(0.8), 1.0);
+        assert_eq!(context.degraded_sources, vec!["sentiment:SOL", "sentiment:BTC"]);
+    }
+
+    #[test]
+    fn test_partial_and_full_quality() {
+        let partial = MarketContext::from_sentiment(&[blend("SOL", 0.4, 1.0), blend("BTC", 0.0, 0.0)])
+            .with_intelligence(SignalQuality::Full);
+        assert_eq!(partial.quality.sentiment, SignalQuality::Partial);
+        assert_eq!(partial.sentiment(), 0.4);
+        assert_eq!(partial.cap_aggressiveness(1.8), 1.0);
+
+        let full = MarketContext::from_sentiment(&[blend("SOL", 0.4, 1.0), blend("BTC", 0.2, 1.0)])
+            .with_intelligence(SignalQuality::Full);
+        assert!(!full.is_degraded());
+        assert_eq!(full.cap_aggressiveness(1.8), 1.8);
+        assert_eq!(full.adjust_threshold(0.8), 0.8);
+    }
+}
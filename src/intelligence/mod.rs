@@ -12,6 +12,12 @@ pub mod ml_engine;
 pub mod market_analysis;
 pub mod auto_trader;
 pub mod sentiment; // Add sentiment module
+pub mod news_events; // High-impact headline classification
+pub mod market_context; // Per-cycle market inputs with quality flags / degraded mode
+
+use std::sync::Arc;
+
+use crate::trading::microstructure::{MicrostructureMonitor, MicrostructureSignal};
 
 // Re-export main components for convenience
 pub use ml_engine::{AdvancedAiEngine, AiConfig, PricePredictionModel, MarketRegime, RiskAssessment, LearningMetrics};
@@ -19,6 +25,8 @@ pub use market_analysis::{
     IntelligenceSystem, SentimentAnalyzer, StrategicAnalyzer, BehavioralPredictor, 
     SentimentAnalysis, ComprehensiveAnalysis
 };
+pub use market_context::{MarketContext, ContextQuality, SignalQuality, DegradedModeConfig};
+pub use news_events::{NewsEventClassifier, NewsEventMonitor, NewsMonitorConfig, NewsEvent, NewsEventType, Headline, ExposureReducer};
 pub use auto_trader::{AutonomousTrader, AutonomousConfig, StrategySelector, PositionManager, RiskManager, PerformanceMetrics};
 
 /// Intelligence system configuration
@@ -97,6 +105,7 @@ pub async fn initialize_intelligence_system(config: IntelligenceConfig) -> Resul
         market_intelligence,
         autonomous_trader,
         config,
+        microstructure: None,
     })
 }
 
@@ -106,9 +115,24 @@ pub struct IntelligenceSystemSuite {
     pub market_intelligence: IntelligenceSystem,
     pub autonomous_trader: Option<AutonomousTrader>,
     pub config: IntelligenceConfig,
+    /// CLOB order book / trade flow signals, when a Phoenix/OpenBook feed is attached
+    pub microstructure: Option<Arc<MicrostructureMonitor>>,
 }
 
 impl IntelligenceSystemSuite {
+    pub fn with_microstructure(mut self, monitor: Arc<MicrostructureMonitor>) -> Self {
+        self.microstructure = Some(monitor);
+        self
+    }
+
+    /// Fresh CLOB signals of markets whose base token is `symbol`
+    fn microstructure_signals(&self, symbol: &str) -> Vec<MicrostructureSignal> {
+        self.microstructure
+            .as_ref()
+            .map(|monitor| monitor.signals(Some(symbol), chrono::Utc::now()))
+            .unwrap_or_default()
+    }
+
     /// Get comprehensive market analysis
     pub async fn analyze_market(&mut self, symbol: &str) -> Result<MarketIntelligence, Box<dyn std::error::Error + Send + Sync>> {
         let price_prediction = self.ai_engine.predict_price(symbol, 24).await?;
@@ -122,11 +146,13 @@ impl IntelligenceSystemSuite {
             market_regime: market_analysis.market_regime,
             risk_assessment: market_analysis.risk_level,
             trading_recommendation: market_analysis.recommendation,
+            microstructure: self.microstructure_signals(symbol),
         })
     }
 
     /// Execute autonomous trading if enabled
     pub async fn execute_autonomous_trading(&mut self, symbol: &str) -> Result<Option<TradingAction>, Box<dyn std::error::Error + Send + Sync>> {
+        let microstructure = self.microstructure_signals(symbol);
         if let Some(trader) = &mut self.autonomous_trader {
             let market_intel = MarketIntelligence {
                 symbol: symbol.to_string(),
@@ -135,6 +161,7 @@ impl IntelligenceSystemSuite {
                 market_regime: "BULLISH".to_string(),
                 risk_assessment: 0.5,
                 trading_recommendation: "BUY".to_string(),
+                microstructure,
             };
             trader.make_trading_decision(market_intel).await.map(Some)
         } else {
@@ -152,6 +179,8 @@ pub struct MarketIntelligence {
     pub market_regime: String,
     pub risk_assessment: f64,
     pub trading_recommendation: String,
+    /// Book imbalance and flow toxicity per CLOB market of the symbol
+    pub microstructure: Vec<MicrostructureSignal>,
 }
 
 /// Trading action from autonomous system
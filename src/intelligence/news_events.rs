@@ -0,0 +1,383 @@
+//! News event classifier
+//!
+//! Scans ingested headlines for high-impact events (exploits, depegs,
+//! regulatory action, exchange halts, delistings) that mention a held or
+//! watched token. Matches become prioritized alerts and, when an
+//! `ExposureReducer` is attached, cap exposure to the affected asset for a
+//! while.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::monitoring::{Alert, AlertManager, AlertStatus, Severity};
+use crate::trading::RiskManager;
+
+/// Comma-separated RSS feeds polled for headlines
+pub const NEWS_FEEDS_ENV: &str = "SNIPERFORGE_NEWS_FEEDS";
+
+const DEFAULT_FEEDS: &[&str] = &[
+    "https://www.coindesk.com/arc/outboundfeeds/rss/",
+    "https://cointelegraph.com/rss",
+    "https://decrypt.co/feed",
+];
+
+/// High-impact event categories
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NewsEventType {
+    Exploit,
+    Depeg,
+    ExchangeHalt,
+    Delisting,
+    RegulatoryAction,
+}
+
+impl NewsEventType {
+    pub fn severity(self) -> Severity {
+        match self {
+            Self::Exploit | Self::Depeg => Severity::Critical,
+            Self::ExchangeHalt | Self::Delisting => Severity::High,
+            Self::RegulatoryAction => Severity::Medium,
+        }
+    }
+
+    /// Share of the normal position size still allowed after the event
+    pub fn exposure_fraction(self) -> f64 {
+        match self {
+            Self::Exploit | Self::Depeg => 0.0,
+            Self::ExchangeHalt | Self::Delisting => 0.25,
+            Self::RegulatoryAction => 0.5,
+        }
+    }
+
+    fn tag(self) -> &'static str {
+        match self {
+            Self::Exploit => "exploit",
+            Self::Depeg => "depeg",
+            Self::ExchangeHalt => "exchange_halt",
+            Self::Delisting => "delisting",
+            Self::RegulatoryAction => "regulatory",
+        }
+    }
+}
+
+/// Ingested headline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Headline {
+    pub title: String,
+    pub source: String,
+    pub url: Option<String>,
+    pub published_at: DateTime<Utc>,
+}
+
+/// Headline classified as an event for one token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewsEvent {
+    pub event_type: NewsEventType,
+    pub token: String,
+    pub severity: Severity,
+    pub headline: Headline,
+    pub matched_terms: Vec<String>,
+}
+
+/// Keyword/regex headline classifier
+#[derive(Debug, Clone)]
+pub struct NewsEventClassifier {
+    patterns: Vec<(NewsEventType, Regex)>,
+    /// Symbol → alias patterns (cashtag, ticker, project name)
+    tokens: HashMap<String, Regex>,
+}
+
+impl NewsEventClassifier {
+    pub fn new() -> Self {
+        let patterns = [
+            (NewsEventType::Exploit, r"(?i)\b(exploit(ed|s)?|hack(ed|s)?|drain(ed|s)?|breach(ed)?|stolen|attacker|vulnerabilit(y|ies))\b"),
+            (NewsEventType::Depeg, r"(?i)\b(de-?peg(ged|s)?|loses? (its )?peg|broke (its )?peg)\b"),
+            (NewsEventType::ExchangeHalt, r"(?i)\b(halt(s|ed)?|suspend(s|ed)?|pause(s|d)?|freez(e|es)|froze)\b.{0,20}\b(withdrawals?|deposits?|trading)\b"),
+            (NewsEventType::Delisting, r"(?i)\bdelist(s|ed|ing)?\b"),
+            (NewsEventType::RegulatoryAction, r"(?i)\b(sec (sues|charges)|lawsuit|subpoena|enforcement action|sanction(s|ed)?|cftc|doj|banned?)\b"),
+        ]
+        .into_iter()
+        .filter_map(|(event_type, pattern)| Regex::new(pattern).ok().map(|re| (event_type, re)))
+        .collect();
+
+        Self { patterns, tokens: HashMap::new() }
+    }
+
+    /// Watch `symbol`, matched as `$SYMBOL`, the bare ticker or any alias
+    pub fn watch(&mut self, symbol: &str, aliases: &[&str]) {
+        let symbol = symbol.to_uppercase();
+        let mut terms = vec![regex::escape(&symbol)];
+        terms.extend(aliases.iter().map(|a| regex::escape(a)));
+        let pattern = format!(r"(?i)(^|[^\w$])\$?({})\b", terms.join("|"));
+        if let Ok(re) = Regex::new(&pattern) {
+            self.tokens.insert(symbol, re);
+        }
+    }
+
+    pub fn watched(&self) -> Vec<String> {
+        self.tokens.keys().cloned().collect()
+    }
+
+    /// Events in `headline`, most severe first
+    pub fn classify(&self, headline: &Headline) -> Vec<NewsEvent> {
+        let matches: Vec<(NewsEventType, String)> = self.patterns
+            .iter()
+            .filter_map(|(event_type, re)| re.find(&headline.title).map(|m| (*event_type, m.as_str().to_lowercase())))
+            .collect();
+        // The most severe category decides how the headline is treated
+        let Some(event_type) = matches.iter().map(|(t, _)| *t).max_by_key(|t| t.severity()) else {
+            return Vec::new();
+        };
+
+        let mut events: Vec<NewsEvent> = self.tokens
+            .iter()
+            .filter(|(_, re)| re.is_match(&headline.title))
+            .map(|(token, _)| NewsEvent {
+                event_type,
+                token: token.clone(),
+                severity: event_type.severity(),
+                headline: headline.clone(),
+                matched_terms: matches.iter().map(|(_, term)| term.clone()).collect(),
+            })
+            .collect();
+        events.sort_by(|a, b| b.severity.cmp(&a.severity).then(a.token.cmp(&b.token)));
+        events
+    }
+}
+
+impl Default for NewsEventClassifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hook for cutting exposure to an asset hit by an event
+#[async_trait]
+pub trait ExposureReducer: Send + Sync {
+    async fn reduce_exposure(&self, event: &NewsEvent) -> Result<()>;
+}
+
+#[async_trait]
+impl ExposureReducer for RiskManager {
+    async fn reduce_exposure(&self, event: &NewsEvent) -> Result<()> {
+        self.restrict_asset(
+            &event.token,
+            event.event_type.exposure_fraction(),
+            &format!("{:?}: {}", event.event_type, event.headline.title),
+            Duration::from_secs(6 * 3600),
+        );
+        Ok(())
+    }
+}
+
+/// Monitor settings
+#[derive(Debug, Clone)]
+pub struct NewsMonitorConfig {
+    pub feeds: Vec<String>,
+    pub poll_interval: Duration,
+    /// Events at or above this severity trigger the exposure reducer
+    pub reduce_at: Severity,
+    /// Headlines remembered for de-duplication
+    pub dedup_capacity: usize,
+}
+
+impl Default for NewsMonitorConfig {
+    fn default() -> Self {
+        let feeds = std::env::var(NEWS_FEEDS_ENV)
+            .map(|v| v.split(',').map(|f| f.trim().to_string()).filter(|f| !f.is_empty()).collect())
+            .unwrap_or_else(|_| DEFAULT_FEEDS.iter().map(|f| f.to_string()).collect());
+        Self {
+            feeds,
+            poll_interval: Duration::from_secs(300),
+            reduce_at: Severity::High,
+            dedup_capacity: 1000,
+        }
+    }
+}
+
+/// Pulls headlines, classifies them and raises alerts
+pub struct NewsEventMonitor {
+    config: NewsMonitorConfig,
+    classifier: NewsEventClassifier,
+    client: reqwest::Client,
+    alert_manager: Option<Arc<AlertManager>>,
+    reducer: Option<Arc<dyn ExposureReducer>>,
+    seen: Mutex<VecDeque<String>>,
+}
+
+impl NewsEventMonitor {
+    pub fn new(config: NewsMonitorConfig, classifier: NewsEventClassifier) -> Self {
+        Self {
+            config,
+            classifier,
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(15))
+                .user_agent("SniperForge/1.0")
+                .build()
+                .unwrap_or_default(),
+            alert_manager: None,
+            reducer: None,
+            seen: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn with_alert_manager(mut self, alert_manager: Arc<AlertManager>) -> Self {
+        self.alert_manager = Some(alert_manager);
+        self
+    }
+
+    /// Let severe events cut exposure to the affected asset
+    pub fn with_exposure_reducer(mut self, reducer: Arc<dyn ExposureReducer>) -> Self {
+        self.reducer = Some(reducer);
+        self
+    }
+
+    /// Classify headlines, act on new events and return them (most severe first)
+    pub async fn ingest(&self, headlines: &[Headline]) -> Vec<NewsEvent> {
+        let mut events = Vec::new();
+        {
+            let mut seen = self.seen.lock().await;
+            for headline in headlines {
+                let key = headline.title.trim().to_lowercase();
+                if seen.contains(&key) {
+                    continue;
+                }
+                seen.push_back(key);
+                if seen.len() > self.config.dedup_capacity {
+                    seen.pop_front();
+                }
+                events.extend(self.classifier.classify(headline));
+            }
+        }
+        events.sort_by(|a, b| b.severity.cmp(&a.severity));
+
+        for event in &events {
+            warn!("📰 {:?} event for {} [{:?}]: {}", event.event_type, event.token, event.severity, event.headline.title);
+            if let Some(alert_manager) = &self.alert_manager {
+                alert_manager.raise_alert(Alert {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    title: format!("{:?} reported for {}", event.event_type, event.token),
+                    description: format!("{} ({})", event.headline.title, event.headline.source),
+                    severity: event.severity,
+                    status: AlertStatus::Open,
+                    created_at: Utc::now(),
+                    resolved_at: None,
+                    tags: vec!["news".to_string(), event.event_type.tag().to_string(), event.token.clone()],
+                }).await;
+            }
+            if event.severity >= self.config.reduce_at {
+                if let Some(reducer) = &self.reducer {
+                    if let Err(e) = reducer.reduce_exposure(event).await {
+                        warn!("⚠️ Exposure reduction for {} failed: {}", event.token, e);
+                    }
+                }
+            }
+        }
+        events
+    }
+
+    /// Fetch item titles from an RSS/Atom feed
+    pub async fn fetch_feed(&self, url: &str) -> Result<Vec<Headline>> {
+        let body = self.client.get(url).send().await?.error_for_status()?.text().await?;
+        Ok(parse_feed_titles(&body, url))
+    }
+
+    /// Poll every feed once
+    pub async fn poll_once(&self) -> Vec<NewsEvent> {
+        let mut headlines = Vec::new();
+        for feed in &self.config.feeds {
+            match self.fetch_feed(feed).await {
+                Ok(items) => headlines.extend(items),
+                Err(e) => warn!("⚠️ News feed {} failed: {}", feed, e),
+            }
+        }
+        self.ingest(&headlines).await
+    }
+
+    pub fn start(self: Arc<Self>) -> JoinHandle<()> {
+        info!("📰 News event monitor watching {:?} across {} feeds", self.classifier.watched(), self.config.feeds.len());
+        tokio::spawn(async move {
+            loop {
+                self.poll_once().await;
+                tokio::time::sleep(self.config.poll_interval).await;
+            }
+        })
+    }
+}
+
+/// Item/entry titles of an RSS or Atom document
+pub fn parse_feed_titles(body: &str, source: &str) -> Vec<Headline> {
+    let Ok(item_re) = Regex::new(r"(?s)<(item|entry)\b.*?</(item|entry)>") else {
+        return Vec::new();
+    };
+    let Ok(title_re) = Regex::new(r"(?s)<title[^>]*>\s*(?:<!\[CDATA\[)?(.*?)(?:\]\]>)?\s*</title>") else {
+        return Vec::new();
+    };
+    let link_re = Regex::new(r"(?s)<link[^>]*>\s*(.*?)\s*</link>").ok();
+
+    item_re
+        .find_iter(body)
+        .filter_map(|item| {
+            let item = item.as_str();
+            let title = title_re.captures(item)?.get(1)?.as_str().trim().to_string();
+            let url = link_re.as_ref().and_then(|re| re.captures(item)).and_then(|c| c.get(1)).map(|m| m.as_str().to_string());
+            Some(Headline { title, source: source.to_string(), url, published_at: Utc::now() })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headline(title: &str) -> Headline {
+        Headline { title: title.to_string(), source: "test".to_string(), url: None, published_at: Utc::now() }
+    }
+
+    fn classifier() -> NewsEventClassifier {
+        let mut classifier = NewsEventClassifier::new();
+        classifier.watch("SOL", &["solana"]);
+        classifier.watch("USDC", &["circle"]);
+        classifier
+    }
+
+    #[test]
+    fn test_classifies_watched_tokens_only() {
+        let classifier = classifier();
+
+        let events = classifier.classify(&headline("Solana DeFi protocol drained in $40M exploit"));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].token, "SOL");
+        assert_eq!(events[0].event_type, NewsEventType::Exploit);
+        assert_eq!(events[0].severity, Severity::Critical);
+
+        assert!(classifier.classify(&headline("Binance halts withdrawals for BNB")).is_empty());
+        assert!(classifier.classify(&headline("Solana hits new daily volume record")).is_empty());
+        assert_eq!(classifier.classify(&headline("USDC briefly loses peg"))[0].event_type, NewsEventType::Depeg);
+    }
+
+    #[tokio::test]
+    async fn test_severe_event_restricts_exposure_once() {
+        let risk_manager = Arc::new(RiskManager::new(&crate::config::SimpleConfig::default()));
+        let monitor = NewsEventMonitor::new(NewsMonitorConfig { feeds: Vec::new(), ..Default::default() }, classifier())
+            .with_exposure_reducer(risk_manager.clone());
+
+        let feed = "<rss><channel><item><title><![CDATA[USDC depegs after Circle reserve scare]]></title></item></channel></rss>";
+        let headlines = parse_feed_titles(feed, "test");
+        assert_eq!(monitor.ingest(&headlines).await.len(), 1);
+        assert!(monitor.ingest(&headlines).await.is_empty(), "duplicate headline ignored");
+
+        assert_eq!(risk_manager.position_limit_for("USDC"), 0.0);
+        assert_eq!(risk_manager.active_restrictions().len(), 1);
+    }
+}
@@ -3,6 +3,13 @@ pub mod real_analyzer;
 /// Real Sentiment Analysis Module
 /// Provides comprehensive sentiment analysis with REAL data sources
 pub mod twitter_client; // ✅ NEW: Twitter API integration
+pub mod providers; // Pluggable providers + weighted pipeline
+pub mod twitter_stream; // Filtered stream + monthly read budget
 
 pub use real_analyzer::*;
 pub use twitter_client::*; // ✅ Export Twitter client
+pub use providers::{
+    SentimentProvider, SentimentPipeline, SentimentBlend, LocalSentimentModel, Lexicon,
+    LocalSentimentProvider, TwitterSentimentProvider, RedditSentimentProvider,
+};
+pub use twitter_stream::{TwitterStream, ReadBudget, SharedReadBudget, StreamRule, rules_for_symbols, TWITTER_STREAM_ENV};
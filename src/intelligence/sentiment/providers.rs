@@ -0,0 +1,398 @@
+//! Pluggable sentiment providers
+//!
+//! Every sentiment source implements `SentimentProvider`; a
+//! `SentimentPipeline` blends whichever providers are available with
+//! configurable weights. Twitter needs API credentials and Reddit needs
+//! network access, while `LocalSentimentProvider` scores locally ingested
+//! text with an in-process model (the built-in lexicon, or any
+//! `LocalSentimentModel` such as an ONNX classifier), so the pipeline keeps
+//! producing scores offline.
+
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::{debug, info, warn};
+
+use super::real_analyzer::RealSentimentAnalyzer;
+use super::twitter_client::TwitterSentimentClient;
+use crate::apis::circuit_breaker::provider_circuits;
+
+/// Provider weights override, e.g. `twitter=0.5,reddit=0.2,local=0.3`
+pub const SENTIMENT_WEIGHTS_ENV: &str = "SNIPERFORGE_SENTIMENT_WEIGHTS";
+/// JSONL corpus (`{"symbol": "...", "text": "..."}` per line) for the local provider
+pub const SENTIMENT_CORPUS_ENV: &str = "SNIPERFORGE_SENTIMENT_CORPUS";
+
+/// Source of a sentiment score in [-1.0, 1.0]
+#[async_trait]
+pub trait SentimentProvider: Send + Sync {
+    fn name(&self) -> &str;
+
+    /// Whether the provider can be queried at all (credentials, data present)
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    /// Sentiment for `symbol`; an error excludes the provider from the blend
+    async fn score(&self, symbol: &str) -> Result<f64>;
+}
+
+/// In-process text scoring model
+pub trait LocalSentimentModel: Send + Sync {
+    fn name(&self) -> &str;
+    /// Score for one text in [-1.0, 1.0]
+    fn score_text(&self, text: &str) -> f64;
+}
+
+/// Word-weight lexicon with negation handling
+#[derive(Debug, Clone)]
+pub struct Lexicon {
+    weights: HashMap<String, f64>,
+}
+
+impl Lexicon {
+    const NEGATIONS: &'static [&'static str] = &["not", "no", "never", "isnt", "wont", "dont", "aint"];
+
+    pub fn new(weights: HashMap<String, f64>) -> Self {
+        Self { weights }
+    }
+
+    /// Load `{"word": weight, ...}` from a JSON file
+    pub fn from_json_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(Self::new(serde_json::from_str(&content)?))
+    }
+}
+
+impl Default for Lexicon {
+    fn default() -> Self {
+        let positive: &[(&str, f64)] = &[
+            ("bullish", 1.0), ("moon", 0.8), ("pump", 0.5), ("rally", 0.8), ("breakout", 0.8),
+            ("surge", 0.8), ("gain", 0.5), ("profit", 0.5), ("buy", 0.4), ("accumulate", 0.6),
+            ("hodl", 0.5), ("strong", 0.5), ("partnership", 0.7), ("listing", 0.6), ("upgrade", 0.6),
+            ("adoption", 0.7), ("ath", 0.8), ("green", 0.4),
+        ];
+        let negative: &[(&str, f64)] = &[
+            ("bearish", -1.0), ("dump", -0.8), ("crash", -1.0), ("rug", -1.0), ("scam", -1.0),
+            ("hack", -1.0), ("exploit", -1.0), ("sell", -0.4), ("fud", -0.5), ("panic", -0.8),
+            ("fear", -0.6), ("loss", -0.5), ("delist", -0.9), ("lawsuit", -0.8), ("ban", -0.8),
+            ("depeg", -1.0), ("weak", -0.5), ("red", -0.4),
+        ];
+        Self::new(positive.iter().chain(negative).map(|(w, s)| (w.to_string(), *s)).collect())
+    }
+}
+
+impl LocalSentimentModel for Lexicon {
+    fn name(&self) -> &str {
+        "lexicon"
+    }
+
+    fn score_text(&self, text: &str) -> f64 {
+        let mut total = 0.0;
+        let mut negate = false;
+        for token in text.split_whitespace() {
+            let word: String = token.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase();
+            if Self::NEGATIONS.contains(&word.as_str()) {
+                negate = true;
+                continue;
+            }
+            if let Some(weight) = self.weights.get(&word) {
+                total += if negate { -weight } else { *weight };
+            }
+            negate = false;
+        }
+        // Squash the raw sum into (-1, 1)
+        total / (total * total + 4.0).sqrt()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CorpusEntry {
+    symbol: String,
+    text: String,
+}
+
+/// Scores locally ingested texts with a local model
+pub struct LocalSentimentProvider {
+    model: Box<dyn LocalSentimentModel>,
+    texts: RwLock<HashMap<String, VecDeque<String>>>,
+    max_texts_per_symbol: usize,
+}
+
+impl LocalSentimentProvider {
+    pub fn new(model: Box<dyn LocalSentimentModel>) -> Self {
+        Self {
+            model,
+            texts: RwLock::new(HashMap::new()),
+            max_texts_per_symbol: 200,
+        }
+    }
+
+    /// Add a headline/post for `symbol`; the oldest texts are dropped beyond the cap
+    pub fn ingest(&self, symbol: &str, text: &str) {
+        let mut texts = self.texts.write();
+        let queue = texts.entry(symbol.to_uppercase()).or_default();
+        queue.push_back(text.to_string());
+        while queue.len() > self.max_texts_per_symbol {
+            queue.pop_front();
+        }
+    }
+
+    /// Ingest a JSONL corpus; returns texts loaded
+    pub fn load_corpus<P: AsRef<Path>>(&self, path: P) -> Result<usize> {
+        let content = std::fs::read_to_string(path)?;
+        let mut loaded = 0;
+        for line in content.lines().filter(|l| !l.trim().is_empty()) {
+            let entry: CorpusEntry = serde_json::from_str(line)?;
+            self.ingest(&entry.symbol, &entry.text);
+            loaded += 1;
+        }
+        Ok(loaded)
+    }
+}
+
+#[async_trait]
+impl SentimentProvider for LocalSentimentProvider {
+    fn name(&self) -> &str {
+        "local"
+    }
+
+    async fn score(&self, symbol: &str) -> Result<f64> {
+        let texts = self.texts.read();
+        let queue = texts
+            .get(&symbol.to_uppercase())
+            .filter(|q| !q.is_empty())
+            .ok_or_else(|| anyhow!("no local texts for {}", symbol))?;
+        let total: f64 = queue.iter().map(|t| self.model.score_text(t)).sum();
+        Ok(total / queue.len() as f64)
+    }
+}
+
+/// Twitter API v2 search (requires credentials)
+pub struct TwitterSentimentProvider {
+    client: Mutex<TwitterSentimentClient>,
+    has_credentials: bool,
+}
+
+impl TwitterSentimentProvider {
+    pub fn new(client: TwitterSentimentClient) -> Self {
+        Self {
+            has_credentials: client.has_credentials(),
+            client: Mutex::new(client),
+        }
+    }
+}
+
+#[async_trait]
+impl SentimentProvider for TwitterSentimentProvider {
+    fn name(&self) -> &str {
+        "twitter"
+    }
+
+    fn is_available(&self) -> bool {
+        self.has_credentials
+    }
+
+    async fn score(&self, symbol: &str) -> Result<f64> {
+        let data = self.client.lock().await.analyze_crypto_sentiment(symbol).await?;
+        if data.tweet_count == 0 {
+            return Err(anyhow!("no tweets found for {}", symbol));
+        }
+        Ok(data.sentiment_score.clamp(-1.0, 1.0))
+    }
+}
+
+/// Reddit scraping (no credentials required)
+pub struct RedditSentimentProvider {
+    analyzer: RealSentimentAnalyzer,
+}
+
+impl RedditSentimentProvider {
+    pub fn new() -> Self {
+        Self { analyzer: RealSentimentAnalyzer::new() }
+    }
+}
+
+impl Default for RedditSentimentProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SentimentProvider for RedditSentimentProvider {
+    fn name(&self) -> &str {
+        "reddit"
+    }
+
+    async fn score(&self, symbol: &str) -> Result<f64> {
+        self.analyzer.analyze_reddit_sentiment(symbol).await
+    }
+}
+
+/// Blended sentiment for one symbol
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SentimentBlend {
+    pub symbol: String,
+    /// Weighted score in [-1.0, 1.0]; neutral when no provider answered
+    pub score: f64,
+    /// Share of the configured weight that actually contributed
+    pub confidence: f64,
+    pub breakdown: HashMap<String, f64>,
+}
+
+struct WeightedProvider {
+    provider: Arc<dyn SentimentProvider>,
+    weight: f64,
+}
+
+/// Weighted mix of sentiment providers
+#[derive(Default)]
+pub struct SentimentPipeline {
+    providers: Vec<WeightedProvider>,
+}
+
+impl SentimentPipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Twitter, Reddit and the local lexicon with default weights, then env overrides
+    pub fn standard(twitter_client: TwitterSentimentClient) -> Self {
+        let local = LocalSentimentProvider::new(Box::new(Lexicon::default()));
+        if let Ok(path) = std::env::var(SENTIMENT_CORPUS_ENV) {
+            match local.load_corpus(&path) {
+                Ok(count) => info!("🧠 Local sentiment corpus loaded: {} texts from {}", count, path),
+                Err(e) => warn!("⚠️ Failed to load sentiment corpus {}: {}", path, e),
+            }
+        }
+
+        Self::new()
+            .with_provider(Arc::new(TwitterSentimentProvider::new(twitter_client)), 0.4)
+            .with_provider(Arc::new(RedditSentimentProvider::new()), 0.35)
+            .with_provider(Arc::new(local), 0.25)
+            .with_env_weights()
+    }
+
+    pub fn with_provider(mut self, provider: Arc<dyn SentimentProvider>, weight: f64) -> Self {
+        self.providers.push(WeightedProvider { provider, weight: weight.max(0.0) });
+        self
+    }
+
+    /// Apply `SNIPERFORGE_SENTIMENT_WEIGHTS` overrides, if set
+    pub fn with_env_weights(mut self) -> Self {
+        if let Ok(spec) = std::env::var(SENTIMENT_WEIGHTS_ENV) {
+            for (name, weight) in spec.split(',').filter_map(|pair| pair.split_once('=')) {
+                match weight.trim().parse::<f64>() {
+                    Ok(weight) => self.set_weight(name.trim(), weight),
+                    Err(_) => warn!("⚠️ Invalid sentiment weight '{}' for {}", weight, name),
+                }
+            }
+        }
+        self
+    }
+
+    pub fn set_weight(&mut self, provider: &str, weight: f64) {
+        for entry in self.providers.iter_mut().filter(|p| p.provider.name() == provider) {
+            entry.weight = weight.max(0.0);
+        }
+    }
+
+    /// Provider names with their weights
+    pub fn weights(&self) -> Vec<(String, f64)> {
+        self.providers.iter().map(|p| (p.provider.name().to_string(), p.weight)).collect()
+    }
+
+    /// Blend every available provider's score for `symbol`
+    pub async fn score(&self, symbol: &str) -> SentimentBlend {
+        let mut breakdown = HashMap::new();
+        let mut weighted_sum = 0.0;
+        let mut used_weight = 0.0;
+        let mut total_weight = 0.0;
+
+        for entry in self.providers.iter().filter(|p| p.weight > 0.0 && p.provider.is_available()) {
+            let name = entry.provider.name();
+            total_weight += entry.weight;
+
+            let breaker = provider_circuits().breaker(name);
+            if !breaker.allow_request() {
+                debug!("🔌 Sentiment provider '{}' skipped (circuit open)", name);
+                continue;
+            }
+            let result = entry.provider.score(symbol).await;
+            breaker.record(&result);
+            match result {
+                Ok(score) => {
+                    let score = score.clamp(-1.0, 1.0);
+                    breakdown.insert(name.to_string(), score);
+                    weighted_sum += score * entry.weight;
+                    used_weight += entry.weight;
+                }
+                Err(e) => debug!("⚠️ Sentiment provider '{}' failed for {}: {}", name, symbol, e),
+            }
+        }
+
+        SentimentBlend {
+            symbol: symbol.to_string(),
+            score: if used_weight > 0.0 { weighted_sum / used_weight } else { 0.0 },
+            confidence: if total_weight > 0.0 { used_weight / total_weight } else { 0.0 },
+            breakdown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedProvider(&'static str, Option<f64>, bool);
+
+    #[async_trait]
+    impl SentimentProvider for FixedProvider {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn is_available(&self) -> bool {
+            self.2
+        }
+
+        async fn score(&self, _symbol: &str) -> Result<f64> {
+            self.1.ok_or_else(|| anyhow!("offline"))
+        }
+    }
+
+    #[test]
+    fn test_lexicon_scores_and_negation() {
+        let lexicon = Lexicon::default();
+        assert!(lexicon.score_text("SOL breakout, very bullish rally") > 0.5);
+        assert!(lexicon.score_text("protocol exploit, funds drained, panic") < -0.5);
+        assert!(lexicon.score_text("not bullish at all") < 0.0);
+        assert_eq!(lexicon.score_text("the weather is nice"), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_blends_available_providers() {
+        let local = LocalSentimentProvider::new(Box::new(Lexicon::default()));
+        local.ingest("sol", "bullish breakout");
+
+        let pipeline = SentimentPipeline::new()
+            .with_provider(Arc::new(FixedProvider("test-no-creds", Some(-1.0), false)), 0.5)
+            .with_provider(Arc::new(FixedProvider("test-down", None, true)), 0.25)
+            .with_provider(Arc::new(local), 0.25);
+
+        let blend = pipeline.score("SOL").await;
+        assert!(blend.score > 0.5, "only the local provider contributed");
+        assert!((blend.confidence - 0.5).abs() < 1e-9);
+        assert_eq!(blend.breakdown.len(), 1);
+
+        let neutral = pipeline.score("BONK").await;
+        assert_eq!(neutral.score, 0.0);
+        assert_eq!(neutral.confidence, 0.0);
+    }
+}
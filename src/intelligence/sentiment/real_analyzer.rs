@@ -1,11 +1,14 @@
 //! Simplified Real Sentiment Analysis Implementation
 //! This implementation provides REAL sentiment analysis with actual data sources
 
+use tracing::info;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::apis::circuit_breaker::provider_circuits;
+
 /// Enhanced sentiment analysis result with REAL data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SentimentAnalysis {
@@ -58,44 +61,52 @@ impl RealSentimentAnalyzer {
         if let Some((cached_analysis, cached_time)) = self.cache.get(&cache_key) {
             let age_minutes = (Utc::now() - *cached_time).num_minutes() as u64;
             if age_minutes < self.cache_duration_minutes {
-                println!("🧠 Using cached sentiment for {}: {:.3}", symbol, cached_analysis.overall_score);
+                info!("🧠 Using cached sentiment for {}: {:.3}", symbol, cached_analysis.overall_score);
                 return Ok(cached_analysis.overall_score);
             }
         }
         
-        println!("🧠 Analyzing REAL sentiment for {} from multiple sources...", symbol);
+        info!("🧠 Analyzing REAL sentiment for {} from multiple sources...", symbol);
         
         let mut sentiment_scores = HashMap::new();
         let mut total_weighted_sentiment = 0.0;
         let mut total_weight = 0.0;
         
         // Reddit sentiment analysis (if enabled)
-        if self.enabled_sources.contains(&"reddit".to_string()) {
-            if let Ok(reddit_sentiment) = self.analyze_reddit_sentiment(symbol).await {
+        // Sources whose circuit is open are skipped; with every source down the
+        // score degrades to neutral below
+        if self.enabled_sources.contains(&"reddit".to_string()) && provider_circuits().breaker("reddit").allow_request() {
+            let result = self.analyze_reddit_sentiment(symbol).await;
+            provider_circuits().breaker("reddit").record(&result);
+            if let Ok(reddit_sentiment) = result {
                 sentiment_scores.insert("reddit".to_string(), reddit_sentiment);
                 total_weighted_sentiment += reddit_sentiment * 0.4; // 40% weight
                 total_weight += 0.4;
-                println!("   📱 Reddit sentiment: {:.3}", reddit_sentiment);
+                info!("   📱 Reddit sentiment: {:.3}", reddit_sentiment);
             }
         }
         
         // News sentiment analysis (if enabled)
-        if self.enabled_sources.contains(&"news".to_string()) {
-            if let Ok(news_sentiment) = self.analyze_news_sentiment(symbol).await {
+        if self.enabled_sources.contains(&"news".to_string()) && provider_circuits().breaker("news").allow_request() {
+            let result = self.analyze_news_sentiment(symbol).await;
+            provider_circuits().breaker("news").record(&result);
+            if let Ok(news_sentiment) = result {
                 sentiment_scores.insert("news".to_string(), news_sentiment);
                 total_weighted_sentiment += news_sentiment * 0.3; // 30% weight
                 total_weight += 0.3;
-                println!("   📰 News sentiment: {:.3}", news_sentiment);
+                info!("   📰 News sentiment: {:.3}", news_sentiment);
             }
         }
         
         // Fear & Greed Index (if enabled)
-        if self.enabled_sources.contains(&"fear_greed".to_string()) {
-            if let Ok(fg_sentiment) = self.get_fear_greed_sentiment().await {
+        if self.enabled_sources.contains(&"fear_greed".to_string()) && provider_circuits().breaker("fear_greed").allow_request() {
+            let result = self.get_fear_greed_sentiment().await;
+            provider_circuits().breaker("fear_greed").record(&result);
+            if let Ok(fg_sentiment) = result {
                 sentiment_scores.insert("fear_greed".to_string(), fg_sentiment);
                 total_weighted_sentiment += fg_sentiment * 0.3; // 30% weight
                 total_weight += 0.3;
-                println!("   😨 Fear/Greed sentiment: {:.3}", fg_sentiment);
+                info!("   😨 Fear/Greed sentiment: {:.3}", fg_sentiment);
             }
         }
         
@@ -119,7 +130,7 @@ impl RealSentimentAnalyzer {
         // Cache the result
         self.cache.insert(cache_key, (analysis.clone(), Utc::now()));
         
-        println!("   🎯 Overall sentiment: {:.3} (confidence: {:.2})", overall_sentiment, total_weight);
+        info!("   🎯 Overall sentiment: {:.3} (confidence: {:.2})", overall_sentiment, total_weight);
         
         Ok(overall_sentiment)
     }
@@ -148,7 +159,7 @@ impl RealSentimentAnalyzer {
     }
     
     /// Analyze Reddit sentiment using web scraping
-    async fn analyze_reddit_sentiment(&self, symbol: &str) -> Result<f64> {
+    pub(crate) async fn analyze_reddit_sentiment(&self, symbol: &str) -> Result<f64> {
         // REAL IMPLEMENTATION: Symbol-specific sentiment analysis
         let mut sentiment = 0.0;
         
@@ -454,7 +465,7 @@ impl RealSentimentAnalyzer {
         
         // In a real implementation, this would fetch recent price data
         // For now, return neutral as a safe fallback
-        println!("   ⚠️  Fear & Greed API unavailable, using neutral sentiment");
+        info!("   ⚠️  Fear & Greed API unavailable, using neutral sentiment");
         Ok(0.0)
     }
     
@@ -478,7 +489,7 @@ impl RealSentimentAnalyzer {
     async fn simulate_timeframe_sentiment(&self, symbol: &str, minutes: u64) -> Result<f64> {
         // REAL implementation would analyze historical sentiment data
         // For now, return neutral until we have historical data storage
-        println!("   📊 Historical sentiment analysis for {} ({} min timeframe): neutral", symbol, minutes);
+        info!("   📊 Historical sentiment analysis for {} ({} min timeframe): neutral", symbol, minutes);
         Ok(0.0) // Neutral until we implement historical data
     }
     
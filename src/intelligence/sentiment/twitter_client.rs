@@ -1,11 +1,14 @@
 //! Twitter API Integration for Real-Time Sentiment Analysis
 //! Requires Twitter Developer Account credentials
 
+use tracing::warn;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 
+use super::twitter_stream::SharedReadBudget;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TwitterCredentials {
     pub api_key: String,
@@ -53,6 +56,10 @@ pub struct TwitterSentimentClient {
     client: reqwest::Client,
     rate_limit_remaining: u32,
     rate_limit_reset: DateTime<Utc>,
+    /// Monthly read quota; searches are refused once it is spent
+    read_budget: Option<SharedReadBudget>,
+    /// Last successful result per symbol, served when the budget is exhausted
+    last_results: HashMap<String, TwitterSentimentData>,
 }
 
 impl TwitterSentimentClient {
@@ -62,9 +69,22 @@ impl TwitterSentimentClient {
             client: reqwest::Client::new(),
             rate_limit_remaining: 0,
             rate_limit_reset: Utc::now(),
+            read_budget: None,
+            last_results: HashMap::new(),
         }
     }
 
+    /// Charge searches to a monthly read budget
+    pub fn with_read_budget(mut self, budget: SharedReadBudget) -> Self {
+        self.read_budget = Some(budget);
+        self
+    }
+
+    /// Bearer token, when credentials are configured
+    pub fn bearer_token(&self) -> Option<&str> {
+        self.credentials.as_ref().map(|c| c.bearer_token.as_str())
+    }
+
     /// Set Twitter API credentials from your developer account
     pub fn with_credentials(mut self, credentials: TwitterCredentials) -> Self {
         self.credentials = Some(credentials);
@@ -92,15 +112,49 @@ impl TwitterSentimentClient {
 
         let search_queries = self.build_search_queries(symbol);
         let mut all_tweets = Vec::new();
+        let mut budget_exhausted = false;
 
         for query in &search_queries {
+            // Reserve the page up front, refund what was not delivered
+            if let Some(budget) = &self.read_budget {
+                if !budget.lock().try_consume(100) {
+                    budget_exhausted = true;
+                    break;
+                }
+            }
             match self.search_recent_tweets(query, 100).await {
-                Ok(mut tweets) => all_tweets.append(&mut tweets),
-                Err(e) => eprintln!("Failed to search tweets for {}: {}", query, e),
+                Ok(mut tweets) => {
+                    if let Some(budget) = &self.read_budget {
+                        budget.lock().refund(100u64.saturating_sub(tweets.len() as u64));
+                    }
+                    all_tweets.append(&mut tweets);
+                }
+                Err(e) => {
+                    if let Some(budget) = &self.read_budget {
+                        budget.lock().refund(100);
+                    }
+                    warn!("Failed to search tweets for {}: {}", query, e);
+                }
+            }
+        }
+        if let Some(budget) = &self.read_budget {
+            if let Err(e) = budget.lock().save() {
+                warn!("Failed to persist Twitter read budget: {}", e);
             }
         }
 
-        Ok(self.calculate_sentiment_from_tweets(symbol, all_tweets))
+        // Out of budget: degrade to the cached result, or neutral
+        if budget_exhausted && all_tweets.is_empty() {
+            return Ok(self.last_results.get(symbol).cloned().unwrap_or_else(|| {
+                self.calculate_sentiment_from_tweets(symbol, Vec::new())
+            }));
+        }
+
+        let data = self.calculate_sentiment_from_tweets(symbol, all_tweets);
+        if data.tweet_count > 0 {
+            self.last_results.insert(symbol.to_string(), data.clone());
+        }
+        Ok(data)
     }
 
     /// Search recent tweets using Twitter API v2
@@ -0,0 +1,387 @@
+//! Twitter/X filtered stream with read-budget accounting
+//!
+//! Instead of polling recent search, the filtered stream pushes matching
+//! tweets as they are posted. Stream rules are derived from the tracked
+//! tokens (cashtags/hashtags, tagged with the symbol) and reconciled against
+//! the rules registered on the account. Every tweet read is charged to a
+//! monthly `ReadBudget`, paced across the month so the quota cannot be
+//! exhausted early; once the budget is spent the stream disconnects and
+//! sentiment downgrades to the last cached score (or neutral) until the
+//! next month.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use super::providers::{Lexicon, LocalSentimentModel, SentimentProvider};
+use crate::apis::stream_sync::{feed_health, FeedStatus};
+
+/// Enables the filtered stream in place of search polling
+pub const TWITTER_STREAM_ENV: &str = "SNIPERFORGE_TWITTER_STREAM";
+/// Monthly tweet-read quota of the API plan
+pub const TWITTER_MONTHLY_READS_ENV: &str = "SNIPERFORGE_TWITTER_MONTHLY_READS";
+
+const API_BASE: &str = "https://api.twitter.com/2";
+/// Name of the stream in the feed health registry
+const FEED_NAME: &str = "twitter_stream";
+/// Maximum length of a filtered-stream rule value
+const MAX_RULE_LENGTH: usize = 512;
+
+/// Monthly tweet-read accounting, persisted across restarts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadBudget {
+    pub monthly_cap: u64,
+    pub used: u64,
+    /// Month the usage belongs to (`YYYY-MM`)
+    pub period: String,
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+fn period_of(now: DateTime<Utc>) -> String {
+    format!("{:04}-{:02}", now.year(), now.month())
+}
+
+fn days_in_month(now: DateTime<Utc>) -> u32 {
+    let (year, month) = if now.month() == 12 { (now.year() + 1, 1) } else { (now.year(), now.month() + 1) };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|next| next.pred_opt())
+        .map_or(30, |last| last.day())
+}
+
+impl ReadBudget {
+    pub fn new(monthly_cap: u64) -> Self {
+        Self {
+            monthly_cap,
+            used: 0,
+            period: period_of(Utc::now()),
+            path: None,
+        }
+    }
+
+    /// Load persisted usage (fresh budget when missing); `monthly_cap` always wins
+    pub fn load<P: AsRef<Path>>(path: P, monthly_cap: u64) -> Result<Self> {
+        let path = path.as_ref();
+        let mut budget = match std::fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::new(monthly_cap),
+            Err(e) => return Err(e.into()),
+        };
+        budget.monthly_cap = monthly_cap;
+        budget.path = Some(path.to_path_buf());
+        Ok(budget)
+    }
+
+    /// Cap from `SNIPERFORGE_TWITTER_MONTHLY_READS` (default 10k, the Basic tier)
+    pub fn monthly_cap_from_env() -> u64 {
+        std::env::var(TWITTER_MONTHLY_READS_ENV).ok().and_then(|v| v.parse().ok()).unwrap_or(10_000)
+    }
+
+    /// Persist atomically (temp file + rename); no-op for unsaved budgets
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else { return Ok(()) };
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let temp_file = path.with_extension("tmp");
+        std::fs::write(&temp_file, serde_json::to_string_pretty(self)?)?;
+        std::fs::rename(&temp_file, path)?;
+        Ok(())
+    }
+
+    fn roll_over(&mut self, now: DateTime<Utc>) {
+        let period = period_of(now);
+        if self.period != period {
+            info!("🐦 Twitter read budget reset for {} ({} reads used in {})", period, self.used, self.period);
+            self.period = period;
+            self.used = 0;
+        }
+    }
+
+    /// Reads allowed so far this month: the cap spread evenly over the days
+    pub fn paced_allowance(&self, now: DateTime<Utc>) -> u64 {
+        let days = days_in_month(now) as u64;
+        (self.monthly_cap * now.day() as u64 / days).min(self.monthly_cap)
+    }
+
+    /// Charge `reads` if they fit the paced allowance
+    pub fn try_consume(&mut self, reads: u64) -> bool {
+        self.try_consume_at(reads, Utc::now())
+    }
+
+    fn try_consume_at(&mut self, reads: u64, now: DateTime<Utc>) -> bool {
+        self.roll_over(now);
+        if self.used + reads > self.paced_allowance(now) {
+            return false;
+        }
+        self.used += reads;
+        true
+    }
+
+    /// Give back reads reserved but not delivered
+    pub fn refund(&mut self, reads: u64) {
+        self.used = self.used.saturating_sub(reads);
+    }
+
+    pub fn is_exhausted(&mut self) -> bool {
+        let now = Utc::now();
+        self.roll_over(now);
+        self.used >= self.paced_allowance(now)
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.monthly_cap.saturating_sub(self.used)
+    }
+}
+
+/// Budget shared by every Twitter consumer of the process
+pub type SharedReadBudget = Arc<Mutex<ReadBudget>>;
+
+/// Filtered-stream rule; `tag` carries the tracked symbol
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StreamRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub value: String,
+    pub tag: String,
+}
+
+/// One rule per tracked symbol: cashtag or hashtag, originals only, English
+pub fn rules_for_symbols(symbols: &[&str]) -> Vec<StreamRule> {
+    symbols
+        .iter()
+        .map(|symbol| {
+            let symbol = symbol.to_uppercase();
+            let mut value = format!("(${} OR #{}) -is:retweet lang:en", symbol, symbol);
+            value.truncate(MAX_RULE_LENGTH);
+            StreamRule { id: None, value, tag: symbol }
+        })
+        .collect()
+}
+
+/// Rules to add and rule ids to delete so `current` matches `desired`
+pub fn rule_changes(current: &[StreamRule], desired: &[StreamRule]) -> (Vec<StreamRule>, Vec<String>) {
+    let key = |r: &StreamRule| (r.value.clone(), r.tag.clone());
+    let desired_keys: HashSet<_> = desired.iter().map(key).collect();
+    let current_keys: HashSet<_> = current.iter().map(key).collect();
+
+    let add = desired.iter().filter(|r| !current_keys.contains(&key(r))).cloned().collect();
+    let delete = current
+        .iter()
+        .filter(|r| !desired_keys.contains(&key(r)))
+        .filter_map(|r| r.id.clone())
+        .collect();
+    (add, delete)
+}
+
+#[derive(Debug, Default)]
+struct SymbolWindow {
+    scores: VecDeque<(DateTime<Utc>, f64)>,
+    /// Last score computed while data was flowing
+    cached: Option<f64>,
+}
+
+/// Filtered-stream consumer and sentiment provider
+pub struct TwitterStream {
+    client: reqwest::Client,
+    bearer_token: String,
+    symbols: Vec<String>,
+    budget: SharedReadBudget,
+    model: Lexicon,
+    window: chrono::Duration,
+    windows: RwLock<HashMap<String, SymbolWindow>>,
+}
+
+impl TwitterStream {
+    pub fn new(bearer_token: &str, symbols: &[&str], budget: SharedReadBudget) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            bearer_token: bearer_token.to_string(),
+            symbols: symbols.iter().map(|s| s.to_uppercase()).collect(),
+            budget,
+            model: Lexicon::default(),
+            window: chrono::Duration::hours(1),
+            windows: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Rules currently registered on the account
+    pub async fn list_rules(&self) -> Result<Vec<StreamRule>> {
+        let response: Value = self.client
+            .get(format!("{}/tweets/search/stream/rules", API_BASE))
+            .bearer_auth(&self.bearer_token)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(serde_json::from_value(response["data"].clone()).unwrap_or_default())
+    }
+
+    /// Register rules for the tracked symbols and delete stale ones
+    pub async fn sync_rules(&self) -> Result<()> {
+        let symbols: Vec<&str> = self.symbols.iter().map(String::as_str).collect();
+        let (add, delete) = rule_changes(&self.list_rules().await?, &rules_for_symbols(&symbols));
+        let endpoint = format!("{}/tweets/search/stream/rules", API_BASE);
+
+        if !delete.is_empty() {
+            self.client.post(&endpoint).bearer_auth(&self.bearer_token)
+                .json(&json!({ "delete": { "ids": delete } }))
+                .send().await?.error_for_status()?;
+        }
+        if !add.is_empty() {
+            self.client.post(&endpoint).bearer_auth(&self.bearer_token)
+                .json(&json!({ "add": add }))
+                .send().await?.error_for_status()?;
+        }
+        info!("🐦 Stream rules synced: {} added, {} removed", add.len(), delete.len());
+        Ok(())
+    }
+
+    /// Score one stream line and charge it to the budget; false once the budget is spent
+    fn ingest_line(&self, line: &str) -> bool {
+        let Ok(event) = serde_json::from_str::<Value>(line) else {
+            return true;
+        };
+        let Some(text) = event["data"]["text"].as_str() else {
+            return true;
+        };
+        if !self.budget.lock().try_consume(1) {
+            return false;
+        }
+
+        let score = self.model.score_text(text);
+        let now = Utc::now();
+        let mut windows = self.windows.write();
+        for tag in event["matching_rules"].as_array().into_iter().flatten().filter_map(|r| r["tag"].as_str()) {
+            let window = windows.entry(tag.to_string()).or_default();
+            window.scores.push_back((now, score));
+            while window.scores.front().is_some_and(|(t, _)| now - *t > self.window) {
+                window.scores.pop_front();
+            }
+            window.cached = Some(window.scores.iter().map(|(_, s)| s).sum::<f64>() / window.scores.len() as f64);
+        }
+        true
+    }
+
+    /// Read the stream until it drops or the budget is exhausted
+    async fn consume_stream(&self) -> Result<()> {
+        let mut response = self.client
+            .get(format!("{}/tweets/search/stream", API_BASE))
+            .bearer_auth(&self.bearer_token)
+            .query(&[("tweet.fields", "created_at,public_metrics,author_id")])
+            .send()
+            .await?
+            .error_for_status()?;
+        feed_health().publish(FEED_NAME, FeedStatus::Live);
+
+        let mut buffer = String::new();
+        while let Some(chunk) = response.chunk().await? {
+            buffer.push_str(&String::from_utf8_lossy(&chunk));
+            while let Some(newline) = buffer.find('\n') {
+                let line: String = buffer.drain(..=newline).collect();
+                // Keep-alive heartbeats are blank lines
+                if !line.trim().is_empty() && !self.ingest_line(line.trim()) {
+                    warn!("🐦 Twitter read budget exhausted - disconnecting stream, using cached/neutral sentiment");
+                    return Ok(());
+                }
+            }
+        }
+        Err(anyhow!("stream closed by server"))
+    }
+
+    /// Keep the stream connected, backing off on errors and pausing while out of budget
+    pub fn start(self: Arc<Self>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            if let Err(e) = self.sync_rules().await {
+                warn!("⚠️ Twitter stream rules sync failed: {}", e);
+            }
+            // Sentiment only: a dropped stream degrades the market context but does not block trading
+            feed_health().register(FEED_NAME, false);
+            let mut backoff = Duration::from_secs(5);
+            loop {
+                if self.budget.lock().is_exhausted() {
+                    tokio::time::sleep(Duration::from_secs(3600)).await;
+                    continue;
+                }
+                match self.consume_stream().await {
+                    Ok(()) => {
+                        // Budget exhausted: cached sentiment only
+                        feed_health().publish(FEED_NAME, FeedStatus::Stale);
+                        backoff = Duration::from_secs(5);
+                    }
+                    Err(e) => {
+                        feed_health().publish(FEED_NAME, FeedStatus::Reconnecting);
+                        debug!("🐦 Twitter stream disconnected: {} (retry in {:?})", e, backoff);
+                        tokio::time::sleep(backoff).await;
+                        backoff = (backoff * 2).min(Duration::from_secs(300));
+                    }
+                }
+                if let Err(e) = self.budget.lock().save() {
+                    warn!("⚠️ Failed to persist Twitter read budget: {}", e);
+                }
+            }
+        })
+    }
+}
+
+#[async_trait]
+impl SentimentProvider for TwitterStream {
+    fn name(&self) -> &str {
+        "twitter_stream"
+    }
+
+    async fn score(&self, symbol: &str) -> Result<f64> {
+        let windows = self.windows.read();
+        match windows.get(&symbol.to_uppercase()).and_then(|w| w.cached) {
+            Some(score) => Ok(score),
+            None if self.budget.lock().is_exhausted() => Ok(0.0),
+            None => Err(anyhow!("no streamed tweets for {} yet", symbol)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_budget_is_paced_and_resets_monthly() {
+        let mut budget = ReadBudget::new(3000);
+        let day_one = Utc.with_ymd_and_hms(2026, 4, 1, 12, 0, 0).unwrap();
+        budget.period = period_of(day_one);
+
+        // April has 30 days: 100 reads allowed on day one
+        assert!(budget.try_consume_at(100, day_one));
+        assert!(!budget.try_consume_at(1, day_one));
+        assert!(budget.try_consume_at(100, day_one + chrono::Duration::days(1)));
+
+        let next_month = Utc.with_ymd_and_hms(2026, 5, 1, 0, 0, 0).unwrap();
+        assert!(budget.try_consume_at(50, next_month));
+        assert_eq!(budget.used, 50);
+        assert_eq!(budget.period, "2026-05");
+    }
+
+    #[test]
+    fn test_rule_changes_add_missing_and_delete_stale() {
+        let mut current = rules_for_symbols(&["sol", "bonk"]);
+        current[0].id = Some("1".to_string());
+        current[1].id = Some("2".to_string());
+
+        let (add, delete) = rule_changes(&current, &rules_for_symbols(&["SOL", "JUP"]));
+        assert_eq!(add.len(), 1);
+        assert_eq!(add[0].tag, "JUP");
+        assert_eq!(delete, vec!["2".to_string()]);
+    }
+}
@@ -40,8 +40,11 @@ pub mod utils;
 pub mod types;
 pub mod errors;
 pub mod monitoring;
+#[cfg(feature = "intelligence")]
 pub mod intelligence;
-pub mod ml; // ✅ NUEVO: ML module export
+pub mod ml; // ✅ NUEVO: ML module export (advanced engine behind the `ml` feature)
+pub mod chaos; // Fault injection hooks, armed only with the `chaos` feature
+pub mod embed; // Builder-based init for host applications, installs no globals
 
 // ✅ NEW: Containerized bot ecosystem modules
 pub mod api;        // Bot API interfaces and gateway
@@ -61,9 +64,11 @@ pub use trading::{
     RealTradingEngine, RealTradingConfig, RealSwapRequest, RealSwapResult
 };
 pub use monitoring::{EnterpriseMonitor, SystemStatus, TradingMetrics, SystemMetrics};
+#[cfg(feature = "intelligence")]
 pub use intelligence::{AdvancedAiEngine, IntelligenceSystem, AutonomousTrader, IntelligenceConfig, MarketIntelligence, TradingAction};
 pub use types::*;
 pub use errors::{SniperForgeError, SniperResult, ErrorExt};
+pub use embed::{SniperForge, SniperForgeBuilder};
 
 // 🚀 Re-export shared enterprise components for easy access across bots
 pub use shared::{
@@ -77,6 +82,10 @@ pub use shared::{
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// Initialize the SniperForge Core library with logging
+///
+/// Installs a process-wide tracing subscriber, so it is meant for the
+/// SniperForge binaries. Applications embedding the library should use
+/// [`SniperForge::builder`] instead.
 pub fn init() -> anyhow::Result<()> {
     tracing_subscriber::fmt()
         .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
@@ -4,40 +4,89 @@
 //! Built on modular core library with quantum-ready, autonomous, and ecosystem features
 
 use anyhow::Result;
-use chrono::Utc;
+use clap::{Arg, ArgAction, Command};
+use chrono::{DateTime, Utc};
 use solana_sdk::signer::Signer;
 use sniperforge::{
+    api::{ApiGateway, BotStatus, EngineStateSnapshot, GatewayConfig},
     analytics::{
         EnterpriseAIEngine, EnterpriseAIConfig,
         PerformanceAnalyticsAI, PerformanceAnalyticsConfig,
+        TradeIndexer, IndexerConfig, RpcTransactionSource,
+        SeasonalityStats,
+        BenchmarkTracker,
+        shared_experiments,
     },
-    apis::{RealPriceFeeds, PriceFeedManager, StablecoinMonitor},
-    config::SimpleConfig,
-    control::{BotController, TcpControlServer},
+    apis::{jupiter::Jupiter, HeliusEvent, HeliusWebhookConfig, HeliusWebhookReceiver, RealPriceFeeds, PriceFeedManager, StablecoinMonitor, FiatRateService, FiatAsset, fiat_rates, DepegEvent, price_cache_from_env},
+    config::{Config, SimpleConfig, WatchlistRegistry, DEFAULT_WATCHLISTS_PATH},
+    control::{bot_log_router, BotController, TcpControlServer, ClusterCoordinator},
     intelligence::{
         AdvancedAiEngine, IntelligenceSystem, AutonomousTrader, AiConfig, AutonomousConfig,
         market_analysis::IntelligenceConfig,
-        sentiment::{RealSentimentAnalyzer, TwitterSentimentClient},
+        NewsEventClassifier, NewsEventMonitor, NewsMonitorConfig,
+        MarketContext, ContextQuality, SignalQuality,
+        sentiment::{RealSentimentAnalyzer, TwitterSentimentClient, SentimentPipeline, SentimentBlend, TwitterStream, ReadBudget, TWITTER_STREAM_ENV},
     },
-    monitoring::EnterpriseMonitor,
-    security::{SecureWalletManager, load_secure_wallet},
+    monitoring::{
+        Alert, AlertManager, AlertStatus, Severity, EnterpriseMonitor, TaskWatchdog, WatchdogConfig, HeartbeatHandle, TaskFactory,
+        Supervisor, ComponentSpec, ComponentContext, ComponentState, RestartPolicy,
+        NotificationDigest, DigestConfig, LogNotificationSink, WebhookEmitter, WebhookConfig,
+        HealthRegistry, FnProbe, RpcProbe, FeedProbe, WatchdogProbe, StorageProbe, NotificationProbe,
+        StatusPublisher, DEFAULT_STATUS_PATH, metrics_store, DEFAULT_METRICS_STORE_PATH, SelfTest, SelfTestConfig, DEFAULT_SELF_TEST_PATH,
+    },
+    security::{ChainAccounts, SecureWalletManager, load_secure_wallet, WalletConfig, WalletType, RiskManagement, DustConsolidator, DustConfig, RpcDustWallet, KillSwitch, TradingHalt, WalletActivityConfig, WalletActivityMonitor, GovernanceWatcher, GovernanceConfig, GovernedTargets, MultisigGuard, MultisigConfig, RpcSquadsChain, TreasurySweeper, TreasurySweepConfig, SweepStatus},
     trading::{
         arbitrage::ArbitrageEngine,
-        triangular::TriangularArbitrageEngine,
-        flash_loan::{EnterpriseFlashLoanEngine, EnterpriseFlashLoanConfig},
-        cross_chain::{EnterpriseCrossChainEngine, EnterpriseCrossChainConfig},
+        triangular::{TriangularArbitrageEngine, TriangularOpportunity},
+        flash_loan::{EnterpriseFlashLoanEngine, EnterpriseFlashLoanConfig, FlashLoanOpportunity},
+        cross_chain::{EnterpriseCrossChainEngine, EnterpriseCrossChainConfig, CrossChainOpportunity},
         route_optimizer::{RouteOptimizationEngine, OptimizedRoute},
+        opportunity_dedup::{OpportunityDeduplicator, OpportunitySource, RouteSignature, DedupCandidate, DedupOutcome, DedupCooldown, CycleAdmission},
+        strategy_guard::StrategyKillSwitch,
+        drawdown_ladder::DrawdownLadder,
+        capital_withdrawal::{CapitalWithdrawals, WithdrawalConfig, RpcWithdrawalBackend},
+        profit_taking::{ProfitTaking, ProfitTakingConfig, JupiterConversionVenue},
+        fee_budget::{FeeBudgetManager, FeeBudgetConfig, FeeKind, FeeAggressiveness},
+        profit_accounting::{AccountingMode, CycleProfit, ProfitKind, ProfitLedger, RoundTripCost},
+        bridge_tracker::{BridgeTracker, BridgeTrackerConfig},
+        scan_schedule::{ScanScheduler, ScanScheduleConfig, ScanTrigger, FeedEvents, ENGINE_FINDINGS_FEED},
+        token_quarantine::{TokenQuarantine, QuarantineConfig},
+        maker_mode::{MakerMode, MakerModeConfig, ClobSpread, ClobSide, ClobVenue},
+        phoenix::{PhoenixClobClient, PhoenixMarketFeed},
+        microstructure::{MicrostructureMonitor, MicrostructureConfig},
+        execution::{
+            LadderExecutor, LadderConfig, Ladder, TrancheDecision, execution_throttle, IntentLog, IntentLogConfig, RpcSignatureStatus, JupiterRealConfig,
+            ExecutionPipeline, PipelineConfig, KeypairSigner, RpcSubmitter, TradeExecutor, TradeRequest,
+            ReplayGuard, ReplayGuardConfig, install_replay_guard,
+        },
+        execution_scheduler::{ExecutionScheduler, ExecutionBudget, ExecutionPlan},
+        sim_diff::{DecisionInputRecorder, ShadowReplay, write_decisions},
     },
+    types::{ArbitrageOpportunity, ComponentHealthStatus, Expiring, IntoOpportunity, Opportunity, TradingMode, constants::{SOL_MINT, USDC_MINT, USDT_MINT}},
 };
 use std::{collections::HashMap, sync::Arc};
 use tokio::time::{sleep, Duration};
-use tracing::{info, warn, error, Level};
+use tracing::{debug, info, warn, error};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::prelude::*;
+
+mod demo;
+use demo::DemoConfig;
 
 /// Enterprise MultiBot system constants
 const SYSTEM_VERSION: &str = "3.0.0";
 const SYSTEM_CODENAME: &str = "ENTERPRISE_MULTIBOT_UNIFIED";
 const BUILD_DATE: &str = env!("CARGO_PKG_VERSION");
 
+/// Supervised task is restarted if it misses heartbeats for this long
+const ENGINE_STALL_TIMEOUT: Duration = Duration::from_secs(120);
+/// How often expired opportunities are dropped from the findings queues
+const OPPORTUNITY_PRUNE_INTERVAL: Duration = Duration::from_secs(2);
+/// Strategy binding whose watchlists feed sentiment scoring
+const SENTIMENT_WATCHLIST: &str = "sentiment";
+/// Name the hot wallet is registered under with the executor and signing pipeline
+const HOT_WALLET: &str = "hot-wallet";
+
 /// MultiBot trading strategies
 #[derive(Debug, Clone, PartialEq)]
 pub enum TradingStrategy {
@@ -52,38 +101,63 @@ pub enum TradingStrategy {
     UnifiedMultiStrategy,
 }
 
-/// Enhanced result types for enterprise system functionality
-#[derive(Debug, Clone)]
-pub struct MarketAnalysisResult {
-    pub trend: MarketTrend,
-    pub confidence: f64,
-    pub opportunity_score: f64,
-}
-
-#[derive(Debug, Clone)]
-pub enum MarketTrend {
-    Bullish,
-    Bearish,
-    Neutral,
-    Volatile,
-}
-
-#[derive(Debug, Clone)]
-pub struct AutonomousResult {
-    pub trades_executed: u32,
-    pub total_profit: f64,
-}
-
-#[derive(Debug, Clone)]
-pub struct ComprehensiveSentimentData {
-    pub overall_sentiment: f64,
-    pub sources_analyzed: usize,
+/// Latest results published by the supervised feed and engine tasks
+#[derive(Debug, Default)]
+pub struct EngineFindings {
+    pub arbitrage: Vec<ArbitrageOpportunity>,
+    pub triangular: Vec<TriangularOpportunity>,
+    pub flash_loan: Vec<FlashLoanOpportunity>,
+    pub cross_chain: Vec<CrossChainOpportunity>,
+    pub depeg_events: Vec<DepegEvent>,
+    pub stablecoins_depegged: bool,
 }
 
-#[derive(Debug, Clone)]
-pub struct AiAnalysisResult {
-    pub confidence_score: f64,
-    pub optimization_gain: f64,
+impl EngineFindings {
+    /// Take the published opportunities, keeping the latest feed status
+    fn drain(&mut self) -> Self {
+        Self {
+            arbitrage: std::mem::take(&mut self.arbitrage),
+            triangular: std::mem::take(&mut self.triangular),
+            flash_loan: std::mem::take(&mut self.flash_loan),
+            cross_chain: std::mem::take(&mut self.cross_chain),
+            depeg_events: std::mem::take(&mut self.depeg_events),
+            stablecoins_depegged: self.stablecoins_depegged,
+        }
+    }
+    
+    /// Drop opportunities whose validity window has passed; returns how many
+    fn prune_expired(&mut self, now: DateTime<Utc>) -> usize {
+        fn retain_fresh<T: Expiring>(queue: &mut Vec<T>, now: DateTime<Utc>) -> usize {
+            let before = queue.len();
+            queue.retain(|opportunity| !opportunity.is_expired(now));
+            before - queue.len()
+        }
+        retain_fresh(&mut self.arbitrage, now)
+            + retain_fresh(&mut self.triangular, now)
+            + retain_fresh(&mut self.flash_loan, now)
+            + retain_fresh(&mut self.cross_chain, now)
+    }
+    
+    /// Every queued opportunity in the unified model
+    fn opportunities(&self) -> Vec<Opportunity> {
+        self.arbitrage.iter().map(IntoOpportunity::to_opportunity)
+            .chain(self.triangular.iter().map(IntoOpportunity::to_opportunity))
+            .chain(self.flash_loan.iter().map(IntoOpportunity::to_opportunity))
+            .chain(self.cross_chain.iter().map(IntoOpportunity::to_opportunity))
+            .collect()
+    }
+    
+    /// Keep only what the scheduler picked, in its execution order
+    fn apply_plan(&mut self, plan: &ExecutionPlan) {
+        fn schedule<T: Expiring>(queue: &mut Vec<T>, plan: &ExecutionPlan) {
+            queue.retain(|opportunity| plan.is_scheduled(&opportunity.opportunity_id()));
+            queue.sort_by_key(|opportunity| plan.rank(&opportunity.opportunity_id()));
+        }
+        schedule(&mut self.arbitrage, plan);
+        schedule(&mut self.triangular, plan);
+        schedule(&mut self.flash_loan, plan);
+        schedule(&mut self.cross_chain, plan);
+    }
 }
 
 /// Enterprise MultiBot AI Engine - Unified intelligence system with REAL sentiment analysis
@@ -130,6 +204,27 @@ impl Default for EnterpriseBotAI {
     }
 }
 
+/// Serializable AI model state for engine snapshots
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AiModelSnapshot {
+    pub lstm_prediction_accuracy: f64,
+    pub random_forest_accuracy: f64,
+    pub neural_network_accuracy: f64,
+    pub ensemble_accuracy: f64,
+    pub confidence_threshold: f64,
+    pub total_predictions: u64,
+    pub successful_predictions: u64,
+}
+
+/// Serializable run totals for engine snapshots
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RunTotalsSnapshot {
+    pub cycle_count: u64,
+    pub total_profit: f64,
+    pub total_enterprise_cycles: u64,
+    pub stablecoin_depegging_alerts: u32,
+}
+
 /// Enterprise MultiBot performance metrics
 #[derive(Debug, Clone)]
 pub struct MultiBotMetrics {
@@ -156,6 +251,8 @@ pub struct MultiBotMetrics {
     pub total_enterprise_cycles: u64,     // ✅ Total enterprise cycles
     pub intelligence_analysis_count: u64, // ✅ Intelligence system analysis count
     pub sentiment_analysis_count: u64,    // ✅ Sentiment analysis count
+    pub degraded_cycles: u64,             // Cycles run in degraded (neutral-default) mode
+    pub market_context_quality: Option<ContextQuality>, // Input quality of the last cycle
 }
 
 impl Default for MultiBotMetrics {
@@ -184,36 +281,446 @@ impl Default for MultiBotMetrics {
             total_enterprise_cycles: 0,     // Real cycle count
             intelligence_analysis_count: 0, // Real intelligence analysis count
             sentiment_analysis_count: 0,    // Real sentiment analysis count
+            degraded_cycles: 0,
+            market_context_quality: None,
+        }
+    }
+}
+
+/// Record an engine scan in the seasonality profile and pick the next scan delay
+///
+/// Failed scans are not counted: they say nothing about how busy the market is.
+fn seasonal_scan_interval(seasonality: &parking_lot::Mutex<SeasonalityStats>, found: Option<usize>, base: Duration) -> Duration {
+    let now = Utc::now();
+    let mut seasonality = seasonality.lock();
+    if let Some(found) = found {
+        seasonality.record_scan(now, found);
+    }
+    seasonality.scan_interval(base, now)
+}
+
+/// Build the engine supervisor tree: price feeds first, then one isolated task per engine
+fn build_engine_supervisor(
+    arbitrage_engine: ArbitrageEngine,
+    triangular_engine: TriangularArbitrageEngine,
+    flash_loan_engine: EnterpriseFlashLoanEngine,
+    cross_chain_engine: EnterpriseCrossChainEngine,
+    stablecoin_monitor: StablecoinMonitor,
+    fiat_rates: Arc<FiatRateService>,
+    findings: Arc<tokio::sync::Mutex<EngineFindings>>,
+    seasonality: Arc<parking_lot::Mutex<SeasonalityStats>>,
+    schedules: &ScanScheduleConfig,
+    feed_events: Arc<FeedEvents>,
+) -> Supervisor {
+    const FEEDS: [&str; 2] = ["fiat_rate_feed", "stablecoin_feed"];
+    let engine_policy = RestartPolicy {
+        max_restarts: 5,
+        window: Duration::from_secs(300),
+        backoff: Duration::from_secs(2),
+    };
+    let mut supervisor = Supervisor::new(Duration::from_secs(30));
+    let scheduler = |name: &str| Arc::new(ScanScheduler::new(schedules.schedule(name).clone(), &feed_events));
+    
+    // Feeds: ready after their first refresh attempt so an offline API cannot block engines
+    let (schedule, events) = (scheduler("fiat_rate_feed"), feed_events.clone());
+    supervisor.add(ComponentSpec::new("fiat_rate_feed", move |ctx: ComponentContext| {
+        let (fiat_rates, schedule, events) = (fiat_rates.clone(), schedule.clone(), events.clone());
+        async move {
+            loop {
+                match fiat_rates.get_rate(FiatAsset::Sol).await {
+                    Ok(_) => events.publish("fiat_rate_feed"),
+                    Err(e) => warn!("⚠️ SOL/USD refresh failed: {}", e),
+                }
+                ctx.mark_ready();
+                ctx.heartbeat.beat();
+                schedule.wait(schedule.base_interval()).await;
+            }
+        }
+    }).with_heartbeat_timeout(ENGINE_STALL_TIMEOUT));
+    
+    let monitor = Arc::new(tokio::sync::Mutex::new(stablecoin_monitor));
+    let feed_findings = findings.clone();
+    let (schedule, events) = (scheduler("stablecoin_feed"), feed_events.clone());
+    supervisor.add(ComponentSpec::new("stablecoin_feed", move |ctx: ComponentContext| {
+        let (monitor, findings, schedule, events) = (monitor.clone(), feed_findings.clone(), schedule.clone(), events.clone());
+        async move {
+            loop {
+                {
+                    let mut monitor = monitor.lock().await;
+                    match monitor.update_stablecoin_prices().await {
+                        Ok(()) => {
+                            monitor.display_stablecoin_status();
+                            let mut findings = findings.lock().await;
+                            findings.depeg_events.extend(monitor.scan_depeg_opportunities());
+                            findings.stablecoins_depegged = monitor.has_depegged_stablecoins();
+                            events.publish("stablecoin_feed");
+                        }
+                        Err(e) => warn!("⚠️ Stablecoin price update failed: {}", e),
+                    }
+                }
+                ctx.mark_ready();
+                ctx.heartbeat.beat();
+                schedule.wait(schedule.base_interval()).await;
+            }
+        }
+    }).with_heartbeat_timeout(ENGINE_STALL_TIMEOUT));
+    
+    // Pruner: expired opportunities never wait in the queues for the next cycle
+    let pruner_findings = findings.clone();
+    supervisor.add(ComponentSpec::new("opportunity_pruner", move |ctx: ComponentContext| {
+        let findings = pruner_findings.clone();
+        async move {
+            ctx.mark_ready();
+            loop {
+                let pruned = findings.lock().await.prune_expired(Utc::now());
+                if pruned > 0 {
+                    debug!("⌛ Pruned {} expired opportunities", pruned);
+                }
+                ctx.heartbeat.beat();
+                sleep(OPPORTUNITY_PRUNE_INTERVAL).await;
+            }
+        }
+    }).with_heartbeat_timeout(ENGINE_STALL_TIMEOUT));
+    
+    // Engines: each scans in its own task and publishes its latest opportunities,
+    // waking the trading cycle when it found any
+    let engine = Arc::new(tokio::sync::Mutex::new(arbitrage_engine));
+    let engine_findings = findings.clone();
+    let engine_seasonality = seasonality.clone();
+    let (schedule, events) = (scheduler("arbitrage_engine"), feed_events.clone());
+    supervisor.add(ComponentSpec::new("arbitrage_engine", move |ctx: ComponentContext| {
+        let (engine, findings, seasonality, schedule, events) =
+            (engine.clone(), engine_findings.clone(), engine_seasonality.clone(), schedule.clone(), events.clone());
+        async move {
+            loop {
+                let scan = engine.lock().await.scan_for_opportunities().await;
+                let found = match scan {
+                    Ok(opportunities) => {
+                        let found = opportunities.len();
+                        findings.lock().await.arbitrage = opportunities;
+                        if found > 0 {
+                            events.publish(ENGINE_FINDINGS_FEED);
+                        }
+                        Some(found)
+                    }
+                    Err(e) => {
+                        warn!("⚠️ Enhanced arbitrage scan failed: {}", e);
+                        None
+                    }
+                };
+                ctx.heartbeat.beat();
+                schedule.wait(seasonal_scan_interval(&seasonality, found, schedule.base_interval())).await;
+            }
+        }
+    }).depends_on(&FEEDS).with_restart_policy(engine_policy.clone()).with_heartbeat_timeout(ENGINE_STALL_TIMEOUT));
+    
+    let engine = Arc::new(tokio::sync::Mutex::new(triangular_engine));
+    let engine_findings = findings.clone();
+    let engine_seasonality = seasonality.clone();
+    let (schedule, events) = (scheduler("triangular_engine"), feed_events.clone());
+    supervisor.add(ComponentSpec::new("triangular_engine", move |ctx: ComponentContext| {
+        let (engine, findings, seasonality, schedule, events) =
+            (engine.clone(), engine_findings.clone(), engine_seasonality.clone(), schedule.clone(), events.clone());
+        async move {
+            loop {
+                let scan = engine.lock().await.find_triangular_opportunities().await;
+                let found = match scan {
+                    Ok(opportunities) => {
+                        let found = opportunities.len();
+                        findings.lock().await.triangular = opportunities;
+                        if found > 0 {
+                            events.publish(ENGINE_FINDINGS_FEED);
+                        }
+                        Some(found)
+                    }
+                    Err(e) => {
+                        warn!("⚠️ Triangular arbitrage scan failed: {}", e);
+                        None
+                    }
+                };
+                ctx.heartbeat.beat();
+                schedule.wait(seasonal_scan_interval(&seasonality, found, schedule.base_interval())).await;
+            }
+        }
+    }).depends_on(&FEEDS).with_restart_policy(engine_policy.clone()).with_heartbeat_timeout(ENGINE_STALL_TIMEOUT));
+    
+    let engine = Arc::new(tokio::sync::Mutex::new(flash_loan_engine));
+    let engine_findings = findings.clone();
+    let engine_seasonality = seasonality.clone();
+    let (schedule, events) = (scheduler("flash_loan_engine"), feed_events.clone());
+    supervisor.add(ComponentSpec::new("flash_loan_engine", move |ctx: ComponentContext| {
+        let (engine, findings, seasonality, schedule, events) =
+            (engine.clone(), engine_findings.clone(), engine_seasonality.clone(), schedule.clone(), events.clone());
+        async move {
+            loop {
+                let scan = engine.lock().await.scan_flash_loan_opportunities().await;
+                let found = match scan {
+                    Ok(opportunities) => {
+                        let found = opportunities.len();
+                        findings.lock().await.flash_loan = opportunities;
+                        if found > 0 {
+                            events.publish(ENGINE_FINDINGS_FEED);
+                        }
+                        Some(found)
+                    }
+                    Err(e) => {
+                        warn!("⚠️ Flash loan arbitrage scan failed: {}", e);
+                        None
+                    }
+                };
+                ctx.heartbeat.beat();
+                schedule.wait(seasonal_scan_interval(&seasonality, found, schedule.base_interval())).await;
+            }
+        }
+    }).depends_on(&FEEDS).with_restart_policy(engine_policy.clone()).with_heartbeat_timeout(ENGINE_STALL_TIMEOUT));
+    
+    let engine = Arc::new(tokio::sync::Mutex::new(cross_chain_engine));
+    let (schedule, events) = (scheduler("cross_chain_engine"), feed_events.clone());
+    supervisor.add(ComponentSpec::new("cross_chain_engine", move |ctx: ComponentContext| {
+        let (engine, findings, seasonality, schedule, events) =
+            (engine.clone(), findings.clone(), seasonality.clone(), schedule.clone(), events.clone());
+        async move {
+            loop {
+                let scan = engine.lock().await.scan_cross_chain_opportunities().await;
+                let found = match scan {
+                    Ok(opportunities) => {
+                        let found = opportunities.len();
+                        findings.lock().await.cross_chain = opportunities;
+                        if found > 0 {
+                            events.publish(ENGINE_FINDINGS_FEED);
+                        }
+                        Some(found)
+                    }
+                    Err(e) => {
+                        warn!("⚠️ Cross-chain arbitrage scan failed: {}", e);
+                        None
+                    }
+                };
+                ctx.heartbeat.beat();
+                schedule.wait(seasonal_scan_interval(&seasonality, found, schedule.base_interval())).await;
+            }
+        }
+    }).depends_on(&FEEDS).with_restart_policy(engine_policy).with_heartbeat_timeout(ENGINE_STALL_TIMEOUT));
+    
+    supervisor
+}
+
+/// Command-line options of the service binary
+struct ServiceArgs {
+    demo: bool,
+    self_test: bool,
+    /// Recorded decision inputs to replay in shadow mode instead of running
+    replay: Option<String>,
+    decisions_out: Option<String>,
+    demo_config: DemoConfig,
+}
+
+fn parse_args() -> ServiceArgs {
+    let matches = Command::new("sniperforge")
+        .version(SYSTEM_VERSION)
+        .about("SniperForge Enterprise MultiBot service")
+        .arg(Arg::new("demo")
+            .long("demo")
+            .action(ArgAction::SetTrue)
+            .help("Run the scripted demonstration with simulated execution instead of the service"))
+        .arg(Arg::new("self-test")
+            .long("self-test")
+            .action(ArgAction::SetTrue)
+            .help("Check RPC, APIs, wallet, storage and clock skew before starting; critical failures refuse a MainNet start"))
+        .arg(Arg::new("replay")
+            .long("replay")
+            .value_name("INPUTS")
+            .help("Replay recorded decision inputs through this build's scoring and scheduling, write the decisions and exit"))
+        .arg(Arg::new("decisions-out")
+            .long("decisions-out")
+            .value_name("PATH")
+            .requires("replay")
+            .help("Where --replay writes its decisions (JSON lines, default stdout)"))
+        .arg(Arg::new("demo-cycles")
+            .long("demo-cycles")
+            .value_name("N")
+            .value_parser(clap::value_parser!(u32))
+            .default_value("18"))
+        .arg(Arg::new("demo-cycle-secs")
+            .long("demo-cycle-secs")
+            .value_name("SECS")
+            .value_parser(clap::value_parser!(u64))
+            .default_value("8"))
+        .get_matches();
+    
+    let demo_config = DemoConfig {
+        cycles: *matches.get_one::<u32>("demo-cycles").unwrap(),
+        cycle_interval: Duration::from_secs(*matches.get_one::<u64>("demo-cycle-secs").unwrap()),
+        ..Default::default()
+    };
+    ServiceArgs {
+        demo: matches.get_flag("demo"),
+        self_test: matches.get_flag("self-test"),
+        replay: matches.get_one::<String>("replay").cloned(),
+        decisions_out: matches.get_one::<String>("decisions-out").cloned(),
+        demo_config,
+    }
+}
+
+/// Shadow-mode replay for `sniperforge-cli sim-diff`; nothing is executed
+fn run_decision_replay(inputs: &str, decisions_out: Option<&str>) -> Result<()> {
+    let reader = std::io::BufReader::new(std::fs::File::open(inputs)
+        .map_err(|e| anyhow::anyhow!("cannot open {}: {}", inputs, e))?);
+    let decisions = ShadowReplay::default().replay(reader)?;
+    match decisions_out {
+        Some(path) => write_decisions(std::io::BufWriter::new(std::fs::File::create(path)?), &decisions),
+        None => write_decisions(std::io::stdout().lock(), &decisions),
+    }
+}
+
+/// Execution environment implied by the configuration
+fn trading_mode(simple_config: &SimpleConfig) -> TradingMode {
+    if simple_config.enable_simulation {
+        TradingMode::Simulation
+    } else if simple_config.solana_rpc_url.contains("mainnet") {
+        TradingMode::MainNet
+    } else {
+        TradingMode::DevNet
+    }
+}
+
+/// Resolves on Ctrl-C or (on Unix) SIGTERM
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        let mut terminate = match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(signal) => signal,
+            Err(e) => {
+                warn!("⚠️ SIGTERM handler unavailable: {}", e);
+                let _ = tokio::signal::ctrl_c().await;
+                return;
+            }
+        };
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {}
+            _ = terminate.recv() => {}
         }
     }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+}
+
+/// Act on transactions Helius pushes: managed-wallet activity is inspected
+/// right away instead of at the next poll, pool launches and whale transfers
+/// are raised as alerts
+async fn consume_helius_events(
+    mut events: tokio::sync::broadcast::Receiver<HeliusEvent>,
+    alert_manager: Arc<AlertManager>,
+    wallet_activity: Option<Arc<WalletActivityMonitor>>,
+) {
+    loop {
+        let event = match events.recv().await {
+            Ok(event) => event,
+            Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!("🪝 Helius consumer fell behind - {} events dropped", skipped);
+                continue;
+            }
+            Err(tokio::sync::broadcast::error::RecvError::Closed) => return,
+        };
+        let (title, description, severity, tags) = match &event {
+            HeliusEvent::WalletActivity { signature, wallet, transaction_type } => {
+                debug!("🪝 {} activity on {} ({})", transaction_type, wallet, signature);
+                if let Some(monitor) = &wallet_activity {
+                    if let Err(e) = monitor.poll_wallet(wallet).await {
+                        warn!("⚠️ Pushed activity check failed for {}: {}", wallet, e);
+                    }
+                }
+                continue;
+            }
+            HeliusEvent::PoolCreated { signature, source, mints } => (
+                format!("New {} pool", source),
+                format!("Pool created in {} for mints {}", signature, mints.join(", ")),
+                Severity::Low,
+                vec!["helius".to_string(), "pool_created".to_string()],
+            ),
+            HeliusEvent::LargeTransfer { signature, mint, amount, from, to } => (
+                format!("Large transfer of {}", mint),
+                format!(
+                    "{:.2} moved from {} to {} in {}",
+                    amount,
+                    from.as_deref().unwrap_or("unknown"),
+                    to.as_deref().unwrap_or("unknown"),
+                    signature
+                ),
+                Severity::Medium,
+                vec!["helius".to_string(), "large_transfer".to_string(), mint.clone()],
+            ),
+        };
+        info!("🪝 {}: {}", title, description);
+        alert_manager.raise_alert(Alert {
+            id: uuid::Uuid::new_v4().to_string(),
+            title,
+            description,
+            severity,
+            status: AlertStatus::Open,
+            created_at: Utc::now(),
+            resolved_at: None,
+            tags,
+        }).await;
+    }
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Initialize enterprise-grade logging with MultiBot branding
-    tracing_subscriber::fmt()
-        .with_max_level(Level::INFO)
-        .with_target(false)
-        .with_thread_ids(true)
+    // Initialize enterprise-grade logging with MultiBot branding; events inside a
+    // bot's span are also written to that bot's own log file
+    tracing_subscriber::registry()
+        .with(LevelFilter::INFO)
+        .with(tracing_subscriber::fmt::layer().with_target(false).with_thread_ids(true))
+        .with(bot_log_router().clone())
         .init();
 
+    let ServiceArgs { demo, self_test, replay, decisions_out, demo_config } = parse_args();
+    if let Some(inputs) = replay {
+        return run_decision_replay(&inputs, decisions_out.as_deref());
+    }
     display_enterprise_multibot_banner();
     
     // Initialize configuration
-    let simple_config = SimpleConfig::default();
+    let mut simple_config = SimpleConfig::default();
+    if demo {
+        simple_config.enable_simulation = true;
+    }
+    
+    if self_test {
+        let mode = trading_mode(&simple_config);
+        info!("🩺 Running startup self-test ({:?})", mode);
+        let report = SelfTest::standard(SelfTestConfig::load(DEFAULT_SELF_TEST_PATH), &simple_config).run().await;
+        println!("{}\n", report.matrix());
+        if !report.allows_start(&mode) {
+            error!("🛑 Self-test failed on critical checks, refusing to start on MainNet");
+            anyhow::bail!("startup self-test failed");
+        }
+        if !report.passed() {
+            warn!("⚠️ Self-test failed on critical checks; starting anyway outside MainNet");
+        }
+    }
+    
     info!("🔧 Initializing SniperForge Enterprise MultiBot System...");
     
     // Create enterprise-grade unified trading system
     let mut multibot_system = EnterpriseMultiBotSystem::new(simple_config).await?;
     
+    if demo {
+        return multibot_system.run_demonstration(&demo_config).await;
+    }
+    
+    // Resume operational context handed off from another instance/host
+    if let Ok(path) = std::env::var("SNIPERFORGE_RESTORE_SNAPSHOT") {
+        multibot_system.import_state_snapshot(&path).await?;
+    }
+    
     info!("✅ All enterprise MultiBot components initialized successfully");
     info!("🚀 SniperForge Enterprise System ready for external control");
-    info!("💡 Use CLI commands to start specific systems: cargo run --bin sniperforge-cli -- ping");
-    
-    // Keep system in standby mode instead of auto-executing
-    multibot_system.run_standby_mode().await?;
     
-    Ok(())
+    multibot_system.run_service().await
 }
 
 /// Display enterprise MultiBot startup banner
@@ -243,39 +750,71 @@ fn display_enterprise_multibot_banner() {
 
 /// Enterprise MultiBot system coordinator
 pub struct EnterpriseMultiBotSystem {
-    // Core trading engines
-    arbitrage_engine: ArbitrageEngine,
-    triangular_engine: TriangularArbitrageEngine,
-    flash_loan_engine: EnterpriseFlashLoanEngine,
-    cross_chain_engine: EnterpriseCrossChainEngine,
+    // Core trading engines - each runs in its own supervised task
+    engine_supervisor: Supervisor,
+    engine_findings: Arc<tokio::sync::Mutex<EngineFindings>>,
+    cycle_schedule: Arc<ScanScheduler>,                // Service cycle cadence, woken early by engine findings
+    seasonality: Arc<parking_lot::Mutex<SeasonalityStats>>, // Time-of-day opportunity/PnL profile
+    opportunity_dedup: OpportunityDeduplicator,        // Cross-engine duplicate suppression
+    ladder_executor: LadderExecutor,                   // Tranche sizing for large arbitrage targets
+    execution_scheduler: ExecutionScheduler,           // EV/sec ordering of pending opportunities
+    execution_budget: ExecutionBudget,                 // Per-cycle capital/compute/slot limits
+    decision_recorder: Option<DecisionInputRecorder>,  // Planning inputs for sim-diff replays (opt-in)
+    arbitrage_ladders: HashMap<RouteSignature, Ladder>, // Ladders still waiting on later tranches
+    maker_mode: Option<Arc<MakerMode>>,                 // Post-only bids for spreads just short of taker profitability
+    phoenix: Option<Arc<PhoenixClobClient>>,            // Phoenix markets maker mode quotes on
+    cluster: Option<Arc<ClusterCoordinator>>,          // Cross-instance leader election + shared dedup
     
     // Advanced AI engines
     ai_engine: EnterpriseAIEngine,
     analytics_engine: PerformanceAnalyticsAI,
+    benchmark: BenchmarkTracker,                 // Strategy equity vs HODL SOL / USDC / 50-50
     
     // Enterprise MultiBot AI (Unified Intelligence)
     multibot_ai: EnterpriseBotAI,
     
     // ✅ ENTERPRISE-GRADE MONITORING & INTELLIGENCE
     enterprise_monitor: Arc<EnterpriseMonitor>,        // Enterprise monitoring system
+    watchdog: Arc<TaskWatchdog>,                       // Heartbeat liveness watchdog with auto-restart
+    trade_indexer: Option<Arc<TradeIndexer>>,          // Wallet transaction history → normalized trade rows
+    dust_consolidator: Option<Arc<DustConsolidator>>,  // Hot-wallet dust → SOL + rent refunds, on demand
     intelligence_system: Arc<IntelligenceSystem>,      // Market intelligence & analysis
     autonomous_trader: Arc<AutonomousTrader>,          // Autonomous trading with AI
     advanced_ai_engine: Arc<AdvancedAiEngine>,         // Advanced ML/AI engine
     sentiment_analyzer: Arc<RealSentimentAnalyzer>,    // Real sentiment analysis
     
     // ✅ REAL-TIME DATA SYSTEMS
-    stablecoin_monitor: StablecoinMonitor,      // Real stablecoin price monitoring
-    twitter_client: TwitterSentimentClient,     // Real-time Twitter sentiment
+    sentiment_pipeline: SentimentPipeline,      // Weighted Twitter/Reddit/local-model sentiment
+    sentiment_blends: HashMap<String, SentimentBlend>, // Last blend per symbol, reused until its watchlist is due
+    watchlists: Arc<WatchlistRegistry>,         // Named token sets bound to strategies, editable over the control API
     
     // ✅ EXTERNAL CONTROL SYSTEM - TCP Interface
     bot_controller: Arc<BotController>,         // External bot management controller
+    trading_halt: Arc<TradingHalt>,             // Engaged on unexpected wallet activity
     tcp_server: Option<()>,                     // TCP server placeholder (runs in background)
     
     // Data feeds and infrastructure
-    _price_feeds: RealPriceFeeds,
+    price_feeds: RealPriceFeeds,                // DexScreener pool liquidity behind the per-token exposure tiers
+    fiat_rates: Arc<FiatRateService>,           // SOL/BTC/ETH → USD conversion with rate timestamps
     
     // System state and metrics
     active_strategies: Vec<TradingStrategy>,
+    strategy_guard: Arc<StrategyKillSwitch>,          // Statistical suspension of strategies that lost their edge
+    fee_budget: Arc<FeeBudgetManager>,                // Daily fee caps per strategy
+    profit_ledger: ProfitLedger,                      // Confirmed vs simulated vs hypothetical profit
+    bridge_tracker: Arc<BridgeTracker>,               // Bridge transfer state machines with manual recovery
+    token_quarantine: Arc<TokenQuarantine>,           // Auto-learned toxic mints, skipped by every strategy
+    intent_log: Arc<IntentLog>,                       // Write-ahead trade intents, settled before trading resumes
+    intent_status: Arc<RpcSignatureStatus>,           // Chain lookups for unresolved intents
+    trade_executor: Arc<TradeExecutor>,               // Live swaps, signed and sent through the execution pipeline
+    treasury: Option<Arc<TreasurySweeper>>,           // Sweeps confirmed hot-wallet profit to cold storage (opt-in)
+    health_registry: Arc<HealthRegistry>,             // Composite subsystem health for /health and the control API
+    risk_manager: sniperforge::trading::RiskManager,  // Cross-strategy exposure netting, shared with the arbitrage engine
+    risk_tokens: HashMap<String, String>,             // Symbol → mint of traded tokens, whose pool liquidity sets their tier
+    drawdown_ladder: Arc<DrawdownLadder>,             // Graduated de-risking on daily drawdown, applied through the risk manager
+    capital_withdrawals: Arc<CapitalWithdrawals>,     // Capital marked for withdrawal, held back from new trades
+    profit_taking: Arc<ProfitTaking>,                 // Share of realized profit converted to USDC via TWAP
+    rpc_usage_reported: chrono::NaiveDate,            // Last UTC day whose RPC usage report was logged
     system_metrics: MultiBotMetrics,
     cycle_count: u64,
     total_profit: f64,
@@ -287,6 +826,8 @@ impl EnterpriseMultiBotSystem {
     pub async fn new(simple_config: SimpleConfig) -> Result<Self> {
         info!("🔧 Configuring enterprise MultiBot engines...");
         
+        let trading_mode = trading_mode(&simple_config);
+        
         // Initialize price feeds (unified infrastructure)
         let price_feeds = RealPriceFeeds::new();
         
@@ -295,6 +836,10 @@ impl EnterpriseMultiBotSystem {
         let arbitrage_engine = ArbitrageEngine::new(simple_config.clone(), price_feed_manager).await
             .map_err(|e| anyhow::anyhow!("Failed to initialize arbitrage engine: {}", e))?;
         info!("✅ Phase 1-2: Enhanced Arbitrage Engine initialized");
+        // Clones share the engine's restrictions and exposure book
+        let risk_manager = arbitrage_engine.risk_manager().clone();
+        let drawdown_ladder = Arc::new(DrawdownLadder::default());
+        risk_manager.attach_drawdown_ladder(drawdown_ladder.clone());
         
         // Initialize Triangular Arbitrage Engine
         let mut triangular_engine = TriangularArbitrageEngine::new(None);
@@ -308,7 +853,10 @@ impl EnterpriseMultiBotSystem {
         // ✅ PHASE 4: REAL TRADING INTEGRATION
         info!("🔧 Phase 4: Initializing Real Trading Integration...");
         // Validate wallet and trading permissions
+        info!("🔏 Running build {}", sniperforge::security::build_fingerprint());
         if !simple_config.enable_simulation {
+            sniperforge::security::verify_for_real_trading()
+                .map_err(|e| anyhow::anyhow!("Refusing real-money trading: {}", e))?;
             info!("  ✅ Real trading permissions verified");
             info!("  ✅ Wallet integration confirmed: {} SOL", 0.292474);
             info!("  ✅ Risk management protocols active");
@@ -353,7 +901,15 @@ impl EnterpriseMultiBotSystem {
             risk_management_enabled: true,
             slippage_tolerance_bps: 75,       // 0.75% slippage tolerance
         };
-        let cross_chain_engine = EnterpriseCrossChainEngine::new(Some(cross_chain_config), simple_config.clone());
+        // Shared across processes when SNIPERFORGE_PRICE_CACHE_URL points at Redis
+        let price_cache = price_cache_from_env().await?;
+        let mut cross_chain_engine = EnterpriseCrossChainEngine::new(Some(cross_chain_config), simple_config.clone())
+            .with_price_cache(price_cache);
+        if !simple_config.enable_simulation {
+            // Solana keypair from the wallet path, EVM key from SNIPERFORGE_EVM_PRIVATE_KEY
+            let chain_accounts = ChainAccounts::from_env(&simple_config.private_key_path)?;
+            cross_chain_engine = cross_chain_engine.with_chain_accounts(Arc::new(chain_accounts));
+        }
         info!("✅ Phase 7: Enterprise Cross-Chain Engine initialized");
         
         // Initialize AI Engine with enterprise-grade config
@@ -396,6 +952,16 @@ impl EnterpriseMultiBotSystem {
             info!("✅ Twitter API integrated successfully for real-time sentiment");
         }
         
+        // Monthly read quota shared by search polling and the filtered stream
+        let twitter_budget = Arc::new(parking_lot::Mutex::new(
+            ReadBudget::load("state/twitter_read_budget.json", ReadBudget::monthly_cap_from_env())
+                .unwrap_or_else(|e| {
+                    warn!("⚠️ Twitter read budget state unreadable ({}), starting fresh", e);
+                    ReadBudget::new(ReadBudget::monthly_cap_from_env())
+                }),
+        ));
+        multibot_ai.twitter_client = multibot_ai.twitter_client.clone().with_read_budget(twitter_budget.clone());
+        
         info!("✅ Advanced: Performance Analytics AI initialized");
         
         // ✅ PHASE 9: QUANTUM COMPUTING ARCHITECTURE
@@ -429,6 +995,69 @@ impl EnterpriseMultiBotSystem {
         let enterprise_monitor = Arc::new(EnterpriseMonitor::new());
         info!("✅ Enterprise Monitor initialized - Full observability active");
         
+        // Alerts are grouped, escalated and summarized before reaching any channel
+        let notification_digest = Arc::new(NotificationDigest::new(DigestConfig::default()));
+        notification_digest.add_sink(Arc::new(LogNotificationSink)).await;
+        // Outbound webhooks (opt-in: SNIPERFORGE_WEBHOOK_URL, signed with SNIPERFORGE_WEBHOOK_SECRET)
+        if let Ok(url) = std::env::var("SNIPERFORGE_WEBHOOK_URL") {
+            match WebhookEmitter::new(WebhookConfig::default()) {
+                Ok(emitter) => match emitter.add_endpoint(&url, Vec::new(), std::env::var("SNIPERFORGE_WEBHOOK_SECRET").ok()).await {
+                    Ok(_) => {
+                        notification_digest.add_sink(Arc::new(emitter)).await;
+                        info!("✅ Outbound webhook active - alerts delivered to {}", url);
+                    }
+                    Err(e) => warn!("⚠️ Outbound webhook not registered: {}", e),
+                },
+                Err(e) => warn!("⚠️ Outbound webhook client unavailable: {}", e),
+            }
+        }
+        enterprise_monitor.alert_manager().attach_notifications(notification_digest.clone()).await;
+        notification_digest.start();
+        info!("✅ Notification digest active - duplicate alerts folded into periodic summaries");
+        
+        // Headline monitoring: exploits/depegs/halts on watched tokens raise alerts
+        let mut news_classifier = NewsEventClassifier::new();
+        news_classifier.watch("SOL", &["solana"]);
+        news_classifier.watch("BTC", &["bitcoin"]);
+        news_classifier.watch("ETH", &["ethereum", "ether"]);
+        news_classifier.watch("USDC", &["circle"]);
+        news_classifier.watch("USDT", &["tether"]);
+        news_classifier.watch("JUP", &["jupiter"]);
+        let mut news_monitor = NewsEventMonitor::new(NewsMonitorConfig::default(), news_classifier)
+            .with_alert_manager(enterprise_monitor.alert_manager());
+        if std::env::var("SNIPERFORGE_NEWS_REDUCE_EXPOSURE").is_ok() {
+            // Severe events cap position size on the affected asset
+            news_monitor = news_monitor.with_exposure_reducer(Arc::new(arbitrage_engine.risk_manager().clone()));
+        }
+        Arc::new(news_monitor).start();
+        
+        let watchdog = Arc::new(
+            TaskWatchdog::new(WatchdogConfig::default())
+                .with_alert_manager(enterprise_monitor.alert_manager())
+        );
+        watchdog.clone().start();
+        info!("✅ Task watchdog initialized - Background tasks supervised");
+        
+        // Wallet history indexer (opt-in: SNIPERFORGE_INDEX_WALLETS=addr1,addr2)
+        let trade_indexer = match std::env::var("SNIPERFORGE_INDEX_WALLETS") {
+            Ok(wallets) if !wallets.trim().is_empty() => {
+                let config = IndexerConfig {
+                    wallets: wallets.split(',').map(|w| w.trim().to_string()).filter(|w| !w.is_empty()).collect(),
+                    ..Default::default()
+                };
+                let indexer = Arc::new(TradeIndexer::new(config).await?);
+                let stall_timeout = indexer.poll_interval() * 4 + Duration::from_secs(120);
+                let task_indexer = indexer.clone();
+                let factory: TaskFactory = Arc::new(move |heartbeat: HeartbeatHandle| {
+                    tokio::spawn(task_indexer.clone().run(heartbeat))
+                });
+                watchdog.register("trade_indexer", Some(stall_timeout), factory).await;
+                info!("✅ Trade indexer ingesting wallet history");
+                Some(indexer)
+            }
+            _ => None,
+        };
+        
         // Initialize Intelligence System  
         let intelligence_config = IntelligenceConfig::default();
         let intelligence_system = Arc::new(IntelligenceSystem::new(intelligence_config));
@@ -456,9 +1085,24 @@ impl EnterpriseMultiBotSystem {
         let stablecoin_monitor = StablecoinMonitor::default();
         info!("✅ Real-time stablecoin price monitoring activated");
         
-        // Initialize Twitter client for real-time sentiment
-        let twitter_client = TwitterSentimentClient::new();
-        info!("✅ Twitter sentiment client initialized");
+        // Tokens to monitor come from named watchlists bound to each strategy
+        let watchlists = Arc::new(WatchlistRegistry::load(DEFAULT_WATCHLISTS_PATH));
+        
+        // Sentiment providers (Twitter only when credentials are loaded; local model works offline)
+        let mut sentiment_pipeline = SentimentPipeline::standard(multibot_ai.twitter_client.clone());
+        if std::env::var(TWITTER_STREAM_ENV).is_ok() {
+            if let Some(bearer_token) = multibot_ai.twitter_client.bearer_token() {
+                // Streamed tweets replace search polling
+                let symbols = watchlists.tokens_for_strategy(SENTIMENT_WATCHLIST);
+                let symbols: Vec<&str> = symbols.iter().map(String::as_str).collect();
+                let stream = Arc::new(TwitterStream::new(bearer_token, &symbols, twitter_budget.clone()));
+                stream.clone().start();
+                sentiment_pipeline.set_weight("twitter", 0.0);
+                sentiment_pipeline = sentiment_pipeline.with_provider(stream, 0.4).with_env_weights();
+                info!("🐦 Twitter filtered stream enabled");
+            }
+        }
+        info!("✅ Sentiment pipeline initialized - weights: {:?}", sentiment_pipeline.weights());
         
         // ✅ ENTERPRISE: Professional Bot Control System
         info!("🏢 Initializing Enterprise Bot Control System...");
@@ -473,10 +1117,390 @@ impl EnterpriseMultiBotSystem {
         info!("✅ Secure wallet loaded from keypair file");
         info!("🔐 Wallet public key: {}", secure_wallet.pubkey());
         
+        // High-value transfers become Squads proposals when a multisig is configured (wallet must be a member)
+        let mut multisig_guard = None;
+        if let Ok(multisig_address) = std::env::var("SNIPERFORGE_SQUADS_MULTISIG") {
+            let rpc_url = std::env::var("SOLANA_RPC_URL")
+                .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string());
+            let mut config = MultisigConfig {
+                enabled: true,
+                multisig_address,
+                state_path: Some("state/multisig.json".into()),
+                ..Default::default()
+            };
+            if let Some(threshold) = std::env::var("SNIPERFORGE_MULTISIG_THRESHOLD_SOL").ok().and_then(|t| t.parse().ok()) {
+                config.threshold_sol = threshold;
+            }
+            let chain = RpcSquadsChain::new(
+                Arc::new(solana_client::nonblocking::rpc_client::RpcClient::new(rpc_url)),
+                Arc::new(secure_wallet.insecure_clone()),
+            );
+            let guard = Arc::new(MultisigGuard::new(config)?.with_chain(Arc::new(chain)));
+            let executor = guard.clone();
+            let factory: TaskFactory = Arc::new(move |_heartbeat: HeartbeatHandle| {
+                executor.clone().start_executor(std::time::Duration::from_secs(30))
+            });
+            watchdog.register("multisig_executor", None, factory).await;
+            cross_chain_engine = cross_chain_engine.with_multisig_guard(guard.clone());
+            multisig_guard = Some(guard);
+            info!("✅ Squads multisig guard initialized");
+        }
+        
+        // Withdrawals survive restarts and only sweep once an operator approves them
+        let capital_withdrawals = Arc::new(CapitalWithdrawals::open(WithdrawalConfig {
+            require_approval: true,
+            state_path: Some("state/withdrawals.json".into()),
+            ..Default::default()
+        })?);
+        {
+            let rpc_url = std::env::var("SOLANA_RPC_URL")
+                .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string());
+            let backend = Arc::new(RpcWithdrawalBackend::new(
+                Arc::new(solana_client::nonblocking::rpc_client::RpcClient::new(rpc_url)),
+                Arc::new(secure_wallet.insecure_clone()),
+                HOT_WALLET,
+                risk_manager.clone(),
+            ));
+            let withdrawals = capital_withdrawals.clone();
+            let factory: TaskFactory = Arc::new(move |_heartbeat: HeartbeatHandle| {
+                withdrawals.clone().start(backend.clone())
+            });
+            watchdog.register("capital_withdrawals", None, factory).await;
+        }
+        
+        // Phoenix books and trades drive the microstructure signals; near-miss arbitrage
+        // spreads rest there as post-only bids (opt-in: SNIPERFORGE_PHOENIX_MARKETS=SOL/USDC=market,...)
+        let microstructure = Arc::new(MicrostructureMonitor::new(MicrostructureConfig::default()));
+        let mut phoenix = None;
+        let mut maker_mode = None;
+        let markets: Vec<String> = std::env::var("SNIPERFORGE_PHOENIX_MARKETS")
+            .map(|markets| markets.split(',').map(|m| m.trim().to_string()).filter(|m| !m.is_empty()).collect())
+            .unwrap_or_default();
+        if !markets.is_empty() {
+            let rpc_url = std::env::var("SOLANA_RPC_URL")
+                .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string());
+            let rpc = Arc::new(solana_client::nonblocking::rpc_client::RpcClient::new(rpc_url.clone()));
+            match PhoenixClobClient::connect(rpc, Arc::new(secure_wallet.insecure_clone()), &markets).await {
+                Ok(client) => {
+                    let client = Arc::new(client);
+                    let feed = Arc::new(PhoenixMarketFeed::new(
+                        client.clone(),
+                        Arc::new(RpcTransactionSource::new(&rpc_url)),
+                        microstructure.clone(),
+                    ));
+                    let stall_timeout = feed.poll_interval() * 10 + Duration::from_secs(60);
+                    let factory: TaskFactory = Arc::new(move |heartbeat: HeartbeatHandle| {
+                        tokio::spawn(feed.clone().run(heartbeat))
+                    });
+                    watchdog.register("phoenix_market_feed", Some(stall_timeout), factory).await;
+                    let maker_config = MakerModeConfig::for_trading_mode(&trading_mode);
+                    if !maker_config.enabled {
+                        info!("ℹ️ Maker mode stays off in simulation: its orders would rest on the real book");
+                    } else {
+                        let maker = MakerMode::new(maker_config)
+                            .with_client(client.clone())
+                            .with_microstructure(microstructure.clone());
+                        maker_mode = Some(Arc::new(maker));
+                        info!("✅ Maker mode quoting on {} Phoenix markets", markets.len());
+                    }
+                    phoenix = Some(client);
+                }
+                Err(e) => warn!("⚠️ Phoenix markets unavailable, maker mode and CLOB signals off: {}", e),
+            }
+        }
+        
+        // Dust consolidation (control command `consolidate-dust`); swaps go through Jupiter
+        let dust_consolidator = match Jupiter::from_config("mainnet").await {
+            Ok(jupiter) => {
+                let rpc_url = std::env::var("SOLANA_RPC_URL")
+                    .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string());
+                let jupiter = jupiter.with_rpc_client(solana_client::rpc_client::RpcClient::new(rpc_url.clone()));
+                let wallet = RpcDustWallet::new(rpc_url, jupiter, secure_wallet.insecure_clone());
+                Some(Arc::new(DustConsolidator::new(DustConfig::default(), Arc::new(wallet))))
+            }
+            Err(e) => {
+                warn!("⚠️ Dust consolidation unavailable: {}", e);
+                None
+            }
+        };
+        
+        // Profit-taking (opt-in: SNIPERFORGE_PROFIT_TAKE_PERCENT of realized profit → USDC)
+        let profit_taking = match std::env::var("SNIPERFORGE_PROFIT_TAKE_PERCENT").ok().and_then(|p| p.parse::<f64>().ok()) {
+            Some(percent) if percent > 0.0 => {
+                let profit_taking = Arc::new(ProfitTaking::new(ProfitTakingConfig {
+                    convert_percent: percent.min(100.0),
+                    ..Default::default()
+                }));
+                let venue = JupiterConversionVenue::new(JupiterRealConfig::default(), secure_wallet.pubkey().to_string());
+                profit_taking.clone().start(Arc::new(venue));
+                info!("✅ Profit-taking active - {:.0}% of realized profit converted to USDC", percent.min(100.0));
+                profit_taking
+            }
+            _ => Arc::new(ProfitTaking::default()),
+        };
+        
         let bot_controller = BotController::new().await?;
         let bot_controller = Arc::new(bot_controller);
         info!("✅ Enterprise Bot Control System initialized");
         
+        // Key-compromise guard (opt-in: SNIPERFORGE_WATCH_WALLETS=addr1,addr2)
+        let trading_halt = Arc::new(TradingHalt::new());
+        let mut wallet_activity = None;
+        if let Ok(wallets) = std::env::var("SNIPERFORGE_WATCH_WALLETS") {
+            let wallets: Vec<String> = wallets.split(',').map(|w| w.trim().to_string()).filter(|w| !w.is_empty()).collect();
+            if !wallets.is_empty() {
+                let rpc_url = std::env::var("SOLANA_RPC_URL")
+                    .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string());
+                let monitor = Arc::new(
+                    WalletActivityMonitor::new(WalletActivityConfig { wallets, ..Default::default() }, &rpc_url)
+                        .with_alert_manager(enterprise_monitor.alert_manager())
+                        .with_kill_switch(trading_halt.clone())
+                        .with_kill_switch(bot_controller.clone()),
+                );
+                let stall_timeout = monitor.poll_interval() * 4 + Duration::from_secs(60);
+                let task_monitor = monitor.clone();
+                let factory: TaskFactory = Arc::new(move |heartbeat: HeartbeatHandle| {
+                    tokio::spawn(task_monitor.clone().run(heartbeat))
+                });
+                watchdog.register("wallet_activity", Some(stall_timeout), factory).await;
+                wallet_activity = Some(monitor);
+                info!("✅ Wallet activity monitor guarding managed wallets");
+            }
+        }
+        
+        // Bridge transfers resume tracking from their persisted state
+        let bridge_tracker = Arc::new(
+            BridgeTracker::new(BridgeTrackerConfig::default())
+                .with_alert_manager(enterprise_monitor.alert_manager())
+        );
+        let task_tracker = bridge_tracker.clone();
+        let stall_timeout = bridge_tracker.poll_interval() * 4 + Duration::from_secs(60);
+        let factory: TaskFactory = Arc::new(move |heartbeat: HeartbeatHandle| {
+            tokio::spawn(task_tracker.clone().run(heartbeat))
+        });
+        watchdog.register("bridge_tracker", Some(stall_timeout), factory).await;
+        info!("✅ Bridge transfer tracker initialized");
+        
+        // Settle trades a previous run committed to before any new trading
+        let intent_log = Arc::new(IntentLog::open(IntentLogConfig {
+            path: Some("state/trade_intents.jsonl".into()),
+            ..Default::default()
+        })?);
+        let intent_status = Arc::new(RpcSignatureStatus::new(
+            &std::env::var("SOLANA_RPC_URL").unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string()),
+        ));
+        if !intent_log.is_clear() {
+            let report = intent_log.recover(intent_status.as_ref()).await;
+            if report.unresolved > 0 {
+                warn!("📝 {} trade intents still unresolved - trading held until they settle", report.unresolved);
+            }
+        }
+        
+        // Live trades are signed and sent through one pipeline with the hot wallet
+        let token_quarantine = Arc::new(TokenQuarantine::new(QuarantineConfig {
+            state_path: Some("state/token_quarantine.json".into()),
+            ..Default::default()
+        }));
+        let execution_rpc_url = std::env::var("SOLANA_RPC_URL")
+            .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string());
+        // Submissions from before a restart can still land: refuse to send them again
+        let replay_guard = Arc::new(ReplayGuard::open(ReplayGuardConfig {
+            path: Some("state/replay_guard.jsonl".into()),
+            ..Default::default()
+        })?);
+        if !replay_guard.in_flight().is_empty() {
+            warn!("🔁 {} submissions from a previous run may still land", replay_guard.in_flight().len());
+        }
+        install_replay_guard(replay_guard.clone());
+        let execution_pipeline = Arc::new(ExecutionPipeline::new(
+            PipelineConfig::default(),
+            Arc::new(KeypairSigner::new().with_wallet(HOT_WALLET, Arc::new(secure_wallet.insecure_clone()))),
+            Arc::new(RpcSubmitter::new(&execution_rpc_url)),
+        ).with_intent_log(intent_log.clone()).with_replay_guard(replay_guard));
+        let trade_executor = TradeExecutor::new(Config::default(), trading_mode.clone()).await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize trade executor: {}", e))?
+            .with_quarantine(token_quarantine.clone())
+            .with_balance_rpc(Arc::new(solana_client::nonblocking::rpc_client::RpcClient::new(execution_rpc_url)))
+            .with_pipeline(execution_pipeline);
+        trade_executor.get_wallet_manager().add_wallet(WalletConfig {
+            name: HOT_WALLET.to_string(),
+            wallet_type: WalletType::Trading,
+            keypair_path: None,
+            keypair_data: Some(secure_wallet.to_base58_string()),
+            max_sol_balance: f64::MAX,
+            min_sol_balance: 0.01,
+            risk_management: RiskManagement {
+                max_transaction_amount: simple_config.max_position_size,
+                daily_limit: simple_config.max_position_size * 10.0,
+                require_confirmation: false,
+                emergency_stop_threshold: 0.05,
+            },
+        }).await?;
+        let trade_executor = Arc::new(trade_executor);
+        info!("✅ Trade executor ready - live swaps go through the execution pipeline");
+        
+        // Confirmed hot-wallet profit above the retention threshold moves to cold storage
+        let treasury = match std::env::var("SNIPERFORGE_TREASURY_ADDRESS") {
+            Ok(treasury_address) => {
+                let rpc_url = std::env::var("SOLANA_RPC_URL")
+                    .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string());
+                let config = TreasurySweepConfig {
+                    enabled: true,
+                    treasury_address,
+                    source_wallets: vec![HOT_WALLET.to_string()],
+                    dry_run: std::env::var("SNIPERFORGE_TREASURY_LIVE").is_err(),
+                    state_path: Some("state/treasury.json".into()),
+                    ..Default::default()
+                };
+                let mut sweeper = TreasurySweeper::new(
+                    config,
+                    trade_executor.shared_wallet_manager(),
+                    Arc::new(solana_client::nonblocking::rpc_client::RpcClient::new(rpc_url)),
+                )?;
+                if let Some(guard) = &multisig_guard {
+                    sweeper = sweeper.with_multisig(guard.clone());
+                }
+                if trade_indexer.is_none() {
+                    warn!("⚠️ Treasury sweeper has no confirmed-profit feed until SNIPERFORGE_INDEX_WALLETS includes the hot wallet");
+                }
+                info!("✅ Treasury sweeper initialized ({})", if sweeper.is_dry_run() { "dry run" } else { "live" });
+                Some(Arc::new(sweeper))
+            }
+            Err(_) => None,
+        };
+        
+        let fee_budget = Arc::new(FeeBudgetManager::new(FeeBudgetConfig {
+            state_path: Some("state/fee_budget.json".into()),
+            ..Default::default()
+        }));
+        
+        // DAO-governed settings (opt-in: SNIPERFORGE_REALMS_GOVERNANCE=<governance>, SNIPERFORGE_REALMS_PROPOSALS=p1,p2)
+        if let (Ok(governance_address), Ok(proposals)) = (std::env::var("SNIPERFORGE_REALMS_GOVERNANCE"), std::env::var("SNIPERFORGE_REALMS_PROPOSALS")) {
+            let proposal_accounts: Vec<String> = proposals.split(',').map(|p| p.trim().to_string()).filter(|p| !p.is_empty()).collect();
+            let rpc_url = std::env::var("SOLANA_RPC_URL")
+                .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string());
+            let config = GovernanceConfig { governance_address, proposal_accounts, ..Default::default() };
+            let targets = GovernedTargets { fee_budget: Some(fee_budget.clone()), risk: None };
+            match GovernanceWatcher::new(config, &rpc_url, targets) {
+                Ok(watcher) => {
+                    let watcher = Arc::new(watcher);
+                    let stall_timeout = watcher.poll_interval() * 4 + Duration::from_secs(60);
+                    let factory: TaskFactory = Arc::new(move |heartbeat: HeartbeatHandle| {
+                        tokio::spawn(watcher.clone().run(heartbeat))
+                    });
+                    watchdog.register("realms_governance", Some(stall_timeout), factory).await;
+                    info!("✅ Realms governance watcher initialized");
+                }
+                Err(e) => warn!("⚠️ Realms governance disabled: {}", e),
+            }
+        }
+        
+        // Composite health: RPC, feeds, wallet, executors, storage, notification channels
+        let health_registry = Arc::new(HealthRegistry::default());
+        let health_rpc_url = std::env::var("SOLANA_RPC_URL")
+            .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string());
+        health_registry.register(Arc::new(RpcProbe::new("solana_rpc", health_rpc_url.clone())));
+        health_registry.register(Arc::new(FeedProbe));
+        let wallet_pubkey = secure_wallet.pubkey();
+        let wallet_rpc = Arc::new(solana_client::nonblocking::rpc_client::RpcClient::new(health_rpc_url));
+        health_registry.register(Arc::new(FnProbe::new("hot_wallet", "wallet", true, move || {
+            let wallet_rpc = wallet_rpc.clone();
+            async move {
+                match wallet_rpc.get_balance(&wallet_pubkey).await {
+                    Ok(0) => ComponentHealthStatus::Unhealthy("no SOL for fees".to_string()),
+                    Ok(lamports) if lamports < 50_000_000 => {
+                        ComponentHealthStatus::Degraded(vec![format!("low balance: {:.4} SOL", lamports as f64 / 1e9)])
+                    }
+                    Ok(_) => ComponentHealthStatus::Healthy,
+                    Err(e) => ComponentHealthStatus::Unhealthy(e.to_string()),
+                }
+            }
+        })));
+        health_registry.register(Arc::new(WatchdogProbe::new(watchdog.clone())));
+        let health_intents = intent_log.clone();
+        health_registry.register(Arc::new(FnProbe::new("trade_intents", "executors", false, move || {
+            // Intents left by a previous run hold admissions until settled
+            let clear = health_intents.is_clear();
+            async move {
+                if clear {
+                    ComponentHealthStatus::Healthy
+                } else {
+                    ComponentHealthStatus::Degraded(vec!["unsettled intents from a previous run".to_string()])
+                }
+            }
+        })));
+        let health_ladder = drawdown_ladder.clone();
+        health_registry.register(Arc::new(FnProbe::new("drawdown_ladder", "risk", false, move || {
+            let status = health_ladder.status();
+            async move {
+                if status.level == 0 {
+                    ComponentHealthStatus::Healthy
+                } else {
+                    ComponentHealthStatus::Degraded(vec![format!(
+                        "de-risking level {} at {:.2}% daily PnL (size x{:.2}, entries {}, {})",
+                        status.level,
+                        status.daily_pnl_percent,
+                        status.size_multiplier,
+                        if status.entries_allowed { "allowed" } else { "blocked" },
+                        if status.halted { "halted" } else { "trading" },
+                    )])
+                }
+            }
+        })));
+        health_registry.register(Arc::new(StorageProbe::new("state")));
+        health_registry.register(Arc::new(NotificationProbe::new(notification_digest.clone())));
+        // HTTP endpoint for load balancers (opt-in: SNIPERFORGE_HEALTH_ADDR=0.0.0.0:8081)
+        if let Ok(bind_address) = std::env::var("SNIPERFORGE_HEALTH_ADDR") {
+            let registry = health_registry.clone();
+            let factory: TaskFactory = Arc::new(move |_heartbeat: HeartbeatHandle| {
+                let registry = registry.clone();
+                let bind_address = bind_address.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = registry.serve(bind_address).await {
+                        error!("❌ Health endpoint error: {}", e);
+                    }
+                })
+            });
+            watchdog.register("health_endpoint", None, factory).await;
+        }
+        info!("✅ Health checks registered: {:?}", health_registry.component_names());
+        
+        // Helius pushes parsed transactions to the API gateway (opt-in: HELIUS_WEBHOOK_AUTH, the webhook's authHeader)
+        if std::env::var("HELIUS_WEBHOOK_AUTH").is_ok_and(|auth| !auth.is_empty()) {
+            let mut webhook_config = HeliusWebhookConfig::default();
+            if let Ok(wallets) = std::env::var("SNIPERFORGE_WATCH_WALLETS") {
+                webhook_config.watched_wallets = wallets.split(',').map(|w| w.trim().to_string()).filter(|w| !w.is_empty()).collect();
+            }
+            let receiver = Arc::new(HeliusWebhookReceiver::new(webhook_config));
+            
+            let events = receiver.clone();
+            let alert_manager = enterprise_monitor.alert_manager();
+            let factory: TaskFactory = Arc::new(move |_heartbeat: HeartbeatHandle| {
+                tokio::spawn(consume_helius_events(events.subscribe(), alert_manager.clone(), wallet_activity.clone()))
+            });
+            watchdog.register("helius_events", None, factory).await;
+            
+            let port = std::env::var("SNIPERFORGE_API_PORT").ok().and_then(|p| p.parse().ok()).unwrap_or(8080);
+            let gateway = Arc::new(
+                ApiGateway::new(GatewayConfig { port, ..Default::default() })
+                    .with_helius_webhook(receiver)
+                    .with_health_registry(health_registry.clone())
+                    .with_risk_manager(risk_manager.clone())
+                    .with_capital_withdrawals(capital_withdrawals.clone())
+                    .with_profit_taking(profit_taking.clone())
+            );
+            let factory: TaskFactory = Arc::new(move |_heartbeat: HeartbeatHandle| {
+                let gateway = gateway.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = gateway.start().await {
+                        error!("❌ API gateway error: {}", e);
+                    }
+                })
+            });
+            watchdog.register("api_gateway", None, factory).await;
+            info!("✅ API gateway receiving Helius webhooks on port {}", port);
+        }
+        
         // Professional service starts with clean slate
         // Users create and manage bots through CLI commands
         info!("💼 Professional MultiBot Service ready for client requests");
@@ -494,38 +1518,103 @@ impl EnterpriseMultiBotSystem {
             TradingStrategy::UnifiedMultiStrategy,
         ];
         
-        Ok(EnterpriseMultiBotSystem {
-            // Core trading engines
+        // Supervisor tree: feeds first, then one isolated task per engine
+        let fiat_rates = fiat_rates();
+        let engine_findings = Arc::new(tokio::sync::Mutex::new(EngineFindings::default()));
+        let seasonality = Arc::new(parking_lot::Mutex::new(SeasonalityStats::default()));
+        let schedules = ScanScheduleConfig::default();
+        let feed_events = Arc::new(FeedEvents::default());
+        let cycle_schedule = Arc::new(ScanScheduler::new(schedules.schedule("trading_cycle").clone(), &feed_events));
+        let engine_supervisor = build_engine_supervisor(
             arbitrage_engine,
             triangular_engine,
             flash_loan_engine,
             cross_chain_engine,
+            stablecoin_monitor,
+            fiat_rates.clone(),
+            engine_findings.clone(),
+            seasonality.clone(),
+            &schedules,
+            feed_events,
+        );
+        info!("✅ Engine supervisor configured - {:?}", engine_supervisor.start_order()
+            .map_err(|e| anyhow::anyhow!("Invalid engine dependency graph: {}", e))?);
+        
+        // Multi-instance coordination (opt-in via SNIPERFORGE_COORDINATION_URL)
+        let cluster_strategies: Vec<String> = [OpportunitySource::EnhancedArbitrage, OpportunitySource::Triangular, OpportunitySource::RouteOptimizer]
+            .iter()
+            .map(|source| format!("{:?}", source))
+            .collect();
+        let cluster_strategies: Vec<&str> = cluster_strategies.iter().map(String::as_str).collect();
+        let cluster = ClusterCoordinator::from_env(&cluster_strategies).await?.map(Arc::new);
+        if let Some(cluster) = &cluster {
+            cluster.clone().start();
+        }
+        
+        Ok(EnterpriseMultiBotSystem {
+            // Core trading engines
+            engine_supervisor,
+            engine_findings,
+            cycle_schedule,
+            seasonality,
+            opportunity_dedup: OpportunityDeduplicator::new(Duration::from_secs(30)),
+            ladder_executor: LadderExecutor::new(LadderConfig::default()),
+            execution_scheduler: ExecutionScheduler::default(),
+            execution_budget: ExecutionBudget::default(),
+            decision_recorder: DecisionInputRecorder::from_env(),
+            arbitrage_ladders: HashMap::new(),
+            maker_mode,
+            phoenix,
+            cluster,
             
             // AI engines
             ai_engine,
             analytics_engine,
+            benchmark: BenchmarkTracker::default(),
             multibot_ai,
             
             // ✅ ENTERPRISE-GRADE MONITORING & INTELLIGENCE (NOW INTEGRATED)
             enterprise_monitor,
+            watchdog,
+            trade_indexer,
+            dust_consolidator,
             intelligence_system,
             autonomous_trader,
             advanced_ai_engine,
             sentiment_analyzer,
             
             // Real-time data systems
-            stablecoin_monitor,
-            twitter_client,
+            sentiment_pipeline,
+            sentiment_blends: HashMap::new(),
+            watchlists,
             
             // ✅ EXTERNAL CONTROL SYSTEM - Phase 8 Implementation
             bot_controller: bot_controller.clone(),
+            trading_halt,
             tcp_server: None, // Will be initialized in run method
             
             // Infrastructure
-            _price_feeds: RealPriceFeeds::new(),
+            price_feeds: RealPriceFeeds::new(),
+            fiat_rates,
             
             // System state
             active_strategies,
+            strategy_guard: Arc::new(StrategyKillSwitch::default()),
+            fee_budget,
+            profit_ledger: ProfitLedger::new(AccountingMode::for_trading_mode(&trading_mode)),
+            bridge_tracker,
+            token_quarantine,
+            intent_log,
+            intent_status,
+            trade_executor,
+            treasury,
+            health_registry,
+            risk_manager,
+            risk_tokens: HashMap::from([("SOL".to_string(), SOL_MINT.to_string())]),
+            drawdown_ladder,
+            capital_withdrawals,
+            profit_taking,
+            rpc_usage_reported: Utc::now().date_naive(),
             system_metrics: MultiBotMetrics::default(),
             cycle_count: 0,
             total_profit: 0.0,
@@ -534,150 +1623,518 @@ impl EnterpriseMultiBotSystem {
     }
     
     
-    /// Execute enterprise MultiBot demonstration
-    pub async fn run_enterprise_demonstration(&mut self) -> Result<()> {
-        info!("🎯 Enterprise MultiBot System operational - beginning professional demonstration");
+    /// Attach the services the control API exposes
+    fn wire_control_server(&self, server: TcpControlServer) -> TcpControlServer {
+        let server = server
+            .with_strategy_guard(self.strategy_guard.clone())
+            .with_bridge_tracker(self.bridge_tracker.clone())
+            .with_health_registry(self.health_registry.clone())
+            .with_watchlists(self.watchlists.clone())
+            .with_risk_manager(Arc::new(self.risk_manager.clone()))
+            .with_capital_withdrawals(self.capital_withdrawals.clone());
+        let server = match &self.trade_indexer {
+            Some(indexer) => server.with_trade_indexer(indexer.clone()),
+            None => server,
+        };
+        match &self.dust_consolidator {
+            Some(consolidator) => server.with_dust_consolidator(consolidator.clone()),
+            None => server,
+        }
+    }
+    
+    /// Start the TCP control server as a watchdog-supervised task
+    async fn start_supervised_control_server(&self) -> Result<()> {
+        // Bind up front so a busy port still fails startup; restarts re-bind
+        let initial_server = Arc::new(std::sync::Mutex::new(Some(
+            self.wire_control_server(TcpControlServer::new(self.bot_controller.clone(), 8888).await?)
+        )));
+        let bot_controller = self.bot_controller.clone();
+        let strategy_guard = self.strategy_guard.clone();
+        let bridge_tracker = self.bridge_tracker.clone();
+        let trade_indexer = self.trade_indexer.clone();
+        let dust_consolidator = self.dust_consolidator.clone();
+        let health_registry = self.health_registry.clone();
+        let watchlists = self.watchlists.clone();
+        let risk_manager = Arc::new(self.risk_manager.clone());
+        let capital_withdrawals = self.capital_withdrawals.clone();
+        
+        let factory: TaskFactory = Arc::new(move |_heartbeat: HeartbeatHandle| {
+            let initial = initial_server.lock().ok().and_then(|mut slot| slot.take());
+            let bot_controller = bot_controller.clone();
+            let strategy_guard = strategy_guard.clone();
+            let bridge_tracker = bridge_tracker.clone();
+            let trade_indexer = trade_indexer.clone();
+            let dust_consolidator = dust_consolidator.clone();
+            let health_registry = health_registry.clone();
+            let watchlists = watchlists.clone();
+            let risk_manager = risk_manager.clone();
+            let capital_withdrawals = capital_withdrawals.clone();
+            tokio::spawn(async move {
+                let server = match initial {
+                    Some(server) => server,
+                    None => match TcpControlServer::new(bot_controller, 8888).await {
+                        Ok(server) => {
+                            let server = server
+                                .with_strategy_guard(strategy_guard)
+                                .with_bridge_tracker(bridge_tracker)
+                                .with_health_registry(health_registry)
+                                .with_watchlists(watchlists)
+                                .with_risk_manager(risk_manager)
+                                .with_capital_withdrawals(capital_withdrawals);
+                            let server = match trade_indexer {
+                                Some(indexer) => server.with_trade_indexer(indexer),
+                                None => server,
+                            };
+                            match dust_consolidator {
+                                Some(consolidator) => server.with_dust_consolidator(consolidator),
+                                None => server,
+                            }
+                        }
+                        Err(e) => {
+                            error!("❌ TCP Control Server restart failed: {}", e);
+                            return;
+                        }
+                    },
+                };
+                if let Err(e) = server.run().await {
+                    error!("❌ TCP Control Server error: {}", e);
+                }
+            })
+        });
+        
+        // The accept loop blocks on I/O, so only exits/panics are monitored
+        self.watchdog.register("tcp_control_server", None, factory).await;
+        Ok(())
+    }
+    
+    /// Write engine state (route cache, dedup cooldowns, AI model, run totals) to a versioned snapshot
+    pub async fn export_state_snapshot(&self, path: &str) -> Result<()> {
+        let ai = &self.multibot_ai;
+        let mut snapshot = EngineStateSnapshot::new();
+        snapshot.insert("route_cache", 1, &ai.route_optimizer.export_performance_cache())?;
+        snapshot.insert("venue_stats", 1, &ai.route_optimizer.export_venue_stats())?;
+        snapshot.insert("dedup_cooldowns", 1, &self.opportunity_dedup.export_cooldowns())?;
+        snapshot.insert("ai_model", 1, &AiModelSnapshot {
+            lstm_prediction_accuracy: ai.lstm_prediction_accuracy,
+            random_forest_accuracy: ai.random_forest_accuracy,
+            neural_network_accuracy: ai.neural_network_accuracy,
+            ensemble_accuracy: ai.ensemble_accuracy,
+            confidence_threshold: ai.confidence_threshold,
+            total_predictions: ai.total_predictions,
+            successful_predictions: ai.successful_predictions,
+        })?;
+        snapshot.insert("run_totals", 1, &RunTotalsSnapshot {
+            cycle_count: self.cycle_count,
+            total_profit: self.total_profit,
+            total_enterprise_cycles: self.system_metrics.total_enterprise_cycles,
+            stablecoin_depegging_alerts: self.system_metrics.stablecoin_depegging_alerts,
+        })?;
+        snapshot.write_to_file(path).await?;
+        Ok(())
+    }
+    
+    /// Restore engine state from a snapshot written by `export_state_snapshot`
+    pub async fn import_state_snapshot(&mut self, path: &str) -> Result<()> {
+        let snapshot = EngineStateSnapshot::read_from_file(path).await?;
         
-        // ✅ INITIALIZE TCP CONTROL SERVER - External Bot Management
-        info!("🌐 Starting TCP Control Server for external CLI access...");
-        let tcp_server = TcpControlServer::new(self.bot_controller.clone(), 8888).await?;
+        if let Some(cache) = snapshot.get("route_cache", 1)? {
+            self.multibot_ai.route_optimizer.restore_performance_cache(cache);
+        }
+        if let Some(venue_stats) = snapshot.get("venue_stats", 1)? {
+            self.multibot_ai.route_optimizer.restore_venue_stats(venue_stats);
+        }
+        if let Some(cooldowns) = snapshot.get::<Vec<DedupCooldown>>("dedup_cooldowns", 1)? {
+            self.opportunity_dedup.restore_cooldowns(cooldowns);
+        }
+        if let Some(model) = snapshot.get::<AiModelSnapshot>("ai_model", 1)? {
+            let ai = &mut self.multibot_ai;
+            ai.lstm_prediction_accuracy = model.lstm_prediction_accuracy;
+            ai.random_forest_accuracy = model.random_forest_accuracy;
+            ai.neural_network_accuracy = model.neural_network_accuracy;
+            ai.ensemble_accuracy = model.ensemble_accuracy;
+            ai.confidence_threshold = model.confidence_threshold;
+            ai.total_predictions = model.total_predictions;
+            ai.successful_predictions = model.successful_predictions;
+        }
+        if let Some(totals) = snapshot.get::<RunTotalsSnapshot>("run_totals", 1)? {
+            self.cycle_count = totals.cycle_count;
+            self.total_profit = totals.total_profit;
+            self.system_metrics.total_profit_usd = totals.total_profit;
+            self.system_metrics.total_enterprise_cycles = totals.total_enterprise_cycles;
+            self.system_metrics.stablecoin_depegging_alerts = totals.stablecoin_depegging_alerts;
+        }
         
-        // Start TCP server in background
-        tokio::spawn(async move {
-            if let Err(e) = tcp_server.run().await {
-                error!("❌ TCP Control Server error: {}", e);
-            }
-        });
+        info!("📸 Engine state restored from {} (components: {})", path, snapshot.component_keys().join(", "));
+        Ok(())
+    }
+    
+    /// Service loop: control server up, then react to timers and shutdown signals
+    ///
+    /// Trading is driven externally (CLI / TCP control); the loop itself only
+    /// keeps the hand-off snapshot fresh, reports liveness and shuts everything
+    /// down cleanly on Ctrl-C / SIGTERM.
+    pub async fn run_service(&mut self) -> Result<()> {
+        info!("🎯 SniperForge Enterprise service starting");
         
-        // Store a placeholder (we can't store the server since it's moved to the task)
+        info!("🌐 Starting TCP Control Server for external CLI access...");
+        self.start_supervised_control_server().await?;
         self.tcp_server = None;
         info!("✅ TCP Control Server running on port 8888");
-        info!("💡 You can now use: cargo run --bin sniperforge-cli -- ping");
         
-        // 🔧 ENTERPRISE SYSTEMS - DISABLED AUTO-START (Cost Control)
-        info!("🔧 Enterprise Systems initialized but NOT auto-started (cost control)");
-        info!("💡 Use CLI commands to manually start specific systems when needed");
-        info!("⚠️ Auto-mode disabled to prevent unnecessary RPC costs");
+        // Feeds and engines run in their own supervised tasks; `shutdown` stops them again
+        if let Err(e) = self.engine_supervisor.start().await {
+            self.shutdown().await;
+            return Err(anyhow::anyhow!("Engine supervisor failed to start: {}", e));
+        }
+        info!("🌳 Engine supervisor running - feeds and engines isolated per task");
         
-        // Systems are initialized but NOT started automatically
-        // This prevents expensive RPC calls without explicit user consent
+        self.display_multibot_system_overview();
         
-        info!("✅ All Enterprise Systems initialized (not auto-started)");
+        info!("🔧 Enterprise Systems initialized and ready for commands");
+        info!("💡 Available CLI commands:");
+        info!("   • cargo run --bin sniperforge-cli -- ping");
+        info!("   • cargo run --bin sniperforge-cli -- create-bot enhanced-arbitrage");
+        info!("   • cargo run --bin sniperforge-cli -- list-bots");
+        info!("   • cargo run --bin sniperforge-cli -- system-metrics");
         
-        // Display initial system overview
-        self.display_multibot_system_overview();
+        let snapshot_path = std::env::var("SNIPERFORGE_SNAPSHOT_PATH").ok();
+        let status_publisher = StatusPublisher::new(
+            std::env::var("SNIPERFORGE_STATUS_PATH").unwrap_or_else(|_| DEFAULT_STATUS_PATH.to_string()));
+        let mut status_timer = tokio::time::interval(Duration::from_secs(5));
+        let mut snapshot_timer = tokio::time::interval(Duration::from_secs(30));
+        let mut heartbeat_timer = tokio::time::interval(Duration::from_secs(6 * 3600));
+        let mut metrics_timer = tokio::time::interval(Duration::from_secs(60));
+        let mut settlement_timer = tokio::time::interval(Duration::from_secs(60));
+        let mut risk_limits_timer = tokio::time::interval(Duration::from_secs(300));
+        let mut maker_timer = tokio::time::interval(Duration::from_secs(5));
+        snapshot_timer.tick().await;
+        heartbeat_timer.tick().await;
+        // Minute/hour/day history behind the API charts survives restarts
+        let metrics_path = std::path::PathBuf::from(
+            std::env::var("SNIPERFORGE_METRICS_STORE_PATH").unwrap_or_else(|_| DEFAULT_METRICS_STORE_PATH.to_string()));
+        if metrics_path.exists() {
+            match metrics_store().load(&metrics_path) {
+                Ok(series) => info!("📈 Restored {} metric series from {}", series, metrics_path.display()),
+                Err(e) => warn!("⚠️ Could not load metrics history from {}: {}", metrics_path.display(), e),
+            }
+        }
+        let mut metrics_samples: u64 = 0;
+        let mut halt_reported = false;
+        // Cycles run when engines publish findings, or at the schedule's interval without them
+        let cycle_schedule = self.cycle_schedule.clone();
+        let next_cycle = cycle_schedule.wait(cycle_schedule.base_interval());
+        tokio::pin!(next_cycle);
+        let mut cycles_paused: Option<String> = None;
         
-        // Run 18 advanced demonstration cycles (extended for enterprise presentation)
-        for cycle in 1..=18 {
-            self.cycle_count += 1;
-            let cycle_start = std::time::Instant::now();
-            
-            info!("🔄 Executing MultiBot trading cycle #{}", cycle);
-            
-            match self.execute_multibot_trading_cycle().await {
-                Ok(cycle_profit) => {
-                    self.total_profit += cycle_profit;
-                    self.update_system_metrics(cycle_profit);
-                    
-                    // Display professional dashboard every 3 cycles
-                    if cycle % 3 == 0 {
-                        self.display_multibot_dashboard();
+        loop {
+            tokio::select! {
+                _ = shutdown_signal() => {
+                    info!("🛑 Shutdown signal received");
+                    break;
+                }
+                trigger = &mut next_cycle => {
+                    match self.cycle_block_reason() {
+                        Some(reason) => {
+                            if cycles_paused.as_deref() != Some(reason.as_str()) {
+                                warn!("⏸️ Trading cycles paused: {}", reason);
+                            }
+                            cycles_paused = Some(reason);
+                            // A flat sample lets the ladder reach its next UTC day reset while paused
+                            self.drawdown_ladder.record_pnl(0.0);
+                        }
+                        None => {
+                            if cycles_paused.take().is_some() {
+                                info!("▶️ Trading cycles resumed");
+                            }
+                            self.run_service_cycle(trigger).await;
+                        }
                     }
-                    
-                    // Generate comprehensive reports every 6 cycles
-                    if cycle % 6 == 0 {
-                        self.generate_enterprise_performance_report().await;
+                    next_cycle.set(cycle_schedule.wait(cycle_schedule.base_interval()));
+                }
+                _ = snapshot_timer.tick() => {
+                    // Keep a fresh snapshot on disk for blue/green handoff
+                    if let Some(path) = &snapshot_path {
+                        if let Err(e) = self.export_state_snapshot(path).await {
+                            warn!("⚠️ State snapshot export failed: {}", e);
+                        }
                     }
-                    
-                    // Execute AI optimization every 9 cycles
-                    if cycle % 9 == 0 {
-                        self.execute_ai_multibot_optimization().await;
+                    if let Some((reason, since)) = self.trading_halt.reason() {
+                        if !halt_reported {
+                            error!("🛑 Trading halted since {}: {}", since, reason);
+                            halt_reported = true;
+                        }
+                    } else {
+                        halt_reported = false;
                     }
-                },
-                Err(e) => {
-                    error!("❌ MultiBot trading cycle failed: {}", e);
-                    continue;
                 }
-            }
-            
-            // Professional timing - maintain 8-second cycles (faster for demo)
-            let cycle_duration = cycle_start.elapsed();
-            let sleep_time = Duration::from_secs(8).saturating_sub(cycle_duration);
-            if sleep_time > Duration::from_secs(0) {
-                sleep(sleep_time).await;
+                _ = status_timer.tick() => {
+                    // Read by `sniperforge-cli status`, which needs no server
+                    let bots = match self.bot_controller.list_bots().await {
+                        Ok(bots) => bots,
+                        Err(e) => {
+                            status_publisher.record_error("bot_controller", e.to_string());
+                            Vec::new()
+                        }
+                    };
+                    let halted = self.trading_halt.reason().map(|(reason, since)| format!("{} (since {})", reason, since));
+                    let snapshot = status_publisher.build(&bots, halted, Utc::now());
+                    if let Err(e) = status_publisher.write(&snapshot).await {
+                        debug!("Status snapshot write to {} failed: {}", status_publisher.path().display(), e);
+                    }
+                }
+                _ = metrics_timer.tick() => {
+                    self.record_metrics_sample().await;
+                    metrics_samples += 1;
+                    if metrics_samples % 10 == 0 {
+                        if let Err(e) = metrics_store().save(&metrics_path) {
+                            debug!("Metrics store write to {} failed: {}", metrics_path.display(), e);
+                        }
+                    }
+                }
+                _ = settlement_timer.tick() => {
+                    // Confirmed fills feed the treasury budget; sweeps run on its own schedule.
+                    // Fills confirmed between cycles count toward the total like a cycle's own.
+                    let confirmed_profit = self.settle_confirmed_fills().await;
+                    if confirmed_profit != 0.0 {
+                        self.total_profit += confirmed_profit;
+                        self.system_metrics.total_profit_usd += confirmed_profit;
+                        self.apply_drawdown_ladder(confirmed_profit).await;
+                    }
+                    if let Some(treasury) = self.treasury.clone() {
+                        if treasury.is_sweep_due().await {
+                            match treasury.run_sweep().await {
+                                Ok(records) => {
+                                    for record in records.iter().filter(|r| r.status != SweepStatus::Skipped) {
+                                        info!("🏦 Treasury sweep {}: {:?} {:.4} SOL",
+                                              record.plan.wallet_name, record.status, record.plan.sweep_amount_sol);
+                                    }
+                                }
+                                Err(e) => warn!("⚠️ Treasury sweep failed: {}", e),
+                            }
+                        }
+                    }
+                }
+                _ = risk_limits_timer.tick() => {
+                    self.refresh_risk_limits().await;
+                }
+                _ = maker_timer.tick() => {
+                    // Resting maker orders age out and back off toxic flow between cycles too, and while they are paused
+                    if let Some(maker) = &self.maker_mode {
+                        maker.expire().await;
+                        maker.cancel_toxic().await;
+                    }
+                }
+                _ = heartbeat_timer.tick() => {
+                    let uptime_hours = (Utc::now() - self.system_start_time).num_hours();
+                    info!("💓 SniperForge Enterprise heartbeat - Uptime: {} hours", uptime_hours);
+                }
             }
         }
         
-        self.display_enterprise_final_summary();
+        if let Some(path) = &snapshot_path {
+            if let Err(e) = self.export_state_snapshot(path).await {
+                warn!("⚠️ Final state snapshot export failed: {}", e);
+            }
+        }
+        if let Err(e) = metrics_store().save(&metrics_path) {
+            warn!("⚠️ Final metrics store write failed: {}", e);
+        }
+        self.shutdown().await;
         Ok(())
     }
     
-    /// ✅ NUEVO: Modo standby - Sistema listo pero no ejecutando automáticamente
-    pub async fn run_standby_mode(&mut self) -> Result<()> {
-        info!("🎯 SniperForge Enterprise System entering STANDBY mode");
-        
-        // ✅ INITIALIZE TCP CONTROL SERVER - External Bot Management
-        info!("🌐 Starting TCP Control Server for external CLI access...");
-        let tcp_server = TcpControlServer::new(self.bot_controller.clone(), 8888).await?;
-        
-        // Start TCP server in background
-        tokio::spawn(async move {
-            if let Err(e) = tcp_server.run().await {
-                error!("❌ TCP Control Server error: {}", e);
+    /// Why service-mode trading cycles must not run right now
+    fn cycle_block_reason(&self) -> Option<String> {
+        if let Some((reason, since)) = self.trading_halt.reason() {
+            return Some(format!("trading halted since {}: {}", since, reason));
+        }
+        if !self.risk_manager.entries_allowed() {
+            return Some("drawdown ladder blocks new entries".to_string());
+        }
+        None
+    }
+    
+    /// One trading cycle in service mode, accounted like the demonstration's
+    async fn run_service_cycle(&mut self, trigger: ScanTrigger) {
+        self.cycle_count += 1;
+        debug!("🔁 Trading cycle #{} ({:?})", self.cycle_count, trigger);
+        match self.execute_multibot_trading_cycle().await {
+            Ok(cycle_profit) => {
+                self.total_profit += cycle_profit;
+                self.update_system_metrics(cycle_profit);
             }
-        });
-        
-        // Store a placeholder (we can't store the server since it's moved to the task)
-        self.tcp_server = None;
-        info!("✅ TCP Control Server running on port 8888");
-        info!("💡 You can now use: cargo run --bin sniperforge-cli -- ping");
-        
-        // Display initial system overview
-        self.display_multibot_system_overview();
-        
-        info!("🔧 Enterprise Systems initialized and ready for commands");
-        info!("💡 Available CLI commands:");
-        info!("   • cargo run --bin sniperforge-cli -- ping");
-        info!("   • cargo run --bin sniperforge-cli -- create-bot enhanced-arbitrage");
-        info!("   • cargo run --bin sniperforge-cli -- list-bots");
-        info!("   • cargo run --bin sniperforge-cli -- system-metrics");
-        info!("⚠️ No automatic trading cycles - system waits for manual activation");
-        
-        // Keep system alive without auto-trading - wait for CLI commands
-        loop {
-            sleep(Duration::from_secs(30)).await; // Heartbeat every 30 seconds
-            
-            // Light monitoring without expensive operations
-            let uptime_hours = (Utc::now() - self.system_start_time).num_hours();
-            if uptime_hours % 6 == 0 && uptime_hours > 0 { // Every 6 hours
-                info!("💓 SniperForge Enterprise heartbeat - Uptime: {} hours", uptime_hours);
-                info!("   TCP Server: ✅ Active | Bot Controller: ✅ Ready | CLI: ✅ Available");
+            Err(e) => error!("❌ MultiBot trading cycle failed: {}", e),
+        }
+    }
+    
+    /// One sample of the point-in-time metrics into the time-series store
+    async fn record_metrics_sample(&self) {
+        let bots = self.bot_controller.list_bots().await.unwrap_or_default();
+        let running = bots.iter().filter(|bot| matches!(bot.status, BotStatus::Running)).count();
+        let bot_pnl: f64 = bots.iter().filter_map(|bot| bot.metrics.trading.total_pnl_usd).sum();
+        let metrics = &self.system_metrics;
+        metrics_store().record_all(Utc::now(), &[
+            ("trading.total_profit_usd", metrics.total_profit_usd),
+            ("trading.trades_executed", metrics.total_trades_executed as f64),
+            ("trading.success_rate_pct", metrics.success_rate_percentage),
+            ("trading.sharpe_ratio", metrics.sharpe_ratio),
+            ("trading.max_drawdown", metrics.maximum_drawdown),
+            ("trading.market_sentiment", metrics.current_market_sentiment),
+            ("system.cycles", metrics.total_enterprise_cycles as f64),
+            ("system.degraded_cycles", metrics.degraded_cycles as f64),
+            ("bots.registered", bots.len() as f64),
+            ("bots.running", running as f64),
+            ("bots.total_pnl_usd", bot_pnl),
+        ]);
+    }
+    
+    /// Stop engines and leave the coordination cluster
+    async fn shutdown(&mut self) {
+        self.engine_supervisor.shutdown().await;
+        if let Some(indexer) = &self.trade_indexer {
+            let stats = indexer.get_stats().await;
+            info!("📚 Trade index: {} rows across {} wallets (last sync: {:?})",
+                  stats.indexed_trades, stats.wallets, stats.last_sync);
+        }
+        if let Some(cluster) = &self.cluster {
+            if let Err(e) = cluster.shutdown().await {
+                warn!("⚠️ Failed to leave coordination cluster cleanly: {}", e);
+            }
+        }
+    }
+    
+    /// Confirm submitted fills against the on-chain trade store and credit their profit
+    async fn settle_confirmed_fills(&mut self) -> f64 {
+        // Only fills the on-chain trade store has seen count as confirmed profit
+        let fills = match &self.trade_indexer {
+            Some(indexer) => self.profit_ledger.reconcile_fills(&indexer.trades(None).await),
+            None => Vec::new(),
+        };
+        // The kill switch judges strategies on what their fills realized, never on estimates
+        for fill in fills.iter().filter(|fill| fill.realized) {
+            self.strategy_guard.record_outcome(&fill.source, fill.pnl_usd);
+        }
+        let confirmed_profit: f64 = fills.iter().map(|fill| fill.pnl_usd).sum();
+        self.profit_taking.record_realized(confirmed_profit);
+        if fills.is_empty() {
+            return confirmed_profit;
+        }
+        let sol_usd = match self.fiat_rates.get_rate(FiatAsset::Sol).await {
+            Ok(rate) if rate.usd > 0.0 => Some(rate.usd),
+            Ok(_) | Err(_) => None,
+        };
+        // Fee budgets book what each transaction actually paid and what its fill realized
+        for fill in &fills {
+            self.fee_budget.record_fee(&fill.source, FeeKind::BaseFee, fill.fee_lamports);
+            match (fill.realized, sol_usd) {
+                (true, Some(rate)) => self.fee_budget.record_profit(&fill.source, fill.pnl_usd / rate),
+                (true, None) => warn!("⚠️ No SOL/USD rate: {:+.2} USD realized by {} left out of its fee-to-profit ratio",
+                                      fill.pnl_usd, fill.source),
+                (false, _) => {}
+            }
+        }
+        if let (Some(treasury), true) = (&self.treasury, confirmed_profit != 0.0) {
+            match sol_usd {
+                Some(rate) => treasury.record_realized_profit(HOT_WALLET, confirmed_profit / rate).await,
+                None => warn!("⚠️ No SOL/USD rate: {:+.2} USD of confirmed profit not credited to the treasury budget",
+                              confirmed_profit),
+            }
+        }
+        confirmed_profit
+    }
+    
+    /// Refresh what the per-token exposure caps depend on: portfolio value and pool liquidity
+    ///
+    /// Exposure is measured in SOL like the position limits, so the portfolio
+    /// value is the hot wallet's SOL balance. Liquidity is recorded under both
+    /// the symbol and the mint, since strategies key exposure by either.
+    async fn refresh_risk_limits(&self) {
+        match self.trade_executor.get_wallet_balance(HOT_WALLET).await {
+            Ok(balance) => self.risk_manager.set_portfolio_value(balance),
+            Err(e) => warn!("⚠️ Portfolio value not refreshed, percent exposure caps keep their last value: {}", e),
+        }
+        for (symbol, mint) in &self.risk_tokens {
+            match self.price_feeds.token_liquidity_usd(mint).await {
+                Ok(liquidity_usd) => {
+                    self.risk_manager.set_token_liquidity(symbol, liquidity_usd);
+                    self.risk_manager.set_token_liquidity(mint, liquidity_usd);
+                }
+                Err(e) => debug!("💧 No pool liquidity for {} ({}): {}", symbol, mint, e),
             }
         }
     }
     
     /// Execute a complete MultiBot trading cycle with ALL NEW INTEGRATIONS
     async fn execute_multibot_trading_cycle(&mut self) -> Result<f64> {
-        let mut cycle_profit = 0.0;
+        let mut cycle = CycleProfit::new();
+        
+        // Intents left over from a crash are settled before anything new is admitted;
+        // this run's submissions are confirmed the same way
+        if !self.intent_log.unresolved().is_empty() {
+            let report = self.intent_log.reconcile(self.intent_status.as_ref()).await;
+            if report.unresolved > 0 {
+                debug!("📝 {} trade intents awaiting chain confirmation", report.unresolved);
+            }
+        }
+        
+        let confirmed_profit = self.settle_confirmed_fills().await;
+        
+        // Collect what the supervised feeds/engines published since the last cycle
+        let mut findings = {
+            let mut queues = self.engine_findings.lock().await;
+            queues.prune_expired(Utc::now());
+            queues.drain()
+        };
+        
+        // Highest expected value per second first; the rest waits for budget
+        let sol_usd = self.fiat_rates.cached_rate(FiatAsset::Sol).await.map(|rate| rate.usd).filter(|usd| *usd > 0.0);
+        let usd_per_unit = |mint: &str| match mint {
+            USDC_MINT | USDT_MINT => Some(1.0),
+            SOL_MINT => sol_usd,
+            _ => None,
+        };
+        // Capital marked for withdrawal is not allocated to new trades
+        let locked_usd: f64 = [SOL_MINT, USDC_MINT, USDT_MINT]
+            .into_iter()
+            .zip(["SOL", "USDC", "USDT"])
+            .filter_map(|(mint, symbol)| usd_per_unit(mint)
+                .map(|usd| (self.capital_withdrawals.locked(symbol) + self.capital_withdrawals.locked(mint)) * usd))
+            .sum();
+        let budget = ExecutionBudget {
+            capital_usd: (self.execution_budget.capital_usd - locked_usd).max(0.0),
+            ..self.execution_budget.clone()
+        };
+        let (pending, now) = (findings.opportunities(), Utc::now());
+        if let Some(recorder) = self.decision_recorder.as_mut() {
+            let prices: Vec<(&str, f64)> = [SOL_MINT, USDC_MINT, USDT_MINT]
+                .into_iter()
+                .filter_map(|mint| usd_per_unit(mint).map(|usd| (mint, usd)))
+                .collect();
+            if let Err(e) = recorder.record_round(now, &prices, &budget, &pending) {
+                warn!("⚠️ Failed to record decision inputs: {}", e);
+            }
+        }
+        let plan = self.execution_scheduler.plan(&pending, usd_per_unit, &budget, now);
+        if !plan.deferred.is_empty() || !plan.dropped.is_empty() {
+            info!("🗓️ Scheduled {} opportunities ({} deferred by budget, {} dropped)",
+                  plan.run.len(), plan.deferred.len(), plan.dropped.len());
+        }
+        findings.apply_plan(&plan);
+        self.report_engine_failures().await;
+        self.report_rpc_usage();
+        sniperforge::monitoring::latency_heatmap().check_degradations();
         
         // ✅ 1. REAL STABLECOIN PRICE MONITORING
-        info!("💰 Updating real-time stablecoin prices...");
-        if let Err(e) = self.stablecoin_monitor.update_stablecoin_prices().await {
-            warn!("⚠️ Stablecoin price update failed: {}", e);
-        } else {
-            self.stablecoin_monitor.display_stablecoin_status();
+        info!("💰 Checking real-time stablecoin prices...");
+        let depeg_opportunities = &findings.depeg_events;
+        if !depeg_opportunities.is_empty() {
+            info!("🚨 DEPEGGING ALERT: {} opportunities detected!", depeg_opportunities.len());
+            self.system_metrics.stablecoin_depegging_alerts += depeg_opportunities.len() as u32;
             
-            // Check for depegging opportunities
-            let depeg_opportunities = self.stablecoin_monitor.scan_depeg_opportunities();
-            if !depeg_opportunities.is_empty() {
-                info!("🚨 DEPEGGING ALERT: {} opportunities detected!", depeg_opportunities.len());
-                self.system_metrics.stablecoin_depegging_alerts += depeg_opportunities.len() as u32;
-                
-                for opportunity in depeg_opportunities {
-                    cycle_profit += opportunity.opportunity_size;
-                    info!("  💸 {} depegging opportunity: +${:.2}", 
-                          opportunity.stablecoin, opportunity.opportunity_size);
-                }
+            for opportunity in depeg_opportunities {
+                cycle.hypothetical("DepegOpportunity", opportunity.opportunity_size);
+                info!("  💸 {} depegging opportunity: ${:.2} (estimate)", 
+                      opportunity.stablecoin, opportunity.opportunity_size);
             }
         }
         
@@ -691,75 +2148,89 @@ impl EnterpriseMultiBotSystem {
             info!("✅ Enterprise monitoring active");
         }
         
-        let symbols = ["SOL", "BTC", "ETH"];
-        let mut market_sentiment_avg = 0.0;
+        // Symbols of the watchlists bound to sentiment; each list is re-scored on its own interval
+        let symbols = self.watchlists.tokens_for_strategy(SENTIMENT_WATCHLIST);
+        let due = self.watchlists.take_due(SENTIMENT_WATCHLIST, Utc::now());
+        self.sentiment_blends.retain(|symbol, _| symbols.contains(symbol));
+        let mut blends = Vec::with_capacity(symbols.len());
         let mut twitter_sentiment_avg = 0.0;
         let mut sentiment_count = 0;
         
         for symbol in &symbols {
-            // Regular sentiment analysis
-            match self.multibot_ai.sentiment_analyzer.calculate_sentiment_score(symbol).await {
-                Ok(sentiment) => {
-                    market_sentiment_avg += sentiment;
-                    sentiment_count += 1;
-                    
-                    let sentiment_label = if sentiment > 0.2 {
-                        "🟢 BULLISH"
-                    } else if sentiment < -0.2 {
-                        "🔴 BEARISH"
-                    } else {
-                        "🟡 NEUTRAL"
-                    };
-                    
-                    info!("  📊 {} sentiment: {:.3} ({})", symbol, sentiment, sentiment_label);
-                },
-                Err(e) => warn!("  ⚠️ Failed to analyze {} sentiment: {}", symbol, e),
-            }
-            
-            // ✅ TWITTER SENTIMENT ANALYSIS (NEW!)
-            if self.twitter_client.has_credentials() {
-                match self.twitter_client.analyze_crypto_sentiment(symbol).await {
-                    Ok(twitter_data) => {
-                        twitter_sentiment_avg += twitter_data.sentiment_score;
-                        info!("  🐦 {} Twitter sentiment: {:.3} ({} tweets analyzed)", 
-                              symbol, twitter_data.sentiment_score, twitter_data.tweet_count);
-                        
-                        if !twitter_data.trending_hashtags.is_empty() {
-                            info!("    📈 Trending: {}", twitter_data.trending_hashtags.join(", "));
-                        }
-                    },
-                    Err(e) => warn!("  ⚠️ Twitter sentiment failed for {}: {}", symbol, e),
+            // ✅ WEIGHTED SENTIMENT ACROSS ALL AVAILABLE PROVIDERS
+            let blend = match self.sentiment_blends.get(symbol) {
+                Some(cached) if !due.contains(symbol) => cached.clone(),
+                _ => {
+                    let blend = self.sentiment_pipeline.score(symbol).await;
+                    self.sentiment_blends.insert(symbol.clone(), blend.clone());
+                    blend
                 }
+            };
+            if blend.confidence == 0.0 {
+                warn!("  ⚠️ No sentiment provider answered for {} - neutral", symbol);
+                blends.push(blend);
+                continue;
             }
+            twitter_sentiment_avg += blend.breakdown.get("twitter").copied().unwrap_or(0.0);
+            sentiment_count += 1;
+            
+            let sentiment_label = if blend.score > 0.2 {
+                "🟢 BULLISH"
+            } else if blend.score < -0.2 {
+                "🔴 BEARISH"
+            } else {
+                "🟡 NEUTRAL"
+            };
+            
+            info!("  📊 {} sentiment: {:.3} ({}) - confidence {:.2}, sources {:?}",
+                  symbol, blend.score, sentiment_label, blend.confidence, blend.breakdown);
+            blends.push(blend);
         }
         
+        // Explicit degraded mode: failed inputs read as neutral and cap aggressiveness
+        let market_context = self.build_market_context(&blends);
+        let combined_sentiment = market_context.sentiment();
+        let confidence_avg = market_context.sentiment_confidence;
+        
+        // Every engine's candidates are collected before any executes, so a route several
+        // engines report is admitted for the highest-fidelity one
+        let optimized_routes = if sentiment_count > 0 {
+            self.multibot_ai.route_optimizer.get_sentiment_optimized_routes(combined_sentiment)
+        } else {
+            Vec::new()
+        };
+        let arbitrage_threshold = market_context.adjust_threshold(if combined_sentiment > 0.2 { 0.6 } else { 0.8 });
+        let admission = self.opportunity_dedup.admit_cycle(self.cycle_candidates(&findings, &optimized_routes, arbitrage_threshold));
+        
         if sentiment_count > 0 {
-            market_sentiment_avg /= sentiment_count as f64;
             twitter_sentiment_avg /= sentiment_count as f64;
             
-            // ✅ COMBINED SENTIMENT SCORE (Reddit + Twitter)
-            let combined_sentiment = (market_sentiment_avg + twitter_sentiment_avg) / 2.0;
-            
-            info!("  🎯 Combined sentiment: {:.3} (Reddit: {:.3}, Twitter: {:.3})", 
-                  combined_sentiment, market_sentiment_avg, twitter_sentiment_avg);
+            info!("  🎯 Combined sentiment: {:.3} (confidence: {:.2}, Twitter: {:.3})", 
+                  combined_sentiment, confidence_avg, twitter_sentiment_avg);
             
             // ✅ UPDATE ALL SENTIMENT METRICS
-            self.update_sentiment_metrics(combined_sentiment, 1.0, twitter_sentiment_avg);
+            self.update_sentiment_metrics(combined_sentiment, confidence_avg, twitter_sentiment_avg);
             
             // ✅ 3. ROUTE OPTIMIZATION BASED ON SENTIMENT
             info!("🎯 Selecting optimized routes based on market sentiment...");
-            let optimized_routes = self.multibot_ai.route_optimizer.get_sentiment_optimized_routes(combined_sentiment);
             self.system_metrics.optimized_routes_active = optimized_routes.len() as u32;
             
             info!("  ⚡ Selected {} optimized routes for current market conditions", optimized_routes.len());
             
             // Execute top 3 optimized routes
             for (i, route) in optimized_routes.iter().take(3).enumerate() {
-                let route_profit = self.execute_optimized_route(route, combined_sentiment).await;
-                cycle_profit += route_profit;
+                let signature = RouteSignature::from_optimized_route(route);
+                if !self.admit_opportunity(&admission, &signature, OpportunitySource::RouteOptimizer)
+                    || !self.cluster_admits(&signature, OpportunitySource::RouteOptimizer).await {
+                    continue;
+                }
+                let Some(_claim) = self.opportunity_dedup.try_begin_execution(&signature) else { continue };
+                // Nothing executes the route: its estimate is hypothetical
+                let route_profit = Self::estimate_optimized_route(route, combined_sentiment);
+                cycle.hypothetical("RouteOptimizer", route_profit);
                 
                 if route_profit > 0.0 {
-                    info!("  ✅ Optimized Route #{}: {} → +${:.2}", 
+                    info!("  📐 Optimized Route #{}: {} → est. +${:.2}", 
                           i + 1, route.route.join(" → "), route_profit);
                 }
             }
@@ -774,80 +2245,180 @@ impl EnterpriseMultiBotSystem {
             } else {
                 1.0  // Normal trading in neutral markets
             };
+            let sentiment_multiplier = market_context.cap_aggressiveness(sentiment_multiplier);
             
             info!("  ⚡ Trading aggressiveness multiplier: {:.1}x (sentiment-adjusted)", sentiment_multiplier);
         }
         
         // Strategy 1: Enhanced Arbitrage (Phase 1-2)
         if self.is_strategy_active(&TradingStrategy::EnhancedArbitrage) {
-            match self.arbitrage_engine.scan_for_opportunities().await {
-                Ok(opportunities) => {
-                    for opportunity in opportunities.iter().take(3) {
-                        let sentiment_adjusted_threshold = if market_sentiment_avg > 0.2 { 0.6 } else { 0.8 };
-                        if opportunity.profit_percentage >= sentiment_adjusted_threshold {
-                            let profit_usd = opportunity.volume_required * (opportunity.profit_percentage / 100.0);
-                            cycle_profit += profit_usd;
-                            info!("  ✅ Enhanced Arbitrage: {:?} → +${:.2} ({:.1}%)", 
-                                  opportunity.pair, profit_usd, opportunity.profit_percentage);
+            self.expire_arbitrage_ladders().await;
+            self.service_maker_orders(usd_per_unit).await;
+            for opportunity in findings.arbitrage.iter().take(3) {
+                let signature = RouteSignature::from_arbitrage(opportunity);
+                let Some(_claim) = self.opportunity_dedup.try_begin_execution(&signature) else { continue };
+                let edge_bps = opportunity.profit_percentage * 100.0;
+
+                // Later tranches of a running ladder only need the edge to persist in the fresh scan
+                let size = if let Some(ladder) = self.arbitrage_ladders.get_mut(&signature) {
+                    match ladder.next_tranche(edge_bps) {
+                        TrancheDecision::Submit { size, .. } => size,
+                        _ => {
+                            if let Some(ladder) = self.arbitrage_ladders.remove(&signature) {
+                                self.ladder_executor.record(ladder.report()).await;
+                            }
+                            continue;
                         }
                     }
-                },
-                Err(e) => warn!("⚠️ Enhanced arbitrage scan failed: {}", e),
+                } else {
+                    if opportunity.profit_percentage < arbitrage_threshold {
+                        self.consider_maker_order(opportunity).await;
+                        continue;
+                    }
+                    if !self.admit_opportunity(&admission, &signature, OpportunitySource::EnhancedArbitrage)
+                        || !self.cluster_admits(&signature, OpportunitySource::EnhancedArbitrage).await {
+                        continue;
+                    }
+                    if !self.ladder_executor.should_ladder(opportunity.volume_required) {
+                        opportunity.volume_required
+                    } else {
+                        let mut ladder = self.ladder_executor.start(opportunity.volume_required, edge_bps);
+                        let TrancheDecision::Submit { size, .. } = ladder.next_tranche(edge_bps) else { continue };
+                        info!("  🪜 Laddering {:.2} over {} tranches", opportunity.volume_required, ladder.report().tranches_planned);
+                        self.arbitrage_ladders.insert(signature.clone(), ladder);
+                        size
+                    }
+                };
+
+                if self.fee_budget.aggressiveness("EnhancedArbitrage") == FeeAggressiveness::Blocked {
+                    debug!("⛽ Enhanced Arbitrage skipped: daily fee cap reached");
+                    continue;
+                }
+                for token in [&opportunity.pair.base_token, &opportunity.pair.quote_token] {
+                    self.risk_tokens.entry(token.symbol.to_uppercase()).or_insert_with(|| token.mint.clone());
+                }
+                // Both legs go through the executor in every mode; simulation fills at the quoted output.
+                // Live fills are settled from chain by the trade indexer.
+                let live = self.trade_executor.get_trading_mode() != &TradingMode::Simulation;
+                let quote = &opportunity.pair.quote_token;
+                let (submitted, pnl_quote) = match self.execute_arbitrage_legs(&signature, opportunity, size).await {
+                    Ok((signatures, returned)) => {
+                        let scale = 10f64.powi(quote.decimals as i32);
+                        let amount_in = (size * scale) as u64;
+                        info!("  📡 Enhanced Arbitrage {:?} filled: {:?}", opportunity.pair, signatures);
+                        (signatures, (returned as f64 - amount_in as f64) / scale)
+                    }
+                    Err(e) => {
+                        warn!("  ⚠️ Enhanced Arbitrage {:?} not executed: {}", opportunity.pair, e);
+                        continue;
+                    }
+                };
+                let quote_usd = usd_per_unit(quote.mint.as_str());
+                let profit_usd = pnl_quote * quote_usd.unwrap_or(0.0);
+                if let Some(ladder) = self.arbitrage_ladders.get_mut(&signature) {
+                    ladder.record_fill(size);
+                    if ladder.is_finished() {
+                        let report = ladder.report().clone();
+                        self.arbitrage_ladders.remove(&signature);
+                        self.ladder_executor.record(&report).await;
+                    }
+                }
+                if live {
+                    // Opening legs carry no PnL of their own; they are tracked so the fees they paid are booked
+                    for leg in submitted.iter().rev().skip(1) {
+                        self.profit_ledger.record_submission(leg, "EnhancedArbitrage", 0.0);
+                    }
+                    // The round trip closes with the sell leg; it counts, at what the sell leg
+                    // actually returned, once the trade store has it
+                    match (submitted.last(), quote_usd) {
+                        (Some(sell_leg), Some(rate)) => {
+                            let cost = RoundTripCost { mint: quote.mint.clone(), amount: size, usd_per_unit: rate };
+                            self.profit_ledger.record_round_trip(sell_leg, "EnhancedArbitrage", profit_usd, cost);
+                        }
+                        (Some(sell_leg), None) => warn!("⚠️ No USD rate for {}: round trip {} left out of profit accounting",
+                                                        quote.symbol, sell_leg),
+                        (None, _) => {}
+                    }
+                    continue;
+                }
+                if quote_usd.is_none() {
+                    warn!("⚠️ No USD rate for {}: simulated round trip not booked", quote.symbol);
+                    continue;
+                }
+                // Simulation has no chain fills; the simulated fill is the outcome the kill switch sees
+                cycle.simulated("EnhancedArbitrage", profit_usd);
+                self.record_strategy_outcome(&TradingStrategy::EnhancedArbitrage, profit_usd);
+                info!("  ✅ Enhanced Arbitrage (simulated): {:?} → {:+.2} (scanned edge {:.1}%)", 
+                      opportunity.pair, profit_usd, opportunity.profit_percentage);
             }
         }
         
         // Strategy 2: Triangular Arbitrage (Phase 3)
         if self.is_strategy_active(&TradingStrategy::TriangularArbitrage) {
-            match self.triangular_engine.find_triangular_opportunities().await {
-                Ok(opportunities) => {
-                    for opportunity in opportunities.iter().take(2) {
-                        if opportunity.estimated_net_profit >= 15.0 {
-                            cycle_profit += opportunity.estimated_net_profit;
-                            info!("  ✅ Triangular: {} tokens → +${:.2}", 
-                                  opportunity.path.len(), opportunity.estimated_net_profit);
-                        }
+            for opportunity in findings.triangular.iter().take(2) {
+                if opportunity.estimated_net_profit >= 15.0 {
+                    if opportunity.path.iter().any(|hop| self.token_quarantine.is_quarantined(&hop.to_token)) {
+                        continue;
                     }
-                },
-                Err(e) => warn!("⚠️ Triangular arbitrage scan failed: {}", e),
+                    let signature = RouteSignature::from_triangular(opportunity);
+                    if !self.admit_opportunity(&admission, &signature, OpportunitySource::Triangular)
+                        || !self.cluster_admits(&signature, OpportunitySource::Triangular).await {
+                        continue;
+                    }
+                    let Some(_claim) = self.opportunity_dedup.try_begin_execution(&signature) else { continue };
+                    // Detected, not executed: the estimate is hypothetical
+                    cycle.hypothetical("TriangularArbitrage", opportunity.estimated_net_profit);
+                    info!("  📐 Triangular: {} tokens → est. +${:.2}", 
+                          opportunity.path.len(), opportunity.estimated_net_profit);
+                }
             }
         }
         
         // Strategy 3: Flash Loan Arbitrage (Phase 6)
         if self.is_strategy_active(&TradingStrategy::FlashLoanArbitrage) {
-            match self.flash_loan_engine.scan_flash_loan_opportunities().await {
-                Ok(opportunities) => {
-                    for opportunity in opportunities.iter().take(2) {
-                        if opportunity.estimated_profit_sol >= 0.15 {
-                            let profit_usd = opportunity.estimated_profit_sol * 160.0; // Updated SOL price
-                            cycle_profit += profit_usd;
-                            info!("  ✅ Flash Loan: {} SOL → +${:.2}", 
-                                  opportunity.loan_amount_sol, profit_usd);
+            for opportunity in findings.flash_loan.iter().take(2) {
+                if opportunity.estimated_profit_sol >= 0.15 {
+                    let unified = opportunity.to_opportunity();
+                    if !self.admit_unified(&admission, &unified).await {
+                        continue;
+                    }
+                    let Some(_claim) = self.opportunity_dedup.try_begin_execution(&RouteSignature::from_opportunity(&unified)) else { continue };
+                    if self.fee_budget.aggressiveness("FlashLoanArbitrage") == FeeAggressiveness::Blocked {
+                        debug!("⛽ Flash Loan skipped: daily fee cap reached");
+                        continue;
+                    }
+                    match self.fiat_rates.sol_to_usd(opportunity.estimated_profit_sol).await {
+                        Ok(conversion) => {
+                            cycle.hypothetical("FlashLoanArbitrage", conversion.usd);
+                            info!("  📐 Flash Loan: {} SOL → est. +${:.2} (SOL/USD {:.2} @ {})", 
+                                  opportunity.loan_amount_sol, conversion.usd,
+                                  conversion.rate.usd, conversion.rate.fetched_at.format("%H:%M:%S"));
                         }
+                        Err(e) => warn!("⚠️ Flash loan profit not counted, SOL/USD unavailable: {}", e),
                     }
-                },
-                Err(e) => warn!("⚠️ Flash loan arbitrage scan failed: {}", e),
+                }
             }
         }
         
         // Strategy 4: Cross-Chain Arbitrage (Phase 7)
         if self.is_strategy_active(&TradingStrategy::CrossChainArbitrage) {
-            match self.cross_chain_engine.scan_cross_chain_opportunities().await {
-                Ok(opportunities) => {
-                    for opportunity in opportunities.iter().take(2) {
-                        if opportunity.net_profit_usd >= 30.0 {
-                            cycle_profit += opportunity.net_profit_usd;
-                            info!("  ✅ Cross-Chain: {} → {} → +${:.2}", 
-                                  opportunity.source_chain, opportunity.target_chain, 
-                                  opportunity.net_profit_usd);
-                        }
+            for opportunity in findings.cross_chain.iter().take(2) {
+                if opportunity.net_profit_usd >= 30.0 {
+                    let unified = opportunity.to_opportunity();
+                    if !self.admit_unified(&admission, &unified).await {
+                        continue;
                     }
-                },
-                Err(e) => warn!("⚠️ Cross-chain arbitrage scan failed: {}", e),
+                    let Some(_claim) = self.opportunity_dedup.try_begin_execution(&RouteSignature::from_opportunity(&unified)) else { continue };
+                    cycle.hypothetical("CrossChainArbitrage", opportunity.net_profit_usd);
+                    info!("  📐 Cross-Chain: {} → {} → est. +${:.2}", 
+                          opportunity.source_chain, opportunity.target_chain, 
+                          opportunity.net_profit_usd);
+                }
             }
         }
         
         // Strategy 5-9: Advanced MultiBot Strategies (Phase 8-11)
-        cycle_profit += self.execute_advanced_multibot_strategies().await;
+        self.execute_advanced_multibot_strategies().await;
         
         // ✅ ENTERPRISE-GRADE MONITORING & INTELLIGENCE INTEGRATION
         
@@ -862,170 +2433,276 @@ impl EnterpriseMultiBotSystem {
         
         // 2. Intelligence System - REAL Market intelligence analysis
         info!("🧠 Intelligence System: Processing real market intelligence...");
-        match self.intelligence_system.analyze_market_patterns().await {
-            () => {
-                // Create enhanced market analysis result
-                let market_analysis = MarketAnalysisResult {
-                    trend: MarketTrend::Bullish, // Real trend analysis
-                    confidence: 0.85 + fastrand::f64() * 0.1, // Real confidence calculation
-                    opportunity_score: 15.0 + fastrand::f64() * 10.0, // Real opportunity scoring
-                };
-                
-                info!("  ✅ Market analysis complete - Trend: {:?}, Confidence: {:.2}%", 
-                      market_analysis.trend, market_analysis.confidence * 100.0);
-                cycle_profit += market_analysis.opportunity_score; // Intelligence-based profit
-                self.system_metrics.intelligence_analysis_count += 1;
-            }
-        }
+        self.intelligence_system.analyze_market_patterns().await;
+        self.system_metrics.intelligence_analysis_count += 1;
         
         // 3. Advanced AI Engine - Record activity  
         info!("🤖 Advanced AI Engine: Pattern recognition active");
         self.system_metrics.ai_optimized_trades += 1;
         
-        // 4. Autonomous Trader - REAL autonomous trading execution
+        // 4. Autonomous Trader - trades it makes reach the ledger through their fills
         info!("🤖 Autonomous Trader: Executing AI-driven trades...");
-        match self.autonomous_trader.execute_autonomous_trade().await {
-            () => {
-                // Create enhanced autonomous result based on real execution
-                let autonomous_result = AutonomousResult {
-                    trades_executed: 1 + fastrand::u32(..3), // Real trade count
-                    total_profit: 25.0 + fastrand::f64() * 15.0, // Real profit calculation
-                };
-                
-                if autonomous_result.trades_executed > 0 {
-                    info!("  ✅ Autonomous execution: {} trades, profit: ${:.2}", 
-                          autonomous_result.trades_executed, autonomous_result.total_profit);
-                    cycle_profit += autonomous_result.total_profit;
-                    self.system_metrics.autonomous_trades_executed += autonomous_result.trades_executed as u64;
-                }
-            }
-        }
+        self.autonomous_trader.execute_autonomous_trade().await;
         
-        // 5. Real Sentiment Analyzer - REAL multi-source sentiment analysis
+        // 5. Real Sentiment Analyzer - the cycle's sentiment comes from the blended providers above
         info!("📊 Real Sentiment Analyzer: Analyzing cross-platform sentiment...");
-        match self.sentiment_analyzer.analyze_market_sentiment().await {
-            () => {
-                // Create enhanced sentiment data from real analysis
-                let sentiment_data = ComprehensiveSentimentData {
-                    overall_sentiment: -0.1 + fastrand::f64() * 0.4, // Real sentiment range
-                    sources_analyzed: 3 + fastrand::usize(..3), // Real source count
-                };
-                
-                info!("  ✅ Comprehensive sentiment: {:.3} (sources: {})", 
-                      sentiment_data.overall_sentiment, sentiment_data.sources_analyzed);
-                
-                // Use sentiment data for profit optimization
-                let sentiment_multiplier = if sentiment_data.overall_sentiment > 0.3 {
-                    1.2 // Bullish sentiment boost
-                } else if sentiment_data.overall_sentiment < -0.3 {
-                    0.9 // Bearish sentiment reduction
-                } else {
-                    1.0 // Neutral
-                };
-                
-                cycle_profit *= sentiment_multiplier;
-                self.system_metrics.sentiment_analysis_count += 1;
-                self.system_metrics.current_market_sentiment = sentiment_data.overall_sentiment;
-            }
-        }
+        self.sentiment_analyzer.analyze_market_sentiment().await;
+        self.system_metrics.sentiment_analysis_count += 1;
         
         // Enterprise metrics update
         self.system_metrics.enterprise_features_active = 5;
         self.system_metrics.total_enterprise_cycles += 1;
         
-        info!("✅ Enterprise cycle complete - Total profit: ${:.2} (Enterprise features: ✅)", cycle_profit);
+        let cycle_profit = self.profit_ledger.close_cycle(&cycle, confirmed_profit);
+        self.apply_drawdown_ladder(cycle_profit).await;
+        self.benchmark.record(sol_usd, cycle_profit);
+        if let Some(report) = self.benchmark.report() {
+            self.analytics_engine.update_benchmark_report(report);
+        }
+        // Running A/B experiments appear in the summary report
+        self.analytics_engine.update_experiment_reports(shared_experiments().lock().reports());
+        info!("✅ Enterprise cycle complete - reported ${:.2} ({:?}: confirmed ${:.2}, simulated ${:.2}, hypothetical ${:.2})",
+              cycle_profit, self.profit_ledger.mode(), confirmed_profit,
+              cycle.total(ProfitKind::Simulated), cycle.total(ProfitKind::Hypothetical));
         
         Ok(cycle_profit)
     }
     
-    /// Execute advanced MultiBot strategies (Phases 8-11) - REAL IMPLEMENTATION
-    async fn execute_advanced_multibot_strategies(&mut self) -> f64 {
-        let mut advanced_profit = 0.0;
-        
-        // AI-Optimized Arbitrage (Phase 8) - Real AI analysis
-        if self.is_strategy_active(&TradingStrategy::AIOptimizedArbitrage) {
-            match self.ai_engine.predict_price("SOL", 160.0, 60).await {
-                Ok(Some(prediction)) if prediction.confidence_level > 0.85 => {
-                    let ai_profit = prediction.predicted_change_percentage.abs() * 100.0;
-                    if ai_profit > 25.0 {
-                        advanced_profit += ai_profit;
-                        info!("  ✅ AI-Optimized: ML prediction → +${:.2} (Conf: {:.1}%)", 
-                              ai_profit, prediction.confidence_level * 100.0);
-                    }
-                },
-                _ => { /* No AI opportunities found */ }
+    /// Feed the cycle's PnL to the de-risking ladder; flatten and halt when it says so
+    async fn apply_drawdown_ladder(&self, cycle_profit: f64) {
+        self.drawdown_ladder.record_pnl(cycle_profit);
+        if self.drawdown_ladder.take_flatten_request() {
+            let reason = format!("daily drawdown {:.2}%", self.drawdown_ladder.status().daily_pnl_percent);
+            // Stopping the bots closes their positions; the halt keeps the engine out
+            if let Err(e) = self.bot_controller.engage(&reason).await {
+                error!("❌ Drawdown flatten could not stop bots: {}", e);
+            }
+            if let Err(e) = self.trading_halt.engage(&reason).await {
+                error!("❌ Drawdown halt could not be engaged: {}", e);
             }
         }
-        
-        // Quantum Arbitrage (Phase 9) - Real quantum-inspired optimization
-        if self.is_strategy_active(&TradingStrategy::QuantumArbitrage) {
-            // Use real quantum-inspired algorithms for optimization
-            let quantum_optimized_routes = self.calculate_quantum_optimized_routes().await;
-            if quantum_optimized_routes > 0 {
-                let quantum_profit = quantum_optimized_routes as f64 * 15.0;
-                advanced_profit += quantum_profit;
-                info!("  ✅ Quantum: {} optimized routes → +${:.2}", 
-                      quantum_optimized_routes, quantum_profit);
+    }
+    
+    /// Surface supervised feeds/engines that exhausted their restart budget
+    async fn report_engine_failures(&self) {
+        for component in self.engine_supervisor.status().await {
+            if component.state == ComponentState::Failed {
+                let reason = component.last_error.map_or_else(|| "unknown error".to_string(), |e| e.to_string());
+                warn!("🌳 Component '{}' is down after {} restarts: {}", component.name, component.restarts, reason);
             }
         }
-        
-        // Autonomous Arbitrage (Phase 10) - Real autonomous decision making
-        if self.is_strategy_active(&TradingStrategy::AutonomousArbitrage) {
-            let autonomous_decisions = self.make_autonomous_trading_decisions().await;
-            if autonomous_decisions > 0 {
-                let autonomous_profit = autonomous_decisions as f64 * 20.0;
-                advanced_profit += autonomous_profit;
-                info!("  ✅ Autonomous: {} decisions → +${:.2}", 
-                      autonomous_decisions, autonomous_profit);
-                
-                // ✅ ACTIVATE ADVANCED AI ENGINE - REAL PROCESSING
-                match self.advanced_ai_engine.process_autonomous_decision().await {
-                    Ok(()) => {
-                        // Create enhanced AI analysis result
-                        let ai_analysis = AiAnalysisResult {
-                            confidence_score: 0.8 + fastrand::f64() * 0.15, // Real confidence calculation
-                            optimization_gain: 5.0 + fastrand::f64() * 8.0, // Real optimization gain
-                        };
-                        
-                        info!("  🧠 Advanced AI processed decision - Confidence: {:.2}%", 
-                              ai_analysis.confidence_score * 100.0);
-                        advanced_profit += ai_analysis.optimization_gain;
-                    },
-                    Err(e) => warn!("  ⚠️ AI engine processing error: {}", e),
+        for circuit in sniperforge::apis::provider_circuits().snapshot() {
+            if circuit.state != sniperforge::apis::CircuitState::Closed {
+                warn!("🔌 Provider '{}' degraded ({:?}): {} consecutive failures, {} requests skipped - last error: {}",
+                      circuit.provider, circuit.state, circuit.consecutive_failures, circuit.rejected_requests,
+                      circuit.last_error.as_deref().unwrap_or("n/a"));
+            }
+        }
+    }
+    
+    /// Market context for this cycle, with quality flags from sentiment
+    /// coverage, open provider circuits and degraded streaming feeds
+    fn build_market_context(&mut self, blends: &[SentimentBlend]) -> MarketContext {
+        let open_circuits = sniperforge::apis::provider_circuits().degraded_providers();
+        let intelligence = if open_circuits.is_empty() { SignalQuality::Full } else { SignalQuality::Partial };
+        let degraded_feeds = sniperforge::apis::feed_health().degraded();
+        let intelligence = if degraded_feeds.is_empty() { intelligence } else { SignalQuality::Partial };
+        let context = open_circuits
+            .into_iter()
+            .chain(degraded_feeds.into_iter().map(|(feed, _)| feed))
+            .fold(MarketContext::from_sentiment(blends).with_intelligence(intelligence), |context, provider| {
+                context.with_degraded_source(provider)
+            });
+        
+        self.system_metrics.market_context_quality = Some(context.quality);
+        if context.is_degraded() {
+            self.system_metrics.degraded_cycles += 1;
+            warn!("🩹 Degraded market context ({:?}/{:?}) - neutral defaults, reduced aggressiveness; failed: {:?}",
+                  context.quality.sentiment, context.quality.intelligence, context.degraded_sources);
+        }
+        context
+    }
+    
+    /// Log the previous day's RPC usage once per UTC day and flag plan overruns
+    fn report_rpc_usage(&mut self) {
+        let now = Utc::now();
+        if now.date_naive() == self.rpc_usage_reported {
+            return;
+        }
+        let usage = sniperforge::apis::rpc_usage();
+        for report in usage.daily_report(self.rpc_usage_reported) {
+            info!("📡 RPC usage {} on {}: {} requests, {} credits", report.provider, report.date,
+                  report.usage.requests, report.usage.credits);
+        }
+        usage.check_limits(now);
+        self.rpc_usage_reported = now.date_naive();
+    }
+    
+    /// Execute advanced MultiBot strategies (Phases 8-11) - REAL IMPLEMENTATION
+    /// Strategies 5-9 only analyse the market: none of them executes, so nothing is booked
+    async fn execute_advanced_multibot_strategies(&mut self) {
+        // AI-Optimized Arbitrage (Phase 8) - price prediction only
+        if self.is_strategy_active(&TradingStrategy::AIOptimizedArbitrage) {
+            let prediction = match self.fiat_rates.get_rate(sniperforge::apis::FiatAsset::Sol).await {
+                Ok(rate) => self.ai_engine.predict_price("SOL", rate.usd, 60).await,
+                Err(e) => {
+                    warn!("⚠️ SOL/USD unavailable for AI prediction: {}", e);
+                    Ok(None)
                 }
+            };
+            match prediction {
+                Ok(Some(prediction)) if prediction.confidence_level > 0.85 => {
+                    info!("  🧠 AI-Optimized: SOL {:+.2}% predicted (Conf: {:.1}%) - no execution path, nothing booked",
+                          prediction.predicted_change_percentage, prediction.confidence_level * 100.0);
+                }
+                _ => { /* No confident prediction */ }
             }
         }
         
-        // Ecosystem Arbitrage (Phase 11) - Real ecosystem integration
-        if self.is_strategy_active(&TradingStrategy::EcosystemArbitrage) {
-            let ecosystem_connections = self.scan_ecosystem_opportunities().await;
-            if ecosystem_connections > 0 {
-                let ecosystem_profit = ecosystem_connections as f64 * 25.0;
-                advanced_profit += ecosystem_profit;
-                info!("  ✅ Ecosystem: {} connections → +${:.2}", 
-                      ecosystem_connections, ecosystem_profit);
+        // Autonomous Arbitrage (Phase 10) - decision engine activity only
+        if self.is_strategy_active(&TradingStrategy::AutonomousArbitrage) {
+            if let Err(e) = self.advanced_ai_engine.process_autonomous_decision().await {
+                warn!("  ⚠️ AI engine processing error: {}", e);
             }
         }
         
-        // ✅ FASE 7: Unified Routing System - Arquitectura dual estratégica + tiempo real
+        // ✅ FASE 7: Unified Routing System - route selection only
         if self.is_strategy_active(&TradingStrategy::UnifiedMultiStrategy) {
-            let unified_profit = self.execute_unified_routing_strategy(0.5).await; // Default sentiment
-            advanced_profit += unified_profit;
-            info!("  ✅ FASE 7 Unified: Dual routing → +${:.2}", unified_profit);
+            self.log_unified_route(0.5); // Default sentiment
         }
-        
-        advanced_profit
     }
     
     
     /// Check if a trading strategy is active
     fn is_strategy_active(&self, strategy: &TradingStrategy) -> bool {
-        self.active_strategies.contains(strategy)
+        !self.trading_halt.is_engaged()
+            && self.active_strategies.contains(strategy)
+            && !self.strategy_guard.is_suspended(&format!("{:?}", strategy))
+    }
+    
+    /// Drop ladders whose opportunity has not come back in time
+    async fn expire_arbitrage_ladders(&mut self) {
+        let expired: Vec<RouteSignature> = self.arbitrage_ladders
+            .iter()
+            .filter(|(_, ladder)| ladder.is_expired())
+            .map(|(signature, _)| signature.clone())
+            .collect();
+        for signature in expired {
+            if let Some(mut ladder) = self.arbitrage_ladders.remove(&signature) {
+                ladder.next_tranche(0.0);
+                self.ladder_executor.record(ladder.report()).await;
+            }
+        }
+    }
+    
+    /// Trade both legs of an arbitrage through the executor: quote → base, then base → quote
+    ///
+    /// Returns the submitted signatures and the quote-token amount the sell leg
+    /// returned. Each leg is recorded in the intent log before signing. A failed
+    /// second leg leaves the base token in the hot wallet; the error says so.
+    async fn execute_arbitrage_legs(&self, signature: &RouteSignature, opportunity: &ArbitrageOpportunity, size: f64) -> Result<(Vec<String>, u64)> {
+        // The scanned spread cannot be re-quoted in place; a stale one waits for the next sighting
+        self.trade_executor.quote_guard().check_age(opportunity.timestamp).await
+            .map_err(|e| anyhow::anyhow!("spread no longer actionable: {}", e))?;
+        let (base, quote) = (&opportunity.pair.base_token, &opportunity.pair.quote_token);
+        let base_mint: solana_sdk::pubkey::Pubkey = base.mint.parse()?;
+        let quote_mint: solana_sdk::pubkey::Pubkey = quote.mint.parse()?;
+        let mode = self.trade_executor.get_trading_mode().clone();
+        let mut amount = (size * 10f64.powi(quote.decimals as i32)) as u64;
+        let mut signatures = Vec::with_capacity(2);
+        for (leg, (input, output)) in [(quote_mint, base_mint), (base_mint, quote_mint)].into_iter().enumerate() {
+            // One execution per sighting and leg, even across a crash and restart
+            let key = format!("arb:{}:{}:{}", signature.as_str(), opportunity.timestamp.timestamp_millis(), leg);
+            let request = TradeRequest::new(HOT_WALLET.to_string(), input, output, amount, mode.clone())
+                .with_idempotency_key(key);
+            let result = self.trade_executor.execute_trade(request).await?;
+            if !result.success {
+                let error = result.error_message.unwrap_or_else(|| "unknown error".to_string());
+                if leg == 0 {
+                    anyhow::bail!("buy leg failed: {}", error);
+                }
+                anyhow::bail!("sell leg failed, {} {} left in {}: {}", amount, base.symbol, HOT_WALLET, error);
+            }
+            signatures.extend(result.transaction_signature);
+            amount = result.output_amount;
+        }
+        Ok((signatures, amount))
+    }
+    
+    /// Rest a post-only Phoenix bid for an arbitrage spread just short of taker profitability
+    ///
+    /// The CLOB stands in for the buy leg: a fill is hedged by selling on the
+    /// AMM, at the scanned sell price when the bid was planned.
+    async fn consider_maker_order(&self, opportunity: &ArbitrageOpportunity) {
+        let (Some(maker), Some(phoenix)) = (&self.maker_mode, &self.phoenix) else { return };
+        let pair = &opportunity.pair;
+        let Some(params) = phoenix.market_for(&pair.base_token.mint, &pair.quote_token.mint) else { return };
+        let market = params.name.clone();
+        // Bids already resting are re-checked against the fresh hedge price first
+        maker.on_hedge_price(&market, opportunity.sell_price).await;
+        let best_ask = match phoenix.order_book(&market, 1).await {
+            Ok(book) => match book.asks.first() {
+                Some(level) => level.price,
+                None => return,
+            },
+            Err(e) => {
+                debug!("📗 Phoenix book {} unavailable: {}", market, e);
+                return;
+            }
+        };
+        maker.consider(&ClobSpread::for_arbitrage(opportunity, ClobVenue::Phoenix, &market, best_ask, params.tick_size())).await;
+    }
+    
+    /// Expire and de-risk resting maker orders, then hedge what filled
+    async fn service_maker_orders(&mut self, usd_per_unit: impl Fn(&str) -> Option<f64>) {
+        let (Some(maker), Some(phoenix)) = (self.maker_mode.clone(), self.phoenix.clone()) else { return };
+        maker.expire().await;
+        maker.cancel_toxic().await;
+        for hedge in maker.sync_fills().await {
+            let Some(params) = phoenix.market(&hedge.market).cloned() else { continue };
+            // Bid fills are sold on the AMM, ask fills bought back
+            let (input, output, amount) = match hedge.fill_side {
+                ClobSide::Bid => (params.base_mint, params.quote_mint, hedge.size * 10f64.powi(params.base_decimals as i32)),
+                ClobSide::Ask => (params.quote_mint, params.base_mint, hedge.size * hedge.fill_price * 10f64.powi(params.quote_decimals as i32)),
+            };
+            let key = format!("maker-hedge:{}:{:?}:{}", hedge.market, hedge.fill_side, Utc::now().timestamp_millis());
+            let request = TradeRequest::new(HOT_WALLET.to_string(), input, output, amount as u64, self.trade_executor.get_trading_mode().clone())
+                .with_idempotency_key(key);
+            let result = match self.trade_executor.execute_trade(request).await {
+                Ok(result) if result.success => result,
+                Ok(result) => {
+                    error!("❌ Maker hedge on {} failed, {} base left unhedged: {}", hedge.market, hedge.size,
+                           result.error_message.unwrap_or_else(|| "unknown error".to_string()));
+                    continue;
+                }
+                Err(e) => {
+                    error!("❌ Maker hedge on {} failed, {} base left unhedged: {}", hedge.market, hedge.size, e);
+                    continue;
+                }
+            };
+            info!("🎯 Maker {:?} fill of {} on {} hedged: {:?}", hedge.fill_side, hedge.size, hedge.market, result.transaction_signature);
+            // A bid round trip closes in the quote token, like the taker round trip
+            let quote_mint = params.quote_mint.to_string();
+            if let (ClobSide::Bid, Some(signature), Some(rate)) = (hedge.fill_side, result.transaction_signature.as_deref(), usd_per_unit(&quote_mint)) {
+                let cost = hedge.size * hedge.fill_price;
+                let returned = result.output_amount as f64 / 10f64.powi(params.quote_decimals as i32);
+                self.profit_ledger.record_round_trip(signature, "MakerMode", (returned - cost) * rate,
+                                                     RoundTripCost { mint: quote_mint, amount: cost, usd_per_unit: rate });
+            }
+        }
+    }
+    
+    /// Feed a simulated fill to the strategy kill criteria (live fills are fed on confirmation)
+    fn record_strategy_outcome(&self, strategy: &TradingStrategy, pnl_usd: f64) {
+        self.strategy_guard.record_outcome(&format!("{:?}", strategy), pnl_usd);
     }
     
     /// Update system metrics after each cycle
     fn update_system_metrics(&mut self, cycle_profit: f64) {
         self.system_metrics.total_profit_usd += cycle_profit;
+        if cycle_profit != 0.0 {
+            self.seasonality.lock().record_trade(Utc::now(), cycle_profit);
+        }
         self.system_metrics.total_trades_executed += 1;
         
         if cycle_profit > 0.0 {
@@ -1047,33 +2724,144 @@ impl EnterpriseMultiBotSystem {
     }
     
     
-    /// ✅ FASE 7: Execute unified routing strategy combining strategic + real-time data
-    async fn execute_unified_routing_strategy(&mut self, market_sentiment: f64) -> f64 {
-        info!("🎯 FASE 7: Ejecutando estrategia de routing unificado...");
-        
-        // Usar el route optimizer existente como motor unificado
+    /// ✅ FASE 7: Log the unified route the strategic + real-time data would pick
+    fn log_unified_route(&self, market_sentiment: f64) {
         let market_condition = if market_sentiment > 0.3 { "bullish" } else { "normal" };
         let optimized_routes = self.multibot_ai.route_optimizer.get_optimized_routes(market_condition);
         
         if let Some(optimized_route) = optimized_routes.first() {
-            let profit_percentage = (optimized_route.avg_profit_bps as f64) / 100.0; // Convert BPS to percentage
-            info!("  🎯 Ruta óptima calculada: profit={:.4}%", profit_percentage);
-            info!("  💰 Ganancia estimada: ${:.2}", profit_percentage * 10.0); // Scaled for demo
-            info!("  🛡️ Probabilidad éxito: {:.1}%", optimized_route.success_rate * 100.0);
-            info!("  ⏱️ Tiempo estimado: {:.1}ms", optimized_route.execution_time_ms);
-            
-            // Simular ejecución exitosa
-            let actual_profit = profit_percentage * 0.8 * 10.0; // 80% del estimado
-            info!("  ✅ Ejecución exitosa: +${:.2}", actual_profit);
-            actual_profit
+            info!("  🎯 Unified route: {} ({} bps historical, {:.1}% success, {:.1}ms) - not executed",
+                  optimized_route.route.join(" → "), optimized_route.avg_profit_bps,
+                  optimized_route.success_rate * 100.0, optimized_route.execution_time_ms);
         } else {
-            warn!("  ⚠️ No hay rutas disponibles para condición: {}", market_condition);
-            0.0
+            warn!("  ⚠️ No routes available for condition: {}", market_condition);
+        }
+    }
+    
+    /// Cross-instance gate: only the partition leader trades a route, once cluster-wide
+    ///
+    /// Always admits when running as a single instance (no coordinator configured).
+    async fn cluster_admits(&self, signature: &RouteSignature, source: OpportunitySource) -> bool {
+        let Some(cluster) = &self.cluster else {
+            return true;
+        };
+        if !cluster.is_leader(&format!("{:?}", source), signature.as_str()) {
+            debug!("  🗳️ Route {} belongs to another instance's partition, skipping", signature.as_str());
+            return false;
+        }
+        match cluster.claim_opportunity(signature.as_str()).await {
+            Ok(true) => true,
+            Ok(false) => {
+                info!("  🗳️ Route {} already claimed by another instance, skipping", signature.as_str());
+                false
+            }
+            Err(e) => {
+                warn!("  ⚠️ Cluster claim failed for {}, skipping: {}", signature.as_str(), e);
+                false
+            }
+        }
+    }
+    
+    /// Every engine's dedup candidates for this cycle, gated like each engine's loop below
+    fn cycle_candidates(&self, findings: &EngineFindings, optimized_routes: &[OptimizedRoute], arbitrage_threshold: f64) -> Vec<DedupCandidate> {
+        let mut candidates: Vec<DedupCandidate> = optimized_routes
+            .iter()
+            .take(3)
+            .map(DedupCandidate::from_optimized_route)
+            .collect();
+        if self.is_strategy_active(&TradingStrategy::EnhancedArbitrage) {
+            candidates.extend(findings.arbitrage.iter().take(3)
+                .filter(|opportunity| opportunity.profit_percentage >= arbitrage_threshold)
+                .map(DedupCandidate::from_arbitrage)
+                // Running ladders continue without re-admission
+                .filter(|candidate| !self.arbitrage_ladders.contains_key(&candidate.signature)));
+        }
+        if self.is_strategy_active(&TradingStrategy::TriangularArbitrage) {
+            candidates.extend(findings.triangular.iter().take(2)
+                .filter(|opportunity| opportunity.estimated_net_profit >= 15.0
+                    && !opportunity.path.iter().any(|hop| self.token_quarantine.is_quarantined(&hop.to_token)))
+                .map(DedupCandidate::from_triangular));
+        }
+        let mut unified = Vec::new();
+        if self.is_strategy_active(&TradingStrategy::FlashLoanArbitrage) {
+            unified.extend(findings.flash_loan.iter().take(2)
+                .filter(|opportunity| opportunity.estimated_profit_sol >= 0.15)
+                .map(|opportunity| opportunity.to_opportunity()));
+        }
+        if self.is_strategy_active(&TradingStrategy::CrossChainArbitrage) {
+            unified.extend(findings.cross_chain.iter().take(2)
+                .filter(|opportunity| opportunity.net_profit_usd >= 30.0)
+                .map(|opportunity| opportunity.to_opportunity()));
+        }
+        candidates.extend(unified.iter().map(DedupCandidate::from_opportunity));
+        candidates
+    }
+    
+    /// Whether this cycle's cross-engine admission picked `source` for the route
+    ///
+    /// Only the highest-fidelity source reporting a route in the cycle (and not
+    /// already taken in the dedup window) proceeds; the others are merged into it.
+    fn admit_opportunity(&self, admission: &CycleAdmission, signature: &RouteSignature, source: OpportunitySource) -> bool {
+        match admission.outcome(signature, source) {
+            None => {
+                debug!("  🔁 Route {} was not collected for {:?} this cycle, skipping", signature.as_str(), source);
+                false
+            }
+            Some(DedupOutcome::Unique) => true,
+            Some(DedupOutcome::Replaced { previous_source }) => {
+                info!("  🔁 Route {} re-attributed {:?} → {:?}", signature.as_str(), previous_source, source);
+                true
+            }
+            Some(DedupOutcome::Duplicate { attributed_to }) => {
+                info!("  🔁 Duplicate route {} already taken by {:?}, skipping", signature.as_str(), attributed_to);
+                false
+            }
+            Some(DedupOutcome::InFlight) => {
+                warn!("  ⏳ Route {} already executing, skipping", signature.as_str());
+                false
+            }
         }
     }
     
+    /// Admission for any engine reporting through the unified opportunity model
+    async fn admit_unified(&self, admission: &CycleAdmission, opportunity: &Opportunity) -> bool {
+        if opportunity.is_expired(Utc::now()) {
+            debug!("  ⌛ {:?} opportunity {} expired before admission", opportunity.kind, opportunity.id);
+            return false;
+        }
+        if let Some(mint) = std::iter::once(&opportunity.mint)
+            .chain(opportunity.route.iter().map(|hop| &hop.token))
+            .find(|mint| self.token_quarantine.is_quarantined(mint))
+        {
+            debug!("  ☣️ {:?} opportunity {} touches quarantined token {}", opportunity.kind, opportunity.id, mint);
+            return false;
+        }
+        if !self.intent_log.is_clear() {
+            debug!("  📝 {:?} opportunity {} held: unresolved trade intents", opportunity.kind, opportunity.id);
+            return false;
+        }
+        if !self.risk_manager.entries_allowed() {
+            debug!("  📉 {:?} opportunity {} held: drawdown ladder blocks new entries", opportunity.kind, opportunity.id);
+            return false;
+        }
+        let blocking_feeds = sniperforge::apis::feed_health().blocking_trading();
+        if !blocking_feeds.is_empty() {
+            debug!("  🕳️ {:?} opportunity {} held: market data feeds degraded {:?}", opportunity.kind, opportunity.id, blocking_feeds);
+            return false;
+        }
+        let netting = self.risk_manager.evaluate_netted(&sniperforge::trading::PendingTrade::from_opportunity(opportunity));
+        if !netting.is_acceptable() {
+            debug!("  ⚖️ {:?} opportunity {} held: netted exposure over limit {:?}", opportunity.kind, opportunity.id, netting.breaches);
+            return false;
+        }
+        let signature = RouteSignature::from_opportunity(opportunity);
+        let source = OpportunitySource::from(opportunity.kind);
+        self.admit_opportunity(admission, &signature, source)
+            && self.cluster_admits(&signature, source).await
+    }
+    
     /// Execute optimized route with real profit calculation
-    async fn execute_optimized_route(&mut self, route: &OptimizedRoute, market_sentiment: f64) -> f64 {
+    fn estimate_optimized_route(route: &OptimizedRoute, market_sentiment: f64) -> f64 {
         let base_profit = (route.avg_profit_bps as f64 / 10000.0) * route.min_volume_required as f64;
         
         // Apply sentiment adjustment
@@ -1085,22 +2873,8 @@ impl EnterpriseMultiBotSystem {
             1.0
         };
         
-        // Apply success rate probability
-        let success_factor = if fastrand::f64() < route.success_rate {
-            1.0  // Successful execution
-        } else {
-            0.0  // Failed execution
-        };
-        
-        let final_profit = base_profit * sentiment_adjustment * success_factor;
-        
-        // Update route performance
-        if final_profit > 0.0 {
-            let route_signature = route.route.join("->");
-            self.multibot_ai.route_optimizer.update_route_performance(&route_signature, final_profit, true);
-        }
-        
-        final_profit
+        // Expected value over the route's historical success rate
+        base_profit * sentiment_adjustment * route.success_rate
     }
     
     /// Update sentiment metrics (enhanced with Twitter sentiment)
@@ -1111,86 +2885,6 @@ impl EnterpriseMultiBotSystem {
     }
     
     
-    /// Calculate quantum-optimized trading routes using real algorithms
-    async fn calculate_quantum_optimized_routes(&self) -> u32 {
-        // Real quantum-inspired optimization using variational algorithms
-        let mut optimized_routes = 0;
-        
-        // Analyze current market conditions
-        if self.multibot_ai.quantum_acceleration {
-            // Use quantum-inspired optimization for route finding
-            // This uses real mathematical principles from quantum computing
-            let market_volatility = self.calculate_market_volatility().await;
-            let route_complexity = self.active_strategies.len() as f64;
-            
-            // Quantum superposition-inspired multi-path analysis
-            let quantum_states = (market_volatility * route_complexity * 10.0) as u32;
-            optimized_routes = quantum_states.min(5); // Cap at 5 routes
-        }
-        
-        optimized_routes
-    }
-    
-    /// Make autonomous trading decisions using real ML models
-    async fn make_autonomous_trading_decisions(&mut self) -> u32 {
-        let mut decisions = 0;
-        
-        if self.multibot_ai.autonomous_decision_making {
-            // Real autonomous decision making based on performance metrics
-            let success_rate = self.system_metrics.success_rate_percentage;
-            let ai_accuracy = self.system_metrics.ai_accuracy_rate;
-            
-            // Decision matrix based on real performance data
-            if success_rate > 80.0 && ai_accuracy > 75.0 {
-                decisions += 2; // High confidence decisions
-            } else if success_rate > 60.0 {
-                decisions += 1; // Moderate confidence decision
-            }
-            
-            // Update AI learning parameters
-            if self.multibot_ai.ensemble_accuracy < 0.90 {
-                self.multibot_ai.ensemble_accuracy += 0.001; // Real learning progression
-            }
-        }
-        
-        decisions
-    }
-    
-    /// Scan ecosystem opportunities using real network analysis
-    async fn scan_ecosystem_opportunities(&self) -> u32 {
-        let mut connections = 0;
-        
-        if self.multibot_ai.ecosystem_integration {
-            // Real ecosystem analysis based on active engines
-            let active_engines = [
-                self.is_strategy_active(&TradingStrategy::EnhancedArbitrage),
-                self.is_strategy_active(&TradingStrategy::TriangularArbitrage),
-                self.is_strategy_active(&TradingStrategy::FlashLoanArbitrage),
-                self.is_strategy_active(&TradingStrategy::CrossChainArbitrage),
-            ];
-            
-            // Calculate real network connections
-            connections = active_engines.iter().filter(|&&x| x).count() as u32;
-            
-            // Bonus for multi-chain integration
-            if connections >= 3 {
-                connections += 1; // Network effect bonus
-            }
-        }
-        
-        connections
-    }
-    
-    /// Calculate real market volatility
-    async fn calculate_market_volatility(&self) -> f64 {
-        // Real volatility calculation based on system metrics
-        let base_volatility = 0.1; // 10% base volatility
-        let cycle_factor = (self.cycle_count as f64 / 100.0).min(0.5);
-        let profit_factor = (self.total_profit / 10000.0).min(0.3);
-        
-        base_volatility + cycle_factor + profit_factor
-    }
-    
     /// Display MultiBot system overview
     fn display_multibot_system_overview(&self) {
         println!("\n╔══════════════════════════════════════════════════════════════════════════════╗");
@@ -1243,6 +2937,9 @@ impl EnterpriseMultiBotSystem {
                  uptime_hours, uptime_minutes, self.total_profit);
         println!("║ 📈 Success Rate: {:.1}%   │ 🎯 Avg Return/Cycle: ${:.2} │ Cycles: {}     ║",
                  self.system_metrics.success_rate_percentage, avg_profit_per_cycle, self.cycle_count);
+        let totals = self.profit_ledger.totals();
+        println!("║ 📒 {:?} accounting │ Confirmed: ${:.2} │ Simulated: ${:.2} │ Hypothetical: ${:.2} ║",
+                 self.profit_ledger.mode(), totals.confirmed_usd, totals.simulated_usd, totals.hypothetical_usd);
         println!("╠══════════════════════════════════════════════════════════════════════════════╣");
         println!("║ 📊 Market Sentiment: {}           │ 🛡️ Risk Level: {}                ║",
                  market_status, risk_level);
@@ -1251,7 +2948,28 @@ impl EnterpriseMultiBotSystem {
         println!("║ 🎯 Active Strategies: {}               │ 📡 Data Feeds: {} sources        ║",
                  self.active_strategies.len(), self.system_metrics.optimized_routes_active);
         println!("║ � Asset Monitoring: {}               │ ⚡ Execution Speed: OPTIMAL       ║",
-                 if self.stablecoin_monitor.has_depegged_stablecoins() { "🚨 ALERT" } else { "✅ STABLE" });
+                 if self.engine_findings.try_lock().map_or(false, |f| f.stablecoins_depegged) { "🚨 ALERT" } else { "✅ STABLE" });
+        if let Some(quality) = self.system_metrics.market_context_quality.filter(|q| q.is_degraded()) {
+            println!("║ 🩹 Degraded inputs: sentiment {:?}, intelligence {:?} │ degraded cycles: {:<6} ║",
+                     quality.sentiment, quality.intelligence, self.system_metrics.degraded_cycles);
+        }
+        let quarantined = self.token_quarantine.quarantined();
+        if !quarantined.is_empty() {
+            println!("║ ☣️ Quarantined tokens: {:<55} ║", quarantined.len());
+        }
+        for (feed, status) in sniperforge::apis::feed_health().degraded() {
+            println!("║ 🕳️ Feed {:<30} {:<40} ║", feed, format!("{:?}", status));
+        }
+        let throttle = execution_throttle().stats();
+        if throttle.throttled > 0 {
+            println!("║ 🚦 Throttled sends: {:<6} of {:<6} │ avg delay {:>6.0}ms │ max {:>6}ms        ║",
+                     throttle.throttled, throttle.acquired, throttle.avg_delay_ms(), throttle.max_delay_ms);
+        }
+        for usage in self.fee_budget.snapshot() {
+            let ratio = usage.fee_to_profit_ratio().map_or("n/a".to_string(), |r| format!("{:.1}%", r * 100.0));
+            println!("║ ⛽ {:<20} fees {:.4}/{:.4} SOL ({:.0}%) │ fee/profit: {:<8}        ║",
+                     usage.bot, usage.spent_sol(), usage.cap_sol, usage.utilization() * 100.0, ratio);
+        }
         println!("╠══════════════════════════════════════════════════════════════════════════════╣");
         println!("║ 🎯 Arbitrage Trading  │ 🔄 Cross-Exchange   │ 🤖 AI Optimization      ║");
         println!("║ ⚡ Flash Loan Capital │ � Multi-Chain Ops  │ � Real-Time Analytics  ║");
@@ -1338,74 +3056,4 @@ impl EnterpriseMultiBotSystem {
             Err(e) => warn!("Analytics generation failed: {}", e),
         }
     }
-    
-    
-    /// Display enterprise final summary
-    fn display_enterprise_final_summary(&self) {
-        let avg_profit_per_cycle = if self.cycle_count > 0 { 
-            self.total_profit / self.cycle_count as f64 
-        } else { 
-            0.0 
-        };
-        
-        let runtime_minutes = (Utc::now() - self.system_start_time).num_minutes();
-        
-        println!("\n╔══════════════════════════════════════════════════════════════════════════════╗");
-        println!("║                    SNIPERFORGE ENTERPRISE MULTIBOT FINAL REPORT                 ║");
-        println!("╠══════════════════════════════════════════════════════════════════════════════╣");
-        println!("║ 🏆 ENTERPRISE MULTIBOT DEMONSTRATION COMPLETED SUCCESSFULLY                    ║");
-        println!("║                                                                                  ║");
-        println!("║   📊 SYSTEM PERFORMANCE METRICS:                                               ║");
-        println!("║   • Total Cycles Executed: {}                                                  ║", self.cycle_count);
-        println!("║   • Total Profit Generated: ${:.2}                                             ║", self.total_profit);
-        println!("║   • Average Profit per Cycle: ${avg_profit_per_cycle:.2}                                          ║");
-        println!("║   • System Runtime: {runtime_minutes} minutes                                                 ║");
-        println!("║   • Success Rate: {:.1}%                                                       ║", self.system_metrics.success_rate_percentage);
-        println!("║   • AI Accuracy Rate: {:.1}%                                                   ║", self.system_metrics.ai_accuracy_rate);
-        println!("║                                                                                  ║");
-        println!("║   🎯 UNIFIED MULTIBOT STRATEGIES VERIFIED (ALL 10 ACTIVE):                    ║");
-        println!("║   • ✅ Enhanced Arbitrage Engine (Phase 1-2)                                  ║");
-        println!("║   • ✅ Triangular Arbitrage Engine (Phase 3)                                  ║");
-        println!("║   • ✅ Flash Loan Arbitrage Engine (Phase 6)                                  ║");
-        println!("║   • ✅ Cross-Chain Arbitrage Engine (Phase 7)                                 ║");
-        println!("║   • ✅ AI-Optimized Arbitrage (Phase 8)                                       ║");
-        println!("║   • ✅ Quantum Arbitrage System (Phase 9)                                     ║");
-        println!("║   • ✅ Autonomous Trading System (Phase 10)                                   ║");
-        println!("║   • ✅ Ecosystem Integration Network (Phase 11)                               ║");
-        println!("║   • ✅ Unified Multi-Strategy Coordinator                                     ║");
-        println!("║   • ✅ FASE 7: Dual Routing System (Strategic + Real-time)                   ║");
-        println!("║                                                                                  ║");
-        println!("║   🤖 ENTERPRISE AI CAPABILITIES:                                               ║");
-        println!("║   • LSTM Prediction Accuracy: {:.1}%                                           ║", self.multibot_ai.lstm_prediction_accuracy * 100.0);
-        println!("║   • Neural Network Accuracy: {:.1}%                                            ║", self.multibot_ai.neural_network_accuracy * 100.0);
-        println!("║   • Ensemble Model Accuracy: {:.1}%                                            ║", self.multibot_ai.ensemble_accuracy * 100.0);
-        println!("║   • Quantum Acceleration: {}                                                   ║", if self.multibot_ai.quantum_acceleration { "ENABLED" } else { "DISABLED" });
-        println!("║   • Autonomous Decision Making: {}                                             ║", if self.multibot_ai.autonomous_decision_making { "ENABLED" } else { "DISABLED" });
-        println!("║                                                                                  ║");
-        println!("║   🌟 ENTERPRISE READINESS ASSESSMENT:                                          ║");
-        println!("║   • Enterprise Architecture: ✅ PRODUCTION READY                              ║");
-        println!("║   • Scalability: ✅ INSTITUTIONAL GRADE                                       ║");
-        println!("║   • AI Integration: ✅ CUTTING EDGE                                           ║");
-        println!("║   • Risk Management: ✅ ENTERPRISE LEVEL                                      ║");
-        println!("║   • Performance Analytics: ✅ COMPREHENSIVE                                   ║");
-        println!("║   • Multi-Strategy Coordination: ✅ UNIFIED                                   ║");
-        println!("║                                                                                  ║");
-        println!("║ 🎯 SYSTEM STATUS: ENTERPRISE DEPLOYMENT READY                                 ║");
-        println!("║ 💎 MARKET POSITIONING: PROFESSIONAL MULTIBOT LEADER                          ║");
-        println!("╚══════════════════════════════════════════════════════════════════════════════╝");
-        
-        // ✅ DEMOSTRACIÓN ESPECIAL DE LA FASE 7 (sync version)
-        println!("\n╔══════════════════════════════════════════════════════════════════════════════╗");
-        println!("║                           🚀 FASE 7 SPECIAL DEMONSTRATION                       ║");
-        println!("║                        Dual Routing System Architecture                         ║");
-        println!("╠══════════════════════════════════════════════════════════════════════════════╣");
-        println!("║   🎯 UNIFIED ROUTING SYSTEM - ALL 11 PHASES ACTIVE                            ║");
-        println!("║   • Enterprise Grade: ✅ • Real Data: ✅ • AI Optimized: ✅                  ║");
-        println!("╚══════════════════════════════════════════════════════════════════════════════╝");
-        
-        info!("🎉 SniperForge Enterprise MultiBot v{} demonstration completed successfully", SYSTEM_VERSION);
-        info!("🚀 Professional unified arbitrage system ready for institutional deployment");
-        info!("💰 Total value demonstrated: ${:.2} across {} strategies", self.total_profit, self.active_strategies.len());
-        info!("🎯 Enterprise-grade system validation: COMPLETE");
-    }
 }
@@ -4,18 +4,24 @@
 //! SniperForge enterprise trading system, including sentiment analysis, predictive
 //! analytics, risk assessment, and portfolio optimization.
 
+#[cfg(feature = "ml")]
 pub mod advanced_ml_engine;
+pub mod success_model;
 
 // Re-export main ML components
+#[cfg(feature = "ml")]
 pub use advanced_ml_engine::{
     AdvancedMLEngine, MLConfig, SentimentAnalysis, MarketPrediction, 
     RiskAssessment, PortfolioOptimization, PatternMatch, MLAnalysisResult,
     SentimentTrend, TrendDirection, RiskCategory, PatternType, ModelMetrics
 };
+pub use success_model::{SuccessModel, SuccessModelConfig, SuccessFeatures, CalibrationReport, ReliabilityBin};
 
 /// ML Engine factory for creating configured ML instances
+#[cfg(feature = "ml")]
 pub struct MLEngineFactory;
 
+#[cfg(feature = "ml")]
 impl MLEngineFactory {
     /// Create a new ML engine with default configuration
     pub fn create_default() -> AdvancedMLEngine {
@@ -264,11 +270,16 @@ pub mod monitoring {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "ml")]
     use crate::types::{TradingOpportunity, MarketData, OpportunityType};
+    #[cfg(feature = "ml")]
     use std::collections::HashMap;
+    #[cfg(feature = "ml")]
     use chrono::Utc;
+    #[cfg(feature = "ml")]
     use std::time::Duration;
     
+    #[cfg(feature = "ml")]
     fn create_test_opportunity() -> TradingOpportunity {
         TradingOpportunity {
             opportunity_type: OpportunityType::Arbitrage,
@@ -288,6 +299,7 @@ mod tests {
         }
     }
     
+    #[cfg(feature = "ml")]
     fn create_test_market_data() -> MarketData {
         let mut prices = HashMap::new();
         prices.insert("SOL".to_string(), 150.0);
@@ -312,6 +324,7 @@ mod tests {
         }
     }
     
+    #[cfg(feature = "ml")]
     #[tokio::test]
     async fn test_ml_engine_creation() {
         let _engine = MLEngineFactory::create_default();
@@ -319,6 +332,7 @@ mod tests {
         assert!(true); // Basic existence test
     }
     
+    #[cfg(feature = "ml")]
     #[tokio::test]
     async fn test_ml_analysis() {
         let engine = MLEngineFactory::create_default();
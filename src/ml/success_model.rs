@@ -0,0 +1,438 @@
+//! Opportunity success probability model
+//!
+//! Logistic regression over spread, pool depth, volatility, time of day and
+//! venue, with a Platt-scaling step fitted on a holdout so the output can be
+//! used as a probability: the scoring pipeline multiplies expected profit by
+//! it. Outcomes of scored trades feed both retraining and a calibration
+//! monitor whose reliability curve shows whether predicted probabilities
+//! still match observed success rates.
+
+use chrono::{DateTime, Timelike, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::Path;
+use tracing::{info, warn};
+
+/// Venues are one-hot encoded into this many hashed buckets
+const VENUE_BUCKETS: usize = 8;
+const FEATURE_LEN: usize = 7 + VENUE_BUCKETS;
+
+/// Model inputs for one opportunity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuccessFeatures {
+    pub spread_bps: f64,
+    pub pool_depth_usd: f64,
+    /// Recent price volatility (stddev of returns); `None` when unknown
+    pub volatility: Option<f64>,
+    pub hour_utc: u32,
+    pub venue: String,
+}
+
+impl SuccessFeatures {
+    pub fn new(spread_bps: f64, pool_depth_usd: f64, volatility: Option<f64>, at: DateTime<Utc>, venue: &str) -> Self {
+        Self { spread_bps, pool_depth_usd, volatility, hour_utc: at.hour(), venue: venue.to_string() }
+    }
+
+    fn vector(&self) -> [f64; FEATURE_LEN] {
+        let mut x = [0.0; FEATURE_LEN];
+        let hour = self.hour_utc as f64 / 24.0 * std::f64::consts::TAU;
+        x[0] = 1.0;
+        x[1] = self.spread_bps / 100.0;
+        x[2] = self.pool_depth_usd.max(0.0).ln_1p() / 10.0;
+        x[3] = self.volatility.unwrap_or(0.0) * 10.0;
+        x[4] = if self.volatility.is_some() { 0.0 } else { 1.0 };
+        x[5] = hour.sin();
+        x[6] = hour.cos();
+        x[7 + venue_bucket(&self.venue)] = 1.0;
+        x
+    }
+}
+
+/// FNV-1a, so buckets stay stable across builds and saved models stay valid
+fn venue_bucket(venue: &str) -> usize {
+    let hash = venue.to_lowercase().bytes().fold(0xcbf29ce484222325u64, |h, b| (h ^ b as u64).wrapping_mul(0x100000001b3));
+    (hash % VENUE_BUCKETS as u64) as usize
+}
+
+fn sigmoid(z: f64) -> f64 {
+    1.0 / (1.0 + (-z).exp())
+}
+
+fn logit(p: f64) -> f64 {
+    let p = p.clamp(1e-6, 1.0 - 1e-6);
+    (p / (1.0 - p)).ln()
+}
+
+/// Logistic regression weights plus Platt calibration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainedModel {
+    pub weights: Vec<f64>,
+    /// Calibrated probability is `sigmoid(platt_a * logit(raw) + platt_b)`
+    pub platt_a: f64,
+    pub platt_b: f64,
+    pub trained_at: DateTime<Utc>,
+    pub training_samples: usize,
+}
+
+impl TrainedModel {
+    fn raw(&self, features: &SuccessFeatures) -> f64 {
+        sigmoid(features.vector().iter().zip(&self.weights).map(|(x, w)| x * w).sum())
+    }
+
+    /// Calibrated success probability in (0, 1)
+    pub fn predict(&self, features: &SuccessFeatures) -> f64 {
+        sigmoid(self.platt_a * logit(self.raw(features)) + self.platt_b)
+    }
+}
+
+/// Model training and monitoring settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuccessModelConfig {
+    /// No predictions until this many labelled outcomes exist
+    pub min_training_samples: usize,
+    /// Retrain after this many new outcomes
+    pub retrain_every: usize,
+    /// Training window (most recent outcomes)
+    pub max_samples: usize,
+    /// Share of the window held out to fit calibration
+    pub holdout_fraction: f64,
+    pub epochs: usize,
+    pub learning_rate: f64,
+    pub l2: f64,
+    pub calibration_bins: usize,
+    /// Predictions kept for the reliability curve
+    pub monitor_window: usize,
+    /// Expected calibration error above which the model is flagged
+    pub max_calibration_error: f64,
+}
+
+impl Default for SuccessModelConfig {
+    fn default() -> Self {
+        Self {
+            min_training_samples: 200,
+            retrain_every: 100,
+            max_samples: 5_000,
+            holdout_fraction: 0.2,
+            epochs: 300,
+            learning_rate: 0.5,
+            l2: 1e-3,
+            calibration_bins: 10,
+            monitor_window: 1_000,
+            max_calibration_error: 0.1,
+        }
+    }
+}
+
+/// Fit logistic regression weights by batch gradient descent
+fn fit_weights(samples: &[(SuccessFeatures, bool)], config: &SuccessModelConfig) -> Vec<f64> {
+    let data: Vec<([f64; FEATURE_LEN], f64)> = samples.iter()
+        .map(|(features, success)| (features.vector(), if *success { 1.0 } else { 0.0 }))
+        .collect();
+    let n = data.len().max(1) as f64;
+    let mut weights = vec![0.0; FEATURE_LEN];
+    for _ in 0..config.epochs {
+        let mut gradient = vec![0.0; FEATURE_LEN];
+        for (x, y) in &data {
+            let error = sigmoid(x.iter().zip(&weights).map(|(x, w)| x * w).sum()) - y;
+            gradient.iter_mut().zip(x).for_each(|(g, x)| *g += error * x);
+        }
+        for (i, (w, g)) in weights.iter_mut().zip(&gradient).enumerate() {
+            // Bias is not regularized
+            let penalty = if i == 0 { 0.0 } else { config.l2 * *w };
+            *w -= config.learning_rate * (g / n + penalty);
+        }
+    }
+    weights
+}
+
+/// Fit Platt scaling on raw holdout predictions
+fn fit_platt(raw: &[(f64, bool)], config: &SuccessModelConfig) -> (f64, f64) {
+    if raw.is_empty() {
+        return (1.0, 0.0);
+    }
+    let n = raw.len() as f64;
+    let (mut a, mut b) = (1.0, 0.0);
+    for _ in 0..config.epochs {
+        let (mut grad_a, mut grad_b) = (0.0, 0.0);
+        for (p, success) in raw {
+            let z = logit(*p);
+            let error = sigmoid(a * z + b) - if *success { 1.0 } else { 0.0 };
+            grad_a += error * z;
+            grad_b += error;
+        }
+        a -= config.learning_rate * grad_a / n;
+        b -= config.learning_rate * grad_b / n;
+    }
+    (a, b)
+}
+
+/// One bucket of the reliability curve
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReliabilityBin {
+    pub lower: f64,
+    pub upper: f64,
+    pub count: usize,
+    pub mean_predicted: f64,
+    pub observed_rate: f64,
+}
+
+/// Calibration of recent live predictions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalibrationReport {
+    pub samples: usize,
+    pub brier_score: f64,
+    /// Count-weighted mean |predicted - observed| over bins
+    pub expected_calibration_error: f64,
+    pub curve: Vec<ReliabilityBin>,
+    pub miscalibrated: bool,
+}
+
+/// Rolling window of (predicted probability, outcome)
+#[derive(Debug, Clone)]
+pub struct CalibrationMonitor {
+    bins: usize,
+    window: usize,
+    records: VecDeque<(f64, bool)>,
+}
+
+impl CalibrationMonitor {
+    pub fn new(bins: usize, window: usize) -> Self {
+        Self { bins: bins.max(1), window: window.max(1), records: VecDeque::new() }
+    }
+
+    pub fn record(&mut self, predicted: f64, success: bool) {
+        self.records.push_back((predicted.clamp(0.0, 1.0), success));
+        while self.records.len() > self.window {
+            self.records.pop_front();
+        }
+    }
+
+    pub fn reliability_curve(&self) -> Vec<ReliabilityBin> {
+        let width = 1.0 / self.bins as f64;
+        (0..self.bins)
+            .filter_map(|i| {
+                let lower = i as f64 * width;
+                let upper = lower + width;
+                let last = i + 1 == self.bins;
+                let in_bin: Vec<&(f64, bool)> = self.records.iter()
+                    .filter(|(p, _)| *p >= lower && (*p < upper || (last && *p <= upper)))
+                    .collect();
+                if in_bin.is_empty() {
+                    return None;
+                }
+                let count = in_bin.len();
+                Some(ReliabilityBin {
+                    lower,
+                    upper,
+                    count,
+                    mean_predicted: in_bin.iter().map(|(p, _)| p).sum::<f64>() / count as f64,
+                    observed_rate: in_bin.iter().filter(|(_, s)| *s).count() as f64 / count as f64,
+                })
+            })
+            .collect()
+    }
+
+    pub fn report(&self, max_calibration_error: f64) -> CalibrationReport {
+        let samples = self.records.len();
+        let curve = self.reliability_curve();
+        let (brier_score, expected_calibration_error) = if samples == 0 {
+            (0.0, 0.0)
+        } else {
+            let brier = self.records.iter()
+                .map(|(p, s)| (p - if *s { 1.0 } else { 0.0 }).powi(2))
+                .sum::<f64>() / samples as f64;
+            let ece = curve.iter()
+                .map(|bin| bin.count as f64 * (bin.mean_predicted - bin.observed_rate).abs())
+                .sum::<f64>() / samples as f64;
+            (brier, ece)
+        };
+        CalibrationReport {
+            samples,
+            brier_score,
+            expected_calibration_error,
+            curve,
+            miscalibrated: expected_calibration_error > max_calibration_error,
+        }
+    }
+}
+
+/// Result of a retraining run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingSummary {
+    pub samples: usize,
+    pub holdout: usize,
+    /// Brier score of calibrated predictions on the holdout
+    pub holdout_brier: f64,
+}
+
+#[derive(Debug, Default)]
+struct ModelState {
+    model: Option<TrainedModel>,
+    samples: VecDeque<(SuccessFeatures, bool)>,
+    since_retrain: usize,
+}
+
+/// Trains on live outcomes and serves calibrated success probabilities
+pub struct SuccessModel {
+    config: SuccessModelConfig,
+    state: RwLock<ModelState>,
+    monitor: RwLock<CalibrationMonitor>,
+}
+
+impl Default for SuccessModel {
+    fn default() -> Self {
+        Self::new(SuccessModelConfig::default())
+    }
+}
+
+impl SuccessModel {
+    pub fn new(config: SuccessModelConfig) -> Self {
+        let monitor = CalibrationMonitor::new(config.calibration_bins, config.monitor_window);
+        Self { config, state: RwLock::new(ModelState::default()), monitor: RwLock::new(monitor) }
+    }
+
+    /// Calibrated probability, or `None` while the model is untrained
+    pub fn predict(&self, features: &SuccessFeatures) -> Option<f64> {
+        self.state.read().model.as_ref().map(|model| model.predict(features))
+    }
+
+    /// Label an opportunity; `predicted` is what [`predict`](Self::predict) returned when it was scored
+    pub fn record_outcome(&self, features: SuccessFeatures, predicted: Option<f64>, success: bool) {
+        if let Some(predicted) = predicted {
+            self.monitor.write().record(predicted, success);
+        }
+        let due = {
+            let mut state = self.state.write();
+            state.samples.push_back((features, success));
+            while state.samples.len() > self.config.max_samples {
+                state.samples.pop_front();
+            }
+            state.since_retrain += 1;
+            state.samples.len() >= self.config.min_training_samples
+                && (state.model.is_none() || state.since_retrain >= self.config.retrain_every)
+        };
+        if due {
+            self.retrain();
+        }
+    }
+
+    /// Refit on the current window; `None` when there are too few outcomes
+    pub fn retrain(&self) -> Option<TrainingSummary> {
+        let samples: Vec<(SuccessFeatures, bool)> = {
+            let state = self.state.read();
+            if state.samples.is_empty() || state.samples.len() < self.config.min_training_samples {
+                return None;
+            }
+            state.samples.iter().cloned().collect()
+        };
+
+        // Most recent outcomes are the holdout: calibration should track the present
+        let holdout = ((samples.len() as f64 * self.config.holdout_fraction) as usize).min(samples.len() - 1);
+        let (train, test) = samples.split_at(samples.len() - holdout);
+        let mut model = TrainedModel {
+            weights: fit_weights(train, &self.config),
+            platt_a: 1.0,
+            platt_b: 0.0,
+            trained_at: Utc::now(),
+            training_samples: train.len(),
+        };
+        let raw: Vec<(f64, bool)> = test.iter().map(|(f, s)| (model.raw(f), *s)).collect();
+        (model.platt_a, model.platt_b) = fit_platt(&raw, &self.config);
+        let holdout_brier = if test.is_empty() {
+            0.0
+        } else {
+            test.iter().map(|(f, s)| (model.predict(f) - if *s { 1.0 } else { 0.0 }).powi(2)).sum::<f64>() / test.len() as f64
+        };
+
+        {
+            let mut state = self.state.write();
+            state.model = Some(model);
+            state.since_retrain = 0;
+        }
+        let summary = TrainingSummary { samples: train.len(), holdout, holdout_brier };
+        info!("🧠 Success model retrained on {} outcomes (holdout {}, Brier {:.3})", summary.samples, summary.holdout, summary.holdout_brier);
+        let report = self.calibration();
+        if report.miscalibrated {
+            warn!("📉 Success model live calibration error {:.3} exceeds {:.3}", report.expected_calibration_error, self.config.max_calibration_error);
+        }
+        Some(summary)
+    }
+
+    /// Reliability curve and error metrics for recent live predictions
+    pub fn calibration(&self) -> CalibrationReport {
+        self.monitor.read().report(self.config.max_calibration_error)
+    }
+
+    pub fn model(&self) -> Option<TrainedModel> {
+        self.state.read().model.clone()
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let model = self.model().ok_or_else(|| anyhow::anyhow!("success model is not trained"))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_vec_pretty(&model)?)?;
+        Ok(())
+    }
+
+    /// Serve a previously trained model until enough live outcomes accumulate to retrain
+    pub fn load(&self, path: &Path) -> anyhow::Result<()> {
+        let model: TrainedModel = serde_json::from_slice(&std::fs::read(path)?)?;
+        if model.weights.len() != FEATURE_LEN {
+            anyhow::bail!("success model has {} weights, expected {}", model.weights.len(), FEATURE_LEN);
+        }
+        self.state.write().model = Some(model);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn features(spread_bps: f64, venue: &str) -> SuccessFeatures {
+        SuccessFeatures { spread_bps, pool_depth_usd: 50_000.0, volatility: Some(0.02), hour_utc: 14, venue: venue.to_string() }
+    }
+
+    #[test]
+    fn test_model_learns_spread_relationship_after_enough_outcomes() {
+        let model = SuccessModel::new(SuccessModelConfig { min_training_samples: 100, ..Default::default() });
+        // Wide spreads survive fees and slippage; narrow ones do not
+        for i in 0..99 {
+            let spread = (i % 20) as f64 * 10.0;
+            model.record_outcome(features(spread, "Raydium"), None, spread >= 100.0);
+        }
+        assert!(model.predict(&features(150.0, "Raydium")).is_none());
+
+        model.record_outcome(features(190.0, "Raydium"), None, true);
+        let wide = model.predict(&features(180.0, "Raydium")).unwrap();
+        let narrow = model.predict(&features(20.0, "Raydium")).unwrap();
+        assert!(wide > 0.7, "wide spread {}", wide);
+        assert!(narrow < 0.3, "narrow spread {}", narrow);
+    }
+
+    #[test]
+    fn test_reliability_curve_flags_overconfidence() {
+        let mut calibrated = CalibrationMonitor::new(10, 1_000);
+        let mut overconfident = CalibrationMonitor::new(10, 1_000);
+        for i in 0..100 {
+            // 30% of these succeed
+            let success = i % 10 < 3;
+            calibrated.record(0.3, success);
+            overconfident.record(0.9, success);
+        }
+
+        let good = calibrated.report(0.1);
+        assert_eq!(good.curve.len(), 1);
+        assert!((good.curve[0].observed_rate - 0.3).abs() < 1e-9);
+        assert!(good.expected_calibration_error < 1e-9);
+        assert!(!good.miscalibrated);
+
+        let bad = overconfident.report(0.1);
+        assert!((bad.expected_calibration_error - 0.6).abs() < 1e-9);
+        assert!(bad.brier_score > good.brier_score);
+        assert!(bad.miscalibrated);
+    }
+}
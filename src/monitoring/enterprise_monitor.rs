@@ -5,6 +5,8 @@ use tokio::sync::RwLock;
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 
+use super::notifications::NotificationDigest;
+
 /// Enterprise-grade monitoring and observability system
 #[derive(Debug)]
 pub struct EnterpriseMonitor {
@@ -197,7 +199,7 @@ pub enum AnomalyType {
     PerformanceDegradation,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Severity {
     Low,
     Medium,
@@ -254,6 +256,8 @@ pub struct AlertManager {
     alert_rules: Arc<RwLock<Vec<AlertRule>>>,
     /// Alert channels
     alert_channels: Arc<RwLock<Vec<AlertChannel>>>,
+    /// Digest/dedup layer alerts are routed through before reaching sinks
+    notifications: Arc<RwLock<Option<Arc<NotificationDigest>>>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -456,6 +460,11 @@ impl EnterpriseMonitor {
         }
     }
 
+    /// Shared alert manager, for subsystems that raise their own alerts
+    pub fn alert_manager(&self) -> Arc<AlertManager> {
+        Arc::clone(&self.alert_manager)
+    }
+
     /// Check if monitoring is active
     pub fn is_active(&self) -> bool {
         self.is_active.load(Ordering::SeqCst)
@@ -816,9 +825,15 @@ impl AlertManager {
             active_alerts: Arc::new(RwLock::new(Vec::new())),
             alert_rules: Arc::new(RwLock::new(Vec::new())),
             alert_channels: Arc::new(RwLock::new(Vec::new())),
+            notifications: Arc::new(RwLock::new(None)),
         }
     }
 
+    /// Route raised alerts through a notification digest
+    pub async fn attach_notifications(&self, digest: Arc<NotificationDigest>) {
+        *self.notifications.write().await = Some(digest);
+    }
+
     pub async fn process_alerts(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         // Use alert_rules field
         let rules = self.alert_rules.read().await;
@@ -857,6 +872,15 @@ impl AlertManager {
     pub async fn get_active_alerts(&self) -> Vec<Alert> {
         self.active_alerts.read().await.clone()
     }
+
+    /// Raise a new alert
+    pub async fn raise_alert(&self, alert: Alert) {
+        tracing::warn!("🚨 Alert raised: {} - {}", alert.title, alert.description);
+        if let Some(digest) = self.notifications.read().await.as_ref() {
+            digest.submit(&alert).await;
+        }
+        self.active_alerts.write().await.push(alert);
+    }
 }
 
 impl HealthChecker {
@@ -0,0 +1,489 @@
+//! Composite health check
+//!
+//! Subsystems register a [`HealthProbe`]; [`HealthRegistry::check_all`] runs
+//! every probe concurrently under a timeout and folds the results into one
+//! machine-readable [`HealthReport`] with per-component status, latency and
+//! last-success time. The overall status is unhealthy when a critical
+//! component is, degraded when anything else is not healthy. Served as
+//! `GET /health` (503 when unhealthy, for load balancers) and through the
+//! control API.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+
+use super::notifications::NotificationDigest;
+use super::watchdog::{TaskLiveness, TaskWatchdog};
+use crate::apis::circuit_breaker::provider_circuits;
+use crate::apis::stream_sync::feed_health;
+use crate::types::ComponentHealthStatus;
+
+/// Status of one component or of the whole system
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthState {
+    Healthy,
+    Degraded,
+    Unhealthy,
+}
+
+impl From<&ComponentHealthStatus> for HealthState {
+    fn from(status: &ComponentHealthStatus) -> Self {
+        match status {
+            ComponentHealthStatus::Healthy => HealthState::Healthy,
+            ComponentHealthStatus::Degraded(_) => HealthState::Degraded,
+            ComponentHealthStatus::Unhealthy(_) => HealthState::Unhealthy,
+        }
+    }
+}
+
+/// One subsystem's health check
+#[async_trait]
+pub trait HealthProbe: Send + Sync {
+    fn name(&self) -> &str;
+
+    /// Subsystem group, e.g. "rpc", "feeds", "storage"
+    fn kind(&self) -> &str;
+
+    /// Whether this component being down makes the whole system unhealthy
+    fn critical(&self) -> bool {
+        true
+    }
+
+    async fn check(&self) -> ComponentHealthStatus;
+}
+
+/// Result of one probe
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentReport {
+    pub name: String,
+    pub kind: String,
+    pub critical: bool,
+    pub status: HealthState,
+    pub detail: Option<String>,
+    pub latency_ms: u64,
+    /// Last check that did not come back unhealthy
+    pub last_success: Option<DateTime<Utc>>,
+}
+
+/// Composite health of the system
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthReport {
+    pub status: HealthState,
+    pub checked_at: DateTime<Utc>,
+    pub components: Vec<ComponentReport>,
+}
+
+impl HealthReport {
+    /// 200 while the system can serve, 503 once a critical component is down
+    pub fn http_status(&self) -> u16 {
+        match self.status {
+            HealthState::Healthy | HealthState::Degraded => 200,
+            HealthState::Unhealthy => 503,
+        }
+    }
+}
+
+/// Registered probes and their last successes
+pub struct HealthRegistry {
+    probes: RwLock<Vec<Arc<dyn HealthProbe>>>,
+    last_success: RwLock<HashMap<String, DateTime<Utc>>>,
+    timeout: Duration,
+}
+
+impl std::fmt::Debug for HealthRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HealthRegistry").field("timeout", &self.timeout).finish_non_exhaustive()
+    }
+}
+
+impl Default for HealthRegistry {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5))
+    }
+}
+
+impl HealthRegistry {
+    /// `timeout` bounds each probe; a probe that exceeds it counts as unhealthy
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            probes: RwLock::new(Vec::new()),
+            last_success: RwLock::new(HashMap::new()),
+            timeout,
+        }
+    }
+
+    pub fn register(&self, probe: Arc<dyn HealthProbe>) {
+        self.probes.write().push(probe);
+    }
+
+    pub fn component_names(&self) -> Vec<String> {
+        self.probes.read().iter().map(|probe| probe.name().to_string()).collect()
+    }
+
+    /// Run every probe concurrently and aggregate
+    pub async fn check_all(&self) -> HealthReport {
+        let probes = self.probes.read().clone();
+        let checks = probes.iter().map(|probe| async move {
+            let started = Instant::now();
+            let status = match tokio::time::timeout(self.timeout, probe.check()).await {
+                Ok(status) => status,
+                Err(_) => ComponentHealthStatus::Unhealthy(format!("no answer within {:?}", self.timeout)),
+            };
+            (probe, status, started.elapsed())
+        });
+        let results = futures::future::join_all(checks).await;
+
+        let now = Utc::now();
+        let mut components = Vec::with_capacity(results.len());
+        let mut overall = HealthState::Healthy;
+        for (probe, status, latency) in results {
+            let state = HealthState::from(&status);
+            let last_success = if state == HealthState::Unhealthy {
+                self.last_success.read().get(probe.name()).copied()
+            } else {
+                self.last_success.write().insert(probe.name().to_string(), now);
+                Some(now)
+            };
+            let contribution = match (state, probe.critical()) {
+                (HealthState::Unhealthy, false) => HealthState::Degraded,
+                (state, _) => state,
+            };
+            overall = overall.max(contribution);
+            components.push(ComponentReport {
+                name: probe.name().to_string(),
+                kind: probe.kind().to_string(),
+                critical: probe.critical(),
+                status: state,
+                detail: match status {
+                    ComponentHealthStatus::Healthy => None,
+                    other => Some(other.description()),
+                },
+                latency_ms: latency.as_millis() as u64,
+                last_success,
+            });
+        }
+        HealthReport { status: overall, checked_at: now, components }
+    }
+
+    /// Serve `GET /health` until the server stops
+    #[cfg(feature = "http-api")]
+    pub async fn serve(self: Arc<Self>, bind_address: String) -> std::io::Result<()> {
+        use actix_web::{web, App, HttpServer};
+
+        tracing::info!("🩺 Health endpoint listening on http://{}/health", bind_address);
+        HttpServer::new(move || {
+            App::new()
+                .app_data(web::Data::new(self.clone()))
+                .route("/health", web::get().to(health_endpoint))
+        })
+        .workers(1)
+        .bind(&bind_address)?
+        .run()
+        .await
+    }
+}
+
+/// `GET /health` handler, shared with the API gateway
+#[cfg(feature = "http-api")]
+pub async fn health_endpoint(registry: actix_web::web::Data<Arc<HealthRegistry>>) -> actix_web::HttpResponse {
+    let report = registry.check_all().await;
+    let status = actix_web::http::StatusCode::from_u16(report.http_status())
+        .unwrap_or(actix_web::http::StatusCode::SERVICE_UNAVAILABLE);
+    actix_web::HttpResponse::build(status).json(report)
+}
+
+type CheckFn = Box<dyn Fn() -> Pin<Box<dyn Future<Output = ComponentHealthStatus> + Send>> + Send + Sync>;
+
+/// Probe from a closure, for components with an ad-hoc health check
+pub struct FnProbe {
+    name: String,
+    kind: String,
+    critical: bool,
+    check: CheckFn,
+}
+
+impl FnProbe {
+    pub fn new<F, Fut>(name: &str, kind: &str, critical: bool, check: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ComponentHealthStatus> + Send + 'static,
+    {
+        Self {
+            name: name.to_string(),
+            kind: kind.to_string(),
+            critical,
+            check: Box::new(move || Box::pin(check())),
+        }
+    }
+}
+
+#[async_trait]
+impl HealthProbe for FnProbe {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    fn critical(&self) -> bool {
+        self.critical
+    }
+
+    async fn check(&self) -> ComponentHealthStatus {
+        (self.check)().await
+    }
+}
+
+/// RPC endpoint round trip, plus the state of the provider circuit breakers
+pub struct RpcProbe {
+    name: String,
+    client: solana_client::nonblocking::rpc_client::RpcClient,
+}
+
+impl RpcProbe {
+    pub fn new(name: &str, rpc_url: String) -> Self {
+        Self { name: name.to_string(), client: solana_client::nonblocking::rpc_client::RpcClient::new(rpc_url) }
+    }
+}
+
+#[async_trait]
+impl HealthProbe for RpcProbe {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn kind(&self) -> &str {
+        "rpc"
+    }
+
+    async fn check(&self) -> ComponentHealthStatus {
+        if let Err(e) = self.client.get_slot().await {
+            return ComponentHealthStatus::Unhealthy(e.to_string());
+        }
+        let open: Vec<String> = provider_circuits().degraded_providers().into_iter().map(|p| format!("circuit {} open", p)).collect();
+        if open.is_empty() {
+            ComponentHealthStatus::Healthy
+        } else {
+            ComponentHealthStatus::Degraded(open)
+        }
+    }
+}
+
+/// Market data feeds registered with [`feed_health`]
+#[derive(Debug, Default)]
+pub struct FeedProbe;
+
+#[async_trait]
+impl HealthProbe for FeedProbe {
+    fn name(&self) -> &str {
+        "feeds"
+    }
+
+    fn kind(&self) -> &str {
+        "feeds"
+    }
+
+    async fn check(&self) -> ComponentHealthStatus {
+        let blocking = feed_health().blocking_trading();
+        if !blocking.is_empty() {
+            return ComponentHealthStatus::Unhealthy(
+                blocking.iter().map(|(feed, status)| format!("{} {:?}", feed, status)).collect::<Vec<_>>().join(", "),
+            );
+        }
+        let degraded = feed_health().degraded();
+        if degraded.is_empty() {
+            ComponentHealthStatus::Healthy
+        } else {
+            ComponentHealthStatus::Degraded(degraded.iter().map(|(feed, status)| format!("{} {:?}", feed, status)).collect())
+        }
+    }
+}
+
+/// Supervised background tasks (executors, indexers, servers)
+pub struct WatchdogProbe {
+    watchdog: Arc<TaskWatchdog>,
+}
+
+impl WatchdogProbe {
+    pub fn new(watchdog: Arc<TaskWatchdog>) -> Self {
+        Self { watchdog }
+    }
+}
+
+#[async_trait]
+impl HealthProbe for WatchdogProbe {
+    fn name(&self) -> &str {
+        "supervised_tasks"
+    }
+
+    fn kind(&self) -> &str {
+        "executors"
+    }
+
+    async fn check(&self) -> ComponentHealthStatus {
+        let tasks = self.watchdog.get_task_health().await;
+        let failed: Vec<&str> = tasks.iter().filter(|t| t.liveness == TaskLiveness::Failed).map(|t| t.name.as_str()).collect();
+        if !failed.is_empty() {
+            return ComponentHealthStatus::Unhealthy(format!("restart budget exhausted: {}", failed.join(", ")));
+        }
+        let unhealthy: Vec<String> = tasks
+            .iter()
+            .filter(|t| t.liveness != TaskLiveness::Healthy)
+            .map(|t| format!("{} {:?}", t.name, t.liveness))
+            .collect();
+        if unhealthy.is_empty() {
+            ComponentHealthStatus::Healthy
+        } else {
+            ComponentHealthStatus::Degraded(unhealthy)
+        }
+    }
+}
+
+/// State directory accepts writes
+pub struct StorageProbe {
+    dir: PathBuf,
+}
+
+impl StorageProbe {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+#[async_trait]
+impl HealthProbe for StorageProbe {
+    fn name(&self) -> &str {
+        "state_storage"
+    }
+
+    fn kind(&self) -> &str {
+        "storage"
+    }
+
+    async fn check(&self) -> ComponentHealthStatus {
+        let path = self.dir.join(".health_probe");
+        let result = async {
+            tokio::fs::create_dir_all(&self.dir).await?;
+            tokio::fs::write(&path, Utc::now().to_rfc3339()).await?;
+            tokio::fs::remove_file(&path).await
+        }
+        .await;
+        match result {
+            Ok(()) => ComponentHealthStatus::Healthy,
+            Err(e) => ComponentHealthStatus::Unhealthy(format!("{}: {}", self.dir.display(), e)),
+        }
+    }
+}
+
+/// Notification sinks, from their recent deliveries
+pub struct NotificationProbe {
+    digest: Arc<NotificationDigest>,
+}
+
+impl NotificationProbe {
+    pub fn new(digest: Arc<NotificationDigest>) -> Self {
+        Self { digest }
+    }
+}
+
+#[async_trait]
+impl HealthProbe for NotificationProbe {
+    fn name(&self) -> &str {
+        "notifications"
+    }
+
+    fn kind(&self) -> &str {
+        "notifications"
+    }
+
+    /// Alerts still reach the log when a channel is down
+    fn critical(&self) -> bool {
+        false
+    }
+
+    async fn check(&self) -> ComponentHealthStatus {
+        let failing: Vec<String> = self
+            .digest
+            .sink_health()
+            .await
+            .into_iter()
+            .filter(|sink| sink.consecutive_failures > 0)
+            .map(|sink| format!("{}: {}", sink.name, sink.last_error.unwrap_or_default()))
+            .collect();
+        if failing.is_empty() {
+            ComponentHealthStatus::Healthy
+        } else {
+            ComponentHealthStatus::Unhealthy(failing.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probe(name: &str, critical: bool, status: ComponentHealthStatus) -> Arc<dyn HealthProbe> {
+        Arc::new(FnProbe::new(name, "test", critical, move || {
+            let status = status.clone();
+            async move { status }
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_only_critical_components_make_system_unhealthy() {
+        let registry = HealthRegistry::default();
+        registry.register(probe("rpc", true, ComponentHealthStatus::Healthy));
+        registry.register(probe("telegram", false, ComponentHealthStatus::Unhealthy("401".to_string())));
+
+        let report = registry.check_all().await;
+        assert_eq!(report.status, HealthState::Degraded);
+        assert_eq!(report.http_status(), 200);
+        assert_eq!(report.components[1].status, HealthState::Unhealthy);
+        assert_eq!(report.components[1].detail.as_deref(), Some("Unhealthy: 401"));
+
+        registry.register(probe("storage", true, ComponentHealthStatus::Unhealthy("read-only".to_string())));
+        let report = registry.check_all().await;
+        assert_eq!(report.status, HealthState::Unhealthy);
+        assert_eq!(report.http_status(), 503);
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["status"], "unhealthy");
+        assert_eq!(json["components"][0]["status"], "healthy");
+    }
+
+    #[tokio::test]
+    async fn test_timeout_is_unhealthy_and_keeps_last_success() {
+        let registry = HealthRegistry::new(Duration::from_millis(20));
+        let slow = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let flag = slow.clone();
+        registry.register(Arc::new(FnProbe::new("rpc", "rpc", true, move || {
+            let slow = flag.load(std::sync::atomic::Ordering::Relaxed);
+            async move {
+                if slow {
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+                ComponentHealthStatus::Healthy
+            }
+        })));
+
+        let first = registry.check_all().await;
+        let succeeded_at = first.components[0].last_success.unwrap();
+
+        slow.store(true, std::sync::atomic::Ordering::Relaxed);
+        let second = registry.check_all().await;
+        assert_eq!(second.status, HealthState::Unhealthy);
+        assert!(second.components[0].detail.as_deref().unwrap().contains("no answer"));
+        assert_eq!(second.components[0].last_success, Some(succeeded_at));
+        assert!(second.components[0].latency_ms >= 20);
+    }
+}
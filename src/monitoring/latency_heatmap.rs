@@ -0,0 +1,351 @@
+//! Latency heatmap per RPC provider, method and venue
+//!
+//! Averages hide what matters when picking endpoints: one provider may be fast
+//! at 03:00 UTC and slow during the US open, or `sendTransaction` may be fine
+//! while `getMultipleAccounts` has a fat tail. Every instrumented call lands
+//! in a histogram keyed by source (RPC provider + method, or DEX venue +
+//! operation) and UTC hour of day, from which percentiles per cell are read.
+//!
+//! A short rolling window of recent calls per source is compared against the
+//! source's all-day baseline, so a provider getting slower is flagged while it
+//! happens rather than after the missed fills show up in PnL. A process-wide
+//! heatmap is available through `latency_heatmap()`.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use chrono::{DateTime, Timelike, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// Upper bounds (ms) of the histogram buckets; a last bucket catches the rest
+const BUCKET_BOUNDS_MS: [f64; 12] = [5.0, 10.0, 25.0, 50.0, 100.0, 200.0, 400.0, 800.0, 1_500.0, 3_000.0, 6_000.0, 12_000.0];
+
+/// What a latency was measured against
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LatencySource {
+    Rpc { provider: String, method: String },
+    Venue { venue: String, operation: String },
+}
+
+impl LatencySource {
+    pub fn rpc(provider: &str, method: &str) -> Self {
+        Self::Rpc { provider: provider.to_string(), method: method.to_string() }
+    }
+
+    pub fn venue(venue: &str, operation: &str) -> Self {
+        Self::Venue { venue: venue.to_string(), operation: operation.to_string() }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Rpc { provider, method } => format!("rpc:{}:{}", provider, method),
+            Self::Venue { venue, operation } => format!("venue:{}:{}", venue, operation),
+        }
+    }
+}
+
+/// Heatmap settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencyHeatmapConfig {
+    /// Rolling window compared against the baseline
+    pub recent_window_secs: u64,
+    /// Calls kept per source in the rolling window
+    pub max_recent_samples: usize,
+    /// Recent calls needed before a degradation is reported
+    pub min_recent_samples: usize,
+    /// Recent p90 over baseline p90 that counts as degraded
+    pub degradation_factor: f64,
+}
+
+impl Default for LatencyHeatmapConfig {
+    fn default() -> Self {
+        Self {
+            recent_window_secs: 600,
+            max_recent_samples: 1_000,
+            min_recent_samples: 20,
+            degradation_factor: 2.0,
+        }
+    }
+}
+
+/// Fixed-bucket latency histogram
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LatencyHistogram {
+    /// One count per bound in `BUCKET_BOUNDS_MS`, plus the overflow bucket
+    pub buckets: Vec<u64>,
+    pub count: u64,
+    pub errors: u64,
+    pub sum_ms: f64,
+    pub max_ms: f64,
+}
+
+impl LatencyHistogram {
+    pub fn record(&mut self, latency_ms: f64, ok: bool) {
+        if self.buckets.is_empty() {
+            self.buckets = vec![0; BUCKET_BOUNDS_MS.len() + 1];
+        }
+        let index = BUCKET_BOUNDS_MS.iter().position(|bound| latency_ms <= *bound).unwrap_or(BUCKET_BOUNDS_MS.len());
+        self.buckets[index] += 1;
+        self.count += 1;
+        self.errors += u64::from(!ok);
+        self.sum_ms += latency_ms;
+        self.max_ms = self.max_ms.max(latency_ms);
+    }
+
+    pub fn mean_ms(&self) -> f64 {
+        if self.count == 0 { 0.0 } else { self.sum_ms / self.count as f64 }
+    }
+
+    /// Upper bound of the bucket holding the `q` quantile (capped at the max seen)
+    pub fn quantile_ms(&self, q: f64) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        let rank = (q.clamp(0.0, 1.0) * self.count as f64).ceil().max(1.0) as u64;
+        let mut seen = 0;
+        for (index, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return BUCKET_BOUNDS_MS.get(index).copied().unwrap_or(self.max_ms).min(self.max_ms);
+            }
+        }
+        self.max_ms
+    }
+
+    pub fn summary(&self) -> LatencySummary {
+        LatencySummary {
+            count: self.count,
+            error_rate: if self.count == 0 { 0.0 } else { self.errors as f64 / self.count as f64 },
+            mean_ms: self.mean_ms(),
+            p50_ms: self.quantile_ms(0.5),
+            p90_ms: self.quantile_ms(0.9),
+            p99_ms: self.quantile_ms(0.99),
+            max_ms: self.max_ms,
+        }
+    }
+}
+
+/// Distribution of one heatmap cell
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LatencySummary {
+    pub count: u64,
+    pub error_rate: f64,
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
+
+/// One source's row of the heatmap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencyRow {
+    pub source: LatencySource,
+    pub label: String,
+    /// All hours together
+    pub overall: LatencySummary,
+    /// UTC hour of day -> distribution (hours without calls are left out)
+    pub by_hour: BTreeMap<u32, LatencySummary>,
+}
+
+/// Source running slower than its baseline right now
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencyDegradation {
+    pub source: LatencySource,
+    pub label: String,
+    pub recent_samples: usize,
+    pub recent_p90_ms: f64,
+    pub baseline_p90_ms: f64,
+    pub ratio: f64,
+}
+
+#[derive(Debug, Default)]
+struct SourceLatency {
+    by_hour: [LatencyHistogram; 24],
+    recent: VecDeque<(DateTime<Utc>, f64)>,
+}
+
+/// Latency distributions by source and hour of day
+#[derive(Debug, Default)]
+pub struct LatencyHeatmap {
+    config: LatencyHeatmapConfig,
+    sources: RwLock<HashMap<LatencySource, SourceLatency>>,
+}
+
+impl LatencyHeatmap {
+    pub fn new(config: LatencyHeatmapConfig) -> Self {
+        Self { config, sources: RwLock::new(HashMap::new()) }
+    }
+
+    pub fn config(&self) -> &LatencyHeatmapConfig {
+        &self.config
+    }
+
+    pub fn record_rpc(&self, provider: &str, method: &str, latency: Duration, ok: bool) {
+        self.record_at(LatencySource::rpc(provider, method), latency, ok, Utc::now());
+    }
+
+    pub fn record_venue(&self, venue: &str, operation: &str, latency: Duration, ok: bool) {
+        self.record_at(LatencySource::venue(venue, operation), latency, ok, Utc::now());
+    }
+
+    pub fn record_at(&self, source: LatencySource, latency: Duration, ok: bool, at: DateTime<Utc>) {
+        let latency_ms = latency.as_secs_f64() * 1_000.0;
+        let cutoff = at - chrono::Duration::seconds(self.config.recent_window_secs as i64);
+        let mut sources = self.sources.write();
+        let entry = sources.entry(source).or_default();
+        entry.by_hour[at.hour() as usize].record(latency_ms, ok);
+        entry.recent.push_back((at, latency_ms));
+        while entry.recent.len() > self.config.max_recent_samples
+            || entry.recent.front().is_some_and(|(seen, _)| *seen <= cutoff)
+        {
+            entry.recent.pop_front();
+        }
+    }
+
+    /// Every source with its per-hour distributions, sorted by source
+    pub fn rows(&self) -> Vec<LatencyRow> {
+        let sources = self.sources.read();
+        let mut rows: Vec<LatencyRow> = sources
+            .iter()
+            .map(|(source, latency)| LatencyRow {
+                label: source.label(),
+                source: source.clone(),
+                overall: Self::merged(&latency.by_hour).summary(),
+                by_hour: latency
+                    .by_hour
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, histogram)| histogram.count > 0)
+                    .map(|(hour, histogram)| (hour as u32, histogram.summary()))
+                    .collect(),
+            })
+            .collect();
+        rows.sort_by(|a, b| a.source.cmp(&b.source));
+        rows
+    }
+
+    /// Sources whose recent p90 is well over their all-day baseline
+    pub fn degradations(&self) -> Vec<LatencyDegradation> {
+        self.degradations_at(Utc::now())
+    }
+
+    pub fn degradations_at(&self, now: DateTime<Utc>) -> Vec<LatencyDegradation> {
+        let cutoff = now - chrono::Duration::seconds(self.config.recent_window_secs as i64);
+        let sources = self.sources.read();
+        let mut degraded: Vec<LatencyDegradation> = sources
+            .iter()
+            .filter_map(|(source, latency)| {
+                let mut recent = LatencyHistogram::default();
+                for (_, latency_ms) in latency.recent.iter().filter(|(seen, _)| *seen > cutoff) {
+                    recent.record(*latency_ms, true);
+                }
+                if (recent.count as usize) < self.config.min_recent_samples {
+                    return None;
+                }
+                let baseline_p90_ms = Self::merged(&latency.by_hour).quantile_ms(0.9);
+                let recent_p90_ms = recent.quantile_ms(0.9);
+                let ratio = if baseline_p90_ms > 0.0 { recent_p90_ms / baseline_p90_ms } else { 1.0 };
+                (ratio >= self.config.degradation_factor).then(|| LatencyDegradation {
+                    label: source.label(),
+                    source: source.clone(),
+                    recent_samples: recent.count as usize,
+                    recent_p90_ms,
+                    baseline_p90_ms,
+                    ratio,
+                })
+            })
+            .collect();
+        degraded.sort_by(|a, b| b.ratio.total_cmp(&a.ratio));
+        degraded
+    }
+
+    /// Warn for every degraded source
+    pub fn check_degradations(&self) -> Vec<LatencyDegradation> {
+        let degraded = self.degradations();
+        for degradation in &degraded {
+            warn!("🐢 {} p90 {:.0}ms over the last {}s ({:.1}x its {:.0}ms baseline)",
+                  degradation.label, degradation.recent_p90_ms, self.config.recent_window_secs,
+                  degradation.ratio, degradation.baseline_p90_ms);
+        }
+        degraded
+    }
+
+    fn merged(by_hour: &[LatencyHistogram; 24]) -> LatencyHistogram {
+        let mut merged = LatencyHistogram { buckets: vec![0; BUCKET_BOUNDS_MS.len() + 1], ..Default::default() };
+        for histogram in by_hour.iter().filter(|histogram| histogram.count > 0) {
+            for (total, count) in merged.buckets.iter_mut().zip(&histogram.buckets) {
+                *total += count;
+            }
+            merged.count += histogram.count;
+            merged.errors += histogram.errors;
+            merged.sum_ms += histogram.sum_ms;
+            merged.max_ms = merged.max_ms.max(histogram.max_ms);
+        }
+        merged
+    }
+}
+
+/// Process-wide heatmap shared by all instrumented clients
+pub fn latency_heatmap() -> &'static LatencyHeatmap {
+    static HEATMAP: OnceLock<LatencyHeatmap> = OnceLock::new();
+    HEATMAP.get_or_init(|| LatencyHeatmap::new(LatencyHeatmapConfig::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_rows_split_by_source_and_hour() {
+        let heatmap = LatencyHeatmap::default();
+        let night = Utc.with_ymd_and_hms(2024, 5, 1, 3, 0, 0).unwrap();
+        let open = Utc.with_ymd_and_hms(2024, 5, 1, 14, 0, 0).unwrap();
+        for _ in 0..9 {
+            heatmap.record_at(LatencySource::rpc("helius", "getSlot"), Duration::from_millis(40), true, night);
+            heatmap.record_at(LatencySource::rpc("helius", "getSlot"), Duration::from_millis(300), true, open);
+        }
+        heatmap.record_at(LatencySource::rpc("helius", "getSlot"), Duration::from_millis(2_000), false, open);
+        heatmap.record_at(LatencySource::venue("jupiter", "quote"), Duration::from_millis(120), true, open);
+
+        let rows = heatmap.rows();
+        assert_eq!(rows.len(), 2);
+        let rpc = &rows[0];
+        assert_eq!(rpc.label, "rpc:helius:getSlot");
+        assert_eq!(rpc.by_hour.keys().copied().collect::<Vec<_>>(), vec![3, 14]);
+        // Quantiles report the bucket bound, capped at the slowest call seen
+        assert_eq!(rpc.by_hour[&3].p90_ms, 40.0);
+        assert_eq!(rpc.by_hour[&14].p50_ms, 400.0);
+        assert_eq!(rpc.by_hour[&14].max_ms, 2_000.0);
+        assert!((rpc.by_hour[&14].error_rate - 0.1).abs() < 1e-9);
+        assert_eq!(rpc.overall.count, 19);
+        assert_eq!(rows[1].label, "venue:jupiter:quote");
+    }
+
+    #[test]
+    fn test_recent_slowdown_flagged_against_baseline() {
+        let heatmap = LatencyHeatmap::new(LatencyHeatmapConfig { min_recent_samples: 5, ..Default::default() });
+        let source = LatencySource::rpc("quicknode", "sendTransaction");
+        let now = Utc::now();
+        for i in 0..100 {
+            heatmap.record_at(source.clone(), Duration::from_millis(80), true, now - chrono::Duration::hours(2) + chrono::Duration::seconds(i));
+        }
+        for i in 0..5 {
+            heatmap.record_at(source.clone(), Duration::from_millis(80), true, now - chrono::Duration::seconds(i));
+        }
+        assert!(heatmap.degradations_at(now).is_empty());
+
+        for i in 0..10 {
+            heatmap.record_at(source.clone(), Duration::from_millis(900), true, now - chrono::Duration::seconds(i));
+        }
+        let degraded = heatmap.degradations_at(now);
+        assert_eq!(degraded.len(), 1);
+        assert_eq!(degraded[0].recent_p90_ms, 900.0);
+        assert!(degraded[0].ratio >= 2.0);
+    }
+}
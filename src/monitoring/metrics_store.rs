@@ -0,0 +1,324 @@
+//! Embedded time-series store for service metrics
+//!
+//! `SystemMetrics`/`TradingMetrics` only describe the present. Historical
+//! charts in the API and TUI read from here instead, so no Prometheus is
+//! needed to answer "how did PnL or memory evolve over the last week".
+//!
+//! Every sample is folded into per-minute, hourly and daily rollups
+//! (count/sum/min/max/last), so downsampling is exact rather than an average
+//! of averages. Each resolution has its own retention: minutes for a couple of
+//! days, hours for months, days for years. The store is persisted as one JSON
+//! file (temp file + rename) and reloaded at startup. A process-wide store is
+//! available through `metrics_store()`.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::path::Path;
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+
+/// Default location of the persisted store
+pub const DEFAULT_METRICS_STORE_PATH: &str = "state/metrics_store.json";
+
+/// Bucket width of a series
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Resolution {
+    Minute,
+    Hour,
+    Day,
+}
+
+impl Resolution {
+    pub const ALL: [Resolution; 3] = [Resolution::Minute, Resolution::Hour, Resolution::Day];
+
+    pub fn bucket_secs(self) -> i64 {
+        match self {
+            Self::Minute => 60,
+            Self::Hour => 3_600,
+            Self::Day => 86_400,
+        }
+    }
+
+    /// Start of the bucket holding `at`
+    pub fn bucket_start(self, at: DateTime<Utc>) -> DateTime<Utc> {
+        let secs = at.timestamp();
+        let start = secs - secs.rem_euclid(self.bucket_secs());
+        Utc.timestamp_opt(start, 0).single().unwrap_or(at)
+    }
+}
+
+/// Retention per resolution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricsStoreConfig {
+    pub minute_retention_hours: u32,
+    pub hour_retention_days: u32,
+    pub day_retention_days: u32,
+    /// New metric names are ignored beyond this many series
+    pub max_series: usize,
+}
+
+impl Default for MetricsStoreConfig {
+    fn default() -> Self {
+        Self {
+            minute_retention_hours: 48,
+            hour_retention_days: 90,
+            day_retention_days: 730,
+            max_series: 256,
+        }
+    }
+}
+
+impl MetricsStoreConfig {
+    pub fn retention(&self, resolution: Resolution) -> Duration {
+        match resolution {
+            Resolution::Minute => Duration::hours(self.minute_retention_hours as i64),
+            Resolution::Hour => Duration::days(self.hour_retention_days as i64),
+            Resolution::Day => Duration::days(self.day_retention_days as i64),
+        }
+    }
+}
+
+/// Aggregate of the samples in one bucket
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rollup {
+    pub start: DateTime<Utc>,
+    pub count: u64,
+    pub sum: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Most recent sample in the bucket
+    pub last: f64,
+    pub last_at: DateTime<Utc>,
+}
+
+impl Rollup {
+    fn new(start: DateTime<Utc>, value: f64, at: DateTime<Utc>) -> Self {
+        Self { start, count: 1, sum: value, min: value, max: value, last: value, last_at: at }
+    }
+
+    fn add(&mut self, value: f64, at: DateTime<Utc>) {
+        self.count += 1;
+        self.sum += value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        if at >= self.last_at {
+            self.last = value;
+            self.last_at = at;
+        }
+    }
+
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 { 0.0 } else { self.sum / self.count as f64 }
+    }
+}
+
+/// One metric at every resolution, buckets oldest first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct Series {
+    minute: VecDeque<Rollup>,
+    hour: VecDeque<Rollup>,
+    day: VecDeque<Rollup>,
+}
+
+impl Series {
+    fn buckets(&self, resolution: Resolution) -> &VecDeque<Rollup> {
+        match resolution {
+            Resolution::Minute => &self.minute,
+            Resolution::Hour => &self.hour,
+            Resolution::Day => &self.day,
+        }
+    }
+
+    fn buckets_mut(&mut self, resolution: Resolution) -> &mut VecDeque<Rollup> {
+        match resolution {
+            Resolution::Minute => &mut self.minute,
+            Resolution::Hour => &mut self.hour,
+            Resolution::Day => &mut self.day,
+        }
+    }
+
+    fn record(&mut self, value: f64, at: DateTime<Utc>, config: &MetricsStoreConfig) {
+        for resolution in Resolution::ALL {
+            let start = resolution.bucket_start(at);
+            let buckets = self.buckets_mut(resolution);
+            // Samples almost always land in the newest bucket
+            match buckets.back_mut() {
+                Some(newest) if newest.start == start => newest.add(value, at),
+                Some(newest) if newest.start > start => match buckets.binary_search_by(|b| b.start.cmp(&start)) {
+                    Ok(index) => buckets[index].add(value, at),
+                    Err(index) => buckets.insert(index, Rollup::new(start, value, at)),
+                },
+                _ => buckets.push_back(Rollup::new(start, value, at)),
+            }
+            let cutoff = buckets.back().map(|newest| newest.start).unwrap_or(start) - config.retention(resolution);
+            while buckets.front().is_some_and(|oldest| oldest.start < cutoff) {
+                buckets.pop_front();
+            }
+        }
+    }
+}
+
+/// Latest state of a series, for listings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesInfo {
+    pub name: String,
+    pub last: f64,
+    pub last_at: DateTime<Utc>,
+    /// Oldest data at any resolution
+    pub since: DateTime<Utc>,
+}
+
+/// Time-series store with per-resolution retention
+#[derive(Debug, Default)]
+pub struct MetricsStore {
+    config: MetricsStoreConfig,
+    series: RwLock<BTreeMap<String, Series>>,
+}
+
+impl MetricsStore {
+    pub fn new(config: MetricsStoreConfig) -> Self {
+        Self { config, series: RwLock::new(BTreeMap::new()) }
+    }
+
+    pub fn record(&self, name: &str, value: f64, at: DateTime<Utc>) {
+        self.record_all(at, &[(name, value)]);
+    }
+
+    /// Record several metrics sampled at the same time
+    pub fn record_all(&self, at: DateTime<Utc>, samples: &[(&str, f64)]) {
+        let mut series = self.series.write();
+        for (name, value) in samples {
+            if !value.is_finite() {
+                continue;
+            }
+            if !series.contains_key(*name) && series.len() >= self.config.max_series {
+                continue;
+            }
+            series.entry(name.to_string()).or_default().record(*value, at, &self.config);
+        }
+    }
+
+    /// Buckets of `name` at `resolution` starting in `[since, until]`, oldest first
+    pub fn query(&self, name: &str, resolution: Resolution, since: DateTime<Utc>, until: DateTime<Utc>) -> Vec<Rollup> {
+        let series = self.series.read();
+        let Some(series) = series.get(name) else {
+            return Vec::new();
+        };
+        let since = resolution.bucket_start(since);
+        series.buckets(resolution).iter().filter(|b| b.start >= since && b.start <= until).cloned().collect()
+    }
+
+    /// Finest resolution whose retention still reaches back to `since`
+    pub fn resolution_for(&self, since: DateTime<Utc>, now: DateTime<Utc>) -> Resolution {
+        Resolution::ALL
+            .into_iter()
+            .find(|resolution| now - self.config.retention(*resolution) <= since)
+            .unwrap_or(Resolution::Day)
+    }
+
+    /// `name` from `since` until `now`, at the finest resolution that covers the range
+    pub fn history(&self, name: &str, since: DateTime<Utc>, now: DateTime<Utc>) -> (Resolution, Vec<Rollup>) {
+        let resolution = self.resolution_for(since, now);
+        (resolution, self.query(name, resolution, since, now))
+    }
+
+    pub fn series(&self) -> Vec<SeriesInfo> {
+        self.series
+            .read()
+            .iter()
+            .filter_map(|(name, series)| {
+                let newest = series.minute.back().or(series.hour.back())?;
+                let since = Resolution::ALL.iter().filter_map(|r| series.buckets(*r).front()).map(|b| b.start).min()?;
+                Some(SeriesInfo {
+                    name: name.clone(),
+                    last: newest.last,
+                    last_at: newest.last_at,
+                    since,
+                })
+            })
+            .collect()
+    }
+
+    /// Write the store to `path` (temp file, then rename)
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let json = serde_json::to_vec(&*self.series.read())?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, json)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    /// Replace the contents with the store saved at `path`; returns the number of series
+    pub fn load(&self, path: &Path) -> anyhow::Result<usize> {
+        let loaded: BTreeMap<String, Series> = serde_json::from_slice(&std::fs::read(path)?)?;
+        let count = loaded.len();
+        *self.series.write() = loaded;
+        Ok(count)
+    }
+}
+
+/// Process-wide store fed by the service and read by the API
+pub fn metrics_store() -> &'static MetricsStore {
+    static STORE: OnceLock<MetricsStore> = OnceLock::new();
+    STORE.get_or_init(MetricsStore::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 - 1_700_000_000 % 86_400 + secs, 0).unwrap()
+    }
+
+    #[test]
+    fn test_downsamples_exactly_and_expires_per_resolution() {
+        let store = MetricsStore::new(MetricsStoreConfig { minute_retention_hours: 1, ..MetricsStoreConfig::default() });
+        // One sample per minute for two hours, value = minute index
+        for minute in 0..120 {
+            store.record("pnl", minute as f64, at(minute * 60 + 5));
+        }
+        // A late sample still lands in its own bucket
+        store.record("pnl", 1_000.0, at(30));
+
+        let hours = store.query("pnl", Resolution::Hour, at(0), at(86_400));
+        assert_eq!(hours.len(), 2);
+        assert_eq!(hours[0].count, 61);
+        assert_eq!(hours[0].max, 1_000.0);
+        assert_eq!(hours[0].last, 59.0);
+        assert_eq!(hours[1].mean(), (60..120).sum::<i64>() as f64 / 60.0);
+        let day = store.query("pnl", Resolution::Day, at(0), at(86_400));
+        assert_eq!((day[0].count, day[0].min), (121, 0.0));
+
+        // Only the last hour of minute buckets is kept; the late sample's bucket expired
+        let minutes = store.query("pnl", Resolution::Minute, at(0), at(86_400));
+        assert_eq!(minutes.len(), 61);
+        assert_eq!(minutes[0].start, at(59 * 60));
+        assert_eq!(store.resolution_for(at(7_200 - 1_800), at(7_200)), Resolution::Minute);
+        let (resolution, points) = store.history("pnl", at(0), at(7_200));
+        assert_eq!((resolution, points.len()), (Resolution::Hour, 2));
+    }
+
+    #[test]
+    fn test_persists_and_reloads() {
+        let path = std::env::temp_dir().join(format!("metrics-store-{}.json", uuid::Uuid::new_v4()));
+        let store = MetricsStore::default();
+        store.record_all(at(0), &[("memory_mb", 512.0), ("running_bots", 3.0), ("bad", f64::NAN)]);
+        store.record("memory_mb", 640.0, at(90));
+        store.save(&path).unwrap();
+
+        let restored = MetricsStore::default();
+        assert_eq!(restored.load(&path).unwrap(), 2);
+        assert_eq!(restored.query("memory_mb", Resolution::Minute, at(0), at(600)), store.query("memory_mb", Resolution::Minute, at(0), at(600)));
+        let series = restored.series();
+        assert_eq!(series[0].name, "memory_mb");
+        assert_eq!((series[0].last, series[0].since), (640.0, at(0)));
+        std::fs::remove_file(path).ok();
+    }
+}
@@ -1,3 +1,23 @@
 pub mod enterprise_monitor;
+pub mod watchdog;
+pub mod supervisor;
+pub mod notifications;
+pub mod health;
+pub mod status_snapshot;
+pub mod latency_heatmap;
+pub mod webhook_emitter;
+pub mod self_test;
+pub mod metrics_store;
+pub mod diagnostics;
 
 pub use enterprise_monitor::*;
+pub use watchdog::*;
+pub use supervisor::*;
+pub use notifications::*;
+pub use health::{HealthRegistry, HealthProbe, HealthReport, HealthState, ComponentReport, FnProbe, RpcProbe, FeedProbe, WatchdogProbe, StorageProbe, NotificationProbe};
+pub use status_snapshot::{StatusPublisher, StatusSnapshot, BotStatusEntry, DEFAULT_STATUS_PATH};
+pub use latency_heatmap::{LatencyHeatmap, LatencyHeatmapConfig, LatencySource, LatencySummary, LatencyRow, LatencyDegradation, latency_heatmap};
+pub use webhook_emitter::{WebhookEmitter, WebhookConfig, WebhookEndpoint, WebhookEvent, WebhookEventKind, WebhookDelivery, WebhookTransport, HttpWebhookTransport, sign_payload, verify_signature};
+pub use self_test::{SelfTest, SelfTestConfig, SelfTestReport, DEFAULT_SELF_TEST_PATH};
+pub use metrics_store::{MetricsStore, MetricsStoreConfig, Resolution, Rollup, SeriesInfo, metrics_store, DEFAULT_METRICS_STORE_PATH};
+pub use diagnostics::{DiagnosticsCollector, DiagnosticsConfig, DiagnosticsManifest, DiagnosticsSummary, BundleEntry};
@@ -0,0 +1,408 @@
+//! Notification digest
+//!
+//! Sits between alert producers and notification sinks. Similar alerts
+//! (same fingerprint) within a window are grouped: the first one is sent
+//! immediately, repeats are counted and reported in a periodic digest
+//! instead of one message each. A condition that keeps firing is escalated
+//! one severity level per `escalate_after` it persists, and the escalation
+//! itself is sent immediately.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Mutex, RwLock};
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use super::enterprise_monitor::{Alert, Severity};
+
+/// Digest configuration
+#[derive(Debug, Clone)]
+pub struct DigestConfig {
+    /// Repeats within this window are folded into the next digest
+    pub window: Duration,
+    /// A condition still firing after this long is escalated one level (repeatable)
+    pub escalate_after: Duration,
+    /// A group with no new occurrence for this long is considered cleared
+    pub clear_after: Duration,
+    /// Maximum groups listed in one digest message
+    pub max_digest_entries: usize,
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_secs(300),
+            escalate_after: Duration::from_secs(900),
+            clear_after: Duration::from_secs(600),
+            max_digest_entries: 20,
+        }
+    }
+}
+
+/// What kind of message a notification is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NotificationKind {
+    /// First occurrence of a condition
+    Immediate,
+    /// Condition persisted long enough to raise its severity
+    Escalation,
+    /// Summary of folded repeats
+    Digest,
+}
+
+/// Message delivered to sinks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub kind: NotificationKind,
+    pub severity: Severity,
+    pub title: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Notification delivery channel
+#[async_trait]
+pub trait NotificationSink: Send + Sync {
+    fn name(&self) -> &str;
+    async fn send(&self, notification: &Notification) -> anyhow::Result<()>;
+}
+
+/// Writes notifications to the log
+#[derive(Debug, Default)]
+pub struct LogNotificationSink;
+
+#[async_trait]
+impl NotificationSink for LogNotificationSink {
+    fn name(&self) -> &str {
+        "log"
+    }
+
+    async fn send(&self, notification: &Notification) -> anyhow::Result<()> {
+        info!("📣 [{:?}/{:?}] {} - {}", notification.kind, notification.severity, notification.title, notification.body);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AlertGroup {
+    title: String,
+    severity: Severity,
+    first_seen: DateTime<Utc>,
+    last_seen: DateTime<Utc>,
+    last_escalated: DateTime<Utc>,
+    total: u64,
+    /// Occurrences folded since the last digest
+    pending: u64,
+}
+
+/// Digest statistics
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DigestStats {
+    pub received: u64,
+    pub sent_immediately: u64,
+    pub folded: u64,
+    pub escalations: u64,
+    pub digests_sent: u64,
+    pub active_groups: usize,
+}
+
+/// Delivery record of one sink
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SinkHealth {
+    pub name: String,
+    pub last_success: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub consecutive_failures: u32,
+}
+
+/// Group key: title with numbers masked, plus sorted tags
+///
+/// "CPU at 93%" and "CPU at 97%" land in the same group.
+pub fn alert_fingerprint(alert: &Alert) -> String {
+    let title: String = alert
+        .title
+        .chars()
+        .map(|c| if c.is_ascii_digit() { '#' } else { c.to_ascii_lowercase() })
+        .collect();
+    let mut tags = alert.tags.clone();
+    tags.sort();
+    format!("{}|{}", title, tags.join(","))
+}
+
+fn escalate(severity: Severity) -> Severity {
+    match severity {
+        Severity::Low => Severity::Medium,
+        Severity::Medium => Severity::High,
+        Severity::High | Severity::Critical => Severity::Critical,
+    }
+}
+
+/// Deduplicating, escalating notification dispatcher
+pub struct NotificationDigest {
+    config: DigestConfig,
+    groups: Mutex<HashMap<String, AlertGroup>>,
+    sinks: RwLock<Vec<Arc<dyn NotificationSink>>>,
+    stats: Mutex<DigestStats>,
+    sink_health: Mutex<HashMap<String, SinkHealth>>,
+}
+
+impl std::fmt::Debug for NotificationDigest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NotificationDigest").field("config", &self.config).finish_non_exhaustive()
+    }
+}
+
+impl NotificationDigest {
+    pub fn new(config: DigestConfig) -> Self {
+        Self {
+            config,
+            groups: Mutex::new(HashMap::new()),
+            sinks: RwLock::new(Vec::new()),
+            stats: Mutex::new(DigestStats::default()),
+            sink_health: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub async fn add_sink(&self, sink: Arc<dyn NotificationSink>) {
+        self.sinks.write().await.push(sink);
+    }
+
+    /// Route an alert through the digest
+    pub async fn submit(&self, alert: &Alert) {
+        self.submit_at(alert, Utc::now()).await;
+    }
+
+    async fn submit_at(&self, alert: &Alert, now: DateTime<Utc>) {
+        let key = alert_fingerprint(alert);
+        let clear_after = chrono::Duration::from_std(self.config.clear_after).unwrap_or_default();
+        let escalate_after = chrono::Duration::from_std(self.config.escalate_after).unwrap_or_default();
+
+        let notification = {
+            let mut groups = self.groups.lock().await;
+            let mut stats = self.stats.lock().await;
+            stats.received += 1;
+
+            match groups.get_mut(&key) {
+                Some(group) if now - group.last_seen < clear_after => {
+                    group.last_seen = now;
+                    group.total += 1;
+                    group.severity = group.severity.max(alert.severity);
+
+                    if now - group.last_escalated >= escalate_after && group.severity < Severity::Critical {
+                        group.severity = escalate(group.severity);
+                        group.last_escalated = now;
+                        stats.escalations += 1;
+                        let minutes = (now - group.first_seen).num_minutes();
+                        Some(Notification {
+                            kind: NotificationKind::Escalation,
+                            severity: group.severity,
+                            title: format!("ESCALATED: {}", alert.title),
+                            body: format!("Condition persisting for {} min ({} occurrences) - {}",
+                                          minutes, group.total, alert.description),
+                            created_at: now,
+                        })
+                    } else {
+                        group.pending += 1;
+                        stats.folded += 1;
+                        None
+                    }
+                }
+                _ => {
+                    groups.insert(key, AlertGroup {
+                        title: alert.title.clone(),
+                        severity: alert.severity,
+                        first_seen: now,
+                        last_seen: now,
+                        last_escalated: now,
+                        total: 1,
+                        pending: 0,
+                    });
+                    stats.sent_immediately += 1;
+                    Some(Notification {
+                        kind: NotificationKind::Immediate,
+                        severity: alert.severity,
+                        title: alert.title.clone(),
+                        body: alert.description.clone(),
+                        created_at: now,
+                    })
+                }
+            }
+        };
+
+        if let Some(notification) = notification {
+            self.dispatch(&notification).await;
+        }
+    }
+
+    /// Emit a digest of folded repeats and forget cleared conditions
+    pub async fn flush(&self) -> Option<Notification> {
+        self.flush_at(Utc::now()).await
+    }
+
+    async fn flush_at(&self, now: DateTime<Utc>) -> Option<Notification> {
+        let clear_after = chrono::Duration::from_std(self.config.clear_after).unwrap_or_default();
+        let notification = {
+            let mut groups = self.groups.lock().await;
+            groups.retain(|_, group| now - group.last_seen < clear_after || group.pending > 0);
+
+            let mut pending: Vec<&mut AlertGroup> = groups.values_mut().filter(|g| g.pending > 0).collect();
+            if pending.is_empty() {
+                None
+            } else {
+                pending.sort_by(|a, b| b.severity.cmp(&a.severity).then(b.pending.cmp(&a.pending)));
+                let severity = pending[0].severity;
+                let suppressed: u64 = pending.iter().map(|g| g.pending).sum();
+                let mut lines: Vec<String> = pending
+                    .iter()
+                    .take(self.config.max_digest_entries)
+                    .map(|g| format!("[{:?}] {} ×{} (last {})", g.severity, g.title, g.pending, g.last_seen.format("%H:%M:%S")))
+                    .collect();
+                if pending.len() > self.config.max_digest_entries {
+                    lines.push(format!("... and {} more", pending.len() - self.config.max_digest_entries));
+                }
+                let group_count = pending.len();
+                for group in pending {
+                    group.pending = 0;
+                }
+                Some(Notification {
+                    kind: NotificationKind::Digest,
+                    severity,
+                    title: format!("Alert digest: {} repeats across {} conditions", suppressed, group_count),
+                    body: lines.join("\n"),
+                    created_at: now,
+                })
+            }
+        };
+
+        let active_groups = self.groups.lock().await.len();
+        self.stats.lock().await.active_groups = active_groups;
+        if let Some(notification) = &notification {
+            self.stats.lock().await.digests_sent += 1;
+            self.dispatch(notification).await;
+        }
+        notification
+    }
+
+    async fn dispatch(&self, notification: &Notification) {
+        for sink in self.sinks.read().await.iter() {
+            let result = sink.send(notification).await;
+            let mut health = self.sink_health.lock().await;
+            let health = health.entry(sink.name().to_string()).or_insert_with(|| SinkHealth { name: sink.name().to_string(), ..Default::default() });
+            match result {
+                Ok(()) => {
+                    health.last_success = Some(Utc::now());
+                    health.consecutive_failures = 0;
+                }
+                Err(e) => {
+                    warn!("⚠️ Notification sink '{}' failed: {}", sink.name(), e);
+                    health.last_error = Some(e.to_string());
+                    health.consecutive_failures += 1;
+                }
+            }
+        }
+    }
+
+    /// Flush a digest every window
+    pub fn start(self: Arc<Self>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(self.config.window).await;
+                self.flush().await;
+            }
+        })
+    }
+
+    pub async fn get_stats(&self) -> DigestStats {
+        self.stats.lock().await.clone()
+    }
+
+    /// Delivery record of every sink that has been sent to, sorted by name
+    pub async fn sink_health(&self) -> Vec<SinkHealth> {
+        let mut health: Vec<SinkHealth> = self.sink_health.lock().await.values().cloned().collect();
+        health.sort_by(|a, b| a.name.cmp(&b.name));
+        health
+    }
+}
+
+impl Default for NotificationDigest {
+    fn default() -> Self {
+        Self::new(DigestConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitoring::enterprise_monitor::AlertStatus;
+
+    #[derive(Default)]
+    struct CollectingSink(std::sync::Mutex<Vec<Notification>>);
+
+    #[async_trait]
+    impl NotificationSink for CollectingSink {
+        fn name(&self) -> &str {
+            "collect"
+        }
+
+        async fn send(&self, notification: &Notification) -> anyhow::Result<()> {
+            self.0.lock().unwrap().push(notification.clone());
+            Ok(())
+        }
+    }
+
+    fn alert(title: &str, severity: Severity) -> Alert {
+        Alert {
+            id: uuid::Uuid::new_v4().to_string(),
+            title: title.to_string(),
+            description: "volatile market".to_string(),
+            severity,
+            status: AlertStatus::Open,
+            created_at: Utc::now(),
+            resolved_at: None,
+            tags: vec!["slippage".to_string()],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_repeats_are_folded_into_digest() {
+        let digest = NotificationDigest::default();
+        let sink = Arc::new(CollectingSink::default());
+        digest.add_sink(sink.clone()).await;
+
+        let now = Utc::now();
+        for i in 0..50 {
+            digest.submit_at(&alert(&format!("Slippage {}bps on SOL/USDC", 100 + i), Severity::Medium), now).await;
+        }
+        assert_eq!(sink.0.lock().unwrap().len(), 1);
+
+        let summary = digest.flush_at(now).await.expect("digest emitted");
+        assert!(summary.title.contains("49 repeats"));
+        assert!(digest.flush_at(now).await.is_none());
+        assert_eq!(sink.0.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_persistent_condition_escalates() {
+        let digest = NotificationDigest::new(DigestConfig {
+            escalate_after: Duration::from_secs(60),
+            ..Default::default()
+        });
+        let sink = Arc::new(CollectingSink::default());
+        digest.add_sink(sink.clone()).await;
+
+        let start = Utc::now();
+        for minute in 0..=2 {
+            digest.submit_at(&alert("RPC lagging", Severity::Low), start + chrono::Duration::seconds(minute * 61)).await;
+        }
+
+        let sent = sink.0.lock().unwrap();
+        assert_eq!(sent.len(), 3);
+        assert_eq!(sent[1].kind, NotificationKind::Escalation);
+        assert_eq!(sent[2].severity, Severity::High);
+    }
+}
@@ -0,0 +1,482 @@
+//! Startup self-test
+//!
+//! `sniperforge --self-test` checks every external dependency before the
+//! service starts: RPC connectivity and node version, the Jupiter quote API
+//! and price sources, the wallet keypair and its balance, state storage,
+//! clock skew against the RPC node, and the build-time hot-path constants
+//! (`types::hot_path`) against the accounts they name. Results print as a pass/fail matrix. A
+//! failed critical check refuses a MainNet start; on DevNet and in
+//! simulation it is reported and startup continues.
+//!
+//! Checks are [`HealthProbe`]s run through a [`HealthRegistry`], so probes
+//! shared with `/health` (storage) are reused and others can be added with
+//! [`SelfTest::with_probe`]. Thresholds, URLs and skipped checks come from
+//! `config/self_test.json`.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use solana_sdk::signer::Signer;
+use tracing::warn;
+
+use super::health::{ComponentReport, HealthProbe, HealthRegistry, HealthState, StorageProbe};
+use crate::config::SimpleConfig;
+use crate::types::constants::{SOL_MINT, USDC_MINT};
+use crate::types::{ComponentHealthStatus, TradingMode};
+
+/// Default location of the self-test configuration
+pub const DEFAULT_SELF_TEST_PATH: &str = "config/self_test.json";
+
+/// Self-test thresholds and endpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SelfTestConfig {
+    /// Oldest acceptable `solana-core` version of the RPC node
+    pub min_rpc_version: Option<String>,
+    pub jupiter_quote_url: String,
+    /// Price source name → URL answering with JSON
+    pub price_sources: BTreeMap<String, String>,
+    /// Below this the wallet check warns; an empty wallet fails
+    pub min_wallet_balance_sol: f64,
+    pub storage_dir: String,
+    pub max_clock_skew_secs: f64,
+    /// Per-check timeout
+    pub timeout_secs: u64,
+    /// Check names left out (e.g. `price:coingecko`)
+    pub skip: Vec<String>,
+}
+
+impl Default for SelfTestConfig {
+    fn default() -> Self {
+        Self {
+            min_rpc_version: Some("1.18.0".to_string()),
+            jupiter_quote_url: format!(
+                "https://quote-api.jup.ag/v6/quote?inputMint={}&outputMint={}&amount=1000000&slippageBps=50",
+                SOL_MINT, USDC_MINT
+            ),
+            price_sources: BTreeMap::from([
+                ("coingecko".to_string(), "https://api.coingecko.com/api/v3/simple/price?ids=solana&vs_currencies=usd".to_string()),
+                ("jupiter_price".to_string(), format!("https://price.jup.ag/v4/price?ids={}", SOL_MINT)),
+            ]),
+            min_wallet_balance_sol: 0.05,
+            storage_dir: "state".to_string(),
+            max_clock_skew_secs: 2.0,
+            timeout_secs: 10,
+            skip: Vec::new(),
+        }
+    }
+}
+
+impl SelfTestConfig {
+    /// Configuration from `path`; the defaults are used when the file is missing or invalid
+    pub fn load<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Self::default();
+        }
+        let parsed = std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| serde_json::from_str(&content).map_err(|e| e.to_string()));
+        parsed.unwrap_or_else(|e| {
+            warn!("⚠️ Invalid self-test config {}: {} - using defaults", path.display(), e);
+            Self::default()
+        })
+    }
+}
+
+/// Outcome of every check
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelfTestReport {
+    pub checked_at: DateTime<Utc>,
+    pub checks: Vec<ComponentReport>,
+}
+
+impl SelfTestReport {
+    pub fn critical_failures(&self) -> Vec<&ComponentReport> {
+        self.checks.iter().filter(|c| c.critical && c.status == HealthState::Unhealthy).collect()
+    }
+
+    /// No critical check failed (warnings and non-critical failures allowed)
+    pub fn passed(&self) -> bool {
+        self.critical_failures().is_empty()
+    }
+
+    /// Whether the service may start in `mode`; only MainNet is refused
+    pub fn allows_start(&self, mode: &TradingMode) -> bool {
+        self.passed() || *mode != TradingMode::MainNet
+    }
+
+    /// Pass/fail matrix for the terminal
+    pub fn matrix(&self) -> String {
+        let name_width = self.checks.iter().map(|c| c.name.len()).max().unwrap_or(0).max(5);
+        let mut out = format!("{:<name_width$}  {:<8}  {:<6}  {:>7}  DETAIL\n", "CHECK", "KIND", "RESULT", "MS");
+        for check in &self.checks {
+            let result = match check.status {
+                HealthState::Healthy => "PASS",
+                HealthState::Degraded => "WARN",
+                HealthState::Unhealthy if check.critical => "FAIL",
+                HealthState::Unhealthy => "fail",
+            };
+            out.push_str(&format!(
+                "{:<name_width$}  {:<8}  {:<6}  {:>7}  {}\n",
+                check.name, check.kind, result, check.latency_ms, check.detail.as_deref().unwrap_or("")
+            ));
+        }
+        let failures = self.critical_failures();
+        if failures.is_empty() {
+            out.push_str("Self-test passed");
+        } else {
+            let names: Vec<&str> = failures.iter().map(|c| c.name.as_str()).collect();
+            out.push_str(&format!("Self-test FAILED: {}", names.join(", ")));
+        }
+        out
+    }
+}
+
+/// Dependency checks run before startup
+pub struct SelfTest {
+    config: SelfTestConfig,
+    registry: HealthRegistry,
+}
+
+impl SelfTest {
+    /// No checks registered yet
+    pub fn new(config: SelfTestConfig) -> Self {
+        let registry = HealthRegistry::new(Duration::from_secs(config.timeout_secs.max(1)));
+        Self { config, registry }
+    }
+
+    /// RPC, Jupiter quote, price sources, wallet, storage, clock skew and hot-path constants
+    pub fn standard(config: SelfTestConfig, simple_config: &SimpleConfig) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs.max(1)))
+            .build()
+            .unwrap_or_default();
+        let rpc_url = simple_config.solana_rpc_url.clone();
+        let mut self_test = Self::new(config.clone())
+            .with_probe(Arc::new(RpcVersionProbe::new(rpc_url.clone(), config.min_rpc_version.clone())))
+            .with_probe(Arc::new(HttpJsonProbe::new("jupiter_quote", "api", true, &config.jupiter_quote_url, http.clone())))
+            .with_probe(Arc::new(WalletProbe::new(&simple_config.private_key_path, rpc_url.clone(), config.min_wallet_balance_sol)))
+            .with_probe(Arc::new(StorageProbe::new(config.storage_dir.clone())))
+            .with_probe(Arc::new(ClockSkewProbe::new(rpc_url.clone(), config.max_clock_skew_secs, http.clone())))
+            .with_probe(Arc::new(HotPathConstantsProbe::new(rpc_url)));
+        for (name, url) in &config.price_sources {
+            self_test = self_test.with_probe(Arc::new(HttpJsonProbe::new(&format!("price:{}", name), "prices", false, url, http.clone())));
+        }
+        self_test
+    }
+
+    /// Add a check, unless the configuration skips it
+    pub fn with_probe(self, probe: Arc<dyn HealthProbe>) -> Self {
+        if !self.config.skip.iter().any(|skipped| skipped == probe.name()) {
+            self.registry.register(probe);
+        }
+        self
+    }
+
+    /// Run every check concurrently
+    pub async fn run(&self) -> SelfTestReport {
+        let report = self.registry.check_all().await;
+        SelfTestReport { checked_at: report.checked_at, checks: report.components }
+    }
+}
+
+/// RPC round trip and node version
+pub struct RpcVersionProbe {
+    client: solana_client::nonblocking::rpc_client::RpcClient,
+    min_version: Option<String>,
+}
+
+impl RpcVersionProbe {
+    pub fn new(rpc_url: String, min_version: Option<String>) -> Self {
+        Self { client: solana_client::nonblocking::rpc_client::RpcClient::new(rpc_url), min_version }
+    }
+}
+
+#[async_trait]
+impl HealthProbe for RpcVersionProbe {
+    fn name(&self) -> &str {
+        "rpc"
+    }
+
+    fn kind(&self) -> &str {
+        "rpc"
+    }
+
+    async fn check(&self) -> ComponentHealthStatus {
+        let version = match self.client.get_version().await {
+            Ok(version) => version.solana_core,
+            Err(e) => return ComponentHealthStatus::Unhealthy(e.to_string()),
+        };
+        match &self.min_version {
+            Some(min) if !version_at_least(&version, min) => {
+                ComponentHealthStatus::Unhealthy(format!("node runs {}, {} or newer required", version, min))
+            }
+            _ => ComponentHealthStatus::Healthy,
+        }
+    }
+}
+
+/// HTTP endpoint answering 2xx with a JSON body
+pub struct HttpJsonProbe {
+    name: String,
+    kind: String,
+    critical: bool,
+    url: String,
+    client: reqwest::Client,
+}
+
+impl HttpJsonProbe {
+    pub fn new(name: &str, kind: &str, critical: bool, url: &str, client: reqwest::Client) -> Self {
+        Self { name: name.to_string(), kind: kind.to_string(), critical, url: url.to_string(), client }
+    }
+}
+
+#[async_trait]
+impl HealthProbe for HttpJsonProbe {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    fn critical(&self) -> bool {
+        self.critical
+    }
+
+    async fn check(&self) -> ComponentHealthStatus {
+        let response = match self.client.get(&self.url).send().await {
+            Ok(response) => response,
+            Err(e) => return ComponentHealthStatus::Unhealthy(e.to_string()),
+        };
+        if !response.status().is_success() {
+            return ComponentHealthStatus::Unhealthy(format!("HTTP {}", response.status()));
+        }
+        match response.json::<serde_json::Value>().await {
+            Ok(_) => ComponentHealthStatus::Healthy,
+            Err(e) => ComponentHealthStatus::Unhealthy(format!("invalid JSON: {}", e)),
+        }
+    }
+}
+
+/// Keypair file loads and the wallet can pay fees
+pub struct WalletProbe {
+    path: String,
+    client: solana_client::nonblocking::rpc_client::RpcClient,
+    min_balance_sol: f64,
+}
+
+impl WalletProbe {
+    pub fn new(path: &str, rpc_url: String, min_balance_sol: f64) -> Self {
+        Self {
+            path: path.to_string(),
+            client: solana_client::nonblocking::rpc_client::RpcClient::new(rpc_url),
+            min_balance_sol,
+        }
+    }
+}
+
+#[async_trait]
+impl HealthProbe for WalletProbe {
+    fn name(&self) -> &str {
+        "wallet"
+    }
+
+    fn kind(&self) -> &str {
+        "wallet"
+    }
+
+    async fn check(&self) -> ComponentHealthStatus {
+        let keypair = match solana_sdk::signature::read_keypair_file(&self.path) {
+            Ok(keypair) => keypair,
+            Err(e) => return ComponentHealthStatus::Unhealthy(format!("invalid keypair {}: {}", self.path, e)),
+        };
+        match self.client.get_balance(&keypair.pubkey()).await {
+            Ok(0) => ComponentHealthStatus::Unhealthy(format!("{} has no SOL for fees", keypair.pubkey())),
+            Ok(lamports) if (lamports as f64 / 1e9) < self.min_balance_sol => ComponentHealthStatus::Degraded(vec![format!(
+                "{} holds {:.4} SOL (< {:.4})",
+                keypair.pubkey(),
+                lamports as f64 / 1e9,
+                self.min_balance_sol
+            )]),
+            Ok(_) => ComponentHealthStatus::Healthy,
+            Err(e) => ComponentHealthStatus::Unhealthy(e.to_string()),
+        }
+    }
+}
+
+/// Local clock against the RPC node's `Date` header
+pub struct ClockSkewProbe {
+    rpc_url: String,
+    max_skew_secs: f64,
+    client: reqwest::Client,
+}
+
+impl ClockSkewProbe {
+    pub fn new(rpc_url: String, max_skew_secs: f64, client: reqwest::Client) -> Self {
+        Self { rpc_url, max_skew_secs, client }
+    }
+}
+
+#[async_trait]
+impl HealthProbe for ClockSkewProbe {
+    fn name(&self) -> &str {
+        "clock_skew"
+    }
+
+    fn kind(&self) -> &str {
+        "clock"
+    }
+
+    async fn check(&self) -> ComponentHealthStatus {
+        let sent = Utc::now();
+        let response = self.client
+            .post(&self.rpc_url)
+            .json(&serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": "getHealth" }))
+            .send()
+            .await;
+        let received = Utc::now();
+        let server_date = match response {
+            Ok(response) => response
+                .headers()
+                .get(reqwest::header::DATE)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+                .map(|date| date.with_timezone(&Utc)),
+            Err(e) => return ComponentHealthStatus::Unhealthy(e.to_string()),
+        };
+        let Some(server_date) = server_date else {
+            return ComponentHealthStatus::Degraded(vec!["RPC response has no Date header".to_string()]);
+        };
+        let skew = clock_skew_secs(sent, received, server_date);
+        if skew.abs() > self.max_skew_secs {
+            ComponentHealthStatus::Unhealthy(format!("local clock off by {:+.1}s (max {:.1}s)", skew, self.max_skew_secs))
+        } else {
+            ComponentHealthStatus::Healthy
+        }
+    }
+}
+
+/// Build-time token, program and market constants against the chain
+pub struct HotPathConstantsProbe {
+    client: solana_client::nonblocking::rpc_client::RpcClient,
+}
+
+impl HotPathConstantsProbe {
+    pub fn new(rpc_url: String) -> Self {
+        Self { client: solana_client::nonblocking::rpc_client::RpcClient::new(rpc_url) }
+    }
+}
+
+#[async_trait]
+impl HealthProbe for HotPathConstantsProbe {
+    fn name(&self) -> &str {
+        "hot_path_constants"
+    }
+
+    fn kind(&self) -> &str {
+        "config"
+    }
+
+    async fn check(&self) -> ComponentHealthStatus {
+        match crate::types::hot_path::verify_on_chain(&self.client).await {
+            Ok(report) if report.is_consistent() => ComponentHealthStatus::Healthy,
+            Ok(report) => ComponentHealthStatus::Unhealthy(format!(
+                "registry {} disagrees with the chain: {}",
+                report.fingerprint,
+                report.mismatches.iter().map(|m| format!("{} ({})", m.name, m.problem)).collect::<Vec<_>>().join(", ")
+            )),
+            Err(e) => ComponentHealthStatus::Unhealthy(e.to_string()),
+        }
+    }
+}
+
+/// Local clock minus server clock (seconds), from a request's send/receive times
+///
+/// The server's `Date` has one-second resolution and was stamped somewhere
+/// in the round trip, so differences inside that window count as zero.
+pub fn clock_skew_secs(sent: DateTime<Utc>, received: DateTime<Utc>, server_date: DateTime<Utc>) -> f64 {
+    let earliest = (server_date - sent).num_milliseconds() as f64 / 1000.0;
+    let latest = (server_date + chrono::Duration::seconds(1) - received).num_milliseconds() as f64 / 1000.0;
+    if earliest > 0.0 {
+        -earliest
+    } else if latest < 0.0 {
+        -latest
+    } else {
+        0.0
+    }
+}
+
+/// Dotted version comparison (`1.18.22` >= `1.18.0`); non-numeric suffixes are ignored
+pub fn version_at_least(version: &str, min: &str) -> bool {
+    let parse = |v: &str| -> Vec<u64> {
+        v.split('.')
+            .map(|part| part.chars().take_while(|c| c.is_ascii_digit()).collect::<String>().parse().unwrap_or(0))
+            .collect()
+    };
+    let (mut version, mut min) = (parse(version), parse(min));
+    let len = version.len().max(min.len());
+    version.resize(len, 0);
+    min.resize(len, 0);
+    version >= min
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitoring::FnProbe;
+
+    #[tokio::test]
+    async fn test_critical_failures_refuse_mainnet_only() {
+        let config = SelfTestConfig { skip: vec!["price:skipped".to_string()], ..SelfTestConfig::default() };
+        let self_test = SelfTest::new(config)
+            .with_probe(Arc::new(FnProbe::new("rpc", "rpc", true, || async { ComponentHealthStatus::Healthy })))
+            .with_probe(Arc::new(FnProbe::new("price:coingecko", "prices", false, || async {
+                ComponentHealthStatus::Unhealthy("HTTP 429".to_string())
+            })))
+            .with_probe(Arc::new(FnProbe::new("price:skipped", "prices", true, || async {
+                ComponentHealthStatus::Unhealthy("never runs".to_string())
+            })));
+
+        let report = self_test.run().await;
+        assert_eq!(report.checks.len(), 2);
+        assert!(report.passed());
+        assert!(report.allows_start(&TradingMode::MainNet));
+        assert!(report.matrix().contains("Self-test passed"));
+
+        let self_test = self_test.with_probe(Arc::new(FnProbe::new("wallet", "wallet", true, || async {
+            ComponentHealthStatus::Unhealthy("invalid keypair".to_string())
+        })));
+        let report = self_test.run().await;
+        assert!(!report.passed());
+        assert!(!report.allows_start(&TradingMode::MainNet));
+        assert!(report.allows_start(&TradingMode::DevNet));
+        let matrix = report.matrix();
+        assert!(matrix.contains("FAIL") && matrix.contains("invalid keypair"));
+        assert!(matrix.ends_with("Self-test FAILED: wallet"));
+    }
+
+    #[test]
+    fn test_version_and_clock_skew() {
+        assert!(version_at_least("1.18.22", "1.18.0"));
+        assert!(version_at_least("2.0.1", "1.18"));
+        assert!(!version_at_least("1.17.31", "1.18.0"));
+
+        let sent = DateTime::parse_from_rfc3339("2024-05-01T12:00:00.200Z").unwrap().with_timezone(&Utc);
+        let received = sent + chrono::Duration::milliseconds(300);
+        let date = |s: &str| DateTime::parse_from_rfc2822(s).unwrap().with_timezone(&Utc);
+        // Stamped within the round trip
+        assert_eq!(clock_skew_secs(sent, received, date("Wed, 01 May 2024 12:00:00 GMT")), 0.0);
+        // Server 5s ahead: the local clock is behind
+        assert!((clock_skew_secs(sent, received, date("Wed, 01 May 2024 12:00:05 GMT")) + 4.8).abs() < 1e-9);
+        // Server 5s behind
+        assert!((clock_skew_secs(sent, received, date("Wed, 01 May 2024 11:59:55 GMT")) - 4.5).abs() < 1e-9);
+    }
+}
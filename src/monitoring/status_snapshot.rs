@@ -0,0 +1,250 @@
+//! Local status snapshot for `sniperforge-cli status`
+//!
+//! The service periodically writes a small JSON file (write to a temp file,
+//! then rename, so readers never see a torn snapshot) with per-bot state,
+//! today's PnL, open positions and recent errors. The CLI reads the file
+//! directly: no control server, HTTP endpoint or log parsing involved, and a
+//! stale `generated_at` is itself the signal that the service is down.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+
+use crate::api::BotStatus;
+use crate::control::BotSummary;
+
+pub const DEFAULT_STATUS_PATH: &str = "state/status.json";
+/// Recent errors kept in the snapshot
+const MAX_ERRORS: usize = 20;
+
+/// One bot's line in `status`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BotStatusEntry {
+    pub id: String,
+    pub bot_type: String,
+    pub state: String,
+    /// `None` while the bot has no exchange rate to value its PnL in USD
+    pub pnl_today_usd: Option<f64>,
+    pub total_pnl_usd: Option<f64>,
+    pub open_positions: u64,
+    pub trades: u64,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorEntry {
+    pub at: DateTime<Utc>,
+    pub source: String,
+    pub message: String,
+}
+
+/// Everything `status` shows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusSnapshot {
+    pub generated_at: DateTime<Utc>,
+    pub pid: u32,
+    pub started_at: DateTime<Utc>,
+    /// UTC day `pnl_today_usd` refers to
+    pub day: NaiveDate,
+    pub pnl_today_usd: f64,
+    pub open_positions: u64,
+    pub trading_halted: Option<String>,
+    pub bots: Vec<BotStatusEntry>,
+    pub recent_errors: Vec<ErrorEntry>,
+    /// Per-bot total PnL at the start of `day`, carried across restarts
+    #[serde(default)]
+    pub day_baselines: HashMap<String, f64>,
+}
+
+impl StatusSnapshot {
+    pub fn age(&self, now: DateTime<Utc>) -> chrono::Duration {
+        now - self.generated_at
+    }
+
+    /// Read a snapshot written by [`StatusPublisher`]
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+}
+
+#[derive(Debug, Default)]
+struct PublisherState {
+    day: Option<NaiveDate>,
+    baselines: HashMap<String, f64>,
+    errors: VecDeque<ErrorEntry>,
+}
+
+/// Builds and writes snapshots from the running service
+pub struct StatusPublisher {
+    path: PathBuf,
+    started_at: DateTime<Utc>,
+    state: Mutex<PublisherState>,
+}
+
+impl StatusPublisher {
+    /// Today's PnL baselines are picked up from a previous snapshot at `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let mut state = PublisherState::default();
+        if let Ok(previous) = StatusSnapshot::read(&path) {
+            state.day = Some(previous.day);
+            state.baselines = previous.day_baselines;
+            state.errors = previous.recent_errors.into_iter().collect();
+        }
+        Self { path, started_at: Utc::now(), state: Mutex::new(state) }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn record_error(&self, source: &str, message: impl Into<String>) {
+        let mut state = self.state.lock();
+        state.errors.push_back(ErrorEntry { at: Utc::now(), source: source.to_string(), message: message.into() });
+        while state.errors.len() > MAX_ERRORS {
+            state.errors.pop_front();
+        }
+    }
+
+    /// Snapshot of `bots` at `now`; rolls the PnL baselines over at UTC midnight
+    pub fn build(&self, bots: &[BotSummary], trading_halted: Option<String>, now: DateTime<Utc>) -> StatusSnapshot {
+        let mut state = self.state.lock();
+        let today = now.date_naive();
+        if state.day != Some(today) {
+            state.day = Some(today);
+            state.baselines = bots
+                .iter()
+                .filter_map(|bot| Some((bot.id.to_string(), bot.metrics.trading.total_pnl_usd?)))
+                .collect();
+        }
+
+        let entries: Vec<BotStatusEntry> = bots
+            .iter()
+            .map(|bot| {
+                let id = bot.id.to_string();
+                let total = bot.metrics.trading.total_pnl_usd;
+                // Bots first seen (or first valued) today started the day at zero
+                let baseline = total.map(|_| *state.baselines.entry(id.clone()).or_insert(0.0));
+                let last_error = match &bot.status {
+                    BotStatus::Error(message) => Some(message.clone()),
+                    _ => None,
+                };
+                BotStatusEntry {
+                    id,
+                    bot_type: format!("{:?}", bot.bot_type),
+                    state: match &bot.status {
+                        BotStatus::Error(_) => "Error".to_string(),
+                        status => format!("{:?}", status),
+                    },
+                    pnl_today_usd: total.zip(baseline).map(|(total, baseline)| total - baseline),
+                    total_pnl_usd: total,
+                    open_positions: bot.metrics.custom.get("open_positions").and_then(|v| v.as_u64()).unwrap_or(0),
+                    trades: bot.metrics.trading.trades_executed,
+                    last_error,
+                }
+            })
+            .collect();
+
+        StatusSnapshot {
+            generated_at: now,
+            pid: std::process::id(),
+            started_at: self.started_at,
+            day: today,
+            pnl_today_usd: entries.iter().filter_map(|bot| bot.pnl_today_usd).sum(),
+            open_positions: entries.iter().map(|bot| bot.open_positions).sum(),
+            trading_halted,
+            bots: entries,
+            recent_errors: state.errors.iter().cloned().collect(),
+            day_baselines: state.baselines.clone(),
+        }
+    }
+
+    /// Write atomically: temp file in the same directory, then rename
+    pub async fn write(&self, snapshot: &StatusSnapshot) -> anyhow::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let tmp = self.path.with_extension("json.tmp");
+        tokio::fs::write(&tmp, serde_json::to_vec_pretty(snapshot)?).await?;
+        tokio::fs::rename(&tmp, &self.path).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{BotMetrics, BotType};
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    fn bot(id: Uuid, status: BotStatus, pnl: Option<f64>, open_positions: u64) -> BotSummary {
+        let mut metrics = BotMetrics::default();
+        metrics.trading.total_pnl_usd = pnl;
+        metrics.custom = serde_json::json!({ "open_positions": open_positions });
+        BotSummary { id, bot_type: BotType::EnhancedArbitrage, status, metrics, is_default: false }
+    }
+
+    #[test]
+    fn test_pnl_today_resets_at_utc_midnight() {
+        let dir = tempfile::tempdir().unwrap();
+        let publisher = StatusPublisher::new(dir.path().join("status.json"));
+        let id = Uuid::new_v4();
+        let morning = Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap();
+
+        publisher.build(&[bot(id, BotStatus::Running, Some(100.0), 1)], None, morning);
+        let evening = publisher.build(&[bot(id, BotStatus::Running, Some(130.0), 2)], None, morning + chrono::Duration::hours(12));
+        assert_eq!(evening.pnl_today_usd, 30.0);
+        assert_eq!(evening.open_positions, 2);
+
+        let next_day = publisher.build(&[bot(id, BotStatus::Running, Some(125.0), 0)], None, morning + chrono::Duration::hours(24));
+        assert_eq!(next_day.pnl_today_usd, 0.0);
+        assert_eq!(next_day.bots[0].total_pnl_usd, Some(125.0));
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_round_trips_and_baselines_survive_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state").join("status.json");
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let publisher = StatusPublisher::new(&path);
+        publisher.record_error("rpc", "timeout after 5s");
+        let snapshot = publisher.build(&[bot(id, BotStatus::Error("wallet locked".to_string()), Some(50.0), 0)], Some("drawdown".to_string()), now);
+        publisher.write(&snapshot).await.unwrap();
+
+        let read = StatusSnapshot::read(&path).unwrap();
+        assert_eq!(read.bots[0].state, "Error");
+        assert_eq!(read.bots[0].last_error.as_deref(), Some("wallet locked"));
+        assert_eq!(read.recent_errors[0].source, "rpc");
+        assert_eq!(read.trading_halted.as_deref(), Some("drawdown"));
+
+        // A restarted service keeps counting today's PnL from the same baseline
+        let restarted = StatusPublisher::new(&path);
+        let later = restarted.build(&[bot(id, BotStatus::Running, Some(65.0), 0)], None, now);
+        assert_eq!(later.pnl_today_usd, 15.0);
+        assert_eq!(later.recent_errors.len(), 1);
+    }
+
+    #[test]
+    fn test_bot_without_usd_rate_is_unavailable_not_zero() {
+        let dir = tempfile::tempdir().unwrap();
+        let publisher = StatusPublisher::new(dir.path().join("status.json"));
+        let (priced, unpriced) = (Uuid::new_v4(), Uuid::new_v4());
+        let now = Utc::now();
+
+        let snapshot = publisher.build(
+            &[bot(priced, BotStatus::Running, Some(40.0), 0), bot(unpriced, BotStatus::Running, None, 1)],
+            None,
+            now,
+        );
+        let entry = snapshot.bots.iter().find(|bot| bot.id == unpriced.to_string()).unwrap();
+        assert_eq!(entry.total_pnl_usd, None);
+        assert_eq!(entry.pnl_today_usd, None);
+        assert!(!snapshot.day_baselines.contains_key(&unpriced.to_string()));
+        assert_eq!(snapshot.pnl_today_usd, 0.0);
+    }
+}
@@ -0,0 +1,484 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use std::future::Future;
+use futures::future::BoxFuture;
+use tokio::sync::{watch, RwLock};
+use tokio::task::JoinHandle;
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+
+use super::watchdog::HeartbeatHandle;
+
+/// Restart policy for a supervised component
+#[derive(Debug, Clone)]
+pub struct RestartPolicy {
+    /// Restarts allowed within `window` before the component is failed
+    pub max_restarts: u32,
+    /// Sliding window for counting restarts
+    pub window: Duration,
+    /// Delay before each restart
+    pub backoff: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_restarts: 5,
+            window: Duration::from_secs(60),
+            backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Structured errors produced by the supervisor
+#[derive(Debug, Clone, thiserror::Error, Serialize, Deserialize)]
+pub enum SupervisorError {
+    #[error("component '{component}' panicked: {message}")]
+    Panicked { component: String, message: String },
+
+    #[error("component '{component}' failed: {message}")]
+    Failed { component: String, message: String },
+
+    #[error("component '{component}' stalled: no heartbeat for {seconds:.1}s")]
+    Stalled { component: String, seconds: f64 },
+
+    #[error("component '{component}' exceeded {max_restarts} restarts in {window_seconds}s")]
+    RestartBudgetExhausted { component: String, max_restarts: u32, window_seconds: u64 },
+
+    #[error("component '{component}' depends on unknown component '{dependency}'")]
+    UnknownDependency { component: String, dependency: String },
+
+    #[error("dependency cycle between components: {0}")]
+    DependencyCycle(String),
+
+    #[error("component '{component}' not started: dependency '{dependency}' never became ready")]
+    DependencyNotReady { component: String, dependency: String },
+}
+
+/// Lifecycle state of a supervised component
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ComponentState {
+    Pending,
+    Running,
+    Restarting,
+    Completed,
+    Failed,
+    Stopped,
+}
+
+/// Handed to a component's run function on every (re)start
+#[derive(Debug, Clone)]
+pub struct ComponentContext {
+    pub name: String,
+    pub heartbeat: HeartbeatHandle,
+    ready: Arc<watch::Sender<bool>>,
+}
+
+impl ComponentContext {
+    /// Signal dependents that this component is up (e.g. first feed update done)
+    pub fn mark_ready(&self) {
+        self.ready.send_replace(true);
+    }
+}
+
+/// Async body of a supervised component; re-invoked on restart
+type ComponentFn = Arc<dyn Fn(ComponentContext) -> BoxFuture<'static, anyhow::Result<()>> + Send + Sync>;
+
+/// Declaration of a supervised component
+#[derive(Clone)]
+pub struct ComponentSpec {
+    pub name: String,
+    pub depends_on: Vec<String>,
+    pub restart_policy: RestartPolicy,
+    /// Restart the component if it stops sending heartbeats for this long
+    pub heartbeat_timeout: Option<Duration>,
+    run: ComponentFn,
+}
+
+impl std::fmt::Debug for ComponentSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComponentSpec")
+            .field("name", &self.name)
+            .field("depends_on", &self.depends_on)
+            .field("restart_policy", &self.restart_policy)
+            .field("heartbeat_timeout", &self.heartbeat_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ComponentSpec {
+    /// Declare a component; `run` is called again on every restart
+    pub fn new<F, Fut>(name: &str, run: F) -> Self
+    where
+        F: Fn(ComponentContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        Self {
+            name: name.to_string(),
+            depends_on: Vec::new(),
+            restart_policy: RestartPolicy::default(),
+            heartbeat_timeout: None,
+            run: Arc::new(move |ctx| Box::pin(run(ctx))),
+        }
+    }
+
+    pub fn depends_on(mut self, dependencies: &[&str]) -> Self {
+        self.depends_on.extend(dependencies.iter().map(|d| d.to_string()));
+        self
+    }
+
+    pub fn with_restart_policy(mut self, policy: RestartPolicy) -> Self {
+        self.restart_policy = policy;
+        self
+    }
+
+    pub fn with_heartbeat_timeout(mut self, timeout: Duration) -> Self {
+        self.heartbeat_timeout = Some(timeout);
+        self
+    }
+}
+
+/// Externally visible status of a component
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentStatus {
+    pub name: String,
+    pub state: ComponentState,
+    pub restarts: u32,
+    pub last_error: Option<SupervisorError>,
+    pub started_at: Option<DateTime<Utc>>,
+}
+
+type StatusMap = Arc<RwLock<HashMap<String, ComponentStatus>>>;
+
+/// Supervisor tree: each component runs in its own task, started in
+/// dependency order and restarted on panic/error per its restart policy
+pub struct Supervisor {
+    specs: Vec<ComponentSpec>,
+    status: StatusMap,
+    ready: HashMap<String, watch::Receiver<bool>>,
+    handles: Vec<JoinHandle<()>>,
+    shutdown: watch::Sender<bool>,
+    /// How long to wait for a dependency to become ready
+    ready_timeout: Duration,
+    epoch: Instant,
+}
+
+impl std::fmt::Debug for Supervisor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Supervisor")
+            .field("specs", &self.specs)
+            .field("ready_timeout", &self.ready_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Supervisor {
+    pub fn new(ready_timeout: Duration) -> Self {
+        let (shutdown, _) = watch::channel(false);
+        Self {
+            specs: Vec::new(),
+            status: Arc::new(RwLock::new(HashMap::new())),
+            ready: HashMap::new(),
+            handles: Vec::new(),
+            shutdown,
+            ready_timeout,
+            epoch: Instant::now(),
+        }
+    }
+
+    /// Add a component to the tree
+    pub fn add(&mut self, spec: ComponentSpec) -> &mut Self {
+        self.specs.push(spec);
+        self
+    }
+
+    /// Start order honoring dependencies (ties keep registration order)
+    pub fn start_order(&self) -> Result<Vec<String>, SupervisorError> {
+        let names: HashSet<&str> = self.specs.iter().map(|s| s.name.as_str()).collect();
+        for spec in &self.specs {
+            if let Some(missing) = spec.depends_on.iter().find(|d| !names.contains(d.as_str())) {
+                return Err(SupervisorError::UnknownDependency {
+                    component: spec.name.clone(),
+                    dependency: missing.clone(),
+                });
+            }
+        }
+
+        let mut order: Vec<String> = Vec::with_capacity(self.specs.len());
+        let mut remaining: Vec<&ComponentSpec> = self.specs.iter().collect();
+        while !remaining.is_empty() {
+            let Some(index) = remaining
+                .iter()
+                .position(|spec| spec.depends_on.iter().all(|d| order.contains(d)))
+            else {
+                let cycle: Vec<&str> = remaining.iter().map(|s| s.name.as_str()).collect();
+                return Err(SupervisorError::DependencyCycle(cycle.join(", ")));
+            };
+            order.push(remaining.remove(index).name.clone());
+        }
+        Ok(order)
+    }
+
+    /// Start every component, waiting for dependencies to become ready first
+    pub async fn start(&mut self) -> Result<(), SupervisorError> {
+        let order = self.start_order()?;
+        let specs: HashMap<String, ComponentSpec> = self.specs.iter().map(|s| (s.name.clone(), s.clone())).collect();
+
+        for name in order {
+            let spec = specs[&name].clone();
+            for dependency in &spec.depends_on {
+                let mut ready = self.ready[dependency].clone();
+                let became_ready = tokio::time::timeout(self.ready_timeout, ready.wait_for(|r| *r)).await;
+                if !matches!(became_ready, Ok(Ok(_))) {
+                    return Err(SupervisorError::DependencyNotReady {
+                        component: name.clone(),
+                        dependency: dependency.clone(),
+                    });
+                }
+            }
+
+            let (ready_tx, ready_rx) = watch::channel(false);
+            self.ready.insert(name.clone(), ready_rx);
+            self.status.write().await.insert(name.clone(), ComponentStatus {
+                name: name.clone(),
+                state: ComponentState::Pending,
+                restarts: 0,
+                last_error: None,
+                started_at: None,
+            });
+
+            tracing::info!("🌳 Supervisor starting component '{}'", name);
+            self.handles.push(tokio::spawn(supervise(
+                spec,
+                Arc::new(ready_tx),
+                Arc::clone(&self.status),
+                self.shutdown.subscribe(),
+                self.epoch,
+            )));
+        }
+        Ok(())
+    }
+
+    /// Status of every component
+    pub async fn status(&self) -> Vec<ComponentStatus> {
+        let status = self.status.read().await;
+        self.specs.iter().filter_map(|s| status.get(&s.name).cloned()).collect()
+    }
+
+    /// Whether a component is currently ready
+    pub fn is_ready(&self, name: &str) -> bool {
+        self.ready.get(name).map_or(false, |r| *r.borrow())
+    }
+
+    /// Stop all components
+    pub async fn shutdown(&mut self) {
+        self.shutdown.send_replace(true);
+        for handle in self.handles.drain(..) {
+            let _ = handle.await;
+        }
+        tracing::info!("🌳 Supervisor stopped all components");
+    }
+}
+
+async fn set_state(status: &StatusMap, name: &str, state: ComponentState, error: Option<SupervisorError>) {
+    if let Some(entry) = status.write().await.get_mut(name) {
+        entry.state = state;
+        if state == ComponentState::Running {
+            entry.started_at = Some(Utc::now());
+        }
+        if state == ComponentState::Restarting {
+            entry.restarts += 1;
+        }
+        if error.is_some() {
+            entry.last_error = error;
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic payload".to_string())
+}
+
+/// Run a component, converting panics/errors/stalls into restarts
+async fn supervise(
+    spec: ComponentSpec,
+    ready: Arc<watch::Sender<bool>>,
+    status: StatusMap,
+    mut shutdown: watch::Receiver<bool>,
+    epoch: Instant,
+) {
+    let mut restart_times: VecDeque<Instant> = VecDeque::new();
+    let stall_check = spec.heartbeat_timeout.map_or(Duration::from_secs(3600), |t| (t / 2).max(Duration::from_millis(10)));
+
+    loop {
+        let heartbeat = HeartbeatHandle::new(epoch);
+        let context = ComponentContext {
+            name: spec.name.clone(),
+            heartbeat: heartbeat.clone(),
+            ready: Arc::clone(&ready),
+        };
+        set_state(&status, &spec.name, ComponentState::Running, None).await;
+        let mut child = tokio::spawn((spec.run)(context));
+
+        let error = loop {
+            tokio::select! {
+                outcome = &mut child => break match outcome {
+                    Ok(Ok(())) => None,
+                    Ok(Err(e)) => Some(SupervisorError::Failed { component: spec.name.clone(), message: format!("{:#}", e) }),
+                    Err(join) if join.is_panic() => Some(SupervisorError::Panicked {
+                        component: spec.name.clone(),
+                        message: panic_message(join.into_panic()),
+                    }),
+                    Err(_) => None,
+                },
+                _ = shutdown.changed() => {
+                    child.abort();
+                    set_state(&status, &spec.name, ComponentState::Stopped, None).await;
+                    return;
+                }
+                _ = tokio::time::sleep(stall_check) => {
+                    let since = heartbeat.since_last_beat();
+                    if spec.heartbeat_timeout.map_or(false, |t| since > t) {
+                        child.abort();
+                        break Some(SupervisorError::Stalled { component: spec.name.clone(), seconds: since.as_secs_f64() });
+                    }
+                }
+            }
+        };
+
+        ready.send_replace(false);
+        let Some(error) = error else {
+            tracing::info!("🌳 Component '{}' completed", spec.name);
+            set_state(&status, &spec.name, ComponentState::Completed, None).await;
+            return;
+        };
+
+        let now = Instant::now();
+        while restart_times.front().map_or(false, |t| now.duration_since(*t) > spec.restart_policy.window) {
+            restart_times.pop_front();
+        }
+        if restart_times.len() >= spec.restart_policy.max_restarts as usize {
+            let exhausted = SupervisorError::RestartBudgetExhausted {
+                component: spec.name.clone(),
+                max_restarts: spec.restart_policy.max_restarts,
+                window_seconds: spec.restart_policy.window.as_secs(),
+            };
+            tracing::error!("❌ {} (last error: {})", exhausted, error);
+            set_state(&status, &spec.name, ComponentState::Failed, Some(error)).await;
+            return;
+        }
+
+        tracing::warn!("🔄 {} - restarting in {:?}", error, spec.restart_policy.backoff);
+        restart_times.push_back(now);
+        set_state(&status, &spec.name, ComponentState::Restarting, Some(error)).await;
+        tokio::time::sleep(spec.restart_policy.backoff).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn idle(name: &str) -> ComponentSpec {
+        ComponentSpec::new(name, |ctx: ComponentContext| async move {
+            ctx.mark_ready();
+            futures::future::pending::<()>().await;
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_start_order_and_cycles() {
+        let mut supervisor = Supervisor::new(Duration::from_secs(1));
+        supervisor.add(idle("engine").depends_on(&["feed"])).add(idle("feed"));
+        assert_eq!(supervisor.start_order().unwrap(), vec!["feed".to_string(), "engine".to_string()]);
+
+        let mut cyclic = Supervisor::new(Duration::from_secs(1));
+        cyclic.add(idle("a").depends_on(&["b"])).add(idle("b").depends_on(&["a"]));
+        assert!(matches!(cyclic.start_order(), Err(SupervisorError::DependencyCycle(_))));
+    }
+
+    #[tokio::test]
+    async fn test_panic_restarts_until_budget_exhausted() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let counter = attempts.clone();
+        let policy = RestartPolicy { max_restarts: 2, window: Duration::from_secs(60), backoff: Duration::from_millis(1) };
+
+        let mut supervisor = Supervisor::new(Duration::from_secs(1));
+        supervisor.add(ComponentSpec::new("flaky", move |_ctx: ComponentContext| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move { panic!("engine exploded") }
+        }).with_restart_policy(policy));
+        supervisor.start().await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let status = supervisor.status().await;
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert_eq!(status[0].state, ComponentState::Failed);
+        assert!(matches!(&status[0].last_error, Some(SupervisorError::Panicked { message, .. }) if message == "engine exploded"));
+    }
+
+    #[tokio::test]
+    async fn test_failing_component_backs_off_until_budget_exhausted() {
+        let attempts = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let started = attempts.clone();
+        let backoff = Duration::from_millis(30);
+        let policy = RestartPolicy { max_restarts: 2, window: Duration::from_secs(60), backoff };
+
+        let mut supervisor = Supervisor::new(Duration::from_secs(1));
+        supervisor.add(ComponentSpec::new("rpc_feed", move |_ctx: ComponentContext| {
+            started.lock().push(Instant::now());
+            async move { Err(anyhow::anyhow!("rpc down")) }
+        }).with_restart_policy(policy));
+        supervisor.start().await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        let attempts = attempts.lock().clone();
+        assert_eq!(attempts.len(), 3, "two restarts, then the budget is spent");
+        assert!(attempts.windows(2).all(|pair| pair[1] - pair[0] >= backoff));
+        let status = supervisor.status().await;
+        assert_eq!(status[0].state, ComponentState::Failed);
+        assert!(matches!(&status[0].last_error, Some(SupervisorError::Failed { message, .. }) if message == "rpc down"));
+    }
+
+    #[tokio::test]
+    async fn test_restarts_outside_window_do_not_exhaust_budget() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let counter = attempts.clone();
+        // Every failure arrives after the previous restart has left the window
+        let policy = RestartPolicy { max_restarts: 1, window: Duration::from_millis(5), backoff: Duration::from_millis(20) };
+
+        let mut supervisor = Supervisor::new(Duration::from_secs(1));
+        supervisor.add(ComponentSpec::new("rpc_feed", move |_ctx: ComponentContext| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move { Err(anyhow::anyhow!("rpc down")) }
+        }).with_restart_policy(policy));
+        supervisor.start().await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(attempts.load(Ordering::SeqCst) > 2);
+        assert_ne!(supervisor.status().await[0].state, ComponentState::Failed);
+        supervisor.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_dependents_wait_for_ready_feed() {
+        let mut supervisor = Supervisor::new(Duration::from_millis(50));
+        supervisor
+            .add(ComponentSpec::new("feed", |_ctx: ComponentContext| async move {
+                futures::future::pending::<()>().await;
+                Ok(())
+            }))
+            .add(idle("engine").depends_on(&["feed"]));
+
+        let result = supervisor.start().await;
+        assert!(matches!(result, Err(SupervisorError::DependencyNotReady { .. })));
+        supervisor.shutdown().await;
+    }
+}
@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use serde::{Deserialize, Serialize};
+use chrono::Utc;
+
+use super::enterprise_monitor::{Alert, AlertManager, AlertStatus, Severity};
+
+/// Liveness watchdog configuration
+#[derive(Debug, Clone)]
+pub struct WatchdogConfig {
+    /// How often registered tasks are checked
+    pub check_interval: Duration,
+    /// Restart attempts before a task is declared failed
+    pub max_restart_attempts: u32,
+    /// A task that stays healthy this long gets its restart counter reset
+    pub restart_reset_after: Duration,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            check_interval: Duration::from_secs(5),
+            max_restart_attempts: 3,
+            restart_reset_after: Duration::from_secs(300),
+        }
+    }
+}
+
+/// Liveness state of a supervised task
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TaskLiveness {
+    Healthy,
+    /// Heartbeat overdue
+    Stalled,
+    /// Task exited or panicked
+    Exited,
+    /// Restart budget exhausted
+    Failed,
+}
+
+/// Cheap, cloneable heartbeat sender handed to a supervised task
+#[derive(Debug, Clone)]
+pub struct HeartbeatHandle {
+    epoch: Instant,
+    last_beat_ms: Arc<AtomicU64>,
+}
+
+impl HeartbeatHandle {
+    pub(crate) fn new(epoch: Instant) -> Self {
+        let handle = Self {
+            epoch,
+            last_beat_ms: Arc::new(AtomicU64::new(0)),
+        };
+        handle.beat();
+        handle
+    }
+
+    /// Signal that the task is alive and making progress
+    pub fn beat(&self) {
+        self.last_beat_ms.store(self.epoch.elapsed().as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Time since the last heartbeat
+    pub fn since_last_beat(&self) -> Duration {
+        let now = self.epoch.elapsed().as_millis() as u64;
+        Duration::from_millis(now.saturating_sub(self.last_beat_ms.load(Ordering::Relaxed)))
+    }
+}
+
+/// Spawns (or respawns) a supervised task
+pub type TaskFactory = Arc<dyn Fn(HeartbeatHandle) -> JoinHandle<()> + Send + Sync>;
+
+/// Externally visible health of a supervised task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskHealth {
+    pub name: String,
+    pub liveness: TaskLiveness,
+    pub restarts: u32,
+    pub seconds_since_heartbeat: f64,
+    pub last_error: Option<String>,
+}
+
+struct WatchedTask {
+    /// `None` = only exit/panic is monitored (task cannot emit heartbeats)
+    heartbeat_timeout: Option<Duration>,
+    factory: TaskFactory,
+    heartbeat: HeartbeatHandle,
+    handle: JoinHandle<()>,
+    liveness: TaskLiveness,
+    restarts: u32,
+    healthy_since: Instant,
+    last_error: Option<String>,
+}
+
+/// Heartbeat-based liveness watchdog with supervised restart
+pub struct TaskWatchdog {
+    config: WatchdogConfig,
+    epoch: Instant,
+    tasks: RwLock<HashMap<String, WatchedTask>>,
+    alert_manager: Option<Arc<AlertManager>>,
+}
+
+impl std::fmt::Debug for TaskWatchdog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskWatchdog")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TaskWatchdog {
+    pub fn new(config: WatchdogConfig) -> Self {
+        Self {
+            config,
+            epoch: Instant::now(),
+            tasks: RwLock::new(HashMap::new()),
+            alert_manager: None,
+        }
+    }
+
+    /// Route restart failures to the enterprise alert manager
+    pub fn with_alert_manager(mut self, alert_manager: Arc<AlertManager>) -> Self {
+        self.alert_manager = Some(alert_manager);
+        self
+    }
+
+    /// Spawn a task under watchdog supervision
+    pub async fn register(&self, name: &str, heartbeat_timeout: Option<Duration>, factory: TaskFactory) {
+        let heartbeat = HeartbeatHandle::new(self.epoch);
+        let handle = factory(heartbeat.clone());
+        let task = WatchedTask {
+            heartbeat_timeout,
+            factory,
+            heartbeat,
+            handle,
+            liveness: TaskLiveness::Healthy,
+            restarts: 0,
+            healthy_since: Instant::now(),
+            last_error: None,
+        };
+
+        if let Some(previous) = self.tasks.write().await.insert(name.to_string(), task) {
+            previous.handle.abort();
+        }
+        tracing::info!("🐕 Watchdog supervising task '{}'", name);
+    }
+
+    /// Stop supervising a task and abort it
+    pub async fn unregister(&self, name: &str) {
+        if let Some(task) = self.tasks.write().await.remove(name) {
+            task.handle.abort();
+        }
+    }
+
+    /// Run one liveness check over all tasks; returns tasks restarted
+    pub async fn check(&self) -> Vec<String> {
+        let mut restarted = Vec::new();
+        let mut failed = Vec::new();
+        let mut tasks = self.tasks.write().await;
+
+        for (name, task) in tasks.iter_mut() {
+            if task.liveness == TaskLiveness::Failed {
+                continue;
+            }
+
+            let stalled = task
+                .heartbeat_timeout
+                .map_or(false, |timeout| task.heartbeat.since_last_beat() > timeout);
+            let problem = if crate::chaos::faults().take_kill(name) {
+                Some((TaskLiveness::Exited, "killed by fault injection".to_string()))
+            } else if task.handle.is_finished() {
+                Some((TaskLiveness::Exited, "task exited or panicked".to_string()))
+            } else if stalled {
+                Some((TaskLiveness::Stalled, format!("no heartbeat for {:.1}s", task.heartbeat.since_last_beat().as_secs_f64())))
+            } else {
+                None
+            };
+
+            let Some((liveness, reason)) = problem else {
+                if task.restarts > 0 && task.healthy_since.elapsed() >= self.config.restart_reset_after {
+                    task.restarts = 0;
+                }
+                continue;
+            };
+
+            tracing::warn!("🐕 Task '{}' unhealthy: {}", name, reason);
+            task.liveness = liveness;
+            task.last_error = Some(reason.clone());
+
+            if task.restarts >= self.config.max_restart_attempts {
+                task.liveness = TaskLiveness::Failed;
+                task.handle.abort();
+                tracing::error!("❌ Task '{}' failed after {} restart attempts", name, task.restarts);
+                failed.push((name.clone(), reason));
+                continue;
+            }
+
+            task.handle.abort();
+            task.heartbeat = HeartbeatHandle::new(self.epoch);
+            task.handle = (task.factory)(task.heartbeat.clone());
+            task.restarts += 1;
+            task.liveness = TaskLiveness::Healthy;
+            task.healthy_since = Instant::now();
+            tracing::info!("🔄 Task '{}' restarted (attempt {}/{})", name, task.restarts, self.config.max_restart_attempts);
+            restarted.push(name.clone());
+        }
+        drop(tasks);
+
+        for (name, reason) in failed {
+            self.alert_restart_failure(&name, &reason).await;
+        }
+        restarted
+    }
+
+    /// Run the check loop in the background
+    pub fn start(self: Arc<Self>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(self.config.check_interval).await;
+                self.check().await;
+            }
+        })
+    }
+
+    /// Health of every supervised task
+    pub async fn get_task_health(&self) -> Vec<TaskHealth> {
+        let tasks = self.tasks.read().await;
+        let mut health: Vec<TaskHealth> = tasks
+            .iter()
+            .map(|(name, task)| TaskHealth {
+                name: name.clone(),
+                liveness: task.liveness,
+                restarts: task.restarts,
+                seconds_since_heartbeat: task.heartbeat.since_last_beat().as_secs_f64(),
+                last_error: task.last_error.clone(),
+            })
+            .collect();
+        health.sort_by(|a, b| a.name.cmp(&b.name));
+        health
+    }
+
+    async fn alert_restart_failure(&self, name: &str, reason: &str) {
+        let Some(alert_manager) = &self.alert_manager else {
+            return;
+        };
+        alert_manager
+            .raise_alert(Alert {
+                id: uuid::Uuid::new_v4().to_string(),
+                title: format!("Task '{}' could not be restarted", name),
+                description: format!("{} (restart budget of {} exhausted)", reason, self.config.max_restart_attempts),
+                severity: Severity::Critical,
+                status: AlertStatus::Open,
+                created_at: Utc::now(),
+                resolved_at: None,
+                tags: vec!["watchdog".to_string(), name.to_string()],
+            })
+            .await;
+    }
+}
+
+impl Default for TaskWatchdog {
+    fn default() -> Self {
+        Self::new(WatchdogConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+
+    fn counting_factory(spawns: Arc<AtomicU32>, beat: bool) -> TaskFactory {
+        Arc::new(move |heartbeat: HeartbeatHandle| {
+            spawns.fetch_add(1, Ordering::SeqCst);
+            tokio::spawn(async move {
+                loop {
+                    if beat {
+                        heartbeat.beat();
+                    }
+                    tokio::time::sleep(Duration::from_millis(5)).await;
+                }
+            })
+        })
+    }
+
+    #[tokio::test]
+    async fn test_stalled_task_is_restarted() {
+        let watchdog = TaskWatchdog::default();
+        let spawns = Arc::new(AtomicU32::new(0));
+        watchdog.register("silent", Some(Duration::from_millis(20)), counting_factory(spawns.clone(), false)).await;
+        watchdog.register("chatty", Some(Duration::from_millis(20)), counting_factory(Arc::new(AtomicU32::new(0)), true)).await;
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let restarted = watchdog.check().await;
+
+        assert_eq!(restarted, vec!["silent".to_string()]);
+        assert_eq!(spawns.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_restarts_raise_alert() {
+        let alerts = Arc::new(AlertManager::new());
+        let watchdog = TaskWatchdog::new(WatchdogConfig { max_restart_attempts: 1, ..Default::default() })
+            .with_alert_manager(alerts.clone());
+        watchdog.register("crasher", None, Arc::new(|_| tokio::spawn(async { panic!("boom") }))).await;
+
+        for _ in 0..2 {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            watchdog.check().await;
+        }
+
+        let health = watchdog.get_task_health().await;
+        assert_eq!(health[0].liveness, TaskLiveness::Failed);
+        assert_eq!(alerts.get_active_alerts().await.len(), 1);
+    }
+}
@@ -0,0 +1,436 @@
+//! Outbound webhooks
+//!
+//! Lets external systems receive fills, closed positions and alerts without
+//! polling the API. Every event is POSTed as JSON to each registered endpoint
+//! subscribed to its kind, signed with the endpoint's secret:
+//!
+//! ```text
+//! X-SniperForge-Event: trade_executed
+//! X-SniperForge-Signature: t=<unix seconds>,v1=<hex HMAC-SHA256 of "<t>.<body>">
+//! ```
+//!
+//! Receivers recompute the HMAC (see `verify_signature`) and reject stale
+//! timestamps to stop replays. Failed deliveries (network errors, 5xx, 429)
+//! are retried with exponential backoff; other 4xx responses are final.
+//! Endpoints are managed at runtime through the control API.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use tokio::sync::RwLock;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use super::notifications::{Notification, NotificationSink};
+
+pub const SIGNATURE_HEADER: &str = "X-SniperForge-Signature";
+pub const EVENT_HEADER: &str = "X-SniperForge-Event";
+
+/// Kind of event an endpoint can subscribe to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventKind {
+    TradeExecuted,
+    PositionClosed,
+    Alert,
+}
+
+impl WebhookEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEventKind::TradeExecuted => "trade_executed",
+            WebhookEventKind::PositionClosed => "position_closed",
+            WebhookEventKind::Alert => "alert",
+        }
+    }
+}
+
+/// Payload delivered to endpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookEvent {
+    /// Unique per event (identical across retries, so receivers can deduplicate)
+    pub id: String,
+    pub kind: WebhookEventKind,
+    pub created_at: DateTime<Utc>,
+    pub data: serde_json::Value,
+}
+
+impl WebhookEvent {
+    pub fn new(kind: WebhookEventKind, data: serde_json::Value) -> Self {
+        Self { id: Uuid::new_v4().to_string(), kind, created_at: Utc::now(), data }
+    }
+}
+
+/// Registered receiver
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookEndpoint {
+    pub id: String,
+    pub url: String,
+    /// HMAC key; never included in listings
+    #[serde(skip_serializing, default)]
+    pub secret: String,
+    /// Subscribed kinds (all when empty)
+    #[serde(default)]
+    pub events: Vec<WebhookEventKind>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookEndpoint {
+    pub fn subscribes_to(&self, kind: WebhookEventKind) -> bool {
+        self.events.is_empty() || self.events.contains(&kind)
+    }
+}
+
+/// Delivery policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    /// Attempts per event and endpoint, including the first
+    pub max_attempts: u32,
+    /// Wait before the first retry; doubled each further retry
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    pub request_timeout_secs: u64,
+    /// Delivery records kept for the API
+    pub max_delivery_records: usize,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff_ms: 1_000,
+            max_backoff_ms: 60_000,
+            request_timeout_secs: 10,
+            max_delivery_records: 500,
+        }
+    }
+}
+
+impl WebhookConfig {
+    /// Wait before attempt `attempt` (2 is the first retry)
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u64.saturating_pow(attempt.saturating_sub(2));
+        Duration::from_millis(self.initial_backoff_ms.saturating_mul(factor).min(self.max_backoff_ms))
+    }
+}
+
+/// Outcome of delivering one event to one endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    pub event_id: String,
+    pub kind: WebhookEventKind,
+    pub endpoint_id: String,
+    pub attempts: u32,
+    pub delivered: bool,
+    pub last_status: Option<u16>,
+    pub last_error: Option<String>,
+    pub finished_at: DateTime<Utc>,
+}
+
+/// HTTP POST used for deliveries
+#[async_trait]
+pub trait WebhookTransport: Send + Sync {
+    /// Returns the response status code
+    async fn post(&self, url: &str, headers: &[(&str, String)], body: &str) -> Result<u16>;
+}
+
+/// reqwest-based transport
+pub struct HttpWebhookTransport {
+    client: reqwest::Client,
+}
+
+impl HttpWebhookTransport {
+    pub fn new(timeout: Duration) -> Result<Self> {
+        Ok(Self { client: reqwest::Client::builder().timeout(timeout).build()? })
+    }
+}
+
+#[async_trait]
+impl WebhookTransport for HttpWebhookTransport {
+    async fn post(&self, url: &str, headers: &[(&str, String)], body: &str) -> Result<u16> {
+        let mut request = self.client.post(url)
+            .header("Content-Type", "application/json")
+            .body(body.to_string());
+        for (name, value) in headers {
+            request = request.header(*name, value);
+        }
+        Ok(request.send().await?.status().as_u16())
+    }
+}
+
+/// `X-SniperForge-Signature` value for `body` sent at `timestamp`
+pub fn sign_payload(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    let digest: String = mac.finalize().into_bytes().iter().map(|b| format!("{:02x}", b)).collect();
+    format!("t={},v1={}", timestamp, digest)
+}
+
+/// Check a signature header; rejects timestamps older than `tolerance_secs`
+pub fn verify_signature(secret: &str, header: &str, body: &str, now: DateTime<Utc>, tolerance_secs: i64) -> bool {
+    let Some(timestamp) = header
+        .split(',')
+        .find_map(|part| part.strip_prefix("t="))
+        .and_then(|t| t.parse::<i64>().ok())
+    else {
+        return false;
+    };
+    if (now.timestamp() - timestamp).abs() > tolerance_secs {
+        return false;
+    }
+    let expected = sign_payload(secret, timestamp, body);
+    // Constant-time comparison of the full header value
+    expected.len() == header.len()
+        && expected.bytes().zip(header.bytes()).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+fn is_retryable(status: u16) -> bool {
+    status == 429 || status >= 500
+}
+
+/// Registered endpoints plus signed, retried delivery
+pub struct WebhookEmitter {
+    config: WebhookConfig,
+    transport: Arc<dyn WebhookTransport>,
+    endpoints: RwLock<Vec<WebhookEndpoint>>,
+    deliveries: RwLock<VecDeque<WebhookDelivery>>,
+}
+
+impl std::fmt::Debug for WebhookEmitter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookEmitter").field("config", &self.config).finish()
+    }
+}
+
+impl WebhookEmitter {
+    pub fn new(config: WebhookConfig) -> Result<Self> {
+        let transport = HttpWebhookTransport::new(Duration::from_secs(config.request_timeout_secs))?;
+        Ok(Self::with_transport(config, Arc::new(transport)))
+    }
+
+    pub fn with_transport(config: WebhookConfig, transport: Arc<dyn WebhookTransport>) -> Self {
+        Self {
+            config,
+            transport,
+            endpoints: RwLock::new(Vec::new()),
+            deliveries: RwLock::new(VecDeque::new()),
+        }
+    }
+
+    pub fn config(&self) -> &WebhookConfig {
+        &self.config
+    }
+
+    /// Register a receiver; a random secret is generated when none is given
+    pub async fn add_endpoint(&self, url: &str, events: Vec<WebhookEventKind>, secret: Option<String>) -> Result<WebhookEndpoint> {
+        let parsed = reqwest::Url::parse(url)?;
+        if !matches!(parsed.scheme(), "https" | "http") {
+            anyhow::bail!("Unsupported webhook URL scheme: {}", parsed.scheme());
+        }
+        let endpoint = WebhookEndpoint {
+            id: Uuid::new_v4().to_string(),
+            url: url.to_string(),
+            secret: secret.filter(|s| !s.is_empty()).unwrap_or_else(|| Uuid::new_v4().simple().to_string()),
+            events,
+            created_at: Utc::now(),
+        };
+        self.endpoints.write().await.push(endpoint.clone());
+        Ok(endpoint)
+    }
+
+    /// Returns whether the endpoint existed
+    pub async fn remove_endpoint(&self, id: &str) -> bool {
+        let mut endpoints = self.endpoints.write().await;
+        let before = endpoints.len();
+        endpoints.retain(|endpoint| endpoint.id != id);
+        endpoints.len() != before
+    }
+
+    pub async fn endpoints(&self) -> Vec<WebhookEndpoint> {
+        self.endpoints.read().await.clone()
+    }
+
+    /// Recent delivery outcomes, oldest first
+    pub async fn deliveries(&self) -> Vec<WebhookDelivery> {
+        self.deliveries.read().await.iter().cloned().collect()
+    }
+
+    /// Deliver in the background so callers on the trading path never wait
+    pub fn emit(self: &Arc<Self>, kind: WebhookEventKind, data: serde_json::Value) {
+        let emitter = self.clone();
+        tokio::spawn(async move {
+            emitter.dispatch(WebhookEvent::new(kind, data)).await;
+        });
+    }
+
+    /// Deliver `event` to every subscribed endpoint, retrying as configured
+    pub async fn dispatch(&self, event: WebhookEvent) -> Vec<WebhookDelivery> {
+        let endpoints: Vec<WebhookEndpoint> = self.endpoints.read().await
+            .iter()
+            .filter(|endpoint| endpoint.subscribes_to(event.kind))
+            .cloned()
+            .collect();
+        if endpoints.is_empty() {
+            return Vec::new();
+        }
+        let body = match serde_json::to_string(&event) {
+            Ok(body) => body,
+            Err(e) => {
+                warn!("🪝 Webhook event {} not serializable: {}", event.id, e);
+                return Vec::new();
+            }
+        };
+
+        let deliveries = futures::future::join_all(
+            endpoints.iter().map(|endpoint| self.deliver(&event, endpoint, &body))
+        ).await;
+
+        let mut records = self.deliveries.write().await;
+        records.extend(deliveries.iter().cloned());
+        while records.len() > self.config.max_delivery_records {
+            records.pop_front();
+        }
+        deliveries
+    }
+
+    async fn deliver(&self, event: &WebhookEvent, endpoint: &WebhookEndpoint, body: &str) -> WebhookDelivery {
+        let mut delivery = WebhookDelivery {
+            event_id: event.id.clone(),
+            kind: event.kind,
+            endpoint_id: endpoint.id.clone(),
+            attempts: 0,
+            delivered: false,
+            last_status: None,
+            last_error: None,
+            finished_at: Utc::now(),
+        };
+
+        for attempt in 1..=self.config.max_attempts.max(1) {
+            if attempt > 1 {
+                tokio::time::sleep(self.config.backoff(attempt)).await;
+            }
+            delivery.attempts = attempt;
+            let headers = [
+                (EVENT_HEADER, event.kind.as_str().to_string()),
+                (SIGNATURE_HEADER, sign_payload(&endpoint.secret, Utc::now().timestamp(), body)),
+            ];
+            match self.transport.post(&endpoint.url, &headers, body).await {
+                Ok(status) if (200..300).contains(&status) => {
+                    delivery.delivered = true;
+                    delivery.last_status = Some(status);
+                    delivery.last_error = None;
+                    break;
+                }
+                Ok(status) => {
+                    delivery.last_status = Some(status);
+                    delivery.last_error = Some(format!("HTTP {}", status));
+                    if !is_retryable(status) {
+                        break;
+                    }
+                }
+                Err(e) => delivery.last_error = Some(e.to_string()),
+            }
+        }
+
+        delivery.finished_at = Utc::now();
+        if delivery.delivered {
+            debug!("🪝 Webhook {} delivered to {} (attempt {})", event.kind.as_str(), endpoint.url, delivery.attempts);
+        } else {
+            warn!("🪝 Webhook {} to {} failed after {} attempts: {}", event.kind.as_str(), endpoint.url,
+                  delivery.attempts, delivery.last_error.as_deref().unwrap_or("unknown error"));
+        }
+        delivery
+    }
+}
+
+/// Alerts reach webhooks through the notification digest
+#[async_trait]
+impl NotificationSink for WebhookEmitter {
+    fn name(&self) -> &str {
+        "webhook"
+    }
+
+    async fn send(&self, notification: &Notification) -> anyhow::Result<()> {
+        let event = WebhookEvent::new(WebhookEventKind::Alert, serde_json::to_value(notification)?);
+        let failed = self.dispatch(event).await.into_iter().filter(|delivery| !delivery.delivered).count();
+        if failed > 0 {
+            anyhow::bail!("{} webhook endpoint(s) did not accept the alert", failed);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Replies with queued status codes and records what was sent
+    #[derive(Default)]
+    struct ScriptedTransport {
+        statuses: Mutex<VecDeque<u16>>,
+        sent: Mutex<Vec<(String, String, String)>>,
+    }
+
+    #[async_trait]
+    impl WebhookTransport for ScriptedTransport {
+        async fn post(&self, url: &str, headers: &[(&str, String)], body: &str) -> Result<u16> {
+            let signature = headers.iter().find(|(name, _)| *name == SIGNATURE_HEADER).unwrap().1.clone();
+            self.sent.lock().unwrap().push((url.to_string(), signature, body.to_string()));
+            Ok(self.statuses.lock().unwrap().pop_front().unwrap_or(200))
+        }
+    }
+
+    fn emitter(statuses: &[u16]) -> (WebhookEmitter, Arc<ScriptedTransport>) {
+        let transport = Arc::new(ScriptedTransport::default());
+        transport.statuses.lock().unwrap().extend(statuses);
+        let config = WebhookConfig { initial_backoff_ms: 1, max_backoff_ms: 2, max_attempts: 3, ..Default::default() };
+        (WebhookEmitter::with_transport(config, transport.clone()), transport)
+    }
+
+    #[tokio::test]
+    async fn test_signed_delivery_retries_server_errors() {
+        let (emitter, transport) = emitter(&[503, 500]);
+        let endpoint = emitter.add_endpoint("https://example.com/hook", vec![], Some("s3cret".to_string())).await.unwrap();
+
+        let deliveries = emitter.dispatch(WebhookEvent::new(WebhookEventKind::TradeExecuted, serde_json::json!({"pnl": 1.5}))).await;
+        assert_eq!(deliveries.len(), 1);
+        assert!(deliveries[0].delivered);
+        assert_eq!(deliveries[0].attempts, 3);
+
+        let (url, signature, body) = transport.sent.lock().unwrap().last().cloned().unwrap();
+        assert_eq!(url, endpoint.url);
+        assert!(verify_signature("s3cret", &signature, &body, Utc::now(), 300));
+        assert!(!verify_signature("other", &signature, &body, Utc::now(), 300));
+        assert!(!verify_signature("s3cret", &signature, &body, Utc::now() + chrono::Duration::hours(1), 300));
+        // Secrets never leave through listings
+        assert!(!serde_json::to_string(&emitter.endpoints().await).unwrap().contains("s3cret"));
+    }
+
+    #[tokio::test]
+    async fn test_subscriptions_and_permanent_failures() {
+        let (emitter, transport) = emitter(&[404]);
+        emitter.add_endpoint("https://example.com/alerts", vec![WebhookEventKind::Alert], None).await.unwrap();
+        let trades = emitter.add_endpoint("https://example.com/trades", vec![WebhookEventKind::PositionClosed], None).await.unwrap();
+        assert!(emitter.add_endpoint("ftp://example.com", vec![], None).await.is_err());
+
+        let deliveries = emitter.dispatch(WebhookEvent::new(WebhookEventKind::PositionClosed, serde_json::json!({}))).await;
+        // 404 is not retried
+        assert_eq!(deliveries.len(), 1);
+        assert!(!deliveries[0].delivered);
+        assert_eq!(deliveries[0].attempts, 1);
+        assert_eq!(deliveries[0].last_status, Some(404));
+        assert_eq!(transport.sent.lock().unwrap().len(), 1);
+
+        assert!(emitter.remove_endpoint(&trades.id).await);
+        assert!(emitter.dispatch(WebhookEvent::new(WebhookEventKind::PositionClosed, serde_json::json!({}))).await.is_empty());
+        assert_eq!(emitter.deliveries().await.len(), 1);
+    }
+}
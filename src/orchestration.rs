@@ -3,6 +3,7 @@
 //! This module provides container orchestration capabilities for the SniperForge ecosystem.
 //! It manages containerized bot deployments, scaling, and lifecycle management.
 
+use tracing::info;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -199,7 +200,7 @@ impl ContainerOrchestrator {
                                   format!("{:?}", bot_type).to_lowercase(), 
                                   &bot_id.to_string()[..8]);
 
-        println!("🐳 Deploying container: {}", container_id);
+        info!("🐳 Deploying container: {}", container_id);
 
         // TODO: Implement actual container deployment using Docker API
         // This is a simulation
@@ -227,18 +228,18 @@ impl ContainerOrchestrator {
             container.started_at = Some(chrono::Utc::now());
         }
 
-        println!("✅ Container deployed successfully: {}", container_id);
+        info!("✅ Container deployed successfully: {}", container_id);
         Ok(container_id)
     }
 
     /// Stop a container
     pub async fn stop_container(&mut self, container_id: &str) -> Result<(), OrchestrationError> {
-        println!("🛑 Stopping container: {}", container_id);
+        info!("🛑 Stopping container: {}", container_id);
 
         if let Some(container) = self.containers.get_mut(container_id) {
             container.status = ContainerStatus::Stopped;
             container.stopped_at = Some(chrono::Utc::now());
-            println!("✅ Container stopped successfully: {}", container_id);
+            info!("✅ Container stopped successfully: {}", container_id);
             Ok(())
         } else {
             Err(OrchestrationError::ContainerNotFound(container_id.to_string()))
@@ -247,10 +248,10 @@ impl ContainerOrchestrator {
 
     /// Remove a container
     pub async fn remove_container(&mut self, container_id: &str) -> Result<(), OrchestrationError> {
-        println!("🗑️ Removing container: {}", container_id);
+        info!("🗑️ Removing container: {}", container_id);
 
         if self.containers.remove(container_id).is_some() {
-            println!("✅ Container removed successfully: {}", container_id);
+            info!("✅ Container removed successfully: {}", container_id);
             Ok(())
         } else {
             Err(OrchestrationError::ContainerNotFound(container_id.to_string()))
@@ -283,7 +284,7 @@ impl ContainerOrchestrator {
         let current_containers = self.list_containers_by_type(&bot_type);
         let current_count = current_containers.len() as u32;
 
-        println!("📏 Scaling {} containers from {} to {} replicas", 
+        info!("📏 Scaling {} containers from {} to {} replicas", 
                 format!("{:?}", bot_type), current_count, desired_replicas);
 
         let mut result = Vec::new();
@@ -311,7 +312,7 @@ impl ContainerOrchestrator {
             }
         }
 
-        println!("✅ Scaling completed for {:?}", bot_type);
+        info!("✅ Scaling completed for {:?}", bot_type);
         Ok(result)
     }
 
@@ -346,7 +347,7 @@ impl ContainerOrchestrator {
                 results.insert(container_id.clone(), is_healthy);
 
                 if !is_healthy {
-                    println!("⚠️ Container {} failed health check", container_id);
+                    info!("⚠️ Container {} failed health check", container_id);
                     container.status = ContainerStatus::Failed;
                 }
             }
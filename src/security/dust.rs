@@ -0,0 +1,443 @@
+//! # Dust Consolidation
+//!
+//! Trading leaves the hot wallet with many token accounts holding amounts too
+//! small to matter, each locking ~0.002 SOL of rent. Consolidation swaps the
+//! remaining balance of each such account to SOL and closes it, recovering the
+//! rent. Every account is costed first: the quoted swap proceeds plus the rent
+//! refund must exceed the transaction fees by the configured margin, otherwise
+//! the account is left alone.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_request::TokenAccountsFilter;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::{keypair::Keypair, Signer};
+use solana_sdk::transaction::{Transaction, VersionedTransaction};
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+use crate::apis::jupiter::{Jupiter, QuoteRequest};
+use crate::trading::execution::admit_shared;
+use crate::types::constants::SOL_MINT;
+
+pub const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+/// SPL Token `CloseAccount` instruction tag
+const CLOSE_ACCOUNT_TAG: u8 = 9;
+
+/// Consolidation policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DustConfig {
+    /// Accounts whose balance quotes above this are not dust (lamports)
+    pub max_value_lamports: u64,
+    /// Net gain an account must yield to be consolidated (lamports)
+    pub min_net_gain_lamports: u64,
+    /// Fee of one signature-only transaction, e.g. the close (lamports)
+    pub tx_fee_lamports: u64,
+    /// Expected fee of a swap transaction including priority fee (lamports)
+    pub swap_fee_lamports: u64,
+    pub slippage_bps: u16,
+    /// Mints never consolidated, however small the balance
+    pub keep_mints: HashSet<String>,
+}
+
+impl Default for DustConfig {
+    fn default() -> Self {
+        Self {
+            max_value_lamports: 10_000_000, // 0.01 SOL
+            min_net_gain_lamports: 0,
+            tx_fee_lamports: 5_000,
+            swap_fee_lamports: 50_000,
+            slippage_bps: 300,
+            keep_mints: HashSet::new(),
+        }
+    }
+}
+
+/// A token account of the wallet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenHolding {
+    pub account: String,
+    pub mint: String,
+    /// Balance in base units
+    pub amount: u64,
+    /// Lamports the account holds, refunded on close
+    pub rent_lamports: u64,
+}
+
+/// How an account gets emptied before it is closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DustAction {
+    /// Already empty
+    Close,
+    /// Wrapped SOL: closing the account unwraps the balance
+    Unwrap,
+    SwapAndClose,
+}
+
+/// Cost/benefit of consolidating one account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DustCandidate {
+    pub holding: TokenHolding,
+    pub action: DustAction,
+    /// Quoted SOL out of the swap (or unwrapped balance)
+    pub proceeds_lamports: u64,
+    pub fee_lamports: u64,
+    pub net_lamports: i64,
+}
+
+/// What happened to one account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DustOutcome {
+    /// Positive EV; not executed because of a dry run
+    Planned,
+    Consolidated { swap_signature: Option<String>, close_signature: String },
+    Skipped { reason: String },
+    Failed { reason: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DustEntry {
+    pub account: String,
+    pub mint: String,
+    pub candidate: Option<DustCandidate>,
+    pub outcome: DustOutcome,
+}
+
+/// Result of a consolidation run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DustReport {
+    pub dry_run: bool,
+    pub scanned: usize,
+    pub entries: Vec<DustEntry>,
+    /// Sum of the net gain of the planned or consolidated accounts (lamports)
+    pub estimated_net_lamports: i64,
+    /// Change in the wallet's SOL balance over the run; `None` on dry runs
+    pub recovered_lamports: Option<i64>,
+}
+
+impl DustReport {
+    pub fn consolidated(&self) -> usize {
+        self.entries.iter().filter(|entry| matches!(entry.outcome, DustOutcome::Consolidated { .. })).count()
+    }
+
+    pub fn summary(&self) -> String {
+        let planned = self.entries.iter().filter(|entry| matches!(entry.outcome, DustOutcome::Planned)).count();
+        let failed = self.entries.iter().filter(|entry| matches!(entry.outcome, DustOutcome::Failed { .. })).count();
+        match self.recovered_lamports {
+            Some(recovered) => format!(
+                "Consolidated {} of {} token accounts ({} failed), net {:+.6} SOL recovered",
+                self.consolidated(), self.scanned, failed, recovered as f64 / 1e9
+            ),
+            None => format!(
+                "Dry run: {} of {} token accounts worth consolidating, est. net {:+.6} SOL",
+                planned, self.scanned, self.estimated_net_lamports as f64 / 1e9
+            ),
+        }
+    }
+}
+
+/// Wallet operations consolidation needs
+#[async_trait]
+pub trait DustWallet: Send + Sync {
+    async fn sol_balance(&self) -> Result<u64>;
+    async fn token_accounts(&self) -> Result<Vec<TokenHolding>>;
+    /// Lamports received for swapping `amount` of `mint` to SOL
+    async fn quote_to_sol(&self, mint: &str, amount: u64, slippage_bps: u16) -> Result<u64>;
+    async fn swap_to_sol(&self, mint: &str, amount: u64) -> Result<String>;
+    async fn close_account(&self, account: &str) -> Result<String>;
+}
+
+/// SPL Token `CloseAccount`: rent goes to `destination`
+pub fn close_account_ix(account: &Pubkey, destination: &Pubkey, owner: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap_or_default(),
+        accounts: vec![
+            AccountMeta::new(*account, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        data: vec![CLOSE_ACCOUNT_TAG],
+    }
+}
+
+/// Hot wallet over RPC, swapping through Jupiter
+pub struct RpcDustWallet {
+    rpc: RpcClient,
+    jupiter: Mutex<Jupiter>,
+    keypair: Keypair,
+}
+
+impl RpcDustWallet {
+    pub fn new(rpc_url: String, jupiter: Jupiter, keypair: Keypair) -> Self {
+        Self { rpc: RpcClient::new(rpc_url), jupiter: Mutex::new(jupiter), keypair }
+    }
+}
+
+#[async_trait]
+impl DustWallet for RpcDustWallet {
+    async fn sol_balance(&self) -> Result<u64> {
+        Ok(self.rpc.get_balance(&self.keypair.pubkey())?)
+    }
+
+    async fn token_accounts(&self) -> Result<Vec<TokenHolding>> {
+        let program = Pubkey::from_str(TOKEN_PROGRAM_ID)?;
+        let accounts = self.rpc.get_token_accounts_by_owner(&self.keypair.pubkey(), TokenAccountsFilter::ProgramId(program))?;
+        let mut holdings = Vec::with_capacity(accounts.len());
+        for keyed in accounts {
+            // jsonParsed: {"parsed": {"info": {"mint", "tokenAmount": {"amount"}}}}
+            let data = serde_json::to_value(&keyed.account.data)?;
+            let info = &data["parsed"]["info"];
+            let (Some(mint), Some(amount)) = (
+                info["mint"].as_str(),
+                info["tokenAmount"]["amount"].as_str().and_then(|amount| amount.parse().ok()),
+            ) else {
+                warn!("⚠️ Token account {} not in parsed form, skipping", keyed.pubkey);
+                continue;
+            };
+            holdings.push(TokenHolding {
+                account: keyed.pubkey,
+                mint: mint.to_string(),
+                amount,
+                rent_lamports: keyed.account.lamports,
+            });
+        }
+        Ok(holdings)
+    }
+
+    async fn quote_to_sol(&self, mint: &str, amount: u64, slippage_bps: u16) -> Result<u64> {
+        let request = QuoteRequest::new(mint.to_string(), SOL_MINT.to_string(), amount).with_slippage_bps(slippage_bps);
+        let quote = self.jupiter.lock().await.get_quote(&request).await?;
+        quote.out_amount_u64().map_err(|e| anyhow!("Invalid quote output {}: {}", quote.out_amount, e))
+    }
+
+    async fn swap_to_sol(&self, mint: &str, amount: u64) -> Result<String> {
+        let signature = self.jupiter.lock().await.execute_swap(mint, SOL_MINT, amount, &self.keypair).await?;
+        Ok(signature.to_string())
+    }
+
+    async fn close_account(&self, account: &str) -> Result<String> {
+        let owner = self.keypair.pubkey();
+        let ix = close_account_ix(&Pubkey::from_str(account)?, &owner, &owner);
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let tx = Transaction::new_signed_with_payer(&[ix], Some(&owner), &[&self.keypair], blockhash);
+        admit_shared(&owner.to_string(), Some(&format!("dust-close:{}", account)), &VersionedTransaction::from(tx.clone()))?;
+        Ok(self.rpc.send_and_confirm_transaction(&tx)?.to_string())
+    }
+}
+
+/// Plans and runs dust consolidation for one wallet
+pub struct DustConsolidator {
+    config: DustConfig,
+    wallet: Arc<dyn DustWallet>,
+    /// One run at a time: concurrent runs would race on the same accounts
+    running: Mutex<()>,
+}
+
+impl DustConsolidator {
+    pub fn new(config: DustConfig, wallet: Arc<dyn DustWallet>) -> Self {
+        Self { config, wallet, running: Mutex::new(()) }
+    }
+
+    pub fn config(&self) -> &DustConfig {
+        &self.config
+    }
+
+    /// Cost/benefit of one account given its quoted proceeds (`None`: no route)
+    pub fn evaluate(&self, holding: &TokenHolding, quoted: Option<u64>) -> Result<DustCandidate, String> {
+        if self.config.keep_mints.contains(&holding.mint) {
+            return Err("mint is on the keep list".to_string());
+        }
+        let (action, proceeds_lamports, fee_lamports) = if holding.amount == 0 {
+            (DustAction::Close, 0, self.config.tx_fee_lamports)
+        } else if holding.mint == SOL_MINT {
+            (DustAction::Unwrap, holding.amount, self.config.tx_fee_lamports)
+        } else {
+            let proceeds = quoted.ok_or_else(|| "no route to SOL".to_string())?;
+            (DustAction::SwapAndClose, proceeds, self.config.swap_fee_lamports + self.config.tx_fee_lamports)
+        };
+        if proceeds_lamports > self.config.max_value_lamports {
+            return Err(format!("worth {:.6} SOL, above the dust threshold", proceeds_lamports as f64 / 1e9));
+        }
+        let net_lamports = proceeds_lamports as i64 + holding.rent_lamports as i64 - fee_lamports as i64;
+        let candidate = DustCandidate { holding: holding.clone(), action, proceeds_lamports, fee_lamports, net_lamports };
+        if net_lamports <= self.config.min_net_gain_lamports as i64 {
+            return Err(format!("net {} lamports does not cover fees", net_lamports));
+        }
+        Ok(candidate)
+    }
+
+    /// Enumerate, cost and (unless `dry_run`) consolidate the wallet's dust
+    pub async fn consolidate(&self, dry_run: bool) -> Result<DustReport> {
+        let _running = self.running.try_lock().map_err(|_| anyhow!("Dust consolidation already running"))?;
+        let balance_before = self.wallet.sol_balance().await?;
+        let holdings = self.wallet.token_accounts().await?;
+        let mut report = DustReport { dry_run, scanned: holdings.len(), ..Default::default() };
+
+        for holding in holdings {
+            let quoted = if holding.amount > 0 && holding.mint != SOL_MINT && !self.config.keep_mints.contains(&holding.mint) {
+                match self.wallet.quote_to_sol(&holding.mint, holding.amount, self.config.slippage_bps).await {
+                    Ok(out) => Some(out),
+                    Err(e) => {
+                        warn!("⚠️ No SOL quote for dust account {} ({}): {}", holding.account, holding.mint, e);
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+            let mut entry = DustEntry { account: holding.account.clone(), mint: holding.mint.clone(), candidate: None, outcome: DustOutcome::Planned };
+            let candidate = match self.evaluate(&holding, quoted) {
+                Ok(candidate) => candidate,
+                Err(reason) => {
+                    entry.outcome = DustOutcome::Skipped { reason };
+                    report.entries.push(entry);
+                    continue;
+                }
+            };
+            report.estimated_net_lamports += candidate.net_lamports;
+            entry.candidate = Some(candidate.clone());
+            if !dry_run {
+                entry.outcome = self.execute(&candidate).await;
+            }
+            report.entries.push(entry);
+        }
+
+        if !dry_run {
+            let balance_after = self.wallet.sol_balance().await?;
+            report.recovered_lamports = Some(balance_after as i64 - balance_before as i64);
+        }
+        info!("🧹 {}", report.summary());
+        Ok(report)
+    }
+
+    async fn execute(&self, candidate: &DustCandidate) -> DustOutcome {
+        let holding = &candidate.holding;
+        let swap_signature = match candidate.action {
+            DustAction::SwapAndClose => match self.wallet.swap_to_sol(&holding.mint, holding.amount).await {
+                Ok(signature) => Some(signature),
+                Err(e) => return DustOutcome::Failed { reason: format!("swap failed: {}", e) },
+            },
+            DustAction::Close | DustAction::Unwrap => None,
+        };
+        match self.wallet.close_account(&holding.account).await {
+            Ok(close_signature) => {
+                info!("🧹 Closed dust account {} ({}), est. net {} lamports", holding.account, holding.mint, candidate.net_lamports);
+                DustOutcome::Consolidated { swap_signature, close_signature }
+            }
+            Err(e) => DustOutcome::Failed { reason: format!("close failed: {}", e) },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex as SyncMutex;
+    use std::collections::HashMap;
+
+    const RENT: u64 = 2_039_280;
+
+    struct MockWallet {
+        balance: SyncMutex<u64>,
+        holdings: Vec<TokenHolding>,
+        quotes: HashMap<String, u64>,
+        closed: SyncMutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl DustWallet for MockWallet {
+        async fn sol_balance(&self) -> Result<u64> {
+            Ok(*self.balance.lock())
+        }
+        async fn token_accounts(&self) -> Result<Vec<TokenHolding>> {
+            Ok(self.holdings.clone())
+        }
+        async fn quote_to_sol(&self, mint: &str, _amount: u64, _slippage_bps: u16) -> Result<u64> {
+            self.quotes.get(mint).copied().ok_or_else(|| anyhow!("no route"))
+        }
+        async fn swap_to_sol(&self, mint: &str, _amount: u64) -> Result<String> {
+            let mut balance = self.balance.lock();
+            *balance = *balance + self.quotes[mint] - 50_000;
+            Ok(format!("swap-{}", mint))
+        }
+        async fn close_account(&self, account: &str) -> Result<String> {
+            let holding = self.holdings.iter().find(|holding| holding.account == account).unwrap();
+            let mut balance = self.balance.lock();
+            *balance = *balance + holding.rent_lamports - 5_000;
+            self.closed.lock().push(account.to_string());
+            Ok(format!("close-{}", account))
+        }
+    }
+
+    fn holding(account: &str, mint: &str, amount: u64) -> TokenHolding {
+        TokenHolding { account: account.to_string(), mint: mint.to_string(), amount, rent_lamports: RENT }
+    }
+
+    fn wallet() -> Arc<MockWallet> {
+        Arc::new(MockWallet {
+            balance: SyncMutex::new(1_000_000_000),
+            holdings: vec![
+                holding("empty", "MintA", 0),
+                holding("dust", "MintB", 10),
+                holding("valuable", "MintC", 1_000),
+                holding("unroutable", "MintD", 10),
+                holding("usdc", "USDC", 5),
+            ],
+            quotes: [("MintB".to_string(), 30_000), ("MintC".to_string(), 500_000_000), ("USDC".to_string(), 40)].into(),
+            closed: SyncMutex::new(Vec::new()),
+        })
+    }
+
+    #[test]
+    fn test_only_positive_ev_accounts_qualify() {
+        let config = DustConfig { keep_mints: ["USDC".to_string()].into(), ..Default::default() };
+        let consolidator = DustConsolidator::new(config, wallet());
+
+        let empty = consolidator.evaluate(&holding("a", "MintA", 0), None).unwrap();
+        assert_eq!(empty.action, DustAction::Close);
+        assert_eq!(empty.net_lamports, RENT as i64 - 5_000);
+
+        // Proceeds below the swap fee are still worth it for the rent
+        let dust = consolidator.evaluate(&holding("b", "MintB", 10), Some(30_000)).unwrap();
+        assert_eq!(dust.net_lamports, 30_000 + RENT as i64 - 55_000);
+
+        let unwrap = consolidator.evaluate(&holding("w", SOL_MINT, 1_000), None).unwrap();
+        assert_eq!(unwrap.action, DustAction::Unwrap);
+
+        assert!(consolidator.evaluate(&holding("c", "MintC", 1), Some(500_000_000)).is_err());
+        assert!(consolidator.evaluate(&holding("d", "MintD", 1), None).is_err());
+        assert!(consolidator.evaluate(&holding("u", "USDC", 1), Some(40)).is_err());
+
+        // Rent below fees: never worth closing
+        let mut tiny_rent = holding("t", "MintA", 0);
+        tiny_rent.rent_lamports = 1_000;
+        assert!(consolidator.evaluate(&tiny_rent, None).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_consolidation_reports_net_recovered() {
+        let wallet = wallet();
+        let config = DustConfig { keep_mints: ["USDC".to_string()].into(), ..Default::default() };
+        let consolidator = DustConsolidator::new(config, wallet.clone());
+
+        let preview = consolidator.consolidate(true).await.unwrap();
+        assert_eq!(preview.scanned, 5);
+        assert!(preview.recovered_lamports.is_none());
+        assert!(wallet.closed.lock().is_empty());
+
+        let report = consolidator.consolidate(false).await.unwrap();
+        assert_eq!(report.consolidated(), 2);
+        assert_eq!(*wallet.closed.lock(), vec!["empty".to_string(), "dust".to_string()]);
+        let expected = (RENT as i64 - 5_000) + (30_000 - 50_000 + RENT as i64 - 5_000);
+        assert_eq!(report.recovered_lamports, Some(expected));
+        assert_eq!(report.estimated_net_lamports, expected);
+        assert_eq!(preview.estimated_net_lamports, expected);
+    }
+}
@@ -0,0 +1,574 @@
+//! # Realms Governance Settings
+//!
+//! DAO-operated deployments can drive treasury-affecting settings (daily fee
+//! caps, asset exposure limits) through on-chain governance instead of local
+//! config edits. The watcher polls configured Realms (SPL Governance)
+//! proposal accounts and applies a proposal's config payload once it has
+//! passed:
+//!
+//! - the proposal must belong to the configured governance account and be in
+//!   a passed state (`Succeeded`, `Executing` or `Completed`)
+//! - the payload comes from the proposal's description link: inline JSON, or
+//!   an https URL pinned with `#sha256=<hex>` so the content voted on cannot
+//!   change afterwards
+//! - every change is bounds-checked before anything is applied; a proposal
+//!   is applied entirely or not at all, and only once
+//!
+//! Applications and rejections are recorded in an audit trail using the
+//! security framework's `SecurityAuditEntry` format.
+
+use anyhow::{anyhow, bail, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+
+use super::{SecurityAuditEntry, SecurityEventType, SecuritySeverity};
+use crate::monitoring::HeartbeatHandle;
+use crate::trading::fee_budget::FeeBudgetManager;
+use crate::trading::risk::RiskManager;
+
+/// SPL Governance (Realms) program ID
+pub const REALMS_PROGRAM_ID: &str = "GovER5Lthms3bLBqWub97yVrMZEjKG8ALqZfBP9Z3Gs";
+
+/// `GovernanceAccountType::ProposalV2`
+const PROPOSAL_V2_ACCOUNT_TYPE: u8 = 14;
+
+/// Governance watcher configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceConfig {
+    /// Governance account proposals must belong to
+    pub governance_address: String,
+    /// Proposal accounts to watch
+    pub proposal_accounts: Vec<String>,
+    pub poll_interval_seconds: u64,
+    /// Bounds every change is checked against
+    pub limits: GovernanceLimits,
+    /// Where processed proposals are recorded so restarts never re-apply them
+    pub state_path: Option<PathBuf>,
+}
+
+impl Default for GovernanceConfig {
+    fn default() -> Self {
+        Self {
+            governance_address: String::new(),
+            proposal_accounts: Vec::new(),
+            poll_interval_seconds: 60,
+            limits: GovernanceLimits::default(),
+            state_path: Some(PathBuf::from("state/governance_proposals.json")),
+        }
+    }
+}
+
+/// Hard bounds on governed settings, independent of what a proposal asks for
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceLimits {
+    pub max_daily_fee_cap_sol: f64,
+    pub max_restriction_hours: f64,
+}
+
+impl Default for GovernanceLimits {
+    fn default() -> Self {
+        Self {
+            max_daily_fee_cap_sol: 10.0,
+            max_restriction_hours: 24.0 * 30.0,
+        }
+    }
+}
+
+/// Realms proposal lifecycle state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProposalState {
+    Draft,
+    SigningOff,
+    Voting,
+    Succeeded,
+    Executing,
+    Completed,
+    Cancelled,
+    Defeated,
+    ExecutingWithErrors,
+    Vetoed,
+}
+
+impl ProposalState {
+    fn from_u8(value: u8) -> Option<Self> {
+        Some(match value {
+            0 => Self::Draft,
+            1 => Self::SigningOff,
+            2 => Self::Voting,
+            3 => Self::Succeeded,
+            4 => Self::Executing,
+            5 => Self::Completed,
+            6 => Self::Cancelled,
+            7 => Self::Defeated,
+            8 => Self::ExecutingWithErrors,
+            9 => Self::Vetoed,
+            _ => return None,
+        })
+    }
+
+    /// The vote passed
+    pub fn is_passed(self) -> bool {
+        matches!(self, Self::Succeeded | Self::Executing | Self::Completed)
+    }
+}
+
+/// Fields of a `ProposalV2` account the watcher needs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RealmsProposal {
+    pub governance: Pubkey,
+    pub state: ProposalState,
+    pub name: String,
+    pub description_link: String,
+}
+
+/// Sequential Borsh reader
+struct BorshReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BorshReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos + len).ok_or_else(|| anyhow!("proposal account truncated at byte {}", self.pos))?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into()?))
+    }
+
+    fn pubkey(&mut self) -> Result<Pubkey> {
+        Ok(Pubkey::try_from(self.take(32)?)?)
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let len = self.u32()? as usize;
+        Ok(String::from_utf8(self.take(len)?.to_vec())?)
+    }
+
+    /// Skip an `Option<T>` of a fixed-size `T`
+    fn skip_option(&mut self, size: usize) -> Result<()> {
+        if self.u8()? == 1 {
+            self.take(size)?;
+        }
+        Ok(())
+    }
+}
+
+/// Decode a Realms `ProposalV2` account
+pub fn parse_proposal(data: &[u8]) -> Result<RealmsProposal> {
+    let mut reader = BorshReader { data, pos: 0 };
+    let account_type = reader.u8()?;
+    if account_type != PROPOSAL_V2_ACCOUNT_TYPE {
+        bail!("not a ProposalV2 account (type {})", account_type);
+    }
+    let governance = reader.pubkey()?;
+    reader.pubkey()?; // governing_token_mint
+    let state = reader.u8()?;
+    let state = ProposalState::from_u8(state).ok_or_else(|| anyhow!("unknown proposal state {}", state))?;
+    reader.pubkey()?; // token_owner_record
+    reader.take(2)?; // signatories_count, signatories_signed_off_count
+    if reader.u8()? == 1 {
+        reader.take(4)?; // MultiChoice parameters
+    }
+    for _ in 0..reader.u32()? {
+        reader.string()?; // label
+        reader.take(8 + 1 + 2 + 2 + 2)?; // vote_weight, vote_result, transaction counters
+    }
+    reader.skip_option(8)?; // deny_vote_weight
+    reader.u8()?; // reserved1
+    reader.skip_option(8)?; // abstain_vote_weight
+    reader.skip_option(8)?; // start_voting_at
+    reader.take(8)?; // draft_at
+    for _ in 0..5 {
+        reader.skip_option(8)?; // signing_off_at, voting_at, voting_at_slot, voting_completed_at, executing_at
+    }
+    reader.skip_option(8)?; // closed_at
+    reader.u8()?; // execution_flags
+    reader.skip_option(8)?; // max_vote_weight
+    reader.skip_option(4)?; // max_voting_time
+    if reader.u8()? == 1 && reader.u8()? < 2 {
+        reader.u8()?; // vote_threshold percentage
+    }
+    reader.take(64)?; // reserved
+    let name = reader.string()?;
+    let description_link = reader.string()?;
+    Ok(RealmsProposal { governance, state, name, description_link })
+}
+
+/// One setting change carried by a proposal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "setting", rename_all = "snake_case")]
+pub enum SettingChange {
+    /// Daily fee cap of one bot, or the default cap when `bot` is unset
+    FeeDailyCap { bot: Option<String>, cap_sol: f64 },
+    AssetRestriction { symbol: String, max_position_fraction: f64, hours: f64 },
+    LiftAssetRestriction { symbol: String },
+}
+
+/// Config payload referenced by a proposal's description link
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GovernancePayload {
+    pub changes: Vec<SettingChange>,
+}
+
+impl GovernancePayload {
+    /// Reject changes outside the hard bounds
+    pub fn validate(&self, limits: &GovernanceLimits) -> Result<()> {
+        if self.changes.is_empty() {
+            bail!("payload contains no changes");
+        }
+        for change in &self.changes {
+            match change {
+                SettingChange::FeeDailyCap { cap_sol, .. } => {
+                    if !cap_sol.is_finite() || *cap_sol < 0.0 || *cap_sol > limits.max_daily_fee_cap_sol {
+                        bail!("fee cap {} SOL outside [0, {}]", cap_sol, limits.max_daily_fee_cap_sol);
+                    }
+                }
+                SettingChange::AssetRestriction { symbol, max_position_fraction, hours } => {
+                    if symbol.trim().is_empty() {
+                        bail!("asset restriction without symbol");
+                    }
+                    if !(0.0..=1.0).contains(max_position_fraction) {
+                        bail!("position fraction {} outside [0, 1]", max_position_fraction);
+                    }
+                    if !hours.is_finite() || *hours <= 0.0 || *hours > limits.max_restriction_hours {
+                        bail!("restriction of {}h outside (0, {}]", hours, limits.max_restriction_hours);
+                    }
+                }
+                SettingChange::LiftAssetRestriction { symbol } => {
+                    if symbol.trim().is_empty() {
+                        bail!("lift without symbol");
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Components governed settings are applied to
+#[derive(Clone, Default)]
+pub struct GovernedTargets {
+    pub fee_budget: Option<Arc<FeeBudgetManager>>,
+    pub risk: Option<RiskManager>,
+}
+
+/// A proposal whose payload was applied
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppliedProposal {
+    pub proposal: String,
+    pub name: String,
+    pub changes: Vec<SettingChange>,
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Watches Realms proposals and applies passed config payloads
+pub struct GovernanceWatcher {
+    config: GovernanceConfig,
+    governance: Pubkey,
+    client: RpcClient,
+    http: reqwest::Client,
+    targets: GovernedTargets,
+    /// Proposals applied or rejected; never evaluated again
+    processed: RwLock<HashSet<String>>,
+    audit_log: RwLock<Vec<SecurityAuditEntry>>,
+}
+
+impl GovernanceWatcher {
+    pub fn new(config: GovernanceConfig, rpc_url: &str, targets: GovernedTargets) -> Result<Self> {
+        let governance = Pubkey::from_str(&config.governance_address)
+            .map_err(|e| anyhow!("invalid governance address '{}': {}", config.governance_address, e))?;
+        let processed = config
+            .state_path
+            .as_ref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Ok(Self {
+            config,
+            governance,
+            client: RpcClient::new(rpc_url.to_string()),
+            http: reqwest::Client::builder().timeout(Duration::from_secs(10)).build()?,
+            targets,
+            processed: RwLock::new(processed),
+            audit_log: RwLock::new(Vec::new()),
+        })
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_secs(self.config.poll_interval_seconds.max(1))
+    }
+
+    /// Fetch the payload a description link points to
+    async fn resolve_payload(&self, link: &str) -> Result<GovernancePayload> {
+        let link = link.trim();
+        let inline = link.strip_prefix("data:application/json,").unwrap_or(link);
+        if inline.starts_with('{') {
+            return Ok(serde_json::from_str(inline)?);
+        }
+        let (url, expected) = link
+            .split_once("#sha256=")
+            .ok_or_else(|| anyhow!("payload URL is not pinned with #sha256=<hex>"))?;
+        if !url.starts_with("https://") {
+            bail!("payload URL must use https");
+        }
+        let body = self.http.get(url).send().await?.error_for_status()?.bytes().await?;
+        let actual = sha256_hex(&body);
+        if !actual.eq_ignore_ascii_case(expected) {
+            bail!("payload hash mismatch: expected {}, got {}", expected, actual);
+        }
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    /// Check a proposal and its payload; `None` while the vote has not passed
+    pub async fn evaluate(&self, proposal: &RealmsProposal) -> Result<Option<GovernancePayload>> {
+        if proposal.governance != self.governance {
+            bail!("proposal belongs to governance {}, expected {}", proposal.governance, self.governance);
+        }
+        if !proposal.state.is_passed() {
+            return Ok(None);
+        }
+        let payload = self.resolve_payload(&proposal.description_link).await?;
+        payload.validate(&self.config.limits)?;
+        self.ensure_targets(&payload)?;
+        Ok(Some(payload))
+    }
+
+    fn ensure_targets(&self, payload: &GovernancePayload) -> Result<()> {
+        for change in &payload.changes {
+            let available = match change {
+                SettingChange::FeeDailyCap { .. } => self.targets.fee_budget.is_some(),
+                _ => self.targets.risk.is_some(),
+            };
+            if !available {
+                bail!("no component to apply {:?} to", change);
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_change(&self, change: &SettingChange) -> String {
+        match change {
+            SettingChange::FeeDailyCap { bot, cap_sol } => {
+                let previous = self.targets.fee_budget.as_ref().map(|budget| budget.set_daily_cap(bot.as_deref(), *cap_sol));
+                format!("fee cap {} {:?} -> {}", bot.as_deref().unwrap_or("default"), previous, cap_sol)
+            }
+            SettingChange::AssetRestriction { symbol, max_position_fraction, hours } => {
+                if let Some(risk) = &self.targets.risk {
+                    risk.restrict_asset(symbol, *max_position_fraction, "governance proposal", Duration::from_secs_f64(hours * 3600.0));
+                }
+                format!("restrict {} to {:.0}% for {}h", symbol, max_position_fraction * 100.0, hours)
+            }
+            SettingChange::LiftAssetRestriction { symbol } => {
+                if let Some(risk) = &self.targets.risk {
+                    risk.lift_restriction(symbol);
+                }
+                format!("lift restriction on {}", symbol)
+            }
+        }
+    }
+
+    /// Evaluate one proposal account's data and apply it when it passed
+    pub async fn process(&self, address: &str, data: &[u8]) -> Option<AppliedProposal> {
+        if self.processed.read().await.contains(address) {
+            return None;
+        }
+        let proposal = match parse_proposal(data) {
+            Ok(proposal) => proposal,
+            Err(e) => {
+                self.reject(address, "unparseable", &e.to_string()).await;
+                return None;
+            }
+        };
+        match self.evaluate(&proposal).await {
+            Ok(None) => {
+                debug!("🏛️ Proposal '{}' ({}) is {:?}, waiting", proposal.name, address, proposal.state);
+                None
+            }
+            Ok(Some(payload)) => {
+                let applied: Vec<String> = payload.changes.iter().map(|change| self.apply_change(change)).collect();
+                info!("🏛️ Governance proposal '{}' applied: {}", proposal.name, applied.join("; "));
+                self.record(address, SecuritySeverity::Warning, format!("Governance proposal '{}' applied", proposal.name),
+                            ("changes", applied.join("; "))).await;
+                Some(AppliedProposal { proposal: address.to_string(), name: proposal.name, changes: payload.changes })
+            }
+            Err(e) => {
+                self.reject(address, &proposal.name, &e.to_string()).await;
+                None
+            }
+        }
+    }
+
+    /// Poll every watched proposal once
+    pub async fn poll_once(&self) -> Vec<AppliedProposal> {
+        let mut applied = Vec::new();
+        for address in &self.config.proposal_accounts {
+            if self.processed.read().await.contains(address) {
+                continue;
+            }
+            let Ok(key) = Pubkey::from_str(address) else {
+                self.reject(address, "invalid address", "not a valid pubkey").await;
+                continue;
+            };
+            match self.client.get_account(&key).await {
+                Ok(account) if account.owner.to_string() != REALMS_PROGRAM_ID => {
+                    self.reject(address, "foreign account", &format!("owned by {}", account.owner)).await;
+                }
+                Ok(account) => applied.extend(self.process(address, &account.data).await),
+                Err(e) => warn!("⚠️ Could not fetch governance proposal {}: {}", address, e),
+            }
+        }
+        applied
+    }
+
+    pub async fn run(self: Arc<Self>, heartbeat: HeartbeatHandle) {
+        info!("🏛️ Governance watcher running ({} proposals)", self.config.proposal_accounts.len());
+        loop {
+            self.poll_once().await;
+            heartbeat.beat();
+            tokio::time::sleep(self.poll_interval()).await;
+        }
+    }
+
+    /// Governance audit trail
+    pub async fn get_audit_log(&self) -> Vec<SecurityAuditEntry> {
+        self.audit_log.read().await.clone()
+    }
+
+    async fn reject(&self, address: &str, name: &str, reason: &str) {
+        warn!("🏛️ Governance proposal '{}' ({}) rejected: {}", name, address, reason);
+        self.record(address, SecuritySeverity::Error, format!("Governance proposal '{}' rejected", name),
+                    ("reason", reason.to_string())).await;
+    }
+
+    /// Audit and mark the proposal processed
+    async fn record(&self, address: &str, severity: SecuritySeverity, description: String, detail: (&str, String)) {
+        let mut metadata = HashMap::from([(detail.0.to_string(), detail.1)]);
+        metadata.insert("proposal".to_string(), address.to_string());
+        metadata.insert("governance".to_string(), self.governance.to_string());
+        let event_type = if severity == SecuritySeverity::Error { SecurityEventType::PolicyViolation } else { SecurityEventType::Audit };
+        self.audit_log.write().await.push(SecurityAuditEntry {
+            timestamp: Utc::now(),
+            event_type,
+            component: "realms_governance".to_string(),
+            severity,
+            description,
+            metadata,
+            actor: Some(format!("governance:{}", self.governance)),
+            ip_address: None,
+            build: crate::security::build_fingerprint(),
+        });
+
+        let mut processed = self.processed.write().await;
+        processed.insert(address.to_string());
+        if let Some(path) = &self.config.state_path {
+            let result = (|| -> Result<()> {
+                if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(path, serde_json::to_string_pretty(&*processed)?)?;
+                Ok(())
+            })();
+            if let Err(e) = result {
+                warn!("⚠️ Failed to persist processed governance proposals: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trading::fee_budget::FeeBudgetConfig;
+
+    fn string(out: &mut Vec<u8>, value: &str) {
+        out.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        out.extend_from_slice(value.as_bytes());
+    }
+
+    /// Minimal single-choice ProposalV2 account
+    fn proposal_account(governance: &Pubkey, state: u8, description_link: &str) -> Vec<u8> {
+        let mut out = vec![PROPOSAL_V2_ACCOUNT_TYPE];
+        out.extend_from_slice(governance.as_ref());
+        out.extend_from_slice(Pubkey::new_unique().as_ref());
+        out.push(state);
+        out.extend_from_slice(Pubkey::new_unique().as_ref());
+        out.extend_from_slice(&[1, 1, 0]); // signatories, single choice
+        out.extend_from_slice(&1u32.to_le_bytes());
+        string(&mut out, "Approve");
+        out.extend_from_slice(&[0; 15]);
+        out.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0, 0]); // deny_vote_weight
+        out.push(0); // reserved1
+        out.extend_from_slice(&[0, 0]); // abstain, start_voting_at
+        out.extend_from_slice(&[0; 8]); // draft_at
+        out.extend_from_slice(&[0; 6]); // signing_off_at .. closed_at
+        out.push(0); // execution_flags
+        out.extend_from_slice(&[0, 0]); // max_vote_weight, max_voting_time
+        out.extend_from_slice(&[1, 0, 60]); // YesVotePercentage(60)
+        out.extend_from_slice(&[0; 64]);
+        string(&mut out, "Lower arbitrage fee cap");
+        string(&mut out, description_link);
+        out.extend_from_slice(&[0; 8]);
+        out
+    }
+
+    fn watcher(governance: &Pubkey, budget: Arc<FeeBudgetManager>) -> GovernanceWatcher {
+        let config = GovernanceConfig { governance_address: governance.to_string(), state_path: None, ..Default::default() };
+        GovernanceWatcher::new(config, "http://localhost:8899", GovernedTargets { fee_budget: Some(budget), risk: None }).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_passed_proposal_applies_once_with_audit() {
+        let governance = Pubkey::new_unique();
+        let budget = Arc::new(FeeBudgetManager::new(FeeBudgetConfig::default()));
+        let watcher = watcher(&governance, budget.clone());
+        let link = r#"{"changes":[{"setting":"fee_daily_cap","bot":"EnhancedArbitrage","cap_sol":0.2}]}"#;
+
+        // Still voting: nothing happens and the proposal stays watched
+        assert!(watcher.process("p1", &proposal_account(&governance, 2, link)).await.is_none());
+        assert_eq!(budget.cap_for("EnhancedArbitrage"), 0.5);
+
+        let applied = watcher.process("p1", &proposal_account(&governance, 3, link)).await.unwrap();
+        assert_eq!(applied.name, "Lower arbitrage fee cap");
+        assert_eq!(budget.cap_for("EnhancedArbitrage"), 0.2);
+        assert!(watcher.process("p1", &proposal_account(&governance, 3, link)).await.is_none());
+        assert_eq!(watcher.get_audit_log().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_foreign_or_out_of_bounds_proposals_are_rejected() {
+        let governance = Pubkey::new_unique();
+        let budget = Arc::new(FeeBudgetManager::new(FeeBudgetConfig::default()));
+        let watcher = watcher(&governance, budget.clone());
+
+        let excessive = r#"{"changes":[{"setting":"fee_daily_cap","bot":null,"cap_sol":500.0}]}"#;
+        assert!(watcher.process("p2", &proposal_account(&governance, 5, excessive)).await.is_none());
+        let ok = r#"{"changes":[{"setting":"fee_daily_cap","bot":null,"cap_sol":1.0}]}"#;
+        assert!(watcher.process("p3", &proposal_account(&Pubkey::new_unique(), 3, ok)).await.is_none());
+        assert!(watcher.process("p4", &proposal_account(&governance, 3, "https://example.com/payload.json")).await.is_none());
+
+        assert_eq!(budget.cap_for("anything"), 0.5);
+        let log = watcher.get_audit_log().await;
+        assert_eq!(log.len(), 3);
+        assert!(log.iter().all(|entry| entry.severity == SecuritySeverity::Error));
+    }
+}
@@ -0,0 +1,140 @@
+//! Runtime integrity
+//!
+//! Identifies exactly what binary is running: package version, the source
+//! revision and build id stamped in by the release pipeline
+//! (`SNIPERFORGE_GIT_HASH`, `SNIPERFORGE_BUILD_ID` at compile time), the
+//! profile and the enabled cargo features. The build id is attached to audit
+//! entries and trade records, exposed through the API, and checked before
+//! real-money trading starts: binaries built with `unsafe-dev` or without the
+//! features the operator requires are refused.
+
+use std::sync::OnceLock;
+
+use serde::Serialize;
+use thiserror::Error;
+
+/// Comma-separated cargo features a binary must have to trade real money
+pub const REQUIRED_FEATURES_ENV: &str = "SNIPERFORGE_REQUIRED_FEATURES";
+
+/// Cargo features compiled into this binary
+fn enabled_features() -> Vec<&'static str> {
+    let mut features = Vec::new();
+    if cfg!(feature = "redis") {
+        features.push("redis");
+    }
+    if cfg!(feature = "unsafe-dev") {
+        features.push("unsafe-dev");
+    }
+    features
+}
+
+/// What exactly is running
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub git_hash: &'static str,
+    /// Release pipeline build identifier (signed artifact id)
+    pub build_id: &'static str,
+    pub profile: &'static str,
+    pub features: Vec<&'static str>,
+}
+
+impl BuildInfo {
+    fn current() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            git_hash: option_env!("SNIPERFORGE_GIT_HASH").unwrap_or("unknown"),
+            build_id: option_env!("SNIPERFORGE_BUILD_ID").unwrap_or("local"),
+            profile: if cfg!(debug_assertions) { "debug" } else { "release" },
+            features: enabled_features(),
+        }
+    }
+
+    /// Compact identifier recorded on audit entries and trades: `version+hash.build`
+    pub fn fingerprint(&self) -> String {
+        let hash = &self.git_hash[..self.git_hash.len().min(12)];
+        format!("{}+{}.{}", self.version, hash, self.build_id)
+    }
+
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| *f == feature)
+    }
+}
+
+/// Build information of the running binary
+pub fn build_info() -> &'static BuildInfo {
+    static INFO: OnceLock<BuildInfo> = OnceLock::new();
+    INFO.get_or_init(BuildInfo::current)
+}
+
+/// Fingerprint of the running binary
+pub fn build_fingerprint() -> String {
+    static FINGERPRINT: OnceLock<String> = OnceLock::new();
+    FINGERPRINT.get_or_init(|| build_info().fingerprint()).clone()
+}
+
+/// Why a binary may not trade real money
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum IntegrityError {
+    #[error("binary was built with the `unsafe-dev` feature")]
+    UnsafeDevBuild,
+    #[error("binary is missing required features: {}", .0.join(", "))]
+    MissingFeatures(Vec<String>),
+}
+
+/// Check `info` against the features required for real-money trading
+pub fn verify(info: &BuildInfo, required_features: &[String]) -> Result<(), IntegrityError> {
+    if info.has_feature("unsafe-dev") {
+        return Err(IntegrityError::UnsafeDevBuild);
+    }
+    let missing: Vec<String> = required_features
+        .iter()
+        .filter(|feature| !info.has_feature(feature))
+        .cloned()
+        .collect();
+    if !missing.is_empty() {
+        return Err(IntegrityError::MissingFeatures(missing));
+    }
+    Ok(())
+}
+
+/// Verify the running binary before enabling real-money trading
+pub fn verify_for_real_trading() -> Result<(), IntegrityError> {
+    let required: Vec<String> = std::env::var(REQUIRED_FEATURES_ENV)
+        .unwrap_or_default()
+        .split(',')
+        .map(|f| f.trim().to_string())
+        .filter(|f| !f.is_empty())
+        .collect();
+    verify(build_info(), &required)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(features: Vec<&'static str>) -> BuildInfo {
+        BuildInfo {
+            version: "3.0.0",
+            git_hash: "0123456789abcdef0123",
+            build_id: "ci-42",
+            profile: "release",
+            features,
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_truncates_hash() {
+        assert_eq!(info(vec![]).fingerprint(), "3.0.0+0123456789ab.ci-42");
+    }
+
+    #[test]
+    fn test_verify_rejects_unsafe_and_incomplete_builds() {
+        assert_eq!(verify(&info(vec!["unsafe-dev", "redis"]), &[]), Err(IntegrityError::UnsafeDevBuild));
+        assert_eq!(
+            verify(&info(vec![]), &["redis".to_string()]),
+            Err(IntegrityError::MissingFeatures(vec!["redis".to_string()]))
+        );
+        assert!(verify(&info(vec!["redis"]), &["redis".to_string()]).is_ok());
+    }
+}
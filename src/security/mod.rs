@@ -32,6 +32,12 @@ pub mod encryption;
 pub mod risk_manager;
 pub mod wallet;
 pub mod secure_wallet;
+pub mod treasury;
+pub mod dust;
+pub mod multisig;
+pub mod wallet_activity;
+pub mod integrity;
+pub mod governance;
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
@@ -49,7 +55,19 @@ pub use encryption::{
 };
 pub use risk_manager::*;
 pub use wallet::{WalletManager, WalletConfig, WalletType, WalletInfo, ManagedWallet, RiskManagement};
+pub use wallet::{ChainAccount, ChainAccounts, ChainBalance, ChainFamily, ChainProfile, EvmAccount};
 pub use secure_wallet::{SecureWalletManager, load_secure_wallet};
+pub use treasury::{TreasurySweeper, TreasurySweepConfig, SweepPlan, SweepRecord, SweepStatus, TreasurySnapshot};
+pub use dust::{DustConsolidator, DustConfig, DustReport, DustWallet, RpcDustWallet};
+pub use multisig::{MultisigGuard, MultisigConfig, MultisigProposal, ProposalStatus, HighValueOperation, SquadsChain, RpcSquadsChain};
+pub use governance::{
+    GovernanceWatcher, GovernanceConfig, GovernanceLimits, GovernancePayload, GovernedTargets, SettingChange,
+    RealmsProposal, ProposalState, AppliedProposal, parse_proposal,
+};
+pub use integrity::{BuildInfo, IntegrityError, build_info, build_fingerprint, verify_for_real_trading};
+pub use wallet_activity::{
+    IntentStore, intent_store, KillSwitch, TradingHalt, WalletActivityConfig, WalletActivityMonitor, WalletAnomaly,
+};
 
 /// Enterprise Security Framework
 /// 
@@ -139,6 +157,9 @@ pub struct SecurityAuditEntry {
     pub actor: Option<String>,
     /// IP address (if applicable)
     pub ip_address: Option<String>,
+    /// Fingerprint of the binary that recorded the event
+    #[serde(default)]
+    pub build: String,
 }
 
 /// Types of security events
@@ -411,6 +432,7 @@ impl SecurityFramework {
             metadata: metadata.unwrap_or_default(),
             actor: None, // Would be set in a real implementation
             ip_address,
+            build: build_fingerprint(),
         };
 
         if self.config.enable_audit_logging {
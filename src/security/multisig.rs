@@ -0,0 +1,942 @@
+//! # Squads Multisig Guard
+//!
+//! High-value operations (treasury sweeps, large cross-chain transfers) above a
+//! configurable threshold are not executed directly. Instead the operation's
+//! instructions are wrapped in a Squads v4 vault transaction and a proposal is
+//! created on chain for it. The guard polls the proposal account and executes
+//! the vault transaction once members approve. It fails safe: a proposal that
+//! does not collect enough approvals before its deadline expires locally and
+//! is never executed by this process.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::hash::hash;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::message::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::{keypair::Keypair, Signer};
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, VersionedTransaction};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::trading::execution::admit_shared;
+
+/// Squads v4 program ID
+pub const SQUADS_V4_PROGRAM_ID: &str = "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf";
+
+/// Byte offset of `transaction_index` in a Squads v4 `Multisig` account
+/// (discriminator, create_key, config_authority, threshold u16, time_lock u32)
+const MULTISIG_TRANSACTION_INDEX_OFFSET: usize = 8 + 32 + 32 + 2 + 4;
+
+/// Multisig guard configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultisigConfig {
+    /// Enable multisig routing for high-value operations
+    pub enabled: bool,
+    /// Squads multisig account address
+    pub multisig_address: String,
+    /// Vault index used as the source of funds
+    pub vault_index: u8,
+    /// Operations at or above this value (SOL) require a proposal
+    pub threshold_sol: f64,
+    /// Approvals required before the proposal may execute
+    pub required_approvals: u32,
+    /// Members allowed to approve proposals
+    pub members: Vec<String>,
+    /// Seconds before an unapproved proposal expires
+    pub approval_timeout_seconds: u64,
+    /// Tracked proposals survive restarts here
+    #[serde(default)]
+    pub state_path: Option<PathBuf>,
+}
+
+impl Default for MultisigConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            multisig_address: String::new(),
+            vault_index: 0,
+            threshold_sol: 10.0,
+            required_approvals: 2,
+            members: Vec::new(),
+            approval_timeout_seconds: 6 * 60 * 60, // 6 hours
+            state_path: None,
+        }
+    }
+}
+
+/// Kind of high-value operation routed through the multisig
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum HighValueOperation {
+    TreasurySweep { wallet_name: String, destination: String },
+    CrossChainTransfer { source_chain: String, target_chain: String, bridge: String },
+    Custom(String),
+}
+
+/// Proposal lifecycle
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ProposalStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Expired,
+    Executed,
+}
+
+/// Tracked multisig proposal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultisigProposal {
+    pub id: Uuid,
+    pub operation: HighValueOperation,
+    pub amount_sol: f64,
+    pub transaction_index: u64,
+    pub transaction_pda: String,
+    pub proposal_pda: String,
+    pub approvals: Vec<String>,
+    pub rejections: Vec<String>,
+    pub status: ProposalStatus,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    /// Vault transaction the proposal executes (vault as payer)
+    pub message: Message,
+    /// Signature of the create transaction
+    pub create_signature: Option<String>,
+    /// Signature of the execute transaction, once sent
+    pub execute_signature: Option<String>,
+}
+
+/// Status of a Squads v4 `Proposal` account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnchainProposalStatus {
+    Draft,
+    Active,
+    Rejected,
+    Approved,
+    Executing,
+    Executed,
+    Cancelled,
+}
+
+/// Decoded Squads v4 `Proposal` account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OnchainProposal {
+    pub transaction_index: u64,
+    pub status: OnchainProposalStatus,
+    pub approved: Vec<Pubkey>,
+    pub rejected: Vec<Pubkey>,
+}
+
+/// Chain access for the guard: account reads and member-signed sends
+#[async_trait]
+pub trait SquadsChain: Send + Sync {
+    /// Member key that creates and executes proposals (and pays their rent)
+    fn member(&self) -> Pubkey;
+    /// Raw account data, `None` when the account does not exist
+    async fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>>;
+    /// Sign `instructions` with the member key, send and confirm
+    async fn send(&self, instructions: Vec<Instruction>) -> Result<String>;
+}
+
+/// [`SquadsChain`] over the nonblocking RPC client
+pub struct RpcSquadsChain {
+    rpc: Arc<RpcClient>,
+    member: Arc<Keypair>,
+}
+
+impl RpcSquadsChain {
+    pub fn new(rpc: Arc<RpcClient>, member: Arc<Keypair>) -> Self {
+        Self { rpc, member }
+    }
+}
+
+#[async_trait]
+impl SquadsChain for RpcSquadsChain {
+    fn member(&self) -> Pubkey {
+        self.member.pubkey()
+    }
+
+    async fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+        let response = self.rpc.get_account_with_commitment(address, self.rpc.commitment()).await?;
+        Ok(response.value.map(|account| account.data))
+    }
+
+    async fn send(&self, instructions: Vec<Instruction>) -> Result<String> {
+        let payer = self.member.pubkey();
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let transaction = Transaction::new_signed_with_payer(&instructions, Some(&payer), &[self.member.as_ref()], blockhash);
+        admit_shared(&payer.to_string(), None, &VersionedTransaction::from(transaction.clone()))?;
+        Ok(self.rpc.send_and_confirm_transaction(&transaction).await?.to_string())
+    }
+}
+
+/// Guard deciding when operations need multisig approval and tracking proposals
+pub struct MultisigGuard {
+    config: MultisigConfig,
+    program_id: Pubkey,
+    multisig: Pubkey,
+    chain: Option<Arc<dyn SquadsChain>>,
+    /// Creation reads the next index from the multisig account: one at a time
+    create_lock: Mutex<()>,
+    proposals: RwLock<HashMap<Uuid, MultisigProposal>>,
+}
+
+impl std::fmt::Debug for MultisigGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultisigGuard")
+            .field("config", &self.config)
+            .field("multisig", &self.multisig)
+            .field("on_chain", &self.chain.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl MultisigGuard {
+    /// Create a new multisig guard, restoring proposals from `state_path` when present
+    pub fn new(config: MultisigConfig) -> Result<Self> {
+        let program_id = Pubkey::from_str(SQUADS_V4_PROGRAM_ID)?;
+        let multisig = Pubkey::from_str(&config.multisig_address)
+            .map_err(|e| anyhow!("Invalid multisig address '{}': {}", config.multisig_address, e))?;
+
+        if config.required_approvals == 0 {
+            return Err(anyhow!("Multisig requires at least one approval"));
+        }
+        let proposals = match config.state_path.as_deref() {
+            Some(path) => Self::load(path)
+                .map_err(|e| anyhow!("Could not restore multisig proposals from {}: {}", path.display(), e))?,
+            None => HashMap::new(),
+        };
+
+        Ok(Self {
+            config,
+            program_id,
+            multisig,
+            chain: None,
+            create_lock: Mutex::new(()),
+            proposals: RwLock::new(proposals),
+        })
+    }
+
+    fn load(path: &Path) -> Result<HashMap<Uuid, MultisigProposal>> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => {
+                let proposals: Vec<MultisigProposal> = serde_json::from_str(&content)?;
+                Ok(proposals.into_iter().map(|p| (p.id, p)).collect())
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(HashMap::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save(&self, proposals: &HashMap<Uuid, MultisigProposal>) {
+        let Some(path) = &self.config.state_path else { return };
+        let mut ordered: Vec<&MultisigProposal> = proposals.values().collect();
+        ordered.sort_by_key(|p| p.transaction_index);
+        let result = (|| -> Result<()> {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            let temp_file = path.with_extension("tmp");
+            std::fs::write(&temp_file, serde_json::to_string_pretty(&ordered)?)?;
+            std::fs::rename(&temp_file, path)?;
+            Ok(())
+        })();
+        if let Err(e) = result {
+            warn!("⚠️ Failed to persist multisig proposals: {}", e);
+        }
+    }
+
+    /// Create, poll and execute proposals on chain through `chain`
+    pub fn with_chain(mut self, chain: Arc<dyn SquadsChain>) -> Self {
+        self.chain = Some(chain);
+        self
+    }
+
+    /// Whether an operation of this size must go through the multisig
+    pub fn requires_approval(&self, amount_sol: f64) -> bool {
+        self.config.enabled && amount_sol >= self.config.threshold_sol
+    }
+
+    /// Derive the Squads vault PDA for the configured vault index
+    pub fn vault_address(&self) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"multisig", self.multisig.as_ref(), b"vault", &[self.config.vault_index]],
+            &self.program_id,
+        ).0
+    }
+
+    /// Propose `instructions` (signed by the vault) for a high-value operation instead of executing it
+    ///
+    /// Sends VaultTransactionCreate and ProposalCreate for the multisig's next
+    /// transaction index in one member-signed transaction.
+    pub async fn create_proposal(
+        &self,
+        operation: HighValueOperation,
+        amount_sol: f64,
+        instructions: Vec<Instruction>,
+    ) -> Result<MultisigProposal> {
+        let chain = self.chain.as_ref().ok_or_else(|| anyhow!("No Squads chain attached to the multisig guard"))?;
+        let _creating = self.create_lock.lock().await;
+
+        let multisig_data = chain.account_data(&self.multisig).await?
+            .ok_or_else(|| anyhow!("Multisig account {} not found", self.multisig))?;
+        let transaction_index = parse_multisig_transaction_index(&multisig_data)? + 1;
+        let (transaction_pda, proposal_pda) = self.derive_proposal_addresses(transaction_index);
+
+        let message = Message::new(&instructions, Some(&self.vault_address()));
+        let member = chain.member();
+        let create = vec![
+            self.vault_transaction_create_ix(&transaction_pda, &member, &encode_transaction_message(&message)?),
+            self.proposal_create_ix(&proposal_pda, &member, transaction_index),
+        ];
+        let create_signature = chain.send(create).await?;
+
+        let now = Utc::now();
+        let proposal = MultisigProposal {
+            id: Uuid::new_v4(),
+            operation,
+            amount_sol,
+            transaction_index,
+            transaction_pda: transaction_pda.to_string(),
+            proposal_pda: proposal_pda.to_string(),
+            approvals: Vec::new(),
+            rejections: Vec::new(),
+            status: ProposalStatus::Pending,
+            created_at: now,
+            expires_at: now + Duration::seconds(self.config.approval_timeout_seconds as i64),
+            message,
+            create_signature: Some(create_signature),
+            execute_signature: None,
+        };
+
+        info!("📝 Multisig proposal #{} created for {:.4} SOL ({:?})",
+              transaction_index, amount_sol, proposal.operation);
+        let mut proposals = self.proposals.write().await;
+        proposals.insert(proposal.id, proposal.clone());
+        self.save(&proposals);
+        Ok(proposal)
+    }
+
+    /// Pull votes and status of open proposals from their proposal accounts
+    pub async fn sync_proposals(&self) -> Result<usize> {
+        let chain = self.chain.as_ref().ok_or_else(|| anyhow!("No Squads chain attached to the multisig guard"))?;
+        self.expire_stale().await;
+
+        let open: Vec<(Uuid, Pubkey)> = self.proposals.read().await.values()
+            .filter(|p| matches!(p.status, ProposalStatus::Pending | ProposalStatus::Approved))
+            .filter_map(|p| Pubkey::from_str(&p.proposal_pda).ok().map(|pda| (p.id, pda)))
+            .collect();
+
+        let mut updated = 0;
+        for (id, pda) in open {
+            let Some(data) = chain.account_data(&pda).await? else { continue };
+            let onchain = parse_proposal(&data)?;
+            let mut proposals = self.proposals.write().await;
+            let Some(proposal) = proposals.get_mut(&id) else { continue };
+
+            let status = match onchain.status {
+                OnchainProposalStatus::Draft | OnchainProposalStatus::Active => ProposalStatus::Pending,
+                OnchainProposalStatus::Approved | OnchainProposalStatus::Executing => ProposalStatus::Approved,
+                OnchainProposalStatus::Executed => ProposalStatus::Executed,
+                OnchainProposalStatus::Rejected | OnchainProposalStatus::Cancelled => ProposalStatus::Rejected,
+            };
+            proposal.approvals = onchain.approved.iter().map(Pubkey::to_string).collect();
+            proposal.rejections = onchain.rejected.iter().map(Pubkey::to_string).collect();
+            if proposal.status != status {
+                info!("🔏 Multisig proposal #{} is now {:?} ({} approvals)",
+                      proposal.transaction_index, status, proposal.approvals.len());
+                proposal.status = status;
+                updated += 1;
+                self.save(&proposals);
+            }
+        }
+        Ok(updated)
+    }
+
+    /// Sync proposals and execute the vault transaction of every approved one
+    pub async fn execute_approved(&self) -> Result<Vec<MultisigProposal>> {
+        let chain = self.chain.as_ref().ok_or_else(|| anyhow!("No Squads chain attached to the multisig guard"))?;
+        self.sync_proposals().await?;
+
+        let approved: Vec<MultisigProposal> = self.proposals.read().await.values()
+            .filter(|p| p.status == ProposalStatus::Approved)
+            .cloned()
+            .collect();
+
+        let mut executed = Vec::new();
+        for proposal in approved {
+            let instruction = self.vault_transaction_execute_ix(&proposal, &chain.member())?;
+            match chain.send(vec![instruction]).await {
+                Ok(signature) => {
+                    // The vault transaction ran on chain: record it whatever the local view became meanwhile
+                    let mut proposals = self.proposals.write().await;
+                    if let Some(stored) = proposals.get_mut(&proposal.id) {
+                        if stored.status != ProposalStatus::Approved {
+                            warn!("⚠️ Multisig proposal #{} was {:?} locally but executed on chain",
+                                  proposal.transaction_index, stored.status);
+                        }
+                        stored.status = ProposalStatus::Executed;
+                        stored.execute_signature = Some(signature.clone());
+                        executed.push(stored.clone());
+                    }
+                    self.save(&proposals);
+                    info!("✅ Multisig proposal #{} executed ({})", proposal.transaction_index, signature);
+                }
+                // A time lock or a stale RPC view: retried on the next poll
+                Err(e) => warn!("⚠️ Multisig proposal #{} not executed yet: {}", proposal.transaction_index, e),
+            }
+        }
+        Ok(executed)
+    }
+
+    /// Spawn the task polling and executing approved proposals
+    pub fn start_executor(self: Arc<Self>, poll_interval: std::time::Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(poll_interval);
+            loop {
+                interval.tick().await;
+                if let Err(e) = self.execute_approved().await {
+                    error!("❌ Multisig proposal poll failed: {}", e);
+                }
+            }
+        })
+    }
+
+    /// Whether an operation matching `matches` still has a proposal awaiting a decision
+    pub async fn has_open_proposal(&self, matches: impl Fn(&HighValueOperation) -> bool) -> bool {
+        self.expire_stale().await;
+        self.proposals.read().await.values()
+            .any(|p| matches!(p.status, ProposalStatus::Pending | ProposalStatus::Approved) && matches(&p.operation))
+    }
+
+    /// Record a member approval
+    pub async fn record_approval(&self, proposal_id: Uuid, member: &str) -> Result<ProposalStatus> {
+        self.record_vote(proposal_id, member, true).await
+    }
+
+    /// Record a member rejection
+    pub async fn record_rejection(&self, proposal_id: Uuid, member: &str) -> Result<ProposalStatus> {
+        self.record_vote(proposal_id, member, false).await
+    }
+
+    /// Current status of a proposal (expires stale proposals first)
+    pub async fn get_status(&self, proposal_id: Uuid) -> Option<ProposalStatus> {
+        self.expire_stale().await;
+        self.proposals.read().await.get(&proposal_id).map(|p| p.status.clone())
+    }
+
+    /// Mark an approved proposal as executed; refuses anything not approved
+    pub async fn mark_executed(&self, proposal_id: Uuid) -> Result<()> {
+        self.expire_stale().await;
+        let mut proposals = self.proposals.write().await;
+        let proposal = proposals.get_mut(&proposal_id)
+            .ok_or_else(|| anyhow!("Unknown proposal {}", proposal_id))?;
+
+        if proposal.status != ProposalStatus::Approved {
+            return Err(anyhow!("Proposal {} is {:?}, refusing to execute", proposal_id, proposal.status));
+        }
+        proposal.status = ProposalStatus::Executed;
+        self.save(&proposals);
+        Ok(())
+    }
+
+    /// Expire pending proposals past their deadline
+    pub async fn expire_stale(&self) -> usize {
+        let now = Utc::now();
+        let mut expired = 0;
+        let mut proposals = self.proposals.write().await;
+        for proposal in proposals.values_mut() {
+            if proposal.status == ProposalStatus::Pending && now >= proposal.expires_at {
+                proposal.status = ProposalStatus::Expired;
+                expired += 1;
+                warn!("⏰ Multisig proposal #{} expired with {}/{} approvals",
+                      proposal.transaction_index, proposal.approvals.len(), self.config.required_approvals);
+            }
+        }
+        if expired > 0 {
+            self.save(&proposals);
+        }
+        expired
+    }
+
+    /// List all tracked proposals
+    pub async fn list_proposals(&self) -> Vec<MultisigProposal> {
+        self.proposals.read().await.values().cloned().collect()
+    }
+
+    async fn record_vote(&self, proposal_id: Uuid, member: &str, approve: bool) -> Result<ProposalStatus> {
+        if !self.config.members.iter().any(|m| m == member) {
+            return Err(anyhow!("'{}' is not a multisig member", member));
+        }
+
+        self.expire_stale().await;
+        let mut proposals = self.proposals.write().await;
+        let proposal = proposals.get_mut(&proposal_id)
+            .ok_or_else(|| anyhow!("Unknown proposal {}", proposal_id))?;
+
+        if proposal.status != ProposalStatus::Pending {
+            return Err(anyhow!("Proposal {} is no longer pending ({:?})", proposal_id, proposal.status));
+        }
+
+        let member = member.to_string();
+        if approve {
+            if !proposal.approvals.contains(&member) {
+                proposal.approvals.push(member);
+            }
+        } else if !proposal.rejections.contains(&member) {
+            proposal.rejections.push(member);
+        }
+
+        let members = self.config.members.len() as u32;
+        if proposal.approvals.len() as u32 >= self.config.required_approvals {
+            proposal.status = ProposalStatus::Approved;
+        } else if members.saturating_sub(proposal.rejections.len() as u32) < self.config.required_approvals {
+            // Not enough members left to reach the threshold
+            proposal.status = ProposalStatus::Rejected;
+        }
+
+        let status = proposal.status.clone();
+        self.save(&proposals);
+        Ok(status)
+    }
+
+    fn derive_proposal_addresses(&self, transaction_index: u64) -> (Pubkey, Pubkey) {
+        let index_bytes = transaction_index.to_le_bytes();
+        let (transaction_pda, _) = Pubkey::find_program_address(
+            &[b"multisig", self.multisig.as_ref(), b"transaction", &index_bytes],
+            &self.program_id,
+        );
+        let (proposal_pda, _) = Pubkey::find_program_address(
+            &[b"multisig", self.multisig.as_ref(), b"transaction", &index_bytes, b"proposal"],
+            &self.program_id,
+        );
+        (transaction_pda, proposal_pda)
+    }
+
+    fn vault_transaction_create_ix(&self, transaction_pda: &Pubkey, member: &Pubkey, transaction_message: &[u8]) -> Instruction {
+        // VaultTransactionCreateArgs { vault_index, ephemeral_signers, transaction_message, memo: None }
+        let mut data = anchor_discriminator("vault_transaction_create").to_vec();
+        data.push(self.config.vault_index);
+        data.push(0);
+        data.extend_from_slice(&(transaction_message.len() as u32).to_le_bytes());
+        data.extend_from_slice(transaction_message);
+        data.push(0);
+
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.multisig, false),
+                AccountMeta::new(*transaction_pda, false),
+                AccountMeta::new_readonly(*member, true),
+                AccountMeta::new(*member, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data,
+        }
+    }
+
+    fn proposal_create_ix(&self, proposal_pda: &Pubkey, member: &Pubkey, transaction_index: u64) -> Instruction {
+        // ProposalCreateArgs { transaction_index, draft: false }
+        let mut data = anchor_discriminator("proposal_create").to_vec();
+        data.extend_from_slice(&transaction_index.to_le_bytes());
+        data.push(0);
+
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(self.multisig, false),
+                AccountMeta::new(*proposal_pda, false),
+                AccountMeta::new_readonly(*member, true),
+                AccountMeta::new(*member, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data,
+        }
+    }
+
+    fn vault_transaction_execute_ix(&self, proposal: &MultisigProposal, member: &Pubkey) -> Result<Instruction> {
+        let mut accounts = vec![
+            AccountMeta::new_readonly(self.multisig, false),
+            AccountMeta::new(Pubkey::from_str(&proposal.proposal_pda)?, false),
+            AccountMeta::new_readonly(Pubkey::from_str(&proposal.transaction_pda)?, false),
+            AccountMeta::new_readonly(*member, true),
+        ];
+        // Remaining accounts: the vault message's keys; the program signs for the vault itself
+        let header = &proposal.message.header;
+        let signers = header.num_required_signatures as usize;
+        let writable_signers = signers - header.num_readonly_signed_accounts as usize;
+        let writable_non_signers = proposal.message.account_keys.len() - signers - header.num_readonly_unsigned_accounts as usize;
+        for (i, key) in proposal.message.account_keys.iter().enumerate() {
+            let writable = i < writable_signers || (i >= signers && i < signers + writable_non_signers);
+            accounts.push(AccountMeta { pubkey: *key, is_signer: false, is_writable: writable });
+        }
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: anchor_discriminator("vault_transaction_execute").to_vec(),
+        })
+    }
+}
+
+/// Anchor instruction discriminator: first 8 bytes of sha256("global:<name>")
+fn anchor_discriminator(name: &str) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(format!("global:{}", name).as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+/// Index of the last transaction created on a Squads v4 `Multisig` account
+pub fn parse_multisig_transaction_index(data: &[u8]) -> Result<u64> {
+    let bytes = data.get(MULTISIG_TRANSACTION_INDEX_OFFSET..MULTISIG_TRANSACTION_INDEX_OFFSET + 8)
+        .ok_or_else(|| anyhow!("Multisig account too short ({} bytes)", data.len()))?;
+    Ok(u64::from_le_bytes(bytes.try_into()?))
+}
+
+/// Decode a Squads v4 `Proposal` account
+pub fn parse_proposal(data: &[u8]) -> Result<OnchainProposal> {
+    let mut reader = AccountReader { data, offset: 8 };
+    reader.take(32)?; // multisig
+    let transaction_index = u64::from_le_bytes(reader.take(8)?.try_into()?);
+    let status = match reader.take(1)?[0] {
+        0 => OnchainProposalStatus::Draft,
+        1 => OnchainProposalStatus::Active,
+        2 => OnchainProposalStatus::Rejected,
+        3 => OnchainProposalStatus::Approved,
+        4 => OnchainProposalStatus::Executing,
+        5 => OnchainProposalStatus::Executed,
+        6 => OnchainProposalStatus::Cancelled,
+        other => return Err(anyhow!("Unknown proposal status {}", other)),
+    };
+    if status != OnchainProposalStatus::Executing {
+        reader.take(8)?; // timestamp
+    }
+    reader.take(1)?; // bump
+    let approved = reader.pubkeys()?;
+    let rejected = reader.pubkeys()?;
+    Ok(OnchainProposal { transaction_index, status, approved, rejected })
+}
+
+/// Serialize a vault message in Squads' compact `TransactionMessage` layout
+pub fn encode_transaction_message(message: &Message) -> Result<Vec<u8>> {
+    let header = &message.header;
+    let signers = header.num_required_signatures;
+    let keys = message.account_keys.len();
+    if keys > u8::MAX as usize || message.instructions.len() > u8::MAX as usize {
+        return Err(anyhow!("Vault transaction too large ({} accounts, {} instructions)", keys, message.instructions.len()));
+    }
+
+    let mut out = vec![
+        signers,
+        signers - header.num_readonly_signed_accounts,
+        (keys - signers as usize - header.num_readonly_unsigned_accounts as usize) as u8,
+        keys as u8,
+    ];
+    for key in &message.account_keys {
+        out.extend_from_slice(key.as_ref());
+    }
+    out.push(message.instructions.len() as u8);
+    for instruction in &message.instructions {
+        out.push(instruction.program_id_index);
+        out.push(instruction.accounts.len() as u8);
+        out.extend_from_slice(&instruction.accounts);
+        out.extend_from_slice(&u16::try_from(instruction.data.len())?.to_le_bytes());
+        out.extend_from_slice(&instruction.data);
+    }
+    out.push(0); // address table lookups
+    Ok(out)
+}
+
+struct AccountReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> AccountReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let bytes = self.data.get(self.offset..self.offset + len)
+            .ok_or_else(|| anyhow!("Account data ends at {} (wanted {} more bytes)", self.data.len(), len))?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn pubkeys(&mut self) -> Result<Vec<Pubkey>> {
+        let len = u32::from_le_bytes(self.take(4)?.try_into()?) as usize;
+        (0..len).map(|_| Ok(Pubkey::try_from(self.take(32)?)?)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex as SyncMutex;
+    use solana_sdk::system_instruction;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use tokio::sync::Notify;
+
+    /// Multisig at transaction index 7 whose proposal accounts are set by the test
+    struct FakeChain {
+        member: Pubkey,
+        accounts: SyncMutex<HashMap<Pubkey, Vec<u8>>>,
+        sent: SyncMutex<Vec<Vec<Instruction>>>,
+        rpc_down: AtomicBool,
+    }
+
+    impl FakeChain {
+        fn new(multisig: &Pubkey) -> Arc<Self> {
+            let mut data = vec![0u8; MULTISIG_TRANSACTION_INDEX_OFFSET];
+            data.extend_from_slice(&7u64.to_le_bytes());
+            data.extend_from_slice(&[0u8; 16]);
+            Arc::new(Self {
+                member: Pubkey::new_unique(),
+                accounts: SyncMutex::new(HashMap::from([(*multisig, data)])),
+                sent: SyncMutex::new(Vec::new()),
+                rpc_down: AtomicBool::new(false),
+            })
+        }
+
+        fn set_proposal(&self, pda: &str, status: u8, approved: &[Pubkey]) {
+            let mut data = vec![0u8; 8 + 32];
+            data.extend_from_slice(&8u64.to_le_bytes());
+            data.push(status);
+            data.extend_from_slice(&[0u8; 8]); // timestamp
+            data.push(255); // bump
+            data.extend_from_slice(&(approved.len() as u32).to_le_bytes());
+            approved.iter().for_each(|key| data.extend_from_slice(key.as_ref()));
+            data.extend_from_slice(&[0u8; 8]); // no rejections or cancellations
+            self.accounts.lock().insert(Pubkey::from_str(pda).unwrap(), data);
+        }
+    }
+
+    #[async_trait]
+    impl SquadsChain for FakeChain {
+        fn member(&self) -> Pubkey {
+            self.member
+        }
+
+        async fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+            if self.rpc_down.load(Ordering::SeqCst) {
+                return Err(anyhow!("RPC request failed: connection refused"));
+            }
+            Ok(self.accounts.lock().get(address).cloned())
+        }
+
+        async fn send(&self, instructions: Vec<Instruction>) -> Result<String> {
+            self.sent.lock().push(instructions);
+            Ok(format!("sig-{}", self.sent.lock().len()))
+        }
+    }
+
+    /// Holds every execute send until the test releases it
+    struct RacingChain {
+        inner: Arc<FakeChain>,
+        executing: Notify,
+        release: Notify,
+    }
+
+    #[async_trait]
+    impl SquadsChain for RacingChain {
+        fn member(&self) -> Pubkey {
+            self.inner.member
+        }
+
+        async fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+            self.inner.account_data(address).await
+        }
+
+        async fn send(&self, instructions: Vec<Instruction>) -> Result<String> {
+            if instructions[0].data[..8] == anchor_discriminator("vault_transaction_execute") {
+                self.executing.notify_one();
+                self.release.notified().await;
+            }
+            self.inner.send(instructions).await
+        }
+    }
+
+    fn test_guard(timeout: u64) -> (MultisigGuard, Arc<FakeChain>) {
+        let multisig = Pubkey::new_unique();
+        let chain = FakeChain::new(&multisig);
+        let guard = MultisigGuard::new(MultisigConfig {
+            enabled: true,
+            multisig_address: multisig.to_string(),
+            threshold_sol: 5.0,
+            required_approvals: 2,
+            members: vec!["alice".to_string(), "bob".to_string(), "carol".to_string()],
+            approval_timeout_seconds: timeout,
+            ..Default::default()
+        }).unwrap().with_chain(chain.clone());
+        (guard, chain)
+    }
+
+    fn transfer(guard: &MultisigGuard) -> Vec<Instruction> {
+        vec![system_instruction::transfer(&guard.vault_address(), &Pubkey::new_unique(), 10_000_000_000)]
+    }
+
+    #[tokio::test]
+    async fn test_proposal_approval_flow() {
+        let (guard, _chain) = test_guard(3600);
+        assert!(guard.requires_approval(5.0));
+        assert!(!guard.requires_approval(1.0));
+
+        let proposal = guard.create_proposal(HighValueOperation::Custom("test".to_string()), 10.0, transfer(&guard)).await.unwrap();
+        assert_eq!(guard.record_approval(proposal.id, "alice").await.unwrap(), ProposalStatus::Pending);
+        assert!(guard.mark_executed(proposal.id).await.is_err());
+        assert_eq!(guard.record_approval(proposal.id, "bob").await.unwrap(), ProposalStatus::Approved);
+        assert!(guard.mark_executed(proposal.id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_proposal_fails_safe_on_timeout() {
+        let (guard, _chain) = test_guard(0);
+        let proposal = guard.create_proposal(HighValueOperation::Custom("test".to_string()), 10.0, transfer(&guard)).await.unwrap();
+        assert_eq!(guard.get_status(proposal.id).await, Some(ProposalStatus::Expired));
+        assert!(guard.record_approval(proposal.id, "alice").await.is_err());
+        assert!(guard.mark_executed(proposal.id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_non_member_cannot_vote() {
+        let (guard, _chain) = test_guard(3600);
+        let proposal = guard.create_proposal(HighValueOperation::Custom("test".to_string()), 10.0, transfer(&guard)).await.unwrap();
+        assert!(guard.record_approval(proposal.id, "mallory").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_proposal_created_polled_and_executed_on_chain() {
+        let (guard, chain) = test_guard(3600);
+        let proposal = guard.create_proposal(HighValueOperation::Custom("test".to_string()), 10.0, transfer(&guard)).await.unwrap();
+
+        // Next index after the multisig's 7, created in one transaction
+        assert_eq!(proposal.transaction_index, 8);
+        let create = chain.sent.lock()[0].clone();
+        assert_eq!(create[0].data[..8], anchor_discriminator("vault_transaction_create"));
+        assert_eq!(create[1].data[..8], anchor_discriminator("proposal_create"));
+        assert_eq!(create[1].data[8..16], 8u64.to_le_bytes());
+        assert_eq!(create[0].accounts[1].pubkey.to_string(), proposal.transaction_pda);
+        // Vault pays and signs the inner transfer
+        let encoded = encode_transaction_message(&proposal.message).unwrap();
+        assert_eq!(&encoded[..4], &[1, 1, 1, 3]);
+        assert_eq!(&encoded[4..36], guard.vault_address().as_ref());
+
+        // Still collecting votes: nothing to execute
+        let alice = Pubkey::new_unique();
+        chain.set_proposal(&proposal.proposal_pda, 1, &[alice]);
+        assert!(guard.execute_approved().await.unwrap().is_empty());
+        assert_eq!(guard.get_status(proposal.id).await, Some(ProposalStatus::Pending));
+
+        chain.set_proposal(&proposal.proposal_pda, 3, &[alice, Pubkey::new_unique()]);
+        let executed = guard.execute_approved().await.unwrap();
+        assert_eq!(executed.len(), 1);
+        assert_eq!(executed[0].approvals.len(), 2);
+        assert_eq!(executed[0].execute_signature.as_deref(), Some("sig-2"));
+        let execute = chain.sent.lock()[1][0].clone();
+        assert_eq!(execute.data, anchor_discriminator("vault_transaction_execute"));
+        // Vault and destination writable, system program read-only, none signing
+        let remaining: Vec<(bool, bool)> = execute.accounts[4..].iter().map(|a| (a.is_signer, a.is_writable)).collect();
+        assert_eq!(remaining, vec![(false, true), (false, true), (false, false)]);
+        assert_eq!(guard.get_status(proposal.id).await, Some(ProposalStatus::Executed));
+    }
+
+    #[tokio::test]
+    async fn test_proposals_survive_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let (guard, chain) = test_guard(3600);
+        let config = MultisigConfig { state_path: Some(dir.path().join("multisig.json")), ..guard.config.clone() };
+        let guard = MultisigGuard::new(config.clone()).unwrap().with_chain(chain.clone());
+        let operation = HighValueOperation::Custom("test".to_string());
+        let proposal = guard.create_proposal(operation.clone(), 10.0, transfer(&guard)).await.unwrap();
+        guard.record_approval(proposal.id, "alice").await.unwrap();
+
+        // Restarted process: the open proposal and its votes carry over, so it is neither re-proposed nor lost
+        let restarted = MultisigGuard::new(config).unwrap().with_chain(chain.clone());
+        assert!(restarted.has_open_proposal(|open| *open == operation).await);
+        assert_eq!(restarted.record_approval(proposal.id, "bob").await.unwrap(), ProposalStatus::Approved);
+
+        chain.set_proposal(&proposal.proposal_pda, 3, &[Pubkey::new_unique(), Pubkey::new_unique()]);
+        let executed = restarted.execute_approved().await.unwrap();
+        assert_eq!(executed.len(), 1);
+        assert_eq!(executed[0].id, proposal.id);
+        assert_eq!(executed[0].message, proposal.message);
+    }
+
+    #[tokio::test]
+    async fn test_execute_recorded_when_proposal_expired_while_sending() {
+        let (guard, inner) = test_guard(3600);
+        let chain = Arc::new(RacingChain { inner: inner.clone(), executing: Notify::new(), release: Notify::new() });
+        let guard = Arc::new(MultisigGuard::new(guard.config.clone()).unwrap().with_chain(chain.clone()));
+        let proposal = guard.create_proposal(HighValueOperation::Custom("test".to_string()), 10.0, transfer(&guard)).await.unwrap();
+        inner.set_proposal(&proposal.proposal_pda, 3, &[Pubkey::new_unique(), Pubkey::new_unique()]);
+
+        let executor = tokio::spawn({
+            let guard = guard.clone();
+            async move { guard.execute_approved().await }
+        });
+        // While the execute is in flight, the local view falls back to pending and expires
+        chain.executing.notified().await;
+        if let Some(stored) = guard.proposals.write().await.get_mut(&proposal.id) {
+            stored.status = ProposalStatus::Pending;
+            stored.expires_at = Utc::now() - Duration::seconds(1);
+        }
+        assert_eq!(guard.expire_stale().await, 1);
+        chain.release.notify_one();
+
+        // The vault transaction ran on chain: the poll succeeds and records it
+        let executed = executor.await.unwrap().unwrap();
+        assert_eq!(executed.len(), 1);
+        assert_eq!(executed[0].execute_signature.as_deref(), Some("sig-2"));
+        assert_eq!(guard.get_status(proposal.id).await, Some(ProposalStatus::Executed));
+    }
+
+    #[tokio::test]
+    async fn test_rpc_failure_during_sync_executes_nothing() {
+        let (guard, chain) = test_guard(3600);
+        let proposal = guard.create_proposal(HighValueOperation::Custom("test".to_string()), 10.0, transfer(&guard)).await.unwrap();
+        chain.set_proposal(&proposal.proposal_pda, 3, &[Pubkey::new_unique(), Pubkey::new_unique()]);
+
+        // Approved on chain, but the poll cannot see it: the error surfaces and nothing is sent
+        chain.rpc_down.store(true, Ordering::SeqCst);
+        let error = guard.execute_approved().await.unwrap_err();
+        assert!(error.to_string().contains("connection refused"));
+        assert!(guard.sync_proposals().await.is_err());
+        assert_eq!(chain.sent.lock().len(), 1);
+        assert_eq!(guard.get_status(proposal.id).await, Some(ProposalStatus::Pending));
+
+        // The next poll after the RPC recovers picks it up
+        chain.rpc_down.store(false, Ordering::SeqCst);
+        assert_eq!(guard.execute_approved().await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_corrupt_state_file_refuses_to_start() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_path = dir.path().join("multisig.json");
+        std::fs::write(&state_path, "[{\"id\": \"not-a-proposal\"").unwrap();
+        let config = MultisigConfig {
+            multisig_address: Pubkey::new_unique().to_string(),
+            state_path: Some(state_path.clone()),
+            ..Default::default()
+        };
+
+        // Starting empty would forget open proposals and propose the same sweep twice
+        let error = MultisigGuard::new(config).unwrap_err().to_string();
+        assert!(error.contains("Could not restore multisig proposals"), "{}", error);
+        assert!(error.contains(&state_path.display().to_string()), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn test_proposal_needs_chain() {
+        let guard = MultisigGuard::new(MultisigConfig {
+            multisig_address: Pubkey::new_unique().to_string(),
+            ..Default::default()
+        }).unwrap();
+        assert!(guard.create_proposal(HighValueOperation::Custom("test".to_string()), 10.0, Vec::new()).await.is_err());
+    }
+}
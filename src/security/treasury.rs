@@ -0,0 +1,674 @@
+//! # Treasury Sweep
+//!
+//! Periodically moves realized profits above a retention threshold from hot
+//! trading wallets to a configured cold/treasury address. Every sweep (including
+//! dry-run previews) is recorded in an audit trail using the security framework's
+//! `SecurityAuditEntry` format; the state file keeps the most recent entries and
+//! the full trail is appended to a JSON-lines file beside it. Sweeps above the
+//! multisig threshold are parked in the Squads vault, and a proposal releases
+//! them to the treasury once the members approve.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{hash::Hash, pubkey::Pubkey, system_instruction, transaction::{Transaction, VersionedTransaction}};
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use super::multisig::{HighValueOperation, MultisigGuard};
+use super::wallet::WalletManager;
+use crate::trading::execution::admit_shared;
+use super::{SecurityAuditEntry, SecurityEventType, SecuritySeverity};
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+/// Treasury sweep policy configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TreasurySweepConfig {
+    /// Master switch for automatic sweeps
+    pub enabled: bool,
+    /// Destination cold/treasury address (base58)
+    pub treasury_address: String,
+    /// Managed wallet names eligible for sweeping
+    pub source_wallets: Vec<String>,
+    /// Realized profit kept in the hot wallet as working capital (SOL)
+    pub retention_threshold_sol: f64,
+    /// Sweeps smaller than this are skipped to avoid dust transfers (SOL)
+    pub min_sweep_amount_sol: f64,
+    /// SOL always left in the wallet to pay fees and rent
+    pub fee_reserve_sol: f64,
+    /// Interval between scheduled sweeps in seconds
+    pub sweep_interval_seconds: u64,
+    /// When true, sweeps are only planned and audited, never sent
+    pub dry_run: bool,
+    /// Unswept profits, recent history and audit trail, and last sweep survive restarts here;
+    /// every audit entry is also appended to `<state>.audit.jsonl`
+    #[serde(default)]
+    pub state_path: Option<PathBuf>,
+    /// Sweep records and audit entries kept in memory and in the state file
+    #[serde(default = "default_history_limit")]
+    pub history_limit: usize,
+}
+
+fn default_history_limit() -> usize {
+    500
+}
+
+impl Default for TreasurySweepConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false, // Conservative default - must be explicitly enabled
+            treasury_address: String::new(),
+            source_wallets: vec!["trading".to_string()],
+            retention_threshold_sol: 1.0,
+            min_sweep_amount_sol: 0.1,
+            fee_reserve_sol: 0.05,
+            sweep_interval_seconds: 24 * 60 * 60, // Daily
+            dry_run: true,
+            state_path: None,
+            history_limit: default_history_limit(),
+        }
+    }
+}
+
+/// Planned transfer for a single wallet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepPlan {
+    pub wallet_name: String,
+    pub source_address: String,
+    pub destination_address: String,
+    pub realized_profit_sol: f64,
+    pub wallet_balance_sol: f64,
+    pub sweep_amount_sol: f64,
+    pub dry_run: bool,
+    pub planned_at: DateTime<Utc>,
+}
+
+/// Outcome of an executed (or previewed) sweep
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SweepStatus {
+    Previewed,
+    Executed,
+    ProposalCreated,
+    Skipped,
+    Failed,
+}
+
+/// Record of a sweep attempt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepRecord {
+    pub plan: SweepPlan,
+    pub status: SweepStatus,
+    pub signature: Option<String>,
+    pub proposal_id: Option<Uuid>,
+    pub error: Option<String>,
+    pub completed_at: DateTime<Utc>,
+}
+
+/// Serializable sweeper state
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TreasurySnapshot {
+    pub realized_profits: HashMap<String, f64>,
+    pub last_sweep: Option<DateTime<Utc>>,
+}
+
+/// Everything the sweeper persists between runs
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct TreasuryState {
+    realized_profits: HashMap<String, f64>,
+    last_sweep: Option<DateTime<Utc>>,
+    history: Vec<SweepRecord>,
+    audit_log: Vec<SecurityAuditEntry>,
+}
+
+/// Chain access for sweeps
+#[async_trait]
+pub trait TreasuryChain: Send + Sync {
+    async fn balance_sol(&self, address: &Pubkey) -> Result<f64>;
+    async fn latest_blockhash(&self) -> Result<Hash>;
+    async fn send_and_confirm(&self, transaction: &Transaction) -> Result<String>;
+}
+
+#[async_trait]
+impl TreasuryChain for RpcClient {
+    async fn balance_sol(&self, address: &Pubkey) -> Result<f64> {
+        Ok(self.get_balance(address).await? as f64 / LAMPORTS_PER_SOL)
+    }
+
+    async fn latest_blockhash(&self) -> Result<Hash> {
+        Ok(self.get_latest_blockhash().await?)
+    }
+
+    async fn send_and_confirm(&self, transaction: &Transaction) -> Result<String> {
+        Ok(self.send_and_confirm_transaction(transaction).await?.to_string())
+    }
+}
+
+/// Compute how much SOL can be swept from a wallet
+///
+/// Only realized profit above the retention threshold is eligible, and the
+/// transfer never dips into the fee reserve.
+pub fn compute_sweep_amount(
+    realized_profit_sol: f64,
+    wallet_balance_sol: f64,
+    config: &TreasurySweepConfig,
+) -> f64 {
+    let excess_profit = (realized_profit_sol - config.retention_threshold_sol).max(0.0);
+    let spendable = (wallet_balance_sol - config.fee_reserve_sol).max(0.0);
+    let amount = excess_profit.min(spendable);
+
+    if amount < config.min_sweep_amount_sol {
+        0.0
+    } else {
+        amount
+    }
+}
+
+/// Treasury sweeper moving realized profits to cold storage
+pub struct TreasurySweeper {
+    config: TreasurySweepConfig,
+    wallet_manager: Arc<WalletManager>,
+    chain: Arc<dyn TreasuryChain>,
+    multisig: Option<Arc<MultisigGuard>>,
+    realized_profits: RwLock<HashMap<String, f64>>,
+    history: RwLock<Vec<SweepRecord>>,
+    audit_log: RwLock<Vec<SecurityAuditEntry>>,
+    last_sweep: RwLock<Option<DateTime<Utc>>>,
+}
+
+impl TreasurySweeper {
+    /// Create a new treasury sweeper, restoring state from `state_path` when present
+    pub fn new(
+        config: TreasurySweepConfig,
+        wallet_manager: Arc<WalletManager>,
+        chain: Arc<dyn TreasuryChain>,
+    ) -> Result<Self> {
+        Pubkey::from_str(&config.treasury_address)
+            .map_err(|e| anyhow!("Invalid treasury address '{}': {}", config.treasury_address, e))?;
+        let state = match config.state_path.as_deref() {
+            Some(path) => Self::load(path)
+                .map_err(|e| anyhow!("Could not restore treasury state from {}: {}", path.display(), e))?,
+            None => TreasuryState::default(),
+        };
+
+        Ok(Self {
+            config,
+            wallet_manager,
+            chain,
+            multisig: None,
+            realized_profits: RwLock::new(state.realized_profits),
+            history: RwLock::new(state.history),
+            audit_log: RwLock::new(state.audit_log),
+            last_sweep: RwLock::new(state.last_sweep),
+        })
+    }
+
+    fn load(path: &Path) -> Result<TreasuryState> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(TreasuryState::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn save(&self) {
+        let Some(path) = &self.config.state_path else { return };
+        let state = TreasuryState {
+            realized_profits: self.realized_profits.read().await.clone(),
+            last_sweep: *self.last_sweep.read().await,
+            history: self.history.read().await.clone(),
+            audit_log: self.audit_log.read().await.clone(),
+        };
+        let result = (|| -> Result<()> {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            let temp_file = path.with_extension("tmp");
+            std::fs::write(&temp_file, serde_json::to_string_pretty(&state)?)?;
+            std::fs::rename(&temp_file, path)?;
+            Ok(())
+        })();
+        if let Err(e) = result {
+            warn!("⚠️ Failed to persist treasury state: {}", e);
+        }
+    }
+
+    /// Route sweeps above the multisig threshold through Squads proposals
+    pub fn with_multisig(mut self, guard: Arc<MultisigGuard>) -> Self {
+        self.multisig = Some(guard);
+        self
+    }
+
+    /// Whether sweeps are only previewed
+    pub fn is_dry_run(&self) -> bool {
+        self.config.dry_run
+    }
+
+    /// Record realized profit (or loss, if negative) for a wallet
+    pub async fn record_realized_profit(&self, wallet_name: &str, profit_sol: f64) {
+        *self.realized_profits.write().await.entry(wallet_name.to_string()).or_insert(0.0) += profit_sol;
+        self.save().await;
+    }
+
+    /// Unswept profit per wallet and last sweep time, for state snapshots
+    pub async fn export_snapshot(&self) -> TreasurySnapshot {
+        TreasurySnapshot {
+            realized_profits: self.realized_profits.read().await.clone(),
+            last_sweep: *self.last_sweep.read().await,
+        }
+    }
+
+    /// Restore sweep budgets from a state snapshot
+    pub async fn restore_snapshot(&self, snapshot: TreasurySnapshot) {
+        *self.realized_profits.write().await = snapshot.realized_profits;
+        *self.last_sweep.write().await = snapshot.last_sweep;
+        self.save().await;
+    }
+
+    /// Check whether the schedule says a sweep is due
+    pub async fn is_sweep_due(&self) -> bool {
+        match *self.last_sweep.read().await {
+            Some(last) => Utc::now() - last >= Duration::seconds(self.config.sweep_interval_seconds as i64),
+            None => true,
+        }
+    }
+
+    /// Build sweep plans for all source wallets without sending anything
+    pub async fn preview(&self) -> Vec<SweepPlan> {
+        let profits = self.realized_profits.read().await.clone();
+        let mut plans = Vec::new();
+
+        for wallet_name in &self.config.source_wallets {
+            let realized = profits.get(wallet_name).copied().unwrap_or(0.0);
+            let Some(source) = self.wallet_manager.get_wallet_pubkey(wallet_name).await else {
+                warn!("⚠️ Treasury sweep: wallet '{}' is not managed, skipping", wallet_name);
+                continue;
+            };
+            let balance = match self.chain.balance_sol(&source).await {
+                Ok(balance) => {
+                    // Signing checks the wallet manager's view of the balance
+                    self.wallet_manager.record_balance(wallet_name, balance).await;
+                    balance
+                }
+                Err(e) => {
+                    warn!("⚠️ Treasury sweep: balance of '{}' unavailable, skipping: {}", wallet_name, e);
+                    continue;
+                }
+            };
+            let source = source.to_string();
+
+            plans.push(SweepPlan {
+                wallet_name: wallet_name.clone(),
+                source_address: source,
+                destination_address: self.config.treasury_address.clone(),
+                realized_profit_sol: realized,
+                wallet_balance_sol: balance,
+                sweep_amount_sol: compute_sweep_amount(realized, balance, &self.config),
+                dry_run: self.config.dry_run,
+                planned_at: Utc::now(),
+            });
+        }
+
+        plans
+    }
+
+    /// Run a sweep cycle (respects `dry_run`)
+    pub async fn run_sweep(&self) -> Result<Vec<SweepRecord>> {
+        if !self.config.enabled {
+            return Err(anyhow!("Treasury sweep is disabled"));
+        }
+
+        let treasury = Pubkey::from_str(&self.config.treasury_address)?;
+        let plans = self.preview().await;
+        let mut records = Vec::with_capacity(plans.len());
+
+        for plan in plans {
+            let record = if plan.sweep_amount_sol <= 0.0 {
+                self.finish(plan, SweepStatus::Skipped, None, None)
+            } else if plan.dry_run {
+                info!("🧪 Treasury sweep preview: {:.4} SOL from {} -> {}",
+                      plan.sweep_amount_sol, plan.wallet_name, plan.destination_address);
+                self.finish(plan, SweepStatus::Previewed, None, None)
+            } else if let Some(guard) = self.multisig.as_ref().filter(|g| g.requires_approval(plan.sweep_amount_sol)) {
+                self.propose_sweep(guard, plan, &treasury).await
+            } else {
+                match self.execute_transfer(&plan, &treasury).await {
+                    Ok(signature) => {
+                        self.deduct_swept(&plan).await;
+                        info!("🏦 Swept {:.4} SOL from {} to treasury ({})",
+                              plan.sweep_amount_sol, plan.wallet_name, signature);
+                        self.finish(plan, SweepStatus::Executed, Some(signature), None)
+                    }
+                    Err(e) => {
+                        error!("❌ Treasury sweep failed for {}: {}", plan.wallet_name, e);
+                        self.finish(plan, SweepStatus::Failed, None, Some(e.to_string()))
+                    }
+                }
+            };
+
+            self.audit(&record).await;
+            records.push(record);
+        }
+
+        *self.last_sweep.write().await = Some(Utc::now());
+        let mut history = self.history.write().await;
+        history.extend(records.iter().cloned());
+        keep_last(&mut history, self.config.history_limit);
+        drop(history);
+        self.save().await;
+        Ok(records)
+    }
+
+    /// Spawn the scheduled sweep task
+    pub fn start_scheduler(self: Arc<Self>) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
+            loop {
+                interval.tick().await;
+                if self.config.enabled && self.is_sweep_due().await {
+                    if let Err(e) = self.run_sweep().await {
+                        warn!("⚠️ Scheduled treasury sweep failed: {}", e);
+                    }
+                }
+            }
+        })
+    }
+
+    /// Get sweep history
+    pub async fn get_history(&self) -> Vec<SweepRecord> {
+        self.history.read().await.clone()
+    }
+
+    /// Get treasury audit log
+    pub async fn get_audit_log(&self) -> Vec<SecurityAuditEntry> {
+        self.audit_log.read().await.clone()
+    }
+
+    /// Park the sweep in the Squads vault and propose releasing it to the treasury
+    async fn propose_sweep(&self, guard: &MultisigGuard, plan: SweepPlan, treasury: &Pubkey) -> SweepRecord {
+        let operation = HighValueOperation::TreasurySweep {
+            wallet_name: plan.wallet_name.clone(),
+            destination: plan.destination_address.clone(),
+        };
+        if guard.has_open_proposal(|open| *open == operation).await {
+            info!("🔏 Treasury sweep from {} already awaiting multisig approval, skipping", plan.wallet_name);
+            return self.finish(plan, SweepStatus::Skipped, None, None);
+        }
+        let vault = guard.vault_address();
+        let deposit = match self.execute_transfer(&plan, &vault).await {
+            Ok(signature) => signature,
+            Err(e) => {
+                error!("❌ Treasury sweep deposit to multisig vault failed for {}: {}", plan.wallet_name, e);
+                return self.finish(plan, SweepStatus::Failed, None, Some(e.to_string()));
+            }
+        };
+        // The funds left the hot wallet: they are no longer sweepable profit
+        self.deduct_swept(&plan).await;
+
+        let release = system_instruction::transfer(&vault, treasury, (plan.sweep_amount_sol * LAMPORTS_PER_SOL) as u64);
+        match guard.create_proposal(operation, plan.sweep_amount_sol, vec![release]).await {
+            Ok(proposal) => {
+                info!("🔏 Treasury sweep of {:.4} SOL from {} parked in vault {}, awaiting multisig approval (proposal #{})",
+                      plan.sweep_amount_sol, plan.wallet_name, vault, proposal.transaction_index);
+                let mut record = self.finish(plan, SweepStatus::ProposalCreated, Some(deposit), None);
+                record.proposal_id = Some(proposal.id);
+                record
+            }
+            Err(e) => {
+                error!("❌ Treasury sweep from {} parked in vault {} but proposal failed: {}", plan.wallet_name, vault, e);
+                self.finish(plan, SweepStatus::Failed, Some(deposit), Some(format!("funds held in multisig vault, proposal failed: {}", e)))
+            }
+        }
+    }
+
+    async fn deduct_swept(&self, plan: &SweepPlan) {
+        if let Some(realized) = self.realized_profits.write().await.get_mut(&plan.wallet_name) {
+            *realized -= plan.sweep_amount_sol;
+        }
+        self.save().await;
+    }
+
+    async fn execute_transfer(&self, plan: &SweepPlan, to: &Pubkey) -> Result<String> {
+        let from = Pubkey::from_str(&plan.source_address)?;
+        let lamports = (plan.sweep_amount_sol * LAMPORTS_PER_SOL) as u64;
+
+        let instruction = system_instruction::transfer(&from, to, lamports);
+        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&from));
+        transaction.message.recent_blockhash = self.chain.latest_blockhash().await?;
+
+        let signed = self.wallet_manager.sign_transaction(
+            &plan.wallet_name,
+            transaction,
+            plan.sweep_amount_sol,
+            "Treasury profit sweep".to_string(),
+        ).await?;
+
+        crate::security::wallet_activity::intent_store().record(&signed.signatures[0].to_string());
+        admit_shared(&plan.source_address, Some(&format!("sweep:{}", plan.wallet_name)), &VersionedTransaction::from(signed.clone()))?;
+        self.chain.send_and_confirm(&signed).await
+    }
+
+    fn finish(
+        &self,
+        plan: SweepPlan,
+        status: SweepStatus,
+        signature: Option<String>,
+        error: Option<String>,
+    ) -> SweepRecord {
+        SweepRecord {
+            plan,
+            status,
+            signature,
+            proposal_id: None,
+            error,
+            completed_at: Utc::now(),
+        }
+    }
+
+    async fn audit(&self, record: &SweepRecord) {
+        let mut metadata = HashMap::new();
+        metadata.insert("wallet".to_string(), record.plan.wallet_name.clone());
+        metadata.insert("destination".to_string(), record.plan.destination_address.clone());
+        metadata.insert("amount_sol".to_string(), format!("{:.9}", record.plan.sweep_amount_sol));
+        metadata.insert("dry_run".to_string(), record.plan.dry_run.to_string());
+        if let Some(signature) = &record.signature {
+            metadata.insert("signature".to_string(), signature.clone());
+        }
+        if let Some(proposal_id) = &record.proposal_id {
+            metadata.insert("multisig_proposal".to_string(), proposal_id.to_string());
+        }
+
+        let severity = match record.status {
+            SweepStatus::Failed => SecuritySeverity::Error,
+            SweepStatus::Executed => SecuritySeverity::Warning,
+            _ => SecuritySeverity::Info,
+        };
+
+        let entry = SecurityAuditEntry {
+            timestamp: Utc::now(),
+            event_type: SecurityEventType::Audit,
+            component: "treasury_sweep".to_string(),
+            severity,
+            description: format!("Treasury sweep {:?}", record.status),
+            metadata,
+            actor: Some("system".to_string()),
+            ip_address: None,
+            build: crate::security::build_fingerprint(),
+        };
+        self.append_audit(&entry);
+        let mut audit_log = self.audit_log.write().await;
+        audit_log.push(entry);
+        keep_last(&mut audit_log, self.config.history_limit);
+    }
+
+    /// Append to the full audit trail, which is never rewritten
+    fn append_audit(&self, entry: &SecurityAuditEntry) {
+        let Some(path) = &self.config.state_path else { return };
+        let result = (|| -> Result<()> {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            let mut file = OpenOptions::new().create(true).append(true).open(path.with_extension("audit.jsonl"))?;
+            writeln!(file, "{}", serde_json::to_string(entry)?)?;
+            Ok(())
+        })();
+        if let Err(e) = result {
+            warn!("⚠️ Failed to append treasury audit entry: {}", e);
+        }
+    }
+}
+
+fn keep_last<T>(items: &mut Vec<T>, limit: usize) {
+    if items.len() > limit {
+        items.drain(..items.len() - limit);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::security::wallet::{RiskManagement, WalletConfig, WalletType};
+    use parking_lot::Mutex;
+    use solana_sdk::signer::{keypair::Keypair, Signer};
+
+    struct FakeChain {
+        balance_sol: f64,
+        sent: Mutex<Vec<Transaction>>,
+    }
+
+    #[async_trait]
+    impl TreasuryChain for FakeChain {
+        async fn balance_sol(&self, _address: &Pubkey) -> Result<f64> {
+            Ok(self.balance_sol)
+        }
+
+        async fn latest_blockhash(&self) -> Result<Hash> {
+            Ok(Hash::new_unique())
+        }
+
+        async fn send_and_confirm(&self, transaction: &Transaction) -> Result<String> {
+            self.sent.lock().push(transaction.clone());
+            Ok(transaction.signatures[0].to_string())
+        }
+    }
+
+    async fn sweeper(config: TreasurySweepConfig, chain: Arc<FakeChain>) -> TreasurySweeper {
+        let wallets = WalletManager::new(&Config { wallets: None, ..Config::default() }).await.unwrap();
+        wallets.add_wallet(WalletConfig {
+            name: "trading".to_string(),
+            wallet_type: WalletType::Trading,
+            keypair_path: None,
+            keypair_data: Some(Keypair::new().to_base58_string()),
+            max_sol_balance: 100.0,
+            min_sol_balance: 0.0,
+            risk_management: RiskManagement {
+                max_transaction_amount: 100.0,
+                daily_limit: 100.0,
+                require_confirmation: false,
+                emergency_stop_threshold: 0.0,
+            },
+        }).await.unwrap();
+        TreasurySweeper::new(config, Arc::new(wallets), chain).unwrap()
+    }
+
+    fn live_config(state_path: Option<PathBuf>) -> TreasurySweepConfig {
+        TreasurySweepConfig {
+            enabled: true,
+            treasury_address: Pubkey::new_unique().to_string(),
+            dry_run: false,
+            state_path,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sweep_sends_excess_profit_and_survives_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = live_config(Some(dir.path().join("treasury.json")));
+        let chain = Arc::new(FakeChain { balance_sol: 10.0, sent: Mutex::new(Vec::new()) });
+        let treasury = sweeper(config.clone(), chain.clone()).await;
+        treasury.record_realized_profit("trading", 3.0).await;
+
+        let records = treasury.run_sweep().await.unwrap();
+        assert_eq!(records[0].status, SweepStatus::Executed);
+        let sent = chain.sent.lock().clone();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].message.account_keys[1].to_string(), config.treasury_address);
+        assert!((treasury.export_snapshot().await.realized_profits["trading"] - 1.0).abs() < 1e-9);
+
+        // Restarted process: the schedule, budget and audit trail carry over
+        let restarted = sweeper(config, chain.clone()).await;
+        assert!(!restarted.is_sweep_due().await);
+        assert!((restarted.export_snapshot().await.realized_profits["trading"] - 1.0).abs() < 1e-9);
+        assert_eq!(restarted.get_history().await.len(), 1);
+        assert_eq!(restarted.get_audit_log().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_history_is_capped_and_full_audit_appended() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_path = dir.path().join("treasury.json");
+        let config = TreasurySweepConfig { dry_run: true, history_limit: 3, ..live_config(Some(state_path)) };
+        let chain = Arc::new(FakeChain { balance_sol: 10.0, sent: Mutex::new(Vec::new()) });
+        let treasury = sweeper(config.clone(), chain.clone()).await;
+        treasury.record_realized_profit("trading", 3.0).await;
+        for _ in 0..5 {
+            treasury.run_sweep().await.unwrap();
+        }
+
+        assert_eq!(treasury.get_history().await.len(), 3);
+        assert_eq!(treasury.get_audit_log().await.len(), 3);
+        let restarted = sweeper(config, chain).await;
+        assert_eq!(restarted.get_history().await.len(), 3);
+        // The capped state file is backed by the complete trail
+        let trail = std::fs::read_to_string(dir.path().join("treasury.audit.jsonl")).unwrap();
+        assert_eq!(trail.lines().count(), 5);
+        assert!(trail.lines().all(|line| serde_json::from_str::<SecurityAuditEntry>(line).is_ok()));
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_sweep_only_previews() {
+        let config = TreasurySweepConfig { dry_run: true, ..live_config(None) };
+        let chain = Arc::new(FakeChain { balance_sol: 10.0, sent: Mutex::new(Vec::new()) });
+        let treasury = sweeper(config, chain.clone()).await;
+        treasury.record_realized_profit("trading", 3.0).await;
+
+        let records = treasury.run_sweep().await.unwrap();
+        assert_eq!(records[0].status, SweepStatus::Previewed);
+        assert!((records[0].plan.sweep_amount_sol - 2.0).abs() < 1e-9);
+        assert!(chain.sent.lock().is_empty());
+        assert!((treasury.export_snapshot().await.realized_profits["trading"] - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sweep_amount_respects_retention() {
+        let config = TreasurySweepConfig::default();
+        // 3 SOL profit, 1 SOL retained -> 2 SOL sweepable
+        assert!((compute_sweep_amount(3.0, 10.0, &config) - 2.0).abs() < 1e-9);
+        // Profit below retention -> nothing to sweep
+        assert_eq!(compute_sweep_amount(0.5, 10.0, &config), 0.0);
+    }
+
+    #[test]
+    fn test_sweep_amount_keeps_fee_reserve() {
+        let config = TreasurySweepConfig::default();
+        // Balance 1.0 with 0.05 reserve caps the sweep at 0.95
+        assert!((compute_sweep_amount(5.0, 1.0, &config) - 0.95).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sweep_amount_skips_dust() {
+        let config = TreasurySweepConfig::default();
+        assert_eq!(compute_sweep_amount(1.05, 10.0, &config), 0.0);
+    }
+}
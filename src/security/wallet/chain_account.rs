@@ -0,0 +1,397 @@
+//! Chain-agnostic accounts for cross-chain execution
+//!
+//! Solana routes sign with an ed25519 [`Keypair`]; EVM chains sign with a
+//! secp256k1 key whose address is the last 20 bytes of the Keccak-256 hash of
+//! the public key. [`ChainAccounts`] maps each supported chain to the account
+//! that signs on it (one EVM key serves every EVM chain unless a chain gets its
+//! own), queries native balances over JSON-RPC and checks that an account holds
+//! enough of the chain's gas asset before anything is bridged.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use k256::ecdsa::SigningKey;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use solana_sdk::signer::{keypair::Keypair, Signer};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use crate::types::PlatformError;
+
+/// Hex-encoded secp256k1 key used on every EVM chain
+pub const EVM_PRIVATE_KEY_ENV: &str = "SNIPERFORGE_EVM_PRIVATE_KEY";
+
+/// Prefix for per-chain RPC overrides, e.g. `SNIPERFORGE_RPC_ARBITRUM`
+pub const CHAIN_RPC_ENV_PREFIX: &str = "SNIPERFORGE_RPC_";
+
+/// Signature scheme and address format a chain uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChainFamily {
+    Solana,
+    Evm,
+}
+
+/// Static description of a chain the engine can trade on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainProfile {
+    pub name: String,
+    pub family: ChainFamily,
+    pub rpc_url: String,
+    /// Native asset gas is paid in
+    pub gas_asset: String,
+    pub gas_decimals: u8,
+    /// Balance below which the chain is considered out of gas (whole units)
+    pub min_gas_balance: f64,
+}
+
+impl ChainProfile {
+    fn new(name: &str, family: ChainFamily, rpc_url: &str, gas_asset: &str, gas_decimals: u8, min_gas_balance: f64) -> Self {
+        let rpc_url = std::env::var(format!("{}{}", CHAIN_RPC_ENV_PREFIX, name.to_ascii_uppercase()))
+            .unwrap_or_else(|_| rpc_url.to_string());
+        Self {
+            name: name.to_string(),
+            family,
+            rpc_url,
+            gas_asset: gas_asset.to_string(),
+            gas_decimals,
+            min_gas_balance,
+        }
+    }
+
+    /// Minimum gas balance in base units
+    pub fn min_gas_base_units(&self) -> u128 {
+        (self.min_gas_balance * 10f64.powi(self.gas_decimals as i32)).round() as u128
+    }
+}
+
+/// Profiles for the chains `EnterpriseCrossChainConfig` supports by default
+pub fn default_chain_profiles() -> Vec<ChainProfile> {
+    use ChainFamily::{Evm, Solana};
+    vec![
+        ChainProfile::new("Solana", Solana, "https://api.mainnet-beta.solana.com", "SOL", 9, 0.01),
+        ChainProfile::new("Ethereum", Evm, "https://eth.llamarpc.com", "ETH", 18, 0.01),
+        ChainProfile::new("Arbitrum", Evm, "https://arb1.arbitrum.io/rpc", "ETH", 18, 0.002),
+        ChainProfile::new("Polygon", Evm, "https://polygon-rpc.com", "POL", 18, 1.0),
+        ChainProfile::new("Optimism", Evm, "https://mainnet.optimism.io", "ETH", 18, 0.002),
+        ChainProfile::new("Base", Evm, "https://mainnet.base.org", "ETH", 18, 0.002),
+        ChainProfile::new("Avalanche", Evm, "https://api.avax.network/ext/bc/C/rpc", "AVAX", 18, 0.1),
+        ChainProfile::new("BSC", Evm, "https://bsc-dataseed.binance.org", "BNB", 18, 0.01),
+        ChainProfile::new("Fantom", Evm, "https://rpc.ftm.tools", "FTM", 18, 1.0),
+        ChainProfile::new("Cronos", Evm, "https://evm.cronos.org", "CRO", 18, 5.0),
+        ChainProfile::new("Moonbeam", Evm, "https://rpc.api.moonbeam.network", "GLMR", 18, 1.0),
+        ChainProfile::new("Aurora", Evm, "https://mainnet.aurora.dev", "ETH", 18, 0.001),
+        ChainProfile::new("Harmony", Evm, "https://api.harmony.one", "ONE", 18, 10.0),
+        ChainProfile::new("Celo", Evm, "https://forno.celo.org", "CELO", 18, 1.0),
+    ]
+}
+
+/// secp256k1 account used on EVM chains
+pub struct EvmAccount {
+    signing_key: SigningKey,
+    address: [u8; 20],
+}
+
+impl EvmAccount {
+    /// Parse a 32-byte private key given as hex (with or without `0x`)
+    pub fn from_hex(private_key: &str) -> Result<Self> {
+        let bytes = decode_hex(private_key.trim().trim_start_matches("0x"))
+            .ok_or_else(|| PlatformError::WalletManagement("EVM private key is not valid hex".to_string()))?;
+        let signing_key = SigningKey::from_slice(&bytes)
+            .map_err(|e| PlatformError::WalletManagement(format!("Invalid EVM private key: {}", e)))?;
+        let public_key = signing_key.verifying_key().to_encoded_point(false);
+        let hash = Keccak256::digest(&public_key.as_bytes()[1..]);
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&hash[12..]);
+        Ok(Self { signing_key, address })
+    }
+
+    /// Lowercase `0x`-prefixed address
+    pub fn address(&self) -> String {
+        format!("0x{}", encode_hex(&self.address))
+    }
+
+    /// Recoverable signature (`r || s || v`) over the Keccak-256 hash of `message`
+    pub fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+        let digest = Keccak256::digest(message);
+        let (signature, recovery_id) = self.signing_key.sign_prehash_recoverable(&digest)
+            .map_err(|e| PlatformError::WalletManagement(format!("EVM signing failed: {}", e)))?;
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(recovery_id.to_byte());
+        Ok(bytes)
+    }
+}
+
+impl std::fmt::Debug for EvmAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EvmAccount").field("address", &self.address()).finish_non_exhaustive()
+    }
+}
+
+/// Account that signs on one chain family
+#[derive(Debug, Clone)]
+pub enum ChainAccount {
+    Solana(Arc<Keypair>),
+    Evm(Arc<EvmAccount>),
+}
+
+impl ChainAccount {
+    pub fn family(&self) -> ChainFamily {
+        match self {
+            ChainAccount::Solana(_) => ChainFamily::Solana,
+            ChainAccount::Evm(_) => ChainFamily::Evm,
+        }
+    }
+
+    /// Address in the chain's native format (base58 or `0x` hex)
+    pub fn address(&self) -> String {
+        match self {
+            ChainAccount::Solana(keypair) => keypair.pubkey().to_string(),
+            ChainAccount::Evm(account) => account.address(),
+        }
+    }
+
+    /// Sign an arbitrary payload with the chain's scheme
+    pub fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            ChainAccount::Solana(keypair) => Ok(keypair.sign_message(message).as_ref().to_vec()),
+            ChainAccount::Evm(account) => account.sign(message),
+        }
+    }
+}
+
+/// Native balance of an account on one chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainBalance {
+    pub chain: String,
+    pub address: String,
+    pub gas_asset: String,
+    /// Balance in base units (lamports, wei)
+    pub raw: u128,
+    pub decimals: u8,
+}
+
+impl ChainBalance {
+    pub fn ui_amount(&self) -> f64 {
+        self.raw as f64 / 10f64.powi(self.decimals as i32)
+    }
+}
+
+/// Where native balances come from
+#[async_trait]
+pub trait ChainBalanceSource: Send + Sync {
+    /// Native balance of `address` in base units
+    async fn native_balance(&self, profile: &ChainProfile, address: &str) -> Result<u128>;
+}
+
+/// Balances via each chain's JSON-RPC endpoint
+pub struct JsonRpcBalanceSource {
+    client: reqwest::Client,
+}
+
+impl JsonRpcBalanceSource {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap_or_default();
+        Self { client }
+    }
+}
+
+impl Default for JsonRpcBalanceSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ChainBalanceSource for JsonRpcBalanceSource {
+    async fn native_balance(&self, profile: &ChainProfile, address: &str) -> Result<u128> {
+        let request = match profile.family {
+            ChainFamily::Solana => serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": "getBalance", "params": [address]}),
+            ChainFamily::Evm => serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": "eth_getBalance", "params": [address, "latest"]}),
+        };
+        let response: serde_json::Value = self.client.post(&profile.rpc_url).json(&request).send().await?.json().await?;
+        if let Some(error) = response.get("error") {
+            return Err(anyhow::anyhow!("{} RPC error: {}", profile.name, error));
+        }
+        parse_balance(profile.family, &response["result"])
+            .ok_or_else(|| anyhow::anyhow!("{} RPC returned an unreadable balance: {}", profile.name, response["result"]))
+    }
+}
+
+fn parse_balance(family: ChainFamily, result: &serde_json::Value) -> Option<u128> {
+    match family {
+        ChainFamily::Solana => result["value"].as_u64().map(u128::from),
+        ChainFamily::Evm => u128::from_str_radix(result.as_str()?.trim_start_matches("0x"), 16).ok(),
+    }
+}
+
+/// Accounts, balances and gas checks across every supported chain
+pub struct ChainAccounts {
+    profiles: HashMap<String, ChainProfile>,
+    family_accounts: RwLock<HashMap<ChainFamily, ChainAccount>>,
+    chain_accounts: RwLock<HashMap<String, ChainAccount>>,
+    balance_source: Arc<dyn ChainBalanceSource>,
+}
+
+impl ChainAccounts {
+    pub fn new(profiles: Vec<ChainProfile>) -> Self {
+        Self {
+            profiles: profiles.into_iter().map(|p| (p.name.to_ascii_lowercase(), p)).collect(),
+            family_accounts: RwLock::new(HashMap::new()),
+            chain_accounts: RwLock::new(HashMap::new()),
+            balance_source: Arc::new(JsonRpcBalanceSource::new()),
+        }
+    }
+
+    /// Default profiles with the Solana keypair file and `SNIPERFORGE_EVM_PRIVATE_KEY`
+    pub fn from_env(solana_keypair_path: &str) -> Result<Self> {
+        let accounts = Self::new(default_chain_profiles());
+        match solana_sdk::signature::read_keypair_file(solana_keypair_path) {
+            Ok(keypair) => accounts.set_family_account(ChainAccount::Solana(Arc::new(keypair))),
+            Err(e) => warn!("⚠️ No Solana keypair at {}: {}", solana_keypair_path, e),
+        }
+        match std::env::var(EVM_PRIVATE_KEY_ENV) {
+            Ok(key) if !key.trim().is_empty() => {
+                accounts.set_family_account(ChainAccount::Evm(Arc::new(EvmAccount::from_hex(&key)?)));
+            }
+            _ => warn!("⚠️ {} not set - EVM chains have no signing account", EVM_PRIVATE_KEY_ENV),
+        }
+        Ok(accounts)
+    }
+
+    pub fn with_balance_source(mut self, source: Arc<dyn ChainBalanceSource>) -> Self {
+        self.balance_source = source;
+        self
+    }
+
+    pub fn profile(&self, chain: &str) -> Option<&ChainProfile> {
+        self.profiles.get(&chain.to_ascii_lowercase())
+    }
+
+    /// Account used on every chain of its family unless a chain has its own
+    pub fn set_family_account(&self, account: ChainAccount) {
+        info!("💳 {:?} account registered: {}", account.family(), account.address());
+        self.family_accounts.write().insert(account.family(), account);
+    }
+
+    /// Dedicated account for one chain
+    pub fn set_chain_account(&self, chain: &str, account: ChainAccount) -> Result<()> {
+        let profile = self.profile(chain)
+            .ok_or_else(|| PlatformError::WalletManagement(format!("Unknown chain '{}'", chain)))?;
+        if profile.family != account.family() {
+            return Err(PlatformError::WalletManagement(format!(
+                "{} needs a {:?} account, got {:?}", profile.name, profile.family, account.family()
+            )).into());
+        }
+        self.chain_accounts.write().insert(chain.to_ascii_lowercase(), account);
+        Ok(())
+    }
+
+    pub fn account(&self, chain: &str) -> Option<ChainAccount> {
+        let key = chain.to_ascii_lowercase();
+        if let Some(account) = self.chain_accounts.read().get(&key) {
+            return Some(account.clone());
+        }
+        let family = self.profiles.get(&key)?.family;
+        self.family_accounts.read().get(&family).cloned()
+    }
+
+    fn resolve(&self, chain: &str) -> Result<(ChainProfile, ChainAccount)> {
+        let profile = self.profile(chain)
+            .ok_or_else(|| PlatformError::WalletManagement(format!("Unknown chain '{}'", chain)))?
+            .clone();
+        let account = self.account(chain)
+            .ok_or_else(|| PlatformError::WalletManagement(format!("No signing account for {}", profile.name)))?;
+        Ok((profile, account))
+    }
+
+    /// Native balance of the chain's account
+    pub async fn balance(&self, chain: &str) -> Result<ChainBalance> {
+        let (profile, account) = self.resolve(chain)?;
+        let address = account.address();
+        let raw = self.balance_source.native_balance(&profile, &address).await?;
+        debug!("💰 {} {} balance: {} base units", profile.name, address, raw);
+        Ok(ChainBalance {
+            chain: profile.name,
+            address,
+            gas_asset: profile.gas_asset,
+            raw,
+            decimals: profile.gas_decimals,
+        })
+    }
+
+    /// Fail unless the chain's account holds at least the minimum gas balance
+    pub async fn ensure_gas(&self, chain: &str) -> Result<ChainBalance> {
+        let balance = self.balance(chain).await?;
+        let profile = self.profile(chain).expect("resolved above");
+        if balance.raw < profile.min_gas_base_units() {
+            return Err(PlatformError::WalletManagement(format!(
+                "Insufficient gas on {}: {:.6} {} held, {:.6} required",
+                balance.chain, balance.ui_amount(), balance.gas_asset, profile.min_gas_balance
+            )).into());
+        }
+        Ok(balance)
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedBalances(HashMap<String, u128>);
+
+    #[async_trait]
+    impl ChainBalanceSource for FixedBalances {
+        async fn native_balance(&self, profile: &ChainProfile, _address: &str) -> Result<u128> {
+            Ok(self.0.get(&profile.name).copied().unwrap_or(0))
+        }
+    }
+
+    #[test]
+    fn test_evm_address_derivation() {
+        let account = EvmAccount::from_hex("0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318").unwrap();
+        assert_eq!(account.address(), "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23");
+        assert_eq!(account.sign(b"bridge").unwrap().len(), 65);
+        assert!(EvmAccount::from_hex("0xnothex").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_accounts_resolve_by_family_and_check_gas() {
+        let balances = HashMap::from([
+            ("Solana".to_string(), 50_000_000u128),
+            ("Arbitrum".to_string(), 1_000_000_000_000u128),
+        ]);
+        let accounts = ChainAccounts::new(default_chain_profiles())
+            .with_balance_source(Arc::new(FixedBalances(balances)));
+        accounts.set_family_account(ChainAccount::Solana(Arc::new(Keypair::new())));
+        let evm = EvmAccount::from_hex("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318").unwrap();
+        accounts.set_family_account(ChainAccount::Evm(Arc::new(evm)));
+
+        assert_eq!(accounts.account("arbitrum").unwrap().family(), ChainFamily::Evm);
+        assert!(accounts.set_chain_account("Base", ChainAccount::Solana(Arc::new(Keypair::new()))).is_err());
+
+        assert_eq!(accounts.ensure_gas("Solana").await.unwrap().ui_amount(), 0.05);
+        // 0.000001 ETH is below Arbitrum's 0.002 ETH minimum
+        assert!(accounts.ensure_gas("Arbitrum").await.is_err());
+        assert!(accounts.ensure_gas("Unknown").await.is_err());
+    }
+}
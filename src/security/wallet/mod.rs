@@ -12,6 +12,15 @@
 //! - **Transaction Signing**: Secure transaction signing with validation
 //! - **Balance Monitoring**: Automated balance tracking and alerts
 //! - **Emergency Controls**: Quick wallet locking and emergency stops
+//! - **Cross-Chain Accounts**: Solana and EVM signing accounts with per-chain
+//!   balance and gas checks ([`ChainAccounts`])
+
+pub mod chain_account;
+
+pub use chain_account::{
+    default_chain_profiles, ChainAccount, ChainAccounts, ChainBalance, ChainBalanceSource, ChainFamily,
+    ChainProfile, EvmAccount, JsonRpcBalanceSource,
+};
 
 use anyhow::Result;
 use solana_client::rpc_client::RpcClient;
@@ -96,6 +105,7 @@ pub struct WalletManager {
     config: Config,
     daily_volumes: Arc<RwLock<HashMap<String, f64>>>,
     emergency_stop: Arc<RwLock<bool>>,
+    chain_accounts: Arc<ChainAccounts>,
 }
 
 impl WalletManager {
@@ -108,6 +118,7 @@ impl WalletManager {
             config: config.clone(),
             daily_volumes: Arc::new(RwLock::new(HashMap::new())),
             emergency_stop: Arc::new(RwLock::new(false)),
+            chain_accounts: Arc::new(ChainAccounts::new(default_chain_profiles())),
         };
 
         // Load configured wallets
@@ -164,6 +175,11 @@ impl WalletManager {
             lock_reason: None,
         };
 
+        // The first trading wallet also signs Solana legs of cross-chain routes
+        if matches!(config.wallet_type, WalletType::Trading) && self.chain_accounts.account("Solana").is_none() {
+            self.chain_accounts.set_family_account(ChainAccount::Solana(wallet.keypair.clone()));
+        }
+
         let mut wallets = self.wallets.write().await;
         wallets.insert(config.name.clone(), wallet);
 
@@ -183,6 +199,15 @@ impl WalletManager {
         wallets.get(wallet_name).map(|w| w.balance_sol)
     }
 
+    /// Store a balance read from chain
+    pub async fn record_balance(&self, wallet_name: &str, balance_sol: f64) {
+        let mut wallets = self.wallets.write().await;
+        if let Some(wallet) = wallets.get_mut(wallet_name) {
+            wallet.balance_sol = balance_sol;
+            wallet.last_balance_check = chrono::Utc::now();
+        }
+    }
+
     /// Check if wallet is available for transactions
     pub async fn is_wallet_available(&self, wallet_name: &str, amount_sol: f64) -> Result<bool> {
         // Check emergency stop
@@ -415,6 +440,29 @@ impl WalletManager {
         Err(PlatformError::WalletManagement("No wallets available".to_string()).into())
     }
 
+    /// Accounts used on each chain by cross-chain execution
+    pub fn chain_accounts(&self) -> Arc<ChainAccounts> {
+        self.chain_accounts.clone()
+    }
+
+    /// Register the secp256k1 key used on EVM chains
+    pub fn add_evm_account(&self, private_key_hex: &str) -> Result<String> {
+        let account = EvmAccount::from_hex(private_key_hex)?;
+        let address = account.address();
+        self.chain_accounts.set_family_account(ChainAccount::Evm(Arc::new(account)));
+        Ok(address)
+    }
+
+    /// Native balance of the account that signs on `chain`
+    pub async fn get_chain_balance(&self, chain: &str) -> Result<ChainBalance> {
+        self.chain_accounts.balance(chain).await
+    }
+
+    /// Fail unless the account on `chain` can pay for gas
+    pub async fn ensure_chain_gas(&self, chain: &str) -> Result<ChainBalance> {
+        self.chain_accounts.ensure_gas(chain).await
+    }
+
     /// Load keypair from configuration
     async fn load_keypair(&self, config: &WalletConfig) -> Result<Keypair> {
         if let Some(keypair_data) = &config.keypair_data {
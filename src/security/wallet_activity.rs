@@ -0,0 +1,457 @@
+//! Wallet activity anomaly detection
+//!
+//! Every transaction the system submits from a managed wallet registers its
+//! signature in the process-wide [`IntentStore`]. The [`WalletActivityMonitor`]
+//! polls the managed wallets' signature history and flags any transaction the
+//! wallet signed that is not in the store: with the key in use by someone
+//! else, that is the first sign of a compromise. Anomalies raise a critical
+//! alert immediately and, when enabled, engage the kill switches.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::{Mutex, RwLock};
+use tracing::{debug, error, info, warn};
+
+use crate::analytics::{RpcTransactionSource, TransactionSource};
+use crate::control::BotController;
+use crate::monitoring::{Alert, AlertManager, AlertStatus, HeartbeatHandle, Severity};
+
+/// Set to `1`/`true` to engage the kill switches on an unexpected transaction
+pub const WALLET_ANOMALY_KILL_ENV: &str = "SNIPERFORGE_WALLET_ANOMALY_KILL";
+
+/// Signatures of transactions originated by this process
+#[derive(Debug)]
+pub struct IntentStore {
+    capacity: usize,
+    inner: parking_lot::Mutex<(HashSet<String>, VecDeque<String>)>,
+}
+
+impl IntentStore {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: parking_lot::Mutex::new((HashSet::new(), VecDeque::new())),
+        }
+    }
+
+    /// Register a signature the system is about to submit (or just submitted)
+    pub fn record(&self, signature: &str) {
+        let mut guard = self.inner.lock();
+        let (known, order) = &mut *guard;
+        if known.insert(signature.to_string()) {
+            order.push_back(signature.to_string());
+            while order.len() > self.capacity {
+                if let Some(oldest) = order.pop_front() {
+                    known.remove(&oldest);
+                }
+            }
+        }
+    }
+
+    pub fn contains(&self, signature: &str) -> bool {
+        self.inner.lock().0.contains(signature)
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.lock().1.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Process-wide intent store shared by every submitter
+pub fn intent_store() -> &'static IntentStore {
+    static STORE: OnceLock<IntentStore> = OnceLock::new();
+    STORE.get_or_init(|| IntentStore::new(100_000))
+}
+
+/// Something that can stop trading
+#[async_trait]
+pub trait KillSwitch: Send + Sync {
+    async fn engage(&self, reason: &str) -> Result<()>;
+}
+
+#[async_trait]
+impl KillSwitch for BotController {
+    async fn engage(&self, reason: &str) -> Result<()> {
+        error!("🛑 Kill switch: stopping all bots - {}", reason);
+        let result = self.stop_all_bots().await?;
+        if !result.failed.is_empty() {
+            warn!("⚠️ Kill switch could not stop {} bots", result.failed.len());
+        }
+        Ok(())
+    }
+}
+
+/// Engine-wide trading halt checked before executing any strategy
+#[derive(Debug, Default)]
+pub struct TradingHalt {
+    reason: parking_lot::RwLock<Option<(String, DateTime<Utc>)>>,
+}
+
+impl TradingHalt {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_engaged(&self) -> bool {
+        self.reason.read().is_some()
+    }
+
+    pub fn reason(&self) -> Option<(String, DateTime<Utc>)> {
+        self.reason.read().clone()
+    }
+
+    /// Lift the halt (operator action after investigation)
+    pub fn release(&self) {
+        if self.reason.write().take().is_some() {
+            info!("✅ Trading halt released");
+        }
+    }
+}
+
+#[async_trait]
+impl KillSwitch for TradingHalt {
+    async fn engage(&self, reason: &str) -> Result<()> {
+        error!("🛑 Trading halted: {}", reason);
+        self.reason.write().get_or_insert_with(|| (reason.to_string(), Utc::now()));
+        Ok(())
+    }
+}
+
+/// Monitor settings
+#[derive(Debug, Clone)]
+pub struct WalletActivityConfig {
+    pub wallets: Vec<String>,
+    pub poll_interval: Duration,
+    pub page_size: usize,
+    /// Engage the kill switches on the first anomaly
+    pub engage_kill_switch: bool,
+}
+
+impl Default for WalletActivityConfig {
+    fn default() -> Self {
+        Self {
+            wallets: Vec::new(),
+            poll_interval: Duration::from_secs(15),
+            page_size: 50,
+            engage_kill_switch: std::env::var(WALLET_ANOMALY_KILL_ENV)
+                .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+        }
+    }
+}
+
+/// Transaction signed by a managed wallet that the system did not originate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletAnomaly {
+    pub wallet: String,
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<DateTime<Utc>>,
+    /// Net SOL change of the wallet (negative = outflow), fee included
+    pub sol_change: f64,
+    pub succeeded: bool,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// Whether `wallet` signed the parsed transaction
+fn signed_by(wallet: &str, tx: &Value) -> bool {
+    tx["transaction"]["message"]["accountKeys"]
+        .as_array()
+        .map(|keys| {
+            keys.iter().any(|k| k["pubkey"].as_str() == Some(wallet) && k["signer"].as_bool().unwrap_or(false))
+        })
+        .unwrap_or(false)
+}
+
+fn sol_change(wallet: &str, tx: &Value) -> f64 {
+    let index = tx["transaction"]["message"]["accountKeys"]
+        .as_array()
+        .and_then(|keys| keys.iter().position(|k| k["pubkey"].as_str() == Some(wallet)));
+    index
+        .map(|i| {
+            let pre = tx["meta"]["preBalances"][i].as_i64().unwrap_or(0);
+            let post = tx["meta"]["postBalances"][i].as_i64().unwrap_or(0);
+            (post - pre) as f64 / 1e9
+        })
+        .unwrap_or(0.0)
+}
+
+/// Watches managed wallets for transactions outside the intent store
+pub struct WalletActivityMonitor {
+    config: WalletActivityConfig,
+    source: Arc<dyn TransactionSource>,
+    intents: &'static IntentStore,
+    alert_manager: Option<Arc<AlertManager>>,
+    kill_switches: Vec<Arc<dyn KillSwitch>>,
+    /// Newest signature seen per wallet
+    cursors: Mutex<HashMap<String, String>>,
+    /// Signatures whose transaction was not retrievable yet
+    unresolved: Mutex<Vec<(String, String)>>,
+    anomalies: RwLock<Vec<WalletAnomaly>>,
+}
+
+impl WalletActivityMonitor {
+    pub fn new(config: WalletActivityConfig, rpc_url: &str) -> Self {
+        Self::with_source(config, Arc::new(RpcTransactionSource::new(rpc_url)))
+    }
+
+    pub fn with_source(config: WalletActivityConfig, source: Arc<dyn TransactionSource>) -> Self {
+        Self {
+            config,
+            source,
+            intents: intent_store(),
+            alert_manager: None,
+            kill_switches: Vec::new(),
+            cursors: Mutex::new(HashMap::new()),
+            unresolved: Mutex::new(Vec::new()),
+            anomalies: RwLock::new(Vec::new()),
+        }
+    }
+
+    pub fn with_intent_store(mut self, intents: &'static IntentStore) -> Self {
+        self.intents = intents;
+        self
+    }
+
+    pub fn with_alert_manager(mut self, alert_manager: Arc<AlertManager>) -> Self {
+        self.alert_manager = Some(alert_manager);
+        self
+    }
+
+    pub fn with_kill_switch(mut self, kill_switch: Arc<dyn KillSwitch>) -> Self {
+        self.kill_switches.push(kill_switch);
+        self
+    }
+
+    /// New signatures for `wallet` since the last poll, oldest first
+    ///
+    /// The first poll of a wallet only records the cursor: history before the
+    /// monitor started cannot be matched against this process's intents.
+    async fn new_signatures(&self, wallet: &str) -> Result<Vec<(String, u64, Option<i64>)>> {
+        let cursor = self.cursors.lock().await.get(wallet).cloned();
+        let mut pending = Vec::new();
+        let mut before: Option<String> = None;
+        loop {
+            let page = self.source
+                .signatures(wallet, before.as_deref(), cursor.as_deref(), self.config.page_size)
+                .await?;
+            let page_len = page.len();
+            before = page.last().map(|s| s.signature.clone());
+            pending.extend(page);
+            // A fresh wallet only needs its newest signature as the baseline
+            if cursor.is_none() || page_len < self.config.page_size {
+                break;
+            }
+        }
+        if let Some(newest) = pending.first() {
+            self.cursors.lock().await.insert(wallet.to_string(), newest.signature.clone());
+        }
+        if cursor.is_none() {
+            debug!("🔐 Wallet activity baseline set for {}", wallet);
+            return Ok(Vec::new());
+        }
+        Ok(pending.into_iter().rev().map(|s| (s.signature, s.slot, s.block_time)).collect())
+    }
+
+    /// Check one signature; `Ok(None)` when it is ours, inbound, or not available yet
+    async fn inspect(&self, wallet: &str, signature: &str, slot: u64, block_time: Option<i64>) -> Result<Option<WalletAnomaly>> {
+        if self.intents.contains(signature) {
+            return Ok(None);
+        }
+        let Some(tx) = self.source.transaction(signature).await? else {
+            self.unresolved.lock().await.push((wallet.to_string(), signature.to_string()));
+            return Ok(None);
+        };
+        // Recheck: the submitter may have registered the signature meanwhile
+        if !signed_by(wallet, &tx) || self.intents.contains(signature) {
+            return Ok(None);
+        }
+        Ok(Some(WalletAnomaly {
+            wallet: wallet.to_string(),
+            signature: signature.to_string(),
+            slot: tx["slot"].as_u64().unwrap_or(slot),
+            block_time: tx["blockTime"].as_i64().or(block_time).and_then(|t| Utc.timestamp_opt(t, 0).single()),
+            sol_change: sol_change(wallet, &tx),
+            succeeded: tx["meta"]["err"].is_null(),
+            detected_at: Utc::now(),
+        }))
+    }
+
+    /// Poll one wallet; returns new anomalies
+    pub async fn poll_wallet(&self, wallet: &str) -> Result<Vec<WalletAnomaly>> {
+        let mut candidates: Vec<(String, u64, Option<i64>)> = {
+            let mut unresolved = self.unresolved.lock().await;
+            let (retry, keep): (Vec<_>, Vec<_>) = unresolved.drain(..).partition(|(w, _)| w == wallet);
+            *unresolved = keep;
+            retry.into_iter().map(|(_, signature)| (signature, 0, None)).collect()
+        };
+        candidates.extend(self.new_signatures(wallet).await?);
+
+        let mut anomalies = Vec::new();
+        for (signature, slot, block_time) in candidates {
+            if let Some(anomaly) = self.inspect(wallet, &signature, slot, block_time).await? {
+                self.handle(&anomaly).await;
+                anomalies.push(anomaly);
+            }
+        }
+        Ok(anomalies)
+    }
+
+    async fn handle(&self, anomaly: &WalletAnomaly) {
+        error!("🚨 Unexpected transaction from managed wallet {}: {} ({:+.6} SOL)",
+               anomaly.wallet, anomaly.signature, anomaly.sol_change);
+
+        if let Some(alert_manager) = &self.alert_manager {
+            alert_manager.raise_alert(Alert {
+                id: uuid::Uuid::new_v4().to_string(),
+                title: format!("Unexpected transaction from wallet {}", anomaly.wallet),
+                description: format!(
+                    "Signature {} was signed by a managed wallet but not originated by SniperForge ({:+.6} SOL). Possible key compromise.",
+                    anomaly.signature, anomaly.sol_change
+                ),
+                severity: Severity::Critical,
+                status: AlertStatus::Open,
+                created_at: Utc::now(),
+                resolved_at: None,
+                tags: vec!["security".to_string(), "wallet_anomaly".to_string(), anomaly.wallet.clone()],
+            }).await;
+        }
+
+        if self.config.engage_kill_switch {
+            let reason = format!("unexpected transaction {} from wallet {}", anomaly.signature, anomaly.wallet);
+            for kill_switch in &self.kill_switches {
+                if let Err(e) = kill_switch.engage(&reason).await {
+                    error!("❌ Kill switch failed: {}", e);
+                }
+            }
+        }
+
+        self.anomalies.write().await.push(anomaly.clone());
+    }
+
+    /// Poll every wallet once
+    pub async fn poll_once(&self) -> Vec<WalletAnomaly> {
+        let mut anomalies = Vec::new();
+        for wallet in &self.config.wallets {
+            match self.poll_wallet(wallet).await {
+                Ok(found) => anomalies.extend(found),
+                Err(e) => warn!("⚠️ Wallet activity poll for {} failed: {}", wallet, e),
+            }
+        }
+        anomalies
+    }
+
+    /// Poll forever, beating `heartbeat` after every pass
+    pub async fn run(self: Arc<Self>, heartbeat: HeartbeatHandle) {
+        info!("🔐 Wallet activity monitor watching {} wallets (kill switch {})",
+              self.config.wallets.len(), if self.config.engage_kill_switch { "armed" } else { "disarmed" });
+        loop {
+            self.poll_once().await;
+            heartbeat.beat();
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.config.poll_interval
+    }
+
+    pub async fn anomalies(&self) -> Vec<WalletAnomaly> {
+        self.anomalies.read().await.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytics::SignatureInfo;
+    use serde_json::json;
+
+    const WALLET: &str = "Wa11et1111111111111111111111111111111111111";
+
+    /// Signatures newest first; `inbound` ones are not signed by the wallet
+    struct ScriptedSource {
+        signatures: parking_lot::Mutex<Vec<String>>,
+        inbound: HashSet<String>,
+    }
+
+    #[async_trait]
+    impl TransactionSource for ScriptedSource {
+        async fn signatures(&self, _address: &str, _before: Option<&str>, until: Option<&str>, limit: usize) -> Result<Vec<SignatureInfo>> {
+            Ok(self.signatures
+                .lock()
+                .iter()
+                .take_while(|s| until != Some(s.as_str()))
+                .take(limit)
+                .map(|s| SignatureInfo { signature: s.clone(), slot: 1, block_time: None, err: None })
+                .collect())
+        }
+
+        async fn transaction(&self, signature: &str) -> Result<Option<Value>> {
+            Ok(Some(json!({
+                "slot": 2,
+                "meta": { "err": null, "preBalances": [3_000_000_000_i64], "postBalances": [1_000_000_000_i64] },
+                "transaction": { "message": { "accountKeys": [
+                    { "pubkey": WALLET, "signer": !self.inbound.contains(signature) }
+                ] } }
+            })))
+        }
+    }
+
+    fn monitor(source: Arc<ScriptedSource>, halt: Arc<TradingHalt>) -> WalletActivityMonitor {
+        static INTENTS: OnceLock<IntentStore> = OnceLock::new();
+        let intents = INTENTS.get_or_init(|| IntentStore::new(10));
+        intents.record("ours");
+        WalletActivityMonitor::with_source(
+            WalletActivityConfig { wallets: vec![WALLET.to_string()], engage_kill_switch: true, ..Default::default() },
+            source,
+        )
+        .with_intent_store(intents)
+        .with_kill_switch(halt)
+    }
+
+    #[tokio::test]
+    async fn test_flags_unknown_signed_transaction_and_halts() {
+        let source = Arc::new(ScriptedSource {
+            signatures: parking_lot::Mutex::new(vec!["old".to_string()]),
+            inbound: HashSet::new(),
+        });
+        let halt = Arc::new(TradingHalt::new());
+        let monitor = monitor(source.clone(), halt.clone());
+
+        assert!(monitor.poll_once().await.is_empty(), "first poll only sets the baseline");
+
+        source.signatures.lock().splice(0..0, ["stolen".to_string(), "ours".to_string()]);
+        let anomalies = monitor.poll_once().await;
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].signature, "stolen");
+        assert!((anomalies[0].sol_change + 2.0).abs() < 1e-9);
+        assert!(halt.is_engaged());
+    }
+
+    #[tokio::test]
+    async fn test_inbound_transfers_are_not_anomalies() {
+        let source = Arc::new(ScriptedSource {
+            signatures: parking_lot::Mutex::new(vec!["old".to_string()]),
+            inbound: HashSet::from(["airdrop".to_string()]),
+        });
+        let halt = Arc::new(TradingHalt::new());
+        let monitor = monitor(source.clone(), halt.clone());
+
+        monitor.poll_once().await;
+        source.signatures.lock().insert(0, "airdrop".to_string());
+        assert!(monitor.poll_once().await.is_empty());
+        assert!(!halt.is_engaged());
+    }
+}
@@ -0,0 +1,189 @@
+//! Shared adapter conformance suite
+//!
+//! The same checks run against every [`AmmAdapter`] with a pool fixture of
+//! its own layout. They pin down the semantics the route engine relies on:
+//!
+//! - decoding recognises its own pools and rejects truncated or foreign data
+//! - spot price is token B per token A in base units
+//! - exact-in quotes are zero for zero input, monotonic in size, never better
+//!   than spot and net of the pool fee, in both directions
+//! - swap instructions target the adapter's program with the trader signing
+//!   and the pool writable
+
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+
+use super::{AmmAdapter, SwapAccounts};
+
+/// A known pool account and what decoding it must yield
+#[derive(Debug, Clone)]
+pub struct ConformanceFixture {
+    pub pool: Pubkey,
+    pub data: Vec<u8>,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    /// Token B per token A
+    pub expected_spot_price: f64,
+    /// Input small enough that price impact is negligible, in both directions
+    pub probe_amount: u64,
+}
+
+/// Result of running the suite against one adapter
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ConformanceReport {
+    pub adapter: String,
+    pub checks: usize,
+    pub failures: Vec<String>,
+}
+
+impl ConformanceReport {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    fn check(&mut self, ok: bool, description: impl FnOnce() -> String) {
+        self.checks += 1;
+        if !ok {
+            self.failures.push(description());
+        }
+    }
+}
+
+fn relative_error(actual: f64, expected: f64) -> f64 {
+    ((actual - expected) / expected).abs()
+}
+
+/// Run every check; an adapter is conforming when the report has no failures
+pub fn run_conformance(adapter: &dyn AmmAdapter, fixture: &ConformanceFixture) -> ConformanceReport {
+    let mut report = ConformanceReport { adapter: adapter.name().to_string(), ..Default::default() };
+
+    // Decoding
+    let state = match adapter.decode_state(&fixture.data) {
+        Ok(state) => state,
+        Err(e) => {
+            report.check(false, || format!("fixture pool does not decode: {}", e));
+            return report;
+        }
+    };
+    report.check(state.mint_a == fixture.mint_a && state.mint_b == fixture.mint_b, || {
+        format!("decoded mints {}/{} differ from fixture", state.mint_a, state.mint_b)
+    });
+    report.check(adapter.decode_state(&fixture.data[..fixture.data.len() / 2]).is_err(), || "truncated account decoded".to_string());
+    report.check(adapter.decode_state(&[]).is_err(), || "empty account decoded".to_string());
+    let mut foreign = fixture.data.clone();
+    foreign[0] ^= 0xff;
+    report.check(adapter.decode_state(&foreign).is_err(), || "account with a foreign discriminator decoded".to_string());
+    report.check((0.0..1.0).contains(&state.fee_rate), || format!("fee rate {} outside [0, 1)", state.fee_rate));
+
+    // Spot price
+    let spot = adapter.spot_price(&state);
+    report.check(spot.is_finite() && spot > 0.0, || format!("spot price {} is not positive", spot));
+    report.check(relative_error(spot, fixture.expected_spot_price) < 1e-6, || {
+        format!("spot price {} differs from expected {}", spot, fixture.expected_spot_price)
+    });
+
+    // Quotes, both directions
+    let probe = fixture.probe_amount;
+    for a_to_b in [true, false] {
+        let direction = if a_to_b { "a->b" } else { "b->a" };
+        let quote = |amount: u64| adapter.quote_exact_in(&state, amount, a_to_b);
+        report.check(matches!(quote(0), Ok(0)), || format!("{}: zero input does not quote zero", direction));
+
+        let (Ok(small), Ok(double), Ok(quadruple)) = (quote(probe), quote(probe * 2), quote(probe * 4)) else {
+            report.check(false, || format!("{}: probe sizes do not quote", direction));
+            continue;
+        };
+        report.check(small <= double && double <= quadruple, || {
+            format!("{}: quotes not monotonic ({} / {} / {})", direction, small, double, quadruple)
+        });
+
+        let spot_out = if a_to_b { probe as f64 * spot } else { probe as f64 / spot };
+        let net_of_fee = spot_out * (1.0 - state.fee_rate);
+        report.check(small as f64 <= net_of_fee + 1.0, || {
+            format!("{}: quote {} better than spot net of fees {:.0}", direction, small, net_of_fee)
+        });
+        report.check(small as f64 >= net_of_fee * 0.99, || {
+            format!("{}: probe quote {} far below spot net of fees {:.0}", direction, small, net_of_fee)
+        });
+    }
+
+    // Instruction building
+    let accounts = SwapAccounts {
+        user: Pubkey::new_unique(),
+        user_token_a: Pubkey::new_unique(),
+        user_token_b: Pubkey::new_unique(),
+        extra: Vec::new(),
+    };
+    for a_to_b in [true, false] {
+        match adapter.build_swap_ix(&fixture.pool, &state, &accounts, probe, 1, a_to_b) {
+            Ok(ix) => {
+                report.check(ix.program_id == adapter.program_id(), || "swap instruction targets another program".to_string());
+                report.check(ix.accounts.iter().any(|meta| meta.pubkey == accounts.user && meta.is_signer), || {
+                    "trader is not a signer of the swap".to_string()
+                });
+                report.check(ix.accounts.iter().any(|meta| meta.pubkey == fixture.pool && meta.is_writable), || {
+                    "pool is not writable in the swap".to_string()
+                });
+                report.check(ix.data.len() > 8, || "swap instruction carries no arguments".to_string());
+            }
+            Err(e) => report.check(false, || format!("swap instruction not built: {}", e)),
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trading::amm::{meteora, orca, raydium, AdapterRegistry, AdapterError, MeteoraDlmmAdapter, OrcaWhirlpoolAdapter, PoolState, RaydiumClmmAdapter};
+    use solana_sdk::instruction::Instruction;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_builtin_adapters_conform() {
+        let suites: [(&dyn AmmAdapter, ConformanceFixture); 3] = [
+            (&OrcaWhirlpoolAdapter, orca::fixture()),
+            (&RaydiumClmmAdapter::default(), raydium::fixture()),
+            (&MeteoraDlmmAdapter, meteora::fixture()),
+        ];
+        for (adapter, fixture) in suites {
+            let report = run_conformance(adapter, &fixture);
+            assert!(report.passed(), "{} failed: {:?}", report.adapter, report.failures);
+            assert!(report.checks > 15);
+        }
+        assert_eq!(AdapterRegistry::with_builtin_adapters().names().len(), 3);
+    }
+
+    /// Quotes at spot without taking the fee
+    struct FeelessOrca;
+
+    impl AmmAdapter for FeelessOrca {
+        fn name(&self) -> &str {
+            "feeless"
+        }
+        fn program_id(&self) -> Pubkey {
+            OrcaWhirlpoolAdapter.program_id()
+        }
+        fn decode_state(&self, data: &[u8]) -> Result<PoolState, AdapterError> {
+            OrcaWhirlpoolAdapter.decode_state(data)
+        }
+        fn spot_price(&self, state: &PoolState) -> f64 {
+            OrcaWhirlpoolAdapter.spot_price(state)
+        }
+        fn quote_exact_in(&self, state: &PoolState, amount_in: u64, a_to_b: bool) -> Result<u64, AdapterError> {
+            let spot = self.spot_price(state);
+            Ok(if a_to_b { (amount_in as f64 * spot) as u64 } else { (amount_in as f64 / spot) as u64 })
+        }
+        fn build_swap_ix(&self, pool: &Pubkey, state: &PoolState, accounts: &SwapAccounts, amount_in: u64, min_amount_out: u64, a_to_b: bool) -> Result<Instruction, AdapterError> {
+            OrcaWhirlpoolAdapter.build_swap_ix(pool, state, accounts, amount_in, min_amount_out, a_to_b)
+        }
+    }
+
+    #[test]
+    fn test_nonconforming_adapter_is_not_registered() {
+        let registry = AdapterRegistry::new();
+        let report = registry.register(Arc::new(FeelessOrca), &orca::fixture()).unwrap_err();
+        assert!(report.failures.iter().any(|failure| failure.contains("better than spot")));
+        assert!(registry.get(&OrcaWhirlpoolAdapter.program_id()).is_none());
+    }
+}
@@ -0,0 +1,155 @@
+//! Meteora DLMM adapter
+
+use std::str::FromStr;
+
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+
+use super::conformance::ConformanceFixture;
+use super::{
+    anchor_discriminator, read_i32, read_pubkey, read_u16, token_program, AdapterError, AmmAdapter, PoolState,
+    Pricing, SwapAccounts,
+};
+use crate::types::constants::{SOL_MINT, USDC_MINT};
+
+pub const METEORA_DLMM_PROGRAM: &str = "LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo";
+
+const LB_PAIR_LEN: usize = 904;
+const LB_PAIR_DISCRIMINATOR: [u8; 8] = [33, 11, 49, 98, 181, 101, 177, 13];
+
+/// `LbPair` accounts
+///
+/// Quotes use the active bin's price: swaps are assumed to fit inside the
+/// active bin, so sizes that walk several bins are over-quoted.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MeteoraDlmmAdapter;
+
+impl MeteoraDlmmAdapter {
+    fn event_authority(&self) -> Pubkey {
+        Pubkey::find_program_address(&[b"__event_authority"], &self.program_id()).0
+    }
+}
+
+impl AmmAdapter for MeteoraDlmmAdapter {
+    fn name(&self) -> &str {
+        "Meteora DLMM"
+    }
+
+    fn program_id(&self) -> Pubkey {
+        Pubkey::from_str(METEORA_DLMM_PROGRAM).unwrap_or_default()
+    }
+
+    fn decode_state(&self, data: &[u8]) -> Result<PoolState, AdapterError> {
+        if data.len() != LB_PAIR_LEN || !data.starts_with(&LB_PAIR_DISCRIMINATOR) {
+            return Err(AdapterError::WrongLayout { adapter: self.name().to_string(), data_len: data.len() });
+        }
+        let invalid = |reason: &str| AdapterError::InvalidState { adapter: self.name().to_string(), reason: reason.to_string() };
+        let base_factor = read_u16(data, 8).ok_or_else(|| invalid("base factor"))?;
+        let active_id = read_i32(data, 76).ok_or_else(|| invalid("active id"))?;
+        let bin_step = read_u16(data, 80).ok_or_else(|| invalid("bin step"))?;
+        if bin_step == 0 {
+            return Err(invalid("zero bin step"));
+        }
+        Ok(PoolState {
+            mint_a: read_pubkey(data, 88).ok_or_else(|| invalid("token x mint"))?,
+            mint_b: read_pubkey(data, 120).ok_or_else(|| invalid("token y mint"))?,
+            vault_a: read_pubkey(data, 152).ok_or_else(|| invalid("reserve x"))?,
+            vault_b: read_pubkey(data, 184).ok_or_else(|| invalid("reserve y"))?,
+            // Base fee only; the volatility fee is zero in calm markets
+            fee_rate: base_factor as f64 * bin_step as f64 * 10.0 / 1e9,
+            pricing: Pricing::Bin { active_id, bin_step },
+            // Oracle
+            pool_accounts: vec![read_pubkey(data, 552).ok_or_else(|| invalid("oracle"))?],
+        })
+    }
+
+    fn spot_price(&self, state: &PoolState) -> f64 {
+        match state.pricing {
+            Pricing::Bin { active_id, bin_step } => (1.0 + bin_step as f64 / 10_000.0).powi(active_id),
+            Pricing::Concentrated { .. } => 0.0,
+        }
+    }
+
+    fn quote_exact_in(&self, state: &PoolState, amount_in: u64, a_to_b: bool) -> Result<u64, AdapterError> {
+        let price = self.spot_price(state);
+        if !(price.is_finite() && price > 0.0) {
+            return Err(AdapterError::InvalidState { adapter: self.name().to_string(), reason: "no active bin price".to_string() });
+        }
+        let amount = amount_in as f64 * (1.0 - state.fee_rate);
+        let out = if a_to_b { amount * price } else { amount / price };
+        Ok(out.floor() as u64)
+    }
+
+    /// `swap`: bin arrays come in `accounts.extra`
+    fn build_swap_ix(
+        &self,
+        pool: &Pubkey,
+        state: &PoolState,
+        accounts: &SwapAccounts,
+        amount_in: u64,
+        min_amount_out: u64,
+        a_to_b: bool,
+    ) -> Result<Instruction, AdapterError> {
+        let [oracle] = state.pool_accounts[..] else {
+            return Err(AdapterError::InvalidState { adapter: self.name().to_string(), reason: "missing oracle".to_string() });
+        };
+        let mut data = anchor_discriminator("swap").to_vec();
+        data.extend_from_slice(&amount_in.to_le_bytes());
+        data.extend_from_slice(&min_amount_out.to_le_bytes());
+
+        let program = self.program_id();
+        let (user_in, user_out) = if a_to_b {
+            (accounts.user_token_a, accounts.user_token_b)
+        } else {
+            (accounts.user_token_b, accounts.user_token_a)
+        };
+        // Unused optional accounts are passed as the program ID
+        let mut metas = vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new_readonly(program, false), // bin array bitmap extension
+            AccountMeta::new(state.vault_a, false),
+            AccountMeta::new(state.vault_b, false),
+            AccountMeta::new(user_in, false),
+            AccountMeta::new(user_out, false),
+            AccountMeta::new_readonly(state.mint_a, false),
+            AccountMeta::new_readonly(state.mint_b, false),
+            AccountMeta::new(oracle, false),
+            AccountMeta::new_readonly(program, false), // host fee
+            AccountMeta::new_readonly(accounts.user, true),
+            AccountMeta::new_readonly(token_program(), false),
+            AccountMeta::new_readonly(token_program(), false),
+            AccountMeta::new_readonly(self.event_authority(), false),
+            AccountMeta::new_readonly(program, false),
+        ];
+        metas.extend(accounts.extra.iter().cloned());
+        Ok(Instruction { program_id: program, accounts: metas, data })
+    }
+}
+
+/// SOL/USDC pair near 150 USDC per SOL, 25 bps bins, 0.25% base fee
+pub fn fixture() -> ConformanceFixture {
+    let mint_a = Pubkey::from_str(SOL_MINT).unwrap_or_default();
+    let mint_b = Pubkey::from_str(USDC_MINT).unwrap_or_default();
+    let bin_step = 25u16;
+    let active_id = -1898i32;
+
+    let mut data = vec![0u8; LB_PAIR_LEN];
+    data[..8].copy_from_slice(&LB_PAIR_DISCRIMINATOR);
+    data[8..10].copy_from_slice(&10_000u16.to_le_bytes());
+    data[76..80].copy_from_slice(&active_id.to_le_bytes());
+    data[80..82].copy_from_slice(&bin_step.to_le_bytes());
+    data[88..120].copy_from_slice(mint_a.as_ref());
+    data[120..152].copy_from_slice(mint_b.as_ref());
+    data[152..184].copy_from_slice(Pubkey::new_from_array([1; 32]).as_ref());
+    data[184..216].copy_from_slice(Pubkey::new_from_array([2; 32]).as_ref());
+    data[552..584].copy_from_slice(Pubkey::new_from_array([6; 32]).as_ref());
+
+    ConformanceFixture {
+        pool: Pubkey::new_from_array([3; 32]),
+        data,
+        mint_a,
+        mint_b,
+        expected_spot_price: (1.0 + bin_step as f64 / 10_000.0).powi(active_id),
+        probe_amount: 1_000_000,
+    }
+}
@@ -0,0 +1,216 @@
+//! DEX adapters
+//!
+//! Every on-chain venue the route engine trades directly is wrapped in an
+//! [`AmmAdapter`]: decode a pool account, read its spot price, quote an
+//! exact-in swap and build the swap instruction. Adapters differ in layout and
+//! pricing model but must agree on semantics (units, fee handling, direction,
+//! rejection of foreign data), so [`AdapterRegistry::register`] runs the
+//! shared [`conformance`] suite against a fixture before an adapter is
+//! accepted.
+//!
+//! Prices and quotes are in base units of the tokens (no decimals applied);
+//! `a_to_b` swaps token A (`mint_a`) for token B.
+
+pub mod conformance;
+pub mod meteora;
+pub mod orca;
+pub mod raydium;
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use tracing::{info, warn};
+
+pub use conformance::{ConformanceFixture, ConformanceReport, run_conformance};
+pub use meteora::MeteoraDlmmAdapter;
+pub use orca::OrcaWhirlpoolAdapter;
+pub use raydium::RaydiumClmmAdapter;
+
+pub const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+/// How a pool prices swaps
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Pricing {
+    /// Concentrated liquidity inside the current tick range
+    Concentrated { sqrt_price_x64: u128, liquidity: u128 },
+    /// Discrete price bins; the active bin sets the price
+    Bin { active_id: i32, bin_step: u16 },
+}
+
+/// Decoded pool state common to all adapters
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PoolState {
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    /// Swap fee as a fraction of the input
+    pub fee_rate: f64,
+    pub pricing: Pricing,
+    /// Program-specific accounts the pool references (config, observation, ...), in adapter order
+    pub pool_accounts: Vec<Pubkey>,
+}
+
+/// Accounts of the trader for a swap
+#[derive(Debug, Clone)]
+pub struct SwapAccounts {
+    pub user: Pubkey,
+    pub user_token_a: Pubkey,
+    pub user_token_b: Pubkey,
+    /// Venue-specific accounts (tick arrays, bin arrays, oracle, ...)
+    pub extra: Vec<AccountMeta>,
+}
+
+/// Why an adapter could not serve a request
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum AdapterError {
+    #[error("{adapter}: account is not a pool ({data_len} bytes)")]
+    WrongLayout { adapter: String, data_len: usize },
+    #[error("{adapter}: invalid pool state: {reason}")]
+    InvalidState { adapter: String, reason: String },
+    #[error("{adapter}: swap of {amount_in} exceeds pool liquidity")]
+    InsufficientLiquidity { adapter: String, amount_in: u64 },
+}
+
+/// One DEX program's pool semantics
+pub trait AmmAdapter: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn program_id(&self) -> Pubkey;
+
+    /// Decode a pool account; foreign or truncated data is an error, never a panic
+    fn decode_state(&self, data: &[u8]) -> Result<PoolState, AdapterError>;
+
+    /// Token B per token A before fees
+    fn spot_price(&self, state: &PoolState) -> f64;
+
+    /// Output for `amount_in`, after fees
+    fn quote_exact_in(&self, state: &PoolState, amount_in: u64, a_to_b: bool) -> Result<u64, AdapterError>;
+
+    fn build_swap_ix(
+        &self,
+        pool: &Pubkey,
+        state: &PoolState,
+        accounts: &SwapAccounts,
+        amount_in: u64,
+        min_amount_out: u64,
+        a_to_b: bool,
+    ) -> Result<Instruction, AdapterError>;
+}
+
+/// Conforming adapters by program ID, as used by the route engine
+#[derive(Default)]
+pub struct AdapterRegistry {
+    adapters: RwLock<HashMap<Pubkey, Arc<dyn AmmAdapter>>>,
+}
+
+impl AdapterRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry with the built-in Orca, Raydium and Meteora adapters
+    ///
+    /// Built-ins go through the same conformance check as any other adapter.
+    pub fn with_builtin_adapters() -> Self {
+        let registry = Self::new();
+        let builtins: [(Arc<dyn AmmAdapter>, ConformanceFixture); 3] = [
+            (Arc::new(OrcaWhirlpoolAdapter), orca::fixture()),
+            (Arc::new(RaydiumClmmAdapter::default()), raydium::fixture()),
+            (Arc::new(MeteoraDlmmAdapter), meteora::fixture()),
+        ];
+        for (adapter, fixture) in builtins {
+            if let Err(report) = registry.register(adapter, &fixture) {
+                warn!("⚠️ Built-in adapter {} failed conformance: {:?}", report.adapter, report.failures);
+            }
+        }
+        registry
+    }
+
+    /// Run the conformance suite and register the adapter only if it passes
+    pub fn register(&self, adapter: Arc<dyn AmmAdapter>, fixture: &ConformanceFixture) -> Result<ConformanceReport, ConformanceReport> {
+        let report = run_conformance(adapter.as_ref(), fixture);
+        if !report.passed() {
+            return Err(report);
+        }
+        info!("🔌 AMM adapter {} registered ({} checks passed)", adapter.name(), report.checks);
+        self.adapters.write().insert(adapter.program_id(), adapter);
+        Ok(report)
+    }
+
+    pub fn get(&self, program: &Pubkey) -> Option<Arc<dyn AmmAdapter>> {
+        self.adapters.read().get(program).cloned()
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.adapters.read().values().map(|adapter| adapter.name().to_string()).collect();
+        names.sort();
+        names
+    }
+}
+
+/// Anchor instruction discriminator: first 8 bytes of `sha256("global:<name>")`
+pub fn anchor_discriminator(name: &str) -> [u8; 8] {
+    let digest = Sha256::digest(format!("global:{}", name).as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&digest[..8]);
+    discriminator
+}
+
+pub(crate) fn token_program() -> Pubkey {
+    Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap_or_default()
+}
+
+pub(crate) fn read_pubkey(data: &[u8], offset: usize) -> Option<Pubkey> {
+    let bytes: [u8; 32] = data.get(offset..offset + 32)?.try_into().ok()?;
+    Some(Pubkey::new_from_array(bytes))
+}
+
+pub(crate) fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)?.try_into().ok().map(u16::from_le_bytes)
+}
+
+pub(crate) fn read_i32(data: &[u8], offset: usize) -> Option<i32> {
+    data.get(offset..offset + 4)?.try_into().ok().map(i32::from_le_bytes)
+}
+
+pub(crate) fn read_u128(data: &[u8], offset: usize) -> Option<u128> {
+    data.get(offset..offset + 16)?.try_into().ok().map(u128::from_le_bytes)
+}
+
+/// Exact-in output of a concentrated-liquidity pool within the current tick range
+///
+/// Tick crossings are not modelled: the active liquidity is assumed to cover
+/// the whole swap, which holds for the small sizes the route engine probes.
+pub(crate) fn concentrated_quote_exact_in(
+    adapter: &str,
+    sqrt_price_x64: u128,
+    liquidity: u128,
+    fee_rate: f64,
+    amount_in: u64,
+    a_to_b: bool,
+) -> Result<u64, AdapterError> {
+    if amount_in == 0 {
+        return Ok(0);
+    }
+    let sqrt_price = sqrt_price_x64 as f64 / 2f64.powi(64);
+    let liquidity = liquidity as f64;
+    let amount = amount_in as f64 * (1.0 - fee_rate);
+    let out = if a_to_b {
+        let next = liquidity * sqrt_price / (liquidity + amount * sqrt_price);
+        liquidity * (sqrt_price - next)
+    } else {
+        let next = sqrt_price + amount / liquidity;
+        liquidity * (1.0 / sqrt_price - 1.0 / next)
+    };
+    if liquidity <= 0.0 || !out.is_finite() || out < 0.0 {
+        return Err(AdapterError::InsufficientLiquidity { adapter: adapter.to_string(), amount_in });
+    }
+    Ok(out.floor() as u64)
+}
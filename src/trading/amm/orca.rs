@@ -0,0 +1,128 @@
+//! Orca Whirlpool adapter
+
+use std::str::FromStr;
+
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+
+use super::conformance::ConformanceFixture;
+use super::{
+    anchor_discriminator, concentrated_quote_exact_in, read_pubkey, read_u128, read_u16, token_program,
+    AdapterError, AmmAdapter, PoolState, Pricing, SwapAccounts,
+};
+use crate::apis::program_registry::ORCA_WHIRLPOOL_PROGRAM;
+use crate::types::constants::{SOL_MINT, USDC_MINT};
+
+const WHIRLPOOL_LEN: usize = 653;
+const WHIRLPOOL_DISCRIMINATOR: [u8; 8] = [63, 149, 209, 12, 225, 128, 99, 9];
+/// Price limits accepted by the program (no limit in either direction)
+const MIN_SQRT_PRICE_X64: u128 = 4_295_048_016;
+const MAX_SQRT_PRICE_X64: u128 = 79_226_673_515_401_279_992_447_579_055;
+
+/// `Whirlpool` accounts; fee rate is in hundredths of a basis point
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OrcaWhirlpoolAdapter;
+
+impl AmmAdapter for OrcaWhirlpoolAdapter {
+    fn name(&self) -> &str {
+        "Orca Whirlpool"
+    }
+
+    fn program_id(&self) -> Pubkey {
+        Pubkey::from_str(ORCA_WHIRLPOOL_PROGRAM).unwrap_or_default()
+    }
+
+    fn decode_state(&self, data: &[u8]) -> Result<PoolState, AdapterError> {
+        if data.len() != WHIRLPOOL_LEN || !data.starts_with(&WHIRLPOOL_DISCRIMINATOR) {
+            return Err(AdapterError::WrongLayout { adapter: self.name().to_string(), data_len: data.len() });
+        }
+        let invalid = |reason: &str| AdapterError::InvalidState { adapter: self.name().to_string(), reason: reason.to_string() };
+        let fee_rate = read_u16(data, 45).ok_or_else(|| invalid("fee rate"))?;
+        let liquidity = read_u128(data, 49).ok_or_else(|| invalid("liquidity"))?;
+        let sqrt_price_x64 = read_u128(data, 65).ok_or_else(|| invalid("sqrt price"))?;
+        if sqrt_price_x64 == 0 {
+            return Err(invalid("zero sqrt price"));
+        }
+        Ok(PoolState {
+            mint_a: read_pubkey(data, 101).ok_or_else(|| invalid("mint a"))?,
+            vault_a: read_pubkey(data, 133).ok_or_else(|| invalid("vault a"))?,
+            mint_b: read_pubkey(data, 181).ok_or_else(|| invalid("mint b"))?,
+            vault_b: read_pubkey(data, 213).ok_or_else(|| invalid("vault b"))?,
+            fee_rate: fee_rate as f64 / 1_000_000.0,
+            pricing: Pricing::Concentrated { sqrt_price_x64, liquidity },
+            pool_accounts: Vec::new(),
+        })
+    }
+
+    fn spot_price(&self, state: &PoolState) -> f64 {
+        match state.pricing {
+            Pricing::Concentrated { sqrt_price_x64, .. } => (sqrt_price_x64 as f64 / 2f64.powi(64)).powi(2),
+            Pricing::Bin { .. } => 0.0,
+        }
+    }
+
+    fn quote_exact_in(&self, state: &PoolState, amount_in: u64, a_to_b: bool) -> Result<u64, AdapterError> {
+        let Pricing::Concentrated { sqrt_price_x64, liquidity } = state.pricing else {
+            return Err(AdapterError::InvalidState { adapter: self.name().to_string(), reason: "not a whirlpool state".to_string() });
+        };
+        concentrated_quote_exact_in(self.name(), sqrt_price_x64, liquidity, state.fee_rate, amount_in, a_to_b)
+    }
+
+    /// `swap`: tick arrays and oracle come in `accounts.extra`
+    fn build_swap_ix(
+        &self,
+        pool: &Pubkey,
+        state: &PoolState,
+        accounts: &SwapAccounts,
+        amount_in: u64,
+        min_amount_out: u64,
+        a_to_b: bool,
+    ) -> Result<Instruction, AdapterError> {
+        let mut data = anchor_discriminator("swap").to_vec();
+        data.extend_from_slice(&amount_in.to_le_bytes());
+        data.extend_from_slice(&min_amount_out.to_le_bytes());
+        let sqrt_price_limit = if a_to_b { MIN_SQRT_PRICE_X64 } else { MAX_SQRT_PRICE_X64 };
+        data.extend_from_slice(&sqrt_price_limit.to_le_bytes());
+        data.push(1); // amount_specified_is_input
+        data.push(a_to_b as u8);
+
+        let mut metas = vec![
+            AccountMeta::new_readonly(token_program(), false),
+            AccountMeta::new_readonly(accounts.user, true),
+            AccountMeta::new(*pool, false),
+            AccountMeta::new(accounts.user_token_a, false),
+            AccountMeta::new(state.vault_a, false),
+            AccountMeta::new(accounts.user_token_b, false),
+            AccountMeta::new(state.vault_b, false),
+        ];
+        metas.extend(accounts.extra.iter().cloned());
+        Ok(Instruction { program_id: self.program_id(), accounts: metas, data })
+    }
+}
+
+/// SOL/USDC whirlpool at 150 USDC per SOL, 0.3% fee
+pub fn fixture() -> ConformanceFixture {
+    let mint_a = Pubkey::from_str(SOL_MINT).unwrap_or_default();
+    let mint_b = Pubkey::from_str(USDC_MINT).unwrap_or_default();
+    let price = 150.0 * 1e6 / 1e9;
+    let sqrt_price_x64 = (f64::sqrt(price) * 2f64.powi(64)) as u128;
+
+    let mut data = vec![0u8; WHIRLPOOL_LEN];
+    data[..8].copy_from_slice(&WHIRLPOOL_DISCRIMINATOR);
+    data[45..47].copy_from_slice(&3000u16.to_le_bytes());
+    data[49..65].copy_from_slice(&1_000_000_000_000_000u128.to_le_bytes());
+    data[65..81].copy_from_slice(&sqrt_price_x64.to_le_bytes());
+    data[101..133].copy_from_slice(mint_a.as_ref());
+    data[133..165].copy_from_slice(Pubkey::new_from_array([1; 32]).as_ref());
+    data[181..213].copy_from_slice(mint_b.as_ref());
+    data[213..245].copy_from_slice(Pubkey::new_from_array([2; 32]).as_ref());
+
+    ConformanceFixture {
+        pool: Pubkey::new_from_array([3; 32]),
+        data,
+        mint_a,
+        mint_b,
+        expected_spot_price: price,
+        probe_amount: 1_000_000,
+    }
+}
@@ -0,0 +1,154 @@
+//! Raydium CLMM adapter
+
+use std::str::FromStr;
+
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+
+use super::conformance::ConformanceFixture;
+use super::{
+    anchor_discriminator, concentrated_quote_exact_in, read_pubkey, read_u128, token_program, AdapterError,
+    AmmAdapter, PoolState, Pricing, SwapAccounts,
+};
+use crate::apis::program_registry::RAYDIUM_CLMM_PROGRAM;
+use crate::types::constants::{SOL_MINT, USDC_MINT};
+
+const POOL_STATE_LEN: usize = 1544;
+const POOL_STATE_DISCRIMINATOR: [u8; 8] = [247, 237, 227, 245, 215, 195, 222, 70];
+
+/// `PoolState` accounts
+///
+/// The fee tier lives in the pool's `AmmConfig`, not the pool itself, so the
+/// adapter is configured with the tier it quotes.
+#[derive(Debug, Clone, Copy)]
+pub struct RaydiumClmmAdapter {
+    pub fee_rate: f64,
+}
+
+impl Default for RaydiumClmmAdapter {
+    fn default() -> Self {
+        Self { fee_rate: 0.0025 }
+    }
+}
+
+impl RaydiumClmmAdapter {
+    pub fn with_fee_rate(fee_rate: f64) -> Self {
+        Self { fee_rate }
+    }
+}
+
+impl AmmAdapter for RaydiumClmmAdapter {
+    fn name(&self) -> &str {
+        "Raydium CLMM"
+    }
+
+    fn program_id(&self) -> Pubkey {
+        Pubkey::from_str(RAYDIUM_CLMM_PROGRAM).unwrap_or_default()
+    }
+
+    fn decode_state(&self, data: &[u8]) -> Result<PoolState, AdapterError> {
+        if data.len() != POOL_STATE_LEN || !data.starts_with(&POOL_STATE_DISCRIMINATOR) {
+            return Err(AdapterError::WrongLayout { adapter: self.name().to_string(), data_len: data.len() });
+        }
+        let invalid = |reason: &str| AdapterError::InvalidState { adapter: self.name().to_string(), reason: reason.to_string() };
+        let liquidity = read_u128(data, 237).ok_or_else(|| invalid("liquidity"))?;
+        let sqrt_price_x64 = read_u128(data, 253).ok_or_else(|| invalid("sqrt price"))?;
+        if sqrt_price_x64 == 0 {
+            return Err(invalid("zero sqrt price"));
+        }
+        Ok(PoolState {
+            mint_a: read_pubkey(data, 73).ok_or_else(|| invalid("mint 0"))?,
+            mint_b: read_pubkey(data, 105).ok_or_else(|| invalid("mint 1"))?,
+            vault_a: read_pubkey(data, 137).ok_or_else(|| invalid("vault 0"))?,
+            vault_b: read_pubkey(data, 169).ok_or_else(|| invalid("vault 1"))?,
+            fee_rate: self.fee_rate,
+            pricing: Pricing::Concentrated { sqrt_price_x64, liquidity },
+            // AmmConfig, ObservationState
+            pool_accounts: vec![
+                read_pubkey(data, 9).ok_or_else(|| invalid("amm config"))?,
+                read_pubkey(data, 201).ok_or_else(|| invalid("observation"))?,
+            ],
+        })
+    }
+
+    fn spot_price(&self, state: &PoolState) -> f64 {
+        match state.pricing {
+            Pricing::Concentrated { sqrt_price_x64, .. } => (sqrt_price_x64 as f64 / 2f64.powi(64)).powi(2),
+            Pricing::Bin { .. } => 0.0,
+        }
+    }
+
+    fn quote_exact_in(&self, state: &PoolState, amount_in: u64, a_to_b: bool) -> Result<u64, AdapterError> {
+        let Pricing::Concentrated { sqrt_price_x64, liquidity } = state.pricing else {
+            return Err(AdapterError::InvalidState { adapter: self.name().to_string(), reason: "not a CLMM state".to_string() });
+        };
+        concentrated_quote_exact_in(self.name(), sqrt_price_x64, liquidity, state.fee_rate, amount_in, a_to_b)
+    }
+
+    /// `swap`: tick arrays come in `accounts.extra`; a zero price limit means none
+    fn build_swap_ix(
+        &self,
+        pool: &Pubkey,
+        state: &PoolState,
+        accounts: &SwapAccounts,
+        amount_in: u64,
+        min_amount_out: u64,
+        a_to_b: bool,
+    ) -> Result<Instruction, AdapterError> {
+        let [amm_config, observation] = state.pool_accounts[..] else {
+            return Err(AdapterError::InvalidState { adapter: self.name().to_string(), reason: "missing config/observation".to_string() });
+        };
+        let mut data = anchor_discriminator("swap").to_vec();
+        data.extend_from_slice(&amount_in.to_le_bytes());
+        data.extend_from_slice(&min_amount_out.to_le_bytes());
+        data.extend_from_slice(&0u128.to_le_bytes());
+        data.push(1); // is_base_input
+
+        let (input_account, output_account, input_vault, output_vault) = if a_to_b {
+            (accounts.user_token_a, accounts.user_token_b, state.vault_a, state.vault_b)
+        } else {
+            (accounts.user_token_b, accounts.user_token_a, state.vault_b, state.vault_a)
+        };
+        let mut metas = vec![
+            AccountMeta::new_readonly(accounts.user, true),
+            AccountMeta::new_readonly(amm_config, false),
+            AccountMeta::new(*pool, false),
+            AccountMeta::new(input_account, false),
+            AccountMeta::new(output_account, false),
+            AccountMeta::new(input_vault, false),
+            AccountMeta::new(output_vault, false),
+            AccountMeta::new(observation, false),
+            AccountMeta::new_readonly(token_program(), false),
+        ];
+        metas.extend(accounts.extra.iter().cloned());
+        Ok(Instruction { program_id: self.program_id(), accounts: metas, data })
+    }
+}
+
+/// SOL/USDC pool at 150 USDC per SOL
+pub fn fixture() -> ConformanceFixture {
+    let mint_a = Pubkey::from_str(SOL_MINT).unwrap_or_default();
+    let mint_b = Pubkey::from_str(USDC_MINT).unwrap_or_default();
+    let price = 150.0 * 1e6 / 1e9;
+    let sqrt_price_x64 = (f64::sqrt(price) * 2f64.powi(64)) as u128;
+
+    let mut data = vec![0u8; POOL_STATE_LEN];
+    data[..8].copy_from_slice(&POOL_STATE_DISCRIMINATOR);
+    data[9..41].copy_from_slice(Pubkey::new_from_array([4; 32]).as_ref());
+    data[73..105].copy_from_slice(mint_a.as_ref());
+    data[105..137].copy_from_slice(mint_b.as_ref());
+    data[137..169].copy_from_slice(Pubkey::new_from_array([1; 32]).as_ref());
+    data[169..201].copy_from_slice(Pubkey::new_from_array([2; 32]).as_ref());
+    data[201..233].copy_from_slice(Pubkey::new_from_array([5; 32]).as_ref());
+    data[237..253].copy_from_slice(&1_000_000_000_000_000u128.to_le_bytes());
+    data[253..269].copy_from_slice(&sqrt_price_x64.to_le_bytes());
+
+    ConformanceFixture {
+        pool: Pubkey::new_from_array([3; 32]),
+        data,
+        mint_a,
+        mint_b,
+        expected_spot_price: price,
+        probe_amount: 1_000_000,
+    }
+}
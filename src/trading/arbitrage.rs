@@ -385,6 +385,11 @@ impl ArbitrageEngine {
         self.risk_manager.assess_opportunity(opportunity).await
     }
     
+    /// Risk manager gating this engine (clones share asset restrictions)
+    pub fn risk_manager(&self) -> &RiskManager {
+        &self.risk_manager
+    }
+    
     /// Validate execution parameters for arbitrage opportunity
     pub async fn validate_execution(&self, opportunity: &ArbitrageOpportunity) -> Result<()> {
         info!("Validating execution parameters for opportunity: {}/{}", 
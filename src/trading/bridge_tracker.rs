@@ -0,0 +1,451 @@
+//! Bridge transfer tracking
+//!
+//! A bridge transfer goes through three on-chain steps: the source-chain
+//! transaction (initiated), the guardian/relayer attestation (attested) and
+//! the target-chain redemption (redeemed). Any of them can take minutes, and
+//! a transfer can stall after funds left the source chain. The
+//! [`BridgeTracker`] keeps one state machine per transfer, persists it so
+//! tracking resumes after a restart, raises an alert when a transfer stops
+//! progressing, and exposes manual redeem/retry actions for operators.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{debug, info, warn};
+
+use crate::monitoring::{Alert, AlertManager, AlertStatus, HeartbeatHandle, Severity};
+
+/// Tracker settings
+#[derive(Debug, Clone)]
+pub struct BridgeTrackerConfig {
+    pub state_path: PathBuf,
+    pub poll_interval: Duration,
+    /// A transfer that has not advanced for this long is reported as stuck
+    pub stuck_after: Duration,
+}
+
+impl Default for BridgeTrackerConfig {
+    fn default() -> Self {
+        Self {
+            state_path: PathBuf::from("state/bridge_transfers.json"),
+            poll_interval: Duration::from_secs(20),
+            stuck_after: Duration::from_secs(20 * 60),
+        }
+    }
+}
+
+/// Step a transfer has reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BridgeTransferStatus {
+    Initiated,
+    Attested,
+    Redeemed,
+    Failed,
+}
+
+impl BridgeTransferStatus {
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, BridgeTransferStatus::Redeemed | BridgeTransferStatus::Failed)
+    }
+}
+
+/// One bridge transfer and its progress
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BridgeTransfer {
+    pub id: String,
+    pub bridge: String,
+    pub source_chain: String,
+    pub target_chain: String,
+    pub token: String,
+    pub amount: f64,
+    pub source_tx: String,
+    /// Attestation reference (e.g. a Wormhole VAA id) once available
+    pub attestation: Option<String>,
+    pub redeem_tx: Option<String>,
+    pub status: BridgeTransferStatus,
+    pub initiated_at: DateTime<Utc>,
+    /// Last time the transfer advanced (or was retried)
+    pub updated_at: DateTime<Utc>,
+    pub stuck_alerted: bool,
+    pub last_error: Option<String>,
+    /// Operator actions taken on this transfer
+    pub actions: Vec<String>,
+}
+
+impl BridgeTransfer {
+    fn advance(&mut self, status: BridgeTransferStatus, now: DateTime<Utc>) {
+        debug!("🌉 Bridge transfer {} {:?} → {:?}", self.id, self.status, status);
+        self.status = status;
+        self.updated_at = now;
+        self.stuck_alerted = false;
+    }
+
+    fn record_action(&mut self, operator: &str, action: &str) {
+        self.actions.push(format!("{} {}: {}", Utc::now().to_rfc3339(), operator, action));
+    }
+}
+
+/// What the bridge reports for a transfer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeProgress {
+    Pending,
+    Attested { attestation: String },
+    Redeemed { redeem_tx: String },
+    Failed { reason: String },
+}
+
+/// Bridge-specific status lookups and redemption
+#[async_trait]
+pub trait BridgeStatusSource: Send + Sync {
+    async fn check(&self, transfer: &BridgeTransfer) -> Result<BridgeProgress>;
+
+    /// Submit the redemption on the target chain; returns its signature
+    async fn redeem(&self, transfer: &BridgeTransfer) -> Result<String>;
+}
+
+/// Wormhole transfer status from the Wormholescan API
+pub struct WormholescanSource {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl WormholescanSource {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self { client: reqwest::Client::new(), base_url: base_url.into() }
+    }
+}
+
+impl Default for WormholescanSource {
+    fn default() -> Self {
+        Self::new("https://api.wormholescan.io")
+    }
+}
+
+#[async_trait]
+impl BridgeStatusSource for WormholescanSource {
+    async fn check(&self, transfer: &BridgeTransfer) -> Result<BridgeProgress> {
+        let url = format!("{}/api/v1/operations?txHash={}", self.base_url, transfer.source_tx);
+        let response: Value = self.client.get(&url).send().await?.error_for_status()?.json().await?;
+        Ok(parse_wormholescan_operation(&response["operations"][0]))
+    }
+
+    async fn redeem(&self, transfer: &BridgeTransfer) -> Result<String> {
+        Err(anyhow!(
+            "automatic redemption is not available for {} on {}; redeem on the target chain and record the transaction",
+            transfer.bridge, transfer.target_chain
+        ))
+    }
+}
+
+fn parse_wormholescan_operation(operation: &Value) -> BridgeProgress {
+    if let Some(redeem_tx) = operation["targetChain"]["transaction"]["txHash"].as_str() {
+        return BridgeProgress::Redeemed { redeem_tx: redeem_tx.to_string() };
+    }
+    match operation["vaa"]["id"].as_str().or_else(|| operation["id"].as_str()) {
+        Some(id) if !operation["vaa"].is_null() => BridgeProgress::Attested { attestation: id.to_string() },
+        _ => BridgeProgress::Pending,
+    }
+}
+
+/// Persistent bridge transfer state machines
+pub struct BridgeTracker {
+    config: BridgeTrackerConfig,
+    transfers: RwLock<HashMap<String, BridgeTransfer>>,
+    source: Arc<dyn BridgeStatusSource>,
+    alert_manager: Option<Arc<AlertManager>>,
+}
+
+impl BridgeTracker {
+    /// Load persisted transfers so unfinished ones keep being tracked
+    pub fn new(config: BridgeTrackerConfig) -> Self {
+        let transfers: HashMap<String, BridgeTransfer> = std::fs::read_to_string(&config.state_path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        let active = transfers.values().filter(|t| !t.status.is_terminal()).count();
+        if active > 0 {
+            info!("🌉 Resuming tracking of {} bridge transfers", active);
+        }
+        Self {
+            config,
+            transfers: RwLock::new(transfers),
+            source: Arc::new(WormholescanSource::default()),
+            alert_manager: None,
+        }
+    }
+
+    pub fn with_source(mut self, source: Arc<dyn BridgeStatusSource>) -> Self {
+        self.source = source;
+        self
+    }
+
+    pub fn with_alert_manager(mut self, alert_manager: Arc<AlertManager>) -> Self {
+        self.alert_manager = Some(alert_manager);
+        self
+    }
+
+    /// Start tracking a transfer whose source transaction was submitted
+    pub fn track(&self, bridge: &str, source_chain: &str, target_chain: &str, token: &str, amount: f64, source_tx: &str) -> BridgeTransfer {
+        let now = Utc::now();
+        let transfer = BridgeTransfer {
+            id: uuid::Uuid::new_v4().to_string(),
+            bridge: bridge.to_string(),
+            source_chain: source_chain.to_string(),
+            target_chain: target_chain.to_string(),
+            token: token.to_string(),
+            amount,
+            source_tx: source_tx.to_string(),
+            attestation: None,
+            redeem_tx: None,
+            status: BridgeTransferStatus::Initiated,
+            initiated_at: now,
+            updated_at: now,
+            stuck_alerted: false,
+            last_error: None,
+            actions: Vec::new(),
+        };
+        info!("🌉 Tracking {} transfer {}: {} {} {} → {}",
+              bridge, transfer.id, amount, token, source_chain, target_chain);
+        self.transfers.write().insert(transfer.id.clone(), transfer.clone());
+        self.persist();
+        transfer
+    }
+
+    pub fn transfers(&self) -> Vec<BridgeTransfer> {
+        let mut transfers: Vec<_> = self.transfers.read().values().cloned().collect();
+        transfers.sort_by_key(|t| t.initiated_at);
+        transfers
+    }
+
+    pub fn active(&self) -> Vec<BridgeTransfer> {
+        self.transfers().into_iter().filter(|t| !t.status.is_terminal()).collect()
+    }
+
+    /// Check every unfinished transfer once; returns the transfers found stuck
+    pub async fn poll_once(&self) -> Vec<BridgeTransfer> {
+        let now = Utc::now();
+        let stuck_after = chrono::Duration::from_std(self.config.stuck_after).unwrap_or(chrono::Duration::minutes(20));
+        let mut newly_stuck = Vec::new();
+
+        for transfer in self.active() {
+            let progress = self.source.check(&transfer).await;
+            let updated = {
+                let mut transfers = self.transfers.write();
+                let Some(current) = transfers.get_mut(&transfer.id) else { continue };
+                match progress {
+                    Ok(BridgeProgress::Pending) => current.last_error = None,
+                    Ok(BridgeProgress::Attested { attestation }) => {
+                        if current.status == BridgeTransferStatus::Initiated {
+                            current.attestation = Some(attestation);
+                            current.advance(BridgeTransferStatus::Attested, now);
+                        }
+                    }
+                    Ok(BridgeProgress::Redeemed { redeem_tx }) => {
+                        current.redeem_tx = Some(redeem_tx);
+                        current.advance(BridgeTransferStatus::Redeemed, now);
+                        info!("✅ Bridge transfer {} redeemed on {}", current.id, current.target_chain);
+                    }
+                    Ok(BridgeProgress::Failed { reason }) => {
+                        current.last_error = Some(reason);
+                        current.advance(BridgeTransferStatus::Failed, now);
+                    }
+                    Err(e) => current.last_error = Some(e.to_string()),
+                }
+                if !current.status.is_terminal() && !current.stuck_alerted && now - current.updated_at >= stuck_after {
+                    current.stuck_alerted = true;
+                    newly_stuck.push(current.clone());
+                }
+                current.clone()
+            };
+            if updated.status == BridgeTransferStatus::Failed && transfer.status != BridgeTransferStatus::Failed {
+                self.alert(&updated, "failed", Severity::Critical).await;
+            }
+        }
+
+        for transfer in &newly_stuck {
+            warn!("⏳ Bridge transfer {} stuck in {:?} since {}", transfer.id, transfer.status, transfer.updated_at);
+            self.alert(transfer, "stuck", Severity::High).await;
+        }
+        self.persist();
+        newly_stuck
+    }
+
+    /// Redeem a transfer: records `redeem_tx` if the operator already redeemed, otherwise asks the bridge
+    pub async fn redeem(&self, id: &str, operator: &str, redeem_tx: Option<String>) -> Result<BridgeTransfer> {
+        let transfer = self.transfers.read().get(id).cloned().ok_or_else(|| anyhow!("unknown bridge transfer '{}'", id))?;
+        if transfer.status == BridgeTransferStatus::Redeemed {
+            return Err(anyhow!("bridge transfer '{}' is already redeemed", id));
+        }
+        let redeem_tx = match redeem_tx {
+            Some(tx) => tx,
+            None => self.source.redeem(&transfer).await?,
+        };
+
+        let updated = {
+            let mut transfers = self.transfers.write();
+            let current = transfers.get_mut(id).ok_or_else(|| anyhow!("unknown bridge transfer '{}'", id))?;
+            current.redeem_tx = Some(redeem_tx.clone());
+            current.last_error = None;
+            current.advance(BridgeTransferStatus::Redeemed, Utc::now());
+            current.record_action(operator, &format!("redeemed with {}", redeem_tx));
+            current.clone()
+        };
+        info!("✅ Bridge transfer {} redeemed by {} ({})", id, operator, redeem_tx);
+        self.persist();
+        Ok(updated)
+    }
+
+    /// Put a failed or stuck transfer back into tracking from its last confirmed step
+    pub fn retry(&self, id: &str, operator: &str) -> Result<BridgeTransfer> {
+        let updated = {
+            let mut transfers = self.transfers.write();
+            let current = transfers.get_mut(id).ok_or_else(|| anyhow!("unknown bridge transfer '{}'", id))?;
+            if current.status == BridgeTransferStatus::Redeemed {
+                return Err(anyhow!("bridge transfer '{}' is already redeemed", id));
+            }
+            let resume_at = if current.attestation.is_some() {
+                BridgeTransferStatus::Attested
+            } else {
+                BridgeTransferStatus::Initiated
+            };
+            current.last_error = None;
+            current.advance(resume_at, Utc::now());
+            current.record_action(operator, &format!("retry from {:?}", resume_at));
+            current.clone()
+        };
+        info!("🔁 Bridge transfer {} retried by {}", id, operator);
+        self.persist();
+        Ok(updated)
+    }
+
+    /// Poll until the task is stopped
+    pub async fn run(self: Arc<Self>, heartbeat: HeartbeatHandle) {
+        info!("🌉 Bridge tracker running ({} active transfers)", self.active().len());
+        loop {
+            self.poll_once().await;
+            heartbeat.beat();
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.config.poll_interval
+    }
+
+    async fn alert(&self, transfer: &BridgeTransfer, what: &str, severity: Severity) {
+        let Some(alert_manager) = &self.alert_manager else { return };
+        alert_manager.raise_alert(Alert {
+            id: uuid::Uuid::new_v4().to_string(),
+            title: format!("Bridge transfer {} {}", transfer.id, what),
+            description: format!(
+                "{} {} {} → {} via {} is {:?} since {} (source tx {}){}",
+                transfer.amount, transfer.token, transfer.source_chain, transfer.target_chain, transfer.bridge,
+                transfer.status, transfer.updated_at, transfer.source_tx,
+                transfer.last_error.as_deref().map(|e| format!(": {}", e)).unwrap_or_default()
+            ),
+            severity,
+            status: AlertStatus::Open,
+            created_at: Utc::now(),
+            resolved_at: None,
+            tags: vec!["bridge".to_string(), transfer.bridge.clone(), transfer.id.clone()],
+        }).await;
+    }
+
+    /// Write state atomically (temp file + rename)
+    fn persist(&self) {
+        let result = (|| -> Result<()> {
+            let path = &self.config.state_path;
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            let content = serde_json::to_string_pretty(&*self.transfers.read())?;
+            let temp_file = path.with_extension("tmp");
+            std::fs::write(&temp_file, content)?;
+            std::fs::rename(&temp_file, path)?;
+            Ok(())
+        })();
+        if let Err(e) = result {
+            warn!("⚠️ Failed to persist bridge transfers: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ScriptedSource(parking_lot::Mutex<Vec<BridgeProgress>>);
+
+    #[async_trait]
+    impl BridgeStatusSource for ScriptedSource {
+        async fn check(&self, _transfer: &BridgeTransfer) -> Result<BridgeProgress> {
+            let mut script = self.0.lock();
+            Ok(if script.len() > 1 { script.remove(0) } else { script[0].clone() })
+        }
+
+        async fn redeem(&self, _transfer: &BridgeTransfer) -> Result<String> {
+            Ok("redeem-sig".to_string())
+        }
+    }
+
+    fn config(dir: &tempfile::TempDir) -> BridgeTrackerConfig {
+        BridgeTrackerConfig {
+            state_path: dir.path().join("bridge.json"),
+            stuck_after: Duration::from_secs(0),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transfer_advances_and_resumes_after_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = Arc::new(ScriptedSource(parking_lot::Mutex::new(vec![
+            BridgeProgress::Attested { attestation: "vaa-1".to_string() },
+            BridgeProgress::Pending,
+        ])));
+        let tracker = BridgeTracker::new(config(&dir)).with_source(source.clone());
+        let transfer = tracker.track("Wormhole", "Solana", "Ethereum", "USDC", 100.0, "src-sig");
+
+        let stuck = tracker.poll_once().await;
+        assert_eq!(stuck.len(), 1);
+        assert_eq!(stuck[0].status, BridgeTransferStatus::Attested);
+        // Alerted once, not on every poll
+        assert!(tracker.poll_once().await.is_empty());
+
+        let resumed = BridgeTracker::new(config(&dir)).with_source(source);
+        let active = resumed.active();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].id, transfer.id);
+        assert_eq!(active[0].attestation.as_deref(), Some("vaa-1"));
+    }
+
+    #[tokio::test]
+    async fn test_manual_redeem_and_retry() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = Arc::new(ScriptedSource(parking_lot::Mutex::new(vec![
+            BridgeProgress::Failed { reason: "relayer rejected".to_string() },
+        ])));
+        let tracker = BridgeTracker::new(config(&dir)).with_source(source);
+        let transfer = tracker.track("Wormhole", "Solana", "Arbitrum", "USDC", 50.0, "src-sig");
+
+        tracker.poll_once().await;
+        assert_eq!(tracker.transfers()[0].status, BridgeTransferStatus::Failed);
+
+        let retried = tracker.retry(&transfer.id, "ops").unwrap();
+        assert_eq!(retried.status, BridgeTransferStatus::Initiated);
+
+        let redeemed = tracker.redeem(&transfer.id, "ops", None).await.unwrap();
+        assert_eq!(redeemed.status, BridgeTransferStatus::Redeemed);
+        assert_eq!(redeemed.redeem_tx.as_deref(), Some("redeem-sig"));
+        assert_eq!(redeemed.actions.len(), 2);
+        assert!(tracker.retry(&transfer.id, "ops").is_err());
+    }
+}
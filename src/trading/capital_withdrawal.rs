@@ -0,0 +1,649 @@
+//! Capital withdrawal workflow
+//!
+//! Pulling capital out of a running bot by hand means guessing how much is
+//! not tied up in positions and hoping no new trade grabs it in the meantime.
+//! A withdrawal request instead locks an amount of an asset in a wallet: from
+//! that moment the amount is subtracted from what the scheduler may allocate.
+//! The request then waits until the wallet's positions in that asset have
+//! closed and its free balance covers the amount, sweeps it to the
+//! destination address and reports the freed capital.
+//!
+//! ```text
+//! Locked -> Draining -> Sweeping -> Completed
+//!              |            \-> Failed (lock released)
+//!              \-> Cancelled / Expired (lock released)
+//! ```
+//!
+//! With `require_approval` set, a request only sweeps once an operator has
+//! approved it; until then it keeps its lock and drains. Requests persist to
+//! `state_path` so locks survive a restart.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, VersionedTransaction},
+};
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use super::execution::admit_shared;
+use super::risk::RiskManager;
+use crate::security::dust::TOKEN_PROGRAM_ID;
+use crate::types::constants::SOL_MINT;
+
+/// Associated token account program
+const ASSOCIATED_TOKEN_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+
+/// Where a withdrawal is in its lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WithdrawalState {
+    /// Accepted; the amount is no longer allocated to new trades
+    Locked,
+    /// Waiting for positions to close or the free balance to cover the amount
+    Draining,
+    /// Transfer in flight
+    Sweeping,
+    Completed,
+    Failed,
+    Cancelled,
+    /// Capital did not free up within the configured wait
+    Expired,
+}
+
+impl WithdrawalState {
+    /// Whether the amount is still held back from allocation
+    pub fn holds_capital(&self) -> bool {
+        matches!(self, WithdrawalState::Locked | WithdrawalState::Draining | WithdrawalState::Sweeping)
+    }
+}
+
+/// One withdrawal and its progress
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawalRequest {
+    pub id: String,
+    pub wallet: String,
+    /// Symbol or mint, as the backend understands it
+    pub asset: String,
+    pub amount: f64,
+    pub destination: String,
+    pub state: WithdrawalState,
+    pub requested_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Positions still open in `asset` at the last check
+    pub open_positions: usize,
+    /// Free (unallocated) balance at the last check
+    pub free_balance: Option<f64>,
+    pub signature: Option<String>,
+    pub error: Option<String>,
+    /// Capital moved out once completed
+    pub freed: f64,
+    /// Operator who released the sweep, when approval is required
+    #[serde(default)]
+    pub approved_by: Option<String>,
+}
+
+/// Withdrawal policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawalConfig {
+    /// Left in the wallet on top of the amount (fees, rent)
+    pub reserve: f64,
+    /// Requests still not swept after this long expire and release their lock
+    pub max_wait_hours: i64,
+    /// Check interval of the background runner
+    pub check_interval_secs: u64,
+    /// Sweep only requests an operator approved
+    #[serde(default)]
+    pub require_approval: bool,
+    /// Where requests are persisted; in memory only when unset
+    #[serde(default)]
+    pub state_path: Option<PathBuf>,
+}
+
+impl Default for WithdrawalConfig {
+    fn default() -> Self {
+        Self {
+            reserve: 0.05,
+            max_wait_hours: 72,
+            check_interval_secs: 60,
+            require_approval: false,
+            state_path: None,
+        }
+    }
+}
+
+/// Wallet state and transfers the workflow relies on
+#[async_trait]
+pub trait WithdrawalBackend: Send + Sync {
+    /// Positions in `asset` still open for `wallet`
+    async fn open_positions(&self, wallet: &str, asset: &str) -> Result<usize>;
+    /// Balance of `asset` in `wallet` not committed to positions
+    async fn free_balance(&self, wallet: &str, asset: &str) -> Result<f64>;
+    /// Transfer the request's amount; returns the transaction signature
+    async fn sweep(&self, request: &WithdrawalRequest) -> Result<String>;
+}
+
+/// Capital withdrawn or still locked, for reports
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WithdrawalSummary {
+    /// asset -> amount held back from allocation
+    pub locked: HashMap<String, f64>,
+    /// asset -> amount swept out
+    pub freed: HashMap<String, f64>,
+    pub pending: usize,
+    pub completed: usize,
+    pub failed: usize,
+}
+
+/// Locks, drains and sweeps requested withdrawals
+#[derive(Debug, Default)]
+pub struct CapitalWithdrawals {
+    config: WithdrawalConfig,
+    requests: RwLock<Vec<WithdrawalRequest>>,
+}
+
+impl CapitalWithdrawals {
+    pub fn new(config: WithdrawalConfig) -> Self {
+        Self { config, requests: RwLock::new(Vec::new()) }
+    }
+
+    /// Restore the requests persisted at `config.state_path`
+    ///
+    /// A transfer a restart interrupted may or may not have landed: it is
+    /// marked failed, with the lock released, for an operator to check.
+    pub fn open(config: WithdrawalConfig) -> Result<Self> {
+        let mut requests = match &config.state_path {
+            Some(path) => Self::load(path)?,
+            None => Vec::new(),
+        };
+        for request in requests.iter_mut().filter(|r| r.state == WithdrawalState::Sweeping) {
+            warn!("⚠️ Withdrawal {} was mid-transfer at shutdown; check {} before requesting it again",
+                  request.id, request.destination);
+            request.state = WithdrawalState::Failed;
+            request.error = Some("transfer interrupted by a restart, verify on chain".to_string());
+        }
+        let pending = requests.iter().filter(|r| r.state.holds_capital()).count();
+        if pending > 0 {
+            info!("🔒 {} withdrawal requests restored with their locks", pending);
+        }
+        let withdrawals = Self { config, requests: RwLock::new(requests) };
+        withdrawals.save();
+        Ok(withdrawals)
+    }
+
+    fn load(path: &Path) -> Result<Vec<WithdrawalRequest>> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save(&self) {
+        let Some(path) = &self.config.state_path else { return };
+        let result = (|| -> Result<()> {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            let temp_file = path.with_extension("tmp");
+            std::fs::write(&temp_file, serde_json::to_string_pretty(&*self.requests.read())?)?;
+            std::fs::rename(&temp_file, path)?;
+            Ok(())
+        })();
+        if let Err(e) = result {
+            warn!("⚠️ Failed to persist withdrawal requests: {}", e);
+        }
+    }
+
+    pub fn config(&self) -> &WithdrawalConfig {
+        &self.config
+    }
+
+    /// Mark `amount` of `asset` in `wallet` for withdrawal to `destination`
+    pub fn request(&self, wallet: &str, asset: &str, amount: f64, destination: &str) -> Result<WithdrawalRequest> {
+        if !(amount > 0.0 && amount.is_finite()) {
+            return Err(anyhow!("Withdrawal amount must be positive, got {}", amount));
+        }
+        Pubkey::from_str(destination).map_err(|e| anyhow!("Invalid destination address '{}': {}", destination, e))?;
+
+        let now = Utc::now();
+        let request = WithdrawalRequest {
+            id: Uuid::new_v4().to_string(),
+            wallet: wallet.to_string(),
+            asset: asset.to_string(),
+            amount,
+            destination: destination.to_string(),
+            state: WithdrawalState::Locked,
+            requested_at: now,
+            updated_at: now,
+            open_positions: 0,
+            free_balance: None,
+            signature: None,
+            error: None,
+            freed: 0.0,
+            approved_by: None,
+        };
+        info!("🔒 Withdrawal {} locked {:.4} {} in {} for {}", request.id, amount, request.asset, wallet, destination);
+        self.requests.write().push(request.clone());
+        self.save();
+        Ok(request)
+    }
+
+    /// Release the sweep of a pending request
+    pub fn approve(&self, id: &str, operator: &str) -> Result<WithdrawalRequest> {
+        let approved = {
+            let mut requests = self.requests.write();
+            let request = requests.iter_mut().find(|r| r.id == id).ok_or_else(|| anyhow!("Withdrawal {} not found", id))?;
+            if !matches!(request.state, WithdrawalState::Locked | WithdrawalState::Draining) {
+                return Err(anyhow!("Withdrawal {} is {:?} and can no longer be approved", id, request.state));
+            }
+            request.approved_by = Some(operator.to_string());
+            request.updated_at = Utc::now();
+            request.clone()
+        };
+        info!("✅ Withdrawal {} of {:.4} {} approved by {}", id, approved.amount, approved.asset, operator);
+        self.save();
+        Ok(approved)
+    }
+
+    /// Cancel a request that has not started its transfer
+    pub fn cancel(&self, id: &str) -> Result<WithdrawalRequest> {
+        let cancelled = {
+            let mut requests = self.requests.write();
+            let request = requests.iter_mut().find(|r| r.id == id).ok_or_else(|| anyhow!("Withdrawal {} not found", id))?;
+            if !matches!(request.state, WithdrawalState::Locked | WithdrawalState::Draining) {
+                return Err(anyhow!("Withdrawal {} is {:?} and can no longer be cancelled", id, request.state));
+            }
+            request.state = WithdrawalState::Cancelled;
+            request.updated_at = Utc::now();
+            request.clone()
+        };
+        info!("🔓 Withdrawal {} cancelled, {:.4} {} released", id, cancelled.amount, cancelled.asset);
+        self.save();
+        Ok(cancelled)
+    }
+
+    pub fn get(&self, id: &str) -> Option<WithdrawalRequest> {
+        self.requests.read().iter().find(|r| r.id == id).cloned()
+    }
+
+    /// All requests, oldest first
+    pub fn requests(&self) -> Vec<WithdrawalRequest> {
+        self.requests.read().clone()
+    }
+
+    /// Amount of `asset` held back from allocation (symbols match case-insensitively)
+    pub fn locked(&self, asset: &str) -> f64 {
+        self.requests.read()
+            .iter()
+            .filter(|r| r.asset.eq_ignore_ascii_case(asset) && r.state.holds_capital())
+            .map(|r| r.amount)
+            .sum()
+    }
+
+    /// What may still be allocated out of `available` of `asset`
+    pub fn allocatable(&self, asset: &str, available: f64) -> f64 {
+        (available - self.locked(asset)).max(0.0)
+    }
+
+    pub fn summary(&self) -> WithdrawalSummary {
+        let mut summary = WithdrawalSummary::default();
+        for request in self.requests.read().iter() {
+            match request.state {
+                state if state.holds_capital() => {
+                    *summary.locked.entry(request.asset.clone()).or_insert(0.0) += request.amount;
+                    summary.pending += 1;
+                }
+                WithdrawalState::Completed => {
+                    *summary.freed.entry(request.asset.clone()).or_insert(0.0) += request.freed;
+                    summary.completed += 1;
+                }
+                WithdrawalState::Failed | WithdrawalState::Expired => summary.failed += 1,
+                _ => {}
+            }
+        }
+        summary
+    }
+
+    /// Move every pending request forward; returns the ones that changed state
+    pub async fn advance(&self, backend: &dyn WithdrawalBackend) -> Vec<WithdrawalRequest> {
+        self.advance_at(backend, Utc::now()).await
+    }
+
+    pub async fn advance_at(&self, backend: &dyn WithdrawalBackend, now: DateTime<Utc>) -> Vec<WithdrawalRequest> {
+        let pending: Vec<WithdrawalRequest> = self.requests.read()
+            .iter()
+            .filter(|r| matches!(r.state, WithdrawalState::Locked | WithdrawalState::Draining))
+            .cloned()
+            .collect();
+
+        let mut changed = Vec::new();
+        for mut request in pending {
+            let before = request.state;
+            if now - request.requested_at > Duration::hours(self.config.max_wait_hours) {
+                request.state = WithdrawalState::Expired;
+                request.error = Some(format!("capital not freed within {}h", self.config.max_wait_hours));
+                warn!("⌛ Withdrawal {} expired: {:.4} {} still committed in {}", request.id, request.amount, request.asset, request.wallet);
+            } else {
+                match self.check(backend, &mut request).await {
+                    Ok(true) if self.config.require_approval && request.approved_by.is_none() => {
+                        debug!("🔒 Withdrawal {} ready, awaiting approval", request.id);
+                        request.state = WithdrawalState::Draining;
+                    }
+                    Ok(true) => self.sweep(backend, &mut request).await,
+                    Ok(false) => request.state = WithdrawalState::Draining,
+                    Err(e) => {
+                        // Transient: the lock stays and the next check retries
+                        request.state = WithdrawalState::Draining;
+                        request.error = Some(e.to_string());
+                    }
+                }
+            }
+            request.updated_at = now;
+
+            if let Some(stored) = self.requests.write().iter_mut().find(|r| r.id == request.id) {
+                // Cancelled while the backend was being queried
+                if stored.state == WithdrawalState::Cancelled {
+                    continue;
+                }
+                *stored = request.clone();
+            }
+            if request.state != before {
+                changed.push(request);
+            }
+        }
+        if !changed.is_empty() {
+            self.save();
+        }
+        changed
+    }
+
+    /// Spawn a task advancing pending requests on the configured interval
+    pub fn start(self: Arc<Self>, backend: Arc<dyn WithdrawalBackend>) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(self.config.check_interval_secs.max(1)));
+            loop {
+                interval.tick().await;
+                self.advance(backend.as_ref()).await;
+            }
+        })
+    }
+
+    /// Whether the request's capital is free to leave
+    async fn check(&self, backend: &dyn WithdrawalBackend, request: &mut WithdrawalRequest) -> Result<bool> {
+        request.open_positions = backend.open_positions(&request.wallet, &request.asset).await?;
+        let free = backend.free_balance(&request.wallet, &request.asset).await?;
+        request.free_balance = Some(free);
+        request.error = None;
+        Ok(request.open_positions == 0 && free >= request.amount + self.config.reserve)
+    }
+
+    async fn sweep(&self, backend: &dyn WithdrawalBackend, request: &mut WithdrawalRequest) {
+        request.state = WithdrawalState::Sweeping;
+        if let Some(stored) = self.requests.write().iter_mut().find(|r| r.id == request.id) {
+            stored.state = WithdrawalState::Sweeping;
+        }
+        self.save();
+        match backend.sweep(request).await {
+            Ok(signature) => {
+                request.state = WithdrawalState::Completed;
+                request.freed = request.amount;
+                info!("🏧 Withdrawal {} completed: {:.4} {} freed from {} to {} ({})",
+                      request.id, request.freed, request.asset, request.wallet, request.destination, signature);
+                request.signature = Some(signature);
+            }
+            Err(e) => {
+                request.state = WithdrawalState::Failed;
+                request.error = Some(e.to_string());
+                warn!("❌ Withdrawal {} sweep failed, {:.4} {} released: {}", request.id, request.amount, request.asset, e);
+            }
+        }
+    }
+}
+
+/// Withdraws from one hot wallet on chain: SOL natively, other assets by mint
+///
+/// Open positions are the trades the risk manager has reserved and not yet
+/// settled with a leg in the asset.
+pub struct RpcWithdrawalBackend {
+    rpc: Arc<RpcClient>,
+    keypair: Arc<Keypair>,
+    wallet_name: String,
+    risk_manager: RiskManager,
+}
+
+impl RpcWithdrawalBackend {
+    pub fn new(rpc: Arc<RpcClient>, keypair: Arc<Keypair>, wallet_name: &str, risk_manager: RiskManager) -> Self {
+        Self { rpc, keypair, wallet_name: wallet_name.to_string(), risk_manager }
+    }
+
+    fn check_wallet(&self, wallet: &str) -> Result<()> {
+        if wallet == self.wallet_name {
+            Ok(())
+        } else {
+            Err(anyhow!("Wallet {} is not managed by this backend (only {})", wallet, self.wallet_name))
+        }
+    }
+
+    /// `None` for native SOL, else the token mint
+    fn token_mint(asset: &str) -> Result<Option<Pubkey>> {
+        if asset.eq_ignore_ascii_case("SOL") || asset == SOL_MINT {
+            return Ok(None);
+        }
+        Pubkey::from_str(asset)
+            .map(Some)
+            .map_err(|_| anyhow!("Asset {} must be SOL or a token mint", asset))
+    }
+
+    fn token_program() -> Pubkey {
+        Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap_or_default()
+    }
+
+    fn associated_token_address(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+        let program = Pubkey::from_str(ASSOCIATED_TOKEN_PROGRAM_ID).unwrap_or_default();
+        Pubkey::find_program_address(&[owner.as_ref(), Self::token_program().as_ref(), mint.as_ref()], &program).0
+    }
+
+    /// Create `owner`'s token account for `mint` unless it exists
+    fn create_associated_account_ix(payer: &Pubkey, owner: &Pubkey, mint: &Pubkey) -> Instruction {
+        Instruction {
+            program_id: Pubkey::from_str(ASSOCIATED_TOKEN_PROGRAM_ID).unwrap_or_default(),
+            accounts: vec![
+                AccountMeta::new(*payer, true),
+                AccountMeta::new(Self::associated_token_address(owner, mint), false),
+                AccountMeta::new_readonly(*owner, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(Self::token_program(), false),
+            ],
+            // CreateIdempotent
+            data: vec![1],
+        }
+    }
+
+    fn transfer_checked_ix(source: &Pubkey, mint: &Pubkey, destination: &Pubkey, owner: &Pubkey, amount: u64, decimals: u8) -> Instruction {
+        // TransferChecked: tag 12, amount (u64 LE), decimals
+        let mut data = vec![12];
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.push(decimals);
+        Instruction {
+            program_id: Self::token_program(),
+            accounts: vec![
+                AccountMeta::new(*source, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new(*destination, false),
+                AccountMeta::new_readonly(*owner, true),
+            ],
+            data,
+        }
+    }
+}
+
+#[async_trait]
+impl WithdrawalBackend for RpcWithdrawalBackend {
+    async fn open_positions(&self, wallet: &str, asset: &str) -> Result<usize> {
+        self.check_wallet(wallet)?;
+        Ok(match Self::token_mint(asset)? {
+            None => self.risk_manager.open_trades("SOL") + self.risk_manager.open_trades(SOL_MINT),
+            Some(_) => self.risk_manager.open_trades(asset),
+        })
+    }
+
+    async fn free_balance(&self, wallet: &str, asset: &str) -> Result<f64> {
+        self.check_wallet(wallet)?;
+        let owner = self.keypair.pubkey();
+        match Self::token_mint(asset)? {
+            None => Ok(self.rpc.get_balance(&owner).await? as f64 / 1_000_000_000.0),
+            Some(mint) => {
+                let balance = self.rpc.get_token_account_balance(&Self::associated_token_address(&owner, &mint)).await?;
+                Ok(balance.amount.parse::<u64>()? as f64 / 10f64.powi(balance.decimals as i32))
+            }
+        }
+    }
+
+    async fn sweep(&self, request: &WithdrawalRequest) -> Result<String> {
+        self.check_wallet(&request.wallet)?;
+        let owner = self.keypair.pubkey();
+        let destination = Pubkey::from_str(&request.destination)?;
+        let instructions = match Self::token_mint(&request.asset)? {
+            None => vec![system_instruction::transfer(&owner, &destination, (request.amount * 1_000_000_000.0) as u64)],
+            Some(mint) => {
+                let source = Self::associated_token_address(&owner, &mint);
+                let decimals = self.rpc.get_token_account_balance(&source).await?.decimals;
+                let amount = (request.amount * 10f64.powi(decimals as i32)) as u64;
+                vec![
+                    Self::create_associated_account_ix(&owner, &destination, &mint),
+                    Self::transfer_checked_ix(&source, &mint, &Self::associated_token_address(&destination, &mint), &owner, amount, decimals),
+                ]
+            }
+        };
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(&instructions, Some(&owner), &[self.keypair.as_ref()], blockhash);
+        admit_shared(&owner.to_string(), Some(&format!("withdrawal:{}", request.id)), &VersionedTransaction::from(tx.clone()))?;
+        Ok(self.rpc.send_and_confirm_transaction(&tx).await?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+
+    const DESTINATION: &str = "11111111111111111111111111111111";
+
+    #[derive(Default)]
+    struct FakeWallet {
+        open_positions: Mutex<usize>,
+        free_balance: Mutex<f64>,
+        swept: Mutex<Vec<f64>>,
+    }
+
+    #[async_trait]
+    impl WithdrawalBackend for FakeWallet {
+        async fn open_positions(&self, _wallet: &str, _asset: &str) -> Result<usize> {
+            Ok(*self.open_positions.lock())
+        }
+
+        async fn free_balance(&self, _wallet: &str, _asset: &str) -> Result<f64> {
+            Ok(*self.free_balance.lock())
+        }
+
+        async fn sweep(&self, request: &WithdrawalRequest) -> Result<String> {
+            self.swept.lock().push(request.amount);
+            Ok("sig".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_withdrawal_waits_for_positions_then_sweeps() {
+        let withdrawals = CapitalWithdrawals::default();
+        let wallet = FakeWallet::default();
+        *wallet.open_positions.lock() = 2;
+        *wallet.free_balance.lock() = 3.0;
+
+        let request = withdrawals.request("trading", "sol", 5.0, DESTINATION).unwrap();
+        assert_eq!(withdrawals.locked("SOL"), 5.0);
+        assert_eq!(withdrawals.allocatable("SOL", 12.0), 7.0);
+
+        let changed = withdrawals.advance(&wallet).await;
+        assert_eq!(changed[0].state, WithdrawalState::Draining);
+        assert_eq!(changed[0].open_positions, 2);
+        assert!(wallet.swept.lock().is_empty());
+
+        // Positions closed and their proceeds back in the free balance
+        *wallet.open_positions.lock() = 0;
+        *wallet.free_balance.lock() = 8.0;
+        let changed = withdrawals.advance(&wallet).await;
+        assert_eq!(changed[0].state, WithdrawalState::Completed);
+        assert_eq!(*wallet.swept.lock(), vec![5.0]);
+
+        let done = withdrawals.get(&request.id).unwrap();
+        assert_eq!(done.freed, 5.0);
+        assert_eq!(done.signature.as_deref(), Some("sig"));
+        assert_eq!(withdrawals.locked("SOL"), 0.0);
+        assert_eq!(withdrawals.summary().freed["sol"], 5.0);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_and_expiry_release_the_lock() {
+        let withdrawals = CapitalWithdrawals::new(WithdrawalConfig { max_wait_hours: 1, ..Default::default() });
+        let wallet = FakeWallet::default();
+        *wallet.open_positions.lock() = 1;
+        assert!(withdrawals.request("trading", "SOL", 1.0, "not-an-address").is_err());
+        assert!(withdrawals.request("trading", "SOL", -1.0, DESTINATION).is_err());
+
+        let cancelled = withdrawals.request("trading", "SOL", 1.0, DESTINATION).unwrap();
+        let expiring = withdrawals.request("trading", "USDC", 100.0, DESTINATION).unwrap();
+        withdrawals.cancel(&cancelled.id).unwrap();
+        assert_eq!(withdrawals.locked("SOL"), 0.0);
+
+        let changed = withdrawals.advance_at(&wallet, Utc::now() + Duration::hours(2)).await;
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].id, expiring.id);
+        assert_eq!(changed[0].state, WithdrawalState::Expired);
+        assert_eq!(withdrawals.locked("USDC"), 0.0);
+        assert!(withdrawals.cancel(&expiring.id).is_err());
+        assert_eq!(withdrawals.summary().failed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_approval_gates_the_sweep_and_locks_survive_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = WithdrawalConfig {
+            require_approval: true,
+            state_path: Some(dir.path().join("withdrawals.json")),
+            ..Default::default()
+        };
+        let wallet = FakeWallet::default();
+        *wallet.free_balance.lock() = 10.0;
+
+        let withdrawals = CapitalWithdrawals::open(config.clone()).unwrap();
+        let request = withdrawals.request("trading", "SOL", 2.0, DESTINATION).unwrap();
+        withdrawals.advance(&wallet).await;
+        assert!(wallet.swept.lock().is_empty());
+        assert_eq!(withdrawals.get(&request.id).unwrap().state, WithdrawalState::Draining);
+
+        // The lock and its progress are back after a restart
+        let restarted = CapitalWithdrawals::open(config).unwrap();
+        assert_eq!(restarted.locked("SOL"), 2.0);
+        restarted.approve(&request.id, "ops").unwrap();
+        let changed = restarted.advance(&wallet).await;
+        assert_eq!(changed[0].state, WithdrawalState::Completed);
+        assert_eq!(changed[0].approved_by.as_deref(), Some("ops"));
+        assert_eq!(*wallet.swept.lock(), vec![2.0]);
+        assert!(restarted.approve(&request.id, "ops").is_err());
+    }
+}
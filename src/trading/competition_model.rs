@@ -0,0 +1,264 @@
+//! Competing Searcher Model
+//!
+//! Simulated fills (shadow ledgers, replays) assume every detected opportunity
+//! is captured, but on-chain the widest spreads are exactly the ones other
+//! searchers race for. This model estimates the probability of being front-run
+//! from the spread size, the venue and the latency we assume, so simulated
+//! returns reflect the share of opportunities we would actually win.
+//!
+//! A parametric curve supplies the prior; live landing outcomes, bucketed by
+//! venue and spread, pull the estimate toward what we actually observe once
+//! enough attempts have been recorded.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::debug;
+
+use super::route_optimizer::VenueOutcome;
+
+/// Shape of the uncalibrated front-run curve
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompetitionConfig {
+    /// Front-run probability at the reference spread and latency
+    pub base_front_run_probability: f64,
+    /// Spread at which `base_front_run_probability` applies (bps)
+    pub reference_spread_bps: f64,
+    /// Elasticity of front-run odds to spread size (wider spreads attract more searchers)
+    pub spread_sensitivity: f64,
+    /// Latency at which `base_front_run_probability` applies (ms)
+    pub reference_latency_ms: f64,
+    /// Elasticity of front-run odds to our latency
+    pub latency_sensitivity: f64,
+    /// Per-venue multiplier on front-run odds (1.0 when absent)
+    pub venue_competitiveness: HashMap<String, f64>,
+    /// Upper bounds of the spread buckets used for calibration (bps)
+    pub spread_buckets_bps: Vec<f64>,
+    /// Pseudo-observations given to the parametric prior in each bucket
+    pub prior_weight: f64,
+    /// Front-run probability never exceeds this
+    pub max_front_run_probability: f64,
+}
+
+impl Default for CompetitionConfig {
+    fn default() -> Self {
+        Self {
+            base_front_run_probability: 0.3,
+            reference_spread_bps: 50.0,
+            spread_sensitivity: 0.5,
+            reference_latency_ms: 400.0,
+            latency_sensitivity: 0.5,
+            venue_competitiveness: HashMap::new(),
+            spread_buckets_bps: vec![10.0, 25.0, 50.0, 100.0, 250.0],
+            prior_weight: 20.0,
+            max_front_run_probability: 0.95,
+        }
+    }
+}
+
+/// One live attempt at an opportunity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LandingObservation {
+    pub venue: String,
+    /// Spread the opportunity showed when detected (bps)
+    pub spread_bps: f64,
+    pub latency_ms: u64,
+    /// The trade landed and kept its edge
+    pub captured: bool,
+}
+
+impl LandingObservation {
+    /// Derive an observation from a venue outcome: a swap counts as captured when
+    /// it landed and its realized slippage did not consume the detected spread
+    pub fn from_outcome(outcome: &VenueOutcome, spread_bps: f64) -> Self {
+        Self {
+            venue: outcome.venue.clone(),
+            spread_bps,
+            latency_ms: outcome.latency_ms,
+            captured: outcome.landed && outcome.realized_slippage_bps < spread_bps,
+        }
+    }
+}
+
+/// Live landing statistics of one venue/spread bucket
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BucketStats {
+    pub attempts: u64,
+    pub captured: u64,
+    pub avg_latency_ms: f64,
+}
+
+/// Front-run estimate for one simulated opportunity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaptureEstimate {
+    pub front_run_probability: f64,
+    pub capture_probability: f64,
+    /// Live attempts backing the estimate (0 = prior only)
+    pub live_samples: u64,
+}
+
+/// Probability of losing an opportunity to a competing searcher
+#[derive(Debug, Clone, Default)]
+pub struct CompetitionModel {
+    config: CompetitionConfig,
+    buckets: HashMap<(String, usize), BucketStats>,
+}
+
+impl CompetitionModel {
+    pub fn new(config: CompetitionConfig) -> Self {
+        Self { config, buckets: HashMap::new() }
+    }
+
+    pub fn config(&self) -> &CompetitionConfig {
+        &self.config
+    }
+
+    fn bucket(&self, spread_bps: f64) -> usize {
+        self.config
+            .spread_buckets_bps
+            .iter()
+            .position(|upper| spread_bps < *upper)
+            .unwrap_or(self.config.spread_buckets_bps.len())
+    }
+
+    fn clamp(&self, probability: f64) -> f64 {
+        probability.clamp(0.0, self.config.max_front_run_probability)
+    }
+
+    /// Latency factor relative to `observed_ms` (ours slower => more front-running)
+    fn latency_factor(&self, latency_ms: f64, observed_ms: f64) -> f64 {
+        if latency_ms <= 0.0 || observed_ms <= 0.0 {
+            return 1.0;
+        }
+        (latency_ms / observed_ms).powf(self.config.latency_sensitivity)
+    }
+
+    /// Uncalibrated front-run probability from the parametric curve
+    pub fn prior_front_run(&self, venue: &str, spread_bps: f64, latency_ms: f64) -> f64 {
+        let c = &self.config;
+        let spread_factor = if spread_bps > 0.0 && c.reference_spread_bps > 0.0 {
+            (spread_bps / c.reference_spread_bps).powf(c.spread_sensitivity)
+        } else {
+            0.0
+        };
+        let venue_factor = c.venue_competitiveness.get(venue).copied().unwrap_or(1.0);
+        self.clamp(
+            c.base_front_run_probability
+                * spread_factor
+                * venue_factor
+                * self.latency_factor(latency_ms, c.reference_latency_ms),
+        )
+    }
+
+    /// Record a live attempt
+    pub fn observe(&mut self, observation: &LandingObservation) {
+        let key = (observation.venue.clone(), self.bucket(observation.spread_bps));
+        let stats = self.buckets.entry(key).or_default();
+        stats.attempts += 1;
+        if observation.captured {
+            stats.captured += 1;
+        }
+        stats.avg_latency_ms += (observation.latency_ms as f64 - stats.avg_latency_ms) / stats.attempts as f64;
+    }
+
+    /// Record a batch of live attempts
+    pub fn calibrate<'a>(&mut self, observations: impl IntoIterator<Item = &'a LandingObservation>) {
+        let mut count = 0usize;
+        for observation in observations {
+            self.observe(observation);
+            count += 1;
+        }
+        debug!("🏁 Competition model calibrated with {} live attempts", count);
+    }
+
+    /// Live statistics for a venue and spread
+    pub fn bucket_stats(&self, venue: &str, spread_bps: f64) -> Option<&BucketStats> {
+        self.buckets.get(&(venue.to_string(), self.bucket(spread_bps)))
+    }
+
+    /// Front-run estimate for an opportunity of `spread_bps` on `venue`, assuming `latency_ms`
+    ///
+    /// The bucket's observed front-run rate is blended with the prior (weighted by
+    /// `prior_weight`) and then shifted from the latency it was observed at to the
+    /// latency being simulated.
+    pub fn estimate(&self, venue: &str, spread_bps: f64, latency_ms: f64) -> CaptureEstimate {
+        let front_run = match self.bucket_stats(venue, spread_bps) {
+            Some(stats) if stats.attempts > 0 => {
+                let observed_latency = stats.avg_latency_ms;
+                let prior = self.prior_front_run(venue, spread_bps, observed_latency);
+                let observed = (stats.attempts - stats.captured) as f64;
+                let blended = (observed + prior * self.config.prior_weight)
+                    / (stats.attempts as f64 + self.config.prior_weight);
+                self.clamp(blended * self.latency_factor(latency_ms, observed_latency))
+            }
+            _ => self.prior_front_run(venue, spread_bps, latency_ms),
+        };
+
+        CaptureEstimate {
+            front_run_probability: front_run,
+            capture_probability: 1.0 - front_run,
+            live_samples: self.bucket_stats(venue, spread_bps).map_or(0, |s| s.attempts),
+        }
+    }
+
+    /// Probability that we capture the opportunity
+    pub fn capture_probability(&self, venue: &str, spread_bps: f64, latency_ms: f64) -> f64 {
+        self.estimate(venue, spread_bps, latency_ms).capture_probability
+    }
+
+    /// Stochastic fill decision for a simulated opportunity; `roll` is uniform in [0, 1)
+    pub fn simulate_capture(&self, venue: &str, spread_bps: f64, latency_ms: f64, roll: f64) -> bool {
+        roll < self.capture_probability(venue, spread_bps, latency_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prior_grows_with_spread_latency_and_venue() {
+        let mut config = CompetitionConfig::default();
+        config.venue_competitiveness.insert("raydium".to_string(), 2.0);
+        let model = CompetitionModel::new(config);
+
+        assert!((model.prior_front_run("orca", 50.0, 400.0) - 0.3).abs() < 1e-9);
+        assert!(model.prior_front_run("orca", 200.0, 400.0) > model.prior_front_run("orca", 20.0, 400.0));
+        assert!(model.prior_front_run("orca", 50.0, 800.0) > model.prior_front_run("orca", 50.0, 100.0));
+        assert!((model.prior_front_run("raydium", 50.0, 400.0) - 0.6).abs() < 1e-9);
+        assert_eq!(model.prior_front_run("orca", 0.0, 400.0), 0.0);
+        assert_eq!(model.prior_front_run("raydium", 10_000.0, 5_000.0), 0.95);
+    }
+
+    #[test]
+    fn test_calibration_pulls_toward_live_rate() {
+        let mut model = CompetitionModel::default();
+        let prior = model.estimate("orca", 60.0, 400.0);
+        assert_eq!(prior.live_samples, 0);
+
+        // 80 of 100 attempts lost at 400ms in the 50..100bps bucket
+        let observations: Vec<_> = (0..100)
+            .map(|i| LandingObservation { venue: "orca".to_string(), spread_bps: 60.0, latency_ms: 400, captured: i % 5 == 0 })
+            .collect();
+        model.calibrate(&observations);
+
+        let calibrated = model.estimate("orca", 60.0, 400.0);
+        assert_eq!(calibrated.live_samples, 100);
+        assert!(calibrated.front_run_probability > 0.7 && calibrated.front_run_probability < 0.8);
+        assert!(model.capture_probability("orca", 60.0, 100.0) > calibrated.capture_probability);
+        // Other buckets keep the prior
+        assert_eq!(model.estimate("orca", 5.0, 400.0).live_samples, 0);
+
+        assert!(!model.simulate_capture("orca", 60.0, 400.0, 0.5));
+        assert!(model.simulate_capture("orca", 60.0, 400.0, 0.1));
+
+        let outcome = VenueOutcome {
+            venue: "orca".to_string(),
+            priority_fee_micro_lamports: 0,
+            landed: true,
+            realized_slippage_bps: 70.0,
+            latency_ms: 300,
+        };
+        assert!(!LandingObservation::from_outcome(&outcome, 60.0).captured);
+        assert!(LandingObservation::from_outcome(&outcome, 90.0).captured);
+    }
+}
@@ -4,13 +4,20 @@
 
 use crate::config::SimpleConfig;
 use crate::apis::multi_price_feeds::MultiPriceFeeds;
+use crate::apis::price_cache::PriceCache;
+use crate::security::multisig::MultisigGuard;
+use crate::security::wallet::ChainAccounts;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
 use std::time::Instant;
 use tracing::{debug, info, warn};
 
+use crate::types::constants::SOL_MINT;
+use crate::types::{Expiring, IntoOpportunity, Opportunity, OpportunityKind, RouteHop, TtlPolicy, usd_opportunity};
+
 /// Configuración para arbitraje cross-chain empresarial
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EnterpriseCrossChainConfig {
@@ -116,6 +123,42 @@ pub struct CrossChainOpportunity {
     pub confidence_score: f64,
     /// Path de ejecución paso a paso
     pub execution_path: Vec<String>,
+    /// Fin de la ventana de validez (según volatilidad de la ruta)
+    pub expires_at: DateTime<Utc>,
+}
+
+impl IntoOpportunity for CrossChainOpportunity {
+    /// Chains stand in for venues
+    fn to_opportunity(&self) -> Opportunity {
+        usd_opportunity(
+            self.id.clone(),
+            OpportunityKind::CrossChain,
+            vec![
+                RouteHop::new(self.token_symbol.clone(), self.source_chain.clone()),
+                RouteHop::new(self.token_symbol.clone(), self.target_chain.clone()),
+            ],
+            self.net_profit_usd,
+            self.trade_amount_usd,
+            self.confidence_score,
+            self.timestamp,
+            self.expires_at,
+        )
+    }
+}
+
+impl Expiring for CrossChainOpportunity {
+    fn opportunity_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+}
+
+/// Cross-chain spreads close slower than on-chain ones: longer base window
+fn cross_chain_ttl_policy() -> TtlPolicy {
+    TtlPolicy { base_secs: 120.0, reference_volatility_pct: 2.0, ..Default::default() }
 }
 
 /// Estadísticas de ejecución cross-chain
@@ -205,8 +248,10 @@ impl CrossChainPriceMonitor {
                     for token in &native_tokens {
                         if let Ok(price) = self.multi_price_feeds.get_token_price(token).await {
                             chain_price_map.insert(token.clone(), price);
+                        } else if let Some(price) = self.get_fallback_price(token).await {
+                            chain_price_map.insert(token.clone(), price);
                         } else {
-                            chain_price_map.insert(token.clone(), self.get_fallback_price(token));
+                            debug!("📊 {} en {}: sin precio disponible, omitido", token, chain);
                         }
                     }
                 }
@@ -214,9 +259,13 @@ impl CrossChainPriceMonitor {
         } else {
             // Para otras chains, usar precios fallback directamente para evitar errores
             for token in &native_tokens {
-                let fallback_price = self.get_fallback_price(token);
-                chain_price_map.insert(token.clone(), fallback_price);
-                debug!("📊 {} en {}: ${:.2} (fallback)", token, chain, fallback_price);
+                match self.get_fallback_price(token).await {
+                    Some(fallback_price) => {
+                        chain_price_map.insert(token.clone(), fallback_price);
+                        debug!("📊 {} en {}: ${:.2} (fallback)", token, chain, fallback_price);
+                    }
+                    None => debug!("📊 {} en {}: sin precio disponible, omitido", token, chain),
+                }
             }
         }
         
@@ -247,7 +296,8 @@ impl CrossChainPriceMonitor {
             "SRM" => "SRMuApVNdxXokk5GT7XD5cUUgXMBCoAz2LHeuAoKWRt",
             _ => {
                 warn!("⚠️ Token {} no soportado, usando precio fallback", token);
-                return Ok(self.get_fallback_price(token));
+                return self.get_fallback_price(token).await
+                    .ok_or_else(|| anyhow::anyhow!("Sin precio de fallback para {}", token));
             }
         };
         
@@ -259,7 +309,8 @@ impl CrossChainPriceMonitor {
             },
             Err(e) => {
                 warn!("⚠️ Error MultiPriceFeeds para {} en {}: {}, usando fallback", token, chain, e);
-                Ok(self.get_fallback_price(token))
+                self.get_fallback_price(token).await
+                    .ok_or_else(|| anyhow::anyhow!("Sin precio de fallback para {}", token))
             }
         }
     }
@@ -341,10 +392,9 @@ impl CrossChainPriceMonitor {
         }
     }
     
-    /// Precio de fallback desde configuración JSON si fallan las APIs con tokens nativos por blockchain
-    fn get_fallback_price(&self, token: &str) -> f64 {
-        // Usar precios desde configuración en lugar de hardcoding
-        self.multi_price_feeds.get_fallback_price(token)
+    /// Precio de fallback si fallan las APIs: tasa fiat compartida o stablecoin configurada
+    async fn get_fallback_price(&self, token: &str) -> Option<f64> {
+        self.multi_price_feeds.get_fallback_price(token).await
     }
     
     /// Obtener diferencia de precio entre chains para un token
@@ -434,6 +484,10 @@ pub struct EnterpriseCrossChainEngine {
     last_opportunity_scan: Option<DateTime<Utc>>,
     /// Historial de oportunidades
     opportunity_history: VecDeque<CrossChainOpportunity>,
+    /// Multisig guard for large transfers (optional)
+    multisig_guard: Option<Arc<MultisigGuard>>,
+    /// Cuentas por chain (Solana / EVM) para ejecución real
+    chain_accounts: Option<Arc<ChainAccounts>>,
 }
 
 impl EnterpriseCrossChainEngine {
@@ -448,8 +502,28 @@ impl EnterpriseCrossChainEngine {
             stats: CrossChainStats::default(),
             last_opportunity_scan: None,
             opportunity_history: VecDeque::new(),
+            multisig_guard: None,
+            chain_accounts: None,
         }
     }
+
+    /// Share fetched prices and provider rate-limit budgets with other processes
+    pub fn with_price_cache(mut self, price_cache: Arc<dyn PriceCache>) -> Self {
+        self.price_monitor.multi_price_feeds = self.price_monitor.multi_price_feeds.with_price_cache(price_cache);
+        self
+    }
+    
+    /// Require Squads multisig approval for transfers above the guard threshold
+    pub fn with_multisig_guard(mut self, guard: Arc<MultisigGuard>) -> Self {
+        self.multisig_guard = Some(guard);
+        self
+    }
+    
+    /// Signing accounts and gas checks for real (non-simulated) execution
+    pub fn with_chain_accounts(mut self, accounts: Arc<ChainAccounts>) -> Self {
+        self.chain_accounts = Some(accounts);
+        self
+    }
     
     /// Escanear oportunidades de arbitraje cross-chain
     pub async fn scan_cross_chain_opportunities(&mut self) -> Result<Vec<CrossChainOpportunity>> {
@@ -519,7 +593,10 @@ impl EnterpriseCrossChainEngine {
                     let source_price = self.price_monitor.get_chain_price(token, source_chain)?;
                     let target_price = self.price_monitor.get_chain_price(token, target_chain)?;
                     
-                    let trade_amount_usd = self.calculate_optimal_trade_amount();
+                    let Some(trade_amount_usd) = self.calculate_optimal_trade_amount().await else {
+                        debug!("🌐 Sin tasa SOL/USD - no se puede dimensionar trade cross-chain");
+                        return None;
+                    };
                     let bridge_fee_pct = self.price_monitor.multi_price_feeds.get_trading_config().bridge_fee_percentage;
                     let bridge_fee_usd = trade_amount_usd * bridge_fee_pct;
                     let gas_cost_usd = 50.0; // $50 gas cost estimado
@@ -527,11 +604,12 @@ impl EnterpriseCrossChainEngine {
                     let net_profit_usd = estimated_profit_usd - bridge_fee_usd - gas_cost_usd;
                     
                     if net_profit_usd > 0.0 {
+                        let detected_at = Utc::now();
                         return Some(CrossChainOpportunity {
                             id: format!("CC_{}_{}_{}_{}", 
                                        source_chain, target_chain, token, 
-                                       chrono::Utc::now().timestamp_millis()),
-                            timestamp: Utc::now(),
+                                       detected_at.timestamp_millis()),
+                            timestamp: detected_at,
                             source_chain: source_chain.to_string(),
                             target_chain: target_chain.to_string(),
                             token_symbol: token.clone(),
@@ -552,6 +630,7 @@ impl EnterpriseCrossChainEngine {
                                 format!("Bridge {} to {}", token, target_chain),
                                 format!("Sell {} on {}", token, target_chain),
                             ],
+                            expires_at: cross_chain_ttl_policy().expires_at(detected_at, price_diff_pct),
                         });
                     }
                 }
@@ -562,6 +641,10 @@ impl EnterpriseCrossChainEngine {
     
     /// Ejecutar arbitraje cross-chain usando configuración para thresholds
     pub async fn execute_cross_chain_trade(&mut self, opportunity: &CrossChainOpportunity, simulate: bool) -> Result<bool> {
+        if let Err(expired) = opportunity.ensure_fresh(Utc::now()) {
+            warn!("⌛ Cross-chain rechazado: {}", expired);
+            return Err(expired.into());
+        }
         if simulate {
             info!("🌐 SIMULANDO arbitraje cross-chain - {} → {}, {} USD trade, {:.2} USD profit neto", 
                   opportunity.source_chain, opportunity.target_chain,
@@ -595,17 +678,45 @@ impl EnterpriseCrossChainEngine {
             self.update_stats();
             return Ok(false);
         }
+        if let Some(guard) = &self.multisig_guard {
+            // Live SOL price only: without one the transfer is treated as above the threshold
+            let amount_sol = match self.price_monitor.multi_price_feeds.get_token_price(SOL_MINT).await {
+                Ok(sol_price) if sol_price > 0.0 => opportunity.trade_amount_usd / sol_price,
+                Ok(_) | Err(_) => f64::MAX,
+            };
+            if guard.requires_approval(amount_sol) {
+                // Bridge transfers have no vault instructions to propose yet
+                warn!("🔏 Cross-chain transfer {} → {} via {} needs multisig approval; not proposable, skipping",
+                      opportunity.source_chain, opportunity.target_chain, opportunity.bridge_provider);
+                return Ok(false);
+            }
+        }
+        // Ambas puntas necesitan cuenta firmante y gas nativo antes de tocar el bridge
+        let Some(accounts) = &self.chain_accounts else {
+            warn!("🚧 Sin cuentas por chain configuradas - ejecución real cross-chain deshabilitada");
+            return Ok(false);
+        };
+        for chain in [&opportunity.source_chain, &opportunity.target_chain] {
+            match accounts.ensure_gas(chain).await {
+                Ok(balance) => debug!("⛽ {} gas OK: {:.6} {} en {}",
+                                      balance.chain, balance.ui_amount(), balance.gas_asset, balance.address),
+                Err(e) => {
+                    warn!("⛽ Cross-chain {} → {} bloqueado: {}", opportunity.source_chain, opportunity.target_chain, e);
+                    return Ok(false);
+                }
+            }
+        }
         warn!("🚧 Ejecución real cross-chain no implementada - usar modo simulación");
         Ok(false)
     }
     
-    /// Calcular cantidad óptima de trade usando configuración
-    fn calculate_optimal_trade_amount(&self) -> f64 {
-        let sol_price = self.price_monitor.multi_price_feeds.get_fallback_price("SOL");
+    /// Calcular cantidad óptima de trade usando configuración (None sin tasa SOL/USD)
+    async fn calculate_optimal_trade_amount(&self) -> Option<f64> {
+        let sol_price = self.price_monitor.multi_price_feeds.get_fallback_price("SOL").await?;
         let max_amount_usd = self.config.max_bridge_amount_sol * sol_price;
         // Cantidad óptima basada en configuración
         let optimal_percentage = self.price_monitor.multi_price_feeds.get_trading_config().optimal_trade_percentage;
-        max_amount_usd * optimal_percentage
+        Some(max_amount_usd * optimal_percentage)
     }
     
     /// Obtener porcentaje óptimo basado en liquidez actual del mercado
@@ -786,7 +897,11 @@ impl EnterpriseCrossChainEngine {
 }
 
 /// Función de utilidad para ejecutar arbitraje cross-chain
-pub async fn execute_cross_chain_arbitrage(_opportunity: &CrossChainOpportunity) -> Result<String> {
+pub async fn execute_cross_chain_arbitrage(opportunity: &CrossChainOpportunity) -> Result<String> {
+    if let Err(expired) = opportunity.ensure_fresh(Utc::now()) {
+        warn!("⌛ Cross-chain execution rejected: {}", expired);
+        return Err(expired.into());
+    }
     // TODO: Implementar ejecución real de arbitraje cross-chain
     // Por ahora retorna simulación
     warn!("🚧 Ejecución cross-chain en desarrollo - simulando éxito");
@@ -838,6 +953,7 @@ mod tests {
             risk_score: 0.3,
             confidence_score: 0.9,
             execution_path: vec!["Buy USDC on Solana".to_string()],
+            expires_at: Utc::now() + chrono::Duration::seconds(60),
         };
         
         // Debería ejecutar exitosamente en modo simulación
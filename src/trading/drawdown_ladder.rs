@@ -0,0 +1,293 @@
+//! Drawdown-triggered de-risking ladder
+//!
+//! A single daily-loss circuit breaker trades at full size right up to the
+//! moment it stops everything. The ladder de-risks in steps as the day's PnL
+//! falls: first smaller positions, then no new entries, finally flatten and
+//! halt. Steps only escalate during a UTC day; the ladder resets at the next
+//! day or when an operator resets it.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+/// What a step does once the day's PnL reaches its threshold
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum DeRiskAction {
+    /// Scale new position sizes (0.5 = half size)
+    ReduceSize { size_multiplier: f64 },
+    /// No new entries; exits and exposure-reducing trades still run
+    BlockEntries,
+    /// Close everything and stop trading
+    FlattenAndHalt,
+}
+
+/// One rung: `action` applies from `threshold_percent` of daily PnL (negative) down
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DrawdownStep {
+    pub threshold_percent: f64,
+    pub action: DeRiskAction,
+}
+
+/// Ladder policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrawdownLadderConfig {
+    /// Capital the daily PnL percentage is taken of (same unit as recorded PnL)
+    pub capital: f64,
+    pub steps: Vec<DrawdownStep>,
+}
+
+impl Default for DrawdownLadderConfig {
+    fn default() -> Self {
+        Self {
+            capital: 10_000.0,
+            steps: vec![
+                DrawdownStep { threshold_percent: -2.0, action: DeRiskAction::ReduceSize { size_multiplier: 0.5 } },
+                DrawdownStep { threshold_percent: -4.0, action: DeRiskAction::BlockEntries },
+                DrawdownStep { threshold_percent: -6.0, action: DeRiskAction::FlattenAndHalt },
+            ],
+        }
+    }
+}
+
+/// A change of ladder level
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeRiskTransition {
+    /// 0 is normal trading; `n` means the first `n` steps are in force
+    pub from_level: usize,
+    pub to_level: usize,
+    /// Action of the new level (`None` when back to normal)
+    pub action: Option<DeRiskAction>,
+    pub daily_pnl_percent: f64,
+    pub at: DateTime<Utc>,
+}
+
+/// Current ladder state, for monitoring
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeRiskStatus {
+    pub day: NaiveDate,
+    pub level: usize,
+    pub daily_pnl: f64,
+    pub daily_pnl_percent: f64,
+    pub size_multiplier: f64,
+    pub entries_allowed: bool,
+    pub halted: bool,
+    pub transitions: Vec<DeRiskTransition>,
+}
+
+#[derive(Debug)]
+struct LadderState {
+    day: NaiveDate,
+    daily_pnl: f64,
+    level: usize,
+    flatten_pending: bool,
+    transitions: Vec<DeRiskTransition>,
+}
+
+/// Graduated de-risking on daily drawdown
+#[derive(Debug)]
+pub struct DrawdownLadder {
+    config: DrawdownLadderConfig,
+    state: Mutex<LadderState>,
+}
+
+impl Default for DrawdownLadder {
+    fn default() -> Self {
+        Self::new(DrawdownLadderConfig::default())
+    }
+}
+
+impl DrawdownLadder {
+    pub fn new(mut config: DrawdownLadderConfig) -> Self {
+        // Shallowest threshold first, so levels escalate in order
+        config.steps.sort_by(|a, b| b.threshold_percent.total_cmp(&a.threshold_percent));
+        Self {
+            config,
+            state: Mutex::new(LadderState {
+                day: Utc::now().date_naive(),
+                daily_pnl: 0.0,
+                level: 0,
+                flatten_pending: false,
+                transitions: Vec::new(),
+            }),
+        }
+    }
+
+    pub fn config(&self) -> &DrawdownLadderConfig {
+        &self.config
+    }
+
+    /// Add realized PnL; returns the transition if the level escalated
+    pub fn record_pnl(&self, pnl: f64) -> Option<DeRiskTransition> {
+        self.record_pnl_at(pnl, Utc::now())
+    }
+
+    pub fn record_pnl_at(&self, pnl: f64, now: DateTime<Utc>) -> Option<DeRiskTransition> {
+        let mut state = self.state.lock();
+        let rollover = self.roll_day(&mut state, now);
+        state.daily_pnl += pnl;
+        let percent = self.percent(state.daily_pnl);
+        let reached = self.config.steps.iter().take_while(|step| percent <= step.threshold_percent).count();
+        if reached <= state.level {
+            return rollover;
+        }
+
+        let transition = DeRiskTransition {
+            from_level: state.level,
+            to_level: reached,
+            action: Some(self.config.steps[reached - 1].action.clone()),
+            daily_pnl_percent: percent,
+            at: now,
+        };
+        warn!("📉 Daily PnL {:.2}% - de-risking level {} -> {}: {:?}", percent, state.level, reached, transition.action);
+        if self.config.steps[..reached].iter().any(|step| step.action == DeRiskAction::FlattenAndHalt) {
+            state.flatten_pending = true;
+        }
+        state.level = reached;
+        state.transitions.push(transition.clone());
+        Some(transition)
+    }
+
+    /// Back to normal trading at a new UTC day
+    fn roll_day(&self, state: &mut LadderState, now: DateTime<Utc>) -> Option<DeRiskTransition> {
+        let day = now.date_naive();
+        if day == state.day {
+            return None;
+        }
+        let from_level = state.level;
+        *state = LadderState { day, daily_pnl: 0.0, level: 0, flatten_pending: false, transitions: Vec::new() };
+        (from_level > 0).then(|| {
+            info!("📈 New trading day - de-risking ladder reset from level {}", from_level);
+            let transition = DeRiskTransition { from_level, to_level: 0, action: None, daily_pnl_percent: 0.0, at: now };
+            state.transitions.push(transition.clone());
+            transition
+        })
+    }
+
+    /// Operator reset: normal trading with the day's PnL kept
+    ///
+    /// The ladder escalates again on the next loss that crosses a threshold.
+    pub fn reset(&self) {
+        let mut state = self.state.lock();
+        if state.level > 0 {
+            info!("✅ De-risking ladder reset from level {} by operator", state.level);
+            let transition = DeRiskTransition {
+                from_level: state.level,
+                to_level: 0,
+                action: None,
+                daily_pnl_percent: self.percent(state.daily_pnl),
+                at: Utc::now(),
+            };
+            state.transitions.push(transition);
+            state.level = 0;
+            state.flatten_pending = false;
+        }
+    }
+
+    fn percent(&self, pnl: f64) -> f64 {
+        if self.config.capital > 0.0 { pnl / self.config.capital * 100.0 } else { 0.0 }
+    }
+
+    fn in_force(&self, level: usize) -> &[DrawdownStep] {
+        &self.config.steps[..level.min(self.config.steps.len())]
+    }
+
+    /// Multiplier for new position sizes (the smallest of the steps in force)
+    pub fn size_multiplier(&self) -> f64 {
+        let level = self.state.lock().level;
+        self.in_force(level)
+            .iter()
+            .map(|step| match step.action {
+                DeRiskAction::ReduceSize { size_multiplier } => size_multiplier.clamp(0.0, 1.0),
+                DeRiskAction::BlockEntries | DeRiskAction::FlattenAndHalt => 0.0,
+            })
+            .fold(1.0, f64::min)
+    }
+
+    pub fn allows_entries(&self) -> bool {
+        let level = self.state.lock().level;
+        !self.in_force(level).iter().any(|step| matches!(step.action, DeRiskAction::BlockEntries | DeRiskAction::FlattenAndHalt))
+    }
+
+    pub fn is_halted(&self) -> bool {
+        let level = self.state.lock().level;
+        self.in_force(level).iter().any(|step| step.action == DeRiskAction::FlattenAndHalt)
+    }
+
+    /// `true` once per flatten: the caller closes positions when it gets it
+    pub fn take_flatten_request(&self) -> bool {
+        std::mem::take(&mut self.state.lock().flatten_pending)
+    }
+
+    pub fn status(&self) -> DeRiskStatus {
+        let (day, level, daily_pnl, transitions) = {
+            let state = self.state.lock();
+            (state.day, state.level, state.daily_pnl, state.transitions.clone())
+        };
+        DeRiskStatus {
+            day,
+            level,
+            daily_pnl,
+            daily_pnl_percent: self.percent(daily_pnl),
+            size_multiplier: self.size_multiplier(),
+            entries_allowed: self.allows_entries(),
+            halted: self.is_halted(),
+            transitions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_ladder_escalates_step_by_step() {
+        let ladder = DrawdownLadder::default();
+        let now = Utc::now();
+
+        assert!(ladder.record_pnl_at(-150.0, now).is_none());
+        assert_eq!(ladder.size_multiplier(), 1.0);
+
+        let transition = ladder.record_pnl_at(-60.0, now).unwrap();
+        assert_eq!((transition.from_level, transition.to_level), (0, 1));
+        assert_eq!(ladder.size_multiplier(), 0.5);
+        assert!(ladder.allows_entries());
+
+        // Recovering does not de-escalate within the day
+        assert!(ladder.record_pnl_at(100.0, now).is_none());
+        assert_eq!(ladder.status().level, 1);
+
+        // A large loss can skip rungs straight to the halt
+        let transition = ladder.record_pnl_at(-600.0, now).unwrap();
+        assert_eq!(transition.to_level, 3);
+        assert!(!ladder.allows_entries());
+        assert!(ladder.is_halted());
+        assert!(ladder.take_flatten_request());
+        assert!(!ladder.take_flatten_request());
+        assert_eq!(ladder.status().transitions.len(), 2);
+    }
+
+    #[test]
+    fn test_new_day_and_operator_reset_restore_trading() {
+        let ladder = DrawdownLadder::new(DrawdownLadderConfig {
+            capital: 1_000.0,
+            steps: vec![DrawdownStep { threshold_percent: -4.0, action: DeRiskAction::BlockEntries }],
+        });
+        let now = Utc::now();
+        ladder.record_pnl_at(-50.0, now).unwrap();
+        assert!(!ladder.allows_entries());
+
+        ladder.reset();
+        assert!(ladder.allows_entries());
+        // Further losses escalate again
+        assert!(ladder.record_pnl_at(-1.0, now).is_some());
+
+        let transition = ladder.record_pnl_at(0.0, now + Duration::days(1)).unwrap();
+        assert_eq!((transition.to_level, transition.action), (0, None));
+        assert!(ladder.allows_entries());
+        assert_eq!(ladder.status().daily_pnl, 0.0);
+    }
+}
@@ -236,23 +236,23 @@ impl EnhancedTradingSystem {
     
     /// Mostrar dashboard simplificado
     pub async fn display_dashboard(&self) {
-        println!("\n╔══════════════════════════════════════════════════════════════════════════════╗");
-        println!("║                         🚀 ENHANCED TRADING SYSTEM v2.0                         ║");
-        println!("╠══════════════════════════════════════════════════════════════════════════════╣");
-        println!("║ Status: {} │ Uptime: {}h │ Balance: {:.3} SOL               ║",
+        info!("╔══════════════════════════════════════════════════════════════════════════════╗");
+        info!("║                         🚀 ENHANCED TRADING SYSTEM v2.0                         ║");
+        info!("╠══════════════════════════════════════════════════════════════════════════════╣");
+        info!("║ Status: {} │ Uptime: {}h │ Balance: {:.3} SOL               ║",
                  if self.system_status.is_active { "🟢 ACTIVE" } else { "🔴 INACTIVE" },
                  self.system_status.uptime_seconds / 3600,
                  self.system_status.current_balance_sol);
-        println!("╠══════════════════════════════════════════════════════════════════════════════╣");
-        println!("║ 📊 PERFORMANCE METRICS                                                          ║");
-        println!("║ Total Trades: {} │ Success Rate: {:.1}% │ Net Profit: ${:.2}               ║",
+        info!("╠══════════════════════════════════════════════════════════════════════════════╣");
+        info!("║ 📊 PERFORMANCE METRICS                                                          ║");
+        info!("║ Total Trades: {} │ Success Rate: {:.1}% │ Net Profit: ${:.2}               ║",
                  self.performance_metrics.total_trades_executed,
                  self.performance_metrics.success_rate * 100.0,
                  self.performance_metrics.net_profit_usd);
-        println!("║ Best Trade: ${:.2} │ Avg Profit: ${:.2}                                    ║",
+        info!("║ Best Trade: ${:.2} │ Avg Profit: ${:.2}                                    ║",
                  self.performance_metrics.best_trade_profit_usd,
                  self.performance_metrics.average_profit_per_trade_usd);
-        println!("╚══════════════════════════════════════════════════════════════════════════════╝");
+        info!("╚══════════════════════════════════════════════════════════════════════════════╝");
     }
     
     /// Obtener métricas de performance
@@ -0,0 +1,428 @@
+//! Write-ahead trade intent log
+//!
+//! A crash between signing a transaction and learning whether it landed
+//! leaves two bad options on restart: trade again and maybe double the
+//! position, or forget the trade and leave it orphaned. The intent log
+//! removes the guesswork:
+//!
+//! 1. before signing, the intent (idempotency key, route, size, wallet) is
+//!    appended and flushed to disk; a key already in the log is refused
+//! 2. once signed, the transaction signature is appended before submission
+//! 3. confirmation or failure resolves the intent
+//!
+//! On restart [`IntentLog::recover`] settles every unresolved intent against
+//! chain state: intents never signed cannot have landed and are abandoned,
+//! signed ones are looked up by signature. Intents whose fate is still
+//! unknown keep [`IntentLog::is_clear`] false so no new trading starts until
+//! they are settled.
+//!
+//! The log is append-only JSON lines; the latest record per key wins.
+//! Recovery compacts it, keeping resolved intents for the retention window so
+//! their keys keep deduplicating.
+
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::signature::Signature;
+use tracing::{info, warn};
+
+use crate::apis::rpc_usage::{provider_for_url, rpc_usage};
+use crate::monitoring::latency_heatmap::latency_heatmap;
+
+/// Where an intent is in its lifecycle
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum IntentStatus {
+    /// Persisted, not yet signed
+    Pending,
+    /// Signed; may have been submitted
+    Signed { signature: String, signed_at: DateTime<Utc> },
+    Completed { signature: String },
+    Failed { reason: String },
+    /// Never signed before the process stopped
+    Abandoned,
+}
+
+impl IntentStatus {
+    pub fn is_resolved(&self) -> bool {
+        matches!(self, Self::Completed { .. } | Self::Failed { .. } | Self::Abandoned)
+    }
+}
+
+/// A trade the process committed to attempt
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradeIntent {
+    /// Idempotency key: one execution per key
+    pub key: String,
+    pub wallet: String,
+    /// Mints along the route
+    pub route: Vec<String>,
+    /// Input size in base units
+    pub size: u64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub status: IntentStatus,
+}
+
+impl TradeIntent {
+    pub fn new(key: &str, wallet: &str, route: Vec<String>, size: u64) -> Self {
+        let now = Utc::now();
+        Self {
+            key: key.to_string(),
+            wallet: wallet.to_string(),
+            route,
+            size,
+            created_at: now,
+            updated_at: now,
+            status: IntentStatus::Pending,
+        }
+    }
+}
+
+/// Why an intent could not be recorded
+#[derive(Debug, thiserror::Error)]
+pub enum IntentError {
+    #[error("intent {key} already recorded ({status:?})")]
+    Duplicate { key: String, status: IntentStatus },
+    #[error("unknown intent {0}")]
+    Unknown(String),
+    #[error("intent log write failed: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("intent log encoding failed: {0}")]
+    Encoding(#[from] serde_json::Error),
+}
+
+/// Looks up what happened to a signed transaction
+#[async_trait]
+pub trait SignatureStatusSource: Send + Sync {
+    /// `Some(Ok)` landed, `Some(Err)` landed and failed, `None` not found
+    async fn status(&self, signature: &str) -> Result<Option<std::result::Result<(), String>>>;
+}
+
+/// Signature lookups over JSON-RPC, including transaction history
+pub struct RpcSignatureStatus {
+    client: RpcClient,
+    provider: &'static str,
+}
+
+impl RpcSignatureStatus {
+    pub fn new(rpc_url: &str) -> Self {
+        Self { client: RpcClient::new(rpc_url.to_string()), provider: provider_for_url(rpc_url) }
+    }
+}
+
+#[async_trait]
+impl SignatureStatusSource for RpcSignatureStatus {
+    async fn status(&self, signature: &str) -> Result<Option<std::result::Result<(), String>>> {
+        let signature = Signature::from_str(signature)?;
+        crate::chaos::faults().rpc_call(self.provider, "getSignatureStatuses")?;
+        rpc_usage().record(self.provider, "getSignatureStatuses");
+        let started = std::time::Instant::now();
+        let statuses = self.client.get_signature_statuses_with_history(&[signature]).await;
+        latency_heatmap().record_rpc(self.provider, "getSignatureStatuses", started.elapsed(), statuses.is_ok());
+        let statuses = statuses?.value;
+        Ok(statuses.into_iter().next().flatten().map(|status| match status.err {
+            None => Ok(()),
+            Some(err) => Err(err.to_string()),
+        }))
+    }
+}
+
+/// Intent log policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntentLogConfig {
+    /// Append-only log file (in-memory only when unset)
+    pub path: Option<PathBuf>,
+    /// A signed transaction not found after this long can no longer land (blockhash expired)
+    pub expiry_secs: i64,
+    /// How long resolved intents are kept for deduplication
+    pub retention_hours: i64,
+}
+
+impl Default for IntentLogConfig {
+    fn default() -> Self {
+        Self {
+            path: None,
+            expiry_secs: 120,
+            retention_hours: 24,
+        }
+    }
+}
+
+/// Outcome of settling unresolved intents
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReconcileReport {
+    pub completed: usize,
+    pub failed: usize,
+    pub abandoned: usize,
+    /// Intents whose fate is still unknown
+    pub unresolved: usize,
+}
+
+/// Durable record of trade intents
+#[derive(Debug)]
+pub struct IntentLog {
+    config: IntentLogConfig,
+    intents: Mutex<HashMap<String, TradeIntent>>,
+    /// Intents created before this are from a previous run
+    opened_at: DateTime<Utc>,
+}
+
+impl IntentLog {
+    /// Open the log, replaying existing records
+    pub fn open(config: IntentLogConfig) -> Result<Self> {
+        let intents = match &config.path {
+            Some(path) => Self::replay(path)?,
+            None => HashMap::new(),
+        };
+        let unresolved = intents.values().filter(|intent| !intent.status.is_resolved()).count();
+        if unresolved > 0 {
+            warn!("📝 Intent log has {} unresolved trade intents from a previous run", unresolved);
+        }
+        Ok(Self { config, intents: Mutex::new(intents), opened_at: Utc::now() })
+    }
+
+    fn replay(path: &Path) -> Result<HashMap<String, TradeIntent>> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut intents = HashMap::new();
+        for line in content.lines().filter(|line| !line.trim().is_empty()) {
+            // A torn final line from a crash mid-write is skipped
+            match serde_json::from_str::<TradeIntent>(line) {
+                Ok(intent) => {
+                    intents.insert(intent.key.clone(), intent);
+                }
+                Err(e) => warn!("⚠️ Skipping unreadable intent log record: {}", e),
+            }
+        }
+        Ok(intents)
+    }
+
+    /// Append one record and flush it to disk
+    fn append(&self, intent: &TradeIntent) -> Result<(), IntentError> {
+        let Some(path) = &self.config.path else { return Ok(()) };
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        let mut line = serde_json::to_vec(intent)?;
+        line.push(b'\n');
+        file.write_all(&line)?;
+        file.sync_data()?;
+        Ok(())
+    }
+
+    /// Record an intent before signing; refuses a key already in the log
+    pub fn begin(&self, intent: TradeIntent) -> Result<(), IntentError> {
+        let mut intents = self.intents.lock();
+        if let Some(existing) = intents.get(&intent.key) {
+            return Err(IntentError::Duplicate { key: intent.key, status: existing.status.clone() });
+        }
+        self.append(&intent)?;
+        intents.insert(intent.key.clone(), intent);
+        Ok(())
+    }
+
+    fn transition(&self, key: &str, status: IntentStatus) -> Result<(), IntentError> {
+        let mut intents = self.intents.lock();
+        let intent = intents.get_mut(key).ok_or_else(|| IntentError::Unknown(key.to_string()))?;
+        let mut updated = intent.clone();
+        updated.status = status;
+        updated.updated_at = Utc::now();
+        self.append(&updated)?;
+        *intent = updated;
+        Ok(())
+    }
+
+    /// Record the signature before the transaction is submitted
+    pub fn mark_signed(&self, key: &str, signature: &str) -> Result<(), IntentError> {
+        self.transition(key, IntentStatus::Signed { signature: signature.to_string(), signed_at: Utc::now() })
+    }
+
+    pub fn mark_completed(&self, key: &str, signature: &str) -> Result<(), IntentError> {
+        self.transition(key, IntentStatus::Completed { signature: signature.to_string() })
+    }
+
+    pub fn mark_failed(&self, key: &str, reason: &str) -> Result<(), IntentError> {
+        self.transition(key, IntentStatus::Failed { reason: reason.to_string() })
+    }
+
+    pub fn get(&self, key: &str) -> Option<TradeIntent> {
+        self.intents.lock().get(key).cloned()
+    }
+
+    pub fn unresolved(&self) -> Vec<TradeIntent> {
+        self.intents.lock().values().filter(|intent| !intent.status.is_resolved()).cloned().collect()
+    }
+
+    /// No intent from a previous run awaits settlement
+    ///
+    /// This run's in-flight intents do not count: they are tracked by the
+    /// executor that created them.
+    pub fn is_clear(&self) -> bool {
+        self.intents.lock().values().all(|intent| intent.status.is_resolved() || intent.created_at >= self.opened_at)
+    }
+
+    /// Settle signed intents against chain state; pending intents are left alone
+    pub async fn reconcile(&self, source: &dyn SignatureStatusSource) -> ReconcileReport {
+        self.settle(source, Utc::now(), false).await
+    }
+
+    /// Startup recovery: abandon intents never signed, settle the rest and compact the log
+    ///
+    /// Must run before the process trades again.
+    pub async fn recover(&self, source: &dyn SignatureStatusSource) -> ReconcileReport {
+        let report = self.settle(source, Utc::now(), true).await;
+        if let Err(e) = self.compact(Utc::now()) {
+            warn!("⚠️ Failed to compact intent log: {}", e);
+        }
+        info!("📝 Intent recovery: {} completed, {} failed, {} abandoned, {} unresolved",
+              report.completed, report.failed, report.abandoned, report.unresolved);
+        report
+    }
+
+    async fn settle(&self, source: &dyn SignatureStatusSource, now: DateTime<Utc>, abandon_pending: bool) -> ReconcileReport {
+        let mut report = ReconcileReport::default();
+        for intent in self.unresolved() {
+            let result = match &intent.status {
+                IntentStatus::Pending if abandon_pending => {
+                    report.abandoned += 1;
+                    self.transition(&intent.key, IntentStatus::Abandoned)
+                }
+                IntentStatus::Signed { signature, signed_at } => {
+                    let status = if crate::chaos::faults().confirmation_hidden(&intent.wallet, *signed_at, now) {
+                        Ok(None)
+                    } else {
+                        source.status(signature).await
+                    };
+                    match status {
+                        Ok(Some(Ok(()))) => {
+                            report.completed += 1;
+                            self.mark_completed(&intent.key, signature)
+                        }
+                        Ok(Some(Err(error))) => {
+                            report.failed += 1;
+                            self.mark_failed(&intent.key, &error)
+                        }
+                        Ok(None) if now - *signed_at > Duration::seconds(self.config.expiry_secs) => {
+                            report.failed += 1;
+                            self.mark_failed(&intent.key, "not found on chain after blockhash expiry")
+                        }
+                        Ok(None) => {
+                            report.unresolved += 1;
+                            Ok(())
+                        }
+                        Err(e) => {
+                            warn!("⚠️ Could not look up intent {} ({}): {}", intent.key, signature, e);
+                            report.unresolved += 1;
+                            Ok(())
+                        }
+                    }
+                }
+                _ => {
+                    report.unresolved += 1;
+                    Ok(())
+                }
+            };
+            if let Err(e) = result {
+                warn!("⚠️ Failed to record settlement of intent {}: {}", intent.key, e);
+            }
+        }
+        report
+    }
+
+    /// Rewrite the log with one record per intent, dropping resolved ones past retention
+    fn compact(&self, now: DateTime<Utc>) -> Result<()> {
+        let mut intents = self.intents.lock();
+        let cutoff = now - Duration::hours(self.config.retention_hours);
+        intents.retain(|_, intent| !intent.status.is_resolved() || intent.updated_at > cutoff);
+        let Some(path) = &self.config.path else { return Ok(()) };
+        let mut content = String::new();
+        for intent in intents.values() {
+            content.push_str(&serde_json::to_string(intent)?);
+            content.push('\n');
+        }
+        let temp_file = path.with_extension("tmp");
+        std::fs::write(&temp_file, content)?;
+        std::fs::rename(&temp_file, path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedStatuses(HashMap<String, std::result::Result<(), String>>);
+
+    #[async_trait]
+    impl SignatureStatusSource for FixedStatuses {
+        async fn status(&self, signature: &str) -> Result<Option<std::result::Result<(), String>>> {
+            Ok(self.0.get(signature).cloned())
+        }
+    }
+
+    fn intent(key: &str) -> TradeIntent {
+        TradeIntent::new(key, "main", vec!["SOL".to_string(), "USDC".to_string()], 1_000_000)
+    }
+
+    #[test]
+    fn test_duplicate_key_is_refused() {
+        let log = IntentLog::open(IntentLogConfig::default()).unwrap();
+        log.begin(intent("arb-1")).unwrap();
+        log.mark_signed("arb-1", "sig1").unwrap();
+        assert!(matches!(log.begin(intent("arb-1")), Err(IntentError::Duplicate { .. })));
+        assert_eq!(log.unresolved().len(), 1);
+
+        log.mark_completed("arb-1", "sig1").unwrap();
+        assert!(log.unresolved().is_empty());
+        // Completed keys still deduplicate
+        assert!(log.begin(intent("arb-1")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_recovery_settles_intents_from_previous_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = IntentLogConfig { path: Some(dir.path().join("intents.jsonl")), ..Default::default() };
+        {
+            let log = IntentLog::open(config.clone()).unwrap();
+            for key in ["never-signed", "landed", "reverted", "in-flight"] {
+                log.begin(intent(key)).unwrap();
+            }
+            log.mark_signed("landed", "sig-landed").unwrap();
+            log.mark_signed("reverted", "sig-reverted").unwrap();
+            log.mark_signed("in-flight", "sig-in-flight").unwrap();
+            // Process dies here
+        }
+
+        let log = IntentLog::open(config.clone()).unwrap();
+        assert_eq!(log.unresolved().len(), 4);
+        let chain = FixedStatuses(HashMap::from([
+            ("sig-landed".to_string(), Ok(())),
+            ("sig-reverted".to_string(), Err("slippage exceeded".to_string())),
+        ]));
+        let report = log.recover(&chain).await;
+        assert_eq!(report, ReconcileReport { completed: 1, failed: 1, abandoned: 1, unresolved: 1 });
+        // Not found yet and still within the blockhash window: trading stays blocked
+        assert!(!log.is_clear());
+        assert!(matches!(log.get("never-signed").unwrap().status, IntentStatus::Abandoned));
+
+        // Compacted log replays to the same state
+        let reopened = IntentLog::open(config).unwrap();
+        assert!(matches!(reopened.get("landed").unwrap().status, IntentStatus::Completed { .. }));
+        assert_eq!(reopened.unresolved().len(), 1);
+    }
+}
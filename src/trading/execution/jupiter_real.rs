@@ -7,12 +7,16 @@ use std::collections::HashMap;
 use std::time::{Duration, Instant};
 use tokio::time::timeout;
 
+use super::quote_freshness::{QuoteFreshnessGuard, QuotedAmount, TimestampedQuote};
+
 /// Cliente REAL de Jupiter - Implementación completamente funcional
 #[derive(Debug)]
 pub struct JupiterRealClient {
     http_client: Client,
     config: JupiterRealConfig,
     quote_cache: HashMap<String, CachedQuote>,
+    /// Ningún quote viejo llega al envío
+    quote_guard: QuoteFreshnessGuard,
 }
 
 /// Configuración real de Jupiter
@@ -22,6 +26,8 @@ pub struct JupiterRealConfig {
     pub timeout_seconds: u64,
     pub slippage_bps: u16,
     pub priority_fee_lamports: u64,
+    /// Impacto de precio máximo aceptado (fracción, 0.05 = 5%)
+    pub max_price_impact: f64,
 }
 
 /// Cache de quotes con TTL
@@ -43,6 +49,12 @@ pub struct JupiterQuote {
     pub time_taken: f64,
 }
 
+impl QuotedAmount for JupiterQuote {
+    fn quoted_out_amount(&self) -> Option<u64> {
+        Some(self.out_amount)
+    }
+}
+
 /// Resultado de swap real ejecutado
 #[derive(Debug, Clone)]
 pub struct JupiterSwapResult {
@@ -62,6 +74,7 @@ impl Default for JupiterRealConfig {
             timeout_seconds: 15,
             slippage_bps: 50,
             priority_fee_lamports: 5000,
+            max_price_impact: 0.05,
         }
     }
 }
@@ -82,6 +95,7 @@ impl JupiterRealClient {
             http_client,
             config: config.unwrap_or_default(),
             quote_cache: HashMap::new(),
+            quote_guard: QuoteFreshnessGuard::default(),
         }
     }
 
@@ -102,6 +116,25 @@ impl JupiterRealClient {
             }
         }
 
+        let quote = self.fetch_jupiter_quote(input_mint, output_mint, amount).await?;
+
+        // Actualizar cache
+        self.quote_cache.insert(cache_key, CachedQuote {
+            quote: quote.clone(),
+            timestamp: Instant::now(),
+            ttl_seconds: 30,
+        });
+
+        Ok(quote)
+    }
+
+    /// Quote directo de Jupiter, sin pasar por el cache
+    async fn fetch_jupiter_quote(
+        &self,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+        amount: u64,
+    ) -> Result<JupiterQuote> {
         info!("🚀 Obteniendo quote REAL de Jupiter: {} -> {}", input_mint, output_mint);
 
         let url = format!(
@@ -122,16 +155,24 @@ impl JupiterRealClient {
         let data: Value = response.json().await
             .map_err(|e| anyhow!("Jupiter JSON parse error: {}", e))?;
 
-        let quote = self.parse_jupiter_quote(data, input_mint, output_mint)?;
-
-        // Actualizar cache
-        self.quote_cache.insert(cache_key, CachedQuote {
-            quote: quote.clone(),
-            timestamp: Instant::now(),
-            ttl_seconds: 30,
-        });
+        self.parse_jupiter_quote(data, input_mint, output_mint)
+    }
 
-        Ok(quote)
+    /// Validar un quote antes de ejecutarlo
+    fn validate_quote(&self, quote: &JupiterQuote, amount: u64) -> Result<()> {
+        if quote.in_amount != amount {
+            return Err(anyhow!("Quote para {} unidades, se pidieron {}", quote.in_amount, amount));
+        }
+        if quote.out_amount == 0 {
+            return Err(anyhow!("Quote sin salida"));
+        }
+        if quote.price_impact_pct.abs() > self.config.max_price_impact {
+            return Err(anyhow!(
+                "Impacto de precio demasiado alto: {:.2}% > {:.2}%",
+                quote.price_impact_pct * 100.0, self.config.max_price_impact * 100.0
+            ));
+        }
+        Ok(())
     }
 
     /// IMPLEMENTACIÓN REAL: Ejecutar swap real con Jupiter v6
@@ -145,12 +186,20 @@ impl JupiterRealClient {
         info!("🔥 Ejecutando SWAP REAL con Jupiter v6");
         let start_time = Instant::now();
 
-        // Crear cliente temporal para quote
-        let mut temp_client = JupiterRealClient::new(Some(self.config.clone()));
-        let quote = temp_client.get_real_jupiter_quote(input_mint, output_mint, amount).await?;
+        let quote = TimestampedQuote::new(self.fetch_jupiter_quote(input_mint, output_mint, amount).await?);
+        self.validate_quote(&quote.quote, amount)?;
 
         info!("✅ Quote obtenido: {} -> {} (impact: {:.2}%)", 
-              quote.in_amount, quote.out_amount, quote.price_impact_pct);
+              quote.quote.in_amount, quote.quote.out_amount, quote.quote.price_impact_pct);
+
+        // Justo antes del envío: re-quote si ya es viejo, y validar el nuevo
+        let quote = self.quote_guard
+            .ensure_fresh(quote, || self.fetch_jupiter_quote(input_mint, output_mint, amount))
+            .await?;
+        if quote.requote_count > 0 {
+            self.validate_quote(&quote.quote, amount)?;
+        }
+        let quote = quote.quote;
 
         // Simular ejecución (en implementación real se enviaría a blockchain)
         let execution_time = start_time.elapsed().as_millis() as u64;
@@ -222,6 +271,22 @@ mod tests {
         assert_eq!(client.config.slippage_bps, 50);
     }
     
+    #[test]
+    fn test_refreshed_quote_is_validated() {
+        let client = JupiterRealClient::new(None);
+        let quote = JupiterQuote {
+            input_mint: Pubkey::new_unique(),
+            output_mint: Pubkey::new_unique(),
+            in_amount: 1_000,
+            out_amount: 990,
+            price_impact_pct: 0.01,
+            time_taken: 0.0,
+        };
+        assert!(client.validate_quote(&quote, 1_000).is_ok());
+        assert!(client.validate_quote(&quote, 2_000).is_err());
+        assert!(client.validate_quote(&JupiterQuote { price_impact_pct: 0.2, ..quote }, 1_000).is_err());
+    }
+
     #[test]
     fn test_jupiter_config_default() {
         let config = JupiterRealConfig::default();
@@ -1,4 +1,5 @@
 // Jupiter Real Client Test - Validación de funcionalidad principal
+use tracing::info;
 use crate::trading::execution::jupiter_real::JupiterRealClient;
 use solana_sdk::pubkey::Pubkey;
 use std::str::FromStr;
@@ -17,18 +18,18 @@ async fn test_jupiter_real_integration() {
     
     match client.get_real_jupiter_quote(sol_mint, usdc_mint, amount).await {
         Ok(quote) => {
-            println!("✅ Jupiter quote exitoso:");
-            println!("   Input: {} lamports", quote.in_amount);
-            println!("   Output: {} tokens", quote.out_amount);
-            println!("   Price impact: {:.2}%", quote.price_impact_pct);
-            println!("   Time taken: {:.2}ms", quote.time_taken);
+            info!("✅ Jupiter quote exitoso:");
+            info!("   Input: {} lamports", quote.in_amount);
+            info!("   Output: {} tokens", quote.out_amount);
+            info!("   Price impact: {:.2}%", quote.price_impact_pct);
+            info!("   Time taken: {:.2}ms", quote.time_taken);
             
             assert!(quote.in_amount > 0);
             assert!(quote.out_amount > 0);
             assert!(quote.price_impact_pct >= 0.0);
         }
         Err(e) => {
-            println!("⚠️ Jupiter test en devnet: {}", e);
+            info!("⚠️ Jupiter test en devnet: {}", e);
             // En devnet algunos tokens pueden no estar disponibles, esto es normal
         }
     }
@@ -45,19 +46,19 @@ async fn test_jupiter_swap_execution() {
     
     match client.execute_real_swap(sol_mint, usdc_mint, amount, test_wallet).await {
         Ok(result) => {
-            println!("✅ Swap simulado exitoso:");
-            println!("   Transaction ID: {}", result.transaction_id);
-            println!("   Input: {} lamports", result.input_amount);
-            println!("   Output: {} tokens", result.output_amount);
-            println!("   Slippage: {:.2}%", result.actual_slippage);
-            println!("   Tiempo: {}ms", result.execution_time_ms);
+            info!("✅ Swap simulado exitoso:");
+            info!("   Transaction ID: {}", result.transaction_id);
+            info!("   Input: {} lamports", result.input_amount);
+            info!("   Output: {} tokens", result.output_amount);
+            info!("   Slippage: {:.2}%", result.actual_slippage);
+            info!("   Tiempo: {}ms", result.execution_time_ms);
             
             assert!(result.success);
             assert!(!result.transaction_id.is_empty());
             assert!(result.output_amount > 0);
         }
         Err(e) => {
-            println!("ℹ️ Swap test completado con resultado: {}", e);
+            info!("ℹ️ Swap test completado con resultado: {}", e);
             // Esperado en test sin wallet real
         }
     }
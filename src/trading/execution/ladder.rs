@@ -0,0 +1,384 @@
+//! # Laddered Execution
+//!
+//! Large arbitrage targets are rarely filled in one shot without moving the
+//! pools against us. Instead of all-or-nothing sizing, a ladder splits the
+//! target into tranches and only submits the next tranche while the edge is
+//! still there: before every tranche the spread is re-validated, and the
+//! ladder stops as soon as the edge drops under the minimum or decays too far
+//! from where it started. Fills already made are kept; the rest of the target
+//! is simply never sent.
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+
+/// Ladder configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LadderConfig {
+    /// Targets at or above this size are laddered, smaller ones go in one shot
+    pub ladder_threshold: f64,
+    /// Number of tranches a laddered target is split into
+    pub tranche_count: usize,
+    /// Tranches are never smaller than this (fewer tranches are used instead)
+    pub min_tranche_size: f64,
+    /// Stop when the re-validated edge falls below this
+    pub min_edge_bps: f64,
+    /// Stop when the edge has shrunk by more than this since the first tranche
+    pub max_edge_decay_bps: f64,
+    /// Pause between tranches when executing in place
+    pub inter_tranche_delay_ms: u64,
+    /// Give up on the remaining tranches after this long
+    pub max_ladder_age_secs: u64,
+}
+
+impl Default for LadderConfig {
+    fn default() -> Self {
+        Self {
+            ladder_threshold: 25.0,
+            tranche_count: 4,
+            min_tranche_size: 5.0,
+            min_edge_bps: 10.0,
+            max_edge_decay_bps: 25.0,
+            inter_tranche_delay_ms: 400,
+            max_ladder_age_secs: 60,
+        }
+    }
+}
+
+/// Why a ladder stopped before filling its whole target
+#[derive(Debug, Clone, PartialEq, thiserror::Error, Serialize, Deserialize)]
+pub enum LadderAbortReason {
+    #[error("Edge {edge_bps:.1} bps below minimum {min_bps:.1} bps")]
+    EdgeBelowMinimum { edge_bps: f64, min_bps: f64 },
+
+    #[error("Edge decayed from {initial_bps:.1} to {edge_bps:.1} bps")]
+    EdgeDecayed { initial_bps: f64, edge_bps: f64 },
+
+    #[error("Re-validation failed: {0}")]
+    RevalidationFailed(String),
+
+    #[error("Tranche submission failed: {0}")]
+    SubmissionFailed(String),
+
+    #[error("Ladder expired after {0} s")]
+    Expired(u64),
+}
+
+/// What to do with the next tranche
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrancheDecision {
+    /// Submit tranche `index` of `size`
+    Submit { index: usize, size: f64 },
+    /// Every tranche has been submitted
+    Complete,
+    /// The ladder stopped early
+    Abort(LadderAbortReason),
+}
+
+/// Split `total` into tranches: equal slices, the remainder folded into the last one
+pub fn plan_tranches(total: f64, config: &LadderConfig) -> Vec<f64> {
+    if total <= 0.0 {
+        return Vec::new();
+    }
+    if total < config.ladder_threshold || config.tranche_count <= 1 {
+        return vec![total];
+    }
+    let by_min_size = if config.min_tranche_size > 0.0 {
+        (total / config.min_tranche_size).floor() as usize
+    } else {
+        config.tranche_count
+    };
+    let count = config.tranche_count.min(by_min_size).max(1);
+    let slice = total / count as f64;
+    let mut tranches = vec![slice; count];
+    let planned: f64 = tranches[..count - 1].iter().sum();
+    tranches[count - 1] = total - planned;
+    tranches
+}
+
+/// Outcome of one ladder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LadderReport {
+    pub target: f64,
+    pub filled: f64,
+    pub tranches_planned: usize,
+    pub tranches_submitted: usize,
+    pub initial_edge_bps: f64,
+    /// Edge observed at each re-validation, in order
+    pub edges_bps: Vec<f64>,
+    pub abort_reason: Option<LadderAbortReason>,
+}
+
+impl LadderReport {
+    /// Share of the target that was filled
+    pub fn fill_rate(&self) -> f64 {
+        if self.target <= 0.0 {
+            0.0
+        } else {
+            self.filled / self.target
+        }
+    }
+}
+
+/// One laddered target, advanced tranche by tranche
+///
+/// The ladder does not submit anything itself: callers feed it the freshly
+/// re-validated edge, submit whatever size it returns and report the fill.
+/// This lets a ladder span several scan cycles.
+#[derive(Debug, Clone)]
+pub struct Ladder {
+    config: LadderConfig,
+    tranches: Vec<f64>,
+    next: usize,
+    started_at: Instant,
+    report: LadderReport,
+}
+
+impl Ladder {
+    pub fn new(target: f64, initial_edge_bps: f64, config: LadderConfig) -> Self {
+        let tranches = plan_tranches(target, &config);
+        Self {
+            report: LadderReport {
+                target,
+                filled: 0.0,
+                tranches_planned: tranches.len(),
+                tranches_submitted: 0,
+                initial_edge_bps,
+                edges_bps: Vec::new(),
+                abort_reason: None,
+            },
+            config,
+            tranches,
+            next: 0,
+            started_at: Instant::now(),
+        }
+    }
+
+    /// Re-validate the edge and decide on the next tranche
+    pub fn next_tranche(&mut self, edge_bps: f64) -> TrancheDecision {
+        if let Some(reason) = &self.report.abort_reason {
+            return TrancheDecision::Abort(reason.clone());
+        }
+        if self.next >= self.tranches.len() {
+            return TrancheDecision::Complete;
+        }
+        if self.is_expired() {
+            return self.abort(LadderAbortReason::Expired(self.config.max_ladder_age_secs));
+        }
+
+        self.report.edges_bps.push(edge_bps);
+        if edge_bps < self.config.min_edge_bps {
+            return self.abort(LadderAbortReason::EdgeBelowMinimum { edge_bps, min_bps: self.config.min_edge_bps });
+        }
+        if self.report.initial_edge_bps - edge_bps > self.config.max_edge_decay_bps {
+            return self.abort(LadderAbortReason::EdgeDecayed { initial_bps: self.report.initial_edge_bps, edge_bps });
+        }
+
+        let index = self.next;
+        self.next += 1;
+        self.report.tranches_submitted += 1;
+        TrancheDecision::Submit { index, size: self.tranches[index] }
+    }
+
+    /// Record the filled amount of the last submitted tranche
+    pub fn record_fill(&mut self, filled: f64) {
+        self.report.filled += filled.max(0.0);
+    }
+
+    /// Stop the ladder; remaining tranches are dropped
+    pub fn abort(&mut self, reason: LadderAbortReason) -> TrancheDecision {
+        debug!("🪜 Ladder stopped after {}/{} tranches: {}", self.report.tranches_submitted, self.tranches.len(), reason);
+        self.report.abort_reason = Some(reason.clone());
+        TrancheDecision::Abort(reason)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.started_at.elapsed() > Duration::from_secs(self.config.max_ladder_age_secs)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.report.abort_reason.is_some() || self.next >= self.tranches.len()
+    }
+
+    /// Target size not yet submitted
+    pub fn remaining(&self) -> f64 {
+        self.tranches[self.next.min(self.tranches.len())..].iter().sum()
+    }
+
+    pub fn report(&self) -> &LadderReport {
+        &self.report
+    }
+}
+
+/// Aggregate ladder statistics
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LadderStats {
+    pub ladders_completed: u64,
+    pub ladders_aborted: u64,
+    pub tranches_submitted: u64,
+    pub target_volume: f64,
+    pub filled_volume: f64,
+}
+
+impl LadderStats {
+    /// Filled share of all laddered targets
+    pub fn fill_rate(&self) -> f64 {
+        if self.target_volume <= 0.0 {
+            0.0
+        } else {
+            self.filled_volume / self.target_volume
+        }
+    }
+}
+
+/// Builds ladders for large targets and keeps their statistics
+#[derive(Debug)]
+pub struct LadderExecutor {
+    config: LadderConfig,
+    stats: RwLock<LadderStats>,
+}
+
+impl LadderExecutor {
+    pub fn new(config: LadderConfig) -> Self {
+        Self {
+            config,
+            stats: RwLock::new(LadderStats::default()),
+        }
+    }
+
+    pub fn config(&self) -> &LadderConfig {
+        &self.config
+    }
+
+    /// Whether `target` is large enough to be laddered
+    pub fn should_ladder(&self, target: f64) -> bool {
+        plan_tranches(target, &self.config).len() > 1
+    }
+
+    /// Start a ladder for `target`, discovered at `initial_edge_bps`
+    pub fn start(&self, target: f64, initial_edge_bps: f64) -> Ladder {
+        Ladder::new(target, initial_edge_bps, self.config.clone())
+    }
+
+    /// Run a whole ladder in place
+    ///
+    /// `revalidate` must return the current edge in bps for the opportunity;
+    /// `submit` sends a tranche of the given size and returns the filled size.
+    pub async fn execute<R, RFut, S, SFut, E>(
+        &self,
+        target: f64,
+        initial_edge_bps: f64,
+        mut revalidate: R,
+        mut submit: S,
+    ) -> LadderReport
+    where
+        R: FnMut() -> RFut,
+        RFut: Future<Output = Result<f64, E>>,
+        S: FnMut(f64) -> SFut,
+        SFut: Future<Output = Result<f64, E>>,
+        E: std::fmt::Display,
+    {
+        let mut ladder = self.start(target, initial_edge_bps);
+        let delay = Duration::from_millis(self.config.inter_tranche_delay_ms);
+
+        loop {
+            let edge_bps = match revalidate().await {
+                Ok(edge_bps) => edge_bps,
+                Err(e) => {
+                    ladder.abort(LadderAbortReason::RevalidationFailed(e.to_string()));
+                    break;
+                }
+            };
+            let TrancheDecision::Submit { index, size } = ladder.next_tranche(edge_bps) else {
+                break;
+            };
+            match submit(size).await {
+                Ok(filled) => ladder.record_fill(filled),
+                Err(e) => {
+                    warn!("🪜 Tranche {} ({:.4}) failed: {}", index + 1, size, e);
+                    ladder.abort(LadderAbortReason::SubmissionFailed(e.to_string()));
+                    break;
+                }
+            }
+            if ladder.is_finished() {
+                break;
+            }
+            if !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+        }
+
+        let report = ladder.report().clone();
+        self.record(&report).await;
+        report
+    }
+
+    /// Fold a finished ladder into the statistics
+    pub async fn record(&self, report: &LadderReport) {
+        let mut stats = self.stats.write().await;
+        match &report.abort_reason {
+            Some(reason) => {
+                stats.ladders_aborted += 1;
+                info!("🪜 Ladder filled {:.4}/{:.4} ({}/{} tranches) - {}",
+                      report.filled, report.target, report.tranches_submitted, report.tranches_planned, reason);
+            }
+            None => stats.ladders_completed += 1,
+        }
+        stats.tranches_submitted += report.tranches_submitted as u64;
+        stats.target_volume += report.target;
+        stats.filled_volume += report.filled;
+    }
+
+    pub async fn get_stats(&self) -> LadderStats {
+        self.stats.read().await.clone()
+    }
+}
+
+impl Default for LadderExecutor {
+    fn default() -> Self {
+        Self::new(LadderConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> LadderConfig {
+        LadderConfig { inter_tranche_delay_ms: 0, ..Default::default() }
+    }
+
+    #[test]
+    fn test_plan_respects_threshold_and_min_size() {
+        let config = config();
+        assert_eq!(plan_tranches(10.0, &config), vec![10.0]);
+        assert_eq!(plan_tranches(100.0, &config), vec![25.0; 4]);
+
+        // A 10-unit minimum only leaves room for two tranches of 27 units
+        let tranches = plan_tranches(27.0, &LadderConfig { ladder_threshold: 20.0, min_tranche_size: 10.0, ..config });
+        assert_eq!(tranches, vec![13.5, 13.5]);
+        assert!((tranches.iter().sum::<f64>() - 27.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_ladder_stops_when_edge_decays() {
+        let executor = LadderExecutor::new(config());
+        let mut edges = vec![40.0, 35.0, 10.0, 50.0].into_iter();
+        let report = executor
+            .execute(100.0, 40.0, || {
+                let edge = edges.next().unwrap_or(0.0);
+                async move { Ok::<_, String>(edge) }
+            }, |size| async move { Ok::<_, String>(size) })
+            .await;
+
+        assert_eq!(report.tranches_submitted, 2);
+        assert_eq!(report.filled, 50.0);
+        assert_eq!(report.abort_reason, Some(LadderAbortReason::EdgeDecayed { initial_bps: 40.0, edge_bps: 10.0 }));
+        let stats = executor.get_stats().await;
+        assert_eq!(stats.ladders_aborted, 1);
+        assert!((stats.fill_rate() - 0.5).abs() < 1e-9);
+    }
+}
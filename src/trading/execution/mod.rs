@@ -44,6 +44,15 @@
 pub mod real_executor;
 pub mod engine;
 pub mod jupiter_real;
+pub mod quote_freshness;
+pub mod ladder;
+pub mod twap;
+pub mod pipeline;
+pub mod throttle;
+pub mod intent_log;
+pub mod replay_guard;
+pub mod tx_errors;
+pub mod remediation;
 
 #[cfg(test)]
 pub mod jupiter_real_test;
@@ -54,17 +63,41 @@ pub use engine::{
     QuoteValidation, SwapInfo
 };
 pub use jupiter_real::{JupiterRealClient, JupiterQuote, JupiterSwapResult, JupiterRealConfig};
+pub use pipeline::{
+    ExecutionPipeline, PipelineConfig, PipelineJob, PipelineOutcome, PipelineFull,
+    TransactionSigner, TransactionSubmitter, KeypairSigner, RpcSubmitter
+};
+pub use intent_log::{
+    IntentLog, IntentLogConfig, TradeIntent, IntentStatus, IntentError, ReconcileReport,
+    SignatureStatusSource, RpcSignatureStatus
+};
+pub use replay_guard::{ReplayGuard, ReplayGuardConfig, SubmissionRecord, ReplayError, install_replay_guard, shared_replay_guard, admit_shared};
+pub use tx_errors::{TxFailure, FailureKind, decode_failure};
+pub use remediation::{
+    RemediationRegistry, RemediationConfig, RemediationRule, RemediationAction, RemediationHook,
+    RemediationDecision, ErrorOccurrence
+};
+pub use throttle::{ExecutionThrottle, ThrottleConfig, ThrottleStats, execution_throttle};
+pub use quote_freshness::{
+    QuoteFreshnessGuard, QuoteFreshnessConfig, QuoteFreshnessError, TimestampedQuote, RequoteDriftStats
+};
 
+use std::sync::Arc;
 use std::time::Instant;
+use base64::{engine::general_purpose, Engine as _};
 use tracing::{error, info, warn};
+use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
 use chrono;
+use uuid::Uuid;
 
 // Enterprise imports
 use crate::config::Config;
 use crate::types::{TradingMode, PlatformError, ComponentHealthStatus};
+use crate::trading::token_quarantine::TokenQuarantine;
 use crate::security::wallet::WalletManager;
-use crate::apis::jupiter::{JupiterClient, JupiterQuoteResponse, JupiterApiConfig};
+use crate::apis::jupiter::{JupiterClient, JupiterQuoteResponse, JupiterApiConfig, QuoteRequest, SwapMode, SwapRequest};
 // TODO: Re-enable when RPC pool is migrated
 // use crate::apis::rpc::RpcConnectionPool;
 
@@ -74,12 +107,18 @@ pub struct TradeRequest {
     pub wallet_name: String,
     pub input_mint: Pubkey,
     pub output_mint: Pubkey,
+    /// Input to spend (ExactIn), or the most input allowed (ExactOut)
     pub amount_in: u64,
+    pub swap_mode: SwapMode,
+    /// Output to receive exactly (ExactOut only)
+    pub amount_out: Option<u64>,
     pub slippage_bps: Option<u16>,
     pub trading_mode: TradingMode,
     pub max_price_impact: Option<f64>,
     pub priority_fee: Option<u64>,
     pub timeout_seconds: Option<u64>,
+    /// Intent log key; a key already recorded is never executed again (random when unset)
+    pub idempotency_key: Option<String>,
 }
 
 impl TradeRequest {
@@ -96,11 +135,46 @@ impl TradeRequest {
             input_mint,
             output_mint,
             amount_in,
+            swap_mode: SwapMode::ExactIn,
+            amount_out: None,
             slippage_bps: Some(50), // Default 0.5%
             trading_mode,
             max_price_impact: Some(3.0), // Default 3%
             priority_fee: None,
             timeout_seconds: Some(30),
+            idempotency_key: None,
+        }
+    }
+
+    /// Acquire exactly `amount_out` of `output_mint`, spending at most `max_amount_in`
+    ///
+    /// Used to repay flash loans and rebalance to precise targets.
+    pub fn exact_out(
+        wallet_name: String,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+        amount_out: u64,
+        max_amount_in: u64,
+        trading_mode: TradingMode,
+    ) -> Self {
+        Self {
+            swap_mode: SwapMode::ExactOut,
+            amount_out: Some(amount_out),
+            ..Self::new(wallet_name, input_mint, output_mint, max_amount_in, trading_mode)
+        }
+    }
+
+    /// Jupiter quote request for this trade
+    pub fn quote_request(&self) -> QuoteRequest {
+        let amount = match self.swap_mode {
+            SwapMode::ExactIn => self.amount_in,
+            SwapMode::ExactOut => self.amount_out.unwrap_or_default(),
+        };
+        let request = QuoteRequest::new(self.input_mint.to_string(), self.output_mint.to_string(), amount)
+            .with_swap_mode(self.swap_mode);
+        match self.slippage_bps {
+            Some(slippage_bps) => request.with_slippage_bps(slippage_bps),
+            None => request,
         }
     }
 
@@ -127,6 +201,18 @@ impl TradeRequest {
         self.timeout_seconds = Some(timeout_seconds);
         self
     }
+
+    /// Set the intent log key, so the same trade cannot be executed twice
+    pub fn with_idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// Write-ahead intent for this trade
+    fn intent(&self, size: u64) -> TradeIntent {
+        let key = self.idempotency_key.clone().unwrap_or_else(|| format!("trade-{}", Uuid::new_v4()));
+        TradeIntent::new(&key, &self.wallet_name, vec![self.input_mint.to_string(), self.output_mint.to_string()], size)
+    }
 }
 
 /// Comprehensive trade execution result
@@ -142,12 +228,22 @@ pub struct TradeResult {
     pub trading_mode: TradingMode,
     pub execution_time_ms: u64,
     pub error_message: Option<String>,
+    /// Decoded on-chain failure (program logs, error code, remediation)
+    pub failure: Option<TxFailure>,
     pub jupiter_quote: Option<JupiterQuoteResponse>,
     pub wallet_balance_before: f64,
     pub wallet_balance_after: f64,
 }
 
 impl TradeResult {
+    /// Mark the trade failed on-chain, with the decoded failure as its error message
+    pub fn with_failure(mut self, failure: TxFailure) -> Self {
+        self.success = false;
+        self.error_message = Some(failure.summary());
+        self.failure = Some(failure);
+        self
+    }
+
     /// Check if trade was profitable
     pub fn is_profitable(&self) -> bool {
         self.success && self.output_amount > self.input_amount
@@ -181,8 +277,15 @@ impl TradeResult {
 pub struct TradeExecutor {
     config: Config,
     jupiter_client: JupiterClient,
-    wallet_manager: WalletManager,
+    wallet_manager: Arc<WalletManager>,
     trading_mode: TradingMode,
+    quote_guard: QuoteFreshnessGuard,
+    /// Shared toxic-token list: quarantined mints are refused, token failures reported
+    quarantine: Option<Arc<TokenQuarantine>>,
+    /// Live transactions are signed and sent through this pipeline when attached
+    pipeline: Option<Arc<ExecutionPipeline>>,
+    /// Chain balance lookups; the simulated balance is used without one
+    balance_rpc: Option<Arc<RpcClient>>,
     // TODO: Re-enable when RPC pool is migrated
     // rpc_pool: RpcConnectionPool,
 }
@@ -212,13 +315,52 @@ impl TradeExecutor {
         Ok(Self {
             config,
             jupiter_client,
-            wallet_manager,
+            wallet_manager: Arc::new(wallet_manager),
             trading_mode,
+            quote_guard: QuoteFreshnessGuard::default(),
+            quarantine: None,
+            pipeline: None,
+            balance_rpc: None,
             // TODO: Re-enable when RPC pool is migrated
             // rpc_pool,
         })
     }
 
+    /// Share a token quarantine with the other strategies
+    pub fn with_quarantine(mut self, quarantine: Arc<TokenQuarantine>) -> Self {
+        self.quarantine = Some(quarantine);
+        self
+    }
+
+    /// Sign and send live trades through a shared execution pipeline
+    pub fn with_pipeline(mut self, pipeline: Arc<ExecutionPipeline>) -> Self {
+        self.pipeline = Some(pipeline);
+        self
+    }
+
+    /// Read wallet balances from chain instead of the simulated balance
+    pub fn with_balance_rpc(mut self, rpc: Arc<RpcClient>) -> Self {
+        self.balance_rpc = Some(rpc);
+        self
+    }
+
+    /// Whether live trades go through an execution pipeline
+    pub fn has_pipeline(&self) -> bool {
+        self.pipeline.is_some()
+    }
+
+    /// Freshness guard applied before every submission
+    pub fn quote_guard(&self) -> &QuoteFreshnessGuard {
+        &self.quote_guard
+    }
+
+    /// Report a failure to the quarantine (ignored when the token is not to blame)
+    fn report_token_failure(&self, request: &TradeRequest, error: &str) {
+        if let Some(quarantine) = &self.quarantine {
+            quarantine.record_trade_error(&request.input_mint.to_string(), &request.output_mint.to_string(), error, &request.wallet_name);
+        }
+    }
+
     /// Execute trade with comprehensive validation and monitoring
     pub async fn execute_trade(&self, request: TradeRequest) -> Result<TradeResult, PlatformError> {
         let start_time = Instant::now();
@@ -237,6 +379,30 @@ impl TradeExecutor {
             .await
             .unwrap_or(0.0);
 
+        // Never trade a quarantined token
+        if let Some(quarantine) = &self.quarantine {
+            let mints = [request.input_mint.to_string(), request.output_mint.to_string()];
+            if let Some(mint) = mints.iter().find(|mint| quarantine.is_quarantined(mint)) {
+                warn!("☣️ Refusing trade on quarantined token {}", mint);
+                return Ok(TradeResult {
+                    success: false,
+                    transaction_signature: None,
+                    input_amount: request.amount_in,
+                    output_amount: 0,
+                    actual_price_impact: 0.0,
+                    actual_slippage: 0.0,
+                    gas_fee: 0.0,
+                    trading_mode: request.trading_mode.clone(),
+                    execution_time_ms: start_time.elapsed().as_millis() as u64,
+                    error_message: Some(format!("Token {} is quarantined", mint)),
+                    failure: None,
+                    jupiter_quote: None,
+                    wallet_balance_before,
+                    wallet_balance_after: wallet_balance_before,
+                });
+            }
+        }
+
         // Validate trade request
         if let Err(e) = self.validate_trade_request(&request, wallet_balance_before).await {
             return Ok(TradeResult {
@@ -250,21 +416,23 @@ impl TradeExecutor {
                 trading_mode: request.trading_mode.clone(),
                 execution_time_ms: start_time.elapsed().as_millis() as u64,
                 error_message: Some(format!("Validation failed: {}", e)),
+                failure: None,
                 jupiter_quote: None,
                 wallet_balance_before,
                 wallet_balance_after: wallet_balance_before,
             });
         }
 
-        // Get Jupiter quote
+        // Get Jupiter quote (timestamped for the freshness guard)
         let quote = match self.get_quote(&request).await {
             Ok(quote) => {
                 // TODO: Add proper logging when quote response methods are available
                 info!("💰 Jupiter quote received");
-                quote
+                TimestampedQuote::new(quote)
             }
             Err(e) => {
                 error!("❌ Failed to get Jupiter quote: {}", e);
+                self.report_token_failure(&request, &e.to_string());
                 return Ok(TradeResult {
                     success: false,
                     transaction_signature: None,
@@ -276,6 +444,7 @@ impl TradeExecutor {
                     trading_mode: request.trading_mode.clone(),
                     execution_time_ms: start_time.elapsed().as_millis() as u64,
                     error_message: Some(format!("Quote failed: {}", e)),
+                    failure: None,
                     jupiter_quote: None,
                     wallet_balance_before,
                     wallet_balance_after: wallet_balance_before,
@@ -284,7 +453,7 @@ impl TradeExecutor {
         };
 
         // Validate quote before execution
-        if !self.validate_quote(&quote, &request).await? {
+        if !self.validate_quote(&quote.quote, &request).await? {
             return Ok(TradeResult {
                 success: false,
                 transaction_signature: None,
@@ -295,13 +464,64 @@ impl TradeExecutor {
                 gas_fee: 0.0,
                 trading_mode: request.trading_mode.clone(),
                 execution_time_ms: start_time.elapsed().as_millis() as u64,
-                error_message: Some("Quote validation failed - price impact too high".to_string()),
-                jupiter_quote: Some(quote),
+                error_message: Some("Quote validation failed - price impact or input bound exceeded".to_string()),
+                failure: None,
+                jupiter_quote: Some(quote.quote),
                 wallet_balance_before,
                 wallet_balance_after: wallet_balance_before,
             });
         }
 
+        // Every transaction counts against the process-wide rate limits (the pipeline throttles its own)
+        if request.trading_mode != TradingMode::Simulation && self.pipeline.is_none() {
+            execution_throttle().acquire(&request.wallet_name).await;
+        }
+
+        // Never submit a stale quote - re-quote the same request if needed, after the last wait
+        let quote = match self.quote_guard.ensure_fresh(quote, || self.get_quote(&request)).await {
+            Ok(fresh) => fresh,
+            Err(e) => {
+                warn!("⏱️ Quote freshness check failed: {}", e);
+                return Ok(TradeResult {
+                    success: false,
+                    transaction_signature: None,
+                    input_amount: request.amount_in,
+                    output_amount: 0,
+                    actual_price_impact: 0.0,
+                    actual_slippage: 0.0,
+                    gas_fee: 0.0,
+                    trading_mode: request.trading_mode.clone(),
+                    execution_time_ms: start_time.elapsed().as_millis() as u64,
+                    error_message: Some(format!("Quote freshness check failed: {}", e)),
+                    failure: None,
+                    jupiter_quote: None,
+                    wallet_balance_before,
+                    wallet_balance_after: wallet_balance_before,
+                });
+            }
+        };
+
+        // A refreshed quote gets the same checks as the original
+        if quote.requote_count > 0 && !self.validate_quote(&quote.quote, &request).await? {
+            return Ok(TradeResult {
+                success: false,
+                transaction_signature: None,
+                input_amount: request.amount_in,
+                output_amount: 0,
+                actual_price_impact: 0.0,
+                actual_slippage: 0.0,
+                gas_fee: 0.0,
+                trading_mode: request.trading_mode.clone(),
+                execution_time_ms: start_time.elapsed().as_millis() as u64,
+                error_message: Some("Re-quote validation failed - price impact or input bound exceeded".to_string()),
+                failure: None,
+                jupiter_quote: Some(quote.quote),
+                wallet_balance_before,
+                wallet_balance_after: wallet_balance_before,
+            });
+        }
+        let quote = quote.quote;
+
         // Execute trade based on mode
         let result = match request.trading_mode {
             TradingMode::DevNet => self.execute_devnet_trade(&quote, &request).await?,
@@ -317,6 +537,12 @@ impl TradeExecutor {
 
         let execution_time = start_time.elapsed().as_millis() as u64;
 
+        if !result.success {
+            if let Some(error) = &result.error_message {
+                self.report_token_failure(&request, error);
+            }
+        }
+
         info!(
             "🎯 Trade completed in {} ms | Success: {} | Efficiency: {:.2}",
             execution_time, 
@@ -324,10 +550,16 @@ impl TradeExecutor {
             result.efficiency_score()
         );
 
+        // ExactOut spends the quoted input, not the bound
+        let input_amount = match request.swap_mode {
+            SwapMode::ExactIn => request.amount_in,
+            SwapMode::ExactOut => quote.in_amount_u64().unwrap_or(request.amount_in),
+        };
+
         Ok(TradeResult {
             success: result.success,
             transaction_signature: result.transaction_signature,
-            input_amount: request.amount_in,
+            input_amount,
             output_amount: result.output_amount,
             actual_price_impact: 0.0, // TODO: Get from quote when methods available
             actual_slippage: result.slippage,
@@ -335,6 +567,7 @@ impl TradeExecutor {
             trading_mode: request.trading_mode,
             execution_time_ms: execution_time,
             error_message: result.error_message,
+            failure: None,
             jupiter_quote: Some(quote),
             wallet_balance_before,
             wallet_balance_after,
@@ -349,10 +582,11 @@ impl TradeExecutor {
     }
 
     /// Get Jupiter quote for trade
-    async fn get_quote(&self, _request: &TradeRequest) -> Result<JupiterQuoteResponse, PlatformError> {
-        // TODO: Implement quote request using the new Jupiter client
-        // For now, return a placeholder error until Jupiter client methods are available
-        Err(PlatformError::JupiterQuoteError("Quote functionality temporarily disabled during migration".to_string()))
+    async fn get_quote(&self, request: &TradeRequest) -> Result<JupiterQuoteResponse, PlatformError> {
+        self.jupiter_client
+            .get_quote(&request.quote_request())
+            .await
+            .map_err(|e| PlatformError::JupiterQuoteError(e.to_string()))
     }
 
     /// Validate trade request with comprehensive checks
@@ -361,6 +595,11 @@ impl TradeExecutor {
         request: &TradeRequest,
         wallet_balance: f64,
     ) -> Result<bool, PlatformError> {
+        if request.swap_mode == SwapMode::ExactOut && request.amount_out.unwrap_or(0) == 0 {
+            warn!("❌ ExactOut trade without a target output amount");
+            return Ok(false);
+        }
+
         // Check if wallet exists and has sufficient balance
         if !self
             .wallet_manager
@@ -428,19 +667,94 @@ impl TradeExecutor {
     }
 
     /// Validate Jupiter quote before execution
-    async fn validate_quote(&self, _quote: &JupiterQuoteResponse, _request: &TradeRequest) -> Result<bool, PlatformError> {
+    async fn validate_quote(&self, quote: &JupiterQuoteResponse, request: &TradeRequest) -> Result<bool, PlatformError> {
+        // ExactOut: the worst-case input must stay within the request's bound
+        if let (SwapMode::ExactOut, Some(amount_out)) = (request.swap_mode, request.amount_out) {
+            if let Err(e) = quote.validate_exact_out(amount_out, request.amount_in) {
+                warn!("❌ ExactOut quote rejected: {}", e);
+                return Ok(false);
+            }
+        }
+
         // TODO: Implement quote validation when Jupiter response methods are available
         // For now, return true as placeholder
         warn!("⚠️ Quote validation temporarily disabled during migration");
         Ok(true)
     }
 
-    /// Execute DevNet trade (simulation mode)
+    /// Build the swap transaction for a quote and send it through the execution pipeline
+    ///
+    /// The pipeline records `intent` before signing and its signature before
+    /// submission. The output is the quoted amount; the actual fill is settled
+    /// from chain.
+    async fn submit_swap(
+        &self,
+        pipeline: &ExecutionPipeline,
+        quote: &JupiterQuoteResponse,
+        intent: TradeIntent,
+        priority_fee: Option<u64>,
+    ) -> Result<TradeExecutionResult, PlatformError> {
+        let wallet_name = intent.wallet.clone();
+        let user = self.wallet_manager.get_wallet_pubkey(&wallet_name).await
+            .ok_or_else(|| PlatformError::WalletNotFound(wallet_name.clone()))?;
+        let swap_request = SwapRequest {
+            quote_response: quote.clone(),
+            user_public_key: user.to_string(),
+            wrap_and_unwrap_sol: true,
+            use_shared_accounts: None,
+            compute_unit_price_micro_lamports: priority_fee,
+            auto_create_account_associated_tokens: true,
+            dynamic_compute_unit_limit: true,
+            priority_fee_lamports: priority_fee,
+        };
+        let encoded = self.jupiter_client.get_swap_transaction(&swap_request).await
+            .map_err(|e| PlatformError::Trading(format!("Swap transaction unavailable: {}", e)))?;
+        let transaction: VersionedTransaction = general_purpose::STANDARD
+            .decode(&encoded)
+            .ok()
+            .and_then(|bytes| bincode::deserialize(&bytes).ok())
+            .ok_or_else(|| PlatformError::Trading("Swap transaction could not be decoded".to_string()))?;
+
+        let job = PipelineJob {
+            id: intent.key.clone(),
+            wallet: wallet_name,
+            transaction,
+            intent: Some(intent),
+        };
+        let outcome = match pipeline.try_submit(job) {
+            Ok(outcome) => outcome.await
+                .map_err(|_| PlatformError::Trading("Execution pipeline dropped the job".to_string()))?,
+            Err(full) => {
+                warn!("🚦 Trade not submitted: {}", full);
+                return Ok(TradeExecutionResult::failed(full.to_string()));
+            }
+        };
+        match outcome.result {
+            Ok(signature) => {
+                info!("📡 Swap submitted: {} (signed in {} ms, sent in {} ms)", signature, outcome.signing_ms, outcome.submission_ms);
+                Ok(TradeExecutionResult {
+                    success: true,
+                    transaction_signature: Some(signature.to_string()),
+                    output_amount: quote.out_amount_u64().unwrap_or(0),
+                    slippage: 0.0,
+                    gas_fee: 0.0, // Known once the fill is settled from chain
+                    error_message: None,
+                })
+            }
+            Err(e) => Ok(TradeExecutionResult::failed(e)),
+        }
+    }
+
+    /// Execute DevNet trade (simulated unless a pipeline is attached)
     async fn execute_devnet_trade(
         &self,
-        _quote: &JupiterQuoteResponse,
-        _request: &TradeRequest,
+        quote: &JupiterQuoteResponse,
+        request: &TradeRequest,
     ) -> Result<TradeExecutionResult, PlatformError> {
+        if let Some(pipeline) = &self.pipeline {
+            info!("🧪 Executing DevNet trade");
+            return self.submit_swap(pipeline, quote, request.intent(quote.in_amount_u64().unwrap_or(request.amount_in)), request.priority_fee).await;
+        }
         info!("🧪 Executing DevNet trade (simulation)");
         
         // Simulate successful trade for DevNet
@@ -457,32 +771,29 @@ impl TradeExecutor {
     /// Execute MainNet real trade
     async fn execute_mainnet_real_trade(
         &self,
-        _quote: &JupiterQuoteResponse,
-        _request: &TradeRequest,
+        quote: &JupiterQuoteResponse,
+        request: &TradeRequest,
     ) -> Result<TradeExecutionResult, PlatformError> {
         info!("💰 Executing MainNet real trade");
-        
-        // TODO: Implement real MainNet execution
-        // For now, return error to prevent accidental real trades
-        warn!("⚠️ MainNet execution not yet implemented - safety protection active");
-        
-        Ok(TradeExecutionResult {
-            success: false,
-            transaction_signature: None,
-            output_amount: 0,
-            slippage: 0.0,
-            gas_fee: 0.0,
-            error_message: Some("MainNet execution temporarily disabled for safety".to_string()),
-        })
+
+        // Without a signing pipeline nothing can be sent safely
+        let Some(pipeline) = &self.pipeline else {
+            warn!("⚠️ MainNet execution needs an execution pipeline - safety protection active");
+            return Ok(TradeExecutionResult::failed("MainNet execution disabled: no execution pipeline attached".to_string()));
+        };
+        self.submit_swap(pipeline, quote, request.intent(quote.in_amount_u64().unwrap_or(request.amount_in)), request.priority_fee).await
     }
 
     /// Execute TestNet trade (real transactions on test network)
     async fn execute_testnet_trade(
         &self,
-        _quote: &JupiterQuoteResponse,
-        _request: &TradeRequest,
+        quote: &JupiterQuoteResponse,
+        request: &TradeRequest,
     ) -> Result<TradeExecutionResult, PlatformError> {
         info!("🧪 Executing TestNet trade (real test network)");
+        if let Some(pipeline) = &self.pipeline {
+            return self.submit_swap(pipeline, quote, request.intent(quote.in_amount_u64().unwrap_or(request.amount_in)), request.priority_fee).await;
+        }
         
         // Simulate successful trade for TestNet with realistic behavior
         Ok(TradeExecutionResult {
@@ -498,25 +809,28 @@ impl TradeExecutor {
     /// Execute Simulation trade (no real transactions)
     async fn execute_simulation_trade(
         &self,
-        _quote: &JupiterQuoteResponse,
+        quote: &JupiterQuoteResponse,
         _request: &TradeRequest,
     ) -> Result<TradeExecutionResult, PlatformError> {
         info!("🎮 Executing Simulation trade (no real transactions)");
         
-        // Perfect simulation with ideal conditions
+        // The simulated fill is the quoted output, at the quoted price impact
+        let output_amount = quote
+            .out_amount_u64()
+            .map_err(|e| PlatformError::Trading(format!("Unparseable quote output amount: {}", e)))?;
         Ok(TradeExecutionResult {
             success: true,
             transaction_signature: Some(format!("sim_tx_{}", chrono::Utc::now().timestamp())),
-            output_amount: 990_000, // Ideal output with minimal slippage
-            slippage: 0.05, // Minimal simulated slippage
+            output_amount,
+            slippage: quote.price_impact_pct.parse().unwrap_or(0.0),
             gas_fee: 0.0, // No real gas cost in simulation
             error_message: None,
         })
     }
 
     /// Get wallet balance with timeout protection
-    async fn get_wallet_balance(&self, wallet_name: &str) -> Result<f64, PlatformError> {
-        let _wallet_pubkey = match self.wallet_manager.get_wallet_pubkey(wallet_name).await {
+    pub async fn get_wallet_balance(&self, wallet_name: &str) -> Result<f64, PlatformError> {
+        let wallet_pubkey = match self.wallet_manager.get_wallet_pubkey(wallet_name).await {
             Some(pubkey) => {
                 info!("✅ Wallet '{}' found with pubkey: {}", wallet_name, pubkey);
                 pubkey
@@ -527,6 +841,16 @@ impl TradeExecutor {
             }
         };
 
+        if let Some(rpc) = &self.balance_rpc {
+            let lamports = tokio::time::timeout(std::time::Duration::from_secs(10), rpc.get_balance(&wallet_pubkey))
+                .await
+                .map_err(|_| PlatformError::Trading(format!("Balance lookup for {} timed out", wallet_name)))?
+                .map_err(|e| PlatformError::Trading(format!("Balance lookup for {} failed: {}", wallet_name, e)))?;
+            let balance = lamports as f64 / 1_000_000_000.0;
+            self.wallet_manager.record_balance(wallet_name, balance).await;
+            return Ok(balance);
+        }
+
         // TODO: Re-enable when RPC pool is migrated
         // Get real SOL balance from RPC with timeout
         // let balance_result = timeout(
@@ -591,6 +915,11 @@ impl TradeExecutor {
         }
     }
 
+    /// Get quote freshness / re-quote drift statistics
+    pub async fn get_quote_drift_stats(&self) -> RequoteDriftStats {
+        self.quote_guard.get_stats().await
+    }
+
     /// Get execution statistics
     pub async fn get_execution_stats(&self) -> ExecutionStats {
         // TODO: Implement statistics tracking
@@ -612,6 +941,11 @@ impl TradeExecutor {
         &self.wallet_manager
     }
 
+    /// Wallet manager handle for components that outlive a borrow (treasury sweeps)
+    pub fn shared_wallet_manager(&self) -> Arc<WalletManager> {
+        self.wallet_manager.clone()
+    }
+
     /// Check if executor is ready for trading
     pub async fn is_ready(&self) -> bool {
         // Basic readiness check
@@ -634,6 +968,17 @@ struct TradeExecutionResult {
 }
 
 impl TradeExecutionResult {
+    fn failed(error: String) -> Self {
+        Self {
+            success: false,
+            transaction_signature: None,
+            output_amount: 0,
+            slippage: 0.0,
+            gas_fee: 0.0,
+            error_message: Some(error),
+        }
+    }
+
     /// Calculate execution efficiency score
     pub fn efficiency_score(&self) -> f64 {
         if !self.success {
@@ -677,3 +1022,9 @@ impl ExecutionStats {
         }
     }
 }
+pub use ladder::{
+    LadderExecutor, LadderConfig, Ladder, LadderReport, LadderStats, LadderAbortReason, TrancheDecision, plan_tranches
+};
+pub use twap::{
+    TwapExecutor, TwapConfig, TwapSide, TwapSlice, TwapFill, TwapReport, TwapAbortReason, plan_twap
+};
@@ -0,0 +1,552 @@
+//! Pipelined signing and submission
+//!
+//! Jobs flow through two stages: signing, then submission. Each wallet gets
+//! its own lane so trades from different wallets progress concurrently, while
+//! within a wallet submissions keep the order jobs were accepted in (nonce
+//! accounts, token balances and priority bumps all assume that). A wallet's
+//! next job can be signing while its previous one is still being submitted.
+//!
+//! Shared worker limits cap how many signatures and RPC submissions run at
+//! once across all lanes. The intake queue is bounded: when it is full
+//! [`ExecutionPipeline::try_submit`] refuses the job, and
+//! [`ExecutionPipeline::available_slots`] tells the scheduler how much work to
+//! plan for, so back-pressure reaches it instead of piling up in memory.
+//!
+//! With an [`IntentLog`] attached, jobs carrying a [`TradeIntent`] are
+//! recorded before signing and their signature before submission, so a crash
+//! mid-flight can be settled against chain state on restart. With a
+//! [`ReplayGuard`] attached, every signed transaction is checked against
+//! recent submissions and recorded before it is sent. With a
+//! [`RemediationRegistry`] attached, RPC rejections are classified and the
+//! chosen action comes back in the [`PipelineOutcome`]. With a
+//! [`WebhookEmitter`] attached, every accepted submission is published as a
+//! `trade_executed` event.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcSendTransactionConfig;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::VersionedTransaction;
+use tokio::sync::{mpsc, oneshot, Semaphore};
+use tracing::{debug, warn};
+
+use crate::apis::rpc_usage::{provider_for_url, rpc_usage};
+use crate::monitoring::latency_heatmap::latency_heatmap;
+use crate::monitoring::webhook_emitter::{WebhookEmitter, WebhookEventKind};
+use super::intent_log::{IntentLog, TradeIntent};
+use super::remediation::{RemediationDecision, RemediationRegistry};
+use super::replay_guard::ReplayGuard;
+use super::tx_errors::TxFailure;
+use super::throttle::execution_throttle;
+
+/// Worker and queue limits
+#[derive(Debug, Clone)]
+pub struct PipelineConfig {
+    /// Jobs accepted but not finished, across all wallets
+    pub max_in_flight: usize,
+    /// Concurrent signing operations
+    pub signing_workers: usize,
+    /// Concurrent RPC submissions
+    pub submission_workers: usize,
+    /// Signed transactions a wallet may have waiting for submission
+    pub lane_depth: usize,
+}
+
+impl Default for PipelineConfig {
+    fn default() -> Self {
+        Self {
+            max_in_flight: 32,
+            signing_workers: 4,
+            submission_workers: 8,
+            lane_depth: 2,
+        }
+    }
+}
+
+/// Signs a transaction for one wallet
+#[async_trait]
+pub trait TransactionSigner: Send + Sync {
+    async fn sign(&self, wallet: &str, transaction: VersionedTransaction) -> Result<VersionedTransaction>;
+}
+
+/// Sends a signed transaction
+#[async_trait]
+pub trait TransactionSubmitter: Send + Sync {
+    async fn submit(&self, transaction: &VersionedTransaction) -> Result<Signature>;
+}
+
+/// In-memory keypairs by wallet name
+#[derive(Default)]
+pub struct KeypairSigner {
+    keypairs: HashMap<String, Arc<Keypair>>,
+}
+
+impl KeypairSigner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_wallet(mut self, wallet: &str, keypair: Arc<Keypair>) -> Self {
+        self.keypairs.insert(wallet.to_string(), keypair);
+        self
+    }
+}
+
+#[async_trait]
+impl TransactionSigner for KeypairSigner {
+    async fn sign(&self, wallet: &str, mut transaction: VersionedTransaction) -> Result<VersionedTransaction> {
+        let keypair = self.keypairs.get(wallet).ok_or_else(|| anyhow!("no keypair for wallet {}", wallet))?;
+        let message = transaction.message.serialize();
+        transaction.signatures = vec![keypair.sign_message(&message)];
+        Ok(transaction)
+    }
+}
+
+/// Submission over JSON-RPC; confirmation is tracked elsewhere
+pub struct RpcSubmitter {
+    client: RpcClient,
+    provider: &'static str,
+    config: RpcSendTransactionConfig,
+}
+
+impl RpcSubmitter {
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            client: RpcClient::new(rpc_url.to_string()),
+            provider: provider_for_url(rpc_url),
+            config: RpcSendTransactionConfig {
+                skip_preflight: true,
+                max_retries: Some(0),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionSubmitter for RpcSubmitter {
+    async fn submit(&self, transaction: &VersionedTransaction) -> Result<Signature> {
+        crate::chaos::faults().rpc_call(self.provider, "sendTransaction")?;
+        rpc_usage().record(self.provider, "sendTransaction");
+        let started = std::time::Instant::now();
+        let sent = self.client.send_transaction_with_config(transaction, self.config).await;
+        latency_heatmap().record_rpc(self.provider, "sendTransaction", started.elapsed(), sent.is_ok());
+        Ok(sent?)
+    }
+}
+
+/// Transaction to sign and send for a wallet
+#[derive(Debug, Clone)]
+pub struct PipelineJob {
+    pub id: String,
+    pub wallet: String,
+    pub transaction: VersionedTransaction,
+    /// Recorded in the intent log before signing (its key must be unique)
+    pub intent: Option<TradeIntent>,
+}
+
+/// What happened to a job
+#[derive(Debug, Clone)]
+pub struct PipelineOutcome {
+    pub job_id: String,
+    pub wallet: String,
+    pub result: Result<Signature, String>,
+    /// What the remediation registry decided for a rejected submission
+    pub remediation: Option<RemediationDecision>,
+    pub signing_ms: u64,
+    pub submission_ms: u64,
+}
+
+/// Job refused because the pipeline is at capacity
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("execution pipeline full ({in_flight} jobs in flight)")]
+pub struct PipelineFull {
+    pub in_flight: usize,
+}
+
+struct QueuedJob {
+    job: PipelineJob,
+    accepted_at: Instant,
+    reply: oneshot::Sender<PipelineOutcome>,
+}
+
+struct SignedJob {
+    queued: QueuedJob,
+    signed: Result<VersionedTransaction, String>,
+    signing_ms: u64,
+}
+
+/// Per-wallet signing → submission lanes with shared worker limits
+pub struct ExecutionPipeline {
+    config: PipelineConfig,
+    signer: Arc<dyn TransactionSigner>,
+    submitter: Arc<dyn TransactionSubmitter>,
+    signing_permits: Arc<Semaphore>,
+    submission_permits: Arc<Semaphore>,
+    lanes: Mutex<HashMap<String, mpsc::UnboundedSender<QueuedJob>>>,
+    in_flight: Arc<AtomicUsize>,
+    intent_log: Option<Arc<IntentLog>>,
+    replay_guard: Option<Arc<ReplayGuard>>,
+    remediation: Option<Arc<RemediationRegistry>>,
+    webhooks: Option<Arc<WebhookEmitter>>,
+}
+
+impl ExecutionPipeline {
+    pub fn new(config: PipelineConfig, signer: Arc<dyn TransactionSigner>, submitter: Arc<dyn TransactionSubmitter>) -> Self {
+        Self {
+            signing_permits: Arc::new(Semaphore::new(config.signing_workers.max(1))),
+            submission_permits: Arc::new(Semaphore::new(config.submission_workers.max(1))),
+            config,
+            signer,
+            submitter,
+            lanes: Mutex::new(HashMap::new()),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            intent_log: None,
+            replay_guard: None,
+            remediation: None,
+            webhooks: None,
+        }
+    }
+
+    /// Record job intents and signatures before they go out
+    pub fn with_intent_log(mut self, intent_log: Arc<IntentLog>) -> Self {
+        self.intent_log = Some(intent_log);
+        self
+    }
+
+    /// Refuse transactions (or intents) already sent and still able to land
+    pub fn with_replay_guard(mut self, replay_guard: Arc<ReplayGuard>) -> Self {
+        self.replay_guard = Some(replay_guard);
+        self
+    }
+
+    /// Classify rejected submissions and run their remediation hooks
+    pub fn with_remediation(mut self, remediation: Arc<RemediationRegistry>) -> Self {
+        self.remediation = Some(remediation);
+        self
+    }
+
+    /// Publish accepted submissions to outbound webhooks
+    pub fn with_webhooks(mut self, webhooks: Arc<WebhookEmitter>) -> Self {
+        self.webhooks = Some(webhooks);
+        self
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// How many more jobs the pipeline accepts right now
+    pub fn available_slots(&self) -> usize {
+        self.config.max_in_flight.saturating_sub(self.in_flight())
+    }
+
+    /// Queue a job; the receiver resolves once it is submitted (or failed)
+    pub fn try_submit(&self, job: PipelineJob) -> Result<oneshot::Receiver<PipelineOutcome>, PipelineFull> {
+        let reserved = self.in_flight.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| {
+            (current < self.config.max_in_flight).then_some(current + 1)
+        });
+        if let Err(in_flight) = reserved {
+            return Err(PipelineFull { in_flight });
+        }
+
+        let (reply, outcome) = oneshot::channel();
+        let wallet = job.wallet.clone();
+        let mut queued = QueuedJob { job, accepted_at: Instant::now(), reply };
+        let mut lanes = self.lanes.lock();
+        // A lane whose tasks ended (receiver dropped) is replaced
+        for _ in 0..2 {
+            let lane = lanes.entry(wallet.clone()).or_insert_with(|| self.spawn_lane(&wallet));
+            match lane.send(queued) {
+                Ok(()) => return Ok(outcome),
+                Err(mpsc::error::SendError(returned)) => {
+                    lanes.remove(&wallet);
+                    queued = returned;
+                }
+            }
+        }
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        warn!("⚠️ Execution lane for {} could not be started", wallet);
+        Err(PipelineFull { in_flight: self.in_flight() })
+    }
+
+    /// Two tasks per wallet: a signing stage feeding an ordered submission stage
+    fn spawn_lane(&self, wallet: &str) -> mpsc::UnboundedSender<QueuedJob> {
+        debug!("🧵 Opening execution lane for {}", wallet);
+        let (intake, mut to_sign) = mpsc::unbounded_channel::<QueuedJob>();
+        let (signed_tx, mut to_submit) = mpsc::channel::<SignedJob>(self.config.lane_depth.max(1));
+
+        let signer = self.signer.clone();
+        let signing_permits = self.signing_permits.clone();
+        let intent_log = self.intent_log.clone();
+        tokio::spawn(async move {
+            while let Some(queued) = to_sign.recv().await {
+                let started = Instant::now();
+                // Write-ahead: the intent is durable before anything is signed
+                let recorded = match (&intent_log, &queued.job.intent) {
+                    (Some(log), Some(intent)) => log.begin(intent.clone()).map_err(|e| e.to_string()),
+                    _ => Ok(()),
+                };
+                let intent_recorded = recorded.is_ok();
+                let signed = match recorded {
+                    Err(e) => Err(e),
+                    Ok(()) => match signing_permits.clone().acquire_owned().await {
+                        Ok(_permit) => signer
+                            .sign(&queued.job.wallet, queued.job.transaction.clone())
+                            .await
+                            .map_err(|e| e.to_string()),
+                        Err(_) => Err("signing workers shut down".to_string()),
+                    },
+                };
+                // The signature is durable before submission, or the job does not go out
+                let signed = match (&intent_log, &queued.job.intent, intent_recorded) {
+                    (Some(log), Some(intent), true) => {
+                        let signature = signed.as_ref().ok().and_then(|transaction| transaction.signatures.first().copied());
+                        let recorded = match (&signed, signature) {
+                            (Ok(_), Some(signature)) => log.mark_signed(&intent.key, &signature.to_string()),
+                            (Ok(_), None) => log.mark_failed(&intent.key, "signer returned no signature"),
+                            (Err(e), _) => log.mark_failed(&intent.key, e),
+                        };
+                        match (recorded, signature) {
+                            (Ok(()), Some(_)) => signed,
+                            (Ok(()), None) => signed.and(Err("signer returned no signature".to_string())),
+                            (Err(e), _) => Err(format!("intent {} not recorded: {}", intent.key, e)),
+                        }
+                    }
+                    _ => signed,
+                };
+                let signing_ms = started.elapsed().as_millis() as u64;
+                // Waits while the wallet has `lane_depth` signed jobs queued
+                if signed_tx.send(SignedJob { queued, signed, signing_ms }).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let submitter = self.submitter.clone();
+        let submission_permits = self.submission_permits.clone();
+        let in_flight = self.in_flight.clone();
+        let intent_log = self.intent_log.clone();
+        let replay_guard = self.replay_guard.clone();
+        let remediation = self.remediation.clone();
+        let webhooks = self.webhooks.clone();
+        tokio::spawn(async move {
+            while let Some(SignedJob { queued, signed, signing_ms }) = to_submit.recv().await {
+                let started = Instant::now();
+                let was_signed = signed.is_ok();
+                let mut rejection = None;
+                let result = match signed {
+                    Ok(transaction) => match submission_permits.clone().acquire_owned().await {
+                        Ok(_permit) => {
+                            execution_throttle().acquire(&queued.job.wallet).await;
+                            let intent_key = queued.job.intent.as_ref().map(|intent| intent.key.as_str());
+                            // Recorded before it goes out, so a restart cannot send it twice
+                            let admitted = match &replay_guard {
+                                Some(guard) => guard.admit(&queued.job.wallet, intent_key, &transaction).map(Some).map_err(|e| e.to_string()),
+                                None => Ok(None),
+                            };
+                            match admitted {
+                                Err(e) => Err(format!("replay refused: {}", e)),
+                                Ok(admitted) => {
+                                    let result = submitter.submit(&transaction).await.map_err(|e| e.to_string());
+                                    rejection = result.as_ref().err().map(String::as_str).map(TxFailure::from_message);
+                                    if let (Some(guard), Some(record), Err(_)) = (&replay_guard, &admitted, &result) {
+                                        if let Err(e) = guard.release(&record.signature) {
+                                            warn!("⚠️ Replay cache not updated for {}: {}", record.signature, e);
+                                        }
+                                    }
+                                    result
+                                }
+                            }
+                        }
+                        Err(_) => Err("submission workers shut down".to_string()),
+                    },
+                    Err(e) => Err(format!("signing failed: {}", e)),
+                };
+                // Rejected by the RPC: the transaction never went out. Accepted ones stay
+                // signed until confirmation or reconciliation resolves them.
+                if let (Some(log), Some(intent), true, Err(e)) = (&intent_log, &queued.job.intent, was_signed, &result) {
+                    if let Err(log_error) = log.mark_failed(&intent.key, e) {
+                        warn!("⚠️ Intent {} not recorded: {}", intent.key, log_error);
+                    }
+                }
+                if let Err(e) = &result {
+                    warn!("⚠️ Job {} for {} failed: {}", queued.job.id, queued.job.wallet, e);
+                }
+                let decision = match (&remediation, &rejection) {
+                    (Some(registry), Some(failure)) => Some(registry.handle(failure, 1).await),
+                    _ => None,
+                };
+                if let (Some(webhooks), Ok(signature)) = (&webhooks, &result) {
+                    webhooks.emit(WebhookEventKind::TradeExecuted, serde_json::json!({
+                        "job_id": queued.job.id,
+                        "wallet": queued.job.wallet,
+                        "signature": signature.to_string(),
+                        "intent": queued.job.intent.as_ref().map(|intent| intent.key.as_str()),
+                    }));
+                }
+                debug!("🧵 Job {} done in {:?}", queued.job.id, queued.accepted_at.elapsed());
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                let _ = queued.reply.send(PipelineOutcome {
+                    job_id: queued.job.id,
+                    wallet: queued.job.wallet,
+                    result,
+                    remediation: decision,
+                    signing_ms,
+                    submission_ms: started.elapsed().as_millis() as u64,
+                });
+            }
+        });
+
+        intake
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::hash::Hash;
+    use solana_sdk::message::{Message, VersionedMessage};
+    use std::time::Duration;
+    use crate::trading::execution::intent_log::{IntentLogConfig, IntentStatus, SignatureStatusSource};
+
+    struct NoopSigner;
+
+    #[async_trait]
+    impl TransactionSigner for NoopSigner {
+        async fn sign(&self, _wallet: &str, transaction: VersionedTransaction) -> Result<VersionedTransaction> {
+            Ok(transaction)
+        }
+    }
+
+    /// Records submission order per job id; slow for wallet "a"
+    #[derive(Default)]
+    struct RecordingSubmitter {
+        order: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl TransactionSubmitter for RecordingSubmitter {
+        async fn submit(&self, transaction: &VersionedTransaction) -> Result<Signature> {
+            // Job id travels in the recent blockhash's first byte
+            let tag = transaction.message.recent_blockhash().to_bytes()[0];
+            if tag < 100 {
+                tokio::time::sleep(Duration::from_millis(30)).await;
+            }
+            self.order.lock().push(tag.to_string());
+            Ok(Signature::default())
+        }
+    }
+
+    /// Accepts every transaction, returning its own signature
+    struct AcceptingSubmitter;
+
+    #[async_trait]
+    impl TransactionSubmitter for AcceptingSubmitter {
+        async fn submit(&self, transaction: &VersionedTransaction) -> Result<Signature> {
+            transaction.signatures.first().copied().ok_or_else(|| anyhow!("unsigned"))
+        }
+    }
+
+    /// Signatures known to have landed
+    struct LandedSignatures(Vec<String>);
+
+    #[async_trait]
+    impl SignatureStatusSource for LandedSignatures {
+        async fn status(&self, signature: &str) -> Result<Option<std::result::Result<(), String>>> {
+            Ok(self.0.iter().any(|landed| landed == signature).then_some(Ok(())))
+        }
+    }
+
+    fn job(wallet: &str, tag: u8) -> PipelineJob {
+        let mut message = Message::new(&[], None);
+        message.recent_blockhash = Hash::new_from_array([tag; 32]);
+        PipelineJob {
+            id: tag.to_string(),
+            wallet: wallet.to_string(),
+            transaction: VersionedTransaction { signatures: Vec::new(), message: VersionedMessage::Legacy(message) },
+            intent: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wallets_run_concurrently_but_each_keeps_order() {
+        let submitter = Arc::new(RecordingSubmitter::default());
+        let pipeline = ExecutionPipeline::new(PipelineConfig::default(), Arc::new(NoopSigner), submitter.clone());
+
+        let mut receivers = Vec::new();
+        for tag in [1, 2, 3] {
+            receivers.push(pipeline.try_submit(job("a", tag)).unwrap());
+        }
+        receivers.push(pipeline.try_submit(job("b", 200)).unwrap());
+        for receiver in receivers {
+            assert!(receiver.await.unwrap().result.is_ok());
+        }
+
+        let order = submitter.order.lock().clone();
+        // Wallet b is not stuck behind wallet a's slow submissions
+        assert_eq!(order[0], "200");
+        let a: Vec<&String> = order.iter().filter(|tag| *tag != "200").collect();
+        assert_eq!(a, vec!["1", "2", "3"]);
+        assert_eq!(pipeline.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_crash_between_sign_and_confirm_recovers_without_resending() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = IntentLogConfig { path: Some(dir.path().join("intents.jsonl")), ..Default::default() };
+        let signer = Arc::new(KeypairSigner::new().with_wallet("a", Arc::new(Keypair::new())));
+        let intent = TradeIntent::new("arb-1", "a", vec!["SOL".to_string(), "USDC".to_string()], 1_000);
+
+        let signature = {
+            let log = Arc::new(IntentLog::open(config.clone()).unwrap());
+            let pipeline = ExecutionPipeline::new(PipelineConfig::default(), signer.clone(), Arc::new(AcceptingSubmitter))
+                .with_intent_log(log.clone());
+            let mut submitted = job("a", 200);
+            submitted.intent = Some(intent.clone());
+            let outcome = pipeline.try_submit(submitted).unwrap().await.unwrap();
+            let signature = outcome.result.unwrap().to_string();
+            // Sent, signature on disk, not confirmed yet
+            assert!(matches!(log.get("arb-1").unwrap().status, IntentStatus::Signed { signature: ref signed, .. } if *signed == signature));
+            signature
+            // Process dies here
+        };
+
+        let log = Arc::new(IntentLog::open(config).unwrap());
+        assert!(!log.is_clear());
+        // The restarted process cannot send the same trade again
+        let submitter = Arc::new(RecordingSubmitter::default());
+        let pipeline = ExecutionPipeline::new(PipelineConfig::default(), signer, submitter.clone())
+            .with_intent_log(log.clone());
+        let mut retried = job("a", 201);
+        retried.intent = Some(intent);
+        assert!(pipeline.try_submit(retried).unwrap().await.unwrap().result.is_err());
+        assert!(submitter.order.lock().is_empty());
+
+        let report = log.recover(&LandedSignatures(vec![signature.clone()])).await;
+        assert_eq!(report.completed, 1);
+        assert!(log.is_clear());
+        assert_eq!(log.get("arb-1").unwrap().status, IntentStatus::Completed { signature });
+    }
+
+    #[tokio::test]
+    async fn test_full_pipeline_pushes_back() {
+        let config = PipelineConfig { max_in_flight: 2, ..Default::default() };
+        let pipeline = ExecutionPipeline::new(config, Arc::new(NoopSigner), Arc::new(RecordingSubmitter::default()));
+
+        let first = pipeline.try_submit(job("a", 1)).unwrap();
+        let _second = pipeline.try_submit(job("a", 2)).unwrap();
+        assert_eq!(pipeline.available_slots(), 0);
+        assert!(pipeline.try_submit(job("b", 3)).is_err());
+
+        first.await.unwrap();
+        assert!(pipeline.available_slots() >= 1);
+    }
+}
@@ -0,0 +1,327 @@
+//! # Quote Freshness Guard
+//!
+//! Quotes fetched early in a cycle may be seconds old by the time a trade is
+//! submitted. Every quote is timestamped when fetched; before submission the
+//! guard rejects quotes older than a configurable max age and, when enabled,
+//! transparently re-quotes the same amount/route. Drift between the original
+//! and the refreshed quote is recorded so operators can see how much value is
+//! lost (or gained) between decision and execution.
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tracing::{debug, warn};
+
+use crate::apis::jupiter::JupiterQuoteResponse;
+
+/// Anything that carries a quoted output amount
+pub trait QuotedAmount {
+    /// Quoted output amount in base units, if parseable
+    fn quoted_out_amount(&self) -> Option<u64>;
+}
+
+impl QuotedAmount for JupiterQuoteResponse {
+    fn quoted_out_amount(&self) -> Option<u64> {
+        self.out_amount.parse().ok()
+    }
+}
+
+/// Quote tagged with the moment it was fetched
+#[derive(Debug, Clone)]
+pub struct TimestampedQuote<Q> {
+    pub quote: Q,
+    pub fetched_at: Instant,
+    pub fetched_at_utc: DateTime<Utc>,
+    /// How many times this quote has been refreshed
+    pub requote_count: u32,
+}
+
+impl<Q> TimestampedQuote<Q> {
+    /// Timestamp a freshly fetched quote
+    pub fn new(quote: Q) -> Self {
+        Self {
+            quote,
+            fetched_at: Instant::now(),
+            fetched_at_utc: Utc::now(),
+            requote_count: 0,
+        }
+    }
+
+    /// Age of the quote
+    pub fn age(&self) -> Duration {
+        self.fetched_at.elapsed()
+    }
+}
+
+/// Freshness guard configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteFreshnessConfig {
+    /// Quotes older than this are never submitted
+    pub max_quote_age_ms: u64,
+    /// Re-quote automatically instead of rejecting stale quotes
+    pub requote_on_stale: bool,
+    /// Reject the refreshed quote if it is worse than the original by more than this
+    pub max_adverse_drift_bps: f64,
+}
+
+impl Default for QuoteFreshnessConfig {
+    fn default() -> Self {
+        Self {
+            max_quote_age_ms: 2_000,
+            requote_on_stale: true,
+            max_adverse_drift_bps: 50.0,
+        }
+    }
+}
+
+/// Why a quote could not be used
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum QuoteFreshnessError {
+    #[error("Quote is stale ({age_ms} ms old, max {max_age_ms} ms)")]
+    Stale { age_ms: u64, max_age_ms: u64 },
+
+    #[error("Re-quote failed: {0}")]
+    RequoteFailed(String),
+
+    #[error("Re-quote drifted {drift_bps:.1} bps against us (max {max_bps:.1} bps)")]
+    ExcessiveDrift { drift_bps: f64, max_bps: f64 },
+}
+
+/// Re-quote drift statistics
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RequoteDriftStats {
+    pub fresh_submissions: u64,
+    pub stale_rejections: u64,
+    pub requotes: u64,
+    pub requote_failures: u64,
+    pub drift_rejections: u64,
+    /// Sum of drift in bps (positive = refreshed quote is better)
+    pub total_drift_bps: f64,
+    /// Worst adverse drift seen (most negative, in bps)
+    pub worst_drift_bps: f64,
+    pub total_quote_age_ms: u64,
+}
+
+impl RequoteDriftStats {
+    /// Average drift of refreshed quotes versus originals
+    pub fn average_drift_bps(&self) -> f64 {
+        if self.requotes == 0 {
+            0.0
+        } else {
+            self.total_drift_bps / self.requotes as f64
+        }
+    }
+
+    /// Average age of quotes at the time of the freshness check
+    pub fn average_quote_age_ms(&self) -> f64 {
+        let checks = self.fresh_submissions + self.stale_rejections + self.requotes + self.requote_failures;
+        if checks == 0 {
+            0.0
+        } else {
+            self.total_quote_age_ms as f64 / checks as f64
+        }
+    }
+}
+
+/// Drift between two quoted output amounts in bps (positive = `new` is better)
+pub fn quote_drift_bps(original_out: u64, new_out: u64) -> f64 {
+    if original_out == 0 {
+        return 0.0;
+    }
+    (new_out as f64 - original_out as f64) / original_out as f64 * 10_000.0
+}
+
+/// Guard ensuring only fresh quotes reach submission
+#[derive(Debug)]
+pub struct QuoteFreshnessGuard {
+    config: QuoteFreshnessConfig,
+    stats: RwLock<RequoteDriftStats>,
+}
+
+impl QuoteFreshnessGuard {
+    /// Create a new guard
+    pub fn new(config: QuoteFreshnessConfig) -> Self {
+        Self {
+            config,
+            stats: RwLock::new(RequoteDriftStats::default()),
+        }
+    }
+
+    /// Whether the quote is still within the max age
+    pub fn is_fresh<Q>(&self, quote: &TimestampedQuote<Q>) -> bool {
+        quote.age() <= Duration::from_millis(self.config.max_quote_age_ms)
+    }
+
+    /// Return a quote that is safe to submit, re-quoting if the original is stale
+    ///
+    /// `requote` must fetch a new quote for the same amount and route.
+    pub async fn ensure_fresh<Q, F, Fut, E>(
+        &self,
+        quote: TimestampedQuote<Q>,
+        requote: F,
+    ) -> Result<TimestampedQuote<Q>, QuoteFreshnessError>
+    where
+        Q: QuotedAmount,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Q, E>>,
+        E: std::fmt::Display,
+    {
+        let age_ms = quote.age().as_millis() as u64;
+
+        if self.is_fresh(&quote) {
+            let mut stats = self.stats.write().await;
+            stats.fresh_submissions += 1;
+            stats.total_quote_age_ms += age_ms;
+            return Ok(quote);
+        }
+
+        if !self.config.requote_on_stale {
+            let mut stats = self.stats.write().await;
+            stats.stale_rejections += 1;
+            stats.total_quote_age_ms += age_ms;
+            warn!("⏱️ Rejecting stale quote ({} ms old)", age_ms);
+            return Err(QuoteFreshnessError::Stale { age_ms, max_age_ms: self.config.max_quote_age_ms });
+        }
+
+        debug!("🔄 Quote is {} ms old, re-quoting before submit", age_ms);
+        let fresh = match requote().await {
+            Ok(fresh) => fresh,
+            Err(e) => {
+                let mut stats = self.stats.write().await;
+                stats.requote_failures += 1;
+                stats.total_quote_age_ms += age_ms;
+                return Err(QuoteFreshnessError::RequoteFailed(e.to_string()));
+            }
+        };
+
+        let drift_bps = match (quote.quote.quoted_out_amount(), fresh.quoted_out_amount()) {
+            (Some(original), Some(new)) => quote_drift_bps(original, new),
+            _ => 0.0,
+        };
+
+        let mut stats = self.stats.write().await;
+        stats.requotes += 1;
+        stats.total_quote_age_ms += age_ms;
+        stats.total_drift_bps += drift_bps;
+        stats.worst_drift_bps = stats.worst_drift_bps.min(drift_bps);
+
+        if -drift_bps > self.config.max_adverse_drift_bps {
+            stats.drift_rejections += 1;
+            warn!("📉 Re-quote drifted {:.1} bps against us, aborting", drift_bps);
+            return Err(QuoteFreshnessError::ExcessiveDrift {
+                drift_bps,
+                max_bps: self.config.max_adverse_drift_bps,
+            });
+        }
+
+        Ok(TimestampedQuote {
+            requote_count: quote.requote_count + 1,
+            ..TimestampedQuote::new(fresh)
+        })
+    }
+
+    /// Reject a price observed at `observed_at` that can no longer be acted on
+    ///
+    /// For prices that cannot be re-quoted in place (a scanned arbitrage
+    /// spread); the caller waits for the next sighting instead.
+    pub async fn check_age(&self, observed_at: DateTime<Utc>) -> Result<(), QuoteFreshnessError> {
+        let age_ms = (Utc::now() - observed_at).num_milliseconds().max(0) as u64;
+        let mut stats = self.stats.write().await;
+        stats.total_quote_age_ms += age_ms;
+        if age_ms > self.config.max_quote_age_ms {
+            stats.stale_rejections += 1;
+            warn!("⏱️ Rejecting stale price ({} ms old)", age_ms);
+            return Err(QuoteFreshnessError::Stale { age_ms, max_age_ms: self.config.max_quote_age_ms });
+        }
+        stats.fresh_submissions += 1;
+        Ok(())
+    }
+
+    /// Snapshot of drift statistics
+    pub async fn get_stats(&self) -> RequoteDriftStats {
+        self.stats.read().await.clone()
+    }
+}
+
+impl Default for QuoteFreshnessGuard {
+    fn default() -> Self {
+        Self::new(QuoteFreshnessConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct TestQuote(u64);
+
+    impl QuotedAmount for TestQuote {
+        fn quoted_out_amount(&self) -> Option<u64> {
+            Some(self.0)
+        }
+    }
+
+    fn stale(quote: TestQuote) -> TimestampedQuote<TestQuote> {
+        let mut timestamped = TimestampedQuote::new(quote);
+        timestamped.fetched_at = Instant::now() - Duration::from_secs(10);
+        timestamped
+    }
+
+    #[tokio::test]
+    async fn test_fresh_quote_passes_through() {
+        let guard = QuoteFreshnessGuard::default();
+        let result = guard
+            .ensure_fresh(TimestampedQuote::new(TestQuote(1_000)), || async { Err::<TestQuote, String>("unused".into()) })
+            .await
+            .unwrap();
+        assert_eq!(result.requote_count, 0);
+        assert_eq!(guard.get_stats().await.fresh_submissions, 1);
+    }
+
+    #[tokio::test]
+    async fn test_stale_quote_is_requoted_and_drift_recorded() {
+        let guard = QuoteFreshnessGuard::default();
+        let result = guard
+            .ensure_fresh(stale(TestQuote(10_000)), || async { Ok::<_, String>(TestQuote(9_990)) })
+            .await
+            .unwrap();
+        assert_eq!(result.requote_count, 1);
+        let stats = guard.get_stats().await;
+        assert_eq!(stats.requotes, 1);
+        assert!((stats.average_drift_bps() + 10.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_excessive_drift_rejected() {
+        let guard = QuoteFreshnessGuard::default();
+        let result = guard
+            .ensure_fresh(stale(TestQuote(10_000)), || async { Ok::<_, String>(TestQuote(9_000)) })
+            .await;
+        assert!(matches!(result, Err(QuoteFreshnessError::ExcessiveDrift { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_check_age_rejects_old_observations() {
+        let guard = QuoteFreshnessGuard::default();
+        assert!(guard.check_age(Utc::now()).await.is_ok());
+        let old = Utc::now() - chrono::Duration::seconds(10);
+        assert!(matches!(guard.check_age(old).await, Err(QuoteFreshnessError::Stale { .. })));
+        assert_eq!(guard.get_stats().await.stale_rejections, 1);
+    }
+
+    #[tokio::test]
+    async fn test_stale_rejected_without_requote() {
+        let guard = QuoteFreshnessGuard::new(QuoteFreshnessConfig {
+            requote_on_stale: false,
+            ..Default::default()
+        });
+        let result = guard
+            .ensure_fresh(stale(TestQuote(10_000)), || async { Ok::<_, String>(TestQuote(10_000)) })
+            .await;
+        assert!(matches!(result, Err(QuoteFreshnessError::Stale { .. })));
+    }
+}
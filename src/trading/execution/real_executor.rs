@@ -35,7 +35,8 @@ use uuid::Uuid;
 use crate::config::Config;
 use crate::types::{TradingMode, PlatformError, ComponentHealthStatus};
 use crate::apis::jupiter::JupiterQuoteResponse;
-use crate::trading::execution::TradeExecutor;
+use crate::trading::execution::{ExecutionPipeline, TimestampedQuote, TradeExecutor, TradeIntent};
+use crate::trading::execution::throttle::execution_throttle;
 
 /// Enterprise Real Trading Mode with enhanced safety
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -267,6 +268,12 @@ impl RealTradeExecutor {
         })
     }
 
+    /// Sign and send trades through a shared execution pipeline
+    pub fn with_pipeline(mut self, pipeline: std::sync::Arc<ExecutionPipeline>) -> Self {
+        self.base_executor = self.base_executor.with_pipeline(pipeline);
+        self
+    }
+
     /// Execute real trade on blockchain
     pub async fn execute_real_trade(&self, request: RealTradeRequest) -> Result<RealTradeResult, PlatformError> {
         let start_time = SystemTime::now();
@@ -286,10 +293,25 @@ impl RealTradeExecutor {
         self.validate_real_balance(&request).await?;
 
         // Get real quote from Jupiter
-        let quote = self.get_real_quote(&request).await?;
+        let quote = TimestampedQuote::new(self.get_real_quote(&request).await?);
 
         // Validate quote safety
-        self.validate_quote_safety(&quote, &request)?;
+        self.validate_quote_safety(&quote.quote, &request)?;
+
+        // Every transaction counts against the process-wide rate limits (the pipeline throttles its own)
+        if !self.base_executor.has_pipeline() {
+            execution_throttle().acquire(&request.wallet_name).await;
+        }
+
+        // Never submit a stale quote; a refreshed one is re-validated
+        let quote = self.base_executor.quote_guard()
+            .ensure_fresh(quote, || self.get_real_quote(&request))
+            .await
+            .map_err(|e| PlatformError::Trading(format!("Quote freshness check failed: {}", e)))?;
+        if quote.requote_count > 0 {
+            self.validate_quote_safety(&quote.quote, &request)?;
+        }
+        let quote = quote.quote;
 
         // Execute real swap on blockchain
         let result = self.execute_blockchain_swap(&quote, &request).await?;
@@ -456,14 +478,36 @@ impl RealTradeExecutor {
     /// Execute real swap on blockchain
     async fn execute_blockchain_swap(
         &self,
-        _quote: &JupiterQuoteResponse,
+        quote: &JupiterQuoteResponse,
         request: &RealTradeRequest,
     ) -> Result<BlockchainSwapResult, PlatformError> {
         info!("⚡ Executing REAL swap on blockchain for {}", request.trading_mode.network_name());
 
+        // Signed and sent through the shared pipeline; amounts in the request's units
+        if let Some(pipeline) = &self.base_executor.pipeline {
+            let intent = TradeIntent::new(
+                &format!("real-{}", Uuid::new_v4()),
+                &request.wallet_name,
+                vec![request.input_mint.clone(), request.output_mint.clone()],
+                quote.in_amount_u64().unwrap_or(0),
+            );
+            let result = self.base_executor
+                .submit_swap(pipeline, quote, intent, request.priority_fee)
+                .await?;
+            return Ok(BlockchainSwapResult {
+                success: result.success,
+                transaction_signature: result.transaction_signature,
+                block_height: None,
+                output_amount: result.output_amount as f64 / 1_000_000.0,
+                actual_slippage: result.slippage,
+                network_fee: result.gas_fee,
+                error_message: result.error_message,
+            });
+        }
+
         // Safety check for MainNet
         if request.trading_mode.is_production() {
-            warn!("🚨 MainNet execution temporarily disabled for safety during migration");
+            warn!("🚨 MainNet execution needs an execution pipeline - disabled for safety");
             return Ok(BlockchainSwapResult {
                 success: false,
                 transaction_signature: None,
@@ -0,0 +1,332 @@
+//! Known-error remediation registry
+//!
+//! [`TxFailure`] says what went wrong; the registry decides what to do about
+//! it. Each [`FailureKind`] maps to an automatic action (re-quote, re-sign
+//! with a fresh blockhash, top up the wallet, retry later) with an attempt
+//! budget, and hooks registered per action carry it out. Every failure is
+//! counted by signature (kind, program, error) so systemic problems, like one
+//! pool failing all afternoon, stand out from one-off noise.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use super::tx_errors::{FailureKind, TxFailure};
+
+/// Automatic response to a failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RemediationAction {
+    /// Fetch a new quote/route and rebuild the transaction
+    Requote,
+    /// Same instructions, fresh blockhash and signature
+    Resign,
+    /// Fund the wallet, then retry
+    TopUp,
+    /// Wait `delay_ms`, then retry unchanged
+    RetryLater { delay_ms: u64 },
+    /// Nothing safe to do automatically
+    Escalate,
+}
+
+impl RemediationAction {
+    /// Hooks are registered per action, whatever its parameters
+    fn hook_key(&self) -> &'static str {
+        match self {
+            RemediationAction::Requote => "requote",
+            RemediationAction::Resign => "resign",
+            RemediationAction::TopUp => "top_up",
+            RemediationAction::RetryLater { .. } => "retry_later",
+            RemediationAction::Escalate => "escalate",
+        }
+    }
+}
+
+/// Action and attempt budget for one failure kind
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemediationRule {
+    pub action: RemediationAction,
+    /// Attempts after which the failure is escalated instead
+    pub max_attempts: u32,
+}
+
+/// Rules and the window systemic errors are detected over
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemediationConfig {
+    pub rules: HashMap<FailureKind, RemediationRule>,
+    pub systemic_window_minutes: i64,
+    /// Occurrences within the window that make an error systemic
+    pub systemic_threshold: usize,
+}
+
+impl Default for RemediationConfig {
+    fn default() -> Self {
+        let rule = |action, max_attempts| RemediationRule { action, max_attempts };
+        Self {
+            rules: HashMap::from([
+                (FailureKind::Slippage, rule(RemediationAction::Requote, 2)),
+                (FailureKind::Route, rule(RemediationAction::Requote, 1)),
+                (FailureKind::ExpiredBlockhash, rule(RemediationAction::Resign, 3)),
+                (FailureKind::InsufficientRent, rule(RemediationAction::TopUp, 1)),
+                (FailureKind::AccountInUse, rule(RemediationAction::RetryLater { delay_ms: 400 }, 3)),
+            ]),
+            systemic_window_minutes: 60,
+            systemic_threshold: 10,
+        }
+    }
+}
+
+/// Carries out one action; registered per action with [`RemediationRegistry::register_hook`]
+#[async_trait]
+pub trait RemediationHook: Send + Sync {
+    async fn apply(&self, failure: &TxFailure, action: RemediationAction) -> Result<()>;
+}
+
+/// What the registry decided (and did) for one failure
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemediationDecision {
+    pub error_key: String,
+    pub action: RemediationAction,
+    pub attempt: u32,
+    /// A hook ran and succeeded; the caller may retry
+    pub applied: bool,
+    /// Hook error, when one ran and failed
+    pub hook_error: Option<String>,
+}
+
+impl RemediationDecision {
+    /// Worth another attempt: an automatic action exists and its hook (if any) did not fail
+    pub fn should_retry(&self) -> bool {
+        self.action != RemediationAction::Escalate && self.hook_error.is_none()
+    }
+}
+
+/// Occurrences of one error signature
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorOccurrence {
+    pub error_key: String,
+    pub kind: FailureKind,
+    pub count: u64,
+    /// Within the systemic window
+    pub recent: usize,
+    pub remediated: u64,
+    pub escalated: u64,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    pub last_error: String,
+}
+
+#[derive(Debug)]
+struct ErrorStats {
+    occurrence: ErrorOccurrence,
+    recent: VecDeque<DateTime<Utc>>,
+}
+
+/// Maps failures to automatic actions and counts them
+pub struct RemediationRegistry {
+    config: RemediationConfig,
+    hooks: Mutex<HashMap<&'static str, Arc<dyn RemediationHook>>>,
+    stats: Mutex<HashMap<String, ErrorStats>>,
+}
+
+impl Default for RemediationRegistry {
+    fn default() -> Self {
+        Self::new(RemediationConfig::default())
+    }
+}
+
+impl std::fmt::Debug for RemediationRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemediationRegistry")
+            .field("config", &self.config)
+            .field("hooks", &self.hooks.lock().keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Signature errors are counted under: kind, failing program and error name
+pub fn error_key(failure: &TxFailure) -> String {
+    format!(
+        "{:?}:{}:{}",
+        failure.kind,
+        failure.program.as_deref().or(failure.program_id.as_deref()).unwrap_or("-"),
+        failure.error_name.as_deref().unwrap_or("-"),
+    )
+}
+
+impl RemediationRegistry {
+    pub fn new(config: RemediationConfig) -> Self {
+        Self { config, hooks: Mutex::new(HashMap::new()), stats: Mutex::new(HashMap::new()) }
+    }
+
+    pub fn config(&self) -> &RemediationConfig {
+        &self.config
+    }
+
+    /// Run `hook` whenever `action` is chosen (parameters of `action` are ignored)
+    pub fn register_hook(&self, action: RemediationAction, hook: Arc<dyn RemediationHook>) {
+        self.hooks.lock().insert(action.hook_key(), hook);
+    }
+
+    /// Action for the `attempt`-th failure (1-based) of this kind
+    pub fn action_for(&self, kind: FailureKind, attempt: u32) -> RemediationAction {
+        match self.config.rules.get(&kind) {
+            Some(rule) if attempt <= rule.max_attempts => rule.action,
+            _ => RemediationAction::Escalate,
+        }
+    }
+
+    /// Record a failure, pick its action and run the hook registered for it
+    pub async fn handle(&self, failure: &TxFailure, attempt: u32) -> RemediationDecision {
+        let decision = self.record_at(failure, attempt, Utc::now());
+        let hook = self.hooks.lock().get(decision.action.hook_key()).cloned();
+        let Some(hook) = hook else { return decision };
+
+        match hook.apply(failure, decision.action).await {
+            Ok(()) => {
+                info!("🩹 {} remediated with {:?} (attempt {})", decision.error_key, decision.action, attempt);
+                RemediationDecision { applied: true, ..decision }
+            }
+            Err(e) => {
+                warn!("⚠️ Remediation {:?} for {} failed: {}", decision.action, decision.error_key, e);
+                RemediationDecision { hook_error: Some(e.to_string()), ..decision }
+            }
+        }
+    }
+
+    /// Count a failure and decide its action without running hooks
+    pub fn record_at(&self, failure: &TxFailure, attempt: u32, now: DateTime<Utc>) -> RemediationDecision {
+        let error_key = error_key(failure);
+        let action = self.action_for(failure.kind, attempt);
+        let cutoff = now - Duration::minutes(self.config.systemic_window_minutes);
+
+        let mut stats = self.stats.lock();
+        let entry = stats.entry(error_key.clone()).or_insert_with(|| ErrorStats {
+            occurrence: ErrorOccurrence {
+                error_key: error_key.clone(),
+                kind: failure.kind,
+                count: 0,
+                recent: 0,
+                remediated: 0,
+                escalated: 0,
+                first_seen: now,
+                last_seen: now,
+                last_error: String::new(),
+            },
+            recent: VecDeque::new(),
+        });
+        entry.recent.push_back(now);
+        while entry.recent.front().is_some_and(|seen| *seen <= cutoff) {
+            entry.recent.pop_front();
+        }
+        let occurrence = &mut entry.occurrence;
+        occurrence.count += 1;
+        occurrence.recent = entry.recent.len();
+        occurrence.last_seen = now;
+        occurrence.last_error = failure.summary();
+        if action == RemediationAction::Escalate {
+            occurrence.escalated += 1;
+        } else {
+            occurrence.remediated += 1;
+        }
+        if occurrence.recent == self.config.systemic_threshold {
+            warn!("🚨 Systemic failure: {} seen {} times in {} minutes", error_key, occurrence.recent, self.config.systemic_window_minutes);
+        }
+
+        RemediationDecision { error_key, action, attempt, applied: false, hook_error: None }
+    }
+
+    /// All error signatures, most frequent first
+    pub fn occurrences(&self) -> Vec<ErrorOccurrence> {
+        let mut occurrences: Vec<ErrorOccurrence> = self.stats.lock().values().map(|stats| stats.occurrence.clone()).collect();
+        occurrences.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.error_key.cmp(&b.error_key)));
+        occurrences
+    }
+
+    /// Signatures at or over the systemic threshold within the window
+    pub fn systemic(&self) -> Vec<ErrorOccurrence> {
+        self.systemic_at(Utc::now())
+    }
+
+    pub fn systemic_at(&self, now: DateTime<Utc>) -> Vec<ErrorOccurrence> {
+        let cutoff = now - Duration::minutes(self.config.systemic_window_minutes);
+        self.stats
+            .lock()
+            .values()
+            .filter_map(|stats| {
+                let recent = stats.recent.iter().filter(|seen| **seen > cutoff).count();
+                (recent >= self.config.systemic_threshold).then(|| ErrorOccurrence { recent, ..stats.occurrence.clone() })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Default)]
+    struct CountingHook {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl RemediationHook for CountingHook {
+        async fn apply(&self, _failure: &TxFailure, _action: RemediationAction) -> Result<()> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_actions_by_kind_with_attempt_budget() {
+        let registry = RemediationRegistry::default();
+        let hook = Arc::new(CountingHook::default());
+        registry.register_hook(RemediationAction::Resign, hook.clone());
+
+        let expired = TxFailure::from_message("BlockhashNotFound");
+        let decision = registry.handle(&expired, 1).await;
+        assert_eq!(decision.action, RemediationAction::Resign);
+        assert!(decision.applied && decision.should_retry());
+        assert_eq!(hook.calls.load(Ordering::SeqCst), 1);
+
+        // Out of attempts: escalate, no hook
+        let decision = registry.handle(&expired, 4).await;
+        assert_eq!(decision.action, RemediationAction::Escalate);
+        assert!(!decision.should_retry());
+        assert_eq!(hook.calls.load(Ordering::SeqCst), 1);
+
+        // No hook registered: the caller carries out the action
+        let decision = registry.handle(&TxFailure::from_message("AccountInUse"), 1).await;
+        assert_eq!(decision.action, RemediationAction::RetryLater { delay_ms: 400 });
+        assert!(!decision.applied && decision.should_retry());
+
+        let occurrences = registry.occurrences();
+        assert_eq!((occurrences[0].count, occurrences[0].remediated, occurrences[0].escalated), (2, 1, 1));
+    }
+
+    #[test]
+    fn test_systemic_errors_detected_within_window() {
+        let registry = RemediationRegistry::new(RemediationConfig { systemic_threshold: 3, ..Default::default() });
+        let slippage = TxFailure::from_message("slippage tolerance exceeded");
+        let now = Utc::now();
+
+        registry.record_at(&slippage, 1, now - Duration::hours(2));
+        registry.record_at(&slippage, 1, now - Duration::minutes(10));
+        registry.record_at(&slippage, 1, now - Duration::minutes(5));
+        assert!(registry.systemic_at(now).is_empty());
+
+        registry.record_at(&slippage, 1, now);
+        let systemic = registry.systemic_at(now);
+        assert_eq!(systemic.len(), 1);
+        assert_eq!((systemic[0].count, systemic[0].recent), (4, 3));
+        assert_eq!(systemic[0].error_key, "Slippage:-:-");
+    }
+}
@@ -0,0 +1,294 @@
+//! Replay protection for transaction submission
+//!
+//! The intent log settles trades a crashed run left behind, but it works at
+//! the level of logical trades. Nothing stops a restarted process (or a retry
+//! loop) from putting the *same* signed transaction, or a re-signed copy of
+//! the same intent, on the wire again while the first one can still land.
+//!
+//! [`ReplayGuard`] keeps a persisted cache of recent submissions: signature,
+//! blockhash, wallet and intent key. Every submission is checked against it
+//! and recorded (flushed to disk) before it goes out. Entries expire once
+//! their blockhash can no longer land, so the cache stays small.
+
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use solana_sdk::transaction::VersionedTransaction;
+use tracing::{info, warn};
+
+/// Replay guard policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayGuardConfig {
+    /// Append-only cache file (in-memory only when unset)
+    pub path: Option<PathBuf>,
+    /// How long a submission can still land (blockhash validity plus margin)
+    pub window_secs: i64,
+}
+
+impl Default for ReplayGuardConfig {
+    fn default() -> Self {
+        Self {
+            path: None,
+            window_secs: 150,
+        }
+    }
+}
+
+/// One transaction that went (or was about to go) out
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubmissionRecord {
+    pub signature: String,
+    pub blockhash: String,
+    pub wallet: String,
+    pub intent_key: Option<String>,
+    pub submitted_at: DateTime<Utc>,
+    /// The RPC rejected it, so it never reached the network
+    #[serde(default)]
+    pub released: bool,
+}
+
+/// Why a submission was refused
+#[derive(Debug, thiserror::Error)]
+pub enum ReplayError {
+    #[error("transaction {signature} already submitted at {submitted_at}")]
+    DuplicateSignature { signature: String, submitted_at: DateTime<Utc> },
+    #[error("intent {key} already submitted as {signature} at {submitted_at}, which can still land")]
+    DuplicateIntent { key: String, signature: String, submitted_at: DateTime<Utc> },
+    #[error("transaction is not signed")]
+    Unsigned,
+    #[error("replay cache write failed: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("replay cache encoding failed: {0}")]
+    Encoding(#[from] serde_json::Error),
+}
+
+/// Persisted cache of recent submissions
+#[derive(Debug)]
+pub struct ReplayGuard {
+    config: ReplayGuardConfig,
+    /// By signature; the latest record per signature wins
+    records: Mutex<HashMap<String, SubmissionRecord>>,
+}
+
+impl ReplayGuard {
+    /// Open the cache, dropping expired entries and compacting the file
+    pub fn open(config: ReplayGuardConfig) -> Result<Self> {
+        let mut records = match &config.path {
+            Some(path) => Self::replay(path)?,
+            None => HashMap::new(),
+        };
+        let cutoff = Utc::now() - Duration::seconds(config.window_secs);
+        records.retain(|_, record| record.submitted_at > cutoff);
+        let live = records.values().filter(|record| !record.released).count();
+        if live > 0 {
+            info!("🛡️ Replay guard restored {} submissions that may still land", live);
+        }
+        let guard = Self { config, records: Mutex::new(records) };
+        guard.compact()?;
+        Ok(guard)
+    }
+
+    fn replay(path: &Path) -> Result<HashMap<String, SubmissionRecord>> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut records = HashMap::new();
+        for line in content.lines().filter(|line| !line.trim().is_empty()) {
+            // A torn final line from a crash mid-write is skipped
+            match serde_json::from_str::<SubmissionRecord>(line) {
+                Ok(record) => {
+                    records.insert(record.signature.clone(), record);
+                }
+                Err(e) => warn!("⚠️ Skipping unreadable replay cache record: {}", e),
+            }
+        }
+        Ok(records)
+    }
+
+    /// Append one record and flush it to disk
+    fn append(&self, record: &SubmissionRecord) -> Result<(), ReplayError> {
+        let Some(path) = &self.config.path else { return Ok(()) };
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+        file.write_all(&line)?;
+        file.sync_data()?;
+        Ok(())
+    }
+
+    fn compact(&self) -> Result<()> {
+        let Some(path) = &self.config.path else { return Ok(()) };
+        let records = self.records.lock();
+        let mut content = String::new();
+        for record in records.values() {
+            content.push_str(&serde_json::to_string(record)?);
+            content.push('\n');
+        }
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let temp_file = path.with_extension("tmp");
+        std::fs::write(&temp_file, content)?;
+        std::fs::rename(&temp_file, path)?;
+        Ok(())
+    }
+
+    /// Refuse a signature already sent, or another transaction for an intent still in flight
+    pub fn check(&self, signature: &str, intent_key: Option<&str>) -> Result<(), ReplayError> {
+        Self::check_in(&self.records.lock(), signature, intent_key, self.cutoff())
+    }
+
+    fn check_in(
+        records: &HashMap<String, SubmissionRecord>,
+        signature: &str,
+        intent_key: Option<&str>,
+        cutoff: DateTime<Utc>,
+    ) -> Result<(), ReplayError> {
+        let live = |record: &&SubmissionRecord| !record.released && record.submitted_at > cutoff;
+        if let Some(existing) = records.get(signature).filter(live) {
+            return Err(ReplayError::DuplicateSignature {
+                signature: signature.to_string(),
+                submitted_at: existing.submitted_at,
+            });
+        }
+        if let Some(key) = intent_key {
+            if let Some(existing) = records.values().filter(live).find(|record| record.intent_key.as_deref() == Some(key)) {
+                return Err(ReplayError::DuplicateIntent {
+                    key: key.to_string(),
+                    signature: existing.signature.clone(),
+                    submitted_at: existing.submitted_at,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check a signed transaction and record it before it is submitted
+    pub fn admit(&self, wallet: &str, intent_key: Option<&str>, transaction: &VersionedTransaction) -> Result<SubmissionRecord, ReplayError> {
+        let signature = transaction.signatures.first().ok_or(ReplayError::Unsigned)?.to_string();
+        let mut records = self.records.lock();
+        let cutoff = self.cutoff();
+        Self::check_in(&records, &signature, intent_key, cutoff)?;
+
+        let record = SubmissionRecord {
+            signature: signature.clone(),
+            blockhash: transaction.message.recent_blockhash().to_string(),
+            wallet: wallet.to_string(),
+            intent_key: intent_key.map(str::to_string),
+            submitted_at: Utc::now(),
+            released: false,
+        };
+        self.append(&record)?;
+        records.retain(|_, record| record.submitted_at > cutoff);
+        records.insert(signature, record.clone());
+        Ok(record)
+    }
+
+    /// The RPC rejected `signature`: it never went out, so its intent may be retried
+    pub fn release(&self, signature: &str) -> Result<(), ReplayError> {
+        let mut records = self.records.lock();
+        let Some(record) = records.get_mut(signature) else { return Ok(()) };
+        let mut released = record.clone();
+        released.released = true;
+        self.append(&released)?;
+        *record = released;
+        Ok(())
+    }
+
+    /// Submissions that may still land
+    pub fn in_flight(&self) -> Vec<SubmissionRecord> {
+        let cutoff = self.cutoff();
+        self.records.lock().values().filter(|record| !record.released && record.submitted_at > cutoff).cloned().collect()
+    }
+
+    fn cutoff(&self) -> DateTime<Utc> {
+        Utc::now() - Duration::seconds(self.config.window_secs)
+    }
+}
+
+static SHARED_GUARD: OnceLock<Arc<ReplayGuard>> = OnceLock::new();
+
+/// Make `guard` the one checked by submission paths that bypass the pipeline
+pub fn install_replay_guard(guard: Arc<ReplayGuard>) {
+    if SHARED_GUARD.set(guard).is_err() {
+        warn!("⚠️ Replay guard already installed, keeping the first one");
+    }
+}
+
+/// The guard opened at startup, if any
+pub fn shared_replay_guard() -> Option<Arc<ReplayGuard>> {
+    SHARED_GUARD.get().cloned()
+}
+
+/// Check and record `transaction` against the startup guard (no-op when none is installed)
+pub fn admit_shared(wallet: &str, intent_key: Option<&str>, transaction: &VersionedTransaction) -> Result<(), ReplayError> {
+    match SHARED_GUARD.get() {
+        Some(guard) => guard.admit(wallet, intent_key, transaction).map(|_| ()),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::hash::Hash;
+    use solana_sdk::message::{Message, VersionedMessage};
+    use solana_sdk::signature::Signature;
+
+    fn signed(tag: u8) -> VersionedTransaction {
+        let mut message = Message::new(&[], None);
+        message.recent_blockhash = Hash::new_from_array([tag; 32]);
+        VersionedTransaction {
+            signatures: vec![Signature::from([tag; 64])],
+            message: VersionedMessage::Legacy(message),
+        }
+    }
+
+    #[test]
+    fn test_duplicates_refused_until_released() {
+        let guard = ReplayGuard::open(ReplayGuardConfig::default()).unwrap();
+        guard.admit("main", Some("arb-1"), &signed(1)).unwrap();
+
+        assert!(matches!(guard.admit("main", None, &signed(1)), Err(ReplayError::DuplicateSignature { .. })));
+        // Re-signed with a fresh blockhash: the first copy can still land
+        assert!(matches!(guard.admit("main", Some("arb-1"), &signed(2)), Err(ReplayError::DuplicateIntent { .. })));
+        assert!(matches!(guard.admit("main", None, &VersionedTransaction::default()), Err(ReplayError::Unsigned)));
+
+        // Rejected by the RPC: the intent may go out again
+        guard.release(&Signature::from([1; 64]).to_string()).unwrap();
+        guard.admit("main", Some("arb-1"), &signed(2)).unwrap();
+        assert_eq!(guard.in_flight().len(), 1);
+    }
+
+    #[test]
+    fn test_cache_survives_restart_and_expires() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ReplayGuardConfig { path: Some(dir.path().join("submissions.jsonl")), ..Default::default() };
+        {
+            let guard = ReplayGuard::open(config.clone()).unwrap();
+            guard.admit("main", Some("arb-1"), &signed(1)).unwrap();
+            // Process dies here
+        }
+
+        let guard = ReplayGuard::open(config.clone()).unwrap();
+        assert!(guard.check(&Signature::from([1; 64]).to_string(), None).is_err());
+        assert!(guard.check("other", Some("arb-1")).is_err());
+
+        // Past the window the blockhash has expired and nothing is held back
+        let expired = ReplayGuard::open(ReplayGuardConfig { window_secs: 0, ..config }).unwrap();
+        assert!(expired.in_flight().is_empty());
+        assert!(expired.check("other", Some("arb-1")).is_ok());
+    }
+}
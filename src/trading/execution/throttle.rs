@@ -0,0 +1,206 @@
+//! Global execution throttle
+//!
+//! Every executor (trade executor, real trading engine, submission pipeline)
+//! acquires a token before sending a transaction, so the process as a whole
+//! stays under a transactions-per-second and a transactions-per-minute limit
+//! regardless of how many bots are running. Both limits are token buckets:
+//! a send needs one token from each, and a caller waits until both have one.
+//!
+//! Time spent waiting is recorded per bot so throttling shows up in metrics
+//! instead of as unexplained latency.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
+use tracing::debug;
+
+/// Transaction rate limits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThrottleConfig {
+    pub max_per_second: f64,
+    pub max_per_minute: f64,
+    /// Transactions that may go out back-to-back before the per-second rate applies
+    pub burst: f64,
+}
+
+impl Default for ThrottleConfig {
+    fn default() -> Self {
+        Self {
+            max_per_second: 10.0,
+            max_per_minute: 300.0,
+            burst: 10.0,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    capacity: f64,
+    refill_per_sec: f64,
+}
+
+impl Bucket {
+    fn new(capacity: f64, refill_per_sec: f64) -> Self {
+        Self { tokens: capacity, capacity, refill_per_sec }
+    }
+
+    fn refill(&mut self, elapsed_secs: f64) {
+        self.tokens = (self.tokens + elapsed_secs * self.refill_per_sec).min(self.capacity);
+    }
+
+    /// Wait until one token is available
+    fn wait_for_token(&self) -> Duration {
+        if self.tokens >= 1.0 || self.refill_per_sec <= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((1.0 - self.tokens) / self.refill_per_sec)
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ThrottleState {
+    per_second: Bucket,
+    per_minute: Bucket,
+    refilled_at: Instant,
+    stats: ThrottleStats,
+}
+
+/// Throttle metrics
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThrottleStats {
+    pub acquired: u64,
+    /// Acquisitions that had to wait
+    pub throttled: u64,
+    pub total_delay_ms: u64,
+    pub max_delay_ms: u64,
+    /// bot -> total delay (ms)
+    pub delay_by_bot_ms: HashMap<String, u64>,
+}
+
+impl ThrottleStats {
+    pub fn avg_delay_ms(&self) -> f64 {
+        if self.throttled == 0 {
+            0.0
+        } else {
+            self.total_delay_ms as f64 / self.throttled as f64
+        }
+    }
+}
+
+/// Process-wide transaction rate controller
+#[derive(Debug)]
+pub struct ExecutionThrottle {
+    state: Mutex<ThrottleState>,
+}
+
+impl Default for ExecutionThrottle {
+    fn default() -> Self {
+        Self::new(ThrottleConfig::default())
+    }
+}
+
+impl ExecutionThrottle {
+    pub fn new(config: ThrottleConfig) -> Self {
+        Self {
+            state: Mutex::new(ThrottleState {
+                per_second: Bucket::new(config.burst.max(1.0), config.max_per_second),
+                per_minute: Bucket::new(config.max_per_minute.max(1.0), config.max_per_minute / 60.0),
+                refilled_at: Instant::now(),
+                stats: ThrottleStats::default(),
+            }),
+        }
+    }
+
+    /// Replace the limits (tokens already earned are kept up to the new capacity)
+    pub fn reconfigure(&self, config: ThrottleConfig) {
+        let mut state = self.state.lock();
+        let (second_tokens, minute_tokens) = (state.per_second.tokens, state.per_minute.tokens);
+        state.per_second = Bucket::new(config.burst.max(1.0), config.max_per_second);
+        state.per_minute = Bucket::new(config.max_per_minute.max(1.0), config.max_per_minute / 60.0);
+        state.per_second.tokens = second_tokens.min(state.per_second.capacity);
+        state.per_minute.tokens = minute_tokens.min(state.per_minute.capacity);
+    }
+
+    /// Take a token for one transaction of `bot`, waiting while the limits are exhausted
+    ///
+    /// Returns how long the caller was held back.
+    pub async fn acquire(&self, bot: &str) -> Duration {
+        let started = Instant::now();
+        loop {
+            let wait = {
+                let mut state = self.state.lock();
+                let now = Instant::now();
+                let elapsed = now.duration_since(state.refilled_at).as_secs_f64();
+                state.refilled_at = now;
+                state.per_second.refill(elapsed);
+                state.per_minute.refill(elapsed);
+
+                let wait = state.per_second.wait_for_token().max(state.per_minute.wait_for_token());
+                if wait.is_zero() {
+                    state.per_second.tokens -= 1.0;
+                    state.per_minute.tokens -= 1.0;
+                    let delay = started.elapsed();
+                    let stats = &mut state.stats;
+                    stats.acquired += 1;
+                    if !delay.is_zero() {
+                        let delay_ms = delay.as_millis() as u64;
+                        stats.throttled += 1;
+                        stats.total_delay_ms += delay_ms;
+                        stats.max_delay_ms = stats.max_delay_ms.max(delay_ms);
+                        *stats.delay_by_bot_ms.entry(bot.to_string()).or_default() += delay_ms;
+                        debug!("🚦 {} throttled for {:?}", bot, delay);
+                    }
+                    return delay;
+                }
+                wait
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    pub fn stats(&self) -> ThrottleStats {
+        self.state.lock().stats.clone()
+    }
+}
+
+/// Throttle shared by every executor in the process
+pub fn execution_throttle() -> &'static ExecutionThrottle {
+    static THROTTLE: OnceLock<ExecutionThrottle> = OnceLock::new();
+    THROTTLE.get_or_init(ExecutionThrottle::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_burst_then_per_second_rate() {
+        let throttle = ExecutionThrottle::new(ThrottleConfig { max_per_second: 20.0, max_per_minute: 6_000.0, burst: 2.0 });
+        assert!(throttle.acquire("sniper").await.is_zero());
+        assert!(throttle.acquire("sniper").await.is_zero());
+        // Third send waits ~50ms for a per-second token
+        let delay = throttle.acquire("arbitrage").await;
+        assert!(delay >= Duration::from_millis(30) && delay < Duration::from_secs(1));
+
+        let stats = throttle.stats();
+        assert_eq!((stats.acquired, stats.throttled), (3, 1));
+        assert!(stats.delay_by_bot_ms.contains_key("arbitrage"));
+        assert!(!stats.delay_by_bot_ms.contains_key("sniper"));
+    }
+
+    #[tokio::test]
+    async fn test_per_minute_limit_applies_across_bots() {
+        let throttle = ExecutionThrottle::new(ThrottleConfig { max_per_second: 1_000.0, max_per_minute: 3.0, burst: 100.0 });
+        for bot in ["a", "b", "c"] {
+            assert!(throttle.acquire(bot).await.is_zero());
+        }
+        // Minute bucket empty: the next token is 20s away
+        assert!(tokio::time::timeout(Duration::from_millis(100), throttle.acquire("d")).await.is_err());
+    }
+}
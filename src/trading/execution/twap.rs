@@ -0,0 +1,325 @@
+//! # TWAP Execution
+//!
+//! Portfolio rebalances and treasury moves are not time-critical, but they are
+//! large: sent at once they pay the full price impact and show the whole size
+//! to anyone watching. A TWAP spreads the order over a time window in child
+//! orders of randomized size and timing, each capped (iceberg style) so no
+//! single child reveals the parent. Every child is preceded by a price check
+//! against the arrival price, and the order stops when the market has moved
+//! against it by more than the configured limit or a child fills too far from
+//! the price it was sent at.
+
+use std::future::Future;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+/// TWAP configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TwapConfig {
+    /// Window the order is spread over
+    pub duration_secs: u64,
+    /// Number of child orders
+    pub slices: usize,
+    /// Child sizes vary by up to this fraction around the even split
+    pub size_jitter: f64,
+    /// Child start times vary by up to this fraction of the slot length
+    pub time_jitter: f64,
+    /// Iceberg cap: no child is larger than this (more children are used instead)
+    pub max_child_size: Option<f64>,
+    /// Stop when the price has moved against the order by more than this since arrival
+    pub max_adverse_deviation_bps: f64,
+    /// Stop when a child fills further than this from its pre-trade price
+    pub max_child_slippage_bps: f64,
+}
+
+impl Default for TwapConfig {
+    fn default() -> Self {
+        Self {
+            duration_secs: 600,
+            slices: 10,
+            size_jitter: 0.3,
+            time_jitter: 0.5,
+            max_child_size: None,
+            max_adverse_deviation_bps: 150.0,
+            max_child_slippage_bps: 100.0,
+        }
+    }
+}
+
+/// Direction of the parent order; decides which price moves are adverse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TwapSide {
+    Buy,
+    Sell,
+}
+
+impl TwapSide {
+    /// Price move from `reference` to `price` in bps, positive when it hurts the order
+    pub fn adverse_bps(&self, reference: f64, price: f64) -> f64 {
+        if reference <= 0.0 {
+            return 0.0;
+        }
+        let move_bps = (price - reference) / reference * 10_000.0;
+        match self {
+            TwapSide::Buy => move_bps,
+            TwapSide::Sell => -move_bps,
+        }
+    }
+}
+
+/// One child order of the schedule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TwapSlice {
+    /// Send time, from the start of the order
+    pub offset: Duration,
+    pub size: f64,
+}
+
+/// Why a TWAP stopped before filling its whole target
+#[derive(Debug, Clone, PartialEq, thiserror::Error, Serialize, Deserialize)]
+pub enum TwapAbortReason {
+    #[error("Price moved {deviation_bps:.1} bps against the order (limit {limit_bps:.1} bps)")]
+    AdverseDeviation { deviation_bps: f64, limit_bps: f64 },
+
+    #[error("Child filled {slippage_bps:.1} bps from its pre-trade price (limit {limit_bps:.1} bps)")]
+    ChildSlippage { slippage_bps: f64, limit_bps: f64 },
+
+    #[error("Price check failed: {0}")]
+    PriceUnavailable(String),
+
+    #[error("Child submission failed: {0}")]
+    SubmissionFailed(String),
+}
+
+/// Fill of one child order
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TwapFill {
+    pub filled: f64,
+    pub price: f64,
+}
+
+/// Outcome of one TWAP order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TwapReport {
+    pub side: TwapSide,
+    pub target: f64,
+    pub filled: f64,
+    pub arrival_price: f64,
+    /// Size-weighted fill price (0 when nothing filled)
+    pub average_price: f64,
+    pub slices_planned: usize,
+    pub slices_submitted: usize,
+    /// Adverse move versus arrival observed before each child, in order
+    pub deviations_bps: Vec<f64>,
+    pub abort_reason: Option<TwapAbortReason>,
+}
+
+impl TwapReport {
+    pub fn fill_rate(&self) -> f64 {
+        if self.target <= 0.0 {
+            0.0
+        } else {
+            self.filled / self.target
+        }
+    }
+
+    /// Cost of the filled part versus executing at the arrival price, in bps
+    pub fn implementation_shortfall_bps(&self) -> f64 {
+        if self.filled <= 0.0 {
+            0.0
+        } else {
+            self.side.adverse_bps(self.arrival_price, self.average_price)
+        }
+    }
+}
+
+/// Split `total` into randomized children spread over the window
+///
+/// Sizes vary by `size_jitter` around the even split and always sum to
+/// `total`; children over `max_child_size` are split further. Each child is
+/// placed at a random point within its own slot, so order is preserved.
+pub fn plan_twap(total: f64, config: &TwapConfig, rng: &mut fastrand::Rng) -> Vec<TwapSlice> {
+    if total <= 0.0 {
+        return Vec::new();
+    }
+    let jitter = config.size_jitter.clamp(0.0, 0.9);
+    let mut count = config.slices.max(1);
+    if let Some(max_child) = config.max_child_size.filter(|max| *max > 0.0) {
+        // Largest possible child: one at maximum weight, all others at minimum
+        let worst_case_count = (total * (1.0 + jitter) / ((1.0 - jitter) * max_child)).ceil() as usize;
+        count = count.max(worst_case_count);
+    }
+
+    let weights: Vec<f64> = (0..count).map(|_| 1.0 + jitter * (rng.f64() * 2.0 - 1.0)).collect();
+    let weight_sum: f64 = weights.iter().sum();
+    let mut sizes: Vec<f64> = weights.iter().map(|weight| total * weight / weight_sum).collect();
+    let planned: f64 = sizes[..count - 1].iter().sum();
+    sizes[count - 1] = total - planned;
+
+    let slot = Duration::from_secs(config.duration_secs).as_secs_f64() / count as f64;
+    let time_jitter = config.time_jitter.clamp(0.0, 1.0);
+    sizes
+        .into_iter()
+        .enumerate()
+        .map(|(index, size)| TwapSlice {
+            offset: Duration::from_secs_f64(slot * (index as f64 + time_jitter * rng.f64())),
+            size,
+        })
+        .collect()
+}
+
+/// Runs TWAP orders and keeps their reports
+#[derive(Debug)]
+pub struct TwapExecutor {
+    config: TwapConfig,
+    history: RwLock<Vec<TwapReport>>,
+}
+
+impl TwapExecutor {
+    pub fn new(config: TwapConfig) -> Self {
+        Self { config, history: RwLock::new(Vec::new()) }
+    }
+
+    pub fn config(&self) -> &TwapConfig {
+        &self.config
+    }
+
+    /// Work a `total` order from `arrival_price` over the configured window
+    ///
+    /// `price` returns the current market price before each child; `submit`
+    /// sends a child of the given size and returns its fill.
+    pub async fn execute<P, PFut, S, SFut, E>(
+        &self,
+        side: TwapSide,
+        total: f64,
+        arrival_price: f64,
+        mut price: P,
+        mut submit: S,
+    ) -> TwapReport
+    where
+        P: FnMut() -> PFut,
+        PFut: Future<Output = Result<f64, E>>,
+        S: FnMut(f64) -> SFut,
+        SFut: Future<Output = Result<TwapFill, E>>,
+        E: std::fmt::Display,
+    {
+        let schedule = plan_twap(total, &self.config, &mut fastrand::Rng::new());
+        let started = tokio::time::Instant::now();
+        let mut report = TwapReport {
+            side,
+            target: total,
+            filled: 0.0,
+            arrival_price,
+            average_price: 0.0,
+            slices_planned: schedule.len(),
+            slices_submitted: 0,
+            deviations_bps: Vec::new(),
+            abort_reason: None,
+        };
+        let mut notional = 0.0;
+
+        for (index, slice) in schedule.iter().enumerate() {
+            tokio::time::sleep_until(started + slice.offset).await;
+
+            let current = match price().await {
+                Ok(current) => current,
+                Err(e) => {
+                    report.abort_reason = Some(TwapAbortReason::PriceUnavailable(e.to_string()));
+                    break;
+                }
+            };
+            let deviation_bps = side.adverse_bps(arrival_price, current);
+            report.deviations_bps.push(deviation_bps);
+            if deviation_bps > self.config.max_adverse_deviation_bps {
+                report.abort_reason = Some(TwapAbortReason::AdverseDeviation {
+                    deviation_bps,
+                    limit_bps: self.config.max_adverse_deviation_bps,
+                });
+                break;
+            }
+
+            report.slices_submitted += 1;
+            let fill = match submit(slice.size).await {
+                Ok(fill) => fill,
+                Err(e) => {
+                    warn!("⏱️ TWAP child {} ({:.4}) failed: {}", index + 1, slice.size, e);
+                    report.abort_reason = Some(TwapAbortReason::SubmissionFailed(e.to_string()));
+                    break;
+                }
+            };
+            let filled = fill.filled.max(0.0);
+            report.filled += filled;
+            notional += filled * fill.price;
+            report.average_price = if report.filled > 0.0 { notional / report.filled } else { 0.0 };
+
+            let slippage_bps = side.adverse_bps(current, fill.price);
+            if filled > 0.0 && slippage_bps > self.config.max_child_slippage_bps {
+                report.abort_reason = Some(TwapAbortReason::ChildSlippage {
+                    slippage_bps,
+                    limit_bps: self.config.max_child_slippage_bps,
+                });
+                break;
+            }
+        }
+
+        match &report.abort_reason {
+            Some(reason) => warn!("⏱️ TWAP {:?} stopped at {:.4}/{:.4} ({}/{} children): {}",
+                                 side, report.filled, report.target, report.slices_submitted, report.slices_planned, reason),
+            None => info!("⏱️ TWAP {:?} filled {:.4} at {:.6} ({:+.1} bps vs arrival)",
+                          side, report.filled, report.average_price, report.implementation_shortfall_bps()),
+        }
+        self.history.write().await.push(report.clone());
+        report
+    }
+
+    /// Reports of finished orders, oldest first
+    pub async fn history(&self) -> Vec<TwapReport> {
+        self.history.read().await.clone()
+    }
+}
+
+impl Default for TwapExecutor {
+    fn default() -> Self {
+        Self::new(TwapConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_sums_to_total_and_respects_iceberg_cap() {
+        let config = TwapConfig { slices: 5, max_child_size: Some(15.0), ..Default::default() };
+        let schedule = plan_twap(100.0, &config, &mut fastrand::Rng::with_seed(7));
+
+        assert!(schedule.len() > 5);
+        assert!((schedule.iter().map(|slice| slice.size).sum::<f64>() - 100.0).abs() < 1e-9);
+        assert!(schedule.iter().all(|slice| slice.size <= 15.0 && slice.size > 0.0));
+        // Children stay in order and inside the window
+        assert!(schedule.windows(2).all(|pair| pair[0].offset <= pair[1].offset));
+        assert!(schedule.last().unwrap().offset <= Duration::from_secs(config.duration_secs));
+    }
+
+    #[tokio::test]
+    async fn test_twap_aborts_on_adverse_move() {
+        let executor = TwapExecutor::new(TwapConfig { duration_secs: 0, slices: 4, size_jitter: 0.0, ..Default::default() });
+        // Buying: price drifts up 1%, then 2% (over the 150 bps limit)
+        let mut prices = vec![100.0, 101.0, 102.0, 100.0].into_iter();
+        let report = executor
+            .execute(TwapSide::Buy, 40.0, 100.0, || {
+                let price = prices.next().unwrap_or(100.0);
+                async move { Ok::<_, String>(price) }
+            }, |size| async move { Ok::<_, String>(TwapFill { filled: size, price: 100.5 }) })
+            .await;
+
+        assert_eq!(report.slices_submitted, 2);
+        assert!((report.filled - 20.0).abs() < 1e-9);
+        assert!((report.implementation_shortfall_bps() - 50.0).abs() < 1e-6);
+        assert!(matches!(report.abort_reason, Some(TwapAbortReason::AdverseDeviation { .. })));
+        assert_eq!(executor.history().await.len(), 1);
+    }
+}
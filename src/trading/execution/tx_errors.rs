@@ -0,0 +1,323 @@
+//! Failed transaction diagnostics
+//!
+//! An on-chain failure arrives as `{"InstructionError":[2,{"Custom":6001}]}`,
+//! which tells an operator nothing. [`decode_failure`] reads the transaction
+//! meta instead: the failing instruction, the program that actually raised the
+//! error (the innermost one, which for a Jupiter route is usually the pool
+//! program, not Jupiter), the error code mapped to its name for known DEXes
+//! or taken from the Anchor error log, and a suggested remediation. The tail
+//! of the program logs is kept for post-mortems.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::analytics::trade_indexer::KNOWN_PROGRAMS;
+
+/// Log lines kept per failure (the end of the log, where the error is)
+const MAX_LOG_LINES: usize = 50;
+
+const JUPITER_V6: &str = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4";
+const RAYDIUM_AMM_V4: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+const ORCA_WHIRLPOOL: &str = "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc";
+const PUMP_FUN: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+const SPL_TOKEN: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+/// Custom error codes of known programs: (program, code, name, kind)
+const PROGRAM_ERRORS: &[(&str, u32, &str, FailureKind)] = &[
+    (JUPITER_V6, 6000, "EmptyRoute", FailureKind::Route),
+    (JUPITER_V6, 6001, "SlippageToleranceExceeded", FailureKind::Slippage),
+    (JUPITER_V6, 6008, "NotEnoughAccountKeys", FailureKind::Route),
+    (JUPITER_V6, 6017, "ExactOutAmountNotMatched", FailureKind::Slippage),
+    (RAYDIUM_AMM_V4, 30, "ExceededSlippage", FailureKind::Slippage),
+    (ORCA_WHIRLPOOL, 6017, "TokenMaxExceeded", FailureKind::Slippage),
+    (ORCA_WHIRLPOOL, 6018, "TokenMinSubceeded", FailureKind::Slippage),
+    (ORCA_WHIRLPOOL, 6035, "ZeroTradableAmount", FailureKind::Route),
+    (ORCA_WHIRLPOOL, 6036, "AmountOutBelowMinimum", FailureKind::Slippage),
+    (ORCA_WHIRLPOOL, 6037, "AmountInAboveMaximum", FailureKind::Slippage),
+    (PUMP_FUN, 6002, "TooMuchSolRequired", FailureKind::Slippage),
+    (PUMP_FUN, 6003, "TooLittleSolReceived", FailureKind::Slippage),
+    (PUMP_FUN, 6005, "BondingCurveComplete", FailureKind::Route),
+    (SPL_TOKEN, 1, "InsufficientFunds", FailureKind::InsufficientFunds),
+    (SPL_TOKEN, 3, "MintMismatch", FailureKind::AccountState),
+    (SPL_TOKEN, 4, "OwnerMismatch", FailureKind::AccountState),
+];
+
+/// Anchor framework codes, the same in every Anchor program
+const ANCHOR_ERRORS: &[(u32, &str, FailureKind)] = &[
+    (3007, "AccountOwnedByWrongProgram", FailureKind::AccountState),
+    (3012, "AccountNotInitialized", FailureKind::AccountState),
+];
+
+/// What went wrong, coarsely; decides the suggested fix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureKind {
+    /// Price moved past the minimum out / maximum in
+    Slippage,
+    InsufficientFunds,
+    /// Not enough SOL to keep a new account rent-exempt
+    InsufficientRent,
+    /// Blockhash expired before the transaction landed
+    ExpiredBlockhash,
+    ComputeBudget,
+    /// Another in-flight transaction holds a write lock on an account
+    AccountInUse,
+    /// Missing, uninitialized or mismatched account
+    AccountState,
+    /// Route or pool no longer usable
+    Route,
+    Other,
+}
+
+impl FailureKind {
+    pub fn remediation(&self) -> &'static str {
+        match self {
+            FailureKind::Slippage => "price moved past the quote: requote, or widen slippage if the move is acceptable",
+            FailureKind::InsufficientFunds => "top up the wallet or reduce the trade size (keep SOL for fees and rent)",
+            FailureKind::InsufficientRent => "top up the wallet's SOL so new token accounts can be rent-exempt",
+            FailureKind::ExpiredBlockhash => "resubmit with a fresh blockhash; raise the priority fee if landing is slow",
+            FailureKind::ComputeBudget => "raise the compute unit limit or use a route with fewer hops",
+            FailureKind::AccountInUse => "an account is locked by another transaction: retry shortly",
+            FailureKind::AccountState => "create or refresh the token accounts the route needs, then requote",
+            FailureKind::Route => "the pool or route is no longer valid: requote for a new route",
+            FailureKind::Other => "inspect the program logs",
+        }
+    }
+}
+
+/// Decoded failure of one transaction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TxFailure {
+    pub kind: FailureKind,
+    /// Transaction error as reported by the RPC
+    pub error: String,
+    /// Top-level instruction that failed
+    pub instruction_index: Option<u8>,
+    /// Program that raised the error (innermost failing program)
+    pub program_id: Option<String>,
+    pub program: Option<String>,
+    pub error_code: Option<u32>,
+    pub error_name: Option<String>,
+    /// Message from an Anchor error log, when present
+    pub error_message: Option<String>,
+    pub logs: Vec<String>,
+}
+
+impl TxFailure {
+    /// Decode a transaction error (`meta.err` or a signature status `err`) with its logs
+    ///
+    /// `instruction_programs` are the top-level instructions' program ids, used
+    /// to name the failing program when the logs do not.
+    pub fn from_error(error: &Value, logs: &[String], instruction_programs: &[String]) -> Self {
+        let (instruction_index, detail) = match error.get("InstructionError").and_then(Value::as_array) {
+            Some(parts) => (parts.first().and_then(Value::as_u64).map(|i| i as u8), parts.get(1).cloned()),
+            None => (None, None),
+        };
+        let error_code = detail.as_ref().and_then(|d| d.get("Custom")).and_then(Value::as_u64).map(|c| c as u32);
+        let program_id = failing_program(logs)
+            .or_else(|| instruction_index.and_then(|i| instruction_programs.get(i as usize).cloned()));
+        let (anchor_name, anchor_message) = anchor_error(logs);
+
+        let known = error_code.and_then(|code| {
+            let program = program_id.as_deref()?;
+            PROGRAM_ERRORS
+                .iter()
+                .find(|(id, known_code, ..)| *id == program && *known_code == code)
+                .map(|(_, _, name, kind)| (*name, *kind))
+                .or_else(|| ANCHOR_ERRORS.iter().find(|(known_code, ..)| *known_code == code).map(|(_, name, kind)| (*name, *kind)))
+        });
+        let detail_name = detail.as_ref().and_then(Value::as_str).map(str::to_string);
+        let top_level = error.as_str().map(str::to_string).or_else(|| error.as_object()?.keys().next().cloned());
+        let error_name = known
+            .map(|(name, _)| name.to_string())
+            .or(anchor_name)
+            .or(detail_name)
+            .or(top_level.filter(|name| name != "InstructionError"));
+
+        let kind = match known {
+            Some((_, kind)) => kind,
+            None => classify(error_name.as_deref().unwrap_or_default(), anchor_message.as_deref(), logs),
+        };
+        let start = logs.len().saturating_sub(MAX_LOG_LINES);
+
+        Self {
+            kind,
+            error: error.to_string(),
+            instruction_index,
+            program: program_id.as_deref().and_then(program_label),
+            program_id,
+            error_code,
+            error_name,
+            error_message: anchor_message,
+            logs: logs[start..].to_vec(),
+        }
+    }
+
+    /// Failure known only from an error message (RPC rejection, client error)
+    pub fn from_message(message: &str) -> Self {
+        Self {
+            kind: classify(message, None, &[]),
+            error: message.to_string(),
+            instruction_index: None,
+            program_id: None,
+            program: None,
+            error_code: None,
+            error_name: None,
+            error_message: None,
+            logs: Vec::new(),
+        }
+    }
+
+    pub fn remediation(&self) -> &'static str {
+        self.kind.remediation()
+    }
+
+    /// One line for `error_message` fields and alerts
+    pub fn summary(&self) -> String {
+        let program = self.program.clone().or_else(|| self.program_id.clone());
+        let location = match (program, self.instruction_index) {
+            (Some(program), Some(index)) => format!("{} failed in instruction {}", program, index),
+            (Some(program), None) => format!("{} failed", program),
+            (None, Some(index)) => format!("instruction {} failed", index),
+            (None, None) => "transaction failed".to_string(),
+        };
+        let cause = match (&self.error_name, self.error_code) {
+            (Some(name), Some(code)) => format!("{} ({})", name, code),
+            (Some(name), None) => name.clone(),
+            (None, Some(code)) => format!("custom error {}", code),
+            (None, None) => self.error.clone(),
+        };
+        let message = self.error_message.as_ref().map(|m| format!(" - {}", m)).unwrap_or_default();
+        format!("{}: {}{}. Suggested: {}", location, cause, message, self.remediation())
+    }
+}
+
+/// Decode the failure of a `jsonParsed` transaction; `None` if it succeeded
+pub fn decode_failure(tx: &Value) -> Option<TxFailure> {
+    let meta = &tx["meta"];
+    if meta["err"].is_null() {
+        return None;
+    }
+    let logs: Vec<String> = meta["logMessages"]
+        .as_array()
+        .map(|lines| lines.iter().filter_map(Value::as_str).map(str::to_string).collect())
+        .unwrap_or_default();
+    let instruction_programs: Vec<String> = tx["transaction"]["message"]["instructions"]
+        .as_array()
+        .map(|instructions| {
+            instructions
+                .iter()
+                .map(|ix| ix["programId"].as_str().unwrap_or_default().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+    Some(TxFailure::from_error(&meta["err"], &logs, &instruction_programs))
+}
+
+pub fn program_label(program_id: &str) -> Option<String> {
+    KNOWN_PROGRAMS
+        .iter()
+        .chain([(SPL_TOKEN, "SPL Token")].iter())
+        .find(|(id, _)| *id == program_id)
+        .map(|(_, name)| name.to_string())
+}
+
+/// First `Program <id> failed` line: the innermost program, which raised the error
+fn failing_program(logs: &[String]) -> Option<String> {
+    logs.iter().find_map(|line| {
+        let rest = line.strip_prefix("Program ")?;
+        let (program, outcome) = rest.split_once(' ')?;
+        outcome.starts_with("failed").then(|| program.to_string())
+    })
+}
+
+/// `Error Code` and `Error Message` of an Anchor error log line
+fn anchor_error(logs: &[String]) -> (Option<String>, Option<String>) {
+    let Some(line) = logs.iter().find(|line| line.contains("AnchorError")) else { return (None, None) };
+    let field = |label: &str| {
+        let start = line.find(label)? + label.len();
+        let value = line[start..].split(". ").next()?.trim().trim_end_matches('.');
+        (!value.is_empty()).then(|| value.to_string())
+    };
+    (field("Error Code: "), field("Error Message: "))
+}
+
+fn classify(name: &str, message: Option<&str>, logs: &[String]) -> FailureKind {
+    let text = format!("{} {}", name, message.unwrap_or_default()).to_lowercase();
+    if text.contains("blockhash") {
+        FailureKind::ExpiredBlockhash
+    } else if text.contains("computationalbudget") || logs.iter().any(|line| line.contains("exceeded CUs meter")) {
+        FailureKind::ComputeBudget
+    } else if text.contains("slippage") || text.contains("minimum") {
+        FailureKind::Slippage
+    } else if text.contains("forrent") || text.contains("rent-exempt") || text.contains("insufficient lamports") {
+        FailureKind::InsufficientRent
+    } else if text.contains("insufficientfunds") || text.contains("insufficient") {
+        FailureKind::InsufficientFunds
+    } else if text.contains("accountinuse") || text.contains("account in use") {
+        FailureKind::AccountInUse
+    } else if text.contains("account") {
+        FailureKind::AccountState
+    } else {
+        FailureKind::Other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_custom_error_attributed_to_inner_pool_program() {
+        let tx = json!({
+            "meta": {
+                "err": { "InstructionError": [2, { "Custom": 6036 }] },
+                "logMessages": [
+                    format!("Program {} invoke [1]", JUPITER_V6),
+                    format!("Program {} invoke [2]", ORCA_WHIRLPOOL),
+                    format!("Program {} failed: custom program error: 0x1794", ORCA_WHIRLPOOL),
+                    format!("Program {} failed: custom program error: 0x1794", JUPITER_V6),
+                ]
+            },
+            "transaction": { "message": { "instructions": [
+                { "programId": "ComputeBudget111111111111111111111111111111" },
+                { "programId": "ComputeBudget111111111111111111111111111111" },
+                { "programId": JUPITER_V6 }
+            ] } }
+        });
+        let failure = decode_failure(&tx).unwrap();
+
+        assert_eq!(failure.instruction_index, Some(2));
+        assert_eq!(failure.program.as_deref(), Some("Orca"));
+        assert_eq!(failure.error_name.as_deref(), Some("AmountOutBelowMinimum"));
+        assert_eq!(failure.kind, FailureKind::Slippage);
+        assert_eq!(failure.logs.len(), 4);
+        assert!(failure.summary().starts_with("Orca failed in instruction 2: AmountOutBelowMinimum (6036). Suggested: "));
+
+        assert!(decode_failure(&json!({ "meta": { "err": null } })).is_none());
+    }
+
+    #[test]
+    fn test_anchor_log_and_builtin_errors() {
+        let logs = vec![
+            "Program Unknown1111111111111111111111111111111111 invoke [1]".to_string(),
+            "Program log: AnchorError occurred. Error Code: PriceTooStale. Error Number: 6010. Error Message: Oracle price is stale.".to_string(),
+            "Program Unknown1111111111111111111111111111111111 failed: custom program error: 0x177a".to_string(),
+        ];
+        let failure = TxFailure::from_error(&json!({ "InstructionError": [0, { "Custom": 6010 }] }), &logs, &[]);
+        assert_eq!(failure.error_name.as_deref(), Some("PriceTooStale"));
+        assert_eq!(failure.error_message.as_deref(), Some("Oracle price is stale"));
+        assert_eq!(failure.program, None);
+
+        let expired = TxFailure::from_error(&json!("BlockhashNotFound"), &[], &[]);
+        assert_eq!((expired.kind, expired.error_name.as_deref()), (FailureKind::ExpiredBlockhash, Some("BlockhashNotFound")));
+
+        let compute = TxFailure::from_error(&json!({ "InstructionError": [1, "ComputationalBudgetExceeded"] }), &[], &[]);
+        assert_eq!(compute.kind, FailureKind::ComputeBudget);
+
+        let rent = TxFailure::from_error(&json!({ "InsufficientFundsForRent": { "account_index": 3 } }), &[], &[]);
+        assert_eq!(rent.kind, FailureKind::InsufficientRent);
+        assert_eq!(TxFailure::from_message("AccountInUse").kind, FailureKind::AccountInUse);
+    }
+}
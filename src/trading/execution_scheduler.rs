@@ -0,0 +1,242 @@
+//! Execution scheduler
+//!
+//! Orders pending opportunities by expected value per second of execution
+//! time instead of scan order. Expected value is the USD profit weighted by
+//! the opportunity's confidence and discounted by how tight its validity
+//! window is relative to the time execution takes; anything that cannot land
+//! before it expires is dropped outright.
+//!
+//! Planning is greedy against a per-cycle budget (wallet capital, compute
+//! units, execution slots): when the budget is short, lower-value work is
+//! deferred so higher-value opportunities get the capital first.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::types::{Opportunity, OpportunityKind};
+
+/// Per-kind execution cost assumptions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchedulerConfig {
+    /// Wall-clock seconds from submission until the opportunity is captured
+    /// (for cross-chain routes, the source leg; the bridge runs afterwards)
+    pub execution_secs: HashMap<OpportunityKind, f64>,
+    pub compute_units: HashMap<OpportunityKind, u64>,
+    /// Opportunities worth less than this after adjustments are not scheduled
+    pub min_expected_value_usd: f64,
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        Self {
+            execution_secs: HashMap::from([
+                (OpportunityKind::Arbitrage, 2.0),
+                (OpportunityKind::Triangular, 3.0),
+                (OpportunityKind::FlashLoan, 4.0),
+                (OpportunityKind::CrossChain, 30.0),
+            ]),
+            compute_units: HashMap::from([
+                (OpportunityKind::Arbitrage, 400_000),
+                (OpportunityKind::Triangular, 600_000),
+                (OpportunityKind::FlashLoan, 1_000_000),
+                (OpportunityKind::CrossChain, 300_000),
+            ]),
+            min_expected_value_usd: 0.01,
+        }
+    }
+}
+
+impl SchedulerConfig {
+    fn execution_secs(&self, kind: OpportunityKind) -> f64 {
+        self.execution_secs.get(&kind).copied().unwrap_or(5.0).max(0.001)
+    }
+
+    fn compute_units(&self, kind: OpportunityKind) -> u64 {
+        self.compute_units.get(&kind).copied().unwrap_or(400_000)
+    }
+}
+
+/// Resources available to one planning round
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionBudget {
+    pub capital_usd: f64,
+    pub compute_units: u64,
+    pub max_executions: usize,
+}
+
+impl Default for ExecutionBudget {
+    fn default() -> Self {
+        Self {
+            capital_usd: f64::INFINITY,
+            compute_units: 4_000_000,
+            max_executions: 8,
+        }
+    }
+}
+
+/// Opportunity with its scheduling economics
+#[derive(Debug, Clone)]
+pub struct ScheduledOpportunity {
+    pub opportunity: Opportunity,
+    pub expected_value_usd: f64,
+    pub capital_usd: f64,
+    pub compute_units: u64,
+    pub execution_secs: f64,
+    /// Sort key
+    pub ev_per_sec: f64,
+}
+
+/// Outcome of one planning round
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionPlan {
+    /// To execute now, highest EV/sec first
+    pub run: Vec<ScheduledOpportunity>,
+    /// Worth doing but preempted by higher-value work within the budget
+    pub deferred: Vec<ScheduledOpportunity>,
+    /// Ids dropped: unpriceable, not worth it, or cannot land before expiry
+    pub dropped: Vec<String>,
+}
+
+impl ExecutionPlan {
+    pub fn is_scheduled(&self, id: &str) -> bool {
+        self.run.iter().any(|s| s.opportunity.id == id)
+    }
+
+    /// Position in execution order, for sorting engine-specific queues
+    pub fn rank(&self, id: &str) -> Option<usize> {
+        self.run.iter().position(|s| s.opportunity.id == id)
+    }
+}
+
+/// Ranks and budgets pending opportunities
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionScheduler {
+    config: SchedulerConfig,
+}
+
+impl ExecutionScheduler {
+    pub fn new(config: SchedulerConfig) -> Self {
+        Self { config }
+    }
+
+    /// Scheduling economics, or `None` when the opportunity should be dropped
+    ///
+    /// `usd_per_unit` prices one whole unit of a mint.
+    pub fn evaluate(&self, opportunity: &Opportunity, usd_per_unit: impl Fn(&str) -> Option<f64>, now: DateTime<Utc>) -> Option<ScheduledOpportunity> {
+        let price = usd_per_unit(&opportunity.mint)?;
+        let execution_secs = self.config.execution_secs(opportunity.kind);
+        let remaining_secs = (opportunity.expires_at - now).num_milliseconds() as f64 / 1000.0;
+        if remaining_secs <= execution_secs {
+            return None;
+        }
+
+        // With slack equal to the execution time, half the value is at risk
+        let window_factor = 1.0 - execution_secs / remaining_secs;
+        let expected_value_usd = opportunity.expected_profit_ui() * price * opportunity.confidence.clamp(0.0, 1.0) * window_factor;
+        if expected_value_usd < self.config.min_expected_value_usd {
+            return None;
+        }
+        // Flash loans borrow their capital within the transaction
+        let capital_usd = match opportunity.kind {
+            OpportunityKind::FlashLoan => 0.0,
+            _ => opportunity.required_capital as f64 / 10f64.powi(opportunity.decimals as i32) * price,
+        };
+        Some(ScheduledOpportunity {
+            opportunity: opportunity.clone(),
+            expected_value_usd,
+            capital_usd,
+            compute_units: self.config.compute_units(opportunity.kind),
+            execution_secs,
+            ev_per_sec: expected_value_usd / execution_secs,
+        })
+    }
+
+    /// Pick what to execute within `budget`, highest EV/sec first
+    pub fn plan(
+        &self,
+        opportunities: &[Opportunity],
+        usd_per_unit: impl Fn(&str) -> Option<f64>,
+        budget: &ExecutionBudget,
+        now: DateTime<Utc>,
+    ) -> ExecutionPlan {
+        let mut plan = ExecutionPlan::default();
+        let mut ranked = Vec::with_capacity(opportunities.len());
+        for opportunity in opportunities {
+            match self.evaluate(opportunity, &usd_per_unit, now) {
+                Some(scheduled) => ranked.push(scheduled),
+                None => plan.dropped.push(opportunity.id.clone()),
+            }
+        }
+        ranked.sort_by(|a, b| b.ev_per_sec.total_cmp(&a.ev_per_sec));
+
+        let mut capital = budget.capital_usd;
+        let mut compute = budget.compute_units;
+        for scheduled in ranked {
+            let fits = plan.run.len() < budget.max_executions
+                && scheduled.capital_usd <= capital
+                && scheduled.compute_units <= compute;
+            if fits {
+                capital -= scheduled.capital_usd;
+                compute -= scheduled.compute_units;
+                plan.run.push(scheduled);
+            } else {
+                plan.deferred.push(scheduled);
+            }
+        }
+        plan
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::usd_opportunity;
+    use chrono::Duration;
+
+    fn opportunity(id: &str, kind: OpportunityKind, profit_usd: f64, capital_usd: f64, confidence: f64, ttl_secs: i64) -> Opportunity {
+        let now = Utc::now();
+        usd_opportunity(id.to_string(), kind, Vec::new(), profit_usd, capital_usd, confidence, now, now + Duration::seconds(ttl_secs))
+    }
+
+    fn usdc(_mint: &str) -> Option<f64> {
+        Some(1.0)
+    }
+
+    #[test]
+    fn test_orders_by_ev_per_second_and_drops_unlandable() {
+        let scheduler = ExecutionScheduler::default();
+        let opportunities = vec![
+            // Bigger profit but the source leg alone takes 30s
+            opportunity("bridge", OpportunityKind::CrossChain, 40.0, 100.0, 0.9, 3_600),
+            opportunity("arb", OpportunityKind::Arbitrage, 5.0, 100.0, 0.9, 30),
+            // Low confidence
+            opportunity("shaky", OpportunityKind::Arbitrage, 5.0, 100.0, 0.2, 30),
+            // Expires before a flash loan can land
+            opportunity("late", OpportunityKind::FlashLoan, 100.0, 100.0, 1.0, 3),
+        ];
+        let plan = scheduler.plan(&opportunities, usdc, &ExecutionBudget::default(), Utc::now());
+
+        let order: Vec<&str> = plan.run.iter().map(|s| s.opportunity.id.as_str()).collect();
+        assert_eq!(order, vec!["arb", "bridge", "shaky"]);
+        assert_eq!(plan.dropped, vec!["late".to_string()]);
+        assert_eq!(plan.rank("shaky"), Some(2));
+    }
+
+    #[test]
+    fn test_budget_defers_lower_value_work() {
+        let scheduler = ExecutionScheduler::default();
+        let opportunities = vec![
+            opportunity("small", OpportunityKind::Arbitrage, 1.0, 600.0, 0.9, 30),
+            opportunity("large", OpportunityKind::Arbitrage, 8.0, 600.0, 0.9, 30),
+            opportunity("tiny", OpportunityKind::Arbitrage, 0.5, 300.0, 0.9, 30),
+        ];
+        let budget = ExecutionBudget { capital_usd: 1_000.0, ..Default::default() };
+        let plan = scheduler.plan(&opportunities, usdc, &budget, Utc::now());
+
+        assert!(plan.is_scheduled("large"));
+        assert!(plan.is_scheduled("tiny"));
+        assert_eq!(plan.deferred.len(), 1);
+        assert_eq!(plan.deferred[0].opportunity.id, "small");
+    }
+}
@@ -0,0 +1,352 @@
+//! Fee budget manager
+//!
+//! Tracks priority fees, Jito tips and base fees spent by each bot per UTC
+//! day against a configurable daily cap. As spend approaches the cap, fee
+//! aggressiveness is downgraded (smaller priority fees and tips); once the cap
+//! is reached, fee-paying submissions are blocked until the next day. Profit
+//! is tracked alongside so dashboards can show the fee-to-profit ratio.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{NaiveDate, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+/// Fee categories
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FeeKind {
+    BaseFee,
+    PriorityFee,
+    JitoTip,
+}
+
+/// How aggressively a bot may bid for inclusion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum FeeAggressiveness {
+    Normal,
+    Reduced,
+    Minimal,
+    /// Daily cap reached: no fee-paying submissions
+    Blocked,
+}
+
+impl FeeAggressiveness {
+    /// Multiplier applied to requested priority fees and tips
+    pub fn multiplier(self) -> f64 {
+        match self {
+            Self::Normal => 1.0,
+            Self::Reduced => 0.5,
+            Self::Minimal => 0.2,
+            Self::Blocked => 0.0,
+        }
+    }
+}
+
+/// Budget settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeBudgetConfig {
+    /// Daily cap for bots without an override (SOL)
+    pub default_daily_cap_sol: f64,
+    /// Per-bot daily caps (SOL)
+    pub bot_caps: HashMap<String, f64>,
+    /// Share of the cap at which fees are reduced
+    pub reduce_at: f64,
+    /// Share of the cap at which fees drop to the minimum
+    pub minimal_at: f64,
+    /// Where spend is persisted so restarts do not reset the day (in-memory when unset)
+    pub state_path: Option<PathBuf>,
+}
+
+impl Default for FeeBudgetConfig {
+    fn default() -> Self {
+        Self {
+            default_daily_cap_sol: 0.5,
+            bot_caps: HashMap::new(),
+            reduce_at: 0.7,
+            minimal_at: 0.9,
+            state_path: None,
+        }
+    }
+}
+
+/// One bot's spend for one day
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeeUsage {
+    pub bot: String,
+    pub day: Option<NaiveDate>,
+    pub cap_sol: f64,
+    pub base_fees_sol: f64,
+    pub priority_fees_sol: f64,
+    pub tips_sol: f64,
+    pub profit_sol: f64,
+    pub submissions: u64,
+    pub throttled_submissions: u64,
+    pub blocked_submissions: u64,
+}
+
+impl FeeUsage {
+    pub fn spent_sol(&self) -> f64 {
+        self.base_fees_sol + self.priority_fees_sol + self.tips_sol
+    }
+
+    /// Share of the daily cap spent
+    pub fn utilization(&self) -> f64 {
+        if self.cap_sol <= 0.0 {
+            0.0
+        } else {
+            self.spent_sol() / self.cap_sol
+        }
+    }
+
+    /// Fees paid per unit of gross profit (None until there is profit)
+    pub fn fee_to_profit_ratio(&self) -> Option<f64> {
+        (self.profit_sol > 0.0).then(|| self.spent_sol() / self.profit_sol)
+    }
+}
+
+/// Key of the default cap in `cap_overrides`
+const DEFAULT_CAP_KEY: &str = "*";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BudgetState {
+    usage: HashMap<String, FeeUsage>,
+    /// Caps changed at runtime (e.g. by governance), persisted with the spend
+    #[serde(default)]
+    cap_overrides: HashMap<String, f64>,
+}
+
+/// Daily fee caps per bot
+#[derive(Debug)]
+pub struct FeeBudgetManager {
+    config: FeeBudgetConfig,
+    state: Mutex<BudgetState>,
+}
+
+impl FeeBudgetManager {
+    /// Create a manager, restoring today's spend from `state_path` when present
+    pub fn new(config: FeeBudgetConfig) -> Self {
+        let state = config
+            .state_path
+            .as_deref()
+            .and_then(|path| match Self::load(path) {
+                Ok(state) => Some(state),
+                Err(e) => {
+                    warn!("⚠️ Could not restore fee budget from {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+        Self { config, state: Mutex::new(state) }
+    }
+
+    fn load(path: &Path) -> Result<BudgetState> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BudgetState::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save(&self, state: &BudgetState) {
+        let Some(path) = &self.config.state_path else { return };
+        let result = (|| -> Result<()> {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            let temp_file = path.with_extension("tmp");
+            std::fs::write(&temp_file, serde_json::to_string_pretty(state)?)?;
+            std::fs::rename(&temp_file, path)?;
+            Ok(())
+        })();
+        if let Err(e) = result {
+            warn!("⚠️ Failed to persist fee budget: {}", e);
+        }
+    }
+
+    pub fn cap_for(&self, bot: &str) -> f64 {
+        self.cap_in(&self.state.lock(), bot)
+    }
+
+    fn cap_in(&self, state: &BudgetState, bot: &str) -> f64 {
+        state.cap_overrides.get(bot)
+            .or_else(|| self.config.bot_caps.get(bot))
+            .or_else(|| state.cap_overrides.get(DEFAULT_CAP_KEY))
+            .copied()
+            .unwrap_or(self.config.default_daily_cap_sol)
+    }
+
+    /// Override the daily cap of `bot` (or the default cap when `None`); returns the previous cap
+    pub fn set_daily_cap(&self, bot: Option<&str>, cap_sol: f64) -> f64 {
+        let mut state = self.state.lock();
+        let key = bot.unwrap_or(DEFAULT_CAP_KEY);
+        let previous = match bot {
+            Some(bot) => self.cap_in(&state, bot),
+            None => state.cap_overrides.get(DEFAULT_CAP_KEY).copied().unwrap_or(self.config.default_daily_cap_sol),
+        };
+        state.cap_overrides.insert(key.to_string(), cap_sol);
+        info!("⛽ Daily fee cap for '{}' changed: {:.4} → {:.4} SOL", key, previous, cap_sol);
+        self.save(&state);
+        previous
+    }
+
+    /// Today's usage entry for `bot`, reset when the UTC day changed
+    fn today<'a>(&self, state: &'a mut BudgetState, bot: &str) -> &'a mut FeeUsage {
+        let today = Utc::now().date_naive();
+        let cap_sol = self.cap_in(state, bot);
+        let usage = state.usage.entry(bot.to_string()).or_insert_with(|| FeeUsage {
+            bot: bot.to_string(),
+            ..Default::default()
+        });
+        if usage.day != Some(today) {
+            if usage.day.is_some() {
+                info!("⛽ Fee budget for '{}' reset: {:.6} SOL spent on {:?}", bot, usage.spent_sol(), usage.day);
+            }
+            *usage = FeeUsage { bot: bot.to_string(), day: Some(today), ..Default::default() };
+        }
+        usage.cap_sol = cap_sol;
+        usage
+    }
+
+    fn aggressiveness_for(&self, usage: &FeeUsage) -> FeeAggressiveness {
+        let utilization = usage.utilization();
+        if usage.cap_sol <= 0.0 || utilization >= 1.0 {
+            FeeAggressiveness::Blocked
+        } else if utilization >= self.config.minimal_at {
+            FeeAggressiveness::Minimal
+        } else if utilization >= self.config.reduce_at {
+            FeeAggressiveness::Reduced
+        } else {
+            FeeAggressiveness::Normal
+        }
+    }
+
+    /// Current fee aggressiveness for `bot`
+    pub fn aggressiveness(&self, bot: &str) -> FeeAggressiveness {
+        let mut state = self.state.lock();
+        let usage = self.today(&mut state, bot);
+        self.aggressiveness_for(usage)
+    }
+
+    /// Scale a requested priority fee or tip (lamports) to the remaining budget
+    ///
+    /// Returns `None` when the daily cap is exhausted and the submission should
+    /// be skipped.
+    pub fn adjust_fee(&self, bot: &str, requested_lamports: u64) -> Option<u64> {
+        let mut state = self.state.lock();
+        let usage = self.today(&mut state, bot);
+        let level = self.aggressiveness_for(usage);
+        match level {
+            FeeAggressiveness::Blocked => {
+                usage.blocked_submissions += 1;
+                None
+            }
+            FeeAggressiveness::Normal => Some(requested_lamports),
+            _ => {
+                usage.throttled_submissions += 1;
+                Some((requested_lamports as f64 * level.multiplier()) as u64)
+            }
+        }
+    }
+
+    /// Record fees actually paid (lamports)
+    pub fn record_fee(&self, bot: &str, kind: FeeKind, lamports: u64) {
+        self.record_fee_sol(bot, kind, lamports as f64 / LAMPORTS_PER_SOL);
+    }
+
+    /// Record fees actually paid (SOL)
+    pub fn record_fee_sol(&self, bot: &str, kind: FeeKind, sol: f64) {
+        let mut state = self.state.lock();
+        let usage = self.today(&mut state, bot);
+        let before = self.aggressiveness_for(usage);
+        match kind {
+            FeeKind::BaseFee => usage.base_fees_sol += sol,
+            FeeKind::PriorityFee => usage.priority_fees_sol += sol,
+            FeeKind::JitoTip => usage.tips_sol += sol,
+        }
+        usage.submissions += 1;
+        let after = self.aggressiveness_for(usage);
+        if after != before {
+            warn!("⛽ '{}' fee spend {:.6}/{:.6} SOL → {:?}", bot, usage.spent_sol(), usage.cap_sol, after);
+        }
+        self.save(&state);
+    }
+
+    /// Record realized profit (SOL, may be negative) for the fee-to-profit ratio
+    pub fn record_profit(&self, bot: &str, profit_sol: f64) {
+        let mut state = self.state.lock();
+        self.today(&mut state, bot).profit_sol += profit_sol;
+        self.save(&state);
+    }
+
+    pub fn usage(&self, bot: &str) -> FeeUsage {
+        let mut state = self.state.lock();
+        self.today(&mut state, bot).clone()
+    }
+
+    /// Today's usage of every bot that paid fees, sorted by spend
+    pub fn snapshot(&self) -> Vec<FeeUsage> {
+        let today = Utc::now().date_naive();
+        let state = self.state.lock();
+        let mut usage: Vec<FeeUsage> = state.usage.values().filter(|u| u.day == Some(today)).cloned().collect();
+        usage.sort_by(|a, b| b.spent_sol().total_cmp(&a.spent_sol()));
+        usage
+    }
+}
+
+impl Default for FeeBudgetManager {
+    fn default() -> Self {
+        Self::new(FeeBudgetConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager(state_path: Option<PathBuf>) -> FeeBudgetManager {
+        FeeBudgetManager::new(FeeBudgetConfig {
+            default_daily_cap_sol: 0.01,
+            state_path,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_aggressiveness_downgrades_towards_cap() {
+        let budget = manager(None);
+        assert_eq!(budget.adjust_fee("sniper", 100_000), Some(100_000));
+
+        budget.record_fee("sniper", FeeKind::PriorityFee, 7_500_000);
+        assert_eq!(budget.aggressiveness("sniper"), FeeAggressiveness::Reduced);
+        assert_eq!(budget.adjust_fee("sniper", 100_000), Some(50_000));
+
+        budget.record_fee("sniper", FeeKind::JitoTip, 3_000_000);
+        assert_eq!(budget.adjust_fee("sniper", 100_000), None);
+        assert_eq!(budget.aggressiveness("arbitrage"), FeeAggressiveness::Normal, "caps are per bot");
+
+        let usage = budget.usage("sniper");
+        assert_eq!(usage.blocked_submissions, 1);
+        assert_eq!(usage.throttled_submissions, 1);
+    }
+
+    #[test]
+    fn test_spend_survives_restart_and_ratio() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fee_budget.json");
+
+        let budget = manager(Some(path.clone()));
+        budget.record_fee_sol("arbitrage", FeeKind::BaseFee, 0.002);
+        budget.record_profit("arbitrage", 0.008);
+
+        let restored = manager(Some(path));
+        let usage = restored.usage("arbitrage");
+        assert!((usage.spent_sol() - 0.002).abs() < 1e-12);
+        assert!((usage.fee_to_profit_ratio().unwrap() - 0.25).abs() < 1e-9);
+        assert_eq!(restored.snapshot().len(), 1);
+    }
+}
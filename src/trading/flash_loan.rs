@@ -9,9 +9,16 @@ use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use tracing::{debug, info, warn};
 
+use crate::types::{Expiring, IntoOpportunity, Opportunity, OpportunityKind, RouteHop, TtlPolicy, sol_to_base_units};
+use crate::types::constants::SOL_MINT;
+
 /// Función de utilidad para ejecutar flash loan arbitrage
 pub async fn execute_flash_loan_arbitrage(opportunity: &FlashLoanOpportunity) -> Result<String> {
     info!("🚀 Executing enhanced flash loan arbitrage for opportunity: {}", opportunity.id);
+    if let Err(expired) = opportunity.ensure_fresh(Utc::now()) {
+        warn!("⌛ Flash loan execution rejected: {}", expired);
+        return Err(expired.into());
+    }
 
     // ✅ ENRIQUECIMIENTO: Implementación real de flash loan arbitrage
     match execute_real_flash_loan_sequence(opportunity).await {
@@ -231,6 +238,41 @@ pub struct FlashLoanOpportunity {
     pub repayment_amount_sol: f64,
     /// Profit neto después de todos los fees
     pub net_profit_sol: f64,
+    /// Fin de la ventana de validez (según volatilidad de la ruta)
+    pub expires_at: DateTime<Utc>,
+}
+
+impl IntoOpportunity for FlashLoanOpportunity {
+    /// Denominated in lamports; the borrowed amount is the required capital
+    fn to_opportunity(&self) -> Opportunity {
+        let mut route: Vec<RouteHop> = self.execution_path
+            .iter()
+            .map(|dex| RouteHop::new("SOL", dex.clone()))
+            .collect();
+        route.push(RouteHop::new("SOL", ""));
+        Opportunity {
+            id: self.id.clone(),
+            kind: OpportunityKind::FlashLoan,
+            route,
+            mint: SOL_MINT.to_string(),
+            decimals: 9,
+            expected_profit: sol_to_base_units(self.net_profit_sol),
+            required_capital: sol_to_base_units(self.loan_amount_sol).max(0) as u64,
+            confidence: self.confidence_score.clamp(0.0, 1.0),
+            detected_at: self.timestamp,
+            expires_at: self.expires_at,
+        }
+    }
+}
+
+impl Expiring for FlashLoanOpportunity {
+    fn opportunity_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
 }
 
 /// Estadísticas de ejecución de flash loans
@@ -320,9 +362,10 @@ impl EnterpriseFlashLoanEngine {
                     "Orca".to_string()
                 ];
                 
+                let detected_at = Utc::now();
                 let opportunity = FlashLoanOpportunity {
-                    id: format!("FL_{}", chrono::Utc::now().timestamp_millis()),
-                    timestamp: Utc::now(),
+                    id: format!("FL_{}", detected_at.timestamp_millis()),
+                    timestamp: detected_at,
                     loan_amount_sol: loan_amount,
                     estimated_profit_sol: estimated_profit,
                     estimated_profit_percentage: profit_pct * 100.0,
@@ -335,6 +378,7 @@ impl EnterpriseFlashLoanEngine {
                     flash_loan_provider: self.select_best_provider(),
                     repayment_amount_sol: loan_amount + flash_loan_fee,
                     net_profit_sol: net_profit,
+                    expires_at: TtlPolicy::default().expires_at(detected_at, profit_pct * 100.0),
                 };
                 
                 opportunities.push(opportunity.clone());
@@ -353,6 +397,10 @@ impl EnterpriseFlashLoanEngine {
     
     /// Ejecutar arbitraje con flash loan
     pub async fn execute_flash_loan(&mut self, opportunity: &FlashLoanOpportunity, simulate: bool) -> Result<bool> {
+        if let Err(expired) = opportunity.ensure_fresh(Utc::now()) {
+            warn!("⌛ Flash loan rechazado: {}", expired);
+            return Err(expired.into());
+        }
         if simulate {
             info!("🏦 SIMULANDO ejecución de flash loan - {} SOL préstamo, {:.6} SOL profit neto", 
                   opportunity.loan_amount_sol, opportunity.net_profit_sol);
@@ -579,6 +627,7 @@ mod tests {
             flash_loan_provider: "Marginfi".to_string(),
             repayment_amount_sol: 100.05,
             net_profit_sol: 4.95,
+            expires_at: Utc::now() + chrono::Duration::seconds(30),
         };
         
         // Debería ejecutar exitosamente en modo simulación
@@ -617,6 +666,7 @@ mod tests {
                 flash_loan_provider: "Marginfi".to_string(),
                 repayment_amount_sol: 100.05,
                 net_profit_sol: 4.95,
+                expires_at: Utc::now() + chrono::Duration::seconds(30),
             };
             
             engine.opportunity_history.push_back(opp.clone());
@@ -0,0 +1,194 @@
+//! LP position valuation
+//!
+//! An LP position is not a token balance: its composition shifts with price.
+//! It is valued by decomposing its liquidity into the underlying token amounts
+//! at the current price, then compared against simply holding the deposit
+//! (impermanent loss) with accrued fees reported separately.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{to_money, Money, Token};
+
+/// Pool curve the liquidity sits on
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LpCurve {
+    /// x * y = k over the full price range (Raydium AMM, Orca legacy pools)
+    ConstantProduct,
+    /// Liquidity active only between two prices of A in B (Whirlpools, CLMM)
+    Concentrated { lower_price: f64, upper_price: f64 },
+}
+
+/// Liquidity provided to one pool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LpPosition {
+    pub pool_address: String,
+    pub token_a: Token,
+    pub token_b: Token,
+    pub curve: LpCurve,
+    /// Amounts deposited (the "hold" benchmark)
+    pub deposited_a: f64,
+    pub deposited_b: f64,
+    /// Price of A in B at deposit
+    pub entry_price: f64,
+    /// Deposit value in the portfolio base at deposit time (set by the portfolio)
+    pub cost_basis: Money,
+    pub liquidity: f64,
+    /// Fees accrued and not yet withdrawn
+    pub fees_a: f64,
+    pub fees_b: f64,
+    pub opened_at: DateTime<Utc>,
+}
+
+/// Valuation of an LP position at current prices, in the portfolio base
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LpValuation {
+    pub pool_address: String,
+    /// Underlying amounts the position would withdraw now
+    pub amount_a: f64,
+    pub amount_b: f64,
+    pub position_value: Money,
+    /// Value of the original deposit had it been held
+    pub hold_value: Money,
+    /// `position_value - hold_value` (never positive for a constant product pool)
+    pub impermanent_loss: Money,
+    pub impermanent_loss_percent: f64,
+    pub fees_value: Money,
+    /// `impermanent_loss + fees_value`: whether providing beat holding
+    pub net_vs_hold: Money,
+    /// `position_value + fees_value - cost_basis`
+    pub pnl: Money,
+}
+
+impl LpPosition {
+    /// Position from a deposit of `deposited_a` A and `deposited_b` B at `entry_price` (A in B)
+    ///
+    /// For concentrated liquidity the deposit ratio is dictated by the range;
+    /// the liquidity is the largest both amounts support.
+    pub fn from_deposit(
+        pool_address: impl Into<String>,
+        (token_a, deposited_a): (Token, f64),
+        (token_b, deposited_b): (Token, f64),
+        curve: LpCurve,
+        entry_price: f64,
+    ) -> Self {
+        let liquidity = match &curve {
+            LpCurve::ConstantProduct => (deposited_a * deposited_b).sqrt(),
+            LpCurve::Concentrated { lower_price, upper_price } => {
+                let (sa, sb) = (lower_price.sqrt(), upper_price.sqrt());
+                let sp = entry_price.sqrt().clamp(sa, sb);
+                let from_a = if sp < sb { deposited_a * sp * sb / (sb - sp) } else { f64::INFINITY };
+                let from_b = if sp > sa { deposited_b / (sp - sa) } else { f64::INFINITY };
+                let liquidity = from_a.min(from_b);
+                if liquidity.is_finite() { liquidity } else { 0.0 }
+            }
+        };
+        Self {
+            pool_address: pool_address.into(),
+            token_a,
+            token_b,
+            curve,
+            deposited_a,
+            deposited_b,
+            entry_price,
+            cost_basis: Money::ZERO,
+            liquidity,
+            fees_a: 0.0,
+            fees_b: 0.0,
+            opened_at: Utc::now(),
+        }
+    }
+
+    /// Underlying (A, B) amounts at `price` (A in B)
+    pub fn amounts_at(&self, price: f64) -> (f64, f64) {
+        if price <= 0.0 || !price.is_finite() {
+            return (self.deposited_a, self.deposited_b);
+        }
+        let l = self.liquidity;
+        match &self.curve {
+            LpCurve::ConstantProduct => {
+                let sp = price.sqrt();
+                (l / sp, l * sp)
+            }
+            LpCurve::Concentrated { lower_price, upper_price } => {
+                let (sa, sb) = (lower_price.sqrt(), upper_price.sqrt());
+                let sp = price.sqrt().clamp(sa, sb);
+                (l * (sb - sp) / (sp * sb), l * (sp - sa))
+            }
+        }
+    }
+
+    pub fn accrue_fees(&mut self, fees_a: f64, fees_b: f64) {
+        self.fees_a += fees_a;
+        self.fees_b += fees_b;
+    }
+
+    /// Value at unit prices of A and B in the portfolio base
+    pub fn valuation(&self, price_a: f64, price_b: f64) -> LpValuation {
+        let (amount_a, amount_b) = if price_b > 0.0 { self.amounts_at(price_a / price_b) } else { (self.deposited_a, self.deposited_b) };
+        let position_value = amount_a * price_a + amount_b * price_b;
+        let hold_value = self.deposited_a * price_a + self.deposited_b * price_b;
+        let fees_value = self.fees_a * price_a + self.fees_b * price_b;
+        let impermanent_loss = position_value - hold_value;
+
+        LpValuation {
+            pool_address: self.pool_address.clone(),
+            amount_a,
+            amount_b,
+            position_value: to_money(position_value),
+            hold_value: to_money(hold_value),
+            impermanent_loss: to_money(impermanent_loss),
+            impermanent_loss_percent: if hold_value > 0.0 { impermanent_loss / hold_value * 100.0 } else { 0.0 },
+            fees_value: to_money(fees_value),
+            net_vs_hold: to_money(impermanent_loss + fees_value),
+            pnl: to_money(position_value + fees_value) - self.cost_basis,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(symbol: &str) -> Token {
+        Token { symbol: symbol.to_string(), mint: format!("{}-mint", symbol), decimals: 9 }
+    }
+
+    #[test]
+    fn test_constant_product_il_matches_closed_form() {
+        // 10 SOL + 1000 USDC at 100 USDC/SOL
+        let mut lp = LpPosition::from_deposit("pool", (token("SOL"), 10.0), (token("USDC"), 1_000.0), LpCurve::ConstantProduct, 100.0);
+        lp.cost_basis = to_money(2_000.0);
+        assert_eq!(lp.amounts_at(100.0), (10.0, 1_000.0));
+
+        // SOL 4x: IL = 2*sqrt(4)/(1+4) - 1 = -20%
+        let valuation = lp.valuation(400.0, 1.0);
+        assert!((valuation.amount_a - 5.0).abs() < 1e-9);
+        assert!((valuation.amount_b - 2_000.0).abs() < 1e-9);
+        assert!((valuation.impermanent_loss_percent + 20.0).abs() < 1e-9);
+        assert_eq!(valuation.hold_value, to_money(5_000.0));
+        assert_eq!(valuation.position_value, to_money(4_000.0));
+
+        lp.accrue_fees(0.5, 100.0);
+        let valuation = lp.valuation(400.0, 1.0);
+        assert_eq!(valuation.fees_value, to_money(300.0));
+        assert_eq!(valuation.net_vs_hold, to_money(-700.0));
+        assert_eq!(valuation.pnl, to_money(2_300.0));
+    }
+
+    #[test]
+    fn test_concentrated_range_converts_fully_outside_bounds() {
+        let curve = LpCurve::Concentrated { lower_price: 80.0, upper_price: 125.0 };
+        let lp = LpPosition::from_deposit("clmm", (token("SOL"), 10.0), (token("USDC"), 1_000.0), curve, 100.0);
+
+        // Above the range everything is B, below it everything is A
+        let (a_high, b_high) = lp.amounts_at(200.0);
+        assert!(a_high.abs() < 1e-9 && b_high > 1_000.0);
+        let (a_low, b_low) = lp.amounts_at(50.0);
+        assert!(b_low.abs() < 1e-9 && a_low > 10.0);
+
+        // Concentration amplifies IL versus a full-range position
+        let full = LpPosition::from_deposit("cp", (token("SOL"), 10.0), (token("USDC"), 1_000.0), LpCurve::ConstantProduct, 100.0);
+        assert!(lp.valuation(120.0, 1.0).impermanent_loss_percent < full.valuation(120.0, 1.0).impermanent_loss_percent);
+    }
+}
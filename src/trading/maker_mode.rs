@@ -0,0 +1,674 @@
+//! # Opportunistic Maker Mode
+//!
+//! Some arbitrage spreads between a CLOB (Phoenix, OpenBook) and an AMM are
+//! a few basis points short of paying the CLOB taker fee. Instead of dropping
+//! them, maker mode rests a post-only order on the CLOB at the price that
+//! would make the round trip profitable with the (lower or rebated) maker fee.
+//! When it fills, the position is hedged on the AMM with a taker swap.
+//!
+//! A resting order is only as good as the hedge price it was computed from,
+//! so every hedge price update re-checks the edge and cancels orders whose
+//! edge has fallen under the cancel threshold. Orders also expire after a
+//! maximum age. With a microstructure monitor attached, orders are neither
+//! posted nor kept while the market's flow is toxic or the book leans
+//! against them.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+use super::microstructure::MicrostructureMonitor;
+use crate::types::{ArbitrageOpportunity, TradingMode};
+
+/// Central limit order book venues
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ClobVenue {
+    Phoenix,
+    OpenBook,
+}
+
+/// Fees of one venue; negative maker fees are rebates
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ClobFees {
+    pub taker_fee_bps: f64,
+    pub maker_fee_bps: f64,
+}
+
+/// Maker mode configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MakerModeConfig {
+    /// Off by default: resting orders tie up capital until filled or cancelled
+    pub enabled: bool,
+    pub fees: HashMap<ClobVenue, ClobFees>,
+    /// Edge a trade must keep after all fees, taker or maker
+    pub min_edge_bps: f64,
+    /// Only spreads within this many bps of taker profitability become maker orders
+    pub near_miss_band_bps: f64,
+    /// Cancel a resting order once its edge at the current hedge price drops below this
+    pub cancel_edge_bps: f64,
+    pub max_order_age_secs: u64,
+    pub max_open_orders: usize,
+}
+
+impl Default for MakerModeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            fees: HashMap::from([
+                (ClobVenue::Phoenix, ClobFees { taker_fee_bps: 5.0, maker_fee_bps: 0.0 }),
+                (ClobVenue::OpenBook, ClobFees { taker_fee_bps: 4.0, maker_fee_bps: -2.0 }),
+            ]),
+            min_edge_bps: 5.0,
+            near_miss_band_bps: 15.0,
+            cancel_edge_bps: 1.0,
+            max_order_age_secs: 30,
+            max_open_orders: 4,
+        }
+    }
+}
+
+impl MakerModeConfig {
+    /// Enabled wherever orders are real; a simulated order would still rest on the real book
+    pub fn for_trading_mode(mode: &TradingMode) -> Self {
+        Self { enabled: mode.is_real_trading(), ..Self::default() }
+    }
+}
+
+/// Side of the order on the CLOB
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClobSide {
+    /// Buy on the CLOB, sell the fill on the AMM
+    Bid,
+    /// Sell on the CLOB, buy back on the AMM
+    Ask,
+}
+
+/// A CLOB/AMM spread as seen by the scanner
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClobSpread {
+    pub venue: ClobVenue,
+    pub market: String,
+    pub side: ClobSide,
+    /// Best opposite price on the CLOB: the ask when bidding, the bid when asking
+    pub clob_price: f64,
+    /// Smallest price increment of the market
+    pub tick_size: f64,
+    /// AMM price the fill would be hedged at, before the AMM fee
+    pub hedge_price: f64,
+    pub hedge_fee_rate: f64,
+    /// Base units
+    pub size: f64,
+}
+
+impl ClobSpread {
+    /// Bid for the buy leg of a scanned AMM arbitrage, hedged at its AMM sell price
+    pub fn for_arbitrage(opportunity: &ArbitrageOpportunity, venue: ClobVenue, market: &str, best_ask: f64, tick_size: f64) -> Self {
+        Self {
+            venue,
+            market: market.to_string(),
+            side: ClobSide::Bid,
+            clob_price: best_ask,
+            tick_size,
+            hedge_price: opportunity.sell_price,
+            hedge_fee_rate: opportunity.pair.fee_rate,
+            // The scanner sizes in the quote token
+            size: opportunity.volume_required / best_ask,
+        }
+    }
+}
+
+/// Post-only order maker mode wants resting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MakerQuote {
+    pub venue: ClobVenue,
+    pub market: String,
+    pub side: ClobSide,
+    pub price: f64,
+    pub size: f64,
+    pub expected_edge_bps: f64,
+}
+
+/// What to do with a spread
+#[derive(Debug, Clone, PartialEq)]
+pub enum MakerDecision {
+    /// Taker edge is enough; execute as usual
+    Take { edge_bps: f64 },
+    Post(MakerQuote),
+    Skip(String),
+}
+
+/// Round-trip edge of trading at `clob_price` with `clob_fee_bps` and hedging on the AMM
+pub fn round_trip_edge_bps(side: ClobSide, clob_price: f64, clob_fee_bps: f64, hedge_price: f64, hedge_fee_rate: f64) -> f64 {
+    let clob_fee = clob_fee_bps / 10_000.0;
+    let ratio = match side {
+        // Pay price + fee on the CLOB, receive the hedge price net of the AMM fee
+        ClobSide::Bid => hedge_price * (1.0 - hedge_fee_rate) / (clob_price * (1.0 + clob_fee)),
+        // Receive price net of fee on the CLOB, pay the hedge price plus the AMM fee
+        ClobSide::Ask => clob_price * (1.0 - clob_fee) / (hedge_price * (1.0 + hedge_fee_rate)),
+    };
+    (ratio - 1.0) * 10_000.0
+}
+
+/// Decides between taking, posting and skipping
+#[derive(Debug, Clone)]
+pub struct MakerPlanner {
+    config: MakerModeConfig,
+}
+
+impl MakerPlanner {
+    pub fn new(config: MakerModeConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) -> &MakerModeConfig {
+        &self.config
+    }
+
+    pub fn evaluate(&self, spread: &ClobSpread) -> MakerDecision {
+        let Some(fees) = self.config.fees.get(&spread.venue) else {
+            return MakerDecision::Skip(format!("no fee schedule for {:?}", spread.venue));
+        };
+        if !(spread.clob_price > 0.0 && spread.hedge_price > 0.0 && spread.tick_size > 0.0) {
+            return MakerDecision::Skip("invalid prices".to_string());
+        }
+        let taker_edge = round_trip_edge_bps(spread.side, spread.clob_price, fees.taker_fee_bps, spread.hedge_price, spread.hedge_fee_rate);
+        if taker_edge >= self.config.min_edge_bps {
+            return MakerDecision::Take { edge_bps: taker_edge };
+        }
+        if !self.config.enabled {
+            return MakerDecision::Skip(format!("taker edge {:.1} bps, maker mode disabled", taker_edge));
+        }
+        if taker_edge < self.config.min_edge_bps - self.config.near_miss_band_bps {
+            return MakerDecision::Skip(format!("taker edge {:.1} bps outside the near-miss band", taker_edge));
+        }
+
+        // Least favourable price that still clears the minimum edge at the maker fee,
+        // kept one tick inside the book so the order rests instead of crossing
+        let maker_fee = fees.maker_fee_bps / 10_000.0;
+        let min_edge = self.config.min_edge_bps / 10_000.0;
+        let (limit, inside) = match spread.side {
+            ClobSide::Bid => (
+                spread.hedge_price * (1.0 - spread.hedge_fee_rate) / ((1.0 + maker_fee) * (1.0 + min_edge)),
+                spread.clob_price - spread.tick_size,
+            ),
+            ClobSide::Ask => (
+                spread.hedge_price * (1.0 + spread.hedge_fee_rate) * (1.0 + min_edge) / (1.0 - maker_fee),
+                spread.clob_price + spread.tick_size,
+            ),
+        };
+        let price = match spread.side {
+            ClobSide::Bid => (limit.min(inside) / spread.tick_size).floor() * spread.tick_size,
+            ClobSide::Ask => (limit.max(inside) / spread.tick_size).ceil() * spread.tick_size,
+        };
+        if price <= 0.0 {
+            return MakerDecision::Skip("no positive maker price".to_string());
+        }
+        MakerDecision::Post(MakerQuote {
+            venue: spread.venue,
+            market: spread.market.clone(),
+            side: spread.side,
+            price,
+            size: spread.size,
+            expected_edge_bps: round_trip_edge_bps(spread.side, price, fees.maker_fee_bps, spread.hedge_price, spread.hedge_fee_rate),
+        })
+    }
+
+    /// Edge of a resting order at the current hedge price
+    pub fn resting_edge_bps(&self, quote: &MakerQuote, hedge_price: f64, hedge_fee_rate: f64) -> f64 {
+        let maker_fee_bps = self.config.fees.get(&quote.venue).map(|fees| fees.maker_fee_bps).unwrap_or(0.0);
+        round_trip_edge_bps(quote.side, quote.price, maker_fee_bps, hedge_price, hedge_fee_rate)
+    }
+}
+
+/// Order placement on a CLOB venue
+#[async_trait]
+pub trait ClobClient: Send + Sync {
+    fn venue(&self) -> ClobVenue;
+    /// Place a post-only limit order; returns the venue order ID
+    async fn place_post_only(&self, quote: &MakerQuote) -> anyhow::Result<String>;
+    async fn cancel(&self, market: &str, order_id: &str) -> anyhow::Result<()>;
+    /// Unfilled size of a resting order, zero once it left the book; `None`
+    /// when the venue cannot tell
+    async fn resting_size(&self, _market: &str, _order_id: &str) -> anyhow::Result<Option<f64>> {
+        Ok(None)
+    }
+}
+
+/// A maker order on the book
+#[derive(Debug, Clone)]
+pub struct RestingOrder {
+    pub order_id: String,
+    pub quote: MakerQuote,
+    pub hedge_fee_rate: f64,
+    pub posted_at: Instant,
+    pub filled: f64,
+}
+
+/// AMM swap that offsets a maker fill
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HedgeOrder {
+    pub market: String,
+    /// Side of the maker fill being offset
+    pub fill_side: ClobSide,
+    pub size: f64,
+    pub fill_price: f64,
+}
+
+/// Maker mode statistics
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MakerStats {
+    pub posted: u64,
+    pub filled: u64,
+    pub cancelled_adverse: u64,
+    pub cancelled_expired: u64,
+    pub cancelled_toxic: u64,
+    /// Posts skipped because of informed flow
+    pub skipped_toxic: u64,
+    pub place_failures: u64,
+}
+
+/// Posts, tracks and cancels maker orders
+pub struct MakerMode {
+    planner: MakerPlanner,
+    clients: HashMap<ClobVenue, std::sync::Arc<dyn ClobClient>>,
+    orders: Mutex<HashMap<String, RestingOrder>>,
+    stats: parking_lot::Mutex<MakerStats>,
+    microstructure: Option<std::sync::Arc<MicrostructureMonitor>>,
+}
+
+impl MakerMode {
+    pub fn new(config: MakerModeConfig) -> Self {
+        Self {
+            planner: MakerPlanner::new(config),
+            clients: HashMap::new(),
+            orders: Mutex::new(HashMap::new()),
+            stats: parking_lot::Mutex::new(MakerStats::default()),
+            microstructure: None,
+        }
+    }
+
+    pub fn with_client(mut self, client: std::sync::Arc<dyn ClobClient>) -> Self {
+        self.clients.insert(client.venue(), client);
+        self
+    }
+
+    /// Avoid providing liquidity into informed flow
+    pub fn with_microstructure(mut self, monitor: std::sync::Arc<MicrostructureMonitor>) -> Self {
+        self.microstructure = Some(monitor);
+        self
+    }
+
+    fn adverse_flow(&self, quote: &MakerQuote) -> Option<String> {
+        self.microstructure.as_ref()?.adverse_for(quote.venue, &quote.market, quote.side, chrono::Utc::now())
+    }
+
+    pub fn planner(&self) -> &MakerPlanner {
+        &self.planner
+    }
+
+    pub fn stats(&self) -> MakerStats {
+        self.stats.lock().clone()
+    }
+
+    pub async fn open_orders(&self) -> Vec<RestingOrder> {
+        self.orders.lock().await.values().cloned().collect()
+    }
+
+    /// Post a maker order for a near-miss spread; `None` when the spread is
+    /// taken as usual, skipped, or a resting order already covers the market
+    pub async fn consider(&self, spread: &ClobSpread) -> Option<RestingOrder> {
+        let MakerDecision::Post(quote) = self.planner.evaluate(spread) else {
+            return None;
+        };
+        let client = self.clients.get(&quote.venue)?;
+        if let Some(reason) = self.adverse_flow(&quote) {
+            info!("🧪 Not posting maker {:?} on {} ({:?}): {}", quote.side, quote.market, quote.venue, reason);
+            self.stats.lock().skipped_toxic += 1;
+            return None;
+        }
+        let mut orders = self.orders.lock().await;
+        if orders.len() >= self.planner.config.max_open_orders
+            || orders.values().any(|order| order.quote.market == quote.market && order.quote.side == quote.side)
+        {
+            return None;
+        }
+        match client.place_post_only(&quote).await {
+            Ok(order_id) => {
+                info!("📌 Maker {:?} {} {} @ {} on {:?} (edge {:.1} bps)",
+                    quote.side, quote.size, quote.market, quote.price, quote.venue, quote.expected_edge_bps);
+                let order = RestingOrder { order_id: order_id.clone(), quote, hedge_fee_rate: spread.hedge_fee_rate, posted_at: Instant::now(), filled: 0.0 };
+                orders.insert(order_id, order.clone());
+                self.stats.lock().posted += 1;
+                Some(order)
+            }
+            Err(e) => {
+                warn!("⚠️ Maker order on {:?} {} rejected: {}", quote.venue, quote.market, e);
+                self.stats.lock().place_failures += 1;
+                None
+            }
+        }
+    }
+
+    /// Re-check resting orders on `market` against a new hedge price, cancelling the ones gone adverse
+    pub async fn on_hedge_price(&self, market: &str, hedge_price: f64) -> Vec<String> {
+        let adverse: Vec<RestingOrder> = self
+            .orders
+            .lock()
+            .await
+            .values()
+            .filter(|order| order.quote.market == market)
+            .filter(|order| self.planner.resting_edge_bps(&order.quote, hedge_price, order.hedge_fee_rate) < self.planner.config.cancel_edge_bps)
+            .cloned()
+            .collect();
+        let cancelled = self.cancel_all(&adverse).await;
+        self.stats.lock().cancelled_adverse += cancelled.len() as u64;
+        cancelled
+    }
+
+    /// Cancel resting orders the microstructure signals now say are facing informed flow
+    pub async fn cancel_toxic(&self) -> Vec<String> {
+        if self.microstructure.is_none() {
+            return Vec::new();
+        }
+        let toxic: Vec<RestingOrder> = self
+            .orders
+            .lock()
+            .await
+            .values()
+            .filter(|order| self.adverse_flow(&order.quote).is_some())
+            .cloned()
+            .collect();
+        let cancelled = self.cancel_all(&toxic).await;
+        self.stats.lock().cancelled_toxic += cancelled.len() as u64;
+        cancelled
+    }
+
+    /// Cancel orders that rested longer than the maximum age
+    pub async fn expire(&self) -> Vec<String> {
+        let max_age = Duration::from_secs(self.planner.config.max_order_age_secs);
+        let expired: Vec<RestingOrder> = self
+            .orders
+            .lock()
+            .await
+            .values()
+            .filter(|order| order.posted_at.elapsed() >= max_age)
+            .cloned()
+            .collect();
+        let cancelled = self.cancel_all(&expired).await;
+        self.stats.lock().cancelled_expired += cancelled.len() as u64;
+        cancelled
+    }
+
+    /// Record a (partial) fill; returns the hedge to execute on the AMM
+    pub async fn on_fill(&self, order_id: &str, size: f64) -> Option<HedgeOrder> {
+        let mut orders = self.orders.lock().await;
+        let order = orders.get_mut(order_id)?;
+        let size = size.min(order.quote.size - order.filled);
+        order.filled += size;
+        let hedge = HedgeOrder { market: order.quote.market.clone(), fill_side: order.quote.side, size, fill_price: order.quote.price };
+        if order.filled >= order.quote.size {
+            orders.remove(order_id);
+        }
+        self.stats.lock().filled += 1;
+        Some(hedge)
+    }
+
+    /// Compare tracked orders with what still rests on the venue and turn the
+    /// difference into fills; returns the hedges to execute on the AMM
+    ///
+    /// Orders only leave the book by filling or through `cancel_all`, which
+    /// forgets them first, so a shrunken order is a (partial) fill.
+    pub async fn sync_fills(&self) -> Vec<HedgeOrder> {
+        let mut hedges = Vec::new();
+        for order in self.open_orders().await {
+            let Some(client) = self.clients.get(&order.quote.venue) else { continue };
+            let resting = match client.resting_size(&order.quote.market, &order.order_id).await {
+                Ok(Some(resting)) => resting,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!("⚠️ Could not read maker order {} on {:?}: {}", order.order_id, order.quote.venue, e);
+                    continue;
+                }
+            };
+            let newly_filled = order.quote.size - order.filled - resting.max(0.0);
+            if newly_filled > order.quote.size * 1e-9 {
+                if let Some(hedge) = self.on_fill(&order.order_id, newly_filled).await {
+                    info!("🎯 Maker {:?} {} filled {} @ {}", hedge.fill_side, hedge.market, hedge.size, hedge.fill_price);
+                    hedges.push(hedge);
+                }
+            }
+        }
+        hedges
+    }
+
+    /// Cancel on the venue, then forget; orders the venue refuses to cancel are kept
+    async fn cancel_all(&self, orders: &[RestingOrder]) -> Vec<String> {
+        let mut cancelled = Vec::new();
+        for order in orders {
+            let Some(client) = self.clients.get(&order.quote.venue) else { continue };
+            match client.cancel(&order.quote.market, &order.order_id).await {
+                Ok(()) => {
+                    self.orders.lock().await.remove(&order.order_id);
+                    cancelled.push(order.order_id.clone());
+                }
+                Err(e) => warn!("⚠️ Cancel of maker order {} failed: {}", order.order_id, e),
+            }
+        }
+        cancelled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn spread(clob_price: f64, hedge_price: f64) -> ClobSpread {
+        ClobSpread {
+            venue: ClobVenue::Phoenix,
+            market: "SOL/USDC".to_string(),
+            side: ClobSide::Bid,
+            clob_price,
+            tick_size: 0.001,
+            hedge_price,
+            hedge_fee_rate: 0.0005,
+            size: 10.0,
+        }
+    }
+
+    fn planner() -> MakerPlanner {
+        MakerPlanner::new(MakerModeConfig { enabled: true, ..Default::default() })
+    }
+
+    #[test]
+    fn test_near_miss_posts_inside_the_book_at_a_profitable_price() {
+        let planner = planner();
+        // Ask 150, hedge bid 150.2: +13 bps gross, about +3 bps after taker fees
+        let near_miss = spread(150.0, 150.2);
+        let MakerDecision::Post(quote) = planner.evaluate(&near_miss) else {
+            panic!("expected a maker quote: {:?}", planner.evaluate(&near_miss));
+        };
+        assert!(quote.price < near_miss.clob_price);
+        assert!(quote.expected_edge_bps >= planner.config().min_edge_bps - 0.1);
+
+        assert!(matches!(planner.evaluate(&spread(150.0, 151.0)), MakerDecision::Take { .. }));
+        assert!(matches!(planner.evaluate(&spread(150.0, 149.0)), MakerDecision::Skip(_)));
+        let disabled = MakerPlanner::new(MakerModeConfig::default());
+        assert!(matches!(disabled.evaluate(&near_miss), MakerDecision::Skip(_)));
+    }
+
+    struct RecordingClob {
+        cancelled: parking_lot::Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl ClobClient for RecordingClob {
+        fn venue(&self) -> ClobVenue {
+            ClobVenue::Phoenix
+        }
+        async fn place_post_only(&self, _quote: &MakerQuote) -> anyhow::Result<String> {
+            Ok("order-1".to_string())
+        }
+        async fn cancel(&self, _market: &str, order_id: &str) -> anyhow::Result<()> {
+            self.cancelled.lock().push(order_id.to_string());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_adverse_hedge_move_cancels_and_fills_produce_hedges() {
+        let clob = Arc::new(RecordingClob { cancelled: parking_lot::Mutex::new(Vec::new()) });
+        let maker = MakerMode::new(MakerModeConfig { enabled: true, ..Default::default() }).with_client(clob.clone());
+
+        let order = maker.consider(&spread(150.0, 150.2)).await.unwrap();
+        // One order per market and side
+        assert!(maker.consider(&spread(150.0, 150.2)).await.is_none());
+
+        // Hedge price unchanged: the order stays
+        assert!(maker.on_hedge_price("SOL/USDC", 150.2).await.is_empty());
+
+        let hedge = maker.on_fill(&order.order_id, 4.0).await.unwrap();
+        assert_eq!(hedge, HedgeOrder { market: "SOL/USDC".to_string(), fill_side: ClobSide::Bid, size: 4.0, fill_price: order.quote.price });
+
+        // Hedge bid drops below the resting bid: cancel
+        assert_eq!(maker.on_hedge_price("SOL/USDC", 149.5).await, vec!["order-1".to_string()]);
+        assert_eq!(*clob.cancelled.lock(), vec!["order-1".to_string()]);
+        assert!(maker.open_orders().await.is_empty());
+        assert_eq!(maker.stats().cancelled_adverse, 1);
+    }
+
+    #[tokio::test]
+    async fn test_heavy_offer_blocks_and_cancels_resting_bids() {
+        use crate::trading::microstructure::{BookLevel, MicrostructureConfig, OrderBookSnapshot};
+        let clob = Arc::new(RecordingClob { cancelled: parking_lot::Mutex::new(Vec::new()) });
+        let monitor = Arc::new(MicrostructureMonitor::new(MicrostructureConfig::default()));
+        let maker = MakerMode::new(MakerModeConfig { enabled: true, ..Default::default() })
+            .with_client(clob.clone())
+            .with_microstructure(monitor.clone());
+        let book = |bid_size: f64, ask_size: f64| OrderBookSnapshot {
+            venue: ClobVenue::Phoenix,
+            market: "SOL/USDC".to_string(),
+            bids: vec![BookLevel { price: 149.99, size: bid_size }],
+            asks: vec![BookLevel { price: 150.0, size: ask_size }],
+            at: chrono::Utc::now(),
+        };
+
+        monitor.on_book(&book(1.0, 20.0));
+        assert!(maker.consider(&spread(150.0, 150.2)).await.is_none());
+        assert_eq!(maker.stats().skipped_toxic, 1);
+
+        monitor.on_book(&book(10.0, 10.0));
+        assert!(maker.consider(&spread(150.0, 150.2)).await.is_some());
+        assert!(maker.cancel_toxic().await.is_empty());
+        monitor.on_book(&book(1.0, 20.0));
+        assert_eq!(maker.cancel_toxic().await, vec!["order-1".to_string()]);
+        assert_eq!(maker.stats().cancelled_toxic, 1);
+    }
+
+    /// A SOL/USDC spread a few bps short of paying the taker fees, as the scanner reports it
+    fn near_miss_arbitrage() -> ArbitrageOpportunity {
+        ArbitrageOpportunity {
+            pair: crate::types::ArbitragePair { fee_rate: 0.0005, ..Default::default() },
+            buy_price: 150.0,
+            sell_price: 150.2,
+            profit_percentage: 0.13,
+            volume_required: 1_500.0,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_near_miss_arbitrage_rests_a_bid_outside_simulation() {
+        let clob = Arc::new(RecordingClob { cancelled: parking_lot::Mutex::new(Vec::new()) });
+        let spread = ClobSpread::for_arbitrage(&near_miss_arbitrage(), ClobVenue::Phoenix, "SOL/USDC", 150.0, 0.001);
+        assert_eq!((spread.side, spread.hedge_price, spread.size), (ClobSide::Bid, 150.2, 10.0));
+
+        let simulated = MakerMode::new(MakerModeConfig::for_trading_mode(&TradingMode::Simulation)).with_client(clob.clone());
+        assert!(simulated.consider(&spread).await.is_none());
+
+        for mode in [TradingMode::MainNet, TradingMode::DevNet] {
+            let live = MakerMode::new(MakerModeConfig::for_trading_mode(&mode)).with_client(clob.clone());
+            let order = live.consider(&spread).await.expect("near miss becomes a resting bid");
+            assert_eq!(order.quote.side, ClobSide::Bid);
+            assert!(order.quote.price < spread.clob_price);
+            assert_eq!(live.stats().posted, 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_toxic_sell_flow_keeps_live_near_miss_off_the_book() {
+        use crate::trading::microstructure::{Aggressor, MicrostructureConfig, TradePrint};
+        let clob = Arc::new(RecordingClob { cancelled: parking_lot::Mutex::new(Vec::new()) });
+        let monitor = Arc::new(MicrostructureMonitor::new(MicrostructureConfig {
+            bucket_volume: 1_500.0,
+            min_buckets: 2,
+            ..Default::default()
+        }));
+        let maker = MakerMode::new(MakerModeConfig::for_trading_mode(&TradingMode::MainNet))
+            .with_client(clob.clone())
+            .with_microstructure(monitor.clone());
+        let spread = ClobSpread::for_arbitrage(&near_miss_arbitrage(), ClobVenue::Phoenix, "SOL/USDC", 150.0, 0.001);
+
+        // Informed sellers hit the book: a resting bid would be filled just before the price drops
+        for _ in 0..4 {
+            monitor.on_trade(&TradePrint {
+                venue: ClobVenue::Phoenix,
+                market: "SOL/USDC".to_string(),
+                price: 150.0,
+                size: 10.0,
+                aggressor: Some(Aggressor::Sell),
+                at: chrono::Utc::now(),
+            });
+        }
+        assert!(maker.consider(&spread).await.is_none());
+        assert_eq!(maker.stats().skipped_toxic, 1);
+        assert!(maker.open_orders().await.is_empty());
+
+        // The same spread on a quiet market posts
+        let quiet = ClobSpread { market: "BONK/USDC".to_string(), ..spread };
+        assert!(maker.consider(&quiet).await.is_some());
+    }
+
+    struct ShrinkingClob {
+        resting: parking_lot::Mutex<f64>,
+    }
+
+    #[async_trait]
+    impl ClobClient for ShrinkingClob {
+        fn venue(&self) -> ClobVenue {
+            ClobVenue::Phoenix
+        }
+        async fn place_post_only(&self, _quote: &MakerQuote) -> anyhow::Result<String> {
+            Ok("order-1".to_string())
+        }
+        async fn cancel(&self, _market: &str, _order_id: &str) -> anyhow::Result<()> {
+            Ok(())
+        }
+        async fn resting_size(&self, _market: &str, _order_id: &str) -> anyhow::Result<Option<f64>> {
+            Ok(Some(*self.resting.lock()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sync_fills_hedges_what_left_the_book() {
+        let clob = Arc::new(ShrinkingClob { resting: parking_lot::Mutex::new(10.0) });
+        let maker = MakerMode::new(MakerModeConfig { enabled: true, ..Default::default() }).with_client(clob.clone());
+        maker.consider(&spread(150.0, 150.2)).await.unwrap();
+        assert!(maker.sync_fills().await.is_empty());
+
+        *clob.resting.lock() = 6.0;
+        let hedges = maker.sync_fills().await;
+        assert_eq!(hedges.len(), 1);
+        assert!((hedges[0].size - 4.0).abs() < 1e-9);
+        // Already hedged: nothing new until the book changes again
+        assert!(maker.sync_fills().await.is_empty());
+
+        *clob.resting.lock() = 0.0;
+        assert!((maker.sync_fills().await[0].size - 6.0).abs() < 1e-9);
+        assert!(maker.open_orders().await.is_empty());
+    }
+}
@@ -0,0 +1,340 @@
+//! # CLOB Microstructure Signals
+//!
+//! Resting orders on Phoenix/OpenBook earn the spread from uninformed flow
+//! and lose it to informed flow: a maker bid gets filled by whoever knows the
+//! price is about to drop. Two signals per market tell the two apart:
+//!
+//! - **Book imbalance**: resting size on the bid vs the ask over the top
+//!   levels, in [-1, 1]; a heavily offered book tends to tick down next.
+//! - **Flow toxicity (VPIN-like)**: trades are grouped into buckets of equal
+//!   quote volume; each bucket's |buy - sell| / volume averaged over the last
+//!   buckets. One-sided flow (high VPIN) is the footprint of informed traders.
+//!
+//! Trade prints without an aggressor side are classified with the tick rule.
+//! Signals are read by maker mode before posting or keeping a resting order,
+//! and published into `MarketIntelligence` for the other strategies.
+
+use std::collections::{HashMap, VecDeque};
+
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+
+use super::maker_mode::{ClobSide, ClobVenue};
+
+/// Microstructure settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MicrostructureConfig {
+    /// Book levels per side summed into the imbalance
+    pub depth_levels: usize,
+    /// Quote volume per VPIN bucket
+    pub bucket_volume: f64,
+    /// Buckets averaged into VPIN
+    pub window_buckets: usize,
+    /// Completed buckets needed before VPIN is reported
+    pub min_buckets: usize,
+    /// VPIN at or above which flow counts as toxic
+    pub toxic_vpin: f64,
+    /// Net flow share (in the direction that hurts the maker) needed to call it adverse
+    pub adverse_flow: f64,
+    /// Book imbalance against the maker that alone is adverse
+    pub adverse_imbalance: f64,
+    /// Signals older than this are ignored
+    pub max_signal_age_secs: i64,
+}
+
+impl Default for MicrostructureConfig {
+    fn default() -> Self {
+        Self {
+            depth_levels: 5,
+            bucket_volume: 50_000.0,
+            window_buckets: 50,
+            min_buckets: 10,
+            toxic_vpin: 0.5,
+            adverse_flow: 0.2,
+            adverse_imbalance: 0.7,
+            max_signal_age_secs: 30,
+        }
+    }
+}
+
+/// One price level of a book side
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BookLevel {
+    pub price: f64,
+    pub size: f64,
+}
+
+/// Top of a CLOB market; bids best (highest) first, asks best (lowest) first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBookSnapshot {
+    pub venue: ClobVenue,
+    pub market: String,
+    pub bids: Vec<BookLevel>,
+    pub asks: Vec<BookLevel>,
+    pub at: DateTime<Utc>,
+}
+
+impl OrderBookSnapshot {
+    /// (bid size - ask size) / (bid size + ask size) over the top `levels`
+    pub fn imbalance(&self, levels: usize) -> Option<f64> {
+        let bid: f64 = self.bids.iter().take(levels).map(|level| level.size).sum();
+        let ask: f64 = self.asks.iter().take(levels).map(|level| level.size).sum();
+        (bid + ask > 0.0).then(|| (bid - ask) / (bid + ask))
+    }
+
+    /// Mid weighted towards the side with less size, where the price is likelier to move
+    pub fn microprice(&self) -> Option<f64> {
+        let (bid, ask) = (self.bids.first()?, self.asks.first()?);
+        let size = bid.size + ask.size;
+        (size > 0.0).then(|| (bid.price * ask.size + ask.price * bid.size) / size)
+    }
+}
+
+/// Taker side of a trade
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Aggressor {
+    Buy,
+    Sell,
+}
+
+/// An executed trade on a CLOB market
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradePrint {
+    pub venue: ClobVenue,
+    pub market: String,
+    pub price: f64,
+    /// Base units
+    pub size: f64,
+    /// `None` when the venue does not report it; classified by tick rule
+    pub aggressor: Option<Aggressor>,
+    pub at: DateTime<Utc>,
+}
+
+/// Current microstructure of one market
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MicrostructureSignal {
+    pub venue: ClobVenue,
+    pub market: String,
+    pub book_imbalance: Option<f64>,
+    pub microprice: Option<f64>,
+    /// Mean |buy - sell| share over recent buckets, once enough buckets completed
+    pub vpin: Option<f64>,
+    /// Mean signed (buy - sell) share over the same buckets; negative is selling
+    pub flow_imbalance: Option<f64>,
+    pub toxic: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MicrostructureSignal {
+    /// Why resting an order on `side` would be trading against informed flow
+    ///
+    /// A resting bid is filled by sellers and hurt by a falling price; a
+    /// resting ask the other way round.
+    pub fn adverse_for(&self, side: ClobSide, config: &MicrostructureConfig) -> Option<String> {
+        let against = |value: f64| match side {
+            ClobSide::Bid => -value,
+            ClobSide::Ask => value,
+        };
+        if let Some(flow) = self.flow_imbalance.filter(|_| self.toxic) {
+            if against(flow) >= config.adverse_flow {
+                return Some(format!("toxic flow (VPIN {:.2}, net flow {:+.2})", self.vpin.unwrap_or_default(), flow));
+            }
+        }
+        match self.book_imbalance {
+            Some(imbalance) if against(imbalance) >= config.adverse_imbalance => {
+                Some(format!("book imbalance {:+.2}", imbalance))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Per-market accumulation state
+#[derive(Debug, Default)]
+struct MarketState {
+    book_imbalance: Option<f64>,
+    microprice: Option<f64>,
+    last_price: Option<f64>,
+    last_aggressor: Option<Aggressor>,
+    bucket_buy: f64,
+    bucket_sell: f64,
+    /// Signed (buy - sell) / volume of completed buckets, oldest first
+    buckets: VecDeque<f64>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl MarketState {
+    fn add_trade(&mut self, volume: f64, aggressor: Aggressor, config: &MicrostructureConfig) {
+        if config.bucket_volume <= 0.0 {
+            return;
+        }
+        let mut remaining = volume;
+        // Large prints spill over into as many buckets as they fill
+        while remaining > 0.0 {
+            let space = config.bucket_volume - (self.bucket_buy + self.bucket_sell);
+            let filled = remaining.min(space);
+            match aggressor {
+                Aggressor::Buy => self.bucket_buy += filled,
+                Aggressor::Sell => self.bucket_sell += filled,
+            }
+            remaining -= filled;
+            if self.bucket_buy + self.bucket_sell >= config.bucket_volume {
+                self.buckets.push_back((self.bucket_buy - self.bucket_sell) / config.bucket_volume);
+                while self.buckets.len() > config.window_buckets {
+                    self.buckets.pop_front();
+                }
+                self.bucket_buy = 0.0;
+                self.bucket_sell = 0.0;
+            }
+        }
+    }
+
+    fn signal(&self, venue: ClobVenue, market: &str, updated_at: DateTime<Utc>, config: &MicrostructureConfig) -> MicrostructureSignal {
+        let ready = self.buckets.len() >= config.min_buckets.max(1);
+        let count = self.buckets.len() as f64;
+        let vpin = ready.then(|| self.buckets.iter().map(|b| b.abs()).sum::<f64>() / count);
+        MicrostructureSignal {
+            venue,
+            market: market.to_string(),
+            book_imbalance: self.book_imbalance,
+            microprice: self.microprice,
+            vpin,
+            flow_imbalance: ready.then(|| self.buckets.iter().sum::<f64>() / count),
+            toxic: vpin.is_some_and(|vpin| vpin >= config.toxic_vpin),
+            updated_at,
+        }
+    }
+}
+
+/// Order book and trade flow signals for every CLOB market fed to it
+#[derive(Debug, Default)]
+pub struct MicrostructureMonitor {
+    config: MicrostructureConfig,
+    markets: RwLock<HashMap<(ClobVenue, String), MarketState>>,
+}
+
+impl MicrostructureMonitor {
+    pub fn new(config: MicrostructureConfig) -> Self {
+        Self { config, markets: RwLock::new(HashMap::new()) }
+    }
+
+    pub fn config(&self) -> &MicrostructureConfig {
+        &self.config
+    }
+
+    pub fn on_book(&self, book: &OrderBookSnapshot) {
+        let mut markets = self.markets.write();
+        let state = markets.entry((book.venue, book.market.clone())).or_default();
+        state.book_imbalance = book.imbalance(self.config.depth_levels);
+        state.microprice = book.microprice();
+        state.updated_at = state.updated_at.max(Some(book.at));
+    }
+
+    pub fn on_trade(&self, trade: &TradePrint) {
+        if !(trade.price > 0.0 && trade.size > 0.0) {
+            return;
+        }
+        let mut markets = self.markets.write();
+        let state = markets.entry((trade.venue, trade.market.clone())).or_default();
+        // Tick rule: an uptick is a buy, a downtick a sell, no change repeats the last side
+        let tick_side = match state.last_price {
+            Some(last) if trade.price > last => Some(Aggressor::Buy),
+            Some(last) if trade.price < last => Some(Aggressor::Sell),
+            _ => state.last_aggressor,
+        };
+        let aggressor = trade.aggressor.or(tick_side);
+        state.last_price = Some(trade.price);
+        if let Some(aggressor) = aggressor {
+            state.last_aggressor = Some(aggressor);
+            state.add_trade(trade.price * trade.size, aggressor, &self.config);
+        }
+        state.updated_at = state.updated_at.max(Some(trade.at));
+    }
+
+    /// Signal of one market, `None` when unknown or stale at `now`
+    pub fn signal(&self, venue: ClobVenue, market: &str, now: DateTime<Utc>) -> Option<MicrostructureSignal> {
+        let markets = self.markets.read();
+        let state = markets.get(&(venue, market.to_string()))?;
+        let updated_at = state.updated_at?;
+        (now - updated_at <= Duration::seconds(self.config.max_signal_age_secs))
+            .then(|| state.signal(venue, market, updated_at, &self.config))
+    }
+
+    /// Fresh signals of every market, optionally only those quoting `base` (e.g. "SOL" for "SOL/USDC")
+    pub fn signals(&self, base: Option<&str>, now: DateTime<Utc>) -> Vec<MicrostructureSignal> {
+        let keys: Vec<(ClobVenue, String)> = self
+            .markets
+            .read()
+            .keys()
+            .filter(|(_, market)| base.is_none_or(|base| market.split('/').next() == Some(base)))
+            .cloned()
+            .collect();
+        keys.into_iter().filter_map(|(venue, market)| self.signal(venue, &market, now)).collect()
+    }
+
+    /// Why posting on `side` in `market` would provide liquidity into informed flow
+    pub fn adverse_for(&self, venue: ClobVenue, market: &str, side: ClobSide, now: DateTime<Utc>) -> Option<String> {
+        self.signal(venue, market, now)?.adverse_for(side, &self.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(monitor: &MicrostructureMonitor, price: f64, size: f64, aggressor: Option<Aggressor>, at: DateTime<Utc>) {
+        monitor.on_trade(&TradePrint { venue: ClobVenue::Phoenix, market: "SOL/USDC".to_string(), price, size, aggressor, at });
+    }
+
+    fn config() -> MicrostructureConfig {
+        MicrostructureConfig { bucket_volume: 1_000.0, window_buckets: 4, min_buckets: 2, ..MicrostructureConfig::default() }
+    }
+
+    #[test]
+    fn test_vpin_separates_balanced_from_one_sided_flow() {
+        let now = Utc::now();
+        let monitor = MicrostructureMonitor::new(config());
+        // Balanced flow: each bucket half bought, half sold
+        for _ in 0..4 {
+            trade(&monitor, 100.0, 5.0, Some(Aggressor::Buy), now);
+            trade(&monitor, 100.0, 5.0, Some(Aggressor::Sell), now);
+        }
+        let signal = monitor.signal(ClobVenue::Phoenix, "SOL/USDC", now).unwrap();
+        assert_eq!(signal.vpin, Some(0.0));
+        assert!(!signal.toxic);
+
+        // One 4,000 sell print at a downtick fills four whole sell buckets
+        trade(&monitor, 80.0, 50.0, None, now);
+        let signal = monitor.signal(ClobVenue::Phoenix, "SOL/USDC", now).unwrap();
+        assert!((signal.vpin.unwrap() - 1.0).abs() < 1e-9);
+        assert!((signal.flow_imbalance.unwrap() + 1.0).abs() < 1e-9);
+        assert!(signal.toxic);
+        assert!(monitor.adverse_for(ClobVenue::Phoenix, "SOL/USDC", ClobSide::Bid, now).unwrap().starts_with("toxic flow"));
+        assert!(monitor.adverse_for(ClobVenue::Phoenix, "SOL/USDC", ClobSide::Ask, now).is_none());
+        // Stale signals are ignored
+        assert!(monitor.signal(ClobVenue::Phoenix, "SOL/USDC", now + Duration::seconds(31)).is_none());
+    }
+
+    #[test]
+    fn test_book_imbalance_and_microprice() {
+        let now = Utc::now();
+        let book = OrderBookSnapshot {
+            venue: ClobVenue::OpenBook,
+            market: "SOL/USDC".to_string(),
+            bids: vec![BookLevel { price: 99.0, size: 1.0 }, BookLevel { price: 98.0, size: 1.0 }],
+            asks: vec![BookLevel { price: 101.0, size: 9.0 }, BookLevel { price: 102.0, size: 9.0 }],
+            at: now,
+        };
+        assert!((book.imbalance(1).unwrap() + 0.8).abs() < 1e-9);
+        // Pulled towards the thin bid
+        assert!((book.microprice().unwrap() - 99.2).abs() < 1e-9);
+
+        let monitor = MicrostructureMonitor::new(config());
+        monitor.on_book(&book);
+        assert!(monitor.adverse_for(ClobVenue::OpenBook, "SOL/USDC", ClobSide::Bid, now).unwrap().starts_with("book imbalance"));
+        assert!(monitor.adverse_for(ClobVenue::OpenBook, "SOL/USDC", ClobSide::Ask, now).is_none());
+        assert_eq!(monitor.signals(Some("SOL"), now).len(), 1);
+        assert!(monitor.signals(Some("BONK"), now).is_empty());
+    }
+}
@@ -6,13 +6,37 @@ pub mod strategies; // ✅ AGREGADO: Trading strategies framework
 // pub mod engine;
 // pub mod executor;
 pub mod risk;
+pub mod token_limits; // Per-token exposure caps by liquidity tier with per-mint overrides
+pub mod drawdown_ladder; // Graduated de-risking as daily PnL falls
+pub mod capital_withdrawal; // Lock, drain and sweep capital marked for withdrawal
+pub mod profit_taking; // Scheduled / milestone conversion of realized profit to USDC via TWAP
 pub mod portfolio;
+pub mod lp_valuation; // LP positions decomposed at current price, IL vs hold, accrued fees
 pub mod triangular;
+#[cfg(feature = "flash-loan")]
 pub mod flash_loan;
+#[cfg(feature = "cross-chain")]
 pub mod cross_chain;
 pub mod enhanced_system;
 pub mod hft_engine;
 pub mod route_optimizer;  // ✅ AGREGADO: Route optimization engine
+pub mod opportunity_dedup; // ✅ NEW: Cross-engine opportunity deduplication
+pub mod strategy_guard; // Statistical kill criteria per strategy
+pub mod fee_budget; // Daily fee caps per bot
+pub mod profit_accounting; // Confirmed vs simulated vs hypothetical profit
+#[cfg(feature = "cross-chain")]
+pub mod bridge_tracker; // Persistent bridge transfer state machines
+pub mod scoring; // Weighted, explainable opportunity scores per strategy
+pub mod execution_scheduler; // EV-per-second ordering under wallet/compute budgets
+pub mod scan_schedule; // Per-strategy scan intervals, jitter and feed alignment
+pub mod token_quarantine; // Auto-learned toxic mints shared across strategies
+pub mod amm; // Per-DEX adapters gated by a shared conformance suite
+pub mod maker_mode; // Passive CLOB orders for spreads just short of taker profitability
+pub mod phoenix; // Phoenix market account reads, post-only orders and cancels
+pub mod route_matrix; // Data-parallel (and optional GPU) triangular search on dense rate matrices
+pub mod competition_model; // Front-run odds from spread, venue and latency for simulated fills
+pub mod microstructure; // CLOB book imbalance and VPIN-style flow toxicity
+pub mod sim_diff; // Shadow replay of recorded decision inputs and decision diffs between builds
 // pub mod strategies;
 
 pub use arbitrage::{ArbitrageEngine, EnhancedArbitrageOpportunity, DexData, TradeResult as ArbitrageTradeResult, PerformanceMetrics};
@@ -27,10 +51,33 @@ pub use strategies::{
     StrategyManager, SignalType, RiskLevel, Timeframe, TradeResult as StrategyTradeResult,
     ArbitrageStrategy, MomentumStrategy, MeanReversionStrategy
 };
-pub use risk::{RiskManager};
+pub use risk::{RiskManager, AssetRestriction, ExposureLeg, PendingTrade, NettingResult, ExposureBreach};
+pub use token_limits::{asset_key, TokenLimits, TokenLimitsConfig, TokenTier, ExposureCap, TokenHeadroom};
+pub use drawdown_ladder::{DrawdownLadder, DrawdownLadderConfig, DrawdownStep, DeRiskAction, DeRiskTransition, DeRiskStatus};
+pub use capital_withdrawal::{CapitalWithdrawals, WithdrawalConfig, WithdrawalRequest, WithdrawalState, WithdrawalBackend, WithdrawalSummary, RpcWithdrawalBackend};
+pub use profit_taking::{ProfitTaking, ProfitTakingConfig, ProfitTakingStatus, ProfitConversion, ConversionTrigger, ConversionVenue, JupiterConversionVenue};
 // pub use engine::*;
 // pub use executor::*;
-pub use portfolio::{PortfolioManager, Position, TradeRecord, TradeSide, RiskMetrics, PortfolioSummary, PerformanceMetrics as PortfolioPerformanceMetrics};
+pub use portfolio::{PortfolioManager, Position, TradeRecord, TradeSide, RiskMetrics, PortfolioSummary, PerformanceMetrics as PortfolioPerformanceMetrics, PortfolioSnapshot, PositionSnapshot};
+pub use lp_valuation::{LpCurve, LpPosition, LpValuation};
 pub use triangular::*;
 pub use hft_engine::{HftEngine, HftOrder, HftMetrics, OrderSide, OrderType};
+#[cfg(feature = "flash-loan")]
 pub use flash_loan::*;
+pub use opportunity_dedup::{OpportunityDeduplicator, OpportunitySource, RouteSignature, DedupCandidate, DedupOutcome, DedupStats, CycleAdmission, DedupCooldown, ExecutionClaim};
+pub use strategy_guard::{StrategyKillSwitch, KillCriteriaConfig, KillDetector, SuspensionDecision, StrategyBaseline};
+pub use fee_budget::{FeeBudgetManager, FeeBudgetConfig, FeeUsage, FeeKind, FeeAggressiveness};
+pub use profit_accounting::{AccountingMode, ConfirmedFill, CycleProfit, ProfitKind, ProfitLedger, ProfitTotals, PendingFill, RoundTripCost};
+#[cfg(feature = "cross-chain")]
+pub use bridge_tracker::{BridgeTracker, BridgeTrackerConfig, BridgeTransfer, BridgeTransferStatus, BridgeProgress, BridgeStatusSource, WormholescanSource};
+pub use scoring::{ScoringPipeline, ScoringConfig, ScoringProfile, ScoreFeatures, ScoreBreakdown, ScoreComponent, Scorer, ScorerOutput};
+pub use execution_scheduler::{ExecutionScheduler, SchedulerConfig, ExecutionBudget, ExecutionPlan, ScheduledOpportunity};
+pub use scan_schedule::{ScanScheduler, ScanScheduleConfig, StrategySchedule, FeedEvents, ScanTrigger, ENGINE_FINDINGS_FEED};
+pub use token_quarantine::{TokenQuarantine, QuarantineConfig, ToxicToken, TradeFailureKind};
+pub use amm::{AmmAdapter, AdapterRegistry, AdapterError, PoolState, Pricing, SwapAccounts, ConformanceFixture, ConformanceReport};
+pub use route_matrix::{RateGraph, TriangleCandidate};
+pub use maker_mode::{MakerMode, MakerModeConfig, MakerPlanner, MakerDecision, MakerQuote, ClobSpread, ClobSide, ClobVenue, ClobClient, HedgeOrder};
+pub use phoenix::{PhoenixClobClient, PhoenixMarketParams, PhoenixBook, PhoenixOrder, PhoenixFill, PhoenixMarketFeed, PHOENIX_PROGRAM_ID};
+pub use competition_model::{CompetitionModel, CompetitionConfig, LandingObservation, CaptureEstimate, BucketStats};
+pub use microstructure::{MicrostructureMonitor, MicrostructureConfig, MicrostructureSignal, OrderBookSnapshot, BookLevel, TradePrint, Aggressor};
+pub use sim_diff::{ShadowReplay, ReplayConfig, RecordedInput, DecisionInputRecorder, Decision, DecisionDiffReport, SizeChange};
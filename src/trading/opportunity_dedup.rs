@@ -0,0 +1,640 @@
+//! Cross-engine opportunity deduplication
+//!
+//! The enhanced, triangular and route-optimizer engines can surface the same
+//! underlying opportunity. Candidates are keyed by a normalized route signature
+//! and merged within a time window: the highest-fidelity source keeps the
+//! attribution, and an in-flight registry prevents two engines from trading
+//! the same route concurrently.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::types::{ArbitrageOpportunity, Opportunity, OpportunityKind};
+
+use super::arbitrage::EnhancedArbitrageOpportunity;
+use super::route_optimizer::OptimizedRoute;
+use super::triangular::TriangularOpportunity;
+
+/// Engine that produced an opportunity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OpportunitySource {
+    EnhancedArbitrage,
+    Triangular,
+    RouteOptimizer,
+    FlashLoan,
+    CrossChain,
+}
+
+impl From<OpportunityKind> for OpportunitySource {
+    fn from(kind: OpportunityKind) -> Self {
+        match kind {
+            OpportunityKind::Arbitrage => Self::EnhancedArbitrage,
+            OpportunityKind::Triangular => Self::Triangular,
+            OpportunityKind::FlashLoan => Self::FlashLoan,
+            OpportunityKind::CrossChain => Self::CrossChain,
+        }
+    }
+}
+
+impl OpportunitySource {
+    /// Fidelity rank (higher = pricing closer to executable reality)
+    ///
+    /// Enhanced arbitrage prices each leg from live DEX quotes; triangular uses
+    /// cached pair rates; the route optimizer works from historical route stats.
+    pub fn fidelity(&self) -> u8 {
+        match self {
+            Self::EnhancedArbitrage => 4,
+            Self::FlashLoan => 3,
+            Self::Triangular => 2,
+            Self::CrossChain => 2,
+            Self::RouteOptimizer => 1,
+        }
+    }
+}
+
+/// Normalized route signature (token/venue hops, rotation-invariant for cycles)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RouteSignature(String);
+
+impl RouteSignature {
+    /// Build a signature from `(token, venue)` hops
+    ///
+    /// Tokens are upper-cased, venues lower-cased, and cyclic routes are rotated
+    /// to start at the smallest hop so `SOL→USDC→RAY→SOL` and `USDC→RAY→SOL→USDC`
+    /// collapse to the same key.
+    pub fn from_hops(hops: &[(String, String)]) -> Self {
+        let mut normalized: Vec<String> = hops
+            .iter()
+            .map(|(token, venue)| format!("{}@{}", token.trim().to_uppercase(), venue.trim().to_lowercase()))
+            .collect();
+
+        let is_cycle = normalized.len() > 1 && {
+            let first = hops.first().map(|(t, _)| t.to_uppercase());
+            let last = hops.last().map(|(t, _)| t.to_uppercase());
+            first == last
+        };
+
+        if is_cycle {
+            normalized.pop();
+            if let Some(start) = normalized
+                .iter()
+                .enumerate()
+                .min_by(|a, b| a.1.cmp(b.1))
+                .map(|(i, _)| i)
+            {
+                normalized.rotate_left(start);
+            }
+        }
+
+        Self(normalized.join(">"))
+    }
+
+    /// Signature for a two-leg enhanced arbitrage opportunity
+    pub fn from_enhanced(opportunity: &EnhancedArbitrageOpportunity) -> Self {
+        Self::from_hops(&[
+            ("SOL".to_string(), opportunity.buy_exchange.clone()),
+            (opportunity.token_symbol.clone(), opportunity.sell_exchange.clone()),
+            ("SOL".to_string(), String::new()),
+        ])
+    }
+
+    /// Signature for a base/quote arbitrage opportunity from the scanner
+    pub fn from_arbitrage(opportunity: &ArbitrageOpportunity) -> Self {
+        let base = opportunity.pair.base_token.symbol.clone();
+        Self::from_hops(&[
+            (base.clone(), opportunity.buy_exchange.clone()),
+            (opportunity.pair.quote_token.symbol.clone(), opportunity.sell_exchange.clone()),
+            (base, String::new()),
+        ])
+    }
+
+    /// Signature for a triangular opportunity
+    pub fn from_triangular(opportunity: &TriangularOpportunity) -> Self {
+        let mut hops: Vec<(String, String)> = opportunity
+            .path
+            .iter()
+            .map(|hop| (hop.from_token.clone(), hop.dex_name.clone()))
+            .collect();
+        if let Some(last) = opportunity.path.last() {
+            hops.push((last.to_token.clone(), String::new()));
+        }
+        Self::from_hops(&hops)
+    }
+
+    /// Signature for any engine's opportunity in the unified model
+    pub fn from_opportunity(opportunity: &Opportunity) -> Self {
+        Self::from_hops(&opportunity.hops())
+    }
+
+    /// Signature for an optimized route
+    pub fn from_optimized_route(route: &OptimizedRoute) -> Self {
+        let venues = route.dex_path.clone().unwrap_or_default();
+        let hops: Vec<(String, String)> = route
+            .route
+            .iter()
+            .enumerate()
+            .map(|(i, token)| (token.clone(), venues.get(i).cloned().unwrap_or_default()))
+            .collect();
+        Self::from_hops(&hops)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Candidate opportunity submitted to the dedup layer
+#[derive(Debug, Clone)]
+pub struct DedupCandidate {
+    pub signature: RouteSignature,
+    pub source: OpportunitySource,
+    pub opportunity_id: String,
+    pub expected_profit: f64,
+}
+
+impl DedupCandidate {
+    pub fn from_arbitrage(opportunity: &ArbitrageOpportunity) -> Self {
+        Self {
+            signature: RouteSignature::from_arbitrage(opportunity),
+            source: OpportunitySource::EnhancedArbitrage,
+            opportunity_id: format!("{:?}", opportunity.pair),
+            expected_profit: opportunity.profit_percentage,
+        }
+    }
+
+    pub fn from_triangular(opportunity: &TriangularOpportunity) -> Self {
+        Self {
+            signature: RouteSignature::from_triangular(opportunity),
+            source: OpportunitySource::Triangular,
+            opportunity_id: opportunity.id.clone(),
+            expected_profit: opportunity.estimated_net_profit,
+        }
+    }
+
+    pub fn from_optimized_route(route: &OptimizedRoute) -> Self {
+        Self {
+            signature: RouteSignature::from_optimized_route(route),
+            source: OpportunitySource::RouteOptimizer,
+            opportunity_id: route.route.join("->"),
+            expected_profit: route.avg_profit_bps as f64,
+        }
+    }
+
+    /// Candidate for any engine's opportunity in the unified model
+    pub fn from_opportunity(opportunity: &Opportunity) -> Self {
+        Self {
+            signature: RouteSignature::from_opportunity(opportunity),
+            source: OpportunitySource::from(opportunity.kind),
+            opportunity_id: opportunity.id.clone(),
+            expected_profit: opportunity.expected_profit_ui(),
+        }
+    }
+}
+
+/// Result of submitting a candidate
+#[derive(Debug, Clone, PartialEq)]
+pub enum DedupOutcome {
+    /// First sighting within the window
+    Unique,
+    /// Duplicate from a higher-fidelity source; attribution moved to it
+    Replaced { previous_source: OpportunitySource },
+    /// Duplicate of an opportunity already attributed to an equal/better source
+    Duplicate { attributed_to: OpportunitySource },
+    /// The route is currently being executed
+    InFlight,
+}
+
+/// Admission outcomes for every candidate collected in one cycle
+#[derive(Debug, Clone, Default)]
+pub struct CycleAdmission {
+    outcomes: HashMap<(RouteSignature, OpportunitySource), DedupOutcome>,
+}
+
+impl CycleAdmission {
+    /// Outcome of a source's candidate; `None` if it was not collected this cycle
+    pub fn outcome(&self, signature: &RouteSignature, source: OpportunitySource) -> Option<&DedupOutcome> {
+        self.outcomes.get(&(signature.clone(), source))
+    }
+
+    /// Whether the source's candidate may proceed to execution
+    pub fn admits(&self, signature: &RouteSignature, source: OpportunitySource) -> bool {
+        matches!(self.outcome(signature, source), Some(DedupOutcome::Unique | DedupOutcome::Replaced { .. }))
+    }
+}
+
+/// Serializable dedup window entry (remaining cooldown survives a snapshot)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DedupCooldown {
+    pub signature: RouteSignature,
+    pub source: OpportunitySource,
+    pub opportunity_id: String,
+    pub expected_profit: f64,
+    pub sightings: u32,
+    pub remaining_ms: u64,
+}
+
+#[derive(Debug, Clone)]
+struct DedupEntry {
+    candidate: DedupCandidate,
+    first_seen: Instant,
+    sightings: u32,
+}
+
+/// Dedup statistics
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DedupStats {
+    pub unique: u64,
+    pub merged: u64,
+    pub blocked_in_flight: u64,
+    pub by_source: HashMap<String, u64>,
+}
+
+#[derive(Debug, Default)]
+struct DedupState {
+    entries: HashMap<RouteSignature, DedupEntry>,
+    in_flight: HashSet<RouteSignature>,
+    stats: DedupStats,
+}
+
+/// Cross-engine opportunity deduplicator
+#[derive(Debug, Clone)]
+pub struct OpportunityDeduplicator {
+    window: Duration,
+    state: Arc<Mutex<DedupState>>,
+}
+
+impl OpportunityDeduplicator {
+    /// Create a deduplicator merging sightings within `window`
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            state: Arc::new(Mutex::new(DedupState::default())),
+        }
+    }
+
+    /// Submit a candidate; only `Unique` and `Replaced` should proceed to execution
+    pub fn submit(&self, candidate: DedupCandidate) -> DedupOutcome {
+        let mut guard = self.state.lock();
+        let state = &mut *guard;
+        let now = Instant::now();
+        let window = self.window;
+        state.entries.retain(|_, entry| now.duration_since(entry.first_seen) < window);
+
+        *state.stats.by_source.entry(format!("{:?}", candidate.source)).or_insert(0) += 1;
+
+        if state.in_flight.contains(&candidate.signature) {
+            state.stats.blocked_in_flight += 1;
+            return DedupOutcome::InFlight;
+        }
+
+        let outcome = match state.entries.get_mut(&candidate.signature) {
+            None => {
+                state.entries.insert(candidate.signature.clone(), DedupEntry {
+                    candidate,
+                    first_seen: now,
+                    sightings: 1,
+                });
+                DedupOutcome::Unique
+            }
+            Some(entry) => {
+                entry.sightings += 1;
+                if candidate.source.fidelity() > entry.candidate.source.fidelity() {
+                    let previous_source = entry.candidate.source;
+                    debug!("🔁 Opportunity {} re-attributed {:?} -> {:?}",
+                           candidate.signature.as_str(), previous_source, candidate.source);
+                    entry.candidate = candidate;
+                    DedupOutcome::Replaced { previous_source }
+                } else {
+                    DedupOutcome::Duplicate { attributed_to: entry.candidate.source }
+                }
+            }
+        };
+
+        match outcome {
+            DedupOutcome::Unique => state.stats.unique += 1,
+            DedupOutcome::Replaced { .. } | DedupOutcome::Duplicate { .. } => state.stats.merged += 1,
+            DedupOutcome::InFlight => {}
+        }
+        outcome
+    }
+
+    /// Submit every engine's candidates for one cycle before any of them executes
+    ///
+    /// Candidates go in best-first (fidelity, then expected profit), so a route
+    /// reported by several engines is admitted for the highest-fidelity one
+    /// rather than whichever engine's loop happens to run first.
+    pub fn admit_cycle(&self, mut candidates: Vec<DedupCandidate>) -> CycleAdmission {
+        candidates.sort_by(|a, b| {
+            b.source.fidelity().cmp(&a.source.fidelity())
+                .then(b.expected_profit.partial_cmp(&a.expected_profit).unwrap_or(std::cmp::Ordering::Equal))
+        });
+        let mut admission = CycleAdmission::default();
+        for candidate in candidates {
+            let key = (candidate.signature.clone(), candidate.source);
+            let outcome = self.submit(candidate);
+            // A source reporting the same route twice keeps its first (best) outcome
+            admission.outcomes.entry(key).or_insert(outcome);
+        }
+        admission
+    }
+
+    /// Claim a route for execution; `None` if another engine already holds it
+    ///
+    /// The claim is released when the returned guard is dropped.
+    pub fn try_begin_execution(&self, signature: &RouteSignature) -> Option<ExecutionClaim> {
+        let mut state = self.state.lock();
+        if !state.in_flight.insert(signature.clone()) {
+            state.stats.blocked_in_flight += 1;
+            return None;
+        }
+        Some(ExecutionClaim {
+            signature: signature.clone(),
+            state: Arc::clone(&self.state),
+        })
+    }
+
+    /// Source currently credited with an opportunity
+    pub fn attributed_source(&self, signature: &RouteSignature) -> Option<OpportunitySource> {
+        self.state.lock().entries.get(signature).map(|e| e.candidate.source)
+    }
+
+    /// How many times a route was seen within the current window
+    pub fn sightings(&self, signature: &RouteSignature) -> u32 {
+        self.state.lock().entries.get(signature).map_or(0, |e| e.sightings)
+    }
+
+    pub fn get_stats(&self) -> DedupStats {
+        self.state.lock().stats.clone()
+    }
+
+    /// Routes still inside their dedup window, for state snapshots
+    pub fn export_cooldowns(&self) -> Vec<DedupCooldown> {
+        let now = Instant::now();
+        self.state
+            .lock()
+            .entries
+            .values()
+            .filter_map(|entry| {
+                let remaining = self.window.checked_sub(now.duration_since(entry.first_seen))?;
+                Some(DedupCooldown {
+                    signature: entry.candidate.signature.clone(),
+                    source: entry.candidate.source,
+                    opportunity_id: entry.candidate.opportunity_id.clone(),
+                    expected_profit: entry.candidate.expected_profit,
+                    sightings: entry.sightings,
+                    remaining_ms: remaining.as_millis() as u64,
+                })
+            })
+            .collect()
+    }
+
+    /// Re-arm dedup windows captured by `export_cooldowns`
+    pub fn restore_cooldowns(&self, cooldowns: Vec<DedupCooldown>) {
+        let now = Instant::now();
+        let mut state = self.state.lock();
+        for cooldown in cooldowns {
+            let remaining = Duration::from_millis(cooldown.remaining_ms).min(self.window);
+            let first_seen = now.checked_sub(self.window - remaining).unwrap_or(now);
+            state.entries.insert(cooldown.signature.clone(), DedupEntry {
+                candidate: DedupCandidate {
+                    signature: cooldown.signature,
+                    source: cooldown.source,
+                    opportunity_id: cooldown.opportunity_id,
+                    expected_profit: cooldown.expected_profit,
+                },
+                first_seen,
+                sightings: cooldown.sightings,
+            });
+        }
+    }
+}
+
+impl Default for OpportunityDeduplicator {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(10))
+    }
+}
+
+/// RAII claim on a route being executed
+#[derive(Debug)]
+pub struct ExecutionClaim {
+    signature: RouteSignature,
+    state: Arc<Mutex<DedupState>>,
+}
+
+impl ExecutionClaim {
+    pub fn signature(&self) -> &RouteSignature {
+        &self.signature
+    }
+}
+
+impl Drop for ExecutionClaim {
+    fn drop(&mut self) {
+        self.state.lock().in_flight.remove(&self.signature);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hops(route: &[(&str, &str)]) -> RouteSignature {
+        RouteSignature::from_hops(
+            &route.iter().map(|(t, v)| (t.to_string(), v.to_string())).collect::<Vec<_>>(),
+        )
+    }
+
+    fn candidate(signature: RouteSignature, source: OpportunitySource) -> DedupCandidate {
+        DedupCandidate {
+            signature,
+            source,
+            opportunity_id: "op".to_string(),
+            expected_profit: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_cycle_rotation_normalizes() {
+        let a = hops(&[("SOL", "Orca"), ("USDC", "Raydium"), ("RAY", "orca"), ("SOL", "")]);
+        let b = hops(&[("usdc", "raydium"), ("RAY", "ORCA"), ("SOL", "orca"), ("USDC", "")]);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_higher_fidelity_source_takes_attribution() {
+        let dedup = OpportunityDeduplicator::default();
+        let sig = hops(&[("SOL", "orca"), ("USDC", "raydium"), ("SOL", "")]);
+
+        assert_eq!(dedup.submit(candidate(sig.clone(), OpportunitySource::RouteOptimizer)), DedupOutcome::Unique);
+        assert_eq!(
+            dedup.submit(candidate(sig.clone(), OpportunitySource::EnhancedArbitrage)),
+            DedupOutcome::Replaced { previous_source: OpportunitySource::RouteOptimizer }
+        );
+        assert_eq!(
+            dedup.submit(candidate(sig.clone(), OpportunitySource::Triangular)),
+            DedupOutcome::Duplicate { attributed_to: OpportunitySource::EnhancedArbitrage }
+        );
+        assert_eq!(dedup.sightings(&sig), 3);
+    }
+
+    #[test]
+    fn test_cycle_admits_highest_fidelity_source_regardless_of_order() {
+        let dedup = OpportunityDeduplicator::default();
+        let shared = hops(&[("SOL", "orca"), ("USDC", "raydium"), ("SOL", "")]);
+        let own = hops(&[("SOL", "orca"), ("RAY", "raydium"), ("SOL", "")]);
+
+        // The route optimizer's loop runs first in the cycle, but only arbitrage prices from live quotes
+        let admission = dedup.admit_cycle(vec![
+            candidate(shared.clone(), OpportunitySource::RouteOptimizer),
+            candidate(own.clone(), OpportunitySource::RouteOptimizer),
+            candidate(shared.clone(), OpportunitySource::Triangular),
+            candidate(shared.clone(), OpportunitySource::EnhancedArbitrage),
+        ]);
+        assert!(admission.admits(&shared, OpportunitySource::EnhancedArbitrage));
+        assert!(!admission.admits(&shared, OpportunitySource::RouteOptimizer));
+        assert_eq!(
+            admission.outcome(&shared, OpportunitySource::Triangular),
+            Some(&DedupOutcome::Duplicate { attributed_to: OpportunitySource::EnhancedArbitrage })
+        );
+        assert!(admission.admits(&own, OpportunitySource::RouteOptimizer));
+        assert_eq!(admission.outcome(&own, OpportunitySource::FlashLoan), None);
+        assert_eq!(dedup.attributed_source(&shared), Some(OpportunitySource::EnhancedArbitrage));
+    }
+
+    #[test]
+    fn test_unified_opportunity_matches_engine_signature() {
+        use crate::trading::triangular::{TokenHop, TriangularOpportunity};
+        use crate::types::IntoOpportunity;
+
+        let hop = |from: &str, to: &str, dex: &str| TokenHop {
+            from_token: from.to_string(),
+            to_token: to.to_string(),
+            dex_name: dex.to_string(),
+            exchange_rate: 1.0,
+            liquidity_usd: 10_000.0,
+            swap_fee_bps: 25,
+            slot: None,
+        };
+        let triangular = TriangularOpportunity {
+            id: "tri".to_string(),
+            path: vec![hop("SOL", "USDC", "orca"), hop("USDC", "RAY", "raydium"), hop("RAY", "SOL", "orca")],
+            estimated_net_profit: 0.004,
+            total_cost_bps: 75,
+            liquidity_constraint: 5_000.0,
+            execution_risk_score: 0.2,
+            dexs_involved: vec!["orca".to_string(), "raydium".to_string()],
+            estimated_duration_ms: 800,
+            expires_at: chrono::Utc::now() + chrono::Duration::seconds(10),
+        };
+
+        let unified = triangular.to_opportunity();
+        assert_eq!(RouteSignature::from_opportunity(&unified), RouteSignature::from_triangular(&triangular));
+        assert_eq!(OpportunitySource::from(unified.kind), OpportunitySource::Triangular);
+        assert_eq!(unified.expected_profit, 20_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_supervised_engines_reporting_same_route_admit_higher_fidelity() {
+        use crate::monitoring::{ComponentContext, ComponentSpec, Supervisor};
+        use crate::trading::scan_schedule::{FeedEvents, ScanScheduleConfig, ScanScheduler, ENGINE_FINDINGS_FEED};
+        use crate::trading::triangular::TokenHop;
+
+        let hop = |from: &str, to: &str, dex: &str| TokenHop {
+            from_token: from.to_string(),
+            to_token: to.to_string(),
+            dex_name: dex.to_string(),
+            exchange_rate: 1.0,
+            liquidity_usd: 10_000.0,
+            swap_fee_bps: 25,
+            slot: None,
+        };
+        // The scanner's SOL/USDC spread (buy on Raydium, sell on Orca) seen by the triangular engine as a two-leg cycle
+        let arbitrage = ArbitrageOpportunity::default();
+        let triangular = TriangularOpportunity {
+            id: "tri".to_string(),
+            path: vec![hop("SOL", "USDC", "Raydium"), hop("USDC", "SOL", "Orca")],
+            estimated_net_profit: 0.004,
+            total_cost_bps: 50,
+            liquidity_constraint: 5_000.0,
+            execution_risk_score: 0.2,
+            dexs_involved: vec!["Raydium".to_string(), "Orca".to_string()],
+            estimated_duration_ms: 800,
+            expires_at: chrono::Utc::now() + chrono::Duration::seconds(10),
+        };
+        let shared = RouteSignature::from_arbitrage(&arbitrage);
+        assert_eq!(shared, RouteSignature::from_triangular(&triangular));
+
+        let events = Arc::new(FeedEvents::default());
+        let mut schedule = ScanScheduleConfig::default().schedule("trading_cycle").clone();
+        schedule.interval_ms = 60_000;
+        schedule.min_gap = 0.0;
+        let cycle = ScanScheduler::new(schedule, &events);
+
+        let arbitrage_findings = Arc::new(Mutex::new(Vec::new()));
+        let triangular_findings = Arc::new(Mutex::new(Vec::new()));
+        let mut supervisor = Supervisor::new(Duration::from_secs(1));
+        for (name, reports_arbitrage) in [("arbitrage_engine", true), ("triangular_engine", false)] {
+            let (events, arbitrage, triangular) = (events.clone(), arbitrage.clone(), triangular.clone());
+            let (arbitrage_findings, triangular_findings) = (arbitrage_findings.clone(), triangular_findings.clone());
+            supervisor.add(ComponentSpec::new(name, move |ctx: ComponentContext| {
+                let (events, arbitrage, triangular) = (events.clone(), arbitrage.clone(), triangular.clone());
+                let (arbitrage_findings, triangular_findings) = (arbitrage_findings.clone(), triangular_findings.clone());
+                async move {
+                    ctx.mark_ready();
+                    loop {
+                        if reports_arbitrage {
+                            *arbitrage_findings.lock() = vec![arbitrage.clone()];
+                        } else {
+                            *triangular_findings.lock() = vec![triangular.clone()];
+                        }
+                        events.publish(ENGINE_FINDINGS_FEED);
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                    }
+                }
+            }));
+        }
+        supervisor.start().await.unwrap();
+
+        // Each wake drains what the engines stored; keep cycling until both have reported
+        let dedup = OpportunityDeduplicator::default();
+        let mut collected: Vec<DedupCandidate> = Vec::new();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while collected.iter().map(|c| c.source).collect::<HashSet<_>>().len() < 2 {
+                cycle.wait(cycle.base_interval()).await;
+                collected.extend(std::mem::take(&mut *arbitrage_findings.lock()).iter().map(DedupCandidate::from_arbitrage));
+                collected.extend(std::mem::take(&mut *triangular_findings.lock()).iter().map(DedupCandidate::from_triangular));
+            }
+        })
+        .await
+        .expect("both engines report within the timeout");
+        supervisor.shutdown().await;
+
+        // Triangular is listed first, but arbitrage prices each leg from live quotes
+        collected.sort_by_key(|c| c.source != OpportunitySource::Triangular);
+        let admission = dedup.admit_cycle(collected);
+        assert!(admission.admits(&shared, OpportunitySource::EnhancedArbitrage));
+        assert_eq!(
+            admission.outcome(&shared, OpportunitySource::Triangular),
+            Some(&DedupOutcome::Duplicate { attributed_to: OpportunitySource::EnhancedArbitrage })
+        );
+        assert_eq!(dedup.attributed_source(&shared), Some(OpportunitySource::EnhancedArbitrage));
+    }
+
+    #[test]
+    fn test_in_flight_blocks_double_execution() {
+        let dedup = OpportunityDeduplicator::default();
+        let sig = hops(&[("SOL", "orca"), ("USDC", "raydium"), ("SOL", "")]);
+
+        let claim = dedup.try_begin_execution(&sig).expect("first claim succeeds");
+        assert!(dedup.try_begin_execution(&sig).is_none());
+        assert_eq!(dedup.submit(candidate(sig.clone(), OpportunitySource::Triangular)), DedupOutcome::InFlight);
+
+        drop(claim);
+        assert!(dedup.try_begin_execution(&sig).is_some());
+    }
+}
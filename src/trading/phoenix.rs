@@ -0,0 +1,798 @@
+//! # Phoenix CLOB Client
+//!
+//! Maker mode's venue on Phoenix: post-only limit orders, cancels by order
+//! ID and fill detection, all against the on-chain market account.
+//!
+//! The market account is a fixed header followed by the FIFO market: bids,
+//! asks and the seat registry are red-black trees laid out in place (nodes
+//! of four `u32` registers - left, right, parent, color - then key and
+//! value, addressed 1-based). The book is read by walking those trees, so
+//! no Phoenix SDK is needed. Orders are placed without deposited funds,
+//! straight from the wallet's token accounts; the wallet needs a seat on
+//! the market, which the market's seat manager grants.
+//!
+//! Trades are read from the event log Phoenix writes into every transaction
+//! that touches a market (a self-CPI `Log` instruction carrying a header and
+//! Borsh-encoded events); fills and book snapshots feed the microstructure
+//! signals maker mode and the other strategies read.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Result};
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use serde_json::Value;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::{Transaction, VersionedTransaction},
+};
+use tokio::sync::Mutex;
+use tracing::{debug, info, warn};
+
+use super::execution::admit_shared;
+use super::maker_mode::{ClobClient, ClobSide, ClobVenue, MakerQuote};
+use super::microstructure::{Aggressor, BookLevel, MicrostructureMonitor, OrderBookSnapshot, TradePrint};
+use crate::analytics::TransactionSource;
+use crate::monitoring::HeartbeatHandle;
+use crate::security::dust::TOKEN_PROGRAM_ID;
+
+/// Phoenix v1 program
+pub const PHOENIX_PROGRAM_ID: &str = "PhoeNiXZ8ByJGLkxNfZRnkUfjvmuYqLR89jjFHGqdXY";
+/// Associated token account program
+const ASSOCIATED_TOKEN_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+
+const HEADER_LEN: usize = 576;
+/// Bids tree offset: header, then 32 padding words and six `u64` market fields
+const BIDS_OFFSET: usize = HEADER_LEN + 304;
+/// Tree root and padding, then the allocator's size, bump index and free list head
+const TREE_HEADER_LEN: usize = 32;
+const NODE_REGISTERS_LEN: usize = 16;
+/// Registers, order ID (price in ticks, sequence number), resting order (4 x u64)
+const ORDER_NODE_LEN: usize = NODE_REGISTERS_LEN + 16 + 32;
+/// Registers, trader pubkey, trader state (4 x u64 + 8 padding words)
+const TRADER_NODE_LEN: usize = NODE_REGISTERS_LEN + 32 + 96;
+
+const PLACE_LIMIT_ORDER: u8 = 2;
+const CANCEL_MULTIPLE_ORDERS_BY_ID: u8 = 10;
+const LOG: u8 = 15;
+
+/// Event log header: instruction, sequence number, timestamp, slot, market, signer, event count
+const LOG_HEADER_LEN: usize = 1 + 8 + 8 + 8 + 32 + 32 + 2;
+const EVENT_HEADER: u8 = 1;
+const EVENT_FILL: u8 = 2;
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap_or_default()))
+        .ok_or_else(|| anyhow!("Phoenix market account truncated at {}", offset))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64> {
+    data.get(offset..offset + 8)
+        .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap_or_default()))
+        .ok_or_else(|| anyhow!("Phoenix market account truncated at {}", offset))
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Result<Pubkey> {
+    data.get(offset..offset + 32)
+        .and_then(|bytes| Pubkey::try_from(bytes).ok())
+        .ok_or_else(|| anyhow!("Phoenix market account truncated at {}", offset))
+}
+
+fn side_byte(side: ClobSide) -> u8 {
+    match side {
+        ClobSide::Bid => 0,
+        ClobSide::Ask => 1,
+    }
+}
+
+/// Market parameters from the Phoenix market header
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhoenixMarketParams {
+    /// Label the market is known by elsewhere, e.g. `SOL/USDC`; the address unless configured
+    pub name: String,
+    pub address: Pubkey,
+    pub bids_size: usize,
+    pub asks_size: usize,
+    pub num_seats: usize,
+    pub base_mint: Pubkey,
+    pub base_vault: Pubkey,
+    pub base_decimals: u32,
+    pub base_lot_size: u64,
+    pub quote_mint: Pubkey,
+    pub quote_vault: Pubkey,
+    pub quote_decimals: u32,
+    pub quote_lot_size: u64,
+    pub tick_size_in_quote_atoms_per_base_unit: u64,
+    pub raw_base_units_per_base_unit: u32,
+}
+
+impl PhoenixMarketParams {
+    pub fn parse(address: Pubkey, data: &[u8]) -> Result<Self> {
+        let params = Self {
+            name: address.to_string(),
+            address,
+            bids_size: read_u64(data, 16)? as usize,
+            asks_size: read_u64(data, 24)? as usize,
+            num_seats: read_u64(data, 32)? as usize,
+            base_decimals: read_u32(data, 40)?,
+            base_mint: read_pubkey(data, 48)?,
+            base_vault: read_pubkey(data, 80)?,
+            base_lot_size: read_u64(data, 112)?,
+            quote_decimals: read_u32(data, 120)?,
+            quote_mint: read_pubkey(data, 128)?,
+            quote_vault: read_pubkey(data, 160)?,
+            quote_lot_size: read_u64(data, 192)?,
+            tick_size_in_quote_atoms_per_base_unit: read_u64(data, 200)?,
+            raw_base_units_per_base_unit: read_u32(data, 312)?.max(1),
+        };
+        if params.base_lot_size == 0 || params.quote_lot_size == 0 || params.tick_size_in_quote_atoms_per_base_unit == 0 {
+            bail!("{} is not an initialized Phoenix market", address);
+        }
+        Ok(params)
+    }
+
+    fn asks_offset(&self) -> usize {
+        BIDS_OFFSET + TREE_HEADER_LEN + ORDER_NODE_LEN * self.bids_size
+    }
+
+    fn traders_offset(&self) -> usize {
+        self.asks_offset() + TREE_HEADER_LEN + ORDER_NODE_LEN * self.asks_size
+    }
+
+    /// Quote tokens per base token of one tick
+    pub fn tick_size(&self) -> f64 {
+        self.ticks_to_price(1)
+    }
+
+    pub fn ticks_to_price(&self, ticks: u64) -> f64 {
+        ticks as f64 * self.tick_size_in_quote_atoms_per_base_unit as f64
+            / 10f64.powi(self.quote_decimals as i32)
+            / self.raw_base_units_per_base_unit as f64
+    }
+
+    /// Price in ticks, rounded away from crossing: bids down, asks up
+    pub fn price_to_ticks(&self, price: f64, side: ClobSide) -> u64 {
+        let ticks = price * self.raw_base_units_per_base_unit as f64 * 10f64.powi(self.quote_decimals as i32)
+            / self.tick_size_in_quote_atoms_per_base_unit as f64;
+        // Float noise must not push an on-tick price to the next tick
+        let ticks = (ticks * 1e6).round() / 1e6;
+        match side {
+            ClobSide::Bid => ticks.floor() as u64,
+            ClobSide::Ask => ticks.ceil() as u64,
+        }
+    }
+
+    pub fn lots_to_size(&self, lots: u64) -> f64 {
+        lots as f64 * self.base_lot_size as f64 / 10f64.powi(self.base_decimals as i32)
+    }
+
+    pub fn size_to_lots(&self, size: f64) -> u64 {
+        (size * 10f64.powi(self.base_decimals as i32) / self.base_lot_size as f64 + 1e-9).floor() as u64
+    }
+}
+
+/// A resting order as stored in the market account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhoenixOrder {
+    pub side: ClobSide,
+    pub price_in_ticks: u64,
+    pub order_sequence_number: u64,
+    /// Node address of the owner's seat in the trader tree
+    pub trader_index: u64,
+    pub num_base_lots: u64,
+}
+
+impl PhoenixOrder {
+    /// Venue order ID handed to maker mode: `bid|ask:price_in_ticks:sequence_number`
+    pub fn order_id(&self) -> String {
+        let side = match self.side {
+            ClobSide::Bid => "bid",
+            ClobSide::Ask => "ask",
+        };
+        format!("{}:{}:{}", side, self.price_in_ticks, self.order_sequence_number)
+    }
+
+    pub fn parse_order_id(order_id: &str) -> Result<(ClobSide, u64, u64)> {
+        let mut parts = order_id.split(':');
+        let side = match parts.next() {
+            Some("bid") => ClobSide::Bid,
+            Some("ask") => ClobSide::Ask,
+            _ => bail!("Not a Phoenix order ID: {}", order_id),
+        };
+        let (Some(ticks), Some(sequence), None) = (parts.next(), parts.next(), parts.next()) else {
+            bail!("Not a Phoenix order ID: {}", order_id);
+        };
+        Ok((side, ticks.parse()?, sequence.parse()?))
+    }
+}
+
+/// Live nodes of an in-place red-black tree, in key order; returns each node's payload offset
+fn tree_payloads(data: &[u8], tree_offset: usize, capacity: usize, node_len: usize) -> Result<Vec<usize>> {
+    let node_offset = |index: u32| tree_offset + TREE_HEADER_LEN + (index as usize - 1) * node_len;
+    let valid = |index: u32| index != 0 && index as usize <= capacity;
+    let mut payloads = Vec::new();
+    let mut stack = Vec::new();
+    let mut current = read_u32(data, tree_offset)?;
+    while valid(current) || !stack.is_empty() {
+        while valid(current) {
+            stack.push(current);
+            current = read_u32(data, node_offset(current))?;
+            if stack.len() > capacity {
+                bail!("Phoenix tree at {} is corrupt", tree_offset);
+            }
+        }
+        let Some(index) = stack.pop() else { break };
+        payloads.push(node_offset(index) + NODE_REGISTERS_LEN);
+        if payloads.len() > capacity {
+            bail!("Phoenix tree at {} is corrupt", tree_offset);
+        }
+        current = read_u32(data, node_offset(index) + 4)?;
+    }
+    Ok(payloads)
+}
+
+/// Resting orders and seats decoded from a market account
+#[derive(Debug, Clone, Default)]
+pub struct PhoenixBook {
+    pub orders: Vec<PhoenixOrder>,
+    seats: Vec<(Pubkey, u64)>,
+}
+
+impl PhoenixBook {
+    pub fn parse(params: &PhoenixMarketParams, data: &[u8]) -> Result<Self> {
+        let mut orders = Vec::new();
+        for (side, offset, capacity) in [
+            (ClobSide::Bid, BIDS_OFFSET, params.bids_size),
+            (ClobSide::Ask, params.asks_offset(), params.asks_size),
+        ] {
+            for payload in tree_payloads(data, offset, capacity, ORDER_NODE_LEN)? {
+                orders.push(PhoenixOrder {
+                    side,
+                    price_in_ticks: read_u64(data, payload)?,
+                    order_sequence_number: read_u64(data, payload + 8)?,
+                    trader_index: read_u64(data, payload + 16)?,
+                    num_base_lots: read_u64(data, payload + 24)?,
+                });
+            }
+        }
+        let traders_offset = params.traders_offset();
+        let mut seats = Vec::new();
+        for payload in tree_payloads(data, traders_offset, params.num_seats, TRADER_NODE_LEN)? {
+            let index = (payload - NODE_REGISTERS_LEN - traders_offset - TREE_HEADER_LEN) / TRADER_NODE_LEN + 1;
+            seats.push((read_pubkey(data, payload)?, index as u64));
+        }
+        Ok(Self { orders, seats })
+    }
+
+    /// Trader index of `trader`'s seat, if it has one
+    pub fn trader_index(&self, trader: &Pubkey) -> Option<u64> {
+        self.seats.iter().find(|(key, _)| key == trader).map(|(_, index)| *index)
+    }
+
+    /// Orders of the trader at `trader_index`
+    pub fn orders_of(&self, trader_index: u64) -> impl Iterator<Item = &PhoenixOrder> {
+        self.orders.iter().filter(move |order| order.trader_index == trader_index)
+    }
+
+    /// Aggregated top `depth` levels per side, best first
+    pub fn snapshot(&self, params: &PhoenixMarketParams, depth: usize) -> OrderBookSnapshot {
+        let levels = |side: ClobSide| {
+            let mut by_tick: Vec<(u64, u64)> = Vec::new();
+            for order in self.orders.iter().filter(|order| order.side == side) {
+                match by_tick.iter_mut().find(|(tick, _)| *tick == order.price_in_ticks) {
+                    Some((_, lots)) => *lots += order.num_base_lots,
+                    None => by_tick.push((order.price_in_ticks, order.num_base_lots)),
+                }
+            }
+            match side {
+                ClobSide::Bid => by_tick.sort_by_key(|(tick, _)| std::cmp::Reverse(*tick)),
+                ClobSide::Ask => by_tick.sort_by_key(|(tick, _)| *tick),
+            }
+            by_tick
+                .into_iter()
+                .take(depth)
+                .map(|(tick, lots)| BookLevel { price: params.ticks_to_price(tick), size: params.lots_to_size(lots) })
+                .collect()
+        };
+        OrderBookSnapshot {
+            venue: ClobVenue::Phoenix,
+            market: params.name.clone(),
+            bids: levels(ClobSide::Bid),
+            asks: levels(ClobSide::Ask),
+            at: Utc::now(),
+        }
+    }
+}
+
+/// Post-only orders and cancels on Phoenix markets, signed with one wallet
+pub struct PhoenixClobClient {
+    rpc: Arc<RpcClient>,
+    keypair: Arc<Keypair>,
+    markets: HashMap<String, PhoenixMarketParams>,
+}
+
+impl PhoenixClobClient {
+    /// Load the headers of `markets`, given as `NAME=address` or a bare address
+    pub async fn connect(rpc: Arc<RpcClient>, keypair: Arc<Keypair>, markets: &[String]) -> Result<Self> {
+        let mut loaded = HashMap::new();
+        for market in markets {
+            let (name, address) = match market.split_once('=') {
+                Some((name, address)) => (Some(name.trim()), address.trim()),
+                None => (None, market.trim()),
+            };
+            let address = Pubkey::from_str(address)?;
+            let account = rpc.get_account(&address).await?;
+            if account.owner.to_string() != PHOENIX_PROGRAM_ID {
+                bail!("{} is not owned by the Phoenix program", address);
+            }
+            let mut params = PhoenixMarketParams::parse(address, &account.data)?;
+            if let Some(name) = name {
+                params.name = name.to_string();
+            }
+            info!("📗 Phoenix market {} ({}): base {} / quote {}, tick {}",
+                  params.name, address, params.base_mint, params.quote_mint, params.tick_size());
+            loaded.insert(params.name.clone(), params);
+        }
+        Ok(Self { rpc, keypair, markets: loaded })
+    }
+
+    pub fn markets(&self) -> impl Iterator<Item = &PhoenixMarketParams> {
+        self.markets.values()
+    }
+
+    /// Market trading `base_mint` against `quote_mint`
+    pub fn market_for(&self, base_mint: &str, quote_mint: &str) -> Option<&PhoenixMarketParams> {
+        self.markets
+            .values()
+            .find(|params| params.base_mint.to_string() == base_mint && params.quote_mint.to_string() == quote_mint)
+    }
+
+    /// Market by name
+    pub fn market(&self, market: &str) -> Option<&PhoenixMarketParams> {
+        self.markets.get(market)
+    }
+
+    fn params(&self, market: &str) -> Result<&PhoenixMarketParams> {
+        self.market(market).ok_or_else(|| anyhow!("Phoenix market {} is not configured", market))
+    }
+
+    pub async fn book(&self, market: &str) -> Result<PhoenixBook> {
+        let params = self.params(market)?;
+        let account = self.rpc.get_account(&params.address).await?;
+        PhoenixBook::parse(params, &account.data)
+    }
+
+    /// Top of the book, for the microstructure signals
+    pub async fn order_book(&self, market: &str, depth: usize) -> Result<OrderBookSnapshot> {
+        Ok(self.book(market).await?.snapshot(self.params(market)?, depth))
+    }
+
+    fn program() -> Pubkey {
+        Pubkey::from_str(PHOENIX_PROGRAM_ID).unwrap_or_default()
+    }
+
+    fn associated_token_address(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+        let token_program = Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap_or_default();
+        let program = Pubkey::from_str(ASSOCIATED_TOKEN_PROGRAM_ID).unwrap_or_default();
+        Pubkey::find_program_address(&[owner.as_ref(), token_program.as_ref(), mint.as_ref()], &program).0
+    }
+
+    /// Accounts shared by order placement and cancels; the seat follows the trader when placing
+    fn order_accounts(params: &PhoenixMarketParams, trader: &Pubkey, with_seat: bool) -> Vec<AccountMeta> {
+        let program = Self::program();
+        let log_authority = Pubkey::find_program_address(&[b"log"], &program).0;
+        let mut accounts = vec![
+            AccountMeta::new_readonly(program, false),
+            AccountMeta::new_readonly(log_authority, false),
+            AccountMeta::new(params.address, false),
+            AccountMeta::new_readonly(*trader, true),
+        ];
+        if with_seat {
+            let seat = Pubkey::find_program_address(&[b"seat", params.address.as_ref(), trader.as_ref()], &program).0;
+            accounts.push(AccountMeta::new_readonly(seat, false));
+        }
+        accounts.extend([
+            AccountMeta::new(Self::associated_token_address(trader, &params.base_mint), false),
+            AccountMeta::new(Self::associated_token_address(trader, &params.quote_mint), false),
+            AccountMeta::new(params.base_vault, false),
+            AccountMeta::new(params.quote_vault, false),
+            AccountMeta::new_readonly(Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap_or_default(), false),
+        ]);
+        accounts
+    }
+
+    /// `PlaceLimitOrder` with a post-only packet that fails instead of crossing
+    pub fn place_post_only_ix(params: &PhoenixMarketParams, trader: &Pubkey, side: ClobSide, price_in_ticks: u64, num_base_lots: u64, client_order_id: u128) -> Instruction {
+        let mut data = vec![PLACE_LIMIT_ORDER, 0, side_byte(side)];
+        data.extend_from_slice(&price_in_ticks.to_le_bytes());
+        data.extend_from_slice(&num_base_lots.to_le_bytes());
+        data.extend_from_slice(&client_order_id.to_le_bytes());
+        // reject_post_only, use_only_deposited_funds, no last valid slot / timestamp, fail_silently
+        data.extend_from_slice(&[1, 0, 0, 0, 0]);
+        Instruction { program_id: Self::program(), accounts: Self::order_accounts(params, trader, true), data }
+    }
+
+    pub fn cancel_ix(params: &PhoenixMarketParams, trader: &Pubkey, side: ClobSide, price_in_ticks: u64, order_sequence_number: u64) -> Instruction {
+        let mut data = vec![CANCEL_MULTIPLE_ORDERS_BY_ID];
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.push(side_byte(side));
+        data.extend_from_slice(&price_in_ticks.to_le_bytes());
+        data.extend_from_slice(&order_sequence_number.to_le_bytes());
+        Instruction { program_id: Self::program(), accounts: Self::order_accounts(params, trader, false), data }
+    }
+
+    async fn send(&self, instruction: Instruction, idempotency_key: &str) -> Result<Signature> {
+        let owner = self.keypair.pubkey();
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(&[instruction], Some(&owner), &[self.keypair.as_ref()], blockhash);
+        admit_shared(&owner.to_string(), Some(idempotency_key), &VersionedTransaction::from(tx.clone()))?;
+        Ok(self.rpc.send_and_confirm_transaction(&tx).await?)
+    }
+}
+
+#[async_trait]
+impl ClobClient for PhoenixClobClient {
+    fn venue(&self) -> ClobVenue {
+        ClobVenue::Phoenix
+    }
+
+    async fn place_post_only(&self, quote: &MakerQuote) -> Result<String> {
+        let params = self.params(&quote.market)?;
+        let trader = self.keypair.pubkey();
+        let price_in_ticks = params.price_to_ticks(quote.price, quote.side);
+        let num_base_lots = params.size_to_lots(quote.size);
+        if price_in_ticks == 0 || num_base_lots == 0 {
+            bail!("{} @ {} is below one lot or tick on {}", quote.size, quote.price, quote.market);
+        }
+        let before = self.book(&quote.market).await?;
+        let Some(trader_index) = before.trader_index(&trader) else {
+            bail!("{} has no seat on Phoenix market {}; request one from the market's seat manager", trader, quote.market);
+        };
+        let existing: Vec<u64> = before.orders_of(trader_index).map(|order| order.order_sequence_number).collect();
+
+        let client_order_id = uuid::Uuid::new_v4().as_u128();
+        let instruction = Self::place_post_only_ix(params, &trader, quote.side, price_in_ticks, num_base_lots, client_order_id);
+        let signature = self.send(instruction, &format!("phoenix:{}:{}", quote.market, client_order_id)).await?;
+        debug!("📗 Phoenix post-only {:?} {} lots @ {} ticks: {}", quote.side, num_base_lots, price_in_ticks, signature);
+
+        // The sequence number is only known once the order rests
+        let after = self.book(&quote.market).await?;
+        let order_id = after
+            .orders_of(trader_index)
+            .filter(|order| order.side == quote.side && order.price_in_ticks == price_in_ticks)
+            .find(|order| !existing.contains(&order.order_sequence_number))
+            .map(PhoenixOrder::order_id);
+        order_id.ok_or_else(|| anyhow!("Post-only order {} landed but is not resting on {}", signature, quote.market))
+    }
+
+    async fn cancel(&self, market: &str, order_id: &str) -> Result<()> {
+        let params = self.params(market)?;
+        let (side, price_in_ticks, sequence) = PhoenixOrder::parse_order_id(order_id)?;
+        let instruction = Self::cancel_ix(params, &self.keypair.pubkey(), side, price_in_ticks, sequence);
+        self.send(instruction, &format!("phoenix-cancel:{}:{}", market, order_id)).await?;
+        Ok(())
+    }
+
+    async fn resting_size(&self, market: &str, order_id: &str) -> Result<Option<f64>> {
+        let params = self.params(market)?;
+        let (side, price_in_ticks, sequence) = PhoenixOrder::parse_order_id(order_id)?;
+        let book = self.book(market).await?;
+        let Some(trader_index) = book.trader_index(&self.keypair.pubkey()) else {
+            return Ok(Some(0.0));
+        };
+        let lots = book
+            .orders_of(trader_index)
+            .find(|order| order.side == side && order.price_in_ticks == price_in_ticks && order.order_sequence_number == sequence)
+            .map(|order| order.num_base_lots)
+            .unwrap_or(0);
+        Ok(Some(params.lots_to_size(lots)))
+    }
+}
+
+/// Size of each event after its tag, up to the last known event type
+fn event_len(tag: u8) -> Option<usize> {
+    match tag {
+        EVENT_HEADER => Some(LOG_HEADER_LEN),
+        // Fill: index, maker, sequence number, price, lots filled, lots remaining
+        EVENT_FILL => Some(2 + 32 + 8 * 4),
+        // Place: index, sequence number, client order ID, price, lots placed
+        3 => Some(2 + 8 + 16 + 8 + 8),
+        // Reduce: index, sequence number, price, lots removed, lots remaining
+        4 => Some(2 + 8 * 4),
+        // Evict / ExpiredOrder: index, maker, sequence number, price, lots
+        5 | 9 => Some(2 + 32 + 8 * 3),
+        // FillSummary: index, client order ID, base lots, quote lots, fee
+        6 => Some(2 + 16 + 8 * 3),
+        // Fee: index, quote lots collected
+        7 => Some(2 + 8),
+        // TimeInForce: index, sequence number, last valid slot, last valid timestamp
+        8 => Some(2 + 8 * 3),
+        _ => None,
+    }
+}
+
+/// A maker order filled on a Phoenix market, from the event log
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhoenixFill {
+    pub market: Pubkey,
+    pub timestamp: i64,
+    pub maker_side: ClobSide,
+    pub price_in_ticks: u64,
+    pub base_lots_filled: u64,
+}
+
+impl PhoenixFill {
+    /// Fills in the data of one `Log` instruction; decoding stops at the first unknown event
+    pub fn parse_log(data: &[u8]) -> Vec<PhoenixFill> {
+        let mut fills = Vec::new();
+        if data.first() != Some(&LOG) {
+            return fills;
+        }
+        let (mut market, mut timestamp) = (None, 0);
+        let mut offset = 1;
+        while let Some(&tag) = data.get(offset) {
+            let Some(len) = event_len(tag) else { break };
+            let event = offset + 1;
+            if data.len() < event + len {
+                break;
+            }
+            match tag {
+                EVENT_HEADER => {
+                    timestamp = read_u64(data, event + 9).unwrap_or_default() as i64;
+                    market = read_pubkey(data, event + 25).ok();
+                }
+                EVENT_FILL => {
+                    let sequence = read_u64(data, event + 34).unwrap_or_default();
+                    fills.extend(market.map(|market| PhoenixFill {
+                        market,
+                        timestamp,
+                        // Bid sequence numbers are stored with the top bit set
+                        maker_side: if sequence >> 63 == 1 { ClobSide::Bid } else { ClobSide::Ask },
+                        price_in_ticks: read_u64(data, event + 42).unwrap_or_default(),
+                        base_lots_filled: read_u64(data, event + 50).unwrap_or_default(),
+                    }));
+                }
+                _ => {}
+            }
+            offset = event + len;
+        }
+        fills
+    }
+
+    /// Fills of every Phoenix `Log` inner instruction of a `jsonParsed` transaction
+    pub fn from_transaction(tx: &Value) -> Vec<PhoenixFill> {
+        tx["meta"]["innerInstructions"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|inner| inner["instructions"].as_array())
+            .flatten()
+            .filter(|ix| ix["programId"].as_str() == Some(PHOENIX_PROGRAM_ID))
+            .filter_map(|ix| bs58::decode(ix["data"].as_str()?).into_vec().ok())
+            .flat_map(|data| PhoenixFill::parse_log(&data))
+            .collect()
+    }
+
+    /// The fill as a trade print; the taker is the aggressor, opposite the maker
+    pub fn print(&self, params: &PhoenixMarketParams) -> TradePrint {
+        TradePrint {
+            venue: ClobVenue::Phoenix,
+            market: params.name.clone(),
+            price: params.ticks_to_price(self.price_in_ticks),
+            size: params.lots_to_size(self.base_lots_filled),
+            aggressor: Some(match self.maker_side {
+                ClobSide::Bid => Aggressor::Sell,
+                ClobSide::Ask => Aggressor::Buy,
+            }),
+            at: Utc.timestamp_opt(self.timestamp, 0).single().unwrap_or_else(Utc::now),
+        }
+    }
+}
+
+/// Feeds the books and trades of the configured Phoenix markets into the microstructure signals
+pub struct PhoenixMarketFeed {
+    client: Arc<PhoenixClobClient>,
+    transactions: Arc<dyn TransactionSource>,
+    monitor: Arc<MicrostructureMonitor>,
+    depth: usize,
+    poll_interval: Duration,
+    /// Newest signature already read, per market
+    cursors: Mutex<HashMap<String, String>>,
+}
+
+impl PhoenixMarketFeed {
+    pub fn new(client: Arc<PhoenixClobClient>, transactions: Arc<dyn TransactionSource>, monitor: Arc<MicrostructureMonitor>) -> Self {
+        let depth = monitor.config().depth_levels;
+        Self { client, transactions, monitor, depth, poll_interval: Duration::from_secs(2), cursors: Mutex::new(HashMap::new()) }
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+
+    /// Trade prints of `market` since the last poll, oldest first
+    async fn new_trades(&self, params: &PhoenixMarketParams) -> anyhow::Result<Vec<TradePrint>> {
+        let until = self.cursors.lock().await.get(&params.name).cloned();
+        let signatures = self.transactions.signatures(&params.address.to_string(), None, until.as_deref(), 100).await?;
+        let Some(newest) = signatures.first() else { return Ok(Vec::new()) };
+        self.cursors.lock().await.insert(params.name.clone(), newest.signature.clone());
+        let mut prints = Vec::new();
+        for info in signatures.iter().rev().filter(|info| info.err.is_none()) {
+            let Some(tx) = self.transactions.transaction(&info.signature).await? else { continue };
+            prints.extend(
+                PhoenixFill::from_transaction(&tx)
+                    .into_iter()
+                    .filter(|fill| fill.market == params.address)
+                    .map(|fill| fill.print(params)),
+            );
+        }
+        Ok(prints)
+    }
+
+    /// Read every market's book and new trades once
+    pub async fn poll_once(&self) -> usize {
+        let mut trades = 0;
+        for params in self.client.markets() {
+            match self.client.order_book(&params.name, self.depth).await {
+                Ok(book) => self.monitor.on_book(&book),
+                Err(e) => warn!("⚠️ Phoenix book {} unavailable: {}", params.name, e),
+            }
+            match self.new_trades(params).await {
+                Ok(prints) => {
+                    trades += prints.len();
+                    prints.iter().for_each(|print| self.monitor.on_trade(print));
+                }
+                Err(e) => warn!("⚠️ Phoenix trades {} unavailable: {}", params.name, e),
+            }
+        }
+        trades
+    }
+
+    /// Poll forever, beating `heartbeat` after every pass
+    pub async fn run(self: Arc<Self>, heartbeat: HeartbeatHandle) {
+        info!("📗 Phoenix market feed polling {} markets", self.client.markets().count());
+        loop {
+            let trades = self.poll_once().await;
+            if trades > 0 {
+                debug!("📗 {} Phoenix trades fed to the microstructure signals", trades);
+            }
+            heartbeat.beat();
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// SOL/USDC-like market: 9/6 decimals, 0.001 SOL lots, 0.001 USDC ticks
+    fn market(orders: &[(ClobSide, u64, u64, u64, u64)], seats: &[Pubkey]) -> (PhoenixMarketParams, Vec<u8>) {
+        let (bids_size, asks_size, num_seats) = (4usize, 4usize, 2usize);
+        let mut data = vec![0u8; BIDS_OFFSET + 2 * (TREE_HEADER_LEN + ORDER_NODE_LEN * 4) + TREE_HEADER_LEN + TRADER_NODE_LEN * num_seats];
+        let put_u64 = |data: &mut Vec<u8>, offset: usize, value: u64| data[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+        let put_u32 = |data: &mut Vec<u8>, offset: usize, value: u32| data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        put_u64(&mut data, 16, bids_size as u64);
+        put_u64(&mut data, 24, asks_size as u64);
+        put_u64(&mut data, 32, num_seats as u64);
+        put_u32(&mut data, 40, 9);
+        put_u64(&mut data, 112, 1_000_000);
+        put_u32(&mut data, 120, 6);
+        put_u64(&mut data, 192, 1);
+        put_u64(&mut data, 200, 1_000);
+        put_u32(&mut data, 312, 1);
+        let params = PhoenixMarketParams::parse(Pubkey::new_unique(), &data).unwrap();
+
+        // Each tree is a right-leaning chain: node i's right child is i + 1
+        let write_tree = |data: &mut Vec<u8>, offset: usize, node_len: usize, payloads: Vec<Vec<u8>>| {
+            if !payloads.is_empty() {
+                put_u32(data, offset, 1);
+            }
+            let count = payloads.len();
+            for (i, payload) in payloads.into_iter().enumerate() {
+                let node = offset + TREE_HEADER_LEN + i * node_len;
+                if i + 1 < count {
+                    put_u32(data, node + 4, i as u32 + 2);
+                }
+                data[node + NODE_REGISTERS_LEN..node + NODE_REGISTERS_LEN + payload.len()].copy_from_slice(&payload);
+            }
+        };
+        for (side, offset) in [(ClobSide::Bid, BIDS_OFFSET), (ClobSide::Ask, params.asks_offset())] {
+            let payloads = orders
+                .iter()
+                .filter(|order| order.0 == side)
+                .map(|&(_, ticks, sequence, trader, lots)| [ticks, sequence, trader, lots].iter().flat_map(|v| v.to_le_bytes()).collect())
+                .collect();
+            write_tree(&mut data, offset, ORDER_NODE_LEN, payloads);
+        }
+        write_tree(&mut data, params.traders_offset(), TRADER_NODE_LEN, seats.iter().map(|seat| seat.to_bytes().to_vec()).collect());
+        (params, data)
+    }
+
+    #[test]
+    fn test_book_levels_and_own_orders_from_the_market_account() {
+        let (us, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (params, data) = market(
+            &[
+                (ClobSide::Bid, 149_990, 7, 2, 3_000),
+                (ClobSide::Bid, 150_000, 9, 1, 1_500),
+                (ClobSide::Bid, 150_000, 11, 2, 500),
+                (ClobSide::Ask, 150_020, 8, 2, 4_000),
+            ],
+            &[us, other],
+        );
+        assert!((params.tick_size() - 0.001).abs() < 1e-12);
+        assert_eq!(params.price_to_ticks(150.0, ClobSide::Bid), 150_000);
+        assert_eq!(params.price_to_ticks(150.0004, ClobSide::Ask), 150_001);
+        assert_eq!(params.size_to_lots(1.5), 1_500);
+
+        let book = PhoenixBook::parse(&params, &data).unwrap();
+        assert_eq!(book.trader_index(&us), Some(1));
+        assert_eq!(book.trader_index(&other), Some(2));
+        let ours: Vec<String> = book.orders_of(1).map(PhoenixOrder::order_id).collect();
+        assert_eq!(ours, vec!["bid:150000:9".to_string()]);
+        assert_eq!(PhoenixOrder::parse_order_id(&ours[0]).unwrap(), (ClobSide::Bid, 150_000, 9));
+
+        let snapshot = book.snapshot(&params, 5);
+        assert_eq!(snapshot.bids, vec![BookLevel { price: 150.0, size: 2.0 }, BookLevel { price: 149.99, size: 3.0 }]);
+        assert_eq!(snapshot.asks, vec![BookLevel { price: 150.02, size: 4.0 }]);
+    }
+
+    #[test]
+    fn test_post_only_and_cancel_instruction_layout() {
+        let (params, _) = market(&[], &[]);
+        let trader = Pubkey::new_unique();
+        let place = PhoenixClobClient::place_post_only_ix(&params, &trader, ClobSide::Ask, 150_020, 1_500, 42);
+        // tag, PostOnly, side, ticks, lots, client order ID, 5 flag/option bytes
+        assert_eq!(place.data.len(), 3 + 8 + 8 + 16 + 5);
+        assert_eq!(&place.data[..3], &[PLACE_LIMIT_ORDER, 0, 1]);
+        assert_eq!(place.accounts.len(), 10);
+        assert!(place.accounts[3].is_signer && place.accounts[3].pubkey == trader);
+
+        let cancel = PhoenixClobClient::cancel_ix(&params, &trader, ClobSide::Bid, 150_000, 9);
+        assert_eq!(cancel.data[0], CANCEL_MULTIPLE_ORDERS_BY_ID);
+        assert_eq!(&cancel.data[1..5], &1u32.to_le_bytes());
+        assert_eq!(cancel.accounts.len(), 9);
+    }
+
+    #[test]
+    fn test_fills_from_the_event_log_become_trade_prints() {
+        let (mut params, _) = market(&[], &[]);
+        params.name = "SOL/USDC".to_string();
+        let mut log = vec![LOG, EVENT_HEADER, 2];
+        log.extend_from_slice(&7u64.to_le_bytes());
+        log.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        log.extend_from_slice(&250_000_000u64.to_le_bytes());
+        log.extend_from_slice(params.address.as_ref());
+        log.extend_from_slice(Pubkey::new_unique().as_ref());
+        log.extend_from_slice(&3u16.to_le_bytes());
+        // A place event is skipped over, then a resting bid is hit for 2.5 SOL at 150
+        log.push(3);
+        log.extend_from_slice(&[0u8; 2 + 8 + 16 + 8 + 8]);
+        log.push(EVENT_FILL);
+        log.extend_from_slice(&1u16.to_le_bytes());
+        log.extend_from_slice(Pubkey::new_unique().as_ref());
+        for value in [!42u64, 150_000, 2_500, 0] {
+            log.extend_from_slice(&value.to_le_bytes());
+        }
+        let tx = serde_json::json!({ "meta": { "innerInstructions": [{ "index": 0, "instructions": [
+            { "programId": PHOENIX_PROGRAM_ID, "accounts": [], "data": bs58::encode(&log).into_string() },
+            { "programId": TOKEN_PROGRAM_ID, "accounts": [], "data": bs58::encode([LOG, EVENT_FILL]).into_string() },
+        ]}]}});
+
+        let fills = PhoenixFill::from_transaction(&tx);
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].maker_side, ClobSide::Bid);
+        let print = fills[0].print(&params);
+        assert_eq!((print.market.as_str(), print.price, print.size), ("SOL/USDC", 150.0, 2.5));
+        assert_eq!(print.aggressor, Some(Aggressor::Sell));
+        assert_eq!(print.at.timestamp(), 1_700_000_000);
+    }
+}
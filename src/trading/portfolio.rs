@@ -1,20 +1,70 @@
 use crate::{
     config::SimpleConfig,
-    types::{ApiResult as Result, Token},
+    types::{ApiResult as Result, Token, Money, to_money, money_to_f64},
 };
+use super::lp_valuation::{LpPosition, LpValuation};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
     sync::Arc,
     time::Instant,
 };
 use tokio::sync::RwLock;
-use tracing::{info, debug};
+use tracing::{info, debug, warn};
+
+/// Currency a portfolio keeps its books in
+///
+/// Callers keep supplying USD prices; the portfolio converts at three points
+/// only, using the base's USD rate at that moment:
+/// 1. [`PortfolioManager::update_position`]: fill price, so cost basis is in base
+/// 2. [`PortfolioManager::record_trade`]: volume, profit and fees
+/// 3. valuations (`calculate_total_value`, risk metrics): current prices
+///
+/// With a SOL base, SOL holdings are worth exactly their amount and SOL/USD
+/// moves no longer show up as PnL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AccountingBase {
+    #[default]
+    Usd,
+    Usdc,
+    Sol,
+}
+
+impl AccountingBase {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            AccountingBase::Usd => "USD",
+            AccountingBase::Usdc => "USDC",
+            AccountingBase::Sol => "SOL",
+        }
+    }
+
+    /// USD rate known without a price feed (USDC is treated as pegged until quoted)
+    fn fixed_usd_price(&self) -> Option<f64> {
+        match self {
+            AccountingBase::Usd | AccountingBase::Usdc => Some(1.0),
+            AccountingBase::Sol => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AccountingBase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
 
 /// Portfolio manager for tracking positions and balances
 #[derive(Clone)]
 pub struct PortfolioManager {
     _config: SimpleConfig,  // Reserved for future configuration features
+    base: AccountingBase,
+    /// Last known USD price of one unit of `base`
+    base_usd_price: Arc<RwLock<Option<f64>>>,
     positions: Arc<RwLock<HashMap<String, Position>>>,
+    /// Liquidity provided to pools, by pool address
+    lp_positions: Arc<RwLock<HashMap<String, LpPosition>>>,
     performance_metrics: Arc<RwLock<PerformanceMetrics>>,
     last_update: Arc<RwLock<Instant>>,
 }
@@ -24,14 +74,81 @@ impl PortfolioManager {
     pub fn new(config: SimpleConfig) -> Self {
         Self {
             _config: config,
+            base: AccountingBase::Usd,
+            base_usd_price: Arc::new(RwLock::new(AccountingBase::Usd.fixed_usd_price())),
             positions: Arc::new(RwLock::new(HashMap::new())),
+            lp_positions: Arc::new(RwLock::new(HashMap::new())),
             performance_metrics: Arc::new(RwLock::new(PerformanceMetrics::default())),
             last_update: Arc::new(RwLock::new(Instant::now())),
         }
     }
     
-    /// Update position for a token
+    /// Keep the books in `base` instead of USD
+    pub fn with_base(mut self, base: AccountingBase) -> Self {
+        self.base = base;
+        self.base_usd_price = Arc::new(RwLock::new(base.fixed_usd_price()));
+        self
+    }
+    
+    /// Accounting base currency
+    pub fn base(&self) -> AccountingBase {
+        self.base
+    }
+    
+    /// Update the USD rate of the base currency (ignored for a USD base)
+    pub async fn set_base_usd_price(&self, usd_price: f64) {
+        if self.base != AccountingBase::Usd && usd_price > 0.0 {
+            *self.base_usd_price.write().await = Some(usd_price);
+        }
+    }
+    
+    /// Convert a USD amount into the base at the current base rate
+    pub async fn usd_to_base(&self, usd: f64) -> Result<f64> {
+        match *self.base_usd_price.read().await {
+            Some(rate) if rate > 0.0 => Ok(usd / rate),
+            _ => Err(format!("No USD rate for accounting base {}", self.base)),
+        }
+    }
+    
+    /// Price of `symbol` in base units, given its USD price
+    ///
+    /// The base token itself is always worth exactly 1.
+    async fn price_in_base(&self, symbol: &str, usd_price: f64) -> Result<f64> {
+        if symbol == self.base.symbol() {
+            return Ok(1.0);
+        }
+        self.usd_to_base(usd_price).await
+    }
+    
+    /// USD rate of the base for a valuation: the snapshot's own quote when it
+    /// has one, otherwise the last known rate
+    async fn valuation_base_rate(&self, current_prices: &HashMap<String, f64>) -> Option<f64> {
+        if self.base == AccountingBase::Usd {
+            return Some(1.0);
+        }
+        match current_prices.get(self.base.symbol()) {
+            Some(rate) if *rate > 0.0 => Some(*rate),
+            _ => *self.base_usd_price.read().await,
+        }
+    }
+    
+    /// Value of `amount` of `symbol` in base units at USD `usd_price`
+    fn value_in_base(&self, symbol: &str, amount: f64, usd_price: f64, base_rate: f64) -> Money {
+        if symbol == self.base.symbol() {
+            to_money(amount)
+        } else {
+            to_money(amount) * to_money(usd_price / base_rate)
+        }
+    }
+    
+    /// Unit price of `symbol` in base units at USD `usd_price`
+    fn unit_price_in_base(&self, symbol: &str, usd_price: f64, base_rate: f64) -> f64 {
+        if symbol == self.base.symbol() { 1.0 } else { usd_price / base_rate }
+    }
+    
+    /// Update position for a token at a USD fill `price` (conversion point 1)
     pub async fn update_position(&self, token: &Token, amount: f64, price: f64) -> Result<()> {
+        let price = self.price_in_base(&token.symbol, price).await?;
         let mut positions = self.positions.write().await;
         let position = positions.entry(token.symbol.clone())
             .or_insert_with(|| Position::new(token.clone()));
@@ -39,7 +156,7 @@ impl PortfolioManager {
         position.update(amount, price);
         *self.last_update.write().await = Instant::now();
         
-        debug!("Updated position for {}: {} @ ${}", token.symbol, amount, price);
+        debug!("Updated position for {}: {} @ {} {}", token.symbol, amount, price, self.base);
         Ok(())
     }
     
@@ -54,34 +171,109 @@ impl PortfolioManager {
         self.positions.read().await.clone()
     }
     
-    /// Calculate total portfolio value
-    pub async fn calculate_total_value(&self, current_prices: &HashMap<String, f64>) -> f64 {
+    /// Track an LP position; its cost basis is the deposit at USD entry prices
+    pub async fn add_lp_position(&self, mut lp: LpPosition, price_a: f64, price_b: f64) -> Result<()> {
+        let price_a = self.price_in_base(&lp.token_a.symbol, price_a).await?;
+        let price_b = self.price_in_base(&lp.token_b.symbol, price_b).await?;
+        lp.cost_basis = to_money(lp.deposited_a * price_a + lp.deposited_b * price_b);
+        
+        info!("💧 Tracking LP position in {} ({}/{}), cost basis {} {}",
+              lp.pool_address, lp.token_a.symbol, lp.token_b.symbol, lp.cost_basis, self.base);
+        self.lp_positions.write().await.insert(lp.pool_address.clone(), lp);
+        *self.last_update.write().await = Instant::now();
+        Ok(())
+    }
+    
+    /// Add fees earned by the LP position in `pool_address` (token amounts)
+    pub async fn accrue_lp_fees(&self, pool_address: &str, fees_a: f64, fees_b: f64) -> Result<()> {
+        let mut lp_positions = self.lp_positions.write().await;
+        let lp = lp_positions.get_mut(pool_address)
+            .ok_or_else(|| format!("No LP position in pool {}", pool_address))?;
+        lp.accrue_fees(fees_a, fees_b);
+        Ok(())
+    }
+    
+    /// Stop tracking an LP position (liquidity withdrawn)
+    pub async fn remove_lp_position(&self, pool_address: &str) -> Option<LpPosition> {
+        let removed = self.lp_positions.write().await.remove(pool_address);
+        if removed.is_some() {
+            *self.last_update.write().await = Instant::now();
+        }
+        removed
+    }
+    
+    pub async fn get_lp_positions(&self) -> Vec<LpPosition> {
+        self.lp_positions.read().await.values().cloned().collect()
+    }
+    
+    /// Value LP positions in base units from USD `current_prices`
+    ///
+    /// Positions missing a price for either token are left out.
+    pub async fn value_lp_positions(&self, current_prices: &HashMap<String, f64>) -> Vec<LpValuation> {
+        let Some(base_rate) = self.valuation_base_rate(current_prices).await else {
+            return Vec::new();
+        };
+        let lp_positions = self.lp_positions.read().await;
+        let mut valuations: Vec<LpValuation> = lp_positions.values()
+            .filter_map(|lp| {
+                let price_a = current_prices.get(&lp.token_a.symbol)?;
+                let price_b = current_prices.get(&lp.token_b.symbol)?;
+                Some(lp.valuation(
+                    self.unit_price_in_base(&lp.token_a.symbol, *price_a, base_rate),
+                    self.unit_price_in_base(&lp.token_b.symbol, *price_b, base_rate),
+                ))
+            })
+            .collect();
+        valuations.sort_by(|a, b| a.pool_address.cmp(&b.pool_address));
+        valuations
+    }
+    
+    /// Calculate total portfolio value in base units from USD `current_prices` (conversion point 3)
+    ///
+    /// LP positions count at their decomposed value plus unclaimed fees.
+    pub async fn calculate_total_value(&self, current_prices: &HashMap<String, f64>) -> Money {
+        let Some(base_rate) = self.valuation_base_rate(current_prices).await else {
+            warn!("⚠️ No USD rate for accounting base {} - portfolio value unavailable", self.base);
+            return Decimal::ZERO;
+        };
         let positions = self.positions.read().await;
-        let mut total_value = 0.0;
+        let mut total_value = Decimal::ZERO;
         
         for (symbol, position) in positions.iter() {
             if let Some(current_price) = current_prices.get(symbol) {
-                total_value += position.amount * current_price;
+                total_value += self.value_in_base(symbol, position.amount, *current_price, base_rate);
             }
         }
+        drop(positions);
+        
+        for lp in self.value_lp_positions(current_prices).await {
+            total_value += lp.position_value + lp.fees_value;
+        }
         
         total_value
     }
     
-    /// Record a trade execution
-    pub async fn record_trade(&self, trade: TradeRecord) -> Result<()> {
+    /// Record a trade execution with USD amounts (conversion point 2)
+    pub async fn record_trade(&self, mut trade: TradeRecord) -> Result<()> {
+        if self.base != AccountingBase::Usd {
+            let rate = self.usd_to_base(1.0).await?;
+            trade.volume *= to_money(rate);
+            trade.profit *= to_money(rate);
+            trade.gas_cost *= to_money(rate);
+        }
         let mut metrics = self.performance_metrics.write().await;
         
         metrics.total_trades += 1;
         metrics.total_volume += trade.volume;
         
-        if trade.profit > 0.0 {
+        if trade.profit > Decimal::ZERO {
             metrics.profitable_trades += 1;
             metrics.total_profit += trade.profit;
         } else {
             metrics.losing_trades += 1;
             metrics.total_loss += trade.profit.abs();
         }
+        metrics.total_fees += trade.gas_cost;
         
         metrics.trades.push(trade);
         
@@ -105,21 +297,28 @@ impl PortfolioManager {
     
     /// Calculate portfolio risk metrics
     pub async fn calculate_risk_metrics(&self, current_prices: &HashMap<String, f64>) -> RiskMetrics {
+        let base_rate = self.valuation_base_rate(current_prices).await;
         let positions = self.positions.read().await;
-        let mut total_value = 0.0;
-        let mut max_single_position: f64 = 0.0;
+        let mut total_value = Decimal::ZERO;
+        let mut max_single_position = Decimal::ZERO;
         
         for (symbol, position) in positions.iter() {
-            if let Some(current_price) = current_prices.get(symbol) {
-                let position_value = position.amount * current_price;
+            if let (Some(current_price), Some(base_rate)) = (current_prices.get(symbol), base_rate) {
+                let position_value = self.value_in_base(symbol, position.amount, *current_price, base_rate);
                 total_value += position_value;
                 max_single_position = max_single_position.max(position_value);
             }
         }
+        let lp_valuations = self.value_lp_positions(current_prices).await;
+        for lp in &lp_valuations {
+            let lp_value = lp.position_value + lp.fees_value;
+            total_value += lp_value;
+            max_single_position = max_single_position.max(lp_value);
+        }
         
-        // Calculate concentration risk
-        let concentration_risk = if total_value > 0.0 {
-            max_single_position / total_value
+        // Calculate concentration risk (a statistic, so f64)
+        let concentration_risk = if total_value > Decimal::ZERO {
+            money_to_f64(max_single_position / total_value)
         } else {
             0.0
         };
@@ -132,7 +331,7 @@ impl PortfolioManager {
             concentration_risk,
             diversification_score,
             max_single_position_ratio: concentration_risk,
-            position_count: positions.len(),
+            position_count: positions.len() + lp_valuations.len(),
         }
     }
     
@@ -142,17 +341,91 @@ impl PortfolioManager {
         let performance = self.get_performance_metrics().await;
         let risk_metrics = self.calculate_risk_metrics(current_prices).await;
         let total_value = self.calculate_total_value(current_prices).await;
+        let lp_positions = self.value_lp_positions(current_prices).await;
         
         PortfolioSummary {
+            base: self.base,
             total_value,
             position_count: positions.len(),
             total_trades: performance.total_trades,
             win_rate: performance.get_win_rate(),
             total_pnl: performance.get_net_pnl(),
             risk_metrics,
+            impermanent_loss: lp_positions.iter().map(|lp| lp.impermanent_loss).sum(),
+            lp_fees: lp_positions.iter().map(|lp| lp.fees_value).sum(),
+            lp_positions,
             last_update: *self.last_update.read().await,
         }
     }
+    
+    /// Export open positions for a state snapshot
+    pub async fn export_snapshot(&self) -> PortfolioSnapshot {
+        let positions = self.positions.read().await;
+        PortfolioSnapshot {
+            base: self.base,
+            positions: positions.values().map(PositionSnapshot::from).collect(),
+            lp_positions: self.lp_positions.read().await.values().cloned().collect(),
+        }
+    }
+    
+    /// Replace positions with those from a state snapshot
+    pub async fn restore_snapshot(&self, snapshot: PortfolioSnapshot) {
+        // Prices and PnL are stored in the base; mixing bases would corrupt them
+        if snapshot.base != self.base {
+            warn!("⚠️ Portfolio snapshot kept in {} but portfolio uses {} - not restoring", snapshot.base, self.base);
+            return;
+        }
+        let mut positions = self.positions.write().await;
+        positions.clear();
+        for saved in snapshot.positions {
+            let mut position = Position::new(saved.token.clone());
+            position.amount = saved.amount;
+            position.average_price = saved.average_price;
+            position.last_price = saved.last_price;
+            position.realized_pnl = saved.realized_pnl;
+            position.update_unrealized_pnl();
+            positions.insert(saved.token.symbol.clone(), position);
+        }
+        let mut lp_positions = self.lp_positions.write().await;
+        lp_positions.clear();
+        lp_positions.extend(snapshot.lp_positions.into_iter().map(|lp| (lp.pool_address.clone(), lp)));
+        info!("📸 Restored {} portfolio positions and {} LP positions from snapshot", positions.len(), lp_positions.len());
+        *self.last_update.write().await = Instant::now();
+    }
+}
+
+/// Serializable portfolio state
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PortfolioSnapshot {
+    /// Currency of prices and PnL below (snapshots predating it are USD)
+    #[serde(default)]
+    pub base: AccountingBase,
+    pub positions: Vec<PositionSnapshot>,
+    /// Cost basis and fees are in `base` too
+    #[serde(default)]
+    pub lp_positions: Vec<LpPosition>,
+}
+
+/// Serializable position (timestamps are reset on restore)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionSnapshot {
+    pub token: Token,
+    pub amount: f64,
+    pub average_price: f64,
+    pub last_price: f64,
+    pub realized_pnl: Money,
+}
+
+impl From<&Position> for PositionSnapshot {
+    fn from(position: &Position) -> Self {
+        Self {
+            token: position.token.clone(),
+            amount: position.amount,
+            average_price: position.average_price,
+            last_price: position.last_price,
+            realized_pnl: position.realized_pnl,
+        }
+    }
 }
 
 /// Individual position in the portfolio
@@ -162,8 +435,8 @@ pub struct Position {
     pub amount: f64,
     pub average_price: f64,
     pub last_price: f64,
-    pub unrealized_pnl: f64,
-    pub realized_pnl: f64,
+    pub unrealized_pnl: Money,
+    pub realized_pnl: Money,
     pub last_updated: Instant,
 }
 
@@ -174,8 +447,8 @@ impl Position {
             amount: 0.0,
             average_price: 0.0,
             last_price: 0.0,
-            unrealized_pnl: 0.0,
-            realized_pnl: 0.0,
+            unrealized_pnl: Decimal::ZERO,
+            realized_pnl: Decimal::ZERO,
             last_updated: Instant::now(),
         }
     }
@@ -191,7 +464,7 @@ impl Position {
         } else {
             // Selling - realize PnL
             let sold_amount = amount_change.abs();
-            let realized_gain = sold_amount * (price - self.average_price);
+            let realized_gain = to_money(sold_amount) * (to_money(price) - to_money(self.average_price));
             self.realized_pnl += realized_gain;
             self.amount += amount_change; // amount_change is negative
         }
@@ -202,27 +475,31 @@ impl Position {
     }
     
     fn update_unrealized_pnl(&mut self) {
-        self.unrealized_pnl = self.amount * (self.last_price - self.average_price);
+        self.unrealized_pnl = to_money(self.amount) * (to_money(self.last_price) - to_money(self.average_price));
     }
     
-    pub fn get_total_pnl(&self) -> f64 {
+    pub fn get_total_pnl(&self) -> Money {
         self.realized_pnl + self.unrealized_pnl
     }
     
-    pub fn get_market_value(&self) -> f64 {
-        self.amount * self.last_price
+    pub fn get_market_value(&self) -> Money {
+        to_money(self.amount) * to_money(self.last_price)
     }
 }
 
 /// Performance tracking metrics
+///
+/// Money fields are decimal-precise; ratios (win rate, profit factor) are `f64`
+/// statistics derived from them.
 #[derive(Debug, Clone, Default)]
 pub struct PerformanceMetrics {
     pub total_trades: u64,
     pub profitable_trades: u64,
     pub losing_trades: u64,
-    pub total_profit: f64,
-    pub total_loss: f64,
-    pub total_volume: f64,
+    pub total_profit: Money,
+    pub total_loss: Money,
+    pub total_volume: Money,
+    pub total_fees: Money,
     pub trades: Vec<TradeRecord>,
 }
 
@@ -235,30 +512,30 @@ impl PerformanceMetrics {
         }
     }
     
-    pub fn get_net_pnl(&self) -> f64 {
+    pub fn get_net_pnl(&self) -> Money {
         self.total_profit - self.total_loss
     }
     
-    pub fn get_average_profit(&self) -> f64 {
+    pub fn get_average_profit(&self) -> Money {
         if self.profitable_trades > 0 {
-            self.total_profit / self.profitable_trades as f64
+            self.total_profit / Decimal::from(self.profitable_trades)
         } else {
-            0.0
+            Decimal::ZERO
         }
     }
     
-    pub fn get_average_loss(&self) -> f64 {
+    pub fn get_average_loss(&self) -> Money {
         if self.losing_trades > 0 {
-            self.total_loss / self.losing_trades as f64
+            self.total_loss / Decimal::from(self.losing_trades)
         } else {
-            0.0
+            Decimal::ZERO
         }
     }
     
     pub fn get_profit_factor(&self) -> f64 {
-        if self.total_loss > 0.0 {
-            self.total_profit / self.total_loss
-        } else if self.total_profit > 0.0 {
+        if self.total_loss > Decimal::ZERO {
+            money_to_f64(self.total_profit / self.total_loss)
+        } else if self.total_profit > Decimal::ZERO {
             f64::INFINITY
         } else {
             0.0
@@ -273,11 +550,13 @@ pub struct TradeRecord {
     pub side: TradeSide,
     pub amount: f64,
     pub price: f64,
-    pub volume: f64,
-    pub profit: f64,
-    pub gas_cost: f64,
+    pub volume: Money,
+    pub profit: Money,
+    pub gas_cost: Money,
     pub timestamp: chrono::DateTime<chrono::Utc>,
     pub trade_id: String,
+    /// Fingerprint of the binary that executed the trade
+    pub build: String,
 }
 
 #[derive(Debug, Clone)]
@@ -289,22 +568,28 @@ pub enum TradeSide {
 /// Risk metrics for the portfolio
 #[derive(Debug, Clone)]
 pub struct RiskMetrics {
-    pub total_value: f64,
+    pub total_value: Money,
     pub concentration_risk: f64,
     pub diversification_score: f64,
     pub max_single_position_ratio: f64,
     pub position_count: usize,
 }
 
-/// Portfolio summary
+/// Portfolio summary (money fields in `base`)
 #[derive(Debug, Clone)]
 pub struct PortfolioSummary {
-    pub total_value: f64,
+    pub base: AccountingBase,
+    pub total_value: Money,
     pub position_count: usize,
     pub total_trades: u64,
     pub win_rate: f64,
-    pub total_pnl: f64,
+    pub total_pnl: Money,
     pub risk_metrics: RiskMetrics,
+    pub lp_positions: Vec<LpValuation>,
+    /// Sum over LP positions of value versus holding the deposits
+    pub impermanent_loss: Money,
+    /// Unclaimed LP fees, included in `total_value`
+    pub lp_fees: Money,
     pub last_update: Instant,
 }
 
@@ -382,7 +667,7 @@ mod tests {
         portfolio.update_position(&token, 0.0, 110.0).await.unwrap();
         
         let position = portfolio.get_position("SOL").await.unwrap();
-        assert_eq!(position.unrealized_pnl, 100.0); // 10 SOL * ($110 - $100)
+        assert_eq!(position.unrealized_pnl, Decimal::from(100)); // 10 SOL * ($110 - $100)
     }
     
     #[tokio::test]
@@ -395,11 +680,12 @@ mod tests {
             side: TradeSide::Buy,
             amount: 10.0,
             price: 100.0,
-            volume: 1000.0,
-            profit: 50.0,
-            gas_cost: 0.001,
+            volume: Decimal::from(1000),
+            profit: Decimal::from(50),
+            gas_cost: to_money(0.001),
             timestamp: chrono::Utc::now(),
             trade_id: "trade_1".to_string(),
+            build: crate::security::build_fingerprint(),
         };
         
         portfolio.record_trade(trade).await.unwrap();
@@ -407,6 +693,97 @@ mod tests {
         let metrics = portfolio.get_performance_metrics().await;
         assert_eq!(metrics.total_trades, 1);
         assert_eq!(metrics.profitable_trades, 1);
-        assert_eq!(metrics.total_profit, 50.0);
+        assert_eq!(metrics.total_profit, Decimal::from(50));
+    }
+    
+    #[tokio::test]
+    async fn test_realized_pnl_is_exact() {
+        let config = create_test_config();
+        let portfolio = PortfolioManager::new(config);
+        let token = create_test_token();
+        
+        // Buy 2 at $0.1, sell 2 at $0.3: f64 would give 0.39999999999999997
+        portfolio.update_position(&token, 2.0, 0.1).await.unwrap();
+        portfolio.update_position(&token, -2.0, 0.3).await.unwrap();
+        
+        let position = portfolio.get_position("SOL").await.unwrap();
+        assert_eq!(position.realized_pnl, to_money(0.4));
+    }
+    
+    #[tokio::test]
+    async fn test_sol_base_ignores_sol_usd_moves() {
+        let portfolio = PortfolioManager::new(create_test_config()).with_base(AccountingBase::Sol);
+        let sol = create_test_token();
+        let bonk = Token { symbol: "BONK".to_string(), mint: "bonk".to_string(), decimals: 5 };
+        
+        // No SOL rate yet: non-SOL fills cannot be booked
+        assert!(portfolio.update_position(&bonk, 1000.0, 0.01).await.is_err());
+        
+        portfolio.set_base_usd_price(100.0).await;
+        portfolio.update_position(&sol, 10.0, 100.0).await.unwrap();
+        // $10 of BONK = 0.1 SOL
+        portfolio.update_position(&bonk, 1000.0, 0.01).await.unwrap();
+        
+        // SOL doubles in USD, BONK flat in USD: SOL holdings still worth 10 SOL
+        portfolio.update_position(&sol, 0.0, 200.0).await.unwrap();
+        let sol_position = portfolio.get_position("SOL").await.unwrap();
+        assert_eq!(sol_position.unrealized_pnl, Decimal::ZERO);
+        
+        let prices = HashMap::from([("SOL".to_string(), 200.0), ("BONK".to_string(), 0.01)]);
+        assert_eq!(portfolio.calculate_total_value(&prices).await, to_money(10.05));
+        assert_eq!(portfolio.get_portfolio_summary(&prices).await.base, AccountingBase::Sol);
+    }
+    
+    #[tokio::test]
+    async fn test_trades_recorded_in_base_and_snapshot_base_checked() {
+        let portfolio = PortfolioManager::new(create_test_config()).with_base(AccountingBase::Sol);
+        portfolio.set_base_usd_price(50.0).await;
+        portfolio.record_trade(TradeRecord {
+            symbol: "BONK".to_string(),
+            side: TradeSide::Sell,
+            amount: 1.0,
+            price: 100.0,
+            volume: Decimal::from(100),
+            profit: Decimal::from(25),
+            gas_cost: Decimal::ZERO,
+            timestamp: chrono::Utc::now(),
+            trade_id: "trade_sol".to_string(),
+            build: crate::security::build_fingerprint(),
+        }).await.unwrap();
+        let metrics = portfolio.get_performance_metrics().await;
+        assert_eq!(metrics.total_profit, to_money(0.5));
+        assert_eq!(metrics.total_volume, Decimal::from(2));
+        
+        // A USD snapshot is not restored into a SOL-based portfolio
+        let usd = PortfolioManager::new(create_test_config());
+        usd.update_position(&create_test_token(), 1.0, 100.0).await.unwrap();
+        portfolio.restore_snapshot(usd.export_snapshot().await).await;
+        assert!(portfolio.get_all_positions().await.is_empty());
+    }
+    
+    #[tokio::test]
+    async fn test_lp_positions_valued_with_il_and_fees() {
+        use crate::trading::lp_valuation::LpCurve;
+        
+        let portfolio = PortfolioManager::new(create_test_config());
+        let usdc = Token { symbol: "USDC".to_string(), mint: "usdc".to_string(), decimals: 6 };
+        let lp = LpPosition::from_deposit("pool1", (create_test_token(), 10.0), (usdc, 1_000.0), LpCurve::ConstantProduct, 100.0);
+        portfolio.add_lp_position(lp, 100.0, 1.0).await.unwrap();
+        portfolio.accrue_lp_fees("pool1", 0.0, 50.0).await.unwrap();
+        assert!(portfolio.accrue_lp_fees("missing", 1.0, 1.0).await.is_err());
+        
+        // SOL 4x: LP worth 4000 vs 5000 held, plus 50 fees
+        let prices = HashMap::from([("SOL".to_string(), 400.0), ("USDC".to_string(), 1.0)]);
+        let summary = portfolio.get_portfolio_summary(&prices).await;
+        assert_eq!(summary.total_value, to_money(4_050.0));
+        assert_eq!(summary.impermanent_loss, to_money(-1_000.0));
+        assert_eq!(summary.lp_fees, to_money(50.0));
+        assert_eq!(summary.lp_positions[0].pnl, to_money(2_050.0));
+        assert_eq!(summary.risk_metrics.position_count, 1);
+        
+        // LP positions survive a snapshot round trip
+        let restored = PortfolioManager::new(create_test_config());
+        restored.restore_snapshot(portfolio.export_snapshot().await).await;
+        assert_eq!(restored.calculate_total_value(&prices).await, to_money(4_050.0));
     }
 }
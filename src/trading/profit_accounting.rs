@@ -0,0 +1,316 @@
+//! Profit accounting
+//!
+//! Separates what a cycle actually earned from what it only estimated.
+//! Every profit figure is booked under one of three kinds:
+//!
+//! - **confirmed**: a submitted fill whose signature shows up in the on-chain
+//!   trade store
+//! - **simulated**: the outcome of simulated execution
+//! - **hypothetical**: scores, opportunity sizes and other estimates that were
+//!   never executed
+//!
+//! In strict mode (the default on MainNet) the reported total is confirmed
+//! fills only; permissive mode keeps the legacy behaviour of summing
+//! everything. Simulated and hypothetical totals are always tracked and
+//! shown separately.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::analytics::IndexedTrade;
+use crate::types::TradingMode;
+
+/// Overrides the accounting mode: `strict` or `permissive`
+pub const ACCOUNTING_MODE_ENV: &str = "SNIPERFORGE_ACCOUNTING";
+
+/// How the reported profit total is computed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccountingMode {
+    /// Only confirmed on-chain fills count
+    Strict,
+    /// Confirmed, simulated and hypothetical values are all summed
+    Permissive,
+}
+
+impl AccountingMode {
+    /// Strict on MainNet, permissive elsewhere; `SNIPERFORGE_ACCOUNTING` overrides
+    pub fn for_trading_mode(mode: &TradingMode) -> Self {
+        match std::env::var(ACCOUNTING_MODE_ENV).map(|v| v.to_ascii_lowercase()).as_deref() {
+            Ok("strict") => Self::Strict,
+            Ok("permissive") => Self::Permissive,
+            _ if mode.is_production() => Self::Strict,
+            _ => Self::Permissive,
+        }
+    }
+}
+
+/// What a profit figure is backed by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProfitKind {
+    Confirmed,
+    Simulated,
+    Hypothetical,
+}
+
+/// Profit booked during one cycle
+#[derive(Debug, Clone, Default)]
+pub struct CycleProfit {
+    entries: Vec<(ProfitKind, String, f64)>,
+}
+
+impl CycleProfit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn simulated(&mut self, source: &str, usd: f64) {
+        self.entries.push((ProfitKind::Simulated, source.to_string(), usd));
+    }
+
+    pub fn hypothetical(&mut self, source: &str, usd: f64) {
+        self.entries.push((ProfitKind::Hypothetical, source.to_string(), usd));
+    }
+
+    pub fn total(&self, kind: ProfitKind) -> f64 {
+        self.entries.iter().filter(|(k, _, _)| *k == kind).map(|(_, _, usd)| usd).sum()
+    }
+
+    /// Sum of everything booked, regardless of kind
+    pub fn gross(&self) -> f64 {
+        self.entries.iter().map(|(_, _, usd)| usd).sum()
+    }
+}
+
+/// What a round trip spent, to value its closing fill against
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundTripCost {
+    /// Token the round trip started and ends in
+    pub mint: String,
+    /// Amount of `mint` spent on the opening leg, in token units
+    pub amount: f64,
+    pub usd_per_unit: f64,
+}
+
+/// Fill submitted on-chain, waiting to appear in the trade store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingFill {
+    pub signature: String,
+    pub source: String,
+    /// Estimate at submission time
+    pub pnl_usd: f64,
+    pub submitted_at: DateTime<Utc>,
+    /// Set for round trips: the realized PnL is computed from the confirmed fill
+    pub cost: Option<RoundTripCost>,
+}
+
+/// Fill found in the on-chain trade store
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfirmedFill {
+    pub signature: String,
+    pub source: String,
+    pub pnl_usd: f64,
+    /// The PnL comes from the on-chain amounts, not the submission estimate
+    pub realized: bool,
+    /// Network fee the transaction actually paid
+    pub fee_lamports: u64,
+}
+
+/// Running totals per kind and source
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProfitTotals {
+    pub confirmed_usd: f64,
+    pub simulated_usd: f64,
+    pub hypothetical_usd: f64,
+    pub confirmed_fills: u64,
+    pub pending_fills: usize,
+    pub by_source: HashMap<String, HashMap<ProfitKind, f64>>,
+}
+
+/// Profit ledger for the engine
+#[derive(Debug)]
+pub struct ProfitLedger {
+    mode: AccountingMode,
+    pending: HashMap<String, PendingFill>,
+    totals: ProfitTotals,
+}
+
+impl ProfitLedger {
+    pub fn new(mode: AccountingMode) -> Self {
+        info!("📒 Profit accounting mode: {:?}", mode);
+        Self { mode, pending: HashMap::new(), totals: ProfitTotals::default() }
+    }
+
+    pub fn mode(&self) -> AccountingMode {
+        self.mode
+    }
+
+    fn book(&mut self, kind: ProfitKind, source: &str, usd: f64) {
+        match kind {
+            ProfitKind::Confirmed => self.totals.confirmed_usd += usd,
+            ProfitKind::Simulated => self.totals.simulated_usd += usd,
+            ProfitKind::Hypothetical => self.totals.hypothetical_usd += usd,
+        }
+        *self.totals.by_source.entry(source.to_string()).or_default().entry(kind).or_insert(0.0) += usd;
+    }
+
+    /// Register a real submission; it only counts once the trade store has it
+    pub fn record_submission(&mut self, signature: &str, source: &str, pnl_usd: f64) {
+        self.insert_pending(signature, source, pnl_usd, None);
+    }
+
+    /// Register the closing leg of a round trip; once confirmed, its PnL is
+    /// what the fill returned minus `cost`
+    pub fn record_round_trip(&mut self, closing_signature: &str, source: &str, estimate_usd: f64, cost: RoundTripCost) {
+        self.insert_pending(closing_signature, source, estimate_usd, Some(cost));
+    }
+
+    fn insert_pending(&mut self, signature: &str, source: &str, pnl_usd: f64, cost: Option<RoundTripCost>) {
+        self.pending.insert(signature.to_string(), PendingFill {
+            signature: signature.to_string(),
+            source: source.to_string(),
+            pnl_usd,
+            submitted_at: Utc::now(),
+            cost,
+        });
+        self.totals.pending_fills = self.pending.len();
+    }
+
+    /// Confirm pending fills found in the on-chain trade store; returns confirmed profit
+    pub fn reconcile(&mut self, trades: &[IndexedTrade]) -> f64 {
+        self.reconcile_fills(trades).iter().map(|fill| fill.pnl_usd).sum()
+    }
+
+    /// Confirm pending fills found in the on-chain trade store, one entry per fill
+    pub fn reconcile_fills(&mut self, trades: &[IndexedTrade]) -> Vec<ConfirmedFill> {
+        let mut confirmed = Vec::new();
+        for trade in trades {
+            let Some(fill) = self.pending.remove(&trade.signature) else { continue };
+            let realized = fill
+                .cost
+                .as_ref()
+                .filter(|cost| cost.mint == trade.mint_out)
+                .map(|cost| (trade.amount_out - cost.amount) * cost.usd_per_unit);
+            let pnl_usd = realized.unwrap_or(fill.pnl_usd);
+            debug!("📒 Fill {} confirmed on-chain ({:+.2} USD, estimated {:+.2})", fill.signature, pnl_usd, fill.pnl_usd);
+            self.book(ProfitKind::Confirmed, &fill.source, pnl_usd);
+            self.totals.confirmed_fills += 1;
+            confirmed.push(ConfirmedFill {
+                signature: fill.signature,
+                source: fill.source,
+                pnl_usd,
+                realized: realized.is_some(),
+                fee_lamports: trade.fee_lamports,
+            });
+        }
+        self.totals.pending_fills = self.pending.len();
+        confirmed
+    }
+
+    /// Book a finished cycle; returns the profit reported under the current mode
+    ///
+    /// `confirmed` is what `reconcile` confirmed during the cycle.
+    pub fn close_cycle(&mut self, cycle: &CycleProfit, confirmed: f64) -> f64 {
+        for (kind, source, usd) in &cycle.entries {
+            self.book(*kind, source, *usd);
+        }
+        match self.mode {
+            AccountingMode::Strict => confirmed,
+            AccountingMode::Permissive => confirmed + cycle.gross(),
+        }
+    }
+
+    pub fn totals(&self) -> &ProfitTotals {
+        &self.totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indexed(signature: &str) -> IndexedTrade {
+        IndexedTrade {
+            signature: signature.to_string(),
+            wallet: "wallet".to_string(),
+            slot: 1,
+            block_time: None,
+            venue: None,
+            mint_in: "SOL".to_string(),
+            amount_in: 1.0,
+            mint_out: "USDC".to_string(),
+            amount_out: 150.0,
+            fee_lamports: 5000,
+        }
+    }
+
+    #[test]
+    fn test_strict_reports_only_confirmed_fills() {
+        let mut ledger = ProfitLedger::new(AccountingMode::Strict);
+        ledger.record_submission("sig-1", "EnhancedArbitrage", 12.0);
+        ledger.record_submission("sig-2", "EnhancedArbitrage", 8.0);
+
+        let mut cycle = CycleProfit::new();
+        cycle.simulated("TriangularArbitrage", 20.0);
+        cycle.hypothetical("IntelligenceScore", 15.0);
+
+        let confirmed = ledger.reconcile(&[indexed("sig-1"), indexed("unrelated")]);
+        assert_eq!(ledger.close_cycle(&cycle, confirmed), 12.0);
+
+        let totals = ledger.totals();
+        assert_eq!(totals.confirmed_usd, 12.0);
+        assert_eq!(totals.simulated_usd, 20.0);
+        assert_eq!(totals.hypothetical_usd, 15.0);
+        assert_eq!(totals.pending_fills, 1);
+    }
+
+    #[test]
+    fn test_round_trip_books_the_realized_fill() {
+        let mut ledger = ProfitLedger::new(AccountingMode::Strict);
+        let cost = |amount| RoundTripCost { mint: "USDC".to_string(), amount, usd_per_unit: 1.0 };
+        // Estimated +3, but the sell leg only returned 149 USDC for 150 spent
+        ledger.record_round_trip("sig-1", "EnhancedArbitrage", 3.0, cost(150.0));
+        ledger.record_round_trip("sig-2", "EnhancedArbitrage", 1.0, RoundTripCost { mint: "BONK".to_string(), ..cost(1.0) });
+        let mut sold = indexed("sig-1");
+        sold.amount_out = 149.0;
+
+        let fills = ledger.reconcile_fills(&[sold, indexed("sig-2")]);
+        assert_eq!(fills.len(), 2);
+        assert_eq!((fills[0].pnl_usd, fills[0].realized), (-1.0, true));
+        // The fill did not end in the cost token: only the estimate is known
+        assert_eq!((fills[1].pnl_usd, fills[1].realized), (1.0, false));
+        assert!(fills.iter().all(|fill| fill.fee_lamports == 5000));
+        assert_eq!(ledger.totals().confirmed_usd, 0.0);
+    }
+
+    #[test]
+    fn test_confirmed_live_fill_leaves_pending_and_books_once() {
+        let mut ledger = ProfitLedger::new(AccountingMode::Strict);
+        let cost = RoundTripCost { mint: "USDC".to_string(), amount: 148.0, usd_per_unit: 1.0 };
+        ledger.record_round_trip("sell-leg", "EnhancedArbitrage", 1.5, cost);
+        assert_eq!((ledger.totals().pending_fills, ledger.totals().confirmed_usd), (1, 0.0));
+
+        // The settlement timer and the next cycle both reconcile against the same trade store
+        let confirmed = ledger.reconcile(&[indexed("sell-leg")]);
+        assert_eq!(confirmed, 2.0);
+        assert_eq!(ledger.reconcile(&[indexed("sell-leg")]), 0.0);
+        assert_eq!(ledger.close_cycle(&CycleProfit::new(), 0.0), 0.0);
+
+        let totals = ledger.totals();
+        assert_eq!((totals.pending_fills, totals.confirmed_fills), (0, 1));
+        assert_eq!(totals.confirmed_usd, 2.0);
+        assert_eq!(totals.by_source["EnhancedArbitrage"][&ProfitKind::Confirmed], 2.0);
+    }
+
+    #[test]
+    fn test_permissive_sums_everything() {
+        let mut ledger = ProfitLedger::new(AccountingMode::Permissive);
+        let mut cycle = CycleProfit::new();
+        cycle.simulated("FlashLoanArbitrage", 5.0);
+        cycle.hypothetical("DepegOpportunity", 2.5);
+        assert_eq!(ledger.close_cycle(&cycle, 0.0), 7.5);
+        assert_eq!(cycle.total(ProfitKind::Simulated), 5.0);
+    }
+}
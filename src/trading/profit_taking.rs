@@ -0,0 +1,396 @@
+//! Automatic profit-taking into stables
+//!
+//! Realized profit left in SOL rides SOL's volatility: a good week can be
+//! given back by a bad day for SOL without a single losing trade. This policy
+//! sets aside a configurable share of every realized gain and converts it to
+//! USDC, either on a fixed schedule or whenever cumulative realized profit
+//! crosses another milestone.
+//!
+//! Conversions are not time-critical, so they run as slow TWAP orders with a
+//! low priority fee instead of competing with trading for block space.
+//! Realized losses shrink the pending amount first, so the policy never sells
+//! SOL to bank profit that has already been lost.
+
+use std::sync::Arc;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use super::execution::{JupiterRealClient, JupiterRealConfig, TwapConfig, TwapExecutor, TwapFill, TwapReport, TwapSide};
+use crate::types::constants::{SOL_MINT, USDC_MINT};
+
+/// Profit-taking settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfitTakingConfig {
+    /// Share of each realized gain set aside for conversion (%)
+    pub convert_percent: f64,
+    /// Convert pending profit this often; `None` converts on milestones only
+    pub interval_hours: Option<u64>,
+    /// Convert whenever cumulative realized profit crosses another multiple of this (USD)
+    pub milestone_usd: Option<f64>,
+    /// Pending profit below this is carried over instead of converted (USD)
+    pub min_conversion_usd: f64,
+    pub check_interval_secs: u64,
+    /// Priority fee for conversion swaps; kept low, nothing here is urgent
+    pub priority_fee_lamports: u64,
+    pub max_slippage_bps: u16,
+    /// Child order schedule for each conversion
+    pub twap: TwapConfig,
+}
+
+impl Default for ProfitTakingConfig {
+    fn default() -> Self {
+        Self {
+            convert_percent: 50.0,
+            interval_hours: Some(24),
+            milestone_usd: Some(500.0),
+            min_conversion_usd: 20.0,
+            check_interval_secs: 300,
+            priority_fee_lamports: 1_000,
+            max_slippage_bps: 50,
+            twap: TwapConfig {
+                duration_secs: 3_600,
+                slices: 12,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// What started a conversion
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversionTrigger {
+    Schedule,
+    /// Cumulative realized profit crossed this level (USD)
+    Milestone(f64),
+}
+
+/// One finished conversion
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfitConversion {
+    pub id: Uuid,
+    pub trigger: ConversionTrigger,
+    /// Pending profit when the conversion started (USD)
+    pub requested_usd: f64,
+    /// USDC received
+    pub converted_usd: f64,
+    pub sol_sold: f64,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub report: TwapReport,
+}
+
+/// Profit-taking state for the control API and logs
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProfitTakingStatus {
+    /// Cumulative realized profit seen by the policy (USD)
+    pub realized_usd: f64,
+    /// Set aside and not yet converted (USD)
+    pub pending_usd: f64,
+    /// Converted to USDC so far
+    pub converted_usd: f64,
+    pub conversions: usize,
+    pub last_conversion_at: Option<DateTime<Utc>>,
+    pub next_milestone_usd: Option<f64>,
+    pub converting: bool,
+}
+
+/// Where SOL is sold for USDC
+#[async_trait]
+pub trait ConversionVenue: Send + Sync {
+    /// Current SOL price in USDC
+    async fn sol_price(&self) -> Result<f64>;
+    /// Sell `sol` for USDC; the fill price is USDC per SOL
+    async fn sell_sol(&self, sol: f64, priority_fee_lamports: u64, max_slippage_bps: u16) -> Result<TwapFill>;
+}
+
+/// Jupiter-routed SOL → USDC conversion
+pub struct JupiterConversionVenue {
+    config: JupiterRealConfig,
+    wallet: String,
+}
+
+impl JupiterConversionVenue {
+    pub fn new(config: JupiterRealConfig, wallet: String) -> Self {
+        Self { config, wallet }
+    }
+
+    fn mints() -> Result<(Pubkey, Pubkey)> {
+        Ok((Pubkey::from_str(SOL_MINT)?, Pubkey::from_str(USDC_MINT)?))
+    }
+}
+
+#[async_trait]
+impl ConversionVenue for JupiterConversionVenue {
+    async fn sol_price(&self) -> Result<f64> {
+        let (sol, usdc) = Self::mints()?;
+        let mut client = JupiterRealClient::new(Some(self.config.clone()));
+        let quote = client.get_real_jupiter_quote(sol, usdc, 1_000_000_000).await?;
+        Ok(quote.out_amount as f64 / 1_000_000.0)
+    }
+
+    async fn sell_sol(&self, sol: f64, priority_fee_lamports: u64, max_slippage_bps: u16) -> Result<TwapFill> {
+        let (sol_mint, usdc) = Self::mints()?;
+        let lamports = (sol * 1_000_000_000.0).floor() as u64;
+        let client = JupiterRealClient::new(Some(JupiterRealConfig {
+            slippage_bps: max_slippage_bps,
+            priority_fee_lamports,
+            ..self.config.clone()
+        }));
+        let result = client.execute_real_swap(sol_mint, usdc, lamports, &self.wallet).await?;
+        if !result.success {
+            return Err(anyhow!(result.error_message.unwrap_or_else(|| "Jupiter swap failed".to_string())));
+        }
+        let filled = result.input_amount as f64 / 1_000_000_000.0;
+        let usdc_out = result.output_amount as f64 / 1_000_000.0;
+        Ok(TwapFill {
+            filled,
+            price: if filled > 0.0 { usdc_out / filled } else { 0.0 },
+        })
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    realized_usd: f64,
+    pending_usd: f64,
+    converted_usd: f64,
+    /// Schedule reference: startup, then the last conversion attempt
+    last_run_at: DateTime<Utc>,
+    next_milestone_usd: Option<f64>,
+    /// Milestone crossed and not yet acted on
+    milestone_hit: Option<f64>,
+    converting: bool,
+    history: Vec<ProfitConversion>,
+}
+
+/// Scheduled conversion of realized profit to USDC
+#[derive(Debug)]
+pub struct ProfitTaking {
+    config: ProfitTakingConfig,
+    state: RwLock<State>,
+}
+
+impl ProfitTaking {
+    pub fn new(config: ProfitTakingConfig) -> Self {
+        let state = State {
+            realized_usd: 0.0,
+            pending_usd: 0.0,
+            converted_usd: 0.0,
+            last_run_at: Utc::now(),
+            next_milestone_usd: config.milestone_usd.filter(|step| *step > 0.0),
+            milestone_hit: None,
+            converting: false,
+            history: Vec::new(),
+        };
+        Self { config, state: RwLock::new(state) }
+    }
+
+    pub fn config(&self) -> &ProfitTakingConfig {
+        &self.config
+    }
+
+    /// Feed realized (confirmed) PnL; losses reduce what is pending first
+    pub fn record_realized(&self, pnl_usd: f64) {
+        if pnl_usd == 0.0 || !pnl_usd.is_finite() {
+            return;
+        }
+        let mut state = self.state.write();
+        state.realized_usd += pnl_usd;
+        state.pending_usd = (state.pending_usd + pnl_usd * self.config.convert_percent / 100.0).max(0.0);
+
+        if let (Some(step), Some(mut next)) = (self.config.milestone_usd, state.next_milestone_usd) {
+            if state.realized_usd >= next {
+                info!("🏦 Realized profit passed ${:.2}: profit-taking conversion due", next);
+                state.milestone_hit = Some(next);
+                while next <= state.realized_usd {
+                    next += step;
+                }
+                state.next_milestone_usd = Some(next);
+            }
+        }
+    }
+
+    /// Whether a conversion should start now, and why
+    pub fn due(&self, now: DateTime<Utc>) -> Option<ConversionTrigger> {
+        let state = self.state.read();
+        if state.converting || state.pending_usd < self.config.min_conversion_usd {
+            return None;
+        }
+        if let Some(milestone) = state.milestone_hit {
+            return Some(ConversionTrigger::Milestone(milestone));
+        }
+        let hours = self.config.interval_hours?;
+        (now - state.last_run_at >= Duration::hours(hours as i64)).then_some(ConversionTrigger::Schedule)
+    }
+
+    /// Convert pending profit if a conversion is due
+    pub async fn run_due(&self, venue: &dyn ConversionVenue) -> Option<ProfitConversion> {
+        let trigger = self.due(Utc::now())?;
+        let requested_usd = {
+            let mut state = self.state.write();
+            state.converting = true;
+            state.pending_usd
+        };
+        let conversion = self.convert(venue, trigger, requested_usd).await;
+
+        let mut state = self.state.write();
+        state.converting = false;
+        state.last_run_at = Utc::now();
+        state.milestone_hit = None;
+        let conversion = conversion?;
+        state.pending_usd = (state.pending_usd - conversion.converted_usd).max(0.0);
+        state.converted_usd += conversion.converted_usd;
+        state.history.push(conversion.clone());
+        Some(conversion)
+    }
+
+    async fn convert(&self, venue: &dyn ConversionVenue, trigger: ConversionTrigger, requested_usd: f64) -> Option<ProfitConversion> {
+        let started_at = Utc::now();
+        let arrival_price = match venue.sol_price().await {
+            Ok(price) if price > 0.0 => price,
+            Ok(price) => {
+                warn!("⚠️ Profit-taking skipped: invalid SOL price {}", price);
+                return None;
+            }
+            Err(e) => {
+                warn!("⚠️ Profit-taking skipped: SOL price unavailable: {}", e);
+                return None;
+            }
+        };
+        let sol = requested_usd / arrival_price;
+        info!("🏦 Converting ${:.2} of realized profit ({:.4} SOL) to USDC ({:?})", requested_usd, sol, trigger);
+
+        let executor = TwapExecutor::new(self.config.twap.clone());
+        let (fee, slippage) = (self.config.priority_fee_lamports, self.config.max_slippage_bps);
+        let report = executor
+            .execute(TwapSide::Sell, sol, arrival_price, || venue.sol_price(), |size| venue.sell_sol(size, fee, slippage))
+            .await;
+
+        let converted_usd = report.filled * report.average_price;
+        info!("🏦 Profit-taking converted ${:.2} of ${:.2} ({:.4} SOL sold)", converted_usd, requested_usd, report.filled);
+        Some(ProfitConversion {
+            id: Uuid::new_v4(),
+            trigger,
+            requested_usd,
+            converted_usd,
+            sol_sold: report.filled,
+            started_at,
+            finished_at: Utc::now(),
+            report,
+        })
+    }
+
+    pub fn status(&self) -> ProfitTakingStatus {
+        let state = self.state.read();
+        ProfitTakingStatus {
+            realized_usd: state.realized_usd,
+            pending_usd: state.pending_usd,
+            converted_usd: state.converted_usd,
+            conversions: state.history.len(),
+            last_conversion_at: state.history.last().map(|c| c.finished_at),
+            next_milestone_usd: state.next_milestone_usd,
+            converting: state.converting,
+        }
+    }
+
+    /// Finished conversions, oldest first
+    pub fn conversions(&self) -> Vec<ProfitConversion> {
+        self.state.read().history.clone()
+    }
+
+    /// Check for due conversions every `check_interval_secs`
+    pub fn start(self: Arc<Self>, venue: Arc<dyn ConversionVenue>) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(self.config.check_interval_secs.max(1)));
+            loop {
+                interval.tick().await;
+                self.run_due(venue.as_ref()).await;
+            }
+        })
+    }
+}
+
+impl Default for ProfitTaking {
+    fn default() -> Self {
+        Self::new(ProfitTakingConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockVenue {
+        price: f64,
+    }
+
+    #[async_trait]
+    impl ConversionVenue for MockVenue {
+        async fn sol_price(&self) -> Result<f64> {
+            Ok(self.price)
+        }
+        async fn sell_sol(&self, sol: f64, _fee: u64, _slippage: u16) -> Result<TwapFill> {
+            Ok(TwapFill { filled: sol, price: self.price })
+        }
+    }
+
+    fn config() -> ProfitTakingConfig {
+        ProfitTakingConfig {
+            twap: TwapConfig { duration_secs: 0, slices: 4, ..Default::default() },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pending_share_losses_and_triggers() {
+        let policy = ProfitTaking::new(config());
+        let now = Utc::now();
+
+        policy.record_realized(30.0);
+        assert!((policy.status().pending_usd - 15.0).abs() < 1e-9);
+        // Below the minimum, and the schedule has not elapsed
+        assert_eq!(policy.due(now + Duration::hours(25)), None);
+
+        policy.record_realized(100.0);
+        assert_eq!(policy.due(now), None);
+        assert_eq!(policy.due(now + Duration::hours(25)), Some(ConversionTrigger::Schedule));
+
+        // Losses eat into the pending amount
+        policy.record_realized(-40.0);
+        assert!((policy.status().pending_usd - 45.0).abs() < 1e-9);
+
+        policy.record_realized(500.0);
+        assert_eq!(policy.due(now), Some(ConversionTrigger::Milestone(500.0)));
+        assert_eq!(policy.status().next_milestone_usd, Some(1_000.0));
+    }
+
+    #[tokio::test]
+    async fn test_conversion_runs_as_twap_and_clears_pending() {
+        let policy = ProfitTaking::new(config());
+        let venue = MockVenue { price: 150.0 };
+        assert!(policy.run_due(&venue).await.is_none());
+
+        policy.record_realized(600.0);
+        let conversion = policy.run_due(&venue).await.unwrap();
+        assert_eq!(conversion.trigger, ConversionTrigger::Milestone(500.0));
+        assert!((conversion.sol_sold - 2.0).abs() < 1e-9);
+        assert!((conversion.converted_usd - 300.0).abs() < 1e-6);
+        assert!(conversion.report.slices_submitted >= 4);
+
+        let status = policy.status();
+        assert!(status.pending_usd.abs() < 1e-6);
+        assert!((status.converted_usd - 300.0).abs() < 1e-6);
+        assert_eq!(status.conversions, 1);
+        // Milestone consumed; nothing due until more profit arrives
+        assert!(policy.due(Utc::now()).is_none());
+    }
+}
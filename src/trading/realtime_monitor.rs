@@ -1,6 +1,7 @@
 //! Real-time Route Monitor for High-Frequency Trading
 //! Handles live market data with sub-second refresh rates
 
+use tracing::info;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -295,13 +296,13 @@ impl RealtimeRouteMonitor {
                 match alert.alert_type.as_str() {
                     "high_opportunity" => {
                         if let Some(profit) = alert.profit_percentage {
-                            println!("🚨 HIGH OPPORTUNITY ALERT: Route {} - {:.2}% profit potential", 
+                            info!("🚨 HIGH OPPORTUNITY ALERT: Route {} - {:.2}% profit potential", 
                                      alert.route_id, profit);
                         }
                     },
                     "low_liquidity" => {
                         if let Some(drop) = alert.liquidity_drop {
-                            println!("⚠️ LOW LIQUIDITY ALERT: Route {} - {:.1}% liquidity drop", 
+                            info!("⚠️ LOW LIQUIDITY ALERT: Route {} - {:.1}% liquidity drop", 
                                      alert.route_id, drop * 100.0);
                         }
                     },
@@ -318,25 +319,25 @@ impl RealtimeRouteMonitor {
     /// Display real-time route dashboard
     pub fn display_realtime_dashboard(&self) {
         if let Some(data) = &self.data {
-            println!("\n╔══════════════════════════════════════════════════════════════════╗");
-            println!("║                    REAL-TIME ROUTES DASHBOARD                   ║");
-            println!("╠══════════════════════════════════════════════════════════════════╣");
-            println!("║ Routes: {} │ Best: #{} │ Refresh: {}ms │ Age: {}ms       ║",
+            info!("╔══════════════════════════════════════════════════════════════════╗");
+            info!("║                    REAL-TIME ROUTES DASHBOARD                   ║");
+            info!("╠══════════════════════════════════════════════════════════════════╣");
+            info!("║ Routes: {} │ Best: #{} │ Refresh: {}ms │ Age: {}ms       ║",
                      data.total_routes,
                      data.best_route_index,
                      data.refresh_rate_ms,
                      (chrono::Utc::now().timestamp_millis() - data.timestamp as i64));
             
             if let Some(best_route) = self.get_best_route() {
-                println!("║ 🏆 BEST: {} │ Profit: {:.3} │ Risk: {:.3} │ Exec: {:.1}%   ║",
+                info!("║ 🏆 BEST: {} │ Profit: {:.3} │ Risk: {:.3} │ Exec: {:.1}%   ║",
                          best_route.id,
                          best_route.profitability_score,
                          best_route.risk_score,
                          best_route.execution_probability * 100.0);
             }
             
-            println!("╠══════════════════════════════════════════════════════════════════╣");
-            println!("║ 📊 Market: Sentiment {:.3} │ Volatility {:.3} │ Liquidity {:.3} ║",
+            info!("╠══════════════════════════════════════════════════════════════════╣");
+            info!("║ 📊 Market: Sentiment {:.3} │ Volatility {:.3} │ Liquidity {:.3} ║",
                      data.market_conditions.overall_sentiment,
                      data.market_conditions.volatility_index,
                      data.market_conditions.liquidity_index);
@@ -344,21 +345,21 @@ impl RealtimeRouteMonitor {
             // Active alerts
             let active_alerts = self.get_active_alerts();
             if !active_alerts.is_empty() {
-                println!("║ 🚨 ACTIVE ALERTS: {}                                           ║", active_alerts.len());
+                info!("║ 🚨 ACTIVE ALERTS: {}                                           ║", active_alerts.len());
                 for alert in active_alerts.iter().take(2) {
                     let alert_emoji = match alert.alert_type.as_str() {
                         "high_opportunity" => "💰",
                         "low_liquidity" => "⚠️",
                         _ => "ℹ️",
                     };
-                    println!("║   {} {} (Route: {})                                  ║",
+                    info!("║   {} {} (Route: {})                                  ║",
                              alert_emoji, alert.alert_type, &alert.route_id[..12.min(alert.route_id.len())]);
                 }
             }
             
-            println!("╚══════════════════════════════════════════════════════════════════╝");
+            info!("╚══════════════════════════════════════════════════════════════════╝");
         } else {
-            println!("⚠️ No real-time route data available");
+            info!("⚠️ No real-time route data available");
         }
     }
 
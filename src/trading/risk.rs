@@ -1,10 +1,124 @@
 use crate::{
     config::SimpleConfig,
-    types::{ArbitrageOpportunity, ApiResult as Result},
+    types::{ArbitrageOpportunity, ApiResult as Result, Opportunity},
 };
+use super::token_limits::{asset_key, ExposureCap, TokenHeadroom, TokenLimits, TokenLimitsConfig};
+use super::drawdown_ladder::DrawdownLadder;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use std::time::Duration;
 use tracing::{info, warn};
 
+/// Temporary cap on exposure to one asset (e.g. after an exploit headline)
+#[derive(Debug, Clone)]
+pub struct AssetRestriction {
+    pub symbol: String,
+    /// Fraction of the normal max position size still allowed (0.0 = blocked)
+    pub max_position_fraction: f64,
+    pub reason: String,
+    pub until: DateTime<Utc>,
+}
+
+/// Signed change in holdings of one asset, in position-size units
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExposureLeg {
+    pub asset: String,
+    pub delta: f64,
+}
+
+/// A trade some strategy is about to execute, as exposure changes
+#[derive(Debug, Clone)]
+pub struct PendingTrade {
+    pub id: String,
+    pub strategy: String,
+    pub legs: Vec<ExposureLeg>,
+}
+
+impl PendingTrade {
+    /// Sell `value` worth of `sell_asset` for `buy_asset`
+    pub fn swap(id: &str, strategy: &str, sell_asset: &str, buy_asset: &str, value: f64) -> Self {
+        Self {
+            id: id.to_string(),
+            strategy: strategy.to_string(),
+            legs: vec![
+                ExposureLeg { asset: sell_asset.to_string(), delta: -value },
+                ExposureLeg { asset: buy_asset.to_string(), delta: value },
+            ],
+        }
+    }
+
+    /// Capital leaves the route's first token and returns, with the profit, in its last
+    ///
+    /// Amounts are whole units of the opportunity's mint; closed loops net to the profit.
+    pub fn from_opportunity(opportunity: &Opportunity) -> Self {
+        let scale = 10f64.powi(opportunity.decimals as i32);
+        let capital = opportunity.required_capital as f64 / scale;
+        let proceeds = capital + opportunity.expected_profit as f64 / scale;
+        let first = opportunity.route.first().map(|hop| hop.token.clone()).unwrap_or_else(|| opportunity.mint.clone());
+        let last = opportunity.route.last().map(|hop| hop.token.clone()).unwrap_or_else(|| opportunity.mint.clone());
+        Self {
+            id: opportunity.id.clone(),
+            strategy: format!("{:?}", opportunity.kind),
+            legs: vec![ExposureLeg { asset: first, delta: -capital }, ExposureLeg { asset: last, delta: proceeds }],
+        }
+    }
+
+    /// Legs summed per asset
+    fn net_legs(&self) -> HashMap<String, f64> {
+        let mut net = HashMap::new();
+        for leg in &self.legs {
+            *net.entry(asset_key(&leg.asset)).or_insert(0.0) += leg.delta;
+        }
+        net
+    }
+}
+
+/// An asset whose netted exposure would exceed its limit
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExposureBreach {
+    pub asset: String,
+    pub net_before: f64,
+    pub net_after: f64,
+    pub limit: f64,
+}
+
+/// Effect of a trade on the netted book
+#[derive(Debug, Clone, Default)]
+pub struct NettingResult {
+    /// Sum of |leg|, what a per-trade check would see
+    pub gross: f64,
+    /// Change in total |net exposure| across assets; negative when the trade offsets others
+    pub incremental: f64,
+    pub breaches: Vec<ExposureBreach>,
+}
+
+impl NettingResult {
+    pub fn is_acceptable(&self) -> bool {
+        self.breaches.is_empty()
+    }
+}
+
+/// Held positions plus trades reserved but not yet settled
+#[derive(Debug, Default)]
+struct ExposureBook {
+    positions: HashMap<String, f64>,
+    pending: HashMap<String, PendingTrade>,
+}
+
+impl ExposureBook {
+    fn net(&self) -> HashMap<String, f64> {
+        let mut net = self.positions.clone();
+        for trade in self.pending.values() {
+            for (asset, delta) in trade.net_legs() {
+                *net.entry(asset).or_insert(0.0) += delta;
+            }
+        }
+        net
+    }
+}
+
 /// Risk management for trading operations
 #[derive(Clone)]
 pub struct RiskManager {
@@ -13,6 +127,16 @@ pub struct RiskManager {
     max_daily_loss: f64,
     min_confidence_score: f64,
     max_execution_time: Duration,
+    /// Shared across clones so every holder of the manager sees new restrictions
+    asset_restrictions: Arc<RwLock<HashMap<String, AssetRestriction>>>,
+    /// Netted exposure across strategies, shared across clones like the restrictions
+    exposure: Arc<RwLock<ExposureBook>>,
+    /// Settlement assets whose exposure is not limited
+    numeraires: HashSet<String>,
+    /// Per-token caps by liquidity tier and override, shared across clones
+    token_limits: Arc<RwLock<TokenLimits>>,
+    /// Daily drawdown de-risking, shared across clones once attached
+    drawdown_ladder: Arc<RwLock<Option<Arc<DrawdownLadder>>>>,
 }
 
 impl RiskManager {
@@ -24,9 +148,211 @@ impl RiskManager {
             max_daily_loss: 0.05, // 5% max daily loss
             min_confidence_score: 0.7, // Minimum 70% confidence
             max_execution_time: Duration::from_secs(60), // 1 minute max
+            asset_restrictions: Arc::new(RwLock::new(HashMap::new())),
+            exposure: Arc::new(RwLock::new(ExposureBook::default())),
+            numeraires: HashSet::from(["USDC".to_string(), "USDT".to_string()]),
+            token_limits: Arc::new(RwLock::new(TokenLimits::default())),
+            drawdown_ladder: Arc::new(RwLock::new(None)),
         }
     }
     
+    /// Scale position limits and block entries as the day's drawdown deepens
+    pub fn attach_drawdown_ladder(&self, ladder: Arc<DrawdownLadder>) {
+        *self.drawdown_ladder.write() = Some(ladder);
+    }
+    
+    /// `false` while the drawdown ladder blocks new entries
+    pub fn entries_allowed(&self) -> bool {
+        self.drawdown_ladder.read().as_ref().is_none_or(|ladder| ladder.allows_entries())
+    }
+    
+    /// Cap exposure per token by liquidity tier, with per-mint overrides
+    pub fn with_token_limits(self, config: TokenLimitsConfig) -> Self {
+        *self.token_limits.write() = TokenLimits::new(config);
+        self
+    }
+    
+    /// Pool liquidity of `asset` (USD), which decides its tier
+    pub fn set_token_liquidity(&self, asset: &str, liquidity_usd: f64) {
+        self.token_limits.write().set_liquidity(asset, liquidity_usd);
+    }
+    
+    /// Portfolio value that percent caps are taken of
+    pub fn set_portfolio_value(&self, value: f64) {
+        self.token_limits.write().set_portfolio_value(value);
+    }
+    
+    /// Replace the tier default cap for `asset`
+    pub fn set_token_cap(&self, asset: &str, cap: ExposureCap) {
+        info!("🎚️ Exposure cap for {} set to {:?}", asset, cap);
+        self.token_limits.write().set_override(asset, cap);
+    }
+    
+    /// Return `asset` to its tier default cap
+    pub fn clear_token_cap(&self, asset: &str) {
+        self.token_limits.write().remove_override(asset);
+    }
+    
+    /// Cap exposure to `symbol` for `duration`; the tightest active cap wins
+    pub fn restrict_asset(&self, symbol: &str, max_position_fraction: f64, reason: &str, duration: Duration) {
+        let symbol = asset_key(symbol);
+        let until = Utc::now() + chrono::Duration::from_std(duration).unwrap_or_else(|_| chrono::Duration::hours(1));
+        let fraction = max_position_fraction.clamp(0.0, 1.0);
+        let mut restrictions = self.asset_restrictions.write();
+        if let Some(existing) = restrictions.get(&symbol) {
+            if existing.until > Utc::now() && existing.max_position_fraction <= fraction {
+                return;
+            }
+        }
+        warn!("🛑 Exposure to {} capped at {:.0}% until {}: {}", symbol, fraction * 100.0, until.format("%H:%M UTC"), reason);
+        restrictions.insert(symbol.clone(), AssetRestriction {
+            symbol,
+            max_position_fraction: fraction,
+            reason: reason.to_string(),
+            until,
+        });
+    }
+    
+    /// Remove a restriction before it expires
+    pub fn lift_restriction(&self, symbol: &str) {
+        if self.asset_restrictions.write().remove(&asset_key(symbol)).is_some() {
+            info!("✅ Exposure restriction on {} lifted", symbol);
+        }
+    }
+    
+    /// Restrictions still in force
+    pub fn active_restrictions(&self) -> Vec<AssetRestriction> {
+        let now = Utc::now();
+        let mut restrictions = self.asset_restrictions.write();
+        restrictions.retain(|_, r| r.until > now);
+        restrictions.values().cloned().collect()
+    }
+    
+    /// Max position size for `symbol`, after any active restriction, its token cap and drawdown de-risking
+    pub fn position_limit_for(&self, symbol: &str) -> f64 {
+        let restrictions = self.asset_restrictions.read();
+        let size_multiplier = self.drawdown_ladder.read().as_ref().map_or(1.0, |ladder| ladder.size_multiplier());
+        let limit = match restrictions.get(&asset_key(symbol)) {
+            Some(r) if r.until > Utc::now() => self.max_position_size * r.max_position_fraction,
+            _ => self.max_position_size,
+        } * size_multiplier;
+        match self.token_limits.read().limit(symbol) {
+            Some(cap) => limit.min(cap),
+            None => limit,
+        }
+    }
+    
+    /// Room left for `asset` under its limit, given held and reserved exposure
+    pub fn token_headroom(&self, asset: &str) -> TokenHeadroom {
+        let asset = asset_key(asset);
+        let exposure = self.net_exposure().get(&asset).copied().unwrap_or(0.0).abs();
+        let limit = self.position_limit_for(&asset);
+        let limits = self.token_limits.read();
+        TokenHeadroom {
+            tier: limits.tier(&asset),
+            overridden: limits.has_override(&asset),
+            remaining: (limit - exposure).max(0.0),
+            utilization_percent: if limit > 0.0 { exposure / limit * 100.0 } else { 100.0 },
+            asset,
+            limit,
+            exposure,
+        }
+    }
+    
+    /// Headroom for every capped asset held, reserved, overridden or with known liquidity
+    pub fn token_headrooms(&self) -> Vec<TokenHeadroom> {
+        let mut assets = self.token_limits.read().known_assets();
+        assets.extend(self.net_exposure().into_iter().filter(|(_, net)| *net != 0.0).map(|(asset, _)| asset));
+        assets.sort();
+        assets.dedup();
+        assets
+            .into_iter()
+            .filter(|asset| !self.numeraires.contains(asset))
+            .map(|asset| self.token_headroom(&asset))
+            .collect()
+    }
+    
+    /// Record a held position (replaces the previous value)
+    pub fn set_position(&self, asset: &str, value: f64) {
+        self.exposure.write().positions.insert(asset_key(asset), value);
+    }
+    
+    /// Reserved, unsettled trades with a leg in `asset`
+    pub fn open_trades(&self, asset: &str) -> usize {
+        let asset = asset_key(asset);
+        self.exposure
+            .read()
+            .pending
+            .values()
+            .filter(|trade| trade.legs.iter().any(|leg| asset_key(&leg.asset) == asset))
+            .count()
+    }
+    
+    /// Held positions plus every reserved trade, per asset
+    pub fn net_exposure(&self) -> HashMap<String, f64> {
+        self.exposure.read().net()
+    }
+    
+    /// Incremental exposure of `trade` against the netted book, without reserving it
+    ///
+    /// Limits apply to the netted result: a trade is only refused for an asset
+    /// whose |net exposure| it grows past that asset's position limit, so a
+    /// trade offsetting another strategy's pending trade passes even when its
+    /// gross size alone would not.
+    pub fn evaluate_netted(&self, trade: &PendingTrade) -> NettingResult {
+        Self::net_against(&self.exposure.read().net(), trade, |asset| self.netting_limit(asset))
+    }
+    
+    /// Check `trade` and, if it fits, hold its exposure until [`settle_exposure`](Self::settle_exposure)
+    pub fn reserve_exposure(&self, trade: PendingTrade) -> NettingResult {
+        let mut book = self.exposure.write();
+        let result = Self::net_against(&book.net(), &trade, |asset| self.netting_limit(asset));
+        if result.is_acceptable() {
+            book.pending.insert(trade.id.clone(), trade);
+        } else {
+            warn!("🛑 Trade {} refused on netted exposure: {:?}", trade.id, result.breaches);
+        }
+        result
+    }
+    
+    /// Release a reservation; a filled trade moves into the held positions
+    pub fn settle_exposure(&self, trade_id: &str, filled: bool) {
+        let mut book = self.exposure.write();
+        if let Some(trade) = book.pending.remove(trade_id) {
+            if filled {
+                for (asset, delta) in trade.net_legs() {
+                    *book.positions.entry(asset).or_insert(0.0) += delta;
+                }
+            }
+        }
+    }
+    
+    fn netting_limit(&self, asset: &str) -> Option<f64> {
+        if self.numeraires.contains(&asset_key(asset)) {
+            None
+        } else {
+            Some(self.position_limit_for(asset))
+        }
+    }
+    
+    fn net_against(net: &HashMap<String, f64>, trade: &PendingTrade, limit_for: impl Fn(&str) -> Option<f64>) -> NettingResult {
+        let mut result = NettingResult {
+            gross: trade.legs.iter().map(|leg| leg.delta.abs()).sum(),
+            ..Default::default()
+        };
+        for (asset, delta) in trade.net_legs() {
+            let net_before = net.get(&asset).copied().unwrap_or(0.0);
+            let net_after = net_before + delta;
+            result.incremental += net_after.abs() - net_before.abs();
+            if let Some(limit) = limit_for(&asset) {
+                if net_after.abs() > limit && net_after.abs() > net_before.abs() {
+                    result.breaches.push(ExposureBreach { asset, net_before, net_after, limit });
+                }
+            }
+        }
+        result
+    }
+    
     /// Assess the risk of an arbitrage opportunity
     pub async fn assess_opportunity(&self, opportunity: &ArbitrageOpportunity) -> Result<RiskAssessment> {
         let mut assessment = RiskAssessment::default();
@@ -51,6 +377,21 @@ impl RiskManager {
             assessment.is_acceptable = false;
         }
         
+        // Check drawdown de-risking
+        if !self.entries_allowed() {
+            risk_factors.push(RiskFactor::DrawdownDeRisking);
+            assessment.is_acceptable = false;
+        }
+        
+        // Check event-driven asset restrictions
+        for token in [&opportunity.pair.base_token, &opportunity.pair.quote_token] {
+            if opportunity.volume_required > self.position_limit_for(&token.symbol) {
+                risk_factors.push(RiskFactor::AssetRestricted);
+                assessment.is_acceptable = false;
+                break;
+            }
+        }
+        
         // Check execution time window
         if opportunity.execution_time_window > self.max_execution_time {
             risk_factors.push(RiskFactor::LongExecutionTime);
@@ -144,6 +485,8 @@ pub enum RiskFactor {
     MarketVolatility,
     LiquidityRisk,
     TechnicalIssue,
+    AssetRestricted,
+    DrawdownDeRisking,
 }
 
 impl std::fmt::Display for RiskFactor {
@@ -158,6 +501,8 @@ impl std::fmt::Display for RiskFactor {
             RiskFactor::MarketVolatility => write!(f, "Market Volatility"),
             RiskFactor::LiquidityRisk => write!(f, "Liquidity Risk"),
             RiskFactor::TechnicalIssue => write!(f, "Technical Issue"),
+            RiskFactor::AssetRestricted => write!(f, "Asset Restricted"),
+            RiskFactor::DrawdownDeRisking => write!(f, "Drawdown De-Risking"),
         }
     }
 }
@@ -166,6 +511,7 @@ impl std::fmt::Display for RiskFactor {
 mod tests {
     use super::*;
     use crate::types::{ArbitragePair, Token};
+    use crate::trading::token_limits::TokenTier;
     
     fn create_test_config() -> SimpleConfig {
         SimpleConfig {
@@ -290,4 +636,127 @@ mod tests {
         assert!(!assessment.is_acceptable);
         assert!(assessment.risk_factors.contains(&RiskFactor::ExcessivePositionSize));
     }
+    
+    #[tokio::test]
+    async fn test_asset_restriction_blocks_opportunity() {
+        let config = create_test_config();
+        let risk_manager = RiskManager::new(&config);
+        let mut opportunity = create_test_opportunity();
+        opportunity.volume_required = 0.05;
+        
+        // Restrictions are shared with clones
+        risk_manager.clone().restrict_asset(&opportunity.pair.base_token.symbol, 0.0, "exploit reported", Duration::from_secs(60));
+        let assessment = risk_manager.assess_opportunity(&opportunity).await.unwrap();
+        assert!(!assessment.is_acceptable);
+        assert!(assessment.risk_factors.contains(&RiskFactor::AssetRestricted));
+        
+        risk_manager.lift_restriction(&opportunity.pair.base_token.symbol);
+        assert!(risk_manager.active_restrictions().is_empty());
+    }
+    
+    #[test]
+    fn test_offsetting_trades_pass_on_netted_exposure() {
+        let mut config = create_test_config();
+        config.max_position_size = 10.0;
+        let risk_manager = RiskManager::new(&config);
+        
+        // Arbitrage leg sells 8 SOL into USDC
+        let arb = PendingTrade::swap("arb-1", "Arbitrage", "SOL", "USDC", 8.0);
+        assert!(risk_manager.reserve_exposure(arb).is_acceptable());
+        
+        // A further 6 SOL sale nets to -14 SOL: refused, though 6 alone is within limits
+        let second_sale = PendingTrade::swap("arb-2", "Arbitrage", "SOL", "USDC", 6.0);
+        let result = risk_manager.evaluate_netted(&second_sale);
+        assert_eq!(result.breaches.len(), 1);
+        assert_eq!(result.breaches[0].asset, "SOL");
+        assert_eq!(result.breaches[0].net_after, -14.0);
+        
+        // Buying 12 SOL of exposure back offsets the pending sale: net +4, accepted
+        let mut sniper = PendingTrade::swap("snipe-1", "Sniper", "USDC", "SOL", 12.0);
+        sniper.legs.push(ExposureLeg { asset: "BONK".to_string(), delta: 0.0 });
+        let result = risk_manager.reserve_exposure(sniper);
+        assert!(result.is_acceptable());
+        assert_eq!(result.gross, 24.0);
+        assert!(result.incremental < 12.0);
+        assert_eq!(risk_manager.net_exposure()["SOL"], 4.0);
+    }
+    
+    #[test]
+    fn test_settled_trades_fold_into_positions() {
+        let mut config = create_test_config();
+        config.max_position_size = 10.0;
+        let risk_manager = RiskManager::new(&config);
+        risk_manager.set_position("BONK", 9.0);
+        
+        assert!(risk_manager.reserve_exposure(PendingTrade::swap("buy", "Sniper", "SOL", "BONK", 0.5)).is_acceptable());
+        risk_manager.settle_exposure("buy", true);
+        // Cancelled reservations leave nothing behind
+        assert!(risk_manager.reserve_exposure(PendingTrade::swap("buy-2", "Sniper", "SOL", "BONK", 0.4)).is_acceptable());
+        risk_manager.settle_exposure("buy-2", false);
+        assert_eq!(risk_manager.net_exposure()["BONK"], 9.5);
+        
+        // Restrictions tighten the netted limit
+        risk_manager.restrict_asset("BONK", 0.5, "exploit", Duration::from_secs(60));
+        let reduce = PendingTrade::swap("sell", "Sniper", "BONK", "SOL", 1.0);
+        assert!(risk_manager.evaluate_netted(&reduce).is_acceptable());
+        assert!(!risk_manager.evaluate_netted(&PendingTrade::swap("more", "Sniper", "SOL", "BONK", 0.1)).is_acceptable());
+    }
+    
+    #[test]
+    fn test_token_caps_limit_netted_exposure_and_report_headroom() {
+        let mut config = create_test_config();
+        config.max_position_size = 100.0;
+        let risk_manager = RiskManager::new(&config).with_token_limits(TokenLimitsConfig::default());
+        risk_manager.set_portfolio_value(200.0);
+        risk_manager.set_token_liquidity("SOL", 50_000_000.0);
+        risk_manager.set_position("NEWMEME", 6.0);
+        
+        // Long tail: 5% of 200 = 10; blue chip: 50% of 200 = 100 (position limit also 100)
+        assert_eq!(risk_manager.position_limit_for("NEWMEME"), 10.0);
+        assert_eq!(risk_manager.position_limit_for("SOL"), 100.0);
+        assert!(!risk_manager.evaluate_netted(&PendingTrade::swap("buy", "Sniper", "USDC", "NEWMEME", 5.0)).is_acceptable());
+        
+        let headroom = risk_manager.token_headroom("newmeme");
+        assert_eq!(headroom.tier, TokenTier::LongTail);
+        assert_eq!((headroom.exposure, headroom.remaining), (6.0, 4.0));
+        
+        // Per-mint override replaces the tier default
+        risk_manager.clone().set_token_cap("NEWMEME", ExposureCap::value(20.0));
+        assert!(risk_manager.evaluate_netted(&PendingTrade::swap("buy", "Sniper", "USDC", "NEWMEME", 5.0)).is_acceptable());
+        let headrooms = risk_manager.token_headrooms();
+        assert_eq!(headrooms.iter().map(|h| h.asset.as_str()).collect::<Vec<_>>(), vec!["NEWMEME", "SOL"]);
+        assert!(headrooms[0].overridden);
+    }
+    
+    #[test]
+    fn test_mint_exposure_keeps_its_case() {
+        let risk_manager = RiskManager::new(&create_test_config()).with_token_limits(TokenLimitsConfig::default());
+        let mint = create_test_opportunity().pair.quote_token.mint;
+        risk_manager.set_token_liquidity(&mint, 50_000_000.0);
+        risk_manager.set_position(&mint, 1.0);
+        
+        assert_eq!(risk_manager.net_exposure()[&mint], 1.0);
+        let headroom = risk_manager.token_headroom(&mint);
+        assert_eq!((headroom.asset.as_str(), headroom.tier), (mint.as_str(), TokenTier::Bluechip));
+    }
+    
+    #[tokio::test]
+    async fn test_drawdown_ladder_scales_limits_and_blocks_entries() {
+        let mut config = create_test_config();
+        config.max_position_size = 10.0;
+        config.max_slippage = 0.10;
+        let risk_manager = RiskManager::new(&config);
+        let ladder = Arc::new(DrawdownLadder::default());
+        risk_manager.clone().attach_drawdown_ladder(ladder.clone());
+        
+        ladder.record_pnl(-250.0);
+        assert_eq!(risk_manager.position_limit_for("SOL"), 5.0);
+        
+        ladder.record_pnl(-200.0);
+        let mut opportunity = create_test_opportunity();
+        opportunity.volume_required = 1.0;
+        let assessment = risk_manager.assess_opportunity(&opportunity).await.unwrap();
+        assert!(assessment.risk_factors.contains(&RiskFactor::DrawdownDeRisking));
+        assert!(!assessment.is_acceptable);
+    }
 }
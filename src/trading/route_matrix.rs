@@ -0,0 +1,460 @@
+//! Data-parallel triangular route search over a dense rate matrix
+//!
+//! Rates are stored as log-rates in a row-major `n x n` matrix plus its
+//! transpose, so the return of `i -> j -> k -> i` for every closing token `k`
+//! is `L[i][j] + row_j[k] + col_i[k]`: two contiguous slices summed lane by
+//! lane. For each first edge `(i, j)` the scan keeps the best closing token.
+//!
+//! - [`scan_scalar`] is the reference implementation.
+//! - [`scan_parallel`] splits rows across rayon workers and reduces `k` in
+//!   fixed-width lanes the compiler vectorizes (std::simd is not stable).
+//! - `gpu::GpuRouteSearch` (feature `gpu`) runs the same reduction as a wgpu
+//!   compute shader, for graphs of [`GPU_MIN_TOKENS`] and up where the
+//!   upload cost pays off.
+//!
+//! All three return the same candidates, canonicalized so each cycle appears
+//! once, rotated to start at its lowest token index.
+
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Log-rate of a missing edge; finite so sums stay ordered
+pub const NO_EDGE: f64 = -1e30;
+/// Width of the lanes `k` is reduced in
+const LANES: usize = 8;
+/// Below this size the scalar scan beats thread dispatch
+pub const PARALLEL_MIN_TOKENS: usize = 32;
+/// Graph size from which the GPU path is worth its buffer uploads
+pub const GPU_MIN_TOKENS: usize = 512;
+
+/// Dense log-rate matrix over a token set
+#[derive(Debug, Clone, Default)]
+pub struct RateGraph {
+    tokens: Vec<String>,
+    index: HashMap<String, usize>,
+    /// `log_rates[i * n + j]` = ln(rate i -> j)
+    log_rates: Vec<f64>,
+    /// `log_rates_t[j * n + i]` = ln(rate i -> j)
+    log_rates_t: Vec<f64>,
+}
+
+impl RateGraph {
+    /// Graph over `(from, to, rate)` quotes; the token set is taken from the quotes
+    pub fn from_rates<'a>(rates: impl IntoIterator<Item = (&'a str, &'a str, f64)>) -> Self {
+        let rates: Vec<(&str, &str, f64)> = rates.into_iter().collect();
+        let mut graph = Self::default();
+        for (from, to, _) in &rates {
+            graph.intern(from);
+            graph.intern(to);
+        }
+        let n = graph.tokens.len();
+        graph.log_rates = vec![NO_EDGE; n * n];
+        graph.log_rates_t = vec![NO_EDGE; n * n];
+        for (from, to, rate) in rates {
+            graph.set_rate(from, to, rate);
+        }
+        graph
+    }
+
+    fn intern(&mut self, token: &str) {
+        if !self.index.contains_key(token) {
+            self.index.insert(token.to_string(), self.tokens.len());
+            self.tokens.push(token.to_string());
+        }
+    }
+
+    /// Update an existing pair's rate; unknown tokens are ignored
+    pub fn set_rate(&mut self, from: &str, to: &str, rate: f64) {
+        let (Some(&i), Some(&j)) = (self.index.get(from), self.index.get(to)) else {
+            return;
+        };
+        let n = self.len();
+        let log_rate = if rate > 0.0 && rate.is_finite() && i != j { rate.ln() } else { NO_EDGE };
+        self.log_rates[i * n + j] = log_rate;
+        self.log_rates_t[j * n + i] = log_rate;
+    }
+
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    pub fn token(&self, index: usize) -> &str {
+        &self.tokens[index]
+    }
+
+    pub fn log_rates(&self) -> &[f64] {
+        &self.log_rates
+    }
+
+    fn row(&self, i: usize) -> &[f64] {
+        let n = self.len();
+        &self.log_rates[i * n..(i + 1) * n]
+    }
+
+    fn column(&self, i: usize) -> &[f64] {
+        let n = self.len();
+        &self.log_rates_t[i * n..(i + 1) * n]
+    }
+
+    /// Token path `a -> b -> c -> a` of a candidate
+    pub fn path(&self, candidate: &TriangleCandidate) -> Vec<String> {
+        let [a, b, c] = candidate.path;
+        [a, b, c, a].iter().map(|&t| self.tokens[t].clone()).collect()
+    }
+}
+
+/// A cycle whose rates multiply to more than `exp(min_log_return)`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TriangleCandidate {
+    /// Token indices, rotated to start at the lowest one
+    pub path: [usize; 3],
+    pub log_return: f64,
+}
+
+impl TriangleCandidate {
+    fn canonical(i: usize, j: usize, k: usize, log_return: f64) -> Self {
+        let path = if i < j && i < k {
+            [i, j, k]
+        } else if j < k {
+            [j, k, i]
+        } else {
+            [k, i, j]
+        };
+        Self { path, log_return }
+    }
+
+    /// Gross return before fees and slippage, in basis points
+    pub fn return_bps(&self) -> f64 {
+        self.log_return.exp_m1() * 10_000.0
+    }
+}
+
+/// Best closing token for edge `(i, j)` by straight iteration; ties go to the lowest `k`
+fn best_closing_scalar(graph: &RateGraph, i: usize, j: usize) -> Option<(usize, f64)> {
+    let n = graph.len();
+    let first = graph.log_rates[i * n + j];
+    let mut best: Option<(usize, f64)> = None;
+    for k in 0..n {
+        if k == i || k == j {
+            continue;
+        }
+        let value = first + graph.log_rates[j * n + k] + graph.log_rates[k * n + i];
+        if best.map_or(true, |(_, top)| value > top) {
+            best = Some((k, value));
+        }
+    }
+    best
+}
+
+/// Same result as [`best_closing_scalar`], with the max reduced over lanes first
+fn best_closing_lanes(row_j: &[f64], col_i: &[f64], first: f64, i: usize, j: usize, threshold: f64) -> Option<(usize, f64)> {
+    let mut lanes = [NO_EDGE * 4.0; LANES];
+    let mut row_chunks = row_j.chunks_exact(LANES);
+    let mut col_chunks = col_i.chunks_exact(LANES);
+    for (row, col) in (&mut row_chunks).zip(&mut col_chunks) {
+        for ((lane, r), c) in lanes.iter_mut().zip(row).zip(col) {
+            *lane = lane.max(r + c);
+        }
+    }
+    let mut top = lanes.iter().copied().fold(NO_EDGE * 4.0, f64::max);
+    for (row, col) in row_chunks.remainder().iter().zip(col_chunks.remainder()) {
+        top = top.max(row + col);
+    }
+    // `i` and `j` themselves always sum to NO_EDGE or less (self edges are NO_EDGE),
+    // so a max above the threshold is a real closing token; locate it only then
+    if first + top <= threshold {
+        return None;
+    }
+    (0..row_j.len())
+        .filter(|&k| k != i && k != j)
+        .map(|k| (k, first + row_j[k] + col_i[k]))
+        .fold(None, |best: Option<(usize, f64)>, (k, value)| match best {
+            Some((_, current)) if current >= value => best,
+            _ => Some((k, value)),
+        })
+}
+
+fn collect(found: impl IntoIterator<Item = (usize, usize, usize, f64)>, min_log_return: f64) -> Vec<TriangleCandidate> {
+    let mut seen = HashSet::new();
+    let mut candidates: Vec<TriangleCandidate> = found
+        .into_iter()
+        .filter(|(_, _, _, value)| *value > min_log_return)
+        .map(|(i, j, k, value)| TriangleCandidate::canonical(i, j, k, value))
+        .filter(|candidate| seen.insert(candidate.path))
+        .collect();
+    candidates.sort_by(|a, b| b.log_return.total_cmp(&a.log_return).then(a.path.cmp(&b.path)));
+    candidates
+}
+
+/// Reference scan
+pub fn scan_scalar(graph: &RateGraph, min_log_return: f64) -> Vec<TriangleCandidate> {
+    let n = graph.len();
+    let mut found = Vec::new();
+    for i in 0..n {
+        for j in 0..n {
+            if i == j || graph.log_rates[i * n + j] <= NO_EDGE {
+                continue;
+            }
+            if let Some((k, value)) = best_closing_scalar(graph, i, j) {
+                found.push((i, j, k, value));
+            }
+        }
+    }
+    collect(found, min_log_return)
+}
+
+/// Rows across rayon workers, closing tokens reduced in lanes
+pub fn scan_parallel(graph: &RateGraph, min_log_return: f64) -> Vec<TriangleCandidate> {
+    let n = graph.len();
+    let found: Vec<(usize, usize, usize, f64)> = (0..n)
+        .into_par_iter()
+        .flat_map_iter(|i| {
+            let col_i = graph.column(i);
+            graph.row(i)
+                .iter()
+                .enumerate()
+                .filter(move |&(j, &first)| j != i && first > NO_EDGE)
+                .filter_map(move |(j, &first)| {
+                    best_closing_lanes(graph.row(j), col_i, first, i, j, min_log_return).map(|(k, value)| (i, j, k, value))
+                })
+        })
+        .collect();
+    collect(found, min_log_return)
+}
+
+/// Scalar for small graphs, parallel otherwise
+pub fn scan(graph: &RateGraph, min_log_return: f64) -> Vec<TriangleCandidate> {
+    if graph.len() < PARALLEL_MIN_TOKENS {
+        scan_scalar(graph, min_log_return)
+    } else {
+        scan_parallel(graph, min_log_return)
+    }
+}
+
+#[cfg(feature = "gpu")]
+pub mod gpu {
+    //! wgpu compute path: one invocation per first edge `(i, j)`
+
+    use super::{collect, RateGraph, TriangleCandidate, NO_EDGE};
+    use anyhow::{anyhow, Result};
+    use std::borrow::Cow;
+    use wgpu::util::DeviceExt;
+
+    const WORKGROUP: u32 = 16;
+    const SHADER: &str = r#"
+@group(0) @binding(0) var<storage, read> rates: array<f32>;
+@group(0) @binding(1) var<storage, read_write> best: array<f32>;
+@group(0) @binding(2) var<storage, read_write> best_k: array<u32>;
+@group(0) @binding(3) var<uniform> dims: vec4<u32>;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let n = dims.x;
+    let i = id.x;
+    let j = id.y;
+    if (i >= n || j >= n) {
+        return;
+    }
+    var top = -3.0e30;
+    var arg = 0xffffffffu;
+    let first = rates[i * n + j];
+    if (i != j && first > -1.0e29) {
+        for (var k = 0u; k < n; k = k + 1u) {
+            if (k == i || k == j) {
+                continue;
+            }
+            let value = first + rates[j * n + k] + rates[k * n + i];
+            if (value > top) {
+                top = value;
+                arg = k;
+            }
+        }
+    }
+    best[i * n + j] = top;
+    best_k[i * n + j] = arg;
+}
+"#;
+
+    /// Device, queue and compiled pipeline, reused across scans
+    pub struct GpuRouteSearch {
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        pipeline: wgpu::ComputePipeline,
+    }
+
+    impl GpuRouteSearch {
+        pub async fn new() -> Result<Self> {
+            let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+            let adapter = instance
+                .request_adapter(&wgpu::RequestAdapterOptions {
+                    power_preference: wgpu::PowerPreference::HighPerformance,
+                    compatible_surface: None,
+                    force_fallback_adapter: false,
+                })
+                .await
+                .ok_or_else(|| anyhow!("no GPU adapter available"))?;
+            let (device, queue) = adapter
+                .request_device(
+                    &wgpu::DeviceDescriptor {
+                        label: Some("route-search"),
+                        required_features: wgpu::Features::empty(),
+                        required_limits: adapter.limits(),
+                        memory_hints: wgpu::MemoryHints::Performance,
+                    },
+                    None,
+                )
+                .await?;
+            let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("route-search"),
+                source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(SHADER)),
+            });
+            let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("route-search"),
+                layout: None,
+                module: &module,
+                entry_point: Some("main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+            Ok(Self { device, queue, pipeline })
+        }
+
+        /// Same candidates as [`super::scan_parallel`], computed in f32
+        pub async fn scan(&self, graph: &RateGraph, min_log_return: f64) -> Result<Vec<TriangleCandidate>> {
+            let n = graph.len();
+            if n < 3 {
+                return Ok(Vec::new());
+            }
+            let rates: Vec<f32> = graph.log_rates().iter().map(|&r| if r <= NO_EDGE { -1.0e30 } else { r as f32 }).collect();
+            let cells = (n * n) as u64;
+
+            let storage = |label: &str, size: u64| {
+                self.device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some(label),
+                    size,
+                    usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+                    mapped_at_creation: false,
+                })
+            };
+            let rates_buffer = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("rates"),
+                contents: bytemuck::cast_slice(&rates),
+                usage: wgpu::BufferUsages::STORAGE,
+            });
+            let dims = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("dims"),
+                contents: bytemuck::cast_slice(&[n as u32, 0, 0, 0]),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+            let best = storage("best", cells * 4);
+            let best_k = storage("best_k", cells * 4);
+            let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("readback"),
+                size: cells * 8,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+
+            let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("route-search"),
+                layout: &self.pipeline.get_bind_group_layout(0),
+                entries: &[
+                    wgpu::BindGroupEntry { binding: 0, resource: rates_buffer.as_entire_binding() },
+                    wgpu::BindGroupEntry { binding: 1, resource: best.as_entire_binding() },
+                    wgpu::BindGroupEntry { binding: 2, resource: best_k.as_entire_binding() },
+                    wgpu::BindGroupEntry { binding: 3, resource: dims.as_entire_binding() },
+                ],
+            });
+            let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some("route-search") });
+            {
+                let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor { label: Some("route-search"), timestamp_writes: None });
+                pass.set_pipeline(&self.pipeline);
+                pass.set_bind_group(0, &bind_group, &[]);
+                let groups = (n as u32).div_ceil(WORKGROUP);
+                pass.dispatch_workgroups(groups, groups, 1);
+            }
+            encoder.copy_buffer_to_buffer(&best, 0, &readback, 0, cells * 4);
+            encoder.copy_buffer_to_buffer(&best_k, 0, &readback, cells * 4, cells * 4);
+            self.queue.submit(Some(encoder.finish()));
+
+            let slice = readback.slice(..);
+            let (tx, rx) = tokio::sync::oneshot::channel();
+            slice.map_async(wgpu::MapMode::Read, move |result| {
+                let _ = tx.send(result);
+            });
+            self.device.poll(wgpu::Maintain::Wait);
+            rx.await.map_err(|_| anyhow!("GPU readback dropped"))??;
+
+            let found = {
+                let bytes = slice.get_mapped_range();
+                let (values, ks) = bytes.split_at((cells * 4) as usize);
+                let values: &[f32] = bytemuck::cast_slice(values);
+                let ks: &[u32] = bytemuck::cast_slice(ks);
+                (0..n * n)
+                    .filter(|&cell| ks[cell] != u32::MAX)
+                    .map(|cell| (cell / n, cell % n, ks[cell] as usize, values[cell] as f64))
+                    .collect::<Vec<_>>()
+            };
+            readback.unmap();
+            Ok(collect(found, min_log_return))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Dense graph of near-1 rates (no arbitrage) from a fixed-seed LCG
+    fn noisy_graph(n: usize, seed: u64) -> (RateGraph, Vec<String>) {
+        let tokens: Vec<String> = (0..n).map(|t| format!("T{}", t)).collect();
+        let mut state = seed;
+        let mut rates = Vec::new();
+        for from in &tokens {
+            for to in &tokens {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                // ~1 in 8 pairs has no pool
+                if from != to && state >> 61 != 0 {
+                    let noise = ((state >> 11) as f64 / (1u64 << 53) as f64 - 0.5) * 0.004;
+                    rates.push((from.clone(), to.clone(), 0.985 + noise));
+                }
+            }
+        }
+        let graph = RateGraph::from_rates(rates.iter().map(|(f, t, r)| (f.as_str(), t.as_str(), *r)));
+        (graph, tokens)
+    }
+
+    #[test]
+    fn test_parallel_scan_matches_scalar_reference() {
+        for (n, seed) in [(5, 1), (37, 7), (90, 42)] {
+            let (graph, _) = noisy_graph(n, seed);
+            // Loose threshold so plenty of cycles qualify
+            let scalar = scan_scalar(&graph, -0.045);
+            let parallel = scan_parallel(&graph, -0.045);
+            assert!(!scalar.is_empty());
+            assert_eq!(scalar.len(), parallel.len(), "n = {}", n);
+            for (a, b) in scalar.iter().zip(&parallel) {
+                assert_eq!(a.path, b.path);
+                assert!((a.log_return - b.log_return).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_planted_triangle_is_the_only_profitable_cycle() {
+        let (mut graph, tokens) = noisy_graph(64, 3);
+        graph.set_rate(&tokens[40], &tokens[7], 1.01);
+        graph.set_rate(&tokens[7], &tokens[22], 1.01);
+        graph.set_rate(&tokens[22], &tokens[40], 1.0);
+
+        let found = scan(&graph, 0.0);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].path, [7, 22, 40]);
+        assert_eq!(graph.path(&found[0]), vec!["T7", "T22", "T40", "T7"]);
+        assert!((found[0].return_bps() - 201.0).abs() < 1e-6);
+    }
+}
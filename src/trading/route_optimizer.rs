@@ -1,6 +1,11 @@
 //! Route Optimization Engine with JSON Data Integration
 //! Loads and manages optimized arbitrage routes from JSON configuration
+//!
+//! Also tracks per-venue execution quality (landing rate, realized slippage,
+//! latency) by priority-fee band, so that when several venues quote the same
+//! route at nearly the same price the one that actually fills better wins.
 
+use tracing::info;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -99,6 +104,176 @@ pub struct OptimizationSettings {
     pub sentiment_based_routing: bool,
 }
 
+/// Priority fee bands venue statistics are kept per (micro-lamports per CU)
+const FEE_BAND_UPPER_BOUNDS: [u64; 4] = [1_000, 10_000, 100_000, 1_000_000];
+
+/// Fee band index for a priority fee (micro-lamports per compute unit)
+pub fn fee_band(priority_fee_micro_lamports: u64) -> u8 {
+    FEE_BAND_UPPER_BOUNDS
+        .iter()
+        .position(|upper| priority_fee_micro_lamports < *upper)
+        .unwrap_or(FEE_BAND_UPPER_BOUNDS.len()) as u8
+}
+
+/// Result of one submitted swap on a venue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueOutcome {
+    pub venue: String,
+    pub priority_fee_micro_lamports: u64,
+    pub landed: bool,
+    /// Realized vs quoted output, in bps (positive = worse than quoted); ignored when not landed
+    pub realized_slippage_bps: f64,
+    pub latency_ms: u64,
+}
+
+/// Execution statistics of one venue in one fee band
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VenueStats {
+    pub attempts: u64,
+    pub landed: u64,
+    /// Exponentially weighted realized slippage of landed swaps (bps)
+    pub avg_slippage_bps: f64,
+    pub avg_latency_ms: f64,
+}
+
+impl VenueStats {
+    fn record(&mut self, outcome: &VenueOutcome, alpha: f64) {
+        self.attempts += 1;
+        let ewma = |avg: f64, value: f64, first: bool| if first { value } else { avg + alpha * (value - avg) };
+        self.avg_latency_ms = ewma(self.avg_latency_ms, outcome.latency_ms as f64, self.attempts == 1);
+        if outcome.landed {
+            self.landed += 1;
+            self.avg_slippage_bps = ewma(self.avg_slippage_bps, outcome.realized_slippage_bps, self.landed == 1);
+        }
+    }
+}
+
+/// When quotes count as identical and how much history to trust
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueSelectionPolicy {
+    /// Quotes within this distance of the best are treated as the same route (bps)
+    pub price_tolerance_bps: f64,
+    /// Attempts before a venue's stats replace the prior
+    pub min_samples: u64,
+    /// Landing rate assumed for venues without enough history
+    pub prior_landing_rate: f64,
+    /// Weight of the newest sample in slippage/latency averages
+    pub ewma_alpha: f64,
+}
+
+impl Default for VenueSelectionPolicy {
+    fn default() -> Self {
+        Self {
+            price_tolerance_bps: 10.0,
+            min_samples: 5,
+            prior_landing_rate: 0.8,
+            ewma_alpha: 0.2,
+        }
+    }
+}
+
+/// One venue's quote for a route
+#[derive(Debug, Clone)]
+pub struct VenueQuote {
+    pub venue: String,
+    /// Quoted output amount
+    pub expected_out: f64,
+}
+
+/// Chosen venue and why
+#[derive(Debug, Clone)]
+pub struct VenueSelection {
+    pub venue: String,
+    pub quoted_out: f64,
+    /// Quoted output discounted by landing rate and realized slippage
+    pub expected_out: f64,
+    pub landing_rate: f64,
+    pub avg_slippage_bps: f64,
+    /// Other venues that were within the price tolerance
+    pub alternatives: Vec<String>,
+}
+
+/// Venue statistics keyed by venue and fee band
+#[derive(Debug, Clone, Default)]
+pub struct VenueSelector {
+    policy: VenueSelectionPolicy,
+    stats: HashMap<(String, u8), VenueStats>,
+}
+
+impl VenueSelector {
+    pub fn new(policy: VenueSelectionPolicy) -> Self {
+        Self { policy, stats: HashMap::new() }
+    }
+
+    pub fn record(&mut self, outcome: &VenueOutcome) {
+        let key = (outcome.venue.to_lowercase(), fee_band(outcome.priority_fee_micro_lamports));
+        self.stats.entry(key).or_default().record(outcome, self.policy.ewma_alpha);
+    }
+
+    pub fn stats(&self, venue: &str, priority_fee_micro_lamports: u64) -> Option<&VenueStats> {
+        self.stats.get(&(venue.to_lowercase(), fee_band(priority_fee_micro_lamports)))
+    }
+
+    /// Landing rate and slippage, blended with the prior until `min_samples`
+    fn expected_quality(&self, venue: &str, priority_fee_micro_lamports: u64) -> (f64, f64) {
+        let prior = self.policy.prior_landing_rate;
+        match self.stats(venue, priority_fee_micro_lamports) {
+            Some(stats) if stats.attempts > 0 => {
+                let trust = (stats.attempts as f64 / self.policy.min_samples.max(1) as f64).min(1.0);
+                let observed = stats.landed as f64 / stats.attempts as f64;
+                (prior + trust * (observed - prior), trust * stats.avg_slippage_bps)
+            }
+            _ => (prior, 0.0),
+        }
+    }
+
+    /// Best venue among quotes within tolerance of the best price
+    ///
+    /// Quotes far from the best are ignored: price differences beyond the
+    /// tolerance are a different opportunity, not a venue tie-break.
+    pub fn select(&self, quotes: &[VenueQuote], priority_fee_micro_lamports: u64) -> Option<VenueSelection> {
+        let best_quote = quotes.iter().map(|q| q.expected_out).fold(f64::NEG_INFINITY, f64::max);
+        if !best_quote.is_finite() || best_quote <= 0.0 {
+            return None;
+        }
+        let floor = best_quote * (1.0 - self.policy.price_tolerance_bps / 10_000.0);
+        let tied: Vec<&VenueQuote> = quotes.iter().filter(|q| q.expected_out >= floor).collect();
+
+        let mut selection = tied.iter()
+            .map(|quote| {
+                let (landing_rate, avg_slippage_bps) = self.expected_quality(&quote.venue, priority_fee_micro_lamports);
+                VenueSelection {
+                    venue: quote.venue.clone(),
+                    quoted_out: quote.expected_out,
+                    expected_out: quote.expected_out * landing_rate * (1.0 - avg_slippage_bps / 10_000.0),
+                    landing_rate,
+                    avg_slippage_bps,
+                    alternatives: Vec::new(),
+                }
+            })
+            .max_by(|a, b| a.expected_out.total_cmp(&b.expected_out))?;
+        selection.alternatives = tied.iter()
+            .filter(|q| q.venue != selection.venue)
+            .map(|q| q.venue.clone())
+            .collect();
+        Some(selection)
+    }
+
+    /// Stats as `venue@band` rows, for state snapshots
+    pub fn export(&self) -> HashMap<String, VenueStats> {
+        self.stats.iter().map(|((venue, band), stats)| (format!("{}@{}", venue, band), stats.clone())).collect()
+    }
+
+    pub fn restore(&mut self, rows: HashMap<String, VenueStats>) {
+        self.stats = rows.into_iter()
+            .filter_map(|(key, stats)| {
+                let (venue, band) = key.rsplit_once('@')?;
+                Some(((venue.to_string(), band.parse().ok()?), stats))
+            })
+            .collect();
+    }
+}
+
 /// Route optimization engine with intelligent selection
 #[derive(Debug, Clone)]
 pub struct RouteOptimizationEngine {
@@ -108,6 +283,7 @@ pub struct RouteOptimizationEngine {
     #[allow(dead_code)] // ✅ Enterprise feature - used in advanced scenarios  
     active_routes: Vec<OptimizedRoute>,
     performance_cache: HashMap<String, f64>,
+    venue_selector: VenueSelector,
     #[allow(dead_code)] // ✅ Enterprise feature - used in advanced scenarios
     last_update: DateTime<Utc>,
 }
@@ -123,6 +299,7 @@ impl RouteOptimizationEngine {
             current_market_condition: "normal".to_string(),
             active_routes: Vec::new(),
             performance_cache: HashMap::new(),
+            venue_selector: VenueSelector::default(),
             last_update: Utc::now(),
         })
     }
@@ -236,6 +413,16 @@ impl RouteOptimizationEngine {
             .collect()
     }
 
+    /// Route performance cache, for state snapshots
+    pub fn export_performance_cache(&self) -> HashMap<String, f64> {
+        self.performance_cache.clone()
+    }
+
+    /// Restore the route performance cache from a state snapshot
+    pub fn restore_performance_cache(&mut self, cache: HashMap<String, f64>) {
+        self.performance_cache = cache;
+    }
+
     /// Update route performance with real trading results
     pub fn update_route_performance(&mut self, route_signature: &str, actual_profit: f64, success: bool) {
         self.performance_cache.insert(route_signature.to_string(), actual_profit);
@@ -243,10 +430,33 @@ impl RouteOptimizationEngine {
         // Update last profitable timestamp for successful routes
         if success {
             // This would update the JSON file in a real implementation
-            println!("✅ Route {} updated with profit: ${:.2}", route_signature, actual_profit);
+            info!("✅ Route {} updated with profit: ${:.2}", route_signature, actual_profit);
         }
     }
 
+    /// Record how a swap on a venue actually executed
+    pub fn record_venue_outcome(&mut self, outcome: &VenueOutcome) {
+        self.venue_selector.record(outcome);
+    }
+
+    /// Pick between venues quoting the same route, by landing rate and realized slippage
+    pub fn select_venue(&self, quotes: &[VenueQuote], priority_fee_micro_lamports: u64) -> Option<VenueSelection> {
+        self.venue_selector.select(quotes, priority_fee_micro_lamports)
+    }
+
+    pub fn set_venue_policy(&mut self, policy: VenueSelectionPolicy) {
+        self.venue_selector.policy = policy;
+    }
+
+    /// Venue statistics, for state snapshots
+    pub fn export_venue_stats(&self) -> HashMap<String, VenueStats> {
+        self.venue_selector.export()
+    }
+
+    pub fn restore_venue_stats(&mut self, rows: HashMap<String, VenueStats>) {
+        self.venue_selector.restore(rows);
+    }
+
     /// Get route recommendation based on current portfolio and market
     pub fn recommend_route(&self, available_capital: f64, risk_tolerance: f64, market_sentiment: f64) -> Option<OptimizedRoute> {
         let routes = self.get_sentiment_optimized_routes(market_sentiment);
@@ -271,27 +481,27 @@ impl RouteOptimizationEngine {
 
     /// Display route analytics dashboard
     pub fn display_route_analytics(&self) {
-        println!("\n╔══════════════════════════════════════════════════════════════════╗");
-        println!("║                    ROUTE OPTIMIZATION ANALYTICS                 ║");
-        println!("╠══════════════════════════════════════════════════════════════════╣");
-        println!("║ Total Routes: {}                                                 ║", self.routes.performance_metrics.total_routes);
-        println!("║ Avg Success Rate: {:.1}%                                        ║", self.routes.performance_metrics.avg_success_rate * 100.0);
-        println!("║ Total Profit (24h): ${:.2}                                      ║", self.routes.performance_metrics.total_profit_24h);
-        println!("║ Route Types: {} categories                                      ║", self.routes.solana_arbitrage_routes.dex_specific_routes.len() + 3);
-        println!("╠══════════════════════════════════════════════════════════════════╣");
+        info!("╔══════════════════════════════════════════════════════════════════╗");
+        info!("║                    ROUTE OPTIMIZATION ANALYTICS                 ║");
+        info!("╠══════════════════════════════════════════════════════════════════╣");
+        info!("║ Total Routes: {}                                                 ║", self.routes.performance_metrics.total_routes);
+        info!("║ Avg Success Rate: {:.1}%                                        ║", self.routes.performance_metrics.avg_success_rate * 100.0);
+        info!("║ Total Profit (24h): ${:.2}                                      ║", self.routes.performance_metrics.total_profit_24h);
+        info!("║ Route Types: {} categories                                      ║", self.routes.solana_arbitrage_routes.dex_specific_routes.len() + 3);
+        info!("╠══════════════════════════════════════════════════════════════════╣");
         
         // Route category breakdown
-        println!("║ 🎯 High Liquidity: {} routes                                    ║", self.routes.solana_arbitrage_routes.high_liquidity_routes.len());
-        println!("║ 💰 Stablecoin: {} routes                                        ║", self.routes.solana_arbitrage_routes.stablecoin_routes.len());
-        println!("║ ⚡ Flash Loan: {} routes                                        ║", self.routes.solana_arbitrage_routes.flash_loan_routes.len());
-        println!("║ 🌐 Cross-Chain: {} routes                                      ║", self.routes.cross_chain_routes.len());
+        info!("║ 🎯 High Liquidity: {} routes                                    ║", self.routes.solana_arbitrage_routes.high_liquidity_routes.len());
+        info!("║ 💰 Stablecoin: {} routes                                        ║", self.routes.solana_arbitrage_routes.stablecoin_routes.len());
+        info!("║ ⚡ Flash Loan: {} routes                                        ║", self.routes.solana_arbitrage_routes.flash_loan_routes.len());
+        info!("║ 🌐 Cross-Chain: {} routes                                      ║", self.routes.cross_chain_routes.len());
         
-        println!("╠══════════════════════════════════════════════════════════════════╣");
-        println!("║ 🚀 Optimization: ML={}, Sentiment={}, Auto-Discovery={}         ║", 
+        info!("╠══════════════════════════════════════════════════════════════════╣");
+        info!("║ 🚀 Optimization: ML={}, Sentiment={}, Auto-Discovery={}         ║", 
                  if self.routes.optimization_settings.ml_route_optimization { "✅" } else { "❌" },
                  if self.routes.optimization_settings.sentiment_based_routing { "✅" } else { "❌" },
                  if self.routes.optimization_settings.auto_route_discovery { "✅" } else { "❌" });
-        println!("╚══════════════════════════════════════════════════════════════════╝");
+        info!("╚══════════════════════════════════════════════════════════════════╝");
     }
 
     /// Get optimization settings
@@ -349,8 +559,64 @@ impl Default for RouteOptimizationEngine {
                     current_market_condition: "normal".to_string(),
                     active_routes: Vec::new(),
                     performance_cache: HashMap::new(),
+                    venue_selector: VenueSelector::default(),
                     last_update: Utc::now(),
                 }
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(venue: &str, landed: bool, slippage_bps: f64) -> VenueOutcome {
+        VenueOutcome {
+            venue: venue.to_string(),
+            priority_fee_micro_lamports: 5_000,
+            landed,
+            realized_slippage_bps: slippage_bps,
+            latency_ms: 400,
+        }
+    }
+
+    fn quotes(orca: f64, raydium: f64) -> Vec<VenueQuote> {
+        vec![
+            VenueQuote { venue: "Orca".to_string(), expected_out: orca },
+            VenueQuote { venue: "Raydium".to_string(), expected_out: raydium },
+        ]
+    }
+
+    #[test]
+    fn test_landing_rate_breaks_ties_between_similar_quotes() {
+        let mut selector = VenueSelector::default();
+        for i in 0..10 {
+            selector.record(&outcome("Orca", true, 3.0));
+            selector.record(&outcome("Raydium", i % 2 == 0, 3.0));
+        }
+        // Raydium quotes 5 bps better, but lands half the time
+        let selection = selector.select(&quotes(1_000.0, 1_000.5), 5_000).unwrap();
+        assert_eq!(selection.venue, "Orca");
+        assert_eq!(selection.alternatives, vec!["Raydium".to_string()]);
+
+        // Stats are per fee band: at a much higher fee there is no history, best quote wins
+        assert_eq!(selector.select(&quotes(1_000.0, 1_000.5), 2_000_000).unwrap().venue, "Raydium");
+    }
+
+    #[test]
+    fn test_quotes_outside_tolerance_are_not_tie_broken() {
+        let mut selector = VenueSelector::default();
+        for _ in 0..10 {
+            selector.record(&outcome("Orca", true, 0.0));
+            selector.record(&outcome("Raydium", true, 40.0));
+        }
+        // 1% better price is a different opportunity, not a tie
+        let selection = selector.select(&quotes(1_000.0, 1_010.0), 5_000).unwrap();
+        assert_eq!(selection.venue, "Raydium");
+        assert!(selection.alternatives.is_empty());
+
+        let mut restored = VenueSelector::default();
+        restored.restore(selector.export());
+        assert_eq!(restored.stats("raydium", 5_000).unwrap().attempts, 10);
+    }
+}
@@ -1,6 +1,7 @@
 //! Routing System Validator
 //! Enterprise-grade validation system for strategic and real-time routing architecture
 
+use tracing::info;
 use anyhow::Result;
 use tokio::time::{sleep, Duration};
 use chrono::Utc;
@@ -86,8 +87,8 @@ impl UnifiedRoutingDemo {
 
     /// Run complete demo showcasing all features
     pub async fn run_complete_demo(&mut self) -> Result<()> {
-        println!("\n🚀 STARTING UNIFIED ROUTING SYSTEM DEMO");
-        println!("═══════════════════════════════════════════════════════════════════");
+        info!("🚀 STARTING UNIFIED ROUTING SYSTEM DEMO");
+        info!("═══════════════════════════════════════════════════════════════════");
         
         // Display initial dashboard
         self.routing_system.display_unified_dashboard().await;
@@ -95,8 +96,8 @@ impl UnifiedRoutingDemo {
         
         // Run all demo scenarios
         for (i, scenario) in self.demo_scenarios.clone().into_iter().enumerate() {
-            println!("\n📋 SCENARIO {} of {}: {}", i + 1, self.demo_scenarios.len(), scenario.name);
-            println!("─────────────────────────────────────────────────────────────────");
+            info!("📋 SCENARIO {} of {}: {}", i + 1, self.demo_scenarios.len(), scenario.name);
+            info!("─────────────────────────────────────────────────────────────────");
             
             self.run_scenario(&scenario).await?;
             
@@ -112,27 +113,27 @@ impl UnifiedRoutingDemo {
 
     /// Run individual demo scenario
     async fn run_scenario(&mut self, scenario: &DemoScenario) -> Result<()> {
-        println!("🔍 Scenario Parameters:");
-        println!("   Market Sentiment: {:.1} ({})", 
+        info!("🔍 Scenario Parameters:");
+        info!("   Market Sentiment: {:.1} ({})", 
                  scenario.market_sentiment,
                  if scenario.market_sentiment > 0.2 { "Bullish 📈" }
                  else if scenario.market_sentiment < -0.2 { "Bearish 📉" }
                  else { "Neutral ➡️" });
-        println!("   Risk Tolerance: {:.1}% ({})", 
+        info!("   Risk Tolerance: {:.1}% ({})", 
                  scenario.risk_tolerance * 100.0,
                  if scenario.risk_tolerance > 0.6 { "High Risk 🎯" }
                  else if scenario.risk_tolerance < 0.3 { "Low Risk 🛡️" }
                  else { "Moderate Risk ⚖️" });
-        println!("   Execution Urgency: {:.1}% ({})", 
+        info!("   Execution Urgency: {:.1}% ({})", 
                  scenario.execution_urgency * 100.0,
                  if scenario.execution_urgency > 0.7 { "Urgent ⚡" }
                  else if scenario.execution_urgency < 0.3 { "Patient 🕐" }
                  else { "Normal ⏱️" });
-        println!("   Available Capital: ${:.2}", scenario.available_capital);
-        println!("   Expected: {}", scenario.expected_outcome);
+        info!("   Available Capital: ${:.2}", scenario.available_capital);
+        info!("   Expected: {}", scenario.expected_outcome);
         
         // Get optimal route
-        println!("\n🔄 Analyzing routes...");
+        info!("🔄 Analyzing routes...");
         let decision = self.routing_system.get_optimal_route(
             scenario.market_sentiment,
             scenario.available_capital,
@@ -144,7 +145,7 @@ impl UnifiedRoutingDemo {
             self.display_decision(&decision);
             
             // Simulate execution
-            println!("\n⚡ Executing route...");
+            info!("⚡ Executing route...");
             let execution_result = self.routing_system.execute_route(&decision).await?;
             self.display_execution_result(&execution_result, &decision);
             
@@ -154,11 +155,11 @@ impl UnifiedRoutingDemo {
             }
             
         } else {
-            println!("❌ No suitable routes found for current conditions");
+            info!("❌ No suitable routes found for current conditions");
         }
         
         // Display updated dashboard
-        println!("\n📊 Updated System Status:");
+        info!("📊 Updated System Status:");
         self.routing_system.display_unified_dashboard().await;
         
         Ok(())
@@ -166,55 +167,55 @@ impl UnifiedRoutingDemo {
 
     /// Display routing decision details
     fn display_decision(&self, decision: &RoutingDecision) {
-        println!("\n✅ OPTIMAL ROUTE SELECTED:");
-        println!("   Route: {}", decision.selected_route.strategic_route.route.join(" → "));
-        println!("   Reason: {}", decision.reason);
-        println!("   Risk Assessment: {:.1}%", (1.0 - decision.risk_assessment) * 100.0);
-        println!("   Profit Estimate: ${:.2}", decision.profit_estimate);
-        println!("   Execution Window: {}s", decision.execution_window_seconds);
+        info!("✅ OPTIMAL ROUTE SELECTED:");
+        info!("   Route: {}", decision.selected_route.strategic_route.route.join(" → "));
+        info!("   Reason: {}", decision.reason);
+        info!("   Risk Assessment: {:.1}%", (1.0 - decision.risk_assessment) * 100.0);
+        info!("   Profit Estimate: ${:.2}", decision.profit_estimate);
+        info!("   Execution Window: {}s", decision.execution_window_seconds);
         
         // Strategic route details
         let strategic = &decision.selected_route.strategic_route;
-        println!("   Strategic Data:");
-        println!("     └─ Avg Profit: {}bps", strategic.avg_profit_bps);
-        println!("     └─ Success Rate: {:.1}%", strategic.success_rate * 100.0);
-        println!("     └─ Market Condition: {}", strategic.market_condition);
+        info!("   Strategic Data:");
+        info!("     └─ Avg Profit: {}bps", strategic.avg_profit_bps);
+        info!("     └─ Success Rate: {:.1}%", strategic.success_rate * 100.0);
+        info!("     └─ Market Condition: {}", strategic.market_condition);
         
         // Real-time data if available
         if let Some(realtime) = &decision.selected_route.realtime_data {
-            println!("   Real-time Data:");
-            println!("     └─ Execution Probability: {:.1}%", realtime.execution_probability * 100.0);
-            println!("     └─ Latency: {}ms", realtime.latency_ms);
-            println!("     └─ Price Impact: {:.3}%", realtime.price_impact * 100.0);
-            println!("     └─ Profitability Score: {:.3}", realtime.profitability_score);
+            info!("   Real-time Data:");
+            info!("     └─ Execution Probability: {:.1}%", realtime.execution_probability * 100.0);
+            info!("     └─ Latency: {}ms", realtime.latency_ms);
+            info!("     └─ Price Impact: {:.3}%", realtime.price_impact * 100.0);
+            info!("     └─ Profitability Score: {:.3}", realtime.profitability_score);
         } else {
-            println!("   Real-time Data: ❌ Not available (strategic-only execution)");
+            info!("   Real-time Data: ❌ Not available (strategic-only execution)");
         }
     }
 
     /// Display execution result
     fn display_execution_result(&self, result: &ExecutionResult, decision: &RoutingDecision) {
-        println!("\n🎯 EXECUTION RESULT:");
+        info!("🎯 EXECUTION RESULT:");
         if result.success {
-            println!("   Status: ✅ SUCCESS");
-            println!("   Actual Profit: ${:.2}", result.actual_profit);
-            println!("   vs Estimated: ${:.2} ({})", 
+            info!("   Status: ✅ SUCCESS");
+            info!("   Actual Profit: ${:.2}", result.actual_profit);
+            info!("   vs Estimated: ${:.2} ({})", 
                      decision.profit_estimate,
                      if result.actual_profit >= decision.profit_estimate { "✅ Met/Exceeded" } else { "⚠️ Below estimate" });
         } else {
-            println!("   Status: ❌ FAILED");
-            println!("   Actual Profit: $0.00");
-            println!("   Loss: Market conditions changed during execution");
+            info!("   Status: ❌ FAILED");
+            info!("   Actual Profit: $0.00");
+            info!("   Loss: Market conditions changed during execution");
         }
-        println!("   Execution Time: {:.1}ms", result.execution_time_ms);
-        println!("   Route ID: {}", result.route_id);
-        println!("   New Balance: ${:.2}", self.current_capital);
+        info!("   Execution Time: {:.1}ms", result.execution_time_ms);
+        info!("   Route ID: {}", result.route_id);
+        info!("   New Balance: ${:.2}", self.current_capital);
     }
 
     /// Display final demo summary
     async fn display_demo_summary(&self) {
-        println!("\n🏁 DEMO COMPLETED - FINAL SUMMARY");
-        println!("═══════════════════════════════════════════════════════════════════");
+        info!("🏁 DEMO COMPLETED - FINAL SUMMARY");
+        info!("═══════════════════════════════════════════════════════════════════");
         
         let stats = self.routing_system.get_performance_stats();
         let total_executions = stats.successful_executions + stats.failed_executions;
@@ -224,43 +225,43 @@ impl UnifiedRoutingDemo {
             0.0
         };
         
-        println!("📈 PERFORMANCE METRICS:");
-        println!("   Total Executions: {}", total_executions);
-        println!("   Success Rate: {:.1}%", success_rate);
-        println!("   Total Profit: ${:.2}", stats.total_profit);
-        println!("   Avg Execution Time: {:.1}ms", stats.avg_execution_time_ms);
-        println!("   Starting Capital: $1000.00");
-        println!("   Final Capital: ${:.2}", self.current_capital);
-        println!("   Net Gain: ${:.2} ({:.1}%)", 
+        info!("📈 PERFORMANCE METRICS:");
+        info!("   Total Executions: {}", total_executions);
+        info!("   Success Rate: {:.1}%", success_rate);
+        info!("   Total Profit: ${:.2}", stats.total_profit);
+        info!("   Avg Execution Time: {:.1}ms", stats.avg_execution_time_ms);
+        info!("   Starting Capital: $1000.00");
+        info!("   Final Capital: ${:.2}", self.current_capital);
+        info!("   Net Gain: ${:.2} ({:.1}%)", 
                  self.current_capital - 1000.0,
                  ((self.current_capital - 1000.0) / 1000.0) * 100.0);
         
-        println!("\n🎯 SYSTEM CAPABILITIES DEMONSTRATED:");
-        println!("   ✅ Strategic route optimization with historical data");
-        println!("   ✅ Real-time market condition monitoring");
-        println!("   ✅ Unified decision-making combining both approaches");
-        println!("   ✅ Dynamic risk assessment and position sizing");
-        println!("   ✅ Multi-scenario adaptation (bull/bear/neutral markets)");
-        println!("   ✅ Performance tracking and optimization");
-        println!("   ✅ Twitter sentiment integration capabilities");
-        println!("   ✅ Sub-second execution with low-latency monitoring");
+        info!("🎯 SYSTEM CAPABILITIES DEMONSTRATED:");
+        info!("   ✅ Strategic route optimization with historical data");
+        info!("   ✅ Real-time market condition monitoring");
+        info!("   ✅ Unified decision-making combining both approaches");
+        info!("   ✅ Dynamic risk assessment and position sizing");
+        info!("   ✅ Multi-scenario adaptation (bull/bear/neutral markets)");
+        info!("   ✅ Performance tracking and optimization");
+        info!("   ✅ Twitter sentiment integration capabilities");
+        info!("   ✅ Sub-second execution with low-latency monitoring");
         
-        println!("\n💡 KEY ARCHITECTURAL BENEFITS:");
-        println!("   🚀 5-10x faster route selection vs single-file approach");
-        println!("   📊 15-25% higher profitability through dual optimization");
-        println!("   ⚡ Sub-500ms decision making with real-time data");
-        println!("   🎯 10x more opportunities through continuous monitoring");
-        println!("   🛡️ Enhanced risk management with sentiment analysis");
-        println!("   📈 Adaptive strategy based on market conditions");
+        info!("💡 KEY ARCHITECTURAL BENEFITS:");
+        info!("   🚀 5-10x faster route selection vs single-file approach");
+        info!("   📊 15-25% higher profitability through dual optimization");
+        info!("   ⚡ Sub-500ms decision making with real-time data");
+        info!("   🎯 10x more opportunities through continuous monitoring");
+        info!("   🛡️ Enhanced risk management with sentiment analysis");
+        info!("   📈 Adaptive strategy based on market conditions");
         
-        println!("\n🚀 READY FOR PRODUCTION DEPLOYMENT!");
-        println!("═══════════════════════════════════════════════════════════════════");
+        info!("🚀 READY FOR PRODUCTION DEPLOYMENT!");
+        info!("═══════════════════════════════════════════════════════════════════");
     }
 
     /// Quick demo for testing
     pub async fn run_quick_demo(&mut self) -> Result<()> {
-        println!("\n⚡ QUICK UNIFIED ROUTING DEMO");
-        println!("─────────────────────────────────────");
+        info!("⚡ QUICK UNIFIED ROUTING DEMO");
+        info!("─────────────────────────────────────");
         
         // Just run one optimal scenario
         let scenario = &self.demo_scenarios[0]; // Bull market aggressive
@@ -273,16 +274,16 @@ impl UnifiedRoutingDemo {
         ).await?;
         
         if let Some(decision) = decision {
-            println!("✅ Route: {}", decision.selected_route.strategic_route.route.join(" → "));
-            println!("💰 Estimated Profit: ${:.2}", decision.profit_estimate);
-            println!("⏱️ Execution Window: {}s", decision.execution_window_seconds);
+            info!("✅ Route: {}", decision.selected_route.strategic_route.route.join(" → "));
+            info!("💰 Estimated Profit: ${:.2}", decision.profit_estimate);
+            info!("⏱️ Execution Window: {}s", decision.execution_window_seconds);
             
             let result = self.routing_system.execute_route(&decision).await?;
-            println!("🎯 Result: {} (${:.2})", 
+            info!("🎯 Result: {} (${:.2})", 
                      if result.success { "SUCCESS" } else { "FAILED" },
                      result.actual_profit);
         } else {
-            println!("❌ No routes available");
+            info!("❌ No routes available");
         }
         
         Ok(())
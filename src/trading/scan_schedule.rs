@@ -0,0 +1,230 @@
+//! Per-strategy scan scheduling
+//!
+//! Strategies live on very different clocks: a sniper reacts to new pools in
+//! well under a second, cross-chain spreads move on bridge timescales. Each
+//! strategy gets its own schedule instead of one shared sleep:
+//!
+//! - a base interval (still scaled by seasonality in the service loop)
+//! - random jitter, so loops started together do not hit the RPCs in lockstep
+//! - optional alignment to a feed: after a minimum gap the scan runs as soon
+//!   as the feed publishes fresh data, or at the full interval if it does not
+//!
+//! The service's trading cycle is scheduled the same way, aligned to the
+//! engines publishing fresh opportunities on [`ENGINE_FINDINGS_FEED`].
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+
+/// Feed the engines publish on after storing new opportunities
+pub const ENGINE_FINDINGS_FEED: &str = "engine_findings";
+
+/// Cadence of one strategy or feed loop
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategySchedule {
+    pub interval_ms: u64,
+    /// Random spread as a fraction of the interval (0.1 = ±10%)
+    pub jitter: f64,
+    /// Feed whose updates trigger the scan early
+    pub align_to: Option<String>,
+    /// Fraction of the interval that must pass before a feed update may trigger a scan
+    pub min_gap: f64,
+}
+
+impl StrategySchedule {
+    pub fn every(interval: Duration) -> Self {
+        Self {
+            interval_ms: interval.as_millis() as u64,
+            jitter: 0.0,
+            align_to: None,
+            min_gap: 0.5,
+        }
+    }
+
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn aligned_to(mut self, feed: &str) -> Self {
+        self.align_to = Some(feed.to_string());
+        self
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_millis(self.interval_ms.max(1))
+    }
+}
+
+/// Schedules by loop name
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanScheduleConfig {
+    pub schedules: HashMap<String, StrategySchedule>,
+    /// Used for loops without an explicit entry
+    pub default: StrategySchedule,
+}
+
+impl Default for ScanScheduleConfig {
+    fn default() -> Self {
+        let feed = StrategySchedule::every(Duration::from_secs(8)).with_jitter(0.05);
+        Self {
+            schedules: HashMap::from([
+                ("fiat_rate_feed".to_string(), feed.clone()),
+                ("stablecoin_feed".to_string(), feed),
+                ("arbitrage_engine".to_string(), StrategySchedule::every(Duration::from_secs(4)).with_jitter(0.1).aligned_to("fiat_rate_feed")),
+                ("triangular_engine".to_string(), StrategySchedule::every(Duration::from_secs(6)).with_jitter(0.1).aligned_to("fiat_rate_feed")),
+                ("flash_loan_engine".to_string(), StrategySchedule::every(Duration::from_secs(8)).with_jitter(0.1).aligned_to("fiat_rate_feed")),
+                ("cross_chain_engine".to_string(), StrategySchedule::every(Duration::from_secs(60)).with_jitter(0.1)),
+                ("trading_cycle".to_string(), StrategySchedule::every(Duration::from_secs(8)).aligned_to(ENGINE_FINDINGS_FEED)),
+            ]),
+            default: StrategySchedule::every(Duration::from_secs(8)).with_jitter(0.1),
+        }
+    }
+}
+
+impl ScanScheduleConfig {
+    pub fn schedule(&self, name: &str) -> &StrategySchedule {
+        self.schedules.get(name).unwrap_or(&self.default)
+    }
+}
+
+/// Update signals published by feeds
+#[derive(Debug, Default)]
+pub struct FeedEvents {
+    feeds: Mutex<HashMap<String, Arc<Notify>>>,
+}
+
+impl FeedEvents {
+    fn channel(&self, feed: &str) -> Arc<Notify> {
+        self.feeds.lock().entry(feed.to_string()).or_default().clone()
+    }
+
+    /// Wake every scheduler waiting on `feed`
+    pub fn publish(&self, feed: &str) {
+        self.channel(feed).notify_waiters();
+    }
+}
+
+/// What ended a wait
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanTrigger {
+    Interval,
+    FeedUpdate,
+}
+
+/// Timer for one strategy loop
+#[derive(Debug)]
+pub struct ScanScheduler {
+    schedule: StrategySchedule,
+    feed: Option<Arc<Notify>>,
+}
+
+impl ScanScheduler {
+    pub fn new(schedule: StrategySchedule, events: &FeedEvents) -> Self {
+        let feed = schedule.align_to.as_deref().map(|feed| events.channel(feed));
+        Self { schedule, feed }
+    }
+
+    pub fn base_interval(&self) -> Duration {
+        self.schedule.interval()
+    }
+
+    /// `interval` spread by the schedule's jitter
+    pub fn jittered(&self, interval: Duration) -> Duration {
+        let spread = self.schedule.jitter.clamp(0.0, 1.0);
+        interval.mul_f64(1.0 + spread * (fastrand::f64() * 2.0 - 1.0))
+    }
+
+    /// Sleep until the next scan is due
+    ///
+    /// `interval` is the base interval after any external scaling (seasonality).
+    pub async fn wait(&self, interval: Duration) -> ScanTrigger {
+        let delay = self.jittered(interval);
+        let Some(feed) = &self.feed else {
+            tokio::time::sleep(delay).await;
+            return ScanTrigger::Interval;
+        };
+        let min_gap = delay.mul_f64(self.schedule.min_gap.clamp(0.0, 1.0));
+        tokio::time::sleep(min_gap).await;
+        tokio::select! {
+            _ = feed.notified() => ScanTrigger::FeedUpdate,
+            _ = tokio::time::sleep(delay.saturating_sub(min_gap)) => ScanTrigger::Interval,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jitter_stays_within_spread() {
+        let config = ScanScheduleConfig::default();
+        assert_eq!(config.schedule("cross_chain_engine").interval(), Duration::from_secs(60));
+        assert_eq!(config.schedule("unknown").interval(), Duration::from_secs(8));
+
+        let scheduler = ScanScheduler::new(StrategySchedule::every(Duration::from_secs(10)).with_jitter(0.2), &FeedEvents::default());
+        for _ in 0..100 {
+            let delay = scheduler.jittered(scheduler.base_interval());
+            assert!(delay >= Duration::from_secs(8) && delay <= Duration::from_secs(12));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_feed_update_triggers_aligned_scan_early() {
+        let events = Arc::new(FeedEvents::default());
+        let mut schedule = StrategySchedule::every(Duration::from_secs(30)).aligned_to("prices");
+        schedule.min_gap = 0.0;
+        let scheduler = ScanScheduler::new(schedule, &events);
+
+        let publisher = events.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            publisher.publish("prices");
+        });
+        let trigger = tokio::time::timeout(Duration::from_secs(5), scheduler.wait(scheduler.base_interval())).await;
+        assert_eq!(trigger.unwrap(), ScanTrigger::FeedUpdate);
+    }
+
+    #[tokio::test]
+    async fn test_supervised_engine_finding_wakes_cycle_into_admission() {
+        use crate::monitoring::{ComponentContext, ComponentSpec, Supervisor};
+        use crate::trading::opportunity_dedup::{DedupCandidate, OpportunityDeduplicator, OpportunitySource, RouteSignature};
+        use crate::types::ArbitrageOpportunity;
+
+        let events = Arc::new(FeedEvents::default());
+        let findings = Arc::new(Mutex::new(Vec::<ArbitrageOpportunity>::new()));
+        // The service cycle's schedule, stretched so only a finding can wake it in time
+        let mut schedule = ScanScheduleConfig::default().schedule("trading_cycle").clone();
+        schedule.interval_ms = 60_000;
+        schedule.min_gap = 0.0;
+        let cycle = ScanScheduler::new(schedule, &events);
+
+        let mut supervisor = Supervisor::new(Duration::from_secs(1));
+        let (engine_findings, engine_events) = (findings.clone(), events.clone());
+        supervisor.add(ComponentSpec::new("arbitrage_engine", move |ctx: ComponentContext| {
+            let (findings, events) = (engine_findings.clone(), engine_events.clone());
+            async move {
+                ctx.mark_ready();
+                loop {
+                    *findings.lock() = vec![ArbitrageOpportunity::default()];
+                    events.publish(ENGINE_FINDINGS_FEED);
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                }
+            }
+        }));
+        supervisor.start().await.unwrap();
+
+        let trigger = tokio::time::timeout(Duration::from_secs(5), cycle.wait(cycle.base_interval())).await;
+        assert_eq!(trigger.unwrap(), ScanTrigger::FeedUpdate);
+        let drained = std::mem::take(&mut *findings.lock());
+        let admission = OpportunityDeduplicator::default()
+            .admit_cycle(drained.iter().map(DedupCandidate::from_arbitrage).collect());
+        assert!(admission.admits(&RouteSignature::from_arbitrage(&drained[0]), OpportunitySource::EnhancedArbitrage));
+        supervisor.shutdown().await;
+    }
+}
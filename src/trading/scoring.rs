@@ -0,0 +1,396 @@
+//! Opportunity scoring pipeline
+//!
+//! A score is the weighted mean of independent scorer components (profit,
+//! liquidity, safety, sentiment, model prediction), each in [0, 1] and each
+//! carrying a one-line reason. When the success model has a calibrated
+//! probability, the profit scorer works on risk-adjusted expected profit
+//! (expected profit times that probability). Weights and the acceptance threshold come from
+//! a [`ScoringProfile`], configurable per strategy. Scorers without an input
+//! (no sentiment reading, no model) drop out and the remaining weights are
+//! renormalized, so a missing feed never silently counts as a zero.
+//!
+//! [`ScoreBreakdown::explain`] lists the components dragging the score down,
+//! which is what gets logged when an opportunity is rejected.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::types::Opportunity;
+
+/// Inputs scorers read; `None` means the feature is unavailable
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScoreFeatures {
+    /// Expected net profit, % of capital
+    pub expected_profit_pct: f64,
+    pub liquidity_usd: Option<f64>,
+    /// Risk in [0, 1] (1 = highest risk)
+    pub risk_score: Option<f64>,
+    /// Sentiment in [-1, 1]
+    pub sentiment: Option<f64>,
+    /// Predicted success probability (or detector confidence) in [0, 1]
+    pub model_probability: Option<f64>,
+    /// Calibrated probability from [`crate::ml::SuccessModel`]; scales expected profit
+    #[serde(default)]
+    pub success_probability: Option<f64>,
+}
+
+impl From<&Opportunity> for ScoreFeatures {
+    fn from(opportunity: &Opportunity) -> Self {
+        Self {
+            expected_profit_pct: opportunity.return_bps() / 100.0,
+            model_probability: Some(opportunity.confidence),
+            ..Default::default()
+        }
+    }
+}
+
+/// One scorer's verdict
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScorerOutput {
+    /// In [0, 1]
+    pub value: f64,
+    pub reason: String,
+}
+
+/// Independent score component
+pub trait Scorer: Send + Sync {
+    /// Key used for weights in [`ScoringProfile`]
+    fn name(&self) -> &'static str;
+
+    /// `None` when the scorer's input is unavailable
+    fn score(&self, features: &ScoreFeatures) -> Option<ScorerOutput>;
+}
+
+/// Linear in expected profit up to `target_profit_pct`
+#[derive(Debug, Clone)]
+pub struct ProfitScorer {
+    pub target_profit_pct: f64,
+}
+
+impl Scorer for ProfitScorer {
+    fn name(&self) -> &'static str {
+        "profit"
+    }
+
+    fn score(&self, features: &ScoreFeatures) -> Option<ScorerOutput> {
+        let (profit_pct, reason) = match features.success_probability {
+            Some(probability) => {
+                let probability = probability.clamp(0.0, 1.0);
+                let adjusted = features.expected_profit_pct * probability;
+                (adjusted, format!("expected profit {:.2}% x {:.0}% success = {:.2}% vs target {:.2}%",
+                    features.expected_profit_pct, probability * 100.0, adjusted, self.target_profit_pct))
+            }
+            None => (features.expected_profit_pct,
+                format!("expected profit {:.2}% vs target {:.2}%", features.expected_profit_pct, self.target_profit_pct)),
+        };
+        let value = (profit_pct / self.target_profit_pct.max(f64::EPSILON)).clamp(0.0, 1.0);
+        Some(ScorerOutput { value, reason })
+    }
+}
+
+/// Logarithmic between `min_usd` (0) and `full_usd` (1)
+#[derive(Debug, Clone)]
+pub struct LiquidityScorer {
+    pub min_usd: f64,
+    pub full_usd: f64,
+}
+
+impl Scorer for LiquidityScorer {
+    fn name(&self) -> &'static str {
+        "liquidity"
+    }
+
+    fn score(&self, features: &ScoreFeatures) -> Option<ScorerOutput> {
+        let liquidity = features.liquidity_usd?;
+        let value = if liquidity <= self.min_usd {
+            0.0
+        } else {
+            ((liquidity / self.min_usd).ln() / (self.full_usd / self.min_usd).ln()).clamp(0.0, 1.0)
+        };
+        Some(ScorerOutput { value, reason: format!("liquidity ${:.0} (full score at ${:.0})", liquidity, self.full_usd) })
+    }
+}
+
+/// Inverse of the risk score
+#[derive(Debug, Clone, Default)]
+pub struct SafetyScorer;
+
+impl Scorer for SafetyScorer {
+    fn name(&self) -> &'static str {
+        "safety"
+    }
+
+    fn score(&self, features: &ScoreFeatures) -> Option<ScorerOutput> {
+        let risk = features.risk_score?.clamp(0.0, 1.0);
+        Some(ScorerOutput { value: 1.0 - risk, reason: format!("risk score {:.2}", risk) })
+    }
+}
+
+/// Sentiment mapped from [-1, 1] to [0, 1]
+#[derive(Debug, Clone, Default)]
+pub struct SentimentScorer;
+
+impl Scorer for SentimentScorer {
+    fn name(&self) -> &'static str {
+        "sentiment"
+    }
+
+    fn score(&self, features: &ScoreFeatures) -> Option<ScorerOutput> {
+        let sentiment = features.sentiment?.clamp(-1.0, 1.0);
+        Some(ScorerOutput { value: (sentiment + 1.0) / 2.0, reason: format!("sentiment {:+.2}", sentiment) })
+    }
+}
+
+/// Model-predicted success probability, as is
+#[derive(Debug, Clone, Default)]
+pub struct ModelScorer;
+
+impl Scorer for ModelScorer {
+    fn name(&self) -> &'static str {
+        "model"
+    }
+
+    fn score(&self, features: &ScoreFeatures) -> Option<ScorerOutput> {
+        let probability = features.model_probability?.clamp(0.0, 1.0);
+        Some(ScorerOutput { value: probability, reason: format!("predicted success {:.0}%", probability * 100.0) })
+    }
+}
+
+/// Component weights and acceptance threshold for one strategy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoringProfile {
+    /// Scorer name -> weight; scorers not listed are ignored
+    pub weights: HashMap<String, f64>,
+    pub min_score: f64,
+}
+
+impl Default for ScoringProfile {
+    fn default() -> Self {
+        Self {
+            weights: HashMap::from([
+                ("profit".to_string(), 0.25),
+                ("liquidity".to_string(), 0.20),
+                ("safety".to_string(), 0.25),
+                ("sentiment".to_string(), 0.10),
+                ("model".to_string(), 0.20),
+            ]),
+            min_score: 0.65,
+        }
+    }
+}
+
+/// Default profile plus per-strategy overrides
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScoringConfig {
+    pub default_profile: ScoringProfile,
+    pub strategy_profiles: HashMap<String, ScoringProfile>,
+}
+
+impl ScoringConfig {
+    pub fn profile(&self, strategy: &str) -> &ScoringProfile {
+        self.strategy_profiles.get(strategy).unwrap_or(&self.default_profile)
+    }
+
+    pub fn with_strategy_profile(mut self, strategy: &str, profile: ScoringProfile) -> Self {
+        self.strategy_profiles.insert(strategy.to_string(), profile);
+        self
+    }
+}
+
+/// A scorer's weighted part in the final score
+#[derive(Debug, Clone)]
+pub struct ScoreComponent {
+    pub scorer: &'static str,
+    pub value: f64,
+    /// Weight after renormalization over available scorers
+    pub weight: f64,
+    pub reason: String,
+}
+
+impl ScoreComponent {
+    pub fn contribution(&self) -> f64 {
+        self.value * self.weight
+    }
+
+    /// Score lost relative to a perfect value for this component
+    pub fn shortfall(&self) -> f64 {
+        (1.0 - self.value) * self.weight
+    }
+}
+
+/// Final score with the components that produced it
+#[derive(Debug, Clone)]
+pub struct ScoreBreakdown {
+    pub strategy: String,
+    pub score: f64,
+    pub min_score: f64,
+    pub components: Vec<ScoreComponent>,
+    /// Weighted scorers that had no input
+    pub unavailable: Vec<&'static str>,
+}
+
+impl ScoreBreakdown {
+    pub fn passed(&self) -> bool {
+        !self.components.is_empty() && self.score >= self.min_score
+    }
+
+    /// Components ordered by how much score they cost, largest first
+    pub fn weakest(&self) -> Vec<&ScoreComponent> {
+        let mut components: Vec<&ScoreComponent> = self.components.iter().collect();
+        components.sort_by(|a, b| b.shortfall().total_cmp(&a.shortfall()));
+        components
+    }
+
+    /// Human-readable reason the score is where it is
+    pub fn explain(&self) -> String {
+        if self.components.is_empty() {
+            return "no scorer had input".to_string();
+        }
+        let mut parts: Vec<String> = self.weakest()
+            .into_iter()
+            .filter(|c| c.shortfall() > 0.0)
+            .take(3)
+            .map(|c| format!("{} {:.2} (-{:.2}: {})", c.scorer, c.value, c.shortfall(), c.reason))
+            .collect();
+        if !self.unavailable.is_empty() {
+            parts.push(format!("no input for {}", self.unavailable.join(", ")));
+        }
+        if parts.is_empty() {
+            parts.push("all components at maximum".to_string());
+        }
+        parts.join("; ")
+    }
+}
+
+impl fmt::Display for ScoreBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} score {:.2}/{:.2}: {}", self.strategy, self.score, self.min_score, self.explain())
+    }
+}
+
+/// Runs the registered scorers with the strategy's profile
+pub struct ScoringPipeline {
+    config: ScoringConfig,
+    scorers: Vec<Box<dyn Scorer>>,
+}
+
+impl ScoringPipeline {
+    /// Pipeline with no scorers; add them with [`ScoringPipeline::with_scorer`]
+    pub fn new(config: ScoringConfig) -> Self {
+        Self { config, scorers: Vec::new() }
+    }
+
+    /// Profit, liquidity, safety, sentiment and model scorers
+    pub fn standard(config: ScoringConfig, target_profit_pct: f64, min_liquidity_usd: f64) -> Self {
+        Self::new(config)
+            .with_scorer(ProfitScorer { target_profit_pct })
+            .with_scorer(LiquidityScorer { min_usd: min_liquidity_usd.max(1.0), full_usd: min_liquidity_usd.max(1.0) * 20.0 })
+            .with_scorer(SafetyScorer)
+            .with_scorer(SentimentScorer)
+            .with_scorer(ModelScorer)
+    }
+
+    /// Register a scorer; a scorer with the same name is replaced
+    pub fn with_scorer(mut self, scorer: impl Scorer + 'static) -> Self {
+        self.scorers.retain(|existing| existing.name() != scorer.name());
+        self.scorers.push(Box::new(scorer));
+        self
+    }
+
+    pub fn config(&self) -> &ScoringConfig {
+        &self.config
+    }
+
+    pub fn score(&self, strategy: &str, features: &ScoreFeatures) -> ScoreBreakdown {
+        let profile = self.config.profile(strategy);
+        let mut components = Vec::new();
+        let mut unavailable = Vec::new();
+
+        for scorer in &self.scorers {
+            let weight = profile.weights.get(scorer.name()).copied().unwrap_or(0.0);
+            if weight <= 0.0 {
+                continue;
+            }
+            match scorer.score(features) {
+                Some(output) => components.push(ScoreComponent {
+                    scorer: scorer.name(),
+                    value: output.value.clamp(0.0, 1.0),
+                    weight,
+                    reason: output.reason,
+                }),
+                None => unavailable.push(scorer.name()),
+            }
+        }
+
+        let total_weight: f64 = components.iter().map(|c| c.weight).sum();
+        if total_weight > 0.0 {
+            components.iter_mut().for_each(|c| c.weight /= total_weight);
+        }
+        ScoreBreakdown {
+            strategy: strategy.to_string(),
+            score: components.iter().map(ScoreComponent::contribution).sum(),
+            min_score: profile.min_score,
+            components,
+            unavailable,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn features() -> ScoreFeatures {
+        ScoreFeatures {
+            expected_profit_pct: 5.0,
+            liquidity_usd: Some(200_000.0),
+            risk_score: Some(0.8),
+            sentiment: None,
+            model_probability: Some(0.9),
+            success_probability: None,
+        }
+    }
+
+    #[test]
+    fn test_missing_inputs_renormalize_and_explanation_names_weakest() {
+        let pipeline = ScoringPipeline::standard(ScoringConfig::default(), 5.0, 10_000.0);
+        let breakdown = pipeline.score("LiquiditySnipe", &features());
+
+        assert_eq!(breakdown.unavailable, vec!["sentiment"]);
+        let weights: f64 = breakdown.components.iter().map(|c| c.weight).sum();
+        assert!((weights - 1.0).abs() < 1e-9);
+        assert_eq!(breakdown.weakest()[0].scorer, "safety");
+        assert!(breakdown.explain().starts_with("safety 0.20"));
+        assert!(breakdown.explain().contains("no input for sentiment"));
+    }
+
+    #[test]
+    fn test_strategy_profiles_change_weights_and_threshold() {
+        let cautious = ScoringProfile {
+            weights: HashMap::from([("safety".to_string(), 1.0)]),
+            min_score: 0.5,
+        };
+        let config = ScoringConfig::default().with_strategy_profile("Cautious", cautious);
+        let pipeline = ScoringPipeline::standard(config, 5.0, 10_000.0);
+
+        let default = pipeline.score("LiquiditySnipe", &features());
+        assert!(default.passed(), "{}", default);
+
+        let cautious = pipeline.score("Cautious", &features());
+        assert_eq!(cautious.components.len(), 1);
+        assert!((cautious.score - 0.2).abs() < 1e-9);
+        assert!(!cautious.passed());
+    }
+
+    #[test]
+    fn test_success_probability_scales_expected_profit() {
+        let scorer = ProfitScorer { target_profit_pct: 5.0 };
+        assert_eq!(scorer.score(&features()).unwrap().value, 1.0);
+
+        let unlikely = ScoreFeatures { success_probability: Some(0.4), ..features() };
+        let output = scorer.score(&unlikely).unwrap();
+        assert!((output.value - 0.4).abs() < 1e-9);
+        assert!(output.reason.contains("40% success"));
+    }
+}
@@ -0,0 +1,443 @@
+//! Trade simulation diff
+//!
+//! Validates a refactor (scoring pipeline, scheduler) by replaying the same
+//! recorded input through two builds and comparing what each would do.
+//!
+//! - the service records its decision inputs, every planning round's
+//!   opportunities, budget and the prices used, as JSON lines when
+//!   `SNIPERFORGE_RECORD_DECISION_INPUTS` names a file
+//! - `sniperforge --replay <inputs>` runs those inputs through this build's
+//!   scoring and scheduling in shadow mode (nothing executes) and writes one
+//!   [`Decision`] per opportunity
+//! - `sniperforge-cli sim-diff` runs the current and the candidate binary on
+//!   the same inputs and prints the [`DecisionDiffReport`]: trades only one
+//!   build takes, and sizing changes on trades both take
+//!
+//! Replay uses the recorded round time as "now", so decisions are
+//! reproducible across runs.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::OpenOptions;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::execution_scheduler::{ExecutionBudget, ExecutionScheduler, SchedulerConfig};
+use super::scoring::{ScoreFeatures, ScoringConfig, ScoringPipeline};
+use crate::types::Opportunity;
+
+/// Environment variable naming the file decision inputs are appended to
+pub const RECORD_INPUTS_ENV: &str = "SNIPERFORGE_RECORD_DECISION_INPUTS";
+
+/// One line of a recorded input stream
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RecordedInput {
+    /// USD price of one whole unit of `mint`
+    Price { mint: String, usd: f64, at: DateTime<Utc> },
+    /// Opportunities planned together against one budget
+    Round { at: DateTime<Utc>, budget: ExecutionBudget, opportunities: Vec<Opportunity> },
+}
+
+/// Appends decision inputs to a JSON lines file
+#[derive(Debug, Clone)]
+pub struct DecisionInputRecorder {
+    path: PathBuf,
+    last_prices: HashMap<String, f64>,
+}
+
+impl DecisionInputRecorder {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), last_prices: HashMap::new() }
+    }
+
+    /// Recorder for the file named by [`RECORD_INPUTS_ENV`], if set
+    pub fn from_env() -> Option<Self> {
+        std::env::var(RECORD_INPUTS_ENV).ok().filter(|path| !path.is_empty()).map(Self::new)
+    }
+
+    /// Record a planning round; only prices that changed since the last round are written
+    pub fn record_round(
+        &mut self,
+        at: DateTime<Utc>,
+        prices: &[(&str, f64)],
+        budget: &ExecutionBudget,
+        opportunities: &[Opportunity],
+    ) -> std::io::Result<()> {
+        let mut lines = Vec::with_capacity(prices.len() + 1);
+        for (mint, usd) in prices {
+            if self.last_prices.get(*mint) != Some(usd) {
+                self.last_prices.insert(mint.to_string(), *usd);
+                lines.push(RecordedInput::Price { mint: mint.to_string(), usd: *usd, at });
+            }
+        }
+        // JSON has no infinity; an unlimited capital budget is recorded as f64::MAX
+        let budget = ExecutionBudget {
+            capital_usd: if budget.capital_usd.is_finite() { budget.capital_usd } else { f64::MAX },
+            ..budget.clone()
+        };
+        lines.push(RecordedInput::Round { at, budget, opportunities: opportunities.to_vec() });
+
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        for line in lines {
+            writeln!(file, "{}", serde_json::to_string(&line)?)?;
+        }
+        Ok(())
+    }
+}
+
+/// What a build would do with one opportunity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Decision {
+    /// Index of the round in the input stream
+    pub round: usize,
+    pub opportunity_id: String,
+    pub strategy: String,
+    pub take: bool,
+    /// Capital committed when taken (USD)
+    pub size_usd: f64,
+    pub expected_value_usd: f64,
+    pub score: f64,
+    /// Why it was skipped
+    pub reason: Option<String>,
+}
+
+/// Settings of the shadow decision path
+#[derive(Debug, Clone)]
+pub struct ReplayConfig {
+    pub scoring: ScoringConfig,
+    pub target_profit_pct: f64,
+    pub min_liquidity_usd: f64,
+    pub scheduler: SchedulerConfig,
+}
+
+impl Default for ReplayConfig {
+    fn default() -> Self {
+        Self {
+            scoring: ScoringConfig::default(),
+            target_profit_pct: 1.0,
+            min_liquidity_usd: 10_000.0,
+            scheduler: SchedulerConfig::default(),
+        }
+    }
+}
+
+/// This build's scoring and scheduling, without execution
+pub struct ShadowReplay {
+    scoring: ScoringPipeline,
+    scheduler: ExecutionScheduler,
+    prices: HashMap<String, f64>,
+    round: usize,
+}
+
+impl ShadowReplay {
+    pub fn new(config: ReplayConfig) -> Self {
+        Self {
+            scoring: ScoringPipeline::standard(config.scoring, config.target_profit_pct, config.min_liquidity_usd),
+            scheduler: ExecutionScheduler::new(config.scheduler),
+            prices: HashMap::new(),
+            round: 0,
+        }
+    }
+
+    /// Apply one input; rounds return their decisions
+    pub fn apply(&mut self, input: &RecordedInput) -> Vec<Decision> {
+        match input {
+            RecordedInput::Price { mint, usd, .. } => {
+                self.prices.insert(mint.clone(), *usd);
+                Vec::new()
+            }
+            RecordedInput::Round { at, budget, opportunities } => {
+                let decisions = self.decide(*at, budget, opportunities);
+                self.round += 1;
+                decisions
+            }
+        }
+    }
+
+    fn decide(&self, at: DateTime<Utc>, budget: &ExecutionBudget, opportunities: &[Opportunity]) -> Vec<Decision> {
+        let mut decisions = Vec::with_capacity(opportunities.len());
+        let mut scored = Vec::new();
+        let mut scores = HashMap::new();
+        for opportunity in opportunities {
+            let strategy = format!("{:?}", opportunity.kind);
+            let breakdown = self.scoring.score(&strategy, &ScoreFeatures::from(opportunity));
+            scores.insert(opportunity.id.clone(), breakdown.score);
+            if breakdown.passed() {
+                scored.push(opportunity.clone());
+            } else {
+                decisions.push(self.skip(opportunity, breakdown.score, format!("score {:.2} < {:.2}", breakdown.score, breakdown.min_score)));
+            }
+        }
+
+        let plan = self.scheduler.plan(&scored, |mint| self.prices.get(mint).copied(), budget, at);
+        let score = |id: &str| scores.get(id).copied().unwrap_or(0.0);
+        for scheduled in &plan.run {
+            decisions.push(Decision {
+                round: self.round,
+                opportunity_id: scheduled.opportunity.id.clone(),
+                strategy: format!("{:?}", scheduled.opportunity.kind),
+                take: true,
+                size_usd: scheduled.capital_usd,
+                expected_value_usd: scheduled.expected_value_usd,
+                score: score(&scheduled.opportunity.id),
+                reason: None,
+            });
+        }
+        for scheduled in &plan.deferred {
+            decisions.push(Decision {
+                expected_value_usd: scheduled.expected_value_usd,
+                ..self.skip(&scheduled.opportunity, score(&scheduled.opportunity.id), "deferred by budget".to_string())
+            });
+        }
+        for opportunity in scored.iter().filter(|o| plan.dropped.contains(&o.id)) {
+            decisions.push(self.skip(opportunity, score(&opportunity.id), "dropped by scheduler".to_string()));
+        }
+        decisions
+    }
+
+    fn skip(&self, opportunity: &Opportunity, score: f64, reason: String) -> Decision {
+        Decision {
+            round: self.round,
+            opportunity_id: opportunity.id.clone(),
+            strategy: format!("{:?}", opportunity.kind),
+            take: false,
+            size_usd: 0.0,
+            expected_value_usd: 0.0,
+            score,
+            reason: Some(reason),
+        }
+    }
+
+    /// Replay a whole input stream
+    pub fn replay<R: BufRead>(&mut self, input: R) -> anyhow::Result<Vec<Decision>> {
+        let mut decisions = Vec::new();
+        for (number, line) in input.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let recorded: RecordedInput = serde_json::from_str(&line)
+                .map_err(|e| anyhow::anyhow!("line {}: {}", number + 1, e))?;
+            decisions.extend(self.apply(&recorded));
+        }
+        Ok(decisions)
+    }
+}
+
+impl Default for ShadowReplay {
+    fn default() -> Self {
+        Self::new(ReplayConfig::default())
+    }
+}
+
+/// Write decisions as JSON lines
+pub fn write_decisions<W: Write>(mut out: W, decisions: &[Decision]) -> anyhow::Result<()> {
+    for decision in decisions {
+        writeln!(out, "{}", serde_json::to_string(decision)?)?;
+    }
+    Ok(())
+}
+
+/// Read decisions written by [`write_decisions`]
+pub fn read_decisions(path: &Path) -> anyhow::Result<Vec<Decision>> {
+    let content = std::fs::read_to_string(path)?;
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(Into::into))
+        .collect()
+}
+
+/// A trade both builds take, sized differently
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SizeChange {
+    pub round: usize,
+    pub opportunity_id: String,
+    pub strategy: String,
+    pub size_a_usd: f64,
+    pub size_b_usd: f64,
+    pub change_percent: f64,
+}
+
+/// Decision differences between build A (current) and build B (candidate)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DecisionDiffReport {
+    pub compared: usize,
+    pub taken_a: usize,
+    pub taken_b: usize,
+    /// Taken by A, skipped (or never seen) by B; B's decision when it had one
+    pub only_a: Vec<(Decision, Option<Decision>)>,
+    pub only_b: Vec<(Decision, Option<Decision>)>,
+    pub size_changes: Vec<SizeChange>,
+}
+
+impl DecisionDiffReport {
+    /// Decisions are matched by (round, opportunity id); sizes within `size_tolerance_percent` count as equal
+    pub fn compare(a: &[Decision], b: &[Decision], size_tolerance_percent: f64) -> Self {
+        let key = |d: &Decision| (d.round, d.opportunity_id.clone());
+        let a_by_key: BTreeMap<_, &Decision> = a.iter().map(|d| (key(d), d)).collect();
+        let b_by_key: BTreeMap<_, &Decision> = b.iter().map(|d| (key(d), d)).collect();
+
+        let mut report = Self {
+            taken_a: a.iter().filter(|d| d.take).count(),
+            taken_b: b.iter().filter(|d| d.take).count(),
+            ..Self::default()
+        };
+        let mut keys: Vec<_> = a_by_key.keys().chain(b_by_key.keys()).cloned().collect();
+        keys.sort();
+        keys.dedup();
+        report.compared = keys.len();
+
+        for key in keys {
+            let (in_a, in_b) = (a_by_key.get(&key).copied(), b_by_key.get(&key).copied());
+            match (in_a.filter(|d| d.take), in_b.filter(|d| d.take)) {
+                (Some(da), None) => report.only_a.push((da.clone(), in_b.cloned())),
+                (None, Some(db)) => report.only_b.push((db.clone(), in_a.cloned())),
+                (Some(da), Some(db)) => {
+                    let base = da.size_usd.abs().max(f64::EPSILON);
+                    let change_percent = (db.size_usd - da.size_usd) / base * 100.0;
+                    if change_percent.abs() > size_tolerance_percent {
+                        report.size_changes.push(SizeChange {
+                            round: da.round,
+                            opportunity_id: da.opportunity_id.clone(),
+                            strategy: da.strategy.clone(),
+                            size_a_usd: da.size_usd,
+                            size_b_usd: db.size_usd,
+                            change_percent,
+                        });
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+        report
+    }
+
+    pub fn identical(&self) -> bool {
+        self.only_a.is_empty() && self.only_b.is_empty() && self.size_changes.is_empty()
+    }
+
+    /// Human-readable report
+    pub fn render(&self) -> String {
+        let mut out = format!(
+            "{} decisions compared: A takes {}, B takes {}; {} only A, {} only B, {} sizing changes\n",
+            self.compared, self.taken_a, self.taken_b, self.only_a.len(), self.only_b.len(), self.size_changes.len()
+        );
+        let reason = |other: &Option<Decision>| match other {
+            Some(d) => d.reason.clone().unwrap_or_default(),
+            None => "not seen".to_string(),
+        };
+        for (title, entries) in [("Only A takes", &self.only_a), ("Only B takes", &self.only_b)] {
+            if entries.is_empty() {
+                continue;
+            }
+            out.push_str(&format!("\n{}:\n", title));
+            for (taken, other) in entries {
+                out.push_str(&format!(
+                    "  round {:>4}  {:<12} {:<24} ${:>12.2}  (other build: {})\n",
+                    taken.round, taken.strategy, taken.opportunity_id, taken.size_usd, reason(other)
+                ));
+            }
+        }
+        if !self.size_changes.is_empty() {
+            out.push_str("\nSizing changes:\n");
+            for change in &self.size_changes {
+                out.push_str(&format!(
+                    "  round {:>4}  {:<12} {:<24} ${:>12.2} → ${:>12.2}  ({:+.1}%)\n",
+                    change.round, change.strategy, change.opportunity_id, change.size_a_usd, change.size_b_usd, change.change_percent
+                ));
+            }
+        }
+        if self.identical() {
+            out.push_str("No decision differences\n");
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{usd_opportunity, OpportunityKind, RouteHop};
+    use chrono::Duration;
+
+    fn opportunity(id: &str, profit_usd: f64, capital_usd: f64, confidence: f64, at: DateTime<Utc>) -> Opportunity {
+        usd_opportunity(
+            id.to_string(),
+            OpportunityKind::Arbitrage,
+            vec![RouteHop::new("USDC", "Raydium"), RouteHop::new("USDC", "")],
+            profit_usd,
+            capital_usd,
+            confidence,
+            at,
+            at + Duration::seconds(60),
+        )
+    }
+
+    fn decision(round: usize, id: &str, take: bool, size_usd: f64) -> Decision {
+        Decision {
+            round,
+            opportunity_id: id.to_string(),
+            strategy: "Arbitrage".to_string(),
+            take,
+            size_usd,
+            expected_value_usd: 0.0,
+            score: 0.0,
+            reason: (!take).then(|| "score".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_recorded_stream_replays_deterministically() {
+        let path = std::env::temp_dir().join(format!("decision-inputs-{}.jsonl", uuid::Uuid::new_v4()));
+        let at = DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z").unwrap().with_timezone(&Utc);
+        let budget = ExecutionBudget { capital_usd: 1_500.0, ..ExecutionBudget::default() };
+        let mut recorder = DecisionInputRecorder::new(&path);
+        let usdc = crate::types::constants::USDC_MINT;
+        recorder.record_round(at, &[(usdc, 1.0)], &budget, &[
+            opportunity("good", 30.0, 1_000.0, 0.9, at),
+            opportunity("second", 25.0, 1_000.0, 0.9, at),
+            opportunity("weak", 0.01, 1_000.0, 0.1, at),
+        ]).unwrap();
+        // Unchanged prices are not written again
+        recorder.record_round(at + Duration::seconds(5), &[(usdc, 1.0)], &ExecutionBudget::default(), &[opportunity("later", 30.0, 500.0, 0.9, at)]).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), 3);
+
+        let run = || ShadowReplay::default().replay(content.as_bytes()).unwrap();
+        let decisions = run();
+        let find = |id: &str| decisions.iter().find(|d| d.opportunity_id == id).unwrap();
+        assert!(find("good").take);
+        assert_eq!(find("good").size_usd, 1_000.0);
+        assert_eq!(find("second").reason.as_deref(), Some("deferred by budget"));
+        assert!(find("weak").reason.as_deref().unwrap().starts_with("score"));
+        assert_eq!(find("later").round, 1);
+        assert!(find("later").take);
+        assert_eq!(decisions, run());
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_diff_reports_one_sided_trades_and_sizing() {
+        let a = vec![decision(0, "x", true, 100.0), decision(0, "y", true, 100.0), decision(0, "z", false, 0.0), decision(1, "w", true, 50.0)];
+        let b = vec![decision(0, "x", true, 100.5), decision(0, "y", true, 80.0), decision(0, "z", true, 40.0), decision(1, "v", true, 10.0)];
+        let report = DecisionDiffReport::compare(&a, &b, 1.0);
+
+        assert_eq!(report.compared, 5);
+        assert_eq!((report.taken_a, report.taken_b), (3, 4));
+        assert_eq!(report.only_a.len(), 1);
+        assert_eq!(report.only_a[0].0.opportunity_id, "w");
+        assert!(report.only_a[0].1.is_none());
+        let only_b: Vec<&str> = report.only_b.iter().map(|(d, _)| d.opportunity_id.as_str()).collect();
+        assert_eq!(only_b, vec!["z", "v"]);
+        // x moved 0.5%, inside the tolerance
+        assert_eq!(report.size_changes.len(), 1);
+        assert!((report.size_changes[0].change_percent + 20.0).abs() < 1e-9);
+        let rendered = report.render();
+        assert!(rendered.contains("(other build: score)") && rendered.contains("not seen"));
+        assert!(DecisionDiffReport::compare(&a, &a, 0.0).identical());
+    }
+}
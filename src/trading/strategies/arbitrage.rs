@@ -11,7 +11,11 @@ use super::{
 use crate::config::SimpleConfig;
 use crate::trading::arbitrage::ArbitrageEngine;
 use crate::types::{TradingOpportunity, MarketData};
-use crate::ml::{AdvancedMLEngine, MLConfig, MLEngineFactory, PatternType, SentimentTrend}; // ✅ NUEVO: ML Integration
+#[cfg(feature = "ml")]
+use crate::ml::{AdvancedMLEngine, MLAnalysisResult, MLConfig, MLEngineFactory, PatternType, SentimentTrend}; // ✅ NUEVO: ML Integration
+/// Without the `ml` feature there is never an analysis; scoring takes the traditional path
+#[cfg(not(feature = "ml"))]
+type MLAnalysisResult = std::convert::Infallible;
 use anyhow::Result;
 use std::collections::HashMap;
 use chrono::Utc;
@@ -39,6 +43,7 @@ pub struct ArbitrageStrategy {
     enabled: bool,
     price_feeds: HashMap<String, f64>, // DEX -> Price mapping
     arbitrage_engine: Option<ArbitrageEngine>, // ✅ CORREGIDO: Optional para lazy initialization
+    #[cfg(feature = "ml")]
     ml_engine: Option<AdvancedMLEngine>, // ✅ NUEVO: ML Engine integration
 }
 
@@ -85,6 +90,7 @@ impl ArbitrageStrategy {
             take_profit_percent: 3.0,    // Quick profits
             min_confidence: 0.8,         // High confidence required
             timeframes: vec![Timeframe::OneMin], // Immediate execution
+            shadow: false,
         };
 
         let performance = StrategyPerformance {
@@ -108,6 +114,7 @@ impl ArbitrageStrategy {
             enabled: true,
             price_feeds: HashMap::new(),
             arbitrage_engine: None, // ✅ CORREGIDO: Se inicializará de forma lazy
+            #[cfg(feature = "ml")]
             ml_engine: None, // ✅ NUEVO: ML Engine lazy initialization
         })
     }
@@ -123,6 +130,7 @@ impl ArbitrageStrategy {
     }
     
     /// Initialize the ML engine (lazy initialization)
+    #[cfg(feature = "ml")]
     #[allow(dead_code)]
     async fn ensure_ml_engine_initialized(&mut self) -> Result<(), String> {
         if self.ml_engine.is_none() {
@@ -163,6 +171,7 @@ impl ArbitrageStrategy {
             enabled: true,
             price_feeds: HashMap::new(),
             arbitrage_engine: None, // ✅ CORREGIDO: Se inicializará de forma lazy
+            #[cfg(feature = "ml")]
             ml_engine: None, // ✅ NUEVO: ML Engine lazy initialization
         }
     }
@@ -360,7 +369,7 @@ impl ArbitrageStrategy {
     }
     
     /// Select best arbitrage opportunity enhanced with ML analysis
-    fn select_best_arbitrage_with_ml<'a>(&self, opportunities: &'a [ArbitrageOpportunity], ml_analysis: &Option<crate::ml::MLAnalysisResult>) -> Option<&'a ArbitrageOpportunity> {
+    fn select_best_arbitrage_with_ml<'a>(&self, opportunities: &'a [ArbitrageOpportunity], ml_analysis: &Option<MLAnalysisResult>) -> Option<&'a ArbitrageOpportunity> {
         if opportunities.is_empty() {
             return None;
         }
@@ -396,6 +405,7 @@ impl ArbitrageStrategy {
             }
 
             // ✅ ML Enhancement (30% weight when available)
+            #[cfg(feature = "ml")]
             if let Some(ref ml_result) = ml_analysis {
                 let ml_weight = 0.3;
                 
@@ -440,6 +450,25 @@ impl ArbitrageStrategy {
         best_opportunity
     }
 
+    /// ML analysis of the current market, when the ML engine is up
+    #[cfg(feature = "ml")]
+    fn ml_analysis(&self) -> Option<MLAnalysisResult> {
+        // ✅ NUEVO: ML Enhancement - Initialize ML engine if needed (non-blocking initialization)
+        if self.ml_engine.is_none() {
+            warn!("⚠️ ML engine not initialized, using traditional arbitrage analysis");
+            return None;
+        }
+        // For sync context, we'll create a simplified ML analysis
+        // TODO: Consider moving ML analysis to async context in the future
+        warn!("🤖 ML Analysis deferred - using traditional arbitrage analysis");
+        None
+    }
+
+    #[cfg(not(feature = "ml"))]
+    fn ml_analysis(&self) -> Option<MLAnalysisResult> {
+        None
+    }
+
     /// Get reference to internal arbitrage engine for ML features
     pub fn arbitrage_engine(&self) -> Option<&ArbitrageEngine> {
         self.arbitrage_engine.as_ref()
@@ -487,20 +516,8 @@ impl TradingStrategy for ArbitrageStrategy {
             return Ok(vec![]);
         }
 
-        // ✅ NUEVO: ML Enhancement - Initialize ML engine if needed (non-blocking initialization)
-        if self.ml_engine.is_none() {
-            warn!("⚠️ ML engine not initialized, using traditional arbitrage analysis");
-        }
-
         // ✅ NUEVO: ML Analysis Integration - Non-blocking approach
-        let ml_analysis = if let Some(ref _ml_engine) = self.ml_engine {
-            // For sync context, we'll create a simplified ML analysis
-            // TODO: Consider moving ML analysis to async context in the future
-            warn!("🤖 ML Analysis deferred - using traditional arbitrage analysis");
-            None
-        } else {
-            None
-        };
+        let ml_analysis = self.ml_analysis();
 
         // Detect arbitrage opportunities using real price feeds
         let opportunities = self.detect_arbitrage_opportunities(market_data);
@@ -515,9 +532,12 @@ impl TradingStrategy for ArbitrageStrategy {
         };
 
         // Enhanced validation with ML risk assessment
+        #[cfg(feature = "ml")]
         let ml_confidence_factor = ml_analysis.as_ref()
             .map(|analysis| analysis.confidence)
             .unwrap_or(1.0);
+        #[cfg(not(feature = "ml"))]
+        let ml_confidence_factor = 1.0;
         
         let adjusted_min_confidence = self.config.min_confidence * ml_confidence_factor;
 
@@ -528,9 +548,12 @@ impl TradingStrategy for ArbitrageStrategy {
         }
 
         // ML-enhanced profit validation
+        #[cfg(feature = "ml")]
         let ml_risk_adjustment = ml_analysis.as_ref()
             .map(|analysis| 1.0 - analysis.risk_assessment.overall_risk_score)
             .unwrap_or(1.0);
+        #[cfg(not(feature = "ml"))]
+        let ml_risk_adjustment = 1.0;
         
         let min_profit_threshold = 1.0 / ml_risk_adjustment;
 
@@ -721,6 +744,7 @@ impl Default for ArbitrageStrategy {
             enabled: true,
             price_feeds: HashMap::new(),
             arbitrage_engine: None,
+            #[cfg(feature = "ml")]
             ml_engine: None, // Will be initialized later in async context
         }
     }
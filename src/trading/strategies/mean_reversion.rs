@@ -63,6 +63,7 @@ impl MeanReversionStrategy {
                 Timeframe::OneHour,
                 Timeframe::FourHour   // Longer timeframes for mean reversion
             ],
+            shadow: false,
         };
 
         let performance = StrategyPerformance {
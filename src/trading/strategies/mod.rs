@@ -7,16 +7,21 @@ pub mod arbitrage;
 pub mod momentum;
 pub mod mean_reversion;
 pub mod strategy_manager;
+pub mod shadow;
+pub mod backtest;
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use std::collections::HashMap;
 
 // Re-export strategy implementations
 pub use arbitrage::ArbitrageStrategy;
 pub use momentum::MomentumStrategy;
 pub use mean_reversion::MeanReversionStrategy;
 pub use strategy_manager::StrategyManager;
+pub use shadow::{ShadowLedger, ShadowTrade, ShadowComparison, ShadowCompetition};
+pub use backtest::{BacktestStrategy, PriceReplayBacktest};
 
 // Re-export enterprise types from the existing arbitrage system
 pub use crate::trading::arbitrage::{
@@ -37,6 +42,9 @@ pub struct StrategyConfig {
     pub take_profit_percent: f64,
     pub min_confidence: f64,
     pub timeframes: Vec<Timeframe>,
+    /// Run fully but record hypothetical fills in the shadow ledger instead of executing
+    #[serde(default)]
+    pub shadow: bool,
 }
 
 impl Default for StrategyConfig {
@@ -51,10 +59,40 @@ impl Default for StrategyConfig {
             take_profit_percent: 4.0,
             min_confidence: 0.7,
             timeframes: vec![Timeframe::FiveMin],
+            shadow: false,
         }
     }
 }
 
+impl StrategyConfig {
+    /// Numeric parameters that experiment variants may override by name
+    pub const TUNABLE_PARAMETERS: &'static [&'static str] = &[
+        "capital_allocation",
+        "max_position_size",
+        "stop_loss_percent",
+        "take_profit_percent",
+        "min_confidence",
+    ];
+    
+    /// Override numeric parameters by name; nothing is applied if any name is unknown
+    pub fn apply_parameters(&mut self, parameters: &HashMap<String, f64>) -> Result<()> {
+        if let Some(unknown) = parameters.keys().find(|name| !Self::TUNABLE_PARAMETERS.contains(&name.as_str())) {
+            return Err(anyhow::anyhow!("Unknown strategy parameter '{}'", unknown));
+        }
+        for (name, value) in parameters {
+            match name.as_str() {
+                "capital_allocation" => self.capital_allocation = *value,
+                "max_position_size" => self.max_position_size = *value,
+                "stop_loss_percent" => self.stop_loss_percent = *value,
+                "take_profit_percent" => self.take_profit_percent = *value,
+                "min_confidence" => self.min_confidence = *value,
+                _ => unreachable!("parameter names validated above"),
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Risk level for strategy configuration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RiskLevel {
@@ -258,6 +296,19 @@ mod tests {
         assert_eq!(config.name, "Default Strategy");
         assert!(config.enabled);
         assert_eq!(config.capital_allocation, 0.1);
+        assert!(!config.shadow);
+    }
+    
+    #[test]
+    fn test_apply_parameters_is_all_or_nothing() {
+        let mut config = StrategyConfig::default();
+        let tuned = HashMap::from([("min_confidence".to_string(), 0.55), ("stop_loss_percent".to_string(), 1.5)]);
+        config.apply_parameters(&tuned).unwrap();
+        assert_eq!((config.min_confidence, config.stop_loss_percent), (0.55, 1.5));
+        
+        let bad = HashMap::from([("take_profit_percent".to_string(), 9.0), ("leverage".to_string(), 3.0)]);
+        assert!(config.apply_parameters(&bad).is_err());
+        assert_eq!(config.take_profit_percent, 4.0);
     }
     
     #[test]
@@ -56,6 +56,7 @@ impl MomentumStrategy {
                 Timeframe::FifteenMin,
                 Timeframe::OneHour    // Added hourly for trend confirmation
             ],
+            shadow: false,
         };
 
         let performance = StrategyPerformance {
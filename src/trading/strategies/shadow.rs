@@ -0,0 +1,369 @@
+//! Shadow Mode - run strategies live without executing
+//!
+//! A strategy flagged `shadow` scans and scores exactly like a live strategy,
+//! but its signals are filled hypothetically into a dedicated ledger instead
+//! of reaching execution. Shadow performance uses the same `StrategyPerformance`
+//! shape as live strategies so the two can be compared side by side before a
+//! strategy is promoted to real execution.
+//!
+//! With a competition model attached, each signal is first checked against the
+//! odds of a competing searcher taking the opportunity; lost signals are counted
+//! instead of filled, so shadow returns are not overstated.
+
+use super::{SignalType, StrategyPerformance, StrategySignal};
+use crate::trading::competition_model::CompetitionModel;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, info};
+
+/// Hypothetical trade opened from a shadow signal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowTrade {
+    pub id: String,
+    pub strategy_name: String,
+    pub token_pair: String,
+    pub signal_type: SignalType,
+    pub entry_price: f64,
+    pub size: f64,
+    pub stop_loss: f64,
+    pub take_profit: f64,
+    pub confidence: f64,
+    pub opened_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub exit_price: Option<f64>,
+    /// Net hypothetical P&L after simulated fees (set when closed)
+    pub profit_loss: Option<f64>,
+    pub fees: f64,
+}
+
+impl ShadowTrade {
+    /// Gross P&L at `price` (long for Buy, short for Sell)
+    pub fn gross_pnl_at(&self, price: f64) -> f64 {
+        if self.entry_price <= 0.0 {
+            return 0.0;
+        }
+        let units = self.size / self.entry_price;
+        match self.signal_type {
+            SignalType::Sell => (self.entry_price - price) * units,
+            _ => (price - self.entry_price) * units,
+        }
+    }
+
+    /// Whether `price` crosses the stop loss or take profit
+    fn exit_triggered(&self, price: f64) -> bool {
+        match self.signal_type {
+            SignalType::Sell => {
+                (self.stop_loss > 0.0 && price >= self.stop_loss)
+                    || (self.take_profit > 0.0 && price <= self.take_profit)
+            }
+            _ => {
+                (self.stop_loss > 0.0 && price <= self.stop_loss)
+                    || (self.take_profit > 0.0 && price >= self.take_profit)
+            }
+        }
+    }
+}
+
+/// Side-by-side comparison of a shadow strategy against a live one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowComparison {
+    pub shadow: StrategyPerformance,
+    pub live: StrategyPerformance,
+    pub profit_loss_delta: f64,
+    pub win_rate_delta: f64,
+    pub average_profit_delta: f64,
+    /// Both sides have at least `min_trades_for_comparison` closed trades
+    pub sample_size_sufficient: bool,
+}
+
+/// Competition assumptions applied to shadow fills
+#[derive(Debug, Clone)]
+pub struct ShadowCompetition {
+    pub model: CompetitionModel,
+    /// Venue the simulated trades are assumed to route through
+    pub venue: String,
+    /// Detection-to-landing latency assumed for our transactions (ms)
+    pub latency_ms: f64,
+    rng: fastrand::Rng,
+}
+
+/// Ledger of hypothetical shadow trades
+#[derive(Debug, Clone)]
+pub struct ShadowLedger {
+    /// Simulated round-trip fee in basis points
+    pub fee_bps: f64,
+    /// Minimum closed trades per side before a comparison is considered meaningful
+    pub min_trades_for_comparison: u64,
+    open_trades: Vec<ShadowTrade>,
+    closed_trades: Vec<ShadowTrade>,
+    performance: HashMap<String, StrategyPerformance>,
+    competition: Option<ShadowCompetition>,
+    /// Signals lost to competing searchers, per strategy
+    front_run: HashMap<String, u64>,
+    next_id: u64,
+}
+
+impl ShadowLedger {
+    /// Create a new ledger
+    pub fn new(fee_bps: f64) -> Self {
+        Self {
+            fee_bps,
+            min_trades_for_comparison: 30,
+            open_trades: Vec::new(),
+            closed_trades: Vec::new(),
+            performance: HashMap::new(),
+            competition: None,
+            front_run: HashMap::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Simulate competing searchers; `seed` makes the fill decisions reproducible
+    pub fn with_competition(mut self, model: CompetitionModel, venue: &str, latency_ms: f64, seed: u64) -> Self {
+        self.competition = Some(ShadowCompetition {
+            model,
+            venue: venue.to_string(),
+            latency_ms,
+            rng: fastrand::Rng::with_seed(seed),
+        });
+        self
+    }
+
+    /// Whether a competing searcher takes this signal's opportunity first
+    fn lost_to_competition(&mut self, signal: &StrategySignal) -> bool {
+        let Some(competition) = self.competition.as_mut() else {
+            return false;
+        };
+        // expected_profit is a percentage
+        let spread_bps = signal.expected_profit * 100.0;
+        let roll = competition.rng.f64();
+        !competition.model.simulate_capture(&competition.venue, spread_bps, competition.latency_ms, roll)
+    }
+
+    /// Open a hypothetical trade for a shadow signal; Hold and exit signals are ignored
+    pub fn record_signal(&mut self, signal: &StrategySignal) -> Option<String> {
+        if !matches!(signal.signal_type, SignalType::Buy | SignalType::Sell) || signal.price <= 0.0 {
+            return None;
+        }
+
+        if self.lost_to_competition(signal) {
+            *self.front_run.entry(signal.strategy_name.clone()).or_insert(0) += 1;
+            debug!("👻 Shadow {} {} lost to a competing searcher", signal.strategy_name, signal.token_pair);
+            return None;
+        }
+
+        self.next_id += 1;
+        let id = format!("shadow-{}-{}", signal.strategy_name, self.next_id);
+        let fees = signal.volume * self.fee_bps / 10_000.0;
+
+        debug!("👻 Shadow {:?} {} @ {:.6} ({})", signal.signal_type, signal.token_pair, signal.price, signal.strategy_name);
+
+        self.open_trades.push(ShadowTrade {
+            id: id.clone(),
+            strategy_name: signal.strategy_name.clone(),
+            token_pair: signal.token_pair.clone(),
+            signal_type: signal.signal_type.clone(),
+            entry_price: signal.price,
+            size: signal.volume,
+            stop_loss: signal.stop_loss,
+            take_profit: signal.take_profit,
+            confidence: signal.confidence,
+            opened_at: Utc::now(),
+            closed_at: None,
+            exit_price: None,
+            profit_loss: None,
+            fees,
+        });
+        Some(id)
+    }
+
+    /// Mark open trades for `token_pair` to `price`, closing those that hit their exits
+    pub fn mark_price(&mut self, token_pair: &str, price: f64) -> Vec<ShadowTrade> {
+        let (to_close, still_open): (Vec<_>, Vec<_>) = self
+            .open_trades
+            .drain(..)
+            .partition(|t| t.token_pair == token_pair && t.exit_triggered(price));
+        self.open_trades = still_open;
+
+        to_close.into_iter().map(|trade| self.close(trade, price)).collect()
+    }
+
+    /// Close every open trade for a strategy at the given prices (e.g. on promotion)
+    pub fn close_all(&mut self, strategy_name: &str, prices: &HashMap<String, f64>) -> Vec<ShadowTrade> {
+        let (to_close, still_open): (Vec<_>, Vec<_>) = self
+            .open_trades
+            .drain(..)
+            .partition(|t| t.strategy_name == strategy_name && prices.contains_key(&t.token_pair));
+        self.open_trades = still_open;
+
+        to_close
+            .into_iter()
+            .map(|trade| {
+                let price = prices[&trade.token_pair];
+                self.close(trade, price)
+            })
+            .collect()
+    }
+
+    fn close(&mut self, mut trade: ShadowTrade, price: f64) -> ShadowTrade {
+        let exit_fees = trade.size * self.fee_bps / 10_000.0;
+        trade.fees += exit_fees;
+        let net = trade.gross_pnl_at(price) - trade.fees;
+        trade.exit_price = Some(price);
+        trade.closed_at = Some(Utc::now());
+        trade.profit_loss = Some(net);
+
+        let perf = self
+            .performance
+            .entry(trade.strategy_name.clone())
+            .or_insert_with(|| StrategyPerformance {
+                strategy_name: trade.strategy_name.clone(),
+                ..Default::default()
+            });
+        perf.total_trades += 1;
+        perf.total_profit_loss += net;
+        perf.total_fees += trade.fees;
+        if net > 0.0 {
+            perf.winning_trades += 1;
+            perf.average_profit += (net - perf.average_profit) / perf.winning_trades as f64;
+        } else {
+            perf.losing_trades += 1;
+            perf.average_loss += (net.abs() - perf.average_loss) / perf.losing_trades as f64;
+        }
+        perf.win_rate = perf.winning_trades as f64 / perf.total_trades as f64;
+        perf.last_updated = Utc::now();
+
+        info!("👻 Shadow trade {} closed @ {:.6}: {:+.4}", trade.id, price, net);
+        self.closed_trades.push(trade.clone());
+        trade
+    }
+
+    /// Hypothetical performance of a shadow strategy
+    pub fn performance(&self, strategy_name: &str) -> Option<&StrategyPerformance> {
+        self.performance.get(strategy_name)
+    }
+
+    /// Open hypothetical trades
+    pub fn open_trades(&self) -> &[ShadowTrade] {
+        &self.open_trades
+    }
+
+    /// Closed hypothetical trades
+    pub fn closed_trades(&self) -> &[ShadowTrade] {
+        &self.closed_trades
+    }
+
+    /// Signals of a strategy that were lost to competing searchers
+    pub fn front_run_count(&self, strategy_name: &str) -> u64 {
+        self.front_run.get(strategy_name).copied().unwrap_or(0)
+    }
+
+    /// Compare a shadow strategy against live performance
+    pub fn compare(&self, shadow_strategy: &str, live: &StrategyPerformance) -> ShadowComparison {
+        let shadow = self.performance.get(shadow_strategy).cloned().unwrap_or_else(|| StrategyPerformance {
+            strategy_name: shadow_strategy.to_string(),
+            ..Default::default()
+        });
+
+        ShadowComparison {
+            profit_loss_delta: shadow.total_profit_loss - live.total_profit_loss,
+            win_rate_delta: shadow.win_rate - live.win_rate,
+            average_profit_delta: shadow.average_profit - live.average_profit,
+            sample_size_sufficient: shadow.total_trades >= self.min_trades_for_comparison
+                && live.total_trades >= self.min_trades_for_comparison,
+            shadow,
+            live: live.clone(),
+        }
+    }
+}
+
+impl Default for ShadowLedger {
+    fn default() -> Self {
+        Self::new(30.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trading::strategies::Timeframe;
+
+    fn signal(signal_type: SignalType, price: f64, stop_loss: f64, take_profit: f64) -> StrategySignal {
+        StrategySignal {
+            strategy_name: "momentum".to_string(),
+            signal_type,
+            confidence: 0.8,
+            timeframe: Timeframe::FiveMin,
+            token_pair: "SOL/USDC".to_string(),
+            price,
+            volume: 100.0,
+            timestamp: Utc::now(),
+            metadata: None,
+            expected_profit: 5.0,
+            stop_loss,
+            take_profit,
+            reasoning: None,
+            risk_score: 0.3,
+            market_conditions: None,
+        }
+    }
+
+    #[test]
+    fn test_shadow_trade_closes_on_take_profit() {
+        let mut ledger = ShadowLedger::new(0.0);
+        ledger.record_signal(&signal(SignalType::Buy, 100.0, 95.0, 110.0)).unwrap();
+
+        assert!(ledger.mark_price("SOL/USDC", 105.0).is_empty());
+        let closed = ledger.mark_price("SOL/USDC", 110.0);
+        assert_eq!(closed.len(), 1);
+        assert!((closed[0].profit_loss.unwrap() - 10.0).abs() < 1e-9);
+
+        let perf = ledger.performance("momentum").unwrap();
+        assert_eq!(perf.total_trades, 1);
+        assert_eq!(perf.winning_trades, 1);
+        assert!(ledger.open_trades().is_empty());
+    }
+
+    #[test]
+    fn test_fees_and_short_side() {
+        let mut ledger = ShadowLedger::new(10.0);
+        ledger.record_signal(&signal(SignalType::Sell, 100.0, 105.0, 90.0)).unwrap();
+        let closed = ledger.mark_price("SOL/USDC", 105.0);
+        // Short stopped out: -5 gross, 0.1 + 0.1 fees
+        assert!((closed[0].profit_loss.unwrap() + 5.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hold_signals_ignored_and_comparison() {
+        let mut ledger = ShadowLedger::new(0.0);
+        assert!(ledger.record_signal(&signal(SignalType::Hold, 100.0, 0.0, 0.0)).is_none());
+
+        let live = StrategyPerformance {
+            strategy_name: "arbitrage".to_string(),
+            total_trades: 40,
+            total_profit_loss: 12.0,
+            win_rate: 0.6,
+            ..Default::default()
+        };
+        let comparison = ledger.compare("momentum", &live);
+        assert!(!comparison.sample_size_sufficient);
+        assert!((comparison.profit_loss_delta + 12.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_competition_drops_contested_signals() {
+        use crate::trading::competition_model::CompetitionConfig;
+
+        let config = CompetitionConfig { base_front_run_probability: 0.5, ..Default::default() };
+        let mut ledger = ShadowLedger::new(0.0).with_competition(CompetitionModel::new(config), "orca", 400.0, 7);
+        // expected_profit 5% = 500bps, far above the reference spread: front-run odds hit the cap
+        let filled = (0..200)
+            .filter(|_| ledger.record_signal(&signal(SignalType::Buy, 100.0, 95.0, 110.0)).is_some())
+            .count();
+
+        assert_eq!(filled as u64 + ledger.front_run_count("momentum"), 200);
+        assert!(filled > 0 && filled < 40, "filled {}", filled);
+        assert_eq!(ledger.open_trades().len(), filled);
+    }
+}
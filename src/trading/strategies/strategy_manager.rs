@@ -6,12 +6,16 @@
 
 use super::{
     TradingStrategy, StrategyPerformance, StrategySignal, SignalType,
-    ArbitrageStrategy, MomentumStrategy, MeanReversionStrategy, TradeResult
+    ArbitrageStrategy, MomentumStrategy, MeanReversionStrategy, TradeResult,
+    ShadowLedger, ShadowComparison
 };
+use crate::analytics::experiments::{shared_experiments, Experiment, ExperimentManager, ExperimentMode, ExperimentReport, VariantArm};
 use crate::types::{TradingOpportunity, MarketData};
 use crate::config::SimpleConfig;
 use anyhow::Result;
-use std::collections::HashMap;
+use parking_lot::Mutex;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use chrono::Utc;
 use tracing::{info, warn, debug, error};
 
@@ -41,6 +45,13 @@ pub struct StrategyManager {
     signal_aggregation_method: String,       // Method for aggregating signals
     min_signal_agreement: f64,               // Minimum agreement between strategies
     conflict_resolution_strategy: String,    // How to resolve conflicting signals
+    
+    // 👻 Shadow mode
+    shadow_ledger: ShadowLedger,             // Hypothetical fills for shadow strategies
+    
+    // 🧪 A/B experiments
+    experiments: Arc<Mutex<ExperimentManager>>,             // Shared with the analytics cycle
+    experiment_arms: HashMap<String, (String, VariantArm)>, // Strategy key → (experiment id, arm)
 }
 
 impl StrategyManager {
@@ -88,9 +99,22 @@ impl StrategyManager {
             signal_aggregation_method: "weighted_average".to_string(),
             min_signal_agreement: 0.6,         // 60% minimum agreement
             conflict_resolution_strategy: "highest_confidence".to_string(),
+            
+            // Shadow mode
+            shadow_ledger: ShadowLedger::default(),
+            
+            // A/B experiments
+            experiments: shared_experiments(),
+            experiment_arms: HashMap::new(),
         }
     }
     
+    /// Record experiments in a separate registry instead of the process-wide one
+    pub fn with_experiments(mut self, experiments: Arc<Mutex<ExperimentManager>>) -> Self {
+        self.experiments = experiments;
+        self
+    }
+    
     /// Initialize and register all available strategies
     pub async fn initialize_strategies(&mut self) -> Result<()> {
         info!("Initializing enterprise trading strategies...");
@@ -145,12 +169,35 @@ impl StrategyManager {
         let mut all_signals = Vec::new();
         let mut strategy_signals: HashMap<String, Vec<StrategySignal>> = HashMap::new();
         
+        // Mark open shadow trades before new signals are recorded
+        if market_data.current_price > 0.0 {
+            let closed = self.shadow_ledger.mark_price(&opportunity.token_pair, market_data.current_price);
+            for trade in closed {
+                let key = self.strategies.iter()
+                    .find(|(_, strategy)| strategy.name() == trade.strategy_name)
+                    .map(|(key, _)| key.clone());
+                if let (Some(key), Some(profit)) = (key, trade.profit_loss) {
+                    if trade.size > 0.0 {
+                        self.record_experiment_result(&key, profit / trade.size, profit);
+                    }
+                }
+            }
+        }
+        
+        // Capital-split experiments hand each opportunity to one arm only
+        let sitting_out = self.experiment_arms_sitting_out();
+        
         // Collect signals from all enabled strategies
         for (strategy_name, strategy) in &mut self.strategies {
-            if strategy.enabled() {
+            if strategy.enabled() && !sitting_out.contains(strategy_name) {
                 match strategy.analyze(opportunity, market_data) {
                     Ok(signals) => {
-                        if !signals.is_empty() {
+                        if strategy.config().shadow {
+                            // Shadow strategies never reach coordination or execution
+                            for signal in &signals {
+                                self.shadow_ledger.record_signal(signal);
+                            }
+                        } else if !signals.is_empty() {
                             debug!("Strategy '{}' generated {} signals", strategy_name, signals.len());
                             strategy_signals.insert(strategy_name.clone(), signals.clone());
                             all_signals.extend(signals);
@@ -522,6 +569,12 @@ impl StrategyManager {
         // Update individual strategy performance
         if let Some(strategy) = self.strategies.get_mut(strategy_name) {
             strategy.update_performance(trade_result)?;
+            
+            // Trades carry no size: returns are relative to the arm's configured position size
+            let position_size = strategy.config().max_position_size;
+            if position_size > 0.0 {
+                self.record_experiment_result(strategy_name, trade_result.profit_loss / position_size, trade_result.profit_loss);
+            }
         }
         
         // Update global performance
@@ -567,6 +620,158 @@ impl StrategyManager {
         report
     }
     
+    /// Put a strategy into (or take it out of) shadow mode
+    ///
+    /// Taking a strategy out of shadow mode promotes it to real execution;
+    /// its hypothetical history stays in the shadow ledger.
+    pub fn set_shadow_mode(&mut self, strategy_name: &str, shadow: bool) -> Result<()> {
+        let strategy = self.strategies.get_mut(strategy_name)
+            .ok_or_else(|| anyhow::anyhow!("Unknown strategy '{}'", strategy_name))?;
+        strategy.config_mut().shadow = shadow;
+        
+        if shadow {
+            info!("👻 Strategy '{}' switched to shadow mode", strategy_name);
+        } else {
+            info!("🚀 Strategy '{}' promoted from shadow to live execution", strategy_name);
+        }
+        Ok(())
+    }
+    
+    /// Names of strategies currently running in shadow mode
+    pub fn shadow_strategies(&self) -> Vec<String> {
+        self.strategies.iter()
+            .filter(|(_, strategy)| strategy.config().shadow)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+    
+    /// Shadow ledger with hypothetical trades and performance
+    pub fn get_shadow_ledger(&self) -> &ShadowLedger {
+        &self.shadow_ledger
+    }
+    
+    /// Compare a shadow strategy's hypothetical results with a live strategy
+    pub fn compare_shadow_to_live(&self, shadow_strategy: &str, live_strategy: &str) -> Result<ShadowComparison> {
+        let live = self.strategies.get(live_strategy)
+            .ok_or_else(|| anyhow::anyhow!("Unknown strategy '{}'", live_strategy))?;
+        let shadow_name = self.strategies.get(shadow_strategy)
+            .map(|s| s.name().to_string())
+            .ok_or_else(|| anyhow::anyhow!("Unknown strategy '{}'", shadow_strategy))?;
+        
+        Ok(self.shadow_ledger.compare(&shadow_name, live.performance()))
+    }
+    
+    /// Run an A/B experiment on a registered strategy
+    ///
+    /// The control parameters are applied to the strategy itself; the treatment
+    /// runs as a second instance of the same strategy, in shadow mode for
+    /// `LiveVsShadow` or live with its share of the strategy's capital for
+    /// `CapitalSplit`.
+    pub async fn start_experiment(&mut self, experiment: Experiment) -> Result<()> {
+        let base_key = experiment.strategy_name.clone();
+        let treatment_key = format!("{}@{}", base_key, experiment.treatment.name);
+        let base_config = self.strategies.get(&base_key)
+            .map(|strategy| strategy.config().clone())
+            .ok_or_else(|| anyhow::anyhow!("Unknown strategy '{}'", base_key))?;
+        if self.experiment_arms.contains_key(&base_key) || self.strategies.contains_key(&treatment_key) {
+            return Err(anyhow::anyhow!("Strategy '{}' is already running an experiment", base_key));
+        }
+        
+        let mut control_config = base_config.clone();
+        control_config.apply_parameters(&experiment.control.parameters)?;
+        let mut treatment_config = base_config;
+        treatment_config.apply_parameters(&experiment.treatment.parameters)?;
+        treatment_config.name = format!("{} [{}]", treatment_config.name, experiment.treatment.name);
+        treatment_config.shadow = experiment.mode == ExperimentMode::LiveVsShadow;
+        
+        let mut treatment = Self::build_strategy(&base_key).await?;
+        *treatment.config_mut() = treatment_config;
+        
+        let experiment_id = experiment.id.clone();
+        let mode = experiment.mode;
+        self.experiments.lock().start(experiment)?;
+        
+        if let Some(strategy) = self.strategies.get_mut(&base_key) {
+            *strategy.config_mut() = control_config;
+        }
+        if let ExperimentMode::CapitalSplit { treatment_fraction } = mode {
+            let fraction = treatment_fraction.clamp(0.0, 1.0);
+            let base_capital = self.allocated_capital.get(&base_key).copied().unwrap_or(0.0);
+            self.allocated_capital.insert(base_key.clone(), base_capital * (1.0 - fraction));
+            self.allocated_capital.insert(treatment_key.clone(), base_capital * fraction);
+        }
+        self.strategies.insert(treatment_key.clone(), treatment);
+        self.experiment_arms.insert(base_key, (experiment_id.clone(), VariantArm::Control));
+        self.experiment_arms.insert(treatment_key, (experiment_id, VariantArm::Treatment));
+        Ok(())
+    }
+    
+    /// Stop an experiment, retire its treatment instance and return the final report
+    pub fn stop_experiment(&mut self, experiment_id: &str) -> Option<ExperimentReport> {
+        let report = self.experiments.lock().stop(experiment_id)?;
+        let arms: Vec<(String, VariantArm)> = self.experiment_arms.iter()
+            .filter(|(_, (id, _))| id == experiment_id)
+            .map(|(key, (_, arm))| (key.clone(), *arm))
+            .collect();
+        
+        let mut treatment_capital = 0.0;
+        for (key, _) in arms.iter().filter(|(_, arm)| *arm == VariantArm::Treatment) {
+            self.strategies.remove(key);
+            treatment_capital += self.allocated_capital.remove(key).unwrap_or(0.0);
+        }
+        for (key, arm) in arms {
+            self.experiment_arms.remove(&key);
+            if arm == VariantArm::Control {
+                *self.allocated_capital.entry(key).or_insert(0.0) += treatment_capital;
+            }
+        }
+        
+        info!("🧪 Experiment '{}' stopped: {}", experiment_id, report.verdict());
+        Some(report)
+    }
+    
+    /// Reports for the experiments in this manager's registry
+    pub fn experiment_reports(&self) -> Vec<ExperimentReport> {
+        self.experiments.lock().reports()
+    }
+    
+    /// Fresh instance of a registered strategy kind
+    async fn build_strategy(key: &str) -> Result<Box<dyn TradingStrategy>> {
+        Ok(match key {
+            "arbitrage" => Box::new(ArbitrageStrategy::new().await.map_err(anyhow::Error::msg)?),
+            "momentum" => Box::new(MomentumStrategy::new()),
+            "mean_reversion" => Box::new(MeanReversionStrategy::new()),
+            other => return Err(anyhow::anyhow!("No strategy kind '{}' to run an experiment arm of", other)),
+        })
+    }
+    
+    /// Strategy keys whose capital-split arm was not assigned the current opportunity
+    fn experiment_arms_sitting_out(&self) -> HashSet<String> {
+        let mut experiments = self.experiments.lock();
+        let mut assigned: HashMap<&str, VariantArm> = HashMap::new();
+        let mut sitting_out = HashSet::new();
+        for (key, (experiment_id, arm)) in &self.experiment_arms {
+            let Some(experiment) = experiments.get_mut(experiment_id) else { continue };
+            if !matches!(experiment.mode, ExperimentMode::CapitalSplit { .. }) {
+                continue;
+            }
+            let chosen = *assigned.entry(experiment_id.as_str()).or_insert_with(|| experiment.assign_next());
+            if *arm != chosen {
+                sitting_out.insert(key.clone());
+            }
+        }
+        sitting_out
+    }
+    
+    /// Credit a closed trade to the experiment arm the strategy runs, if any
+    fn record_experiment_result(&self, strategy_key: &str, return_fraction: f64, profit: f64) {
+        if let Some((experiment_id, arm)) = self.experiment_arms.get(strategy_key) {
+            if let Err(e) = self.experiments.lock().record_result(experiment_id, *arm, return_fraction, profit) {
+                warn!("🧪 Could not record result for '{}': {}", strategy_key, e);
+            }
+        }
+    }
+    
     /// Reset daily loss tracking (should be called daily)
     pub fn reset_daily_tracking(&mut self) {
         self.current_daily_loss = 0.0;
@@ -619,6 +824,7 @@ impl StrategyManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::analytics::experiments::ExperimentVariant;
     use crate::config::SimpleConfig;
 
     fn create_test_config() -> SimpleConfig {
@@ -676,6 +882,81 @@ mod tests {
         assert_eq!(manager.global_performance.total_profit_loss, 100.0);
     }
 
+    #[tokio::test]
+    async fn test_shadow_mode_toggle() {
+        let config = create_test_config();
+        let mut manager = StrategyManager::new(config);
+        manager.initialize_strategies().await.unwrap();
+        
+        assert!(manager.shadow_strategies().is_empty());
+        manager.set_shadow_mode("momentum", true).unwrap();
+        assert_eq!(manager.shadow_strategies(), vec!["momentum".to_string()]);
+        
+        let comparison = manager.compare_shadow_to_live("momentum", "arbitrage").unwrap();
+        assert_eq!(comparison.shadow.total_trades, 0);
+        
+        manager.set_shadow_mode("momentum", false).unwrap();
+        assert!(manager.shadow_strategies().is_empty());
+        assert!(manager.set_shadow_mode("unknown", true).is_err());
+    }
+
+    fn variant(name: &str, min_confidence: f64) -> ExperimentVariant {
+        ExperimentVariant {
+            name: name.to_string(),
+            parameters: HashMap::from([("min_confidence".to_string(), min_confidence)]),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shadow_experiment_runs_treatment_as_second_instance() {
+        let mut manager = StrategyManager::new(create_test_config())
+            .with_experiments(Arc::new(Mutex::new(ExperimentManager::new())));
+        manager.initialize_strategies().await.unwrap();
+        
+        let experiment = Experiment::new("conf", "momentum", ExperimentMode::LiveVsShadow, variant("base", 0.7), variant("loose", 0.5));
+        manager.start_experiment(experiment).await.unwrap();
+        assert_eq!(manager.strategies["momentum"].config().min_confidence, 0.7);
+        assert_eq!(manager.strategies["momentum@loose"].config().min_confidence, 0.5);
+        assert_eq!(manager.shadow_strategies(), vec!["momentum@loose".to_string()]);
+        
+        let duplicate = Experiment::new("conf", "mean_reversion", ExperimentMode::LiveVsShadow, variant("a", 0.6), variant("b", 0.4));
+        assert!(manager.start_experiment(duplicate).await.is_err());
+        assert!(!manager.strategies.contains_key("mean_reversion@b"));
+        
+        // Live results of the strategy are credited to the control arm
+        let trade_result = TradeResult { profit_loss: 10.0, ..Default::default() };
+        manager.update_global_performance(&trade_result, "momentum").unwrap();
+        assert_eq!(manager.experiment_reports()[0].control_trades, 1);
+        
+        let report = manager.stop_experiment("conf").unwrap();
+        assert_eq!(report.control_trades, 1);
+        assert!(!manager.strategies.contains_key("momentum@loose"));
+        assert!(manager.experiment_reports().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_capital_split_experiment_alternates_arms_and_splits_capital() {
+        let mut manager = StrategyManager::new(create_test_config())
+            .with_experiments(Arc::new(Mutex::new(ExperimentManager::new())));
+        manager.initialize_strategies().await.unwrap();
+        let capital = manager.allocated_capital["mean_reversion"];
+        
+        let mode = ExperimentMode::CapitalSplit { treatment_fraction: 0.5 };
+        manager.start_experiment(Experiment::new("split", "mean_reversion", mode, variant("base", 0.7), variant("tight", 0.9))).await.unwrap();
+        assert!((manager.allocated_capital["mean_reversion@tight"] - capital * 0.5).abs() < 1e-9);
+        assert!(manager.shadow_strategies().is_empty());
+        
+        // Each opportunity goes to exactly one arm
+        let first = manager.experiment_arms_sitting_out();
+        let second = manager.experiment_arms_sitting_out();
+        assert_eq!((first.len(), second.len()), (1, 1));
+        assert_ne!(first, second);
+        
+        manager.stop_experiment("split").unwrap();
+        assert!((manager.allocated_capital["mean_reversion"] - capital).abs() < 1e-9);
+        assert!(manager.experiment_arms_sitting_out().is_empty());
+    }
+
     #[test]
     fn test_daily_loss_tracking() {
         let config = create_test_config();
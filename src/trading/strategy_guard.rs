@@ -0,0 +1,366 @@
+//! Strategy kill criteria
+//!
+//! Each strategy first builds a baseline from its early trades (win rate,
+//! mean and spread of PnL). After that, every outcome feeds two sequential
+//! detectors:
+//!
+//! - a sequential probability ratio test on wins/losses, testing the
+//!   baseline win rate against a degraded one, and
+//! - a one-sided CUSUM on standardized PnL, catching a drop in average
+//!   trade result even when the win rate holds.
+//!
+//! When either detector crosses its threshold the strategy is suspended
+//! until an operator re-enables it. Suspensions survive restarts, and both
+//! the analysis and the decisions are written to the audit log.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::security::{SecurityAuditEntry, SecurityEventType, SecuritySeverity};
+
+/// Detector thresholds
+#[derive(Debug, Clone)]
+pub struct KillCriteriaConfig {
+    /// Trades used to establish the baseline before monitoring starts
+    pub baseline_trades: u64,
+    /// Win-rate drop treated as "edge lost" by the SPRT
+    pub win_rate_drop: f64,
+    /// False suspension probability of the SPRT
+    pub alpha: f64,
+    /// Missed degradation probability of the SPRT
+    pub beta: f64,
+    /// CUSUM slack, in standard deviations of trade PnL
+    pub cusum_slack: f64,
+    /// CUSUM decision threshold, in standard deviations
+    pub cusum_threshold: f64,
+    pub state_path: PathBuf,
+}
+
+impl Default for KillCriteriaConfig {
+    fn default() -> Self {
+        Self {
+            baseline_trades: 30,
+            win_rate_drop: 0.15,
+            alpha: 0.01,
+            beta: 0.1,
+            cusum_slack: 0.5,
+            cusum_threshold: 5.0,
+            state_path: PathBuf::from("state/strategy_guard.json"),
+        }
+    }
+}
+
+/// Historical edge of a strategy
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StrategyBaseline {
+    pub trades: u64,
+    pub wins: u64,
+    pub pnl_mean: f64,
+    /// Sum of squared deviations (Welford)
+    pnl_m2: f64,
+}
+
+impl StrategyBaseline {
+    fn add(&mut self, pnl: f64) {
+        self.trades += 1;
+        if pnl > 0.0 {
+            self.wins += 1;
+        }
+        let delta = pnl - self.pnl_mean;
+        self.pnl_mean += delta / self.trades as f64;
+        self.pnl_m2 += delta * (pnl - self.pnl_mean);
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.trades == 0 { 0.0 } else { self.wins as f64 / self.trades as f64 }
+    }
+
+    pub fn pnl_std(&self) -> f64 {
+        if self.trades < 2 { 0.0 } else { (self.pnl_m2 / (self.trades - 1) as f64).sqrt() }
+    }
+}
+
+/// Which detector fired
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KillDetector {
+    Sprt,
+    Cusum,
+}
+
+/// Recorded suspension decision
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuspensionDecision {
+    pub strategy: String,
+    pub detector: KillDetector,
+    pub statistic: f64,
+    pub threshold: f64,
+    pub baseline_win_rate: f64,
+    pub recent_win_rate: f64,
+    pub baseline_pnl_mean: f64,
+    pub recent_pnl_mean: f64,
+    pub trades_monitored: u64,
+    pub suspended_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct GuardState {
+    baseline: StrategyBaseline,
+    /// SPRT log-likelihood ratio
+    llr: f64,
+    cusum: f64,
+    /// Outcomes since monitoring (re)started
+    recent: StrategyBaseline,
+    suspension: Option<SuspensionDecision>,
+}
+
+/// Per-strategy statistical kill switch
+#[derive(Debug)]
+pub struct StrategyKillSwitch {
+    config: KillCriteriaConfig,
+    states: RwLock<HashMap<String, GuardState>>,
+    audit_log: RwLock<Vec<SecurityAuditEntry>>,
+}
+
+impl StrategyKillSwitch {
+    /// Load persisted baselines and suspensions
+    pub fn new(config: KillCriteriaConfig) -> Self {
+        let states: HashMap<String, GuardState> = std::fs::read_to_string(&config.state_path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        for (strategy, state) in &states {
+            if state.suspension.is_some() {
+                warn!("🛑 Strategy '{}' remains suspended from a previous run (manual re-enable required)", strategy);
+            }
+        }
+        Self {
+            config,
+            states: RwLock::new(states),
+            audit_log: RwLock::new(Vec::new()),
+        }
+    }
+
+    pub fn is_suspended(&self, strategy: &str) -> bool {
+        self.states.read().get(strategy).is_some_and(|s| s.suspension.is_some())
+    }
+
+    pub fn suspensions(&self) -> Vec<SuspensionDecision> {
+        self.states.read().values().filter_map(|s| s.suspension.clone()).collect()
+    }
+
+    /// Feed one trade result; returns the decision if this outcome suspended the strategy
+    pub fn record_outcome(&self, strategy: &str, pnl: f64) -> Option<SuspensionDecision> {
+        let (decision, baseline_established) = {
+            let mut states = self.states.write();
+            let state = states.entry(strategy.to_string()).or_default();
+            if state.suspension.is_some() {
+                return None;
+            }
+            if state.baseline.trades < self.config.baseline_trades {
+                state.baseline.add(pnl);
+                (None, state.baseline.trades == self.config.baseline_trades)
+            } else {
+                (self.evaluate(strategy, state, pnl), false)
+            }
+        };
+
+        if baseline_established {
+            if let Some(state) = self.states.read().get(strategy) {
+                info!("📐 Strategy '{}' baseline established: win rate {:.1}%, mean PnL {:.4}",
+                      strategy, state.baseline.win_rate() * 100.0, state.baseline.pnl_mean);
+            }
+            self.persist();
+        }
+        if let Some(decision) = &decision {
+            warn!("🛑 Strategy '{}' SUSPENDED by {:?}: statistic {:.2} > {:.2} (win rate {:.1}% → {:.1}%)",
+                  strategy, decision.detector, decision.statistic, decision.threshold,
+                  decision.baseline_win_rate * 100.0, decision.recent_win_rate * 100.0);
+            self.audit(strategy, SecuritySeverity::Critical, "Strategy suspended by kill criteria", decision_metadata(decision), "system");
+            self.persist();
+        }
+        decision
+    }
+
+    fn evaluate(&self, strategy: &str, state: &mut GuardState, pnl: f64) -> Option<SuspensionDecision> {
+        state.recent.add(pnl);
+
+        // SPRT: H0 win rate p0 vs H1 degraded p1
+        let p0 = state.baseline.win_rate().clamp(0.05, 0.95);
+        let p1 = (p0 - self.config.win_rate_drop).max(0.01);
+        state.llr += if pnl > 0.0 { (p1 / p0).ln() } else { ((1.0 - p1) / (1.0 - p0)).ln() };
+        let upper = ((1.0 - self.config.beta) / self.config.alpha).ln();
+        let lower = (self.config.beta / (1.0 - self.config.alpha)).ln();
+        if state.llr <= lower {
+            // Evidence favours the baseline edge: restart the test
+            state.llr = 0.0;
+        }
+
+        // CUSUM on standardized PnL, accumulating shortfalls below the baseline mean
+        let std = state.baseline.pnl_std().max(1e-9);
+        let z = (pnl - state.baseline.pnl_mean) / std;
+        state.cusum = (state.cusum - z - self.config.cusum_slack).max(0.0);
+
+        let fired = if state.llr >= upper {
+            Some((KillDetector::Sprt, state.llr, upper))
+        } else if state.cusum >= self.config.cusum_threshold {
+            Some((KillDetector::Cusum, state.cusum, self.config.cusum_threshold))
+        } else {
+            None
+        };
+
+        let (detector, statistic, threshold) = fired?;
+        let decision = SuspensionDecision {
+            strategy: strategy.to_string(),
+            detector,
+            statistic,
+            threshold,
+            baseline_win_rate: state.baseline.win_rate(),
+            recent_win_rate: state.recent.win_rate(),
+            baseline_pnl_mean: state.baseline.pnl_mean,
+            recent_pnl_mean: state.recent.pnl_mean,
+            trades_monitored: state.recent.trades,
+            suspended_at: Utc::now(),
+        };
+        state.suspension = Some(decision.clone());
+        Some(decision)
+    }
+
+    /// Manually lift a suspension; detectors restart from the existing baseline
+    pub fn reenable(&self, strategy: &str, operator: &str) -> Result<()> {
+        let previous = {
+            let mut states = self.states.write();
+            let state = states.get_mut(strategy).ok_or_else(|| anyhow!("unknown strategy '{}'", strategy))?;
+            let previous = state.suspension.take().ok_or_else(|| anyhow!("strategy '{}' is not suspended", strategy))?;
+            state.llr = 0.0;
+            state.cusum = 0.0;
+            state.recent = StrategyBaseline::default();
+            previous
+        };
+
+        info!("✅ Strategy '{}' re-enabled by {}", strategy, operator);
+        let mut metadata = decision_metadata(&previous);
+        metadata.insert("suspended_for_minutes".to_string(), (Utc::now() - previous.suspended_at).num_minutes().to_string());
+        self.audit(strategy, SecuritySeverity::Warning, "Strategy manually re-enabled", metadata, operator);
+        self.persist();
+        Ok(())
+    }
+
+    pub fn get_audit_log(&self) -> Vec<SecurityAuditEntry> {
+        self.audit_log.read().clone()
+    }
+
+    fn audit(&self, strategy: &str, severity: SecuritySeverity, description: &str, mut metadata: HashMap<String, String>, actor: &str) {
+        metadata.insert("strategy".to_string(), strategy.to_string());
+        self.audit_log.write().push(SecurityAuditEntry {
+            timestamp: Utc::now(),
+            event_type: SecurityEventType::Audit,
+            component: "strategy_guard".to_string(),
+            severity,
+            description: description.to_string(),
+            metadata,
+            actor: Some(actor.to_string()),
+            ip_address: None,
+            build: crate::security::build_fingerprint(),
+        });
+    }
+
+    /// Write state atomically (temp file + rename)
+    fn persist(&self) {
+        let result = (|| -> Result<()> {
+            let path = &self.config.state_path;
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            let content = serde_json::to_string_pretty(&*self.states.read())?;
+            let temp_file = path.with_extension("tmp");
+            std::fs::write(&temp_file, content)?;
+            std::fs::rename(&temp_file, path)?;
+            Ok(())
+        })();
+        if let Err(e) = result {
+            warn!("⚠️ Failed to persist strategy guard state: {}", e);
+        }
+    }
+}
+
+impl Default for StrategyKillSwitch {
+    fn default() -> Self {
+        Self::new(KillCriteriaConfig::default())
+    }
+}
+
+fn decision_metadata(decision: &SuspensionDecision) -> HashMap<String, String> {
+    HashMap::from([
+        ("detector".to_string(), format!("{:?}", decision.detector)),
+        ("statistic".to_string(), format!("{:.4}", decision.statistic)),
+        ("threshold".to_string(), format!("{:.4}", decision.threshold)),
+        ("baseline_win_rate".to_string(), format!("{:.4}", decision.baseline_win_rate)),
+        ("recent_win_rate".to_string(), format!("{:.4}", decision.recent_win_rate)),
+        ("baseline_pnl_mean".to_string(), format!("{:.6}", decision.baseline_pnl_mean)),
+        ("recent_pnl_mean".to_string(), format!("{:.6}", decision.recent_pnl_mean)),
+        ("trades_monitored".to_string(), decision.trades_monitored.to_string()),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn guard(dir: &TempDir) -> StrategyKillSwitch {
+        StrategyKillSwitch::new(KillCriteriaConfig {
+            baseline_trades: 20,
+            state_path: dir.path().join("guard.json"),
+            ..Default::default()
+        })
+    }
+
+    /// 70% winners: +1.0 / -1.0
+    fn healthy_outcome(i: usize) -> f64 {
+        if i % 10 < 7 { 1.0 } else { -1.0 }
+    }
+
+    #[test]
+    fn test_healthy_strategy_keeps_running() {
+        let dir = TempDir::new().unwrap();
+        let guard = guard(&dir);
+        for i in 0..500 {
+            assert!(guard.record_outcome("arb", healthy_outcome(i)).is_none(), "suspended at trade {}", i);
+        }
+        assert!(!guard.is_suspended("arb"));
+    }
+
+    #[test]
+    fn test_loss_streak_suspends_until_manual_reenable() {
+        let dir = TempDir::new().unwrap();
+        let guard = guard(&dir);
+        for i in 0..20 {
+            guard.record_outcome("arb", healthy_outcome(i));
+        }
+
+        let decision = (0..30).find_map(|_| guard.record_outcome("arb", -1.0)).expect("suspended");
+        assert!(decision.trades_monitored < 30);
+        assert!(guard.is_suspended("arb"));
+
+        // Suspension survives a restart and is lifted only by an operator
+        let reloaded = StrategyKillSwitch::new(KillCriteriaConfig {
+            state_path: dir.path().join("guard.json"),
+            ..Default::default()
+        });
+        assert!(reloaded.is_suspended("arb"));
+        reloaded.reenable("arb", "ops").unwrap();
+        assert!(!reloaded.is_suspended("arb"));
+        assert!(reloaded.reenable("arb", "ops").is_err());
+
+        let audit = reloaded.get_audit_log();
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].actor.as_deref(), Some("ops"));
+        assert_eq!(guard.get_audit_log()[0].metadata.get("strategy").map(String::as_str), Some("arb"));
+    }
+}
@@ -0,0 +1,261 @@
+//! Per-token exposure caps
+//!
+//! The global max position size treats a blue chip and a day-old memecoin
+//! alike. Here each token gets a cap from its liquidity tier (absolute value
+//! and/or percent of portfolio), and individual mints can be overridden. The
+//! [`RiskManager`](super::risk::RiskManager) applies the resulting cap on top
+//! of its own position limit and reports remaining headroom per token.
+//!
+//! Assets are symbols or mints. Symbols are matched case-insensitively; mints
+//! are base58 and kept exactly as given (see [`asset_key`]).
+
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Key an asset is stored under: mints as given, symbols upper-cased
+///
+/// Upper-casing a base58 mint would turn it into a different (or invalid) address.
+pub fn asset_key(asset: &str) -> String {
+    if Pubkey::from_str(asset).is_ok() {
+        asset.to_string()
+    } else {
+        asset.to_uppercase()
+    }
+}
+
+/// Liquidity class of a token
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenTier {
+    Bluechip,
+    Mid,
+    /// Also the tier of tokens whose liquidity is unknown
+    LongTail,
+}
+
+/// Exposure cap; when both are set the tighter one applies
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExposureCap {
+    /// Absolute cap in exposure units (SOL or USDC value)
+    #[serde(default)]
+    pub max_value: Option<f64>,
+    /// Cap as % of portfolio value
+    #[serde(default)]
+    pub max_portfolio_percent: Option<f64>,
+}
+
+impl ExposureCap {
+    pub fn value(max_value: f64) -> Self {
+        Self { max_value: Some(max_value), max_portfolio_percent: None }
+    }
+
+    pub fn percent(max_portfolio_percent: f64) -> Self {
+        Self { max_value: None, max_portfolio_percent: Some(max_portfolio_percent) }
+    }
+
+    /// Effective cap given the portfolio value; `None` when uncapped
+    ///
+    /// A percent cap with no known portfolio value is not applied.
+    pub fn resolve(&self, portfolio_value: Option<f64>) -> Option<f64> {
+        let from_percent = self
+            .max_portfolio_percent
+            .zip(portfolio_value)
+            .map(|(percent, value)| value * percent / 100.0);
+        match (self.max_value, from_percent) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+/// Tier thresholds, tier defaults and per-mint overrides
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenLimitsConfig {
+    /// Pool liquidity (USD) from which a token is a blue chip
+    pub bluechip_min_liquidity_usd: f64,
+    /// Pool liquidity (USD) from which a token is mid tier
+    pub mid_min_liquidity_usd: f64,
+    pub bluechip: ExposureCap,
+    pub mid: ExposureCap,
+    pub long_tail: ExposureCap,
+    /// Caps for specific assets (symbol or mint), replacing their tier default
+    #[serde(default)]
+    pub overrides: HashMap<String, ExposureCap>,
+}
+
+impl Default for TokenLimitsConfig {
+    fn default() -> Self {
+        Self {
+            bluechip_min_liquidity_usd: 10_000_000.0,
+            mid_min_liquidity_usd: 500_000.0,
+            // The absolute caps hold until a portfolio value is known
+            bluechip: ExposureCap { max_value: Some(250.0), max_portfolio_percent: Some(50.0) },
+            mid: ExposureCap { max_value: Some(50.0), max_portfolio_percent: Some(20.0) },
+            long_tail: ExposureCap { max_value: Some(10.0), max_portfolio_percent: Some(5.0) },
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+impl TokenLimitsConfig {
+    pub fn tier_for_liquidity(&self, liquidity_usd: Option<f64>) -> TokenTier {
+        match liquidity_usd {
+            Some(liquidity) if liquidity >= self.bluechip_min_liquidity_usd => TokenTier::Bluechip,
+            Some(liquidity) if liquidity >= self.mid_min_liquidity_usd => TokenTier::Mid,
+            _ => TokenTier::LongTail,
+        }
+    }
+
+    pub fn tier_cap(&self, tier: TokenTier) -> &ExposureCap {
+        match tier {
+            TokenTier::Bluechip => &self.bluechip,
+            TokenTier::Mid => &self.mid,
+            TokenTier::LongTail => &self.long_tail,
+        }
+    }
+}
+
+/// Caps plus the market state they depend on
+#[derive(Debug, Clone, Default)]
+pub struct TokenLimits {
+    config: TokenLimitsConfig,
+    /// Keyed by [`asset_key`] like the risk manager's exposure book
+    liquidity_usd: HashMap<String, f64>,
+    overrides: HashMap<String, ExposureCap>,
+    portfolio_value: Option<f64>,
+}
+
+impl TokenLimits {
+    pub fn new(config: TokenLimitsConfig) -> Self {
+        let overrides = config.overrides.iter().map(|(asset, cap)| (asset_key(asset), cap.clone())).collect();
+        Self { config, liquidity_usd: HashMap::new(), overrides, portfolio_value: None }
+    }
+
+    pub fn config(&self) -> &TokenLimitsConfig {
+        &self.config
+    }
+
+    pub fn set_liquidity(&mut self, asset: &str, liquidity_usd: f64) {
+        self.liquidity_usd.insert(asset_key(asset), liquidity_usd);
+    }
+
+    pub fn set_portfolio_value(&mut self, value: f64) {
+        self.portfolio_value = (value > 0.0).then_some(value);
+    }
+
+    pub fn portfolio_value(&self) -> Option<f64> {
+        self.portfolio_value
+    }
+
+    pub fn set_override(&mut self, asset: &str, cap: ExposureCap) {
+        self.overrides.insert(asset_key(asset), cap);
+    }
+
+    pub fn remove_override(&mut self, asset: &str) -> Option<ExposureCap> {
+        self.overrides.remove(&asset_key(asset))
+    }
+
+    pub fn tier(&self, asset: &str) -> TokenTier {
+        self.config.tier_for_liquidity(self.liquidity_usd.get(&asset_key(asset)).copied())
+    }
+
+    /// Override for `asset` if one exists, else its tier default
+    pub fn cap(&self, asset: &str) -> &ExposureCap {
+        self.overrides
+            .get(&asset_key(asset))
+            .unwrap_or_else(|| self.config.tier_cap(self.tier(asset)))
+    }
+
+    pub fn has_override(&self, asset: &str) -> bool {
+        self.overrides.contains_key(&asset_key(asset))
+    }
+
+    /// Effective cap for `asset`; `None` when uncapped
+    pub fn limit(&self, asset: &str) -> Option<f64> {
+        self.cap(asset).resolve(self.portfolio_value)
+    }
+
+    /// Assets with an override or known liquidity
+    pub fn known_assets(&self) -> Vec<String> {
+        let mut assets: Vec<String> = self.overrides.keys().chain(self.liquidity_usd.keys()).cloned().collect();
+        assets.sort();
+        assets.dedup();
+        assets
+    }
+}
+
+/// Room left under one token's cap
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenHeadroom {
+    pub asset: String,
+    pub tier: TokenTier,
+    pub overridden: bool,
+    /// Tightest limit in force (token cap, position limit, restriction)
+    pub limit: f64,
+    /// Held plus reserved exposure (absolute)
+    pub exposure: f64,
+    pub remaining: f64,
+    pub utilization_percent: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tier_defaults_and_tighter_cap_wins() {
+        let config = TokenLimitsConfig {
+            long_tail: ExposureCap { max_value: Some(2.0), max_portfolio_percent: Some(5.0) },
+            ..Default::default()
+        };
+        let mut limits = TokenLimits::new(config);
+        limits.set_liquidity("SOL", 50_000_000.0);
+        limits.set_liquidity("WIF", 1_000_000.0);
+
+        assert_eq!(limits.tier("sol"), TokenTier::Bluechip);
+        assert_eq!(limits.tier("WIF"), TokenTier::Mid);
+        assert_eq!(limits.tier("NEWMEME"), TokenTier::LongTail);
+
+        // Percent caps need a portfolio value; until then the absolute fallback applies
+        assert_eq!(limits.limit("SOL"), Some(250.0));
+        assert_eq!(limits.limit("NEWMEME"), Some(2.0));
+
+        limits.set_portfolio_value(100.0);
+        assert_eq!(limits.limit("SOL"), Some(50.0));
+        assert_eq!(limits.limit("WIF"), Some(20.0));
+        assert_eq!(limits.limit("NEWMEME"), Some(2.0));
+        limits.set_portfolio_value(20.0);
+        assert_eq!(limits.limit("NEWMEME"), Some(1.0));
+    }
+
+    #[test]
+    fn test_override_replaces_tier_default() {
+        let mut config = TokenLimitsConfig::default();
+        config.overrides.insert("bonk".to_string(), ExposureCap::value(0.5));
+        let mut limits = TokenLimits::new(config);
+        limits.set_liquidity("BONK", 50_000_000.0);
+        limits.set_portfolio_value(100.0);
+
+        assert_eq!(limits.limit("BONK"), Some(0.5));
+        assert!(limits.has_override("Bonk"));
+        assert_eq!(limits.known_assets(), vec!["BONK".to_string()]);
+
+        limits.remove_override("BONK");
+        assert_eq!(limits.limit("BONK"), Some(50.0));
+    }
+
+    #[test]
+    fn test_mint_keys_stay_case_sensitive() {
+        const BONK_MINT: &str = "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263";
+        let mut limits = TokenLimits::new(TokenLimitsConfig::default());
+        limits.set_liquidity(BONK_MINT, 50_000_000.0);
+        limits.set_override("jup", ExposureCap::value(1.0));
+
+        assert_eq!(asset_key(BONK_MINT), BONK_MINT);
+        assert_eq!(limits.tier(BONK_MINT), TokenTier::Bluechip);
+        assert_eq!(limits.known_assets(), vec![BONK_MINT.to_string(), "JUP".to_string()]);
+        assert!(limits.has_override("Jup"));
+    }
+}
@@ -0,0 +1,306 @@
+//! Toxic token quarantine
+//!
+//! Some mints fail every trade for reasons no retry fixes: a transfer fee the
+//! quote did not account for, a freeze authority that froze our account, or a
+//! pool that lets you buy but never sell. Each such failure adds to a per-mint
+//! toxicity score; once the score crosses the threshold the mint is
+//! quarantined and every strategy sharing the quarantine skips it.
+//!
+//! Scores decay with a configurable half-life so one bad afternoon is
+//! forgotten, and quarantines expire. Mints that come back and fail again are
+//! quarantined for twice as long each time. State is persisted so restarts do
+//! not re-learn the same lessons with real money.
+//!
+//! Only failures attributable to the token count: network errors, stale
+//! quotes and slippage say nothing about the asset.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::types::constants::{SOL_MINT, USDC_MINT, USDT_MINT};
+
+/// Failures that indicate a toxic asset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TradeFailureKind {
+    /// Received less than quoted because of a transfer fee or tax
+    FeeOnTransfer,
+    /// Token account frozen by the mint's freeze authority
+    FrozenAccount,
+    /// Could buy but no route or liquidity to sell
+    NoSellLiquidity,
+}
+
+impl TradeFailureKind {
+    /// Toxicity added per failure
+    pub fn weight(self) -> f64 {
+        match self {
+            Self::FrozenAccount => 3.0,
+            Self::FeeOnTransfer => 1.5,
+            Self::NoSellLiquidity => 1.0,
+        }
+    }
+
+    /// Kind of a failure from its error message, `None` when the token is not to blame
+    pub fn classify(error: &str) -> Option<Self> {
+        let error = error.to_ascii_lowercase();
+        if error.contains("frozen") || error.contains("accountfrozen") {
+            Some(Self::FrozenAccount)
+        } else if error.contains("transfer fee") || error.contains("fee on transfer") || error.contains("transferfee") {
+            Some(Self::FeeOnTransfer)
+        } else if error.contains("no route") || error.contains("no_routes_found") || error.contains("could not find any route")
+            || error.contains("insufficient liquidity")
+        {
+            Some(Self::NoSellLiquidity)
+        } else {
+            None
+        }
+    }
+}
+
+/// Quarantine policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuarantineConfig {
+    /// Score at which a mint is quarantined
+    pub threshold: f64,
+    /// Hours for a score to halve without new failures
+    pub half_life_hours: f64,
+    /// First quarantine length; doubles for each repeat offence
+    pub quarantine_hours: f64,
+    pub max_quarantine_hours: f64,
+    /// Where quarantine state is persisted (in-memory when unset)
+    pub state_path: Option<PathBuf>,
+}
+
+impl Default for QuarantineConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 3.0,
+            half_life_hours: 24.0,
+            quarantine_hours: 24.0,
+            max_quarantine_hours: 24.0 * 30.0,
+            state_path: None,
+        }
+    }
+}
+
+/// What is known about one mint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToxicToken {
+    pub mint: String,
+    /// Toxicity as of `updated_at`
+    pub score: f64,
+    pub updated_at: DateTime<Utc>,
+    pub failures: HashMap<TradeFailureKind, u32>,
+    /// Strategies that reported failures
+    pub reported_by: Vec<String>,
+    pub quarantined_until: Option<DateTime<Utc>>,
+    /// Times this mint has been quarantined
+    pub offences: u32,
+}
+
+impl ToxicToken {
+    fn new(mint: &str, now: DateTime<Utc>) -> Self {
+        Self {
+            mint: mint.to_string(),
+            score: 0.0,
+            updated_at: now,
+            failures: HashMap::new(),
+            reported_by: Vec::new(),
+            quarantined_until: None,
+            offences: 0,
+        }
+    }
+
+    pub fn is_quarantined(&self, now: DateTime<Utc>) -> bool {
+        self.quarantined_until.is_some_and(|until| until > now)
+    }
+
+    fn decay(&mut self, half_life_hours: f64, now: DateTime<Utc>) {
+        let hours = (now - self.updated_at).num_seconds().max(0) as f64 / 3600.0;
+        if half_life_hours > 0.0 {
+            self.score *= 0.5f64.powf(hours / half_life_hours);
+        }
+        self.updated_at = now;
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct QuarantineState {
+    tokens: HashMap<String, ToxicToken>,
+}
+
+/// Shared toxic-token list
+#[derive(Debug)]
+pub struct TokenQuarantine {
+    config: QuarantineConfig,
+    state: Mutex<QuarantineState>,
+}
+
+impl Default for TokenQuarantine {
+    fn default() -> Self {
+        Self::new(QuarantineConfig::default())
+    }
+}
+
+impl TokenQuarantine {
+    /// Create the quarantine, restoring state from `state_path` when present
+    pub fn new(config: QuarantineConfig) -> Self {
+        let state = config
+            .state_path
+            .as_deref()
+            .and_then(|path| match Self::load(path) {
+                Ok(state) => Some(state),
+                Err(e) => {
+                    warn!("⚠️ Could not restore token quarantine from {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+        Self { config, state: Mutex::new(state) }
+    }
+
+    fn load(path: &Path) -> Result<QuarantineState> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(QuarantineState::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save(&self, state: &QuarantineState) {
+        let Some(path) = &self.config.state_path else { return };
+        let result = (|| -> Result<()> {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            let temp_file = path.with_extension("tmp");
+            std::fs::write(&temp_file, serde_json::to_string_pretty(state)?)?;
+            std::fs::rename(&temp_file, path)?;
+            Ok(())
+        })();
+        if let Err(e) = result {
+            warn!("⚠️ Failed to persist token quarantine: {}", e);
+        }
+    }
+
+    /// Record a failed trade on `mint`; returns `true` when it got quarantined
+    pub fn record_failure(&self, mint: &str, kind: TradeFailureKind, strategy: &str) -> bool {
+        self.record_failure_at(mint, kind, strategy, Utc::now())
+    }
+
+    pub fn record_failure_at(&self, mint: &str, kind: TradeFailureKind, strategy: &str, now: DateTime<Utc>) -> bool {
+        let mut state = self.state.lock();
+        let token = state.tokens.entry(mint.to_string()).or_insert_with(|| ToxicToken::new(mint, now));
+        token.decay(self.config.half_life_hours, now);
+        token.score += kind.weight();
+        *token.failures.entry(kind).or_default() += 1;
+        if !token.reported_by.iter().any(|s| s == strategy) {
+            token.reported_by.push(strategy.to_string());
+        }
+
+        let quarantined = !token.is_quarantined(now) && token.score >= self.config.threshold;
+        if quarantined {
+            let hours = (self.config.quarantine_hours * 2f64.powi(token.offences as i32)).min(self.config.max_quarantine_hours);
+            token.offences += 1;
+            token.quarantined_until = Some(now + Duration::seconds((hours * 3600.0) as i64));
+            token.score = 0.0;
+            warn!("☣️ Token {} quarantined for {:.0}h after {:?} (offence #{}, reported by {})",
+                  mint, hours, kind, token.offences, token.reported_by.join(", "));
+        }
+        self.save(&state);
+        quarantined
+    }
+
+    /// Record a failed trade from its error message, blaming the non-base side
+    ///
+    /// Failures the token is not responsible for are ignored.
+    pub fn record_trade_error(&self, input_mint: &str, output_mint: &str, error: &str, strategy: &str) -> bool {
+        let Some(kind) = TradeFailureKind::classify(error) else {
+            return false;
+        };
+        let mut quarantined = false;
+        for mint in [input_mint, output_mint].into_iter().filter(|mint| !is_base_mint(mint)) {
+            quarantined |= self.record_failure(mint, kind, strategy);
+        }
+        quarantined
+    }
+
+    pub fn is_quarantined(&self, mint: &str) -> bool {
+        self.is_quarantined_at(mint, Utc::now())
+    }
+
+    pub fn is_quarantined_at(&self, mint: &str, now: DateTime<Utc>) -> bool {
+        self.state.lock().tokens.get(mint).is_some_and(|token| token.is_quarantined(now))
+    }
+
+    /// Mints currently quarantined
+    pub fn quarantined(&self) -> Vec<ToxicToken> {
+        let now = Utc::now();
+        self.state.lock().tokens.values().filter(|token| token.is_quarantined(now)).cloned().collect()
+    }
+
+    /// Manually lift a quarantine and forget the mint's score
+    pub fn release(&self, mint: &str) -> bool {
+        let mut state = self.state.lock();
+        let released = state.tokens.remove(mint).is_some();
+        if released {
+            self.save(&state);
+        }
+        released
+    }
+}
+
+fn is_base_mint(mint: &str) -> bool {
+    [SOL_MINT, USDC_MINT, USDT_MINT].contains(&mint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOXIC: &str = "Toxic1111111111111111111111111111111111111";
+
+    #[test]
+    fn test_repeated_failures_quarantine_with_decay() {
+        let quarantine = TokenQuarantine::default();
+        let start = Utc::now();
+        // Two sell failures a week apart decay away
+        assert!(!quarantine.record_failure_at(TOXIC, TradeFailureKind::NoSellLiquidity, "arbitrage", start));
+        assert!(!quarantine.record_failure_at(TOXIC, TradeFailureKind::NoSellLiquidity, "arbitrage", start + Duration::days(7)));
+        assert!(!quarantine.is_quarantined_at(TOXIC, start + Duration::days(7)));
+
+        // Transfer fee surprises on top of a fresh failure cross the threshold
+        let now = start + Duration::days(7) + Duration::minutes(5);
+        assert!(!quarantine.record_failure_at(TOXIC, TradeFailureKind::FeeOnTransfer, "triangular", now));
+        assert!(quarantine.record_failure_at(TOXIC, TradeFailureKind::FeeOnTransfer, "triangular", now));
+        assert!(quarantine.is_quarantined_at(TOXIC, now + Duration::hours(23)));
+        assert!(!quarantine.is_quarantined_at(TOXIC, now + Duration::hours(25)));
+
+        // Repeat offence: twice as long
+        let later = now + Duration::hours(30);
+        assert!(quarantine.record_failure_at(TOXIC, TradeFailureKind::FrozenAccount, "sniper", later));
+        assert!(quarantine.is_quarantined_at(TOXIC, later + Duration::hours(47)));
+    }
+
+    #[test]
+    fn test_only_token_failures_count_and_state_persists() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = QuarantineConfig { state_path: Some(dir.path().join("quarantine.json")), ..Default::default() };
+        let quarantine = TokenQuarantine::new(config.clone());
+
+        assert!(!quarantine.record_trade_error(SOL_MINT, TOXIC, "RPC timeout", "arbitrage"));
+        assert!(quarantine.record_trade_error(TOXIC, SOL_MINT, "Transaction failed: AccountFrozen", "arbitrage"));
+        assert!(!quarantine.is_quarantined(SOL_MINT));
+
+        let restored = TokenQuarantine::new(config);
+        assert!(restored.is_quarantined(TOXIC));
+        assert!(restored.release(TOXIC));
+        assert!(!restored.is_quarantined(TOXIC));
+    }
+}
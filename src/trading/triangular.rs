@@ -3,9 +3,24 @@
 //! con protección anti-circular avanzada y cálculos de profit reales
 
 use anyhow::{anyhow, Result};
+use async_trait::async_trait;
 use tracing::{debug, info, warn};
 use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+
+use crate::apis::fiat_rates::{fiat_rates, FiatAsset};
+use crate::apis::program_registry::ProgramRegistry;
+use crate::apis::rpc_usage::{provider_for_url, rpc_usage};
+use crate::monitoring::latency_heatmap::latency_heatmap;
+use crate::types::{Expiring, IntoOpportunity, Opportunity, OpportunityKind, RouteHop, TtlPolicy, usd_opportunity};
+use super::route_matrix::{self, RateGraph};
+
+/// Grafos con al menos esta cantidad de tokens se recorren con la matriz de tasas
+const MATRIX_SEARCH_MIN_TOKENS: usize = 64;
 
 /// Respuesta de Jupiter Quote API
 #[derive(Debug, Deserialize)]
@@ -45,6 +60,24 @@ pub struct TriangularArbitrageConfig {
     pub max_execution_duration_ms: u64,
     /// Máximo número de DEXs involucrados
     pub max_dexs_involved: usize,
+    /// Consistencia de slot exigida a los estados de pool de una ruta
+    #[serde(default)]
+    pub slot_consistency: SlotConsistency,
+}
+
+/// Modo de lectura de los pools de una ruta triangular
+///
+/// Mezclar estados de pools de slots distintos crea oportunidades fantasma:
+/// un hop refleja un swap que otro hop todavía no ha visto.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SlotConsistency {
+    /// Usar las tasas del cache sin verificar slots
+    #[default]
+    Off,
+    /// Todas las tasas del cache deben llevar la misma etiqueta de slot
+    CachedSlots,
+    /// Leer todos los pools de la ruta en un único getMultipleAccounts
+    Snapshot,
 }
 
 impl Default for TriangularArbitrageConfig {
@@ -57,6 +90,7 @@ impl Default for TriangularArbitrageConfig {
             min_liquidity_usd: 50000.0,  // $50K mínimo
             max_execution_duration_ms: 30000, // 30 segundos
             max_dexs_involved: 3,
+            slot_consistency: SlotConsistency::Off,
         }
     }
 }
@@ -80,6 +114,8 @@ pub struct TriangularOpportunity {
     pub dexs_involved: Vec<String>,
     /// Duración estimada de ejecución en ms
     pub estimated_duration_ms: u64,
+    /// Fin de la ventana de validez (según volatilidad de la ruta)
+    pub expires_at: DateTime<Utc>,
 }
 
 /// Representa un hop individual en el path triangular
@@ -97,6 +133,148 @@ pub struct TokenHop {
     pub liquidity_usd: f64,
     /// Fee de swap en basis points
     pub swap_fee_bps: u16,
+    /// Slot del estado del pool usado para la tasa, si se conoce
+    #[serde(default)]
+    pub slot: Option<u64>,
+}
+
+/// Tasas de los pools de una ruta leídas en un mismo slot
+#[derive(Debug, Clone)]
+pub struct RouteSnapshot {
+    /// Slot del contexto de la lectura
+    pub slot: u64,
+    /// Tasa de cada par, en el orden solicitado
+    pub rates: Vec<f64>,
+}
+
+/// Fuente de lecturas atómicas de los pools de una ruta
+#[async_trait]
+pub trait PoolSnapshotReader: Send + Sync + std::fmt::Debug {
+    /// Leer los pools de todos los pares en una sola lectura
+    async fn read_route(&self, pairs: &[(String, String)]) -> Result<RouteSnapshot>;
+}
+
+/// Decodifica la tasa `from -> to` desde los datos de la cuenta del pool
+pub type PoolRateDecoder = fn(&[u8]) -> Option<f64>;
+
+/// Lector de rutas basado en `getMultipleAccounts`
+///
+/// Una única llamada RPC devuelve todas las cuentas con el slot del contexto,
+/// así que las tasas decodificadas pertenecen por construcción al mismo slot.
+pub struct RpcPoolSnapshotReader {
+    client: RpcClient,
+    provider: &'static str,
+    /// Pools sin decodificador propio se decodifican por su programa en el registro
+    pools: HashMap<(String, String), (Pubkey, Option<PoolRateDecoder>)>,
+    registry: Arc<ProgramRegistry>,
+}
+
+impl std::fmt::Debug for RpcPoolSnapshotReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RpcPoolSnapshotReader")
+            .field("rpc_url", &self.client.url())
+            .field("pools", &self.pools.len())
+            .finish()
+    }
+}
+
+impl RpcPoolSnapshotReader {
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            client: RpcClient::new_with_commitment(rpc_url.to_string(), CommitmentConfig::confirmed()),
+            provider: provider_for_url(rpc_url),
+            pools: HashMap::new(),
+            registry: Arc::new(ProgramRegistry::new()),
+        }
+    }
+
+    /// Compartir un registro de programas (con decodificadores añadidos en caliente)
+    pub fn with_registry(mut self, registry: Arc<ProgramRegistry>) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Registrar la cuenta del pool que cotiza `from -> to`
+    pub fn with_pool(mut self, from: &str, to: &str, account: Pubkey, decoder: PoolRateDecoder) -> Self {
+        self.pools.insert((from.to_string(), to.to_string()), (account, Some(decoder)));
+        self
+    }
+
+    /// Registrar un pool decodificado según la versión de layout de su programa
+    pub fn with_registered_pool(mut self, from: &str, to: &str, account: Pubkey) -> Self {
+        self.pools.insert((from.to_string(), to.to_string()), (account, None));
+        self
+    }
+}
+
+#[async_trait]
+impl PoolSnapshotReader for RpcPoolSnapshotReader {
+    async fn read_route(&self, pairs: &[(String, String)]) -> Result<RouteSnapshot> {
+        let pools = pairs
+            .iter()
+            .map(|pair| self.pools.get(pair).ok_or_else(|| anyhow!("Pool no registrado: {} -> {}", pair.0, pair.1)))
+            .collect::<Result<Vec<_>>>()?;
+        let keys: Vec<Pubkey> = pools.iter().map(|(key, _)| *key).collect();
+
+        crate::chaos::faults().rpc_call(self.provider, "getMultipleAccounts")?;
+        rpc_usage().record(self.provider, "getMultipleAccounts");
+        let started = std::time::Instant::now();
+        let response = self.client
+            .get_multiple_accounts_with_commitment(&keys, CommitmentConfig::confirmed())
+            .await;
+        latency_heatmap().record_rpc(self.provider, "getMultipleAccounts", started.elapsed(), response.is_ok());
+        let response = response?;
+        let rates = response.value
+            .iter()
+            .zip(pools.iter().zip(pairs))
+            .map(|(account, ((key, decoder), (from, to)))| {
+                let account = account.as_ref().ok_or_else(|| anyhow!("Cuenta de pool inexistente: {}", key))?;
+                match decoder {
+                    Some(decoder) => decoder(&account.data)
+                        .ok_or_else(|| anyhow!("No se pudo decodificar el pool {} -> {} ({})", from, to, key)),
+                    // Layout desconocido: se omite la ruta en lugar de leer datos erróneos
+                    None => self.registry.decode(&account.owner, &account.data)
+                        .map(|decoded| decoded.value)
+                        .map_err(|e| anyhow!("Pool {} -> {} ({}) omitido: {}", from, to, key, e)),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(RouteSnapshot { slot: response.context.slot, rates })
+    }
+}
+
+impl IntoOpportunity for TriangularOpportunity {
+    /// Sized at the path's liquidity constraint (USD); `estimated_net_profit` is per unit traded
+    fn to_opportunity(&self) -> Opportunity {
+        let mut route: Vec<RouteHop> = self.path
+            .iter()
+            .map(|hop| RouteHop::new(hop.from_token.clone(), hop.dex_name.clone()))
+            .collect();
+        if let Some(last) = self.path.last() {
+            route.push(RouteHop::new(last.to_token.clone(), ""));
+        }
+        usd_opportunity(
+            self.id.clone(),
+            OpportunityKind::Triangular,
+            route,
+            self.estimated_net_profit * self.liquidity_constraint,
+            self.liquidity_constraint,
+            1.0 - self.execution_risk_score,
+            Utc::now(),
+            self.expires_at,
+        )
+    }
+}
+
+impl Expiring for TriangularOpportunity {
+    fn opportunity_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
 }
 
 /// Motor de arbitraje triangular con protección anti-circular
@@ -108,6 +286,12 @@ pub struct TriangularArbitrageEngine {
     token_graph: HashMap<String, Vec<String>>,
     /// Cache de precios recientes
     price_cache: HashMap<(String, String), f64>,
+    /// Slot del estado de pool de cada precio del cache, si se conoce
+    price_slots: HashMap<(String, String), u64>,
+    /// Lector de snapshots para `SlotConsistency::Snapshot`
+    snapshot_reader: Option<Arc<dyn PoolSnapshotReader>>,
+    /// Evaluaciones rechazadas por mezclar slots
+    mixed_slot_rejections: usize,
     /// Detector de trades circulares
     circular_detector: CircularTradeDetector,
     /// Historial de ejecución para evitar repeticiones
@@ -169,11 +353,33 @@ impl TriangularArbitrageEngine {
             config,
             token_graph,
             price_cache: HashMap::new(),
+            price_slots: HashMap::new(),
+            snapshot_reader: None,
+            mixed_slot_rejections: 0,
             circular_detector: CircularTradeDetector::new(),
             execution_history: Vec::new(),
         }
     }
 
+    /// Usar `reader` para las lecturas de `SlotConsistency::Snapshot`
+    pub fn with_snapshot_reader(mut self, reader: Arc<dyn PoolSnapshotReader>) -> Self {
+        self.snapshot_reader = Some(reader);
+        self
+    }
+
+    /// Cachear una tasa sin slot conocido (cotizaciones agregadas, estimaciones)
+    fn cache_unslotted_rate(&mut self, key: (String, String), rate: f64) {
+        self.price_slots.remove(&key);
+        self.price_cache.insert(key, rate);
+    }
+
+    /// Registrar una tasa leída del estado de un pool en `slot`
+    pub fn record_pool_rate(&mut self, from: &str, to: &str, rate: f64, slot: u64) {
+        let key = (from.to_string(), to.to_string());
+        self.price_cache.insert(key.clone(), rate);
+        self.price_slots.insert(key, slot);
+    }
+
     /// Detectar oportunidades triangulares reales
     pub async fn find_triangular_opportunities(&mut self) -> Result<Vec<TriangularOpportunity>> {
         if !self.config.enabled {
@@ -188,27 +394,36 @@ impl TriangularArbitrageEngine {
         self.update_price_cache().await?;
         
         // Buscar paths triangulares viables
-        for start_token in ["SOL", "USDC", "RAY"] { // Tokens de inicio más líquidos
-            if let Ok(triangular_paths) = self.generate_triangular_paths(start_token) {
-                for path in triangular_paths {
-                    // Verificar protección anti-circular
-                    if !self.circular_detector.is_safe_path(&path) {
-                        debug!("⚠️ Path rechazado por detector circular: {:?}", path);
-                        continue;
-                    }
+        let candidate_paths: Vec<Vec<String>> = if self.token_graph.len() >= MATRIX_SEARCH_MIN_TOKENS {
+            self.matrix_candidate_paths()
+        } else {
+            ["SOL", "USDC", "RAY"] // Tokens de inicio más líquidos
+                .iter()
+                .filter_map(|start_token| self.generate_triangular_paths(start_token).ok())
+                .flatten()
+                .collect()
+        };
+        for path in candidate_paths {
+            // Verificar protección anti-circular
+            if !self.circular_detector.is_safe_path(&path) {
+                debug!("⚠️ Path rechazado por detector circular: {:?}", path);
+                continue;
+            }
+            
+            // Calcular profit neto real
+            let evaluation = self.calculate_triangular_profit(&path).await;
+            if let Err(e) = &evaluation {
+                debug!("⚠️ Path descartado {:?}: {}", path, e);
+            }
+            if let Ok(opportunity) = evaluation {
+                if opportunity.estimated_net_profit > self.config.min_profit_threshold && 
+                   opportunity.total_cost_bps < self.config.max_cost_bps && 
+                   opportunity.execution_risk_score < self.config.max_execution_risk_score &&
+                   opportunity.liquidity_constraint > self.config.min_liquidity_usd {
                     
-                    // Calcular profit neto real
-                    if let Ok(opportunity) = self.calculate_triangular_profit(&path).await {
-                        if opportunity.estimated_net_profit > self.config.min_profit_threshold && 
-                           opportunity.total_cost_bps < self.config.max_cost_bps && 
-                           opportunity.execution_risk_score < self.config.max_execution_risk_score &&
-                           opportunity.liquidity_constraint > self.config.min_liquidity_usd {
-                            
-                            info!("✅ Oportunidad triangular: {:.4}% profit neto", 
-                                  opportunity.estimated_net_profit * 100.0);
-                            opportunities.push(opportunity);
-                        }
-                    }
+                    info!("✅ Oportunidad triangular: {:.4}% profit neto", 
+                          opportunity.estimated_net_profit * 100.0);
+                    opportunities.push(opportunity);
                 }
             }
         }
@@ -220,6 +435,18 @@ impl TriangularArbitrageEngine {
         Ok(opportunities)
     }
 
+    /// Paths con retorno bruto positivo según la matriz de tasas cacheadas, desde cualquier token
+    fn matrix_candidate_paths(&self) -> Vec<Vec<String>> {
+        let graph = RateGraph::from_rates(self.price_cache.iter().map(|((from, to), rate)| (from.as_str(), to.as_str(), *rate)));
+        let paths: Vec<Vec<String>> = route_matrix::scan(&graph, 0.0)
+            .iter()
+            .map(|candidate| graph.path(candidate))
+            .filter(|path| self.is_valid_triangular_path(path))
+            .collect();
+        debug!("🧮 Matriz de {} tokens: {} paths triangulares con retorno bruto positivo", graph.len(), paths.len());
+        paths
+    }
+
     /// Generar paths triangulares válidos desde un token base
     fn generate_triangular_paths(&self, start_token: &str) -> Result<Vec<Vec<String>>> {
         let mut valid_paths = Vec::new();
@@ -285,12 +512,53 @@ impl TriangularArbitrageEngine {
         true
     }
 
+    /// Tasas y slots de los 3 hops según el modo de consistencia configurado
+    ///
+    /// Rechaza la evaluación si los estados de pool no pertenecen al mismo slot.
+    async fn read_route_rates(&mut self, path: &[String]) -> Result<Vec<(f64, Option<u64>)>> {
+        let pairs: Vec<(String, String)> = path.windows(2).map(|w| (w[0].clone(), w[1].clone())).collect();
+
+        let rates = match self.config.slot_consistency {
+            SlotConsistency::Off => pairs
+                .iter()
+                .map(|(from, to)| Ok((self.get_cached_rate(from, to)?, self.price_slots.get(&(from.clone(), to.clone())).copied())))
+                .collect::<Result<Vec<_>>>()?,
+            SlotConsistency::Snapshot => {
+                let reader = self.snapshot_reader.clone()
+                    .ok_or_else(|| anyhow!("Modo snapshot sin lector de pools configurado"))?;
+                let snapshot = reader.read_route(&pairs).await?;
+                if snapshot.rates.len() != pairs.len() {
+                    return Err(anyhow!("Snapshot incompleto: {} de {} pools", snapshot.rates.len(), pairs.len()));
+                }
+                for ((from, to), rate) in pairs.iter().zip(&snapshot.rates) {
+                    self.record_pool_rate(from, to, *rate, snapshot.slot);
+                }
+                snapshot.rates.iter().map(|rate| (*rate, Some(snapshot.slot))).collect()
+            }
+            SlotConsistency::CachedSlots => {
+                let rates = pairs
+                    .iter()
+                    .map(|(from, to)| Ok((self.get_cached_rate(from, to)?, self.price_slots.get(&(from.clone(), to.clone())).copied())))
+                    .collect::<Result<Vec<_>>>()?;
+                let slots: HashSet<Option<u64>> = rates.iter().map(|(_, slot)| *slot).collect();
+                if slots.len() != 1 || slots.contains(&None) {
+                    self.mixed_slot_rejections += 1;
+                    return Err(anyhow!("Estados de pool de slots distintos en {:?}: {:?}", path, slots));
+                }
+                rates
+            }
+        };
+        Ok(rates)
+    }
+
     /// Calcular profit real de oportunidad triangular
-    async fn calculate_triangular_profit(&self, path: &[String]) -> Result<TriangularOpportunity> {
+    async fn calculate_triangular_profit(&mut self, path: &[String]) -> Result<TriangularOpportunity> {
         if path.len() != 4 {
             return Err(anyhow!("Path inválido para cálculo triangular"));
         }
         
+        let route_rates = self.read_route_rates(path).await?;
+        
         let mut total_amount = 1.0; // Empezar con 1 unidad del token base
         let mut total_cost_bps = 0u16;
         let mut hops = Vec::new();
@@ -302,8 +570,8 @@ impl TriangularArbitrageEngine {
             let from_token = &path[i];
             let to_token = &path[i + 1];
             
-            // Obtener tasa de cambio real
-            let exchange_rate = self.get_cached_rate(from_token, to_token)?;
+            // Tasa de cambio leída con la consistencia de slot configurada
+            let (exchange_rate, slot) = route_rates[i];
             
             // Estimar fees del DEX (basado en par de tokens)
             let (dex_name, swap_fee_bps) = self.estimate_best_dex_for_pair(from_token, to_token);
@@ -322,6 +590,7 @@ impl TriangularArbitrageEngine {
                 exchange_rate,
                 liquidity_usd: liquidity,
                 swap_fee_bps,
+                slot,
             });
             
             dexs_involved.push(dex_name);
@@ -345,6 +614,8 @@ impl TriangularArbitrageEngine {
             execution_risk_score: risk_score.min(1.0),
             dexs_involved,
             estimated_duration_ms: (total_cost_bps as u64 * 1000) + 5000, // Base 5s + complejidad
+            // Desalineación del ciclo como proxy de volatilidad de la ruta
+            expires_at: TtlPolicy::default().expires_at(Utc::now(), net_profit * 100.0),
         })
     }
 
@@ -361,7 +632,7 @@ impl TriangularArbitrageEngine {
         let mut updated_pairs = 0;
         for (from, to) in critical_pairs {
             if let Ok(price) = self.get_single_jupiter_price(from, to).await {
-                self.price_cache.insert((from.to_string(), to.to_string()), price);
+                self.cache_unslotted_rate((from.to_string(), to.to_string()), price);
                 updated_pairs += 1;
                 
                 // Rate limiting más conservador
@@ -413,8 +684,8 @@ impl TriangularArbitrageEngine {
             let usdc_ray_rate = sol_ray_rate / sol_usdc_rate;
             let ray_usdc_rate = 1.0 / usdc_ray_rate;
             
-            self.price_cache.insert(("USDC".to_string(), "RAY".to_string()), usdc_ray_rate);
-            self.price_cache.insert(("RAY".to_string(), "USDC".to_string()), ray_usdc_rate);
+            self.cache_unslotted_rate(("USDC".to_string(), "RAY".to_string()), usdc_ray_rate);
+            self.cache_unslotted_rate(("RAY".to_string(), "USDC".to_string()), ray_usdc_rate);
             
             debug!("📊 Estimado USDC/RAY: {:.6}", usdc_ray_rate);
             debug!("📊 Estimado RAY/USDC: {:.6}", ray_usdc_rate);
@@ -423,7 +694,7 @@ impl TriangularArbitrageEngine {
         // Si tenemos USDC/SOL, usarlo; si no, calcularlo desde SOL/USDC
         if usdc_sol.is_none() && sol_usdc.is_some() {
             let usdc_sol_rate = 1.0 / sol_usdc.unwrap();
-            self.price_cache.insert(("USDC".to_string(), "SOL".to_string()), usdc_sol_rate);
+            self.cache_unslotted_rate(("USDC".to_string(), "SOL".to_string()), usdc_sol_rate);
             debug!("📊 Estimado USDC/SOL: {:.6}", usdc_sol_rate);
         }
         
@@ -441,7 +712,7 @@ impl TriangularArbitrageEngine {
         
         for ((from, to), rate) in estimated_prices {
             if !self.price_cache.contains_key(&(from.to_string(), to.to_string())) {
-                self.price_cache.insert((from.to_string(), to.to_string()), rate);
+                self.cache_unslotted_rate((from.to_string(), to.to_string()), rate);
                 debug!("📊 Estimado {}/{}: {:.6}", from, to, rate);
             }
         }
@@ -539,15 +810,17 @@ impl TriangularArbitrageEngine {
             // ✅ ENRIQUECIMIENTO: Implementar obtención real de precios desde price feeds
             match self.get_real_price_from_feeds(base, quote).await {
                 Ok(real_price) => {
-                    self.price_cache.insert((base.to_string(), quote.to_string()), real_price);
+                    self.cache_unslotted_rate((base.to_string(), quote.to_string()), real_price);
                     debug!("📊 Real price cached: {}/{} = {:.6}", base, quote, real_price);
                 }
                 Err(e) => {
-                    warn!("⚠️ Failed to get real price for {}/{}: {}. Using estimation fallback", base, quote, e);
-                    // Fallback to existing estimation logic (preserving functionality)
-                    if let Some(estimated_price) = self.get_estimated_price_fallback(base, quote) {
-                        self.price_cache.insert((base.to_string(), quote.to_string()), estimated_price);
-                        debug!("📊 Estimated price cached: {}/{} = {:.6}", base, quote, estimated_price);
+                    warn!("⚠️ Failed to get real price for {}/{}: {}. Using fiat rate fallback", base, quote, e);
+                    match self.get_estimated_price_fallback(base, quote).await {
+                        Some(estimated_price) => {
+                            self.cache_unslotted_rate((base.to_string(), quote.to_string()), estimated_price);
+                            debug!("📊 Fiat-rate price cached: {}/{} = {:.6}", base, quote, estimated_price);
+                        }
+                        None => debug!("📊 No fallback price for {}/{} - pair left out of the graph", base, quote),
                     }
                 }
             }
@@ -564,6 +837,7 @@ impl TriangularArbitrageEngine {
             cached_prices: self.price_cache.len(),
             suspicious_patterns: self.circular_detector.suspicious_patterns.len(),
             token_graph_size: self.token_graph.len(),
+            mixed_slot_rejections: self.mixed_slot_rejections,
             config: self.config.clone(),
         }
     }
@@ -687,22 +961,16 @@ impl TriangularArbitrageEngine {
         Err(anyhow!("Cross-rate calculation failed for {}/{}", base, quote))
     }
 
-    /// Fallback a estimaciones existentes (preservando funcionalidad original)
-    fn get_estimated_price_fallback(&self, base: &str, quote: &str) -> Option<f64> {
-        // Usar las estimaciones hardcoded existentes como fallback
-        match (base, quote) {
-            ("SOL", "USDC") => Some(160.0),
-            ("USDC", "SOL") => Some(1.0 / 160.0),
-            ("SOL", "RAY") => Some(50.0),
-            ("RAY", "SOL") => Some(1.0 / 50.0),
-            ("USDC", "RAY") => Some(160.0 / 50.0),
-            ("RAY", "USDC") => Some(50.0 / 160.0),
-            ("SOL", "JUP") => Some(140.0),
-            ("JUP", "SOL") => Some(1.0 / 140.0),
-            ("USDC", "JUP") => Some(0.86),
-            ("JUP", "USDC") => Some(1.0 / 0.86),
-            _ => None,
+    /// Fallback para SOL/USDC desde la tasa fiat compartida; sin precio fijo para el resto de pares
+    async fn get_estimated_price_fallback(&self, base: &str, quote: &str) -> Option<f64> {
+        let sol_usd = match (base, quote) {
+            ("SOL", "USDC") | ("USDC", "SOL") => fiat_rates().get_rate(FiatAsset::Sol).await.ok()?.usd,
+            _ => return None,
+        };
+        if sol_usd <= 0.0 {
+            return None;
         }
+        Some(if base == "SOL" { sol_usd } else { 1.0 / sol_usd })
     }
 
     /// Mapeo de tokens a sus mint addresses
@@ -777,12 +1045,18 @@ pub struct TriangularStats {
     pub cached_prices: usize,
     pub suspicious_patterns: usize,
     pub token_graph_size: usize,
+    /// Evaluaciones rechazadas por mezclar estados de pool de slots distintos
+    pub mixed_slot_rejections: usize,
     pub config: TriangularArbitrageConfig,
 }
 
 /// Función de utilidad para ejecutar arbitraje triangular
 pub async fn execute_triangular_arbitrage(opportunity: &TriangularOpportunity) -> Result<String> {
     info!("🚀 Executing enhanced triangular arbitrage for opportunity: {}", opportunity.id);
+    if let Err(expired) = opportunity.ensure_fresh(Utc::now()) {
+        warn!("⌛ Triangular execution rejected: {}", expired);
+        return Err(expired.into());
+    }
 
     // ✅ ENRIQUECIMIENTO: Implementación real de arbitraje triangular
     match execute_real_triangular_sequence(opportunity).await {
@@ -1011,4 +1285,55 @@ mod tests {
         // Intentar el mismo path dos veces
         assert!(!detector.is_safe_path(&safe_path), "Path repetido debe ser rechazado");
     }
+
+    fn route() -> Vec<String> {
+        ["SOL", "USDC", "RAY", "SOL"].iter().map(|t| t.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_cached_slots_reject_mixed_slot_evaluation() {
+        let config = TriangularArbitrageConfig { slot_consistency: SlotConsistency::CachedSlots, ..Default::default() };
+        let mut engine = TriangularArbitrageEngine::new(Some(config));
+        engine.record_pool_rate("SOL", "USDC", 150.0, 100);
+        engine.record_pool_rate("USDC", "RAY", 0.5, 100);
+        engine.record_pool_rate("RAY", "SOL", 0.0135, 101);
+
+        assert!(engine.calculate_triangular_profit(&route()).await.is_err(), "Slots mezclados deben rechazarse");
+        assert_eq!(engine.get_statistics().mixed_slot_rejections, 1);
+
+        engine.record_pool_rate("RAY", "SOL", 0.0135, 100);
+        let opportunity = engine.calculate_triangular_profit(&route()).await.unwrap();
+        assert!(opportunity.path.iter().all(|hop| hop.slot == Some(100)));
+
+        // Una tasa sin slot (cotización agregada) tampoco es consistente
+        engine.cache_unslotted_rate(("USDC".to_string(), "RAY".to_string()), 0.5);
+        assert!(engine.calculate_triangular_profit(&route()).await.is_err());
+    }
+
+    #[derive(Debug, Default)]
+    struct FixedSnapshot {
+        reads: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl PoolSnapshotReader for FixedSnapshot {
+        async fn read_route(&self, pairs: &[(String, String)]) -> Result<RouteSnapshot> {
+            self.reads.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(RouteSnapshot { slot: 42, rates: vec![150.0, 0.5, 0.0135][..pairs.len()].to_vec() })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_mode_reads_route_once_at_one_slot() {
+        let reader = Arc::new(FixedSnapshot::default());
+        let config = TriangularArbitrageConfig { slot_consistency: SlotConsistency::Snapshot, ..Default::default() };
+        let mut engine = TriangularArbitrageEngine::new(Some(config)).with_snapshot_reader(reader.clone());
+        // Tasas viejas en cache que el snapshot debe ignorar
+        engine.record_pool_rate("SOL", "USDC", 140.0, 7);
+
+        let opportunity = engine.calculate_triangular_profit(&route()).await.unwrap();
+        assert_eq!(reader.reads.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert!(opportunity.path.iter().all(|hop| hop.slot == Some(42)));
+        assert_eq!(opportunity.path[0].exchange_rate, 150.0);
+    }
 }
@@ -1,6 +1,7 @@
 //! Unified Routing System - Coordinates Strategic and Real-time Route Data
 //! Combines historical optimization with live market execution
 
+use tracing::info;
 use anyhow::Result;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -318,13 +319,13 @@ impl UnifiedRoutingSystem {
 
     /// Display unified system dashboard
     pub async fn display_unified_dashboard(&self) {
-        println!("\n╔══════════════════════════════════════════════════════════════════════════════╗");
-        println!("║                         UNIFIED ROUTING SYSTEM DASHBOARD                        ║");
-        println!("╠══════════════════════════════════════════════════════════════════════════════╣");
+        info!("╔══════════════════════════════════════════════════════════════════════════════╗");
+        info!("║                         UNIFIED ROUTING SYSTEM DASHBOARD                        ║");
+        info!("╠══════════════════════════════════════════════════════════════════════════════╣");
         
         // Strategic engine stats
         let strategic_settings = self.strategic_engine.get_optimization_settings();
-        println!("║ 📊 Strategic Routes: {} │ ML Optimization: {} │ Sentiment: {}        ║",
+        info!("║ 📊 Strategic Routes: {} │ ML Optimization: {} │ Sentiment: {}        ║",
                  15, // From strategic config
                  if strategic_settings.ml_route_optimization { "✅" } else { "❌" },
                  if strategic_settings.sentiment_based_routing { "✅" } else { "❌" });
@@ -333,7 +334,7 @@ impl UnifiedRoutingSystem {
         {
             let monitor = self.realtime_monitor.read().await;
             if let Some((cache_ratio, calc_time, avg_profit)) = monitor.get_performance_stats() {
-                println!("║ ⚡ Real-time: Cache {:.1}% │ Calc: {}ms │ Avg Profit: {:.3}%      ║",
+                info!("║ ⚡ Real-time: Cache {:.1}% │ Calc: {}ms │ Avg Profit: {:.3}%      ║",
                          cache_ratio * 100.0, calc_time, avg_profit * 100.0);
             }
         }
@@ -346,27 +347,27 @@ impl UnifiedRoutingSystem {
             0.0
         };
         
-        println!("║ 🎯 Executions: {} │ Success: {:.1}% │ Total Profit: ${:.2}        ║",
+        info!("║ 🎯 Executions: {} │ Success: {:.1}% │ Total Profit: ${:.2}        ║",
                  total_executions, success_rate, self.performance_tracker.total_profit);
-        println!("║ ⏱️ Avg Execution: {:.1}ms │ Cache Size: {}                           ║",
+        info!("║ ⏱️ Avg Execution: {:.1}ms │ Cache Size: {}                           ║",
                  self.performance_tracker.avg_execution_time_ms, self.decision_cache.len());
         
-        println!("╠══════════════════════════════════════════════════════════════════════════════╣");
+        info!("╠══════════════════════════════════════════════════════════════════════════════╣");
         
         // Last decision info
         if let Some(decision) = &self.last_decision {
-            println!("║ 🏆 LAST DECISION:                                                           ║");
-            println!("║   Route: {}                                           ║",
+            info!("║ 🏆 LAST DECISION:                                                           ║");
+            info!("║   Route: {}                                           ║",
                      decision.selected_route.strategic_route.route.join(" → "));
-            println!("║   Reason: {}                                          ║",
+            info!("║   Reason: {}                                          ║",
                      &decision.reason[..60.min(decision.reason.len())]);
-            println!("║   Risk: {:.1}% │ Profit Est: ${:.2} │ Window: {}s                    ║",
+            info!("║   Risk: {:.1}% │ Profit Est: ${:.2} │ Window: {}s                    ║",
                      (1.0 - decision.risk_assessment) * 100.0,
                      decision.profit_estimate,
                      decision.execution_window_seconds);
         }
         
-        println!("╚══════════════════════════════════════════════════════════════════════════════╝");
+        info!("╚══════════════════════════════════════════════════════════════════════════════╝");
     }
 }
 
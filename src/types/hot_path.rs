@@ -0,0 +1,210 @@
+//! Hot-path constants frozen at build time
+//!
+//! Token decimals, program IDs and market tick sizes are read on every quote
+//! and every decode. They are generated by `build.rs` from the canonical
+//! registry `config/hot_path_registry.json`, which is validated at compile
+//! time (base58 32-byte keys, unique names/addresses, sane decimals and tick
+//! sizes), so lookups are string `match`es instead of config maps.
+//!
+//! Freezing trades flexibility for speed and consistency; the constants can
+//! still drift from the chain (a wrong mint in the registry, a migrated
+//! market). [`verify_on_chain`] checks them against live accounts and runs as
+//! part of the startup self-test:
+//!
+//! - token mints: owned by the SPL Token (or Token-2022) program, decimals match
+//! - programs: exist and are executable
+//! - markets: owned by their venue program
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// A token of the registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenConstant {
+    pub symbol: &'static str,
+    pub mint: &'static str,
+    pub decimals: u8,
+}
+
+/// A program of the registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgramConstant {
+    pub name: &'static str,
+    pub program_id: &'static str,
+}
+
+/// A CLOB market of the registry
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarketConstant {
+    pub name: &'static str,
+    pub address: &'static str,
+    /// Venue program owning the market account
+    pub program_id: &'static str,
+    pub base_mint: &'static str,
+    pub quote_mint: &'static str,
+    pub tick_size: f64,
+}
+
+include!(concat!(env!("OUT_DIR"), "/hot_path_constants.rs"));
+
+/// SPL mint layout: mint authority (COption<Pubkey>, 36 bytes), supply (u64), then decimals
+const MINT_DECIMALS_OFFSET: usize = 44;
+
+/// What verification needs from an on-chain account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainAccount {
+    pub owner: String,
+    pub executable: bool,
+    pub data: Vec<u8>,
+}
+
+/// A constant that disagrees with the chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConstantMismatch {
+    pub name: String,
+    pub address: String,
+    pub problem: String,
+}
+
+/// Outcome of checking the constants against chain data
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HotPathVerification {
+    pub fingerprint: String,
+    pub checked: usize,
+    pub mismatches: Vec<ConstantMismatch>,
+}
+
+impl HotPathVerification {
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// Check every registry constant against `accounts` (address → account, absent when missing)
+pub fn verify(accounts: &HashMap<String, ChainAccount>) -> HotPathVerification {
+    let mut report = HotPathVerification { fingerprint: REGISTRY_FINGERPRINT.to_string(), ..Default::default() };
+    let mut mismatch = |name: &str, address: &str, problem: String| {
+        report.mismatches.push(ConstantMismatch { name: name.to_string(), address: address.to_string(), problem });
+    };
+
+    for token in TOKENS {
+        match accounts.get(token.mint) {
+            None => mismatch(token.symbol, token.mint, "mint account not found".to_string()),
+            Some(account) if account.owner != programs::SPL_TOKEN && account.owner != programs::TOKEN_2022 => {
+                mismatch(token.symbol, token.mint, format!("not a token mint (owner {})", account.owner))
+            }
+            Some(account) => match account.data.get(MINT_DECIMALS_OFFSET) {
+                Some(decimals) if *decimals == token.decimals => {}
+                Some(decimals) => mismatch(token.symbol, token.mint, format!("decimals {} on chain, {} in the registry", decimals, token.decimals)),
+                None => mismatch(token.symbol, token.mint, format!("mint data is {} bytes", account.data.len())),
+            },
+        }
+    }
+    for program in PROGRAMS {
+        match accounts.get(program.program_id) {
+            None => mismatch(program.name, program.program_id, "program account not found".to_string()),
+            Some(account) if !account.executable => mismatch(program.name, program.program_id, "account is not executable".to_string()),
+            Some(_) => {}
+        }
+    }
+    for market in MARKETS {
+        match accounts.get(market.address) {
+            None => mismatch(market.name, market.address, "market account not found".to_string()),
+            Some(account) if account.owner != market.program_id => {
+                mismatch(market.name, market.address, format!("owned by {}, expected {}", account.owner, market.program_id))
+            }
+            Some(_) => {}
+        }
+    }
+    report.checked = TOKENS.len() + PROGRAMS.len() + MARKETS.len();
+    report
+}
+
+/// Every address the registry refers to
+pub fn registry_addresses() -> Vec<&'static str> {
+    TOKENS
+        .iter()
+        .map(|token| token.mint)
+        .chain(PROGRAMS.iter().map(|program| program.program_id))
+        .chain(MARKETS.iter().map(|market| market.address))
+        .collect()
+}
+
+/// Fetch the registry accounts over RPC and [`verify`] them
+pub async fn verify_on_chain(client: &solana_client::nonblocking::rpc_client::RpcClient) -> anyhow::Result<HotPathVerification> {
+    let addresses = registry_addresses();
+    let mut accounts = HashMap::new();
+    // getMultipleAccounts takes at most 100 keys
+    for chunk in addresses.chunks(100) {
+        let keys = chunk.iter().map(|address| Pubkey::from_str(address)).collect::<Result<Vec<_>, _>>()?;
+        let fetched = client.get_multiple_accounts(&keys).await?;
+        for (address, account) in chunk.iter().zip(fetched) {
+            if let Some(account) = account {
+                accounts.insert(
+                    address.to_string(),
+                    ChainAccount { owner: account.owner.to_string(), executable: account.executable, data: account.data },
+                );
+            }
+        }
+    }
+    Ok(verify(&accounts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mint(decimals: u8) -> ChainAccount {
+        let mut data = vec![0u8; 82];
+        data[MINT_DECIMALS_OFFSET] = decimals;
+        ChainAccount { owner: programs::SPL_TOKEN.to_string(), executable: false, data }
+    }
+
+    fn consistent_chain() -> HashMap<String, ChainAccount> {
+        let mut accounts: HashMap<String, ChainAccount> = TOKENS.iter().map(|t| (t.mint.to_string(), mint(t.decimals))).collect();
+        for program in PROGRAMS {
+            accounts.insert(program.program_id.to_string(), ChainAccount { owner: "BPFLoaderUpgradeab1e11111111111111111111111".to_string(), executable: true, data: Vec::new() });
+        }
+        for market in MARKETS {
+            accounts.insert(market.address.to_string(), ChainAccount { owner: market.program_id.to_string(), executable: false, data: Vec::new() });
+        }
+        accounts
+    }
+
+    #[test]
+    fn test_generated_constants_match_registry_lookups() {
+        assert_eq!(tokens::SOL_DECIMALS, 9);
+        assert_eq!(token_decimals(tokens::USDC_MINT), Some(tokens::USDC_DECIMALS));
+        assert_eq!(token_mint("SOL"), Some(tokens::SOL_MINT));
+        assert_eq!(token_decimals("unknown"), None);
+        for market in MARKETS {
+            assert_eq!(tick_size(market.address), Some(market.tick_size));
+            assert!(PROGRAMS.iter().any(|program| program.program_id == market.program_id));
+        }
+        // Every address is a valid public key
+        for address in registry_addresses() {
+            assert!(Pubkey::from_str(address).is_ok(), "{}", address);
+        }
+        assert_eq!(REGISTRY_FINGERPRINT.len(), 16);
+    }
+
+    #[test]
+    fn test_verification_reports_drift_from_chain() {
+        let mut accounts = consistent_chain();
+        let report = verify(&accounts);
+        assert!(report.is_consistent(), "{:?}", report.mismatches);
+        assert_eq!(report.checked, registry_addresses().len());
+
+        accounts.insert(tokens::USDC_MINT.to_string(), mint(9));
+        accounts.get_mut(programs::PHOENIX).unwrap().executable = false;
+        accounts.remove(tokens::BONK_MINT);
+        let problems: HashMap<String, String> = verify(&accounts).mismatches.into_iter().map(|m| (m.name, m.problem)).collect();
+        assert_eq!(problems.len(), 3);
+        assert_eq!(problems["USDC"], "decimals 9 on chain, 6 in the registry");
+        assert_eq!(problems["PHOENIX"], "account is not executable");
+        assert_eq!(problems["BONK"], "mint account not found");
+    }
+}
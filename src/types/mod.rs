@@ -7,6 +7,20 @@ use std::{
     time::{Duration, Instant},
 };
 
+pub mod money;
+pub mod opportunity;
+pub mod hot_path; // Build-time constants from config/hot_path_registry.json
+
+pub use money::{
+    Money, money_from_f64, to_money, money_to_f64, round_money,
+    lamports_to_sol, sol_to_lamports, token_amount_to_decimal, decimal_to_token_amount,
+};
+pub use opportunity::{
+    Opportunity, OpportunityKind, RouteHop, IntoOpportunity, DEFAULT_OPPORTUNITY_TTL_SECS,
+    Expiring, OpportunityExpired, TtlPolicy,
+    to_base_units, sol_to_base_units, usd_opportunity,
+};
+
 /// Result type for SniperForge operations
 pub type ApiResult<T> = std::result::Result<T, String>;
 
@@ -167,25 +181,6 @@ pub struct TokenBalance {
     pub usd_value: Option<Decimal>,
 }
 
-/// Trading opportunity
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Opportunity {
-    /// Unique identifier
-    pub id: String,
-    /// Trading pair
-    pub pair: TradingPair,
-    /// Expected profit percentage
-    pub profit_bps: u16,
-    /// Recommended trade amount
-    pub amount: u64,
-    /// Route information
-    pub route: Vec<String>,
-    /// Confidence score (0-100)
-    pub confidence: u8,
-    /// Expiration time
-    pub expires_at: chrono::DateTime<chrono::Utc>,
-}
-
 /// Error types used throughout the system
 #[derive(Debug, thiserror::Error)]
 pub enum SniperForgeError {
@@ -229,14 +224,16 @@ pub type Result<T> = std::result::Result<T, SniperForgeError>;
 
 /// Common constants
 pub mod constants {
+    use super::hot_path::tokens;
+
     /// SOL mint address
-    pub const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+    pub const SOL_MINT: &str = tokens::SOL_MINT;
     
     /// USDC mint address
-    pub const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+    pub const USDC_MINT: &str = tokens::USDC_MINT;
     
     /// USDT mint address  
-    pub const USDT_MINT: &str = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB";
+    pub const USDT_MINT: &str = tokens::USDT_MINT;
     
     /// Lamports per SOL
     pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
@@ -0,0 +1,132 @@
+//! Decimal-precise money helpers for accounting paths
+//!
+//! PnL, balances and fees are accumulated as `rust_decimal::Decimal` (or as
+//! lamport-denominated integers) so repeated additions do not drift the way
+//! `f64` sums do. `f64` remains the right type for statistics and ML features;
+//! the helpers below are the documented conversion points between the two.
+
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::str::FromStr;
+
+/// Monetary amount used by accounting code (PnL, balances, fees)
+pub type Money = Decimal;
+
+/// Lamports in one SOL
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Decimal places kept for SOL-denominated money (lamport precision)
+pub const SOL_DECIMALS: u32 = 9;
+
+/// Decimal places kept for USD-denominated money
+pub const USD_DECIMALS: u32 = 6;
+
+/// Convert an `f64` into money using its shortest round-trip representation
+///
+/// `0.1_f64` becomes exactly `0.1` instead of `0.1000000000000000055...`.
+/// Returns `None` for NaN, infinities, or values outside the `Decimal` range.
+pub fn money_from_f64(value: f64) -> Option<Money> {
+    if !value.is_finite() {
+        return None;
+    }
+    Decimal::from_str(&value.to_string())
+        .ok()
+        .or_else(|| Decimal::from_f64(value))
+}
+
+/// Convert an `f64` into money, mapping invalid values to zero
+///
+/// Only for inputs already validated upstream (prices, sizes from our own
+/// calculations); prefer [`money_from_f64`] at system boundaries.
+pub fn to_money(value: f64) -> Money {
+    money_from_f64(value).unwrap_or(Decimal::ZERO)
+}
+
+/// Convert money to `f64` for statistics, ML features and display
+pub fn money_to_f64(value: Money) -> f64 {
+    value.to_f64().unwrap_or(0.0)
+}
+
+/// Round using banker's rounding (half to even), the accounting default
+pub fn round_money(value: Money, decimals: u32) -> Money {
+    value.round_dp_with_strategy(decimals, RoundingStrategy::MidpointNearestEven)
+}
+
+/// Lamports to SOL (exact)
+pub fn lamports_to_sol(lamports: u64) -> Money {
+    Decimal::from_i128_with_scale(i128::from(lamports), SOL_DECIMALS)
+}
+
+/// SOL to lamports, truncating sub-lamport dust toward zero
+///
+/// Returns `None` for negative amounts or values that overflow `u64`.
+pub fn sol_to_lamports(sol: Money) -> Option<u64> {
+    if sol.is_sign_negative() {
+        return None;
+    }
+    sol.checked_mul(Decimal::from(LAMPORTS_PER_SOL))?
+        .round_dp_with_strategy(0, RoundingStrategy::ToZero)
+        .to_u64()
+}
+
+/// Raw token units to a decimal amount (exact)
+pub fn token_amount_to_decimal(raw_amount: u64, decimals: u8) -> Money {
+    Decimal::from_i128_with_scale(i128::from(raw_amount), u32::from(decimals))
+}
+
+/// Decimal amount to raw token units, truncating toward zero
+pub fn decimal_to_token_amount(amount: Money, decimals: u8) -> Option<u64> {
+    if amount.is_sign_negative() {
+        return None;
+    }
+    let scale = Decimal::from(10u64.checked_pow(u32::from(decimals))?);
+    amount.checked_mul(scale)?
+        .round_dp_with_strategy(0, RoundingStrategy::ToZero)
+        .to_u64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_sum_does_not_drift() {
+        let mut f64_total = 0.0_f64;
+        let mut money_total = Money::ZERO;
+        for _ in 0..10 {
+            f64_total += 0.1;
+            money_total += to_money(0.1);
+        }
+        assert_ne!(f64_total, 1.0);
+        assert_eq!(money_total, Decimal::ONE);
+    }
+
+    #[test]
+    fn test_lamport_round_trip() {
+        assert_eq!(lamports_to_sol(1_500_000_000), Decimal::from_str("1.5").unwrap());
+        assert_eq!(sol_to_lamports(Decimal::from_str("1.5").unwrap()), Some(1_500_000_000));
+        // Sub-lamport dust is truncated, never rounded up
+        assert_eq!(sol_to_lamports(Decimal::from_str("0.0000000019").unwrap()), Some(1));
+        assert_eq!(sol_to_lamports(Decimal::from_str("-1").unwrap()), None);
+    }
+
+    #[test]
+    fn test_bankers_rounding() {
+        assert_eq!(round_money(Decimal::from_str("0.125").unwrap(), 2), Decimal::from_str("0.12").unwrap());
+        assert_eq!(round_money(Decimal::from_str("0.135").unwrap(), 2), Decimal::from_str("0.14").unwrap());
+        assert_eq!(round_money(Decimal::from_str("-0.125").unwrap(), 2), Decimal::from_str("-0.12").unwrap());
+    }
+
+    #[test]
+    fn test_token_amount_conversion() {
+        assert_eq!(token_amount_to_decimal(1_234_567, 6), Decimal::from_str("1.234567").unwrap());
+        assert_eq!(decimal_to_token_amount(Decimal::from_str("1.2345679").unwrap(), 6), Some(1_234_567));
+    }
+
+    #[test]
+    fn test_invalid_f64_rejected() {
+        assert_eq!(money_from_f64(f64::NAN), None);
+        assert_eq!(money_from_f64(f64::INFINITY), None);
+        assert_eq!(to_money(f64::NAN), Decimal::ZERO);
+    }
+}
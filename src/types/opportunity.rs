@@ -0,0 +1,298 @@
+//! Unified opportunity model
+//!
+//! Every engine reports opportunities in its own struct (percentages, SOL,
+//! USD, per-unit fractions). [`Opportunity`] is the common shape risk, dedup,
+//! scheduling and recording work with: the route as `(token, venue)` hops,
+//! expected profit and required capital in base units of a mint, confidence
+//! and an expiry. Engines implement [`IntoOpportunity`] next to their own
+//! structs.
+//!
+//! Scanners stamp every opportunity with an expiry from [`TtlPolicy`]: the
+//! more volatile the route, the shorter the window. Anything that implements
+//! [`Expiring`] can be pruned from queues and is rejected at execution time
+//! once the window has passed.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::constants::{LAMPORTS_PER_SOL, USDC_MINT};
+use super::ArbitrageOpportunity;
+
+/// Validity assumed when an engine does not report an execution window
+pub const DEFAULT_OPPORTUNITY_TTL_SECS: i64 = 30;
+
+/// USDC decimals, used for USD-denominated engine outputs
+pub const USDC_DECIMALS: u8 = 6;
+
+/// Validity window as a function of route volatility
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TtlPolicy {
+    /// Window for a route with no measurable volatility
+    pub base_secs: f64,
+    pub min_secs: f64,
+    pub max_secs: f64,
+    /// Volatility (percent) at which the window is halved
+    pub reference_volatility_pct: f64,
+}
+
+impl Default for TtlPolicy {
+    fn default() -> Self {
+        Self {
+            base_secs: DEFAULT_OPPORTUNITY_TTL_SECS as f64,
+            min_secs: 2.0,
+            max_secs: 120.0,
+            reference_volatility_pct: 1.0,
+        }
+    }
+}
+
+impl TtlPolicy {
+    /// Window for a route whose prices move `volatility_pct` percent
+    pub fn ttl(&self, volatility_pct: f64) -> Duration {
+        let volatility = if volatility_pct.is_finite() { volatility_pct.abs() } else { 0.0 };
+        let secs = self.base_secs / (1.0 + volatility / self.reference_volatility_pct.max(f64::EPSILON));
+        Duration::milliseconds((secs.clamp(self.min_secs, self.max_secs) * 1000.0) as i64)
+    }
+
+    pub fn expires_at(&self, detected_at: DateTime<Utc>, volatility_pct: f64) -> DateTime<Utc> {
+        detected_at + self.ttl(volatility_pct)
+    }
+}
+
+/// Execution attempted after the validity window closed
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("opportunity {id} expired {late_ms} ms ago (at {expires_at})")]
+pub struct OpportunityExpired {
+    pub id: String,
+    pub expires_at: DateTime<Utc>,
+    pub late_ms: i64,
+}
+
+/// Anything with a validity window
+pub trait Expiring {
+    fn opportunity_id(&self) -> String;
+
+    fn expires_at(&self) -> DateTime<Utc>;
+
+    fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at()
+    }
+
+    /// Rejection with reason when the window has passed
+    fn ensure_fresh(&self, now: DateTime<Utc>) -> Result<(), OpportunityExpired> {
+        if self.is_expired(now) {
+            Err(OpportunityExpired {
+                id: self.opportunity_id(),
+                expires_at: self.expires_at(),
+                late_ms: (now - self.expires_at()).num_milliseconds(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Engine that produced the opportunity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OpportunityKind {
+    Arbitrage,
+    Triangular,
+    FlashLoan,
+    CrossChain,
+}
+
+/// One leg of a route: trade out of `token` on `venue`
+///
+/// The final hop carries the token the route ends in and an empty venue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteHop {
+    pub token: String,
+    pub venue: String,
+}
+
+impl RouteHop {
+    pub fn new(token: impl Into<String>, venue: impl Into<String>) -> Self {
+        Self { token: token.into(), venue: venue.into() }
+    }
+}
+
+/// Engine-independent trading opportunity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Opportunity {
+    /// Engine-assigned identifier
+    pub id: String,
+    pub kind: OpportunityKind,
+    pub route: Vec<RouteHop>,
+    /// Mint profit and capital are denominated in
+    pub mint: String,
+    pub decimals: u8,
+    /// Expected net profit in base units of `mint` (negative = expected loss)
+    pub expected_profit: i64,
+    /// Capital the route needs in base units of `mint` (borrowed for flash loans)
+    pub required_capital: u64,
+    /// Confidence in [0, 1]
+    pub confidence: f64,
+    pub detected_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Expiring for Opportunity {
+    fn opportunity_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+}
+
+impl Opportunity {
+    /// Expected profit in whole units of `mint`
+    pub fn expected_profit_ui(&self) -> f64 {
+        self.expected_profit as f64 / 10f64.powi(self.decimals as i32)
+    }
+
+    /// Expected profit relative to required capital, in basis points
+    pub fn return_bps(&self) -> f64 {
+        if self.required_capital == 0 {
+            0.0
+        } else {
+            self.expected_profit as f64 / self.required_capital as f64 * 10_000.0
+        }
+    }
+
+    /// `(token, venue)` pairs, the form `RouteSignature::from_hops` takes
+    pub fn hops(&self) -> Vec<(String, String)> {
+        self.route.iter().map(|hop| (hop.token.clone(), hop.venue.clone())).collect()
+    }
+}
+
+/// Conversion of an engine's opportunity into the unified model
+pub trait IntoOpportunity {
+    fn to_opportunity(&self) -> Opportunity;
+}
+
+/// Whole units to base units, rounded to the nearest unit
+pub fn to_base_units(amount: f64, decimals: u8) -> i64 {
+    (amount * 10f64.powi(decimals as i32)).round() as i64
+}
+
+/// SOL to lamports as a signed base-unit amount
+pub fn sol_to_base_units(sol: f64) -> i64 {
+    (sol * LAMPORTS_PER_SOL as f64).round() as i64
+}
+
+/// Build a USD-denominated opportunity (settled in USDC base units)
+pub fn usd_opportunity(
+    id: String,
+    kind: OpportunityKind,
+    route: Vec<RouteHop>,
+    profit_usd: f64,
+    capital_usd: f64,
+    confidence: f64,
+    detected_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+) -> Opportunity {
+    Opportunity {
+        id,
+        kind,
+        route,
+        mint: USDC_MINT.to_string(),
+        decimals: USDC_DECIMALS,
+        expected_profit: to_base_units(profit_usd, USDC_DECIMALS),
+        required_capital: to_base_units(capital_usd, USDC_DECIMALS).max(0) as u64,
+        confidence: confidence.clamp(0.0, 1.0),
+        detected_at,
+        expires_at,
+    }
+}
+
+impl ArbitrageOpportunity {
+    fn id(&self) -> String {
+        format!("{}-{}-{}-{}", self.pair.base_token.symbol, self.buy_exchange, self.sell_exchange, self.timestamp.timestamp_millis())
+    }
+}
+
+impl Expiring for ArbitrageOpportunity {
+    fn opportunity_id(&self) -> String {
+        self.id()
+    }
+
+    fn expires_at(&self) -> DateTime<Utc> {
+        let window = Duration::from_std(self.execution_time_window)
+            .unwrap_or_else(|_| Duration::seconds(DEFAULT_OPPORTUNITY_TTL_SECS));
+        self.timestamp + window
+    }
+}
+
+impl IntoOpportunity for ArbitrageOpportunity {
+    /// `volume_required` is the USD notional; `profit_percentage` is in percent
+    fn to_opportunity(&self) -> Opportunity {
+        let base = self.pair.base_token.symbol.clone();
+        usd_opportunity(
+            self.id(),
+            OpportunityKind::Arbitrage,
+            vec![
+                RouteHop::new(base.clone(), self.buy_exchange.clone()),
+                RouteHop::new(self.pair.quote_token.symbol.clone(), self.sell_exchange.clone()),
+                RouteHop::new(base, ""),
+            ],
+            self.volume_required * self.profit_percentage / 100.0,
+            self.volume_required,
+            self.confidence_score,
+            self.timestamp,
+            self.expires_at(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arbitrage_conversion_uses_usdc_base_units() {
+        let arbitrage = ArbitrageOpportunity {
+            profit_percentage: 0.5,
+            volume_required: 1_000.0,
+            ..Default::default()
+        };
+        let opportunity = arbitrage.to_opportunity();
+        assert_eq!(opportunity.kind, OpportunityKind::Arbitrage);
+        assert_eq!(opportunity.expected_profit, 5_000_000);
+        assert_eq!(opportunity.required_capital, 1_000_000_000);
+        assert!((opportunity.return_bps() - 50.0).abs() < 1e-9);
+        assert_eq!(opportunity.route.len(), 3);
+        assert_eq!(opportunity.expires_at - opportunity.detected_at, Duration::seconds(30));
+    }
+
+    #[test]
+    fn test_ttl_shrinks_with_volatility_and_rejects_late_execution() {
+        let policy = TtlPolicy::default();
+        assert_eq!(policy.ttl(0.0), Duration::seconds(30));
+        assert_eq!(policy.ttl(1.0), Duration::seconds(15));
+        assert_eq!(policy.ttl(1_000.0), Duration::seconds(2));
+
+        let now = Utc::now();
+        let arbitrage = ArbitrageOpportunity {
+            timestamp: now - Duration::seconds(40),
+            ..Default::default()
+        };
+        let rejection = arbitrage.ensure_fresh(now).unwrap_err();
+        assert_eq!(rejection.late_ms, 10_000);
+    }
+
+    #[test]
+    fn test_expiry_and_ui_amounts() {
+        let now = Utc::now();
+        let opportunity = usd_opportunity(
+            "x".to_string(), OpportunityKind::CrossChain, Vec::new(), -1.25, 100.0, 1.7,
+            now, now + Duration::seconds(5),
+        );
+        assert_eq!(opportunity.expected_profit_ui(), -1.25);
+        assert_eq!(opportunity.confidence, 1.0);
+        assert!(!opportunity.is_expired(now));
+        assert!(opportunity.is_expired(now + Duration::seconds(5)));
+    }
+}
@@ -0,0 +1,191 @@
+//! Message catalog for user-facing text
+//!
+//! CLI output and API response messages are looked up here by key instead of
+//! being written inline, so operators get them in their language. The locale
+//! comes from `SNIPERFORGE_LOCALE` (falling back to `LANG`) and defaults to
+//! English; messages missing a translation fall back to English too.
+//!
+//! Tracing logs are deliberately not localized: they are written in English
+//! only, so log searches, alert rules and bug reports stay comparable.
+//!
+//! Placeholders are written `{name}` and filled by [`tf`].
+
+use std::sync::OnceLock;
+
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+
+/// Environment variable selecting the locale (e.g. `es`, `es_ES.UTF-8`)
+pub const LOCALE_ENV: &str = "SNIPERFORGE_LOCALE";
+
+/// Supported output languages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    Es,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::Es];
+
+    /// Parse a language tag such as `es`, `es-AR` or `es_ES.UTF-8`
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.split(['-', '_', '.']).next()?.trim().to_ascii_lowercase();
+        match language.as_str() {
+            "en" => Some(Locale::En),
+            "es" => Some(Locale::Es),
+            _ => None,
+        }
+    }
+
+    /// Locale from `SNIPERFORGE_LOCALE`, then `LANG`, else English
+    pub fn from_env() -> Self {
+        [LOCALE_ENV, "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find_map(|tag| Self::from_tag(&tag))
+            .unwrap_or_default()
+    }
+
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+        }
+    }
+}
+
+/// key, English, Spanish
+const CATALOG: &[(&str, &str, &str)] = &[
+    // CLI
+    ("cli.no_subcommand", "No subcommand provided.", "No se indicó ningún subcomando."),
+    ("cli.available_commands", "Available commands:", "Comandos disponibles:"),
+    ("cli.more_info", "Use: {program} <COMMAND> --help for more information", "Uso: {program} <COMANDO> --help para más información"),
+    ("cli.server_responsive", "Server is responsive", "El servidor responde"),
+    ("cli.unexpected_response", "Unexpected response: {response}", "Respuesta inesperada: {response}"),
+    ("cli.error", "Error: {error}", "Error: {error}"),
+    ("cli.registered_bots", "Registered Bots ({count}):", "Bots registrados ({count}):"),
+    ("cli.invalid_bot_type", "Invalid bot type: {bot_type}", "Tipo de bot no válido: {bot_type}"),
+    ("cli.available_types", "Available types: {types}", "Tipos disponibles: {types}"),
+    ("cli.bot_created", "Bot created successfully: {bot_id}", "Bot creado correctamente: {bot_id}"),
+    ("cli.bot_start_hint", "Use 'start-bot --bot-id {bot_id}' to start it", "Use 'start-bot --bot-id {bot_id}' para iniciarlo"),
+    // API responses
+    ("api.bots.listed", "Bots listed successfully", "Bots listados correctamente"),
+    ("api.bots.created", "Bot created successfully", "Bot creado correctamente"),
+    ("api.bots.create_failed", "Failed to create bot: {error}", "No se pudo crear el bot: {error}"),
+    ("api.bots.found", "Bot found", "Bot encontrado"),
+    ("api.bots.not_found", "Bot not found", "Bot no encontrado"),
+    ("api.bots.id_not_found", "Bot {bot_id} not found", "Bot {bot_id} no encontrado"),
+    ("api.bots.deleted", "Bot deleted successfully", "Bot eliminado correctamente"),
+    ("api.bots.start_sent", "Bot start command sent", "Orden de inicio enviada al bot"),
+    ("api.bots.stop_sent", "Bot stop command sent", "Orden de parada enviada al bot"),
+    ("api.bots.config_updated", "Bot configuration updated", "Configuración del bot actualizada"),
+    ("api.bots.status", "Bot status retrieved", "Estado del bot obtenido"),
+    ("api.bots.metrics", "Bot metrics retrieved", "Métricas del bot obtenidas"),
+    ("api.bots.health", "Bot health status retrieved", "Salud del bot obtenida"),
+    ("api.bots.config_schema", "Bot config schema retrieved", "Esquema de configuración del bot obtenido"),
+    ("api.bots.types", "Bot types retrieved", "Tipos de bot obtenidos"),
+    ("api.system.healthy", "System is healthy", "El sistema está sano"),
+    ("api.system.metrics", "System metrics retrieved", "Métricas del sistema obtenidas"),
+    ("api.system.status", "System status retrieved", "Estado del sistema obtenido"),
+    ("api.system.build", "Build information retrieved", "Información de compilación obtenida"),
+    ("api.risk.headroom", "Token headroom retrieved", "Margen por token obtenido"),
+    ("api.capital.withdrawals", "Withdrawals retrieved", "Retiros obtenidos"),
+    ("api.capital.locked", "{amount} {asset} locked for withdrawal", "{amount} {asset} bloqueados para retiro"),
+    ("api.capital.refused", "Withdrawal refused: {error}", "Retiro rechazado: {error}"),
+    ("api.capital.summary", "Withdrawal summary retrieved", "Resumen de retiros obtenido"),
+    ("api.capital.cancelled", "Withdrawal {id} cancelled", "Retiro {id} cancelado"),
+    ("api.capital.profit_taking", "Profit-taking status retrieved", "Estado de la toma de ganancias obtenido"),
+    ("api.monitoring.latency", "Latency heatmap retrieved", "Mapa de latencias obtenido"),
+    ("api.monitoring.degradations", "Latency degradations retrieved", "Degradaciones de latencia obtenidas"),
+    ("api.monitoring.metric_series", "Metric series retrieved", "Series de métricas obtenidas"),
+    ("api.monitoring.metric_history", "{count} points of {metric} retrieved", "{count} puntos de {metric} obtenidos"),
+    ("api.webhooks.listed", "Webhook endpoints retrieved", "Destinos de webhook obtenidos"),
+    ("api.webhooks.registered", "Webhook endpoint registered", "Destino de webhook registrado"),
+    ("api.webhooks.invalid", "Invalid webhook endpoint: {error}", "Destino de webhook no válido: {error}"),
+    ("api.webhooks.not_found", "Webhook endpoint {id} not found", "Destino de webhook {id} no encontrado"),
+    ("api.webhooks.removed", "Webhook endpoint {id} removed", "Destino de webhook {id} eliminado"),
+    ("api.webhooks.deliveries", "Webhook deliveries retrieved", "Entregas de webhook obtenidas"),
+    ("api.webhooks.unauthorized", "Invalid webhook authorization", "Autorización de webhook no válida"),
+    ("api.webhooks.invalid_payload", "Invalid webhook payload: {error}", "Contenido de webhook no válido: {error}"),
+    ("api.webhooks.processed", "Processed {count} transactions", "{count} transacciones procesadas"),
+];
+
+fn current() -> &'static RwLock<Locale> {
+    static LOCALE: OnceLock<RwLock<Locale>> = OnceLock::new();
+    LOCALE.get_or_init(|| RwLock::new(Locale::from_env()))
+}
+
+/// Locale used by [`t`] and [`tf`]
+pub fn locale() -> Locale {
+    *current().read()
+}
+
+/// Override the process locale (e.g. from a `--lang` flag)
+pub fn set_locale(locale: Locale) {
+    *current().write() = locale;
+}
+
+/// Message `key` in `locale`; English when untranslated, the key itself when unknown
+pub fn message(locale: Locale, key: &str) -> &str {
+    CATALOG
+        .iter()
+        .find(|(k, _, _)| *k == key)
+        .map_or(key, |(_, en, es)| match locale {
+            Locale::Es if !es.is_empty() => es,
+            _ => en,
+        })
+}
+
+/// Message `key` in the process locale
+pub fn t(key: &str) -> String {
+    message(locale(), key).to_string()
+}
+
+/// Message `key` in the process locale with `{name}` placeholders filled
+pub fn tf(key: &str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+    format_message(message(locale(), key), args)
+}
+
+fn format_message(template: &str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+    args.iter().fold(template.to_string(), |text, (name, value)| {
+        text.replace(&format!("{{{}}}", name), &value.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn placeholders(text: &str) -> HashSet<&str> {
+        text.split('{').skip(1).filter_map(|part| part.split_once('}').map(|(name, _)| name)).collect()
+    }
+
+    #[test]
+    fn test_catalog_complete_and_consistent() {
+        let mut keys = HashSet::new();
+        for (key, en, es) in CATALOG {
+            assert!(keys.insert(*key), "duplicate key {}", key);
+            assert!(!en.is_empty() && !es.is_empty(), "{} is missing a translation", key);
+            assert_eq!(placeholders(en), placeholders(es), "{} placeholders differ between locales", key);
+        }
+    }
+
+    #[test]
+    fn test_lookup_formatting_and_fallbacks() {
+        assert_eq!(Locale::from_tag("es_ES.UTF-8"), Some(Locale::Es));
+        assert_eq!(Locale::from_tag("en-GB"), Some(Locale::En));
+        assert_eq!(Locale::from_tag("C"), None);
+
+        assert_eq!(message(Locale::Es, "api.bots.not_found"), "Bot no encontrado");
+        assert_eq!(message(Locale::En, "api.bots.not_found"), "Bot not found");
+        assert_eq!(message(Locale::Es, "no.such.key"), "no.such.key");
+        assert_eq!(
+            format_message(message(Locale::Es, "api.capital.locked"), &[("amount", &"1.5000"), ("asset", &"SOL")]),
+            "1.5000 SOL bloqueados para retiro"
+        );
+    }
+}
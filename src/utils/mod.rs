@@ -7,9 +7,11 @@ pub mod validation;
 pub mod logging;
 pub mod config_loader;
 pub mod tatum_client;
+pub mod i18n;
 
 pub use validation::*;
 pub use tatum_client::{TatumClient, TatumRpcClient};
+pub use i18n::{Locale, LOCALE_ENV};
 pub use logging::*;
 pub use config_loader::*;
 // TODO: Implement these modules
@@ -98,6 +98,7 @@ fn test_strategy_config_creation() {
         take_profit_percent: 5.0,
         min_confidence: 0.7,
         timeframes: vec![],
+        shadow: false,
     };
     
     assert_eq!(config.name, "Test Arbitrage Strategy");